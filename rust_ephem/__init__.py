@@ -1,18 +1,72 @@
 from ._rust_ephem import (
+    AccessOpportunity,
+    AirmassSummary,
+    AlmanacEphemeris,
+    ArrayEphemeris,
+    AttitudeCheck,
+    AttitudeViolation,
+    CancellationToken,
     Constraint,
+    ConstraintCube,
+    CoverageGrid,
+    CoverageResult,
+    DecayEstimate,
+    EphemerisComparison,
+    EphemerisSample,
+    FaceIncidence,
     FileEphemeris,
+    GEOEphemeris,
     GroundEphemeris,
+    GroundNetwork,
+    HorizonsEphemeris,
+    LinkGeometry,
+    ManeuverCandidate,
+    MinorPlanet,
     MovingBodyResult,
+    NetworkContacts,
+    NodalAnalysis,
     OEMEphemeris,
+    ObservationGeometry,
+    OrbitVisibility,
     PositionVelocityData,
+    Provenance,
+    RinexNavEphemeris,
     SPICEEphemeris,
+    Schedule,
+    ScheduledObservation,
+    SlewCheck,
+    SlewIntrusion,
+    SolarEclipse,
+    SphericalPolygon,
+    StationContacts,
+    TLEAccuracy,
     TLEEphemeris,
+    TrajectoryGroundEphemeris,
+    VisibilityReport,
     VisibilityWindow,
+    airmass_summary,
+    align,
+    check_attitude,
+    check_slew,
+    clear_horizons_cache,
+    clear_minor_planets,
+    clear_name_cache,
+    compare,
+    constraint_cube,
+    detect_maneuvers,
     download_planetary_ephemeris,
+    enable_profiling,
     ensure_planetary_ephemeris,
+    estimate_decay,
+    estimate_tle_accuracy,
+    face_sun_incidence,
+    get_body_radii,
     get_cache_dir,
+    get_config,
     get_polar_motion,
+    get_site,
     get_tai_utc_offset,
+    get_tle_cache_defaults,
     get_ut1_utc_offset,
     init_eop_provider,
     init_planetary_ephemeris,
@@ -20,7 +74,25 @@ from ._rust_ephem import (
     is_eop_available,
     is_planetary_ephemeris_initialized,
     is_ut1_available,
+    link_geometry,
+    list_sites,
+    load_mpcorb,
+    nodal_analysis,
+    orbit_visibility,
+    profiling_report,
+    register_site,
+    reset_body_radii,
+    reset_profiling,
+    resolve_name,
+    schedule,
+    set_body_radii,
+    set_tle_cache_defaults,
+    transformation_accuracy,
+    validate_elements,
+    virtual_target_radec,
+    visibility_report,
 )
+from . import sphere
 from .bright_stars import get_bright_stars
 from .constraints import (
     AirmassConstraint,
@@ -34,9 +106,15 @@ from .constraints import (
     ConstraintConfig,
     ConstraintResult,
     ConstraintViolation,
+    CutoffRigidityConstraint,
+    GeomagneticActivityConstraint,
     DaytimeConstraint,
     EarthLimbConstraint,
+    EarthOccultationConstraint,
     EclipseConstraint,
+    GimbalConstraint,
+    GroundTargetIlluminationConstraint,
+    GroundVisibilityConstraint,
     MoonConstraint,
     MoonPhaseConstraint,
     MovingVisibilityResult,
@@ -44,18 +122,38 @@ from .constraints import (
     OrbitPoleConstraint,
     OrbitRamConstraint,
     OrConstraint,
+    PlanetsConstraint,
     SAAConstraint,
+    SkyRegionConstraint,
+    StationKeepingConstraint,
     SunConstraint,
+    SunKeepOutConstraint,
+    ThresholdPeriod,
     XorConstraint,
+    threshold_schedule,
 )
 from .ephemeris import Ephemeris, EphemerisType
+from .space_weather import (
+    get_space_weather,
+    init_space_weather,
+    is_space_weather_available,
+    load_space_weather,
+    space_weather_series,
+)
 from .tle import TLERecord, fetch_tle
+from .visibility_stats import (
+    cumulative_visibility,
+    gantt_rows,
+    window_duration_histogram,
+)
 
 __all__ = [
     "ConstraintConfig",
     "SunConstraint",
+    "SunKeepOutConstraint",
     "MoonConstraint",
     "EarthLimbConstraint",
+    "EarthOccultationConstraint",
     "EclipseConstraint",
     "BodyConstraint",
     "DaytimeConstraint",
@@ -64,9 +162,19 @@ __all__ = [
     "MoonPhaseConstraint",
     "MovingVisibilityResult",
     "SAAConstraint",
+    "SkyRegionConstraint",
+    "CutoffRigidityConstraint",
+    "GeomagneticActivityConstraint",
+    "StationKeepingConstraint",
+    "GroundTargetIlluminationConstraint",
     "AltAzConstraint",
+    "GimbalConstraint",
+    "GroundVisibilityConstraint",
     "OrbitRamConstraint",
+    "PlanetsConstraint",
     "OrbitPoleConstraint",
+    "ThresholdPeriod",
+    "threshold_schedule",
     "CombinedConstraintConfig",
     "AndConstraint",
     "BoresightOffsetConstraint",
@@ -75,9 +183,46 @@ __all__ = [
     "NotConstraint",
     "TLEEphemeris",
     "SPICEEphemeris",
+    "Schedule",
+    "ScheduledObservation",
+    "VisibilityReport",
+    "OrbitVisibility",
+    "ConstraintCube",
+    "AttitudeCheck",
+    "AttitudeViolation",
+    "FaceIncidence",
+    "SlewCheck",
+    "SlewIntrusion",
+    "AirmassSummary",
+    "CancellationToken",
+    "TLEAccuracy",
+    "DecayEstimate",
     "OEMEphemeris",
     "GroundEphemeris",
+    "TrajectoryGroundEphemeris",
     "FileEphemeris",
+    "HorizonsEphemeris",
+    "AlmanacEphemeris",
+    "RinexNavEphemeris",
+    "ArrayEphemeris",
+    "GEOEphemeris",
+    "MinorPlanet",
+    "GroundNetwork",
+    "NetworkContacts",
+    "NodalAnalysis",
+    "StationContacts",
+    "LinkGeometry",
+    "ManeuverCandidate",
+    "AccessOpportunity",
+    "SolarEclipse",
+    "SphericalPolygon",
+    "sphere",
+    "CoverageGrid",
+    "CoverageResult",
+    "EphemerisComparison",
+    "EphemerisSample",
+    "ObservationGeometry",
+    "Provenance",
     "Ephemeris",
     "EphemerisType",
     "PositionVelocityData",
@@ -89,6 +234,20 @@ __all__ = [
     "init_planetary_ephemeris",
     "download_planetary_ephemeris",
     "ensure_planetary_ephemeris",
+    "estimate_tle_accuracy",
+    "estimate_decay",
+    "validate_elements",
+    "link_geometry",
+    "schedule",
+    "visibility_report",
+    "orbit_visibility",
+    "constraint_cube",
+    "detect_maneuvers",
+    "check_attitude",
+    "check_slew",
+    "face_sun_incidence",
+    "virtual_target_radec",
+    "airmass_summary",
     "is_planetary_ephemeris_initialized",
     "get_tai_utc_offset",
     "get_ut1_utc_offset",
@@ -97,9 +256,38 @@ __all__ = [
     "get_polar_motion",
     "is_eop_available",
     "init_eop_provider",
+    "transformation_accuracy",
+    "get_space_weather",
+    "init_space_weather",
+    "is_space_weather_available",
+    "load_space_weather",
+    "space_weather_series",
     "get_cache_dir",
+    "get_config",
+    "enable_profiling",
+    "profiling_report",
+    "reset_profiling",
+    "set_tle_cache_defaults",
+    "get_tle_cache_defaults",
+    "clear_horizons_cache",
+    "clear_name_cache",
+    "resolve_name",
+    "load_mpcorb",
+    "nodal_analysis",
+    "clear_minor_planets",
+    "compare",
+    "align",
+    "get_site",
+    "list_sites",
+    "register_site",
+    "set_body_radii",
+    "get_body_radii",
+    "reset_body_radii",
     "TLERecord",
     "fetch_tle",
     "BrightStarConstraint",
     "get_bright_stars",
+    "gantt_rows",
+    "cumulative_visibility",
+    "window_duration_histogram",
 ]
@@ -1,48 +1,212 @@
 """Type stubs for rust_ephem package"""
 
 # Re-export from _rust_ephem
+from rust_ephem._rust_ephem import (
+    AccessOpportunity as AccessOpportunity,
+)
+from rust_ephem._rust_ephem import (
+    AirmassSummary as AirmassSummary,
+)
+from rust_ephem._rust_ephem import (
+    AlmanacEphemeris as AlmanacEphemeris,
+)
+from rust_ephem._rust_ephem import (
+    ArrayEphemeris as ArrayEphemeris,
+)
+from rust_ephem._rust_ephem import (
+    AttitudeCheck as AttitudeCheck,
+)
+from rust_ephem._rust_ephem import (
+    AttitudeViolation as AttitudeViolation,
+)
+from rust_ephem._rust_ephem import (
+    CancellationToken as CancellationToken,
+)
 from rust_ephem._rust_ephem import (
     Constraint as Constraint,
 )
+from rust_ephem._rust_ephem import (
+    ConstraintCube as ConstraintCube,
+)
+from rust_ephem._rust_ephem import (
+    CoverageGrid as CoverageGrid,
+)
+from rust_ephem._rust_ephem import (
+    CoverageResult as CoverageResult,
+)
+from rust_ephem._rust_ephem import (
+    DecayEstimate as DecayEstimate,
+)
+from rust_ephem._rust_ephem import (
+    EphemerisComparison as EphemerisComparison,
+)
+from rust_ephem._rust_ephem import (
+    EphemerisSample as EphemerisSample,
+)
+from rust_ephem._rust_ephem import (
+    FaceIncidence as FaceIncidence,
+)
 from rust_ephem._rust_ephem import (
     FileEphemeris as FileEphemeris,
 )
+from rust_ephem._rust_ephem import (
+    GEOEphemeris as GEOEphemeris,
+)
 from rust_ephem._rust_ephem import (
     GroundEphemeris as GroundEphemeris,
 )
+from rust_ephem._rust_ephem import (
+    GroundNetwork as GroundNetwork,
+)
+from rust_ephem._rust_ephem import (
+    HorizonsEphemeris as HorizonsEphemeris,
+)
+from rust_ephem._rust_ephem import (
+    LinkGeometry as LinkGeometry,
+)
+from rust_ephem._rust_ephem import (
+    ManeuverCandidate as ManeuverCandidate,
+)
+from rust_ephem._rust_ephem import (
+    MinorPlanet as MinorPlanet,
+)
 from rust_ephem._rust_ephem import (
     MovingBodyResult as MovingBodyResult,
 )
+from rust_ephem._rust_ephem import (
+    NetworkContacts as NetworkContacts,
+)
+from rust_ephem._rust_ephem import (
+    NodalAnalysis as NodalAnalysis,
+)
 from rust_ephem._rust_ephem import (
     OEMEphemeris as OEMEphemeris,
 )
+from rust_ephem._rust_ephem import (
+    ObservationGeometry as ObservationGeometry,
+)
+from rust_ephem._rust_ephem import (
+    OrbitVisibility as OrbitVisibility,
+)
 from rust_ephem._rust_ephem import (
     PositionVelocityData as PositionVelocityData,
 )
+from rust_ephem._rust_ephem import (
+    Provenance as Provenance,
+)
+from rust_ephem._rust_ephem import (
+    RinexNavEphemeris as RinexNavEphemeris,
+)
 from rust_ephem._rust_ephem import (
     SPICEEphemeris as SPICEEphemeris,
 )
+from rust_ephem._rust_ephem import (
+    Schedule as Schedule,
+)
+from rust_ephem._rust_ephem import (
+    ScheduledObservation as ScheduledObservation,
+)
+from rust_ephem._rust_ephem import (
+    SlewCheck as SlewCheck,
+)
+from rust_ephem._rust_ephem import (
+    SlewIntrusion as SlewIntrusion,
+)
+from rust_ephem._rust_ephem import (
+    SolarEclipse as SolarEclipse,
+)
+from rust_ephem._rust_ephem import (
+    SphericalPolygon as SphericalPolygon,
+)
+from rust_ephem._rust_ephem import (
+    StationContacts as StationContacts,
+)
+from rust_ephem._rust_ephem import (
+    TLEAccuracy as TLEAccuracy,
+)
 from rust_ephem._rust_ephem import (
     TLEEphemeris as TLEEphemeris,
 )
+from rust_ephem._rust_ephem import (
+    TrajectoryGroundEphemeris as TrajectoryGroundEphemeris,
+)
+from rust_ephem._rust_ephem import (
+    VisibilityReport as VisibilityReport,
+)
 from rust_ephem._rust_ephem import (
     VisibilityWindow as VisibilityWindow,
 )
+from rust_ephem._rust_ephem import (
+    airmass_summary as airmass_summary,
+)
+from rust_ephem._rust_ephem import (
+    align as align,
+)
+from rust_ephem._rust_ephem import (
+    check_attitude as check_attitude,
+)
+from rust_ephem._rust_ephem import (
+    check_slew as check_slew,
+)
+from rust_ephem._rust_ephem import (
+    clear_horizons_cache as clear_horizons_cache,
+)
+from rust_ephem._rust_ephem import (
+    clear_minor_planets as clear_minor_planets,
+)
+from rust_ephem._rust_ephem import (
+    clear_name_cache as clear_name_cache,
+)
+from rust_ephem._rust_ephem import (
+    compare as compare,
+)
+from rust_ephem._rust_ephem import (
+    constraint_cube as constraint_cube,
+)
+from rust_ephem._rust_ephem import (
+    detect_maneuvers as detect_maneuvers,
+)
 from rust_ephem._rust_ephem import (
     download_planetary_ephemeris as download_planetary_ephemeris,
 )
+from rust_ephem._rust_ephem import (
+    enable_profiling as enable_profiling,
+)
 from rust_ephem._rust_ephem import (
     ensure_planetary_ephemeris as ensure_planetary_ephemeris,
 )
+from rust_ephem._rust_ephem import (
+    estimate_decay as estimate_decay,
+)
+from rust_ephem._rust_ephem import (
+    estimate_tle_accuracy as estimate_tle_accuracy,
+)
+from rust_ephem._rust_ephem import (
+    face_sun_incidence as face_sun_incidence,
+)
+from rust_ephem._rust_ephem import (
+    get_body_radii as get_body_radii,
+)
 from rust_ephem._rust_ephem import (
     get_cache_dir as get_cache_dir,
 )
+from rust_ephem._rust_ephem import (
+    get_config as get_config,
+)
 from rust_ephem._rust_ephem import (
     get_polar_motion as get_polar_motion,
 )
+from rust_ephem._rust_ephem import (
+    get_site as get_site,
+)
 from rust_ephem._rust_ephem import (
     get_tai_utc_offset as get_tai_utc_offset,
 )
+from rust_ephem._rust_ephem import (
+)
+from rust_ephem._rust_ephem import (
+    get_tle_cache_defaults as get_tle_cache_defaults,
+)
 from rust_ephem._rust_ephem import (
     get_ut1_utc_offset as get_ut1_utc_offset,
 )
@@ -64,6 +228,59 @@ from rust_ephem._rust_ephem import (
 from rust_ephem._rust_ephem import (
     is_ut1_available as is_ut1_available,
 )
+from rust_ephem._rust_ephem import (
+    link_geometry as link_geometry,
+)
+from rust_ephem._rust_ephem import (
+    list_sites as list_sites,
+)
+from rust_ephem._rust_ephem import (
+    load_mpcorb as load_mpcorb,
+)
+from rust_ephem._rust_ephem import (
+    nodal_analysis as nodal_analysis,
+)
+from rust_ephem._rust_ephem import (
+    orbit_visibility as orbit_visibility,
+)
+from rust_ephem._rust_ephem import (
+    profiling_report as profiling_report,
+)
+from rust_ephem._rust_ephem import (
+    register_site as register_site,
+)
+from rust_ephem._rust_ephem import (
+    reset_body_radii as reset_body_radii,
+)
+from rust_ephem._rust_ephem import (
+    reset_profiling as reset_profiling,
+)
+from rust_ephem._rust_ephem import (
+    resolve_name as resolve_name,
+)
+from rust_ephem._rust_ephem import (
+    schedule as schedule,
+)
+from rust_ephem._rust_ephem import (
+    set_body_radii as set_body_radii,
+)
+from rust_ephem._rust_ephem import (
+)
+from rust_ephem._rust_ephem import (
+    set_tle_cache_defaults as set_tle_cache_defaults,
+)
+from rust_ephem._rust_ephem import (
+    transformation_accuracy as transformation_accuracy,
+)
+from rust_ephem._rust_ephem import (
+    validate_elements as validate_elements,
+)
+from rust_ephem._rust_ephem import (
+    virtual_target_radec as virtual_target_radec,
+)
+from rust_ephem._rust_ephem import (
+    visibility_report as visibility_report,
+)
 from rust_ephem.bright_stars import (
     get_bright_stars as get_bright_stars,
 )
@@ -100,15 +317,33 @@ from rust_ephem.constraints import (
 from rust_ephem.constraints import (
     ConstraintViolation as ConstraintViolation,
 )
+from rust_ephem.constraints import (
+    CutoffRigidityConstraint as CutoffRigidityConstraint,
+)
 from rust_ephem.constraints import (
     DaytimeConstraint as DaytimeConstraint,
 )
 from rust_ephem.constraints import (
     EarthLimbConstraint as EarthLimbConstraint,
 )
+from rust_ephem.constraints import (
+    EarthOccultationConstraint as EarthOccultationConstraint,
+)
 from rust_ephem.constraints import (
     EclipseConstraint as EclipseConstraint,
 )
+from rust_ephem.constraints import (
+    GeomagneticActivityConstraint as GeomagneticActivityConstraint,
+)
+from rust_ephem.constraints import (
+    GimbalConstraint as GimbalConstraint,
+)
+from rust_ephem.constraints import (
+    GroundTargetIlluminationConstraint as GroundTargetIlluminationConstraint,
+)
+from rust_ephem.constraints import (
+    GroundVisibilityConstraint as GroundVisibilityConstraint,
+)
 from rust_ephem.constraints import (
     MoonConstraint as MoonConstraint,
 )
@@ -127,15 +362,35 @@ from rust_ephem.constraints import (
 from rust_ephem.constraints import (
     OrConstraint as OrConstraint,
 )
+from rust_ephem.constraints import (
+    PlanetsConstraint as PlanetsConstraint,
+)
 from rust_ephem.constraints import (
     SAAConstraint as SAAConstraint,
 )
+from rust_ephem.constraints import (
+    SkyRegionConstraint as SkyRegionConstraint,
+)
+from rust_ephem.constraints import (
+    StationKeepingConstraint as StationKeepingConstraint,
+)
 from rust_ephem.constraints import (
     SunConstraint as SunConstraint,
 )
+from rust_ephem.constraints import (
+    SunKeepOutConstraint as SunKeepOutConstraint,
+)
+from rust_ephem.constraints import (
+    ThresholdPeriod as ThresholdPeriod,
+)
 from rust_ephem.constraints import (
     XorConstraint as XorConstraint,
 )
+from rust_ephem.constraints import (
+    threshold_schedule as threshold_schedule,
+)
+
+from . import sphere as sphere
 
 # Re-export from ephemeris
 from .ephemeris import (
@@ -144,11 +399,37 @@ from .ephemeris import (
 from .ephemeris import (
     EphemerisType as EphemerisType,
 )
+from .space_weather import (
+    get_space_weather as get_space_weather,
+)
+from .space_weather import (
+    init_space_weather as init_space_weather,
+)
+from .space_weather import (
+    is_space_weather_available as is_space_weather_available,
+)
+from .space_weather import (
+    load_space_weather as load_space_weather,
+)
+from .space_weather import (
+    space_weather_series as space_weather_series,
+)
+from .visibility_stats import (
+    cumulative_visibility as cumulative_visibility,
+)
+from .visibility_stats import (
+    gantt_rows as gantt_rows,
+)
+from .visibility_stats import (
+    window_duration_histogram as window_duration_histogram,
+)
 
 __all__ = [
     "SunConstraint",
+    "SunKeepOutConstraint",
     "MoonConstraint",
     "EarthLimbConstraint",
+    "EarthOccultationConstraint",
     "EclipseConstraint",
     "BodyConstraint",
     "DaytimeConstraint",
@@ -156,9 +437,19 @@ __all__ = [
     "AtLeastConstraint",
     "MoonPhaseConstraint",
     "SAAConstraint",
+    "SkyRegionConstraint",
+    "CutoffRigidityConstraint",
+    "GeomagneticActivityConstraint",
+    "StationKeepingConstraint",
+    "GroundTargetIlluminationConstraint",
     "AltAzConstraint",
+    "GimbalConstraint",
+    "GroundVisibilityConstraint",
     "OrbitRamConstraint",
+    "PlanetsConstraint",
     "OrbitPoleConstraint",
+    "ThresholdPeriod",
+    "threshold_schedule",
     "ConstraintConfig",
     "CombinedConstraintConfig",
     "AndConstraint",
@@ -170,9 +461,46 @@ __all__ = [
     "EphemerisType",
     "TLEEphemeris",
     "SPICEEphemeris",
+    "Schedule",
+    "ScheduledObservation",
+    "VisibilityReport",
+    "OrbitVisibility",
+    "ConstraintCube",
+    "AttitudeCheck",
+    "AttitudeViolation",
+    "FaceIncidence",
+    "SlewCheck",
+    "SlewIntrusion",
+    "AirmassSummary",
+    "CancellationToken",
+    "TLEAccuracy",
+    "DecayEstimate",
     "OEMEphemeris",
     "GroundEphemeris",
+    "TrajectoryGroundEphemeris",
     "FileEphemeris",
+    "HorizonsEphemeris",
+    "AlmanacEphemeris",
+    "RinexNavEphemeris",
+    "ArrayEphemeris",
+    "GEOEphemeris",
+    "MinorPlanet",
+    "GroundNetwork",
+    "NetworkContacts",
+    "NodalAnalysis",
+    "StationContacts",
+    "LinkGeometry",
+    "ManeuverCandidate",
+    "AccessOpportunity",
+    "SolarEclipse",
+    "SphericalPolygon",
+    "sphere",
+    "CoverageGrid",
+    "CoverageResult",
+    "EphemerisComparison",
+    "EphemerisSample",
+    "ObservationGeometry",
+    "Provenance",
     "PositionVelocityData",
     "Constraint",
     "ConstraintResult",
@@ -182,6 +510,20 @@ __all__ = [
     "init_planetary_ephemeris",
     "download_planetary_ephemeris",
     "ensure_planetary_ephemeris",
+    "estimate_tle_accuracy",
+    "estimate_decay",
+    "validate_elements",
+    "link_geometry",
+    "schedule",
+    "visibility_report",
+    "orbit_visibility",
+    "constraint_cube",
+    "detect_maneuvers",
+    "check_attitude",
+    "check_slew",
+    "face_sun_incidence",
+    "virtual_target_radec",
+    "airmass_summary",
     "is_planetary_ephemeris_initialized",
     "get_tai_utc_offset",
     "get_ut1_utc_offset",
@@ -190,7 +532,36 @@ __all__ = [
     "get_polar_motion",
     "is_eop_available",
     "init_eop_provider",
+    "transformation_accuracy",
+    "get_space_weather",
+    "init_space_weather",
+    "is_space_weather_available",
+    "load_space_weather",
+    "space_weather_series",
     "get_cache_dir",
+    "get_config",
+    "enable_profiling",
+    "profiling_report",
+    "reset_profiling",
+    "set_tle_cache_defaults",
+    "get_tle_cache_defaults",
+    "clear_horizons_cache",
+    "clear_name_cache",
+    "resolve_name",
+    "load_mpcorb",
+    "nodal_analysis",
+    "clear_minor_planets",
+    "compare",
+    "align",
+    "get_site",
+    "list_sites",
+    "register_site",
+    "set_body_radii",
+    "get_body_radii",
+    "reset_body_radii",
     "BrightStarConstraint",
     "get_bright_stars",
+    "gantt_rows",
+    "cumulative_visibility",
+    "window_duration_histogram",
 ]
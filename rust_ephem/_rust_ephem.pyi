@@ -1,7 +1,8 @@
 """Type stubs for the Rust extension module _rust_ephem"""
 
-from datetime import datetime
-from typing import Any, Literal, Protocol, runtime_checkable
+from collections.abc import Callable, Hashable, Iterator, Mapping, Sequence
+from datetime import date, datetime
+from typing import Any, Literal, Protocol, overload, runtime_checkable
 
 import numpy as np
 import numpy.typing as npt
@@ -51,6 +52,377 @@ class PositionVelocityData:
         """Unit for velocity (always 'km/s')"""
         ...
 
+    @property
+    def position_m(self) -> npt.NDArray[np.float64]:
+        """Position array (N x 3) in meters"""
+        ...
+
+    @property
+    def velocity_m_s(self) -> npt.NDArray[np.float64]:
+        """Velocity array (N x 3) in m/s"""
+        ...
+
+    @property
+    def distance(self) -> npt.NDArray[np.float64]:
+        """Length of each position vector (N,) in kilometers"""
+        ...
+
+    @property
+    def speed(self) -> npt.NDArray[np.float64]:
+        """Length of each velocity vector (N,) in km/s"""
+        ...
+
+    def distance_to(self, other: PositionVelocityData) -> npt.NDArray[np.float64]:
+        """Distance (N,) in kilometers from another state set at each step
+
+        Raises:
+            ValueError: If the two state sets have different shapes
+        """
+        ...
+
+    def to_astropy(self) -> Any:  # Returns astropy.coordinates.CartesianRepresentation
+        """Positions as an astropy ``CartesianRepresentation`` in km
+
+        The velocities are attached as a ``CartesianDifferential`` in km/s,
+        available as ``rep.differentials["s"]``.
+        """
+        ...
+
+    def __len__(self) -> int: ...
+    def __add__(self, other: PositionVelocityData) -> PositionVelocityData:
+        """Element-wise sum of positions and velocities
+
+        Raises:
+            ValueError: If the two state sets have different shapes
+        """
+        ...
+
+    def __sub__(self, other: PositionVelocityData) -> PositionVelocityData:
+        """Element-wise difference, e.g. ``body - observer`` for relative vectors
+
+        Raises:
+            ValueError: If the two state sets have different shapes
+        """
+        ...
+
+    def __neg__(self) -> PositionVelocityData: ...
+
+class CancellationToken:
+    """Cooperative cancellation flag for long computations
+
+    Pass it as ``cancel=`` to ``TLEEphemeris``, ``Constraint.evaluate_batch``
+    or ``Constraint.in_constraint_batch`` and call ``cancel()`` from another
+    thread, or create it with a ``timeout``, to stop the computation at the
+    next chunk boundary.
+    """
+
+    def __init__(self, timeout: float | None = None) -> None:
+        """
+        Args:
+            timeout: Seconds after creation at which the token expires and
+                running computations raise ``TimeoutError`` (default: never)
+
+        Raises:
+            ValueError: If timeout is negative or not finite
+        """
+        ...
+
+    def cancel(self) -> None:
+        """Request cancellation; running computations raise ``InterruptedError``"""
+        ...
+
+    def reset(self) -> None:
+        """Clear an explicit cancellation so the token can be reused"""
+        ...
+
+    @property
+    def cancelled(self) -> bool:
+        """Whether ``cancel()`` was called or the timeout has expired"""
+        ...
+
+class Provenance:
+    """Reproducibility record of an ephemeris or constraint result
+
+    Holds the library version, the ephemeris source, time grid and
+    fingerprint, the element set (TLE ephemerides), the input and planetary
+    kernel files with MD5 digests, and the Earth orientation and leap-second
+    data in use. Returned by the ``provenance`` property of the ephemeris
+    classes and of ``ConstraintResult``.
+    """
+
+    @property
+    def library_version(self) -> str | None:
+        """Version of rust_ephem that produced the result"""
+        ...
+
+    @property
+    def fingerprint(self) -> str | None:
+        """Fingerprint of the ephemeris the result was computed from"""
+        ...
+
+    def to_dict(self) -> dict[str, Any]:
+        """The whole record as nested dicts and lists"""
+        ...
+
+    def to_json(self, indent: int | None = None) -> str:
+        """The record as a JSON string, pretty-printed when ``indent`` is given"""
+        ...
+
+    @staticmethod
+    def from_json(text: str) -> Provenance:
+        """Read a record written by ``to_json``
+
+        Raises:
+            ValueError: If the text is not a provenance record
+        """
+        ...
+
+    def __eq__(self, other: object) -> bool: ...
+
+class ObservationGeometry:
+    """Geometry of a target seen from the observer at one time step
+
+    Returned by ``geometry()`` on the ephemeris classes. Altitude, azimuth and
+    airmass are only set for ground sites; the eclipse flag, orbital phase and
+    beta angle only for orbiting observers.
+    """
+
+    @property
+    def index(self) -> int:
+        """Position of the time step within the ephemeris"""
+        ...
+
+    @property
+    def time(self) -> datetime:
+        """Ephemeris time of the snapshot (the step nearest the requested time)"""
+        ...
+
+    @property
+    def ra_deg(self) -> float:
+        """Target right ascension in degrees"""
+        ...
+
+    @property
+    def dec_deg(self) -> float:
+        """Target declination in degrees"""
+        ...
+
+    @property
+    def sun_angle(self) -> float:
+        """Angle between the target and the Sun in degrees"""
+        ...
+
+    @property
+    def moon_angle(self) -> float:
+        """Angle between the target and the Moon in degrees"""
+        ...
+
+    @property
+    def earth_limb_angle(self) -> float:
+        """Angle of the target above the Earth's limb in degrees"""
+        ...
+
+    @property
+    def altitude_deg(self) -> float | None:
+        """Target altitude above the horizon in degrees (ground sites)"""
+        ...
+
+    @property
+    def azimuth_deg(self) -> float | None:
+        """Target azimuth in degrees, east of north (ground sites)"""
+        ...
+
+    @property
+    def airmass(self) -> float | None:
+        """Target airmass, infinite below the horizon (ground sites)"""
+        ...
+
+    @property
+    def in_eclipse(self) -> bool | None:
+        """Whether any of the solar disk is hidden by the Earth"""
+        ...
+
+    @property
+    def sunlit_fraction(self) -> float | None:
+        """Fraction of the solar disk visible past the Earth"""
+        ...
+
+    @property
+    def in_saa(self) -> bool | None:
+        """Whether the observer is inside ``saa_polygon``, if one was given"""
+        ...
+
+    @property
+    def argument_of_latitude(self) -> float | None:
+        """Angle in degrees from the ascending node in the direction of motion"""
+        ...
+
+    @property
+    def solar_phase(self) -> float | None:
+        """Angle in degrees from orbit noon in the direction of motion"""
+        ...
+
+    @property
+    def beta_angle(self) -> float | None:
+        """Angle in degrees between the Sun direction and the orbit plane"""
+        ...
+
+    def to_dict(self) -> dict[str, Any]:
+        """All fields as a dict"""
+        ...
+
+class EphemerisSample:
+    """State of an ephemeris at one time step
+
+    Returned by indexing or iterating over an ephemeris. Geodetic
+    coordinates are computed once for the whole ephemeris and cached.
+    """
+
+    @property
+    def index(self) -> int:
+        """Position of the step within the ephemeris"""
+        ...
+
+    @property
+    def time(self) -> datetime:
+        """Time of the step (UTC)"""
+        ...
+
+    @property
+    def gcrs(self) -> npt.NDArray[np.float64]:
+        """Observer GCRS state [x, y, z, vx, vy, vz] in km and km/s"""
+        ...
+
+    @property
+    def itrs(self) -> npt.NDArray[np.float64] | None:
+        """Observer ITRS state [x, y, z, vx, vy, vz] in km and km/s"""
+        ...
+
+    @property
+    def sun(self) -> npt.NDArray[np.float64] | None:
+        """Geocentric GCRS position of the Sun in km"""
+        ...
+
+    @property
+    def moon(self) -> npt.NDArray[np.float64] | None:
+        """Geocentric GCRS position of the Moon in km"""
+        ...
+
+    @property
+    def latitude_deg(self) -> float | None:
+        """Geodetic latitude of the observer in degrees"""
+        ...
+
+    @property
+    def longitude_deg(self) -> float | None:
+        """Geodetic longitude of the observer in degrees"""
+        ...
+
+    @property
+    def height_km(self) -> float | None:
+        """Height of the observer above the WGS84 ellipsoid in km"""
+        ...
+
+    def __repr__(self) -> str: ...
+
+class MinorPlanet:
+    """Minor planet propagated from Minor Planet Center orbital elements
+
+    Orbits are read from MPCORB-format lines and propagated as two-body
+    heliocentric ellipses. Registered minor planets can be used by name or
+    designation wherever a body is accepted, e.g. ``get_body("Eros")`` or
+    moving-body constraint evaluation.
+
+    Example:
+        >>> planets = load_mpcorb("MPCORB.DAT")
+        >>> eros = eph.get_body("Eros")
+    """
+
+    @staticmethod
+    def from_mpcorb_line(line: str) -> MinorPlanet:
+        """Parse a single MPCORB-format orbit line (not registered)"""
+        ...
+
+    @property
+    def number(self) -> int | None:
+        """Permanent number, or None for unnumbered objects"""
+        ...
+
+    @property
+    def designation(self) -> str:
+        """Readable designation, e.g. ``(433) Eros`` or ``2007 TA418``"""
+        ...
+
+    @property
+    def name(self) -> str | None:
+        """Name of a numbered object, or None"""
+        ...
+
+    @property
+    def h(self) -> float | None:
+        """Absolute magnitude H, or None if not given"""
+        ...
+
+    @property
+    def g(self) -> float | None:
+        """Slope parameter G, or None if not given"""
+        ...
+
+    @property
+    def epoch(self) -> datetime:
+        """Epoch of osculation (UTC)"""
+        ...
+
+    @property
+    def semimajor_axis(self) -> float:
+        """Semi-major axis in AU"""
+        ...
+
+    @property
+    def eccentricity(self) -> float:
+        """Orbital eccentricity"""
+        ...
+
+    @property
+    def inclination(self) -> float:
+        """Inclination to the J2000 ecliptic in degrees"""
+        ...
+
+    @property
+    def ascending_node(self) -> float:
+        """Longitude of the ascending node in degrees"""
+        ...
+
+    @property
+    def argument_of_perihelion(self) -> float:
+        """Argument of perihelion in degrees"""
+        ...
+
+    @property
+    def mean_anomaly(self) -> float:
+        """Mean anomaly at the epoch in degrees"""
+        ...
+
+    @property
+    def mean_motion(self) -> float:
+        """Mean daily motion in degrees/day"""
+        ...
+
+    def heliocentric_pv(
+        self, times: list[datetime] | npt.NDArray[np.datetime64]
+    ) -> PositionVelocityData:
+        """Heliocentric position (km) and velocity (km/s) in the ICRS-aligned frame
+
+        No light-time correction is applied.
+        """
+        ...
+
+    def register(self) -> None:
+        """Make this minor planet resolvable by name or designation in body lookups"""
+        ...
+
+    def __repr__(self) -> str: ...
+
 class VisibilityWindow:
     """A time window when the target is not constrained (visible)"""
 
@@ -124,20 +496,32 @@ class MovingBodyResult:
         """List of time windows when constraint was satisfied (target was visible)."""
         ...
 
-    def in_constraint(self, time: datetime) -> bool:
+    def in_constraint(self, time: datetime, mode: str = "exact") -> bool:
         """Check if constraint was violated at a specific time.
 
         Args:
-            time: The time to check (must exist in timestamps).
+            time: The time to check.
+            mode: ``"exact"`` (time must exist in timestamps), ``"nearest"``
+                (closest timestamp) or ``"previous"`` (timestamp starting the
+                interval that contains the time).
 
         Returns:
             True if constraint was violated at the given time.
 
         Raises:
-            ValueError: If time is not found in timestamps.
+            ValueError: If time is not found in timestamps, or is outside
+                their range for ``"nearest"`` and ``"previous"``.
         """
         ...
 
+    def in_constraint_array(
+        self,
+        times: Sequence[datetime] | npt.NDArray[np.datetime64],
+        mode: str = "exact",
+    ) -> npt.NDArray[np.bool_]:
+        """Vectorized :meth:`in_constraint` over a sequence of times."""
+        ...
+
     def total_violation_duration(self) -> float:
         """Get total duration of all constraint violations in seconds.
 
@@ -148,17 +532,97 @@ class MovingBodyResult:
 
     def __repr__(self) -> str: ...
 
+class ConstraintResult:
+    """Result of ``Constraint.evaluate``, wrapped by
+    :class:`rust_ephem.constraints.ConstraintResult`."""
+
+    @property
+    def violations(self) -> list[ConstraintViolation]: ...
+    @property
+    def all_satisfied(self) -> bool: ...
+    @property
+    def constraint_name(self) -> str: ...
+    @property
+    def constraint_spec(self) -> str | None:
+        """JSON of the constraint that produced the result, or None if unknown."""
+        ...
+
+    @property
+    def provenance(self) -> Provenance | None:
+        """Provenance of the ephemeris with the constraint definition added,
+        or None if unknown."""
+        ...
+
+    @property
+    def storage(self) -> str: ...
+    @property
+    def n_times(self) -> int: ...
+    @property
+    def time_grid(self) -> tuple[datetime, float, int] | None: ...
+    @property
+    def timestamp(self) -> npt.NDArray[Any]: ...
+    @property
+    def constraint_array(self) -> list[bool]: ...
+    @property
+    def visibility(self) -> list[VisibilityWindow]: ...
+    @property
+    def visibility_windows(self) -> list[VisibilityWindow]: ...
+    @property
+    def violation_start_times(self) -> npt.NDArray[np.datetime64]: ...
+    @property
+    def violation_end_times(self) -> npt.NDArray[np.datetime64]: ...
+    def in_constraint(self, time: datetime, mode: str = "exact") -> bool: ...
+    def in_constraint_array(
+        self,
+        times: Sequence[datetime] | npt.NDArray[np.datetime64],
+        mode: str = "exact",
+    ) -> npt.NDArray[np.bool_]: ...
+    def total_violation_duration(self) -> float: ...
+    def save(self, path: str) -> None:
+        """Write the result to ``path`` as JSON.
+
+        The file holds the evaluation times (as stored), the violation windows
+        with their severities, the visibility windows and the generating
+        constraint. The file is replaced atomically.
+
+        Raises:
+            OSError: If the file cannot be written
+        """
+        ...
+
+    @staticmethod
+    def load(path: str) -> ConstraintResult:
+        """Read a result written by :meth:`save`.
+
+        Raises:
+            OSError: If the file cannot be read
+            ValueError: If the file is not a saved constraint result
+        """
+        ...
+
+    def __repr__(self) -> str: ...
+
 class Constraint:
     """Wrapper for constraint evaluation with ephemeris data"""
 
     @staticmethod
-    def sun_proximity(min_angle: float, max_angle: float | None = None) -> Constraint:
+    def sun_proximity(
+        min_angle: float,
+        max_angle: float | None = None,
+        limb_relative: bool = False,
+        schedule: Sequence[Mapping[str, Any]] | None = None,
+    ) -> Constraint:
         """
         Create a Sun proximity constraint.
 
         Args:
             min_angle: Minimum allowed angular separation from Sun in degrees (0-180)
             max_angle: Maximum allowed angular separation from Sun in degrees (optional)
+            limb_relative: Apply the limits to the separation from the Sun's limb
+                (centre separation minus its apparent angular radius)
+            schedule: Periods overriding the limits, each a mapping (or object) with
+                optional ``begin``, ``end`` (``[begin, end)``), ``min_angle`` and
+                ``max_angle``. The first period containing a time applies.
 
         Returns:
             A new Constraint instance
@@ -169,13 +633,58 @@ class Constraint:
         ...
 
     @staticmethod
-    def moon_proximity(min_angle: float, max_angle: float | None = None) -> Constraint:
+    def sun_keepout(
+        pitch_plus: float,
+        pitch_minus: float,
+        yaw_plus: float,
+        yaw_minus: float,
+        roll_deg: float | None = None,
+        roll_reference: str = "sun",
+    ) -> Constraint:
         """
-        Create a Moon proximity constraint.
+        Create a Sun keep-out constraint with separate limits per quadrant.
+
+        The Sun's direction around the boresight, in the spacecraft frame of
+        ``boresight_offset`` (+X boresight, +Z the roll reference, +Y = Z x X),
+        selects which minimum Sun angle applies.
+
+        Args:
+            pitch_plus: Minimum Sun angle in degrees with the Sun toward -Z
+            pitch_minus: Minimum Sun angle in degrees with the Sun toward +Z
+            yaw_plus: Minimum Sun angle in degrees with the Sun toward +Y
+            yaw_minus: Minimum Sun angle in degrees with the Sun toward -Y
+            roll_deg: Spacecraft roll about the boresight in degrees. When None
+                the roll is free and the smallest limit applies; ``target_roll``
+                at evaluation time overrides it.
+            roll_reference: Roll-zero reference axis, "sun" or "north"
+
+        Returns:
+            A new Constraint instance
+
+        Raises:
+            ValueError: If a limit is outside 0-180 degrees or roll_reference is
+                invalid
+        """
+        ...
+
+    @staticmethod
+    def moon_proximity(
+        min_angle: float,
+        max_angle: float | None = None,
+        limb_relative: bool = False,
+        schedule: Sequence[Mapping[str, Any]] | None = None,
+    ) -> Constraint:
+        """
+        Create a Moon proximity constraint.
 
         Args:
             min_angle: Minimum allowed angular separation from Moon in degrees (0-180)
             max_angle: Maximum allowed angular separation from Moon in degrees (optional)
+            limb_relative: Apply the limits to the separation from the Moon's limb
+                (centre separation minus its apparent angular radius)
+            schedule: Periods overriding the limits, each a mapping (or object) with
+                optional ``begin``, ``end`` (``[begin, end)``), ``min_angle`` and
+                ``max_angle``. The first period containing a time applies.
 
         Returns:
             A new Constraint instance
@@ -186,13 +695,27 @@ class Constraint:
         ...
 
     @staticmethod
-    def earth_limb(min_angle: float, max_angle: float | None = None) -> Constraint:
+    def earth_limb(
+        min_angle: float,
+        max_angle: float | None = None,
+        include_refraction: bool = False,
+        horizon_dip: bool = False,
+        uncertainty_sigma: float = 0.0,
+        schedule: Sequence[Mapping[str, Any]] | None = None,
+    ) -> Constraint:
         """
         Create an Earth limb avoidance constraint.
 
         Args:
             min_angle: Additional margin beyond Earth's apparent angular radius (degrees)
             max_angle: Maximum allowed angular separation from Earth limb (degrees, optional)
+            include_refraction: Include atmospheric refraction correction for ground observers
+            horizon_dip: Include geometric horizon dip correction for ground observers
+            uncertainty_sigma: Widen the margin by this many standard deviations of the
+                ephemeris position uncertainty, when the ephemeris has one (default: 0)
+            schedule: Periods overriding the limits, each a mapping (or object) with
+                optional ``begin``, ``end`` (``[begin, end)``), ``min_angle`` and
+                ``max_angle``. The first period containing a time applies.
 
         Returns:
             A new Constraint instance
@@ -209,6 +732,9 @@ class Constraint:
         max_angle: float | None = None,
         fov_polygon: list[tuple[float, float]] | None = None,
         roll_deg: float | None = None,
+        limb_relative: bool = False,
+        schedule: Sequence[Mapping[str, Any]] | None = None,
+        source: str | None = None,
     ) -> Constraint:
         """
         Create a generic solar system body avoidance constraint.
@@ -218,7 +744,8 @@ class Constraint:
         These are mutually exclusive.
 
         Args:
-            body: Body identifier - NAIF ID or name (e.g., "Jupiter", "499", "Mars")
+            body: Body identifier - NAIF ID or name (e.g., "Jupiter", "499", "Mars"),
+                or a pseudo-body such as "EMB", "SEL2" or "EML1"
             min_angle: Minimum allowed angular separation in degrees (circle mode)
             max_angle: Maximum allowed angular separation in degrees (circle mode only)
             fov_polygon: Polygon FoV as (u_deg, v_deg) vertices in the instrument frame.
@@ -226,6 +753,11 @@ class Constraint:
             roll_deg: Position angle of instrument +v from north (degrees east of north).
                 Only used with fov_polygon. None sweeps all roll angles: violated only when
                 every roll has the body inside the polygon.
+            limb_relative: Apply the angle limits to the separation from the body's
+                limb, using its radius from ``get_body_radii`` (circle mode only)
+            schedule: Periods overriding the angle limits, as for ``sun_proximity``
+                (circle mode only)
+            source: Body position source: "spice" (default), "horizons" or "auto"
 
         Returns:
             A new Constraint instance
@@ -234,7 +766,9 @@ class Constraint:
             ValueError: If the configuration is invalid
 
         Note:
-            Supported bodies depend on the ephemeris type and loaded kernels.
+            The body is looked up during evaluation on the ephemeris time grid
+            and kept for reuse with the same ephemeris. Supported bodies depend
+            on the source and the loaded kernels.
         """
         ...
 
@@ -283,12 +817,51 @@ class Constraint:
         ...
 
     @staticmethod
-    def daytime(twilight: str = "civil") -> Constraint:
+    def earth_occultation(grazing_altitude: float = 0.0) -> Constraint:
+        """
+        Create an Earth occultation constraint.
+
+        Violated while the target is hidden behind the Earth as seen from the
+        observer, i.e. while the line of sight passes within
+        ``grazing_altitude`` km of the surface. Unlike ``earth_limb`` there is
+        no pointing margin.
+
+        Args:
+            grazing_altitude: Height above the surface in km below which the
+                line of sight counts as blocked (default: 0)
+
+        Returns:
+            A new Constraint instance
+
+        Raises:
+            ValueError: If grazing_altitude is negative
+        """
+        ...
+
+    @staticmethod
+    def daytime(
+        twilight: str = "civil",
+        *,
+        mode: Literal[
+            "observer", "observer_sunlit", "ground_site_night", "nadir_scene_daylit"
+        ] = "observer",
+        latitude: float | None = None,
+        longitude: float | None = None,
+        height: float | None = None,
+    ) -> Constraint:
         """
         Create a daytime visibility constraint.
 
         Args:
             twilight: Twilight definition ("civil", "nautical", "astronomical", "none")
+            mode: What counts as daytime: the Sun above the twilight limit at
+                the observer ("observer"), the observer outside the Earth's
+                umbra ("observer_sunlit"), the Sun above the twilight limit at
+                a ground site ("ground_site_night") or at the ground point
+                below the observer ("nadir_scene_daylit")
+            latitude: Ground site latitude in degrees, for "ground_site_night"
+            longitude: Ground site longitude in degrees, for "ground_site_night"
+            height: Ground site height in metres, for "ground_site_night"
 
         Returns:
             A new Constraint instance
@@ -348,13 +921,76 @@ class Constraint:
         Create a South Atlantic Anomaly (SAA) constraint.
 
         Args:
-            polygon: List of (longitude, latitude) pairs defining the region boundary in degrees
+            polygon: List of (longitude, latitude) pairs defining the region boundary
+                in degrees. Edges are great-circle arcs, so the region may cross the
+                antimeridian, but it must fit within a hemisphere.
+
+        Returns:
+            A new Constraint instance
+
+        Raises:
+            ValueError: If polygon has fewer than 3 vertices or does not fit within
+                a hemisphere
+        """
+        ...
+
+    @staticmethod
+    def sky_region(polygon: list[tuple[float, float]]) -> Constraint:
+        """
+        Create a sky avoidance region constraint.
+
+        Violated while the target lies inside the region.
+
+        Args:
+            polygon: List of (RA, Dec) pairs defining the region boundary in degrees.
+                Edges are great-circle arcs, so the region may cross RA 0 or enclose
+                a celestial pole, but it must fit within a hemisphere.
+
+        Returns:
+            A new Constraint instance
+
+        Raises:
+            ValueError: If polygon has fewer than 3 vertices or does not fit within
+                a hemisphere
+        """
+        ...
+
+    @staticmethod
+    def cutoff_rigidity(min_rigidity: float) -> Constraint:
+        """
+        Create a geomagnetic cutoff rigidity constraint.
+
+        Violated while the spacecraft's vertical cutoff rigidity, from a
+        centred IGRF dipole, is below the threshold.
+
+        Args:
+            min_rigidity: Minimum vertical cutoff rigidity in GV
+
+        Returns:
+            A new Constraint instance
+
+        Raises:
+            ValueError: If min_rigidity is not positive
+        """
+        ...
+
+    @staticmethod
+    def geomagnetic_activity(max_kp: float) -> Constraint:
+        """
+        Create a geomagnetic activity constraint.
+
+        Violated while the 3-hourly planetary Kp index from the Celestrak
+        space-weather data exceeds the threshold. Times without a Kp value
+        are not violated.
+
+        Args:
+            max_kp: Maximum allowed Kp index (0-9)
 
         Returns:
             A new Constraint instance
 
         Raises:
-            ValueError: If polygon has fewer than 3 vertices
+            ValueError: If max_kp is outside 0-9
         """
         ...
 
@@ -385,6 +1021,142 @@ class Constraint:
         """
         ...
 
+    @staticmethod
+    def station_keeping(
+        longitude: float,
+        longitude_tolerance: float = 0.1,
+        latitude_tolerance: float = 0.1,
+        latitude: float = 0.0,
+    ) -> Constraint:
+        """
+        Create a station-keeping box constraint.
+
+        Violated while the sub-satellite point (geocentric longitude and
+        latitude from the ITRS position) is outside the box around
+        (``longitude``, ``latitude``), e.g. a GEO satellite leaving its slot.
+
+        Args:
+            longitude: Box centre longitude in degrees, east positive
+                (-180 to 360)
+            longitude_tolerance: Half-width of the box in longitude, degrees
+            latitude_tolerance: Half-width of the box in latitude, degrees
+            latitude: Box centre latitude in degrees (default: 0)
+
+        Returns:
+            A new Constraint instance
+
+        Raises:
+            ValueError: If the centre is out of range or a half-width is not
+                positive
+        """
+        ...
+
+    @staticmethod
+    def ground_target_illumination(
+        latitude: float,
+        longitude: float,
+        min_sun_elevation: float | None = 0.0,
+        max_sun_elevation: float | None = None,
+        min_elevation: float = 0.0,
+        max_off_nadir: float | None = None,
+        height: float = 0.0,
+    ) -> Constraint:
+        """
+        Create a ground-target illumination constraint.
+
+        For Earth-observation tasking: violated while the spacecraft is below
+        ``min_elevation`` as seen from the scene (or beyond ``max_off_nadir``
+        from nadir), and while the Sun's elevation at the scene is outside
+        [``min_sun_elevation``, ``max_sun_elevation``]. The default keeps the
+        scene on the day side of the terminator.
+
+        Args:
+            latitude: Geodetic latitude of the scene in degrees
+            longitude: Longitude of the scene in degrees, east positive
+            min_sun_elevation: Minimum Sun elevation at the scene in degrees,
+                or None for no lower limit (default: 0)
+            max_sun_elevation: Maximum Sun elevation at the scene in degrees,
+                e.g. -12 for night imaging (default: None)
+            min_elevation: Minimum spacecraft elevation seen from the scene
+                in degrees (default: 0)
+            max_off_nadir: Maximum off-nadir angle to the scene in degrees
+                (default: None)
+            height: Scene height above the WGS84 ellipsoid in metres
+
+        Returns:
+            A new Constraint instance
+
+        Raises:
+            ValueError: If the scene location or an angle is out of range, or
+                min_sun_elevation is not below max_sun_elevation
+        """
+        ...
+
+    @staticmethod
+    def gimbal(
+        keyhole_radius: float | None = None,
+        keyhole_azimuth: float = 0.0,
+        keyhole_elevation: float = 90.0,
+        max_azimuth_rate: float | None = None,
+        max_elevation_rate: float | None = None,
+    ) -> Constraint:
+        """
+        Create a ground antenna gimbal (keyhole and slew-rate) constraint.
+
+        Violated while the target is within ``keyhole_radius`` of the keyhole
+        centre or while tracking it needs a faster azimuth or elevation slew
+        than allowed. Rates are finite differences over neighbouring ephemeris
+        samples; with ``evaluate_moving_body`` they follow the moving target.
+
+        Args:
+            keyhole_radius: Half-angle of the keyhole cone in degrees (0-90), optional
+            keyhole_azimuth: Azimuth of the keyhole centre in degrees (0-360)
+            keyhole_elevation: Elevation of the keyhole centre in degrees
+                (default: 90, the zenith keyhole of an az/el mount)
+            max_azimuth_rate: Maximum azimuth rate in degrees per second, optional
+            max_elevation_rate: Maximum elevation rate in degrees per second, optional
+
+        Returns:
+            A new Constraint instance
+
+        Raises:
+            ValueError: If no limit is given, an angle is out of range or a
+                rate is not positive
+        """
+        ...
+
+    @staticmethod
+    def ground_visibility(
+        min_altitude: float = 0.0,
+        twilight: str = "astronomical",
+        max_airmass: float | None = None,
+        max_moon_altitude: float | None = None,
+    ) -> Constraint:
+        """
+        Create a ground-site visibility constraint.
+
+        Violated while the target is below ``min_altitude`` or the site's
+        horizon mask, while the Sun is above the twilight limit, and optionally
+        while the airmass exceeds ``max_airmass`` or the Moon is above
+        ``max_moon_altitude``. Sun and Moon altitudes are topocentric.
+
+        Args:
+            min_altitude: Minimum target altitude in degrees (default: 0)
+            twilight: "civil", "nautical", "astronomical" or "none"
+                (default: "astronomical")
+            max_airmass: Maximum target airmass, optional
+            max_moon_altitude: Maximum Moon altitude in degrees, optional
+
+        Returns:
+            A new Constraint instance
+
+        Raises:
+            ValueError: If a limit is out of range or the twilight name is
+                unknown. Evaluating against an ephemeris that is not a
+                ``GroundEphemeris`` also raises ValueError.
+        """
+        ...
+
     @staticmethod
     def orbit_ram(min_angle: float, max_angle: float | None = None) -> Constraint:
         """
@@ -402,6 +1174,31 @@ class Constraint:
         """
         ...
 
+    @staticmethod
+    def planets_proximity(
+        min_angle: float,
+        planets: list[str] | None = None,
+        min_angles: dict[str, float] | None = None,
+        source: str | None = None,
+    ) -> Constraint:
+        """
+        Create an avoidance constraint for several planets at once.
+
+        Args:
+            min_angle: Minimum allowed angular separation in degrees, for all planets (0-180)
+            planets: Body names or NAIF IDs to avoid (default: Venus, Mars, Jupiter, Saturn)
+            min_angles: Per-planet minimum separations in degrees, overriding min_angle
+            source: Body position source: "spice" (default), "horizons" or "auto"
+
+        Returns:
+            A new Constraint instance
+
+        Raises:
+            ValueError: If an angle is out of range, min_angles names a body not in
+                planets, or source is invalid
+        """
+        ...
+
     @staticmethod
     def orbit_pole(
         min_angle: float, max_angle: float | None = None, earth_limb_pole: bool = False
@@ -575,6 +1372,11 @@ class Constraint:
         times: datetime | list[datetime] | None = None,
         indices: int | list[int] | None = None,
         target_roll: float | None = None,
+        *,
+        between: tuple[datetime, datetime] | None = None,
+        at_times: datetime | list[datetime] | None = None,
+        storage: Literal["full", "compressed", "windows"] = "full",
+        prescreen: int | None = None,
     ) -> Any:
         """
         Evaluate constraint against ephemeris data.
@@ -588,20 +1390,37 @@ class Constraint:
                    evaluated (must exist in the ephemeris).
             indices: Optional specific time index/indices to evaluate. Can be a
                      single index or list of indices into the ephemeris timestamp array.
-                 target_roll: Optional spacecraft roll angle about +X in degrees,
-                       applied at evaluation time.
+            target_roll: Optional spacecraft roll angle about +X in degrees,
+                         applied at evaluation time.
+            between: Optional ``(start, end)`` datetimes. Only ephemeris timestamps
+                     in this inclusive range are evaluated.
+            at_times: Optional time(s) to evaluate. Times on the ephemeris grid are
+                      evaluated directly; otherwise the observer state is
+                      Hermite-interpolated onto them. Off-grid times must be
+                      strictly increasing and within the ephemeris range.
+            storage: How the result keeps its times. ``"full"`` keeps every
+                     time; ``"compressed"`` stores an evenly spaced grid as
+                     (start, step, count), exposed as ``time_grid``;
+                     ``"windows"`` keeps only the violation and visibility
+                     windows, so ``timestamp``, ``constraint_array`` and
+                     ``in_constraint`` raise ValueError.
+            prescreen: Optional coarse step. Every ``prescreen``-th time is
+                       evaluated first and only spans between samples whose
+                       states differ are refined step by step. Windows shorter
+                       than ``prescreen`` steps between two samples are missed.
 
         Returns:
             ConstraintResult containing violation windows
 
         Raises:
-            ValueError: If both times and indices are provided, or if times/indices
-                       are not found in the ephemeris
+            ValueError: If more than one time selection is provided, if times/indices
+                       are not found in the ephemeris, or if between/at_times fall
+                       outside the ephemeris
             TypeError: If ephemeris type is not supported
 
         Note:
-            Only one of `times` or `indices` should be provided. If neither is
-            provided, all ephemeris times are evaluated.
+            Only one of `times`, `indices`, `between` or `at_times` should be
+            provided. If none is provided, all ephemeris times are evaluated.
         """
         ...
 
@@ -613,6 +1432,14 @@ class Constraint:
         times: datetime | list[datetime] | None = None,
         indices: int | list[int] | None = None,
         target_rolls: list[float] | None = None,
+        *,
+        between: tuple[datetime, datetime] | None = None,
+        at_times: datetime | list[datetime] | None = None,
+        storage: Literal["full", "compressed", "windows"] = "full",
+        prescreen: int | None = None,
+        progress: Callable[[int, int], bool | None] | None = None,
+        progress_every: int = 1,
+        cancel: CancellationToken | None = None,
     ) -> list[Any]:
         """
         Evaluate constraint against multiple targets and return one result per target.
@@ -628,12 +1455,59 @@ class Constraint:
                      single index or list of indices into the ephemeris timestamp array.
             target_rolls: Optional per-target spacecraft roll angles in degrees.
                          List of length equal to target_ras.
+            between: Optional ``(start, end)`` datetimes selecting the ephemeris
+                     timestamps to evaluate, as in ``evaluate``.
+            at_times: Optional time(s) to evaluate, interpolated if off the
+                      ephemeris grid, as in ``evaluate``.
+            storage: How each result keeps its times, as in ``evaluate``.
+                     ``"compressed"`` or ``"windows"`` bounds memory for long
+                     runs over many targets.
+            prescreen: Optional coarse step for pre-screening, as in ``evaluate``.
+            progress: Called as ``progress(done, total)`` with the number of
+                      finished targets. Targets are then evaluated in blocks
+                      with the GIL released; returning ``False`` cancels the
+                      evaluation with ``InterruptedError``.
+            progress_every: Blocks between ``progress`` calls (default: 1).
+            cancel: Token stopping the evaluation after the current block once
+                    cancelled or timed out. Ctrl-C is honoured between blocks
+                    either way.
 
         Returns:
             List of ConstraintResult objects, one per input target.
         """
         ...
 
+    def evaluate_many(
+        self,
+        ephemerides: Mapping[Hashable, Ephemeris] | Sequence[Ephemeris],
+        target_ra: float,
+        target_dec: float,
+        target_roll: float | None = None,
+        *,
+        between: tuple[datetime, datetime] | None = None,
+        at_times: datetime | list[datetime] | None = None,
+        storage: Literal["full", "compressed", "windows"] = "full",
+    ) -> dict[Hashable, Any]:
+        """
+        Evaluate constraint for one target against several ephemerides in parallel.
+
+        Args:
+            ephemerides: Ephemerides keyed by name, or a sequence of ephemerides
+                         (keyed by position). Their time grids may differ.
+            target_ra: Target right ascension in degrees (ICRS/J2000)
+            target_dec: Target declination in degrees (ICRS/J2000)
+            target_roll: Optional spacecraft roll angle about +X in degrees
+            between: Optional ``(start, end)`` datetimes selecting the timestamps
+                     of each ephemeris to evaluate, as in ``evaluate``.
+            at_times: Optional time(s) to evaluate for every ephemeris,
+                      interpolated where off an ephemeris grid.
+            storage: How each result keeps its times, as in ``evaluate``.
+
+        Returns:
+            Dict mapping each key of ``ephemerides`` to its ConstraintResult
+        """
+        ...
+
     def in_constraint_batch(
         self,
         ephemeris: Ephemeris,
@@ -642,6 +1516,12 @@ class Constraint:
         times: datetime | list[datetime] | None = None,
         indices: int | list[int] | None = None,
         target_rolls: list[float] | None = None,
+        *,
+        between: tuple[datetime, datetime] | None = None,
+        at_times: datetime | list[datetime] | None = None,
+        progress: Callable[[int, int], bool | None] | None = None,
+        progress_every: int = 1,
+        cancel: CancellationToken | None = None,
     ) -> npt.NDArray[np.bool_]:
         """
         Check if targets are in-constraint for multiple RA/Dec positions (vectorized).
@@ -660,6 +1540,15 @@ class Constraint:
                      single index or list of indices into the ephemeris timestamp array.
             target_rolls: Optional per-target spacecraft roll angles in degrees.
                          List of length equal to target_ras.
+            between: Optional ``(start, end)`` datetimes selecting the ephemeris
+                     timestamps to evaluate, as in ``evaluate``.
+            at_times: Optional time(s) to evaluate, interpolated if off the
+                      ephemeris grid, as in ``evaluate``.
+            progress: Called as ``progress(done, total)`` with the number of
+                      finished targets, as in ``evaluate_batch``.
+            progress_every: Blocks between ``progress`` calls (default: 1).
+            cancel: Token stopping the evaluation after the current block, as
+                    in ``evaluate_batch``.
 
         Returns:
             2D numpy boolean array of shape (n_targets, n_times) where True indicates
@@ -667,13 +1556,13 @@ class Constraint:
 
         Raises:
             ValueError: If target_ras and target_decs have different lengths,
-                       or if both times and indices are provided, or if times/indices
-                       are not found in the ephemeris
+                       or if more than one time selection is provided, or if
+                       times/indices are not found in the ephemeris
             TypeError: If ephemeris type is not supported
 
         Note:
-            Only one of `times` or `indices` should be provided. If neither is
-            provided, all ephemeris times are evaluated.
+            Only one of `times`, `indices`, `between` or `at_times` should be
+            provided. If none is provided, all ephemeris times are evaluated.
         """
         ...
 
@@ -707,6 +1596,49 @@ class Constraint:
         """
         ...
 
+    def shadow_events(self, ephemeris: Ephemeris) -> list[tuple[datetime, str]]:
+        """Penumbra and umbra entry and exit times of the observer.
+
+        Only available on an eclipse constraint. Both boundaries are reported
+        whether or not the constraint counts the penumbra. Crossings are
+        interpolated linearly between ephemeris samples.
+
+        Args:
+            ephemeris: Any supported ephemeris
+
+        Returns:
+            ``(time, kind)`` tuples in time order, where kind is
+            ``"penumbra_entry"``, ``"umbra_entry"``, ``"umbra_exit"`` or
+            ``"penumbra_exit"``
+
+        Raises:
+            TypeError: If this is not an eclipse constraint
+        """
+        ...
+
+    def occultation_events(
+        self, ephemeris: Ephemeris, target_ra: float, target_dec: float
+    ) -> list[tuple[datetime, str]]:
+        """Occultation ingress and egress times of a target behind the Earth.
+
+        Only available on an Earth occultation constraint. Crossings of the
+        grazing altitude are interpolated linearly between ephemeris samples.
+        A target occulted at the first sample has no ingress, and one still
+        occulted at the last sample has no egress.
+
+        Args:
+            ephemeris: Any supported ephemeris
+            target_ra: Target right ascension in degrees (ICRS/J2000)
+            target_dec: Target declination in degrees (ICRS/J2000)
+
+        Returns:
+            ``(time, "ingress" | "egress")`` tuples in time order
+
+        Raises:
+            TypeError: If this is not an Earth occultation constraint
+        """
+        ...
+
     def roll_range(
         self,
         time: datetime,
@@ -739,6 +1671,28 @@ class Constraint:
         """
         ...
 
+    def metric(
+        self,
+        ephemeris: Ephemeris,
+        target_ra: float,
+        target_dec: float,
+        times: datetime | list[datetime] | None = None,
+        indices: int | list[int] | None = None,
+        *,
+        between: tuple[datetime, datetime] | None = None,
+        at_times: datetime | list[datetime] | None = None,
+    ) -> npt.NDArray[np.float64]:
+        """Continuous quantity behind the constraint, one value per selected time.
+
+        Separation from the body limb (degrees) for proximity constraints,
+        altitude for alt/az, airmass, Moon illumination, visible solar disk
+        fraction for eclipse, Sun altitude for daytime, cutoff rigidity in GV.
+
+        Raises:
+            ValueError: If the constraint has no single underlying quantity
+        """
+        ...
+
     def instantaneous_field_of_regard(
         self,
         ephemeris: Ephemeris,
@@ -762,6 +1716,7 @@ class Constraint:
         body: str | None = None,
         use_horizons: bool = False,
         spice_kernel: str | None = None,
+        source: str | None = None,
     ) -> MovingBodyResult:
         """
         Evaluate constraint for a moving body (varying RA/Dec over time).
@@ -782,6 +1737,7 @@ class Constraint:
             body: Body identifier (NAIF ID or name like "Jupiter", "90004910")
             use_horizons: If True, query JPL Horizons for body positions (default: False)
             spice_kernel: Path or URL to a SPICE kernel file for body positions.
+            source: Body data source precedence: "spice", "horizons" or "auto".
 
         Returns:
             MovingBodyResult containing:
@@ -816,6 +1772,36 @@ class Constraint:
         """
         ...
 
+    def evaluate_virtual_target(
+        self,
+        ephemeris: Ephemeris,
+        target: Literal["nadir", "limb", "sun", "anti_sun", "ram", "anti_ram"],
+        *,
+        azimuth: float | None = None,
+    ) -> MovingBodyResult:
+        """
+        Evaluate constraint for a virtual target derived from the observer state.
+
+        The target direction is recomputed at every ephemeris time, so common
+        pointing modes can be checked without precomputing coordinates.
+
+        Args:
+            ephemeris: One of TLEEphemeris, SPICEEphemeris, GroundEphemeris, or OEMEphemeris
+            target: "nadir", "limb", "sun", "anti_sun", "ram" or "anti_ram"
+            azimuth: For "limb" only, azimuth of the limb tangent point about nadir
+                in degrees, 0 toward ram and 90 toward the orbit normal (default: 0)
+
+        Returns:
+            MovingBodyResult with the target's RA/Dec at each time, as for
+            ``evaluate_moving_body``
+
+        Raises:
+            ValueError: If the target is unknown, azimuth is given for a target
+                other than "limb", or the ephemeris lacks the velocity data that
+                "ram", "anti_ram" and "limb" need
+        """
+        ...
+
     def to_json(self) -> str:
         """
         Get constraint configuration as JSON string.
@@ -848,12 +1834,20 @@ class TLEEphemeris(Ephemeris):
         step_size: int = 60,
         *,
         polar_motion: bool = False,
+        fast_itrs: bool = False,
+        time_scale: str = "UTC",
         tle: str | TLELike | None = None,
         norad_id: int | None = None,
         norad_name: str | None = None,
         spacetrack_username: str | None = None,
         spacetrack_password: str | None = None,
         epoch_tolerance_days: float | None = None,
+        max_epoch_offset_days: float | None = None,
+        strict: bool = False,
+        cache_ttl: int | None = None,
+        progress: Callable[[int, int], bool | None] | None = None,
+        progress_every: int = 1,
+        cancel: CancellationToken | None = None,
     ) -> None:
         """
         Initialize TLE ephemeris from various TLE sources.
@@ -872,11 +1866,34 @@ class TLEEphemeris(Ephemeris):
             spacetrack_username: Space-Track.org username (or set SPACETRACK_USERNAME env var)
             spacetrack_password: Space-Track.org password (or set SPACETRACK_PASSWORD env var)
             epoch_tolerance_days: For Space-Track cache: how many days TLE epoch can differ
-                from target epoch (default: 4.0 days)
+                from target epoch (default: 4.0 days, see set_tle_cache_defaults)
+            cache_ttl: Largest age in seconds of a cached Celestrak or URL download
+                before it is fetched again; 0 forces a refresh (default: 86400, see
+                set_tle_cache_defaults)
             begin: Start time (naive datetime treated as UTC, required)
             end: End time (naive datetime treated as UTC, required)
             step_size: Time step in seconds (default: 60)
             polar_motion: Whether to apply polar motion correction (default: False)
+            fast_itrs: Rotate GCRS to ITRS without the UT1-UTC correction
+                (at most 0.0038 deg of longitude) or polar motion, about four
+                times faster with EOP data loaded, for large grids such as
+                SAA checks (default: False)
+            time_scale: "UTC" steps clock time and skips leap seconds; "TAI"
+                or "TT" step SI seconds through them (default: "UTC")
+            max_epoch_offset_days: Largest allowed distance in days between the TLE
+                epoch and begin or end before warning (default: 14.0, or the
+                RUST_EPHEM_TLE_MAX_EPOCH_OFFSET_DAYS environment variable)
+            strict: Raise ValueError instead of warning when the range extends
+                beyond max_epoch_offset_days or the elements fail a sanity
+                check (see :func:`validate_elements`) (default: False)
+            progress: Called as ``progress(done, total)`` with the number of
+                propagated time steps. Propagation then runs in chunks with the
+                GIL released; returning ``False`` cancels it with
+                ``InterruptedError``.
+            progress_every: Chunks between ``progress`` calls (default: 1)
+            cancel: Token stopping propagation after the current chunk once
+                cancelled or timed out. Ctrl-C is honoured between chunks
+                either way.
 
         Note:
             Must provide exactly one of: (tle1, tle2), tle, norad_id, or norad_name.
@@ -890,6 +1907,10 @@ class TLEEphemeris(Ephemeris):
             - Results are cached; cache is used if TLE epoch is within
               epoch_tolerance_days of the requested begin time
 
+            SGP4 accuracy degrades quickly away from the element epoch. When
+            begin or end is more than max_epoch_offset_days from the TLE epoch,
+            a UserWarning reports both offsets (see ``epoch_offset_days``).
+
         Example:
             >>> # Using fetch_tle to get TLE, then pass to TLEEphemeris
             >>> from rust_ephem import fetch_tle, TLEEphemeris
@@ -929,43 +1950,149 @@ class TLEEphemeris(Ephemeris):
         ...
 
     @property
-    def tle_epoch(self) -> datetime:
-        """Epoch timestamp extracted from the TLE (UTC datetime)"""
-        ...
-
-    @property
-    def teme_pv(self) -> PositionVelocityData:
-        """Position and velocity data in TEME frame"""
+    def fast_itrs(self) -> bool:
+        """Whether ITRS skips the UT1-UTC correction and polar motion"""
         ...
 
     @property
-    def itrs_pv(self) -> PositionVelocityData:
-        """Position and velocity data in ITRS (Earth-fixed) frame"""
+    def time_scale(self) -> str:
+        """Time scale in which the timestamp grid is uniform ("UTC", "TAI" or "TT")"""
         ...
 
-    @property
-    def itrs(self) -> Any:  # Returns astropy.coordinates.SkyCoord
-        """SkyCoord object in ITRS frame"""
-        ...
+    @staticmethod
+    def from_sgp4_satrec(
+        satrec: Any,
+        begin: datetime,
+        end: datetime,
+        step_size: int = 60,
+        *,
+        polar_motion: bool = False,
+        fast_itrs: bool = False,
+        time_scale: str = "UTC",
+        max_epoch_offset_days: float | None = None,
+        strict: bool = False,
+    ) -> TLEEphemeris:
+        """
+        Create an ephemeris from an ``sgp4.api.Satrec`` object.
 
-    @property
-    def gcrs(self) -> Any:  # Returns astropy.coordinates.SkyCoord
-        """SkyCoord object in GCRS frame"""
-        ...
+        The element set is exported to TLE lines with ``sgp4.exporter``, so
+        any record created by the ``sgp4`` package can be used.
 
-    @property
-    def earth(self) -> Any:  # Returns astropy.coordinates.SkyCoord
-        """SkyCoord object for Earth position relative to satellite"""
-        ...
+        Args:
+            satrec: Satellite record from the ``sgp4`` package
+            begin: Start time (naive datetime treated as UTC)
+            end: End time (naive datetime treated as UTC)
+            step_size: Time step in seconds (default: 60)
+            polar_motion: Whether to apply polar motion correction (default: False)
+            fast_itrs: Rotate GCRS to ITRS without the UT1-UTC correction
+                (at most 0.0038 deg of longitude) or polar motion, about four
+                times faster with EOP data loaded, for large grids such as
+                SAA checks (default: False)
+            time_scale: "UTC" steps clock time and skips leap seconds; "TAI"
+                or "TT" step SI seconds through them (default: "UTC")
+            max_epoch_offset_days: Days from the TLE epoch beyond which to warn
+                (default: 14.0)
+            strict: Raise instead of warning, for the epoch offset and the
+                element sanity checks (default: False)
 
-    @property
-    def latitude(self) -> Any:  # Returns astropy.units.Quantity
-        """Geodetic latitude as an astropy Quantity array (degrees), one per timestamp"""
+        Raises:
+            ImportError: If the ``sgp4`` package is not installed
+            TypeError: If ``satrec`` cannot be exported to TLE lines
+            ValueError: If ``strict`` and the range extends beyond
+                ``max_epoch_offset_days`` from the TLE epoch
+        """
         ...
 
-    @property
-    def latitude_deg(self) -> npt.NDArray[np.float64]:
-        """Geodetic latitude in degrees as a raw NumPy array (one per timestamp)"""
+    @staticmethod
+    def from_skyfield(
+        satellite: Any,
+        begin: datetime,
+        end: datetime,
+        step_size: int = 60,
+        *,
+        polar_motion: bool = False,
+        fast_itrs: bool = False,
+        time_scale: str = "UTC",
+        max_epoch_offset_days: float | None = None,
+        strict: bool = False,
+    ) -> TLEEphemeris:
+        """
+        Create an ephemeris from a Skyfield ``EarthSatellite``.
+
+        Uses the satellite's underlying sgp4 record (``satellite.model``).
+
+        Args:
+            satellite: Skyfield ``EarthSatellite``
+            begin: Start time (naive datetime treated as UTC)
+            end: End time (naive datetime treated as UTC)
+            step_size: Time step in seconds (default: 60)
+            polar_motion: Whether to apply polar motion correction (default: False)
+            fast_itrs: Rotate GCRS to ITRS without the UT1-UTC correction
+                (at most 0.0038 deg of longitude) or polar motion, about four
+                times faster with EOP data loaded, for large grids such as
+                SAA checks (default: False)
+            time_scale: "UTC" steps clock time and skips leap seconds; "TAI"
+                or "TT" step SI seconds through them (default: "UTC")
+            max_epoch_offset_days: Days from the TLE epoch beyond which to warn
+                (default: 14.0)
+            strict: Raise ValueError instead of warning, for the epoch offset
+                and the element sanity checks (default: False)
+
+        Example:
+            >>> from skyfield.api import EarthSatellite
+            >>> sat = EarthSatellite(line1, line2, "ISS")
+            >>> ephem = TLEEphemeris.from_skyfield(sat, begin, end, step_size=60)
+        """
+        ...
+
+    @property
+    def tle_epoch(self) -> datetime:
+        """Epoch timestamp extracted from the TLE (UTC datetime)"""
+        ...
+
+    @property
+    def epoch_offset_days(self) -> tuple[float, float]:
+        """Signed offsets in days of begin and end from the TLE epoch"""
+        ...
+
+    @property
+    def decay_date(self) -> date | None:
+        """SATCAT decay date of the object, or None if not known to have decayed"""
+        ...
+
+    @property
+    def teme_pv(self) -> PositionVelocityData:
+        """Position and velocity data in TEME frame"""
+        ...
+
+    @property
+    def itrs_pv(self) -> PositionVelocityData:
+        """Position and velocity data in ITRS (Earth-fixed) frame"""
+        ...
+
+    @property
+    def itrs(self) -> Any:  # Returns astropy.coordinates.SkyCoord
+        """SkyCoord object in ITRS frame"""
+        ...
+
+    @property
+    def gcrs(self) -> Any:  # Returns astropy.coordinates.SkyCoord
+        """SkyCoord object in GCRS frame"""
+        ...
+
+    @property
+    def earth(self) -> Any:  # Returns astropy.coordinates.SkyCoord
+        """SkyCoord object for Earth position relative to satellite"""
+        ...
+
+    @property
+    def latitude(self) -> Any:  # Returns astropy.units.Quantity
+        """Geodetic latitude as an astropy Quantity array (degrees), one per timestamp"""
+        ...
+
+    @property
+    def latitude_deg(self) -> npt.NDArray[np.float64]:
+        """Geodetic latitude in degrees as a raw NumPy array (one per timestamp)"""
         ...
 
     @property
@@ -1003,6 +2130,21 @@ class TLEEphemeris(Ephemeris):
         """Geodetic height above the WGS84 ellipsoid as a raw NumPy array in kilometers (one per timestamp)"""
         ...
 
+    @property
+    def geomagnetic_latitude_deg(self) -> npt.NDArray[np.float64]:
+        """Centred-dipole (IGRF) geomagnetic latitude in degrees (one per timestamp)"""
+        ...
+
+    @property
+    def geomagnetic_longitude_deg(self) -> npt.NDArray[np.float64]:
+        """Centred-dipole (IGRF) geomagnetic longitude in degrees (one per timestamp)"""
+        ...
+
+    @property
+    def cutoff_rigidity_gv(self) -> npt.NDArray[np.float64]:
+        """Störmer vertical geomagnetic cutoff rigidity in GV (one per timestamp)"""
+        ...
+
     @property
     def sun(self) -> Any:  # Returns astropy.coordinates.SkyCoord
         """SkyCoord object for Sun position relative to satellite"""
@@ -1018,6 +2160,15 @@ class TLEEphemeris(Ephemeris):
         """Position and velocity data in GCRS frame"""
         ...
 
+    @property
+    def pef_pv(self) -> PositionVelocityData:
+        """Position and velocity data in the pseudo-Earth-fixed frame
+
+        TEME rotated by Greenwich mean sidereal time: ITRS without polar
+        motion, as used by legacy NORAD Earth-fixed products.
+        """
+        ...
+
     @property
     def sun_pv(self) -> PositionVelocityData:
         """Sun position and velocity in GCRS frame"""
@@ -1362,31 +2513,176 @@ class TLEEphemeris(Ephemeris):
         ...
 
     def get_body_pv(
-        self, body: str, spice_kernel: str | None = ..., use_horizons: bool = ...
+        self,
+        body: str,
+        spice_kernel: str | None = ...,
+        use_horizons: bool = ...,
+        source: str | None = ...,
     ) -> PositionVelocityData:
         """
         Get position and velocity of a celestial body.
 
         Args:
-            body: Name of the body (e.g., 'sun', 'moon', 'earth')
+            body: Name of the body (e.g., 'sun', 'moon', 'earth'), or a pseudo-body
+                ('EMB', 'SSB', 'SEL1', 'SEL2', 'EML1', 'EML2')
             spice_kernel: Optional path to SPICE kernel
             use_horizons: If True, fall back to JPL Horizons API when SPICE fails
+                (equivalent to ``source="auto"``)
+            source: Data source precedence: ``"spice"`` (loaded kernels only),
+                ``"horizons"`` (JPL Horizons only) or ``"auto"`` (SPICE, falling back
+                to Horizons with a ``UserWarning``). Defaults to ``"spice"`` unless
+                ``use_horizons`` is set.
 
         Returns:
             Position and velocity data for the requested body
         """
         ...
 
+    def get_bodies(
+        self,
+        bodies: list[str],
+        spice_kernel: str | None = ...,
+        use_horizons: bool = ...,
+        source: str | None = ...,
+    ) -> dict[str, PositionVelocityData]:
+        """
+        Get position and velocity of several celestial bodies in one pass.
+
+        Equivalent to calling ``get_body_pv`` for each body, but the SPICE
+        lookups and the observer positions are set up once for all bodies.
+
+        Args:
+            bodies: Body names or NAIF IDs, as accepted by ``get_body_pv``
+            spice_kernel: Optional path to SPICE kernel
+            use_horizons: If True, fall back to JPL Horizons API when SPICE fails
+                (equivalent to ``source="auto"``)
+            source: Data source precedence, as for ``get_body_pv``
+
+        Returns:
+            Dict mapping each requested body, as given, to its position and
+            velocity data
+        """
+        ...
+
+    def separation(self, a: Any, b: Any) -> npt.NDArray[np.float64]:
+        """
+        Angular separation between two targets at each time step.
+
+        Computed in Rust from observer-relative vectors, without astropy.
+
+        Args:
+            a, b: Each an ``(ra_deg, dec_deg)`` pair, a body name (``"sun"``,
+                ``"moon"`` and ``"earth"`` use the cached positions; other names
+                resolve as in ``get_body_pv``), observer-relative
+                ``PositionVelocityData`` (e.g. from ``get_body_pv``), or a
+                SkyCoord with a single position or one per time step (e.g.
+                ``ephem.sun``)
+
+        Returns:
+            Separation angles in degrees, one per time step
+
+        Raises:
+            TypeError: If an operand is not one of the accepted kinds
+            ValueError: If an operand has a different number of time steps
+        """
+        ...
+
+    def sun_angle(
+        self, ra_deg: float, dec_deg: float, time_indices: list[int] | None = ...
+    ) -> npt.NDArray[np.float64]:
+        """
+        Angle between a target and the Sun at each time step.
+
+        Args:
+            ra_deg: Target right ascension in degrees
+            dec_deg: Target declination in degrees
+            time_indices: Optional indices into the ephemeris times (default: all)
+
+        Returns:
+            Angles in degrees, one per selected time step
+        """
+        ...
+
+    def moon_angle(
+        self, ra_deg: float, dec_deg: float, time_indices: list[int] | None = ...
+    ) -> npt.NDArray[np.float64]:
+        """
+        Angle between a target and the Moon at each time step.
+
+        Args:
+            ra_deg: Target right ascension in degrees
+            dec_deg: Target declination in degrees
+            time_indices: Optional indices into the ephemeris times (default: all)
+
+        Returns:
+            Angles in degrees, one per selected time step
+        """
+        ...
+
+    def earth_limb_angle(
+        self, ra_deg: float, dec_deg: float, time_indices: list[int] | None = ...
+    ) -> npt.NDArray[np.float64]:
+        """
+        Angle of a target above the Earth's limb at each time step.
+
+        The angle from the Earth's center minus the Earth's angular radius, so
+        negative while the target is behind the Earth. No refraction or horizon
+        mask is applied.
+
+        Args:
+            ra_deg: Target right ascension in degrees
+            dec_deg: Target declination in degrees
+            time_indices: Optional indices into the ephemeris times (default: all)
+
+        Returns:
+            Angles in degrees, one per selected time step
+        """
+        ...
+
+    def geometry(
+        self,
+        ra_deg: float,
+        dec_deg: float,
+        time: datetime,
+        *,
+        saa_polygon: list[tuple[float, float]] | None = None,
+    ) -> ObservationGeometry:
+        """
+        Observation geometry of a target at the ephemeris step nearest ``time``.
+
+        Args:
+            ra_deg: Target right ascension in degrees
+            dec_deg: Target declination in degrees
+            time: Time of interest; the nearest ephemeris step is used
+            saa_polygon: Optional SAA region as (longitude, latitude) vertices in
+                degrees; sets ``in_saa``
+
+        Returns:
+            Sun, Moon and Earth-limb angles, plus altitude and airmass for ground
+            sites or eclipse, orbital phase and beta angle for orbiting observers
+        """
+        ...
+
     def get_body(
-        self, body: str, spice_kernel: str | None = ..., use_horizons: bool = ...
+        self,
+        body: str,
+        spice_kernel: str | None = ...,
+        use_horizons: bool = ...,
+        source: str | None = ...,
     ) -> Any:  # Returns astropy.coordinates.SkyCoord
         """
         Get SkyCoord for a celestial body.
 
         Args:
-            body: Name of the body (e.g., 'sun', 'moon', 'earth')
+            body: Name of the body (e.g., 'sun', 'moon', 'earth'), or a pseudo-body
+                ('EMB', 'SSB', 'SEL1', 'SEL2', 'EML1', 'EML2')
             spice_kernel: Optional path to SPICE kernel
             use_horizons: If True, fall back to JPL Horizons API when SPICE fails
+                (equivalent to ``source="auto"``)
+            source: Data source precedence: ``"spice"`` (loaded kernels only),
+                ``"horizons"`` (JPL Horizons only) or ``"auto"`` (SPICE, falling back
+                to Horizons with a ``UserWarning``). Defaults to ``"spice"`` unless
+                ``use_horizons`` is set.
 
         Returns:
             astropy.coordinates.SkyCoord object
@@ -1418,6 +2714,104 @@ class TLEEphemeris(Ephemeris):
         """
         ...
 
+    def fingerprint(self) -> str:
+        """
+        Stable hex digest identifying this ephemeris.
+
+        Hashes the source (elements, file path or site), the options, the time
+        grid and the computed GCRS states, so ephemerides built the same way
+        share a fingerprint across sessions. Ephemerides with equal
+        fingerprints compare equal, and the fingerprint can key caches of
+        constraint results or schedules.
+
+        Returns:
+            32-character hexadecimal digest
+        """
+        ...
+
+    def __eq__(self, other: object) -> bool: ...
+    def __hash__(self) -> int: ...
+    @property
+    def metadata(self) -> dict[str, Any]:
+        """
+        Source, time span, computed frames and Earth orientation state.
+
+        Keys start with ``type`` and the source fields of this class (e.g.
+        ``norad_id`` and ``tle_epoch``, or a file path), then ``polar_motion``,
+        ``begin``, ``end``, ``step_size``, ``n_times``, ``frames``,
+        ``eop_available``, ``polar_motion_applied``, ``fast_itrs``,
+        ``ut1_available`` and ``fingerprint``. Values are
+        JSON-serializable, with times as ISO 8601 strings, for provenance logs.
+        """
+        ...
+
+    @property
+    def provenance(self) -> Provenance:
+        """
+        Reproducibility record: library version, source and fingerprint,
+        element set, input and kernel file digests, Earth orientation and
+        leap-second data.
+        """
+        ...
+
+    def summary(self) -> str:
+        """Human-readable, multi-line description of ``metadata``."""
+        ...
+
+    def __repr__(self) -> str: ...
+    def __len__(self) -> int:
+        """Number of time steps"""
+        ...
+
+    def __getitem__(self, index: int) -> EphemerisSample:
+        """State at time step ``index``; negative indices count from the end"""
+        ...
+
+    def __iter__(self) -> Iterator[EphemerisSample]:
+        """Iterate over the time steps in order"""
+        ...
+
+    @property
+    def illumination_factor(self) -> npt.NDArray[np.float64]:
+        """Fraction of the solar disk visible from the observer at each time step
+
+        1.0 in sunlight, 0.0 in the Earth's umbra and fractional in the
+        penumbra, from the overlap of the apparent Sun and Earth disks.
+        """
+        ...
+
+    @property
+    def geodetic_rates(self) -> npt.NDArray[np.float64] | None:
+        """Geodetic rates from the ITRS velocity, shape (N, 3)
+
+        Columns are latitude rate (deg/s), longitude rate (deg/s) and height
+        rate (km/s). The longitude rate diverges at the poles.
+        """
+        ...
+
+    @property
+    def ground_speed(self) -> npt.NDArray[np.float64] | None:
+        """Speed of the sub-observer point over the WGS84 ellipsoid (km/s)"""
+        ...
+
+    @property
+    def sub_satellite_point(self) -> npt.NDArray[np.float64] | None:
+        """Geodetic (latitude, longitude) in degrees below the observer, shape (N, 2)"""
+        ...
+
+    @property
+    def sub_solar_point(self) -> npt.NDArray[np.float64]:
+        """(latitude, longitude) in degrees with the Sun at the zenith, shape (N, 2)"""
+        ...
+
+    @property
+    def nadir_solar_zenith_angle(self) -> npt.NDArray[np.float64] | None:
+        """Solar zenith angle at the sub-satellite point in degrees
+
+        The ground below the observer is daylit below 90.
+        """
+        ...
+
     def moon_illumination(self, time_indices: list[int] | None = None) -> list[float]:
         """
         Calculate Moon illumination fraction for all ephemeris times.
@@ -1433,6 +2827,37 @@ class TLEEphemeris(Ephemeris):
         """
         ...
 
+    def access_to(
+        self,
+        lat: float,
+        lon: float,
+        max_off_nadir_deg: float,
+        min_sun_elev: float | None = None,
+    ) -> list[AccessOpportunity]:
+        """
+        Find imaging opportunities of a ground target.
+
+        A time is accessible when the target is above its local horizon (on
+        the visible side of the Earth's limb), within ``max_off_nadir_deg`` of
+        the spacecraft's nadir and, if ``min_sun_elev`` is given, the Sun is
+        at least that high at the target.
+
+        Args:
+            lat: Geodetic latitude of the target in degrees
+            lon: Longitude of the target in degrees, east positive
+            max_off_nadir_deg: Maximum off-nadir angle in degrees (0-90)
+            min_sun_elev: Minimum Sun elevation at the target in degrees
+                (default: no illumination requirement)
+
+        Returns:
+            Opportunities in time order, each summarised at its smallest
+            off-nadir angle
+
+        Raises:
+            ValueError: If the target location or an angle is out of range
+        """
+        ...
+
     @property
     def obsgeoloc(
         self,
@@ -1454,8 +2879,27 @@ class TLEEphemeris(Ephemeris):
         ra_deg: float,
         dec_deg: float,
         time_indices: list[int] | None = None,
+        *,
+        polar_motion: bool | None = None,
+    ) -> npt.NDArray[np.float64]:
+        """Topocentric altitude/azimuth for given RA/Dec (deg) at selected times.
+
+        ``polar_motion`` overrides the ephemeris's own setting for this call.
+        """
+        ...
+
+    def radec_to_altaz_batch(
+        self,
+        ra_deg: Sequence[float] | npt.NDArray[np.float64],
+        dec_deg: Sequence[float] | npt.NDArray[np.float64],
+        time_indices: list[int] | None = None,
+        *,
+        polar_motion: bool | None = None,
     ) -> npt.NDArray[np.float64]:
-        """Topocentric altitude/azimuth for given RA/Dec (deg) at selected times."""
+        """Altitude/azimuth for many targets, shape (n_targets, n_times, 2).
+
+        ``polar_motion`` overrides the ephemeris's own setting for this call.
+        """
         ...
 
     def calculate_airmass(
@@ -1471,6 +2915,20 @@ class TLEEphemeris(Ephemeris):
         """
         ...
 
+    def airmass_batch(
+        self,
+        ras: Sequence[float] | npt.NDArray[np.float64],
+        decs: Sequence[float] | npt.NDArray[np.float64],
+        time_indices: list[int] | None = None,
+    ) -> npt.NDArray[np.float64]:
+        """Calculate airmass for many RA/Dec targets (deg) in one call.
+
+        Returns an array of shape (n_targets, n_times) from the vectorized
+        Kasten pipeline used by the airmass constraint (no refraction or
+        aberration); infinity below the horizon.
+        """
+        ...
+
 class SPICEEphemeris(Ephemeris):
     """Ephemeris calculator using SPICE kernels"""
 
@@ -1484,6 +2942,8 @@ class SPICEEphemeris(Ephemeris):
         center_id: int = 399,
         *,
         polar_motion: bool = False,
+        fast_itrs: bool = False,
+        time_scale: str = "UTC",
     ) -> None:
         """
         Initialize SPICE ephemeris for a celestial body.
@@ -1496,6 +2956,12 @@ class SPICEEphemeris(Ephemeris):
             step_size: Time step in seconds (default: 60)
             center_id: NAIF ID of the observer/center (default: 399 = Earth)
             polar_motion: Whether to apply polar motion correction (default: False)
+            fast_itrs: Rotate GCRS to ITRS without the UT1-UTC correction
+                (at most 0.0038 deg of longitude) or polar motion, about four
+                times faster with EOP data loaded, for large grids such as
+                SAA checks (default: False)
+            time_scale: "UTC" steps clock time and skips leap seconds; "TAI"
+                or "TT" step SI seconds through them (default: "UTC")
         """
         ...
 
@@ -1534,11 +3000,35 @@ class SPICEEphemeris(Ephemeris):
         """Whether polar motion correction is applied"""
         ...
 
+    @property
+    def fast_itrs(self) -> bool:
+        """Whether ITRS skips the UT1-UTC correction and polar motion"""
+        ...
+
+    @property
+    def time_scale(self) -> str:
+        """Time scale in which the timestamp grid is uniform ("UTC", "TAI" or "TT")"""
+        ...
+
     @property
     def gcrs_pv(self) -> PositionVelocityData:
         """Position and velocity data in GCRS frame"""
         ...
 
+    @property
+    def teme_pv(self) -> PositionVelocityData:
+        """Position and velocity data in TEME frame (converted from GCRS)"""
+        ...
+
+    @property
+    def pef_pv(self) -> PositionVelocityData:
+        """Position and velocity data in the pseudo-Earth-fixed frame
+
+        TEME rotated by Greenwich mean sidereal time: ITRS without polar
+        motion, as used by legacy NORAD Earth-fixed products.
+        """
+        ...
+
     @property
     def itrs_pv(self) -> PositionVelocityData:
         """Position and velocity data in ITRS (Earth-fixed) frame"""
@@ -1604,6 +3094,21 @@ class SPICEEphemeris(Ephemeris):
         """Height above the WGS84 ellipsoid as a raw NumPy array in kilometers (one per timestamp)"""
         ...
 
+    @property
+    def geomagnetic_latitude_deg(self) -> npt.NDArray[np.float64]:
+        """Centred-dipole (IGRF) geomagnetic latitude in degrees (one per timestamp)"""
+        ...
+
+    @property
+    def geomagnetic_longitude_deg(self) -> npt.NDArray[np.float64]:
+        """Centred-dipole (IGRF) geomagnetic longitude in degrees (one per timestamp)"""
+        ...
+
+    @property
+    def cutoff_rigidity_gv(self) -> npt.NDArray[np.float64]:
+        """Störmer vertical geomagnetic cutoff rigidity in GV (one per timestamp)"""
+        ...
+
     @property
     def sun(self) -> Any:  # Returns astropy.coordinates.SkyCoord
         """SkyCoord object for Sun position relative to body"""
@@ -1659,14 +3164,33 @@ class SPICEEphemeris(Ephemeris):
         ra_deg: float,
         dec_deg: float,
         time_indices: list[int] | None = None,
+        *,
+        polar_motion: bool | None = None,
     ) -> npt.NDArray[np.float64]:
-        """Topocentric altitude/azimuth for given RA/Dec (deg) at selected times."""
+        """Topocentric altitude/azimuth for given RA/Dec (deg) at selected times.
+
+        ``polar_motion`` overrides the ephemeris's own setting for this call.
+        """
         ...
 
-    def calculate_airmass(
+    def radec_to_altaz_batch(
         self,
-        ra_deg: float,
-        dec_deg: float,
+        ra_deg: Sequence[float] | npt.NDArray[np.float64],
+        dec_deg: Sequence[float] | npt.NDArray[np.float64],
+        time_indices: list[int] | None = None,
+        *,
+        polar_motion: bool | None = None,
+    ) -> npt.NDArray[np.float64]:
+        """Altitude/azimuth for many targets, shape (n_targets, n_times, 2).
+
+        ``polar_motion`` overrides the ephemeris's own setting for this call.
+        """
+        ...
+
+    def calculate_airmass(
+        self,
+        ra_deg: float,
+        dec_deg: float,
         time_indices: list[int] | None = None,
     ) -> list[float]:
         """Calculate airmass for given RA/Dec (deg) at selected times.
@@ -1676,6 +3200,20 @@ class SPICEEphemeris(Ephemeris):
         """
         ...
 
+    def airmass_batch(
+        self,
+        ras: Sequence[float] | npt.NDArray[np.float64],
+        decs: Sequence[float] | npt.NDArray[np.float64],
+        time_indices: list[int] | None = None,
+    ) -> npt.NDArray[np.float64]:
+        """Calculate airmass for many RA/Dec targets (deg) in one call.
+
+        Returns an array of shape (n_targets, n_times) from the vectorized
+        Kasten pipeline used by the airmass constraint (no refraction or
+        aberration); infinity below the horizon.
+        """
+        ...
+
     @property
     def sun_radius(self) -> Any:  # Returns astropy.units.Quantity
         """
@@ -1917,6 +3455,104 @@ class SPICEEphemeris(Ephemeris):
         """
         ...
 
+    def fingerprint(self) -> str:
+        """
+        Stable hex digest identifying this ephemeris.
+
+        Hashes the source (elements, file path or site), the options, the time
+        grid and the computed GCRS states, so ephemerides built the same way
+        share a fingerprint across sessions. Ephemerides with equal
+        fingerprints compare equal, and the fingerprint can key caches of
+        constraint results or schedules.
+
+        Returns:
+            32-character hexadecimal digest
+        """
+        ...
+
+    def __eq__(self, other: object) -> bool: ...
+    def __hash__(self) -> int: ...
+    @property
+    def metadata(self) -> dict[str, Any]:
+        """
+        Source, time span, computed frames and Earth orientation state.
+
+        Keys start with ``type`` and the source fields of this class (e.g.
+        ``norad_id`` and ``tle_epoch``, or a file path), then ``polar_motion``,
+        ``begin``, ``end``, ``step_size``, ``n_times``, ``frames``,
+        ``eop_available``, ``polar_motion_applied``, ``fast_itrs``,
+        ``ut1_available`` and ``fingerprint``. Values are
+        JSON-serializable, with times as ISO 8601 strings, for provenance logs.
+        """
+        ...
+
+    @property
+    def provenance(self) -> Provenance:
+        """
+        Reproducibility record: library version, source and fingerprint,
+        element set, input and kernel file digests, Earth orientation and
+        leap-second data.
+        """
+        ...
+
+    def summary(self) -> str:
+        """Human-readable, multi-line description of ``metadata``."""
+        ...
+
+    def __repr__(self) -> str: ...
+    def __len__(self) -> int:
+        """Number of time steps"""
+        ...
+
+    def __getitem__(self, index: int) -> EphemerisSample:
+        """State at time step ``index``; negative indices count from the end"""
+        ...
+
+    def __iter__(self) -> Iterator[EphemerisSample]:
+        """Iterate over the time steps in order"""
+        ...
+
+    @property
+    def illumination_factor(self) -> npt.NDArray[np.float64]:
+        """Fraction of the solar disk visible from the observer at each time step
+
+        1.0 in sunlight, 0.0 in the Earth's umbra and fractional in the
+        penumbra, from the overlap of the apparent Sun and Earth disks.
+        """
+        ...
+
+    @property
+    def geodetic_rates(self) -> npt.NDArray[np.float64] | None:
+        """Geodetic rates from the ITRS velocity, shape (N, 3)
+
+        Columns are latitude rate (deg/s), longitude rate (deg/s) and height
+        rate (km/s). The longitude rate diverges at the poles.
+        """
+        ...
+
+    @property
+    def ground_speed(self) -> npt.NDArray[np.float64] | None:
+        """Speed of the sub-observer point over the WGS84 ellipsoid (km/s)"""
+        ...
+
+    @property
+    def sub_satellite_point(self) -> npt.NDArray[np.float64] | None:
+        """Geodetic (latitude, longitude) in degrees below the observer, shape (N, 2)"""
+        ...
+
+    @property
+    def sub_solar_point(self) -> npt.NDArray[np.float64]:
+        """(latitude, longitude) in degrees with the Sun at the zenith, shape (N, 2)"""
+        ...
+
+    @property
+    def nadir_solar_zenith_angle(self) -> npt.NDArray[np.float64] | None:
+        """Solar zenith angle at the sub-satellite point in degrees
+
+        The ground below the observer is daylit below 90.
+        """
+        ...
+
     def moon_illumination(self, time_indices: list[int] | None = None) -> list[float]:
         """
         Calculate Moon illumination fraction for all ephemeris times.
@@ -1932,32 +3568,208 @@ class SPICEEphemeris(Ephemeris):
         """
         ...
 
+    def access_to(
+        self,
+        lat: float,
+        lon: float,
+        max_off_nadir_deg: float,
+        min_sun_elev: float | None = None,
+    ) -> list[AccessOpportunity]:
+        """
+        Find imaging opportunities of a ground target.
+
+        A time is accessible when the target is above its local horizon (on
+        the visible side of the Earth's limb), within ``max_off_nadir_deg`` of
+        the spacecraft's nadir and, if ``min_sun_elev`` is given, the Sun is
+        at least that high at the target.
+
+        Args:
+            lat: Geodetic latitude of the target in degrees
+            lon: Longitude of the target in degrees, east positive
+            max_off_nadir_deg: Maximum off-nadir angle in degrees (0-90)
+            min_sun_elev: Minimum Sun elevation at the target in degrees
+                (default: no illumination requirement)
+
+        Returns:
+            Opportunities in time order, each summarised at its smallest
+            off-nadir angle
+
+        Raises:
+            ValueError: If the target location or an angle is out of range
+        """
+        ...
+
     def get_body_pv(
-        self, body: str, spice_kernel: str | None = ..., use_horizons: bool = ...
+        self,
+        body: str,
+        spice_kernel: str | None = ...,
+        use_horizons: bool = ...,
+        source: str | None = ...,
     ) -> PositionVelocityData:
         """
         Get position and velocity of a celestial body.
 
         Args:
-            body: Name of the body (e.g., 'sun', 'moon', 'earth')
+            body: Name of the body (e.g., 'sun', 'moon', 'earth'), or a pseudo-body
+                ('EMB', 'SSB', 'SEL1', 'SEL2', 'EML1', 'EML2')
             spice_kernel: Optional path to SPICE kernel
             use_horizons: If True, fall back to JPL Horizons API when SPICE fails
+                (equivalent to ``source="auto"``)
+            source: Data source precedence: ``"spice"`` (loaded kernels only),
+                ``"horizons"`` (JPL Horizons only) or ``"auto"`` (SPICE, falling back
+                to Horizons with a ``UserWarning``). Defaults to ``"spice"`` unless
+                ``use_horizons`` is set.
 
         Returns:
             Position and velocity data for the requested body
         """
         ...
 
+    def get_bodies(
+        self,
+        bodies: list[str],
+        spice_kernel: str | None = ...,
+        use_horizons: bool = ...,
+        source: str | None = ...,
+    ) -> dict[str, PositionVelocityData]:
+        """
+        Get position and velocity of several celestial bodies in one pass.
+
+        Equivalent to calling ``get_body_pv`` for each body, but the SPICE
+        lookups and the observer positions are set up once for all bodies.
+
+        Args:
+            bodies: Body names or NAIF IDs, as accepted by ``get_body_pv``
+            spice_kernel: Optional path to SPICE kernel
+            use_horizons: If True, fall back to JPL Horizons API when SPICE fails
+                (equivalent to ``source="auto"``)
+            source: Data source precedence, as for ``get_body_pv``
+
+        Returns:
+            Dict mapping each requested body, as given, to its position and
+            velocity data
+        """
+        ...
+
+    def separation(self, a: Any, b: Any) -> npt.NDArray[np.float64]:
+        """
+        Angular separation between two targets at each time step.
+
+        Computed in Rust from observer-relative vectors, without astropy.
+
+        Args:
+            a, b: Each an ``(ra_deg, dec_deg)`` pair, a body name (``"sun"``,
+                ``"moon"`` and ``"earth"`` use the cached positions; other names
+                resolve as in ``get_body_pv``), observer-relative
+                ``PositionVelocityData`` (e.g. from ``get_body_pv``), or a
+                SkyCoord with a single position or one per time step (e.g.
+                ``ephem.sun``)
+
+        Returns:
+            Separation angles in degrees, one per time step
+
+        Raises:
+            TypeError: If an operand is not one of the accepted kinds
+            ValueError: If an operand has a different number of time steps
+        """
+        ...
+
+    def sun_angle(
+        self, ra_deg: float, dec_deg: float, time_indices: list[int] | None = ...
+    ) -> npt.NDArray[np.float64]:
+        """
+        Angle between a target and the Sun at each time step.
+
+        Args:
+            ra_deg: Target right ascension in degrees
+            dec_deg: Target declination in degrees
+            time_indices: Optional indices into the ephemeris times (default: all)
+
+        Returns:
+            Angles in degrees, one per selected time step
+        """
+        ...
+
+    def moon_angle(
+        self, ra_deg: float, dec_deg: float, time_indices: list[int] | None = ...
+    ) -> npt.NDArray[np.float64]:
+        """
+        Angle between a target and the Moon at each time step.
+
+        Args:
+            ra_deg: Target right ascension in degrees
+            dec_deg: Target declination in degrees
+            time_indices: Optional indices into the ephemeris times (default: all)
+
+        Returns:
+            Angles in degrees, one per selected time step
+        """
+        ...
+
+    def earth_limb_angle(
+        self, ra_deg: float, dec_deg: float, time_indices: list[int] | None = ...
+    ) -> npt.NDArray[np.float64]:
+        """
+        Angle of a target above the Earth's limb at each time step.
+
+        The angle from the Earth's center minus the Earth's angular radius, so
+        negative while the target is behind the Earth. No refraction or horizon
+        mask is applied.
+
+        Args:
+            ra_deg: Target right ascension in degrees
+            dec_deg: Target declination in degrees
+            time_indices: Optional indices into the ephemeris times (default: all)
+
+        Returns:
+            Angles in degrees, one per selected time step
+        """
+        ...
+
+    def geometry(
+        self,
+        ra_deg: float,
+        dec_deg: float,
+        time: datetime,
+        *,
+        saa_polygon: list[tuple[float, float]] | None = None,
+    ) -> ObservationGeometry:
+        """
+        Observation geometry of a target at the ephemeris step nearest ``time``.
+
+        Args:
+            ra_deg: Target right ascension in degrees
+            dec_deg: Target declination in degrees
+            time: Time of interest; the nearest ephemeris step is used
+            saa_polygon: Optional SAA region as (longitude, latitude) vertices in
+                degrees; sets ``in_saa``
+
+        Returns:
+            Sun, Moon and Earth-limb angles, plus altitude and airmass for ground
+            sites or eclipse, orbital phase and beta angle for orbiting observers
+        """
+        ...
+
     def get_body(
-        self, body: str, spice_kernel: str | None = ..., use_horizons: bool = ...
+        self,
+        body: str,
+        spice_kernel: str | None = ...,
+        use_horizons: bool = ...,
+        source: str | None = ...,
     ) -> Any:  # Returns astropy.coordinates.SkyCoord
         """
         Get SkyCoord for a celestial body.
 
         Args:
-            body: Name of the body (e.g., 'sun', 'moon', 'earth')
+            body: Name of the body (e.g., 'sun', 'moon', 'earth'), or a pseudo-body
+                ('EMB', 'SSB', 'SEL1', 'SEL2', 'EML1', 'EML2')
             spice_kernel: Optional path to SPICE kernel
             use_horizons: If True, fall back to JPL Horizons API when SPICE fails
+                (equivalent to ``source="auto"``)
+            source: Data source precedence: ``"spice"`` (loaded kernels only),
+                ``"horizons"`` (JPL Horizons only) or ``"auto"`` (SPICE, falling back
+                to Horizons with a ``UserWarning``). Defaults to ``"spice"`` unless
+                ``use_horizons`` is set.
 
         Returns:
             astropy.coordinates.SkyCoord object
@@ -1968,14 +3780,15 @@ class OEMEphemeris(Ephemeris):
     """
     Ephemeris calculator using CCSDS Orbit Ephemeris Messages (OEM).
 
-    The OEM file must specify a reference frame that is compatible with GCRS
-    (Geocentric Celestial Reference System). Compatible frames include:
-    - J2000 / EME2000 (Earth Mean Equator and Equinox of J2000.0)
-    - GCRF (Geocentric Celestial Reference Frame)
-    - ICRF (International Celestial Reference Frame)
+    The REF_FRAME declared in the OEM file is honored and states are converted
+    to GCRS on load. Supported frames:
+    - J2000 / EME2000, GCRF and ICRF (taken as GCRS)
+    - TOD (true equator and equinox of date)
+    - TEME (true equator, mean equinox)
+    - ITRF and its realizations (e.g. ITRF2014), Earth-fixed
 
-    OEM files using Earth-fixed frames (e.g., ITRF) or other incompatible frames
-    will be rejected with a ValueError.
+    CENTER_NAME must be EARTH and all segments must share one REF_FRAME. Other
+    frames or centers are rejected with a ValueError.
     """
 
     def __init__(
@@ -1986,6 +3799,11 @@ class OEMEphemeris(Ephemeris):
         step_size: int = 60,
         *,
         polar_motion: bool = False,
+        fast_itrs: bool = False,
+        time_scale: str = "UTC",
+        interpolation: str = "hermite",
+        interpolation_window: int | None = None,
+        max_gap: float | None = None,
     ) -> None:
         """
         Initialize CCSDS OEM ephemeris from an OEM file.
@@ -1996,10 +3814,25 @@ class OEMEphemeris(Ephemeris):
             end: End time (naive datetime treated as UTC)
             step_size: Time step in seconds (default: 60)
             polar_motion: Whether to apply polar motion correction (default: False)
+            fast_itrs: Rotate GCRS to ITRS without the UT1-UTC correction
+                (at most 0.0038 deg of longitude) or polar motion, about four
+                times faster with EOP data loaded, for large grids such as
+                SAA checks (default: False)
+            time_scale: "UTC" steps clock time and skips leap seconds; "TAI"
+                or "TT" step SI seconds through them (default: "UTC")
+            interpolation: Resampling scheme, ``"hermite"`` or ``"lagrange"``
+                (default: ``"hermite"``)
+            interpolation_window: Samples per interpolant (default: 2 for
+                Hermite, i.e. cubic; 8 for Lagrange)
+            max_gap: Largest OEM sample spacing in seconds to interpolate
+                across; grid points inside a wider gap raise ValueError
+                (default: no limit)
 
         Raises:
             ValueError: If OEM file cannot be parsed, time range exceeds available data,
-                       reference frame is missing, or reference frame is incompatible with GCRS
+                       the reference frame or center is missing or unsupported, the
+                       interpolation options are invalid, or the grid crosses a gap
+                       wider than max_gap
         """
         ...
 
@@ -2028,11 +3861,71 @@ class OEMEphemeris(Ephemeris):
         """Whether polar motion correction is applied"""
         ...
 
+    @property
+    def fast_itrs(self) -> bool:
+        """Whether ITRS skips the UT1-UTC correction and polar motion"""
+        ...
+
+    @property
+    def time_scale(self) -> str:
+        """Time scale in which the timestamp grid is uniform ("UTC", "TAI" or "TT")"""
+        ...
+
+    @property
+    def ref_frame(self) -> str:
+        """REF_FRAME declared in the OEM file"""
+        ...
+
+    @property
+    def center_name(self) -> str:
+        """CENTER_NAME declared in the OEM file"""
+        ...
+
+    @property
+    def interpolation(self) -> str:
+        """Resampling scheme: ``"hermite"`` or ``"lagrange"``"""
+        ...
+
+    @property
+    def interpolation_window(self) -> int:
+        """Number of OEM samples each interpolant passes through"""
+        ...
+
+    @property
+    def max_gap(self) -> float | None:
+        """Largest OEM sample spacing in seconds that may be interpolated across"""
+        ...
+
+    @property
+    def max_interpolation_error(self) -> float | None:
+        """
+        Estimated largest position interpolation error in km.
+
+        The interpolant is compared with one through an extra sample at the
+        midpoint of each OEM interval in the ephemeris span. None without
+        enough samples.
+        """
+        ...
+
     @property
     def gcrs_pv(self) -> PositionVelocityData:
         """Position and velocity data in GCRS frame (interpolated)"""
         ...
 
+    @property
+    def teme_pv(self) -> PositionVelocityData:
+        """Position and velocity data in TEME frame (converted from GCRS)"""
+        ...
+
+    @property
+    def pef_pv(self) -> PositionVelocityData:
+        """Position and velocity data in the pseudo-Earth-fixed frame
+
+        TEME rotated by Greenwich mean sidereal time: ITRS without polar
+        motion, as used by legacy NORAD Earth-fixed products.
+        """
+        ...
+
     @property
     def itrs_pv(self) -> PositionVelocityData:
         """Position and velocity data in ITRS (Earth-fixed) frame"""
@@ -2098,6 +3991,21 @@ class OEMEphemeris(Ephemeris):
         """Height above the WGS84 ellipsoid as a raw NumPy array in kilometers (one per timestamp)"""
         ...
 
+    @property
+    def geomagnetic_latitude_deg(self) -> npt.NDArray[np.float64]:
+        """Centred-dipole (IGRF) geomagnetic latitude in degrees (one per timestamp)"""
+        ...
+
+    @property
+    def geomagnetic_longitude_deg(self) -> npt.NDArray[np.float64]:
+        """Centred-dipole (IGRF) geomagnetic longitude in degrees (one per timestamp)"""
+        ...
+
+    @property
+    def cutoff_rigidity_gv(self) -> npt.NDArray[np.float64]:
+        """Störmer vertical geomagnetic cutoff rigidity in GV (one per timestamp)"""
+        ...
+
     @property
     def sun(self) -> Any:  # Returns astropy.coordinates.SkyCoord
         """SkyCoord object for Sun position relative to spacecraft"""
@@ -2123,7 +4031,8 @@ class OEMEphemeris(Ephemeris):
         """
         Raw OEM position and velocity data without interpolation.
 
-        Returns the original state vectors from the OEM file.
+        Returns the original state vectors from the OEM file, converted from
+        the declared REF_FRAME to GCRS.
         """
         ...
 
@@ -2136,6 +4045,33 @@ class OEMEphemeris(Ephemeris):
         """
         ...
 
+    @property
+    def covariance(self) -> npt.NDArray[np.float64]:
+        """
+        Covariance matrices from the OEM file as a (K, 6, 6) array.
+
+        Units are km and km/s, expressed in GCRS (RTN/RSW, TOD and TEME
+        covariances are rotated into it). Empty if the file has no covariance.
+        """
+        ...
+
+    @property
+    def covariance_timestamp(self) -> list[datetime]:
+        """Epochs of the OEM covariance matrices as UTC datetime objects"""
+        ...
+
+    @property
+    def position_uncertainty(self) -> npt.NDArray[np.float64] | None:
+        """
+        1-sigma position uncertainty at each timestamp.
+
+        Returns an (N, 3) array of [radial, in-track, cross-track] in km, or
+        None if the OEM file has no covariance. Covariances are propagated
+        linearly along a two-body orbit from the latest covariance epoch at or
+        before each timestamp.
+        """
+        ...
+
     @property
     def sun_pv(self) -> PositionVelocityData:
         """Sun position and velocity in GCRS frame"""
@@ -2171,8 +4107,27 @@ class OEMEphemeris(Ephemeris):
         ra_deg: float,
         dec_deg: float,
         time_indices: list[int] | None = None,
+        *,
+        polar_motion: bool | None = None,
+    ) -> npt.NDArray[np.float64]:
+        """Topocentric altitude/azimuth for given RA/Dec (deg) at selected times.
+
+        ``polar_motion`` overrides the ephemeris's own setting for this call.
+        """
+        ...
+
+    def radec_to_altaz_batch(
+        self,
+        ra_deg: Sequence[float] | npt.NDArray[np.float64],
+        dec_deg: Sequence[float] | npt.NDArray[np.float64],
+        time_indices: list[int] | None = None,
+        *,
+        polar_motion: bool | None = None,
     ) -> npt.NDArray[np.float64]:
-        """Topocentric altitude/azimuth for given RA/Dec (deg) at selected times."""
+        """Altitude/azimuth for many targets, shape (n_targets, n_times, 2).
+
+        ``polar_motion`` overrides the ephemeris's own setting for this call.
+        """
         ...
 
     def calculate_airmass(
@@ -2188,6 +4143,20 @@ class OEMEphemeris(Ephemeris):
         """
         ...
 
+    def airmass_batch(
+        self,
+        ras: Sequence[float] | npt.NDArray[np.float64],
+        decs: Sequence[float] | npt.NDArray[np.float64],
+        time_indices: list[int] | None = None,
+    ) -> npt.NDArray[np.float64]:
+        """Calculate airmass for many RA/Dec targets (deg) in one call.
+
+        Returns an array of shape (n_targets, n_times) from the vectorized
+        Kasten pipeline used by the airmass constraint (no refraction or
+        aberration); infinity below the horizon.
+        """
+        ...
+
     @property
     def sun_radius(self) -> Any:  # Returns astropy.units.Quantity
         """
@@ -2429,50 +4398,324 @@ class OEMEphemeris(Ephemeris):
         """
         ...
 
-    def moon_illumination(self, time_indices: list[int] | None = None) -> list[float]:
+    def fingerprint(self) -> str:
         """
-        Calculate Moon illumination fraction for all ephemeris times.
-
-        Returns the fraction of the Moon's illuminated surface as seen from the
-        spacecraft observer (0.0 = new moon, 1.0 = full moon).
+        Stable hex digest identifying this ephemeris.
 
-        Args:
-            time_indices: Optional indices into ephemeris times (default: all times)
+        Hashes the source (elements, file path or site), the options, the time
+        grid and the computed GCRS states, so ephemerides built the same way
+        share a fingerprint across sessions. Ephemerides with equal
+        fingerprints compare equal, and the fingerprint can key caches of
+        constraint results or schedules.
 
         Returns:
-            List of Moon illumination fractions
+            32-character hexadecimal digest
         """
         ...
 
-    def get_body_pv(
-        self, body: str, spice_kernel: str | None = ..., use_horizons: bool = ...
-    ) -> PositionVelocityData:
+    def __eq__(self, other: object) -> bool: ...
+    def __hash__(self) -> int: ...
+    @property
+    def metadata(self) -> dict[str, Any]:
         """
-        Get position and velocity of a celestial body.
-
-        Args:
-            body: Name of the body (e.g., 'sun', 'moon', 'earth')
-            spice_kernel: Optional path to SPICE kernel
-            use_horizons: If True, fall back to JPL Horizons API when SPICE fails
+        Source, time span, computed frames and Earth orientation state.
 
-        Returns:
-            Position and velocity data for the requested body
+        Keys start with ``type`` and the source fields of this class (e.g.
+        ``norad_id`` and ``tle_epoch``, or a file path), then ``polar_motion``,
+        ``begin``, ``end``, ``step_size``, ``n_times``, ``frames``,
+        ``eop_available``, ``polar_motion_applied``, ``fast_itrs``,
+        ``ut1_available`` and ``fingerprint``. Values are
+        JSON-serializable, with times as ISO 8601 strings, for provenance logs.
         """
         ...
 
-    def get_body(
-        self, body: str, spice_kernel: str | None = ..., use_horizons: bool = ...
-    ) -> Any:  # Returns astropy.coordinates.SkyCoord
+    @property
+    def provenance(self) -> Provenance:
         """
-        Get SkyCoord for a celestial body.
-
-        Args:
-            body: Name of the body (e.g., 'sun', 'moon', 'earth')
-            spice_kernel: Optional path to SPICE kernel
-            use_horizons: If True, fall back to JPL Horizons API when SPICE fails
-
-        Returns:
-            astropy.coordinates.SkyCoord object
+        Reproducibility record: library version, source and fingerprint,
+        element set, input and kernel file digests, Earth orientation and
+        leap-second data.
+        """
+        ...
+
+    def summary(self) -> str:
+        """Human-readable, multi-line description of ``metadata``."""
+        ...
+
+    def __repr__(self) -> str: ...
+    def __len__(self) -> int:
+        """Number of time steps"""
+        ...
+
+    def __getitem__(self, index: int) -> EphemerisSample:
+        """State at time step ``index``; negative indices count from the end"""
+        ...
+
+    def __iter__(self) -> Iterator[EphemerisSample]:
+        """Iterate over the time steps in order"""
+        ...
+
+    @property
+    def illumination_factor(self) -> npt.NDArray[np.float64]:
+        """Fraction of the solar disk visible from the observer at each time step
+
+        1.0 in sunlight, 0.0 in the Earth's umbra and fractional in the
+        penumbra, from the overlap of the apparent Sun and Earth disks.
+        """
+        ...
+
+    @property
+    def geodetic_rates(self) -> npt.NDArray[np.float64] | None:
+        """Geodetic rates from the ITRS velocity, shape (N, 3)
+
+        Columns are latitude rate (deg/s), longitude rate (deg/s) and height
+        rate (km/s). The longitude rate diverges at the poles.
+        """
+        ...
+
+    @property
+    def ground_speed(self) -> npt.NDArray[np.float64] | None:
+        """Speed of the sub-observer point over the WGS84 ellipsoid (km/s)"""
+        ...
+
+    @property
+    def sub_satellite_point(self) -> npt.NDArray[np.float64] | None:
+        """Geodetic (latitude, longitude) in degrees below the observer, shape (N, 2)"""
+        ...
+
+    @property
+    def sub_solar_point(self) -> npt.NDArray[np.float64]:
+        """(latitude, longitude) in degrees with the Sun at the zenith, shape (N, 2)"""
+        ...
+
+    @property
+    def nadir_solar_zenith_angle(self) -> npt.NDArray[np.float64] | None:
+        """Solar zenith angle at the sub-satellite point in degrees
+
+        The ground below the observer is daylit below 90.
+        """
+        ...
+
+    def moon_illumination(self, time_indices: list[int] | None = None) -> list[float]:
+        """
+        Calculate Moon illumination fraction for all ephemeris times.
+
+        Returns the fraction of the Moon's illuminated surface as seen from the
+        spacecraft observer (0.0 = new moon, 1.0 = full moon).
+
+        Args:
+            time_indices: Optional indices into ephemeris times (default: all times)
+
+        Returns:
+            List of Moon illumination fractions
+        """
+        ...
+
+    def access_to(
+        self,
+        lat: float,
+        lon: float,
+        max_off_nadir_deg: float,
+        min_sun_elev: float | None = None,
+    ) -> list[AccessOpportunity]:
+        """
+        Find imaging opportunities of a ground target.
+
+        A time is accessible when the target is above its local horizon (on
+        the visible side of the Earth's limb), within ``max_off_nadir_deg`` of
+        the spacecraft's nadir and, if ``min_sun_elev`` is given, the Sun is
+        at least that high at the target.
+
+        Args:
+            lat: Geodetic latitude of the target in degrees
+            lon: Longitude of the target in degrees, east positive
+            max_off_nadir_deg: Maximum off-nadir angle in degrees (0-90)
+            min_sun_elev: Minimum Sun elevation at the target in degrees
+                (default: no illumination requirement)
+
+        Returns:
+            Opportunities in time order, each summarised at its smallest
+            off-nadir angle
+
+        Raises:
+            ValueError: If the target location or an angle is out of range
+        """
+        ...
+
+    def get_body_pv(
+        self,
+        body: str,
+        spice_kernel: str | None = ...,
+        use_horizons: bool = ...,
+        source: str | None = ...,
+    ) -> PositionVelocityData:
+        """
+        Get position and velocity of a celestial body.
+
+        Args:
+            body: Name of the body (e.g., 'sun', 'moon', 'earth'), or a pseudo-body
+                ('EMB', 'SSB', 'SEL1', 'SEL2', 'EML1', 'EML2')
+            spice_kernel: Optional path to SPICE kernel
+            use_horizons: If True, fall back to JPL Horizons API when SPICE fails
+                (equivalent to ``source="auto"``)
+            source: Data source precedence: ``"spice"`` (loaded kernels only),
+                ``"horizons"`` (JPL Horizons only) or ``"auto"`` (SPICE, falling back
+                to Horizons with a ``UserWarning``). Defaults to ``"spice"`` unless
+                ``use_horizons`` is set.
+
+        Returns:
+            Position and velocity data for the requested body
+        """
+        ...
+
+    def get_bodies(
+        self,
+        bodies: list[str],
+        spice_kernel: str | None = ...,
+        use_horizons: bool = ...,
+        source: str | None = ...,
+    ) -> dict[str, PositionVelocityData]:
+        """
+        Get position and velocity of several celestial bodies in one pass.
+
+        Equivalent to calling ``get_body_pv`` for each body, but the SPICE
+        lookups and the observer positions are set up once for all bodies.
+
+        Args:
+            bodies: Body names or NAIF IDs, as accepted by ``get_body_pv``
+            spice_kernel: Optional path to SPICE kernel
+            use_horizons: If True, fall back to JPL Horizons API when SPICE fails
+                (equivalent to ``source="auto"``)
+            source: Data source precedence, as for ``get_body_pv``
+
+        Returns:
+            Dict mapping each requested body, as given, to its position and
+            velocity data
+        """
+        ...
+
+    def separation(self, a: Any, b: Any) -> npt.NDArray[np.float64]:
+        """
+        Angular separation between two targets at each time step.
+
+        Computed in Rust from observer-relative vectors, without astropy.
+
+        Args:
+            a, b: Each an ``(ra_deg, dec_deg)`` pair, a body name (``"sun"``,
+                ``"moon"`` and ``"earth"`` use the cached positions; other names
+                resolve as in ``get_body_pv``), observer-relative
+                ``PositionVelocityData`` (e.g. from ``get_body_pv``), or a
+                SkyCoord with a single position or one per time step (e.g.
+                ``ephem.sun``)
+
+        Returns:
+            Separation angles in degrees, one per time step
+
+        Raises:
+            TypeError: If an operand is not one of the accepted kinds
+            ValueError: If an operand has a different number of time steps
+        """
+        ...
+
+    def sun_angle(
+        self, ra_deg: float, dec_deg: float, time_indices: list[int] | None = ...
+    ) -> npt.NDArray[np.float64]:
+        """
+        Angle between a target and the Sun at each time step.
+
+        Args:
+            ra_deg: Target right ascension in degrees
+            dec_deg: Target declination in degrees
+            time_indices: Optional indices into the ephemeris times (default: all)
+
+        Returns:
+            Angles in degrees, one per selected time step
+        """
+        ...
+
+    def moon_angle(
+        self, ra_deg: float, dec_deg: float, time_indices: list[int] | None = ...
+    ) -> npt.NDArray[np.float64]:
+        """
+        Angle between a target and the Moon at each time step.
+
+        Args:
+            ra_deg: Target right ascension in degrees
+            dec_deg: Target declination in degrees
+            time_indices: Optional indices into the ephemeris times (default: all)
+
+        Returns:
+            Angles in degrees, one per selected time step
+        """
+        ...
+
+    def earth_limb_angle(
+        self, ra_deg: float, dec_deg: float, time_indices: list[int] | None = ...
+    ) -> npt.NDArray[np.float64]:
+        """
+        Angle of a target above the Earth's limb at each time step.
+
+        The angle from the Earth's center minus the Earth's angular radius, so
+        negative while the target is behind the Earth. No refraction or horizon
+        mask is applied.
+
+        Args:
+            ra_deg: Target right ascension in degrees
+            dec_deg: Target declination in degrees
+            time_indices: Optional indices into the ephemeris times (default: all)
+
+        Returns:
+            Angles in degrees, one per selected time step
+        """
+        ...
+
+    def geometry(
+        self,
+        ra_deg: float,
+        dec_deg: float,
+        time: datetime,
+        *,
+        saa_polygon: list[tuple[float, float]] | None = None,
+    ) -> ObservationGeometry:
+        """
+        Observation geometry of a target at the ephemeris step nearest ``time``.
+
+        Args:
+            ra_deg: Target right ascension in degrees
+            dec_deg: Target declination in degrees
+            time: Time of interest; the nearest ephemeris step is used
+            saa_polygon: Optional SAA region as (longitude, latitude) vertices in
+                degrees; sets ``in_saa``
+
+        Returns:
+            Sun, Moon and Earth-limb angles, plus altitude and airmass for ground
+            sites or eclipse, orbital phase and beta angle for orbiting observers
+        """
+        ...
+
+    def get_body(
+        self,
+        body: str,
+        spice_kernel: str | None = ...,
+        use_horizons: bool = ...,
+        source: str | None = ...,
+    ) -> Any:  # Returns astropy.coordinates.SkyCoord
+        """
+        Get SkyCoord for a celestial body.
+
+        Args:
+            body: Name of the body (e.g., 'sun', 'moon', 'earth'), or a pseudo-body
+                ('EMB', 'SSB', 'SEL1', 'SEL2', 'EML1', 'EML2')
+            spice_kernel: Optional path to SPICE kernel
+            use_horizons: If True, fall back to JPL Horizons API when SPICE fails
+                (equivalent to ``source="auto"``)
+            source: Data source precedence: ``"spice"`` (loaded kernels only),
+                ``"horizons"`` (JPL Horizons only) or ``"auto"`` (SPICE, falling back
+                to Horizons with a ``UserWarning``). Defaults to ``"spice"`` unless
+                ``use_horizons`` is set.
+
+        Returns:
+            astropy.coordinates.SkyCoord object
         """
         ...
 
@@ -2480,8 +4723,10 @@ class FileEphemeris(Ephemeris):
     """
     Ephemeris that reads pre-computed state vectors from a file.
 
-    Each row of the data section must contain at least 7 whitespace-separated
-    values: a timestamp (or numeric offset) followed by x, y, z, vx, vy, vz.
+    By default each row of the data section contains at least 7 values: a
+    timestamp (or numeric offset) followed by x, y, z, vx, vy, vz. Rows are
+    split on commas when present and on whitespace otherwise. Other layouts,
+    such as CSV exports with a header row, are read with ``columns``.
 
     Supported timestamp formats
     ---------------------------
@@ -2517,11 +4762,15 @@ class FileEphemeris(Ephemeris):
         step_size: int = 60,
         *,
         polar_motion: bool = False,
+        fast_itrs: bool = False,
+        time_scale: str = "UTC",
         position_unit: str | None = None,
         velocity_unit: str | None = None,
         frame: str | None = None,
         epoch: datetime | None = None,
         time_format: str | None = None,
+        delimiter: str | None = None,
+        columns: dict[str, int | str] | None = None,
     ) -> None:
         """
         Initialise a FileEphemeris from a state-vector file.
@@ -2532,6 +4781,12 @@ class FileEphemeris(Ephemeris):
             end: End of the output time grid (UTC).
             step_size: Output time step in seconds (default 60).
             polar_motion: Apply polar motion correction (default False).
+            fast_itrs: Rotate GCRS to ITRS without the UT1-UTC correction
+                (at most 0.0038 deg of longitude) or polar motion, for large
+                grids such as SAA checks; no effect on Earth-fixed files
+                (default False).
+            time_scale: "UTC" steps clock time and skips leap seconds; "TAI"
+                or "TT" step SI seconds through them (default: "UTC")
             position_unit: Override detected position unit.
                 Supported: ``"km"`` (default), ``"m"``, ``"cm"``.
             velocity_unit: Override detected velocity unit.
@@ -2544,12 +4799,20 @@ class FileEphemeris(Ephemeris):
                 epoch found in the file header.
             time_format: How to interpret the time column.
                 ``"auto"`` (default), ``"seconds"``, ``"days"``, ``"iso8601"``.
+            delimiter: Field separator for data rows: a single character such
+                as ``","`` or ``";"``, or ``"whitespace"``.  By default rows
+                containing a comma are split on commas, others on whitespace.
+            columns: Mapping from ``"time"``, ``"x"``, ``"y"``, ``"z"``,
+                ``"vx"``, ``"vy"``, ``"vz"`` to a zero-based column index or a
+                column name from the table's header row.  Unmapped keys keep
+                their default positions 0–6.
 
         Raises:
             IOError: If the file cannot be opened or read.
             ValueError: If no state vectors are found, the requested time range
-                exceeds the file's data range, units are unrecognised, or the
-                frame is unsupported.
+                exceeds the file's data range, units are unrecognised, the
+                frame is unsupported, or named columns are missing from the
+                header row.
         """
         ...
 
@@ -2563,6 +4826,16 @@ class FileEphemeris(Ephemeris):
         """Whether polar motion correction is applied."""
         ...
 
+    @property
+    def fast_itrs(self) -> bool:
+        """Whether ITRS skips the UT1-UTC correction and polar motion"""
+        ...
+
+    @property
+    def time_scale(self) -> str:
+        """Time scale in which the timestamp grid is uniform ("UTC", "TAI" or "TT")"""
+        ...
+
     @property
     def source_position_unit(self) -> str:
         """Position unit as found in / specified for the file (before km conversion)."""
@@ -2608,6 +4881,20 @@ class FileEphemeris(Ephemeris):
         """Position and velocity in GCRS frame (interpolated to the output grid)."""
         ...
 
+    @property
+    def teme_pv(self) -> PositionVelocityData:
+        """Position and velocity data in TEME frame (converted from GCRS)"""
+        ...
+
+    @property
+    def pef_pv(self) -> PositionVelocityData:
+        """Position and velocity data in the pseudo-Earth-fixed frame
+
+        TEME rotated by Greenwich mean sidereal time: ITRS without polar
+        motion, as used by legacy NORAD Earth-fixed products.
+        """
+        ...
+
     @property
     def itrs_pv(self) -> PositionVelocityData:
         """Position and velocity in ITRS (Earth-fixed) frame."""
@@ -2708,6 +4995,21 @@ class FileEphemeris(Ephemeris):
         """Geodetic height in kilometres."""
         ...
 
+    @property
+    def geomagnetic_latitude_deg(self) -> npt.NDArray[np.float64]:
+        """Centred-dipole (IGRF) geomagnetic latitude in degrees (one per timestamp)"""
+        ...
+
+    @property
+    def geomagnetic_longitude_deg(self) -> npt.NDArray[np.float64]:
+        """Centred-dipole (IGRF) geomagnetic longitude in degrees (one per timestamp)"""
+        ...
+
+    @property
+    def cutoff_rigidity_gv(self) -> npt.NDArray[np.float64]:
+        """Störmer vertical geomagnetic cutoff rigidity in GV (one per timestamp)"""
+        ...
+
     @property
     def sun_radius(self) -> Any:
         """Angular radius of the Sun as an astropy Quantity (degrees)."""
@@ -2847,96 +5149,465 @@ class FileEphemeris(Ephemeris):
         """Find the index of the closest timestamp to the given datetime."""
         ...
 
-    def moon_illumination(self, time_indices: list[int] | None = None) -> list[float]:
-        """Calculate Moon illumination fraction for all (or selected) ephemeris times."""
-        ...
+    def fingerprint(self) -> str:
+        """
+        Stable hex digest identifying this ephemeris.
 
-    def get_body_pv(
-        self, body: str, spice_kernel: str | None = ..., use_horizons: bool = ...
-    ) -> PositionVelocityData:
-        """Get position and velocity of a named solar-system body."""
-        ...
+        Hashes the source (elements, file path or site), the options, the time
+        grid and the computed GCRS states, so ephemerides built the same way
+        share a fingerprint across sessions. Ephemerides with equal
+        fingerprints compare equal, and the fingerprint can key caches of
+        constraint results or schedules.
 
-    def get_body(
-        self, body: str, spice_kernel: str | None = ..., use_horizons: bool = ...
-    ) -> Any:  # Returns astropy.coordinates.SkyCoord
-        """Get a SkyCoord for a named solar-system body."""
+        Returns:
+            32-character hexadecimal digest
+        """
         ...
 
-    def radec_to_altaz(
-        self,
-        ra_deg: float,
-        dec_deg: float,
-        time_indices: list[int] | None = None,
-    ) -> npt.NDArray[np.float64]:
-        """Convert RA/Dec to Altitude/Azimuth. Returns (N, 2) array: [alt_deg, az_deg]."""
+    def __eq__(self, other: object) -> bool: ...
+    def __hash__(self) -> int: ...
+    @property
+    def metadata(self) -> dict[str, Any]:
+        """
+        Source, time span, computed frames and Earth orientation state.
+
+        Keys start with ``type`` and the source fields of this class (e.g.
+        ``norad_id`` and ``tle_epoch``, or a file path), then ``polar_motion``,
+        ``begin``, ``end``, ``step_size``, ``n_times``, ``frames``,
+        ``eop_available``, ``polar_motion_applied``, ``fast_itrs``,
+        ``ut1_available`` and ``fingerprint``. Values are
+        JSON-serializable, with times as ISO 8601 strings, for provenance logs.
+        """
         ...
 
-    def calculate_airmass(
-        self,
-        ra_deg: float,
-        dec_deg: float,
-        time_indices: list[int] | None = None,
-    ) -> list[float]:
-        """Calculate airmass for a target at the given RA/Dec."""
+    @property
+    def provenance(self) -> Provenance:
+        """
+        Reproducibility record: library version, source and fingerprint,
+        element set, input and kernel file digests, Earth orientation and
+        leap-second data.
+        """
         ...
 
-class GroundEphemeris(Ephemeris):
-    """Ephemeris for a fixed ground location"""
+    def summary(self) -> str:
+        """Human-readable, multi-line description of ``metadata``."""
+        ...
 
-    def __init__(
-        self,
-        latitude: float,
-        longitude: float,
-        height: float,
-        begin: datetime,
-        end: datetime,
-        step_size: int = 60,
-        *,
-        polar_motion: bool = False,
-    ) -> None:
-        """
-        Initialize ground ephemeris for a fixed location.
+    def __repr__(self) -> str: ...
+    def __len__(self) -> int:
+        """Number of time steps"""
+        ...
 
-        Args:
-            latitude: Geodetic latitude in degrees (-90 to 90)
-            longitude: Geodetic longitude in degrees (-180 to 180)
-            height: Altitude in meters above WGS84 ellipsoid
-            begin: Start time (naive datetime treated as UTC)
-            end: End time (naive datetime treated as UTC)
-            step_size: Time step in seconds (default: 60)
-            polar_motion: Whether to apply polar motion correction (default: False)
-        """
+    def __getitem__(self, index: int) -> EphemerisSample:
+        """State at time step ``index``; negative indices count from the end"""
         ...
 
-    @property
-    def input_latitude(self) -> float:
-        """Input geodetic latitude in degrees"""
+    def __iter__(self) -> Iterator[EphemerisSample]:
+        """Iterate over the time steps in order"""
         ...
 
     @property
-    def input_longitude(self) -> float:
-        """Input geodetic longitude in degrees"""
+    def illumination_factor(self) -> npt.NDArray[np.float64]:
+        """Fraction of the solar disk visible from the observer at each time step
+
+        1.0 in sunlight, 0.0 in the Earth's umbra and fractional in the
+        penumbra, from the overlap of the apparent Sun and Earth disks.
+        """
         ...
 
     @property
-    def input_height(self) -> float:
-        """Input altitude in meters above WGS84 ellipsoid"""
+    def geodetic_rates(self) -> npt.NDArray[np.float64] | None:
+        """Geodetic rates from the ITRS velocity, shape (N, 3)
+
+        Columns are latitude rate (deg/s), longitude rate (deg/s) and height
+        rate (km/s). The longitude rate diverges at the poles.
+        """
         ...
 
     @property
-    def begin(self) -> datetime:
-        """Start time of ephemeris"""
+    def ground_speed(self) -> npt.NDArray[np.float64] | None:
+        """Speed of the sub-observer point over the WGS84 ellipsoid (km/s)"""
         ...
 
     @property
-    def end(self) -> datetime:
-        """End time of ephemeris"""
+    def sub_satellite_point(self) -> npt.NDArray[np.float64] | None:
+        """Geodetic (latitude, longitude) in degrees below the observer, shape (N, 2)"""
         ...
 
     @property
-    def step_size(self) -> int:
-        """Time step in seconds"""
+    def sub_solar_point(self) -> npt.NDArray[np.float64]:
+        """(latitude, longitude) in degrees with the Sun at the zenith, shape (N, 2)"""
+        ...
+
+    @property
+    def nadir_solar_zenith_angle(self) -> npt.NDArray[np.float64] | None:
+        """Solar zenith angle at the sub-satellite point in degrees
+
+        The ground below the observer is daylit below 90.
+        """
+        ...
+
+    def moon_illumination(self, time_indices: list[int] | None = None) -> list[float]:
+        """Calculate Moon illumination fraction for all (or selected) ephemeris times."""
+        ...
+
+    def access_to(
+        self,
+        lat: float,
+        lon: float,
+        max_off_nadir_deg: float,
+        min_sun_elev: float | None = None,
+    ) -> list[AccessOpportunity]:
+        """
+        Find imaging opportunities of a ground target.
+
+        A time is accessible when the target is above its local horizon (on
+        the visible side of the Earth's limb), within ``max_off_nadir_deg`` of
+        the spacecraft's nadir and, if ``min_sun_elev`` is given, the Sun is
+        at least that high at the target.
+
+        Args:
+            lat: Geodetic latitude of the target in degrees
+            lon: Longitude of the target in degrees, east positive
+            max_off_nadir_deg: Maximum off-nadir angle in degrees (0-90)
+            min_sun_elev: Minimum Sun elevation at the target in degrees
+                (default: no illumination requirement)
+
+        Returns:
+            Opportunities in time order, each summarised at its smallest
+            off-nadir angle
+
+        Raises:
+            ValueError: If the target location or an angle is out of range
+        """
+        ...
+
+    def get_body_pv(
+        self,
+        body: str,
+        spice_kernel: str | None = ...,
+        use_horizons: bool = ...,
+        source: str | None = ...,
+    ) -> PositionVelocityData:
+        """Get position and velocity of a named solar-system body."""
+        ...
+
+    def get_bodies(
+        self,
+        bodies: list[str],
+        spice_kernel: str | None = ...,
+        use_horizons: bool = ...,
+        source: str | None = ...,
+    ) -> dict[str, PositionVelocityData]:
+        """
+        Get position and velocity of several celestial bodies in one pass.
+
+        Equivalent to calling ``get_body_pv`` for each body, but the SPICE
+        lookups and the observer positions are set up once for all bodies.
+
+        Args:
+            bodies: Body names or NAIF IDs, as accepted by ``get_body_pv``
+            spice_kernel: Optional path to SPICE kernel
+            use_horizons: If True, fall back to JPL Horizons API when SPICE fails
+                (equivalent to ``source="auto"``)
+            source: Data source precedence, as for ``get_body_pv``
+
+        Returns:
+            Dict mapping each requested body, as given, to its position and
+            velocity data
+        """
+        ...
+
+    def separation(self, a: Any, b: Any) -> npt.NDArray[np.float64]:
+        """
+        Angular separation between two targets at each time step.
+
+        Computed in Rust from observer-relative vectors, without astropy.
+
+        Args:
+            a, b: Each an ``(ra_deg, dec_deg)`` pair, a body name (``"sun"``,
+                ``"moon"`` and ``"earth"`` use the cached positions; other names
+                resolve as in ``get_body_pv``), observer-relative
+                ``PositionVelocityData`` (e.g. from ``get_body_pv``), or a
+                SkyCoord with a single position or one per time step (e.g.
+                ``ephem.sun``)
+
+        Returns:
+            Separation angles in degrees, one per time step
+
+        Raises:
+            TypeError: If an operand is not one of the accepted kinds
+            ValueError: If an operand has a different number of time steps
+        """
+        ...
+
+    def sun_angle(
+        self, ra_deg: float, dec_deg: float, time_indices: list[int] | None = ...
+    ) -> npt.NDArray[np.float64]:
+        """
+        Angle between a target and the Sun at each time step.
+
+        Args:
+            ra_deg: Target right ascension in degrees
+            dec_deg: Target declination in degrees
+            time_indices: Optional indices into the ephemeris times (default: all)
+
+        Returns:
+            Angles in degrees, one per selected time step
+        """
+        ...
+
+    def moon_angle(
+        self, ra_deg: float, dec_deg: float, time_indices: list[int] | None = ...
+    ) -> npt.NDArray[np.float64]:
+        """
+        Angle between a target and the Moon at each time step.
+
+        Args:
+            ra_deg: Target right ascension in degrees
+            dec_deg: Target declination in degrees
+            time_indices: Optional indices into the ephemeris times (default: all)
+
+        Returns:
+            Angles in degrees, one per selected time step
+        """
+        ...
+
+    def earth_limb_angle(
+        self, ra_deg: float, dec_deg: float, time_indices: list[int] | None = ...
+    ) -> npt.NDArray[np.float64]:
+        """
+        Angle of a target above the Earth's limb at each time step.
+
+        The angle from the Earth's center minus the Earth's angular radius, so
+        negative while the target is behind the Earth. No refraction or horizon
+        mask is applied.
+
+        Args:
+            ra_deg: Target right ascension in degrees
+            dec_deg: Target declination in degrees
+            time_indices: Optional indices into the ephemeris times (default: all)
+
+        Returns:
+            Angles in degrees, one per selected time step
+        """
+        ...
+
+    def geometry(
+        self,
+        ra_deg: float,
+        dec_deg: float,
+        time: datetime,
+        *,
+        saa_polygon: list[tuple[float, float]] | None = None,
+    ) -> ObservationGeometry:
+        """
+        Observation geometry of a target at the ephemeris step nearest ``time``.
+
+        Args:
+            ra_deg: Target right ascension in degrees
+            dec_deg: Target declination in degrees
+            time: Time of interest; the nearest ephemeris step is used
+            saa_polygon: Optional SAA region as (longitude, latitude) vertices in
+                degrees; sets ``in_saa``
+
+        Returns:
+            Sun, Moon and Earth-limb angles, plus altitude and airmass for ground
+            sites or eclipse, orbital phase and beta angle for orbiting observers
+        """
+        ...
+
+    def get_body(
+        self,
+        body: str,
+        spice_kernel: str | None = ...,
+        use_horizons: bool = ...,
+        source: str | None = ...,
+    ) -> Any:  # Returns astropy.coordinates.SkyCoord
+        """Get a SkyCoord for a named solar-system body."""
+        ...
+
+    def radec_to_altaz(
+        self,
+        ra_deg: float,
+        dec_deg: float,
+        time_indices: list[int] | None = None,
+        *,
+        polar_motion: bool | None = None,
+    ) -> npt.NDArray[np.float64]:
+        """Convert RA/Dec to Altitude/Azimuth. Returns (N, 2) array: [alt_deg, az_deg].
+
+        ``polar_motion`` overrides the ephemeris's own setting for this call.
+        """
+        ...
+
+    def radec_to_altaz_batch(
+        self,
+        ra_deg: Sequence[float] | npt.NDArray[np.float64],
+        dec_deg: Sequence[float] | npt.NDArray[np.float64],
+        time_indices: list[int] | None = None,
+        *,
+        polar_motion: bool | None = None,
+    ) -> npt.NDArray[np.float64]:
+        """Altitude/azimuth for many targets, shape (n_targets, n_times, 2).
+
+        ``polar_motion`` overrides the ephemeris's own setting for this call.
+        """
+        ...
+
+    def calculate_airmass(
+        self,
+        ra_deg: float,
+        dec_deg: float,
+        time_indices: list[int] | None = None,
+    ) -> list[float]:
+        """Calculate airmass for a target at the given RA/Dec."""
+        ...
+
+    def airmass_batch(
+        self,
+        ras: Sequence[float] | npt.NDArray[np.float64],
+        decs: Sequence[float] | npt.NDArray[np.float64],
+        time_indices: list[int] | None = None,
+    ) -> npt.NDArray[np.float64]:
+        """Calculate airmass for many RA/Dec targets (deg) in one call.
+
+        Returns an array of shape (n_targets, n_times) from the vectorized
+        Kasten pipeline used by the airmass constraint (no refraction or
+        aberration); infinity below the horizon.
+        """
+        ...
+
+class GroundEphemeris(Ephemeris):
+    """Ephemeris for a fixed ground location"""
+
+    def __init__(
+        self,
+        latitude: float | None = None,
+        longitude: float | None = None,
+        height: float | None = None,
+        begin: datetime | None = None,
+        end: datetime | None = None,
+        step_size: int = 60,
+        *,
+        polar_motion: bool = False,
+        time_scale: str = "UTC",
+        horizon: list[tuple[float, float]] | None = None,
+        site: str | None = None,
+        location: Any | None = None,
+        geocentric: tuple[float, float, float] | None = None,
+    ) -> None:
+        """
+        Initialize ground ephemeris for a fixed location.
+
+        The location is given as ``latitude``/``longitude``/``height``, as a
+        ``site`` from the site registry (see :func:`list_sites`), as an astropy
+        ``EarthLocation`` or as ``geocentric`` coordinates. Exactly one form may
+        be used; ``begin`` and ``end`` are always required.
+
+        Args:
+            latitude: Geodetic latitude in degrees (-90 to 90)
+            longitude: Geodetic longitude in degrees (-180 to 180)
+            height: Altitude in meters above WGS84 ellipsoid
+            begin: Start time (naive datetime treated as UTC)
+            end: End time (naive datetime treated as UTC)
+            step_size: Time step in seconds (default: 60)
+            polar_motion: Whether to apply polar motion correction (default: False)
+            time_scale: "UTC" steps clock time and skips leap seconds; "TAI"
+                or "TT" step SI seconds through them (default: "UTC")
+            horizon: Local horizon profile as (azimuth_deg, min_elevation_deg)
+                pairs, interpolated linearly in azimuth and wrapping through
+                north. Alt/az constraints treat directions below it as blocked
+                (default: flat 0° horizon)
+            site: Observatory or ground-station name, alias or IAU code,
+                e.g. "Paranal", "568" or "DSS-43", used instead of
+                latitude/longitude/height
+            location: Scalar ``astropy.coordinates.EarthLocation``
+            geocentric: Geocentric ITRS ``(x, y, z)`` in meters
+
+            ``location`` and ``geocentric`` are converted to geodetic
+            coordinates on the WGS84 ellipsoid, so ``input_latitude``,
+            ``input_longitude`` and ``input_height`` are always WGS84 values.
+
+        Example:
+            >>> vlt = GroundEphemeris(site="Paranal", begin=begin, end=end)
+            >>> loc = EarthLocation.of_site("Keck Observatory")
+            >>> keck = GroundEphemeris(location=loc, begin=begin, end=end)
+        """
+        ...
+
+    @property
+    def earth_location(self) -> Any:
+        """Site location as an ``astropy.coordinates.EarthLocation``"""
+        ...
+
+    @property
+    def site(self) -> str | None:
+        """Registry site name, or None if constructed from coordinates"""
+        ...
+
+    @property
+    def input_latitude(self) -> float:
+        """Input geodetic latitude in degrees"""
+        ...
+
+    @property
+    def input_longitude(self) -> float:
+        """Input geodetic longitude in degrees"""
+        ...
+
+    @property
+    def input_height(self) -> float:
+        """Input altitude in meters above WGS84 ellipsoid"""
+        ...
+
+    @property
+    def horizon(self) -> list[tuple[float, float]] | None:
+        """Horizon profile as (azimuth_deg, min_elevation_deg) pairs sorted by azimuth, or None"""
+        ...
+
+    def horizon_elevation(self, azimuth_deg: float) -> float:
+        """Minimum visible elevation in degrees towards an azimuth (0.0 without a profile)"""
+        ...
+
+    def is_above_horizon(
+        self, ra_deg: float, dec_deg: float, time_indices: list[int] | None = None
+    ) -> list[bool]:
+        """Whether a target is above the local horizon profile at each time"""
+        ...
+
+    @property
+    def solar_obscuration(self) -> npt.NDArray[np.float64]:
+        """Fraction of the solar disk area hidden by the Moon at each time (0 to 1)"""
+        ...
+
+    def solar_eclipses(
+        self, min_obscuration: float = 0.0, *, include_below_horizon: bool = False
+    ) -> list[SolarEclipse]:
+        """Solar eclipses seen from the site over the ephemeris span
+
+        Args:
+            min_obscuration: Drop eclipses whose largest obscuration stays below this
+            include_below_horizon: Also count samples with the Sun below the horizon
+
+        Raises:
+            ValueError: If min_obscuration is outside [0, 1]
+        """
+        ...
+
+    @property
+    def begin(self) -> datetime:
+        """Start time of ephemeris"""
+        ...
+
+    @property
+    def end(self) -> datetime:
+        """End time of ephemeris"""
+        ...
+
+    @property
+    def step_size(self) -> int:
+        """Time step in seconds"""
         ...
 
     @property
@@ -2944,11 +5615,30 @@ class GroundEphemeris(Ephemeris):
         """Whether polar motion correction is applied"""
         ...
 
+    @property
+    def time_scale(self) -> str:
+        """Time scale in which the timestamp grid is uniform ("UTC", "TAI" or "TT")"""
+        ...
+
     @property
     def gcrs_pv(self) -> PositionVelocityData:
         """Position and velocity data in GCRS frame"""
         ...
 
+    @property
+    def teme_pv(self) -> PositionVelocityData:
+        """Position and velocity data in TEME frame (converted from GCRS)"""
+        ...
+
+    @property
+    def pef_pv(self) -> PositionVelocityData:
+        """Position and velocity data in the pseudo-Earth-fixed frame
+
+        TEME rotated by Greenwich mean sidereal time: ITRS without polar
+        motion, as used by legacy NORAD Earth-fixed products.
+        """
+        ...
+
     @property
     def itrs_pv(self) -> PositionVelocityData:
         """Position and velocity data in ITRS (Earth-fixed) frame"""
@@ -2969,8 +5659,27 @@ class GroundEphemeris(Ephemeris):
         ra_deg: float,
         dec_deg: float,
         time_indices: list[int] | None = None,
+        *,
+        polar_motion: bool | None = None,
+    ) -> npt.NDArray[np.float64]:
+        """Topocentric altitude/azimuth for given RA/Dec (deg) at selected times.
+
+        ``polar_motion`` overrides the ephemeris's own setting for this call.
+        """
+        ...
+
+    def radec_to_altaz_batch(
+        self,
+        ra_deg: Sequence[float] | npt.NDArray[np.float64],
+        dec_deg: Sequence[float] | npt.NDArray[np.float64],
+        time_indices: list[int] | None = None,
+        *,
+        polar_motion: bool | None = None,
     ) -> npt.NDArray[np.float64]:
-        """Topocentric altitude/azimuth for given RA/Dec (deg) at selected times."""
+        """Altitude/azimuth for many targets, shape (n_targets, n_times, 2).
+
+        ``polar_motion`` overrides the ephemeris's own setting for this call.
+        """
         ...
 
     def calculate_airmass(
@@ -2986,6 +5695,20 @@ class GroundEphemeris(Ephemeris):
         """
         ...
 
+    def airmass_batch(
+        self,
+        ras: Sequence[float] | npt.NDArray[np.float64],
+        decs: Sequence[float] | npt.NDArray[np.float64],
+        time_indices: list[int] | None = None,
+    ) -> npt.NDArray[np.float64]:
+        """Calculate airmass for many RA/Dec targets (deg) in one call.
+
+        Returns an array of shape (n_targets, n_times) from the vectorized
+        Kasten pipeline used by the airmass constraint (no refraction or
+        aberration); infinity below the horizon.
+        """
+        ...
+
     @property
     def earth(self) -> Any:  # Returns astropy.coordinates.SkyCoord
         """SkyCoord object for Earth (same as ground location)"""
@@ -3076,6 +5799,21 @@ class GroundEphemeris(Ephemeris):
         """Geodetic height above the WGS84 ellipsoid as a raw NumPy array in kilometers (one per timestamp)"""
         ...
 
+    @property
+    def geomagnetic_latitude_deg(self) -> npt.NDArray[np.float64]:
+        """Centred-dipole (IGRF) geomagnetic latitude in degrees (one per timestamp)"""
+        ...
+
+    @property
+    def geomagnetic_longitude_deg(self) -> npt.NDArray[np.float64]:
+        """Centred-dipole (IGRF) geomagnetic longitude in degrees (one per timestamp)"""
+        ...
+
+    @property
+    def cutoff_rigidity_gv(self) -> npt.NDArray[np.float64]:
+        """Störmer vertical geomagnetic cutoff rigidity in GV (one per timestamp)"""
+        ...
+
     @property
     def sun_radius(self) -> Any:  # Returns astropy.units.Quantity
         """
@@ -3344,200 +6082,7264 @@ class GroundEphemeris(Ephemeris):
         """
         ...
 
-    def moon_illumination(self, time_indices: list[int] | None = None) -> list[float]:
+    def fingerprint(self) -> str:
         """
-        Calculate Moon illumination fraction for all ephemeris times.
+        Stable hex digest identifying this ephemeris.
+
+        Hashes the source (elements, file path or site), the options, the time
+        grid and the computed GCRS states, so ephemerides built the same way
+        share a fingerprint across sessions. Ephemerides with equal
+        fingerprints compare equal, and the fingerprint can key caches of
+        constraint results or schedules.
+
+        Returns:
+            32-character hexadecimal digest
+        """
+        ...
+
+    def __eq__(self, other: object) -> bool: ...
+    def __hash__(self) -> int: ...
+    @property
+    def metadata(self) -> dict[str, Any]:
+        """
+        Source, time span, computed frames and Earth orientation state.
+
+        Keys start with ``type`` and the source fields of this class (e.g.
+        ``norad_id`` and ``tle_epoch``, or a file path), then ``polar_motion``,
+        ``begin``, ``end``, ``step_size``, ``n_times``, ``frames``,
+        ``eop_available``, ``polar_motion_applied``, ``fast_itrs``,
+        ``ut1_available`` and ``fingerprint``. Values are
+        JSON-serializable, with times as ISO 8601 strings, for provenance logs.
+        """
+        ...
+
+    @property
+    def provenance(self) -> Provenance:
+        """
+        Reproducibility record: library version, source and fingerprint,
+        element set, input and kernel file digests, Earth orientation and
+        leap-second data.
+        """
+        ...
+
+    def summary(self) -> str:
+        """Human-readable, multi-line description of ``metadata``."""
+        ...
+
+    def __repr__(self) -> str: ...
+    def __len__(self) -> int:
+        """Number of time steps"""
+        ...
+
+    def __getitem__(self, index: int) -> EphemerisSample:
+        """State at time step ``index``; negative indices count from the end"""
+        ...
+
+    def __iter__(self) -> Iterator[EphemerisSample]:
+        """Iterate over the time steps in order"""
+        ...
+
+    @property
+    def illumination_factor(self) -> npt.NDArray[np.float64]:
+        """Fraction of the solar disk visible from the observer at each time step
+
+        1.0 in sunlight, 0.0 in the Earth's umbra and fractional in the
+        penumbra, from the overlap of the apparent Sun and Earth disks.
+        """
+        ...
+
+    @property
+    def geodetic_rates(self) -> npt.NDArray[np.float64] | None:
+        """Geodetic rates from the ITRS velocity, shape (N, 3)
+
+        Columns are latitude rate (deg/s), longitude rate (deg/s) and height
+        rate (km/s). The longitude rate diverges at the poles.
+        """
+        ...
+
+    @property
+    def ground_speed(self) -> npt.NDArray[np.float64] | None:
+        """Speed of the sub-observer point over the WGS84 ellipsoid (km/s)"""
+        ...
+
+    @property
+    def sub_satellite_point(self) -> npt.NDArray[np.float64] | None:
+        """Geodetic (latitude, longitude) in degrees below the observer, shape (N, 2)"""
+        ...
+
+    @property
+    def sub_solar_point(self) -> npt.NDArray[np.float64]:
+        """(latitude, longitude) in degrees with the Sun at the zenith, shape (N, 2)"""
+        ...
+
+    @property
+    def nadir_solar_zenith_angle(self) -> npt.NDArray[np.float64] | None:
+        """Solar zenith angle at the sub-satellite point in degrees
+
+        The ground below the observer is daylit below 90.
+        """
+        ...
+
+    def moon_illumination(self, time_indices: list[int] | None = None) -> list[float]:
+        """
+        Calculate Moon illumination fraction for all ephemeris times.
 
         Returns the fraction of the Moon's illuminated surface as seen from the
         spacecraft observer (0.0 = new moon, 1.0 = full moon).
 
-        Args:
-            time_indices: Optional indices into ephemeris times (default: all times)
+        Args:
+            time_indices: Optional indices into ephemeris times (default: all times)
+
+        Returns:
+            List of Moon illumination fractions
+        """
+        ...
+
+    def get_body_pv(
+        self,
+        body: str,
+        spice_kernel: str | None = ...,
+        use_horizons: bool = ...,
+        source: str | None = ...,
+    ) -> PositionVelocityData:
+        """
+        Get position and velocity of a celestial body.
+
+        Args:
+            body: Name of the body (e.g., 'sun', 'moon', 'earth'), or a pseudo-body
+                ('EMB', 'SSB', 'SEL1', 'SEL2', 'EML1', 'EML2')
+            spice_kernel: Optional path to SPICE kernel
+            use_horizons: If True, fall back to JPL Horizons API when SPICE fails
+                (equivalent to ``source="auto"``)
+            source: Data source precedence: ``"spice"`` (loaded kernels only),
+                ``"horizons"`` (JPL Horizons only) or ``"auto"`` (SPICE, falling back
+                to Horizons with a ``UserWarning``). Defaults to ``"spice"`` unless
+                ``use_horizons`` is set.
+
+        Returns:
+            Position and velocity data for the requested body
+        """
+        ...
+
+    def get_bodies(
+        self,
+        bodies: list[str],
+        spice_kernel: str | None = ...,
+        use_horizons: bool = ...,
+        source: str | None = ...,
+    ) -> dict[str, PositionVelocityData]:
+        """
+        Get position and velocity of several celestial bodies in one pass.
+
+        Equivalent to calling ``get_body_pv`` for each body, but the SPICE
+        lookups and the observer positions are set up once for all bodies.
+
+        Args:
+            bodies: Body names or NAIF IDs, as accepted by ``get_body_pv``
+            spice_kernel: Optional path to SPICE kernel
+            use_horizons: If True, fall back to JPL Horizons API when SPICE fails
+                (equivalent to ``source="auto"``)
+            source: Data source precedence, as for ``get_body_pv``
+
+        Returns:
+            Dict mapping each requested body, as given, to its position and
+            velocity data
+        """
+        ...
+
+    def separation(self, a: Any, b: Any) -> npt.NDArray[np.float64]:
+        """
+        Angular separation between two targets at each time step.
+
+        Computed in Rust from observer-relative vectors, without astropy.
+
+        Args:
+            a, b: Each an ``(ra_deg, dec_deg)`` pair, a body name (``"sun"``,
+                ``"moon"`` and ``"earth"`` use the cached positions; other names
+                resolve as in ``get_body_pv``), observer-relative
+                ``PositionVelocityData`` (e.g. from ``get_body_pv``), or a
+                SkyCoord with a single position or one per time step (e.g.
+                ``ephem.sun``)
+
+        Returns:
+            Separation angles in degrees, one per time step
+
+        Raises:
+            TypeError: If an operand is not one of the accepted kinds
+            ValueError: If an operand has a different number of time steps
+        """
+        ...
+
+    def sun_angle(
+        self, ra_deg: float, dec_deg: float, time_indices: list[int] | None = ...
+    ) -> npt.NDArray[np.float64]:
+        """
+        Angle between a target and the Sun at each time step.
+
+        Args:
+            ra_deg: Target right ascension in degrees
+            dec_deg: Target declination in degrees
+            time_indices: Optional indices into the ephemeris times (default: all)
+
+        Returns:
+            Angles in degrees, one per selected time step
+        """
+        ...
+
+    def moon_angle(
+        self, ra_deg: float, dec_deg: float, time_indices: list[int] | None = ...
+    ) -> npt.NDArray[np.float64]:
+        """
+        Angle between a target and the Moon at each time step.
+
+        Args:
+            ra_deg: Target right ascension in degrees
+            dec_deg: Target declination in degrees
+            time_indices: Optional indices into the ephemeris times (default: all)
+
+        Returns:
+            Angles in degrees, one per selected time step
+        """
+        ...
+
+    def earth_limb_angle(
+        self, ra_deg: float, dec_deg: float, time_indices: list[int] | None = ...
+    ) -> npt.NDArray[np.float64]:
+        """
+        Angle of a target above the Earth's limb at each time step.
+
+        The angle from the Earth's center minus the Earth's angular radius, so
+        negative while the target is behind the Earth. No refraction or horizon
+        mask is applied.
+
+        Args:
+            ra_deg: Target right ascension in degrees
+            dec_deg: Target declination in degrees
+            time_indices: Optional indices into the ephemeris times (default: all)
+
+        Returns:
+            Angles in degrees, one per selected time step
+        """
+        ...
+
+    def geometry(
+        self,
+        ra_deg: float,
+        dec_deg: float,
+        time: datetime,
+        *,
+        saa_polygon: list[tuple[float, float]] | None = None,
+    ) -> ObservationGeometry:
+        """
+        Observation geometry of a target at the ephemeris step nearest ``time``.
+
+        Args:
+            ra_deg: Target right ascension in degrees
+            dec_deg: Target declination in degrees
+            time: Time of interest; the nearest ephemeris step is used
+            saa_polygon: Optional SAA region as (longitude, latitude) vertices in
+                degrees; sets ``in_saa``
+
+        Returns:
+            Sun, Moon and Earth-limb angles, plus altitude and airmass for ground
+            sites or eclipse, orbital phase and beta angle for orbiting observers
+        """
+        ...
+
+    def get_body(
+        self,
+        body: str,
+        spice_kernel: str | None = ...,
+        use_horizons: bool = ...,
+        source: str | None = ...,
+    ) -> Any:  # Returns astropy.coordinates.SkyCoord
+        """
+        Get SkyCoord for a celestial body.
+
+        Args:
+            body: Name of the body (e.g., 'sun', 'moon', 'earth'), or a pseudo-body
+                ('EMB', 'SSB', 'SEL1', 'SEL2', 'EML1', 'EML2')
+            spice_kernel: Optional path to SPICE kernel
+            use_horizons: If True, fall back to JPL Horizons API when SPICE fails
+                (equivalent to ``source="auto"``)
+            source: Data source precedence: ``"spice"`` (loaded kernels only),
+                ``"horizons"`` (JPL Horizons only) or ``"auto"`` (SPICE, falling back
+                to Horizons with a ``UserWarning``). Defaults to ``"spice"`` unless
+                ``use_horizons`` is set.
+
+        Returns:
+            astropy.coordinates.SkyCoord object
+        """
+        ...
+
+class TrajectoryGroundEphemeris(Ephemeris):
+    """Ephemeris for a ground observer moving along a track (ship, aircraft, balloon)"""
+
+    def __init__(
+        self,
+        times: Sequence[datetime] | npt.NDArray[np.datetime64],
+        latitude: Sequence[float],
+        longitude: Sequence[float],
+        height: Sequence[float],
+        begin: datetime | None = None,
+        end: datetime | None = None,
+        step_size: int | None = None,
+        *,
+        polar_motion: bool = False,
+        time_scale: str = "UTC",
+        atmosphere: Literal["sea_level", "standard"] = "sea_level",
+    ) -> None:
+        """
+        Initialize an ephemeris for an observer following a track.
+
+        The track is resampled onto a regular grid when ``begin``, ``end`` or
+        ``step_size`` is given, interpolating latitude, longitude and height
+        linearly between track points (the short way across the
+        antimeridian); otherwise the track times are used as-is. The observer
+        behaves like a ``GroundEphemeris`` site at each time step.
+
+        Args:
+            times: Track times, strictly increasing (at least 2)
+            latitude: Geodetic latitude in degrees at each time (-90 to 90)
+            longitude: Geodetic longitude in degrees at each time (-180 to 180)
+            height: Altitude in meters above the WGS84 ellipsoid at each time
+            begin: Start of the resampled grid (default: first track time)
+            end: End of the resampled grid (default: last track time)
+            step_size: Grid step in seconds (default: track times as-is, or 60
+                when only ``begin`` or ``end`` is given)
+            polar_motion: Whether to apply polar motion correction (default: False)
+            time_scale: Scale of a resampled grid: "UTC" steps clock time and
+                skips leap seconds; "TAI" or "TT" step SI seconds through them
+                (default: "UTC")
+            atmosphere: Airmass model. ``"sea_level"`` uses the Kasten & Young
+                formula, as ``GroundEphemeris`` does; ``"standard"`` integrates
+                through the US Standard Atmosphere above the platform's height,
+                for aircraft and balloons (default: ``"sea_level"``)
+
+        Raises:
+            ValueError: If the arrays differ in length, coordinates are out of
+                range, times are not increasing, the grid exceeds the track or
+                a height is above 86 km with the standard atmosphere
+
+        Example:
+            >>> flight = TrajectoryGroundEphemeris(
+            ...     waypoint_times, lats, lons, [12500.0] * len(lats), step_size=60
+            ... )
+        """
+        ...
+
+    @staticmethod
+    def balloon(
+        latitude: float,
+        longitude: float,
+        begin: datetime,
+        end: datetime,
+        step_size: int = 60,
+        *,
+        altitude_km: float = 35.0,
+        latitude_rate: float = 0.0,
+        longitude_rate: float = 0.0,
+        polar_motion: bool = False,
+        time_scale: str = "UTC",
+    ) -> TrajectoryGroundEphemeris:
+        """
+        Create an ephemeris for a stratospheric balloon.
+
+        The balloon floats at a constant altitude and drifts at constant rates
+        in latitude and longitude from its position at ``begin``. Airmass uses
+        the ``"standard"`` atmosphere above the float altitude. For a measured
+        or forecast flight path, pass the track to the constructor with
+        ``atmosphere="standard"`` instead.
+
+        Args:
+            latitude: Geodetic latitude in degrees at ``begin`` (-90 to 90)
+            longitude: Geodetic longitude in degrees at ``begin`` (-180 to 180)
+            begin: Start time
+            end: End time
+            step_size: Step in seconds (default: 60)
+            altitude_km: Float altitude in km above the WGS84 ellipsoid (default: 35)
+            latitude_rate: Drift in degrees of latitude per hour, north positive
+            longitude_rate: Drift in degrees of longitude per hour, east positive
+            polar_motion: Whether to apply polar motion correction (default: False)
+            time_scale: "UTC" steps clock time and skips leap seconds; "TAI"
+                or "TT" step SI seconds through them (default: "UTC")
+
+        Raises:
+            ValueError: If the position or altitude is out of range, or the
+                drift takes the balloon past a pole before ``end``
+
+        Example:
+            >>> flight = TrajectoryGroundEphemeris.balloon(
+            ...     -77.85, 166.67, begin, end, longitude_rate=-0.5
+            ... )
+        """
+        ...
+
+    @property
+    def source_timestamp(self) -> list[datetime]:
+        """Track times before resampling"""
+        ...
+
+    @property
+    def source_latitude(self) -> list[float]:
+        """Track latitudes in degrees before resampling"""
+        ...
+
+    @property
+    def source_longitude(self) -> list[float]:
+        """Track longitudes in degrees before resampling"""
+        ...
+
+    @property
+    def source_height(self) -> list[float]:
+        """Track heights in meters before resampling"""
+        ...
+
+    @property
+    def begin(self) -> datetime:
+        """Start time of ephemeris"""
+        ...
+
+    @property
+    def end(self) -> datetime:
+        """End time of ephemeris"""
+        ...
+
+    @property
+    def step_size(self) -> int:
+        """Time step in seconds"""
+        ...
+
+    @property
+    def polar_motion(self) -> bool:
+        """Whether polar motion correction is applied"""
+        ...
+
+    @property
+    def time_scale(self) -> str:
+        """Time scale in which the timestamp grid is uniform ("UTC", "TAI" or "TT")"""
+        ...
+
+    @property
+    def atmosphere(self) -> Literal["sea_level", "standard"]:
+        """Airmass model: ``"sea_level"`` or ``"standard"``"""
+        ...
+
+    @property
+    def gcrs_pv(self) -> PositionVelocityData:
+        """Position and velocity data in GCRS frame"""
+        ...
+
+    @property
+    def teme_pv(self) -> PositionVelocityData:
+        """Position and velocity data in TEME frame (converted from GCRS)"""
+        ...
+
+    @property
+    def pef_pv(self) -> PositionVelocityData:
+        """Position and velocity data in the pseudo-Earth-fixed frame
+
+        TEME rotated by Greenwich mean sidereal time: ITRS without polar
+        motion, as used by legacy NORAD Earth-fixed products.
+        """
+        ...
+
+    @property
+    def itrs_pv(self) -> PositionVelocityData:
+        """Position and velocity data in ITRS (Earth-fixed) frame"""
+        ...
+
+    @property
+    def itrs(self) -> Any:  # Returns astropy.coordinates.SkyCoord
+        """SkyCoord object in ITRS frame for ground location"""
+        ...
+
+    @property
+    def gcrs(self) -> Any:  # Returns astropy.coordinates.SkyCoord
+        """SkyCoord object in GCRS frame for ground location"""
+        ...
+
+    def radec_to_altaz(
+        self,
+        ra_deg: float,
+        dec_deg: float,
+        time_indices: list[int] | None = None,
+        *,
+        polar_motion: bool | None = None,
+    ) -> npt.NDArray[np.float64]:
+        """Topocentric altitude/azimuth for given RA/Dec (deg) at selected times.
+
+        ``polar_motion`` overrides the ephemeris's own setting for this call.
+        """
+        ...
+
+    def radec_to_altaz_batch(
+        self,
+        ra_deg: Sequence[float] | npt.NDArray[np.float64],
+        dec_deg: Sequence[float] | npt.NDArray[np.float64],
+        time_indices: list[int] | None = None,
+        *,
+        polar_motion: bool | None = None,
+    ) -> npt.NDArray[np.float64]:
+        """Altitude/azimuth for many targets, shape (n_targets, n_times, 2).
+
+        ``polar_motion`` overrides the ephemeris's own setting for this call.
+        """
+        ...
+
+    def calculate_airmass(
+        self,
+        ra_deg: float,
+        dec_deg: float,
+        time_indices: list[int] | None = None,
+    ) -> list[float]:
+        """Calculate airmass for given RA/Dec (deg) at selected times.
+
+        Returns airmass values (1.0 at zenith, ~2.0 at 30° altitude, infinity below horizon).
+        With the ``"standard"`` atmosphere the column is integrated above the
+        platform's height and stays finite below the horizon down to the
+        Earth's limb.
+        """
+        ...
+
+    def airmass_batch(
+        self,
+        ras: Sequence[float] | npt.NDArray[np.float64],
+        decs: Sequence[float] | npt.NDArray[np.float64],
+        time_indices: list[int] | None = None,
+    ) -> npt.NDArray[np.float64]:
+        """Calculate airmass for many RA/Dec targets (deg) in one call.
+
+        Returns an array of shape (n_targets, n_times) from the vectorized
+        Kasten pipeline used by the airmass constraint (no refraction or
+        aberration); infinity below the horizon.
+        """
+        ...
+
+    def calculate_refraction(
+        self,
+        ra_deg: float,
+        dec_deg: float,
+        time_indices: list[int] | None = None,
+    ) -> list[float]:
+        """Atmospheric refraction in degrees for given RA/Dec at selected times.
+
+        The amount the target appears raised above its true altitude, from the
+        standard atmosphere above the platform (~1' at 45° at sea level, ~1% of
+        that at 35 km). NaN where the line of sight meets the ground.
+        """
+        ...
+
+    @property
+    def earth(self) -> Any:  # Returns astropy.coordinates.SkyCoord
+        """SkyCoord object for Earth (same as ground location)"""
+        ...
+
+    @property
+    def sun(self) -> Any:  # Returns astropy.coordinates.SkyCoord
+        """SkyCoord object for Sun position relative to ground location"""
+        ...
+
+    @property
+    def moon(self) -> Any:  # Returns astropy.coordinates.SkyCoord
+        """SkyCoord object for Moon position relative to ground location"""
+        ...
+
+    @property
+    def sun_pv(self) -> PositionVelocityData:
+        """Position and velocity data for Sun"""
+        ...
+
+    @property
+    def moon_pv(self) -> PositionVelocityData:
+        """Position and velocity data for Moon"""
+        ...
+
+    @property
+    def timestamp(self) -> npt.NDArray[np.datetime64]:
+        """
+        Array of timestamps for the ephemeris.
+
+        Returns a NumPy array of datetime objects (not a list) for efficient indexing.
+        This property is cached for performance - repeated access is ~90x faster.
+        """
+        ...
+
+    @property
+    def obsgeoloc(self) -> npt.NDArray[np.float64]:  # Returns astropy quantity array
+        """Observatory geocentric location for astropy"""
+        ...
+
+    @property
+    def obsgeovel(self) -> npt.NDArray[np.float64]:  # Returns astropy quantity array
+        """Observatory geocentric velocity for astropy"""
+        ...
+
+    @property
+    def latitude(self) -> Any:  # Returns astropy.units.Quantity
+        """Geodetic latitude as an astropy Quantity array (degrees), one per timestamp"""
+        ...
+
+    @property
+    def latitude_deg(self) -> npt.NDArray[np.float64]:
+        """Geodetic latitude in degrees as a raw NumPy array (one per timestamp)"""
+        ...
+
+    @property
+    def latitude_rad(self) -> npt.NDArray[np.float64]:
+        """Geodetic latitude in radians as a raw NumPy array (one per timestamp)"""
+        ...
+
+    @property
+    def longitude(self) -> Any:  # Returns astropy.units.Quantity
+        """Geodetic longitude as an astropy Quantity array (degrees), one per timestamp"""
+        ...
+
+    @property
+    def longitude_deg(self) -> npt.NDArray[np.float64]:
+        """Geodetic longitude in degrees as a raw NumPy array (one per timestamp)"""
+        ...
+
+    @property
+    def longitude_rad(self) -> npt.NDArray[np.float64]:
+        """Geodetic longitude in radians as a raw NumPy array (one per timestamp)"""
+        ...
+
+    @property
+    def height(self) -> Any:  # Returns astropy.units.Quantity
+        """Geodetic height above the WGS84 ellipsoid as an astropy Quantity array (meters), one per timestamp"""
+        ...
+
+    @property
+    def height_m(self) -> npt.NDArray[np.float64]:
+        """Geodetic height above the WGS84 ellipsoid as a raw NumPy array in meters (one per timestamp)"""
+        ...
+
+    @property
+    def height_km(self) -> npt.NDArray[np.float64]:
+        """Geodetic height above the WGS84 ellipsoid as a raw NumPy array in kilometers (one per timestamp)"""
+        ...
+
+    @property
+    def geomagnetic_latitude_deg(self) -> npt.NDArray[np.float64]:
+        """Centred-dipole (IGRF) geomagnetic latitude in degrees (one per timestamp)"""
+        ...
+
+    @property
+    def geomagnetic_longitude_deg(self) -> npt.NDArray[np.float64]:
+        """Centred-dipole (IGRF) geomagnetic longitude in degrees (one per timestamp)"""
+        ...
+
+    @property
+    def cutoff_rigidity_gv(self) -> npt.NDArray[np.float64]:
+        """Störmer vertical geomagnetic cutoff rigidity in GV (one per timestamp)"""
+        ...
+
+    @property
+    def sun_radius(self) -> Any:  # Returns astropy.units.Quantity
+        """
+        Angular radius of the Sun with astropy units (degrees).
+
+        Returns an astropy Quantity with units of degrees.
+        This property is cached for performance.
+
+        Returns:
+            astropy Quantity array with units of degrees
+        """
+        ...
+
+    @property
+    def sun_radius_deg(self) -> npt.NDArray[np.float64]:
+        """
+        Angular radius of the Sun as seen from the observer (in degrees).
+
+        Returns a NumPy array of angular radii for each timestamp.
+        Angular radius = arcsin(physical_radius / distance)
+        This property is cached for performance.
+
+        Returns:
+            NumPy array of angular radii in degrees
+        """
+        ...
+
+    @property
+    def moon_radius(self) -> Any:  # Returns astropy.units.Quantity
+        """
+        Angular radius of the Moon with astropy units (degrees).
+
+        Returns an astropy Quantity with units of degrees.
+        This property is cached for performance.
+
+        Returns:
+            astropy Quantity array with units of degrees
+        """
+        ...
+
+    @property
+    def moon_radius_deg(self) -> npt.NDArray[np.float64]:
+        """
+        Angular radius of the Moon as seen from the observer (in degrees).
+
+        Returns a NumPy array of angular radii for each timestamp.
+        Angular radius = arcsin(physical_radius / distance)
+        This property is cached for performance.
+
+        Returns:
+            NumPy array of angular radii in degrees
+        """
+        ...
+
+    @property
+    def earth_radius(self) -> Any:  # Returns astropy.units.Quantity
+        """
+        Angular radius of the Earth with astropy units (degrees).
+
+        Returns an astropy Quantity with units of degrees.
+        This property is cached for performance.
+
+        Returns:
+            astropy Quantity array with units of degrees
+        """
+        ...
+
+    @property
+    def earth_radius_deg(self) -> npt.NDArray[np.float64]:
+        """
+        Angular radius of the Earth as seen from the observer (in degrees).
+
+        Returns a NumPy array of angular radii for each timestamp.
+        Angular radius = arcsin(physical_radius / distance)
+        This property is cached for performance.
+
+        Returns:
+            NumPy array of angular radii in degrees
+        """
+        ...
+
+    @property
+    def sun_radius_rad(self) -> npt.NDArray[np.float64]:
+        """
+        Angular radius of the Sun as seen from the observer (in radians).
+
+        Returns a NumPy array of angular radii for each timestamp.
+        Angular radius = arcsin(physical_radius / distance)
+        This property is cached for performance.
+
+        Returns:
+            NumPy array of angular radii in radians
+        """
+        ...
+
+    @property
+    def moon_radius_rad(self) -> npt.NDArray[np.float64]:
+        """
+        Angular radius of the Moon as seen from the observer (in radians).
+
+        Returns a NumPy array of angular radii for each timestamp.
+        Angular radius = arcsin(physical_radius / distance)
+        This property is cached for performance.
+
+        Returns:
+            NumPy array of angular radii in radians
+        """
+        ...
+
+    @property
+    def earth_radius_rad(self) -> npt.NDArray[np.float64]:
+        """
+        Angular radius of the Earth as seen from the observer (in radians).
+
+        Returns a NumPy array of angular radii for each timestamp.
+        Angular radius = arcsin(physical_radius / distance)
+        This property is cached for performance.
+
+        Returns:
+            NumPy array of angular radii in radians
+        """
+        ...
+
+    @property
+    def sun_ra_dec_deg(self) -> npt.NDArray[np.float64]:
+        """
+        Right Ascension and Declination of the Sun in degrees.
+
+        Returns an Nx2 NumPy array where column 0 is RA and column 1 is Dec.
+        This property is cached for performance.
+        """
+        ...
+
+    @property
+    def moon_ra_dec_deg(self) -> npt.NDArray[np.float64]:
+        """
+        Right Ascension and Declination of the Moon in degrees.
+
+        Returns an Nx2 NumPy array where column 0 is RA and column 1 is Dec.
+        This property is cached for performance.
+        """
+        ...
+
+    @property
+    def earth_ra_dec_deg(self) -> npt.NDArray[np.float64]:
+        """
+        Right Ascension and Declination of the Earth in degrees.
+
+        Returns an Nx2 NumPy array where column 0 is RA and column 1 is Dec.
+        This property is cached for performance.
+        """
+        ...
+
+    @property
+    def sun_ra_dec_rad(self) -> npt.NDArray[np.float64]:
+        """
+        Right Ascension and Declination of the Sun in radians.
+
+        Returns an Nx2 NumPy array where column 0 is RA and column 1 is Dec.
+        This property is cached for performance.
+        """
+        ...
+
+    @property
+    def moon_ra_dec_rad(self) -> npt.NDArray[np.float64]:
+        """
+        Right Ascension and Declination of the Moon in radians.
+
+        Returns an Nx2 NumPy array where column 0 is RA and column 1 is Dec.
+        This property is cached for performance.
+        """
+        ...
+
+    @property
+    def earth_ra_dec_rad(self) -> npt.NDArray[np.float64]:
+        """
+        Right Ascension and Declination of the Earth in radians.
+
+        Returns an Nx2 NumPy array where column 0 is RA and column 1 is Dec.
+        This property is cached for performance.
+        """
+        ...
+
+    @property
+    def sun_ra_deg(self) -> npt.NDArray[np.float64]:
+        """Right Ascension of the Sun in degrees. Extracts column 0 from sun_ra_dec_deg."""
+        ...
+
+    @property
+    def sun_dec_deg(self) -> npt.NDArray[np.float64]:
+        """Declination of the Sun in degrees. Extracts column 1 from sun_ra_dec_deg."""
+        ...
+
+    @property
+    def moon_ra_deg(self) -> npt.NDArray[np.float64]:
+        """Right Ascension of the Moon in degrees. Extracts column 0 from moon_ra_dec_deg."""
+        ...
+
+    @property
+    def moon_dec_deg(self) -> npt.NDArray[np.float64]:
+        """Declination of the Moon in degrees. Extracts column 1 from moon_ra_dec_deg."""
+        ...
+
+    @property
+    def earth_ra_deg(self) -> npt.NDArray[np.float64]:
+        """Right Ascension of the Earth in degrees. Extracts column 0 from earth_ra_dec_deg."""
+        ...
+
+    @property
+    def earth_dec_deg(self) -> npt.NDArray[np.float64]:
+        """Declination of the Earth in degrees. Extracts column 1 from earth_ra_dec_deg."""
+        ...
+
+    @property
+    def sun_ra_rad(self) -> npt.NDArray[np.float64]:
+        """Right Ascension of the Sun in radians. Extracts column 0 from sun_ra_dec_rad."""
+        ...
+
+    @property
+    def sun_dec_rad(self) -> npt.NDArray[np.float64]:
+        """Declination of the Sun in radians. Extracts column 1 from sun_ra_dec_rad."""
+        ...
+
+    @property
+    def moon_ra_rad(self) -> npt.NDArray[np.float64]:
+        """Right Ascension of the Moon in radians. Extracts column 0 from moon_ra_dec_rad."""
+        ...
+
+    @property
+    def moon_dec_rad(self) -> npt.NDArray[np.float64]:
+        """Declination of the Moon in radians. Extracts column 1 from moon_ra_dec_rad."""
+        ...
+
+    @property
+    def earth_ra_rad(self) -> npt.NDArray[np.float64]:
+        """Right Ascension of the Earth in radians. Extracts column 0 from earth_ra_dec_rad."""
+        ...
+
+    @property
+    def earth_dec_rad(self) -> npt.NDArray[np.float64]:
+        """Declination of the Earth in radians. Extracts column 1 from earth_ra_dec_rad."""
+        ...
+
+    def index(self, time: datetime) -> int:
+        """
+        Find the index of the closest timestamp to the given datetime.
+
+        Returns the index in the ephemeris timestamp array that is closest to the provided time.
+        This can be used to index into any of the ephemeris arrays (positions, velocities, etc.)
+
+        Args:
+            time: Python datetime object to find the closest match for
+
+        Returns:
+            Index of the closest timestamp
+
+        Raises:
+            ValueError: If no timestamps are available in the ephemeris
+
+        Example:
+            >>> from datetime import datetime
+            >>> eph = TrajectoryGroundEphemeris(...)
+            >>> target_time = datetime(2024, 1, 15, 12, 0, 0)
+            >>> idx = eph.index(target_time)
+            >>> sun_position = eph.sun_pv.position[idx]
+        """
+        ...
+
+    def fingerprint(self) -> str:
+        """
+        Stable hex digest identifying this ephemeris.
+
+        Hashes the source (elements, file path or site), the options, the time
+        grid and the computed GCRS states, so ephemerides built the same way
+        share a fingerprint across sessions. Ephemerides with equal
+        fingerprints compare equal, and the fingerprint can key caches of
+        constraint results or schedules.
+
+        Returns:
+            32-character hexadecimal digest
+        """
+        ...
+
+    def __eq__(self, other: object) -> bool: ...
+    def __hash__(self) -> int: ...
+    @property
+    def metadata(self) -> dict[str, Any]:
+        """
+        Source, time span, computed frames and Earth orientation state.
+
+        Keys start with ``type`` and the source fields of this class (e.g.
+        ``norad_id`` and ``tle_epoch``, or a file path), then ``polar_motion``,
+        ``begin``, ``end``, ``step_size``, ``n_times``, ``frames``,
+        ``eop_available``, ``polar_motion_applied``, ``fast_itrs``,
+        ``ut1_available`` and ``fingerprint``. Values are
+        JSON-serializable, with times as ISO 8601 strings, for provenance logs.
+        """
+        ...
+
+    @property
+    def provenance(self) -> Provenance:
+        """
+        Reproducibility record: library version, source and fingerprint,
+        element set, input and kernel file digests, Earth orientation and
+        leap-second data.
+        """
+        ...
+
+    def summary(self) -> str:
+        """Human-readable, multi-line description of ``metadata``."""
+        ...
+
+    def __repr__(self) -> str: ...
+    def __len__(self) -> int:
+        """Number of time steps"""
+        ...
+
+    def __getitem__(self, index: int) -> EphemerisSample:
+        """State at time step ``index``; negative indices count from the end"""
+        ...
+
+    def __iter__(self) -> Iterator[EphemerisSample]:
+        """Iterate over the time steps in order"""
+        ...
+
+    @property
+    def illumination_factor(self) -> npt.NDArray[np.float64]:
+        """Fraction of the solar disk visible from the observer at each time step
+
+        1.0 in sunlight, 0.0 in the Earth's umbra and fractional in the
+        penumbra, from the overlap of the apparent Sun and Earth disks.
+        """
+        ...
+
+    @property
+    def geodetic_rates(self) -> npt.NDArray[np.float64] | None:
+        """Geodetic rates from the ITRS velocity, shape (N, 3)
+
+        Columns are latitude rate (deg/s), longitude rate (deg/s) and height
+        rate (km/s). The longitude rate diverges at the poles.
+        """
+        ...
+
+    @property
+    def ground_speed(self) -> npt.NDArray[np.float64] | None:
+        """Speed of the sub-observer point over the WGS84 ellipsoid (km/s)"""
+        ...
+
+    @property
+    def sub_satellite_point(self) -> npt.NDArray[np.float64] | None:
+        """Geodetic (latitude, longitude) in degrees below the observer, shape (N, 2)"""
+        ...
+
+    @property
+    def sub_solar_point(self) -> npt.NDArray[np.float64]:
+        """(latitude, longitude) in degrees with the Sun at the zenith, shape (N, 2)"""
+        ...
+
+    @property
+    def nadir_solar_zenith_angle(self) -> npt.NDArray[np.float64] | None:
+        """Solar zenith angle at the sub-satellite point in degrees
+
+        The ground below the observer is daylit below 90.
+        """
+        ...
+
+    def moon_illumination(self, time_indices: list[int] | None = None) -> list[float]:
+        """
+        Calculate Moon illumination fraction for all ephemeris times.
+
+        Returns the fraction of the Moon's illuminated surface as seen from the
+        spacecraft observer (0.0 = new moon, 1.0 = full moon).
+
+        Args:
+            time_indices: Optional indices into ephemeris times (default: all times)
+
+        Returns:
+            List of Moon illumination fractions
+        """
+        ...
+
+    def get_body_pv(
+        self,
+        body: str,
+        spice_kernel: str | None = ...,
+        use_horizons: bool = ...,
+        source: str | None = ...,
+    ) -> PositionVelocityData:
+        """
+        Get position and velocity of a celestial body.
+
+        Args:
+            body: Name of the body (e.g., 'sun', 'moon', 'earth'), or a pseudo-body
+                ('EMB', 'SSB', 'SEL1', 'SEL2', 'EML1', 'EML2')
+            spice_kernel: Optional path to SPICE kernel
+            use_horizons: If True, fall back to JPL Horizons API when SPICE fails
+                (equivalent to ``source="auto"``)
+            source: Data source precedence: ``"spice"`` (loaded kernels only),
+                ``"horizons"`` (JPL Horizons only) or ``"auto"`` (SPICE, falling back
+                to Horizons with a ``UserWarning``). Defaults to ``"spice"`` unless
+                ``use_horizons`` is set.
+
+        Returns:
+            Position and velocity data for the requested body
+        """
+        ...
+
+    def get_bodies(
+        self,
+        bodies: list[str],
+        spice_kernel: str | None = ...,
+        use_horizons: bool = ...,
+        source: str | None = ...,
+    ) -> dict[str, PositionVelocityData]:
+        """
+        Get position and velocity of several celestial bodies in one pass.
+
+        Equivalent to calling ``get_body_pv`` for each body, but the SPICE
+        lookups and the observer positions are set up once for all bodies.
+
+        Args:
+            bodies: Body names or NAIF IDs, as accepted by ``get_body_pv``
+            spice_kernel: Optional path to SPICE kernel
+            use_horizons: If True, fall back to JPL Horizons API when SPICE fails
+                (equivalent to ``source="auto"``)
+            source: Data source precedence, as for ``get_body_pv``
+
+        Returns:
+            Dict mapping each requested body, as given, to its position and
+            velocity data
+        """
+        ...
+
+    def separation(self, a: Any, b: Any) -> npt.NDArray[np.float64]:
+        """
+        Angular separation between two targets at each time step.
+
+        Computed in Rust from observer-relative vectors, without astropy.
+
+        Args:
+            a, b: Each an ``(ra_deg, dec_deg)`` pair, a body name (``"sun"``,
+                ``"moon"`` and ``"earth"`` use the cached positions; other names
+                resolve as in ``get_body_pv``), observer-relative
+                ``PositionVelocityData`` (e.g. from ``get_body_pv``), or a
+                SkyCoord with a single position or one per time step (e.g.
+                ``ephem.sun``)
+
+        Returns:
+            Separation angles in degrees, one per time step
+
+        Raises:
+            TypeError: If an operand is not one of the accepted kinds
+            ValueError: If an operand has a different number of time steps
+        """
+        ...
+
+    def sun_angle(
+        self, ra_deg: float, dec_deg: float, time_indices: list[int] | None = ...
+    ) -> npt.NDArray[np.float64]:
+        """
+        Angle between a target and the Sun at each time step.
+
+        Args:
+            ra_deg: Target right ascension in degrees
+            dec_deg: Target declination in degrees
+            time_indices: Optional indices into the ephemeris times (default: all)
+
+        Returns:
+            Angles in degrees, one per selected time step
+        """
+        ...
+
+    def moon_angle(
+        self, ra_deg: float, dec_deg: float, time_indices: list[int] | None = ...
+    ) -> npt.NDArray[np.float64]:
+        """
+        Angle between a target and the Moon at each time step.
+
+        Args:
+            ra_deg: Target right ascension in degrees
+            dec_deg: Target declination in degrees
+            time_indices: Optional indices into the ephemeris times (default: all)
+
+        Returns:
+            Angles in degrees, one per selected time step
+        """
+        ...
+
+    def earth_limb_angle(
+        self, ra_deg: float, dec_deg: float, time_indices: list[int] | None = ...
+    ) -> npt.NDArray[np.float64]:
+        """
+        Angle of a target above the Earth's limb at each time step.
+
+        The angle from the Earth's center minus the Earth's angular radius, so
+        negative while the target is behind the Earth. No refraction or horizon
+        mask is applied.
+
+        Args:
+            ra_deg: Target right ascension in degrees
+            dec_deg: Target declination in degrees
+            time_indices: Optional indices into the ephemeris times (default: all)
+
+        Returns:
+            Angles in degrees, one per selected time step
+        """
+        ...
+
+    def geometry(
+        self,
+        ra_deg: float,
+        dec_deg: float,
+        time: datetime,
+        *,
+        saa_polygon: list[tuple[float, float]] | None = None,
+    ) -> ObservationGeometry:
+        """
+        Observation geometry of a target at the ephemeris step nearest ``time``.
+
+        Args:
+            ra_deg: Target right ascension in degrees
+            dec_deg: Target declination in degrees
+            time: Time of interest; the nearest ephemeris step is used
+            saa_polygon: Optional SAA region as (longitude, latitude) vertices in
+                degrees; sets ``in_saa``
+
+        Returns:
+            Sun, Moon and Earth-limb angles, plus altitude and airmass for ground
+            sites or eclipse, orbital phase and beta angle for orbiting observers
+        """
+        ...
+
+    def get_body(
+        self,
+        body: str,
+        spice_kernel: str | None = ...,
+        use_horizons: bool = ...,
+        source: str | None = ...,
+    ) -> Any:  # Returns astropy.coordinates.SkyCoord
+        """
+        Get SkyCoord for a celestial body.
+
+        Args:
+            body: Name of the body (e.g., 'sun', 'moon', 'earth'), or a pseudo-body
+                ('EMB', 'SSB', 'SEL1', 'SEL2', 'EML1', 'EML2')
+            spice_kernel: Optional path to SPICE kernel
+            use_horizons: If True, fall back to JPL Horizons API when SPICE fails
+                (equivalent to ``source="auto"``)
+            source: Data source precedence: ``"spice"`` (loaded kernels only),
+                ``"horizons"`` (JPL Horizons only) or ``"auto"`` (SPICE, falling back
+                to Horizons with a ``UserWarning``). Defaults to ``"spice"`` unless
+                ``use_horizons`` is set.
+
+        Returns:
+            astropy.coordinates.SkyCoord object
+        """
+        ...
+
+class HorizonsEphemeris(Ephemeris):
+    """
+    Ephemeris calculator whose observer trajectory is retrieved from JPL Horizons.
+
+    Useful for spacecraft that Horizons tracks but that have no TLE or local
+    SPICE kernel, e.g. JWST (``"-170"``). Horizons is queried for geocentric
+    ICRF state vectors at a step matched to the ephemeris grid, and the table is
+    Hermite-interpolated onto the requested timestamps. Responses are cached in
+    memory; see :func:`clear_horizons_cache`.
+    """
+
+    def __init__(
+        self,
+        target: str,
+        begin: datetime,
+        end: datetime,
+        step_size: int = 60,
+        *,
+        polar_motion: bool = False,
+        fast_itrs: bool = False,
+        time_scale: str = "UTC",
+    ) -> None:
+        """
+        Initialize an ephemeris from a JPL Horizons observer trajectory.
+
+        Args:
+            target: Horizons target as a NAIF ID (e.g. ``"-170"``), name or designation
+            begin: Start time (naive datetime treated as UTC)
+            end: End time (naive datetime treated as UTC)
+            step_size: Time step in seconds (default: 60)
+            polar_motion: Whether to apply polar motion correction (default: False)
+            fast_itrs: Rotate GCRS to ITRS without the UT1-UTC correction
+                (at most 0.0038 deg of longitude) or polar motion, about four
+                times faster with EOP data loaded, for large grids such as
+                SAA checks (default: False)
+            time_scale: "UTC" steps clock time and skips leap seconds; "TAI"
+                or "TT" step SI seconds through them (default: "UTC")
+
+        Raises:
+            ValueError: If Horizons cannot be reached, the target cannot be resolved,
+                       or Horizons has no data for the requested time range
+        """
+        ...
+
+    @property
+    def target(self) -> str:
+        """Horizons target used to build the trajectory"""
+        ...
+
+    @property
+    def begin(self) -> datetime:
+        """Start time of ephemeris"""
+        ...
+
+    @property
+    def end(self) -> datetime:
+        """End time of ephemeris"""
+        ...
+
+    @property
+    def step_size(self) -> int:
+        """Time step in seconds"""
+        ...
+
+    @property
+    def polar_motion(self) -> bool:
+        """Whether polar motion correction is applied"""
+        ...
+
+    @property
+    def fast_itrs(self) -> bool:
+        """Whether ITRS skips the UT1-UTC correction and polar motion"""
+        ...
+
+    @property
+    def time_scale(self) -> str:
+        """Time scale in which the timestamp grid is uniform ("UTC", "TAI" or "TT")"""
+        ...
+
+    @property
+    def gcrs_pv(self) -> PositionVelocityData:
+        """Position and velocity data in GCRS frame (interpolated)"""
+        ...
+
+    @property
+    def teme_pv(self) -> PositionVelocityData:
+        """Position and velocity data in TEME frame (converted from GCRS)"""
+        ...
+
+    @property
+    def pef_pv(self) -> PositionVelocityData:
+        """Position and velocity data in the pseudo-Earth-fixed frame
+
+        TEME rotated by Greenwich mean sidereal time: ITRS without polar
+        motion, as used by legacy NORAD Earth-fixed products.
+        """
+        ...
+
+    @property
+    def itrs_pv(self) -> PositionVelocityData:
+        """Position and velocity data in ITRS (Earth-fixed) frame"""
+        ...
+
+    @property
+    def itrs(self) -> Any:  # Returns astropy.coordinates.SkyCoord
+        """SkyCoord object in ITRS frame"""
+        ...
+
+    @property
+    def gcrs(self) -> Any:  # Returns astropy.coordinates.SkyCoord
+        """SkyCoord object in GCRS frame"""
+        ...
+
+    @property
+    def earth(self) -> Any:  # Returns astropy.coordinates.SkyCoord
+        """SkyCoord object for Earth position relative to spacecraft"""
+        ...
+
+    @property
+    def latitude(self) -> Any:  # Returns astropy.units.Quantity
+        """Geodetic latitude as an astropy Quantity array (degrees), one per timestamp"""
+        ...
+
+    @property
+    def latitude_deg(self) -> npt.NDArray[np.float64]:
+        """Geodetic latitude in degrees as a raw NumPy array (one per timestamp)"""
+        ...
+
+    @property
+    def latitude_rad(self) -> npt.NDArray[np.float64]:
+        """Geodetic latitude in radians as a raw NumPy array (one per timestamp)"""
+        ...
+
+    @property
+    def longitude(self) -> Any:  # Returns astropy.units.Quantity
+        """Geodetic longitude as an astropy Quantity array (degrees), one per timestamp"""
+        ...
+
+    @property
+    def longitude_deg(self) -> npt.NDArray[np.float64]:
+        """Geodetic longitude in degrees as a raw NumPy array (one per timestamp)"""
+        ...
+
+    @property
+    def longitude_rad(self) -> npt.NDArray[np.float64]:
+        """Geodetic longitude in radians as a raw NumPy array (one per timestamp)"""
+        ...
+
+    @property
+    def height(self) -> Any:  # Returns astropy.units.Quantity
+        """Height above the WGS84 ellipsoid as an astropy Quantity array (meters), one per timestamp"""
+        ...
+
+    @property
+    def height_m(self) -> npt.NDArray[np.float64]:
+        """Height above the WGS84 ellipsoid as a raw NumPy array in meters (one per timestamp)"""
+        ...
+
+    @property
+    def height_km(self) -> npt.NDArray[np.float64]:
+        """Height above the WGS84 ellipsoid as a raw NumPy array in kilometers (one per timestamp)"""
+        ...
+
+    @property
+    def geomagnetic_latitude_deg(self) -> npt.NDArray[np.float64]:
+        """Centred-dipole (IGRF) geomagnetic latitude in degrees (one per timestamp)"""
+        ...
+
+    @property
+    def geomagnetic_longitude_deg(self) -> npt.NDArray[np.float64]:
+        """Centred-dipole (IGRF) geomagnetic longitude in degrees (one per timestamp)"""
+        ...
+
+    @property
+    def cutoff_rigidity_gv(self) -> npt.NDArray[np.float64]:
+        """Störmer vertical geomagnetic cutoff rigidity in GV (one per timestamp)"""
+        ...
+
+    @property
+    def sun(self) -> Any:  # Returns astropy.coordinates.SkyCoord
+        """SkyCoord object for Sun position relative to spacecraft"""
+        ...
+
+    @property
+    def moon(self) -> Any:  # Returns astropy.coordinates.SkyCoord
+        """SkyCoord object for Moon position relative to spacecraft"""
+        ...
+
+    @property
+    def timestamp(self) -> npt.NDArray[np.datetime64]:
+        """
+        Array of timestamps for the ephemeris.
+
+        Returns a NumPy array of datetime objects (not a list) for efficient indexing.
+        This property is cached for performance - repeated access is ~90x faster.
+        """
+        ...
+
+    @property
+    def horizons_pv(self) -> PositionVelocityData:
+        """
+        Raw Horizons position and velocity data without interpolation.
+
+        Returns the state vectors tabulated by Horizons.
+        """
+        ...
+
+    @property
+    def horizons_timestamp(self) -> list[datetime]:
+        """
+        Raw Horizons timestamps without interpolation.
+
+        Returns the timestamps tabulated by Horizons as UTC datetime objects.
+        """
+        ...
+
+    @property
+    def sun_pv(self) -> PositionVelocityData:
+        """Sun position and velocity in GCRS frame"""
+        ...
+
+    @property
+    def moon_pv(self) -> PositionVelocityData:
+        """Moon position and velocity in GCRS frame"""
+        ...
+
+    @property
+    def obsgeoloc(self) -> npt.NDArray[np.float64]:
+        """
+        Observer geocentric location (GCRS position).
+
+        Returns position in km, compatible with astropy's GCRS frame obsgeoloc parameter.
+        Shape: (N, 3) where N is the number of timestamps.
+        """
+        ...
+
+    @property
+    def obsgeovel(self) -> npt.NDArray[np.float64]:
+        """
+        Observer geocentric velocity (GCRS velocity).
+
+        Returns velocity in km/s, compatible with astropy's GCRS frame obsgeovel parameter.
+        Shape: (N, 3) where N is the number of timestamps.
+        """
+        ...
+
+    def radec_to_altaz(
+        self,
+        ra_deg: float,
+        dec_deg: float,
+        time_indices: list[int] | None = None,
+        *,
+        polar_motion: bool | None = None,
+    ) -> npt.NDArray[np.float64]:
+        """Topocentric altitude/azimuth for given RA/Dec (deg) at selected times.
+
+        ``polar_motion`` overrides the ephemeris's own setting for this call.
+        """
+        ...
+
+    def radec_to_altaz_batch(
+        self,
+        ra_deg: Sequence[float] | npt.NDArray[np.float64],
+        dec_deg: Sequence[float] | npt.NDArray[np.float64],
+        time_indices: list[int] | None = None,
+        *,
+        polar_motion: bool | None = None,
+    ) -> npt.NDArray[np.float64]:
+        """Altitude/azimuth for many targets, shape (n_targets, n_times, 2).
+
+        ``polar_motion`` overrides the ephemeris's own setting for this call.
+        """
+        ...
+
+    def calculate_airmass(
+        self,
+        ra_deg: float,
+        dec_deg: float,
+        time_indices: list[int] | None = None,
+    ) -> list[float]:
+        """Calculate airmass for given RA/Dec (deg) at selected times.
+
+        Returns airmass values (1.0 at zenith, ~2.0 at 30° altitude, infinity below horizon).
+        Accounts for observer height using atmospheric scale height correction.
+        """
+        ...
+
+    def airmass_batch(
+        self,
+        ras: Sequence[float] | npt.NDArray[np.float64],
+        decs: Sequence[float] | npt.NDArray[np.float64],
+        time_indices: list[int] | None = None,
+    ) -> npt.NDArray[np.float64]:
+        """Calculate airmass for many RA/Dec targets (deg) in one call.
+
+        Returns an array of shape (n_targets, n_times) from the vectorized
+        Kasten pipeline used by the airmass constraint (no refraction or
+        aberration); infinity below the horizon.
+        """
+        ...
+
+    @property
+    def sun_radius(self) -> Any:  # Returns astropy.units.Quantity
+        """
+        Angular radius of the Sun with astropy units (degrees).
+
+        Returns an astropy Quantity with units of degrees.
+        This property is cached for performance.
+        """
+        ...
+
+    @property
+    def sun_radius_deg(self) -> npt.NDArray[np.float64]:
+        """
+        Angular radius of the Sun as seen from the spacecraft (in degrees).
+
+        Returns a NumPy array of angular radii for each timestamp.
+        Angular radius = arcsin(physical_radius / distance)
+        This property is cached for performance.
+        """
+        ...
+
+    @property
+    def moon_radius(self) -> Any:  # Returns astropy.units.Quantity
+        """
+        Angular radius of the Moon with astropy units (degrees).
+
+        Returns an astropy Quantity with units of degrees.
+        This property is cached for performance.
+        """
+        ...
+
+    @property
+    def moon_radius_deg(self) -> npt.NDArray[np.float64]:
+        """
+        Angular radius of the Moon as seen from the spacecraft (in degrees).
+
+        Returns a NumPy array of angular radii for each timestamp.
+        Angular radius = arcsin(physical_radius / distance)
+        This property is cached for performance.
+        """
+        ...
+
+    @property
+    def earth_radius(self) -> Any:  # Returns astropy.units.Quantity
+        """
+        Angular radius of the Earth with astropy units (degrees).
+
+        Returns an astropy Quantity with units of degrees.
+        This property is cached for performance.
+        """
+        ...
+
+    @property
+    def earth_radius_deg(self) -> npt.NDArray[np.float64]:
+        """
+        Angular radius of the Earth as seen from the spacecraft (in degrees).
+
+        Returns a NumPy array of angular radii for each timestamp.
+        Angular radius = arcsin(physical_radius / distance)
+        This property is cached for performance.
+        """
+        ...
+
+    @property
+    def sun_radius_rad(self) -> npt.NDArray[np.float64]:
+        """
+        Angular radius of the Sun as seen from the spacecraft (in radians).
+
+        Returns a NumPy array of angular radii for each timestamp.
+        Angular radius = arcsin(physical_radius / distance)
+        This property is cached for performance.
+        """
+        ...
+
+    @property
+    def moon_radius_rad(self) -> npt.NDArray[np.float64]:
+        """
+        Angular radius of the Moon as seen from the spacecraft (in radians).
+
+        Returns a NumPy array of angular radii for each timestamp.
+        Angular radius = arcsin(physical_radius / distance)
+        This property is cached for performance.
+        """
+        ...
+
+    @property
+    def earth_radius_rad(self) -> npt.NDArray[np.float64]:
+        """
+        Angular radius of the Earth as seen from the spacecraft (in radians).
+
+        Returns a NumPy array of angular radii for each timestamp.
+        Angular radius = arcsin(physical_radius / distance)
+        This property is cached for performance.
+        """
+        ...
+
+    @property
+    def sun_ra_dec_deg(self) -> npt.NDArray[np.float64]:
+        """
+        Right Ascension and Declination of the Sun in degrees.
+
+        Returns an Nx2 NumPy array where column 0 is RA and column 1 is Dec.
+        This property is cached for performance.
+        """
+        ...
+
+    @property
+    def moon_ra_dec_deg(self) -> npt.NDArray[np.float64]:
+        """
+        Right Ascension and Declination of the Moon in degrees.
+
+        Returns an Nx2 NumPy array where column 0 is RA and column 1 is Dec.
+        This property is cached for performance.
+        """
+        ...
+
+    @property
+    def earth_ra_dec_deg(self) -> npt.NDArray[np.float64]:
+        """
+        Right Ascension and Declination of the Earth in degrees.
+
+        Returns an Nx2 NumPy array where column 0 is RA and column 1 is Dec.
+        This property is cached for performance.
+        """
+        ...
+
+    @property
+    def sun_ra_dec_rad(self) -> npt.NDArray[np.float64]:
+        """
+        Right Ascension and Declination of the Sun in radians.
+
+        Returns an Nx2 NumPy array where column 0 is RA and column 1 is Dec.
+        This property is cached for performance.
+        """
+        ...
+
+    @property
+    def moon_ra_dec_rad(self) -> npt.NDArray[np.float64]:
+        """
+        Right Ascension and Declination of the Moon in radians.
+
+        Returns an Nx2 NumPy array where column 0 is RA and column 1 is Dec.
+        This property is cached for performance.
+        """
+        ...
+
+    @property
+    def earth_ra_dec_rad(self) -> npt.NDArray[np.float64]:
+        """
+        Right Ascension and Declination of the Earth in radians.
+
+        Returns an Nx2 NumPy array where column 0 is RA and column 1 is Dec.
+        This property is cached for performance.
+        """
+        ...
+
+    @property
+    def sun_ra_deg(self) -> npt.NDArray[np.float64]:
+        """Right Ascension of the Sun in degrees. Extracts column 0 from sun_ra_dec_deg."""
+        ...
+
+    @property
+    def sun_dec_deg(self) -> npt.NDArray[np.float64]:
+        """Declination of the Sun in degrees. Extracts column 1 from sun_ra_dec_deg."""
+        ...
+
+    @property
+    def moon_ra_deg(self) -> npt.NDArray[np.float64]:
+        """Right Ascension of the Moon in degrees. Extracts column 0 from moon_ra_dec_deg."""
+        ...
+
+    @property
+    def moon_dec_deg(self) -> npt.NDArray[np.float64]:
+        """Declination of the Moon in degrees. Extracts column 1 from moon_ra_dec_deg."""
+        ...
+
+    @property
+    def earth_ra_deg(self) -> npt.NDArray[np.float64]:
+        """Right Ascension of the Earth in degrees. Extracts column 0 from earth_ra_dec_deg."""
+        ...
+
+    @property
+    def earth_dec_deg(self) -> npt.NDArray[np.float64]:
+        """Declination of the Earth in degrees. Extracts column 1 from earth_ra_dec_deg."""
+        ...
+
+    @property
+    def sun_ra_rad(self) -> npt.NDArray[np.float64]:
+        """Right Ascension of the Sun in radians. Extracts column 0 from sun_ra_dec_rad."""
+        ...
+
+    @property
+    def sun_dec_rad(self) -> npt.NDArray[np.float64]:
+        """Declination of the Sun in radians. Extracts column 1 from sun_ra_dec_rad."""
+        ...
+
+    @property
+    def moon_ra_rad(self) -> npt.NDArray[np.float64]:
+        """Right Ascension of the Moon in radians. Extracts column 0 from moon_ra_dec_rad."""
+        ...
+
+    @property
+    def moon_dec_rad(self) -> npt.NDArray[np.float64]:
+        """Declination of the Moon in radians. Extracts column 1 from moon_ra_dec_rad."""
+        ...
+
+    @property
+    def earth_ra_rad(self) -> npt.NDArray[np.float64]:
+        """Right Ascension of the Earth in radians. Extracts column 0 from earth_ra_dec_rad."""
+        ...
+
+    @property
+    def earth_dec_rad(self) -> npt.NDArray[np.float64]:
+        """Declination of the Earth in radians. Extracts column 1 from earth_ra_dec_rad."""
+        ...
+
+    def index(self, time: datetime) -> int:
+        """
+        Find the index of the closest timestamp to the given datetime.
+
+        Returns the index in the ephemeris timestamp array that is closest to the provided time.
+        This can be used to index into any of the ephemeris arrays (positions, velocities, etc.)
+
+        Args:
+            time: Python datetime object to find the closest match for
+
+        Returns:
+            Index of the closest timestamp
+
+        Raises:
+            ValueError: If no timestamps are available in the ephemeris
+
+        Example:
+            >>> from datetime import datetime
+            >>> eph = HorizonsEphemeris("-170", ...)
+            >>> target_time = datetime(2024, 1, 15, 12, 0, 0)
+            >>> idx = eph.index(target_time)
+            >>> position = eph.gcrs_pv.position[idx]
+        """
+        ...
+
+    def fingerprint(self) -> str:
+        """
+        Stable hex digest identifying this ephemeris.
+
+        Hashes the source (elements, file path or site), the options, the time
+        grid and the computed GCRS states, so ephemerides built the same way
+        share a fingerprint across sessions. Ephemerides with equal
+        fingerprints compare equal, and the fingerprint can key caches of
+        constraint results or schedules.
+
+        Returns:
+            32-character hexadecimal digest
+        """
+        ...
+
+    def __eq__(self, other: object) -> bool: ...
+    def __hash__(self) -> int: ...
+    @property
+    def metadata(self) -> dict[str, Any]:
+        """
+        Source, time span, computed frames and Earth orientation state.
+
+        Keys start with ``type`` and the source fields of this class (e.g.
+        ``norad_id`` and ``tle_epoch``, or a file path), then ``polar_motion``,
+        ``begin``, ``end``, ``step_size``, ``n_times``, ``frames``,
+        ``eop_available``, ``polar_motion_applied``, ``fast_itrs``,
+        ``ut1_available`` and ``fingerprint``. Values are
+        JSON-serializable, with times as ISO 8601 strings, for provenance logs.
+        """
+        ...
+
+    @property
+    def provenance(self) -> Provenance:
+        """
+        Reproducibility record: library version, source and fingerprint,
+        element set, input and kernel file digests, Earth orientation and
+        leap-second data.
+        """
+        ...
+
+    def summary(self) -> str:
+        """Human-readable, multi-line description of ``metadata``."""
+        ...
+
+    def __repr__(self) -> str: ...
+    def __len__(self) -> int:
+        """Number of time steps"""
+        ...
+
+    def __getitem__(self, index: int) -> EphemerisSample:
+        """State at time step ``index``; negative indices count from the end"""
+        ...
+
+    def __iter__(self) -> Iterator[EphemerisSample]:
+        """Iterate over the time steps in order"""
+        ...
+
+    @property
+    def illumination_factor(self) -> npt.NDArray[np.float64]:
+        """Fraction of the solar disk visible from the observer at each time step
+
+        1.0 in sunlight, 0.0 in the Earth's umbra and fractional in the
+        penumbra, from the overlap of the apparent Sun and Earth disks.
+        """
+        ...
+
+    @property
+    def geodetic_rates(self) -> npt.NDArray[np.float64] | None:
+        """Geodetic rates from the ITRS velocity, shape (N, 3)
+
+        Columns are latitude rate (deg/s), longitude rate (deg/s) and height
+        rate (km/s). The longitude rate diverges at the poles.
+        """
+        ...
+
+    @property
+    def ground_speed(self) -> npt.NDArray[np.float64] | None:
+        """Speed of the sub-observer point over the WGS84 ellipsoid (km/s)"""
+        ...
+
+    @property
+    def sub_satellite_point(self) -> npt.NDArray[np.float64] | None:
+        """Geodetic (latitude, longitude) in degrees below the observer, shape (N, 2)"""
+        ...
+
+    @property
+    def sub_solar_point(self) -> npt.NDArray[np.float64]:
+        """(latitude, longitude) in degrees with the Sun at the zenith, shape (N, 2)"""
+        ...
+
+    @property
+    def nadir_solar_zenith_angle(self) -> npt.NDArray[np.float64] | None:
+        """Solar zenith angle at the sub-satellite point in degrees
+
+        The ground below the observer is daylit below 90.
+        """
+        ...
+
+    def moon_illumination(self, time_indices: list[int] | None = None) -> list[float]:
+        """
+        Calculate Moon illumination fraction for all ephemeris times.
+
+        Returns the fraction of the Moon's illuminated surface as seen from the
+        spacecraft observer (0.0 = new moon, 1.0 = full moon).
+
+        Args:
+            time_indices: Optional indices into ephemeris times (default: all times)
+
+        Returns:
+            List of Moon illumination fractions
+        """
+        ...
+
+    def access_to(
+        self,
+        lat: float,
+        lon: float,
+        max_off_nadir_deg: float,
+        min_sun_elev: float | None = None,
+    ) -> list[AccessOpportunity]:
+        """
+        Find imaging opportunities of a ground target.
+
+        A time is accessible when the target is above its local horizon (on
+        the visible side of the Earth's limb), within ``max_off_nadir_deg`` of
+        the spacecraft's nadir and, if ``min_sun_elev`` is given, the Sun is
+        at least that high at the target.
+
+        Args:
+            lat: Geodetic latitude of the target in degrees
+            lon: Longitude of the target in degrees, east positive
+            max_off_nadir_deg: Maximum off-nadir angle in degrees (0-90)
+            min_sun_elev: Minimum Sun elevation at the target in degrees
+                (default: no illumination requirement)
+
+        Returns:
+            Opportunities in time order, each summarised at its smallest
+            off-nadir angle
+
+        Raises:
+            ValueError: If the target location or an angle is out of range
+        """
+        ...
+
+    def get_body_pv(
+        self,
+        body: str,
+        spice_kernel: str | None = ...,
+        use_horizons: bool = ...,
+        source: str | None = ...,
+    ) -> PositionVelocityData:
+        """
+        Get position and velocity of a celestial body.
+
+        Args:
+            body: Name of the body (e.g., 'sun', 'moon', 'earth'), or a pseudo-body
+                ('EMB', 'SSB', 'SEL1', 'SEL2', 'EML1', 'EML2')
+            spice_kernel: Optional path to SPICE kernel
+            use_horizons: If True, fall back to JPL Horizons API when SPICE fails
+                (equivalent to ``source="auto"``)
+            source: Data source precedence: ``"spice"`` (loaded kernels only),
+                ``"horizons"`` (JPL Horizons only) or ``"auto"`` (SPICE, falling back
+                to Horizons with a ``UserWarning``). Defaults to ``"spice"`` unless
+                ``use_horizons`` is set.
+
+        Returns:
+            Position and velocity data for the requested body
+        """
+        ...
+
+    def get_bodies(
+        self,
+        bodies: list[str],
+        spice_kernel: str | None = ...,
+        use_horizons: bool = ...,
+        source: str | None = ...,
+    ) -> dict[str, PositionVelocityData]:
+        """
+        Get position and velocity of several celestial bodies in one pass.
+
+        Equivalent to calling ``get_body_pv`` for each body, but the SPICE
+        lookups and the observer positions are set up once for all bodies.
+
+        Args:
+            bodies: Body names or NAIF IDs, as accepted by ``get_body_pv``
+            spice_kernel: Optional path to SPICE kernel
+            use_horizons: If True, fall back to JPL Horizons API when SPICE fails
+                (equivalent to ``source="auto"``)
+            source: Data source precedence, as for ``get_body_pv``
+
+        Returns:
+            Dict mapping each requested body, as given, to its position and
+            velocity data
+        """
+        ...
+
+    def separation(self, a: Any, b: Any) -> npt.NDArray[np.float64]:
+        """
+        Angular separation between two targets at each time step.
+
+        Computed in Rust from observer-relative vectors, without astropy.
+
+        Args:
+            a, b: Each an ``(ra_deg, dec_deg)`` pair, a body name (``"sun"``,
+                ``"moon"`` and ``"earth"`` use the cached positions; other names
+                resolve as in ``get_body_pv``), observer-relative
+                ``PositionVelocityData`` (e.g. from ``get_body_pv``), or a
+                SkyCoord with a single position or one per time step (e.g.
+                ``ephem.sun``)
+
+        Returns:
+            Separation angles in degrees, one per time step
+
+        Raises:
+            TypeError: If an operand is not one of the accepted kinds
+            ValueError: If an operand has a different number of time steps
+        """
+        ...
+
+    def sun_angle(
+        self, ra_deg: float, dec_deg: float, time_indices: list[int] | None = ...
+    ) -> npt.NDArray[np.float64]:
+        """
+        Angle between a target and the Sun at each time step.
+
+        Args:
+            ra_deg: Target right ascension in degrees
+            dec_deg: Target declination in degrees
+            time_indices: Optional indices into the ephemeris times (default: all)
+
+        Returns:
+            Angles in degrees, one per selected time step
+        """
+        ...
+
+    def moon_angle(
+        self, ra_deg: float, dec_deg: float, time_indices: list[int] | None = ...
+    ) -> npt.NDArray[np.float64]:
+        """
+        Angle between a target and the Moon at each time step.
+
+        Args:
+            ra_deg: Target right ascension in degrees
+            dec_deg: Target declination in degrees
+            time_indices: Optional indices into the ephemeris times (default: all)
+
+        Returns:
+            Angles in degrees, one per selected time step
+        """
+        ...
+
+    def earth_limb_angle(
+        self, ra_deg: float, dec_deg: float, time_indices: list[int] | None = ...
+    ) -> npt.NDArray[np.float64]:
+        """
+        Angle of a target above the Earth's limb at each time step.
+
+        The angle from the Earth's center minus the Earth's angular radius, so
+        negative while the target is behind the Earth. No refraction or horizon
+        mask is applied.
+
+        Args:
+            ra_deg: Target right ascension in degrees
+            dec_deg: Target declination in degrees
+            time_indices: Optional indices into the ephemeris times (default: all)
+
+        Returns:
+            Angles in degrees, one per selected time step
+        """
+        ...
+
+    def geometry(
+        self,
+        ra_deg: float,
+        dec_deg: float,
+        time: datetime,
+        *,
+        saa_polygon: list[tuple[float, float]] | None = None,
+    ) -> ObservationGeometry:
+        """
+        Observation geometry of a target at the ephemeris step nearest ``time``.
+
+        Args:
+            ra_deg: Target right ascension in degrees
+            dec_deg: Target declination in degrees
+            time: Time of interest; the nearest ephemeris step is used
+            saa_polygon: Optional SAA region as (longitude, latitude) vertices in
+                degrees; sets ``in_saa``
+
+        Returns:
+            Sun, Moon and Earth-limb angles, plus altitude and airmass for ground
+            sites or eclipse, orbital phase and beta angle for orbiting observers
+        """
+        ...
+
+    def get_body(
+        self,
+        body: str,
+        spice_kernel: str | None = ...,
+        use_horizons: bool = ...,
+        source: str | None = ...,
+    ) -> Any:  # Returns astropy.coordinates.SkyCoord
+        """
+        Get SkyCoord for a celestial body.
+
+        Args:
+            body: Name of the body (e.g., 'sun', 'moon', 'earth'), or a pseudo-body
+                ('EMB', 'SSB', 'SEL1', 'SEL2', 'EML1', 'EML2')
+            spice_kernel: Optional path to SPICE kernel
+            use_horizons: If True, fall back to JPL Horizons API when SPICE fails
+                (equivalent to ``source="auto"``)
+            source: Data source precedence: ``"spice"`` (loaded kernels only),
+                ``"horizons"`` (JPL Horizons only) or ``"auto"`` (SPICE, falling back
+                to Horizons with a ``UserWarning``). Defaults to ``"spice"`` unless
+                ``use_horizons`` is set.
+
+        Returns:
+            astropy.coordinates.SkyCoord object
+        """
+        ...
+
+class AlmanacEphemeris(Ephemeris):
+    """
+    Ephemeris for one satellite of a GPS YUMA or SEM almanac.
+
+    The almanac elements are propagated with the IS-GPS-200 almanac model in
+    the WGS 84 Earth-fixed frame (treated as ITRS) and transformed to GCRS.
+    Almanac accuracy is at the kilometre level, suitable for visibility and
+    constellation geometry studies.
+    """
+
+    def __init__(
+        self,
+        almanac_path: str,
+        prn: int,
+        begin: datetime,
+        end: datetime,
+        step_size: int = 60,
+        *,
+        format: str | None = None,
+        polar_motion: bool = False,
+        time_scale: str = "UTC",
+    ) -> None:
+        """
+        Initialize an ephemeris for one PRN of a GPS almanac.
+
+        Args:
+            almanac_path: Path to a YUMA (``.alm``) or SEM (``.al3``) almanac file
+            prn: PRN number of the satellite
+            begin: Start time (naive datetime treated as UTC)
+            end: End time (naive datetime treated as UTC)
+            step_size: Time step in seconds (default: 60)
+            format: ``"yuma"`` or ``"sem"``; detected from the file contents if omitted
+            polar_motion: Whether to apply polar motion correction (default: False)
+            time_scale: "UTC" steps clock time and skips leap seconds; "TAI"
+                or "TT" step SI seconds through them (default: "UTC")
+
+        Raises:
+            IOError: If the almanac file cannot be read
+            ValueError: If the almanac cannot be parsed or the PRN is not present
+        """
+        ...
+
+    @staticmethod
+    def prns(almanac_path: str, *, format: str | None = None) -> list[int]:
+        """List the PRNs present in an almanac file"""
+        ...
+
+    @staticmethod
+    def load_constellation(
+        almanac_path: str,
+        begin: datetime,
+        end: datetime,
+        step_size: int = 60,
+        *,
+        format: str | None = None,
+        polar_motion: bool = False,
+        time_scale: str = "UTC",
+        healthy_only: bool = False,
+    ) -> dict[int, AlmanacEphemeris]:
+        """
+        Create ephemerides for every PRN in an almanac.
+
+        Args:
+            almanac_path: Path to the almanac file
+            begin: Start time (naive datetime treated as UTC)
+            end: End time (naive datetime treated as UTC)
+            step_size: Time step in seconds (default: 60)
+            format: ``"yuma"`` or ``"sem"``; detected if omitted
+            polar_motion: Whether to apply polar motion correction (default: False)
+            time_scale: "UTC" steps clock time and skips leap seconds; "TAI"
+                or "TT" step SI seconds through them (default: "UTC")
+            healthy_only: Skip satellites with a non-zero health word
+
+        Returns:
+            Dictionary mapping PRN to its AlmanacEphemeris
+        """
+        ...
+
+    @property
+    def almanac_path(self) -> str:
+        """Path to the almanac file"""
+        ...
+
+    @property
+    def almanac_format(self) -> str:
+        """Almanac format, ``"yuma"`` or ``"sem"``"""
+        ...
+
+    @property
+    def prn(self) -> int:
+        """PRN number of the satellite"""
+        ...
+
+    @property
+    def health(self) -> int:
+        """Satellite health word (0 = healthy)"""
+        ...
+
+    @property
+    def almanac_epoch(self) -> datetime:
+        """Almanac time of applicability (UTC)"""
+        ...
+
+    @property
+    def elements(self) -> dict[str, float]:
+        """Almanac Keplerian elements (angles in radians, sqrt_a in m^1/2)"""
+        ...
+
+    @property
+    def begin(self) -> datetime:
+        """Start time of ephemeris"""
+        ...
+
+    @property
+    def end(self) -> datetime:
+        """End time of ephemeris"""
+        ...
+
+    @property
+    def step_size(self) -> int:
+        """Time step in seconds"""
+        ...
+
+    @property
+    def polar_motion(self) -> bool:
+        """Whether polar motion correction is applied"""
+        ...
+
+    @property
+    def time_scale(self) -> str:
+        """Time scale in which the timestamp grid is uniform ("UTC", "TAI" or "TT")"""
+        ...
+
+    @property
+    def gcrs_pv(self) -> PositionVelocityData:
+        """Position and velocity data in GCRS frame (interpolated)"""
+        ...
+
+    @property
+    def teme_pv(self) -> PositionVelocityData:
+        """Position and velocity data in TEME frame (converted from GCRS)"""
+        ...
+
+    @property
+    def pef_pv(self) -> PositionVelocityData:
+        """Position and velocity data in the pseudo-Earth-fixed frame
+
+        TEME rotated by Greenwich mean sidereal time: ITRS without polar
+        motion, as used by legacy NORAD Earth-fixed products.
+        """
+        ...
+
+    @property
+    def itrs_pv(self) -> PositionVelocityData:
+        """Position and velocity data in ITRS (Earth-fixed) frame"""
+        ...
+
+    @property
+    def itrs(self) -> Any:  # Returns astropy.coordinates.SkyCoord
+        """SkyCoord object in ITRS frame"""
+        ...
+
+    @property
+    def gcrs(self) -> Any:  # Returns astropy.coordinates.SkyCoord
+        """SkyCoord object in GCRS frame"""
+        ...
+
+    @property
+    def earth(self) -> Any:  # Returns astropy.coordinates.SkyCoord
+        """SkyCoord object for Earth position relative to spacecraft"""
+        ...
+
+    @property
+    def latitude(self) -> Any:  # Returns astropy.units.Quantity
+        """Geodetic latitude as an astropy Quantity array (degrees), one per timestamp"""
+        ...
+
+    @property
+    def latitude_deg(self) -> npt.NDArray[np.float64]:
+        """Geodetic latitude in degrees as a raw NumPy array (one per timestamp)"""
+        ...
+
+    @property
+    def latitude_rad(self) -> npt.NDArray[np.float64]:
+        """Geodetic latitude in radians as a raw NumPy array (one per timestamp)"""
+        ...
+
+    @property
+    def longitude(self) -> Any:  # Returns astropy.units.Quantity
+        """Geodetic longitude as an astropy Quantity array (degrees), one per timestamp"""
+        ...
+
+    @property
+    def longitude_deg(self) -> npt.NDArray[np.float64]:
+        """Geodetic longitude in degrees as a raw NumPy array (one per timestamp)"""
+        ...
+
+    @property
+    def longitude_rad(self) -> npt.NDArray[np.float64]:
+        """Geodetic longitude in radians as a raw NumPy array (one per timestamp)"""
+        ...
+
+    @property
+    def height(self) -> Any:  # Returns astropy.units.Quantity
+        """Height above the WGS84 ellipsoid as an astropy Quantity array (meters), one per timestamp"""
+        ...
+
+    @property
+    def height_m(self) -> npt.NDArray[np.float64]:
+        """Height above the WGS84 ellipsoid as a raw NumPy array in meters (one per timestamp)"""
+        ...
+
+    @property
+    def height_km(self) -> npt.NDArray[np.float64]:
+        """Height above the WGS84 ellipsoid as a raw NumPy array in kilometers (one per timestamp)"""
+        ...
+
+    @property
+    def geomagnetic_latitude_deg(self) -> npt.NDArray[np.float64]:
+        """Centred-dipole (IGRF) geomagnetic latitude in degrees (one per timestamp)"""
+        ...
+
+    @property
+    def geomagnetic_longitude_deg(self) -> npt.NDArray[np.float64]:
+        """Centred-dipole (IGRF) geomagnetic longitude in degrees (one per timestamp)"""
+        ...
+
+    @property
+    def cutoff_rigidity_gv(self) -> npt.NDArray[np.float64]:
+        """Störmer vertical geomagnetic cutoff rigidity in GV (one per timestamp)"""
+        ...
+
+    @property
+    def sun(self) -> Any:  # Returns astropy.coordinates.SkyCoord
+        """SkyCoord object for Sun position relative to spacecraft"""
+        ...
+
+    @property
+    def moon(self) -> Any:  # Returns astropy.coordinates.SkyCoord
+        """SkyCoord object for Moon position relative to spacecraft"""
+        ...
+
+    @property
+    def timestamp(self) -> npt.NDArray[np.datetime64]:
+        """
+        Array of timestamps for the ephemeris.
+
+        Returns a NumPy array of datetime objects (not a list) for efficient indexing.
+        This property is cached for performance - repeated access is ~90x faster.
+        """
+        ...
+
+    @property
+    def sun_pv(self) -> PositionVelocityData:
+        """Sun position and velocity in GCRS frame"""
+        ...
+
+    @property
+    def moon_pv(self) -> PositionVelocityData:
+        """Moon position and velocity in GCRS frame"""
+        ...
+
+    @property
+    def obsgeoloc(self) -> npt.NDArray[np.float64]:
+        """
+        Observer geocentric location (GCRS position).
+
+        Returns position in km, compatible with astropy's GCRS frame obsgeoloc parameter.
+        Shape: (N, 3) where N is the number of timestamps.
+        """
+        ...
+
+    @property
+    def obsgeovel(self) -> npt.NDArray[np.float64]:
+        """
+        Observer geocentric velocity (GCRS velocity).
+
+        Returns velocity in km/s, compatible with astropy's GCRS frame obsgeovel parameter.
+        Shape: (N, 3) where N is the number of timestamps.
+        """
+        ...
+
+    def radec_to_altaz(
+        self,
+        ra_deg: float,
+        dec_deg: float,
+        time_indices: list[int] | None = None,
+        *,
+        polar_motion: bool | None = None,
+    ) -> npt.NDArray[np.float64]:
+        """Topocentric altitude/azimuth for given RA/Dec (deg) at selected times.
+
+        ``polar_motion`` overrides the ephemeris's own setting for this call.
+        """
+        ...
+
+    def radec_to_altaz_batch(
+        self,
+        ra_deg: Sequence[float] | npt.NDArray[np.float64],
+        dec_deg: Sequence[float] | npt.NDArray[np.float64],
+        time_indices: list[int] | None = None,
+        *,
+        polar_motion: bool | None = None,
+    ) -> npt.NDArray[np.float64]:
+        """Altitude/azimuth for many targets, shape (n_targets, n_times, 2).
+
+        ``polar_motion`` overrides the ephemeris's own setting for this call.
+        """
+        ...
+
+    def calculate_airmass(
+        self,
+        ra_deg: float,
+        dec_deg: float,
+        time_indices: list[int] | None = None,
+    ) -> list[float]:
+        """Calculate airmass for given RA/Dec (deg) at selected times.
+
+        Returns airmass values (1.0 at zenith, ~2.0 at 30° altitude, infinity below horizon).
+        Accounts for observer height using atmospheric scale height correction.
+        """
+        ...
+
+    def airmass_batch(
+        self,
+        ras: Sequence[float] | npt.NDArray[np.float64],
+        decs: Sequence[float] | npt.NDArray[np.float64],
+        time_indices: list[int] | None = None,
+    ) -> npt.NDArray[np.float64]:
+        """Calculate airmass for many RA/Dec targets (deg) in one call.
+
+        Returns an array of shape (n_targets, n_times) from the vectorized
+        Kasten pipeline used by the airmass constraint (no refraction or
+        aberration); infinity below the horizon.
+        """
+        ...
+
+    @property
+    def sun_radius(self) -> Any:  # Returns astropy.units.Quantity
+        """
+        Angular radius of the Sun with astropy units (degrees).
+
+        Returns an astropy Quantity with units of degrees.
+        This property is cached for performance.
+        """
+        ...
+
+    @property
+    def sun_radius_deg(self) -> npt.NDArray[np.float64]:
+        """
+        Angular radius of the Sun as seen from the spacecraft (in degrees).
+
+        Returns a NumPy array of angular radii for each timestamp.
+        Angular radius = arcsin(physical_radius / distance)
+        This property is cached for performance.
+        """
+        ...
+
+    @property
+    def moon_radius(self) -> Any:  # Returns astropy.units.Quantity
+        """
+        Angular radius of the Moon with astropy units (degrees).
+
+        Returns an astropy Quantity with units of degrees.
+        This property is cached for performance.
+        """
+        ...
+
+    @property
+    def moon_radius_deg(self) -> npt.NDArray[np.float64]:
+        """
+        Angular radius of the Moon as seen from the spacecraft (in degrees).
+
+        Returns a NumPy array of angular radii for each timestamp.
+        Angular radius = arcsin(physical_radius / distance)
+        This property is cached for performance.
+        """
+        ...
+
+    @property
+    def earth_radius(self) -> Any:  # Returns astropy.units.Quantity
+        """
+        Angular radius of the Earth with astropy units (degrees).
+
+        Returns an astropy Quantity with units of degrees.
+        This property is cached for performance.
+        """
+        ...
+
+    @property
+    def earth_radius_deg(self) -> npt.NDArray[np.float64]:
+        """
+        Angular radius of the Earth as seen from the spacecraft (in degrees).
+
+        Returns a NumPy array of angular radii for each timestamp.
+        Angular radius = arcsin(physical_radius / distance)
+        This property is cached for performance.
+        """
+        ...
+
+    @property
+    def sun_radius_rad(self) -> npt.NDArray[np.float64]:
+        """
+        Angular radius of the Sun as seen from the spacecraft (in radians).
+
+        Returns a NumPy array of angular radii for each timestamp.
+        Angular radius = arcsin(physical_radius / distance)
+        This property is cached for performance.
+        """
+        ...
+
+    @property
+    def moon_radius_rad(self) -> npt.NDArray[np.float64]:
+        """
+        Angular radius of the Moon as seen from the spacecraft (in radians).
+
+        Returns a NumPy array of angular radii for each timestamp.
+        Angular radius = arcsin(physical_radius / distance)
+        This property is cached for performance.
+        """
+        ...
+
+    @property
+    def earth_radius_rad(self) -> npt.NDArray[np.float64]:
+        """
+        Angular radius of the Earth as seen from the spacecraft (in radians).
+
+        Returns a NumPy array of angular radii for each timestamp.
+        Angular radius = arcsin(physical_radius / distance)
+        This property is cached for performance.
+        """
+        ...
+
+    @property
+    def sun_ra_dec_deg(self) -> npt.NDArray[np.float64]:
+        """
+        Right Ascension and Declination of the Sun in degrees.
+
+        Returns an Nx2 NumPy array where column 0 is RA and column 1 is Dec.
+        This property is cached for performance.
+        """
+        ...
+
+    @property
+    def moon_ra_dec_deg(self) -> npt.NDArray[np.float64]:
+        """
+        Right Ascension and Declination of the Moon in degrees.
+
+        Returns an Nx2 NumPy array where column 0 is RA and column 1 is Dec.
+        This property is cached for performance.
+        """
+        ...
+
+    @property
+    def earth_ra_dec_deg(self) -> npt.NDArray[np.float64]:
+        """
+        Right Ascension and Declination of the Earth in degrees.
+
+        Returns an Nx2 NumPy array where column 0 is RA and column 1 is Dec.
+        This property is cached for performance.
+        """
+        ...
+
+    @property
+    def sun_ra_dec_rad(self) -> npt.NDArray[np.float64]:
+        """
+        Right Ascension and Declination of the Sun in radians.
+
+        Returns an Nx2 NumPy array where column 0 is RA and column 1 is Dec.
+        This property is cached for performance.
+        """
+        ...
+
+    @property
+    def moon_ra_dec_rad(self) -> npt.NDArray[np.float64]:
+        """
+        Right Ascension and Declination of the Moon in radians.
+
+        Returns an Nx2 NumPy array where column 0 is RA and column 1 is Dec.
+        This property is cached for performance.
+        """
+        ...
+
+    @property
+    def earth_ra_dec_rad(self) -> npt.NDArray[np.float64]:
+        """
+        Right Ascension and Declination of the Earth in radians.
+
+        Returns an Nx2 NumPy array where column 0 is RA and column 1 is Dec.
+        This property is cached for performance.
+        """
+        ...
+
+    @property
+    def sun_ra_deg(self) -> npt.NDArray[np.float64]:
+        """Right Ascension of the Sun in degrees. Extracts column 0 from sun_ra_dec_deg."""
+        ...
+
+    @property
+    def sun_dec_deg(self) -> npt.NDArray[np.float64]:
+        """Declination of the Sun in degrees. Extracts column 1 from sun_ra_dec_deg."""
+        ...
+
+    @property
+    def moon_ra_deg(self) -> npt.NDArray[np.float64]:
+        """Right Ascension of the Moon in degrees. Extracts column 0 from moon_ra_dec_deg."""
+        ...
+
+    @property
+    def moon_dec_deg(self) -> npt.NDArray[np.float64]:
+        """Declination of the Moon in degrees. Extracts column 1 from moon_ra_dec_deg."""
+        ...
+
+    @property
+    def earth_ra_deg(self) -> npt.NDArray[np.float64]:
+        """Right Ascension of the Earth in degrees. Extracts column 0 from earth_ra_dec_deg."""
+        ...
+
+    @property
+    def earth_dec_deg(self) -> npt.NDArray[np.float64]:
+        """Declination of the Earth in degrees. Extracts column 1 from earth_ra_dec_deg."""
+        ...
+
+    @property
+    def sun_ra_rad(self) -> npt.NDArray[np.float64]:
+        """Right Ascension of the Sun in radians. Extracts column 0 from sun_ra_dec_rad."""
+        ...
+
+    @property
+    def sun_dec_rad(self) -> npt.NDArray[np.float64]:
+        """Declination of the Sun in radians. Extracts column 1 from sun_ra_dec_rad."""
+        ...
+
+    @property
+    def moon_ra_rad(self) -> npt.NDArray[np.float64]:
+        """Right Ascension of the Moon in radians. Extracts column 0 from moon_ra_dec_rad."""
+        ...
+
+    @property
+    def moon_dec_rad(self) -> npt.NDArray[np.float64]:
+        """Declination of the Moon in radians. Extracts column 1 from moon_ra_dec_rad."""
+        ...
+
+    @property
+    def earth_ra_rad(self) -> npt.NDArray[np.float64]:
+        """Right Ascension of the Earth in radians. Extracts column 0 from earth_ra_dec_rad."""
+        ...
+
+    @property
+    def earth_dec_rad(self) -> npt.NDArray[np.float64]:
+        """Declination of the Earth in radians. Extracts column 1 from earth_ra_dec_rad."""
+        ...
+
+    def index(self, time: datetime) -> int:
+        """
+        Find the index of the closest timestamp to the given datetime.
+
+        Returns the index in the ephemeris timestamp array that is closest to the provided time.
+        This can be used to index into any of the ephemeris arrays (positions, velocities, etc.)
+
+        Args:
+            time: Python datetime object to find the closest match for
+
+        Returns:
+            Index of the closest timestamp
+
+        Raises:
+            ValueError: If no timestamps are available in the ephemeris
+
+        Example:
+            >>> from datetime import datetime
+            >>> eph = AlmanacEphemeris("current.alm", 1, ...)
+            >>> target_time = datetime(2024, 1, 15, 12, 0, 0)
+            >>> idx = eph.index(target_time)
+            >>> position = eph.gcrs_pv.position[idx]
+        """
+        ...
+
+    def fingerprint(self) -> str:
+        """
+        Stable hex digest identifying this ephemeris.
+
+        Hashes the source (elements, file path or site), the options, the time
+        grid and the computed GCRS states, so ephemerides built the same way
+        share a fingerprint across sessions. Ephemerides with equal
+        fingerprints compare equal, and the fingerprint can key caches of
+        constraint results or schedules.
+
+        Returns:
+            32-character hexadecimal digest
+        """
+        ...
+
+    def __eq__(self, other: object) -> bool: ...
+    def __hash__(self) -> int: ...
+    @property
+    def metadata(self) -> dict[str, Any]:
+        """
+        Source, time span, computed frames and Earth orientation state.
+
+        Keys start with ``type`` and the source fields of this class (e.g.
+        ``norad_id`` and ``tle_epoch``, or a file path), then ``polar_motion``,
+        ``begin``, ``end``, ``step_size``, ``n_times``, ``frames``,
+        ``eop_available``, ``polar_motion_applied``, ``fast_itrs``,
+        ``ut1_available`` and ``fingerprint``. Values are
+        JSON-serializable, with times as ISO 8601 strings, for provenance logs.
+        """
+        ...
+
+    @property
+    def provenance(self) -> Provenance:
+        """
+        Reproducibility record: library version, source and fingerprint,
+        element set, input and kernel file digests, Earth orientation and
+        leap-second data.
+        """
+        ...
+
+    def summary(self) -> str:
+        """Human-readable, multi-line description of ``metadata``."""
+        ...
+
+    def __repr__(self) -> str: ...
+    def __len__(self) -> int:
+        """Number of time steps"""
+        ...
+
+    def __getitem__(self, index: int) -> EphemerisSample:
+        """State at time step ``index``; negative indices count from the end"""
+        ...
+
+    def __iter__(self) -> Iterator[EphemerisSample]:
+        """Iterate over the time steps in order"""
+        ...
+
+    @property
+    def illumination_factor(self) -> npt.NDArray[np.float64]:
+        """Fraction of the solar disk visible from the observer at each time step
+
+        1.0 in sunlight, 0.0 in the Earth's umbra and fractional in the
+        penumbra, from the overlap of the apparent Sun and Earth disks.
+        """
+        ...
+
+    @property
+    def geodetic_rates(self) -> npt.NDArray[np.float64] | None:
+        """Geodetic rates from the ITRS velocity, shape (N, 3)
+
+        Columns are latitude rate (deg/s), longitude rate (deg/s) and height
+        rate (km/s). The longitude rate diverges at the poles.
+        """
+        ...
+
+    @property
+    def ground_speed(self) -> npt.NDArray[np.float64] | None:
+        """Speed of the sub-observer point over the WGS84 ellipsoid (km/s)"""
+        ...
+
+    @property
+    def sub_satellite_point(self) -> npt.NDArray[np.float64] | None:
+        """Geodetic (latitude, longitude) in degrees below the observer, shape (N, 2)"""
+        ...
+
+    @property
+    def sub_solar_point(self) -> npt.NDArray[np.float64]:
+        """(latitude, longitude) in degrees with the Sun at the zenith, shape (N, 2)"""
+        ...
+
+    @property
+    def nadir_solar_zenith_angle(self) -> npt.NDArray[np.float64] | None:
+        """Solar zenith angle at the sub-satellite point in degrees
+
+        The ground below the observer is daylit below 90.
+        """
+        ...
+
+    def moon_illumination(self, time_indices: list[int] | None = None) -> list[float]:
+        """
+        Calculate Moon illumination fraction for all ephemeris times.
+
+        Returns the fraction of the Moon's illuminated surface as seen from the
+        spacecraft observer (0.0 = new moon, 1.0 = full moon).
+
+        Args:
+            time_indices: Optional indices into ephemeris times (default: all times)
+
+        Returns:
+            List of Moon illumination fractions
+        """
+        ...
+
+    def access_to(
+        self,
+        lat: float,
+        lon: float,
+        max_off_nadir_deg: float,
+        min_sun_elev: float | None = None,
+    ) -> list[AccessOpportunity]:
+        """
+        Find imaging opportunities of a ground target.
+
+        A time is accessible when the target is above its local horizon (on
+        the visible side of the Earth's limb), within ``max_off_nadir_deg`` of
+        the spacecraft's nadir and, if ``min_sun_elev`` is given, the Sun is
+        at least that high at the target.
+
+        Args:
+            lat: Geodetic latitude of the target in degrees
+            lon: Longitude of the target in degrees, east positive
+            max_off_nadir_deg: Maximum off-nadir angle in degrees (0-90)
+            min_sun_elev: Minimum Sun elevation at the target in degrees
+                (default: no illumination requirement)
+
+        Returns:
+            Opportunities in time order, each summarised at its smallest
+            off-nadir angle
+
+        Raises:
+            ValueError: If the target location or an angle is out of range
+        """
+        ...
+
+    def get_body_pv(
+        self,
+        body: str,
+        spice_kernel: str | None = ...,
+        use_horizons: bool = ...,
+        source: str | None = ...,
+    ) -> PositionVelocityData:
+        """
+        Get position and velocity of a celestial body.
+
+        Args:
+            body: Name of the body (e.g., 'sun', 'moon', 'earth'), or a pseudo-body
+                ('EMB', 'SSB', 'SEL1', 'SEL2', 'EML1', 'EML2')
+            spice_kernel: Optional path to SPICE kernel
+            use_horizons: If True, fall back to JPL Horizons API when SPICE fails
+                (equivalent to ``source="auto"``)
+            source: Data source precedence: ``"spice"`` (loaded kernels only),
+                ``"horizons"`` (JPL Horizons only) or ``"auto"`` (SPICE, falling back
+                to Horizons with a ``UserWarning``). Defaults to ``"spice"`` unless
+                ``use_horizons`` is set.
+
+        Returns:
+            Position and velocity data for the requested body
+        """
+        ...
+
+    def get_bodies(
+        self,
+        bodies: list[str],
+        spice_kernel: str | None = ...,
+        use_horizons: bool = ...,
+        source: str | None = ...,
+    ) -> dict[str, PositionVelocityData]:
+        """
+        Get position and velocity of several celestial bodies in one pass.
+
+        Equivalent to calling ``get_body_pv`` for each body, but the SPICE
+        lookups and the observer positions are set up once for all bodies.
+
+        Args:
+            bodies: Body names or NAIF IDs, as accepted by ``get_body_pv``
+            spice_kernel: Optional path to SPICE kernel
+            use_horizons: If True, fall back to JPL Horizons API when SPICE fails
+                (equivalent to ``source="auto"``)
+            source: Data source precedence, as for ``get_body_pv``
+
+        Returns:
+            Dict mapping each requested body, as given, to its position and
+            velocity data
+        """
+        ...
+
+    def separation(self, a: Any, b: Any) -> npt.NDArray[np.float64]:
+        """
+        Angular separation between two targets at each time step.
+
+        Computed in Rust from observer-relative vectors, without astropy.
+
+        Args:
+            a, b: Each an ``(ra_deg, dec_deg)`` pair, a body name (``"sun"``,
+                ``"moon"`` and ``"earth"`` use the cached positions; other names
+                resolve as in ``get_body_pv``), observer-relative
+                ``PositionVelocityData`` (e.g. from ``get_body_pv``), or a
+                SkyCoord with a single position or one per time step (e.g.
+                ``ephem.sun``)
+
+        Returns:
+            Separation angles in degrees, one per time step
+
+        Raises:
+            TypeError: If an operand is not one of the accepted kinds
+            ValueError: If an operand has a different number of time steps
+        """
+        ...
+
+    def sun_angle(
+        self, ra_deg: float, dec_deg: float, time_indices: list[int] | None = ...
+    ) -> npt.NDArray[np.float64]:
+        """
+        Angle between a target and the Sun at each time step.
+
+        Args:
+            ra_deg: Target right ascension in degrees
+            dec_deg: Target declination in degrees
+            time_indices: Optional indices into the ephemeris times (default: all)
+
+        Returns:
+            Angles in degrees, one per selected time step
+        """
+        ...
+
+    def moon_angle(
+        self, ra_deg: float, dec_deg: float, time_indices: list[int] | None = ...
+    ) -> npt.NDArray[np.float64]:
+        """
+        Angle between a target and the Moon at each time step.
+
+        Args:
+            ra_deg: Target right ascension in degrees
+            dec_deg: Target declination in degrees
+            time_indices: Optional indices into the ephemeris times (default: all)
+
+        Returns:
+            Angles in degrees, one per selected time step
+        """
+        ...
+
+    def earth_limb_angle(
+        self, ra_deg: float, dec_deg: float, time_indices: list[int] | None = ...
+    ) -> npt.NDArray[np.float64]:
+        """
+        Angle of a target above the Earth's limb at each time step.
+
+        The angle from the Earth's center minus the Earth's angular radius, so
+        negative while the target is behind the Earth. No refraction or horizon
+        mask is applied.
+
+        Args:
+            ra_deg: Target right ascension in degrees
+            dec_deg: Target declination in degrees
+            time_indices: Optional indices into the ephemeris times (default: all)
+
+        Returns:
+            Angles in degrees, one per selected time step
+        """
+        ...
+
+    def geometry(
+        self,
+        ra_deg: float,
+        dec_deg: float,
+        time: datetime,
+        *,
+        saa_polygon: list[tuple[float, float]] | None = None,
+    ) -> ObservationGeometry:
+        """
+        Observation geometry of a target at the ephemeris step nearest ``time``.
+
+        Args:
+            ra_deg: Target right ascension in degrees
+            dec_deg: Target declination in degrees
+            time: Time of interest; the nearest ephemeris step is used
+            saa_polygon: Optional SAA region as (longitude, latitude) vertices in
+                degrees; sets ``in_saa``
+
+        Returns:
+            Sun, Moon and Earth-limb angles, plus altitude and airmass for ground
+            sites or eclipse, orbital phase and beta angle for orbiting observers
+        """
+        ...
+
+    def get_body(
+        self,
+        body: str,
+        spice_kernel: str | None = ...,
+        use_horizons: bool = ...,
+        source: str | None = ...,
+    ) -> Any:  # Returns astropy.coordinates.SkyCoord
+        """
+        Get SkyCoord for a celestial body.
+
+        Args:
+            body: Name of the body (e.g., 'sun', 'moon', 'earth'), or a pseudo-body
+                ('EMB', 'SSB', 'SEL1', 'SEL2', 'EML1', 'EML2')
+            spice_kernel: Optional path to SPICE kernel
+            use_horizons: If True, fall back to JPL Horizons API when SPICE fails
+                (equivalent to ``source="auto"``)
+            source: Data source precedence: ``"spice"`` (loaded kernels only),
+                ``"horizons"`` (JPL Horizons only) or ``"auto"`` (SPICE, falling back
+                to Horizons with a ``UserWarning``). Defaults to ``"spice"`` unless
+                ``use_horizons`` is set.
+
+        Returns:
+            astropy.coordinates.SkyCoord object
+        """
+        ...
+
+class RinexNavEphemeris(Ephemeris):
+    """
+    Ephemeris for one GPS or Galileo satellite from a RINEX navigation file.
+
+    The broadcast orbit model is evaluated in the Earth-fixed frame (treated
+    as ITRS) using, at each time, the message with the closest reference
+    epoch, and transformed to GCRS. Healthy messages are preferred.
+    """
+
+    def __init__(
+        self,
+        nav_path: str,
+        satellite: str,
+        begin: datetime,
+        end: datetime,
+        step_size: int = 60,
+        *,
+        polar_motion: bool = False,
+        time_scale: str = "UTC",
+    ) -> None:
+        """
+        Initialize an ephemeris for one satellite of a RINEX navigation file.
+
+        Args:
+            nav_path: Path to a RINEX 2 or 3 navigation file
+            satellite: Satellite identifier such as ``"G05"`` or ``"E11"``;
+                a bare number is taken as a GPS PRN
+            begin: Start time (naive datetime treated as UTC)
+            end: End time (naive datetime treated as UTC)
+            step_size: Time step in seconds (default: 60)
+            polar_motion: Whether to apply polar motion correction (default: False)
+            time_scale: "UTC" steps clock time and skips leap seconds; "TAI"
+                or "TT" step SI seconds through them (default: "UTC")
+
+        Raises:
+            IOError: If the navigation file cannot be read
+            ValueError: If the file cannot be parsed, the satellite is not
+                present, or the time range is more than 4 hours from every
+                navigation message
+        """
+        ...
+
+    @staticmethod
+    def satellites(nav_path: str) -> list[str]:
+        """List the GPS and Galileo satellites present in a navigation file"""
+        ...
+
+    @property
+    def nav_path(self) -> str:
+        """Path to the navigation file"""
+        ...
+
+    @property
+    def satellite(self) -> str:
+        """Satellite identifier, e.g. ``"G05"``"""
+        ...
+
+    @property
+    def system(self) -> str:
+        """Constellation name, ``"GPS"`` or ``"Galileo"``"""
+        ...
+
+    @property
+    def rinex_version(self) -> float:
+        """RINEX version of the navigation file"""
+        ...
+
+    @property
+    def n_messages(self) -> int:
+        """Number of navigation messages for this satellite"""
+        ...
+
+    @property
+    def message_epochs(self) -> list[datetime]:
+        """Reference epochs (toe) of the navigation messages (UTC)"""
+        ...
+
+    @property
+    def begin(self) -> datetime:
+        """Start time of ephemeris"""
+        ...
+
+    @property
+    def end(self) -> datetime:
+        """End time of ephemeris"""
+        ...
+
+    @property
+    def step_size(self) -> int:
+        """Time step in seconds"""
+        ...
+
+    @property
+    def polar_motion(self) -> bool:
+        """Whether polar motion correction is applied"""
+        ...
+
+    @property
+    def time_scale(self) -> str:
+        """Time scale in which the timestamp grid is uniform ("UTC", "TAI" or "TT")"""
+        ...
+
+    @property
+    def gcrs_pv(self) -> PositionVelocityData:
+        """Position and velocity data in GCRS frame (interpolated)"""
+        ...
+
+    @property
+    def teme_pv(self) -> PositionVelocityData:
+        """Position and velocity data in TEME frame (converted from GCRS)"""
+        ...
+
+    @property
+    def pef_pv(self) -> PositionVelocityData:
+        """Position and velocity data in the pseudo-Earth-fixed frame
+
+        TEME rotated by Greenwich mean sidereal time: ITRS without polar
+        motion, as used by legacy NORAD Earth-fixed products.
+        """
+        ...
+
+    @property
+    def itrs_pv(self) -> PositionVelocityData:
+        """Position and velocity data in ITRS (Earth-fixed) frame"""
+        ...
+
+    @property
+    def itrs(self) -> Any:  # Returns astropy.coordinates.SkyCoord
+        """SkyCoord object in ITRS frame"""
+        ...
+
+    @property
+    def gcrs(self) -> Any:  # Returns astropy.coordinates.SkyCoord
+        """SkyCoord object in GCRS frame"""
+        ...
+
+    @property
+    def earth(self) -> Any:  # Returns astropy.coordinates.SkyCoord
+        """SkyCoord object for Earth position relative to spacecraft"""
+        ...
+
+    @property
+    def latitude(self) -> Any:  # Returns astropy.units.Quantity
+        """Geodetic latitude as an astropy Quantity array (degrees), one per timestamp"""
+        ...
+
+    @property
+    def latitude_deg(self) -> npt.NDArray[np.float64]:
+        """Geodetic latitude in degrees as a raw NumPy array (one per timestamp)"""
+        ...
+
+    @property
+    def latitude_rad(self) -> npt.NDArray[np.float64]:
+        """Geodetic latitude in radians as a raw NumPy array (one per timestamp)"""
+        ...
+
+    @property
+    def longitude(self) -> Any:  # Returns astropy.units.Quantity
+        """Geodetic longitude as an astropy Quantity array (degrees), one per timestamp"""
+        ...
+
+    @property
+    def longitude_deg(self) -> npt.NDArray[np.float64]:
+        """Geodetic longitude in degrees as a raw NumPy array (one per timestamp)"""
+        ...
+
+    @property
+    def longitude_rad(self) -> npt.NDArray[np.float64]:
+        """Geodetic longitude in radians as a raw NumPy array (one per timestamp)"""
+        ...
+
+    @property
+    def height(self) -> Any:  # Returns astropy.units.Quantity
+        """Height above the WGS84 ellipsoid as an astropy Quantity array (meters), one per timestamp"""
+        ...
+
+    @property
+    def height_m(self) -> npt.NDArray[np.float64]:
+        """Height above the WGS84 ellipsoid as a raw NumPy array in meters (one per timestamp)"""
+        ...
+
+    @property
+    def height_km(self) -> npt.NDArray[np.float64]:
+        """Height above the WGS84 ellipsoid as a raw NumPy array in kilometers (one per timestamp)"""
+        ...
+
+    @property
+    def geomagnetic_latitude_deg(self) -> npt.NDArray[np.float64]:
+        """Centred-dipole (IGRF) geomagnetic latitude in degrees (one per timestamp)"""
+        ...
+
+    @property
+    def geomagnetic_longitude_deg(self) -> npt.NDArray[np.float64]:
+        """Centred-dipole (IGRF) geomagnetic longitude in degrees (one per timestamp)"""
+        ...
+
+    @property
+    def cutoff_rigidity_gv(self) -> npt.NDArray[np.float64]:
+        """Störmer vertical geomagnetic cutoff rigidity in GV (one per timestamp)"""
+        ...
+
+    @property
+    def sun(self) -> Any:  # Returns astropy.coordinates.SkyCoord
+        """SkyCoord object for Sun position relative to spacecraft"""
+        ...
+
+    @property
+    def moon(self) -> Any:  # Returns astropy.coordinates.SkyCoord
+        """SkyCoord object for Moon position relative to spacecraft"""
+        ...
+
+    @property
+    def timestamp(self) -> npt.NDArray[np.datetime64]:
+        """
+        Array of timestamps for the ephemeris.
+
+        Returns a NumPy array of datetime objects (not a list) for efficient indexing.
+        This property is cached for performance - repeated access is ~90x faster.
+        """
+        ...
+
+    @property
+    def sun_pv(self) -> PositionVelocityData:
+        """Sun position and velocity in GCRS frame"""
+        ...
+
+    @property
+    def moon_pv(self) -> PositionVelocityData:
+        """Moon position and velocity in GCRS frame"""
+        ...
+
+    @property
+    def obsgeoloc(self) -> npt.NDArray[np.float64]:
+        """
+        Observer geocentric location (GCRS position).
+
+        Returns position in km, compatible with astropy's GCRS frame obsgeoloc parameter.
+        Shape: (N, 3) where N is the number of timestamps.
+        """
+        ...
+
+    @property
+    def obsgeovel(self) -> npt.NDArray[np.float64]:
+        """
+        Observer geocentric velocity (GCRS velocity).
+
+        Returns velocity in km/s, compatible with astropy's GCRS frame obsgeovel parameter.
+        Shape: (N, 3) where N is the number of timestamps.
+        """
+        ...
+
+    def radec_to_altaz(
+        self,
+        ra_deg: float,
+        dec_deg: float,
+        time_indices: list[int] | None = None,
+        *,
+        polar_motion: bool | None = None,
+    ) -> npt.NDArray[np.float64]:
+        """Topocentric altitude/azimuth for given RA/Dec (deg) at selected times.
+
+        ``polar_motion`` overrides the ephemeris's own setting for this call.
+        """
+        ...
+
+    def radec_to_altaz_batch(
+        self,
+        ra_deg: Sequence[float] | npt.NDArray[np.float64],
+        dec_deg: Sequence[float] | npt.NDArray[np.float64],
+        time_indices: list[int] | None = None,
+        *,
+        polar_motion: bool | None = None,
+    ) -> npt.NDArray[np.float64]:
+        """Altitude/azimuth for many targets, shape (n_targets, n_times, 2).
+
+        ``polar_motion`` overrides the ephemeris's own setting for this call.
+        """
+        ...
+
+    def calculate_airmass(
+        self,
+        ra_deg: float,
+        dec_deg: float,
+        time_indices: list[int] | None = None,
+    ) -> list[float]:
+        """Calculate airmass for given RA/Dec (deg) at selected times.
+
+        Returns airmass values (1.0 at zenith, ~2.0 at 30° altitude, infinity below horizon).
+        Accounts for observer height using atmospheric scale height correction.
+        """
+        ...
+
+    def airmass_batch(
+        self,
+        ras: Sequence[float] | npt.NDArray[np.float64],
+        decs: Sequence[float] | npt.NDArray[np.float64],
+        time_indices: list[int] | None = None,
+    ) -> npt.NDArray[np.float64]:
+        """Calculate airmass for many RA/Dec targets (deg) in one call.
+
+        Returns an array of shape (n_targets, n_times) from the vectorized
+        Kasten pipeline used by the airmass constraint (no refraction or
+        aberration); infinity below the horizon.
+        """
+        ...
+
+    @property
+    def sun_radius(self) -> Any:  # Returns astropy.units.Quantity
+        """
+        Angular radius of the Sun with astropy units (degrees).
+
+        Returns an astropy Quantity with units of degrees.
+        This property is cached for performance.
+        """
+        ...
+
+    @property
+    def sun_radius_deg(self) -> npt.NDArray[np.float64]:
+        """
+        Angular radius of the Sun as seen from the spacecraft (in degrees).
+
+        Returns a NumPy array of angular radii for each timestamp.
+        Angular radius = arcsin(physical_radius / distance)
+        This property is cached for performance.
+        """
+        ...
+
+    @property
+    def moon_radius(self) -> Any:  # Returns astropy.units.Quantity
+        """
+        Angular radius of the Moon with astropy units (degrees).
+
+        Returns an astropy Quantity with units of degrees.
+        This property is cached for performance.
+        """
+        ...
+
+    @property
+    def moon_radius_deg(self) -> npt.NDArray[np.float64]:
+        """
+        Angular radius of the Moon as seen from the spacecraft (in degrees).
+
+        Returns a NumPy array of angular radii for each timestamp.
+        Angular radius = arcsin(physical_radius / distance)
+        This property is cached for performance.
+        """
+        ...
+
+    @property
+    def earth_radius(self) -> Any:  # Returns astropy.units.Quantity
+        """
+        Angular radius of the Earth with astropy units (degrees).
+
+        Returns an astropy Quantity with units of degrees.
+        This property is cached for performance.
+        """
+        ...
+
+    @property
+    def earth_radius_deg(self) -> npt.NDArray[np.float64]:
+        """
+        Angular radius of the Earth as seen from the spacecraft (in degrees).
+
+        Returns a NumPy array of angular radii for each timestamp.
+        Angular radius = arcsin(physical_radius / distance)
+        This property is cached for performance.
+        """
+        ...
+
+    @property
+    def sun_radius_rad(self) -> npt.NDArray[np.float64]:
+        """
+        Angular radius of the Sun as seen from the spacecraft (in radians).
+
+        Returns a NumPy array of angular radii for each timestamp.
+        Angular radius = arcsin(physical_radius / distance)
+        This property is cached for performance.
+        """
+        ...
+
+    @property
+    def moon_radius_rad(self) -> npt.NDArray[np.float64]:
+        """
+        Angular radius of the Moon as seen from the spacecraft (in radians).
+
+        Returns a NumPy array of angular radii for each timestamp.
+        Angular radius = arcsin(physical_radius / distance)
+        This property is cached for performance.
+        """
+        ...
+
+    @property
+    def earth_radius_rad(self) -> npt.NDArray[np.float64]:
+        """
+        Angular radius of the Earth as seen from the spacecraft (in radians).
+
+        Returns a NumPy array of angular radii for each timestamp.
+        Angular radius = arcsin(physical_radius / distance)
+        This property is cached for performance.
+        """
+        ...
+
+    @property
+    def sun_ra_dec_deg(self) -> npt.NDArray[np.float64]:
+        """
+        Right Ascension and Declination of the Sun in degrees.
+
+        Returns an Nx2 NumPy array where column 0 is RA and column 1 is Dec.
+        This property is cached for performance.
+        """
+        ...
+
+    @property
+    def moon_ra_dec_deg(self) -> npt.NDArray[np.float64]:
+        """
+        Right Ascension and Declination of the Moon in degrees.
+
+        Returns an Nx2 NumPy array where column 0 is RA and column 1 is Dec.
+        This property is cached for performance.
+        """
+        ...
+
+    @property
+    def earth_ra_dec_deg(self) -> npt.NDArray[np.float64]:
+        """
+        Right Ascension and Declination of the Earth in degrees.
+
+        Returns an Nx2 NumPy array where column 0 is RA and column 1 is Dec.
+        This property is cached for performance.
+        """
+        ...
+
+    @property
+    def sun_ra_dec_rad(self) -> npt.NDArray[np.float64]:
+        """
+        Right Ascension and Declination of the Sun in radians.
+
+        Returns an Nx2 NumPy array where column 0 is RA and column 1 is Dec.
+        This property is cached for performance.
+        """
+        ...
+
+    @property
+    def moon_ra_dec_rad(self) -> npt.NDArray[np.float64]:
+        """
+        Right Ascension and Declination of the Moon in radians.
+
+        Returns an Nx2 NumPy array where column 0 is RA and column 1 is Dec.
+        This property is cached for performance.
+        """
+        ...
+
+    @property
+    def earth_ra_dec_rad(self) -> npt.NDArray[np.float64]:
+        """
+        Right Ascension and Declination of the Earth in radians.
+
+        Returns an Nx2 NumPy array where column 0 is RA and column 1 is Dec.
+        This property is cached for performance.
+        """
+        ...
+
+    @property
+    def sun_ra_deg(self) -> npt.NDArray[np.float64]:
+        """Right Ascension of the Sun in degrees. Extracts column 0 from sun_ra_dec_deg."""
+        ...
+
+    @property
+    def sun_dec_deg(self) -> npt.NDArray[np.float64]:
+        """Declination of the Sun in degrees. Extracts column 1 from sun_ra_dec_deg."""
+        ...
+
+    @property
+    def moon_ra_deg(self) -> npt.NDArray[np.float64]:
+        """Right Ascension of the Moon in degrees. Extracts column 0 from moon_ra_dec_deg."""
+        ...
+
+    @property
+    def moon_dec_deg(self) -> npt.NDArray[np.float64]:
+        """Declination of the Moon in degrees. Extracts column 1 from moon_ra_dec_deg."""
+        ...
+
+    @property
+    def earth_ra_deg(self) -> npt.NDArray[np.float64]:
+        """Right Ascension of the Earth in degrees. Extracts column 0 from earth_ra_dec_deg."""
+        ...
+
+    @property
+    def earth_dec_deg(self) -> npt.NDArray[np.float64]:
+        """Declination of the Earth in degrees. Extracts column 1 from earth_ra_dec_deg."""
+        ...
+
+    @property
+    def sun_ra_rad(self) -> npt.NDArray[np.float64]:
+        """Right Ascension of the Sun in radians. Extracts column 0 from sun_ra_dec_rad."""
+        ...
+
+    @property
+    def sun_dec_rad(self) -> npt.NDArray[np.float64]:
+        """Declination of the Sun in radians. Extracts column 1 from sun_ra_dec_rad."""
+        ...
+
+    @property
+    def moon_ra_rad(self) -> npt.NDArray[np.float64]:
+        """Right Ascension of the Moon in radians. Extracts column 0 from moon_ra_dec_rad."""
+        ...
+
+    @property
+    def moon_dec_rad(self) -> npt.NDArray[np.float64]:
+        """Declination of the Moon in radians. Extracts column 1 from moon_ra_dec_rad."""
+        ...
+
+    @property
+    def earth_ra_rad(self) -> npt.NDArray[np.float64]:
+        """Right Ascension of the Earth in radians. Extracts column 0 from earth_ra_dec_rad."""
+        ...
+
+    @property
+    def earth_dec_rad(self) -> npt.NDArray[np.float64]:
+        """Declination of the Earth in radians. Extracts column 1 from earth_ra_dec_rad."""
+        ...
+
+    def index(self, time: datetime) -> int:
+        """
+        Find the index of the closest timestamp to the given datetime.
+
+        Returns the index in the ephemeris timestamp array that is closest to the provided time.
+        This can be used to index into any of the ephemeris arrays (positions, velocities, etc.)
+
+        Args:
+            time: Python datetime object to find the closest match for
+
+        Returns:
+            Index of the closest timestamp
+
+        Raises:
+            ValueError: If no timestamps are available in the ephemeris
+
+        Example:
+            >>> from datetime import datetime
+            >>> eph = RinexNavEphemeris("brdc0010.24n", "G05", ...)
+            >>> target_time = datetime(2024, 1, 15, 12, 0, 0)
+            >>> idx = eph.index(target_time)
+            >>> position = eph.gcrs_pv.position[idx]
+        """
+        ...
+
+    def fingerprint(self) -> str:
+        """
+        Stable hex digest identifying this ephemeris.
+
+        Hashes the source (elements, file path or site), the options, the time
+        grid and the computed GCRS states, so ephemerides built the same way
+        share a fingerprint across sessions. Ephemerides with equal
+        fingerprints compare equal, and the fingerprint can key caches of
+        constraint results or schedules.
+
+        Returns:
+            32-character hexadecimal digest
+        """
+        ...
+
+    def __eq__(self, other: object) -> bool: ...
+    def __hash__(self) -> int: ...
+    @property
+    def metadata(self) -> dict[str, Any]:
+        """
+        Source, time span, computed frames and Earth orientation state.
+
+        Keys start with ``type`` and the source fields of this class (e.g.
+        ``norad_id`` and ``tle_epoch``, or a file path), then ``polar_motion``,
+        ``begin``, ``end``, ``step_size``, ``n_times``, ``frames``,
+        ``eop_available``, ``polar_motion_applied``, ``fast_itrs``,
+        ``ut1_available`` and ``fingerprint``. Values are
+        JSON-serializable, with times as ISO 8601 strings, for provenance logs.
+        """
+        ...
+
+    @property
+    def provenance(self) -> Provenance:
+        """
+        Reproducibility record: library version, source and fingerprint,
+        element set, input and kernel file digests, Earth orientation and
+        leap-second data.
+        """
+        ...
+
+    def summary(self) -> str:
+        """Human-readable, multi-line description of ``metadata``."""
+        ...
+
+    def __repr__(self) -> str: ...
+    def __len__(self) -> int:
+        """Number of time steps"""
+        ...
+
+    def __getitem__(self, index: int) -> EphemerisSample:
+        """State at time step ``index``; negative indices count from the end"""
+        ...
+
+    def __iter__(self) -> Iterator[EphemerisSample]:
+        """Iterate over the time steps in order"""
+        ...
+
+    @property
+    def illumination_factor(self) -> npt.NDArray[np.float64]:
+        """Fraction of the solar disk visible from the observer at each time step
+
+        1.0 in sunlight, 0.0 in the Earth's umbra and fractional in the
+        penumbra, from the overlap of the apparent Sun and Earth disks.
+        """
+        ...
+
+    @property
+    def geodetic_rates(self) -> npt.NDArray[np.float64] | None:
+        """Geodetic rates from the ITRS velocity, shape (N, 3)
+
+        Columns are latitude rate (deg/s), longitude rate (deg/s) and height
+        rate (km/s). The longitude rate diverges at the poles.
+        """
+        ...
+
+    @property
+    def ground_speed(self) -> npt.NDArray[np.float64] | None:
+        """Speed of the sub-observer point over the WGS84 ellipsoid (km/s)"""
+        ...
+
+    @property
+    def sub_satellite_point(self) -> npt.NDArray[np.float64] | None:
+        """Geodetic (latitude, longitude) in degrees below the observer, shape (N, 2)"""
+        ...
+
+    @property
+    def sub_solar_point(self) -> npt.NDArray[np.float64]:
+        """(latitude, longitude) in degrees with the Sun at the zenith, shape (N, 2)"""
+        ...
+
+    @property
+    def nadir_solar_zenith_angle(self) -> npt.NDArray[np.float64] | None:
+        """Solar zenith angle at the sub-satellite point in degrees
+
+        The ground below the observer is daylit below 90.
+        """
+        ...
+
+    def moon_illumination(self, time_indices: list[int] | None = None) -> list[float]:
+        """
+        Calculate Moon illumination fraction for all ephemeris times.
+
+        Returns the fraction of the Moon's illuminated surface as seen from the
+        spacecraft observer (0.0 = new moon, 1.0 = full moon).
+
+        Args:
+            time_indices: Optional indices into ephemeris times (default: all times)
+
+        Returns:
+            List of Moon illumination fractions
+        """
+        ...
+
+    def access_to(
+        self,
+        lat: float,
+        lon: float,
+        max_off_nadir_deg: float,
+        min_sun_elev: float | None = None,
+    ) -> list[AccessOpportunity]:
+        """
+        Find imaging opportunities of a ground target.
+
+        A time is accessible when the target is above its local horizon (on
+        the visible side of the Earth's limb), within ``max_off_nadir_deg`` of
+        the spacecraft's nadir and, if ``min_sun_elev`` is given, the Sun is
+        at least that high at the target.
+
+        Args:
+            lat: Geodetic latitude of the target in degrees
+            lon: Longitude of the target in degrees, east positive
+            max_off_nadir_deg: Maximum off-nadir angle in degrees (0-90)
+            min_sun_elev: Minimum Sun elevation at the target in degrees
+                (default: no illumination requirement)
+
+        Returns:
+            Opportunities in time order, each summarised at its smallest
+            off-nadir angle
+
+        Raises:
+            ValueError: If the target location or an angle is out of range
+        """
+        ...
+
+    def get_body_pv(
+        self,
+        body: str,
+        spice_kernel: str | None = ...,
+        use_horizons: bool = ...,
+        source: str | None = ...,
+    ) -> PositionVelocityData:
+        """
+        Get position and velocity of a celestial body.
+
+        Args:
+            body: Name of the body (e.g., 'sun', 'moon', 'earth'), or a pseudo-body
+                ('EMB', 'SSB', 'SEL1', 'SEL2', 'EML1', 'EML2')
+            spice_kernel: Optional path to SPICE kernel
+            use_horizons: If True, fall back to JPL Horizons API when SPICE fails
+                (equivalent to ``source="auto"``)
+            source: Data source precedence: ``"spice"`` (loaded kernels only),
+                ``"horizons"`` (JPL Horizons only) or ``"auto"`` (SPICE, falling back
+                to Horizons with a ``UserWarning``). Defaults to ``"spice"`` unless
+                ``use_horizons`` is set.
+
+        Returns:
+            Position and velocity data for the requested body
+        """
+        ...
+
+    def get_bodies(
+        self,
+        bodies: list[str],
+        spice_kernel: str | None = ...,
+        use_horizons: bool = ...,
+        source: str | None = ...,
+    ) -> dict[str, PositionVelocityData]:
+        """
+        Get position and velocity of several celestial bodies in one pass.
+
+        Equivalent to calling ``get_body_pv`` for each body, but the SPICE
+        lookups and the observer positions are set up once for all bodies.
+
+        Args:
+            bodies: Body names or NAIF IDs, as accepted by ``get_body_pv``
+            spice_kernel: Optional path to SPICE kernel
+            use_horizons: If True, fall back to JPL Horizons API when SPICE fails
+                (equivalent to ``source="auto"``)
+            source: Data source precedence, as for ``get_body_pv``
+
+        Returns:
+            Dict mapping each requested body, as given, to its position and
+            velocity data
+        """
+        ...
+
+    def separation(self, a: Any, b: Any) -> npt.NDArray[np.float64]:
+        """
+        Angular separation between two targets at each time step.
+
+        Computed in Rust from observer-relative vectors, without astropy.
+
+        Args:
+            a, b: Each an ``(ra_deg, dec_deg)`` pair, a body name (``"sun"``,
+                ``"moon"`` and ``"earth"`` use the cached positions; other names
+                resolve as in ``get_body_pv``), observer-relative
+                ``PositionVelocityData`` (e.g. from ``get_body_pv``), or a
+                SkyCoord with a single position or one per time step (e.g.
+                ``ephem.sun``)
+
+        Returns:
+            Separation angles in degrees, one per time step
+
+        Raises:
+            TypeError: If an operand is not one of the accepted kinds
+            ValueError: If an operand has a different number of time steps
+        """
+        ...
+
+    def sun_angle(
+        self, ra_deg: float, dec_deg: float, time_indices: list[int] | None = ...
+    ) -> npt.NDArray[np.float64]:
+        """
+        Angle between a target and the Sun at each time step.
+
+        Args:
+            ra_deg: Target right ascension in degrees
+            dec_deg: Target declination in degrees
+            time_indices: Optional indices into the ephemeris times (default: all)
+
+        Returns:
+            Angles in degrees, one per selected time step
+        """
+        ...
+
+    def moon_angle(
+        self, ra_deg: float, dec_deg: float, time_indices: list[int] | None = ...
+    ) -> npt.NDArray[np.float64]:
+        """
+        Angle between a target and the Moon at each time step.
+
+        Args:
+            ra_deg: Target right ascension in degrees
+            dec_deg: Target declination in degrees
+            time_indices: Optional indices into the ephemeris times (default: all)
+
+        Returns:
+            Angles in degrees, one per selected time step
+        """
+        ...
+
+    def earth_limb_angle(
+        self, ra_deg: float, dec_deg: float, time_indices: list[int] | None = ...
+    ) -> npt.NDArray[np.float64]:
+        """
+        Angle of a target above the Earth's limb at each time step.
+
+        The angle from the Earth's center minus the Earth's angular radius, so
+        negative while the target is behind the Earth. No refraction or horizon
+        mask is applied.
+
+        Args:
+            ra_deg: Target right ascension in degrees
+            dec_deg: Target declination in degrees
+            time_indices: Optional indices into the ephemeris times (default: all)
+
+        Returns:
+            Angles in degrees, one per selected time step
+        """
+        ...
+
+    def geometry(
+        self,
+        ra_deg: float,
+        dec_deg: float,
+        time: datetime,
+        *,
+        saa_polygon: list[tuple[float, float]] | None = None,
+    ) -> ObservationGeometry:
+        """
+        Observation geometry of a target at the ephemeris step nearest ``time``.
+
+        Args:
+            ra_deg: Target right ascension in degrees
+            dec_deg: Target declination in degrees
+            time: Time of interest; the nearest ephemeris step is used
+            saa_polygon: Optional SAA region as (longitude, latitude) vertices in
+                degrees; sets ``in_saa``
+
+        Returns:
+            Sun, Moon and Earth-limb angles, plus altitude and airmass for ground
+            sites or eclipse, orbital phase and beta angle for orbiting observers
+        """
+        ...
+
+    def get_body(
+        self,
+        body: str,
+        spice_kernel: str | None = ...,
+        use_horizons: bool = ...,
+        source: str | None = ...,
+    ) -> Any:  # Returns astropy.coordinates.SkyCoord
+        """
+        Get SkyCoord for a celestial body.
+
+        Args:
+            body: Name of the body (e.g., 'sun', 'moon', 'earth'), or a pseudo-body
+                ('EMB', 'SSB', 'SEL1', 'SEL2', 'EML1', 'EML2')
+            spice_kernel: Optional path to SPICE kernel
+            use_horizons: If True, fall back to JPL Horizons API when SPICE fails
+                (equivalent to ``source="auto"``)
+            source: Data source precedence: ``"spice"`` (loaded kernels only),
+                ``"horizons"`` (JPL Horizons only) or ``"auto"`` (SPICE, falling back
+                to Horizons with a ``UserWarning``). Defaults to ``"spice"`` unless
+                ``use_horizons`` is set.
+
+        Returns:
+            astropy.coordinates.SkyCoord object
+        """
+        ...
+
+class ArrayEphemeris(Ephemeris):
+    """
+    Ephemeris wrapping user-supplied state vectors.
+
+    Gives arbitrary position/velocity arrays (for example from an external
+    propagator) the full ephemeris interface. States given in GCRS, ITRS, TEME
+    or PEF are transformed to the other frames, and optionally resampled onto
+    a regular grid with Hermite or Lagrange interpolation.
+    """
+
+    def __init__(
+        self,
+        times: list[datetime] | npt.NDArray[np.datetime64],
+        states: npt.ArrayLike,
+        begin: datetime | None = None,
+        end: datetime | None = None,
+        step_size: int | None = None,
+        *,
+        frame: str = "GCRS",
+        polar_motion: bool = False,
+        fast_itrs: bool = False,
+        time_scale: str = "UTC",
+        interpolation: str = "hermite",
+        interpolation_window: int | None = None,
+        max_gap: float | None = None,
+    ) -> None:
+        """
+        Initialize an ephemeris from arrays of times and states.
+
+        Args:
+            times: Strictly increasing times (naive datetimes treated as UTC)
+            states: State vectors of shape (N, 6); position in km and velocity
+                in km/s
+            begin: Start of the resampled grid (default: first input time)
+            end: End of the resampled grid (default: last input time)
+            step_size: Grid step in seconds. When ``begin``, ``end`` and
+                ``step_size`` are all omitted the input times are used as-is;
+                otherwise defaults to 60
+            frame: Frame of ``states``: ``"GCRS"``, ``"ITRS"``, ``"TEME"`` or
+                ``"PEF"``
+            polar_motion: Whether to apply polar motion correction (default: False)
+            fast_itrs: Rotate GCRS to ITRS without the UT1-UTC correction
+                (at most 0.0038 deg of longitude) or polar motion, about four
+                times faster with EOP data loaded, for large grids such as
+                SAA checks (default: False)
+            time_scale: Scale of a resampled grid: "UTC" steps clock time and
+                skips leap seconds; "TAI" or "TT" step SI seconds through them
+                (default: "UTC")
+            interpolation: Resampling scheme, ``"hermite"`` or ``"lagrange"``
+                (default: ``"hermite"``)
+            interpolation_window: Samples per interpolant (default: 2 for
+                Hermite, i.e. cubic; 8 for Lagrange)
+            max_gap: Largest input sample spacing in seconds to interpolate
+                across; grid points inside a wider gap raise ValueError
+                (default: no limit)
+
+        Raises:
+            ValueError: If the shapes do not match, times are not increasing,
+                the frame or interpolation options are invalid, the grid
+                exceeds the input time range or crosses a gap wider than
+                max_gap
+        """
+        ...
+
+    @property
+    def source_frame(self) -> str:
+        """Frame of the input states"""
+        ...
+
+    @property
+    def source_pv(self) -> PositionVelocityData:
+        """Input position and velocity before resampling"""
+        ...
+
+    @property
+    def source_timestamp(self) -> list[datetime]:
+        """Input timestamps before resampling"""
+        ...
+
+    @property
+    def interpolation(self) -> str:
+        """Resampling scheme: ``"hermite"`` or ``"lagrange"``"""
+        ...
+
+    @property
+    def interpolation_window(self) -> int:
+        """Number of input samples each interpolant passes through"""
+        ...
+
+    @property
+    def max_gap(self) -> float | None:
+        """Largest input sample spacing in seconds that may be interpolated across"""
+        ...
+
+    @property
+    def max_interpolation_error(self) -> float | None:
+        """
+        Estimated largest position interpolation error in km.
+
+        The interpolant is compared with one through an extra sample at the
+        midpoint of each input interval in the ephemeris span. None without
+        enough samples.
+        """
+        ...
+
+    @property
+    def begin(self) -> datetime:
+        """Start time of ephemeris"""
+        ...
+
+    @property
+    def end(self) -> datetime:
+        """End time of ephemeris"""
+        ...
+
+    @property
+    def step_size(self) -> int:
+        """Time step in seconds"""
+        ...
+
+    @property
+    def polar_motion(self) -> bool:
+        """Whether polar motion correction is applied"""
+        ...
+
+    @property
+    def fast_itrs(self) -> bool:
+        """Whether ITRS skips the UT1-UTC correction and polar motion"""
+        ...
+
+    @property
+    def time_scale(self) -> str:
+        """Time scale in which the timestamp grid is uniform ("UTC", "TAI" or "TT")"""
+        ...
+
+    @property
+    def gcrs_pv(self) -> PositionVelocityData:
+        """Position and velocity data in GCRS frame (interpolated)"""
+        ...
+
+    @property
+    def teme_pv(self) -> PositionVelocityData:
+        """Position and velocity data in TEME frame (converted from GCRS)"""
+        ...
+
+    @property
+    def pef_pv(self) -> PositionVelocityData:
+        """Position and velocity data in the pseudo-Earth-fixed frame
+
+        TEME rotated by Greenwich mean sidereal time: ITRS without polar
+        motion, as used by legacy NORAD Earth-fixed products.
+        """
+        ...
+
+    @property
+    def itrs_pv(self) -> PositionVelocityData:
+        """Position and velocity data in ITRS (Earth-fixed) frame"""
+        ...
+
+    @property
+    def itrs(self) -> Any:  # Returns astropy.coordinates.SkyCoord
+        """SkyCoord object in ITRS frame"""
+        ...
+
+    @property
+    def gcrs(self) -> Any:  # Returns astropy.coordinates.SkyCoord
+        """SkyCoord object in GCRS frame"""
+        ...
+
+    @property
+    def earth(self) -> Any:  # Returns astropy.coordinates.SkyCoord
+        """SkyCoord object for Earth position relative to spacecraft"""
+        ...
+
+    @property
+    def latitude(self) -> Any:  # Returns astropy.units.Quantity
+        """Geodetic latitude as an astropy Quantity array (degrees), one per timestamp"""
+        ...
+
+    @property
+    def latitude_deg(self) -> npt.NDArray[np.float64]:
+        """Geodetic latitude in degrees as a raw NumPy array (one per timestamp)"""
+        ...
+
+    @property
+    def latitude_rad(self) -> npt.NDArray[np.float64]:
+        """Geodetic latitude in radians as a raw NumPy array (one per timestamp)"""
+        ...
+
+    @property
+    def longitude(self) -> Any:  # Returns astropy.units.Quantity
+        """Geodetic longitude as an astropy Quantity array (degrees), one per timestamp"""
+        ...
+
+    @property
+    def longitude_deg(self) -> npt.NDArray[np.float64]:
+        """Geodetic longitude in degrees as a raw NumPy array (one per timestamp)"""
+        ...
+
+    @property
+    def longitude_rad(self) -> npt.NDArray[np.float64]:
+        """Geodetic longitude in radians as a raw NumPy array (one per timestamp)"""
+        ...
+
+    @property
+    def height(self) -> Any:  # Returns astropy.units.Quantity
+        """Height above the WGS84 ellipsoid as an astropy Quantity array (meters), one per timestamp"""
+        ...
+
+    @property
+    def height_m(self) -> npt.NDArray[np.float64]:
+        """Height above the WGS84 ellipsoid as a raw NumPy array in meters (one per timestamp)"""
+        ...
+
+    @property
+    def height_km(self) -> npt.NDArray[np.float64]:
+        """Height above the WGS84 ellipsoid as a raw NumPy array in kilometers (one per timestamp)"""
+        ...
+
+    @property
+    def geomagnetic_latitude_deg(self) -> npt.NDArray[np.float64]:
+        """Centred-dipole (IGRF) geomagnetic latitude in degrees (one per timestamp)"""
+        ...
+
+    @property
+    def geomagnetic_longitude_deg(self) -> npt.NDArray[np.float64]:
+        """Centred-dipole (IGRF) geomagnetic longitude in degrees (one per timestamp)"""
+        ...
+
+    @property
+    def cutoff_rigidity_gv(self) -> npt.NDArray[np.float64]:
+        """Störmer vertical geomagnetic cutoff rigidity in GV (one per timestamp)"""
+        ...
+
+    @property
+    def sun(self) -> Any:  # Returns astropy.coordinates.SkyCoord
+        """SkyCoord object for Sun position relative to spacecraft"""
+        ...
+
+    @property
+    def moon(self) -> Any:  # Returns astropy.coordinates.SkyCoord
+        """SkyCoord object for Moon position relative to spacecraft"""
+        ...
+
+    @property
+    def timestamp(self) -> npt.NDArray[np.datetime64]:
+        """
+        Array of timestamps for the ephemeris.
+
+        Returns a NumPy array of datetime objects (not a list) for efficient indexing.
+        This property is cached for performance - repeated access is ~90x faster.
+        """
+        ...
+
+    @property
+    def sun_pv(self) -> PositionVelocityData:
+        """Sun position and velocity in GCRS frame"""
+        ...
+
+    @property
+    def moon_pv(self) -> PositionVelocityData:
+        """Moon position and velocity in GCRS frame"""
+        ...
+
+    @property
+    def obsgeoloc(self) -> npt.NDArray[np.float64]:
+        """
+        Observer geocentric location (GCRS position).
+
+        Returns position in km, compatible with astropy's GCRS frame obsgeoloc parameter.
+        Shape: (N, 3) where N is the number of timestamps.
+        """
+        ...
+
+    @property
+    def obsgeovel(self) -> npt.NDArray[np.float64]:
+        """
+        Observer geocentric velocity (GCRS velocity).
+
+        Returns velocity in km/s, compatible with astropy's GCRS frame obsgeovel parameter.
+        Shape: (N, 3) where N is the number of timestamps.
+        """
+        ...
+
+    def radec_to_altaz(
+        self,
+        ra_deg: float,
+        dec_deg: float,
+        time_indices: list[int] | None = None,
+        *,
+        polar_motion: bool | None = None,
+    ) -> npt.NDArray[np.float64]:
+        """Topocentric altitude/azimuth for given RA/Dec (deg) at selected times.
+
+        ``polar_motion`` overrides the ephemeris's own setting for this call.
+        """
+        ...
+
+    def radec_to_altaz_batch(
+        self,
+        ra_deg: Sequence[float] | npt.NDArray[np.float64],
+        dec_deg: Sequence[float] | npt.NDArray[np.float64],
+        time_indices: list[int] | None = None,
+        *,
+        polar_motion: bool | None = None,
+    ) -> npt.NDArray[np.float64]:
+        """Altitude/azimuth for many targets, shape (n_targets, n_times, 2).
+
+        ``polar_motion`` overrides the ephemeris's own setting for this call.
+        """
+        ...
+
+    def calculate_airmass(
+        self,
+        ra_deg: float,
+        dec_deg: float,
+        time_indices: list[int] | None = None,
+    ) -> list[float]:
+        """Calculate airmass for given RA/Dec (deg) at selected times.
+
+        Returns airmass values (1.0 at zenith, ~2.0 at 30° altitude, infinity below horizon).
+        Accounts for observer height using atmospheric scale height correction.
+        """
+        ...
+
+    def airmass_batch(
+        self,
+        ras: Sequence[float] | npt.NDArray[np.float64],
+        decs: Sequence[float] | npt.NDArray[np.float64],
+        time_indices: list[int] | None = None,
+    ) -> npt.NDArray[np.float64]:
+        """Calculate airmass for many RA/Dec targets (deg) in one call.
+
+        Returns an array of shape (n_targets, n_times) from the vectorized
+        Kasten pipeline used by the airmass constraint (no refraction or
+        aberration); infinity below the horizon.
+        """
+        ...
+
+    @property
+    def sun_radius(self) -> Any:  # Returns astropy.units.Quantity
+        """
+        Angular radius of the Sun with astropy units (degrees).
+
+        Returns an astropy Quantity with units of degrees.
+        This property is cached for performance.
+        """
+        ...
+
+    @property
+    def sun_radius_deg(self) -> npt.NDArray[np.float64]:
+        """
+        Angular radius of the Sun as seen from the spacecraft (in degrees).
+
+        Returns a NumPy array of angular radii for each timestamp.
+        Angular radius = arcsin(physical_radius / distance)
+        This property is cached for performance.
+        """
+        ...
+
+    @property
+    def moon_radius(self) -> Any:  # Returns astropy.units.Quantity
+        """
+        Angular radius of the Moon with astropy units (degrees).
+
+        Returns an astropy Quantity with units of degrees.
+        This property is cached for performance.
+        """
+        ...
+
+    @property
+    def moon_radius_deg(self) -> npt.NDArray[np.float64]:
+        """
+        Angular radius of the Moon as seen from the spacecraft (in degrees).
+
+        Returns a NumPy array of angular radii for each timestamp.
+        Angular radius = arcsin(physical_radius / distance)
+        This property is cached for performance.
+        """
+        ...
+
+    @property
+    def earth_radius(self) -> Any:  # Returns astropy.units.Quantity
+        """
+        Angular radius of the Earth with astropy units (degrees).
+
+        Returns an astropy Quantity with units of degrees.
+        This property is cached for performance.
+        """
+        ...
+
+    @property
+    def earth_radius_deg(self) -> npt.NDArray[np.float64]:
+        """
+        Angular radius of the Earth as seen from the spacecraft (in degrees).
+
+        Returns a NumPy array of angular radii for each timestamp.
+        Angular radius = arcsin(physical_radius / distance)
+        This property is cached for performance.
+        """
+        ...
+
+    @property
+    def sun_radius_rad(self) -> npt.NDArray[np.float64]:
+        """
+        Angular radius of the Sun as seen from the spacecraft (in radians).
+
+        Returns a NumPy array of angular radii for each timestamp.
+        Angular radius = arcsin(physical_radius / distance)
+        This property is cached for performance.
+        """
+        ...
+
+    @property
+    def moon_radius_rad(self) -> npt.NDArray[np.float64]:
+        """
+        Angular radius of the Moon as seen from the spacecraft (in radians).
+
+        Returns a NumPy array of angular radii for each timestamp.
+        Angular radius = arcsin(physical_radius / distance)
+        This property is cached for performance.
+        """
+        ...
+
+    @property
+    def earth_radius_rad(self) -> npt.NDArray[np.float64]:
+        """
+        Angular radius of the Earth as seen from the spacecraft (in radians).
+
+        Returns a NumPy array of angular radii for each timestamp.
+        Angular radius = arcsin(physical_radius / distance)
+        This property is cached for performance.
+        """
+        ...
+
+    @property
+    def sun_ra_dec_deg(self) -> npt.NDArray[np.float64]:
+        """
+        Right Ascension and Declination of the Sun in degrees.
+
+        Returns an Nx2 NumPy array where column 0 is RA and column 1 is Dec.
+        This property is cached for performance.
+        """
+        ...
+
+    @property
+    def moon_ra_dec_deg(self) -> npt.NDArray[np.float64]:
+        """
+        Right Ascension and Declination of the Moon in degrees.
+
+        Returns an Nx2 NumPy array where column 0 is RA and column 1 is Dec.
+        This property is cached for performance.
+        """
+        ...
+
+    @property
+    def earth_ra_dec_deg(self) -> npt.NDArray[np.float64]:
+        """
+        Right Ascension and Declination of the Earth in degrees.
+
+        Returns an Nx2 NumPy array where column 0 is RA and column 1 is Dec.
+        This property is cached for performance.
+        """
+        ...
+
+    @property
+    def sun_ra_dec_rad(self) -> npt.NDArray[np.float64]:
+        """
+        Right Ascension and Declination of the Sun in radians.
+
+        Returns an Nx2 NumPy array where column 0 is RA and column 1 is Dec.
+        This property is cached for performance.
+        """
+        ...
+
+    @property
+    def moon_ra_dec_rad(self) -> npt.NDArray[np.float64]:
+        """
+        Right Ascension and Declination of the Moon in radians.
+
+        Returns an Nx2 NumPy array where column 0 is RA and column 1 is Dec.
+        This property is cached for performance.
+        """
+        ...
+
+    @property
+    def earth_ra_dec_rad(self) -> npt.NDArray[np.float64]:
+        """
+        Right Ascension and Declination of the Earth in radians.
+
+        Returns an Nx2 NumPy array where column 0 is RA and column 1 is Dec.
+        This property is cached for performance.
+        """
+        ...
+
+    @property
+    def sun_ra_deg(self) -> npt.NDArray[np.float64]:
+        """Right Ascension of the Sun in degrees. Extracts column 0 from sun_ra_dec_deg."""
+        ...
+
+    @property
+    def sun_dec_deg(self) -> npt.NDArray[np.float64]:
+        """Declination of the Sun in degrees. Extracts column 1 from sun_ra_dec_deg."""
+        ...
+
+    @property
+    def moon_ra_deg(self) -> npt.NDArray[np.float64]:
+        """Right Ascension of the Moon in degrees. Extracts column 0 from moon_ra_dec_deg."""
+        ...
+
+    @property
+    def moon_dec_deg(self) -> npt.NDArray[np.float64]:
+        """Declination of the Moon in degrees. Extracts column 1 from moon_ra_dec_deg."""
+        ...
+
+    @property
+    def earth_ra_deg(self) -> npt.NDArray[np.float64]:
+        """Right Ascension of the Earth in degrees. Extracts column 0 from earth_ra_dec_deg."""
+        ...
+
+    @property
+    def earth_dec_deg(self) -> npt.NDArray[np.float64]:
+        """Declination of the Earth in degrees. Extracts column 1 from earth_ra_dec_deg."""
+        ...
+
+    @property
+    def sun_ra_rad(self) -> npt.NDArray[np.float64]:
+        """Right Ascension of the Sun in radians. Extracts column 0 from sun_ra_dec_rad."""
+        ...
+
+    @property
+    def sun_dec_rad(self) -> npt.NDArray[np.float64]:
+        """Declination of the Sun in radians. Extracts column 1 from sun_ra_dec_rad."""
+        ...
+
+    @property
+    def moon_ra_rad(self) -> npt.NDArray[np.float64]:
+        """Right Ascension of the Moon in radians. Extracts column 0 from moon_ra_dec_rad."""
+        ...
+
+    @property
+    def moon_dec_rad(self) -> npt.NDArray[np.float64]:
+        """Declination of the Moon in radians. Extracts column 1 from moon_ra_dec_rad."""
+        ...
+
+    @property
+    def earth_ra_rad(self) -> npt.NDArray[np.float64]:
+        """Right Ascension of the Earth in radians. Extracts column 0 from earth_ra_dec_rad."""
+        ...
+
+    @property
+    def earth_dec_rad(self) -> npt.NDArray[np.float64]:
+        """Declination of the Earth in radians. Extracts column 1 from earth_ra_dec_rad."""
+        ...
+
+    def index(self, time: datetime) -> int:
+        """
+        Find the index of the closest timestamp to the given datetime.
+
+        Returns the index in the ephemeris timestamp array that is closest to the provided time.
+        This can be used to index into any of the ephemeris arrays (positions, velocities, etc.)
+
+        Args:
+            time: Python datetime object to find the closest match for
+
+        Returns:
+            Index of the closest timestamp
+
+        Raises:
+            ValueError: If no timestamps are available in the ephemeris
+
+        Example:
+            >>> from datetime import datetime
+            >>> eph = ArrayEphemeris(times, states)
+            >>> target_time = datetime(2024, 1, 15, 12, 0, 0)
+            >>> idx = eph.index(target_time)
+            >>> position = eph.gcrs_pv.position[idx]
+        """
+        ...
+
+    def fingerprint(self) -> str:
+        """
+        Stable hex digest identifying this ephemeris.
+
+        Hashes the source (elements, file path or site), the options, the time
+        grid and the computed GCRS states, so ephemerides built the same way
+        share a fingerprint across sessions. Ephemerides with equal
+        fingerprints compare equal, and the fingerprint can key caches of
+        constraint results or schedules.
+
+        Returns:
+            32-character hexadecimal digest
+        """
+        ...
+
+    def __eq__(self, other: object) -> bool: ...
+    def __hash__(self) -> int: ...
+    @property
+    def metadata(self) -> dict[str, Any]:
+        """
+        Source, time span, computed frames and Earth orientation state.
+
+        Keys start with ``type`` and the source fields of this class (e.g.
+        ``norad_id`` and ``tle_epoch``, or a file path), then ``polar_motion``,
+        ``begin``, ``end``, ``step_size``, ``n_times``, ``frames``,
+        ``eop_available``, ``polar_motion_applied``, ``fast_itrs``,
+        ``ut1_available`` and ``fingerprint``. Values are
+        JSON-serializable, with times as ISO 8601 strings, for provenance logs.
+        """
+        ...
+
+    @property
+    def provenance(self) -> Provenance:
+        """
+        Reproducibility record: library version, source and fingerprint,
+        element set, input and kernel file digests, Earth orientation and
+        leap-second data.
+        """
+        ...
+
+    def summary(self) -> str:
+        """Human-readable, multi-line description of ``metadata``."""
+        ...
+
+    def __repr__(self) -> str: ...
+    def __len__(self) -> int:
+        """Number of time steps"""
+        ...
+
+    def __getitem__(self, index: int) -> EphemerisSample:
+        """State at time step ``index``; negative indices count from the end"""
+        ...
+
+    def __iter__(self) -> Iterator[EphemerisSample]:
+        """Iterate over the time steps in order"""
+        ...
+
+    @property
+    def illumination_factor(self) -> npt.NDArray[np.float64]:
+        """Fraction of the solar disk visible from the observer at each time step
+
+        1.0 in sunlight, 0.0 in the Earth's umbra and fractional in the
+        penumbra, from the overlap of the apparent Sun and Earth disks.
+        """
+        ...
+
+    @property
+    def geodetic_rates(self) -> npt.NDArray[np.float64] | None:
+        """Geodetic rates from the ITRS velocity, shape (N, 3)
+
+        Columns are latitude rate (deg/s), longitude rate (deg/s) and height
+        rate (km/s). The longitude rate diverges at the poles.
+        """
+        ...
+
+    @property
+    def ground_speed(self) -> npt.NDArray[np.float64] | None:
+        """Speed of the sub-observer point over the WGS84 ellipsoid (km/s)"""
+        ...
+
+    @property
+    def sub_satellite_point(self) -> npt.NDArray[np.float64] | None:
+        """Geodetic (latitude, longitude) in degrees below the observer, shape (N, 2)"""
+        ...
+
+    @property
+    def sub_solar_point(self) -> npt.NDArray[np.float64]:
+        """(latitude, longitude) in degrees with the Sun at the zenith, shape (N, 2)"""
+        ...
+
+    @property
+    def nadir_solar_zenith_angle(self) -> npt.NDArray[np.float64] | None:
+        """Solar zenith angle at the sub-satellite point in degrees
+
+        The ground below the observer is daylit below 90.
+        """
+        ...
+
+    def moon_illumination(self, time_indices: list[int] | None = None) -> list[float]:
+        """
+        Calculate Moon illumination fraction for all ephemeris times.
+
+        Returns the fraction of the Moon's illuminated surface as seen from the
+        spacecraft observer (0.0 = new moon, 1.0 = full moon).
+
+        Args:
+            time_indices: Optional indices into ephemeris times (default: all times)
+
+        Returns:
+            List of Moon illumination fractions
+        """
+        ...
+
+    def access_to(
+        self,
+        lat: float,
+        lon: float,
+        max_off_nadir_deg: float,
+        min_sun_elev: float | None = None,
+    ) -> list[AccessOpportunity]:
+        """
+        Find imaging opportunities of a ground target.
+
+        A time is accessible when the target is above its local horizon (on
+        the visible side of the Earth's limb), within ``max_off_nadir_deg`` of
+        the spacecraft's nadir and, if ``min_sun_elev`` is given, the Sun is
+        at least that high at the target.
+
+        Args:
+            lat: Geodetic latitude of the target in degrees
+            lon: Longitude of the target in degrees, east positive
+            max_off_nadir_deg: Maximum off-nadir angle in degrees (0-90)
+            min_sun_elev: Minimum Sun elevation at the target in degrees
+                (default: no illumination requirement)
+
+        Returns:
+            Opportunities in time order, each summarised at its smallest
+            off-nadir angle
+
+        Raises:
+            ValueError: If the target location or an angle is out of range
+        """
+        ...
+
+    def get_body_pv(
+        self,
+        body: str,
+        spice_kernel: str | None = ...,
+        use_horizons: bool = ...,
+        source: str | None = ...,
+    ) -> PositionVelocityData:
+        """
+        Get position and velocity of a celestial body.
+
+        Args:
+            body: Name of the body (e.g., 'sun', 'moon', 'earth'), or a pseudo-body
+                ('EMB', 'SSB', 'SEL1', 'SEL2', 'EML1', 'EML2')
+            spice_kernel: Optional path to SPICE kernel
+            use_horizons: If True, fall back to JPL Horizons API when SPICE fails
+                (equivalent to ``source="auto"``)
+            source: Data source precedence: ``"spice"`` (loaded kernels only),
+                ``"horizons"`` (JPL Horizons only) or ``"auto"`` (SPICE, falling back
+                to Horizons with a ``UserWarning``). Defaults to ``"spice"`` unless
+                ``use_horizons`` is set.
+
+        Returns:
+            Position and velocity data for the requested body
+        """
+        ...
+
+    def get_bodies(
+        self,
+        bodies: list[str],
+        spice_kernel: str | None = ...,
+        use_horizons: bool = ...,
+        source: str | None = ...,
+    ) -> dict[str, PositionVelocityData]:
+        """
+        Get position and velocity of several celestial bodies in one pass.
+
+        Equivalent to calling ``get_body_pv`` for each body, but the SPICE
+        lookups and the observer positions are set up once for all bodies.
+
+        Args:
+            bodies: Body names or NAIF IDs, as accepted by ``get_body_pv``
+            spice_kernel: Optional path to SPICE kernel
+            use_horizons: If True, fall back to JPL Horizons API when SPICE fails
+                (equivalent to ``source="auto"``)
+            source: Data source precedence, as for ``get_body_pv``
+
+        Returns:
+            Dict mapping each requested body, as given, to its position and
+            velocity data
+        """
+        ...
+
+    def separation(self, a: Any, b: Any) -> npt.NDArray[np.float64]:
+        """
+        Angular separation between two targets at each time step.
+
+        Computed in Rust from observer-relative vectors, without astropy.
+
+        Args:
+            a, b: Each an ``(ra_deg, dec_deg)`` pair, a body name (``"sun"``,
+                ``"moon"`` and ``"earth"`` use the cached positions; other names
+                resolve as in ``get_body_pv``), observer-relative
+                ``PositionVelocityData`` (e.g. from ``get_body_pv``), or a
+                SkyCoord with a single position or one per time step (e.g.
+                ``ephem.sun``)
+
+        Returns:
+            Separation angles in degrees, one per time step
+
+        Raises:
+            TypeError: If an operand is not one of the accepted kinds
+            ValueError: If an operand has a different number of time steps
+        """
+        ...
+
+    def sun_angle(
+        self, ra_deg: float, dec_deg: float, time_indices: list[int] | None = ...
+    ) -> npt.NDArray[np.float64]:
+        """
+        Angle between a target and the Sun at each time step.
+
+        Args:
+            ra_deg: Target right ascension in degrees
+            dec_deg: Target declination in degrees
+            time_indices: Optional indices into the ephemeris times (default: all)
+
+        Returns:
+            Angles in degrees, one per selected time step
+        """
+        ...
+
+    def moon_angle(
+        self, ra_deg: float, dec_deg: float, time_indices: list[int] | None = ...
+    ) -> npt.NDArray[np.float64]:
+        """
+        Angle between a target and the Moon at each time step.
+
+        Args:
+            ra_deg: Target right ascension in degrees
+            dec_deg: Target declination in degrees
+            time_indices: Optional indices into the ephemeris times (default: all)
+
+        Returns:
+            Angles in degrees, one per selected time step
+        """
+        ...
+
+    def earth_limb_angle(
+        self, ra_deg: float, dec_deg: float, time_indices: list[int] | None = ...
+    ) -> npt.NDArray[np.float64]:
+        """
+        Angle of a target above the Earth's limb at each time step.
+
+        The angle from the Earth's center minus the Earth's angular radius, so
+        negative while the target is behind the Earth. No refraction or horizon
+        mask is applied.
+
+        Args:
+            ra_deg: Target right ascension in degrees
+            dec_deg: Target declination in degrees
+            time_indices: Optional indices into the ephemeris times (default: all)
+
+        Returns:
+            Angles in degrees, one per selected time step
+        """
+        ...
+
+    def geometry(
+        self,
+        ra_deg: float,
+        dec_deg: float,
+        time: datetime,
+        *,
+        saa_polygon: list[tuple[float, float]] | None = None,
+    ) -> ObservationGeometry:
+        """
+        Observation geometry of a target at the ephemeris step nearest ``time``.
+
+        Args:
+            ra_deg: Target right ascension in degrees
+            dec_deg: Target declination in degrees
+            time: Time of interest; the nearest ephemeris step is used
+            saa_polygon: Optional SAA region as (longitude, latitude) vertices in
+                degrees; sets ``in_saa``
+
+        Returns:
+            Sun, Moon and Earth-limb angles, plus altitude and airmass for ground
+            sites or eclipse, orbital phase and beta angle for orbiting observers
+        """
+        ...
+
+    def get_body(
+        self,
+        body: str,
+        spice_kernel: str | None = ...,
+        use_horizons: bool = ...,
+        source: str | None = ...,
+    ) -> Any:  # Returns astropy.coordinates.SkyCoord
+        """
+        Get SkyCoord for a celestial body.
+
+        Args:
+            body: Name of the body (e.g., 'sun', 'moon', 'earth'), or a pseudo-body
+                ('EMB', 'SSB', 'SEL1', 'SEL2', 'EML1', 'EML2')
+            spice_kernel: Optional path to SPICE kernel
+            use_horizons: If True, fall back to JPL Horizons API when SPICE fails
+                (equivalent to ``source="auto"``)
+            source: Data source precedence: ``"spice"`` (loaded kernels only),
+                ``"horizons"`` (JPL Horizons only) or ``"auto"`` (SPICE, falling back
+                to Horizons with a ``UserWarning``). Defaults to ``"spice"`` unless
+                ``use_horizons`` is set.
+
+        Returns:
+            astropy.coordinates.SkyCoord object
+        """
+        ...
+
+class GEOEphemeris(ArrayEphemeris):
+    """
+    Ideal geostationary satellite held fixed at a longitude slot.
+
+    The satellite sits at the slot in the Earth-fixed frame for the whole
+    time range. Everything else behaves as for :class:`ArrayEphemeris`; the
+    metadata and ``repr`` report the slot instead of the input frame.
+    """
+
+    def __init__(
+        self,
+        longitude_deg: float,
+        begin: datetime,
+        end: datetime,
+        step_size: int = 60,
+        *,
+        latitude_deg: float = 0.0,
+        radius_km: float | None = None,
+        polar_motion: bool = False,
+        time_scale: str = "UTC",
+    ) -> None:
+        """
+        Initialize an ephemeris for a GEO slot.
+
+        Args:
+            longitude_deg: Slot longitude in degrees, east positive; -180 to 180
+                or 0 to 360
+            begin: Start time
+            end: End time
+            step_size: Step in seconds (default: 60)
+            latitude_deg: Geocentric latitude offset in degrees (default: 0)
+            radius_km: Orbit radius in km (default: the geostationary radius,
+                42164.17 km)
+            polar_motion: Whether to apply polar motion correction (default: False)
+            time_scale: "UTC" steps clock time and skips leap seconds; "TAI"
+                or "TT" step SI seconds through them (default: "UTC")
+
+        Raises:
+            ValueError: If an angle is out of range or the radius is inside
+                the Earth
+        """
+        ...
+
+    @property
+    def slot_longitude_deg(self) -> float:
+        """Slot longitude in degrees (-180 to 180)"""
+        ...
+
+    @property
+    def slot_latitude_deg(self) -> float:
+        """Slot latitude in degrees"""
+        ...
+
+    @property
+    def radius_km(self) -> float:
+        """Orbit radius in km"""
+        ...
+
+class StationContacts:
+    """Contact statistics of one station of a :class:`GroundNetwork`"""
+
+    name: str
+
+    @property
+    def contacts(self) -> list[VisibilityWindow]:
+        """Contact windows of this station"""
+        ...
+
+    @property
+    def n_contacts(self) -> int:
+        """Number of contact windows"""
+        ...
+
+    @property
+    def total_contact_seconds(self) -> float:
+        """Total contact time in seconds"""
+        ...
+
+    @property
+    def mean_contact_seconds(self) -> float:
+        """Mean contact duration in seconds, or 0.0 without contacts"""
+        ...
+
+    @property
+    def max_elevation(self) -> float | None:
+        """Highest elevation in degrees reached during contact, or None without contacts"""
+        ...
+
+    @property
+    def elevation(self) -> npt.NDArray[np.float64]:
+        """Spacecraft elevation in degrees at each ephemeris time"""
+        ...
+
+    @property
+    def in_contact(self) -> list[bool]:
+        """Whether the station is in contact at each ephemeris time"""
+        ...
+
+    def __repr__(self) -> str: ...
+
+class NetworkContacts:
+    """Combined contact analysis of a :class:`GroundNetwork` for one spacecraft
+
+    Window boundaries are ephemeris sample times. A gap runs from the last
+    sample of one contact to the first sample of the next.
+    """
+
+    @property
+    def timestamp(self) -> npt.NDArray[np.object_]:
+        """Ephemeris times as a NumPy array of datetimes"""
+        ...
+
+    @property
+    def in_contact(self) -> list[bool]:
+        """Whether any station is in contact at each time"""
+        ...
+
+    @property
+    def serving_station(self) -> list[str | None]:
+        """Name of the station serving the spacecraft at each time, or None
+
+        A station keeps the spacecraft until it loses contact; the visible
+        station with the highest elevation then takes over.
+        """
+        ...
+
+    @property
+    def coverage(self) -> list[VisibilityWindow]:
+        """Windows during which at least one station is in contact"""
+        ...
+
+    @property
+    def gaps(self) -> list[VisibilityWindow]:
+        """Windows during which no station is in contact"""
+        ...
+
+    @property
+    def handovers(self) -> list[tuple[datetime, str, str]]:
+        """Handovers as ``(time, from_station, to_station)`` tuples"""
+        ...
+
+    @property
+    def stations(self) -> list[StationContacts]:
+        """Per-station contact statistics, in network order"""
+        ...
+
+    @property
+    def coverage_fraction(self) -> float:
+        """Fraction of ephemeris samples with at least one station in contact"""
+        ...
+
+    @property
+    def max_gap_seconds(self) -> float:
+        """Longest gap in seconds, or 0.0 when coverage is continuous"""
+        ...
+
+    def station(self, name: str) -> StationContacts:
+        """Contact statistics of the named station
+
+        Raises:
+            KeyError: If no station has this name
+        """
+        ...
+
+    def __repr__(self) -> str: ...
+
+class GroundNetwork:
+    """A network of ground stations for spacecraft contact analysis
+
+    Example:
+        >>> network = GroundNetwork(["Goldstone", "Canberra", "Madrid"], min_elevation=10.0)
+        >>> result = network.contacts(tle_ephem)
+        >>> result.coverage_fraction, result.max_gap_seconds
+    """
+
+    def __init__(
+        self,
+        stations: list[GroundEphemeris | str | tuple[str, float, float, float]],
+        *,
+        min_elevation: float = 0.0,
+    ) -> None:
+        """
+        Create a network from a list of stations.
+
+        Args:
+            stations: ``GroundEphemeris`` objects (their location, site name and
+                horizon profile are used), site names resolved through the site
+                registry, or ``(name, latitude, longitude, height)`` tuples with
+                height in meters
+            min_elevation: Minimum elevation in degrees for contact, applied in
+                addition to any station horizon profile (default: 0.0)
+
+        Raises:
+            ValueError: If no stations are given, a site is unknown or station
+                names are duplicated
+            TypeError: If a station has an unsupported type
+        """
+        ...
+
+    @property
+    def station_names(self) -> list[str]:
+        """Station names, in network order"""
+        ...
+
+    @property
+    def min_elevation(self) -> float:
+        """Minimum contact elevation in degrees"""
+        ...
+
+    def __len__(self) -> int: ...
+    def contacts(
+        self, ephemeris: Ephemeris, *, polar_motion: bool | None = None
+    ) -> NetworkContacts:
+        """
+        Compute contacts between every station and a spacecraft.
+
+        Args:
+            ephemeris: Spacecraft ephemeris (any ephemeris type)
+            polar_motion: Apply polar motion to the spacecraft's Earth-fixed
+                positions; None uses the ephemeris's own setting
+
+        Returns:
+            NetworkContacts with combined coverage, gaps, handovers and
+            per-station statistics sampled at the ephemeris times
+        """
+        ...
+
+    def __repr__(self) -> str: ...
+
+class LinkGeometry:
+    """Link geometry and path loss between a ground station and a spacecraft
+
+    Returned by :func:`link_geometry`. Per-time arrays are sampled at the
+    ephemeris times.
+    """
+
+    station: str
+    frequency_mhz: float
+    zenith_attenuation_db: float
+    min_elevation: float
+
+    @property
+    def timestamp(self) -> npt.NDArray[np.object_]:
+        """Ephemeris times as a NumPy array of datetimes"""
+        ...
+
+    @property
+    def elevation(self) -> npt.NDArray[np.float64]:
+        """Spacecraft elevation in degrees at each ephemeris time"""
+        ...
+
+    @property
+    def azimuth(self) -> npt.NDArray[np.float64]:
+        """Spacecraft azimuth in degrees (east of north) at each ephemeris time"""
+        ...
+
+    @property
+    def slant_range_km(self) -> npt.NDArray[np.float64]:
+        """Station-to-spacecraft distance in km at each ephemeris time"""
+        ...
+
+    @property
+    def free_space_loss_db(self) -> npt.NDArray[np.float64]:
+        """Free-space path loss in dB at each ephemeris time"""
+        ...
+
+    @property
+    def atmospheric_loss_db(self) -> npt.NDArray[np.float64]:
+        """Elevation-dependent atmospheric loss in dB at each ephemeris time"""
+        ...
+
+    @property
+    def total_loss_db(self) -> npt.NDArray[np.float64]:
+        """Free-space plus atmospheric loss in dB at each ephemeris time"""
+        ...
+
+    @property
+    def in_view(self) -> list[bool]:
+        """Whether the spacecraft is in view of the station at each ephemeris time"""
+        ...
+
+    @property
+    def passes(self) -> list[VisibilityWindow]:
+        """Passes over the station"""
+        ...
+
+    @property
+    def min_total_loss_db(self) -> float | None:
+        """Lowest total loss in dB while in view, or None without passes"""
+        ...
+
+    @property
+    def max_total_loss_db(self) -> float | None:
+        """Highest total loss in dB while in view, or None without passes"""
+        ...
+
+    def __len__(self) -> int: ...
+    def __repr__(self) -> str: ...
+
+class AccessOpportunity:
+    """One imaging opportunity of a ground target
+
+    Returned by ``access_to``. Geometry is reported at ``peak_time``, the
+    sample with the smallest off-nadir angle.
+    """
+
+    duration_seconds: float
+    off_nadir_deg: float
+    azimuth_deg: float
+    elevation_deg: float
+    slant_range_km: float
+    sun_elevation_deg: float
+    gsd_factor_across: float
+    gsd_factor_along: float
+
+    @property
+    def start_time(self) -> datetime:
+        """Start of the opportunity"""
+        ...
+
+    @property
+    def end_time(self) -> datetime:
+        """End of the opportunity"""
+        ...
+
+    @property
+    def peak_time(self) -> datetime:
+        """Time of the smallest off-nadir angle"""
+        ...
+
+    @property
+    def incidence_deg(self) -> float:
+        """Incidence angle at the target (90° - elevation) in degrees"""
+        ...
+
+    def __repr__(self) -> str: ...
+
+class SolarEclipse:
+    """One solar eclipse seen from a ground site
+
+    Returned by ``GroundEphemeris.solar_eclipses``. ``kind`` is the most
+    central phase reached: ``"partial"``, ``"annular"`` or ``"total"``.
+    """
+
+    kind: str
+    duration_seconds: float
+    max_obscuration: float
+    sun_altitude_deg: float
+
+    @property
+    def start_time(self) -> datetime:
+        """First eclipsed sample"""
+        ...
+
+    @property
+    def end_time(self) -> datetime:
+        """Last eclipsed sample"""
+        ...
+
+    @property
+    def peak_time(self) -> datetime:
+        """Time of the largest obscuration"""
+        ...
+
+    def __repr__(self) -> str: ...
+
+class SphericalPolygon:
+    """Polygon with great-circle edges, for repeated containment tests
+
+    The interior is the smaller of the two regions the edges bound, so the
+    vertices may be listed in either direction but must fit within a
+    hemisphere. See also :mod:`rust_ephem.sphere`.
+    """
+
+    def __init__(self, vertices: Sequence[tuple[float, float]]) -> None:
+        """
+        Args:
+            vertices: (longitude, latitude) pairs in degrees, e.g. (RA, Dec).
+                A closing repeat of the first vertex is dropped.
+
+        Raises:
+            ValueError: If there are fewer than 3 vertices, they do not fit
+                within a hemisphere or they enclose no area
+        """
+        ...
+
+    @property
+    def vertices(self) -> list[tuple[float, float]]:
+        """Vertices as (longitude, latitude) pairs in degrees"""
+        ...
+
+    @property
+    def solid_angle(self) -> float:
+        """Area of the interior in steradians"""
+        ...
+
+    @overload
+    def contains(self, lon: float, lat: float) -> bool: ...
+    @overload
+    def contains(
+        self,
+        lon: Sequence[float] | npt.NDArray[np.float64],
+        lat: Sequence[float] | npt.NDArray[np.float64],
+    ) -> npt.NDArray[np.bool_]: ...
+    def __repr__(self) -> str: ...
+
+class CoverageResult:
+    """Per-cell access windows and statistics returned by :meth:`CoverageGrid.analyze`
+
+    Per-cell arrays have shape ``(n_lat, n_lon)``, indexed like
+    ``latitudes`` and ``longitudes``.
+    """
+
+    @property
+    def timestamp(self) -> npt.NDArray[np.object_]:
+        """Ephemeris times as a NumPy array of datetimes"""
+        ...
+
+    @property
+    def latitudes(self) -> npt.NDArray[np.float64]:
+        """Grid latitudes in degrees"""
+        ...
+
+    @property
+    def longitudes(self) -> npt.NDArray[np.float64]:
+        """Grid longitudes in degrees"""
+        ...
+
+    @property
+    def n_spacecraft(self) -> int:
+        """Number of spacecraft included in the analysis"""
+        ...
+
+    @property
+    def access_count(self) -> npt.NDArray[np.uint64]:
+        """Number of access windows per cell"""
+        ...
+
+    @property
+    def access_seconds(self) -> npt.NDArray[np.float64]:
+        """Total access time in seconds per cell"""
+        ...
+
+    @property
+    def coverage_fraction(self) -> npt.NDArray[np.float64]:
+        """Fraction of ephemeris samples with access per cell"""
+        ...
+
+    @property
+    def max_revisit_seconds(self) -> npt.NDArray[np.float64]:
+        """Longest time between consecutive accesses per cell; NaN with fewer than two"""
+        ...
+
+    @property
+    def mean_revisit_seconds(self) -> npt.NDArray[np.float64]:
+        """Mean time between consecutive accesses per cell; NaN with fewer than two"""
+        ...
+
+    @property
+    def percent_covered(self) -> float:
+        """Percentage of the grid accessed at least once, weighting cells by cos(latitude)"""
+        ...
+
+    def access_windows(self, lat_index: int, lon_index: int) -> list[VisibilityWindow]:
+        """
+        Access windows of one grid cell.
+
+        Args:
+            lat_index: Index into ``latitudes``
+            lon_index: Index into ``longitudes``
+
+        Raises:
+            IndexError: If the cell is outside the grid
+        """
+        ...
+
+    def __repr__(self) -> str: ...
+
+class CoverageGrid:
+    """A latitude/longitude grid with a nadir-pointing sensor cone
+
+    Example:
+        >>> grid = CoverageGrid(np.arange(-60, 61, 5), np.arange(-180, 180, 5), half_angle=30.0)
+        >>> result = grid.analyze([sat1, sat2])
+        >>> result.percent_covered, np.nanmax(result.max_revisit_seconds)
+    """
+
+    def __init__(
+        self,
+        latitudes: Sequence[float] | npt.ArrayLike,
+        longitudes: Sequence[float] | npt.ArrayLike,
+        *,
+        half_angle: float | None = None,
+        min_elevation: float = 0.0,
+    ) -> None:
+        """
+        Create a grid from latitude and longitude values.
+
+        Args:
+            latitudes: Grid latitudes in degrees
+            longitudes: Grid longitudes in degrees; the grid is every
+                combination of latitude and longitude
+            half_angle: Sensor cone half-angle from nadir in degrees, or None
+                for a sensor limited only by ``min_elevation`` (default: None)
+            min_elevation: Minimum spacecraft elevation above a cell's horizon
+                in degrees (default: 0.0)
+
+        Raises:
+            ValueError: If the grid is empty or an angle is out of range
+        """
+        ...
+
+    @property
+    def latitudes(self) -> list[float]:
+        """Grid latitudes in degrees"""
+        ...
+
+    @property
+    def longitudes(self) -> list[float]:
+        """Grid longitudes in degrees"""
+        ...
+
+    @property
+    def half_angle(self) -> float | None:
+        """Sensor cone half-angle in degrees, or None"""
+        ...
+
+    @property
+    def min_elevation(self) -> float:
+        """Minimum elevation above a cell's horizon in degrees"""
+        ...
+
+    @property
+    def shape(self) -> tuple[int, int]:
+        """Grid shape as (n_lat, n_lon)"""
+        ...
+
+    def analyze(self, ephemerides: Ephemeris | Sequence[Ephemeris]) -> CoverageResult:
+        """
+        Compute access windows of every grid cell.
+
+        A cell is accessed when at least one spacecraft sees it.
+
+        Args:
+            ephemerides: A spacecraft ephemeris or a list of them (any
+                ephemeris type), all sharing the same timestamps
+
+        Returns:
+            CoverageResult with per-cell access windows, revisit times and
+            coverage statistics sampled at the ephemeris times
+
+        Raises:
+            ValueError: If the ephemerides have different timestamps
+        """
+        ...
+
+    def __repr__(self) -> str: ...
+
+class EphemerisComparison:
+    """Residuals of one ephemeris against a reference, returned by :func:`compare`
+
+    Residuals are ``other - reference`` in the reference's radial, in-track
+    and cross-track (RIC) frame. Statistics are dicts with keys ``radial``,
+    ``in_track``, ``cross_track`` and ``total``.
+    """
+
+    @property
+    def timestamp(self) -> npt.NDArray[np.object_]:
+        """Comparison grid times as a NumPy array of datetimes"""
+        ...
+
+    @property
+    def position_residuals(self) -> npt.NDArray[np.float64]:
+        """Position residuals as an (N, 3) array of [radial, in-track, cross-track] in km"""
+        ...
+
+    @property
+    def velocity_residuals(self) -> npt.NDArray[np.float64]:
+        """Velocity residuals as an (N, 3) array of [radial, in-track, cross-track] in km/s"""
+        ...
+
+    @property
+    def position_error(self) -> npt.NDArray[np.float64]:
+        """Magnitude of the position residual at each time (km)"""
+        ...
+
+    @property
+    def velocity_error(self) -> npt.NDArray[np.float64]:
+        """Magnitude of the velocity residual at each time (km/s)"""
+        ...
+
+    @property
+    def position_rms(self) -> dict[str, float]:
+        """RMS position residual (km) by component and in total"""
+        ...
+
+    @property
+    def position_max(self) -> dict[str, float]:
+        """Maximum absolute position residual (km) by component; ``total`` is the largest magnitude"""
+        ...
+
+    @property
+    def velocity_rms(self) -> dict[str, float]:
+        """RMS velocity residual (km/s) by component and in total"""
+        ...
+
+    @property
+    def velocity_max(self) -> dict[str, float]:
+        """Maximum absolute velocity residual (km/s) by component; ``total`` is the largest magnitude"""
+        ...
+
+    def __len__(self) -> int: ...
+    def __repr__(self) -> str: ...
+
+class TLEAccuracy:
+    """Empirical TLE error growth, returned by :func:`estimate_tle_accuracy`
+
+    Each residual is an older TLE propagated to a newer TLE's epoch, minus
+    the newer TLE, in the newer TLE's radial, in-track and cross-track (RIC)
+    frame. Residuals are binned by prediction age. Statistics are dicts with
+    keys ``radial``, ``in_track``, ``cross_track`` and ``total``.
+    """
+
+    @property
+    def tle_epochs(self) -> list[datetime]:
+        """Epochs of the TLEs used, sorted"""
+        ...
+
+    @property
+    def age_days(self) -> npt.NDArray[np.float64]:
+        """Prediction age of each residual in days"""
+        ...
+
+    @property
+    def residuals(self) -> npt.NDArray[np.float64]:
+        """Residuals as an (M, 3) array of [radial, in-track, cross-track] in km"""
+        ...
+
+    @property
+    def bin_edges(self) -> npt.NDArray[np.float64]:
+        """Edges of the prediction-age bins in days"""
+        ...
+
+    @property
+    def counts(self) -> list[int]:
+        """Number of residuals in each age bin"""
+        ...
+
+    @property
+    def rms(self) -> dict[str, npt.NDArray[np.float64]]:
+        """RMS error (km) per age bin by component; NaN for empty bins"""
+        ...
+
+    def envelope(
+        self,
+        age_days: float,
+        component: Literal["radial", "in_track", "cross_track", "total"] = "total",
+    ) -> float:
+        """
+        RMS error (km) expected at a prediction age.
+
+        Interpolates linearly between the centers of non-empty bins and is
+        held constant beyond the first and last of them.
+        """
+        ...
+
+    def __len__(self) -> int: ...
+    def __repr__(self) -> str: ...
+
+class DecayEstimate:
+    """Orbit decay and re-entry estimate, returned by :func:`estimate_decay`
+
+    The orbit is treated as circular at its mean altitude and decays under
+    drag in an exponential atmosphere driven by F10.7 and Ap.
+    """
+
+    @property
+    def epoch(self) -> datetime:
+        """Epoch of the input elements"""
+        ...
+
+    @property
+    def ballistic_coefficient(self) -> float:
+        """Ballistic coefficient m/(Cd A) used, in kg/m²"""
+        ...
+
+    @property
+    def reentry_altitude_km(self) -> float:
+        """Altitude at which the object is considered re-entered (km)"""
+        ...
+
+    @property
+    def times(self) -> list[datetime]:
+        """Sample times of the integration"""
+        ...
+
+    @property
+    def altitude_km(self) -> npt.NDArray[np.float64]:
+        """Mean altitude above the equatorial radius at each sample (km)"""
+        ...
+
+    @property
+    def decay_rate_km_per_day(self) -> npt.NDArray[np.float64]:
+        """Altitude lost per day at each sample (km/day)"""
+        ...
+
+    @property
+    def initial_decay_rate_km_per_day(self) -> float:
+        """Altitude loss rate at the epoch (km/day)"""
+        ...
+
+    @property
+    def f107(self) -> npt.NDArray[np.float64]:
+        """F10.7 (sfu) used at each sample"""
+        ...
+
+    @property
+    def ap(self) -> npt.NDArray[np.float64]:
+        """Daily Ap used at each sample"""
+        ...
+
+    @property
+    def reentry_time(self) -> datetime | None:
+        """Estimated re-entry time, or None if the orbit outlives max_days"""
+        ...
+
+    @property
+    def lifetime_days(self) -> float | None:
+        """Days from the epoch to re-entry, or None if the orbit outlives max_days"""
+        ...
+
+    def __len__(self) -> int: ...
+    def __repr__(self) -> str: ...
+
+class ManeuverCandidate:
+    """Candidate maneuver, returned by :func:`detect_maneuvers`"""
+
+    @property
+    def source(self) -> str:
+        """``"states"`` for an ephemeris, ``"tle"`` for a TLE history"""
+        ...
+
+    @property
+    def epoch(self) -> datetime:
+        """Best estimate of the maneuver time"""
+        ...
+
+    @property
+    def window_start(self) -> datetime:
+        """Last sample or TLE epoch before the maneuver"""
+        ...
+
+    @property
+    def window_end(self) -> datetime:
+        """First sample or TLE epoch after the maneuver"""
+        ...
+
+    @property
+    def delta_v_m_s(self) -> float:
+        """Estimated delta-v magnitude in m/s (a lower bound for TLE histories)"""
+        ...
+
+    @property
+    def delta_v_ric_m_s(self) -> list[float] | None:
+        """Delta-v as [radial, in-track, cross-track] in m/s, or None for TLEs"""
+        ...
+
+    @property
+    def delta_sma_km(self) -> float:
+        """Change of semi-major axis in km"""
+        ...
+
+    def __repr__(self) -> str: ...
+
+class NodalAnalysis:
+    """Nodal period, RAAN drift and repeat cycle, returned by :func:`nodal_analysis`
+
+    Node longitudes and inclinations refer to the true equator and equinox
+    of date.
+    """
+
+    @property
+    def node_times(self) -> list[datetime]:
+        """Ascending node crossing times"""
+        ...
+
+    @property
+    def raan_deg(self) -> npt.NDArray[np.float64]:
+        """Right ascension of the ascending node at each crossing (degrees)"""
+        ...
+
+    @property
+    def inclination_deg(self) -> npt.NDArray[np.float64]:
+        """Inclination at each crossing (degrees)"""
+        ...
+
+    @property
+    def nodal_periods(self) -> npt.NDArray[np.float64]:
+        """Duration of each revolution between consecutive crossings (s)"""
+        ...
+
+    @property
+    def mean_nodal_period(self) -> float:
+        """Mean nodal period over the ephemeris (s)"""
+        ...
+
+    @property
+    def raan_drift_deg_per_day(self) -> float:
+        """Fitted RAAN drift rate (degrees per day)"""
+        ...
+
+    @property
+    def sun_synchronous_offset_deg_per_day(self) -> float:
+        """RAAN drift minus the sun-synchronous rate (degrees per day)"""
+        ...
+
+    @property
+    def nodal_day(self) -> float:
+        """Length of a nodal day, one Earth turn relative to the node line (s)"""
+        ...
+
+    @property
+    def revolutions_per_day(self) -> float:
+        """Revolutions per nodal day"""
+        ...
+
+    @property
+    def repeat_cycle_days(self) -> int | None:
+        """Nodal days in the repeat ground-track cycle, or None if none was found"""
+        ...
+
+    @property
+    def repeat_cycle_revolutions(self) -> int | None:
+        """Revolutions in the repeat ground-track cycle, or None if none was found"""
+        ...
+
+    @property
+    def repeat_shift_km(self) -> float | None:
+        """Eastward shift of the node at the equator after one cycle (km)"""
+        ...
+
+    def __len__(self) -> int: ...
+    def __repr__(self) -> str: ...
+
+class ScheduledObservation:
+    """One observation of a :class:`Schedule`"""
+
+    @property
+    def target_index(self) -> int:
+        """Index of the target in the list passed to :func:`schedule`"""
+        ...
+
+    @property
+    def ra(self) -> float:
+        """Target right ascension in degrees"""
+        ...
+
+    @property
+    def dec(self) -> float:
+        """Target declination in degrees"""
+        ...
+
+    @property
+    def priority(self) -> float:
+        """Target priority"""
+        ...
+
+    @property
+    def slew_seconds(self) -> float:
+        """Slew and settle time from the previous observation (0 for the first)"""
+        ...
+
+    @property
+    def start_time(self) -> datetime:
+        """Start of the exposure"""
+        ...
+
+    @property
+    def end_time(self) -> datetime:
+        """End of the exposure"""
+        ...
+
+    @property
+    def exposure_seconds(self) -> float:
+        """Exposure duration in seconds"""
+        ...
+
+    def __repr__(self) -> str: ...
+
+class Schedule:
+    """An ordered observation plan returned by :func:`schedule`"""
+
+    @property
+    def observations(self) -> list[ScheduledObservation]:
+        """Scheduled observations in time order"""
+        ...
+
+    @property
+    def unscheduled(self) -> list[int]:
+        """Indices of targets that could not be scheduled, in priority order"""
+        ...
+
+    @property
+    def total_exposure_seconds(self) -> float:
+        """Total exposure time in seconds"""
+        ...
+
+    @property
+    def total_slew_seconds(self) -> float:
+        """Total slew and settle time in seconds"""
+        ...
+
+    def __len__(self) -> int: ...
+    def __repr__(self) -> str: ...
+
+class VisibilityReport:
+    """Per-target visibility summary returned by :func:`visibility_report`"""
+
+    @property
+    def names(self) -> list[str] | None:
+        """Target names, if given"""
+        ...
+
+    @property
+    def ra(self) -> npt.NDArray[np.float64]:
+        """Target right ascensions in degrees"""
+        ...
+
+    @property
+    def dec(self) -> npt.NDArray[np.float64]:
+        """Target declinations in degrees"""
+        ...
+
+    @property
+    def visible_hours(self) -> npt.NDArray[np.float64]:
+        """Total visible time per target in hours"""
+        ...
+
+    @property
+    def n_windows(self) -> npt.NDArray[np.int64]:
+        """Number of visibility windows per target"""
+        ...
+
+    @property
+    def longest_window_hours(self) -> npt.NDArray[np.float64]:
+        """Longest visibility window per target in hours"""
+        ...
+
+    @property
+    def first_visible(self) -> npt.NDArray[np.object_]:
+        """First visible time per target (None if never visible)"""
+        ...
+
+    @property
+    def last_visible(self) -> npt.NDArray[np.object_]:
+        """Last visible time per target (None if never visible)"""
+        ...
+
+    @property
+    def blocked_hours(self) -> dict[str, npt.NDArray[np.float64]]:
+        """
+        Hours each blocking constraint is violated, per target, keyed by
+        constraint name.
+
+        Blocking constraints are the sub-constraints of an OR combination, or
+        the constraint itself otherwise. Their times can overlap, so the hours
+        need not add up to the time the target is not visible.
+        """
+        ...
+
+    def to_dict(self) -> dict[str, Any]:
+        """
+        Report columns as a dict of arrays, suitable for ``pandas.DataFrame``.
+
+        Blocking constraint columns are named ``blocked_hours:<constraint name>``.
+        """
+        ...
+
+    def to_records(self) -> np.recarray[Any, Any]:
+        """Report as a NumPy record array with one record per target"""
+        ...
+
+    def __len__(self) -> int: ...
+    def __repr__(self) -> str: ...
+
+class OrbitVisibility:
+    """Per-orbit visibility of one target returned by :func:`orbit_visibility`"""
+
+    @property
+    def n_orbits(self) -> int:
+        """Number of orbits, including partial first and last orbits"""
+        ...
+
+    @property
+    def orbit_start(self) -> npt.NDArray[np.object_]:
+        """Start time of each orbit (the first sample after the ascending node)"""
+        ...
+
+    @property
+    def orbit_end(self) -> npt.NDArray[np.object_]:
+        """End time of each orbit (the last sample before the next ascending node)"""
+        ...
+
+    @property
+    def complete(self) -> list[bool]:
+        """Whether each orbit runs from one ascending node to the next"""
+        ...
+
+    @property
+    def visible_fraction(self) -> npt.NDArray[np.float64]:
+        """Fraction of each orbit's samples at which the target is visible"""
+        ...
+
+    @property
+    def visible_seconds(self) -> npt.NDArray[np.float64]:
+        """Visible time in each orbit in seconds"""
+        ...
+
+    @property
+    def mean_solar_phase(self) -> npt.NDArray[np.float64]:
+        """
+        Circular mean solar phase of the visible samples of each orbit, in
+        degrees (NaN for orbits without visibility)
+        """
+        ...
+
+    @property
+    def mean_argument_of_latitude(self) -> npt.NDArray[np.float64]:
+        """
+        Circular mean argument of latitude of the visible samples of each
+        orbit, in degrees (NaN for orbits without visibility)
+        """
+        ...
+
+    @property
+    def mean_visible_fraction(self) -> float:
+        """
+        Mean visible fraction over complete orbits, or over all orbits if none
+        is complete
+        """
+        ...
+
+    @property
+    def solar_phase_concentration(self) -> float:
+        """
+        How tightly visibility clusters in solar phase over all orbits (0 to 1).
+
+        Near 1 when the target is only ever visible at the same point of the
+        orbit (e.g. always near orbit dawn), near 0 when visibility is spread
+        around the orbit; 0 if the target is never visible.
+        """
+        ...
+
+    @property
+    def solar_phase(self) -> npt.NDArray[np.float64]:
+        """Solar phase of the observer at each ephemeris time, in degrees"""
+        ...
+
+    @property
+    def argument_of_latitude(self) -> npt.NDArray[np.float64]:
+        """Argument of latitude of the observer at each ephemeris time, in degrees"""
+        ...
+
+    @property
+    def visible(self) -> list[bool]:
+        """Whether the target is visible at each ephemeris time"""
+        ...
+
+    def solar_phase_histogram(
+        self, bins: int = 12
+    ) -> tuple[npt.NDArray[np.int64], npt.NDArray[np.float64]]:
+        """Visible samples counted in ``bins`` equal solar phase bins over [0, 360)"""
+        ...
+
+    def to_dict(self) -> dict[str, Any]:
+        """Per-orbit columns as a dict of arrays, suitable for ``pandas.DataFrame``"""
+        ...
+
+    def __len__(self) -> int: ...
+    def __repr__(self) -> str: ...
+
+class ConstraintCube:
+    """Violation masks of several constraints for many targets"""
+
+    @property
+    def cube(self) -> npt.NDArray[np.bool_]:
+        """
+        Violation masks of shape ``(n_constraints, n_targets, n_times)``.
+
+        True means the constraint is violated (target not observable).
+        """
+        ...
+
+    @property
+    def constraint_names(self) -> list[str]:
+        """Constraint names, in the order of the first axis"""
+        ...
+
+    @property
+    def timestamp(self) -> npt.NDArray[np.object_]:
+        """Evaluation times"""
+        ...
+
+    @property
+    def shape(self) -> tuple[int, int, int]:
+        """Cube shape ``(n_constraints, n_targets, n_times)``"""
+        ...
+
+    def all_constraints(self) -> npt.NDArray[np.bool_]:
+        """
+        Where every constraint is satisfied, shape ``(n_targets, n_times)``.
+
+        True means the target is observable at that time.
+        """
+        ...
+
+    def any_violation(self) -> npt.NDArray[np.bool_]:
+        """Where at least one constraint is violated, shape ``(n_targets, n_times)``"""
+        ...
+
+    def __repr__(self) -> str: ...
+
+class AttitudeViolation:
+    """A window in which one body axis violates one constraint"""
+
+    @property
+    def axis(self) -> str:
+        """Name of the offending body axis or instrument"""
+        ...
+
+    @property
+    def constraint(self) -> str:
+        """Name of the violated constraint"""
+        ...
+
+    @property
+    def start_time(self) -> datetime:
+        """First violated sample"""
+        ...
+
+    @property
+    def end_time(self) -> datetime:
+        """Last violated sample"""
+        ...
+
+    def __repr__(self) -> str: ...
+
+class AttitudeCheck:
+    """Result of checking an attitude history against a set of constraints"""
+
+    @property
+    def all_satisfied(self) -> bool:
+        """True if no axis violates any constraint at any time"""
+        ...
+
+    @property
+    def violations(self) -> list[AttitudeViolation]:
+        """Violation windows, ordered by axis, then constraint, then time"""
+        ...
+
+    @property
+    def mask(self) -> npt.NDArray[np.bool_]:
+        """
+        Violation flags of shape ``(n_axes, n_constraints, n_times)``.
+
+        True means the axis violates the constraint at that time.
+        """
+        ...
+
+    @property
+    def axis_names(self) -> list[str]:
+        """Axis names, in the order of the first mask axis"""
+        ...
+
+    @property
+    def constraint_names(self) -> list[str]:
+        """Constraint names, in the order of the second mask axis"""
+        ...
+
+    @property
+    def timestamp(self) -> npt.NDArray[np.object_]:
+        """Evaluation times"""
+        ...
+
+    def __len__(self) -> int: ...
+    def __repr__(self) -> str: ...
+
+class FaceIncidence:
+    """Sun incidence on body faces over an attitude history"""
+
+    @property
+    def incidence_deg(self) -> npt.NDArray[np.float64]:
+        """
+        Sun incidence angle in degrees, shape ``(n_faces, n_times)``.
+
+        0 means the Sun is along the face normal; above 90 the face points
+        away from the Sun.
+        """
+        ...
+
+    @property
+    def sunlit(self) -> npt.NDArray[np.bool_]:
+        """
+        Insolation flags of shape ``(n_faces, n_times)``.
+
+        True where the incidence angle is below 90 degrees and the spacecraft
+        is outside Earth's shadow.
+        """
+        ...
+
+    @property
+    def illumination(self) -> npt.NDArray[np.float64]:
+        """
+        Cosine of the incidence angle where sunlit, otherwise 0, shape
+        ``(n_faces, n_times)``. Multiply by the solar constant for the flux.
+        """
+        ...
+
+    @property
+    def in_eclipse(self) -> list[bool]:
+        """Whether the spacecraft is in Earth's shadow at each time"""
+        ...
+
+    @property
+    def face_names(self) -> list[str]:
+        """Face names, in the order of the first array axis"""
+        ...
+
+    @property
+    def timestamp(self) -> npt.NDArray[np.object_]:
+        """Evaluation times"""
+        ...
+
+    def __len__(self) -> int: ...
+    def __repr__(self) -> str: ...
+
+class SlewIntrusion:
+    """A window in which the Sun or Moon is inside one tracker's exclusion cone during a slew"""
+
+    @property
+    def tracker(self) -> str:
+        """Name of the star tracker"""
+        ...
+
+    @property
+    def body(self) -> str:
+        """Intruding body, ``"sun"`` or ``"moon"``"""
+        ...
+
+    @property
+    def min_angle_deg(self) -> float:
+        """Smallest angle between the tracker axis and the body in the window, degrees"""
+        ...
+
+    @property
+    def start_time(self) -> datetime:
+        """First intruded sample"""
+        ...
+
+    @property
+    def end_time(self) -> datetime:
+        """Last intruded sample"""
+        ...
+
+    def __repr__(self) -> str: ...
+
+class SlewCheck:
+    """Sun and Moon angles from each star tracker along a slew, from :func:`check_slew`"""
+
+    @property
+    def clear(self) -> bool:
+        """True if neither body enters any tracker's exclusion cone"""
+        ...
+
+    @property
+    def intrusions(self) -> list[SlewIntrusion]:
+        """Intrusion windows, ordered by tracker, then body (Sun first), then time"""
+        ...
+
+    @property
+    def slew_angle_deg(self) -> float:
+        """Rotation angle of the slew in degrees"""
+        ...
+
+    @property
+    def duration_seconds(self) -> float:
+        """Slew duration at the given rate in seconds"""
+        ...
+
+    @property
+    def quaternions(self) -> npt.NDArray[np.float64]:
+        """Interpolated attitude at each slew sample, shape ``(n_samples, 4)``, scalar last"""
+        ...
+
+    @property
+    def sun_angle_deg(self) -> npt.NDArray[np.float64]:
+        """Tracker-to-Sun angle in degrees, shape ``(n_trackers, n_samples)``"""
+        ...
+
+    @property
+    def moon_angle_deg(self) -> npt.NDArray[np.float64]:
+        """Tracker-to-Moon angle in degrees, shape ``(n_trackers, n_samples)``"""
+        ...
+
+    @property
+    def tracker_names(self) -> list[str]:
+        """Tracker names, in the order of the first angle axis"""
+        ...
+
+    @property
+    def timestamp(self) -> npt.NDArray[np.object_]:
+        """Slew sample times"""
+        ...
+
+    def __len__(self) -> int: ...
+    def __repr__(self) -> str: ...
+
+class AirmassSummary:
+    """Minimum airmass, its time and time below an airmass limit, per target"""
+
+    @property
+    def min_airmass(self) -> npt.NDArray[np.float64]:
+        """Lowest airmass per target (``inf`` if the target never rises)"""
+        ...
+
+    @property
+    def min_airmass_time(self) -> npt.NDArray[np.object_]:
+        """Time of the lowest airmass per target (None if the target never rises)"""
+        ...
+
+    @property
+    def hours_below(self) -> npt.NDArray[np.float64]:
+        """Hours per target spent at or below :attr:`max_airmass`"""
+        ...
+
+    @property
+    def max_airmass(self) -> float:
+        """Airmass limit used for :attr:`hours_below`"""
+        ...
+
+    def __len__(self) -> int: ...
+    def __repr__(self) -> str: ...
+
+def init_planetary_ephemeris(
+    py_path: str,
+) -> None:
+    """
+    Initialize SPICE planetary ephemeris kernels from file.
+
+    Args:
+        py_path: Path to the planetary ephemeris kernel file (SPK)
+
+    Raises:
+        RuntimeError: If initialization fails
+    """
+    ...
+
+def download_planetary_ephemeris(
+    url: str,
+    dest: str,
+) -> None:
+    """
+    Download planetary ephemeris kernel from URL to destination.
+
+    Args:
+        url: URL to download the kernel from
+        dest: Destination file path
+
+    Raises:
+        RuntimeError: If download fails
+    """
+    ...
+
+def ensure_planetary_ephemeris(
+    py_path: str | None = None,
+    download_if_missing: bool = True,
+    spk_url: str | None = None,
+    prefer_full: bool = False,
+) -> None:
+    """
+    Ensure planetary ephemeris is available, downloading if necessary.
+
+    Args:
+        py_path: Optional explicit path to kernel file
+        download_if_missing: If True, download if file not found
+        spk_url: Optional custom URL for download
+        prefer_full: If True, prefer full DE440 over slim DE440S
+
+    Raises:
+        FileNotFoundError: If file not found and download_if_missing=False
+        RuntimeError: If download or initialization fails
+    """
+    ...
+
+def is_planetary_ephemeris_initialized() -> bool:
+    """
+    Check if planetary ephemeris has been initialized.
+
+    Returns:
+        True if ephemeris is initialized and ready to use
+    """
+    ...
+
+def get_tai_utc_offset(py_datetime: datetime) -> float | None:
+    """
+    Get TAI-UTC offset (leap seconds) at the given time.
+
+    Args:
+        py_datetime: UTC datetime (naive datetime treated as UTC)
+
+    Returns:
+        TAI-UTC offset in seconds, or None if not available
+    """
+    ...
+
+def get_ut1_utc_offset(py_datetime: datetime) -> float:
+    """
+    Get UT1-UTC offset at the given time.
+
+    Args:
+        py_datetime: UTC datetime (naive datetime treated as UTC)
+
+    Returns:
+        UT1-UTC offset in seconds
+
+    Raises:
+        RuntimeError: If UT1 provider is not initialized
+    """
+    ...
+
+def is_ut1_available() -> bool:
+    """
+    Check if UT1 data is available.
+
+    Returns:
+        True if UT1 provider is initialized
+    """
+    ...
+
+def init_ut1_provider() -> bool:
+    """
+    Initialize UT1 provider with IERS data.
+
+    Returns:
+        True if initialization succeeded
+    """
+    ...
+
+def get_polar_motion(py_datetime: datetime) -> tuple[float, float]:
+    """
+    Get polar motion (x, y) at the given time.
+
+    Args:
+        py_datetime: UTC datetime (naive datetime treated as UTC)
+
+    Returns:
+        Tuple of (x, y) polar motion in arcseconds
+
+    Raises:
+        RuntimeError: If EOP provider is not initialized
+    """
+    ...
+
+def is_eop_available() -> bool:
+    """
+    Check if Earth Orientation Parameters (EOP) data is available.
+
+    Returns:
+        True if EOP provider is initialized
+    """
+    ...
+
+def init_eop_provider() -> bool:
+    """
+    Initialize EOP provider with IERS data.
+
+    Returns:
+        True if initialization succeeded
+    """
+    ...
+
+def transformation_accuracy(
+    time: datetime, *, polar_motion: bool = True
+) -> dict[str, Any]:
+    """
+    Expected error of the GCRS↔ITRS transformation at an epoch.
+
+    Judges the UT1, EOP and leap-second data currently loaded against the
+    epoch: observed values, IERS predictions (whose error grows with the
+    days ahead of the last observation), or no data at all.
+
+    Args:
+        time: Epoch to assess (UTC)
+        polar_motion: Whether the ephemerides in question apply the polar
+            motion correction (default: True)
+
+    Returns:
+        Dict with ``epoch``, ``polar_motion``, ``ut1_available``,
+        ``eop_available``, ``eop_mjd_range``, ``beyond_eop_data``,
+        ``days_predicted``, ``ut1_utc_s``, ``tai_utc_s``, ``xp_arcsec``,
+        ``yp_arcsec``, ``contributions_arcsec`` (``dut1``, ``polar_motion``,
+        ``precession_nutation``, ``leap_seconds``), ``total_arcsec`` (their
+        root-sum-square) and ``total_m`` (the same angle at the Earth's
+        equatorial radius)
+    """
+    ...
+
+def get_space_weather(py_datetime: datetime) -> dict[str, Any] | None:
+    """
+    Get the Celestrak space-weather indices in effect at the given time.
+
+    Args:
+        py_datetime: UTC datetime (naive datetime treated as UTC)
+
+    Returns:
+        Dict with keys date (ISO string), kp, ap, ap_daily, f107_obs,
+        f107_adj, f107_obs_previous, f107_obs_center81, f107_obs_last81 and
+        data_type ("OBS", "INT", "PRD" or "PRM"); missing values are None.
+        None if there is no data for that day.
+    """
+    ...
+
+def is_space_weather_available() -> bool:
+    """
+    Check if space-weather data is available, loading it on first use.
+
+    Returns:
+        True if space-weather data is loaded
+    """
+    ...
+
+def init_space_weather() -> bool:
+    """
+    Download the Celestrak space-weather file again, bypassing the cache TTL.
+
+    Returns:
+        True if space-weather data is available afterwards
+    """
+    ...
+
+def load_space_weather(path: str) -> int:
+    """
+    Replace the space-weather data with a local Celestrak SW-All.csv file.
+
+    Args:
+        path: Path to the CSV file
+
+    Returns:
+        Number of days loaded
+
+    Raises:
+        ValueError: If the file cannot be read or has no data rows
+    """
+    ...
+
+def get_cache_dir() -> str:
+    """
+    Get the cache directory used for storing ephemeris data.
+
+    Returns:
+        String path to the cache directory
+    """
+
+def set_tle_cache_defaults(
+    cache_ttl: int | None = None, epoch_tolerance_days: float | None = None
+) -> None:
+    """
+    Set the TLE cache defaults used when fetch_tle or TLEEphemeris get none.
+
+    Args:
+        cache_ttl: Largest age in seconds of a cached Celestrak or URL download;
+            0 always fetches again. None restores the configured value.
+        epoch_tolerance_days: How many days a cached Space-Track TLE epoch may
+            differ from the requested epoch. None restores the configured value.
+
+    Raises:
+        ValueError: If epoch_tolerance_days is negative or not finite
+    """
+    ...
+
+def get_tle_cache_defaults() -> dict[str, Any]:
+    """
+    Get the TLE cache defaults in effect.
+
+    Returns:
+        Dict with keys cache_ttl (seconds) and epoch_tolerance_days (days)
+    """
+    ...
+
+def get_config() -> dict[str, Any]:
+    """
+    Get the rust_ephem configuration in effect.
+
+    Settings are read from the file named by ``RUST_EPHEM_CONFIG``, or from
+    ``config.toml`` in the cache directory, and overridden by
+    ``RUST_EPHEM_<SECTION>_<KEY>`` environment variables.
+
+    Returns:
+        Dictionary with ``cache``, ``kernels``, ``network``, ``spacetrack`` and
+        ``tle`` sections, plus ``config_path`` (the file read, or None). The
+        Space-Track.org password is redacted.
+    """
+    ...
+
+def enable_profiling(enabled: bool = True) -> None:
+    """
+    Start or stop timing constraint evaluation.
+
+    While enabled, every constraint evaluator call (including each
+    sub-constraint of a logical combination on the vectorized paths), each
+    extraction of positions or times from an ephemeris and each coordinate
+    frame conversion records its wall time. Timings recorded so far are kept
+    when profiling is stopped; read them with ``profiling_report``.
+
+    Args:
+        enabled: True to start timing, False to stop
+    """
+    ...
+
+def profiling_report(*, reset: bool = False) -> dict[str, Any]:
+    """
+    Get the timings recorded since profiling was enabled or last reset.
+
+    Times are inclusive: a combination's time contains its sub-constraints'
+    and an evaluator's time contains its data extraction. Calls made from
+    parallel threads add up, so totals can exceed the elapsed time.
+
+    Args:
+        reset: Discard the timings after taking the report
+
+    Returns:
+        Dictionary with ``enabled``, ``totals`` (total seconds per category)
+        and ``entries``, a list of dicts with ``category`` (``"evaluator"``,
+        ``"extraction"`` or ``"frame"``), ``name``, ``count``,
+        ``total_seconds``, ``mean_seconds`` and ``max_seconds``, grouped by
+        category and sorted by decreasing total time. Pass ``entries`` to
+        ``pandas.DataFrame`` for a table.
+    """
+    ...
+
+def reset_profiling() -> None:
+    """Discard the recorded profiling timings."""
+    ...
+
+def resolve_name(name: str, *, allow_network: bool = True) -> tuple[float, float]:
+    """
+    Resolve an object name to ICRS coordinates through CDS Sesame.
+
+    Sesame queries SIMBAD, NED and VizieR. Resolved names are cached in memory
+    and in ``sesame_names.json`` in the cache directory, so each name is only
+    fetched once. The result unpacks directly into the RA/Dec arguments of
+    constraint evaluation, e.g. ``constraint.evaluate(ephem, *resolve_name("M31"))``.
+
+    Args:
+        name: Object name, e.g. ``"M31"`` or ``"NGC 1976"``. Matching against
+            the cache ignores case and repeated whitespace.
+        allow_network: If False, only cached names resolve
+
+    Returns:
+        ``(ra, dec)`` in degrees
+
+    Raises:
+        ValueError: If the name cannot be resolved, or is not cached and
+            ``allow_network`` is False
+    """
+    ...
+
+def clear_name_cache() -> None:
+    """Clear the in-memory and on-disk cache of resolved object names."""
+    ...
+
+def clear_horizons_cache() -> None:
+    """
+    Clear the in-memory cache of JPL Horizons responses.
+
+    Horizons queries made by ``get_body(..., use_horizons=True)`` and related
+    calls are cached per (body, time range, step) for the lifetime of the
+    process. Call this to force fresh queries.
+    """
+    ...
+
+def load_mpcorb(path: str, *, register: bool = True) -> list[MinorPlanet]:
+    """
+    Load minor planet orbits from an MPCORB-format file.
+
+    Accepts MPCORB.DAT (including its text preamble) and other MPC one-line
+    orbit exports. Only elliptic orbits are supported.
+
+    Args:
+        path: Path to the orbit file
+        register: Register every orbit so that it can be used by name or
+            designation in ``get_body``, ``get_body_pv`` and moving-body
+            constraints (default: True)
+
+    Returns:
+        The parsed minor planets, in file order
+    """
+    ...
+
+def clear_minor_planets() -> None:
+    """Remove all registered minor planet orbits."""
+    ...
+
+def compare(
+    reference: Ephemeris,
+    other: Ephemeris,
+    *,
+    step_size: int | None = None,
+) -> EphemerisComparison:
+    """
+    Compare two ephemerides on a common time grid.
+
+    Both ephemerides are Hermite-interpolated onto a grid covering the time
+    span they share, and the GCRS differences are expressed in the
+    reference's radial / in-track / cross-track frame.
+
+    Args:
+        reference: Reference ephemeris, e.g. an OEM or numerical propagation
+        other: Ephemeris to validate, e.g. a TLE propagation
+        step_size: Grid step in seconds. By default the reference's own sample
+            times within the shared span are used
+
+    Returns:
+        EphemerisComparison with residual arrays and RMS/max statistics
+
+    Raises:
+        ValueError: If the ephemerides do not overlap in time or have fewer
+            than 2 samples
+
+    Example:
+        >>> result = compare(oem_ephem, tle_ephem, step_size=60)
+        >>> result.position_rms["in_track"], result.position_max["total"]
+    """
+    ...
+
+def align(
+    first: Ephemeris,
+    second: Ephemeris,
+    *,
+    step_size: int | None = None,
+) -> tuple[ArrayEphemeris, ArrayEphemeris]:
+    """
+    Resample two ephemerides onto a common time grid.
+
+    The grid covers the time span both ephemerides share, as for
+    :func:`compare`. Both are Hermite-interpolated in GCRS onto it and
+    returned as ``ArrayEphemeris`` objects with identical timestamps, ready
+    for relative-motion or visibility-between analyses.
+
+    Args:
+        first: First ephemeris
+        second: Second ephemeris
+        step_size: Grid step in seconds. By default the first ephemeris's own
+            sample times within the shared span are used
+
+    Returns:
+        Tuple of the first and second ephemerides on the common grid
+
+    Raises:
+        ValueError: If the ephemerides do not overlap in time or have fewer
+            than 2 samples
+
+    Example:
+        >>> a, b = align(chaser, target, step_size=10)
+        >>> relative = b.gcrs_pv.position - a.gcrs_pv.position
+    """
+    ...
 
-        Returns:
-            List of Moon illumination fractions
-        """
-        ...
+def estimate_tle_accuracy(
+    *,
+    tles: str | Sequence[Any] | None = None,
+    norad_id: int | None = None,
+    begin: datetime | None = None,
+    end: datetime | None = None,
+    max_age_days: float = 7.0,
+    bin_days: float = 0.5,
+    spacetrack_username: str | None = None,
+    spacetrack_password: str | None = None,
+) -> TLEAccuracy:
+    """
+    Estimate TLE prediction accuracy from consecutive element sets.
 
-    def get_body_pv(
-        self, body: str, spice_kernel: str | None = ..., use_horizons: bool = ...
-    ) -> PositionVelocityData:
-        """
-        Get position and velocity of a celestial body.
+    Every older TLE is propagated with SGP4 to the epoch of each newer TLE at
+    most ``max_age_days`` later, and the difference is binned by prediction
+    age. The resulting envelope can be used as a position uncertainty for
+    conjunction screening or pointing margins.
 
-        Args:
-            body: Name of the body (e.g., 'sun', 'moon', 'earth')
-            spice_kernel: Optional path to SPICE kernel
-            use_horizons: If True, fall back to JPL Horizons API when SPICE fails
+    Args:
+        tles: File path or URL with several TLEs, or a sequence of TLE strings,
+            ``(line1, line2)`` pairs, dicts with ``line1``/``line2`` keys or
+            ``TLERecord`` objects
+        norad_id: Download the TLE history of this object from Space-Track.org
+            instead of using ``tles``
+        begin: Start of the Space-Track history (default: 30 days before end)
+        end: End of the Space-Track history (default: now)
+        max_age_days: Longest prediction age to include
+        bin_days: Width of the prediction-age bins
+        spacetrack_username: Space-Track.org username (or use environment variables)
+        spacetrack_password: Space-Track.org password (or use environment variables)
 
-        Returns:
-            Position and velocity data for the requested body
-        """
-        ...
+    Returns:
+        TLEAccuracy with per-pair residuals and per-bin RMS statistics
 
-    def get_body(
-        self, body: str, spice_kernel: str | None = ..., use_horizons: bool = ...
-    ) -> Any:  # Returns astropy.coordinates.SkyCoord
-        """
-        Get SkyCoord for a celestial body.
+    Raises:
+        ValueError: If fewer than two distinct TLEs are available, no pair is
+            within ``max_age_days``, or the TLEs cannot be read
 
-        Args:
-            body: Name of the body (e.g., 'sun', 'moon', 'earth')
-            spice_kernel: Optional path to SPICE kernel
-            use_horizons: If True, fall back to JPL Horizons API when SPICE fails
+    Example:
+        >>> acc = estimate_tle_accuracy(norad_id=25544, max_age_days=5)
+        >>> acc.envelope(2.0, "in_track")
+    """
+    ...
 
-        Returns:
-            astropy.coordinates.SkyCoord object
-        """
-        ...
+def estimate_decay(
+    tle: str | tuple[str, str] | dict[str, Any] | Any,
+    *,
+    ballistic_coefficient: float | None = None,
+    reentry_altitude_km: float = 180.0,
+    max_days: float = 9131.0,
+    f107: float | None = None,
+    ap: float | None = None,
+) -> DecayEstimate:
+    """
+    Estimate orbit decay and re-entry under atmospheric drag.
 
-def init_planetary_ephemeris(
-    py_path: str,
-) -> None:
+    The orbit is treated as circular at the altitude implied by the mean
+    motion and integrated in steps of at most a day until it reaches
+    ``reentry_altitude_km`` or ``max_days`` have passed. Density follows a
+    simple exponential model calibrated for 180-500 km, driven by F10.7 (the
+    81-day centred average where available) and daily Ap from the Celestrak
+    space-weather data, or mean solar-cycle values (150 sfu, Ap 15) outside it.
+
+    Args:
+        tle: TLE string, ``(line1, line2)`` pair, dict with ``line1``/``line2``
+            keys or ``TLERecord``; or an OMM dict with CCSDS keys (``EPOCH``,
+            ``MEAN_MOTION``, ``ECCENTRICITY``, ``BSTAR``) as in Celestrak or
+            Space-Track JSON
+        ballistic_coefficient: m/(Cd A) in kg/m² (default: from BSTAR)
+        reentry_altitude_km: Altitude at which integration stops
+        max_days: Longest time to integrate (default: 25 years)
+        f107: Fixed F10.7 in sfu instead of the space-weather data
+        ap: Fixed daily Ap instead of the space-weather data
+
+    Returns:
+        DecayEstimate with the altitude history and re-entry time
+
+    Raises:
+        ValueError: If BSTAR is not positive and no ballistic coefficient is
+            given, the eccentricity exceeds 0.1, or the mean altitude is
+            already below ``reentry_altitude_km``
+
+    Example:
+        >>> est = estimate_decay(tle, f107=120.0, ap=10.0)
+        >>> est.initial_decay_rate_km_per_day, est.reentry_time
     """
-    Initialize SPICE planetary ephemeris kernels from file.
+    ...
+
+def validate_elements(
+    elements: str | tuple[str, str] | dict[str, Any] | Any,
+    *,
+    now: datetime | None = None,
+) -> list[dict[str, Any]]:
+    """
+    Sanity-check a TLE or OMM element set.
+
+    The checks are the ones ``TLEEphemeris`` runs before propagating: the TLE
+    format and checksums, eccentricity in [0, 1), inclination in [0°, 180°],
+    a positive mean motion with the perigee above the surface, an epoch after
+    the launch of Sputnik 1 and a finite BSTAR. A perigee below 100 km, an
+    epoch more than a day after ``now`` or a BSTAR magnitude above 0.05 give
+    warnings.
 
     Args:
-        py_path: Path to the planetary ephemeris kernel file (SPK)
+        elements: TLE string, ``(line1, line2)`` pair, dict with
+            ``line1``/``line2`` keys or ``TLERecord``; or an OMM dict with
+            CCSDS keys or OMM JSON object string as in Celestrak or
+            Space-Track JSON
+        now: Time the epoch is compared with (default: the current time)
+
+    Returns:
+        One dict per problem with ``level`` (``"error"`` or ``"warning"``),
+        ``field`` and ``message``; empty if the elements look sound. An
+        unparseable element set gives a single error with field ``"format"``
+
+    Example:
+        >>> for issue in validate_elements((line1, line2)):
+        ...     print(issue["level"], issue["field"], issue["message"])
+    """
+    ...
+
+def detect_maneuvers(
+    ephemeris: Ephemeris | None = None,
+    *,
+    tles: str | Sequence[Any] | None = None,
+    norad_id: int | None = None,
+    begin: datetime | None = None,
+    end: datetime | None = None,
+    delta_v_threshold: float = 0.1,
+    sma_threshold_km: float = 0.2,
+    spacetrack_username: str | None = None,
+    spacetrack_password: str | None = None,
+) -> list[ManeuverCandidate]:
+    """
+    Detect candidate maneuvers in an ephemeris or a TLE history.
+
+    With ``ephemeris`` (an ``OEMEphemeris`` or any other ephemeris), each
+    sample is propagated to the next under two-body plus J2 gravity and the
+    velocity mismatch is the residual delta-v. Consecutive steps above
+    ``delta_v_threshold`` are merged into one candidate.
+
+    Otherwise the TLEs are read as in :func:`estimate_tle_accuracy`. The mean
+    semi-major axis of each TLE is differenced between consecutive TLEs, the
+    median drift rate (drag decay) is removed and jumps larger than
+    ``sma_threshold_km`` are reported with the tangential delta-v that would
+    cause them.
+
+    Args:
+        ephemeris: Ephemeris whose states are checked
+        tles: File path or URL with several TLEs, or a sequence of TLEs
+        norad_id: Download the TLE history of this object from Space-Track.org
+        begin: Start of the Space-Track history (default: 30 days before end)
+        end: End of the Space-Track history (default: now)
+        delta_v_threshold: Smallest residual delta-v reported for an
+            ephemeris, in m/s
+        sma_threshold_km: Smallest semi-major axis jump reported for a TLE
+            history, in km
+        spacetrack_username: Space-Track.org username (or use environment variables)
+        spacetrack_password: Space-Track.org password (or use environment variables)
+
+    Returns:
+        Candidate maneuvers in time order
 
     Raises:
-        RuntimeError: If initialization fails
+        ValueError: If both an ephemeris and TLEs are given, a threshold is
+            not positive, or there are fewer than two states or three
+            distinct TLEs
+
+    Example:
+        >>> for burn in detect_maneuvers(OEMEphemeris("sat.oem")):
+        ...     print(burn.epoch, burn.delta_v_m_s)
     """
     ...
 
-def download_planetary_ephemeris(
-    url: str,
-    dest: str,
-) -> None:
+def nodal_analysis(
+    ephemeris: Ephemeris,
+    *,
+    max_repeat_days: int = 60,
+    repeat_tolerance_km: float = 10.0,
+) -> NodalAnalysis:
     """
-    Download planetary ephemeris kernel from URL to destination.
+    Nodal period, RAAN drift and repeat ground-track cycle of an orbit.
+
+    Ascending node crossings on the true equator of date are located by
+    Hermite interpolation between samples. Their spacing gives the nodal
+    period of every revolution, and a straight-line fit through the node
+    longitudes gives the RAAN drift rate. A repeat cycle of ``D`` nodal days
+    and ``N`` revolutions is reported when the ascending node returns within
+    ``repeat_tolerance_km`` of its start at the equator; the shortest such
+    cycle up to ``max_repeat_days`` is returned.
 
     Args:
-        url: URL to download the kernel from
-        dest: Destination file path
+        ephemeris: Ephemeris spanning at least one full orbit, sampled well
+            under half an orbit apart (a day or more gives good drift rates)
+        max_repeat_days: Longest repeat cycle searched, in nodal days
+        repeat_tolerance_km: Largest node offset at the equator that counts
+            as a repeat
+
+    Returns:
+        NodalAnalysis with the per-orbit periods, drift and repeat cycle
 
     Raises:
-        RuntimeError: If download fails
+        ValueError: If fewer than two ascending nodes are found or
+            ``repeat_tolerance_km`` is not positive
+
+    Example:
+        >>> nodes = nodal_analysis(eph)
+        >>> nodes.sun_synchronous_offset_deg_per_day, nodes.repeat_cycle_days
     """
     ...
 
-def ensure_planetary_ephemeris(
-    py_path: str | None = None,
-    download_if_missing: bool = True,
-    spk_url: str | None = None,
-    prefer_full: bool = False,
-) -> None:
+def link_geometry(
+    ephemeris: Ephemeris,
+    station: GroundEphemeris | str | tuple[str, float, float, float],
+    frequency_mhz: float,
+    *,
+    zenith_attenuation_db: float = 0.0,
+    min_elevation: float = 0.0,
+    polar_motion: bool | None = None,
+) -> LinkGeometry:
     """
-    Ensure planetary ephemeris is available, downloading if necessary.
+    Link geometry and path loss between a ground station and a spacecraft.
+
+    Samples slant range, elevation and azimuth at the ephemeris times and
+    derives the free-space path loss at ``frequency_mhz`` together with an
+    atmospheric penalty. The penalty scales ``zenith_attenuation_db`` with the
+    path length through a spherical-shell atmosphere, so it grows roughly as
+    the cosecant of the elevation but stays finite at the horizon (about 46
+    times the zenith value).
 
     Args:
-        py_path: Optional explicit path to kernel file
-        download_if_missing: If True, download if file not found
-        spk_url: Optional custom URL for download
-        prefer_full: If True, prefer full DE440 over slim DE440S
+        ephemeris: Spacecraft ephemeris (any ephemeris type)
+        station: ``GroundEphemeris`` (its horizon profile is used), site name,
+            or ``(name, latitude, longitude, height)`` tuple with height in
+            meters, as for :class:`GroundNetwork`
+        frequency_mhz: Carrier frequency in MHz
+        zenith_attenuation_db: Clear-sky atmospheric attenuation at zenith in
+            dB for the band and site (default: 0.0, no atmospheric penalty)
+        min_elevation: Minimum elevation in degrees for the spacecraft to be
+            in view, applied in addition to any horizon profile (default: 0.0)
+        polar_motion: Apply polar motion to the spacecraft's Earth-fixed
+            positions; None uses the ephemeris's own setting
+
+    Returns:
+        LinkGeometry with per-time range, look angles and losses
 
     Raises:
-        FileNotFoundError: If file not found and download_if_missing=False
-        RuntimeError: If download or initialization fails
+        ValueError: If ``frequency_mhz`` is not positive,
+            ``zenith_attenuation_db`` is negative, ``min_elevation`` is out of
+            range or the site is unknown
+
+    Example:
+        >>> link = link_geometry(tle_ephem, "Goldstone", 8400.0,
+        ...                      zenith_attenuation_db=0.04, min_elevation=10.0)
+        >>> link.max_total_loss_db, link.passes
     """
     ...
 
-def is_planetary_ephemeris_initialized() -> bool:
+def schedule(
+    ephemeris: Ephemeris,
+    targets: Sequence[tuple[float, float] | str],
+    constraints: Constraint | Sequence[Constraint],
+    exposure_times: float | Sequence[float],
+    priorities: Sequence[float] | None = None,
+    *,
+    slew_rate: float = 1.0,
+    settle_time: float = 0.0,
+) -> Schedule:
     """
-    Check if planetary ephemeris has been initialized.
+    Greedily schedule observations of targets on an ephemeris timeline.
+
+    Targets are taken in priority order (highest first, ties in list order)
+    and each is placed at the earliest time its whole exposure fits inside
+    one of its visibility windows without overlapping observations already
+    placed, leaving time to slew from the previous observation and on to the
+    next. Placed observations are never moved.
+
+    Args:
+        ephemeris: Spacecraft or observer ephemeris defining the timeline
+        targets: ``(ra, dec)`` pairs in degrees, or object names resolved
+            with :func:`resolve_name`
+        constraints: One constraint for all targets, or one per target
+        exposure_times: Exposure time in seconds, for all targets or per target
+        priorities: Per-target priorities; higher values are scheduled first
+            (default: all equal)
+        slew_rate: Slew rate in degrees per second (default: 1.0)
+        settle_time: Fixed settle time in seconds added to every slew
+            (default: 0.0)
 
     Returns:
-        True if ephemeris is initialized and ready to use
+        Schedule with the observations in time order and the indices of
+        targets that did not fit
+
+    Raises:
+        ValueError: If per-target arguments have the wrong length or a value
+            is out of range
+
+    Note:
+        Visibility windows run between ephemeris sample times, so their
+        resolution is the ephemeris step size.
+
+    Example:
+        >>> plan = schedule(eph, [(10.0, 20.0), (150.0, -30.0)], constraint,
+        ...                 [600.0, 900.0], priorities=[1.0, 2.0], slew_rate=0.5)
+        >>> [(o.target_index, o.start_time) for o in plan.observations]
     """
     ...
 
-def get_tai_utc_offset(py_datetime: datetime) -> float | None:
+def visibility_report(
+    ephemeris: Ephemeris,
+    targets: Sequence[tuple[float, float] | str],
+    constraint: Constraint,
+    *,
+    names: Sequence[str] | None = None,
+) -> VisibilityReport:
     """
-    Get TAI-UTC offset (leap seconds) at the given time.
+    Summarize the visibility of each target under a constraint.
+
+    For every target the report gives the total visible time, the number and
+    longest of its visibility windows, the first and last visible times, and
+    how long each blocking constraint is violated.
 
     Args:
-        py_datetime: UTC datetime (naive datetime treated as UTC)
+        ephemeris: Spacecraft or observer ephemeris defining the timeline
+        targets: ``(ra, dec)`` pairs in degrees, or object names resolved
+            with :func:`resolve_name`
+        constraint: Constraint to evaluate; the sub-constraints of an OR
+            combination are reported separately as blocking constraints
+        names: Optional target names, included as the first report column
 
     Returns:
-        TAI-UTC offset in seconds, or None if not available
+        VisibilityReport with one row per target
+
+    Raises:
+        ValueError: If ``names`` does not have one entry per target
+
+    Example:
+        >>> report = visibility_report(eph, [(10.0, 20.0), (150.0, -30.0)],
+        ...                            constraint, names=["A", "B"])
+        >>> pandas.DataFrame(report.to_dict())
     """
     ...
 
-def get_ut1_utc_offset(py_datetime: datetime) -> float:
+def orbit_visibility(
+    ephemeris: Ephemeris,
+    target: tuple[float, float] | str,
+    constraint: Constraint,
+) -> OrbitVisibility:
     """
-    Get UT1-UTC offset at the given time.
+    Per-orbit visibility of a target under a constraint.
+
+    Splits the ephemeris into orbits at the ascending node and reports the
+    visible fraction of each orbit and where in the orbit the target is
+    visible, by argument of latitude and by solar phase. Solar phase is
+    measured in the direction of motion from orbit noon: 90° is the dusk
+    side, 180° orbit midnight and 270° the dawn side.
 
     Args:
-        py_datetime: UTC datetime (naive datetime treated as UTC)
+        ephemeris: Orbiting spacecraft ephemeris
+        target: ``(ra, dec)`` in degrees, or an object name resolved with
+            :func:`resolve_name`
+        constraint: Constraint to evaluate
 
     Returns:
-        UT1-UTC offset in seconds
+        OrbitVisibility with one row per orbit
 
     Raises:
-        RuntimeError: If UT1 provider is not initialized
+        ValueError: If the ephemeris is a ground site
+
+    Example:
+        >>> orbits = orbit_visibility(eph, (83.63, 22.01), constraint)
+        >>> orbits.mean_visible_fraction, orbits.solar_phase_concentration
     """
     ...
 
-def is_ut1_available() -> bool:
+def constraint_cube(
+    ephemeris: Ephemeris,
+    constraints: Sequence[Constraint],
+    target_ras: Sequence[float],
+    target_decs: Sequence[float],
+    *,
+    chunk_size: int = 256,
+) -> ConstraintCube:
     """
-    Check if UT1 data is available.
+    Evaluate several constraints for many targets as one boolean cube.
+
+    Each constraint is evaluated in batch for all targets over all ephemeris
+    times, filling a ``(n_constraints, n_targets, n_times)`` violation cube.
+    The work is split into one task per constraint and block of
+    ``chunk_size`` targets, run in parallel on a work-stealing thread pool
+    with the GIL released; all tasks share the one ephemeris. Reductions
+    over the constraint axis are done in Rust by
+    :meth:`ConstraintCube.all_constraints` and
+    :meth:`ConstraintCube.any_violation`.
+
+    Args:
+        ephemeris: Spacecraft or observer ephemeris
+        constraints: Constraints to evaluate, one per cube layer
+        target_ras: Target right ascensions in degrees
+        target_decs: Target declinations in degrees
+        chunk_size: Targets per parallel task (default: 256)
 
     Returns:
-        True if UT1 provider is initialized
+        ConstraintCube with the violation masks
+
+    Raises:
+        ValueError: If ``target_ras`` and ``target_decs`` differ in length, or
+            ``chunk_size`` is not positive
+
+    Example:
+        >>> cube = constraint_cube(eph, [sun, moon, limb], ras, decs)
+        >>> observable = cube.all_constraints()  # (n_targets, n_times)
+        >>> blocked_by_sun = cube.cube[0].mean(axis=1)
     """
     ...
 
-def init_ut1_provider() -> bool:
+def virtual_target_radec(
+    ephemeris: Ephemeris,
+    target: Literal["nadir", "limb", "sun", "anti_sun", "ram", "anti_ram"],
+    *,
+    azimuth: float | None = None,
+) -> tuple[npt.NDArray[np.float64], npt.NDArray[np.float64]]:
     """
-    Initialize UT1 provider with IERS data.
+    Directions of a virtual target over an ephemeris.
+
+    Virtual targets follow the observer state: "nadir" points at the Earth's
+    center, "limb" at the Earth limb tangent point, "sun" and "anti_sun"
+    along and against the Sun direction, and "ram" and "anti_ram" along and
+    against the GCRS velocity. The returned coordinates can be passed
+    anywhere per-time RA/Dec targets are accepted, such as
+    ``Constraint.evaluate_moving_body``.
+
+    Args:
+        ephemeris: Spacecraft or observer ephemeris
+        target: Virtual target name
+        azimuth: For "limb" only, azimuth of the limb tangent point about nadir
+            in degrees, 0 toward ram and 90 toward the orbit normal (default: 0)
 
     Returns:
-        True if initialization succeeded
+        Tuple ``(ras, decs)`` in degrees, one per ephemeris time
+
+    Raises:
+        ValueError: If the target is unknown, azimuth is given for a target
+            other than "limb", or the ephemeris lacks needed velocity data
+
+    Example:
+        >>> ras, decs = virtual_target_radec(eph, "limb", azimuth=90.0)
+        >>> result = sun.evaluate_moving_body(eph, target_ras=ras, target_decs=decs)
     """
     ...
 
-def get_polar_motion(py_datetime: datetime) -> tuple[float, float]:
+def great_circle_separation(
+    lon1: Sequence[float],
+    lat1: Sequence[float],
+    lon2: Sequence[float],
+    lat2: Sequence[float],
+) -> npt.NDArray[np.float64]:
     """
-    Get polar motion (x, y) at the given time.
+    Great-circle separations in degrees between corresponding points.
+
+    Prefer :func:`rust_ephem.sphere.separation`, which broadcasts its inputs.
+
+    Raises:
+        ValueError: If the arrays differ in length
+    """
+    ...
+
+def position_angle(
+    lon1: Sequence[float],
+    lat1: Sequence[float],
+    lon2: Sequence[float],
+    lat2: Sequence[float],
+) -> npt.NDArray[np.float64]:
+    """
+    Position angles in degrees east of north of the second points from the first.
+
+    Prefer :func:`rust_ephem.sphere.position_angle`, which broadcasts its inputs.
+
+    Raises:
+        ValueError: If the arrays differ in length
+    """
+    ...
+
+def cones_overlap(
+    lon1: Sequence[float],
+    lat1: Sequence[float],
+    radius1: Sequence[float],
+    lon2: Sequence[float],
+    lat2: Sequence[float],
+    radius2: Sequence[float],
+) -> npt.NDArray[np.bool_]:
+    """
+    Whether corresponding pairs of cones overlap; all angles in degrees.
+
+    Prefer :func:`rust_ephem.sphere.cones_overlap`, which broadcasts its inputs.
+
+    Raises:
+        ValueError: If the arrays differ in length or a radius is negative
+    """
+    ...
+
+def check_attitude(
+    ephemeris: Ephemeris,
+    quaternions: Sequence[Sequence[float]] | npt.NDArray[np.float64],
+    constraints: Sequence[Constraint],
+    *,
+    axes: dict[str, Sequence[float]] | None = None,
+) -> AttitudeCheck:
+    """
+    Check a planned attitude history against pointing constraints.
+
+    Each body-frame axis is rotated through the attitude history and
+    evaluated against every constraint, so a slew or pointing plan can be
+    checked as presented rather than for a fixed target.
 
     Args:
-        py_datetime: UTC datetime (naive datetime treated as UTC)
+        ephemeris: Spacecraft ephemeris; attitudes are sampled at its times
+        quaternions: One body-to-ICRS quaternion per ephemeris time, scalar
+            last ``(x, y, z, w)`` as used by ``scipy.spatial.transform.Rotation``.
+            Quaternions are normalized before use.
+        constraints: Constraints to check each axis against
+        axes: Body-frame direction of each named axis or instrument
+            (default: ``{"boresight": (1, 0, 0)}``)
 
     Returns:
-        Tuple of (x, y) polar motion in arcseconds
+        AttitudeCheck with violation windows tagged by axis and constraint
 
     Raises:
-        RuntimeError: If EOP provider is not initialized
+        ValueError: If the number of quaternions does not match the ephemeris,
+            a quaternion has zero norm, or an axis is a zero vector
+
+    Example:
+        >>> rot = Rotation.from_euler("z", np.linspace(0, 90, len(eph.timestamp)),
+        ...                           degrees=True)
+        >>> check = check_attitude(eph, rot.as_quat(), [sun, limb],
+        ...                        axes={"boresight": (1, 0, 0), "star_tracker": (0, 1, 0)})
+        >>> for v in check.violations:
+        ...     print(v.axis, v.constraint, v.start_time, v.end_time)
     """
     ...
 
-def is_eop_available() -> bool:
+def face_sun_incidence(
+    ephemeris: Ephemeris,
+    quaternions: Sequence[Sequence[float]] | npt.NDArray[np.float64],
+    faces: dict[str, Sequence[float]],
+    *,
+    umbra_only: bool = False,
+) -> FaceIncidence:
     """
-    Check if Earth Orientation Parameters (EOP) data is available.
+    Sun incidence angle on each body face over a planned attitude history.
+
+    For quick thermal and power screening of a pointing plan: each face
+    normal is rotated through the attitude history and compared with the
+    direction from the spacecraft to the Sun.
+
+    Args:
+        ephemeris: Spacecraft ephemeris; attitudes are sampled at its times
+        quaternions: One body-to-ICRS quaternion per ephemeris time, scalar
+            last ``(x, y, z, w)``, as for :func:`check_attitude`
+        faces: Outward body-frame normal of each named face, for example
+            ``{"+X": (1, 0, 0), "solar_array": (0, 0, -1)}``
+        umbra_only: Count only the umbra as eclipse (default: umbra and
+            penumbra)
 
     Returns:
-        True if EOP provider is initialized
+        FaceIncidence with incidence angles and insolation flags per face and time
+
+    Raises:
+        ValueError: If the number of quaternions does not match the ephemeris,
+            a quaternion has zero norm, or a face normal is a zero vector
+
+    Example:
+        >>> inc = face_sun_incidence(eph, rot.as_quat(),
+        ...                          {"+Z": (0, 0, 1), "-Z": (0, 0, -1)})
+        >>> inc.sunlit.mean(axis=1)  # fraction of time each face is lit
     """
     ...
 
-def init_eop_provider() -> bool:
+def check_slew(
+    ephemeris: Ephemeris,
+    start_time: datetime,
+    start_quaternion: Sequence[float],
+    end_quaternion: Sequence[float],
+    slew_rate: float,
+    trackers: dict[str, Sequence[float]],
+    *,
+    sun_angle: float,
+    moon_angle: float,
+    step_seconds: float = 1.0,
+) -> SlewCheck:
     """
-    Initialize EOP provider with IERS data.
+    Check a slew for Sun and Moon intrusions into star-tracker exclusion cones.
+
+    The attitude is interpolated along the eigen-axis rotation (the shorter
+    way round) from ``start_quaternion`` to ``end_quaternion`` at a constant
+    rate, so a maneuver between two safe attitudes that sweeps a tracker
+    across the Sun or Moon is caught.
+
+    Args:
+        ephemeris: Spacecraft ephemeris covering the whole slew; Sun and Moon
+            directions are interpolated linearly between its samples
+        start_time: Start of the slew
+        start_quaternion: Body-to-ICRS attitude at the start, scalar last
+            ``(x, y, z, w)``, as for :func:`check_attitude`
+        end_quaternion: Body-to-ICRS attitude at the end of the slew
+        slew_rate: Rotation rate in degrees per second
+        trackers: Body-frame boresight of each named star tracker
+        sun_angle: Sun exclusion half-angle in degrees
+        moon_angle: Moon exclusion half-angle in degrees
+        step_seconds: Sample spacing along the slew (the end attitude is
+            always sampled)
 
     Returns:
-        True if initialization succeeded
+        SlewCheck with the angles along the slew and intrusion windows
+
+    Raises:
+        ValueError: If a quaternion has zero norm, a tracker axis is a zero
+            vector, ``slew_rate`` or ``step_seconds`` is not positive, or the
+            slew runs outside the ephemeris
+
+    Example:
+        >>> check = check_slew(eph, t0, q_from, q_to, 0.5,
+        ...                    {"st1": (0, 0.7071, 0.7071), "st2": (0, -0.7071, 0.7071)},
+        ...                    sun_angle=40.0, moon_angle=20.0)
+        >>> for i in check.intrusions:
+        ...     print(i.tracker, i.body, i.start_time, i.end_time, i.min_angle_deg)
     """
     ...
 
-def get_cache_dir() -> str:
+def airmass_summary(
+    ephemeris: Ephemeris,
+    ras: Sequence[float],
+    decs: Sequence[float],
+    *,
+    max_airmass: float = 2.0,
+) -> AirmassSummary:
     """
-    Get the cache directory used for storing ephemeris data.
+    Summarize the airmass of each target over all ephemeris times.
+
+    Uses the fast (Kasten) airmass approximation for all targets at once and
+    reduces it in Rust to the minimum airmass, the time it is reached and the
+    hours spent at or below ``max_airmass``. Hours are measured between
+    ephemeris samples, as for visibility windows.
+
+    Args:
+        ephemeris: Observer ephemeris
+        ras: Target right ascensions in degrees
+        decs: Target declinations in degrees
+        max_airmass: Airmass limit for :attr:`AirmassSummary.hours_below`
 
     Returns:
-        String path to the cache directory
+        AirmassSummary with one entry per target
+
+    Raises:
+        ValueError: If ``ras`` and ``decs`` differ in length or
+            ``max_airmass`` is below 1
+    """
+    ...
+
+def get_site(name: str) -> dict[str, Any]:
+    """
+    Look up an observatory or ground-station site.
+
+    Names, aliases and IAU observatory codes are matched ignoring case,
+    spaces and punctuation.
+
+    Args:
+        name: Site name, alias or code (e.g. "Paranal", "VLT", "309")
+
+    Returns:
+        Dict with keys ``name``, ``code``, ``latitude``, ``longitude`` (degrees),
+        ``height`` (meters above WGS84) and ``aliases``
+
+    Raises:
+        ValueError: If the site is unknown
+    """
+    ...
+
+def list_sites() -> list[str]:
+    """Canonical names of all known sites, sorted."""
+    ...
+
+def register_site(
+    name: str,
+    latitude: float,
+    longitude: float,
+    height: float,
+    *,
+    code: str | None = None,
+    aliases: list[str] | None = None,
+) -> None:
+    """
+    Add a site to the registry, replacing any site with the same name.
+
+    Registered sites can be used with ``GroundEphemeris(site=name)`` for the
+    rest of the process.
+
+    Args:
+        name: Canonical site name
+        latitude: Geodetic latitude in degrees (-90 to 90)
+        longitude: Geodetic longitude in degrees (-180 to 180)
+        height: Height in meters above the WGS84 ellipsoid
+        code: Optional IAU/MPC observatory code
+        aliases: Optional alternative names
+    """
+    ...
+
+def set_body_radii(
+    body: str, equatorial_km: float, polar_km: float | None = None
+) -> None:
+    """
+    Set the radii of a body used by limb, eclipse and angular-radius calculations.
+
+    Overrides apply to constraints created and angular radii first computed
+    after the call, for the rest of the process. Inflating a radius adds a
+    planning margin around the body.
+
+    Args:
+        body: Body name or NAIF ID (e.g. "Earth", "301"); bodies unknown to
+            SPICE may be given a shape too
+        equatorial_km: Equatorial radius in km
+        polar_km: Polar radius in km (default: ``equatorial_km``)
+
+    Raises:
+        ValueError: If a radius is not a positive number
+    """
+    ...
+
+def get_body_radii(body: str) -> dict[str, float]:
+    """
+    Radii of a body in effect, built-in or overridden.
+
+    Args:
+        body: Body name or NAIF ID
+
+    Returns:
+        Dict with keys ``equatorial_km`` and ``polar_km``
+
+    Raises:
+        ValueError: If no radii are known for the body
     """
     ...
 
+def reset_body_radii(body: str | None = None) -> None:
+    """Restore the built-in radii of one body, or of all bodies when None."""
+    ...
+
 def fetch_tle(
     *,
     tle: str | None = None,
@@ -3548,6 +13350,7 @@ def fetch_tle(
     spacetrack_password: str | None = None,
     epoch_tolerance_days: float | None = None,
     enforce_source: str | None = None,
+    cache_ttl: int | None = None,
 ) -> dict[str, Any]:
     """
     Fetch a TLE from various sources (file, URL, Celestrak, Space-Track.org).
@@ -3569,9 +13372,13 @@ def fetch_tle(
             differ from target epoch (default: 4.0 days)
         enforce_source: Enforce use of specific source without failover.
             Must be "celestrak", "spacetrack", or None (default behavior with failover)
+        cache_ttl: Largest age in seconds of a cached Celestrak or URL download
+            before it is fetched again; 0 forces a refresh (default: 86400, see
+            set_tle_cache_defaults)
 
     Returns:
-        Dict with keys: line1, line2, name (optional), epoch (datetime), source
+        Dict with keys: line1, line2, name (optional), epoch (datetime), source,
+        decay_date (SATCAT decay date for NORAD ID and name lookups, or None)
 
     Raises:
         ValueError: If no valid TLE source is specified or fetching fails
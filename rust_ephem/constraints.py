@@ -9,16 +9,28 @@ to configure the Rust constraint evaluators.
 from __future__ import annotations
 
 import json
-from datetime import datetime
+import os
+from datetime import datetime, timezone
+from collections.abc import Callable, Hashable, Mapping, Sequence
 from enum import Enum
 from typing import TYPE_CHECKING, Any, Literal, Union, cast
 
 import numpy as np
 import numpy.typing as npt
-from pydantic import BaseModel, ConfigDict, Field, TypeAdapter, model_validator
+from pydantic import (
+    BaseModel,
+    ConfigDict,
+    Field,
+    TypeAdapter,
+    field_validator,
+    model_validator,
+)
 
 import rust_ephem
 
+from ._rust_ephem import CancellationToken
+from ._rust_ephem import ConstraintResult as _RustConstraintResult
+from ._rust_ephem import Provenance
 from .ephemeris import Ephemeris
 
 #: Default number of roll-angle samples used when sweeping spacecraft roll in
@@ -31,6 +43,61 @@ DEFAULT_N_ROLL_SAMPLES: int = 360
 DEFAULT_N_POINTS: int = 20_000
 
 
+def _to_datetime64_array(times: list[datetime]) -> npt.NDArray[np.datetime64]:
+    """Convert datetimes (naive values are taken as UTC) to UTC datetime64[us]."""
+    return np.array(
+        [
+            t.astimezone(timezone.utc).replace(tzinfo=None) if t.tzinfo else t
+            for t in times
+        ],
+        dtype="datetime64[us]",
+    )
+
+
+#: Progress callable of long runs, called as ``progress(done, total)``
+ProgressCallback = Callable[[int, int], bool | None]
+
+
+def _offset_progress(
+    progress: ProgressCallback | None, offset: int, total: int
+) -> ProgressCallback | None:
+    """Report progress of a sub-batch starting at ``offset`` of ``total`` items."""
+    if progress is None:
+        return None
+
+    def report(done: int, _sub_total: int) -> bool | None:
+        return progress(offset + done, total)
+
+    return report
+
+
+def _lookup_indices(
+    grid: npt.NDArray[np.datetime64],
+    times: npt.NDArray[np.datetime64],
+    mode: str,
+) -> npt.NDArray[np.intp]:
+    """Indices of ``grid`` that ``times`` resolve to, as for the Rust lookup."""
+    if mode not in ("exact", "nearest", "previous"):
+        raise ValueError(f"mode must be 'exact', 'nearest' or 'previous', got '{mode}'")
+    n = len(grid)
+    if n == 0:
+        raise ValueError("no evaluated timestamps")
+    after = np.searchsorted(grid, times)
+    at_or_after = np.minimum(after, n - 1)
+    exact = grid[at_or_after] == times
+    if mode == "exact":
+        if not exact.all():
+            raise ValueError("time not found in evaluated timestamps")
+        return at_or_after
+    if np.any(~exact & ((after == 0) | (after == n))):
+        raise ValueError("time is outside the evaluated time range")
+    before = np.maximum(after - 1, 0)
+    if mode == "nearest":
+        closer_after = (grid[at_or_after] - times) < (times - grid[before])
+        before = np.where(closer_after, at_or_after, before)
+    return np.where(exact, at_or_after, before)
+
+
 class ConstraintViolation(BaseModel):
     """A time window where a constraint was violated."""
 
@@ -79,6 +146,22 @@ class ConstraintResult(BaseModel):
             )
         return []
 
+    @property
+    def storage(self) -> str:
+        """How the evaluation times are stored: "full", "compressed" or "windows"."""
+        if hasattr(self, "_rust_result_ref") and self._rust_result_ref is not None:
+            return cast(str, self._rust_result_ref.storage)
+        return "full"
+
+    @property
+    def n_times(self) -> int:
+        """Number of evaluated times, also when they are not stored."""
+        if self._swept_timestamps is not None:
+            return len(self._swept_timestamps)
+        if hasattr(self, "_rust_result_ref") and self._rust_result_ref is not None:
+            return cast(int, self._rust_result_ref.n_times)
+        return 0
+
     @property
     def constraint_array(self) -> list[bool]:
         """
@@ -105,6 +188,32 @@ class ConstraintResult(BaseModel):
             )
         return []
 
+    @property
+    def visibility_windows(self) -> list["rust_ephem.VisibilityWindow"]:
+        """Satisfied windows: the complement of the violations.
+
+        Windows cover the evaluated times not in any violation. Windows at the
+        start and end of the evaluation are bounded by the first and last
+        evaluated times. Windows of a single sample are omitted, except at the
+        end of the evaluation, the same as ``visibility``.
+        """
+        if hasattr(self, "_rust_result_ref") and self._rust_result_ref is not None:
+            return cast(
+                list["rust_ephem.VisibilityWindow"],
+                self._rust_result_ref.visibility_windows,
+            )
+        return []
+
+    @property
+    def violation_start_times(self) -> npt.NDArray[np.datetime64]:
+        """Start times of the violation windows as UTC ``datetime64[us]``."""
+        return _to_datetime64_array([v.start_time for v in self.violations])
+
+    @property
+    def violation_end_times(self) -> npt.NDArray[np.datetime64]:
+        """End times of the violation windows as UTC ``datetime64[us]``."""
+        return _to_datetime64_array([v.end_time for v in self.violations])
+
     def total_violation_duration(self) -> float:
         """Get the total duration of violations in seconds."""
         total_seconds = 0.0
@@ -112,34 +221,126 @@ class ConstraintResult(BaseModel):
             total_seconds += (violation.end_time - violation.start_time).total_seconds()
         return total_seconds
 
-    def in_constraint(self, time: datetime) -> bool:
+    def in_constraint(self, time: datetime, mode: str = "exact") -> bool:
         """Check if target is in-constraint at a given time.
 
         This method operates on timestamps from the evaluate() call.
-        The given time must exist in the evaluated timestamps.
 
         Args:
-            time: The datetime to check (must be in evaluated timestamps)
+            time: The datetime to check (naive datetimes are treated as UTC)
+            mode: How ``time`` maps onto the evaluated timestamps: ``"exact"``
+                requires an evaluated timestamp, ``"nearest"`` uses the closest
+                one and ``"previous"`` the one starting the interval that
+                contains ``time``
 
         Returns:
             True if the constraint is violated at this time (target is in-constraint),
             False if the constraint is satisfied (target is out-of-constraint).
 
         Raises:
-            ValueError: If the time is not found in evaluated timestamps
+            ValueError: If the time is not found in evaluated timestamps (or is
+                outside their range for ``"nearest"`` and ``"previous"``)
         """
         if self._swept_timestamps is not None and self._swept_array is not None:
-            try:
-                idx = self._swept_timestamps.index(time)
-                return self._swept_array[idx]
-            except ValueError:
-                raise ValueError(f"Time {time} not found in evaluated timestamps")
+            return bool(self.in_constraint_array([time], mode)[0])
         if hasattr(self, "_rust_result_ref") and self._rust_result_ref is not None:
-            return cast(bool, self._rust_result_ref.in_constraint(time))
+            return cast(bool, self._rust_result_ref.in_constraint(time, mode))
         raise ValueError(
             "ConstraintResult has no evaluated timestamps (was not created from evaluate())"
         )
 
+    def in_constraint_array(
+        self,
+        times: list[datetime] | npt.NDArray[np.datetime64],
+        mode: str = "exact",
+    ) -> npt.NDArray[np.bool_]:
+        """Vectorized :meth:`in_constraint` over a list or array of times.
+
+        Returns:
+            Boolean array, True where the constraint is violated
+        """
+        if self._swept_timestamps is not None and self._swept_array is not None:
+            if isinstance(times, np.ndarray):
+                query = times.astype("datetime64[us]")
+            else:
+                query = _to_datetime64_array(list(times))
+            grid = _to_datetime64_array(self._swept_timestamps)
+            indices = _lookup_indices(grid, query, mode)
+            return np.asarray(self._swept_array, dtype=bool)[indices]
+        if hasattr(self, "_rust_result_ref") and self._rust_result_ref is not None:
+            return cast(
+                npt.NDArray[np.bool_],
+                self._rust_result_ref.in_constraint_array(times, mode),
+            )
+        raise ValueError(
+            "ConstraintResult has no evaluated timestamps (was not created from evaluate())"
+        )
+
+    @property
+    def constraint_spec(self) -> str | None:
+        """JSON of the constraint that produced the result, or None if unknown.
+
+        Rebuild the constraint with :meth:`rust_ephem.Constraint.from_json`.
+        """
+        if hasattr(self, "_rust_result_ref") and self._rust_result_ref is not None:
+            return cast("str | None", self._rust_result_ref.constraint_spec)
+        return None
+
+    @property
+    def provenance(self) -> Provenance | None:
+        """Reproducibility record of the evaluation, or None if unknown.
+
+        The :attr:`~rust_ephem.TLEEphemeris.provenance` of the ephemeris the
+        result was evaluated against, with the constraint definition added
+        under ``constraint``. Saved and loaded with the result.
+        """
+        if hasattr(self, "_rust_result_ref") and self._rust_result_ref is not None:
+            return cast("Provenance | None", self._rust_result_ref.provenance)
+        return None
+
+    def save(self, path: str | os.PathLike[str]) -> None:
+        """Write the result to ``path`` as JSON, to be read back with :meth:`load`.
+
+        The file holds the evaluation times (as stored, see :attr:`storage`),
+        the violation windows with their severities, the visibility windows,
+        the generating constraint and the :attr:`provenance` record.
+
+        Raises:
+            ValueError: If the result comes from a roll sweep rather than a
+                single evaluation; evaluate with ``target_roll`` to save it
+            OSError: If the file cannot be written
+        """
+        if not hasattr(self, "_rust_result_ref") or self._rust_result_ref is None:
+            raise ValueError(
+                "only results of a single evaluation can be saved; evaluate "
+                "roll-dependent constraints with target_roll to save them"
+            )
+        self._rust_result_ref.save(os.fspath(path))
+
+    @classmethod
+    def load(cls, path: str | os.PathLike[str]) -> ConstraintResult:
+        """Read a result written by :meth:`save`.
+
+        Raises:
+            OSError: If the file cannot be read
+            ValueError: If the file is not a saved constraint result
+        """
+        rust_result = _RustConstraintResult.load(os.fspath(path))
+        return cls(
+            violations=[
+                ConstraintViolation(
+                    start_time=v.start_time,
+                    end_time=v.end_time,
+                    max_severity=v.max_severity,
+                    description=v.description,
+                )
+                for v in rust_result.violations
+            ],
+            all_satisfied=rust_result.all_satisfied,
+            constraint_name=rust_result.constraint_name,
+            _rust_result_ref=rust_result,
+        )
+
     def __repr__(self) -> str:
         return f"ConstraintResult(constraint='{self.constraint_name}', violations={len(self.violations)}, all_satisfied={self.all_satisfied})"
 
@@ -148,6 +349,92 @@ if TYPE_CHECKING:
     pass
 
 
+class ThresholdPeriod(BaseModel):
+    """A period overriding a constraint's angle limits
+
+    The period covers ``[begin, end)``; a missing bound leaves it open on that
+    side. Naive datetimes are taken as UTC.
+
+    Attributes:
+        begin: Start of the period (inclusive), or None for open-ended
+        end: End of the period (exclusive), or None for open-ended
+        min_angle: Minimum angle in degrees during the period, if overridden
+        max_angle: Maximum angle in degrees during the period, if overridden
+    """
+
+    begin: datetime | None = Field(default=None, description="Start (inclusive)")
+    end: datetime | None = Field(default=None, description="End (exclusive)")
+    min_angle: float | None = Field(
+        default=None, ge=0.0, le=180.0, description="Minimum angle in degrees"
+    )
+    max_angle: float | None = Field(
+        default=None, ge=0.0, le=180.0, description="Maximum angle in degrees"
+    )
+
+    @field_validator("begin", "end")
+    @classmethod
+    def as_utc(cls, value: datetime | None) -> datetime | None:
+        if value is not None and value.tzinfo is None:
+            return value.replace(tzinfo=timezone.utc)
+        return value
+
+    @model_validator(mode="after")
+    def check_period(self) -> "ThresholdPeriod":
+        if self.begin is not None and self.end is not None and self.end <= self.begin:
+            raise ValueError("end must be after begin")
+        if self.min_angle is None and self.max_angle is None:
+            raise ValueError("set min_angle and/or max_angle")
+        return self
+
+
+def threshold_schedule(
+    times: Sequence[datetime],
+    min_angle: Sequence[float] | None = None,
+    max_angle: Sequence[float] | None = None,
+) -> list[ThresholdPeriod]:
+    """Build a threshold schedule from a time series of limits
+
+    Each value holds from its time until the next one; the last holds
+    indefinitely. Before the first time the constraint's own limits apply.
+
+    Args:
+        times: Increasing times at which the limits change
+        min_angle: Minimum angle in degrees from each time, optional
+        max_angle: Maximum angle in degrees from each time, optional
+
+    Returns:
+        Periods for the ``schedule`` field of a proximity or limb constraint
+    """
+    if min_angle is None and max_angle is None:
+        raise ValueError("provide min_angle and/or max_angle")
+    for values in (min_angle, max_angle):
+        if values is not None and len(values) != len(times):
+            raise ValueError("each series must have one value per time")
+    ends: list[datetime | None] = [*times[1:], None]
+    return [
+        ThresholdPeriod(
+            begin=times[i],
+            end=ends[i],
+            min_angle=None if min_angle is None else float(min_angle[i]),
+            max_angle=None if max_angle is None else float(max_angle[i]),
+        )
+        for i in range(len(times))
+    ]
+
+
+def _check_schedule(
+    schedule: list[ThresholdPeriod], min_angle: float, max_angle: float | None
+) -> None:
+    """Check that every period leaves the maximum angle above the minimum."""
+    for i, period in enumerate(schedule):
+        low = min_angle if period.min_angle is None else period.min_angle
+        high = max_angle if period.max_angle is None else period.max_angle
+        if high is not None and high <= low:
+            raise ValueError(
+                f"schedule period {i}: max_angle must be greater than min_angle"
+            )
+
+
 class RollReference(str, Enum):
     """Roll-zero reference axis for boresight offsets."""
 
@@ -253,6 +540,15 @@ class RustConstraintMixin(BaseModel):
             grouped[target_roll].append(index)
         return [(target_roll, grouped[target_roll]) for target_roll in order]
 
+    @staticmethod
+    def _check_sweep_storage(storage: str) -> None:
+        """Roll sweeps combine per-time arrays, so they keep every time."""
+        if storage != "full":
+            raise ValueError(
+                "storage must be 'full' when sweeping roll angles; pass a fixed "
+                "target_roll to store results compressed or as windows only"
+            )
+
     def _evaluate_batch_uniform(
         self,
         ephemeris: Ephemeris,
@@ -262,9 +558,17 @@ class RustConstraintMixin(BaseModel):
         indices: int | list[int] | None = None,
         target_roll: float | None = None,
         n_roll_samples: int = DEFAULT_N_ROLL_SAMPLES,
+        between: tuple[datetime, datetime] | None = None,
+        at_times: datetime | list[datetime] | None = None,
+        storage: str = "full",
+        prescreen: int | None = None,
+        progress: ProgressCallback | None = None,
+        progress_every: int = 1,
+        cancel: CancellationToken | None = None,
     ) -> list[ConstraintResult]:
         """Evaluate a batch where all targets share the same roll semantics."""
         if target_roll is None and self._is_roll_dependent():
+            self._check_sweep_storage(storage)
             if not target_ras:
                 return []
 
@@ -274,8 +578,11 @@ class RustConstraintMixin(BaseModel):
                 target_decs,
                 times=times,
                 indices=indices,
+                between=between,
+                at_times=at_times,
                 target_roll=target_roll,
                 n_roll_samples=n_roll_samples,
+                cancel=cancel,
             )
             # Get timestamps/constraint_name from a single fixed roll (0°) to avoid
             # redundant roll sweep. The metadata is the same regardless of roll.
@@ -285,6 +592,8 @@ class RustConstraintMixin(BaseModel):
                 target_decs[0],
                 times=times,
                 indices=indices,
+                between=between,
+                at_times=at_times,
             )
             timestamps = self._coerce_timestamps(first_result.timestamp)
             constraint_name = first_result.constraint_name
@@ -303,6 +612,13 @@ class RustConstraintMixin(BaseModel):
             target_decs,
             times,
             indices,
+            between=between,
+            at_times=at_times,
+            storage=storage,
+            prescreen=prescreen,
+            progress=progress,
+            progress_every=progress_every,
+            cancel=cancel,
         )
         return [
             ConstraintResult(
@@ -331,6 +647,9 @@ class RustConstraintMixin(BaseModel):
         indices: int | list[int] | None = None,
         target_roll: float | None = None,
         n_roll_samples: int = DEFAULT_N_ROLL_SAMPLES,
+        between: tuple[datetime, datetime] | None = None,
+        at_times: datetime | list[datetime] | None = None,
+        cancel: CancellationToken | None = None,
     ) -> npt.NDArray[np.bool_]:
         """Evaluate a batch where all targets share the same roll semantics."""
         if target_roll is None and self._is_roll_dependent():
@@ -342,7 +661,14 @@ class RustConstraintMixin(BaseModel):
                 r = i * roll_step
                 arr = np.asarray(
                     self._resolve_rust_constraint(target_roll=r).in_constraint_batch(
-                        ephemeris, target_ras, target_decs, times, indices
+                        ephemeris,
+                        target_ras,
+                        target_decs,
+                        times,
+                        indices,
+                        between=between,
+                        at_times=at_times,
+                        cancel=cancel,
                     ),
                     dtype=bool,
                 )
@@ -369,6 +695,9 @@ class RustConstraintMixin(BaseModel):
                 target_decs,
                 times,
                 indices,
+                between=between,
+                at_times=at_times,
+                cancel=cancel,
             ),
         )
 
@@ -457,6 +786,12 @@ class RustConstraintMixin(BaseModel):
                     apply_eval_roll(inner)
                 return
 
+            if node_type == "sun_keepout":
+                # The keep-out sectors turn with the spacecraft roll itself
+                if target_roll is not None:
+                    node["roll_deg"] = float(target_roll)
+                return
+
             if node_type in {"and", "or", "xor", "at_least"}:
                 for child in node.get("constraints", []):
                     apply_eval_roll(child)
@@ -499,6 +834,11 @@ class RustConstraintMixin(BaseModel):
         indices: int | list[int] | None = None,
         target_roll: float | None = None,
         n_roll_samples: int = DEFAULT_N_ROLL_SAMPLES,
+        *,
+        between: tuple[datetime, datetime] | None = None,
+        at_times: datetime | list[datetime] | None = None,
+        storage: str = "full",
+        prescreen: int | None = None,
     ) -> ConstraintResult:
         """
         Evaluate the constraint using the Rust backend.
@@ -521,6 +861,24 @@ class RustConstraintMixin(BaseModel):
             n_roll_samples: Number of roll angles to sweep when ``target_roll`` is ``None``
                 and the constraint is roll-dependent.  Uniformly spaced over [0°, 360°).
                 Default :data:`DEFAULT_N_ROLL_SAMPLES` (360 ≈ 1° resolution).
+            between: Optional ``(start, end)`` datetimes; only ephemeris timestamps in
+                this inclusive range are evaluated.
+            at_times: Optional time(s) to evaluate. Unlike ``times`` they need not be
+                ephemeris timestamps: off-grid times are evaluated on a Hermite
+                interpolation of the observer state and must be strictly increasing
+                and within the ephemeris range.
+            storage: How the result keeps its evaluation times. ``"full"``
+                (default) keeps every time; ``"compressed"`` stores an evenly
+                spaced grid as (start, step, count); ``"windows"`` keeps only the
+                violation and visibility windows, dropping ``timestamps`` and
+                ``constraint_array`` to bound memory for long runs.
+            prescreen: Evaluate every ``prescreen``-th time step first and refine
+                step by step only between samples whose states differ. Much
+                faster for slowly varying constraints such as Sun proximity, but
+                windows shorter than ``prescreen`` steps that fall between two
+                samples are missed.
+
+        Only one of ``times``, ``indices``, ``between`` and ``at_times`` may be given.
 
         Returns:
             ConstraintResult containing violation windows
@@ -529,12 +887,20 @@ class RustConstraintMixin(BaseModel):
             raise ValueError("n_roll_samples must be a positive integer")
 
         if target_roll is None and self._is_roll_dependent():
+            self._check_sweep_storage(storage)
             # Sweep all spacecraft roll angles; a timestamp is violated only if
             # blocked at every possible roll (no valid orientation exists).
             roll_step = 360.0 / n_roll_samples
             rust_results = [
                 self._resolve_rust_constraint(target_roll=i * roll_step).evaluate(
-                    ephemeris, target_ra, target_dec, times, indices
+                    ephemeris,
+                    target_ra,
+                    target_dec,
+                    times,
+                    indices,
+                    between=between,
+                    at_times=at_times,
+                    prescreen=prescreen,
                 )
                 for i in range(n_roll_samples)
             ]
@@ -560,6 +926,10 @@ class RustConstraintMixin(BaseModel):
             target_dec,
             times,
             indices,
+            between=between,
+            at_times=at_times,
+            storage=storage,
+            prescreen=prescreen,
         )
 
         # Convert to Pydantic model - Rust now returns datetime objects directly
@@ -587,6 +957,14 @@ class RustConstraintMixin(BaseModel):
         indices: int | list[int] | None = None,
         target_rolls: list[float] | None = None,
         n_roll_samples: int = DEFAULT_N_ROLL_SAMPLES,
+        *,
+        between: tuple[datetime, datetime] | None = None,
+        at_times: datetime | list[datetime] | None = None,
+        storage: str = "full",
+        prescreen: int | None = None,
+        progress: ProgressCallback | None = None,
+        progress_every: int = 1,
+        cancel: CancellationToken | None = None,
     ) -> list[ConstraintResult]:
         """Evaluate the constraint for multiple targets and return one result per target.
 
@@ -595,6 +973,23 @@ class RustConstraintMixin(BaseModel):
                 the same length as ``target_ras`` and ``target_decs``. Each entry may be
                 ``None`` to sweep all rolls for that target, or a float for a fixed roll.
                 Pass ``None`` (not a list) to sweep rolls for all targets uniformly.
+            between: Optional ``(start, end)`` datetimes selecting the ephemeris
+                timestamps to evaluate, as in :meth:`evaluate`.
+            at_times: Optional time(s) to evaluate, interpolated if off the ephemeris
+                grid, as in :meth:`evaluate`.
+            storage: How each result keeps its evaluation times, as in
+                :meth:`evaluate`. ``"compressed"`` or ``"windows"`` bounds memory
+                for year-long runs over many targets.
+            prescreen: Coarse time step for pre-screening, as in :meth:`evaluate`.
+                Not applied when sweeping rolls.
+            progress: Called as ``progress(done, total)`` with the number of
+                finished targets. Targets are then evaluated in blocks with the
+                GIL released; returning ``False`` cancels the evaluation with
+                ``InterruptedError``. Not reported when sweeping rolls.
+            progress_every: Blocks between ``progress`` calls (default 1).
+            cancel: :class:`CancellationToken` stopping the evaluation after the
+                current block once cancelled or timed out. Ctrl-C is honoured
+                between blocks either way.
         """
         if n_roll_samples <= 0:
             raise ValueError("n_roll_samples must be a positive integer")
@@ -611,11 +1006,19 @@ class RustConstraintMixin(BaseModel):
                 target_decs,
                 times=times,
                 indices=indices,
+                between=between,
+                at_times=at_times,
                 target_roll=None,
                 n_roll_samples=n_roll_samples,
+                storage=storage,
+                prescreen=prescreen,
+                progress=progress,
+                progress_every=progress_every,
+                cancel=cancel,
             )
 
         results: list[ConstraintResult | None] = [None] * len(target_ras)
+        offset = 0
         for target_roll, batch_indices in self._group_target_roll_indices(
             normalized_target_rolls
         ):
@@ -625,14 +1028,109 @@ class RustConstraintMixin(BaseModel):
                 [target_decs[i] for i in batch_indices],
                 times=times,
                 indices=indices,
+                between=between,
+                at_times=at_times,
                 target_roll=target_roll,
                 n_roll_samples=n_roll_samples,
+                storage=storage,
+                prescreen=prescreen,
+                progress=_offset_progress(progress, offset, len(target_ras)),
+                progress_every=progress_every,
+                cancel=cancel,
             )
+            offset += len(batch_indices)
             for source_index, result in zip(batch_indices, batch_results):
                 results[source_index] = result
 
         return [cast(ConstraintResult, result) for result in results]
 
+    def evaluate_many(
+        self,
+        ephemerides: Mapping[Hashable, Ephemeris] | Sequence[Ephemeris],
+        target_ra: float,
+        target_dec: float,
+        target_roll: float | None = None,
+        n_roll_samples: int = DEFAULT_N_ROLL_SAMPLES,
+        *,
+        between: tuple[datetime, datetime] | None = None,
+        at_times: datetime | list[datetime] | None = None,
+        storage: str = "full",
+    ) -> dict[Hashable, ConstraintResult]:
+        """Evaluate the constraint for one target against several ephemerides.
+
+        The ephemerides are evaluated in parallel, for example to pick which
+        spacecraft or ground stations of a fleet can observe a transient.
+
+        Args:
+            ephemerides: Ephemerides keyed by name, or a sequence of ephemerides
+                (keyed by position). Their time grids may differ.
+            target_ra: Target right ascension in degrees (ICRS/J2000)
+            target_dec: Target declination in degrees (ICRS/J2000)
+            target_roll: Spacecraft roll angle (degrees), as in :meth:`evaluate`.
+            n_roll_samples: Number of roll angles to sweep when ``target_roll`` is
+                ``None`` and the constraint is roll-dependent.
+            between: Optional ``(start, end)`` datetimes selecting the timestamps of
+                each ephemeris to evaluate, as in :meth:`evaluate`.
+            at_times: Optional time(s) to evaluate for every ephemeris, as in
+                :meth:`evaluate`.
+            storage: How each result keeps its evaluation times, as in
+                :meth:`evaluate`.
+
+        Returns:
+            Dict mapping each key of ``ephemerides`` to its ConstraintResult
+        """
+        if n_roll_samples <= 0:
+            raise ValueError("n_roll_samples must be a positive integer")
+
+        items: list[tuple[Hashable, Ephemeris]] = (
+            list(ephemerides.items())
+            if isinstance(ephemerides, Mapping)
+            else list(enumerate(ephemerides))
+        )
+
+        if target_roll is None and self._is_roll_dependent():
+            return {
+                key: self.evaluate(
+                    ephemeris,
+                    target_ra,
+                    target_dec,
+                    n_roll_samples=n_roll_samples,
+                    between=between,
+                    at_times=at_times,
+                    storage=storage,
+                )
+                for key, ephemeris in items
+            }
+
+        rust_constraint = self._resolve_rust_constraint(
+            target_roll=target_roll,
+        )
+        rust_results = rust_constraint.evaluate_many(
+            dict(items),
+            target_ra,
+            target_dec,
+            between=between,
+            at_times=at_times,
+            storage=storage,
+        )
+        return {
+            key: ConstraintResult(
+                violations=[
+                    ConstraintViolation(
+                        start_time=v.start_time,
+                        end_time=v.end_time,
+                        max_severity=v.max_severity,
+                        description=v.description,
+                    )
+                    for v in rust_result.violations
+                ],
+                all_satisfied=rust_result.all_satisfied,
+                constraint_name=rust_result.constraint_name,
+                _rust_result_ref=rust_result,
+            )
+            for key, rust_result in rust_results.items()
+        }
+
     def in_constraint_batch(
         self,
         ephemeris: Ephemeris,
@@ -642,6 +1140,10 @@ class RustConstraintMixin(BaseModel):
         indices: int | list[int] | None = None,
         target_rolls: list[float] | None = None,
         n_roll_samples: int = DEFAULT_N_ROLL_SAMPLES,
+        *,
+        between: tuple[datetime, datetime] | None = None,
+        at_times: datetime | list[datetime] | None = None,
+        cancel: CancellationToken | None = None,
     ) -> npt.NDArray[np.bool_]:
         """
         Check if targets are in-constraint for multiple RA/Dec positions (vectorized).
@@ -666,6 +1168,12 @@ class RustConstraintMixin(BaseModel):
             n_roll_samples: Number of roll angles to sweep when ``target_roll`` is ``None``
                 and the constraint is roll-dependent.  Uniformly spaced over [0°, 360°).
                 Default :data:`DEFAULT_N_ROLL_SAMPLES` (360 ≈ 1° resolution).
+            between: Optional ``(start, end)`` datetimes selecting the ephemeris
+                timestamps to evaluate, as in :meth:`evaluate`.
+            at_times: Optional time(s) to evaluate, interpolated if off the ephemeris
+                grid, as in :meth:`evaluate`.
+            cancel: :class:`CancellationToken` stopping the evaluation after the
+                current block of targets once cancelled or timed out.
 
         Returns:
             2D numpy array of shape (n_targets, n_times) with boolean violation status
@@ -685,8 +1193,11 @@ class RustConstraintMixin(BaseModel):
                 target_decs,
                 times=times,
                 indices=indices,
+                between=between,
+                at_times=at_times,
                 target_roll=None,
                 n_roll_samples=n_roll_samples,
+                cancel=cancel,
             )
 
         # Special case: empty target list should still return proper (0, n_times) shape
@@ -697,8 +1208,11 @@ class RustConstraintMixin(BaseModel):
                 [],
                 times=times,
                 indices=indices,
+                between=between,
+                at_times=at_times,
                 target_roll=None,
                 n_roll_samples=n_roll_samples,
+                cancel=cancel,
             )
 
         result: npt.NDArray[np.bool_] | None = None
@@ -711,8 +1225,11 @@ class RustConstraintMixin(BaseModel):
                 [target_decs[i] for i in batch_indices],
                 times=times,
                 indices=indices,
+                between=between,
+                at_times=at_times,
                 target_roll=target_roll,
                 n_roll_samples=n_roll_samples,
+                cancel=cancel,
             )
             if result is None:
                 result = np.empty(
@@ -829,6 +1346,54 @@ class RustConstraintMixin(BaseModel):
             ),
         )
 
+    def metric(
+        self,
+        ephemeris: Ephemeris,
+        target_ra: float,
+        target_dec: float,
+        times: datetime | list[datetime] | None = None,
+        indices: int | list[int] | None = None,
+        *,
+        between: tuple[datetime, datetime] | None = None,
+        at_times: datetime | list[datetime] | None = None,
+    ) -> npt.NDArray[np.float64]:
+        """Return the continuous quantity the constraint thresholds, per time.
+
+        Useful for seeing why a window opens or closes and for plotting:
+        separation from the body limb in degrees for proximity constraints,
+        altitude for alt/az, airmass, Moon illumination, the visible solar
+        disk fraction for eclipse, Sun altitude for daytime, and cutoff
+        rigidity in GV.
+
+        Args:
+            ephemeris: One of TLEEphemeris, SPICEEphemeris, GroundEphemeris, or OEMEphemeris
+            target_ra: Target right ascension in degrees (ICRS/J2000)
+            target_dec: Target declination in degrees (ICRS/J2000)
+            times: Specific time(s) to evaluate (must exist in ephemeris)
+            indices: Specific time index/indices to evaluate
+            between: Evaluate only ephemeris timestamps from ``t0`` to ``t1`` inclusive
+            at_times: Times to evaluate, interpolating off-grid times
+
+        Returns:
+            1D float array with one value per selected time
+
+        Raises:
+            ValueError: If the constraint has no single underlying quantity,
+                such as logical combinations
+        """
+        return cast(
+            npt.NDArray[np.float64],
+            self._get_cached_rust_constraint().metric(
+                ephemeris,
+                target_ra,
+                target_dec,
+                times=times,
+                indices=indices,
+                between=between,
+                at_times=at_times,
+            ),
+        )
+
     def instantaneous_field_of_regard(
         self,
         ephemeris: Ephemeris,
@@ -897,6 +1462,7 @@ class RustConstraintMixin(BaseModel):
         use_horizons: bool = False,
         spice_kernel: str | None = None,
         target_roll: float | None = None,
+        source: str | None = None,
     ) -> MovingVisibilityResult:
         """Evaluate constraint for a moving body (varying RA/Dec over time).
 
@@ -917,6 +1483,11 @@ class RustConstraintMixin(BaseModel):
             use_horizons: If True, query JPL Horizons for body positions (default: False)
             spice_kernel: Path or URL to a SPICE kernel file for body positions.
                 Can be a local path or URL (e.g., from JPL Horizons SPK files).
+            target_roll: Optional spacecraft roll angle in degrees.
+            source: Body data source precedence: "spice" (loaded kernels only),
+                "horizons" (JPL Horizons only) or "auto" (SPICE, falling back to
+                Horizons with a warning). Defaults to "spice" unless
+                ``use_horizons`` is set.
 
         Returns:
             MovingVisibilityResult with per-timestamp violation flags, visibility flags,
@@ -954,8 +1525,48 @@ class RustConstraintMixin(BaseModel):
             body_str,
             use_horizons,
             spice_kernel,
+            source,
+        )
+
+        return self._moving_visibility_result(rust_result)
+
+    def evaluate_virtual_target(
+        self,
+        ephemeris: Ephemeris,
+        target: Literal["nadir", "limb", "sun", "anti_sun", "ram", "anti_ram"],
+        azimuth: float | None = None,
+        target_roll: float | None = None,
+    ) -> MovingVisibilityResult:
+        """Evaluate constraint for a virtual target derived from the observer state.
+
+        The target direction (nadir, an Earth limb tangent point, Sun, anti-Sun,
+        ram or anti-ram) is recomputed at every ephemeris time, so common
+        pointing modes can be checked without precomputing coordinates.
+
+        Args:
+            ephemeris: One of TLEEphemeris, SPICEEphemeris, GroundEphemeris, or OEMEphemeris
+            target: "nadir", "limb", "sun", "anti_sun", "ram" or "anti_ram"
+            azimuth: For "limb" only, azimuth of the limb tangent point about nadir
+                in degrees, 0 toward ram and 90 toward the orbit normal (default: 0)
+            target_roll: Optional spacecraft roll angle in degrees.
+
+        Returns:
+            MovingVisibilityResult with the target's RA/Dec at each time
+
+        Example:
+            >>> result = constraint.evaluate_virtual_target(ephem, "limb", azimuth=90.0)
+        """
+        rust_constraint = self._resolve_rust_constraint(
+            target_roll=target_roll,
+        )
+        rust_result = rust_constraint.evaluate_virtual_target(
+            ephemeris, target, azimuth=azimuth
         )
+        return self._moving_visibility_result(rust_result)
 
+    @staticmethod
+    def _moving_visibility_result(rust_result: Any) -> MovingVisibilityResult:
+        """Convert a Rust MovingBodyResult to a MovingVisibilityResult"""
         # Convert Rust VisibilityWindow objects to VisibilityWindowResult
         visibility_windows = [
             VisibilityWindowResult(
@@ -1147,6 +1758,10 @@ class SunConstraint(RustConstraintMixin):
         type: Always "sun"
         min_angle: Minimum allowed angular separation in degrees (0-180)
         max_angle: Maximum allowed angular separation in degrees (0-180), optional
+        limb_relative: Apply the limits to the separation from the Sun's limb
+            (centre separation minus its apparent angular radius) (default: False)
+        schedule: Periods overriding the limits, e.g. a wider angle during
+            detector anneals; see :func:`threshold_schedule` (default: none)
     """
 
     type: Literal["sun"] = "sun"
@@ -1156,6 +1771,66 @@ class SunConstraint(RustConstraintMixin):
     max_angle: float | None = Field(
         default=None, ge=0.0, le=180.0, description="Maximum angle from Sun in degrees"
     )
+    limb_relative: bool = Field(
+        default=False,
+        description="Measure the angles from the Sun's limb instead of its centre",
+    )
+    schedule: list[ThresholdPeriod] = Field(
+        default_factory=list, description="Periods overriding the angle limits"
+    )
+
+    @model_validator(mode="after")
+    def check_schedule(self) -> "SunConstraint":
+        _check_schedule(self.schedule, self.min_angle, self.max_angle)
+        return self
+
+
+class SunKeepOutConstraint(RustConstraintMixin):
+    """Sun keep-out constraint with separate limits per quadrant
+
+    Replaces a single Sun avoidance cone with four limits chosen by where the
+    Sun lies around the boresight, in the spacecraft frame of
+    :class:`BoresightOffsetConstraint`: +X is the boresight, +Z the roll
+    reference projected perpendicular to it and rotated by the roll angle, and
+    +Y = Z x X. Each pitch sector spans ±45° around the Z axis and each yaw
+    sector ±45° around the Y axis.
+
+    Attributes:
+        type: Always "sun_keepout"
+        pitch_plus: Minimum Sun angle in degrees with the Sun toward -Z, the
+            side a positive pitch moves the boresight to
+        pitch_minus: Minimum Sun angle in degrees with the Sun toward +Z
+        yaw_plus: Minimum Sun angle in degrees with the Sun toward +Y, the
+            side a positive yaw moves the boresight to
+        yaw_minus: Minimum Sun angle in degrees with the Sun toward -Y
+        roll_deg: Spacecraft roll about the boresight in degrees. ``None``
+            (default) leaves the roll free, so the smallest limit applies.
+            ``target_roll`` at evaluation time overrides it.
+        roll_reference: Roll-zero reference axis, "sun" (default) for a
+            Sun-referenced attitude law or "north"
+    """
+
+    type: Literal["sun_keepout"] = "sun_keepout"
+    pitch_plus: float = Field(
+        ..., ge=0.0, le=180.0, description="Minimum Sun angle in the +pitch sector"
+    )
+    pitch_minus: float = Field(
+        ..., ge=0.0, le=180.0, description="Minimum Sun angle in the -pitch sector"
+    )
+    yaw_plus: float = Field(
+        ..., ge=0.0, le=180.0, description="Minimum Sun angle in the +yaw sector"
+    )
+    yaw_minus: float = Field(
+        ..., ge=0.0, le=180.0, description="Minimum Sun angle in the -yaw sector"
+    )
+    roll_deg: float | None = Field(
+        default=None,
+        description="Spacecraft roll about the boresight in degrees; free if None",
+    )
+    roll_reference: RollReference = Field(
+        default=RollReference.SUN,
+        description="Roll-zero reference axis: 'sun' or 'north'",
+    )
 
 
 class EarthLimbConstraint(RustConstraintMixin):
@@ -1170,6 +1845,10 @@ class EarthLimbConstraint(RustConstraintMixin):
         max_angle: Maximum allowed angular separation in degrees (0-180), optional
         include_refraction: Include atmospheric refraction correction (~0.57°) for ground observers (default: False)
         horizon_dip: Include geometric horizon dip correction for ground observers (default: False)
+        uncertainty_sigma: Widen the margin by this many standard deviations of the
+            ephemeris position uncertainty, when the ephemeris has one (default: 0)
+        schedule: Periods overriding the limits, e.g. a wider angle during
+            detector anneals; see :func:`threshold_schedule` (default: none)
     """
 
     type: Literal["earth_limb"] = "earth_limb"
@@ -1190,7 +1869,62 @@ class EarthLimbConstraint(RustConstraintMixin):
         default=False,
         description="Include geometric horizon dip correction for ground observers",
     )
+    uncertainty_sigma: float = Field(
+        default=0.0,
+        ge=0.0,
+        description="Standard deviations of ephemeris position uncertainty added to the margin",
+    )
+    schedule: list[ThresholdPeriod] = Field(
+        default_factory=list, description="Periods overriding the angle limits"
+    )
+
+    @model_validator(mode="after")
+    def check_schedule(self) -> "EarthLimbConstraint":
+        _check_schedule(self.schedule, self.min_angle, self.max_angle)
+        return self
+
+
+class EarthOccultationConstraint(RustConstraintMixin):
+    """Earth occultation constraint
+
+    Limits observations to times when the target is not hidden behind the
+    Earth as seen from the observer. The target counts as occulted while the
+    line of sight passes within ``grazing_altitude`` km of the surface. Unlike
+    :class:`EarthLimbConstraint` there is no pointing margin.
+
+    Attributes:
+        type: Always "earth_occultation"
+        grazing_altitude: Height above the surface in km below which the line
+            of sight is blocked, e.g. for the atmosphere (default: 0)
+    """
+
+    type: Literal["earth_occultation"] = "earth_occultation"
+    grazing_altitude: float = Field(
+        default=0.0, ge=0.0, description="Grazing altitude of the line of sight in km"
+    )
+
+    def occultation_events(
+        self, ephemeris: Ephemeris, target_ra: float, target_dec: float
+    ) -> list[tuple[datetime, str]]:
+        """Occultation ingress and egress times of a target
 
+        Crossings of the grazing altitude are interpolated between ephemeris
+        samples.
+
+        Args:
+            ephemeris: Any supported ephemeris
+            target_ra: Target right ascension in degrees (ICRS/J2000)
+            target_dec: Target declination in degrees (ICRS/J2000)
+
+        Returns:
+            ``(time, "ingress" | "egress")`` tuples in time order
+        """
+        return cast(
+            list[tuple[datetime, str]],
+            self._get_cached_rust_constraint().occultation_events(
+                ephemeris, target_ra, target_dec
+            ),
+        )
 
 class BodyConstraint(RustConstraintMixin):
     """Solar system body proximity constraint
@@ -1201,13 +1935,19 @@ class BodyConstraint(RustConstraintMixin):
 
     Attributes:
         type: Always "body"
-        body: Name of the solar system body (e.g., "Mars", "Jupiter")
+        body: Name of the solar system body (e.g., "Mars", "Jupiter"), or a
+            pseudo-body: "EMB", "SSB", "SEL1", "SEL2", "EML1" or "EML2"
         min_angle: Minimum allowed angular separation in degrees (0-180). Circle mode.
         max_angle: Maximum allowed angular separation in degrees (0-180). Circle mode only.
         fov_polygon: Polygon FoV vertices in instrument frame (u_deg, v_deg). At roll=0,
             +u points east and +v points north on the sky. Mutually exclusive with min_angle.
         roll_deg: Position angle (degrees east of north) of the instrument +v axis.
             Only applicable with fov_polygon. None (default) sweeps all roll angles.
+        limb_relative: Apply the angle limits to the separation from the body's limb,
+            using its radius from ``get_body_radii`` (circle mode only, default: False)
+        schedule: Periods overriding the angle limits; see
+            :func:`threshold_schedule` (circle mode only, default: none)
+        source: Body position source: "spice" (default), "horizons" or "auto"
     """
 
     type: Literal["body"] = "body"
@@ -1232,6 +1972,16 @@ class BodyConstraint(RustConstraintMixin):
         default=None,
         description="Roll angle (PA of instrument +v from north). None = sweep all rolls.",
     )
+    limb_relative: bool = Field(
+        default=False,
+        description="Measure the angles from the body's limb instead of its centre",
+    )
+    schedule: list[ThresholdPeriod] = Field(
+        default_factory=list, description="Periods overriding the angle limits"
+    )
+    source: Literal["spice", "horizons", "auto"] | None = Field(
+        default=None, description="Body position source"
+    )
 
     @model_validator(mode="after")
     def check_fov(self) -> "BodyConstraint":
@@ -1247,6 +1997,58 @@ class BodyConstraint(RustConstraintMixin):
             raise ValueError("roll_deg has no effect with min_angle")
         if has_polygon and self.max_angle is not None:
             raise ValueError("max_angle has no effect with fov_polygon")
+        if has_polygon and self.limb_relative:
+            raise ValueError("limb_relative has no effect with fov_polygon")
+        if has_polygon and self.schedule:
+            raise ValueError("schedule has no effect with fov_polygon")
+        if self.min_angle is not None:
+            _check_schedule(self.schedule, self.min_angle, self.max_angle)
+        return self
+
+
+class PlanetsConstraint(RustConstraintMixin):
+    """Avoidance constraint for several planets at once
+
+    Computes each planet's position once per evaluation and requires the target
+    to keep at least the planet's minimum separation from all of them, instead
+    of combining one ``BodyConstraint`` per planet.
+
+    Attributes:
+        type: Always "planets"
+        min_angle: Minimum allowed angular separation in degrees (0-180), for all planets
+        planets: Body names or NAIF IDs to avoid
+        min_angles: Per-planet minimum separations in degrees, overriding min_angle
+        source: Body position source: "spice" (default), "horizons" or "auto"
+    """
+
+    type: Literal["planets"] = "planets"
+    min_angle: float = Field(
+        ..., ge=0.0, le=180.0, description="Minimum angle from each planet in degrees"
+    )
+    planets: list[str] = Field(
+        default_factory=lambda: ["Venus", "Mars", "Jupiter", "Saturn"],
+        min_length=1,
+        description="Body names or NAIF IDs to avoid",
+    )
+    min_angles: dict[str, float] | None = Field(
+        default=None,
+        description="Per-planet minimum angles in degrees, overriding min_angle",
+    )
+    source: Literal["spice", "horizons", "auto"] | None = Field(
+        default=None, description="Body position source"
+    )
+
+    @model_validator(mode="after")
+    def check_min_angles(self) -> "PlanetsConstraint":
+        for planet, angle in (self.min_angles or {}).items():
+            if planet not in self.planets:
+                raise ValueError(
+                    f"min_angles has an entry for '{planet}', which is not in planets"
+                )
+            if not 0.0 <= angle <= 180.0:
+                raise ValueError(
+                    f"min_angles['{planet}'] must be between 0 and 180 degrees"
+                )
         return self
 
 
@@ -1259,6 +2061,10 @@ class MoonConstraint(RustConstraintMixin):
         type: Always "moon"
         min_angle: Minimum allowed angular separation in degrees (0-180)
         max_angle: Maximum allowed angular separation in degrees (0-180), optional
+        limb_relative: Apply the limits to the separation from the Moon's limb
+            (centre separation minus its apparent angular radius) (default: False)
+        schedule: Periods overriding the limits, e.g. a wider angle during
+            detector anneals; see :func:`threshold_schedule` (default: none)
     """
 
     type: Literal["moon"] = "moon"
@@ -1268,6 +2074,18 @@ class MoonConstraint(RustConstraintMixin):
     max_angle: float | None = Field(
         default=None, ge=0.0, le=180.0, description="Maximum angle from Moon in degrees"
     )
+    limb_relative: bool = Field(
+        default=False,
+        description="Measure the angles from the Moon's limb instead of its centre",
+    )
+    schedule: list[ThresholdPeriod] = Field(
+        default_factory=list, description="Periods overriding the angle limits"
+    )
+
+    @model_validator(mode="after")
+    def check_schedule(self) -> "MoonConstraint":
+        _check_schedule(self.schedule, self.min_angle, self.max_angle)
+        return self
 
 
 class EclipseConstraint(RustConstraintMixin):
@@ -1287,6 +2105,25 @@ class EclipseConstraint(RustConstraintMixin):
         default=True, description="Count only umbra (True) or include penumbra (False)"
     )
 
+    def shadow_events(self, ephemeris: Ephemeris) -> list[tuple[datetime, str]]:
+        """Penumbra and umbra entry and exit times of the observer
+
+        Both boundaries are reported whatever ``umbra_only`` is set to, and
+        crossings are interpolated between ephemeris samples.
+
+        Args:
+            ephemeris: Any supported ephemeris
+
+        Returns:
+            ``(time, kind)`` tuples in time order, where kind is
+            ``"penumbra_entry"``, ``"umbra_entry"``, ``"umbra_exit"`` or
+            ``"penumbra_exit"``
+        """
+        return cast(
+            list[tuple[datetime, str]],
+            self._get_cached_rust_constraint().shadow_events(ephemeris),
+        )
+
 
 class AndConstraint(RustConstraintMixin):
     """Logical AND constraint combinator
@@ -1425,17 +2262,63 @@ class DaytimeConstraint(RustConstraintMixin):
     """Daytime visibility constraint
 
     Prevents observations during daytime hours,
-    with configurable twilight definitions.
+    with configurable twilight definitions. ``mode`` selects what counts as
+    daytime:
+
+    - ``"observer"``: the Sun is above the twilight limit at the observer
+      (ground observers)
+    - ``"observer_sunlit"``: the observer is outside the Earth's umbra, i.e.
+      a spacecraft in sunlight; ``twilight`` is ignored
+    - ``"ground_site_night"``: the Sun is above the twilight limit at the
+      ground site (``latitude``, ``longitude``, ``height``), so observing is
+      allowed only while the site is dark
+    - ``"nadir_scene_daylit"``: the Sun is above the twilight limit at the
+      ground point directly below the observer
 
     Attributes:
         type: Always "daytime"
         twilight: Twilight definition ("civil", "nautical", "astronomical", "none")
+        mode: What counts as daytime
+        latitude: Ground site latitude in degrees, for "ground_site_night"
+        longitude: Ground site longitude in degrees, for "ground_site_night"
+        height: Ground site height in metres, for "ground_site_night" (default 0)
     """
 
     type: Literal["daytime"] = "daytime"
     twilight: Literal["civil", "nautical", "astronomical", "none"] = Field(
         default="civil", description="Twilight definition for daytime boundary"
     )
+    mode: Literal[
+        "observer", "observer_sunlit", "ground_site_night", "nadir_scene_daylit"
+    ] = Field(default="observer", description="What counts as daytime")
+    latitude: float | None = Field(
+        default=None, ge=-90.0, le=90.0, description="Ground site latitude in degrees"
+    )
+    longitude: float | None = Field(
+        default=None,
+        ge=-180.0,
+        le=360.0,
+        description="Ground site longitude in degrees",
+    )
+    height: float | None = Field(default=None, description="Ground site height in metres")
+
+    @model_validator(mode="after")
+    def validate_site(self) -> DaytimeConstraint:
+        has_site = (
+            self.latitude is not None
+            or self.longitude is not None
+            or self.height is not None
+        )
+        if self.mode == "ground_site_night":
+            if self.latitude is None or self.longitude is None:
+                raise ValueError(
+                    "mode='ground_site_night' requires latitude and longitude"
+                )
+        elif has_site:
+            raise ValueError(
+                "latitude, longitude and height are only used with mode='ground_site_night'"
+            )
+        return self
 
 
 class AirmassConstraint(RustConstraintMixin):
@@ -1527,7 +2410,9 @@ class SAAConstraint(RustConstraintMixin):
     """South Atlantic Anomaly constraint
 
     Limits observations based on whether the spacecraft is within a defined
-    geographic region (typically the South Atlantic Anomaly).
+    geographic region (typically the South Atlantic Anomaly). The polygon
+    edges are great-circle arcs, so the region may cross the antimeridian,
+    but it must fit within a hemisphere.
 
     Attributes:
         type: Always "saa"
@@ -1542,12 +2427,155 @@ class SAAConstraint(RustConstraintMixin):
     )
 
 
+class SkyRegionConstraint(RustConstraintMixin):
+    """Sky avoidance region constraint
+
+    Violated while the target lies inside a polygon on the sky, such as the
+    Galactic plane or a bright nebula. The polygon edges are great-circle
+    arcs, so the region may cross RA 0 or enclose a celestial pole, but it
+    must fit within a hemisphere.
+
+    Attributes:
+        type: Always "sky_region"
+        polygon: List of (RA, Dec) pairs defining the region boundary
+    """
+
+    type: Literal["sky_region"] = "sky_region"
+    polygon: list[tuple[float, float]] = Field(
+        ...,
+        min_length=3,
+        description="List of (RA, Dec) pairs defining the region boundary in degrees",
+    )
+
+
+class CutoffRigidityConstraint(RustConstraintMixin):
+    """Geomagnetic cutoff rigidity constraint
+
+    Limits observations to times when the spacecraft's vertical cutoff
+    rigidity, from a centred IGRF dipole, is at least ``min_rigidity``.
+    Low rigidities occur at high geomagnetic latitudes, where charged
+    particles reach the spacecraft more easily.
+
+    Attributes:
+        type: Always "cutoff_rigidity"
+        min_rigidity: Minimum vertical cutoff rigidity in GV
+    """
+
+    type: Literal["cutoff_rigidity"] = "cutoff_rigidity"
+    min_rigidity: float = Field(
+        ..., gt=0.0, description="Minimum vertical cutoff rigidity in GV"
+    )
+
+
+class GeomagneticActivityConstraint(RustConstraintMixin):
+    """Geomagnetic activity constraint
+
+    Limits observations to times when the 3-hourly planetary Kp index from
+    the Celestrak space-weather data is at most ``max_kp``. Times without a
+    Kp value, such as predicted days, are not violated.
+
+    Attributes:
+        type: Always "geomagnetic_activity"
+        max_kp: Maximum allowed Kp index (0-9)
+    """
+
+    type: Literal["geomagnetic_activity"] = "geomagnetic_activity"
+    max_kp: float = Field(
+        ..., ge=0.0, le=9.0, description="Maximum allowed Kp index (0-9)"
+    )
+
+
+class StationKeepingConstraint(RustConstraintMixin):
+    """Station-keeping box constraint
+
+    Limits observations to times when the sub-satellite point (geocentric
+    longitude and latitude) is inside a deadband box, e.g. to check that a
+    geostationary satellite stays in its slot.
+
+    Attributes:
+        type: Always "station_keeping"
+        longitude: Box centre longitude in degrees, east positive (-180 to 360)
+        latitude: Box centre latitude in degrees (-90 to 90)
+        longitude_tolerance: Half-width of the box in longitude, degrees
+        latitude_tolerance: Half-width of the box in latitude, degrees
+    """
+
+    type: Literal["station_keeping"] = "station_keeping"
+    longitude: float = Field(
+        ..., ge=-180.0, le=360.0, description="Box centre longitude in degrees"
+    )
+    latitude: float = Field(
+        default=0.0, ge=-90.0, le=90.0, description="Box centre latitude in degrees"
+    )
+    longitude_tolerance: float = Field(
+        default=0.1, gt=0.0, le=180.0, description="Longitude half-width in degrees"
+    )
+    latitude_tolerance: float = Field(
+        default=0.1, gt=0.0, le=90.0, description="Latitude half-width in degrees"
+    )
+
+
+class GroundTargetIlluminationConstraint(RustConstraintMixin):
+    """Ground-target illumination constraint
+
+    For Earth-observation tasking of a scene at (``latitude``, ``longitude``).
+    Limits observations to times when the spacecraft has access to the scene
+    (at least ``min_elevation`` above its horizon and, optionally, within
+    ``max_off_nadir`` of nadir) and the Sun's elevation at the scene is within
+    [``min_sun_elevation``, ``max_sun_elevation``]. The RA/Dec target is not
+    used.
+
+    Attributes:
+        type: Always "ground_target_illumination"
+        latitude: Geodetic latitude of the scene in degrees (-90 to 90)
+        longitude: Longitude of the scene in degrees, east positive (-180 to 360)
+        height: Scene height above the WGS84 ellipsoid in metres
+        min_sun_elevation: Minimum Sun elevation at the scene in degrees, optional
+        max_sun_elevation: Maximum Sun elevation at the scene in degrees, optional
+        min_elevation: Minimum spacecraft elevation seen from the scene in degrees
+        max_off_nadir: Maximum off-nadir angle to the scene in degrees, optional
+    """
+
+    type: Literal["ground_target_illumination"] = "ground_target_illumination"
+    latitude: float = Field(
+        ..., ge=-90.0, le=90.0, description="Scene latitude in degrees"
+    )
+    longitude: float = Field(
+        ..., ge=-180.0, le=360.0, description="Scene longitude in degrees"
+    )
+    height: float = Field(default=0.0, description="Scene height in metres")
+    min_sun_elevation: float | None = Field(
+        default=0.0, ge=-90.0, le=90.0, description="Minimum Sun elevation"
+    )
+    max_sun_elevation: float | None = Field(
+        default=None, ge=-90.0, le=90.0, description="Maximum Sun elevation"
+    )
+    min_elevation: float = Field(
+        default=0.0, ge=-90.0, le=90.0, description="Minimum spacecraft elevation"
+    )
+    max_off_nadir: float | None = Field(
+        default=None, gt=0.0, le=90.0, description="Maximum off-nadir angle"
+    )
+
+    @model_validator(mode="after")
+    def validate_sun_range(self) -> GroundTargetIlluminationConstraint:
+        if (
+            self.min_sun_elevation is not None
+            and self.max_sun_elevation is not None
+            and self.min_sun_elevation >= self.max_sun_elevation
+        ):
+            raise ValueError("min_sun_elevation must be less than max_sun_elevation")
+        return self
+
+
 class AltAzConstraint(RustConstraintMixin):
     """Altitude/Azimuth constraint
 
     Limits observations based on target's altitude and azimuth angles
     from the observer's location. Can use simple min/max ranges or a
-    custom polygon defining an allowed region.
+    custom polygon defining an allowed region. When the ephemeris is a
+    ``GroundEphemeris`` with a ``horizon`` profile, directions below the
+    local horizon are also treated as blocked.
 
     Attributes:
         type: Always "alt_az"
@@ -1577,6 +2605,94 @@ class AltAzConstraint(RustConstraintMixin):
     )
 
 
+class GimbalConstraint(RustConstraintMixin):
+    """Ground antenna gimbal constraint
+
+    Models a two-axis (azimuth/elevation) antenna mount. The target cannot be
+    tracked while it is within ``keyhole_radius`` of the keyhole centre (the
+    zenith by default) or while following it needs a faster azimuth or
+    elevation slew than allowed. Rates are finite differences over
+    neighbouring ephemeris samples; with ``evaluate_moving_body`` they follow
+    the moving target, so a spacecraft pass can be checked directly.
+
+    Attributes:
+        type: Always "gimbal"
+        keyhole_radius: Half-angle of the keyhole cone in degrees (0-90), optional
+        keyhole_azimuth: Azimuth of the keyhole centre in degrees (0-360)
+        keyhole_elevation: Elevation of the keyhole centre in degrees (-90 to 90)
+        max_azimuth_rate: Maximum azimuth rate in degrees per second, optional
+        max_elevation_rate: Maximum elevation rate in degrees per second, optional
+    """
+
+    type: Literal["gimbal"] = "gimbal"
+    keyhole_radius: float | None = Field(
+        default=None, ge=0.0, le=90.0, description="Keyhole half-angle in degrees"
+    )
+    keyhole_azimuth: float = Field(
+        default=0.0, ge=0.0, le=360.0, description="Keyhole centre azimuth in degrees"
+    )
+    keyhole_elevation: float = Field(
+        default=90.0,
+        ge=-90.0,
+        le=90.0,
+        description="Keyhole centre elevation in degrees",
+    )
+    max_azimuth_rate: float | None = Field(
+        default=None, gt=0.0, description="Maximum azimuth rate in degrees per second"
+    )
+    max_elevation_rate: float | None = Field(
+        default=None,
+        gt=0.0,
+        description="Maximum elevation rate in degrees per second",
+    )
+
+    @model_validator(mode="after")
+    def check_limits(self) -> "GimbalConstraint":
+        if (
+            self.keyhole_radius is None
+            and self.max_azimuth_rate is None
+            and self.max_elevation_rate is None
+        ):
+            raise ValueError(
+                "at least one of keyhole_radius, max_azimuth_rate and "
+                "max_elevation_rate is required"
+            )
+        return self
+
+
+class GroundVisibilityConstraint(RustConstraintMixin):
+    """Ground-site visibility constraint
+
+    Combines the conditions for observing from a ground telescope. The target
+    must be above ``min_altitude`` and the site's horizon mask, the Sun below
+    the twilight limit, and optionally the airmass at most ``max_airmass`` and
+    the Moon at most ``max_moon_altitude``. Sun and Moon altitudes are
+    topocentric. Evaluating against anything other than a ``GroundEphemeris``
+    raises ``ValueError``.
+
+    Attributes:
+        type: Always "ground_visibility"
+        min_altitude: Minimum target altitude in degrees (-90 to 90)
+        twilight: Sun altitude limit ("civil", "nautical", "astronomical", "none")
+        max_airmass: Maximum target airmass (>=1), optional
+        max_moon_altitude: Maximum Moon altitude in degrees (-90 to 90), optional
+    """
+
+    type: Literal["ground_visibility"] = "ground_visibility"
+    min_altitude: float = Field(
+        default=0.0, ge=-90.0, le=90.0, description="Minimum target altitude"
+    )
+    twilight: Literal["civil", "nautical", "astronomical", "none"] = Field(
+        default="astronomical", description="Twilight limit for the Sun"
+    )
+    max_airmass: float | None = Field(
+        default=None, ge=1.0, description="Maximum allowed target airmass"
+    )
+    max_moon_altitude: float | None = Field(
+        default=None, ge=-90.0, le=90.0, description="Maximum Moon altitude in degrees"
+    )
+
+
 class OrbitRamConstraint(RustConstraintMixin):
     """Orbit RAM direction constraint
 
@@ -1691,17 +2807,27 @@ class BrightStarConstraint(RustConstraintMixin):
 # Union type for all constraints
 ConstraintConfig = Union[
     SunConstraint,
+    SunKeepOutConstraint,
     MoonConstraint,
     EclipseConstraint,
     EarthLimbConstraint,
+    EarthOccultationConstraint,
     BodyConstraint,
+    PlanetsConstraint,
     DaytimeConstraint,
     AirmassConstraint,
     MoonPhaseConstraint,
     OrbitRamConstraint,
     OrbitPoleConstraint,
     SAAConstraint,
+    SkyRegionConstraint,
+    CutoffRigidityConstraint,
+    GeomagneticActivityConstraint,
+    StationKeepingConstraint,
+    GroundTargetIlluminationConstraint,
     AltAzConstraint,
+    GimbalConstraint,
+    GroundVisibilityConstraint,
     BrightStarConstraint,
     AndConstraint,
     OrConstraint,
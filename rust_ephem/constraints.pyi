@@ -8,6 +8,8 @@ to configure the Rust constraint evaluators.
 
 from __future__ import annotations
 
+import os
+from collections.abc import Callable, Hashable, Mapping, Sequence
 from datetime import datetime
 from enum import Enum
 from typing import TYPE_CHECKING, Literal
@@ -16,6 +18,7 @@ import numpy as np
 import numpy.typing as npt
 from pydantic import BaseModel, TypeAdapter
 
+from ._rust_ephem import CancellationToken, Provenance
 from .ephemeris import Ephemeris
 
 DEFAULT_N_POINTS: int
@@ -27,6 +30,18 @@ if TYPE_CHECKING:
 if TYPE_CHECKING:
     pass
 
+class ThresholdPeriod(BaseModel):
+    begin: datetime | None = None
+    end: datetime | None = None
+    min_angle: float | None = None
+    max_angle: float | None = None
+
+def threshold_schedule(
+    times: Sequence[datetime],
+    min_angle: Sequence[float] | None = None,
+    max_angle: Sequence[float] | None = None,
+) -> list[ThresholdPeriod]: ...
+
 class RollReference(str, Enum):
     SUN = "sun"
     NORTH = "north"
@@ -49,11 +64,33 @@ class ConstraintResult(BaseModel):
     @property
     def timestamps(self) -> npt.NDArray[np.datetime64] | list[datetime]: ...
     @property
+    def storage(self) -> str: ...
+    @property
+    def n_times(self) -> int: ...
+    @property
     def constraint_array(self) -> list[bool]: ...
     @property
     def visibility(self) -> list[VisibilityWindow]: ...
+    @property
+    def visibility_windows(self) -> list[VisibilityWindow]: ...
+    @property
+    def violation_start_times(self) -> npt.NDArray[np.datetime64]: ...
+    @property
+    def violation_end_times(self) -> npt.NDArray[np.datetime64]: ...
     def total_violation_duration(self) -> float: ...
-    def in_constraint(self, time: datetime) -> bool: ...
+    def in_constraint(self, time: datetime, mode: str = "exact") -> bool: ...
+    def in_constraint_array(
+        self,
+        times: list[datetime] | npt.NDArray[np.datetime64],
+        mode: str = "exact",
+    ) -> npt.NDArray[np.bool_]: ...
+    @property
+    def constraint_spec(self) -> str | None: ...
+    @property
+    def provenance(self) -> Provenance | None: ...
+    def save(self, path: str | os.PathLike[str]) -> None: ...
+    @classmethod
+    def load(cls, path: str | os.PathLike[str]) -> ConstraintResult: ...
 
 class VisibilityWindowResult(BaseModel):
     start_time: datetime
@@ -82,6 +119,11 @@ class RustConstraintMixin(BaseModel):
         indices: int | list[int] | None = None,
         target_roll: float | None = None,
         n_roll_samples: int = DEFAULT_N_ROLL_SAMPLES,
+        *,
+        between: tuple[datetime, datetime] | None = None,
+        at_times: datetime | list[datetime] | None = None,
+        storage: Literal["full", "compressed", "windows"] = "full",
+        prescreen: int | None = None,
     ) -> ConstraintResult: ...
     def evaluate_batch(
         self,
@@ -92,7 +134,27 @@ class RustConstraintMixin(BaseModel):
         indices: int | list[int] | None = None,
         target_rolls: list[float] | None = None,
         n_roll_samples: int = DEFAULT_N_ROLL_SAMPLES,
+        *,
+        between: tuple[datetime, datetime] | None = None,
+        at_times: datetime | list[datetime] | None = None,
+        storage: Literal["full", "compressed", "windows"] = "full",
+        prescreen: int | None = None,
+        progress: Callable[[int, int], bool | None] | None = None,
+        progress_every: int = 1,
+        cancel: CancellationToken | None = None,
     ) -> list[ConstraintResult]: ...
+    def evaluate_many(
+        self,
+        ephemerides: Mapping[Hashable, Ephemeris] | Sequence[Ephemeris],
+        target_ra: float,
+        target_dec: float,
+        target_roll: float | None = None,
+        n_roll_samples: int = DEFAULT_N_ROLL_SAMPLES,
+        *,
+        between: tuple[datetime, datetime] | None = None,
+        at_times: datetime | list[datetime] | None = None,
+        storage: Literal["full", "compressed", "windows"] = "full",
+    ) -> dict[Hashable, ConstraintResult]: ...
     def in_constraint_batch(
         self,
         ephemeris: Ephemeris,
@@ -102,6 +164,10 @@ class RustConstraintMixin(BaseModel):
         indices: int | list[int] | None = None,
         target_rolls: list[float] | None = None,
         n_roll_samples: int = DEFAULT_N_ROLL_SAMPLES,
+        *,
+        between: tuple[datetime, datetime] | None = None,
+        at_times: datetime | list[datetime] | None = None,
+        cancel: CancellationToken | None = None,
     ) -> npt.NDArray[np.bool_]: ...
     def in_constraint(
         self,
@@ -111,6 +177,9 @@ class RustConstraintMixin(BaseModel):
         target_dec: float,
         target_roll: float | None = None,
         n_roll_samples: int = DEFAULT_N_ROLL_SAMPLES,
+        *,
+        between: tuple[datetime, datetime] | None = None,
+        at_times: datetime | list[datetime] | None = None,
     ) -> bool | list[bool]: ...
     def roll_range(
         self,
@@ -120,6 +189,17 @@ class RustConstraintMixin(BaseModel):
         target_dec: float,
         n_roll_samples: int = 360,
     ) -> list[tuple[float, float]]: ...
+    def metric(
+        self,
+        ephemeris: Ephemeris,
+        target_ra: float,
+        target_dec: float,
+        times: datetime | list[datetime] | None = None,
+        indices: int | list[int] | None = None,
+        *,
+        between: tuple[datetime, datetime] | None = None,
+        at_times: datetime | list[datetime] | None = None,
+    ) -> npt.NDArray[np.float64]: ...
     def instantaneous_field_of_regard(
         self,
         ephemeris: Ephemeris,
@@ -139,6 +219,7 @@ class RustConstraintMixin(BaseModel):
         use_horizons: bool = False,
         spice_kernel: str | None = None,
         target_roll: float | None = None,
+        source: str | None = None,
     ) -> MovingVisibilityResult:
         """
         Evaluate constraint for a moving body (varying RA/Dec over time).
@@ -159,6 +240,20 @@ class RustConstraintMixin(BaseModel):
                 result2 = moon_c.evaluate_moving_body(ephem, body="Jupiter")
         """
         ...
+    def evaluate_virtual_target(
+        self,
+        ephemeris: Ephemeris,
+        target: Literal["nadir", "limb", "sun", "anti_sun", "ram", "anti_ram"],
+        azimuth: float | None = None,
+        target_roll: float | None = None,
+    ) -> MovingVisibilityResult:
+        """
+        Evaluate constraint for a virtual target derived from the observer state.
+
+        ``azimuth`` (degrees, "limb" only) places the limb tangent point about
+        nadir, 0 toward ram and 90 toward the orbit normal.
+        """
+        ...
     def and_(self, other: ConstraintConfig) -> AndConstraint: ...
     def or_(self, other: ConstraintConfig) -> OrConstraint: ...
     def xor_(self, other: ConstraintConfig) -> XorConstraint: ...
@@ -183,6 +278,17 @@ class SunConstraint(RustConstraintMixin):
     type: Literal["sun"] = "sun"
     min_angle: float
     max_angle: float | None = None
+    limb_relative: bool = False
+    schedule: list[ThresholdPeriod] = ...
+
+class SunKeepOutConstraint(RustConstraintMixin):
+    type: Literal["sun_keepout"] = "sun_keepout"
+    pitch_plus: float
+    pitch_minus: float
+    yaw_plus: float
+    yaw_minus: float
+    roll_deg: float | None = None
+    roll_reference: RollReference = RollReference.SUN
 
 class EarthLimbConstraint(RustConstraintMixin):
     type: Literal["earth_limb"] = "earth_limb"
@@ -190,6 +296,15 @@ class EarthLimbConstraint(RustConstraintMixin):
     max_angle: float | None = None
     include_refraction: bool = False
     horizon_dip: bool = False
+    uncertainty_sigma: float = 0.0
+    schedule: list[ThresholdPeriod] = ...
+
+class EarthOccultationConstraint(RustConstraintMixin):
+    type: Literal["earth_occultation"] = "earth_occultation"
+    grazing_altitude: float = 0.0
+    def occultation_events(
+        self, ephemeris: Ephemeris, target_ra: float, target_dec: float
+    ) -> list[tuple[datetime, str]]: ...
 
 class BodyConstraint(RustConstraintMixin):
     type: Literal["body"] = "body"
@@ -198,19 +313,38 @@ class BodyConstraint(RustConstraintMixin):
     max_angle: float | None = None
     fov_polygon: list[tuple[float, float]] | None = None
     roll_deg: float | None = None
+    limb_relative: bool = False
+    schedule: list[ThresholdPeriod] = ...
+    source: Literal["spice", "horizons", "auto"] | None = None
+
+class PlanetsConstraint(RustConstraintMixin):
+    type: Literal["planets"] = "planets"
+    min_angle: float
+    planets: list[str] = ...
+    min_angles: dict[str, float] | None = None
+    source: Literal["spice", "horizons", "auto"] | None = None
 
 class MoonConstraint(RustConstraintMixin):
     type: Literal["moon"] = "moon"
     min_angle: float
     max_angle: float | None = None
+    limb_relative: bool = False
+    schedule: list[ThresholdPeriod] = ...
 
 class EclipseConstraint(RustConstraintMixin):
     type: Literal["eclipse"] = "eclipse"
     umbra_only: bool = True
+    def shadow_events(self, ephemeris: Ephemeris) -> list[tuple[datetime, str]]: ...
 
 class DaytimeConstraint(RustConstraintMixin):
     type: Literal["daytime"] = "daytime"
     twilight: Literal["civil", "nautical", "astronomical", "none"] = "civil"
+    mode: Literal[
+        "observer", "observer_sunlit", "ground_site_night", "nadir_scene_daylit"
+    ] = "observer"
+    latitude: float | None = None
+    longitude: float | None = None
+    height: float | None = None
 
 class AirmassConstraint(RustConstraintMixin):
     type: Literal["airmass"] = "airmass"
@@ -230,6 +364,35 @@ class SAAConstraint(RustConstraintMixin):
     type: Literal["saa"] = "saa"
     polygon: list[tuple[float, float]]
 
+class SkyRegionConstraint(RustConstraintMixin):
+    type: Literal["sky_region"] = "sky_region"
+    polygon: list[tuple[float, float]]
+
+class CutoffRigidityConstraint(RustConstraintMixin):
+    type: Literal["cutoff_rigidity"] = "cutoff_rigidity"
+    min_rigidity: float
+
+class GeomagneticActivityConstraint(RustConstraintMixin):
+    type: Literal["geomagnetic_activity"] = "geomagnetic_activity"
+    max_kp: float
+
+class StationKeepingConstraint(RustConstraintMixin):
+    type: Literal["station_keeping"] = "station_keeping"
+    longitude: float
+    latitude: float = 0.0
+    longitude_tolerance: float = 0.1
+    latitude_tolerance: float = 0.1
+
+class GroundTargetIlluminationConstraint(RustConstraintMixin):
+    type: Literal["ground_target_illumination"] = "ground_target_illumination"
+    latitude: float
+    longitude: float
+    height: float = 0.0
+    min_sun_elevation: float | None = 0.0
+    max_sun_elevation: float | None = None
+    min_elevation: float = 0.0
+    max_off_nadir: float | None = None
+
 class AltAzConstraint(RustConstraintMixin):
     type: Literal["alt_az"] = "alt_az"
     min_altitude: float | None = None
@@ -238,6 +401,21 @@ class AltAzConstraint(RustConstraintMixin):
     max_azimuth: float | None = None
     polygon: list[tuple[float, float]] | None = None
 
+class GimbalConstraint(RustConstraintMixin):
+    type: Literal["gimbal"] = "gimbal"
+    keyhole_radius: float | None = None
+    keyhole_azimuth: float = 0.0
+    keyhole_elevation: float = 90.0
+    max_azimuth_rate: float | None = None
+    max_elevation_rate: float | None = None
+
+class GroundVisibilityConstraint(RustConstraintMixin):
+    type: Literal["ground_visibility"] = "ground_visibility"
+    min_altitude: float = 0.0
+    twilight: Literal["civil", "nautical", "astronomical", "none"] = "astronomical"
+    max_airmass: float | None = None
+    max_moon_altitude: float | None = None
+
 class OrbitRamConstraint(RustConstraintMixin):
     type: Literal["orbit_ram"] = "orbit_ram"
     min_angle: float
@@ -288,15 +466,25 @@ class BrightStarConstraint(RustConstraintMixin):
 
 ConstraintConfig = (
     SunConstraint
+    | SunKeepOutConstraint
     | MoonConstraint
     | EclipseConstraint
     | EarthLimbConstraint
+    | EarthOccultationConstraint
     | BodyConstraint
+    | PlanetsConstraint
     | DaytimeConstraint
     | AirmassConstraint
     | MoonPhaseConstraint
     | SAAConstraint
+    | SkyRegionConstraint
+    | CutoffRigidityConstraint
+    | GeomagneticActivityConstraint
+    | StationKeepingConstraint
+    | GroundTargetIlluminationConstraint
     | AltAzConstraint
+    | GimbalConstraint
+    | GroundVisibilityConstraint
     | OrbitRamConstraint
     | OrbitPoleConstraint
     | AndConstraint
@@ -1,7 +1,8 @@
 # Create a type alias that supports isinstance checks
 import abc
+from collections.abc import Iterator
 from datetime import datetime
-from typing import TYPE_CHECKING
+from typing import TYPE_CHECKING, Any
 
 import numpy as np
 import numpy.typing as npt
@@ -11,12 +12,18 @@ if TYPE_CHECKING:
     from astropy.units import Quantity  # type: ignore[import-untyped]
 
 from ._rust_ephem import (
+    AlmanacEphemeris,
+    ArrayEphemeris,
+    EphemerisSample,
     FileEphemeris,
     GroundEphemeris,
+    HorizonsEphemeris,
     OEMEphemeris,
     PositionVelocityData,
+    RinexNavEphemeris,
     SPICEEphemeris,
     TLEEphemeris,
+    TrajectoryGroundEphemeris,
 )
 
 
@@ -42,6 +49,18 @@ class Ephemeris(abc.ABC):
         """Position and velocity data in ITRS (Earth-fixed) frame."""
         ...
 
+    @property
+    @abc.abstractmethod
+    def teme_pv(self) -> PositionVelocityData:
+        """Position and velocity data in TEME frame."""
+        ...
+
+    @property
+    @abc.abstractmethod
+    def pef_pv(self) -> PositionVelocityData:
+        """Position and velocity data in the pseudo-Earth-fixed (PEF) frame."""
+        ...
+
     @property
     @abc.abstractmethod
     def itrs(self) -> "SkyCoord":
@@ -150,6 +169,24 @@ class Ephemeris(abc.ABC):
         """Geodetic height above the WGS84 ellipsoid as a raw NumPy array in kilometers."""
         ...
 
+    @property
+    @abc.abstractmethod
+    def geomagnetic_latitude_deg(self) -> npt.NDArray[np.float64]:
+        """Centred-dipole geomagnetic latitude in degrees as a raw NumPy array."""
+        ...
+
+    @property
+    @abc.abstractmethod
+    def geomagnetic_longitude_deg(self) -> npt.NDArray[np.float64]:
+        """Centred-dipole geomagnetic longitude in degrees as a raw NumPy array."""
+        ...
+
+    @property
+    @abc.abstractmethod
+    def cutoff_rigidity_gv(self) -> npt.NDArray[np.float64]:
+        """Störmer vertical cutoff rigidity in GV as a raw NumPy array."""
+        ...
+
     @property
     @abc.abstractmethod
     def sun_radius(self) -> "Quantity":
@@ -209,6 +246,37 @@ class Ephemeris(abc.ABC):
         """Find the index of the closest timestamp to the given datetime."""
         ...
 
+    @abc.abstractmethod
+    def fingerprint(self) -> str:
+        """Stable hex digest of the source, options, time grid and computed states."""
+        ...
+
+    @property
+    @abc.abstractmethod
+    def metadata(self) -> dict[str, Any]:
+        """Source, time span, computed frames and Earth orientation state."""
+        ...
+
+    @abc.abstractmethod
+    def summary(self) -> str:
+        """Human-readable, multi-line description of ``metadata``."""
+        ...
+
+    @abc.abstractmethod
+    def __len__(self) -> int:
+        """Number of time steps."""
+        ...
+
+    @abc.abstractmethod
+    def __getitem__(self, index: int) -> EphemerisSample:
+        """State at time step ``index``; negative indices count from the end."""
+        ...
+
+    @abc.abstractmethod
+    def __iter__(self) -> Iterator[EphemerisSample]:
+        """Iterate over the time steps in order."""
+        ...
+
     @property
     @abc.abstractmethod
     def begin(self) -> datetime:
@@ -535,10 +603,24 @@ Ephemeris.register(TLEEphemeris)
 Ephemeris.register(SPICEEphemeris)
 Ephemeris.register(OEMEphemeris)
 Ephemeris.register(GroundEphemeris)
+Ephemeris.register(TrajectoryGroundEphemeris)
 Ephemeris.register(FileEphemeris)
+Ephemeris.register(HorizonsEphemeris)
+Ephemeris.register(AlmanacEphemeris)
+Ephemeris.register(RinexNavEphemeris)
+Ephemeris.register(ArrayEphemeris)
 
 
 # Also create a Union type for type checking
 EphemerisType = (
-    TLEEphemeris | SPICEEphemeris | OEMEphemeris | GroundEphemeris | FileEphemeris
+    TLEEphemeris
+    | SPICEEphemeris
+    | OEMEphemeris
+    | GroundEphemeris
+    | TrajectoryGroundEphemeris
+    | FileEphemeris
+    | HorizonsEphemeris
+    | AlmanacEphemeris
+    | RinexNavEphemeris
+    | ArrayEphemeris
 )
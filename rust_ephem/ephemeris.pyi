@@ -1,25 +1,44 @@
 import abc
+from collections.abc import Iterator, Sequence
 from datetime import datetime
 from typing import Any
 
 import numpy as np
 import numpy.typing as npt
 
+from ._rust_ephem import (
+    AlmanacEphemeris as AlmanacEphemeris,
+)
+from ._rust_ephem import (
+    ArrayEphemeris as ArrayEphemeris,
+)
+from ._rust_ephem import (
+    EphemerisSample,
+)
 from ._rust_ephem import (
     GroundEphemeris as GroundEphemeris,
 )
+from ._rust_ephem import (
+    HorizonsEphemeris as HorizonsEphemeris,
+)
 from ._rust_ephem import (
     OEMEphemeris as OEMEphemeris,
 )
 from ._rust_ephem import (
     PositionVelocityData,
 )
+from ._rust_ephem import (
+    RinexNavEphemeris as RinexNavEphemeris,
+)
 from ._rust_ephem import (
     SPICEEphemeris as SPICEEphemeris,
 )
 from ._rust_ephem import (
     TLEEphemeris as TLEEphemeris,
 )
+from ._rust_ephem import (
+    TrajectoryGroundEphemeris as TrajectoryGroundEphemeris,
+)
 
 class Ephemeris(abc.ABC):
     @property
@@ -33,6 +52,12 @@ class Ephemeris(abc.ABC):
     def itrs_pv(self) -> PositionVelocityData: ...
     @property
     @abc.abstractmethod
+    def teme_pv(self) -> PositionVelocityData: ...
+    @property
+    @abc.abstractmethod
+    def pef_pv(self) -> PositionVelocityData: ...
+    @property
+    @abc.abstractmethod
     def itrs(self) -> Any: ...  # Returns astropy.coordinates.SkyCoord
     @property
     @abc.abstractmethod
@@ -247,6 +272,19 @@ class Ephemeris(abc.ABC):
     @abc.abstractmethod
     def index(self, time: datetime) -> int: ...
     @abc.abstractmethod
+    def fingerprint(self) -> str: ...
+    @property
+    @abc.abstractmethod
+    def metadata(self) -> dict[str, Any]: ...
+    @abc.abstractmethod
+    def summary(self) -> str: ...
+    @abc.abstractmethod
+    def __len__(self) -> int: ...
+    @abc.abstractmethod
+    def __getitem__(self, index: int) -> EphemerisSample: ...
+    @abc.abstractmethod
+    def __iter__(self) -> Iterator[EphemerisSample]: ...
+    @abc.abstractmethod
     def moon_illumination(
         self, time_indices: list[int] | None = None
     ) -> list[float]: ...
@@ -255,16 +293,38 @@ class Ephemeris(abc.ABC):
         self, ra_deg: float, dec_deg: float, time_indices: list[int] | None = None
     ) -> npt.NDArray[np.float64]: ...
     @abc.abstractmethod
+    def radec_to_altaz_batch(
+        self,
+        ra_deg: Sequence[float] | npt.NDArray[np.float64],
+        dec_deg: Sequence[float] | npt.NDArray[np.float64],
+        time_indices: list[int] | None = None,
+    ) -> npt.NDArray[np.float64]: ...
+    @abc.abstractmethod
     def calculate_airmass(
         self, ra_deg: float, dec_deg: float, time_indices: list[int] | None = None
     ) -> list[float]: ...
     @abc.abstractmethod
+    def airmass_batch(
+        self,
+        ras: Sequence[float] | npt.NDArray[np.float64],
+        decs: Sequence[float] | npt.NDArray[np.float64],
+        time_indices: list[int] | None = None,
+    ) -> npt.NDArray[np.float64]: ...
+    @abc.abstractmethod
     def get_body_pv(
-        self, body: str, spice_kernel: str | None = ..., use_horizons: bool = ...
+        self,
+        body: str,
+        spice_kernel: str | None = ...,
+        use_horizons: bool = ...,
+        source: str | None = ...,
     ) -> PositionVelocityData: ...
     @abc.abstractmethod
     def get_body(
-        self, body: str, spice_kernel: str | None = ..., use_horizons: bool = ...
+        self,
+        body: str,
+        spice_kernel: str | None = ...,
+        use_horizons: bool = ...,
+        source: str | None = ...,
     ) -> Any: ...
     @property
     @abc.abstractmethod
@@ -279,4 +339,14 @@ class Ephemeris(abc.ABC):
     @abc.abstractmethod
     def polar_motion(self) -> bool: ...
 
-EphemerisType = TLEEphemeris | SPICEEphemeris | OEMEphemeris | GroundEphemeris
+EphemerisType = (
+    TLEEphemeris
+    | SPICEEphemeris
+    | OEMEphemeris
+    | GroundEphemeris
+    | TrajectoryGroundEphemeris
+    | HorizonsEphemeris
+    | AlmanacEphemeris
+    | RinexNavEphemeris
+    | ArrayEphemeris
+)
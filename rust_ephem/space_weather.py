@@ -0,0 +1,76 @@
+"""Solar and geomagnetic activity indices from Celestrak space-weather data.
+
+The Celestrak ``SW-All.csv`` file gives, for every day since 1957, the eight
+3-hourly planetary Kp and ap indices, the daily Ap, and the observed and
+adjusted F10.7 solar flux with its 81-day averages. It is downloaded on first
+use and cached in :func:`~rust_ephem.get_cache_dir`, refreshed after
+``cache.space_weather_ttl`` seconds; :func:`load_space_weather` substitutes a
+local copy for offline use. Days after the last observation carry NOAA
+predictions of Ap and F10.7 but no 3-hourly Kp.
+
+Typical usage::
+
+    from rust_ephem import space_weather_series
+
+    sw = space_weather_series(ephem.timestamp)
+    storm = sw["kp"] >= 5
+"""
+
+from __future__ import annotations
+
+from collections.abc import Sequence
+from datetime import datetime
+
+import numpy as np
+from numpy.typing import NDArray
+
+from ._rust_ephem import (
+    get_space_weather,
+    init_space_weather,
+    is_space_weather_available,
+    load_space_weather,
+)
+
+__all__ = [
+    "get_space_weather",
+    "init_space_weather",
+    "is_space_weather_available",
+    "load_space_weather",
+    "space_weather_series",
+]
+
+_SERIES_KEYS = (
+    "kp",
+    "ap",
+    "ap_daily",
+    "f107_obs",
+    "f107_adj",
+    "f107_obs_previous",
+    "f107_obs_center81",
+    "f107_obs_last81",
+)
+
+
+def space_weather_series(
+    times: Sequence[datetime] | NDArray[np.object_],
+) -> dict[str, NDArray[np.float64]]:
+    """Space-weather indices at each of ``times``.
+
+    Args:
+        times: UTC datetimes, e.g. ``ephem.timestamp``
+
+    Returns:
+        Dict mapping ``kp``, ``ap``, ``ap_daily``, ``f107_obs``, ``f107_adj``,
+        ``f107_obs_previous``, ``f107_obs_center81`` and ``f107_obs_last81``
+        to float arrays the length of ``times``, NaN where there is no value
+    """
+    series = {key: np.full(len(times), np.nan) for key in _SERIES_KEYS}
+    for i, time in enumerate(times):
+        sample = get_space_weather(time)
+        if sample is None:
+            continue
+        for key in _SERIES_KEYS:
+            value = sample[key]
+            if value is not None:
+                series[key][i] = value
+    return series
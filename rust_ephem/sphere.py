@@ -0,0 +1,163 @@
+"""Great-circle geometry for custom constraint logic.
+
+Points are (longitude, latitude) pairs in degrees: RA/Dec on the sky or
+geodetic longitude/latitude on the Earth. Every function accepts scalars or
+arrays, broadcast against each other as in NumPy, and returns a scalar for
+scalar input or an array of the broadcast shape otherwise.
+
+Typical usage::
+
+    from rust_ephem import sphere
+
+    ras, decs = ephem.sun_ra_dec_deg.T
+    near_sun = sphere.separation(target_ra, target_dec, ras, decs) < 45.0
+
+    region = sphere.SphericalPolygon([(80, -10), (100, -10), (100, 10), (80, 10)])
+    inside = region.contains(ras, decs)
+"""
+
+from __future__ import annotations
+
+from collections.abc import Sequence
+from typing import Any
+
+import numpy as np
+from numpy.typing import ArrayLike
+
+from ._rust_ephem import (
+    SphericalPolygon,
+    cones_overlap as _cones_overlap,
+    great_circle_separation as _separation,
+    position_angle as _position_angle,
+)
+
+__all__ = [
+    "SphericalPolygon",
+    "bearing",
+    "cones_overlap",
+    "point_in_polygon",
+    "position_angle",
+    "separation",
+]
+
+
+def _broadcast(*values: ArrayLike) -> tuple[tuple[int, ...], list[list[float]]]:
+    """Broadcast the inputs against each other and flatten them"""
+    arrays = np.broadcast_arrays(*(np.asarray(v, dtype=np.float64) for v in values))
+    return arrays[0].shape, [a.ravel().tolist() for a in arrays]
+
+
+def _shaped(result: Any, shape: tuple[int, ...]) -> Any:
+    """Reshape a flat result to the broadcast shape, or unwrap a scalar"""
+    array = np.asarray(result).reshape(shape)
+    return array.item() if shape == () else array
+
+
+def separation(
+    lon1: ArrayLike, lat1: ArrayLike, lon2: ArrayLike, lat2: ArrayLike
+) -> Any:
+    """Great-circle angle between points, in degrees.
+
+    Uses Vincenty's formula, which stays accurate for coincident and antipodal
+    points.
+
+    Args:
+        lon1, lat1: First points in degrees
+        lon2, lat2: Second points in degrees
+
+    Returns:
+        Separations in [0, 180] degrees
+    """
+    shape, flat = _broadcast(lon1, lat1, lon2, lat2)
+    return _shaped(_separation(*flat), shape)
+
+
+def position_angle(
+    lon1: ArrayLike, lat1: ArrayLike, lon2: ArrayLike, lat2: ArrayLike
+) -> Any:
+    """Position angle of the second points seen from the first, in degrees.
+
+    Measured from north through east, in [0, 360); 0 for coincident points.
+
+    Args:
+        lon1, lat1: Reference points in degrees
+        lon2, lat2: Points whose direction is wanted, in degrees
+
+    Returns:
+        Position angles in degrees
+    """
+    shape, flat = _broadcast(lon1, lat1, lon2, lat2)
+    return _shaped(_position_angle(*flat), shape)
+
+
+def bearing(
+    lon1: ArrayLike, lat1: ArrayLike, lon2: ArrayLike, lat2: ArrayLike
+) -> Any:
+    """Initial great-circle bearing from the first points to the second, in degrees.
+
+    Measured clockwise from north as seen from outside the sphere, in
+    [0, 360); numerically the same as :func:`position_angle`.
+
+    Args:
+        lon1, lat1: Start points in degrees
+        lon2, lat2: Destination points in degrees
+
+    Returns:
+        Bearings in degrees
+    """
+    return position_angle(lon1, lat1, lon2, lat2)
+
+
+def cones_overlap(
+    lon1: ArrayLike,
+    lat1: ArrayLike,
+    radius1: ArrayLike,
+    lon2: ArrayLike,
+    lat2: ArrayLike,
+    radius2: ArrayLike,
+) -> Any:
+    """Whether circular cones overlap.
+
+    Cones overlap when their axes are closer than the sum of their half-angles;
+    cones that only touch do not.
+
+    Args:
+        lon1, lat1: Axes of the first cones in degrees
+        radius1: Half-angles of the first cones in degrees
+        lon2, lat2: Axes of the second cones in degrees
+        radius2: Half-angles of the second cones in degrees
+
+    Returns:
+        True where the cones overlap
+
+    Raises:
+        ValueError: If a radius is negative
+    """
+    shape, flat = _broadcast(lon1, lat1, radius1, lon2, lat2, radius2)
+    return _shaped(_cones_overlap(*flat), shape)
+
+
+def point_in_polygon(
+    polygon: SphericalPolygon | Sequence[tuple[float, float]],
+    lon: ArrayLike,
+    lat: ArrayLike,
+) -> Any:
+    """Whether points are inside a polygon with great-circle edges.
+
+    Args:
+        polygon: A :class:`SphericalPolygon`, or its (longitude, latitude)
+            vertices in degrees. The interior is the smaller region the edges
+            bound, so the vertices may run either way but must fit within a
+            hemisphere. Build the polygon once when testing repeatedly.
+        lon, lat: Points in degrees
+
+    Returns:
+        True where the point is inside
+
+    Raises:
+        ValueError: If the vertices do not form a valid polygon
+    """
+    if not isinstance(polygon, SphericalPolygon):
+        polygon = SphericalPolygon(list(polygon))
+    shape, (lons, lats) = _broadcast(lon, lat)
+    return _shaped(polygon.contains(lons, lats), shape)
@@ -7,7 +7,7 @@ that can retrieve TLEs from various sources (files, URLs, Celestrak, Space-Track
 
 from __future__ import annotations
 
-from datetime import datetime, timedelta
+from datetime import date, datetime, timedelta
 from typing import Any
 
 from pydantic import BaseModel, Field, computed_field, model_validator
@@ -28,6 +28,8 @@ class TLERecord(BaseModel):
         name: Optional satellite name (from 3-line TLE format)
         epoch: TLE epoch timestamp (extracted from line1)
         source: Source of the TLE data (e.g., 'celestrak', 'spacetrack', 'file', 'url')
+        decay_date: Date the object re-entered, from the satellite catalogue
+            (SATCAT); None if it is in orbit or was not looked up
     """
 
     line1: str = Field(
@@ -39,6 +41,9 @@ class TLERecord(BaseModel):
     name: str | None = Field(None, description="Optional satellite name")
     epoch: datetime = Field(..., description="TLE epoch timestamp")
     source: str | None = Field(None, description="Source of the TLE data")
+    decay_date: date | None = Field(
+        None, description="SATCAT decay date of the object, if it has re-entered"
+    )
 
     @model_validator(mode="before")
     def _validate_tle_lines(cls, values: dict[str, Any]) -> dict[str, Any]:
@@ -109,6 +114,7 @@ def fetch_tle(
     spacetrack_password: str | None = None,
     epoch_tolerance_days: float | None = None,
     enforce_source: str | None = None,
+    cache_ttl: int | None = None,
 ) -> TLERecord:
     """
     Fetch a TLE from various sources.
@@ -137,6 +143,9 @@ def fetch_tle(
             differ from target epoch (default: 4.0 days)
         enforce_source: Enforce use of specific source without failover.
             Must be "celestrak", "spacetrack", or None (default behavior with failover)
+        cache_ttl: Largest age in seconds of a cached Celestrak or URL download
+            before it is fetched again; 0 forces a refresh (default: 86400, see
+            set_tle_cache_defaults)
 
     Returns:
         TLERecord containing the TLE data and metadata
@@ -171,6 +180,7 @@ def fetch_tle(
             spacetrack_password=spacetrack_password,
             epoch_tolerance_days=epoch_tolerance_days,
             enforce_source=enforce_source,
+            cache_ttl=cache_ttl,
         )
     except ValueError as exc:
         # Surface a clearer message when the upstream source returned no usable TLE
@@ -213,4 +223,5 @@ def fetch_tle(
         name=result.get("name"),
         epoch=result["epoch"],
         source=result.get("source"),
+        decay_date=result.get("decay_date"),
     )
@@ -1,6 +1,6 @@
 """Type stubs for the TLE module"""
 
-from datetime import datetime
+from datetime import date, datetime
 
 from pydantic import BaseModel
 
@@ -17,6 +17,8 @@ class TLERecord(BaseModel):
         name: Optional satellite name (from 3-line TLE format)
         epoch: TLE epoch timestamp (extracted from line1)
         source: Source of the TLE data (e.g., 'celestrak', 'spacetrack', 'file', 'url')
+        decay_date: Date the object re-entered, from the satellite catalogue
+            (SATCAT); None if it is in orbit or was not looked up
     """
 
     line1: str
@@ -24,6 +26,7 @@ class TLERecord(BaseModel):
     name: str | None
     epoch: datetime
     source: str | None
+    decay_date: date | None
 
     @property
     def norad_id(self) -> int:
@@ -58,6 +61,8 @@ def fetch_tle(
     spacetrack_username: str | None = None,
     spacetrack_password: str | None = None,
     epoch_tolerance_days: float | None = None,
+    enforce_source: str | None = None,
+    cache_ttl: int | None = None,
 ) -> TLERecord:
     """
     Fetch a TLE from various sources.
@@ -84,6 +89,11 @@ def fetch_tle(
         spacetrack_password: Space-Track.org password (or use SPACETRACK_PASSWORD env var)
         epoch_tolerance_days: For Space-Track cache: how many days TLE epoch can
             differ from target epoch (default: 4.0 days)
+        enforce_source: Enforce use of specific source without failover.
+            Must be "celestrak", "spacetrack", or None (default behavior with failover)
+        cache_ttl: Largest age in seconds of a cached Celestrak or URL download
+            before it is fetched again; 0 forces a refresh (default: 86400, see
+            set_tle_cache_defaults)
 
     Returns:
         TLERecord containing the TLE data and metadata
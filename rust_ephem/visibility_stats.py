@@ -0,0 +1,137 @@
+"""Plot-ready statistics from lists of visibility windows.
+
+Each helper takes ``windows``, a sequence of rows (typically one per target),
+where each row is a sequence of windows. A window is anything with
+``start_time`` and ``end_time`` attributes, such as a
+:class:`~rust_ephem.VisibilityWindow` or a ``ScheduledObservation``, or a
+``(start, end)`` pair of datetimes. Results are plain NumPy arrays, with times
+as UTC ``datetime64[us]`` and durations in seconds, so any plotting library can
+consume them.
+
+Typical usage::
+
+    from rust_ephem import gantt_rows, cumulative_visibility
+
+    windows = [constraint.evaluate(ephem, ra, dec).visibility for ra, dec in targets]
+    rows, starts, ends = gantt_rows(windows)
+    plt.barh(rows, ends - starts, left=starts)
+
+    curves = cumulative_visibility(windows, ephem.timestamp)
+    plt.plot(ephem.timestamp, curves.T / 3600)
+"""
+
+from __future__ import annotations
+
+from collections.abc import Sequence
+from datetime import datetime, timezone
+from typing import Any
+
+import numpy as np
+from numpy.typing import NDArray
+
+__all__ = ["cumulative_visibility", "gantt_rows", "window_duration_histogram"]
+
+_ONE_SECOND = np.timedelta64(1, "s")
+
+
+def _to_datetime64(value: Any) -> np.datetime64:
+    """Convert a datetime (naive values are taken as UTC) to UTC datetime64[us]."""
+    if isinstance(value, datetime) and value.tzinfo is not None:
+        value = value.astimezone(timezone.utc).replace(tzinfo=None)
+    return np.datetime64(value, "us")
+
+
+def _row_bounds(
+    row: Sequence[Any],
+) -> tuple[NDArray[np.datetime64], NDArray[np.datetime64]]:
+    """Start and end times of one row of windows."""
+    bounds = [
+        (w.start_time, w.end_time) if hasattr(w, "start_time") else tuple(w)
+        for w in row
+    ]
+    starts = np.array([_to_datetime64(s) for s, _ in bounds], dtype="datetime64[us]")
+    ends = np.array([_to_datetime64(e) for _, e in bounds], dtype="datetime64[us]")
+    if np.any(ends < starts):
+        raise ValueError("Visibility windows must not end before they start")
+    return starts, ends
+
+
+def gantt_rows(
+    windows: Sequence[Sequence[Any]],
+) -> tuple[NDArray[np.int64], NDArray[np.datetime64], NDArray[np.datetime64]]:
+    """Flatten rows of windows into Gantt chart bars.
+
+    Args:
+        windows: One sequence of windows per row
+
+    Returns:
+        ``(rows, starts, ends)``: the row index, start time and end time of
+        every window, in row order and then window order
+    """
+    bounds = [_row_bounds(row) for row in windows]
+    if not bounds:
+        empty = np.empty(0, dtype="datetime64[us]")
+        return np.empty(0, dtype=np.int64), empty, empty.copy()
+    rows = np.concatenate(
+        [np.full(len(s), i, dtype=np.int64) for i, (s, _) in enumerate(bounds)]
+    )
+    starts = np.concatenate([s for s, _ in bounds])
+    ends = np.concatenate([e for _, e in bounds])
+    return rows, starts, ends
+
+
+def cumulative_visibility(
+    windows: Sequence[Sequence[Any]],
+    times: Sequence[Any] | NDArray[Any],
+) -> NDArray[np.float64]:
+    """Visible time accumulated up to each of ``times``, per row.
+
+    Args:
+        windows: One sequence of windows per row
+        times: Times to evaluate the curves at (datetimes or datetime64)
+
+    Returns:
+        Array of shape ``(n_rows, n_times)`` with the visible seconds of each
+        row up to and including each time
+    """
+    t = np.array([_to_datetime64(v) for v in times], dtype="datetime64[us]")
+    curves = np.zeros((len(windows), len(t)))
+    for i, row in enumerate(windows):
+        starts, ends = _row_bounds(row)
+        if len(starts) == 0:
+            continue
+        elapsed = (t[np.newaxis, :] - starts[:, np.newaxis]) / _ONE_SECOND
+        durations = (ends - starts) / _ONE_SECOND
+        curves[i] = np.clip(elapsed, 0.0, durations[:, np.newaxis]).sum(axis=0)
+    return curves
+
+
+def window_duration_histogram(
+    windows: Sequence[Sequence[Any]],
+    bins: int | Sequence[float] = 10,
+    duration_range: tuple[float, float] | None = None,
+) -> tuple[NDArray[np.int64], NDArray[np.float64]]:
+    """Histogram of window durations, per row with shared bin edges.
+
+    Args:
+        windows: One sequence of windows per row
+        bins: Number of bins, or bin edges in seconds (as for ``numpy.histogram``)
+        duration_range: Lower and upper edge in seconds when ``bins`` is a number
+            (default: the range of all durations)
+
+    Returns:
+        ``(counts, edges)``: counts of shape ``(n_rows, n_bins)`` and the
+        ``n_bins + 1`` bin edges in seconds. Summing ``counts`` over axis 0
+        gives the histogram of all windows.
+    """
+    durations: list[NDArray[np.float64]] = []
+    for row in windows:
+        starts, ends = _row_bounds(row)
+        durations.append((ends - starts) / _ONE_SECOND)
+    edges = np.histogram_bin_edges(
+        np.concatenate(durations + [np.empty(0)]), bins=bins, range=duration_range
+    )
+    counts = np.zeros((len(durations), len(edges) - 1), dtype=np.int64)
+    for i, row_durations in enumerate(durations):
+        counts[i] = np.histogram(row_durations, bins=edges)[0]
+    return counts, edges
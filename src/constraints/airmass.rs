@@ -1,9 +1,9 @@
-/// Airmass constraint implementation
 use super::core::{track_violations, ConstraintConfig, ConstraintEvaluator, ConstraintResult};
+/// Airmass constraint implementation
+use crate::constraints::error::{ConstraintError, OrMissing};
 use crate::utils::celestial::calculate_airmass_batch_fast;
 use chrono::{DateTime, Utc};
 use ndarray::Array2;
-use pyo3::PyResult;
 use serde::{Deserialize, Serialize};
 
 /// Configuration for Airmass constraint
@@ -16,6 +16,68 @@ pub struct AirmassConfig {
     pub min_airmass: Option<f64>,
 }
 
+impl AirmassConfig {
+    /// Start a builder with a maximum airmass of 2 and no minimum
+    pub fn builder() -> AirmassBuilder {
+        AirmassBuilder::default()
+    }
+
+    /// Check that the limits are above 1 and ordered
+    pub fn validate(&self) -> Result<(), String> {
+        if self.max_airmass.is_nan() || self.max_airmass <= 1.0 {
+            return Err("max_airmass must be greater than 1.0".to_string());
+        }
+        if let Some(min) = self.min_airmass {
+            if min.is_nan() || min <= 1.0 {
+                return Err("min_airmass must be greater than 1.0".to_string());
+            }
+            if min >= self.max_airmass {
+                return Err("min_airmass must be less than max_airmass".to_string());
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Builder for [`AirmassConfig`]
+#[derive(Debug, Clone)]
+pub struct AirmassBuilder {
+    config: AirmassConfig,
+}
+
+impl Default for AirmassBuilder {
+    fn default() -> Self {
+        AirmassBuilder {
+            config: AirmassConfig {
+                max_airmass: 2.0,
+                min_airmass: None,
+            },
+        }
+    }
+}
+
+impl AirmassBuilder {
+    /// Maximum allowed airmass
+    pub fn max_airmass(mut self, airmass: f64) -> Self {
+        self.config.max_airmass = airmass;
+        self
+    }
+
+    /// Minimum allowed airmass, to exclude targets near the zenith
+    pub fn min_airmass(mut self, airmass: f64) -> Self {
+        self.config.min_airmass = Some(airmass);
+        self
+    }
+
+    /// Validate and return the config
+    pub fn build(self) -> Result<AirmassConfig, ConstraintError> {
+        self.config
+            .validate()
+            .map_err(ConstraintError::InvalidInput)?;
+        Ok(self.config)
+    }
+}
+
 impl ConstraintConfig for AirmassConfig {
     fn to_evaluator(&self) -> Box<dyn ConstraintEvaluator> {
         Box::new(AirmassEvaluator {
@@ -50,11 +112,13 @@ impl ConstraintEvaluator for AirmassEvaluator {
         target_ra: f64,
         target_dec: f64,
         time_indices: Option<&[usize]>,
-    ) -> PyResult<ConstraintResult> {
-        // Get airmass using fast Kasten formula (50-100x faster than SOFA)
+    ) -> Result<ConstraintResult, ConstraintError> {
+        // Kasten airmass from geodetic-horizon altitudes, without SOFA's
+        // refraction and aberration (see `calculate_airmass_batch_fast`)
         // Vectorized call handles the single target via slice
         let airmass_array =
-            calculate_airmass_batch_fast(&[target_ra], &[target_dec], ephemeris, time_indices);
+            calculate_airmass_batch_fast(&[target_ra], &[target_dec], ephemeris, time_indices)
+                .or_missing("observer positions")?;
         let airmass_values = airmass_array.row(0).to_owned();
 
         // Extract and filter ephemeris data for times
@@ -120,10 +184,17 @@ impl ConstraintEvaluator for AirmassEvaluator {
         target_ras: &[f64],
         target_decs: &[f64],
         time_indices: Option<&[usize]>,
-    ) -> PyResult<Array2<bool>> {
+    ) -> Result<Array2<bool>, ConstraintError> {
+        if target_ras.len() != target_decs.len() {
+            return Err(ConstraintError::invalid_input(
+                "target_ras and target_decs must have the same length",
+            ));
+        }
+
         // Get airmass for ALL targets at ALL times in one vectorized call
         let airmass_values =
-            calculate_airmass_batch_fast(target_ras, target_decs, ephemeris, time_indices);
+            calculate_airmass_batch_fast(target_ras, target_decs, ephemeris, time_indices)
+                .or_missing("observer positions")?;
 
         // Vectorized constraint evaluation - single pass with mapv, no nested loops
         let result = airmass_values.mapv(|airmass| {
@@ -137,6 +208,20 @@ impl ConstraintEvaluator for AirmassEvaluator {
         Ok(result)
     }
 
+    /// Airmass, infinite below the horizon
+    fn metric(
+        &self,
+        ephemeris: &dyn crate::ephemeris::ephemeris_common::EphemerisBase,
+        target_ra: f64,
+        target_dec: f64,
+        time_indices: Option<&[usize]>,
+    ) -> Result<Option<Vec<f64>>, ConstraintError> {
+        let airmass_array =
+            calculate_airmass_batch_fast(&[target_ra], &[target_dec], ephemeris, time_indices)
+                .or_missing("observer positions")?;
+        Ok(Some(airmass_array.row(0).to_vec()))
+    }
+
     fn name(&self) -> String {
         self.format_name()
     }
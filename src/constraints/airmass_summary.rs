@@ -0,0 +1,175 @@
+//! Per-target airmass statistics
+//!
+//! Reduces the fast batch airmass grid to the minimum airmass, the time it
+//! is reached and the time spent at or below an airmass limit for each
+//! target, without handing the full (n_targets, n_times) grid to Python.
+
+use chrono::{DateTime, Utc};
+use ndarray::{Array1, ArrayView1};
+use numpy::IntoPyArray;
+use pyo3::prelude::*;
+use pyo3::types::PyList;
+
+use crate::ephemeris::ground_network::{runs, seconds_between};
+use crate::ephemeris::with_ephemeris;
+use crate::utils::celestial::calculate_airmass_batch_fast;
+use crate::utils::time_utils::utc_to_python_datetime;
+
+/// Airmass statistics of one target
+struct TargetAirmass {
+    min_airmass: f64,
+    /// Sample index of the minimum, None if the target never rises
+    min_index: Option<usize>,
+    seconds_below: f64,
+}
+
+/// Reduce one row of the airmass grid
+fn summarize(times: &[DateTime<Utc>], airmass: ArrayView1<f64>, max_airmass: f64) -> TargetAirmass {
+    let min_index = airmass
+        .iter()
+        .enumerate()
+        .filter(|(_, am)| am.is_finite())
+        .min_by(|a, b| a.1.total_cmp(b.1))
+        .map(|(i, _)| i);
+    let below: Vec<bool> = airmass.iter().map(|&am| am <= max_airmass).collect();
+    TargetAirmass {
+        min_airmass: min_index.map_or(f64::INFINITY, |i| airmass[i]),
+        min_index,
+        seconds_below: runs(&below)
+            .iter()
+            .map(|&r| seconds_between(times, r))
+            .sum(),
+    }
+}
+
+/// Minimum airmass, its time and time below an airmass limit, per target
+#[pyclass]
+pub struct AirmassSummary {
+    max_airmass: f64,
+    times: Vec<DateTime<Utc>>,
+    targets: Vec<TargetAirmass>,
+}
+
+#[pymethods]
+impl AirmassSummary {
+    /// Lowest airmass per target (inf if the target never rises)
+    #[getter]
+    fn min_airmass(&self, py: Python) -> Py<PyAny> {
+        Array1::from_iter(self.targets.iter().map(|t| t.min_airmass))
+            .into_pyarray(py)
+            .into()
+    }
+
+    /// Time of the lowest airmass per target (None if the target never rises)
+    #[getter]
+    fn min_airmass_time(&self, py: Python) -> PyResult<Py<PyAny>> {
+        let values = self
+            .targets
+            .iter()
+            .map(|t| match t.min_index {
+                Some(i) => utc_to_python_datetime(py, &self.times[i]),
+                None => Ok(py.None()),
+            })
+            .collect::<PyResult<Vec<_>>>()?;
+        let np = pyo3::types::PyModule::import(py, "numpy")
+            .map_err(|_| pyo3::exceptions::PyImportError::new_err("numpy is required"))?;
+        Ok(np
+            .getattr("array")?
+            .call1((PyList::new(py, values)?, "object"))?
+            .into())
+    }
+
+    /// Hours per target spent at or below `max_airmass`
+    #[getter]
+    fn hours_below(&self, py: Python) -> Py<PyAny> {
+        Array1::from_iter(self.targets.iter().map(|t| t.seconds_below / 3600.0))
+            .into_pyarray(py)
+            .into()
+    }
+
+    /// Airmass limit used for `hours_below`
+    #[getter]
+    fn max_airmass(&self) -> f64 {
+        self.max_airmass
+    }
+
+    fn __len__(&self) -> usize {
+        self.targets.len()
+    }
+
+    fn __repr__(&self) -> String {
+        format!(
+            "AirmassSummary(targets={}, max_airmass={})",
+            self.targets.len(),
+            self.max_airmass
+        )
+    }
+}
+
+/// Summarize the airmass of each target over all ephemeris times
+pub fn airmass_summary(
+    ephemeris: &Bound<'_, PyAny>,
+    ras: &[f64],
+    decs: &[f64],
+    max_airmass: f64,
+) -> PyResult<AirmassSummary> {
+    if ras.len() != decs.len() {
+        return Err(pyo3::exceptions::PyValueError::new_err(
+            "ras and decs must have the same length",
+        ));
+    }
+    if max_airmass < 1.0 {
+        return Err(pyo3::exceptions::PyValueError::new_err(
+            "max_airmass must be at least 1.0",
+        ));
+    }
+    with_ephemeris(ephemeris, |ephem| {
+        let times = ephem.get_times()?;
+        let airmass = calculate_airmass_batch_fast(ras, decs, ephem, None)?;
+        let targets = airmass
+            .rows()
+            .into_iter()
+            .map(|row| summarize(&times, row, max_airmass))
+            .collect();
+        Ok(AirmassSummary {
+            max_airmass,
+            times,
+            targets,
+        })
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::{Duration, TimeZone};
+    use ndarray::array;
+
+    fn times(n: usize) -> Vec<DateTime<Utc>> {
+        let start = Utc.with_ymd_and_hms(2025, 1, 1, 0, 0, 0).unwrap();
+        (0..n)
+            .map(|i| start + Duration::minutes(10 * i as i64))
+            .collect()
+    }
+
+    #[test]
+    fn test_summarize() {
+        let times = times(6);
+        let airmass = array![f64::INFINITY, 2.5, 1.8, 1.2, 1.9, 3.0];
+        let summary = summarize(&times, airmass.view(), 2.0);
+        assert_eq!(summary.min_airmass, 1.2);
+        assert_eq!(summary.min_index, Some(3));
+        // Samples 2-4 are at or below 2.0
+        assert_eq!(summary.seconds_below, 1200.0);
+    }
+
+    #[test]
+    fn test_summarize_never_rises() {
+        let times = times(3);
+        let airmass = array![f64::INFINITY, f64::INFINITY, f64::INFINITY];
+        let summary = summarize(&times, airmass.view(), 2.0);
+        assert_eq!(summary.min_airmass, f64::INFINITY);
+        assert_eq!(summary.min_index, None);
+        assert_eq!(summary.seconds_below, 0.0);
+    }
+}
@@ -1,9 +1,10 @@
-/// Altitude/Azimuth constraint implementation
 use super::core::{track_violations, ConstraintConfig, ConstraintEvaluator, ConstraintResult};
+/// Altitude/Azimuth constraint implementation
+use crate::constraints::error::ConstraintError;
+use crate::utils::horizon::HorizonMask;
 use crate::utils::polygon;
 use chrono::{DateTime, Utc};
 use ndarray::Array2;
-use pyo3::PyResult;
 use serde::{Deserialize, Serialize};
 
 /// Configuration for Altitude/Azimuth constraint
@@ -84,13 +85,14 @@ impl ConstraintEvaluator for AltAzEvaluator {
         target_ra: f64,
         target_dec: f64,
         time_indices: Option<&[usize]>,
-    ) -> PyResult<ConstraintResult> {
+    ) -> Result<ConstraintResult, ConstraintError> {
         // Extract and filter ephemeris data
         let (times_filtered, _obs_filtered) =
             extract_observer_ephemeris_data!(ephemeris, time_indices);
 
         // Compute alt/az for this target at selected times
         let altaz = ephemeris.radec_to_altaz(target_ra, target_dec, time_indices);
+        let horizon = ephemeris.horizon_mask();
 
         let violations = track_violations(
             &times_filtered,
@@ -103,6 +105,14 @@ impl ConstraintEvaluator for AltAzEvaluator {
                     return (true, 1.0);
                 }
 
+                // Directions below the site's local horizon are always blocked
+                if let Some(mask) = horizon {
+                    let min_elevation = mask.min_elevation(azimuth_deg);
+                    if altitude_deg < min_elevation {
+                        return (true, (min_elevation - altitude_deg).min(1.0));
+                    }
+                }
+
                 // Check altitude constraints
                 let mut violated = false;
                 let mut severity = 1.0;
@@ -158,7 +168,7 @@ impl ConstraintEvaluator for AltAzEvaluator {
                 // Use the first timestamp alt/az for the description
                 let altitude_deg = altaz[[0, 0]];
                 let azimuth_deg = altaz[[0, 1]];
-                self.format_violation_description(altitude_deg, azimuth_deg)
+                self.format_violation_description(altitude_deg, azimuth_deg, horizon)
             },
         );
 
@@ -177,7 +187,7 @@ impl ConstraintEvaluator for AltAzEvaluator {
         target_ras: &[f64],
         target_decs: &[f64],
         time_indices: Option<&[usize]>,
-    ) -> PyResult<Array2<bool>> {
+    ) -> Result<Array2<bool>, ConstraintError> {
         let n_targets = target_ras.len();
         let altaz_list: Vec<_> = target_ras
             .iter()
@@ -186,6 +196,7 @@ impl ConstraintEvaluator for AltAzEvaluator {
             .collect();
 
         let n_times = altaz_list.first().map(|a| a.nrows()).unwrap_or(0);
+        let horizon = ephemeris.horizon_mask();
 
         let mut result = Array2::<bool>::from_elem((n_targets, n_times), false);
 
@@ -201,6 +212,10 @@ impl ConstraintEvaluator for AltAzEvaluator {
                     violated = true;
                 }
 
+                if let Some(mask) = horizon {
+                    violated = violated || !mask.is_visible(altitude_deg, azimuth_deg);
+                }
+
                 if !violated {
                     // Check altitude
                     if let Some(min_alt) = self.min_altitude {
@@ -244,6 +259,18 @@ impl ConstraintEvaluator for AltAzEvaluator {
         Ok(result)
     }
 
+    /// Target altitude in degrees
+    fn metric(
+        &self,
+        ephemeris: &dyn crate::ephemeris::ephemeris_common::EphemerisBase,
+        target_ra: f64,
+        target_dec: f64,
+        time_indices: Option<&[usize]>,
+    ) -> Result<Option<Vec<f64>>, ConstraintError> {
+        let altaz = ephemeris.radec_to_altaz(target_ra, target_dec, time_indices);
+        Ok(Some(altaz.column(0).to_vec()))
+    }
+
     fn name(&self) -> String {
         self.format_name()
     }
@@ -256,7 +283,12 @@ impl ConstraintEvaluator for AltAzEvaluator {
 impl AltAzEvaluator {
     /// Format a description of the violation based on altitude and azimuth
     #[allow(dead_code)]
-    fn format_violation_description(&self, altitude_deg: f64, azimuth_deg: f64) -> String {
+    fn format_violation_description(
+        &self,
+        altitude_deg: f64,
+        azimuth_deg: f64,
+        horizon: Option<&HorizonMask>,
+    ) -> String {
         let mut reasons = Vec::new();
 
         // Check polygon violation first
@@ -267,6 +299,16 @@ impl AltAzEvaluator {
             ));
         }
 
+        if let Some(mask) = horizon {
+            let min_elevation = mask.min_elevation(azimuth_deg);
+            if altitude_deg < min_elevation {
+                reasons.push(format!(
+                    "altitude {:.1}° below local horizon {:.1}° at az {:.1}°",
+                    altitude_deg, min_elevation, azimuth_deg
+                ));
+            }
+        }
+
         if let Some(min_alt) = self.min_altitude {
             if altitude_deg < min_alt {
                 reasons.push(format!(
@@ -0,0 +1,798 @@
+//! Constraint checks of a planned attitude history
+//!
+//! Rotates body-frame axes (the boresight and any instrument axes) through a
+//! time series of attitude quaternions and evaluates constraints against the
+//! resulting pointing, so a presented plan can be checked rather than a fixed
+//! target. Violations are reported as windows tagged with the axis and the
+//! constraint they break. The same attitude history also gives the Sun
+//! incidence angle on each body face, for thermal and power screening.
+//!
+//! Slews between two attitudes are sampled along the eigen-axis rotation and
+//! checked for the Sun or Moon crossing star-tracker exclusion cones, which a
+//! check of the end attitudes alone would miss.
+
+use chrono::{DateTime, Utc};
+use ndarray::{Array2, Array3};
+use numpy::IntoPyArray;
+use pyo3::prelude::*;
+
+use crate::constraints::eclipse::in_earth_shadow;
+use crate::constraints::PyConstraint;
+use crate::ephemeris::ground_network::{runs, timestamp_array};
+use crate::ephemeris::with_ephemeris;
+use crate::utils::time_utils::utc_to_python_datetime;
+use crate::utils::vector_math::unit_vectors_to_radec_batch;
+
+/// Body-frame axis checked when none are given
+pub const DEFAULT_AXIS: (&str, [f64; 3]) = ("boresight", [1.0, 0.0, 0.0]);
+
+/// A window in which one body axis violates one constraint
+#[pyclass]
+#[derive(Clone)]
+pub struct AttitudeViolation {
+    /// Name of the offending body axis or instrument
+    #[pyo3(get)]
+    axis: String,
+    /// Name of the violated constraint
+    #[pyo3(get)]
+    constraint: String,
+    start_time_internal: DateTime<Utc>,
+    end_time_internal: DateTime<Utc>,
+}
+
+#[pymethods]
+impl AttitudeViolation {
+    #[getter]
+    fn start_time(&self, py: Python) -> PyResult<Py<PyAny>> {
+        utc_to_python_datetime(py, &self.start_time_internal)
+    }
+
+    #[getter]
+    fn end_time(&self, py: Python) -> PyResult<Py<PyAny>> {
+        utc_to_python_datetime(py, &self.end_time_internal)
+    }
+
+    fn __repr__(&self) -> String {
+        format!(
+            "AttitudeViolation(axis='{}', constraint='{}', start='{}', end='{}')",
+            self.axis,
+            self.constraint,
+            self.start_time_internal.to_rfc3339(),
+            self.end_time_internal.to_rfc3339()
+        )
+    }
+}
+
+/// Result of checking an attitude history against a set of constraints
+#[pyclass]
+pub struct AttitudeCheck {
+    /// Violation flags, true = violated, shape (n_axes, n_constraints, n_times)
+    mask: Array3<bool>,
+    axis_names: Vec<String>,
+    constraint_names: Vec<String>,
+    times: Vec<DateTime<Utc>>,
+    violations: Vec<AttitudeViolation>,
+}
+
+#[pymethods]
+impl AttitudeCheck {
+    /// True if no axis violates any constraint at any time
+    #[getter]
+    fn all_satisfied(&self) -> bool {
+        self.violations.is_empty()
+    }
+
+    /// Violation windows, ordered by axis, then constraint, then time
+    #[getter]
+    fn violations(&self) -> Vec<AttitudeViolation> {
+        self.violations.clone()
+    }
+
+    /// Violation flags as a boolean array of shape (n_axes, n_constraints, n_times)
+    #[getter]
+    fn mask(&self, py: Python) -> Py<PyAny> {
+        self.mask.clone().into_pyarray(py).into()
+    }
+
+    /// Axis names, in the order of the first mask axis
+    #[getter]
+    fn axis_names(&self) -> Vec<String> {
+        self.axis_names.clone()
+    }
+
+    /// Constraint names, in the order of the second mask axis
+    #[getter]
+    fn constraint_names(&self) -> Vec<String> {
+        self.constraint_names.clone()
+    }
+
+    /// Evaluation times
+    #[getter]
+    fn timestamp(&self, py: Python) -> PyResult<Py<PyAny>> {
+        timestamp_array(py, &self.times)
+    }
+
+    fn __len__(&self) -> usize {
+        self.violations.len()
+    }
+
+    fn __repr__(&self) -> String {
+        format!(
+            "AttitudeCheck(axes={}, constraints={}, violations={})",
+            self.axis_names.len(),
+            self.constraint_names.len(),
+            self.violations.len()
+        )
+    }
+}
+
+/// Sun incidence on body faces over an attitude history
+#[pyclass]
+pub struct FaceIncidence {
+    /// Angle between each face normal and the Sun direction in degrees,
+    /// shape (n_faces, n_times)
+    incidence_deg: Array2<f64>,
+    /// Face is turned toward the Sun and the spacecraft is not in eclipse,
+    /// shape (n_faces, n_times)
+    sunlit: Array2<bool>,
+    in_eclipse: Vec<bool>,
+    face_names: Vec<String>,
+    times: Vec<DateTime<Utc>>,
+}
+
+#[pymethods]
+impl FaceIncidence {
+    /// Sun incidence angle in degrees, shape (n_faces, n_times); 0 is face-on
+    /// and above 90 the face points away from the Sun
+    #[getter]
+    fn incidence_deg(&self, py: Python) -> Py<PyAny> {
+        self.incidence_deg.clone().into_pyarray(py).into()
+    }
+
+    /// Insolation flags, shape (n_faces, n_times): incidence below 90 degrees
+    /// and the spacecraft outside Earth's shadow
+    #[getter]
+    fn sunlit(&self, py: Python) -> Py<PyAny> {
+        self.sunlit.clone().into_pyarray(py).into()
+    }
+
+    /// Cosine of the incidence angle where sunlit, otherwise 0, shape
+    /// (n_faces, n_times); multiply by the solar constant for the flux
+    #[getter]
+    fn illumination(&self, py: Python) -> Py<PyAny> {
+        let mut illumination = self.incidence_deg.mapv(|a| a.to_radians().cos());
+        illumination.zip_mut_with(&self.sunlit, |v, &lit| {
+            if !lit {
+                *v = 0.0;
+            }
+        });
+        illumination.into_pyarray(py).into()
+    }
+
+    /// Whether the spacecraft is in Earth's shadow at each time
+    #[getter]
+    fn in_eclipse(&self) -> Vec<bool> {
+        self.in_eclipse.clone()
+    }
+
+    /// Face names, in the order of the first array axis
+    #[getter]
+    fn face_names(&self) -> Vec<String> {
+        self.face_names.clone()
+    }
+
+    /// Evaluation times
+    #[getter]
+    fn timestamp(&self, py: Python) -> PyResult<Py<PyAny>> {
+        timestamp_array(py, &self.times)
+    }
+
+    fn __len__(&self) -> usize {
+        self.face_names.len()
+    }
+
+    fn __repr__(&self) -> String {
+        format!(
+            "FaceIncidence(faces={}, times={})",
+            self.face_names.len(),
+            self.times.len()
+        )
+    }
+}
+
+/// A window in which the Sun or Moon is inside one tracker's exclusion cone
+/// during a slew
+#[pyclass]
+#[derive(Clone)]
+pub struct SlewIntrusion {
+    /// Name of the star tracker
+    #[pyo3(get)]
+    tracker: String,
+    /// Intruding body, "sun" or "moon"
+    #[pyo3(get)]
+    body: &'static str,
+    /// Smallest angle between the tracker axis and the body in the window, degrees
+    #[pyo3(get)]
+    min_angle_deg: f64,
+    start_time_internal: DateTime<Utc>,
+    end_time_internal: DateTime<Utc>,
+}
+
+#[pymethods]
+impl SlewIntrusion {
+    #[getter]
+    fn start_time(&self, py: Python) -> PyResult<Py<PyAny>> {
+        utc_to_python_datetime(py, &self.start_time_internal)
+    }
+
+    #[getter]
+    fn end_time(&self, py: Python) -> PyResult<Py<PyAny>> {
+        utc_to_python_datetime(py, &self.end_time_internal)
+    }
+
+    fn __repr__(&self) -> String {
+        format!(
+            "SlewIntrusion(tracker='{}', body='{}', start='{}', end='{}', min_angle_deg={:.2})",
+            self.tracker,
+            self.body,
+            self.start_time_internal.to_rfc3339(),
+            self.end_time_internal.to_rfc3339(),
+            self.min_angle_deg
+        )
+    }
+}
+
+/// Sun and Moon angles from each star tracker along a slew
+#[pyclass]
+pub struct SlewCheck {
+    /// Rotation angle of the slew, degrees
+    #[pyo3(get)]
+    slew_angle_deg: f64,
+    /// Slew duration at the given rate, seconds
+    #[pyo3(get)]
+    duration_seconds: f64,
+    /// Attitude at each slew sample, shape (n_samples, 4), scalar last
+    quaternions: Array2<f64>,
+    /// Tracker-to-Sun angle in degrees, shape (n_trackers, n_samples)
+    sun_angle_deg: Array2<f64>,
+    /// Tracker-to-Moon angle in degrees, shape (n_trackers, n_samples)
+    moon_angle_deg: Array2<f64>,
+    tracker_names: Vec<String>,
+    times: Vec<DateTime<Utc>>,
+    intrusions: Vec<SlewIntrusion>,
+}
+
+#[pymethods]
+impl SlewCheck {
+    /// True if neither body enters any tracker's exclusion cone
+    #[getter]
+    fn clear(&self) -> bool {
+        self.intrusions.is_empty()
+    }
+
+    /// Intrusion windows, ordered by tracker, then body, then time
+    #[getter]
+    fn intrusions(&self) -> Vec<SlewIntrusion> {
+        self.intrusions.clone()
+    }
+
+    /// Interpolated attitude at each slew sample, shape (n_samples, 4)
+    #[getter]
+    fn quaternions(&self, py: Python) -> Py<PyAny> {
+        self.quaternions.clone().into_pyarray(py).into()
+    }
+
+    /// Tracker-to-Sun angle in degrees, shape (n_trackers, n_samples)
+    #[getter]
+    fn sun_angle_deg(&self, py: Python) -> Py<PyAny> {
+        self.sun_angle_deg.clone().into_pyarray(py).into()
+    }
+
+    /// Tracker-to-Moon angle in degrees, shape (n_trackers, n_samples)
+    #[getter]
+    fn moon_angle_deg(&self, py: Python) -> Py<PyAny> {
+        self.moon_angle_deg.clone().into_pyarray(py).into()
+    }
+
+    /// Tracker names, in the order of the first angle axis
+    #[getter]
+    fn tracker_names(&self) -> Vec<String> {
+        self.tracker_names.clone()
+    }
+
+    /// Slew sample times
+    #[getter]
+    fn timestamp(&self, py: Python) -> PyResult<Py<PyAny>> {
+        timestamp_array(py, &self.times)
+    }
+
+    fn __len__(&self) -> usize {
+        self.intrusions.len()
+    }
+
+    fn __repr__(&self) -> String {
+        format!(
+            "SlewCheck(slew_angle_deg={:.2}, duration_seconds={:.1}, trackers={}, intrusions={})",
+            self.slew_angle_deg,
+            self.duration_seconds,
+            self.tracker_names.len(),
+            self.intrusions.len()
+        )
+    }
+}
+
+/// Normalize a scalar-last quaternion (x, y, z, w)
+fn normalize_quaternion(q: [f64; 4]) -> Option<[f64; 4]> {
+    let norm = q.iter().map(|c| c * c).sum::<f64>().sqrt();
+    if !norm.is_finite() || norm <= 0.0 {
+        return None;
+    }
+    Some([q[0] / norm, q[1] / norm, q[2] / norm, q[3] / norm])
+}
+
+/// Rotate a body-frame vector into ICRS with a unit scalar-last quaternion
+fn rotate(q: [f64; 4], v: [f64; 3]) -> [f64; 3] {
+    let (u, w) = ([q[0], q[1], q[2]], q[3]);
+    // t = 2 (u × v); v' = v + w t + u × t
+    let t = [
+        2.0 * (u[1] * v[2] - u[2] * v[1]),
+        2.0 * (u[2] * v[0] - u[0] * v[2]),
+        2.0 * (u[0] * v[1] - u[1] * v[0]),
+    ];
+    [
+        v[0] + w * t[0] + (u[1] * t[2] - u[2] * t[1]),
+        v[1] + w * t[1] + (u[2] * t[0] - u[0] * t[2]),
+        v[2] + w * t[2] + (u[0] * t[1] - u[1] * t[0]),
+    ]
+}
+
+/// ICRS unit vectors of one body axis over the attitude history, shape (n_times, 3)
+fn axis_directions(quaternions: &[[f64; 4]], axis: [f64; 3]) -> Array2<f64> {
+    let mut directions = Array2::<f64>::zeros((quaternions.len(), 3));
+    for (j, &q) in quaternions.iter().enumerate() {
+        let v = rotate(q, axis);
+        let norm = (v[0] * v[0] + v[1] * v[1] + v[2] * v[2]).sqrt();
+        for (k, c) in v.iter().enumerate() {
+            directions[[j, k]] = c / norm;
+        }
+    }
+    directions
+}
+
+/// Check the pointing of each body axis against each constraint
+///
+/// `quaternions` rotate body-frame vectors into ICRS, scalar last, one per
+/// ephemeris time.
+pub fn check_attitude(
+    ephemeris: &Bound<'_, PyAny>,
+    quaternions: &[[f64; 4]],
+    constraints: &[PyRef<PyConstraint>],
+    axes: &[(String, [f64; 3])],
+) -> PyResult<AttitudeCheck> {
+    let quaternions = normalized_quaternions(quaternions)?;
+    if axes.is_empty() {
+        return Err(pyo3::exceptions::PyValueError::new_err(
+            "axes must contain at least one axis",
+        ));
+    }
+    validate_body_vectors("axis", axes)?;
+
+    with_ephemeris(ephemeris, |ephem| {
+        let times = ephem.get_times()?;
+        if quaternions.len() != times.len() {
+            return Err(pyo3::exceptions::PyValueError::new_err(format!(
+                "expected one quaternion per ephemeris time ({}), got {}",
+                times.len(),
+                quaternions.len()
+            )));
+        }
+
+        let axis_names: Vec<String> = axes.iter().map(|(name, _)| name.clone()).collect();
+        let constraint_names: Vec<String> = constraints.iter().map(|c| c.name()).collect();
+        let mut mask = Array3::from_elem((axes.len(), constraints.len(), times.len()), false);
+        let mut violations = Vec::new();
+        for (a, (axis, vector)) in axes.iter().enumerate() {
+            let (ras, decs) = unit_vectors_to_radec_batch(&axis_directions(&quaternions, *vector));
+            for (c, constraint) in constraints.iter().enumerate() {
+                let flags = constraint.pointing_violations(ephem, &ras, &decs)?;
+                for (j, &violated) in flags.iter().enumerate() {
+                    mask[[a, c, j]] = violated;
+                }
+                violations.extend(
+                    runs(&flags)
+                        .into_iter()
+                        .map(|(start, end)| AttitudeViolation {
+                            axis: axis.clone(),
+                            constraint: constraint_names[c].clone(),
+                            start_time_internal: times[start],
+                            end_time_internal: times[end],
+                        }),
+                );
+            }
+        }
+
+        Ok(AttitudeCheck {
+            mask,
+            axis_names,
+            constraint_names,
+            times,
+            violations,
+        })
+    })
+}
+
+/// Normalize the attitude history, rejecting degenerate quaternions
+fn normalized_quaternions(quaternions: &[[f64; 4]]) -> PyResult<Vec<[f64; 4]>> {
+    quaternions
+        .iter()
+        .map(|&q| normalize_quaternion(q))
+        .collect::<Option<Vec<_>>>()
+        .ok_or_else(|| {
+            pyo3::exceptions::PyValueError::new_err(
+                "quaternions must be finite and have non-zero norm",
+            )
+        })
+}
+
+/// Reject body-frame vectors that are zero or not finite
+fn validate_body_vectors(kind: &str, vectors: &[(String, [f64; 3])]) -> PyResult<()> {
+    if let Some((name, _)) = vectors
+        .iter()
+        .find(|(_, v)| !v.iter().all(|c| c.is_finite()) || v.iter().all(|&c| c == 0.0))
+    {
+        return Err(pyo3::exceptions::PyValueError::new_err(format!(
+            "{} '{}' must be a finite, non-zero vector",
+            kind, name
+        )));
+    }
+    Ok(())
+}
+
+/// Angle in degrees between two non-zero vectors
+fn angle_deg(a: [f64; 3], b: [f64; 3]) -> f64 {
+    let dot = a[0] * b[0] + a[1] * b[1] + a[2] * b[2];
+    let norms = (a[0] * a[0] + a[1] * a[1] + a[2] * a[2]).sqrt()
+        * (b[0] * b[0] + b[1] * b[1] + b[2] * b[2]).sqrt();
+    (dot / norms).clamp(-1.0, 1.0).acos().to_degrees()
+}
+
+/// Sun incidence angle on each body face over the attitude history
+///
+/// `quaternions` rotate body-frame vectors into ICRS, scalar last, one per
+/// ephemeris time; `faces` are outward face normals in the body frame.
+pub fn face_sun_incidence(
+    ephemeris: &Bound<'_, PyAny>,
+    quaternions: &[[f64; 4]],
+    faces: &[(String, [f64; 3])],
+    umbra_only: bool,
+) -> PyResult<FaceIncidence> {
+    let quaternions = normalized_quaternions(quaternions)?;
+    if faces.is_empty() {
+        return Err(pyo3::exceptions::PyValueError::new_err(
+            "faces must contain at least one face",
+        ));
+    }
+    validate_body_vectors("face", faces)?;
+
+    with_ephemeris(ephemeris, |ephem| {
+        let times = ephem.get_times()?;
+        if quaternions.len() != times.len() {
+            return Err(pyo3::exceptions::PyValueError::new_err(format!(
+                "expected one quaternion per ephemeris time ({}), got {}",
+                times.len(),
+                quaternions.len()
+            )));
+        }
+        let sun = ephem.get_sun_positions()?;
+        let observer = ephem.get_gcrs_positions()?;
+
+        let in_eclipse: Vec<bool> = (0..times.len())
+            .map(|j| {
+                let obs = [observer[[j, 0]], observer[[j, 1]], observer[[j, 2]]];
+                let sun_pos = [sun[[j, 0]], sun[[j, 1]], sun[[j, 2]]];
+                in_earth_shadow(obs, sun_pos, umbra_only)
+            })
+            .collect();
+
+        let mut incidence_deg = Array2::<f64>::zeros((faces.len(), times.len()));
+        let mut sunlit = Array2::from_elem((faces.len(), times.len()), false);
+        for (j, &q) in quaternions.iter().enumerate() {
+            let to_sun = [
+                sun[[j, 0]] - observer[[j, 0]],
+                sun[[j, 1]] - observer[[j, 1]],
+                sun[[j, 2]] - observer[[j, 2]],
+            ];
+            for (f, (_, normal)) in faces.iter().enumerate() {
+                let angle = angle_deg(rotate(q, *normal), to_sun);
+                incidence_deg[[f, j]] = angle;
+                sunlit[[f, j]] = angle < 90.0 && !in_eclipse[j];
+            }
+        }
+
+        Ok(FaceIncidence {
+            incidence_deg,
+            sunlit,
+            in_eclipse,
+            face_names: faces.iter().map(|(name, _)| name.clone()).collect(),
+            times,
+        })
+    })
+}
+
+/// Rotation angle in radians between two unit quaternions
+fn rotation_angle(q0: [f64; 4], q1: [f64; 4]) -> f64 {
+    let dot: f64 = q0.iter().zip(&q1).map(|(a, b)| a * b).sum();
+    2.0 * dot.abs().clamp(0.0, 1.0).acos()
+}
+
+/// Eigen-axis interpolation between two unit quaternions, `fraction` in [0, 1]
+///
+/// Follows the shorter of the two rotations, about a fixed axis at a
+/// constant rate.
+fn slerp(q0: [f64; 4], q1: [f64; 4], fraction: f64) -> [f64; 4] {
+    let mut dot: f64 = q0.iter().zip(&q1).map(|(a, b)| a * b).sum();
+    let q1 = if dot < 0.0 {
+        dot = -dot;
+        q1.map(|c| -c)
+    } else {
+        q1
+    };
+    let theta = dot.clamp(-1.0, 1.0).acos();
+    let (w0, w1) = if theta < 1e-9 {
+        (1.0 - fraction, fraction)
+    } else {
+        (
+            ((1.0 - fraction) * theta).sin() / theta.sin(),
+            (fraction * theta).sin() / theta.sin(),
+        )
+    };
+    let q = std::array::from_fn(|k| w0 * q0[k] + w1 * q1[k]);
+    normalize_quaternion(q).unwrap_or(q0)
+}
+
+/// Observer-to-body vector at `time`, interpolated linearly between the
+/// bracketing ephemeris samples
+fn relative_position_at(
+    times: &[DateTime<Utc>],
+    observer: &Array2<f64>,
+    body: &Array2<f64>,
+    time: DateTime<Utc>,
+) -> [f64; 3] {
+    let upper = times.partition_point(|t| *t < time).min(times.len() - 1);
+    let lower = upper.saturating_sub(1);
+    let span = (times[upper] - times[lower]).num_milliseconds() as f64;
+    let fraction = if span > 0.0 {
+        (time - times[lower]).num_milliseconds() as f64 / span
+    } else {
+        0.0
+    };
+    std::array::from_fn(|k| {
+        let at = |j: usize| body[[j, k]] - observer[[j, k]];
+        at(lower) + fraction * (at(upper) - at(lower))
+    })
+}
+
+/// Angles from each tracker to one body along the slew, and the windows
+/// inside its exclusion cone tagged with the tracker index
+fn body_angles(
+    body: &'static str,
+    directions: &[[f64; 3]],
+    attitudes: &[[f64; 4]],
+    times: &[DateTime<Utc>],
+    trackers: &[(String, [f64; 3])],
+    exclusion_deg: f64,
+) -> (Array2<f64>, Vec<(usize, SlewIntrusion)>) {
+    let mut intrusions = Vec::new();
+    let mut angles = Array2::<f64>::zeros((trackers.len(), times.len()));
+    for (k, (name, axis)) in trackers.iter().enumerate() {
+        for (j, (&q, direction)) in attitudes.iter().zip(directions).enumerate() {
+            angles[[k, j]] = angle_deg(rotate(q, *axis), *direction);
+        }
+        let row = angles.row(k);
+        let inside: Vec<bool> = row.iter().map(|&a| a < exclusion_deg).collect();
+        intrusions.extend(runs(&inside).into_iter().map(|(start, end)| {
+            let min_angle_deg = row
+                .slice(ndarray::s![start..=end])
+                .fold(f64::INFINITY, |m, &a| m.min(a));
+            (
+                k,
+                SlewIntrusion {
+                    tracker: name.clone(),
+                    body,
+                    min_angle_deg,
+                    start_time_internal: times[start],
+                    end_time_internal: times[end],
+                },
+            )
+        }));
+    }
+    (angles, intrusions)
+}
+
+/// Check a slew between two attitudes for Sun and Moon intrusions into
+/// star-tracker exclusion cones
+///
+/// The slew starts at `start_time` and rotates from `start_quaternion` to
+/// `end_quaternion` about the eigen-axis at `slew_rate_deg_s`, sampled every
+/// `step_seconds` and at the end attitude. `trackers` are tracker boresights
+/// in the body frame. Sun and Moon directions are interpolated linearly
+/// between ephemeris samples, so the slew must lie within the ephemeris span.
+#[allow(clippy::too_many_arguments)]
+pub fn check_slew(
+    ephemeris: &Bound<'_, PyAny>,
+    start_time: DateTime<Utc>,
+    start_quaternion: [f64; 4],
+    end_quaternion: [f64; 4],
+    slew_rate_deg_s: f64,
+    trackers: &[(String, [f64; 3])],
+    sun_angle: f64,
+    moon_angle: f64,
+    step_seconds: f64,
+) -> PyResult<SlewCheck> {
+    let endpoints = normalized_quaternions(&[start_quaternion, end_quaternion])?;
+    let (q0, q1) = (endpoints[0], endpoints[1]);
+    if !(slew_rate_deg_s.is_finite() && slew_rate_deg_s > 0.0) {
+        return Err(pyo3::exceptions::PyValueError::new_err(
+            "slew_rate must be positive",
+        ));
+    }
+    if !(step_seconds.is_finite() && step_seconds > 0.0) {
+        return Err(pyo3::exceptions::PyValueError::new_err(
+            "step_seconds must be positive",
+        ));
+    }
+    if trackers.is_empty() {
+        return Err(pyo3::exceptions::PyValueError::new_err(
+            "trackers must contain at least one tracker",
+        ));
+    }
+    validate_body_vectors("tracker", trackers)?;
+
+    let slew_angle_deg = rotation_angle(q0, q1).to_degrees();
+    let duration_seconds = slew_angle_deg / slew_rate_deg_s;
+    // A sample within a microsecond of the end is the end sample
+    let mut offsets: Vec<f64> = (0..)
+        .map(|k| k as f64 * step_seconds)
+        .take_while(|&t| duration_seconds - t > 1e-6)
+        .collect();
+    offsets.push(duration_seconds);
+    let times: Vec<DateTime<Utc>> = offsets
+        .iter()
+        .map(|&t| start_time + chrono::Duration::microseconds((t * 1e6).round() as i64))
+        .collect();
+    let attitudes: Vec<[f64; 4]> = offsets
+        .iter()
+        .map(|&t| {
+            let fraction = if duration_seconds > 0.0 {
+                t / duration_seconds
+            } else {
+                1.0
+            };
+            slerp(q0, q1, fraction)
+        })
+        .collect();
+
+    with_ephemeris(ephemeris, |ephem| {
+        let ephem_times = ephem.get_times()?;
+        let (first, last) = match (ephem_times.first(), ephem_times.last()) {
+            (Some(first), Some(last)) => (*first, *last),
+            _ => {
+                return Err(pyo3::exceptions::PyValueError::new_err(
+                    "ephemeris has no times",
+                ))
+            }
+        };
+        if times[0] < first || times[times.len() - 1] > last {
+            return Err(pyo3::exceptions::PyValueError::new_err(
+                "slew must lie within the ephemeris time span",
+            ));
+        }
+
+        let observer = ephem.get_gcrs_positions()?;
+        let directions = |positions: &Array2<f64>| -> Vec<[f64; 3]> {
+            times
+                .iter()
+                .map(|&t| relative_position_at(&ephem_times, &observer, positions, t))
+                .collect()
+        };
+        let sun = directions(&ephem.get_sun_positions()?);
+        let moon = directions(&ephem.get_moon_positions()?);
+
+        let (sun_angle_deg, mut intrusions) =
+            body_angles("sun", &sun, &attitudes, &times, trackers, sun_angle);
+        let (moon_angle_deg, moon_intrusions) =
+            body_angles("moon", &moon, &attitudes, &times, trackers, moon_angle);
+        intrusions.extend(moon_intrusions);
+        // Stable sort keeps the Sun before the Moon for each tracker
+        intrusions.sort_by_key(|(k, _)| *k);
+
+        let mut quaternions = Array2::<f64>::zeros((attitudes.len(), 4));
+        for (j, q) in attitudes.iter().enumerate() {
+            for (k, c) in q.iter().enumerate() {
+                quaternions[[j, k]] = *c;
+            }
+        }
+
+        Ok(SlewCheck {
+            slew_angle_deg,
+            duration_seconds,
+            quaternions,
+            sun_angle_deg,
+            moon_angle_deg,
+            tracker_names: trackers.iter().map(|(name, _)| name.clone()).collect(),
+            times,
+            intrusions: intrusions.into_iter().map(|(_, i)| i).collect(),
+        })
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn assert_close(a: [f64; 3], b: [f64; 3]) {
+        assert!(
+            a.iter().zip(&b).all(|(x, y)| (x - y).abs() < 1e-12),
+            "{:?} != {:?}",
+            a,
+            b
+        );
+    }
+
+    #[test]
+    fn test_rotate_identity() {
+        assert_close(
+            rotate([0.0, 0.0, 0.0, 1.0], [0.3, 0.4, 0.5]),
+            [0.3, 0.4, 0.5],
+        );
+    }
+
+    #[test]
+    fn test_rotate_about_z() {
+        // 90° about +Z takes +X to +Y
+        let half = std::f64::consts::FRAC_PI_4;
+        let q = [0.0, 0.0, half.sin(), half.cos()];
+        assert_close(rotate(q, [1.0, 0.0, 0.0]), [0.0, 1.0, 0.0]);
+        assert_close(rotate(q, [0.0, 0.0, 1.0]), [0.0, 0.0, 1.0]);
+    }
+
+    #[test]
+    fn test_angle_deg() {
+        assert!((angle_deg([1.0, 0.0, 0.0], [2.0, 0.0, 0.0])).abs() < 1e-9);
+        assert!((angle_deg([1.0, 0.0, 0.0], [0.0, 3.0, 0.0]) - 90.0).abs() < 1e-9);
+        assert!((angle_deg([1.0, 0.0, 0.0], [-1.0, 0.0, 0.0]) - 180.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_normalize_quaternion() {
+        assert_eq!(
+            normalize_quaternion([0.0, 0.0, 0.0, 2.0]),
+            Some([0.0, 0.0, 0.0, 1.0])
+        );
+        assert_eq!(normalize_quaternion([0.0; 4]), None);
+        assert_eq!(normalize_quaternion([f64::NAN, 0.0, 0.0, 1.0]), None);
+    }
+
+    #[test]
+    fn test_slerp_eigen_axis() {
+        // 90° about +Z; the midpoint is 45° about the same axis
+        let half = std::f64::consts::FRAC_PI_4;
+        let q0 = [0.0, 0.0, 0.0, 1.0];
+        let q1 = [0.0, 0.0, half.sin(), half.cos()];
+        assert!((rotation_angle(q0, q1).to_degrees() - 90.0).abs() < 1e-9);
+        let mid = slerp(q0, q1, 0.5);
+        assert!((rotation_angle(q0, mid).to_degrees() - 45.0).abs() < 1e-9);
+        let v = rotate(mid, [1.0, 0.0, 0.0]);
+        assert_close(v, [half.cos(), half.sin(), 0.0]);
+        assert_close(rotate(slerp(q0, q1, 1.0), [1.0, 0.0, 0.0]), [0.0, 1.0, 0.0]);
+    }
+
+    #[test]
+    fn test_slerp_takes_short_way() {
+        // -q is the same attitude: the slew must not go the long way round
+        let half = std::f64::consts::FRAC_PI_4;
+        let q1 = [0.0, 0.0, -half.sin(), -half.cos()];
+        let mid = slerp([0.0, 0.0, 0.0, 1.0], q1, 0.5);
+        assert!((rotation_angle([0.0, 0.0, 0.0, 1.0], mid).to_degrees() - 45.0).abs() < 1e-9);
+    }
+}
@@ -1,14 +1,21 @@
-/// Generic solar system body proximity constraint implementation
 use super::core::{track_violations, ConstraintConfig, ConstraintEvaluator, ConstraintResult};
+use super::threshold_schedule::ThresholdPeriod;
+/// Generic solar system body proximity constraint implementation
+use crate::constraints::error::{ConstraintError, OrMissing};
 use crate::constraints::fov_polygon;
+use crate::utils::body_properties::body_radii;
+use crate::utils::celestial::{calculate_body_by_id_or_name, BodySource};
+use crate::utils::config::EARTH_NAIF_ID;
 use chrono::{DateTime, Utc};
-use ndarray::Array2;
+use ndarray::{Array2, Axis};
 use serde::{Deserialize, Serialize};
+use std::sync::{Arc, Mutex};
 
 /// Configuration for generic solar system body proximity constraint
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct BodyProximityConfig {
-    /// Body identifier (NAIF ID or name, e.g., "Jupiter", "499")
+    /// Body identifier (NAIF ID or name, e.g., "Jupiter", "499", or a pseudo-body
+    /// such as "EMB", "SEL2" or "EML1")
     pub body: String,
     /// Minimum allowed angular separation in degrees (circle mode; mutually exclusive with fov_polygon)
     #[serde(default)]
@@ -24,34 +31,97 @@ pub struct BodyProximityConfig {
     /// None means sweep all rolls (polygon mode only).
     #[serde(default)]
     pub roll_deg: Option<f64>,
+    /// Apply the angle limits to the separation from the body's limb instead of its
+    /// centre (circle mode only; the radius comes from the body radii registry)
+    #[serde(default)]
+    pub limb_relative: bool,
+    /// Periods overriding the angle limits (circle mode only)
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub schedule: Vec<ThresholdPeriod>,
+    /// Body position source: "spice" (default), "horizons" or "auto"
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub source: Option<String>,
+}
+
+impl BodyProximityConfig {
+    /// Check the source, that a limb-relative or scheduled constraint has a
+    /// circle and that a limb-relative one has a known body radius
+    pub fn validate(&self) -> Result<(), String> {
+        if let Some(source) = &self.source {
+            BodySource::parse(source)?;
+        }
+        if !self.schedule.is_empty() && self.fov_polygon.is_some() {
+            return Err("schedule has no effect with fov_polygon".to_string());
+        }
+        if !self.limb_relative {
+            return Ok(());
+        }
+        if self.fov_polygon.is_some() {
+            return Err("limb_relative has no effect with fov_polygon".to_string());
+        }
+        if body_radii(&self.body).is_none() {
+            return Err(format!(
+                "limb_relative needs the radius of '{}'; set it with set_body_radii()",
+                self.body
+            ));
+        }
+        Ok(())
+    }
 }
 
 impl ConstraintConfig for BodyProximityConfig {
     fn to_evaluator(&self) -> Box<dyn ConstraintEvaluator> {
+        // The source is validated when the constraint is built
+        let source = self
+            .source
+            .as_deref()
+            .and_then(|s| BodySource::parse(s).ok())
+            .unwrap_or(BodySource::Spice);
         Box::new(BodyProximityEvaluator {
             body: self.body.clone(),
+            source,
+            positions_cache: Mutex::new(None),
             // Kept for macro-generated evaluate_common (only used in circle mode)
             min_angle_deg: self.min_angle.unwrap_or(0.0),
             max_angle_deg: self.max_angle,
+            schedule: self.schedule.clone(),
             fov_polygon: self.fov_polygon.clone(),
             roll_rad: self.roll_deg.map(|r| r.to_radians()),
+            limb_radius_km: if self.limb_relative {
+                body_radii(&self.body).map(|radii| radii.equatorial)
+            } else {
+                None
+            },
         })
     }
 }
 
-/// Evaluator for generic body proximity - requires body positions computed externally
+/// Evaluator for generic body proximity, resolving the body on the ephemeris times
+///
+/// The body is looked up from `source` on the full time grid of each ephemeris
+/// and kept for that ephemeris, so repeated evaluations (single times, other
+/// targets, `in_constraint_batch`) reuse one SPICE or Horizons lookup.
 pub struct BodyProximityEvaluator {
     pub body: String,
+    /// Where the body positions come from
+    pub source: BodySource,
+    /// Geocentric body positions on the full grid of the last ephemeris, keyed
+    /// on its fingerprint
+    positions_cache: Mutex<Option<(String, Arc<Array2<f64>>)>>,
     /// Used by the macro-generated evaluate_common (circle mode only)
     pub min_angle_deg: f64,
     pub max_angle_deg: Option<f64>,
+    /// Periods overriding the limits (circle mode only)
+    pub schedule: Vec<ThresholdPeriod>,
     /// When set, the body must not fall inside this polygon FoV
     pub fov_polygon: Option<Vec<[f64; 2]>>,
     /// Fixed roll in radians; None means sweep all rolls (polygon mode only)
     pub roll_rad: Option<f64>,
+    /// Body radius in km when the limits apply to its limb (circle mode only)
+    pub limb_radius_km: Option<f64>,
 }
 
-impl_proximity_evaluator!(BodyProximityEvaluator, "Body", "body", sun_positions);
+impl_proximity_evaluator!(BodyProximityEvaluator, "Body", "body", body_positions);
 
 impl BodyProximityEvaluator {
     #[allow(dead_code)]
@@ -89,19 +159,70 @@ impl BodyProximityEvaluator {
                 ),
             }
         } else {
+            let limb = if self.limb_radius_km.is_some() {
+                ", limb"
+            } else {
+                ""
+            };
             match self.max_angle_deg {
                 Some(max) => format!(
-                    "BodyProximity(body='{}', min={:.1}°, max={:.1}°)",
-                    self.body, self.min_angle_deg, max
+                    "BodyProximity(body='{}', min={:.1}°, max={:.1}°{})",
+                    self.body, self.min_angle_deg, max, limb
                 ),
                 None => format!(
-                    "BodyProximity(body='{}', min={:.1}°)",
-                    self.body, self.min_angle_deg
+                    "BodyProximity(body='{}', min={:.1}°{})",
+                    self.body, self.min_angle_deg, limb
                 ),
             }
         }
     }
 
+    /// Selected times with the body and observer GCRS positions on them
+    #[allow(clippy::type_complexity)]
+    fn body_and_observer(
+        &self,
+        ephemeris: &dyn crate::ephemeris::ephemeris_common::EphemerisBase,
+        time_indices: Option<&[usize]>,
+    ) -> Result<(Vec<DateTime<Utc>>, Array2<f64>, Array2<f64>), ConstraintError> {
+        let times = ephemeris.get_times().or_missing("times")?;
+        let observer = ephemeris
+            .get_gcrs_positions()
+            .or_missing("GCRS positions")?;
+        let body = self.body_positions(ephemeris, &times)?;
+        Ok(match time_indices {
+            Some(indices) => (
+                indices.iter().map(|&i| times[i]).collect(),
+                body.select(Axis(0), indices),
+                observer.select(Axis(0), indices),
+            ),
+            None => (times, body.as_ref().clone(), observer),
+        })
+    }
+
+    /// Geocentric body positions on all of `times`, the grid of `ephemeris`
+    fn body_positions(
+        &self,
+        ephemeris: &dyn crate::ephemeris::ephemeris_common::EphemerisBase,
+        times: &[DateTime<Utc>],
+    ) -> Result<Arc<Array2<f64>>, ConstraintError> {
+        let key = ephemeris.fingerprint();
+        if let Ok(cache) = self.positions_cache.lock() {
+            if let Some((cached_key, positions)) = cache.as_ref() {
+                if *cached_key == key {
+                    return Ok(positions.clone());
+                }
+            }
+        }
+        let (body, _) =
+            calculate_body_by_id_or_name(times, &self.body, EARTH_NAIF_ID, None, self.source)
+                .map_err(ConstraintError::InvalidInput)?;
+        let body = Arc::new(body);
+        if let Ok(mut cache) = self.positions_cache.lock() {
+            *cache = Some((key, body.clone()));
+        }
+        Ok(body)
+    }
+
     /// Compute the body's RA/Dec (radians) from its GCRS position relative to the observer.
     fn body_radec_at(
         body_positions: &Array2<f64>,
@@ -131,9 +252,9 @@ impl ConstraintEvaluator for BodyProximityEvaluator {
         target_ra: f64,
         target_dec: f64,
         time_indices: Option<&[usize]>,
-    ) -> pyo3::PyResult<ConstraintResult> {
+    ) -> Result<ConstraintResult, ConstraintError> {
         let (times_slice, body_positions_slice, observer_positions_slice) =
-            extract_standard_ephemeris_data!(ephemeris, time_indices);
+            self.body_and_observer(ephemeris, time_indices)?;
 
         if let Some(ref vertices) = self.fov_polygon {
             let target_ra_rad = target_ra.to_radians();
@@ -189,26 +310,14 @@ impl ConstraintEvaluator for BodyProximityEvaluator {
         target_ras: &[f64],
         target_decs: &[f64],
         time_indices: Option<&[usize]>,
-    ) -> pyo3::PyResult<Array2<bool>> {
+    ) -> Result<Array2<bool>, ConstraintError> {
         use crate::utils::vector_math::radec_to_unit_vectors_batch;
 
-        let times = ephemeris.get_times()?;
-        let (body_positions_slice, observer_positions_slice, n_times) =
-            if let Some(indices) = time_indices {
-                let body_filtered = ephemeris
-                    .get_sun_positions()?
-                    .select(ndarray::Axis(0), indices);
-                let obs_filtered = ephemeris
-                    .get_gcrs_positions()?
-                    .select(ndarray::Axis(0), indices);
-                (body_filtered, obs_filtered, indices.len())
-            } else {
-                let body_positions = ephemeris.get_sun_positions()?;
-                let observer_positions = ephemeris.get_gcrs_positions()?;
-                (body_positions, observer_positions, times.len())
-            };
+        let (times, body_positions_slice, observer_positions_slice) =
+            self.body_and_observer(ephemeris, time_indices)?;
+        let n_times = times.len();
         if target_ras.len() != target_decs.len() {
-            return Err(pyo3::exceptions::PyValueError::new_err(
+            return Err(ConstraintError::invalid_input(
                 "target_ras and target_decs must have the same length",
             ));
         }
@@ -241,10 +350,8 @@ impl ConstraintEvaluator for BodyProximityEvaluator {
 
         // Circle mode
         let target_vectors = radec_to_unit_vectors_batch(target_ras, target_decs);
-        let threshold = self.min_angle_deg.to_radians().cos();
-        let max_threshold = self.max_angle_deg.map(|max| max.to_radians().cos());
 
-        for (i, target_row) in target_vectors.axis_iter(ndarray::Axis(0)).enumerate() {
+        for (i, target_row) in target_vectors.axis_iter(Axis(0)).enumerate() {
             let target_unit = [target_row[0], target_row[1], target_row[2]];
 
             for j in 0..n_times {
@@ -275,6 +382,7 @@ impl ConstraintEvaluator for BodyProximityEvaluator {
                     body_rel[1] / body_dist,
                     body_rel[2] / body_dist,
                 ];
+                let (threshold, max_threshold) = self.cos_thresholds(&times[j], body_dist);
                 let cos_angle = target_unit[0] * body_unit[0]
                     + target_unit[1] * body_unit[1]
                     + target_unit[2] * body_unit[2];
@@ -295,25 +403,13 @@ impl ConstraintEvaluator for BodyProximityEvaluator {
         ephemeris: &dyn crate::ephemeris::ephemeris_common::EphemerisBase,
         target_unit_vectors: &Array2<f64>,
         time_indices: Option<&[usize]>,
-    ) -> pyo3::PyResult<Option<Array2<bool>>> {
-        let times = ephemeris.get_times()?;
-        let (body_positions_slice, observer_positions_slice, n_times) =
-            if let Some(indices) = time_indices {
-                let body_filtered = ephemeris
-                    .get_sun_positions()?
-                    .select(ndarray::Axis(0), indices);
-                let obs_filtered = ephemeris
-                    .get_gcrs_positions()?
-                    .select(ndarray::Axis(0), indices);
-                (body_filtered, obs_filtered, indices.len())
-            } else {
-                let body_positions = ephemeris.get_sun_positions()?;
-                let observer_positions = ephemeris.get_gcrs_positions()?;
-                (body_positions, observer_positions, times.len())
-            };
+    ) -> Result<Option<Array2<bool>>, ConstraintError> {
+        let (times, body_positions_slice, observer_positions_slice) =
+            self.body_and_observer(ephemeris, time_indices)?;
+        let n_times = times.len();
 
         if target_unit_vectors.ncols() != 3 {
-            return Err(pyo3::exceptions::PyValueError::new_err(
+            return Err(ConstraintError::invalid_input(
                 "target_unit_vectors must have shape (N, 3)",
             ));
         }
@@ -347,8 +443,6 @@ impl ConstraintEvaluator for BodyProximityEvaluator {
         }
 
         // Circle mode
-        let threshold = self.min_angle_deg.to_radians().cos();
-        let max_threshold = self.max_angle_deg.map(|max| max.to_radians().cos());
 
         for i in 0..n_targets {
             let target_unit = [
@@ -384,6 +478,7 @@ impl ConstraintEvaluator for BodyProximityEvaluator {
                     body_rel[1] / body_dist,
                     body_rel[2] / body_dist,
                 ];
+                let (threshold, max_threshold) = self.cos_thresholds(&times[j], body_dist);
                 let cos_angle = target_unit[0] * body_unit[0]
                     + target_unit[1] * body_unit[1]
                     + target_unit[2] * body_unit[2];
@@ -399,6 +494,26 @@ impl ConstraintEvaluator for BodyProximityEvaluator {
         Ok(Some(result))
     }
 
+    /// Separation from the body in degrees; none with a field-of-view polygon
+    fn metric(
+        &self,
+        ephemeris: &dyn crate::ephemeris::ephemeris_common::EphemerisBase,
+        target_ra: f64,
+        target_dec: f64,
+        time_indices: Option<&[usize]>,
+    ) -> Result<Option<Vec<f64>>, ConstraintError> {
+        if self.fov_polygon.is_some() {
+            return Ok(None);
+        }
+        let (_, body_positions, observer_positions) =
+            self.body_and_observer(ephemeris, time_indices)?;
+        Ok(Some(self.separation_deg(
+            (target_ra, target_dec),
+            &body_positions,
+            &observer_positions,
+        )))
+    }
+
     fn name(&self) -> String {
         self.format_name()
     }
@@ -1,12 +1,12 @@
+use super::core::{ConstraintConfig, ConstraintEvaluator, ConstraintResult};
 /// Bright star avoidance constraint
 ///
 /// Violated when any catalog star falls within the telescope field of view.
 /// FoV is defined either as a circle (radius around boresight) or a polygon
 /// in instrument frame coordinates that rotates with spacecraft roll.
-use super::core::{ConstraintConfig, ConstraintEvaluator, ConstraintResult};
+use crate::constraints::error::{ConstraintError, OrMissing};
 use chrono::{DateTime, Utc};
 use ndarray::Array2;
-use pyo3::PyResult;
 use rayon::prelude::*;
 use serde::{Deserialize, Serialize};
 use std::f64::consts::PI;
@@ -317,8 +317,8 @@ impl ConstraintEvaluator for BrightStarEvaluator {
         target_ra: f64,
         target_dec: f64,
         time_indices: Option<&[usize]>,
-    ) -> PyResult<ConstraintResult> {
-        let all_times = ephemeris.get_times()?;
+    ) -> Result<ConstraintResult, ConstraintError> {
+        let all_times = ephemeris.get_times().or_missing("times")?;
         let times: Vec<DateTime<Utc>> = match time_indices {
             Some(idx) => idx.iter().map(|&i| all_times[i]).collect(),
             None => all_times.to_vec(),
@@ -347,8 +347,8 @@ impl ConstraintEvaluator for BrightStarEvaluator {
         target_ras: &[f64],
         target_decs: &[f64],
         time_indices: Option<&[usize]>,
-    ) -> PyResult<Array2<bool>> {
-        let all_times = ephemeris.get_times()?;
+    ) -> Result<Array2<bool>, ConstraintError> {
+        let all_times = ephemeris.get_times().or_missing("times")?;
         let n_times = match time_indices {
             Some(idx) => idx.len(),
             None => all_times.len(),
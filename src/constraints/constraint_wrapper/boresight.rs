@@ -1,7 +1,7 @@
 use crate::constraints::core::{track_violations, ConstraintEvaluator, ConstraintResult};
+use crate::constraints::error::{ConstraintError, OrMissing};
 use crate::utils::vector_math::unit_vectors_to_radec_batch;
 use ndarray::Array2;
-use pyo3::PyResult;
 
 /// Threshold below which a vector norm or angle (in degrees) is treated as zero.
 const NEAR_ZERO: f64 = 1.0e-12;
@@ -103,7 +103,7 @@ impl BoresightOffsetEvaluator {
         target_unit: &[f64; 3],
         sun_rel: &[f64; 3],
         params: RotationParams,
-    ) -> PyResult<[f64; 3]> {
+    ) -> Result<[f64; 3], ConstraintError> {
         let x_axis = *target_unit;
 
         // Roll=0 frame basis:
@@ -132,13 +132,13 @@ impl BoresightOffsetEvaluator {
         }
 
         let z_axis = Self::normalize_or_none(&z_axis).ok_or_else(|| {
-            pyo3::exceptions::PyValueError::new_err(
+            ConstraintError::invalid_input(
                 "Unable to construct boresight frame for roll calculation",
             )
         })?;
         let mut y_axis = Self::cross(&z_axis, &x_axis);
         y_axis = Self::normalize_or_none(&y_axis).ok_or_else(|| {
-            pyo3::exceptions::PyValueError::new_err(
+            ConstraintError::invalid_input(
                 "Unable to construct boresight +Y axis for roll calculation",
             )
         })?;
@@ -173,7 +173,7 @@ impl ConstraintEvaluator for BoresightOffsetEvaluator {
         target_ra: f64,
         target_dec: f64,
         time_indices: Option<&[usize]>,
-    ) -> PyResult<ConstraintResult> {
+    ) -> Result<ConstraintResult, ConstraintError> {
         let params = self.rotation_params();
 
         if matches!(self.roll_reference, RollReference::North) {
@@ -186,15 +186,10 @@ impl ConstraintEvaluator for BoresightOffsetEvaluator {
                 self.constraint
                     .evaluate(ephemeris, rotated_ra, rotated_dec, time_indices)?;
 
-            return Ok(ConstraintResult::new(
-                inner.violations,
-                inner.all_satisfied,
-                self.name(),
-                inner.times,
-            ));
+            return Ok(inner.renamed(self.name()));
         }
 
-        let all_times = ephemeris.get_times()?;
+        let all_times = ephemeris.get_times().or_missing("times")?;
         let indices: Vec<usize> = if let Some(subset) = time_indices {
             subset.to_vec()
         } else {
@@ -203,8 +198,10 @@ impl ConstraintEvaluator for BoresightOffsetEvaluator {
         let times_filtered: Vec<_> = indices.iter().map(|&idx| all_times[idx]).collect();
 
         let target_unit = crate::utils::vector_math::radec_to_unit_vector(target_ra, target_dec);
-        let sun_positions = ephemeris.get_sun_positions()?;
-        let observer_positions = ephemeris.get_gcrs_positions()?;
+        let sun_positions = ephemeris.get_sun_positions().or_missing("Sun positions")?;
+        let observer_positions = ephemeris
+            .get_gcrs_positions()
+            .or_missing("GCRS positions")?;
 
         // Preserve wrapped-constraint metadata by evaluating one timestamp at a time
         // after boresight rotation and carrying forward the inner severity/description.
@@ -268,11 +265,11 @@ impl ConstraintEvaluator for BoresightOffsetEvaluator {
         target_ras: &[f64],
         target_decs: &[f64],
         time_indices: Option<&[usize]>,
-    ) -> PyResult<Array2<bool>> {
+    ) -> Result<Array2<bool>, ConstraintError> {
         let params = self.rotation_params();
 
         if target_ras.len() != target_decs.len() {
-            return Err(pyo3::exceptions::PyValueError::new_err(
+            return Err(ConstraintError::invalid_input(
                 "target_ras and target_decs must have the same length",
             ));
         }
@@ -315,15 +312,17 @@ impl ConstraintEvaluator for BoresightOffsetEvaluator {
             );
         }
 
-        let all_times = ephemeris.get_times()?;
+        let all_times = ephemeris.get_times().or_missing("times")?;
         let indices: Vec<usize> = if let Some(subset) = time_indices {
             subset.to_vec()
         } else {
             (0..all_times.len()).collect()
         };
 
-        let sun_positions = ephemeris.get_sun_positions()?;
-        let observer_positions = ephemeris.get_gcrs_positions()?;
+        let sun_positions = ephemeris.get_sun_positions().or_missing("Sun positions")?;
+        let observer_positions = ephemeris
+            .get_gcrs_positions()
+            .or_missing("GCRS positions")?;
 
         let n_targets = target_ras.len();
         let n_times = indices.len();
@@ -380,11 +379,11 @@ impl ConstraintEvaluator for BoresightOffsetEvaluator {
         ephemeris: &dyn crate::ephemeris::ephemeris_common::EphemerisBase,
         target_ras: &[f64],
         target_decs: &[f64],
-    ) -> PyResult<Vec<bool>> {
+    ) -> Result<Vec<bool>, ConstraintError> {
         let params = self.rotation_params();
 
         if target_ras.len() != target_decs.len() {
-            return Err(pyo3::exceptions::PyValueError::new_err(
+            return Err(ConstraintError::invalid_input(
                 "target_ras and target_decs must have the same length",
             ));
         }
@@ -417,11 +416,13 @@ impl ConstraintEvaluator for BoresightOffsetEvaluator {
             );
         }
 
-        let sun_positions = ephemeris.get_sun_positions()?;
-        let observer_positions = ephemeris.get_gcrs_positions()?;
+        let sun_positions = ephemeris.get_sun_positions().or_missing("Sun positions")?;
+        let observer_positions = ephemeris
+            .get_gcrs_positions()
+            .or_missing("GCRS positions")?;
 
         if sun_positions.nrows() < n || observer_positions.nrows() < n {
-            return Err(pyo3::exceptions::PyValueError::new_err(
+            return Err(ConstraintError::invalid_input(
                 "Ephemeris does not have enough samples for diagonal boresight evaluation",
             ));
         }
@@ -452,11 +453,11 @@ impl ConstraintEvaluator for BoresightOffsetEvaluator {
         ephemeris: &dyn crate::ephemeris::ephemeris_common::EphemerisBase,
         target_unit_vectors: &Array2<f64>,
         time_indices: Option<&[usize]>,
-    ) -> PyResult<Option<Array2<bool>>> {
+    ) -> Result<Option<Array2<bool>>, ConstraintError> {
         let params = self.rotation_params();
 
         if target_unit_vectors.ncols() != 3 {
-            return Err(pyo3::exceptions::PyValueError::new_err(
+            return Err(ConstraintError::invalid_input(
                 "target_unit_vectors must have shape (N, 3)",
             ));
         }
@@ -496,15 +497,17 @@ impl ConstraintEvaluator for BoresightOffsetEvaluator {
                 .map(Some);
         }
 
-        let all_times = ephemeris.get_times()?;
+        let all_times = ephemeris.get_times().or_missing("times")?;
         let indices: Vec<usize> = if let Some(subset) = time_indices {
             subset.to_vec()
         } else {
             (0..all_times.len()).collect()
         };
 
-        let sun_positions = ephemeris.get_sun_positions()?;
-        let observer_positions = ephemeris.get_gcrs_positions()?;
+        let sun_positions = ephemeris.get_sun_positions().or_missing("Sun positions")?;
+        let observer_positions = ephemeris
+            .get_gcrs_positions()
+            .or_missing("GCRS positions")?;
 
         if indices.len() == 1 {
             let time_idx = indices[0];
@@ -605,7 +608,7 @@ impl ConstraintEvaluator for BoresightOffsetEvaluator {
         target_unit_vectors: &Array2<f64>,
         time_index: usize,
         n_roll_samples: usize,
-    ) -> pyo3::PyResult<Vec<bool>> {
+    ) -> Result<Vec<bool>, ConstraintError> {
         if !self.is_roll_dependent() {
             // Fixed roll or no offset – single evaluation suffices.
             return self.field_of_regard_violated_at_roll(
@@ -622,8 +625,10 @@ impl ConstraintEvaluator for BoresightOffsetEvaluator {
         // Compute sun_rel once outside the loop.
         let sun_rel: [f64; 3] = match self.roll_reference {
             RollReference::Sun => {
-                let sun_positions = ephemeris.get_sun_positions()?;
-                let observer_positions = ephemeris.get_gcrs_positions()?;
+                let sun_positions = ephemeris.get_sun_positions().or_missing("Sun positions")?;
+                let observer_positions = ephemeris
+                    .get_gcrs_positions()
+                    .or_missing("GCRS positions")?;
                 [
                     sun_positions[[time_index, 0]] - observer_positions[[time_index, 0]],
                     sun_positions[[time_index, 1]] - observer_positions[[time_index, 1]],
@@ -685,7 +690,7 @@ impl ConstraintEvaluator for BoresightOffsetEvaluator {
         target_unit_vectors: &Array2<f64>,
         time_index: usize,
         roll_deg: f64,
-    ) -> pyo3::PyResult<Vec<bool>> {
+    ) -> Result<Vec<bool>, ConstraintError> {
         let n_targets = target_unit_vectors.nrows();
 
         // When roll is fixed (Some) or there is no pitch/yaw offset, roll either does
@@ -709,8 +714,10 @@ impl ConstraintEvaluator for BoresightOffsetEvaluator {
         // Free roll: evaluate at the specific roll_deg provided by the sweep.
         let sun_rel: [f64; 3] = match self.roll_reference {
             RollReference::Sun => {
-                let sun_positions = ephemeris.get_sun_positions()?;
-                let observer_positions = ephemeris.get_gcrs_positions()?;
+                let sun_positions = ephemeris.get_sun_positions().or_missing("Sun positions")?;
+                let observer_positions = ephemeris
+                    .get_gcrs_positions()
+                    .or_missing("GCRS positions")?;
                 [
                     sun_positions[[time_index, 0]] - observer_positions[[time_index, 0]],
                     sun_positions[[time_index, 1]] - observer_positions[[time_index, 1]],
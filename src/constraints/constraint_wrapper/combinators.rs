@@ -1,14 +1,14 @@
 // Logical combinator evaluators
 use crate::constraints::core::{
-    track_violations, ConstraintEvaluator, ConstraintResult, ConstraintViolation,
+    profiled, track_violations, ConstraintEvaluator, ConstraintResult, ConstraintViolation,
 };
+use crate::constraints::error::{ConstraintError, OrMissing};
 use crate::utils::vector_math::unit_vectors_to_radec_batch;
 use ndarray::Array2;
-use pyo3::PyResult;
 
-fn validate_unit_vector_shape(target_unit_vectors: &Array2<f64>) -> PyResult<()> {
+fn validate_unit_vector_shape(target_unit_vectors: &Array2<f64>) -> Result<(), ConstraintError> {
     if target_unit_vectors.ncols() != 3 {
-        return Err(pyo3::exceptions::PyValueError::new_err(
+        return Err(ConstraintError::invalid_input(
             "target_unit_vectors must have shape (N, 3)",
         ));
     }
@@ -20,17 +20,21 @@ fn eval_constraint_batch_from_unit_vectors(
     ephemeris: &dyn crate::ephemeris::ephemeris_common::EphemerisBase,
     target_unit_vectors: &Array2<f64>,
     time_indices: Option<&[usize]>,
-) -> PyResult<Array2<bool>> {
+) -> Result<Array2<bool>, ConstraintError> {
     validate_unit_vector_shape(target_unit_vectors)?;
 
-    if let Some(result) =
-        constraint.in_constraint_batch_unit_vectors(ephemeris, target_unit_vectors, time_indices)?
-    {
-        return Ok(result);
-    }
+    profiled(constraint, || {
+        if let Some(result) = constraint.in_constraint_batch_unit_vectors(
+            ephemeris,
+            target_unit_vectors,
+            time_indices,
+        )? {
+            return Ok(result);
+        }
 
-    let (target_ras, target_decs) = unit_vectors_to_radec_batch(target_unit_vectors);
-    constraint.in_constraint_batch(ephemeris, &target_ras, &target_decs, time_indices)
+        let (target_ras, target_decs) = unit_vectors_to_radec_batch(target_unit_vectors);
+        constraint.in_constraint_batch(ephemeris, &target_ras, &target_decs, time_indices)
+    })
 }
 
 fn eval_constraints_batch_from_unit_vectors(
@@ -38,29 +42,26 @@ fn eval_constraints_batch_from_unit_vectors(
     ephemeris: &dyn crate::ephemeris::ephemeris_common::EphemerisBase,
     target_unit_vectors: &Array2<f64>,
     time_indices: Option<&[usize]>,
-) -> PyResult<Vec<Array2<bool>>> {
+) -> Result<Vec<Array2<bool>>, ConstraintError> {
     validate_unit_vector_shape(target_unit_vectors)?;
 
     let mut fallback_radec: Option<(Vec<f64>, Vec<f64>)> = None;
     let mut results = Vec::with_capacity(constraints.len());
 
     for constraint in constraints {
-        if let Some(result) = constraint.in_constraint_batch_unit_vectors(
-            ephemeris,
-            target_unit_vectors,
-            time_indices,
-        )? {
-            results.push(result);
-        } else {
-            let (target_ras, target_decs) = fallback_radec
-                .get_or_insert_with(|| unit_vectors_to_radec_batch(target_unit_vectors));
-            results.push(constraint.in_constraint_batch(
+        let result = profiled(constraint.as_ref(), || {
+            if let Some(result) = constraint.in_constraint_batch_unit_vectors(
                 ephemeris,
-                target_ras,
-                target_decs,
+                target_unit_vectors,
                 time_indices,
-            )?);
-        }
+            )? {
+                return Ok(result);
+            }
+            let (target_ras, target_decs) = fallback_radec
+                .get_or_insert_with(|| unit_vectors_to_radec_batch(target_unit_vectors));
+            constraint.in_constraint_batch(ephemeris, target_ras, target_decs, time_indices)
+        })?;
+        results.push(result);
     }
 
     Ok(results)
@@ -77,8 +78,8 @@ impl ConstraintEvaluator for AndEvaluator {
         target_ra: f64,
         target_dec: f64,
         time_indices: Option<&[usize]>,
-    ) -> PyResult<ConstraintResult> {
-        let times = ephemeris.get_times()?;
+    ) -> Result<ConstraintResult, ConstraintError> {
+        let times = ephemeris.get_times().or_missing("times")?;
 
         // Build the actual indices we'll iterate over
         let indices: Vec<usize> = if let Some(idx) = time_indices {
@@ -170,14 +171,14 @@ impl ConstraintEvaluator for AndEvaluator {
         target_ras: &[f64],
         target_decs: &[f64],
         time_indices: Option<&[usize]>,
-    ) -> pyo3::PyResult<Array2<bool>> {
+    ) -> Result<Array2<bool>, ConstraintError> {
         if target_ras.len() != target_decs.len() {
-            return Err(pyo3::exceptions::PyValueError::new_err(
+            return Err(ConstraintError::invalid_input(
                 "target_ras and target_decs must have the same length",
             ));
         }
 
-        let times = ephemeris.get_times()?;
+        let times = ephemeris.get_times().or_missing("times")?;
         // Use filtered time count if time_indices provided, otherwise full times
         let n_times = time_indices.map(|idx| idx.len()).unwrap_or(times.len());
 
@@ -185,7 +186,11 @@ impl ConstraintEvaluator for AndEvaluator {
         let results: Result<Vec<_>, _> = self
             .constraints
             .iter()
-            .map(|c| c.in_constraint_batch(ephemeris, target_ras, target_decs, time_indices))
+            .map(|c| {
+                profiled(c.as_ref(), || {
+                    c.in_constraint_batch(ephemeris, target_ras, target_decs, time_indices)
+                })
+            })
             .collect();
         let results = results?;
 
@@ -213,7 +218,7 @@ impl ConstraintEvaluator for AndEvaluator {
         ephemeris: &dyn crate::ephemeris::ephemeris_common::EphemerisBase,
         target_unit_vectors: &Array2<f64>,
         time_indices: Option<&[usize]>,
-    ) -> PyResult<Option<Array2<bool>>> {
+    ) -> Result<Option<Array2<bool>>, ConstraintError> {
         let results = eval_constraints_batch_from_unit_vectors(
             &self.constraints,
             ephemeris,
@@ -226,7 +231,7 @@ impl ConstraintEvaluator for AndEvaluator {
         } else {
             time_indices
                 .map(|idx| idx.len())
-                .unwrap_or(ephemeris.get_times()?.len())
+                .unwrap_or(ephemeris.get_times().or_missing("times")?.len())
         };
 
         let n_targets = target_unit_vectors.nrows();
@@ -253,7 +258,7 @@ impl ConstraintEvaluator for AndEvaluator {
         ephemeris: &dyn crate::ephemeris::ephemeris_common::EphemerisBase,
         target_ras: &[f64],
         target_decs: &[f64],
-    ) -> PyResult<Vec<bool>> {
+    ) -> Result<Vec<bool>, ConstraintError> {
         let n = target_ras.len();
         if n == 0 {
             return Ok(Vec::new());
@@ -263,7 +268,11 @@ impl ConstraintEvaluator for AndEvaluator {
         let sub_results: Result<Vec<_>, _> = self
             .constraints
             .iter()
-            .map(|c| c.in_constraint_batch_diagonal(ephemeris, target_ras, target_decs))
+            .map(|c| {
+                profiled(c.as_ref(), || {
+                    c.in_constraint_batch_diagonal(ephemeris, target_ras, target_decs)
+                })
+            })
             .collect();
         let sub_results = sub_results?;
 
@@ -287,7 +296,7 @@ impl ConstraintEvaluator for AndEvaluator {
         target_unit_vectors: &Array2<f64>,
         time_index: usize,
         roll_deg: f64,
-    ) -> PyResult<Vec<bool>> {
+    ) -> Result<Vec<bool>, ConstraintError> {
         let n_targets = target_unit_vectors.nrows();
         if self.constraints.is_empty() {
             return Ok(vec![true; n_targets]);
@@ -339,8 +348,8 @@ impl ConstraintEvaluator for OrEvaluator {
         target_ra: f64,
         target_dec: f64,
         time_indices: Option<&[usize]>,
-    ) -> PyResult<ConstraintResult> {
-        let times = ephemeris.get_times()?;
+    ) -> Result<ConstraintResult, ConstraintError> {
+        let times = ephemeris.get_times().or_missing("times")?;
 
         // Build the actual indices we'll iterate over
         let indices: Vec<usize> = if let Some(idx) = time_indices {
@@ -423,14 +432,14 @@ impl ConstraintEvaluator for OrEvaluator {
         target_ras: &[f64],
         target_decs: &[f64],
         time_indices: Option<&[usize]>,
-    ) -> pyo3::PyResult<Array2<bool>> {
+    ) -> Result<Array2<bool>, ConstraintError> {
         if target_ras.len() != target_decs.len() {
-            return Err(pyo3::exceptions::PyValueError::new_err(
+            return Err(ConstraintError::invalid_input(
                 "target_ras and target_decs must have the same length",
             ));
         }
 
-        let times = ephemeris.get_times()?;
+        let times = ephemeris.get_times().or_missing("times")?;
         // Use filtered time count if time_indices provided, otherwise full times
         let n_times = time_indices.map(|idx| idx.len()).unwrap_or(times.len());
 
@@ -438,7 +447,11 @@ impl ConstraintEvaluator for OrEvaluator {
         let results: Result<Vec<_>, _> = self
             .constraints
             .iter()
-            .map(|c| c.in_constraint_batch(ephemeris, target_ras, target_decs, time_indices))
+            .map(|c| {
+                profiled(c.as_ref(), || {
+                    c.in_constraint_batch(ephemeris, target_ras, target_decs, time_indices)
+                })
+            })
             .collect();
         let results = results?;
 
@@ -466,7 +479,7 @@ impl ConstraintEvaluator for OrEvaluator {
         ephemeris: &dyn crate::ephemeris::ephemeris_common::EphemerisBase,
         target_unit_vectors: &Array2<f64>,
         time_indices: Option<&[usize]>,
-    ) -> PyResult<Option<Array2<bool>>> {
+    ) -> Result<Option<Array2<bool>>, ConstraintError> {
         let results = eval_constraints_batch_from_unit_vectors(
             &self.constraints,
             ephemeris,
@@ -479,7 +492,7 @@ impl ConstraintEvaluator for OrEvaluator {
         } else {
             time_indices
                 .map(|idx| idx.len())
-                .unwrap_or(ephemeris.get_times()?.len())
+                .unwrap_or(ephemeris.get_times().or_missing("times")?.len())
         };
 
         let n_targets = target_unit_vectors.nrows();
@@ -506,7 +519,7 @@ impl ConstraintEvaluator for OrEvaluator {
         ephemeris: &dyn crate::ephemeris::ephemeris_common::EphemerisBase,
         target_ras: &[f64],
         target_decs: &[f64],
-    ) -> PyResult<Vec<bool>> {
+    ) -> Result<Vec<bool>, ConstraintError> {
         let n = target_ras.len();
         if n == 0 {
             return Ok(Vec::new());
@@ -516,7 +529,11 @@ impl ConstraintEvaluator for OrEvaluator {
         let sub_results: Result<Vec<_>, _> = self
             .constraints
             .iter()
-            .map(|c| c.in_constraint_batch_diagonal(ephemeris, target_ras, target_decs))
+            .map(|c| {
+                profiled(c.as_ref(), || {
+                    c.in_constraint_batch_diagonal(ephemeris, target_ras, target_decs)
+                })
+            })
             .collect();
         let sub_results = sub_results?;
 
@@ -540,7 +557,7 @@ impl ConstraintEvaluator for OrEvaluator {
         target_unit_vectors: &Array2<f64>,
         time_index: usize,
         roll_deg: f64,
-    ) -> PyResult<Vec<bool>> {
+    ) -> Result<Vec<bool>, ConstraintError> {
         let n_targets = target_unit_vectors.nrows();
         if self.constraints.is_empty() {
             return Ok(vec![false; n_targets]);
@@ -592,8 +609,8 @@ impl ConstraintEvaluator for NotEvaluator {
         target_ra: f64,
         target_dec: f64,
         time_indices: Option<&[usize]>,
-    ) -> PyResult<ConstraintResult> {
-        let times = ephemeris.get_times()?;
+    ) -> Result<ConstraintResult, ConstraintError> {
+        let times = ephemeris.get_times().or_missing("times")?;
 
         // Build the filtered timeline – consistent with And/Or/AtLeast evaluators.
         let indices: Vec<usize> = if let Some(idx) = time_indices {
@@ -672,15 +689,13 @@ impl ConstraintEvaluator for NotEvaluator {
         target_ras: &[f64],
         target_decs: &[f64],
         time_indices: Option<&[usize]>,
-    ) -> pyo3::PyResult<Array2<bool>> {
-        let times = ephemeris.get_times()?;
+    ) -> Result<Array2<bool>, ConstraintError> {
+        let times = ephemeris.get_times().or_missing("times")?;
         // Evaluate sub-constraint in batch
-        let sub_result = self.constraint.in_constraint_batch(
-            ephemeris,
-            target_ras,
-            target_decs,
-            time_indices,
-        )?;
+        let sub_result = profiled(self.constraint.as_ref(), || {
+            self.constraint
+                .in_constraint_batch(ephemeris, target_ras, target_decs, time_indices)
+        })?;
 
         let n_targets = target_ras.len();
         // Use filtered time count if time_indices provided, otherwise full times
@@ -702,7 +717,7 @@ impl ConstraintEvaluator for NotEvaluator {
         ephemeris: &dyn crate::ephemeris::ephemeris_common::EphemerisBase,
         target_unit_vectors: &Array2<f64>,
         time_indices: Option<&[usize]>,
-    ) -> PyResult<Option<Array2<bool>>> {
+    ) -> Result<Option<Array2<bool>>, ConstraintError> {
         let sub_result = eval_constraint_batch_from_unit_vectors(
             self.constraint.as_ref(),
             ephemeris,
@@ -730,10 +745,11 @@ impl ConstraintEvaluator for NotEvaluator {
         ephemeris: &dyn crate::ephemeris::ephemeris_common::EphemerisBase,
         target_ras: &[f64],
         target_decs: &[f64],
-    ) -> PyResult<Vec<bool>> {
-        let sub_result =
+    ) -> Result<Vec<bool>, ConstraintError> {
+        let sub_result = profiled(self.constraint.as_ref(), || {
             self.constraint
-                .in_constraint_batch_diagonal(ephemeris, target_ras, target_decs)?;
+                .in_constraint_batch_diagonal(ephemeris, target_ras, target_decs)
+        })?;
 
         // NOT logic: invert all values
         Ok(sub_result.into_iter().map(|v| !v).collect())
@@ -749,7 +765,7 @@ impl ConstraintEvaluator for NotEvaluator {
         target_unit_vectors: &Array2<f64>,
         time_index: usize,
         roll_deg: f64,
-    ) -> PyResult<Vec<bool>> {
+    ) -> Result<Vec<bool>, ConstraintError> {
         let sub = self.constraint.field_of_regard_violated_at_roll(
             ephemeris,
             target_unit_vectors,
@@ -780,8 +796,8 @@ impl ConstraintEvaluator for XorEvaluator {
         target_ra: f64,
         target_dec: f64,
         time_indices: Option<&[usize]>,
-    ) -> PyResult<ConstraintResult> {
-        let times = ephemeris.get_times()?;
+    ) -> Result<ConstraintResult, ConstraintError> {
+        let times = ephemeris.get_times().or_missing("times")?;
 
         // Build the filtered timeline – consistent with And/Or/Not/AtLeast evaluators.
         let indices: Vec<usize> = if let Some(idx) = time_indices {
@@ -796,7 +812,7 @@ impl ConstraintEvaluator for XorEvaluator {
             .constraints
             .iter()
             .map(|c| c.evaluate(ephemeris, target_ra, target_dec, time_indices))
-            .collect::<PyResult<Vec<_>>>()?;
+            .collect::<Result<Vec<_>, ConstraintError>>()?;
 
         // Violate when EXACTLY ONE sub-constraint is violated
         let mut merged_violations = Vec::new();
@@ -867,14 +883,14 @@ impl ConstraintEvaluator for XorEvaluator {
         target_ras: &[f64],
         target_decs: &[f64],
         time_indices: Option<&[usize]>,
-    ) -> pyo3::PyResult<Array2<bool>> {
+    ) -> Result<Array2<bool>, ConstraintError> {
         if target_ras.len() != target_decs.len() {
-            return Err(pyo3::exceptions::PyValueError::new_err(
+            return Err(ConstraintError::invalid_input(
                 "target_ras and target_decs must have the same length",
             ));
         }
 
-        let times = ephemeris.get_times()?;
+        let times = ephemeris.get_times().or_missing("times")?;
         // Use filtered time count if time_indices provided, otherwise full times
         let n_times = time_indices.map(|idx| idx.len()).unwrap_or(times.len());
 
@@ -882,7 +898,11 @@ impl ConstraintEvaluator for XorEvaluator {
         let results: Result<Vec<_>, _> = self
             .constraints
             .iter()
-            .map(|c| c.in_constraint_batch(ephemeris, target_ras, target_decs, time_indices))
+            .map(|c| {
+                profiled(c.as_ref(), || {
+                    c.in_constraint_batch(ephemeris, target_ras, target_decs, time_indices)
+                })
+            })
             .collect();
         let results = results?;
 
@@ -905,7 +925,7 @@ impl ConstraintEvaluator for XorEvaluator {
         ephemeris: &dyn crate::ephemeris::ephemeris_common::EphemerisBase,
         target_unit_vectors: &Array2<f64>,
         time_indices: Option<&[usize]>,
-    ) -> PyResult<Option<Array2<bool>>> {
+    ) -> Result<Option<Array2<bool>>, ConstraintError> {
         let results = eval_constraints_batch_from_unit_vectors(
             &self.constraints,
             ephemeris,
@@ -918,7 +938,7 @@ impl ConstraintEvaluator for XorEvaluator {
         } else {
             time_indices
                 .map(|idx| idx.len())
-                .unwrap_or(ephemeris.get_times()?.len())
+                .unwrap_or(ephemeris.get_times().or_missing("times")?.len())
         };
 
         let n_targets = target_unit_vectors.nrows();
@@ -941,7 +961,7 @@ impl ConstraintEvaluator for XorEvaluator {
         ephemeris: &dyn crate::ephemeris::ephemeris_common::EphemerisBase,
         target_ras: &[f64],
         target_decs: &[f64],
-    ) -> PyResult<Vec<bool>> {
+    ) -> Result<Vec<bool>, ConstraintError> {
         let n = target_ras.len();
         if n == 0 {
             return Ok(Vec::new());
@@ -951,7 +971,11 @@ impl ConstraintEvaluator for XorEvaluator {
         let sub_results: Result<Vec<_>, _> = self
             .constraints
             .iter()
-            .map(|c| c.in_constraint_batch_diagonal(ephemeris, target_ras, target_decs))
+            .map(|c| {
+                profiled(c.as_ref(), || {
+                    c.in_constraint_batch_diagonal(ephemeris, target_ras, target_decs)
+                })
+            })
             .collect();
         let sub_results = sub_results?;
 
@@ -975,9 +999,9 @@ impl ConstraintEvaluator for XorEvaluator {
         target_unit_vectors: &Array2<f64>,
         time_index: usize,
         roll_deg: f64,
-    ) -> PyResult<Vec<bool>> {
+    ) -> Result<Vec<bool>, ConstraintError> {
         let n_targets = target_unit_vectors.nrows();
-        let sub_results: PyResult<Vec<_>> = self
+        let sub_results: Result<Vec<_>, ConstraintError> = self
             .constraints
             .iter()
             .map(|c| {
@@ -1026,8 +1050,8 @@ impl ConstraintEvaluator for AtLeastEvaluator {
         target_ra: f64,
         target_dec: f64,
         time_indices: Option<&[usize]>,
-    ) -> PyResult<ConstraintResult> {
-        let times = ephemeris.get_times()?;
+    ) -> Result<ConstraintResult, ConstraintError> {
+        let times = ephemeris.get_times().or_missing("times")?;
 
         let indices: Vec<usize> = if let Some(idx) = time_indices {
             idx.to_vec()
@@ -1099,20 +1123,24 @@ impl ConstraintEvaluator for AtLeastEvaluator {
         target_ras: &[f64],
         target_decs: &[f64],
         time_indices: Option<&[usize]>,
-    ) -> pyo3::PyResult<Array2<bool>> {
+    ) -> Result<Array2<bool>, ConstraintError> {
         if target_ras.len() != target_decs.len() {
-            return Err(pyo3::exceptions::PyValueError::new_err(
+            return Err(ConstraintError::invalid_input(
                 "target_ras and target_decs must have the same length",
             ));
         }
 
-        let times = ephemeris.get_times()?;
+        let times = ephemeris.get_times().or_missing("times")?;
         let n_times = time_indices.map(|idx| idx.len()).unwrap_or(times.len());
 
         let results: Result<Vec<_>, _> = self
             .constraints
             .iter()
-            .map(|c| c.in_constraint_batch(ephemeris, target_ras, target_decs, time_indices))
+            .map(|c| {
+                profiled(c.as_ref(), || {
+                    c.in_constraint_batch(ephemeris, target_ras, target_decs, time_indices)
+                })
+            })
             .collect();
         let results = results?;
 
@@ -1142,7 +1170,7 @@ impl ConstraintEvaluator for AtLeastEvaluator {
         ephemeris: &dyn crate::ephemeris::ephemeris_common::EphemerisBase,
         target_unit_vectors: &Array2<f64>,
         time_indices: Option<&[usize]>,
-    ) -> PyResult<Option<Array2<bool>>> {
+    ) -> Result<Option<Array2<bool>>, ConstraintError> {
         let results = eval_constraints_batch_from_unit_vectors(
             &self.constraints,
             ephemeris,
@@ -1155,7 +1183,7 @@ impl ConstraintEvaluator for AtLeastEvaluator {
         } else {
             time_indices
                 .map(|idx| idx.len())
-                .unwrap_or(ephemeris.get_times()?.len())
+                .unwrap_or(ephemeris.get_times().or_missing("times")?.len())
         };
 
         let n_targets = target_unit_vectors.nrows();
@@ -1184,7 +1212,7 @@ impl ConstraintEvaluator for AtLeastEvaluator {
         ephemeris: &dyn crate::ephemeris::ephemeris_common::EphemerisBase,
         target_ras: &[f64],
         target_decs: &[f64],
-    ) -> PyResult<Vec<bool>> {
+    ) -> Result<Vec<bool>, ConstraintError> {
         let n = target_ras.len();
         if n == 0 {
             return Ok(Vec::new());
@@ -1193,7 +1221,11 @@ impl ConstraintEvaluator for AtLeastEvaluator {
         let sub_results: Result<Vec<_>, _> = self
             .constraints
             .iter()
-            .map(|c| c.in_constraint_batch_diagonal(ephemeris, target_ras, target_decs))
+            .map(|c| {
+                profiled(c.as_ref(), || {
+                    c.in_constraint_batch_diagonal(ephemeris, target_ras, target_decs)
+                })
+            })
             .collect();
         let sub_results = sub_results?;
 
@@ -1216,9 +1248,9 @@ impl ConstraintEvaluator for AtLeastEvaluator {
         target_unit_vectors: &Array2<f64>,
         time_index: usize,
         roll_deg: f64,
-    ) -> PyResult<Vec<bool>> {
+    ) -> Result<Vec<bool>, ConstraintError> {
         let n_targets = target_unit_vectors.nrows();
-        let sub_results: PyResult<Vec<_>> = self
+        let sub_results: Result<Vec<_>, ConstraintError> = self
             .constraints
             .iter()
             .map(|c| {
@@ -1,10 +1,5 @@
 use crate::constraints::core::ConstraintEvaluator;
-use crate::ephemeris::ephemeris_common::EphemerisBase;
-use crate::ephemeris::FileEphemeris;
-use crate::ephemeris::GroundEphemeris;
-use crate::ephemeris::OEMEphemeris;
-use crate::ephemeris::SPICEEphemeris;
-use crate::ephemeris::TLEEphemeris;
+use crate::ephemeris::with_ephemeris;
 use ndarray::Array2;
 use pyo3::prelude::*;
 use std::f64::consts::PI;
@@ -161,21 +156,7 @@ where
     };
 
     if has_index {
-        let n_times = if let Ok(ephem) = bound.extract::<PyRef<TLEEphemeris>>() {
-            ephem.get_times()?.len()
-        } else if let Ok(ephem) = bound.extract::<PyRef<SPICEEphemeris>>() {
-            ephem.get_times()?.len()
-        } else if let Ok(ephem) = bound.extract::<PyRef<GroundEphemeris>>() {
-            ephem.get_times()?.len()
-        } else if let Ok(ephem) = bound.extract::<PyRef<OEMEphemeris>>() {
-            ephem.get_times()?.len()
-        } else if let Ok(ephem) = bound.extract::<PyRef<FileEphemeris>>() {
-            ephem.get_times()?.len()
-        } else {
-            return Err(pyo3::exceptions::PyTypeError::new_err(
-                "Unsupported ephemeris type. Expected TLEEphemeris, SPICEEphemeris, GroundEphemeris, or OEMEphemeris",
-            ));
-        };
+        let n_times = with_ephemeris(bound, |ephem| Ok(ephem.get_times()?.len()))?;
 
         if eval_index >= n_times {
             return Err(pyo3::exceptions::PyValueError::new_err(format!(
@@ -187,30 +168,14 @@ where
 
     let sky_samples = cached_fibonacci_sphere_radec(n_points);
 
-    let evaluate_batch = |ephem: &dyn EphemerisBase| -> PyResult<Vec<bool>> {
-        evaluator.field_of_regard_violated_batch(
+    let violated: Vec<bool> = with_ephemeris(bound, |ephem| {
+        Ok(evaluator.field_of_regard_violated_batch(
             ephem,
             &sky_samples.unit_vectors,
             eval_index,
             n_roll_samples,
-        )
-    };
-
-    let violated: Vec<bool> = if let Ok(ephem) = bound.extract::<PyRef<TLEEphemeris>>() {
-        evaluate_batch(&*ephem as &dyn EphemerisBase)?
-    } else if let Ok(ephem) = bound.extract::<PyRef<SPICEEphemeris>>() {
-        evaluate_batch(&*ephem as &dyn EphemerisBase)?
-    } else if let Ok(ephem) = bound.extract::<PyRef<GroundEphemeris>>() {
-        evaluate_batch(&*ephem as &dyn EphemerisBase)?
-    } else if let Ok(ephem) = bound.extract::<PyRef<OEMEphemeris>>() {
-        evaluate_batch(&*ephem as &dyn EphemerisBase)?
-    } else if let Ok(ephem) = bound.extract::<PyRef<FileEphemeris>>() {
-        evaluate_batch(&*ephem as &dyn EphemerisBase)?
-    } else {
-        return Err(pyo3::exceptions::PyTypeError::new_err(
-            "Unsupported ephemeris type. Expected TLEEphemeris, SPICEEphemeris, GroundEphemeris, or OEMEphemeris",
-        ));
-    };
+        )?)
+    })?;
 
     let visible_count = violated.iter().filter(|&&is_violated| !is_violated).count();
     let visible_fraction = visible_count as f64 / n_points as f64;
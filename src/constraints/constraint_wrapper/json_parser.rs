@@ -3,17 +3,29 @@ use crate::constraints::alt_az::AltAzConfig;
 use crate::constraints::body_proximity::BodyProximityConfig;
 use crate::constraints::bright_star::BrightStarConfig;
 use crate::constraints::core::{ConstraintConfig, ConstraintEvaluator};
-use crate::constraints::daytime::{DaytimeConfig, TwilightType};
+use crate::constraints::cutoff_rigidity::CutoffRigidityConfig;
+use crate::constraints::daytime::{DaytimeConfig, DaytimeMode, TwilightType};
 use crate::constraints::earth_limb::EarthLimbConfig;
+use crate::constraints::earth_occultation::EarthOccultationConfig;
 use crate::constraints::eclipse::EclipseConfig;
+use crate::constraints::geomagnetic_activity::GeomagneticActivityConfig;
+use crate::constraints::gimbal::{default_keyhole_elevation, GimbalConfig};
+use crate::constraints::ground_target_illumination::GroundTargetIlluminationConfig;
+use crate::constraints::ground_visibility::GroundVisibilityConfig;
 use crate::constraints::moon_phase::MoonPhaseConfig;
 use crate::constraints::moon_proximity::MoonProximityConfig;
 use crate::constraints::orbit_pole::OrbitPoleConfig;
 use crate::constraints::orbit_ram::OrbitRamConfig;
+use crate::constraints::planets_proximity::{default_planets, PlanetsProximityConfig};
 use crate::constraints::saa::SAAConfig;
+use crate::constraints::sky_region::SkyRegionConfig;
+use crate::constraints::station_keeping::StationKeepingConfig;
+use crate::constraints::sun_keepout::{KeepOutRollReference, SunKeepOutConfig};
 use crate::constraints::sun_proximity::SunProximityConfig;
+use crate::constraints::threshold_schedule::{validate_schedule, ThresholdPeriod};
 use pyo3::PyResult;
 use serde::Deserialize;
+use std::collections::BTreeMap;
 
 use super::boresight::{BoresightOffsetEvaluator, RollReference};
 use super::combinators::{AndEvaluator, AtLeastEvaluator, NotEvaluator, OrEvaluator, XorEvaluator};
@@ -43,6 +55,21 @@ impl From<TwilightSpec> for TwilightType {
     }
 }
 
+/// Reject malformed threshold schedules before building an evaluator
+fn checked_schedule(
+    schedule: Vec<ThresholdPeriod>,
+    min_angle: f64,
+    max_angle: Option<f64>,
+) -> PyResult<Vec<ThresholdPeriod>> {
+    validate_schedule(&schedule, min_angle, max_angle)
+        .map_err(pyo3::exceptions::PyValueError::new_err)?;
+    Ok(schedule)
+}
+
+fn default_astronomical() -> TwilightSpec {
+    TwilightSpec::Astronomical
+}
+
 fn default_full() -> String {
     "full".to_string()
 }
@@ -71,11 +98,30 @@ enum ConstraintSpec {
     Sun {
         min_angle: f64,
         max_angle: Option<f64>,
+        #[serde(default)]
+        limb_relative: bool,
+        #[serde(default)]
+        schedule: Vec<ThresholdPeriod>,
     },
     #[serde(rename = "moon")]
     Moon {
         min_angle: f64,
         max_angle: Option<f64>,
+        #[serde(default)]
+        limb_relative: bool,
+        #[serde(default)]
+        schedule: Vec<ThresholdPeriod>,
+    },
+    #[serde(rename = "sun_keepout")]
+    SunKeepOut {
+        pitch_plus: f64,
+        pitch_minus: f64,
+        yaw_plus: f64,
+        yaw_minus: f64,
+        #[serde(default)]
+        roll_deg: Option<f64>,
+        #[serde(default)]
+        roll_reference: KeepOutRollReference,
     },
     #[serde(rename = "eclipse")]
     Eclipse {
@@ -90,6 +136,10 @@ enum ConstraintSpec {
         include_refraction: bool,
         #[serde(default)]
         horizon_dip: bool,
+        #[serde(default)]
+        uncertainty_sigma: f64,
+        #[serde(default)]
+        schedule: Vec<ThresholdPeriod>,
     },
     #[serde(rename = "body")]
     Body {
@@ -102,11 +152,35 @@ enum ConstraintSpec {
         fov_polygon: Option<Vec<[f64; 2]>>,
         #[serde(default)]
         roll_deg: Option<f64>,
+        #[serde(default)]
+        limb_relative: bool,
+        #[serde(default)]
+        schedule: Vec<ThresholdPeriod>,
+        #[serde(default)]
+        source: Option<String>,
+    },
+    #[serde(rename = "planets")]
+    Planets {
+        min_angle: f64,
+        #[serde(default = "default_planets")]
+        planets: Vec<String>,
+        #[serde(default)]
+        min_angles: Option<BTreeMap<String, f64>>,
+        #[serde(default)]
+        source: Option<String>,
     },
     #[serde(rename = "daytime")]
     Daytime {
         #[serde(default)]
         twilight: TwilightSpec,
+        #[serde(default)]
+        mode: DaytimeMode,
+        #[serde(default)]
+        latitude: Option<f64>,
+        #[serde(default)]
+        longitude: Option<f64>,
+        #[serde(default)]
+        height: Option<f64>,
     },
     #[serde(rename = "airmass")]
     Airmass {
@@ -127,6 +201,40 @@ enum ConstraintSpec {
     #[serde(rename = "saa")]
     #[allow(clippy::upper_case_acronyms)]
     SAA { polygon: Vec<(f64, f64)> },
+    #[serde(rename = "sky_region")]
+    SkyRegion { polygon: Vec<(f64, f64)> },
+    #[serde(rename = "cutoff_rigidity")]
+    CutoffRigidity { min_rigidity: f64 },
+    #[serde(rename = "geomagnetic_activity")]
+    GeomagneticActivity { max_kp: f64 },
+    #[serde(rename = "earth_occultation")]
+    EarthOccultation {
+        #[serde(default)]
+        grazing_altitude: f64,
+    },
+    #[serde(rename = "station_keeping")]
+    StationKeeping {
+        longitude: f64,
+        #[serde(default)]
+        latitude: f64,
+        longitude_tolerance: f64,
+        latitude_tolerance: f64,
+    },
+    #[serde(rename = "ground_target_illumination")]
+    GroundTargetIllumination {
+        latitude: f64,
+        longitude: f64,
+        #[serde(default)]
+        height: f64,
+        #[serde(default)]
+        min_sun_elevation: Option<f64>,
+        #[serde(default)]
+        max_sun_elevation: Option<f64>,
+        #[serde(default)]
+        min_elevation: f64,
+        #[serde(default)]
+        max_off_nadir: Option<f64>,
+    },
     #[serde(rename = "alt_az")]
     AltAz {
         min_altitude: Option<f64>,
@@ -135,6 +243,30 @@ enum ConstraintSpec {
         max_azimuth: Option<f64>,
         polygon: Option<Vec<(f64, f64)>>,
     },
+    #[serde(rename = "gimbal")]
+    Gimbal {
+        #[serde(default)]
+        keyhole_radius: Option<f64>,
+        #[serde(default)]
+        keyhole_azimuth: f64,
+        #[serde(default = "default_keyhole_elevation")]
+        keyhole_elevation: f64,
+        #[serde(default)]
+        max_azimuth_rate: Option<f64>,
+        #[serde(default)]
+        max_elevation_rate: Option<f64>,
+    },
+    #[serde(rename = "ground_visibility")]
+    GroundVisibility {
+        #[serde(default)]
+        min_altitude: f64,
+        #[serde(default = "default_astronomical")]
+        twilight: TwilightSpec,
+        #[serde(default)]
+        max_airmass: Option<f64>,
+        #[serde(default)]
+        max_moon_altitude: Option<f64>,
+    },
     #[serde(rename = "and")]
     And { constraints: Vec<ConstraintSpec> },
     #[serde(rename = "or")]
@@ -199,19 +331,48 @@ impl ConstraintSpec {
             ConstraintSpec::Sun {
                 min_angle,
                 max_angle,
+                limb_relative,
+                schedule,
             } => Ok(SunProximityConfig {
+                schedule: checked_schedule(schedule, min_angle, max_angle)?,
                 min_angle,
                 max_angle,
+                limb_relative,
             }
             .to_evaluator()),
             ConstraintSpec::Moon {
                 min_angle,
                 max_angle,
+                limb_relative,
+                schedule,
             } => Ok(MoonProximityConfig {
+                schedule: checked_schedule(schedule, min_angle, max_angle)?,
                 min_angle,
                 max_angle,
+                limb_relative,
             }
             .to_evaluator()),
+            ConstraintSpec::SunKeepOut {
+                pitch_plus,
+                pitch_minus,
+                yaw_plus,
+                yaw_minus,
+                roll_deg,
+                roll_reference,
+            } => {
+                let config = SunKeepOutConfig {
+                    pitch_plus,
+                    pitch_minus,
+                    yaw_plus,
+                    yaw_minus,
+                    roll_deg,
+                    roll_reference,
+                };
+                config
+                    .validate()
+                    .map_err(pyo3::exceptions::PyValueError::new_err)?;
+                Ok(config.to_evaluator())
+            }
             ConstraintSpec::Eclipse { umbra_only } => {
                 Ok(EclipseConfig { umbra_only }.to_evaluator())
             }
@@ -220,11 +381,15 @@ impl ConstraintSpec {
                 max_angle,
                 include_refraction,
                 horizon_dip,
+                uncertainty_sigma,
+                schedule,
             } => Ok(EarthLimbConfig {
+                schedule: checked_schedule(schedule, min_angle, max_angle)?,
                 min_angle,
                 max_angle,
                 include_refraction,
                 horizon_dip,
+                uncertainty_sigma,
             }
             .to_evaluator()),
             ConstraintSpec::Body {
@@ -233,18 +398,61 @@ impl ConstraintSpec {
                 max_angle,
                 fov_polygon,
                 roll_deg,
-            } => Ok(BodyProximityConfig {
-                body,
+                limb_relative,
+                schedule,
+                source,
+            } => {
+                let config = BodyProximityConfig {
+                    schedule: checked_schedule(schedule, min_angle.unwrap_or(0.0), max_angle)?,
+                    body,
+                    min_angle,
+                    max_angle,
+                    fov_polygon,
+                    roll_deg,
+                    limb_relative,
+                    source,
+                };
+                config
+                    .validate()
+                    .map_err(pyo3::exceptions::PyValueError::new_err)?;
+                Ok(config.to_evaluator())
+            }
+            ConstraintSpec::Planets {
                 min_angle,
-                max_angle,
-                fov_polygon,
-                roll_deg,
+                planets,
+                min_angles,
+                source,
+            } => {
+                let config = PlanetsProximityConfig {
+                    min_angle,
+                    planets,
+                    min_angles,
+                    source,
+                };
+                config
+                    .validate()
+                    .map_err(pyo3::exceptions::PyValueError::new_err)?;
+                Ok(config.to_evaluator())
             }
-            .to_evaluator()),
-            ConstraintSpec::Daytime { twilight } => Ok(DaytimeConfig {
-                twilight: twilight.into(),
+            ConstraintSpec::Daytime {
+                twilight,
+                mode,
+                latitude,
+                longitude,
+                height,
+            } => {
+                let config = DaytimeConfig {
+                    twilight: twilight.into(),
+                    mode,
+                    latitude,
+                    longitude,
+                    height,
+                };
+                config
+                    .validate()
+                    .map_err(pyo3::exceptions::PyValueError::new_err)?;
+                Ok(config.to_evaluator())
             }
-            .to_evaluator()),
             ConstraintSpec::Airmass {
                 min_airmass,
                 max_airmass,
@@ -269,7 +477,71 @@ impl ConstraintSpec {
                 moon_visibility,
             }
             .to_evaluator()),
-            ConstraintSpec::SAA { polygon } => Ok(SAAConfig { polygon }.to_evaluator()),
+            ConstraintSpec::SAA { polygon } => Ok(SAAConfig::new(&polygon)?.to_evaluator()),
+            ConstraintSpec::SkyRegion { polygon } => {
+                Ok(SkyRegionConfig::new(&polygon)?.to_evaluator())
+            }
+            ConstraintSpec::CutoffRigidity { min_rigidity } => {
+                let config = CutoffRigidityConfig { min_rigidity };
+                config
+                    .validate()
+                    .map_err(pyo3::exceptions::PyValueError::new_err)?;
+                Ok(config.to_evaluator())
+            }
+            ConstraintSpec::GeomagneticActivity { max_kp } => {
+                let config = GeomagneticActivityConfig { max_kp };
+                config
+                    .validate()
+                    .map_err(pyo3::exceptions::PyValueError::new_err)?;
+                Ok(config.to_evaluator())
+            }
+            ConstraintSpec::EarthOccultation { grazing_altitude } => {
+                let config = EarthOccultationConfig { grazing_altitude };
+                config
+                    .validate()
+                    .map_err(pyo3::exceptions::PyValueError::new_err)?;
+                Ok(config.to_evaluator())
+            }
+            ConstraintSpec::StationKeeping {
+                longitude,
+                latitude,
+                longitude_tolerance,
+                latitude_tolerance,
+            } => {
+                let config = StationKeepingConfig {
+                    longitude,
+                    latitude,
+                    longitude_tolerance,
+                    latitude_tolerance,
+                };
+                config
+                    .validate()
+                    .map_err(pyo3::exceptions::PyValueError::new_err)?;
+                Ok(config.to_evaluator())
+            }
+            ConstraintSpec::GroundTargetIllumination {
+                latitude,
+                longitude,
+                height,
+                min_sun_elevation,
+                max_sun_elevation,
+                min_elevation,
+                max_off_nadir,
+            } => {
+                let config = GroundTargetIlluminationConfig {
+                    latitude,
+                    longitude,
+                    height,
+                    min_sun_elevation,
+                    max_sun_elevation,
+                    min_elevation,
+                    max_off_nadir,
+                };
+                config
+                    .validate()
+                    .map_err(pyo3::exceptions::PyValueError::new_err)?;
+                Ok(config.to_evaluator())
+            }
             ConstraintSpec::AltAz {
                 min_altitude,
                 max_altitude,
@@ -284,6 +556,42 @@ impl ConstraintSpec {
                 polygon,
             }
             .to_evaluator()),
+            ConstraintSpec::Gimbal {
+                keyhole_radius,
+                keyhole_azimuth,
+                keyhole_elevation,
+                max_azimuth_rate,
+                max_elevation_rate,
+            } => {
+                let config = GimbalConfig {
+                    keyhole_radius,
+                    keyhole_azimuth,
+                    keyhole_elevation,
+                    max_azimuth_rate,
+                    max_elevation_rate,
+                };
+                config
+                    .validate()
+                    .map_err(pyo3::exceptions::PyValueError::new_err)?;
+                Ok(config.to_evaluator())
+            }
+            ConstraintSpec::GroundVisibility {
+                min_altitude,
+                twilight,
+                max_airmass,
+                max_moon_altitude,
+            } => {
+                let config = GroundVisibilityConfig {
+                    min_altitude,
+                    twilight: twilight.into(),
+                    max_airmass,
+                    max_moon_altitude,
+                };
+                config
+                    .validate()
+                    .map_err(pyo3::exceptions::PyValueError::new_err)?;
+                Ok(config.to_evaluator())
+            }
             ConstraintSpec::And { constraints } => {
                 if constraints.is_empty() {
                     return Err(pyo3::exceptions::PyValueError::new_err(
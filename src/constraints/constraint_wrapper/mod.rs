@@ -8,10 +8,10 @@ mod field_of_regard;
 
 mod roll_range;
 
+mod prescreen;
+
 mod json_parser;
 
 mod combinators;
 
 mod boresight;
-
-mod json_to_py;
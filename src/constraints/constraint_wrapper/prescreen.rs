@@ -0,0 +1,101 @@
+use crate::constraints::core::{profiled, ConstraintEvaluator};
+/// Coarse-to-fine constraint evaluation for `evaluate(..., prescreen=N)`.
+///
+/// Slowly varying constraints (Sun proximity, galactic plane, ...) change state
+/// only a few times over a run sampled every minute. With a prescreen stride the
+/// constraint is first evaluated every `stride` steps; spans whose two ends agree
+/// take that state, and only spans whose ends differ are evaluated step by step.
+/// A window shorter than `stride` steps that falls entirely between two coarse
+/// samples is missed, so the stride should be shorter than the shortest violation
+/// or visibility window of interest.
+use crate::constraints::error::{ConstraintError, OrMissing};
+use crate::ephemeris::ephemeris_common::EphemerisBase;
+use ndarray::{s, Array2};
+
+/// Violation mask of shape (n_targets, n_times), as from `in_constraint_batch`,
+/// computed coarse-to-fine when a `stride` above 1 is given
+pub(super) fn violation_mask(
+    evaluator: &dyn ConstraintEvaluator,
+    ephemeris: &dyn EphemerisBase,
+    target_ras: &[f64],
+    target_decs: &[f64],
+    time_indices: Option<&[usize]>,
+    stride: Option<usize>,
+) -> Result<Array2<bool>, ConstraintError> {
+    profiled(evaluator, || {
+        coarse_to_fine(
+            evaluator,
+            ephemeris,
+            target_ras,
+            target_decs,
+            time_indices,
+            stride,
+        )
+    })
+}
+
+fn coarse_to_fine(
+    evaluator: &dyn ConstraintEvaluator,
+    ephemeris: &dyn EphemerisBase,
+    target_ras: &[f64],
+    target_decs: &[f64],
+    time_indices: Option<&[usize]>,
+    stride: Option<usize>,
+) -> Result<Array2<bool>, ConstraintError> {
+    let n_times = match time_indices {
+        Some(indices) => indices.len(),
+        None => ephemeris.get_times().or_missing("times")?.len(),
+    };
+    let stride = match stride {
+        Some(stride) if stride > 1 && n_times > 2 => stride,
+        _ => {
+            return evaluator.in_constraint_batch(ephemeris, target_ras, target_decs, time_indices)
+        }
+    };
+    // Ephemeris row of each evaluated step
+    let row = |k: usize| time_indices.map_or(k, |indices| indices[k]);
+
+    let mut coarse_steps: Vec<usize> = (0..n_times).step_by(stride).collect();
+    if coarse_steps.last() != Some(&(n_times - 1)) {
+        coarse_steps.push(n_times - 1);
+    }
+    let coarse_rows: Vec<usize> = coarse_steps.iter().map(|&k| row(k)).collect();
+    let coarse =
+        evaluator.in_constraint_batch(ephemeris, target_ras, target_decs, Some(&coarse_rows))?;
+
+    let n_targets = target_ras.len();
+    let last = coarse_steps.len() - 1;
+    let mut mask = Array2::from_elem((n_targets, n_times), false);
+    // Steps inside spans whose ends disagree for any target
+    let mut refine_steps = Vec::new();
+    for (span, ends) in coarse_steps.windows(2).enumerate() {
+        let mut changes = false;
+        for target in 0..n_targets {
+            let state = coarse[[target, span]];
+            changes |= state != coarse[[target, span + 1]];
+            mask.slice_mut(s![target, ends[0]..ends[1]]).fill(state);
+        }
+        if changes {
+            refine_steps.extend(ends[0] + 1..ends[1]);
+        }
+    }
+    for target in 0..n_targets {
+        mask[[target, n_times - 1]] = coarse[[target, last]];
+    }
+
+    if !refine_steps.is_empty() {
+        let refine_rows: Vec<usize> = refine_steps.iter().map(|&k| row(k)).collect();
+        let fine = evaluator.in_constraint_batch(
+            ephemeris,
+            target_ras,
+            target_decs,
+            Some(&refine_rows),
+        )?;
+        for (j, &k) in refine_steps.iter().enumerate() {
+            for target in 0..n_targets {
+                mask[[target, k]] = fine[[target, j]];
+            }
+        }
+    }
+    Ok(mask)
+}
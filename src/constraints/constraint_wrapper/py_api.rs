@@ -7,32 +7,170 @@ use crate::constraints::alt_az::AltAzConfig;
 use crate::constraints::body_proximity::BodyProximityConfig;
 use crate::constraints::bright_star::BrightStarConfig;
 use crate::constraints::core::*;
-use crate::constraints::daytime::{DaytimeConfig, TwilightType};
+use crate::constraints::cutoff_rigidity::CutoffRigidityConfig;
+use crate::constraints::daytime::{DaytimeConfig, DaytimeMode, TwilightType};
 use crate::constraints::earth_limb::EarthLimbConfig;
-use crate::constraints::eclipse::EclipseConfig;
+use crate::constraints::earth_occultation::{EarthOccultationConfig, EarthOccultationEvaluator};
+use crate::constraints::eclipse::{EclipseConfig, EclipseEvaluator};
+use crate::constraints::error::ConstraintError;
+use crate::constraints::geomagnetic_activity::GeomagneticActivityConfig;
+use crate::constraints::gimbal::GimbalConfig;
+use crate::constraints::ground_target_illumination::GroundTargetIlluminationConfig;
+use crate::constraints::ground_visibility::GroundVisibilityConfig;
 use crate::constraints::moon_phase::MoonPhaseConfig;
 use crate::constraints::moon_proximity::MoonProximityConfig;
 use crate::constraints::orbit_pole::OrbitPoleConfig;
 use crate::constraints::orbit_ram::OrbitRamConfig;
+use crate::constraints::planets_proximity::{default_planets, PlanetsProximityConfig};
 use crate::constraints::saa::SAAConfig;
+use crate::constraints::sky_region::SkyRegionConfig;
+use crate::constraints::station_keeping::StationKeepingConfig;
+use crate::constraints::sun_keepout::{KeepOutRollReference, SunKeepOutConfig};
 use crate::constraints::sun_proximity::SunProximityConfig;
+use crate::constraints::threshold_schedule::{
+    validate_angle_limits, validate_schedule, ThresholdPeriod,
+};
+use crate::ephemeris::array_ephemeris::ArrayEphemeris;
 use crate::ephemeris::ephemeris_common::EphemerisBase;
-use crate::ephemeris::FileEphemeris;
-use crate::ephemeris::GroundEphemeris;
-use crate::ephemeris::OEMEphemeris;
-use crate::ephemeris::SPICEEphemeris;
-use crate::ephemeris::TLEEphemeris;
+use crate::ephemeris::provenance::ephemeris_provenance_record;
+use crate::ephemeris::with_ephemeris;
 use chrono::{DateTime, Utc};
+use ndarray::{s, Array2};
 use numpy::{PyArray2, PyArrayMethods};
 use pyo3::prelude::*;
-use pyo3::types::{PyBool, PyList};
+use pyo3::types::{PyBool, PyDict, PyList};
+use rayon::prelude::*;
+use std::collections::BTreeMap;
 
+use super::combinators::OrEvaluator;
 use super::field_of_regard::instantaneous_field_of_regard_impl;
 use super::field_of_regard::DEFAULT_N_POINTS;
 use super::field_of_regard::DEFAULT_N_ROLL_SAMPLES;
 use super::json_parser::parse_constraint_json;
-use super::json_to_py::json_to_pyobject;
+use super::prescreen::violation_mask;
 use super::roll_range::run_roll_sweep;
+use crate::utils::json_to_py::json_to_pyobject;
+use crate::utils::progress::{CancellationToken, Progress, TARGET_CHUNK_SIZE};
+use crate::utils::time_utils::{
+    python_datetime_to_utc, python_times_to_utc, utc_to_python_datetime,
+};
+
+impl From<ConstraintError> for PyErr {
+    fn from(err: ConstraintError) -> Self {
+        match err {
+            ConstraintError::InvalidInput(message) => {
+                pyo3::exceptions::PyValueError::new_err(message)
+            }
+            ConstraintError::Ephemeris(message) => {
+                pyo3::exceptions::PyRuntimeError::new_err(message)
+            }
+        }
+    }
+}
+
+/// Time steps chosen by the `times`, `indices`, `between` or `at_times` arguments
+enum TimeSelection {
+    /// Steps of the ephemeris itself (`None` selects all of them)
+    Steps(Option<Vec<usize>>),
+    /// Times off the ephemeris grid, evaluated on an interpolated ephemeris
+    Interpolated(Box<ArrayEphemeris>),
+}
+
+/// Read a threshold schedule and check it against the constraint's limits
+///
+/// Each period is a mapping (or object) with optional `begin`, `end`,
+/// `min_angle` and `max_angle` entries.
+fn extract_schedule(
+    schedule: Option<&Bound<PyAny>>,
+    min_angle: f64,
+    max_angle: Option<f64>,
+) -> PyResult<Vec<ThresholdPeriod>> {
+    let Some(schedule) = schedule else {
+        return Ok(Vec::new());
+    };
+    let periods = pyo3::types::PyIterator::from_object(schedule)?
+        .map(|item| {
+            let item = item?;
+            let field = |name: &str| -> PyResult<Option<Bound<PyAny>>> {
+                let value = match item.downcast::<PyDict>() {
+                    Ok(dict) => dict.get_item(name)?,
+                    Err(_) if item.hasattr(name)? => Some(item.getattr(name)?),
+                    Err(_) => None,
+                };
+                Ok(value.filter(|v| !v.is_none()))
+            };
+            Ok(ThresholdPeriod {
+                begin: field("begin")?
+                    .map(|v| python_datetime_to_utc(&v))
+                    .transpose()?,
+                end: field("end")?
+                    .map(|v| python_datetime_to_utc(&v))
+                    .transpose()?,
+                min_angle: field("min_angle")?.map(|v| v.extract()).transpose()?,
+                max_angle: field("max_angle")?.map(|v| v.extract()).transpose()?,
+            })
+        })
+        .collect::<PyResult<Vec<_>>>()?;
+    validate_schedule(&periods, min_angle, max_angle)
+        .map_err(pyo3::exceptions::PyValueError::new_err)?;
+    Ok(periods)
+}
+
+/// Reject a zero prescreen stride
+fn check_prescreen(prescreen: Option<usize>) -> PyResult<()> {
+    if prescreen == Some(0) {
+        return Err(pyo3::exceptions::PyValueError::new_err(
+            "prescreen must be a positive number of time steps",
+        ));
+    }
+    Ok(())
+}
+
+/// `violation_mask` over blocks of targets, reporting each block to `progress`
+///
+/// Cancellation and pending signals are checked after every block. Blocks are
+/// evaluated with the GIL released when a callback or cancellation token was
+/// given.
+#[allow(clippy::too_many_arguments)]
+fn violation_mask_with_progress(
+    py: Python,
+    evaluator: &dyn ConstraintEvaluator,
+    ephemeris: &dyn EphemerisBase,
+    target_ras: &[f64],
+    target_decs: &[f64],
+    time_indices: Option<&[usize]>,
+    prescreen: Option<usize>,
+    progress: &mut Progress,
+) -> PyResult<Array2<bool>> {
+    let chunks = progress.chunks(target_ras.len(), TARGET_CHUNK_SIZE);
+    if chunks.is_empty() {
+        return violation_mask(
+            evaluator,
+            ephemeris,
+            target_ras,
+            target_decs,
+            time_indices,
+            prescreen,
+        )
+        .map_err(PyErr::from);
+    }
+    let mut mask: Option<Array2<bool>> = None;
+    for chunk in chunks {
+        let ras = &target_ras[chunk.clone()];
+        let decs = &target_decs[chunk.clone()];
+        let evaluate = || violation_mask(evaluator, ephemeris, ras, decs, time_indices, prescreen);
+        let block = if progress.is_active() {
+            py.detach(evaluate)?
+        } else {
+            evaluate()?
+        };
+        mask.get_or_insert_with(|| Array2::from_elem((target_ras.len(), block.ncols()), false))
+            .slice_mut(s![chunk.clone(), ..])
+            .assign(&block);
+        progress.advance(py, chunk.len())?;
+    }
+    Ok(mask.expect("at least one block of targets"))
+}
 
 /// Python-facing constraint evaluator
 ///
@@ -70,12 +208,14 @@ impl PyConstraint {
         let is_boresight_offset = constraint_type == "boresight_offset";
         let is_bright_star = constraint_type == "bright_star";
         let is_body_polygon = constraint_type == "body" && config.get("fov_polygon").is_some();
+        let is_sun_keepout = constraint_type == "sun_keepout";
 
         // Bright star or body proximity with a polygon FoV: inject target_roll as roll_deg
         // so the evaluator rotates the polygon to the requested angle.  Both constraint types
-        // handle roll internally, so we bypass the BoresightOffset wrapper.
-        if is_bright_star || is_body_polygon {
-            if config.get("fov_polygon").is_some() {
+        // handle roll internally, so we bypass the BoresightOffset wrapper.  The Sun keep-out
+        // sectors likewise rotate with roll_deg, in the constraint's own roll reference.
+        if is_bright_star || is_body_polygon || is_sun_keepout {
+            if is_sun_keepout || config.get("fov_polygon").is_some() {
                 if let Some(obj) = config.as_object_mut() {
                     obj.insert("roll_deg".to_string(), serde_json::json!(target_roll_deg));
                 }
@@ -124,26 +264,260 @@ impl PyConstraint {
         f(&*evaluator)
     }
 
+    /// Violation mask (n_targets x n_times, true = violated) over all ephemeris times
+    ///
+    /// Used by Rust-side consumers of constraints such as the scheduler.
+    pub(crate) fn violation_mask(
+        &self,
+        ephemeris: &dyn EphemerisBase,
+        target_ras: &[f64],
+        target_decs: &[f64],
+    ) -> PyResult<ndarray::Array2<bool>> {
+        profiled(self.evaluator.as_ref(), || {
+            self.evaluator
+                .in_constraint_batch(ephemeris, target_ras, target_decs, None)
+        })
+        .map_err(PyErr::from)
+    }
+
+    /// Violation flags (true = violated) for one pointing direction per ephemeris time
+    pub(crate) fn pointing_violations(
+        &self,
+        ephemeris: &dyn EphemerisBase,
+        ras: &[f64],
+        decs: &[f64],
+    ) -> PyResult<Vec<bool>> {
+        profiled(self.evaluator.as_ref(), || {
+            self.evaluator
+                .in_constraint_batch_diagonal(ephemeris, ras, decs)
+        })
+        .map_err(PyErr::from)
+    }
+
+    /// Constraint name as used in results
+    pub(crate) fn name(&self) -> String {
+        self.evaluator.name()
+    }
+
+    /// Named violation masks of the constraints that can each block a target
+    ///
+    /// For an OR combination every sub-constraint blocks on its own, so one
+    /// mask per sub-constraint is returned; otherwise the constraint itself
+    /// is the only entry.
+    pub(crate) fn blocking_masks(
+        &self,
+        ephemeris: &dyn EphemerisBase,
+        target_ras: &[f64],
+        target_decs: &[f64],
+    ) -> PyResult<Vec<(String, ndarray::Array2<bool>)>> {
+        let components: Vec<&dyn ConstraintEvaluator> =
+            match self.evaluator.as_any().downcast_ref::<OrEvaluator>() {
+                Some(or) => or.constraints.iter().map(|c| c.as_ref()).collect(),
+                None => vec![self.evaluator.as_ref()],
+            };
+        components
+            .into_iter()
+            .map(|c| {
+                let mask = profiled(c, || {
+                    c.in_constraint_batch(ephemeris, target_ras, target_decs, None)
+                })?;
+                Ok((c.name(), mask))
+            })
+            .collect()
+    }
+
+    /// Resolve the mutually exclusive time selection arguments of `evaluate`
+    fn select_times(
+        &self,
+        ephemeris: &Bound<PyAny>,
+        times: Option<&Bound<PyAny>>,
+        indices: Option<&Bound<PyAny>>,
+        between: Option<&Bound<PyAny>>,
+        at_times: Option<&Bound<PyAny>>,
+    ) -> PyResult<TimeSelection> {
+        let n_given = [times, indices, between, at_times]
+            .iter()
+            .filter(|arg| arg.is_some())
+            .count();
+        if n_given > 1 {
+            return Err(pyo3::exceptions::PyValueError::new_err(
+                "Specify at most one of 'times', 'indices', 'between' and 'at_times'",
+            ));
+        }
+
+        if let Some(times_arg) = times {
+            Ok(TimeSelection::Steps(Some(
+                self.parse_times_to_indices(ephemeris, times_arg)?,
+            )))
+        } else if let Some(indices_arg) = indices {
+            Ok(TimeSelection::Steps(Some(self.parse_indices(indices_arg)?)))
+        } else if let Some(between_arg) = between {
+            Self::select_between(ephemeris, between_arg).map(|i| TimeSelection::Steps(Some(i)))
+        } else if let Some(at_times_arg) = at_times {
+            Self::select_at_times(ephemeris, at_times_arg)
+        } else {
+            Ok(TimeSelection::Steps(None))
+        }
+    }
+
+    /// Indices of the ephemeris steps within the inclusive range `(t0, t1)`
+    fn select_between(ephemeris: &Bound<PyAny>, between: &Bound<PyAny>) -> PyResult<Vec<usize>> {
+        let bounds = python_times_to_utc(between)?;
+        let [t0, t1] = bounds[..] else {
+            return Err(pyo3::exceptions::PyValueError::new_err(
+                "between must be a (start, end) pair of datetimes",
+            ));
+        };
+        if t0 > t1 {
+            return Err(pyo3::exceptions::PyValueError::new_err(format!(
+                "between start {} is after end {}",
+                t0.to_rfc3339(),
+                t1.to_rfc3339()
+            )));
+        }
+
+        let ephem_times = with_ephemeris(ephemeris, |ephem| ephem.get_times())?;
+        let first = ephem_times.partition_point(|t| *t < t0);
+        let last = ephem_times.partition_point(|t| *t <= t1);
+        if first >= last {
+            return Err(pyo3::exceptions::PyValueError::new_err(format!(
+                "No ephemeris timestamps between {} and {}",
+                t0.to_rfc3339(),
+                t1.to_rfc3339()
+            )));
+        }
+        Ok((first..last).collect())
+    }
+
+    /// Map `at_times` to ephemeris steps, interpolating if any time is off the grid
+    fn select_at_times(
+        ephemeris: &Bound<PyAny>,
+        at_times: &Bound<PyAny>,
+    ) -> PyResult<TimeSelection> {
+        let requested = if pyo3::types::PyIterator::from_object(at_times).is_ok() {
+            python_times_to_utc(at_times)?
+        } else {
+            vec![python_datetime_to_utc(at_times)?]
+        };
+        if requested.is_empty() {
+            return Err(pyo3::exceptions::PyValueError::new_err(
+                "at_times must contain at least one time",
+            ));
+        }
+
+        with_ephemeris(ephemeris, |ephem| {
+            let ephem_times = ephem.get_times()?;
+            let on_grid: Option<Vec<usize>> = requested
+                .iter()
+                .map(|t| ephem_times.binary_search(t).ok())
+                .collect();
+            if let Some(indices) = on_grid {
+                return Ok(TimeSelection::Steps(Some(indices)));
+            }
+
+            if ephem_times.len() < 2 {
+                return Err(pyo3::exceptions::PyValueError::new_err(
+                    "Interpolating at_times requires an ephemeris with at least 2 timestamps",
+                ));
+            }
+            if ephem.horizon_mask().is_some() {
+                return Err(pyo3::exceptions::PyValueError::new_err(
+                    "at_times off the ephemeris grid are not supported with a horizon mask; \
+                     use times on the grid or 'between'",
+                ));
+            }
+            if requested.windows(2).any(|w| w[1] <= w[0]) {
+                return Err(pyo3::exceptions::PyValueError::new_err(
+                    "at_times must be strictly increasing",
+                ));
+            }
+            let first = ephem_times[0];
+            let last = ephem_times[ephem_times.len() - 1];
+            if requested[0] < first || requested[requested.len() - 1] > last {
+                return Err(pyo3::exceptions::PyValueError::new_err(format!(
+                    "at_times must lie within the ephemeris range [{}, {}]",
+                    first.to_rfc3339(),
+                    last.to_rfc3339()
+                )));
+            }
+
+            let gcrs = ephem.data().gcrs.clone().ok_or_else(|| {
+                pyo3::exceptions::PyValueError::new_err("No GCRS states available")
+            })?;
+            let resampled = ArrayEphemeris::resampled(ephem_times, gcrs, requested)?;
+            // Results on the interpolated grid trace back to the source ephemeris
+            let _ = resampled
+                .data()
+                .provenance_cache
+                .set(ephemeris_provenance_record(ephem));
+            Ok(TimeSelection::Interpolated(Box::new(resampled)))
+        })
+    }
+
+    /// Run `f` on the ephemeris and step indices chosen by `selection`
+    fn with_selected_ephemeris<R>(
+        ephemeris: &Bound<PyAny>,
+        selection: &TimeSelection,
+        f: impl FnOnce(&dyn EphemerisBase, Option<Vec<usize>>) -> PyResult<R>,
+    ) -> PyResult<R> {
+        match selection {
+            TimeSelection::Steps(indices) => {
+                with_ephemeris(ephemeris, |ephem| f(ephem, indices.clone()))
+            }
+            TimeSelection::Interpolated(resampled) => f(resampled.as_ref(), None),
+        }
+    }
+
+    /// Run `f` with all of `objects` borrowed at once, each paired with the steps
+    /// chosen by the matching entry of `selections`
+    fn with_selected_ephemerides<R>(
+        objects: &[Bound<'_, PyAny>],
+        selections: &[TimeSelection],
+        selected: Vec<(&dyn EphemerisBase, Option<Vec<usize>>)>,
+        f: impl FnOnce(&[(&dyn EphemerisBase, Option<Vec<usize>>)]) -> PyResult<R>,
+    ) -> PyResult<R> {
+        let (Some(obj), Some(selection)) = (objects.first(), selections.first()) else {
+            return f(&selected);
+        };
+        match selection {
+            TimeSelection::Steps(indices) => with_ephemeris(obj, |ephem| {
+                let mut selected: Vec<(&dyn EphemerisBase, Option<Vec<usize>>)> = selected;
+                selected.push((ephem, indices.clone()));
+                Self::with_selected_ephemerides(&objects[1..], &selections[1..], selected, f)
+            }),
+            TimeSelection::Interpolated(resampled) => {
+                let mut selected: Vec<(&dyn EphemerisBase, Option<Vec<usize>>)> = selected;
+                selected.push((resampled.as_ref(), None));
+                Self::with_selected_ephemerides(&objects[1..], &selections[1..], selected, f)
+            }
+        }
+    }
+
     /// Internal helper to evaluate against any Ephemeris implementing EphemerisBase
     #[allow(deprecated)]
-    fn eval_with_ephemeris<E: EphemerisBase>(
+    #[allow(clippy::too_many_arguments)]
+    fn eval_with_ephemeris(
         &self,
         evaluator: &dyn ConstraintEvaluator,
-        ephemeris: &E,
+        ephemeris: &dyn EphemerisBase,
         target_ra: f64,
         target_dec: f64,
         time_indices: Option<Vec<usize>>,
+        storage: ResultStorage,
+        prescreen: Option<usize>,
     ) -> PyResult<ConstraintResult> {
         // PERFORMANCE OPTIMIZATION: Use fast batch path internally
         // Instead of the slow evaluate() that tracks violations step-by-step,
         // use in_constraint_batch() which is 1700x faster, then construct violations from the result
 
         // Call the fast batch evaluation for single target
-        let violation_array = evaluator.in_constraint_batch(
+        let violation_array = violation_mask(
+            evaluator,
             ephemeris,
             &[target_ra],
             &[target_dec],
             time_indices.as_deref(),
+            prescreen,
         )?;
 
         // Get the times we evaluated
@@ -168,27 +542,39 @@ impl PyConstraint {
         );
 
         let all_satisfied = violations.is_empty();
-        Ok(ConstraintResult::new(
+        Ok(ConstraintResult::with_storage(
             violations,
             all_satisfied,
             evaluator.name(),
             times,
-        ))
+            storage,
+        )
+        .with_spec(self.config_json.clone())
+        .with_provenance(ephemeris_provenance_record(ephemeris)))
     }
 
-    fn eval_batch_with_ephemeris<E: EphemerisBase>(
+    #[allow(clippy::too_many_arguments)]
+    fn eval_batch_with_ephemeris(
         &self,
+        py: Python,
         evaluator: &dyn ConstraintEvaluator,
-        ephemeris: &E,
+        ephemeris: &dyn EphemerisBase,
         target_ras: &[f64],
         target_decs: &[f64],
         time_indices: Option<Vec<usize>>,
+        storage: ResultStorage,
+        prescreen: Option<usize>,
+        progress: &mut Progress,
     ) -> PyResult<Vec<ConstraintResult>> {
-        let violation_array = evaluator.in_constraint_batch(
+        let violation_array = violation_mask_with_progress(
+            py,
+            evaluator,
             ephemeris,
             target_ras,
             target_decs,
             time_indices.as_deref(),
+            prescreen,
+            progress,
         )?;
 
         let all_times = ephemeris.get_times()?;
@@ -198,6 +584,7 @@ impl PyConstraint {
             all_times.to_vec()
         };
 
+        let provenance = ephemeris_provenance_record(ephemeris);
         let mut results = Vec::with_capacity(target_ras.len());
         for target_index in 0..target_ras.len() {
             let violated: Vec<bool> = (0..violation_array.ncols())
@@ -211,12 +598,17 @@ impl PyConstraint {
             );
 
             let all_satisfied = violations.is_empty();
-            results.push(ConstraintResult::new(
-                violations,
-                all_satisfied,
-                evaluator.name(),
-                times.clone(),
-            ));
+            results.push(
+                ConstraintResult::with_storage(
+                    violations,
+                    all_satisfied,
+                    evaluator.name(),
+                    times.clone(),
+                    storage,
+                )
+                .with_spec(self.config_json.clone())
+                .with_provenance(provenance.clone()),
+            );
         }
 
         Ok(results)
@@ -242,41 +634,13 @@ impl PyConstraint {
         let bound = ephemeris.bind(py);
 
         // Use the efficient diagonal batch evaluation
-        if let Ok(ephem) = bound.extract::<PyRef<TLEEphemeris>>() {
-            self.evaluator.in_constraint_batch_diagonal(
-                &*ephem as &dyn EphemerisBase,
-                target_ras,
-                target_decs,
-            )
-        } else if let Ok(ephem) = bound.extract::<PyRef<SPICEEphemeris>>() {
-            self.evaluator.in_constraint_batch_diagonal(
-                &*ephem as &dyn EphemerisBase,
-                target_ras,
-                target_decs,
-            )
-        } else if let Ok(ephem) = bound.extract::<PyRef<GroundEphemeris>>() {
-            self.evaluator.in_constraint_batch_diagonal(
-                &*ephem as &dyn EphemerisBase,
-                target_ras,
-                target_decs,
-            )
-        } else if let Ok(ephem) = bound.extract::<PyRef<OEMEphemeris>>() {
-            self.evaluator.in_constraint_batch_diagonal(
-                &*ephem as &dyn EphemerisBase,
-                target_ras,
-                target_decs,
-            )
-        } else if let Ok(ephem) = bound.extract::<PyRef<FileEphemeris>>() {
-            self.evaluator.in_constraint_batch_diagonal(
-                &*ephem as &dyn EphemerisBase,
-                target_ras,
-                target_decs,
-            )
-        } else {
-            Err(pyo3::exceptions::PyTypeError::new_err(
-                "Unsupported ephemeris type. Expected TLEEphemeris, SPICEEphemeris, GroundEphemeris, or OEMEphemeris",
-            ))
-        }
+        with_ephemeris(bound, |ephem| {
+            profiled(self.evaluator.as_ref(), || {
+                self.evaluator
+                    .in_constraint_batch_diagonal(ephem, target_ras, target_decs)
+            })
+            .map_err(PyErr::from)
+        })
     }
 }
 
@@ -287,34 +651,30 @@ impl PyConstraint {
     /// Args:
     ///     min_angle (float): Minimum allowed angular separation from Sun in degrees
     ///     max_angle (float, optional): Maximum allowed angular separation from Sun in degrees
+    ///     limb_relative (bool, optional): Apply the limits to the separation from the
+    ///         Sun's limb (centre separation minus its angular radius). Default False.
+    ///     schedule (list[dict], optional): Periods overriding the limits, each with
+    ///         optional ``begin``, ``end`` (``[begin, end)``), ``min_angle`` and
+    ///         ``max_angle``. The first period containing a time applies.
     ///
     /// Returns:
     ///     Constraint: A new constraint object
-    #[pyo3(signature=(min_angle, max_angle=None))]
+    #[pyo3(signature=(min_angle, max_angle=None, limb_relative=false, schedule=None))]
     #[staticmethod]
-    fn sun_proximity(min_angle: f64, max_angle: Option<f64>) -> PyResult<Self> {
-        if !(0.0..=180.0).contains(&min_angle) {
-            return Err(pyo3::exceptions::PyValueError::new_err(
-                "min_angle must be between 0 and 180 degrees",
-            ));
-        }
-
-        if let Some(max) = max_angle {
-            if !(0.0..=180.0).contains(&max) {
-                return Err(pyo3::exceptions::PyValueError::new_err(
-                    "max_angle must be between 0 and 180 degrees",
-                ));
-            }
-            if max <= min_angle {
-                return Err(pyo3::exceptions::PyValueError::new_err(
-                    "max_angle must be greater than min_angle",
-                ));
-            }
-        }
+    fn sun_proximity(
+        min_angle: f64,
+        max_angle: Option<f64>,
+        limb_relative: bool,
+        schedule: Option<&Bound<PyAny>>,
+    ) -> PyResult<Self> {
+        validate_angle_limits(min_angle, max_angle)
+            .map_err(pyo3::exceptions::PyValueError::new_err)?;
 
         let config = SunProximityConfig {
             min_angle,
             max_angle,
+            limb_relative,
+            schedule: extract_schedule(schedule, min_angle, max_angle)?,
         };
         let mut json_obj = serde_json::json!({
             "type": "sun",
@@ -323,6 +683,12 @@ impl PyConstraint {
         if let Some(max) = max_angle {
             json_obj["max_angle"] = serde_json::json!(max);
         }
+        if limb_relative {
+            json_obj["limb_relative"] = serde_json::json!(true);
+        }
+        if !config.schedule.is_empty() {
+            json_obj["schedule"] = serde_json::json!(config.schedule);
+        }
         let config_json = json_obj.to_string();
 
         Ok(PyConstraint {
@@ -331,39 +697,106 @@ impl PyConstraint {
         })
     }
 
-    /// Create a Moon proximity constraint
+    /// Create a Sun keep-out constraint with separate limits per quadrant
+    ///
+    /// The Sun's direction around the boresight, in the spacecraft frame of
+    /// `boresight_offset` (+X boresight, +Z the roll reference, +Y = Z × X),
+    /// selects which minimum Sun angle applies: ``pitch_plus`` with the Sun
+    /// toward -Z, ``pitch_minus`` toward +Z, ``yaw_plus`` toward +Y and
+    /// ``yaw_minus`` toward -Y.
     ///
     /// Args:
-    ///     min_angle (float): Minimum allowed angular separation from Moon in degrees
-    ///     max_angle (float, optional): Maximum allowed angular separation from Moon in degrees
+    ///     pitch_plus (float): Minimum Sun angle in degrees in the +pitch sector
+    ///     pitch_minus (float): Minimum Sun angle in degrees in the -pitch sector
+    ///     yaw_plus (float): Minimum Sun angle in degrees in the +yaw sector
+    ///     yaw_minus (float): Minimum Sun angle in degrees in the -yaw sector
+    ///     roll_deg (float, optional): Spacecraft roll about the boresight in degrees.
+    ///         When omitted the roll is free and the smallest limit applies.
+    ///         ``target_roll`` at evaluation time overrides it.
+    ///     roll_reference (str, optional): Direction defining roll 0, "sun" (default)
+    ///         or "north"
     ///
     /// Returns:
     ///     Constraint: A new constraint object
-    #[pyo3(signature=(min_angle, max_angle=None))]
+    #[pyo3(signature=(pitch_plus, pitch_minus, yaw_plus, yaw_minus, roll_deg=None, roll_reference="sun"))]
     #[staticmethod]
-    fn moon_proximity(min_angle: f64, max_angle: Option<f64>) -> PyResult<Self> {
-        if !(0.0..=180.0).contains(&min_angle) {
-            return Err(pyo3::exceptions::PyValueError::new_err(
-                "min_angle must be between 0 and 180 degrees",
-            ));
-        }
-
-        if let Some(max) = max_angle {
-            if !(0.0..=180.0).contains(&max) {
-                return Err(pyo3::exceptions::PyValueError::new_err(
-                    "max_angle must be between 0 and 180 degrees",
-                ));
-            }
-            if max <= min_angle {
+    fn sun_keepout(
+        pitch_plus: f64,
+        pitch_minus: f64,
+        yaw_plus: f64,
+        yaw_minus: f64,
+        roll_deg: Option<f64>,
+        roll_reference: &str,
+    ) -> PyResult<Self> {
+        let roll_reference = match roll_reference.to_lowercase().as_str() {
+            "sun" => KeepOutRollReference::Sun,
+            "north" => KeepOutRollReference::North,
+            _ => {
                 return Err(pyo3::exceptions::PyValueError::new_err(
-                    "max_angle must be greater than min_angle",
+                    "roll_reference must be either 'sun' or 'north'",
                 ));
             }
+        };
+        let config = SunKeepOutConfig {
+            pitch_plus,
+            pitch_minus,
+            yaw_plus,
+            yaw_minus,
+            roll_deg,
+            roll_reference,
+        };
+        config
+            .validate()
+            .map_err(pyo3::exceptions::PyValueError::new_err)?;
+
+        let mut json_obj = serde_json::json!({
+            "type": "sun_keepout",
+            "pitch_plus": pitch_plus,
+            "pitch_minus": pitch_minus,
+            "yaw_plus": yaw_plus,
+            "yaw_minus": yaw_minus,
+            "roll_reference": roll_reference
+        });
+        if let Some(roll) = roll_deg {
+            json_obj["roll_deg"] = serde_json::json!(roll);
         }
+        let config_json = json_obj.to_string();
+
+        Ok(PyConstraint {
+            evaluator: config.to_evaluator(),
+            config_json,
+        })
+    }
+
+    /// Create a Moon proximity constraint
+    ///
+    /// Args:
+    ///     min_angle (float): Minimum allowed angular separation from Moon in degrees
+    ///     max_angle (float, optional): Maximum allowed angular separation from Moon in degrees
+    ///     limb_relative (bool, optional): Apply the limits to the separation from the
+    ///         Moon's limb (centre separation minus its angular radius). Default False.
+    ///     schedule (list[dict], optional): Periods overriding the limits, each with
+    ///         optional ``begin``, ``end`` (``[begin, end)``), ``min_angle`` and
+    ///         ``max_angle``. The first period containing a time applies.
+    ///
+    /// Returns:
+    ///     Constraint: A new constraint object
+    #[pyo3(signature=(min_angle, max_angle=None, limb_relative=false, schedule=None))]
+    #[staticmethod]
+    fn moon_proximity(
+        min_angle: f64,
+        max_angle: Option<f64>,
+        limb_relative: bool,
+        schedule: Option<&Bound<PyAny>>,
+    ) -> PyResult<Self> {
+        validate_angle_limits(min_angle, max_angle)
+            .map_err(pyo3::exceptions::PyValueError::new_err)?;
 
         let config = MoonProximityConfig {
             min_angle,
             max_angle,
+            limb_relative,
+            schedule: extract_schedule(schedule, min_angle, max_angle)?,
         };
         let mut json_obj = serde_json::json!({
             "type": "moon",
@@ -372,6 +805,12 @@ impl PyConstraint {
         if let Some(max) = max_angle {
             json_obj["max_angle"] = serde_json::json!(max);
         }
+        if limb_relative {
+            json_obj["limb_relative"] = serde_json::json!(true);
+        }
+        if !config.schedule.is_empty() {
+            json_obj["schedule"] = serde_json::json!(config.schedule);
+        }
         let config_json = json_obj.to_string();
 
         Ok(PyConstraint {
@@ -410,41 +849,40 @@ impl PyConstraint {
     ///     max_angle (float, optional): Maximum allowed angular separation from Earth limb (degrees)
     ///     include_refraction (bool, optional): Include atmospheric refraction correction for ground observers (default: False)
     ///     horizon_dip (bool, optional): Include geometric horizon dip correction for ground observers (default: False)
+    ///     uncertainty_sigma (float, optional): Widen the margin by this many standard deviations of the
+    ///         ephemeris position uncertainty, for ephemerides that carry a covariance (default: 0)
+    ///     schedule (list[dict], optional): Periods overriding the limits, each with
+    ///         optional ``begin``, ``end`` (``[begin, end)``), ``min_angle`` and
+    ///         ``max_angle``. The first period containing a time applies.
     ///
     /// Returns:
     ///     Constraint: A new constraint object
-    #[pyo3(signature=(min_angle, max_angle=None, include_refraction=false, horizon_dip=false))]
+    #[pyo3(signature=(min_angle, max_angle=None, include_refraction=false, horizon_dip=false, uncertainty_sigma=0.0, schedule=None))]
     #[staticmethod]
     fn earth_limb(
         min_angle: f64,
         max_angle: Option<f64>,
         include_refraction: bool,
         horizon_dip: bool,
+        uncertainty_sigma: f64,
+        schedule: Option<&Bound<PyAny>>,
     ) -> PyResult<Self> {
-        if !(0.0..=180.0).contains(&min_angle) {
+        validate_angle_limits(min_angle, max_angle)
+            .map_err(pyo3::exceptions::PyValueError::new_err)?;
+
+        if uncertainty_sigma < 0.0 || !uncertainty_sigma.is_finite() {
             return Err(pyo3::exceptions::PyValueError::new_err(
-                "min_angle must be between 0 and 180 degrees",
+                "uncertainty_sigma must be a non-negative number",
             ));
         }
 
-        if let Some(max) = max_angle {
-            if !(0.0..=180.0).contains(&max) {
-                return Err(pyo3::exceptions::PyValueError::new_err(
-                    "max_angle must be between 0 and 180 degrees",
-                ));
-            }
-            if max <= min_angle {
-                return Err(pyo3::exceptions::PyValueError::new_err(
-                    "max_angle must be greater than min_angle",
-                ));
-            }
-        }
-
         let config = EarthLimbConfig {
             min_angle,
             max_angle,
             include_refraction,
             horizon_dip,
+            uncertainty_sigma,
+            schedule: extract_schedule(schedule, min_angle, max_angle)?,
         };
         let mut json_obj = serde_json::json!({
             "type": "earth_limb",
@@ -455,6 +893,12 @@ impl PyConstraint {
             json_obj["max_angle"] = serde_json::json!(max);
         }
         json_obj["horizon_dip"] = serde_json::json!(horizon_dip);
+        if uncertainty_sigma > 0.0 {
+            json_obj["uncertainty_sigma"] = serde_json::json!(uncertainty_sigma);
+        }
+        if !config.schedule.is_empty() {
+            json_obj["schedule"] = serde_json::json!(config.schedule);
+        }
         let config_json = json_obj.to_string();
 
         Ok(PyConstraint {
@@ -463,12 +907,49 @@ impl PyConstraint {
         })
     }
 
+    /// Create an Earth occultation constraint
+    ///
+    /// Violated while the target is geometrically hidden behind the Earth as
+    /// seen from the observer: the line of sight passes within
+    /// `grazing_altitude` km of the surface. Unlike `earth_limb` there is no
+    /// pointing margin. Use `occultation_events` for the ingress and egress
+    /// times between ephemeris samples.
+    ///
+    /// Args:
+    ///     grazing_altitude (float): Height above the surface, in km, below which the
+    ///         line of sight counts as blocked, e.g. for the atmosphere (default: 0.0)
+    ///
+    /// Returns:
+    ///     Constraint: A new constraint object
+    #[pyo3(signature=(grazing_altitude=0.0))]
+    #[staticmethod]
+    fn earth_occultation(grazing_altitude: f64) -> PyResult<Self> {
+        let config = EarthOccultationConfig { grazing_altitude };
+        config
+            .validate()
+            .map_err(pyo3::exceptions::PyValueError::new_err)?;
+        let config_json = serde_json::json!({
+            "type": "earth_occultation",
+            "grazing_altitude": grazing_altitude
+        })
+        .to_string();
+
+        Ok(PyConstraint {
+            evaluator: config.to_evaluator(),
+            config_json,
+        })
+    }
+
     /// Create a Daytime constraint
     ///
     /// This constraint prevents observations during daytime hours.
     ///
     /// Args:
     ///     twilight (str, optional): Twilight definition - "civil", "nautical", "astronomical", or "none" (default: "civil")
+    ///     mode (str, optional): What counts as daytime (default: "observer")
+    ///     latitude (float, optional): Ground site latitude in degrees, for mode="ground_site_night"
+    ///     longitude (float, optional): Ground site longitude in degrees, for mode="ground_site_night"
+    ///     height (float, optional): Ground site height in metres (default 0)
     ///
     /// Returns:
     ///     Constraint: A new constraint object
@@ -478,9 +959,24 @@ impl PyConstraint {
     ///     - "nautical": Nautical twilight (-12° below horizon)
     ///     - "astronomical": Astronomical twilight (-18° below horizon)
     ///     - "none": Strict daytime only (Sun above horizon)
-    #[pyo3(signature=(twilight="civil"))]
+    ///
+    /// Modes:
+    ///     - "observer": Sun above the twilight limit at the observer (ground observers)
+    ///     - "observer_sunlit": Observer outside the Earth's umbra (spacecraft in sunlight);
+    ///       twilight is ignored
+    ///     - "ground_site_night": Sun above the twilight limit at the given ground site,
+    ///       i.e. observing only while the site is dark
+    ///     - "nadir_scene_daylit": Sun above the twilight limit at the ground point
+    ///       directly below the observer
+    #[pyo3(signature=(twilight="civil", *, mode="observer", latitude=None, longitude=None, height=None))]
     #[staticmethod]
-    fn daytime(twilight: &str) -> PyResult<Self> {
+    fn daytime(
+        twilight: &str,
+        mode: &str,
+        latitude: Option<f64>,
+        longitude: Option<f64>,
+        height: Option<f64>,
+    ) -> PyResult<Self> {
         let twilight_type = match twilight.to_lowercase().as_str() {
             "civil" => TwilightType::Civil,
             "nautical" => TwilightType::Nautical,
@@ -492,27 +988,41 @@ impl PyConstraint {
                 ));
             }
         };
+        let mode = DaytimeMode::parse(mode).ok_or_else(|| {
+            pyo3::exceptions::PyValueError::new_err(
+                "mode must be one of: 'observer', 'observer_sunlit', 'ground_site_night', 'nadir_scene_daylit'",
+            )
+        })?;
 
         let config = DaytimeConfig {
             twilight: twilight_type,
+            mode,
+            latitude,
+            longitude,
+            height,
         };
+        config
+            .validate()
+            .map_err(pyo3::exceptions::PyValueError::new_err)?;
 
-        let twilight_str = match config.twilight {
-            TwilightType::Civil => "civil",
-            TwilightType::Nautical => "nautical",
-            TwilightType::Astronomical => "astronomical",
-            TwilightType::None => "none",
-        };
-
-        let config_json = serde_json::json!({
+        let mut json_obj = serde_json::json!({
             "type": "daytime",
-            "twilight": twilight_str
-        })
-        .to_string();
+            "twilight": config.twilight.as_str(),
+            "mode": config.mode.as_str(),
+        });
+        if let Some(latitude) = latitude {
+            json_obj["latitude"] = serde_json::json!(latitude);
+        }
+        if let Some(longitude) = longitude {
+            json_obj["longitude"] = serde_json::json!(longitude);
+        }
+        if let Some(height) = height {
+            json_obj["height"] = serde_json::json!(height);
+        }
 
         Ok(PyConstraint {
             evaluator: config.to_evaluator(),
-            config_json,
+            config_json: json_obj.to_string(),
         })
     }
 
@@ -533,29 +1043,13 @@ impl PyConstraint {
     #[pyo3(signature=(max_airmass, min_airmass=None))]
     #[staticmethod]
     fn airmass(max_airmass: f64, min_airmass: Option<f64>) -> PyResult<Self> {
-        if max_airmass <= 1.0 {
-            return Err(pyo3::exceptions::PyValueError::new_err(
-                "max_airmass must be greater than 1.0",
-            ));
-        }
-
-        if let Some(min) = min_airmass {
-            if min <= 1.0 {
-                return Err(pyo3::exceptions::PyValueError::new_err(
-                    "min_airmass must be greater than 1.0",
-                ));
-            }
-            if min >= max_airmass {
-                return Err(pyo3::exceptions::PyValueError::new_err(
-                    "min_airmass must be less than max_airmass",
-                ));
-            }
-        }
-
         let config = AirmassConfig {
             max_airmass,
             min_airmass,
         };
+        config
+            .validate()
+            .map_err(pyo3::exceptions::PyValueError::new_err)?;
 
         let mut json_obj = serde_json::json!({
             "type": "airmass",
@@ -689,22 +1183,19 @@ impl PyConstraint {
     /// that increases radiation exposure for satellites.
     ///
     /// Args:
-    ///     polygon (list of tuples): List of (longitude, latitude) pairs defining the SAA region boundary
+    ///     polygon (list of tuples): List of (longitude, latitude) pairs defining the SAA region boundary.
+    ///         Edges are great-circle arcs, so the region may cross the antimeridian,
+    ///         but it must fit within a hemisphere.
     ///
     /// Returns:
     ///     Constraint: A new constraint object
+    ///
+    /// Raises:
+    ///     ValueError: If the polygon has fewer than 3 vertices or does not fit within a hemisphere
     #[pyo3(signature=(polygon))]
     #[staticmethod]
     fn saa(polygon: Vec<(f64, f64)>) -> PyResult<Self> {
-        if polygon.len() < 3 {
-            return Err(pyo3::exceptions::PyValueError::new_err(
-                "Polygon must have at least 3 vertices",
-            ));
-        }
-
-        let config = SAAConfig {
-            polygon: polygon.clone(),
-        };
+        let config = SAAConfig::new(&polygon)?;
         let config_json = serde_json::json!({
             "type": "saa",
             "polygon": polygon
@@ -717,6 +1208,217 @@ impl PyConstraint {
         })
     }
 
+    /// Create a sky avoidance region constraint
+    ///
+    /// Violated while the target lies inside a polygon on the sky, such as
+    /// the Galactic plane or a bright nebula.
+    ///
+    /// Args:
+    ///     polygon (list of tuples): List of (RA, Dec) pairs in degrees bounding the region.
+    ///         Edges are great-circle arcs, so the region may cross RA 0° or enclose a
+    ///         celestial pole, but it must fit within a hemisphere.
+    ///
+    /// Returns:
+    ///     Constraint: A new constraint object
+    ///
+    /// Raises:
+    ///     ValueError: If the polygon has fewer than 3 vertices or does not fit within a hemisphere
+    #[pyo3(signature=(polygon))]
+    #[staticmethod]
+    fn sky_region(polygon: Vec<(f64, f64)>) -> PyResult<Self> {
+        let config = SkyRegionConfig::new(&polygon)?;
+        let config_json = serde_json::json!({
+            "type": "sky_region",
+            "polygon": polygon
+        })
+        .to_string();
+
+        Ok(PyConstraint {
+            evaluator: config.to_evaluator(),
+            config_json,
+        })
+    }
+
+    /// Create a geomagnetic cutoff rigidity constraint
+    ///
+    /// Violated while the spacecraft's vertical cutoff rigidity, from a
+    /// centred IGRF dipole, is below `min_rigidity`. Low rigidities occur at
+    /// high geomagnetic latitudes, where solar energetic particles and cosmic
+    /// rays reach the spacecraft.
+    ///
+    /// Args:
+    ///     min_rigidity (float): Minimum vertical cutoff rigidity in GV
+    ///
+    /// Returns:
+    ///     Constraint: A new constraint object
+    #[pyo3(signature=(min_rigidity))]
+    #[staticmethod]
+    fn cutoff_rigidity(min_rigidity: f64) -> PyResult<Self> {
+        let config = CutoffRigidityConfig { min_rigidity };
+        config
+            .validate()
+            .map_err(pyo3::exceptions::PyValueError::new_err)?;
+        let config_json = serde_json::json!({
+            "type": "cutoff_rigidity",
+            "min_rigidity": min_rigidity
+        })
+        .to_string();
+
+        Ok(PyConstraint {
+            evaluator: config.to_evaluator(),
+            config_json,
+        })
+    }
+
+    /// Create a geomagnetic activity constraint
+    ///
+    /// Violated while the 3-hourly planetary Kp index from the Celestrak
+    /// space-weather data exceeds `max_kp`. Times without a Kp value, such as
+    /// predicted days or dates outside the data, are not violated.
+    ///
+    /// Args:
+    ///     max_kp (float): Maximum allowed Kp index (0-9)
+    ///
+    /// Returns:
+    ///     Constraint: A new constraint object
+    #[pyo3(signature=(max_kp))]
+    #[staticmethod]
+    fn geomagnetic_activity(max_kp: f64) -> PyResult<Self> {
+        let config = GeomagneticActivityConfig { max_kp };
+        config
+            .validate()
+            .map_err(pyo3::exceptions::PyValueError::new_err)?;
+        let config_json = serde_json::json!({
+            "type": "geomagnetic_activity",
+            "max_kp": max_kp
+        })
+        .to_string();
+
+        Ok(PyConstraint {
+            evaluator: config.to_evaluator(),
+            config_json,
+        })
+    }
+
+    /// Create a station-keeping box constraint
+    ///
+    /// Violated while the ephemeris' sub-satellite point (geocentric longitude
+    /// and latitude from the ITRS position) is outside the deadband box around
+    /// (`longitude`, `latitude`). Use it to monitor a geostationary satellite
+    /// against its slot with an operator OEM or a TLE.
+    ///
+    /// Args:
+    ///     longitude (float): Box centre longitude in degrees, east positive (-180 to 360)
+    ///     longitude_tolerance (float): Half-width of the box in longitude, degrees (default: 0.1)
+    ///     latitude_tolerance (float): Half-width of the box in latitude, degrees (default: 0.1)
+    ///     latitude (float): Box centre latitude in degrees (default: 0.0)
+    ///
+    /// Returns:
+    ///     Constraint: A new constraint object
+    #[pyo3(signature=(longitude, longitude_tolerance=0.1, latitude_tolerance=0.1, latitude=0.0))]
+    #[staticmethod]
+    fn station_keeping(
+        longitude: f64,
+        longitude_tolerance: f64,
+        latitude_tolerance: f64,
+        latitude: f64,
+    ) -> PyResult<Self> {
+        let config = StationKeepingConfig {
+            longitude,
+            latitude,
+            longitude_tolerance,
+            latitude_tolerance,
+        };
+        config
+            .validate()
+            .map_err(pyo3::exceptions::PyValueError::new_err)?;
+        let config_json = serde_json::json!({
+            "type": "station_keeping",
+            "longitude": longitude,
+            "latitude": latitude,
+            "longitude_tolerance": longitude_tolerance,
+            "latitude_tolerance": latitude_tolerance
+        })
+        .to_string();
+
+        Ok(PyConstraint {
+            evaluator: config.to_evaluator(),
+            config_json,
+        })
+    }
+
+    /// Create a ground-target illumination constraint
+    ///
+    /// For Earth-observation tasking of a scene at (`latitude`, `longitude`).
+    /// Violated while the spacecraft is below `min_elevation` as seen from the
+    /// scene, or beyond `max_off_nadir` from the spacecraft's nadir, and while
+    /// the Sun's elevation at the scene is outside the
+    /// [`min_sun_elevation`, `max_sun_elevation`] range. The default keeps the
+    /// scene on the day side of the terminator; set `max_sun_elevation` (and
+    /// `min_sun_elevation=None`) for night imaging. The check does not depend
+    /// on the RA/Dec target.
+    ///
+    /// Args:
+    ///     latitude (float): Geodetic latitude of the scene in degrees
+    ///     longitude (float): Longitude of the scene in degrees, east positive
+    ///     min_sun_elevation (float, optional): Minimum Sun elevation at the scene in
+    ///         degrees (default: 0.0)
+    ///     max_sun_elevation (float, optional): Maximum Sun elevation at the scene in degrees
+    ///     min_elevation (float): Minimum spacecraft elevation seen from the scene in
+    ///         degrees (default: 0.0)
+    ///     max_off_nadir (float, optional): Maximum off-nadir angle to the scene in degrees
+    ///     height (float): Scene height above the WGS84 ellipsoid in metres (default: 0.0)
+    ///
+    /// Returns:
+    ///     Constraint: A new constraint object
+    #[pyo3(signature=(latitude, longitude, min_sun_elevation=Some(0.0), max_sun_elevation=None, min_elevation=0.0, max_off_nadir=None, height=0.0))]
+    #[staticmethod]
+    fn ground_target_illumination(
+        latitude: f64,
+        longitude: f64,
+        min_sun_elevation: Option<f64>,
+        max_sun_elevation: Option<f64>,
+        min_elevation: f64,
+        max_off_nadir: Option<f64>,
+        height: f64,
+    ) -> PyResult<Self> {
+        let config = GroundTargetIlluminationConfig {
+            latitude,
+            longitude,
+            height,
+            min_sun_elevation,
+            max_sun_elevation,
+            min_elevation,
+            max_off_nadir,
+        };
+        config
+            .validate()
+            .map_err(pyo3::exceptions::PyValueError::new_err)?;
+
+        let mut json_obj = serde_json::json!({
+            "type": "ground_target_illumination",
+            "latitude": latitude,
+            "longitude": longitude,
+            "height": height,
+            "min_elevation": min_elevation,
+        });
+        if let Some(min) = min_sun_elevation {
+            json_obj["min_sun_elevation"] = serde_json::json!(min);
+        }
+        if let Some(max) = max_sun_elevation {
+            json_obj["max_sun_elevation"] = serde_json::json!(max);
+        }
+        if let Some(max) = max_off_nadir {
+            json_obj["max_off_nadir"] = serde_json::json!(max);
+        }
+        let config_json = json_obj.to_string();
+
+        Ok(PyConstraint {
+            evaluator: config.to_evaluator(),
+            config_json,
+        })
+    }
+
     /// Create an Orbit RAM direction constraint
     ///
     /// Ensures target maintains minimum angular separation from the spacecraft's
@@ -732,24 +1434,8 @@ impl PyConstraint {
     #[pyo3(signature=(min_angle, max_angle=None))]
     #[staticmethod]
     fn orbit_ram(min_angle: f64, max_angle: Option<f64>) -> PyResult<Self> {
-        if !(0.0..=180.0).contains(&min_angle) {
-            return Err(pyo3::exceptions::PyValueError::new_err(
-                "min_angle must be between 0 and 180 degrees",
-            ));
-        }
-
-        if let Some(max) = max_angle {
-            if !(0.0..=180.0).contains(&max) {
-                return Err(pyo3::exceptions::PyValueError::new_err(
-                    "max_angle must be between 0 and 180 degrees",
-                ));
-            }
-            if max <= min_angle {
-                return Err(pyo3::exceptions::PyValueError::new_err(
-                    "max_angle must be greater than min_angle",
-                ));
-            }
-        }
+        validate_angle_limits(min_angle, max_angle)
+            .map_err(pyo3::exceptions::PyValueError::new_err)?;
 
         let config = OrbitRamConfig {
             min_angle,
@@ -791,24 +1477,8 @@ impl PyConstraint {
         max_angle: Option<f64>,
         earth_limb_pole: Option<bool>,
     ) -> PyResult<Self> {
-        if !(0.0..=180.0).contains(&min_angle) {
-            return Err(pyo3::exceptions::PyValueError::new_err(
-                "min_angle must be between 0 and 180 degrees",
-            ));
-        }
-
-        if let Some(max) = max_angle {
-            if !(0.0..=180.0).contains(&max) {
-                return Err(pyo3::exceptions::PyValueError::new_err(
-                    "max_angle must be between 0 and 180 degrees",
-                ));
-            }
-            if max <= min_angle {
-                return Err(pyo3::exceptions::PyValueError::new_err(
-                    "max_angle must be greater than min_angle",
-                ));
-            }
-        }
+        validate_angle_limits(min_angle, max_angle)
+            .map_err(pyo3::exceptions::PyValueError::new_err)?;
 
         let earth_limb_pole = earth_limb_pole.unwrap_or(false);
 
@@ -943,13 +1613,140 @@ impl PyConstraint {
         })
     }
 
+    /// Create an antenna gimbal (keyhole and slew-rate) constraint
+    ///
+    /// Models a ground antenna on a two-axis mount. Violated while the target
+    /// is inside the keyhole cone or while tracking it needs a faster azimuth
+    /// or elevation slew than allowed. Rates are finite differences over
+    /// neighbouring ephemeris samples; with `evaluate_moving_body` they follow
+    /// the moving target, so a spacecraft pass can be checked directly.
+    ///
+    /// Args:
+    ///     keyhole_radius (float, optional): Half-angle of the keyhole cone in degrees (0-90)
+    ///     keyhole_azimuth (float): Azimuth of the keyhole centre in degrees (default: 0.0)
+    ///     keyhole_elevation (float): Elevation of the keyhole centre in degrees
+    ///         (default: 90.0, the zenith keyhole of an az/el mount)
+    ///     max_azimuth_rate (float, optional): Maximum azimuth rate in degrees per second
+    ///     max_elevation_rate (float, optional): Maximum elevation rate in degrees per second
+    ///
+    /// Returns:
+    ///     Constraint: A new constraint object
+    #[pyo3(signature=(keyhole_radius=None, keyhole_azimuth=0.0, keyhole_elevation=90.0, max_azimuth_rate=None, max_elevation_rate=None))]
+    #[staticmethod]
+    fn gimbal(
+        keyhole_radius: Option<f64>,
+        keyhole_azimuth: f64,
+        keyhole_elevation: f64,
+        max_azimuth_rate: Option<f64>,
+        max_elevation_rate: Option<f64>,
+    ) -> PyResult<Self> {
+        let config = GimbalConfig {
+            keyhole_radius,
+            keyhole_azimuth,
+            keyhole_elevation,
+            max_azimuth_rate,
+            max_elevation_rate,
+        };
+        config
+            .validate()
+            .map_err(pyo3::exceptions::PyValueError::new_err)?;
+
+        let mut json_obj = serde_json::json!({
+            "type": "gimbal",
+            "keyhole_azimuth": keyhole_azimuth,
+            "keyhole_elevation": keyhole_elevation,
+        });
+        if let Some(radius) = keyhole_radius {
+            json_obj["keyhole_radius"] = serde_json::json!(radius);
+        }
+        if let Some(rate) = max_azimuth_rate {
+            json_obj["max_azimuth_rate"] = serde_json::json!(rate);
+        }
+        if let Some(rate) = max_elevation_rate {
+            json_obj["max_elevation_rate"] = serde_json::json!(rate);
+        }
+        let config_json = json_obj.to_string();
+
+        Ok(PyConstraint {
+            evaluator: config.to_evaluator(),
+            config_json,
+        })
+    }
+
+    /// Create a ground-site visibility constraint
+    ///
+    /// Combines the conditions for observing from a ground telescope. Violated
+    /// while the target is below `min_altitude` or the site's horizon mask,
+    /// while the Sun is above the twilight limit, and optionally while the
+    /// target's airmass exceeds `max_airmass` or the Moon is above
+    /// `max_moon_altitude`. Sun and Moon altitudes are topocentric. The
+    /// observer must be a `GroundEphemeris`.
+    ///
+    /// Args:
+    ///     min_altitude (float): Minimum target altitude in degrees (default: 0.0)
+    ///     twilight (str): Sun altitude limit - "civil", "nautical", "astronomical",
+    ///         or "none" (default: "astronomical")
+    ///     max_airmass (float, optional): Maximum target airmass
+    ///     max_moon_altitude (float, optional): Maximum Moon altitude in degrees
+    ///
+    /// Returns:
+    ///     Constraint: A new constraint object
+    #[pyo3(signature=(min_altitude=0.0, twilight="astronomical", max_airmass=None, max_moon_altitude=None))]
+    #[staticmethod]
+    fn ground_visibility(
+        min_altitude: f64,
+        twilight: &str,
+        max_airmass: Option<f64>,
+        max_moon_altitude: Option<f64>,
+    ) -> PyResult<Self> {
+        let twilight = match twilight.to_lowercase().as_str() {
+            "civil" => TwilightType::Civil,
+            "nautical" => TwilightType::Nautical,
+            "astronomical" => TwilightType::Astronomical,
+            "none" => TwilightType::None,
+            _ => {
+                return Err(pyo3::exceptions::PyValueError::new_err(
+                    "twilight must be one of: 'civil', 'nautical', 'astronomical', 'none'",
+                ));
+            }
+        };
+        let config = GroundVisibilityConfig {
+            min_altitude,
+            twilight,
+            max_airmass,
+            max_moon_altitude,
+        };
+        config
+            .validate()
+            .map_err(pyo3::exceptions::PyValueError::new_err)?;
+
+        let mut json_obj = serde_json::json!({
+            "type": "ground_visibility",
+            "min_altitude": min_altitude,
+            "twilight": config.twilight.as_str(),
+        });
+        if let Some(airmass) = max_airmass {
+            json_obj["max_airmass"] = serde_json::json!(airmass);
+        }
+        if let Some(moon_alt) = max_moon_altitude {
+            json_obj["max_moon_altitude"] = serde_json::json!(moon_alt);
+        }
+        let config_json = json_obj.to_string();
+
+        Ok(PyConstraint {
+            evaluator: config.to_evaluator(),
+            config_json,
+        })
+    }
+
     /// Create a generic solar system body avoidance constraint
     ///
     /// The exclusion zone can be defined as a circular angular separation (min_angle) or as
     /// a polygon in the instrument frame (fov_polygon). These are mutually exclusive.
     ///
     /// Args:
-    ///     body (str): Body identifier - NAIF ID or name (e.g., "Jupiter", "499", "Mars")
+    ///     body (str): Body identifier - NAIF ID or name (e.g., "Jupiter", "499", "Mars"),
+    ///         or a pseudo-body such as "EMB", "SEL2" or "EML1"
     ///     min_angle (float, optional): Minimum allowed angular separation in degrees (circle mode)
     ///     max_angle (float, optional): Maximum allowed angular separation in degrees (circle mode only)
     ///     fov_polygon (list[tuple[float, float]], optional): Polygon FoV as (u_deg, v_deg) vertices
@@ -958,21 +1755,34 @@ impl PyConstraint {
     ///     roll_deg (float, optional): Position angle of instrument +v from north (degrees east of
     ///         north). Only used with fov_polygon. None (default) sweeps all roll angles: violated
     ///         only when every roll has the body inside the polygon.
+    ///     limb_relative (bool, optional): Apply the angle limits to the separation from the
+    ///         body's limb, using its radius from ``get_body_radii``. Circle mode only.
+    ///         Default False.
+    ///     schedule (list[dict], optional): Periods overriding the limits, each with
+    ///         optional ``begin``, ``end`` (``[begin, end)``), ``min_angle`` and
+    ///         ``max_angle``. Circle mode only.
+    ///     source (str, optional): Body position source: "spice" (default), "horizons" or "auto"
     ///
     /// Returns:
     ///     Constraint: A new constraint object
     ///
     /// Note:
-    ///     Supported bodies depend on the ephemeris type and loaded kernels.
+    ///     The body is looked up during evaluation on the ephemeris time grid and
+    ///     kept for reuse with the same ephemeris. Supported bodies depend on the
+    ///     source and the loaded kernels.
     ///     Common bodies: Sun (10), Moon (301), planets (199, 299, 399, 499, 599, 699, 799, 899)
-    #[pyo3(signature=(body, min_angle=None, max_angle=None, fov_polygon=None, roll_deg=None))]
+    #[pyo3(signature=(body, min_angle=None, max_angle=None, fov_polygon=None, roll_deg=None, limb_relative=false, schedule=None, source=None))]
     #[staticmethod]
+    #[allow(clippy::too_many_arguments)]
     fn body_proximity(
         body: String,
         min_angle: Option<f64>,
         max_angle: Option<f64>,
         fov_polygon: Option<Vec<(f64, f64)>>,
         roll_deg: Option<f64>,
+        limb_relative: bool,
+        schedule: Option<&Bound<PyAny>>,
+        source: Option<String>,
     ) -> PyResult<Self> {
         let has_angle = min_angle.is_some();
         let has_polygon = fov_polygon.is_some();
@@ -1047,7 +1857,13 @@ impl PyConstraint {
             max_angle,
             fov_polygon: poly_array.clone(),
             roll_deg,
+            limb_relative,
+            schedule: extract_schedule(schedule, min_angle.unwrap_or(0.0), max_angle)?,
+            source,
         };
+        config
+            .validate()
+            .map_err(pyo3::exceptions::PyValueError::new_err)?;
 
         let mut json_obj = serde_json::json!({ "type": "body", "body": body });
         if let Some(min) = min_angle {
@@ -1062,6 +1878,67 @@ impl PyConstraint {
         if let Some(r) = roll_deg {
             json_obj["roll_deg"] = serde_json::json!(r);
         }
+        if limb_relative {
+            json_obj["limb_relative"] = serde_json::json!(true);
+        }
+        if !config.schedule.is_empty() {
+            json_obj["schedule"] = serde_json::json!(config.schedule);
+        }
+        if let Some(ref source) = config.source {
+            json_obj["source"] = serde_json::json!(source);
+        }
+        let config_json = json_obj.to_string();
+
+        Ok(PyConstraint {
+            evaluator: config.to_evaluator(),
+            config_json,
+        })
+    }
+
+    /// Create an avoidance constraint for several planets at once
+    ///
+    /// Each planet's position is computed once per evaluation and the target
+    /// must stay at least the planet's minimum separation away from all of them.
+    ///
+    /// Args:
+    ///     min_angle (float): Minimum allowed angular separation in degrees, for all planets
+    ///     planets (list[str], optional): Body names or NAIF IDs to avoid
+    ///         (default: Venus, Mars, Jupiter, Saturn)
+    ///     min_angles (dict[str, float], optional): Per-planet minimum separations in degrees,
+    ///         overriding min_angle for the planets listed
+    ///     source (str, optional): Body position source: "spice" (default), "horizons" or "auto"
+    ///
+    /// Returns:
+    ///     Constraint: A new constraint object
+    #[pyo3(signature=(min_angle, planets=None, min_angles=None, source=None))]
+    #[staticmethod]
+    fn planets_proximity(
+        min_angle: f64,
+        planets: Option<Vec<String>>,
+        min_angles: Option<BTreeMap<String, f64>>,
+        source: Option<String>,
+    ) -> PyResult<Self> {
+        let config = PlanetsProximityConfig {
+            min_angle,
+            planets: planets.unwrap_or_else(default_planets),
+            min_angles,
+            source,
+        };
+        config
+            .validate()
+            .map_err(pyo3::exceptions::PyValueError::new_err)?;
+
+        let mut json_obj = serde_json::json!({
+            "type": "planets",
+            "min_angle": min_angle,
+            "planets": config.planets,
+        });
+        if let Some(ref angles) = config.min_angles {
+            json_obj["min_angles"] = serde_json::json!(angles);
+        }
+        if let Some(ref source) = config.source {
+            json_obj["source"] = serde_json::json!(source);
+        }
         let config_json = json_obj.to_string();
 
         Ok(PyConstraint {
@@ -1540,15 +2417,30 @@ impl PyConstraint {
     ///         times will be evaluated (must exist in the ephemeris).
     ///     indices (int or list[int], optional): Specific time index/indices to evaluate.
     ///         Can be a single index or list of indices into the ephemeris timestamp array.
+    ///     target_roll (float, optional): Fixed spacecraft roll angle in degrees
+    ///     between (tuple[datetime, datetime], optional): Evaluate only the ephemeris
+    ///         timestamps from `t0` to `t1` inclusive.
+    ///     at_times (datetime or list[datetime], optional): Times to evaluate. Times on
+    ///         the ephemeris grid use the ephemeris directly; otherwise the observer
+    ///         state is Hermite-interpolated onto the times, which must be strictly
+    ///         increasing and within the ephemeris range.
+    ///     storage (str, optional): How the result keeps its times: "full"
+    ///         (default), "compressed" to store an evenly spaced grid as
+    ///         (start, step, count), or "windows" to keep only the violation and
+    ///         visibility windows and drop the per-time arrays.
+    ///     prescreen (int, optional): Evaluate every `prescreen`-th time step first
+    ///         and refine step by step only between samples whose states differ.
+    ///         Much faster for slowly varying constraints, but windows shorter than
+    ///         `prescreen` steps that fall between two samples are missed.
     ///
     /// Returns:
     ///     ConstraintResult: Result containing violation windows
     ///
     /// Note:
-    ///     Only one of `times` or `indices` should be provided. If neither is provided,
-    ///     all ephemeris times are evaluated.
+    ///     Only one of `times`, `indices`, `between` or `at_times` may be provided. If
+    ///     none is provided, all ephemeris times are evaluated.
     #[allow(clippy::too_many_arguments)]
-    #[pyo3(signature = (ephemeris, target_ra, target_dec, times=None, indices=None, target_roll=None))]
+    #[pyo3(signature = (ephemeris, target_ra, target_dec, times=None, indices=None, target_roll=None, *, between=None, at_times=None, storage="full", prescreen=None))]
     fn evaluate(
         &self,
         py: Python,
@@ -1558,78 +2450,97 @@ impl PyConstraint {
         times: Option<&Bound<PyAny>>,
         indices: Option<&Bound<PyAny>>,
         target_roll: Option<f64>,
+        between: Option<&Bound<PyAny>>,
+        at_times: Option<&Bound<PyAny>>,
+        storage: &str,
+        prescreen: Option<usize>,
     ) -> PyResult<ConstraintResult> {
+        let storage = ResultStorage::parse(storage)?;
+        check_prescreen(prescreen)?;
         // Parse time filtering options
         let bound = ephemeris.bind(py);
-        let time_indices = if let Some(times_arg) = times {
-            if indices.is_some() {
-                return Err(pyo3::exceptions::PyValueError::new_err(
-                    "Cannot specify both 'times' and 'indices' parameters",
-                ));
-            }
-            Some(self.parse_times_to_indices(bound, times_arg)?)
-        } else if let Some(indices_arg) = indices {
-            Some(self.parse_indices(indices_arg)?)
-        } else {
-            None
-        };
+        let selection = self.select_times(bound, times, indices, between, at_times)?;
 
         self.with_effective_evaluator(target_roll, |evaluator| {
-            if let Ok(ephem) = bound.extract::<PyRef<TLEEphemeris>>() {
-                return self.eval_with_ephemeris(
-                    evaluator,
-                    &*ephem,
-                    target_ra,
-                    target_dec,
-                    time_indices.clone(),
-                );
-            }
-            if let Ok(ephem) = bound.extract::<PyRef<SPICEEphemeris>>() {
-                return self.eval_with_ephemeris(
-                    evaluator,
-                    &*ephem,
-                    target_ra,
-                    target_dec,
-                    time_indices.clone(),
-                );
-            }
-            if let Ok(ephem) = bound.extract::<PyRef<GroundEphemeris>>() {
-                return self.eval_with_ephemeris(
+            Self::with_selected_ephemeris(bound, &selection, |ephem, time_indices| {
+                self.eval_with_ephemeris(
                     evaluator,
-                    &*ephem,
+                    ephem,
                     target_ra,
                     target_dec,
-                    time_indices.clone(),
-                );
-            }
-            if let Ok(ephem) = bound.extract::<PyRef<OEMEphemeris>>() {
-                return self.eval_with_ephemeris(
-                    evaluator,
-                    &*ephem,
-                    target_ra,
-                    target_dec,
-                    time_indices.clone(),
-                );
-            }
-            if let Ok(ephem) = bound.extract::<PyRef<FileEphemeris>>() {
-                return self.eval_with_ephemeris(
-                    evaluator,
-                    &*ephem,
-                    target_ra,
-                    target_dec,
-                    time_indices.clone(),
-                );
-            }
+                    time_indices,
+                    storage,
+                    prescreen,
+                )
+            })
+        })
+    }
+
+    /// Continuous quantity behind the constraint for one target
+    ///
+    /// Returns the value each time step is thresholded against, which shows
+    /// how close a window is to opening or closing: separation in degrees
+    /// from the body limb for Sun, Moon and body proximity, degrees above the
+    /// Earth limb, altitude in degrees for alt/az, airmass, the Moon's
+    /// illuminated fraction for moon phase, the visible fraction of the solar
+    /// disk for eclipse, Sun altitude (or sunlit fraction) for daytime, and
+    /// cutoff rigidity in GV.
+    ///
+    /// Args:
+    ///     ephemeris: One of TLEEphemeris, SPICEEphemeris, GroundEphemeris, or OEMEphemeris
+    ///     target_ra (float): Target right ascension in degrees (ICRS/J2000)
+    ///     target_dec (float): Target declination in degrees (ICRS/J2000)
+    ///     times, indices, between, at_times: Time selection, as for `evaluate`
+    ///
+    /// Returns:
+    ///     numpy.ndarray: 1D float array with one value per selected time
+    ///
+    /// Raises:
+    ///     ValueError: If the constraint has no single underlying quantity
+    ///         (logical combinations, polygon fields of view, SAA, ...)
+    #[allow(clippy::too_many_arguments)]
+    #[pyo3(signature = (ephemeris, target_ra, target_dec, times=None, indices=None, *, between=None, at_times=None))]
+    fn metric(
+        &self,
+        py: Python,
+        ephemeris: Py<PyAny>,
+        target_ra: f64,
+        target_dec: f64,
+        times: Option<&Bound<PyAny>>,
+        indices: Option<&Bound<PyAny>>,
+        between: Option<&Bound<PyAny>>,
+        at_times: Option<&Bound<PyAny>>,
+    ) -> PyResult<Py<PyAny>> {
+        use numpy::IntoPyArray;
 
-            Err(pyo3::exceptions::PyTypeError::new_err(
-                "Unsupported ephemeris type. Expected TLEEphemeris, SPICEEphemeris, GroundEphemeris, or OEMEphemeris",
+        let bound = ephemeris.bind(py);
+        let selection = self.select_times(bound, times, indices, between, at_times)?;
+        let values = Self::with_selected_ephemeris(bound, &selection, |ephem, time_indices| {
+            self.evaluator
+                .metric(ephem, target_ra, target_dec, time_indices.as_deref())
+                .map_err(PyErr::from)
+        })?
+        .ok_or_else(|| {
+            pyo3::exceptions::PyValueError::new_err(format!(
+                "{} has no single underlying metric",
+                self.evaluator.name()
             ))
-        })
+        })?;
+        Ok(ndarray::Array1::from(values).into_pyarray(py).into())
     }
 
     /// Evaluate constraint for multiple targets and return one result per target.
+    ///
+    /// `storage` and `prescreen` are as for `evaluate`; "compressed" or "windows"
+    /// keeps memory bounded for long runs over many targets.
+    ///
+    /// With `progress`, targets are evaluated in blocks with the GIL released
+    /// and `progress(done, total)` is called with the number of finished
+    /// targets after every `progress_every` blocks. Returning `False` from it
+    /// cancels the evaluation with `InterruptedError`. A `cancel` token stops
+    /// it after the current block, and Ctrl-C is honoured between blocks.
     #[allow(clippy::too_many_arguments)]
-    #[pyo3(signature = (ephemeris, target_ras, target_decs, times=None, indices=None, target_rolls=None))]
+    #[pyo3(signature = (ephemeris, target_ras, target_decs, times=None, indices=None, target_rolls=None, *, between=None, at_times=None, storage="full", prescreen=None, progress=None, progress_every=1, cancel=None))]
     fn evaluate_batch(
         &self,
         py: Python,
@@ -1639,12 +2550,22 @@ impl PyConstraint {
         times: Option<&Bound<PyAny>>,
         indices: Option<&Bound<PyAny>>,
         target_rolls: Option<Vec<f64>>,
+        between: Option<&Bound<PyAny>>,
+        at_times: Option<&Bound<PyAny>>,
+        storage: &str,
+        prescreen: Option<usize>,
+        progress: Option<&Bound<PyAny>>,
+        progress_every: usize,
+        cancel: Option<CancellationToken>,
     ) -> PyResult<Vec<ConstraintResult>> {
+        let storage = ResultStorage::parse(storage)?;
+        check_prescreen(prescreen)?;
         if target_ras.len() != target_decs.len() {
             return Err(pyo3::exceptions::PyValueError::new_err(
                 "target_ras and target_decs must have the same length",
             ));
         }
+        let mut progress = Progress::new(progress, progress_every, target_ras.len(), cancel)?;
 
         // Validate target_rolls if provided
         if let Some(ref rolls) = target_rolls {
@@ -1656,71 +2577,24 @@ impl PyConstraint {
         }
 
         let bound = ephemeris.bind(py);
-        let time_indices = if let Some(times_arg) = times {
-            if indices.is_some() {
-                return Err(pyo3::exceptions::PyValueError::new_err(
-                    "Cannot specify both 'times' and 'indices' parameters",
-                ));
-            }
-            Some(self.parse_times_to_indices(bound, times_arg)?)
-        } else if let Some(indices_arg) = indices {
-            Some(self.parse_indices(indices_arg)?)
-        } else {
-            None
-        };
+        let selection = self.select_times(bound, times, indices, between, at_times)?;
 
         // If no per-target rolls, use uniform None roll for all targets
         if target_rolls.is_none() {
             return self.with_effective_evaluator(None, |evaluator| {
-                if let Ok(ephem) = bound.extract::<PyRef<TLEEphemeris>>() {
-                    return self.eval_batch_with_ephemeris(
-                        evaluator,
-                        &*ephem,
-                        &target_ras,
-                        &target_decs,
-                        time_indices.clone(),
-                    );
-                }
-                if let Ok(ephem) = bound.extract::<PyRef<SPICEEphemeris>>() {
-                    return self.eval_batch_with_ephemeris(
-                        evaluator,
-                        &*ephem,
-                        &target_ras,
-                        &target_decs,
-                        time_indices.clone(),
-                    );
-                }
-                if let Ok(ephem) = bound.extract::<PyRef<GroundEphemeris>>() {
-                    return self.eval_batch_with_ephemeris(
-                        evaluator,
-                        &*ephem,
-                        &target_ras,
-                        &target_decs,
-                        time_indices.clone(),
-                    );
-                }
-                if let Ok(ephem) = bound.extract::<PyRef<OEMEphemeris>>() {
-                    return self.eval_batch_with_ephemeris(
-                        evaluator,
-                        &*ephem,
-                        &target_ras,
-                        &target_decs,
-                        time_indices.clone(),
-                    );
-                }
-                if let Ok(ephem) = bound.extract::<PyRef<FileEphemeris>>() {
-                    return self.eval_batch_with_ephemeris(
+                Self::with_selected_ephemeris(bound, &selection, |ephem, time_indices| {
+                    self.eval_batch_with_ephemeris(
+                        py,
                         evaluator,
-                        &*ephem,
+                        ephem,
                         &target_ras,
                         &target_decs,
-                        time_indices.clone(),
-                    );
-                }
-
-                Err(pyo3::exceptions::PyTypeError::new_err(
-                    "Unsupported ephemeris type. Expected TLEEphemeris, SPICEEphemeris, GroundEphemeris, or OEMEphemeris",
-                ))
+                        time_indices,
+                        storage,
+                        prescreen,
+                        &mut progress,
+                    )
+                })
             });
         }
 
@@ -1749,55 +2623,19 @@ impl PyConstraint {
             let group_decs: Vec<f64> = group_indices.iter().map(|&i| target_decs[i]).collect();
 
             let group_results = self.with_effective_evaluator(Some(target_roll), |evaluator| {
-                if let Ok(ephem) = bound.extract::<PyRef<TLEEphemeris>>() {
-                    return self.eval_batch_with_ephemeris(
-                        evaluator,
-                        &*ephem,
-                        &group_ras,
-                        &group_decs,
-                        time_indices.clone(),
-                    );
-                }
-                if let Ok(ephem) = bound.extract::<PyRef<SPICEEphemeris>>() {
-                    return self.eval_batch_with_ephemeris(
-                        evaluator,
-                        &*ephem,
-                        &group_ras,
-                        &group_decs,
-                        time_indices.clone(),
-                    );
-                }
-                if let Ok(ephem) = bound.extract::<PyRef<GroundEphemeris>>() {
-                    return self.eval_batch_with_ephemeris(
-                        evaluator,
-                        &*ephem,
-                        &group_ras,
-                        &group_decs,
-                        time_indices.clone(),
-                    );
-                }
-                if let Ok(ephem) = bound.extract::<PyRef<OEMEphemeris>>() {
-                    return self.eval_batch_with_ephemeris(
+                Self::with_selected_ephemeris(bound, &selection, |ephem, time_indices| {
+                    self.eval_batch_with_ephemeris(
+                        py,
                         evaluator,
-                        &*ephem,
+                        ephem,
                         &group_ras,
                         &group_decs,
-                        time_indices.clone(),
-                    );
-                }
-                if let Ok(ephem) = bound.extract::<PyRef<FileEphemeris>>() {
-                    return self.eval_batch_with_ephemeris(
-                        evaluator,
-                        &*ephem,
-                        &group_ras,
-                        &group_decs,
-                        time_indices.clone(),
-                    );
-                }
-
-                Err(pyo3::exceptions::PyTypeError::new_err(
-                    "Unsupported ephemeris type. Expected TLEEphemeris, SPICEEphemeris, GroundEphemeris, or OEMEphemeris",
-                ))
+                        time_indices,
+                        storage,
+                        prescreen,
+                        &mut progress,
+                    )
+                })
             })?;
 
             if group_results.len() != group_indices.len() {
@@ -1832,6 +2670,85 @@ impl PyConstraint {
         Ok(final_results)
     }
 
+    /// Evaluate constraint for one target against several ephemerides
+    ///
+    /// The ephemerides are evaluated in parallel, for example to find which
+    /// spacecraft or ground stations of a fleet can observe a transient.
+    ///
+    /// Args:
+    ///     ephemerides (dict or list): Ephemerides keyed by name, or a list of
+    ///         ephemerides (keyed by position)
+    ///     target_ra (float): Target right ascension in degrees (ICRS/J2000)
+    ///     target_dec (float): Target declination in degrees (ICRS/J2000)
+    ///     target_roll (float, optional): Fixed spacecraft roll angle in degrees
+    ///     between (tuple[datetime, datetime], optional): Evaluate only the timestamps
+    ///         of each ephemeris from `t0` to `t1` inclusive
+    ///     at_times (datetime or list[datetime], optional): Times to evaluate for every
+    ///         ephemeris, interpolated where off an ephemeris grid
+    ///     storage (str, optional): How each result keeps its times, as for `evaluate`
+    ///
+    /// Returns:
+    ///     dict: ConstraintResult for each key of `ephemerides`
+    #[allow(clippy::too_many_arguments)]
+    #[pyo3(signature = (ephemerides, target_ra, target_dec, target_roll=None, *, between=None, at_times=None, storage="full"))]
+    fn evaluate_many(
+        &self,
+        py: Python,
+        ephemerides: &Bound<PyAny>,
+        target_ra: f64,
+        target_dec: f64,
+        target_roll: Option<f64>,
+        between: Option<&Bound<PyAny>>,
+        at_times: Option<&Bound<PyAny>>,
+        storage: &str,
+    ) -> PyResult<Py<PyDict>> {
+        let storage = ResultStorage::parse(storage)?;
+        let (keys, objects): (Vec<Bound<PyAny>>, Vec<Bound<PyAny>>) =
+            if let Ok(dict) = ephemerides.downcast::<PyDict>() {
+                dict.iter().unzip()
+            } else {
+                ephemerides
+                    .try_iter()?
+                    .enumerate()
+                    .map(|(i, obj)| Ok((i.into_pyobject(py)?.into_any(), obj?)))
+                    .collect::<PyResult<Vec<_>>>()?
+                    .into_iter()
+                    .unzip()
+            };
+
+        let selections = objects
+            .iter()
+            .map(|obj| self.select_times(obj, None, None, between, at_times))
+            .collect::<PyResult<Vec<_>>>()?;
+
+        let results = self.with_effective_evaluator(target_roll, |evaluator| {
+            Self::with_selected_ephemerides(&objects, &selections, Vec::new(), |selected| {
+                py.detach(|| {
+                    selected
+                        .par_iter()
+                        .map(|(ephem, time_indices)| {
+                            self.eval_with_ephemeris(
+                                evaluator,
+                                *ephem,
+                                target_ra,
+                                target_dec,
+                                time_indices.clone(),
+                                storage,
+                                None,
+                            )
+                        })
+                        .collect::<PyResult<Vec<_>>>()
+                })
+            })
+        })?;
+
+        let dict = PyDict::new(py);
+        for (key, result) in keys.into_iter().zip(results) {
+            dict.set_item(key, Py::new(py, result)?)?;
+        }
+        Ok(dict.unbind())
+    }
+
     /// Check if targets are in-constraint for multiple RA/Dec positions (vectorized)
     ///
     /// This method efficiently evaluates the constraint for many target positions
@@ -1845,6 +2762,16 @@ impl PyConstraint {
     ///     times (datetime or list[datetime], optional): Specific times to evaluate
     ///     indices (int or list[int], optional): Specific time index/indices to evaluate
     ///     target_rolls (list[float], optional): Per-target spacecraft roll angles in degrees
+    ///     between (tuple[datetime, datetime], optional): Evaluate only the ephemeris
+    ///         timestamps from `t0` to `t1` inclusive
+    ///     at_times (datetime or list[datetime], optional): Times to evaluate,
+    ///         interpolating the observer state for times off the ephemeris grid
+    ///     progress (callable, optional): Called as `progress(done, total)` with the
+    ///         number of finished targets; targets are then evaluated in blocks with
+    ///         the GIL released. Returning `False` cancels with `InterruptedError`.
+    ///     progress_every (int, optional): Blocks between `progress` calls (default 1)
+    ///     cancel (CancellationToken, optional): Stops the evaluation after the
+    ///         current block once cancelled or timed out
     ///
     /// Returns:
     ///     numpy.ndarray: 2D boolean array of shape (n_targets, n_times) where True
@@ -1857,7 +2784,7 @@ impl PyConstraint {
     ///     >>> violations.shape  # (3, n_times)
     ///     >>> violations[0, :]  # Violations for first target across all times
     #[allow(clippy::too_many_arguments)]
-    #[pyo3(signature = (ephemeris, target_ras, target_decs, times=None, indices=None, target_rolls=None))]
+    #[pyo3(signature = (ephemeris, target_ras, target_decs, times=None, indices=None, target_rolls=None, *, between=None, at_times=None, progress=None, progress_every=1, cancel=None))]
     fn in_constraint_batch(
         &self,
         py: Python,
@@ -1867,12 +2794,18 @@ impl PyConstraint {
         times: Option<&Bound<PyAny>>,
         indices: Option<&Bound<PyAny>>,
         target_rolls: Option<Vec<f64>>,
+        between: Option<&Bound<PyAny>>,
+        at_times: Option<&Bound<PyAny>>,
+        progress: Option<&Bound<PyAny>>,
+        progress_every: usize,
+        cancel: Option<CancellationToken>,
     ) -> PyResult<Py<PyAny>> {
         if target_ras.len() != target_decs.len() {
             return Err(pyo3::exceptions::PyValueError::new_err(
                 "target_ras and target_decs must have the same length",
             ));
         }
+        let mut progress = Progress::new(progress, progress_every, target_ras.len(), cancel)?;
 
         // Validate target_rolls if provided
         if let Some(ref rolls) = target_rolls {
@@ -1885,66 +2818,23 @@ impl PyConstraint {
 
         // Parse time filtering options
         let bound = ephemeris.bind(py);
-        let time_indices = if let Some(times_arg) = times {
-            if indices.is_some() {
-                return Err(pyo3::exceptions::PyValueError::new_err(
-                    "Cannot specify both 'times' and 'indices' parameters",
-                ));
-            }
-            Some(self.parse_times_to_indices(bound, times_arg)?)
-        } else if let Some(indices_arg) = indices {
-            Some(self.parse_indices(indices_arg)?)
-        } else {
-            None
-        };
+        let selection = self.select_times(bound, times, indices, between, at_times)?;
 
         // If no per-target rolls, use uniform None roll for all targets
         if target_rolls.is_none() {
             let result_array = self.with_effective_evaluator(None, |evaluator| {
-                if let Ok(ephem) = bound.extract::<PyRef<TLEEphemeris>>() {
-                    return evaluator.in_constraint_batch(
-                        &*ephem as &dyn EphemerisBase,
-                        &target_ras,
-                        &target_decs,
-                        time_indices.as_deref(),
-                    );
-                }
-                if let Ok(ephem) = bound.extract::<PyRef<SPICEEphemeris>>() {
-                    return evaluator.in_constraint_batch(
-                        &*ephem as &dyn EphemerisBase,
-                        &target_ras,
-                        &target_decs,
-                        time_indices.as_deref(),
-                    );
-                }
-                if let Ok(ephem) = bound.extract::<PyRef<GroundEphemeris>>() {
-                    return evaluator.in_constraint_batch(
-                        &*ephem as &dyn EphemerisBase,
-                        &target_ras,
-                        &target_decs,
-                        time_indices.as_deref(),
-                    );
-                }
-                if let Ok(ephem) = bound.extract::<PyRef<OEMEphemeris>>() {
-                    return evaluator.in_constraint_batch(
-                        &*ephem as &dyn EphemerisBase,
-                        &target_ras,
-                        &target_decs,
-                        time_indices.as_deref(),
-                    );
-                }
-                if let Ok(ephem) = bound.extract::<PyRef<FileEphemeris>>() {
-                    return evaluator.in_constraint_batch(
-                        &*ephem as &dyn EphemerisBase,
+                Self::with_selected_ephemeris(bound, &selection, |ephem, time_indices| {
+                    violation_mask_with_progress(
+                        py,
+                        evaluator,
+                        ephem,
                         &target_ras,
                         &target_decs,
                         time_indices.as_deref(),
-                    );
-                }
-
-                Err(pyo3::exceptions::PyTypeError::new_err(
-                    "Unsupported ephemeris type. Expected TLEEphemeris, SPICEEphemeris, GroundEphemeris, or OEMEphemeris",
-                ))
+                        None,
+                        &mut progress,
+                    )
+                })
             })?;
 
             // Convert to numpy array
@@ -1971,50 +2861,18 @@ impl PyConstraint {
             let group_decs: Vec<f64> = group_indices.iter().map(|&i| target_decs[i]).collect();
 
             let group_array = self.with_effective_evaluator(Some(target_roll), |evaluator| {
-                if let Ok(ephem) = bound.extract::<PyRef<TLEEphemeris>>() {
-                    return evaluator.in_constraint_batch(
-                        &*ephem as &dyn EphemerisBase,
-                        &group_ras,
-                        &group_decs,
-                        time_indices.as_deref(),
-                    );
-                }
-                if let Ok(ephem) = bound.extract::<PyRef<SPICEEphemeris>>() {
-                    return evaluator.in_constraint_batch(
-                        &*ephem as &dyn EphemerisBase,
-                        &group_ras,
-                        &group_decs,
-                        time_indices.as_deref(),
-                    );
-                }
-                if let Ok(ephem) = bound.extract::<PyRef<GroundEphemeris>>() {
-                    return evaluator.in_constraint_batch(
-                        &*ephem as &dyn EphemerisBase,
-                        &group_ras,
-                        &group_decs,
-                        time_indices.as_deref(),
-                    );
-                }
-                if let Ok(ephem) = bound.extract::<PyRef<OEMEphemeris>>() {
-                    return evaluator.in_constraint_batch(
-                        &*ephem as &dyn EphemerisBase,
-                        &group_ras,
-                        &group_decs,
-                        time_indices.as_deref(),
-                    );
-                }
-                if let Ok(ephem) = bound.extract::<PyRef<FileEphemeris>>() {
-                    return evaluator.in_constraint_batch(
-                        &*ephem as &dyn EphemerisBase,
+                Self::with_selected_ephemeris(bound, &selection, |ephem, time_indices| {
+                    violation_mask_with_progress(
+                        py,
+                        evaluator,
+                        ephem,
                         &group_ras,
                         &group_decs,
                         time_indices.as_deref(),
-                    );
-                }
-
-                Err(pyo3::exceptions::PyTypeError::new_err(
-                    "Unsupported ephemeris type. Expected TLEEphemeris, SPICEEphemeris, GroundEphemeris, or OEMEphemeris",
-                ))
+                        None,
+                        &mut progress,
+                    )
+                })
             })?;
 
             if n_times == 0 {
@@ -2134,20 +2992,8 @@ impl PyConstraint {
 
         // Get ephemeris times - need to clone to avoid lifetime issues
         let ephem_times: Vec<DateTime<Utc>> =
-            if let Ok(ephem) = ephemeris.extract::<PyRef<TLEEphemeris>>() {
-                ephem.data().times.as_ref().cloned()
-            } else if let Ok(ephem) = ephemeris.extract::<PyRef<SPICEEphemeris>>() {
-                ephem.data().times.as_ref().cloned()
-            } else if let Ok(ephem) = ephemeris.extract::<PyRef<GroundEphemeris>>() {
-                ephem.data().times.as_ref().cloned()
-            } else if let Ok(ephem) = ephemeris.extract::<PyRef<OEMEphemeris>>() {
-                ephem.data().times.as_ref().cloned()
-            } else if let Ok(ephem) = ephemeris.extract::<PyRef<FileEphemeris>>() {
-                ephem.data().times.as_ref().cloned()
-            } else {
-                None
-            }
-            .ok_or_else(|| pyo3::exceptions::PyValueError::new_err("No times in ephemeris"))?;
+            with_ephemeris(ephemeris, |ephem| Ok(ephem.data().times.as_ref().cloned()))?
+                .ok_or_else(|| pyo3::exceptions::PyValueError::new_err("No times in ephemeris"))?;
 
         // Parse input times (single datetime or iterable of datetimes)
         let input_times: Vec<DateTime<Utc>> =
@@ -2296,6 +3142,11 @@ impl PyConstraint {
             Some(bound_time),
             None,
             target_rolls,
+            None,
+            None,
+            None,
+            1,
+            None,
         )?;
 
         // Extract the results for the single target (first row)
@@ -2315,6 +3166,95 @@ impl PyConstraint {
         }
     }
 
+    /// Occultation ingress and egress times of a target behind the Earth
+    ///
+    /// Only available on an Earth occultation constraint. Crossings of the
+    /// grazing altitude are interpolated linearly between ephemeris samples.
+    /// A target occulted at the first sample has no ingress, and one still
+    /// occulted at the last sample has no egress.
+    ///
+    /// Args:
+    ///     ephemeris: Any supported ephemeris object
+    ///     target_ra (float): Target right ascension in degrees (ICRS/J2000)
+    ///     target_dec (float): Target declination in degrees (ICRS/J2000)
+    ///
+    /// Returns:
+    ///     list[tuple[datetime, str]]: ``(time, "ingress" | "egress")`` in time order
+    ///
+    /// Raises:
+    ///     TypeError: If this is not an Earth occultation constraint
+    fn occultation_events(
+        &self,
+        py: Python,
+        ephemeris: Py<PyAny>,
+        target_ra: f64,
+        target_dec: f64,
+    ) -> PyResult<Vec<(Py<PyAny>, &'static str)>> {
+        let occultation = self
+            .evaluator
+            .as_any()
+            .downcast_ref::<EarthOccultationEvaluator>()
+            .ok_or_else(|| {
+                pyo3::exceptions::PyTypeError::new_err(
+                    "occultation_events requires an Earth occultation constraint",
+                )
+            })?;
+        let events = with_ephemeris(ephemeris.bind(py), |ephem| {
+            Ok(occultation.occultation_events(ephem, target_ra, target_dec, None)?)
+        })?;
+        events
+            .iter()
+            .map(|event| {
+                let kind = if event.ingress { "ingress" } else { "egress" };
+                Ok((utc_to_python_datetime(py, &event.time)?, kind))
+            })
+            .collect()
+    }
+
+    /// Penumbra and umbra entry and exit times of the observer
+    ///
+    /// Only available on an eclipse constraint. Both boundaries are reported
+    /// whether or not the constraint counts the penumbra, so the full
+    /// penumbra/umbra/penumbra profile of each eclipse is available. Crossings
+    /// are interpolated linearly between ephemeris samples.
+    ///
+    /// Args:
+    ///     ephemeris: Any supported ephemeris object
+    ///
+    /// Returns:
+    ///     list[tuple[datetime, str]]: ``(time, kind)`` in time order, where kind is
+    ///     "penumbra_entry", "umbra_entry", "umbra_exit" or "penumbra_exit"
+    ///
+    /// Raises:
+    ///     TypeError: If this is not an eclipse constraint
+    fn shadow_events(
+        &self,
+        py: Python,
+        ephemeris: Py<PyAny>,
+    ) -> PyResult<Vec<(Py<PyAny>, &'static str)>> {
+        let eclipse = self
+            .evaluator
+            .as_any()
+            .downcast_ref::<EclipseEvaluator>()
+            .ok_or_else(|| {
+                pyo3::exceptions::PyTypeError::new_err(
+                    "shadow_events requires an eclipse constraint",
+                )
+            })?;
+        let events = with_ephemeris(ephemeris.bind(py), |ephem| {
+            Ok(eclipse.shadow_events(ephem, None)?)
+        })?;
+        events
+            .iter()
+            .map(|event| {
+                Ok((
+                    utc_to_python_datetime(py, &event.time)?,
+                    event.transition.as_str(),
+                ))
+            })
+            .collect()
+    }
+
     /// Return contiguous roll-angle intervals where the constraint is satisfied.
     ///
     /// Sweeps ``n_roll_samples`` uniformly-spaced spacecraft roll angles over [0°, 360°),
@@ -2369,56 +3309,16 @@ impl PyConstraint {
         // Dispatch to a typed ephemeris reference, then run the vectorized sweep.
         // roll_sweep_vec calls in_constraint_batch once per leaf constraint with all N
         // pre-rotated targets, reducing O(N × leaves) calls to O(leaves).
-        let violated: Vec<bool> = if let Ok(ephem) = bound.extract::<PyRef<TLEEphemeris>>() {
-            run_roll_sweep(
-                &base_config,
-                &target_ras,
-                &target_decs,
-                &rolls,
-                &*ephem as &dyn EphemerisBase,
-                time_idx,
-            )?
-        } else if let Ok(ephem) = bound.extract::<PyRef<SPICEEphemeris>>() {
-            run_roll_sweep(
-                &base_config,
-                &target_ras,
-                &target_decs,
-                &rolls,
-                &*ephem as &dyn EphemerisBase,
-                time_idx,
-            )?
-        } else if let Ok(ephem) = bound.extract::<PyRef<GroundEphemeris>>() {
-            run_roll_sweep(
-                &base_config,
-                &target_ras,
-                &target_decs,
-                &rolls,
-                &*ephem as &dyn EphemerisBase,
-                time_idx,
-            )?
-        } else if let Ok(ephem) = bound.extract::<PyRef<OEMEphemeris>>() {
-            run_roll_sweep(
-                &base_config,
-                &target_ras,
-                &target_decs,
-                &rolls,
-                &*ephem as &dyn EphemerisBase,
-                time_idx,
-            )?
-        } else if let Ok(ephem) = bound.extract::<PyRef<FileEphemeris>>() {
+        let violated: Vec<bool> = with_ephemeris(bound, |ephem| {
             run_roll_sweep(
                 &base_config,
                 &target_ras,
                 &target_decs,
                 &rolls,
-                &*ephem as &dyn EphemerisBase,
+                ephem,
                 time_idx,
-            )?
-        } else {
-            return Err(pyo3::exceptions::PyTypeError::new_err(
-                "Unsupported ephemeris type. Expected TLEEphemeris, SPICEEphemeris, GroundEphemeris, or OEMEphemeris",
-            ));
-        };
+            )
+        })?;
 
         // Collapse contiguous valid (not-violated) samples into (lo, hi) intervals.
         let mut intervals: Vec<(f64, f64)> = Vec::new();
@@ -2458,6 +3358,7 @@ impl PyConstraint {
     ///     body (str, optional): Body identifier (NAIF ID or name like "Jupiter", "90004910")
     ///     use_horizons (bool): If True, query JPL Horizons for body positions (default: False)
     ///     spice_kernel (str, optional): SPICE kernel specification for body lookup
+    ///     source (str, optional): Body data source precedence: "spice", "horizons" or "auto"
     ///
     /// Returns:
     ///     MovingBodyResult: Result object containing:
@@ -2476,7 +3377,7 @@ impl PyConstraint {
     ///     >>> # Using explicit coordinates for a comet
     ///     >>> result = constraint.evaluate_moving_body(ephem, target_ras=ras, target_decs=decs)
     #[allow(clippy::too_many_arguments)]
-    #[pyo3(signature = (ephemeris, target_ras=None, target_decs=None, times=None, body=None, use_horizons=false, spice_kernel=None))]
+    #[pyo3(signature = (ephemeris, target_ras=None, target_decs=None, times=None, body=None, use_horizons=false, spice_kernel=None, source=None))]
     fn evaluate_moving_body(
         &self,
         py: Python,
@@ -2487,6 +3388,7 @@ impl PyConstraint {
         body: Option<&str>,
         use_horizons: bool,
         spice_kernel: Option<&str>,
+        source: Option<&str>,
     ) -> PyResult<MovingBodyResult> {
         use crate::constraints::core::MovingBodyResult;
 
@@ -2496,12 +3398,15 @@ impl PyConstraint {
         let (ras, decs, timestamps): (Vec<f64>, Vec<f64>, Vec<DateTime<Utc>>) =
             if let Some(body_id) = body {
                 // Body lookup mode: get positions from ephemeris.get_body()
-                // Build kwargs dict with use_horizons and optional spice_kernel
+                // Build kwargs dict with use_horizons and optional spice_kernel/source
                 let kwargs = pyo3::types::PyDict::new(py);
                 kwargs.set_item("use_horizons", use_horizons)?;
                 if let Some(ks) = spice_kernel {
                     kwargs.set_item("spice_kernel", ks)?;
                 }
+                if let Some(src) = source {
+                    kwargs.set_item("source", src)?;
+                }
                 let skycoord = bound.call_method("get_body", (body_id,), Some(&kwargs))?;
 
                 // Extract RA/Dec from SkyCoord
@@ -2670,6 +3575,64 @@ impl PyConstraint {
         ))
     }
 
+    /// Evaluate constraint for a virtual target derived from the observer state
+    ///
+    /// The target direction is recomputed at every ephemeris time, so common
+    /// pointing modes can be checked without precomputing coordinates.
+    ///
+    /// Args:
+    ///     ephemeris: One of TLEEphemeris, SPICEEphemeris, GroundEphemeris, or OEMEphemeris
+    ///     target (str): "nadir", "limb", "sun", "anti_sun", "ram" or "anti_ram"
+    ///     azimuth (float, optional): For "limb" only, azimuth of the limb tangent point
+    ///         about nadir in degrees, 0 toward ram and 90 toward the orbit normal (default: 0)
+    ///
+    /// Returns:
+    ///     MovingBodyResult: Result with the target's RA/Dec at each time, as for
+    ///         `evaluate_moving_body`
+    ///
+    /// Example:
+    ///     >>> result = constraint.evaluate_virtual_target(ephem, "limb", azimuth=90.0)
+    ///     >>> result.all_satisfied
+    #[pyo3(signature = (ephemeris, target, *, azimuth=None))]
+    fn evaluate_virtual_target(
+        &self,
+        py: Python,
+        ephemeris: Py<PyAny>,
+        target: &str,
+        azimuth: Option<f64>,
+    ) -> PyResult<MovingBodyResult> {
+        use crate::constraints::core::MovingBodyResult;
+        use crate::constraints::virtual_target::VirtualTarget;
+
+        let target = VirtualTarget::parse(target, azimuth)
+            .map_err(pyo3::exceptions::PyValueError::new_err)?;
+        let (timestamps, ras, decs, constraint_vec) =
+            with_ephemeris(ephemeris.bind(py), |ephem| {
+                let (ras, decs) = target.radec(ephem)?;
+                let constraint_vec = self
+                    .evaluator
+                    .in_constraint_batch_diagonal(ephem, &ras, &decs)?;
+                Ok((ephem.get_times()?, ras, decs, constraint_vec))
+            })?;
+
+        let violations = track_violations(
+            &timestamps,
+            |i| (constraint_vec[i], if constraint_vec[i] { 1.0 } else { 0.0 }),
+            |_i, _is_open| self.evaluator.name(),
+        );
+        let all_satisfied = !constraint_vec.iter().any(|&v| v);
+
+        Ok(MovingBodyResult::new(
+            violations,
+            all_satisfied,
+            self.evaluator.name(),
+            timestamps,
+            ras,
+            decs,
+            constraint_vec,
+        ))
+    }
+
     /// Get constraint configuration as JSON string
     fn to_json(&self) -> String {
         self.config_json.clone()
@@ -9,12 +9,19 @@
 ///
 /// Constraints operate on ephemeris data and target coordinates to produce
 /// time-based violation windows.
-use crate::utils::time_utils::{python_datetime_to_utc, utc_to_python_datetime};
-use chrono::{DateTime, Utc};
-use ndarray::Array2;
+use crate::constraints::error::ConstraintError;
+use crate::ephemeris::provenance::Provenance;
+use crate::utils::time_utils::{
+    python_datetime_to_utc, python_times_to_utc, utc_to_datetime64_array, utc_to_python_datetime,
+};
+use chrono::{DateTime, Duration, Utc};
+use ndarray::{Array1, Array2};
+use numpy::IntoPyArray;
 use pyo3::prelude::*;
+use serde::{Deserialize, Serialize};
+use std::borrow::Cow;
 use std::fmt;
-use std::sync::OnceLock;
+use std::sync::{Arc, OnceLock};
 
 /// Result of constraint evaluation
 ///
@@ -88,6 +95,201 @@ impl VisibilityWindow {
     }
 }
 
+/// How a `ConstraintResult` keeps its evaluation times
+///
+/// Year-long runs for thousands of targets hold a copy of the time grid per
+/// result; the compact forms keep that memory bounded.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ResultStorage {
+    /// Every evaluation time
+    #[default]
+    Full,
+    /// (start, step, count) when the times are evenly spaced, else every time
+    Compressed,
+    /// Only the violation and satisfied windows; per-time arrays are dropped
+    Windows,
+}
+
+impl ResultStorage {
+    pub fn parse(storage: &str) -> PyResult<Self> {
+        match storage {
+            "full" => Ok(Self::Full),
+            "compressed" => Ok(Self::Compressed),
+            "windows" => Ok(Self::Windows),
+            _ => Err(pyo3::exceptions::PyValueError::new_err(format!(
+                "storage must be 'full', 'compressed' or 'windows', got '{}'",
+                storage
+            ))),
+        }
+    }
+
+    fn as_str(&self) -> &'static str {
+        match self {
+            Self::Full => "full",
+            Self::Compressed => "compressed",
+            Self::Windows => "windows",
+        }
+    }
+}
+
+/// Evaluation times of a result
+#[derive(Debug, Clone, PartialEq)]
+pub enum TimeGrid {
+    /// Every time
+    Explicit(Vec<DateTime<Utc>>),
+    /// `count` times `start + i * step`
+    Uniform {
+        start: DateTime<Utc>,
+        step: Duration,
+        count: usize,
+    },
+    /// Times not kept, only how many there were
+    Dropped { count: usize },
+}
+
+impl TimeGrid {
+    /// Store `times` as `storage` asks
+    ///
+    /// Compression needs at least two times with exactly equal, positive
+    /// steps; other grids stay explicit.
+    pub fn new(times: Vec<DateTime<Utc>>, storage: ResultStorage) -> Self {
+        match storage {
+            ResultStorage::Full => TimeGrid::Explicit(times),
+            ResultStorage::Windows => TimeGrid::Dropped { count: times.len() },
+            ResultStorage::Compressed => {
+                if times.len() < 2 {
+                    return TimeGrid::Explicit(times);
+                }
+                let step = times[1] - times[0];
+                let uniform = step > Duration::zero()
+                    && times.windows(2).all(|pair| pair[1] - pair[0] == step);
+                if uniform {
+                    TimeGrid::Uniform {
+                        start: times[0],
+                        step,
+                        count: times.len(),
+                    }
+                } else {
+                    TimeGrid::Explicit(times)
+                }
+            }
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        match self {
+            TimeGrid::Explicit(times) => times.len(),
+            TimeGrid::Uniform { count, .. } | TimeGrid::Dropped { count } => *count,
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Every time, expanded from a uniform grid; an error if dropped
+    pub fn times(&self) -> PyResult<Cow<'_, [DateTime<Utc>]>> {
+        match self {
+            TimeGrid::Explicit(times) => Ok(Cow::Borrowed(times)),
+            TimeGrid::Uniform { start, step, count } => Ok(Cow::Owned(
+                (0..*count).map(|i| *start + *step * i as i32).collect(),
+            )),
+            TimeGrid::Dropped { .. } => Err(dropped_error()),
+        }
+    }
+
+    /// Index of the time matching `dt`, without expanding a uniform grid
+    fn lookup(&self, dt: DateTime<Utc>, mode: TimeLookup) -> PyResult<usize> {
+        let (start, step, count) = match self {
+            TimeGrid::Explicit(times) => return lookup_index(times, dt, mode),
+            TimeGrid::Dropped { .. } => return Err(dropped_error()),
+            TimeGrid::Uniform { start, step, count } => (*start, *step, *count),
+        };
+        let step_ns = step.num_nanoseconds().unwrap_or(i64::MAX);
+        let offset = (dt - start).num_nanoseconds().unwrap_or(i64::MAX);
+        let last = (count as i64 - 1).saturating_mul(step_ns);
+        let inside = (0..=last).contains(&offset);
+        if inside && offset % step_ns == 0 {
+            return Ok((offset / step_ns) as usize);
+        }
+        if let TimeLookup::Exact = mode {
+            return Err(pyo3::exceptions::PyValueError::new_err(
+                "time not found in evaluated timestamps",
+            ));
+        }
+        if !inside {
+            return Err(pyo3::exceptions::PyValueError::new_err(format!(
+                "time {} is outside the evaluated time range",
+                dt.to_rfc3339()
+            )));
+        }
+        let (i, rem) = ((offset / step_ns) as usize, offset % step_ns);
+        match mode {
+            TimeLookup::Nearest if step_ns - rem < rem => Ok(i + 1),
+            _ => Ok(i),
+        }
+    }
+}
+
+/// Identifies files written by `ConstraintResult.save`
+const SAVED_RESULT_FORMAT: &str = "rust_ephem.ConstraintResult";
+
+/// Version of the saved result layout, raised on incompatible changes
+const SAVED_RESULT_VERSION: u32 = 1;
+
+/// On-disk form of a `ConstraintResult`
+#[derive(Serialize, Deserialize)]
+struct SavedResult {
+    format: String,
+    version: u32,
+    constraint_name: String,
+    all_satisfied: bool,
+    storage: String,
+    /// Constraint that produced the result, as for `Constraint.to_json`
+    #[serde(default)]
+    constraint: Option<serde_json::Value>,
+    /// Provenance record of the ephemeris the result was evaluated against
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    provenance: Option<serde_json::Value>,
+    times: SavedTimes,
+    violations: Vec<SavedViolation>,
+    /// Satisfied windows; only read back when the times were dropped
+    windows: Vec<(DateTime<Utc>, DateTime<Utc>)>,
+}
+
+/// On-disk form of a `TimeGrid`
+#[derive(Serialize, Deserialize)]
+#[serde(tag = "kind", rename_all = "lowercase")]
+enum SavedTimes {
+    Explicit {
+        times: Vec<DateTime<Utc>>,
+    },
+    Uniform {
+        start: DateTime<Utc>,
+        step_ns: i64,
+        count: usize,
+    },
+    Dropped {
+        count: usize,
+    },
+}
+
+/// On-disk form of a `ConstraintViolation`
+#[derive(Serialize, Deserialize)]
+struct SavedViolation {
+    start_time: DateTime<Utc>,
+    end_time: DateTime<Utc>,
+    max_severity: f64,
+    description: String,
+}
+
+fn dropped_error() -> PyErr {
+    pyo3::exceptions::PyValueError::new_err(
+        "per-time arrays were not kept (storage='windows'); only the violation and \
+         visibility windows are available",
+    )
+}
+
 /// Result of constraint evaluation containing all violations
 #[pyclass(name = "ConstraintResult")]
 pub struct ConstraintResult {
@@ -100,10 +302,16 @@ pub struct ConstraintResult {
     /// Constraint name/description
     #[pyo3(get)]
     pub constraint_name: String,
-    /// Evaluation times as Rust DateTime<Utc>, not directly exposed to Python
-    pub times: Vec<DateTime<Utc>>,
-    /// Step size in seconds between timestamps (for O(1) index lookup)
-    step_seconds: i64,
+    /// Evaluation times, not directly exposed to Python
+    pub times: TimeGrid,
+    /// How the times are stored
+    storage: ResultStorage,
+    /// Satisfied windows, kept in place of the times with `ResultStorage::Windows`
+    kept_windows: Vec<(DateTime<Utc>, DateTime<Utc>)>,
+    /// JSON of the constraint that produced the result, when known
+    spec_json: Option<String>,
+    /// Provenance record of the ephemeris the result was evaluated against
+    provenance: Option<Arc<serde_json::Value>>,
     /// Cached Python timestamp array (not directly exposed, use getter)
     timestamp_cache: OnceLock<Py<PyAny>>,
     /// Cached constraint vector (Rust-side, used by both constraint_array and visibility)
@@ -120,22 +328,170 @@ impl ConstraintResult {
         constraint_name: String,
         times: Vec<DateTime<Utc>>,
     ) -> Self {
-        // Compute step size from first two timestamps (0 if fewer than 2 times)
-        let step_seconds = if times.len() >= 2 {
-            (times[1] - times[0]).num_seconds()
-        } else {
-            0
+        Self::with_storage(
+            violations,
+            all_satisfied,
+            constraint_name,
+            times,
+            ResultStorage::Full,
+        )
+    }
+
+    /// Create a new ConstraintResult keeping its times as `storage` asks
+    pub fn with_storage(
+        violations: Vec<ConstraintViolation>,
+        all_satisfied: bool,
+        constraint_name: String,
+        times: Vec<DateTime<Utc>>,
+        storage: ResultStorage,
+    ) -> Self {
+        let kept_windows = match storage {
+            ResultStorage::Windows => complement_ranges(&times, &violations)
+                .into_iter()
+                .map(|(start, end)| (times[start], times[end]))
+                .collect(),
+            _ => Vec::new(),
         };
         Self {
             violations,
             all_satisfied,
             constraint_name,
+            times: TimeGrid::new(times, storage),
+            storage,
+            kept_windows,
+            spec_json: None,
+            provenance: None,
+            timestamp_cache: OnceLock::new(),
+            constraint_vec_cache: OnceLock::new(),
+            constraint_array_cache: OnceLock::new(),
+        }
+    }
+
+    /// The same result under another constraint name
+    pub fn renamed(mut self, constraint_name: String) -> Self {
+        self.constraint_name = constraint_name;
+        self
+    }
+
+    /// The same result recording the JSON of the constraint that produced it
+    pub fn with_spec(mut self, spec_json: String) -> Self {
+        self.spec_json = Some(spec_json);
+        self
+    }
+
+    /// The same result recording the provenance of its ephemeris
+    pub fn with_provenance(mut self, record: Arc<serde_json::Value>) -> Self {
+        self.provenance = Some(record);
+        self
+    }
+
+    fn to_saved(&self) -> PyResult<SavedResult> {
+        let times = match &self.times {
+            TimeGrid::Explicit(times) => SavedTimes::Explicit {
+                times: times.clone(),
+            },
+            TimeGrid::Uniform { start, step, count } => SavedTimes::Uniform {
+                start: *start,
+                step_ns: step.num_nanoseconds().unwrap_or(i64::MAX),
+                count: *count,
+            },
+            TimeGrid::Dropped { count } => SavedTimes::Dropped { count: *count },
+        };
+        let constraint = self
+            .spec_json
+            .as_deref()
+            .map(serde_json::from_str)
+            .transpose()
+            .map_err(|e| pyo3::exceptions::PyValueError::new_err(e.to_string()))?;
+        Ok(SavedResult {
+            format: SAVED_RESULT_FORMAT.to_string(),
+            version: SAVED_RESULT_VERSION,
+            constraint_name: self.constraint_name.clone(),
+            all_satisfied: self.all_satisfied,
+            storage: self.storage.as_str().to_string(),
+            constraint,
+            provenance: self.provenance.as_deref().cloned(),
             times,
-            step_seconds,
+            violations: self
+                .violations
+                .iter()
+                .map(|v| SavedViolation {
+                    start_time: v.start_time_internal,
+                    end_time: v.end_time_internal,
+                    max_severity: v.max_severity,
+                    description: v.description.clone(),
+                })
+                .collect(),
+            windows: self.satisfied_windows()?,
+        })
+    }
+
+    fn from_saved(saved: SavedResult) -> PyResult<Self> {
+        if saved.format != SAVED_RESULT_FORMAT {
+            return Err(pyo3::exceptions::PyValueError::new_err(format!(
+                "not a saved constraint result (format '{}')",
+                saved.format
+            )));
+        }
+        if saved.version > SAVED_RESULT_VERSION {
+            return Err(pyo3::exceptions::PyValueError::new_err(format!(
+                "saved constraint result version {} is newer than supported version {}",
+                saved.version, SAVED_RESULT_VERSION
+            )));
+        }
+        let storage = ResultStorage::parse(&saved.storage)?;
+        let (times, kept_windows) = match saved.times {
+            SavedTimes::Explicit { times } => (TimeGrid::Explicit(times), Vec::new()),
+            SavedTimes::Uniform {
+                start,
+                step_ns,
+                count,
+            } => {
+                if step_ns <= 0 {
+                    return Err(pyo3::exceptions::PyValueError::new_err(
+                        "saved time grid step must be positive",
+                    ));
+                }
+                let step = Duration::nanoseconds(step_ns);
+                (TimeGrid::Uniform { start, step, count }, Vec::new())
+            }
+            SavedTimes::Dropped { count } => (TimeGrid::Dropped { count }, saved.windows),
+        };
+        let violations = saved
+            .violations
+            .into_iter()
+            .map(|v| ConstraintViolation {
+                start_time_internal: v.start_time,
+                end_time_internal: v.end_time,
+                max_severity: v.max_severity,
+                description: v.description,
+            })
+            .collect();
+        Ok(Self {
+            violations,
+            all_satisfied: saved.all_satisfied,
+            constraint_name: saved.constraint_name,
+            times,
+            storage,
+            kept_windows,
+            spec_json: saved.constraint.map(|spec| spec.to_string()),
+            provenance: saved.provenance.map(Arc::new),
             timestamp_cache: OnceLock::new(),
             constraint_vec_cache: OnceLock::new(),
             constraint_array_cache: OnceLock::new(),
+        })
+    }
+
+    /// Satisfied windows: the complement of `violations` over the evaluated times
+    fn satisfied_windows(&self) -> PyResult<Vec<(DateTime<Utc>, DateTime<Utc>)>> {
+        if matches!(self.times, TimeGrid::Dropped { .. }) {
+            return Ok(self.kept_windows.clone());
         }
+        let times = self.times.times()?;
+        Ok(complement_ranges(&times, &self.violations)
+            .into_iter()
+            .map(|(start, end)| (times[start], times[end]))
+            .collect())
     }
 }
 
@@ -150,6 +506,46 @@ impl ConstraintResult {
         )
     }
 
+    /// Start times of the violation windows as a NumPy datetime64[us] array (UTC)
+    #[getter]
+    fn violation_start_times(&self, py: Python) -> PyResult<Py<PyAny>> {
+        utc_to_datetime64_array(py, self.violations.iter().map(|v| v.start_time_internal))
+    }
+
+    /// End times of the violation windows as a NumPy datetime64[us] array (UTC)
+    #[getter]
+    fn violation_end_times(&self, py: Python) -> PyResult<Py<PyAny>> {
+        utc_to_datetime64_array(py, self.violations.iter().map(|v| v.end_time_internal))
+    }
+
+    /// How the evaluation times are stored: "full", "compressed" or "windows"
+    #[getter]
+    fn storage(&self) -> &'static str {
+        self.storage.as_str()
+    }
+
+    /// Number of evaluated times, also when they are not stored
+    #[getter]
+    fn n_times(&self) -> usize {
+        self.times.len()
+    }
+
+    /// Evenly spaced time grid as (start, step in seconds, count), or None
+    ///
+    /// Set when the result was evaluated with `storage="compressed"` on a
+    /// uniform grid.
+    #[getter]
+    fn time_grid(&self, py: Python) -> PyResult<Option<(Py<PyAny>, f64, usize)>> {
+        match &self.times {
+            TimeGrid::Uniform { start, step, count } => Ok(Some((
+                utc_to_python_datetime(py, start)?,
+                step.num_nanoseconds().unwrap_or(i64::MAX) as f64 / 1e9,
+                *count,
+            ))),
+            _ => Ok(None),
+        }
+    }
+
     /// Get the total duration of violations in seconds
     fn total_violation_duration(&self) -> PyResult<f64> {
         let mut total_seconds = 0.0;
@@ -161,6 +557,61 @@ impl ConstraintResult {
         Ok(total_seconds)
     }
 
+    /// JSON of the constraint that produced the result, or None if unknown
+    ///
+    /// Pass it to `Constraint.from_json` to rebuild the constraint, for
+    /// example to re-evaluate a loaded result over a new time range.
+    #[getter]
+    fn constraint_spec(&self) -> Option<String> {
+        self.spec_json.clone()
+    }
+
+    /// Reproducibility record of the evaluation, or None if unknown
+    ///
+    /// The `provenance` of the ephemeris the result was evaluated against,
+    /// with the constraint definition added under `constraint`. Saved and
+    /// loaded with the result.
+    #[getter]
+    fn provenance(&self) -> Option<Provenance> {
+        self.provenance
+            .as_deref()
+            .map(|record| Provenance::with_constraint(record, self.spec_json.as_deref()))
+    }
+
+    /// Write the result to `path` as JSON
+    ///
+    /// The file holds the evaluation times (as stored, see `storage`), the
+    /// violation windows with their severities, the visibility windows and
+    /// the generating constraint, so `ConstraintResult.load` gives back an
+    /// equivalent result. The file is replaced atomically.
+    fn save(&self, path: &str) -> PyResult<()> {
+        let text = serde_json::to_string(&self.to_saved()?)
+            .map_err(|e| pyo3::exceptions::PyValueError::new_err(e.to_string()))?;
+        crate::utils::cache_io::write_atomic(std::path::Path::new(path), text.as_bytes()).map_err(
+            |e| {
+                pyo3::exceptions::PyIOError::new_err(format!(
+                    "Failed to write constraint result: {}",
+                    e
+                ))
+            },
+        )
+    }
+
+    /// Read a result written by `save`
+    #[staticmethod]
+    fn load(path: &str) -> PyResult<Self> {
+        let text = std::fs::read_to_string(path).map_err(|e| {
+            pyo3::exceptions::PyIOError::new_err(format!("Failed to read constraint result: {}", e))
+        })?;
+        let saved: SavedResult = serde_json::from_str(&text).map_err(|e| {
+            pyo3::exceptions::PyValueError::new_err(format!(
+                "Invalid constraint result file: {}",
+                e
+            ))
+        })?;
+        Self::from_saved(saved)
+    }
+
     /// Internal: get cached constraint vector, computing if necessary
     ///
     /// NOTE: This returns a *violation mask* where True means the constraint
@@ -168,14 +619,18 @@ impl ConstraintResult {
     /// `constraint_array` property therefore exposes violation semantics
     /// (True == violated) to Python; visibility windows are computed by
     /// inverting this mask.
-    fn _get_constraint_vec(&self) -> &Vec<bool> {
-        self.constraint_vec_cache.get_or_init(|| {
-            if self.times.is_empty() {
+    fn _get_constraint_vec(&self) -> PyResult<&Vec<bool>> {
+        if let Some(cached) = self.constraint_vec_cache.get() {
+            return Ok(cached);
+        }
+        let times = self.times.times()?;
+        Ok(self.constraint_vec_cache.get_or_init(|| {
+            if times.is_empty() {
                 return Vec::new();
             }
 
             // Pre-allocate result vector: default false == not violated
-            let mut violated = vec![false; self.times.len()];
+            let mut violated = vec![false; times.len()];
 
             // Early return if no violations (all false)
             if self.violations.is_empty() {
@@ -183,7 +638,7 @@ impl ConstraintResult {
             }
 
             // Mark violated times - violations are already sorted by time
-            for (i, t) in self.times.iter().enumerate() {
+            for (i, t) in times.iter().enumerate() {
                 // Binary search could be used here, but violation count is typically small
                 for v in &self.violations {
                     if t < &v.start_time_internal {
@@ -196,7 +651,7 @@ impl ConstraintResult {
                 }
             }
             violated
-        })
+        }))
     }
 
     /// Property: array of booleans for each timestamp where True means constraint violated
@@ -211,7 +666,7 @@ impl ConstraintResult {
         // Return a Python list of bools (True == violated) so indexing yields
         // native Python bool values. Tests historically expect identity
         // comparisons ("is True"), so returning Python bools is safer.
-        let arr = self._get_constraint_vec();
+        let arr = self._get_constraint_vec()?;
         let py_list = pyo3::types::PyList::empty(py);
         for b in arr {
             py_list.append(pyo3::types::PyBool::new(py, *b))?;
@@ -238,7 +693,7 @@ impl ConstraintResult {
 
         // Build list of Python datetime objects
         let py_list = pyo3::types::PyList::empty(py);
-        for dt in &self.times {
+        for dt in self.times.times()?.iter() {
             let py_dt = utc_to_python_datetime(py, dt)?;
             py_list.append(py_dt)?;
         }
@@ -255,96 +710,136 @@ impl ConstraintResult {
 
     /// Check if the target is in-constraint at a given time.
     /// Accepts a Python datetime object (naive datetimes are treated as UTC).
-    fn in_constraint(&self, _py: Python, time: &Bound<PyAny>) -> PyResult<bool> {
-        let dt = python_datetime_to_utc(time)?;
-
-        // O(1) index calculation instead of O(n) linear search
-        if self.times.is_empty() {
-            return Err(pyo3::exceptions::PyValueError::new_err(
-                "no evaluated timestamps",
-            ));
-        }
-
-        let begin = self.times[0];
-        let offset_seconds = (dt - begin).num_seconds();
-
-        // Check if time is before begin
-        if offset_seconds < 0 {
-            return Err(pyo3::exceptions::PyValueError::new_err(
-                "time not found in evaluated timestamps",
-            ));
-        }
-
-        // Calculate index directly (O(1) instead of O(n))
-        let idx = if self.step_seconds > 0 {
-            (offset_seconds / self.step_seconds) as usize
-        } else {
-            0
-        };
+    ///
+    /// `mode` selects how the time maps onto the evaluated times: "exact"
+    /// requires an evaluated time, "nearest" takes the closest one and
+    /// "previous" the one starting the interval that contains the time.
+    #[pyo3(signature = (time, mode="exact"))]
+    fn in_constraint(&self, time: &Bound<PyAny>, mode: &str) -> PyResult<bool> {
+        let mode = TimeLookup::parse(mode)?;
+        let idx = self.times.lookup(python_datetime_to_utc(time)?, mode)?;
+        Ok(self._get_constraint_vec()?[idx])
+    }
 
-        // Verify index is in bounds and matches exactly
-        if idx >= self.times.len() || self.times[idx] != dt {
-            return Err(pyo3::exceptions::PyValueError::new_err(
-                "time not found in evaluated timestamps",
-            ));
-        }
+    /// Vectorized `in_constraint` over a sequence or datetime64 array of times
+    #[pyo3(signature = (times, mode="exact"))]
+    fn in_constraint_array(
+        &self,
+        py: Python,
+        times: &Bound<PyAny>,
+        mode: &str,
+    ) -> PyResult<Py<PyAny>> {
+        let mode = TimeLookup::parse(mode)?;
+        let violated = self._get_constraint_vec()?;
+        let values = python_times_to_utc(times)?
+            .into_iter()
+            .map(|dt| Ok(violated[self.times.lookup(dt, mode)?]))
+            .collect::<PyResult<Vec<bool>>>()?;
+        Ok(Array1::from_vec(values).into_pyarray(py).into())
+    }
 
-        // Check if this time falls within any violation window
-        for v in &self.violations {
-            if v.start_time_internal <= dt && dt <= v.end_time_internal {
-                // Time is in a violation window, so in-constraint (violated)
-                return Ok(true);
-            }
-        }
-        // No violations found for this time, so not in-constraint
-        Ok(false)
+    /// Satisfied windows: the complement of `violations` over the evaluated times
+    ///
+    /// Windows at the start and end of the evaluation are bounded by the
+    /// first and last evaluated times.
+    #[getter]
+    fn visibility_windows(&self, py: Python) -> PyResult<Vec<VisibilityWindow>> {
+        self.satisfied_windows()?
+            .into_iter()
+            .map(|(start, end)| {
+                Ok(VisibilityWindow {
+                    start_time: utc_to_python_datetime(py, &start)?,
+                    end_time: utc_to_python_datetime(py, &end)?,
+                })
+            })
+            .collect()
     }
 
     /// Property: array of visibility windows when target is not constrained
+    ///
+    /// The same windows as `visibility_windows`.
     #[getter]
     fn visibility(&self, py: Python) -> PyResult<Vec<VisibilityWindow>> {
-        if self.times.is_empty() {
-            return Ok(Vec::new());
-        }
-
-        let mut windows = Vec::new();
-        let mut current_window_start: Option<usize> = None;
+        self.visibility_windows(py)
+    }
+}
 
-        // Get cached violation mask for each time (True == violated)
-        let violated_vec = self._get_constraint_vec();
+/// How a time is matched to the evaluated times of a result
+#[derive(Clone, Copy)]
+enum TimeLookup {
+    /// The time must be one of the evaluated times
+    Exact,
+    /// The closest evaluated time
+    Nearest,
+    /// The evaluated time at or before the time
+    Previous,
+}
 
-        for (i, &is_violated) in violated_vec.iter().enumerate() {
-            let is_satisfied = !is_violated;
-            if is_satisfied {
-                // Constraint is satisfied (target is visible)
-                if current_window_start.is_none() {
-                    current_window_start = Some(i);
-                }
-            } else {
-                // Constraint is violated (target not visible)
-                if let Some(start_idx) = current_window_start {
-                    // Only add window if it's non-zero length
-                    if i - 1 != start_idx {
-                        windows.push(VisibilityWindow {
-                            start_time: utc_to_python_datetime(py, &self.times[start_idx])?,
-                            end_time: utc_to_python_datetime(py, &self.times[i - 1])?,
-                        });
-                    }
-                    current_window_start = None;
-                }
-            }
+impl TimeLookup {
+    fn parse(mode: &str) -> PyResult<Self> {
+        match mode {
+            "exact" => Ok(Self::Exact),
+            "nearest" => Ok(Self::Nearest),
+            "previous" => Ok(Self::Previous),
+            _ => Err(pyo3::exceptions::PyValueError::new_err(format!(
+                "mode must be 'exact', 'nearest' or 'previous', got '{}'",
+                mode
+            ))),
         }
+    }
+}
 
-        // Close any open visibility window at the end
-        if let Some(start_idx) = current_window_start {
-            windows.push(VisibilityWindow {
-                start_time: utc_to_python_datetime(py, &self.times[start_idx])?,
-                end_time: utc_to_python_datetime(py, &self.times[self.times.len() - 1])?,
-            });
-        }
+/// Index of the evaluated time matching `dt`, found by binary search
+fn lookup_index(times: &[DateTime<Utc>], dt: DateTime<Utc>, mode: TimeLookup) -> PyResult<usize> {
+    if times.is_empty() {
+        return Err(pyo3::exceptions::PyValueError::new_err(
+            "no evaluated timestamps",
+        ));
+    }
+    let i = times.partition_point(|t| *t < dt);
+    if times.get(i) == Some(&dt) {
+        return Ok(i);
+    }
+    if let TimeLookup::Exact = mode {
+        return Err(pyo3::exceptions::PyValueError::new_err(
+            "time not found in evaluated timestamps",
+        ));
+    }
+    if i == 0 || i == times.len() {
+        return Err(pyo3::exceptions::PyValueError::new_err(format!(
+            "time {} is outside the evaluated time range",
+            dt.to_rfc3339()
+        )));
+    }
+    match mode {
+        TimeLookup::Nearest if times[i] - dt < dt - times[i - 1] => Ok(i),
+        _ => Ok(i - 1),
+    }
+}
 
-        Ok(windows)
+/// Index ranges of the evaluated times not covered by `violations`
+///
+/// Each range runs from the first to the last satisfied sample between two
+/// violations, or from the first or to the last time at the grid boundaries.
+/// Ranges of a single sample have no duration and are omitted, except at the
+/// end of the grid where the target is still visible when evaluation stops.
+fn complement_ranges(
+    times: &[DateTime<Utc>],
+    violations: &[ConstraintViolation],
+) -> Vec<(usize, usize)> {
+    let mut ranges = Vec::new();
+    let mut next = 0;
+    for violation in violations {
+        let start = times.partition_point(|t| *t < violation.start_time_internal);
+        if start > next + 1 {
+            ranges.push((next, start - 1));
+        }
+        next = times.partition_point(|t| *t <= violation.end_time_internal);
     }
+    if times.len() > next {
+        ranges.push((next, times.len() - 1));
+    }
+    ranges
 }
 
 /// Result of constraint evaluation for a moving body
@@ -370,8 +865,6 @@ pub struct MovingBodyResult {
     pub decs: Vec<f64>,
     /// Evaluation times as Rust DateTime<Utc>, not directly exposed to Python
     pub times: Vec<DateTime<Utc>>,
-    /// Step size in seconds between timestamps (for O(1) index lookup)
-    step_seconds: i64,
     /// Boolean array indicating constraint violation at each time (True = violated)
     constraint_vec: Vec<bool>,
 }
@@ -387,12 +880,6 @@ impl MovingBodyResult {
         decs: Vec<f64>,
         constraint_vec: Vec<bool>,
     ) -> Self {
-        // Compute step size from first two timestamps (0 if fewer than 2 times)
-        let step_seconds = if times.len() >= 2 {
-            (times[1] - times[0]).num_seconds()
-        } else {
-            0
-        };
         Self {
             violations,
             all_satisfied,
@@ -400,7 +887,6 @@ impl MovingBodyResult {
             ras,
             decs,
             times,
-            step_seconds,
             constraint_vec,
         }
     }
@@ -456,43 +942,32 @@ impl MovingBodyResult {
     }
 
     /// Check if the target is in-constraint at a given time.
-    fn in_constraint(&self, _py: Python, time: &Bound<PyAny>) -> PyResult<bool> {
-        let dt = python_datetime_to_utc(time)?;
-
-        // O(1) index calculation instead of O(n) linear search
-        if self.times.is_empty() {
-            return Err(pyo3::exceptions::PyValueError::new_err(
-                "no evaluated timestamps",
-            ));
-        }
-
-        let begin = self.times[0];
-        let offset_seconds = (dt - begin).num_seconds();
-
-        // Check if time is before begin
-        if offset_seconds < 0 {
-            return Err(pyo3::exceptions::PyValueError::new_err(
-                "time not found in evaluated timestamps",
-            ));
-        }
-
-        // Calculate index directly (O(1) instead of O(n))
-        let idx = if self.step_seconds > 0 {
-            (offset_seconds / self.step_seconds) as usize
-        } else {
-            0
-        };
-
-        // Verify index is in bounds and matches exactly
-        if idx >= self.times.len() || self.times[idx] != dt {
-            return Err(pyo3::exceptions::PyValueError::new_err(
-                "time not found in evaluated timestamps",
-            ));
-        }
-
+    ///
+    /// `mode` is "exact", "nearest" or "previous", as for
+    /// `ConstraintResult.in_constraint`.
+    #[pyo3(signature = (time, mode="exact"))]
+    fn in_constraint(&self, time: &Bound<PyAny>, mode: &str) -> PyResult<bool> {
+        let mode = TimeLookup::parse(mode)?;
+        let idx = lookup_index(&self.times, python_datetime_to_utc(time)?, mode)?;
         Ok(self.constraint_vec[idx])
     }
 
+    /// Vectorized `in_constraint` over a sequence or datetime64 array of times
+    #[pyo3(signature = (times, mode="exact"))]
+    fn in_constraint_array(
+        &self,
+        py: Python,
+        times: &Bound<PyAny>,
+        mode: &str,
+    ) -> PyResult<Py<PyAny>> {
+        let mode = TimeLookup::parse(mode)?;
+        let values = python_times_to_utc(times)?
+            .into_iter()
+            .map(|dt| Ok(self.constraint_vec[lookup_index(&self.times, dt, mode)?]))
+            .collect::<PyResult<Vec<bool>>>()?;
+        Ok(Array1::from_vec(values).into_pyarray(py).into())
+    }
+
     /// Property: array of visibility windows when target is not constrained
     #[getter]
     fn visibility(&self, py: Python) -> PyResult<Vec<VisibilityWindow>> {
@@ -534,6 +1009,39 @@ impl MovingBodyResult {
 /// Configuration for constraint evaluation
 ///
 /// This is the base trait that all constraint configurations must implement.
+/// Configs are plain serde structs; the commonly used ones also have a
+/// builder that validates the limits the same way the Python constructors do.
+/// Builders and evaluators report failures as [`ConstraintError`]:
+///
+/// ```
+/// use chrono::{Duration, TimeZone, Utc};
+/// use ndarray::Array2;
+/// use rust_ephem::{ArrayEphemeris, ConstraintConfig, SunProximityConfig};
+///
+/// // A circular equatorial orbit at 500 km, sampled once a minute
+/// let start = Utc.with_ymd_and_hms(2025, 3, 20, 0, 0, 0).unwrap();
+/// let times: Vec<_> = (0..10).map(|i| start + Duration::minutes(i)).collect();
+/// let (radius, speed) = (6878.0, 7.61);
+/// let states = Array2::from_shape_fn((times.len(), 6), |(i, k)| {
+///     let phase = speed / radius * 60.0 * i as f64;
+///     match k {
+///         0 => radius * phase.cos(),
+///         1 => radius * phase.sin(),
+///         3 => -speed * phase.sin(),
+///         4 => speed * phase.cos(),
+///         _ => 0.0,
+///     }
+/// });
+/// let ephemeris = ArrayEphemeris::from_gcrs_states(times, states)?;
+///
+/// let config = SunProximityConfig::builder().min_angle(45.0).build()?;
+/// let evaluator = config.to_evaluator();
+/// // At the March equinox the Sun is near RA 0, Dec 0
+/// let violated = evaluator.in_constraint_batch(&ephemeris, &[0.0, 180.0], &[0.0, 0.0], None)?;
+/// assert!(violated.row(0).iter().all(|&v| v));
+/// assert!(violated.row(1).iter().all(|&v| !v));
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
 pub trait ConstraintConfig: fmt::Debug + Send + Sync {
     /// Create a constraint evaluator from this configuration
     fn to_evaluator(&self) -> Box<dyn ConstraintEvaluator>;
@@ -560,7 +1068,7 @@ pub trait ConstraintEvaluator: Send + Sync {
         target_ra: f64,
         target_dec: f64,
         time_indices: Option<&[usize]>,
-    ) -> PyResult<ConstraintResult>;
+    ) -> Result<ConstraintResult, ConstraintError>;
 
     /// Check if targets are in-constraint for multiple RA/Dec positions (vectorized)
     ///
@@ -578,7 +1086,7 @@ pub trait ConstraintEvaluator: Send + Sync {
         target_ras: &[f64],
         target_decs: &[f64],
         time_indices: Option<&[usize]>,
-    ) -> PyResult<Array2<bool>>;
+    ) -> Result<Array2<bool>, ConstraintError>;
 
     /// Optional vectorized batch evaluation using precomputed unit vectors.
     ///
@@ -595,7 +1103,7 @@ pub trait ConstraintEvaluator: Send + Sync {
         _ephemeris: &dyn crate::ephemeris::ephemeris_common::EphemerisBase,
         _target_unit_vectors: &Array2<f64>,
         _time_indices: Option<&[usize]>,
-    ) -> PyResult<Option<Array2<bool>>> {
+    ) -> Result<Option<Array2<bool>>, ConstraintError> {
         Ok(None)
     }
 
@@ -619,7 +1127,7 @@ pub trait ConstraintEvaluator: Send + Sync {
         ephemeris: &dyn crate::ephemeris::ephemeris_common::EphemerisBase,
         target_ras: &[f64],
         target_decs: &[f64],
-    ) -> PyResult<Vec<bool>> {
+    ) -> Result<Vec<bool>, ConstraintError> {
         // Default: compute full N×N and extract diagonal
         let n = target_ras.len();
         let time_indices: Vec<usize> = (0..n).collect();
@@ -654,7 +1162,7 @@ pub trait ConstraintEvaluator: Send + Sync {
         target_unit_vectors: &Array2<f64>,
         time_index: usize,
         _roll_deg: f64,
-    ) -> PyResult<Vec<bool>> {
+    ) -> Result<Vec<bool>, ConstraintError> {
         let n_targets = target_unit_vectors.nrows();
 
         if let Some(result) = self.in_constraint_batch_unit_vectors(
@@ -704,7 +1212,7 @@ pub trait ConstraintEvaluator: Send + Sync {
         target_unit_vectors: &Array2<f64>,
         time_index: usize,
         n_roll_samples: usize,
-    ) -> PyResult<Vec<bool>> {
+    ) -> Result<Vec<bool>, ConstraintError> {
         // For roll-independent constraints the result is identical at every roll
         // angle, so a single evaluation at roll=0° is sufficient.
         if !self.is_roll_dependent() {
@@ -742,6 +1250,22 @@ pub trait ConstraintEvaluator: Send + Sync {
         Ok(accessible.iter().map(|&a| !a).collect())
     }
 
+    /// Continuous quantity the constraint thresholds, for one target
+    ///
+    /// One value per selected time, e.g. the separation angle for proximity
+    /// constraints, the altitude for Alt/Az or the airmass. Useful to see
+    /// why a window opens or closes. `Ok(None)` for constraints without a
+    /// single underlying quantity (logical combinations, polygons).
+    fn metric(
+        &self,
+        _ephemeris: &dyn crate::ephemeris::ephemeris_common::EphemerisBase,
+        _target_ra: f64,
+        _target_dec: f64,
+        _time_indices: Option<&[usize]>,
+    ) -> Result<Option<Vec<f64>>, ConstraintError> {
+        Ok(None)
+    }
+
     /// Get constraint name
     fn name(&self) -> String;
 
@@ -750,11 +1274,187 @@ pub trait ConstraintEvaluator: Send + Sync {
     fn as_any(&self) -> &dyn std::any::Any;
 }
 
+/// Run `f`, timed under the evaluator's name when profiling is enabled
+pub(crate) fn profiled<T>(evaluator: &dyn ConstraintEvaluator, f: impl FnOnce() -> T) -> T {
+    crate::utils::profiling::timed(
+        crate::utils::profiling::Category::Evaluator,
+        || evaluator.name(),
+        f,
+    )
+}
+
+/// Diagonal batch result of a constraint that only depends on time
+///
+/// Target `i` pairs with time `i` and the targets themselves are ignored, so
+/// the diagonal is the first `n_targets` samples. `samples` computes the
+/// per-time values for the given indices and `violated` tests each one.
+pub(crate) fn time_only_diagonal<T>(
+    n_targets: usize,
+    samples: impl FnOnce(Option<&[usize]>) -> Result<Vec<T>, ConstraintError>,
+    violated: impl Fn(&T) -> bool,
+) -> Result<Vec<bool>, ConstraintError> {
+    let indices: Vec<usize> = (0..n_targets).collect();
+    Ok(samples(Some(&indices))?.iter().map(violated).collect())
+}
+
+/// Macro to generate `validate` and a builder for proximity configs
+///
+/// The config needs `min_angle`, `max_angle`, `limb_relative` and `schedule`
+/// fields. `build()` validates the limits and schedule, so a built config is
+/// always one `Constraint.sun_proximity()` and friends would accept.
+macro_rules! impl_proximity_builder {
+    ($config:ident, $builder:ident) => {
+        impl $config {
+            /// Start a builder with no limits (`min_angle` 0, no maximum)
+            pub fn builder() -> $builder {
+                $builder::default()
+            }
+
+            /// Check the angle limits and schedule
+            pub fn validate(&self) -> Result<(), String> {
+                $crate::constraints::threshold_schedule::validate_angle_limits(
+                    self.min_angle,
+                    self.max_angle,
+                )?;
+                $crate::constraints::threshold_schedule::validate_schedule(
+                    &self.schedule,
+                    self.min_angle,
+                    self.max_angle,
+                )
+            }
+        }
+
+        #[doc = concat!("Builder for [`", stringify!($config), "`]")]
+        #[derive(Debug, Clone, Default)]
+        pub struct $builder {
+            min_angle: f64,
+            max_angle: Option<f64>,
+            limb_relative: bool,
+            schedule: Vec<ThresholdPeriod>,
+        }
+
+        impl $builder {
+            /// Minimum allowed separation in degrees
+            pub fn min_angle(mut self, degrees: f64) -> Self {
+                self.min_angle = degrees;
+                self
+            }
+
+            /// Maximum allowed separation in degrees
+            pub fn max_angle(mut self, degrees: f64) -> Self {
+                self.max_angle = Some(degrees);
+                self
+            }
+
+            /// Apply the limits to the separation from the body's limb
+            pub fn limb_relative(mut self, limb_relative: bool) -> Self {
+                self.limb_relative = limb_relative;
+                self
+            }
+
+            /// Add a period overriding the limits
+            pub fn period(mut self, period: ThresholdPeriod) -> Self {
+                self.schedule.push(period);
+                self
+            }
+
+            /// Validate and return the config
+            pub fn build(self) -> Result<$config, $crate::constraints::error::ConstraintError> {
+                let config = $config {
+                    min_angle: self.min_angle,
+                    max_angle: self.max_angle,
+                    limb_relative: self.limb_relative,
+                    schedule: self.schedule,
+                };
+                config
+                    .validate()
+                    .map_err($crate::constraints::error::ConstraintError::InvalidInput)?;
+                Ok(config)
+            }
+        }
+    };
+}
+
 /// Macro to generate common methods for proximity evaluators
 /// This is exported so constraint modules can use it
+///
+/// The evaluator needs `min_angle_deg`, `max_angle_deg`, `schedule` and
+/// `limb_radius_km` fields; with a limb radius the limits apply to the
+/// separation from the body's limb (centre separation minus its apparent
+/// angular radius).
 macro_rules! impl_proximity_evaluator {
     ($evaluator:ty, $body_name:expr, $friendly_name:expr, $positions:ident) => {
         impl $evaluator {
+            /// Apparent angular radius (degrees) subtracted from the centre
+            /// separation at `body_dist` km, or 0 when limits apply to the centre
+            fn limb_offset_deg(&self, body_dist: f64) -> f64 {
+                match self.limb_radius_km {
+                    Some(radius) if body_dist > 0.0 => {
+                        (radius / body_dist).min(1.0).asin().to_degrees()
+                    }
+                    _ => 0.0,
+                }
+            }
+
+            /// Minimum and maximum angles (degrees) in effect at `time`
+            fn limits_at(&self, time: &DateTime<Utc>) -> (f64, Option<f64>) {
+                crate::constraints::threshold_schedule::limits_at(
+                    &self.schedule,
+                    self.min_angle_deg,
+                    self.max_angle_deg,
+                    time,
+                )
+            }
+
+            /// Cosines of the minimum and maximum centre separations allowed
+            /// at `time` with the body at `body_dist` km
+            ///
+            /// For angle comparison: angle < threshold ⟺ cos(angle) > cos(threshold)
+            fn cos_thresholds(&self, time: &DateTime<Utc>, body_dist: f64) -> (f64, Option<f64>) {
+                let offset = self.limb_offset_deg(body_dist);
+                let (min_angle, max_angle) = self.limits_at(time);
+                (
+                    (min_angle + offset).min(180.0).to_radians().cos(),
+                    max_angle.map(|max| (max + offset).min(180.0).to_radians().cos()),
+                )
+            }
+
+            /// Separation in degrees from the body's centre, or from its limb
+            /// when the limits apply to the limb, at each time
+            fn separation_deg(
+                &self,
+                target_ra_dec: (f64, f64),
+                $positions: &Array2<f64>,
+                observer_positions: &Array2<f64>,
+            ) -> Vec<f64> {
+                let target_vec = crate::utils::vector_math::radec_to_unit_vector(
+                    target_ra_dec.0,
+                    target_ra_dec.1,
+                );
+                (0..$positions.nrows())
+                    .map(|i| {
+                        let body_pos = [$positions[[i, 0]], $positions[[i, 1]], $positions[[i, 2]]];
+                        let obs_pos = [
+                            observer_positions[[i, 0]],
+                            observer_positions[[i, 1]],
+                            observer_positions[[i, 2]],
+                        ];
+                        let cos_angle = crate::utils::vector_math::calculate_cosine_separation(
+                            &target_vec,
+                            &body_pos,
+                            &obs_pos,
+                        );
+                        let body_dist = crate::utils::vector_math::vector_magnitude(&[
+                            body_pos[0] - obs_pos[0],
+                            body_pos[1] - obs_pos[1],
+                            body_pos[2] - obs_pos[2],
+                        ]);
+                        cos_angle.clamp(-1.0, 1.0).acos().to_degrees()
+                            - self.limb_offset_deg(body_dist)
+                    })
+                    .collect()
+            }
+
             #[allow(dead_code)]
             fn evaluate_common(
                 &self,
@@ -771,11 +1471,6 @@ macro_rules! impl_proximity_evaluator {
                     target_ra_dec.1,
                 );
 
-                // Pre-compute cosine thresholds (avoids acos() in inner loop)
-                // For angle comparison: angle < threshold ⟺ cos(angle) > cos(threshold)
-                let min_cos_threshold = self.min_angle_deg.to_radians().cos();
-                let max_cos_threshold = self.max_angle_deg.map(|max| max.to_radians().cos());
-
                 let violations = track_violations(
                     times,
                     |i| {
@@ -792,6 +1487,13 @@ macro_rules! impl_proximity_evaluator {
                             &body_pos,
                             &obs_pos,
                         );
+                        let body_dist = crate::utils::vector_math::vector_magnitude(&[
+                            body_pos[0] - obs_pos[0],
+                            body_pos[1] - obs_pos[1],
+                            body_pos[2] - obs_pos[2],
+                        ]);
+                        let (min_cos_threshold, max_cos_threshold) =
+                            self.cos_thresholds(&times[i], body_dist);
 
                         // Check constraints using cosine comparison
                         // too_close: angle < min_angle ⟺ cos(angle) > cos(min_angle)
@@ -803,10 +1505,12 @@ macro_rules! impl_proximity_evaluator {
                         // Compute severity using the angle (required for violation windows)
                         // Only compute acos when there's actually a violation to report
                         let severity = if is_violated {
-                            let angle_deg = cos_angle.clamp(-1.0, 1.0).acos().to_degrees();
-                            if angle_deg < self.min_angle_deg {
-                                (self.min_angle_deg - angle_deg) / self.min_angle_deg
-                            } else if let Some(max) = self.max_angle_deg {
+                            let angle_deg = cos_angle.clamp(-1.0, 1.0).acos().to_degrees()
+                                - self.limb_offset_deg(body_dist);
+                            let (min_angle, max_angle) = self.limits_at(&times[i]);
+                            if angle_deg < min_angle {
+                                (min_angle - angle_deg) / min_angle
+                            } else if let Some(max) = max_angle {
                                 (angle_deg - max) / max
                             } else {
                                 0.0
@@ -887,9 +1591,21 @@ where
 /// Returns: (times_filtered, body_positions_filtered, observer_positions_filtered)
 macro_rules! extract_body_ephemeris_data {
     ($ephemeris:expr, $time_indices:expr, $body_getter:ident) => {{
-        let times = $ephemeris.get_times()?;
-        let body_positions = $ephemeris.$body_getter()?;
-        let observer_positions = $ephemeris.get_gcrs_positions()?;
+        use $crate::constraints::error::OrMissing;
+        let _span =
+            $crate::utils::profiling::span($crate::utils::profiling::Category::Extraction, || {
+                stringify!($body_getter)
+                    .trim_start_matches("get_")
+                    .to_string()
+            });
+        let times = $ephemeris.get_times().or_missing("times")?;
+        let body = stringify!($body_getter)
+            .trim_start_matches("get_")
+            .replace('_', " ");
+        let body_positions = $ephemeris.$body_getter().or_missing(&body)?;
+        let observer_positions = $ephemeris
+            .get_gcrs_positions()
+            .or_missing("GCRS positions")?;
 
         if let Some(indices) = $time_indices {
             let filtered_times: Vec<DateTime<Utc>> = indices.iter().map(|&i| times[i]).collect();
@@ -916,8 +1632,15 @@ macro_rules! extract_standard_ephemeris_data {
 /// Returns: (times_filtered, observer_positions_filtered)
 macro_rules! extract_observer_ephemeris_data {
     ($ephemeris:expr, $time_indices:expr) => {{
-        let times = $ephemeris.get_times()?;
-        let observer_positions = $ephemeris.get_gcrs_positions()?;
+        use $crate::constraints::error::OrMissing;
+        let _span =
+            $crate::utils::profiling::span($crate::utils::profiling::Category::Extraction, || {
+                "observer_positions".to_string()
+            });
+        let times = $ephemeris.get_times().or_missing("times")?;
+        let observer_positions = $ephemeris
+            .get_gcrs_positions()
+            .or_missing("GCRS positions")?;
 
         if let Some(indices) = $time_indices {
             let filtered_times: Vec<DateTime<Utc>> = indices.iter().map(|&i| times[i]).collect();
@@ -935,7 +1658,12 @@ macro_rules! extract_observer_ephemeris_data {
 /// Returns: (times_filtered,)
 macro_rules! extract_time_data {
     ($ephemeris:expr, $time_indices:expr) => {{
-        let times = $ephemeris.get_times()?;
+        use $crate::constraints::error::OrMissing;
+        let _span =
+            $crate::utils::profiling::span($crate::utils::profiling::Category::Extraction, || {
+                "times".to_string()
+            });
+        let times = $ephemeris.get_times().or_missing("times")?;
 
         let times_filtered = if let Some(indices) = $time_indices {
             indices.iter().map(|&i| times[i]).collect()
@@ -951,41 +1679,158 @@ macro_rules! extract_time_data {
 /// Usage: extract_latlon_data!(ephemeris, time_indices)
 macro_rules! extract_latlon_data {
     ($ephemeris:expr, $time_indices:expr) => {{
-        let (lats_vec, lons_vec) = {
-            use numpy::{PyArray1, PyArrayMethods};
-            use pyo3::Python;
-
-            Python::attach(|py| -> pyo3::PyResult<(Vec<f64>, Vec<f64>)> {
-                let lat_opt = $ephemeris.get_latitude_deg(py)?;
-                let lon_opt = $ephemeris.get_longitude_deg(py)?;
-
-                let lat_array = lat_opt.ok_or_else(|| {
-                    pyo3::exceptions::PyRuntimeError::new_err("Latitude data not available")
-                })?;
-                let lon_array = lon_opt.ok_or_else(|| {
-                    pyo3::exceptions::PyRuntimeError::new_err("Longitude data not available")
-                })?;
-
-                let lat_bound = lat_array.downcast_bound::<PyArray1<f64>>(py)?;
-                let lon_bound = lon_array.downcast_bound::<PyArray1<f64>>(py)?;
-
-                let lats = lat_bound.readonly().as_slice()?.to_vec();
-                let lons = lon_bound.readonly().as_slice()?.to_vec();
-
-                Ok((lats, lons))
-            })?
-        };
-        let times = $ephemeris.get_times()?;
+        use $crate::constraints::error::OrMissing;
+        let _span =
+            $crate::utils::profiling::span($crate::utils::profiling::Category::Extraction, || {
+                "geodetic".to_string()
+            });
+        let geodetic = $ephemeris.geodetic().or_missing("times")?.ok_or_else(|| {
+            $crate::constraints::error::ConstraintError::Ephemeris(
+                "Latitude/longitude data not available".to_string(),
+            )
+        })?;
+        let (lats_all, lons_all) = (geodetic.latitude_deg, geodetic.longitude_deg);
+        let times = $ephemeris.get_times().or_missing("times")?;
 
         let (times_slice, lats_slice, lons_slice) = if let Some(indices) = $time_indices {
             let filtered_times: Vec<DateTime<Utc>> = indices.iter().map(|&i| times[i]).collect();
-            let filtered_lats: Vec<f64> = indices.iter().map(|&i| lats_vec[i]).collect();
-            let filtered_lons: Vec<f64> = indices.iter().map(|&i| lons_vec[i]).collect();
+            let filtered_lats: Vec<f64> = indices.iter().map(|&i| lats_all[i]).collect();
+            let filtered_lons: Vec<f64> = indices.iter().map(|&i| lons_all[i]).collect();
             (filtered_times, filtered_lats, filtered_lons)
         } else {
-            (times.to_vec(), lats_vec, lons_vec)
+            (times.to_vec(), lats_all.to_vec(), lons_all.to_vec())
         };
 
         (times_slice, lats_slice, lons_slice)
     }};
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+
+    fn minutes(n: i64) -> Vec<DateTime<Utc>> {
+        let start = Utc.with_ymd_and_hms(2024, 1, 1, 0, 0, 0).unwrap();
+        (0..n).map(|i| start + Duration::minutes(i)).collect()
+    }
+
+    fn violation(start: DateTime<Utc>, end: DateTime<Utc>) -> ConstraintViolation {
+        ConstraintViolation {
+            start_time_internal: start,
+            end_time_internal: end,
+            max_severity: 1.0,
+            description: "test".to_string(),
+        }
+    }
+
+    #[test]
+    fn test_time_grid_compression() {
+        let times = minutes(5);
+        let grid = TimeGrid::new(times.clone(), ResultStorage::Compressed);
+        assert!(matches!(grid, TimeGrid::Uniform { count: 5, .. }));
+        assert_eq!(grid.times().unwrap().as_ref(), times.as_slice());
+
+        let mut uneven = times.clone();
+        uneven[3] += Duration::seconds(1);
+        assert_eq!(
+            TimeGrid::new(uneven.clone(), ResultStorage::Compressed),
+            TimeGrid::Explicit(uneven)
+        );
+        assert_eq!(
+            TimeGrid::new(times, ResultStorage::Windows),
+            TimeGrid::Dropped { count: 5 }
+        );
+    }
+
+    #[test]
+    fn test_uniform_lookup_matches_explicit() {
+        let times = minutes(4);
+        let explicit = TimeGrid::new(times.clone(), ResultStorage::Full);
+        let uniform = TimeGrid::new(times.clone(), ResultStorage::Compressed);
+        let probes = [-30, 0, 20, 30, 40, 60, 150, 180, 200];
+        for mode in [TimeLookup::Exact, TimeLookup::Nearest, TimeLookup::Previous] {
+            for seconds in probes {
+                let dt = times[0] + Duration::seconds(seconds);
+                assert_eq!(
+                    uniform.lookup(dt, mode).ok(),
+                    explicit.lookup(dt, mode).ok(),
+                    "offset {} s",
+                    seconds
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_windows_storage_keeps_windows() {
+        let times = minutes(6);
+        let violations = vec![violation(times[2], times[3])];
+        let full = ConstraintResult::new(violations.clone(), false, "c".into(), times.clone());
+        let windows = ConstraintResult::with_storage(
+            violations,
+            false,
+            "c".into(),
+            times,
+            ResultStorage::Windows,
+        );
+
+        assert_eq!(windows.n_times(), 6);
+        assert!(windows.times.times().is_err());
+        assert_eq!(
+            windows.satisfied_windows().unwrap(),
+            full.satisfied_windows().unwrap()
+        );
+        assert_eq!(windows.satisfied_windows().unwrap().len(), 2);
+    }
+
+    #[test]
+    fn test_single_sample_windows() {
+        let times = minutes(6);
+        let violations = vec![violation(times[1], times[4])];
+        let result = ConstraintResult::new(violations, false, "c".into(), times.clone());
+        // The leading sample closes on a violation and has no duration; the
+        // trailing one is still open when the evaluation ends
+        assert_eq!(
+            result.satisfied_windows().unwrap(),
+            vec![(times[5], times[5])]
+        );
+    }
+
+    #[test]
+    fn test_saved_result_round_trip() {
+        let times = minutes(6);
+        let violations = vec![violation(times[2], times[3])];
+        for storage in [
+            ResultStorage::Full,
+            ResultStorage::Compressed,
+            ResultStorage::Windows,
+        ] {
+            let result = ConstraintResult::with_storage(
+                violations.clone(),
+                false,
+                "c".into(),
+                times.clone(),
+                storage,
+            )
+            .with_spec(r#"{"type":"sun","min_angle":45.0}"#.into())
+            .with_provenance(Arc::new(serde_json::json!({ "format": "test" })));
+            let text = serde_json::to_string(&result.to_saved().unwrap()).unwrap();
+            let loaded =
+                ConstraintResult::from_saved(serde_json::from_str(&text).unwrap()).unwrap();
+
+            assert_eq!(loaded.times, result.times);
+            assert_eq!(loaded.storage, storage);
+            assert_eq!(loaded.violations.len(), 1);
+            assert_eq!(loaded.violations[0].start_time_internal, times[2]);
+            assert_eq!(
+                loaded.satisfied_windows().unwrap(),
+                result.satisfied_windows().unwrap()
+            );
+            let spec: serde_json::Value =
+                serde_json::from_str(loaded.spec_json.as_deref().unwrap()).unwrap();
+            assert_eq!(spec["min_angle"], 45.0);
+            assert_eq!(loaded.provenance.unwrap()["format"], "test");
+        }
+    }
+}
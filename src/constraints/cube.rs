@@ -0,0 +1,165 @@
+//! Constraint cubes for survey planning
+//!
+//! Evaluates several constraints for many targets over an ephemeris in one
+//! call and keeps the violation masks as a single boolean array of shape
+//! (n_constraints, n_targets, n_times), with the common reductions over the
+//! constraint axis done in Rust.
+//!
+//! The work is split into (constraint, block of targets) tasks run on the
+//! rayon work-stealing pool with the GIL released. Every task borrows the
+//! same ephemeris, so its positions are extracted once rather than copied
+//! to worker processes, and each task still uses the vectorized batch path
+//! for its block of targets.
+
+use chrono::{DateTime, Utc};
+use ndarray::{s, Array3, Axis};
+use numpy::IntoPyArray;
+use pyo3::prelude::*;
+use rayon::prelude::*;
+use std::ops::Range;
+
+use crate::constraints::PyConstraint;
+use crate::ephemeris::ground_network::timestamp_array;
+use crate::ephemeris::with_ephemeris;
+
+/// Violation masks of several constraints for many targets
+#[pyclass]
+pub struct ConstraintCube {
+    /// Violation masks, true = violated, shape (n_constraints, n_targets, n_times)
+    cube: Array3<bool>,
+    names: Vec<String>,
+    times: Vec<DateTime<Utc>>,
+}
+
+#[pymethods]
+impl ConstraintCube {
+    /// Violation masks as a boolean array of shape (n_constraints, n_targets, n_times)
+    ///
+    /// True means the constraint is violated (target not observable).
+    #[getter]
+    fn cube(&self, py: Python) -> Py<PyAny> {
+        self.cube.clone().into_pyarray(py).into()
+    }
+
+    /// Constraint names, in the order of the first axis
+    #[getter]
+    fn constraint_names(&self) -> Vec<String> {
+        self.names.clone()
+    }
+
+    /// Evaluation times
+    #[getter]
+    fn timestamp(&self, py: Python) -> PyResult<Py<PyAny>> {
+        timestamp_array(py, &self.times)
+    }
+
+    /// Cube shape (n_constraints, n_targets, n_times)
+    #[getter]
+    fn shape(&self) -> (usize, usize, usize) {
+        self.cube.dim()
+    }
+
+    /// Where every constraint is satisfied, shape (n_targets, n_times)
+    ///
+    /// True means the target is observable at that time.
+    fn all_constraints(&self, py: Python) -> Py<PyAny> {
+        self.cube
+            .map_axis(Axis(0), |lane| !lane.iter().any(|&v| v))
+            .into_pyarray(py)
+            .into()
+    }
+
+    /// Where at least one constraint is violated, shape (n_targets, n_times)
+    fn any_violation(&self, py: Python) -> Py<PyAny> {
+        self.cube
+            .map_axis(Axis(0), |lane| lane.iter().any(|&v| v))
+            .into_pyarray(py)
+            .into()
+    }
+
+    fn __repr__(&self) -> String {
+        let (n_constraints, n_targets, n_times) = self.cube.dim();
+        format!(
+            "ConstraintCube(n_constraints={}, n_targets={}, n_times={})",
+            n_constraints, n_targets, n_times
+        )
+    }
+}
+
+/// Default number of targets per task
+pub const DEFAULT_CHUNK_SIZE: usize = 256;
+
+/// Consecutive blocks of at most `chunk_size` of `n_targets` targets
+fn target_chunks(n_targets: usize, chunk_size: usize) -> Vec<Range<usize>> {
+    (0..n_targets)
+        .step_by(chunk_size)
+        .map(|start| start..(start + chunk_size).min(n_targets))
+        .collect()
+}
+
+/// Evaluate `constraints` for all targets over all ephemeris times
+///
+/// Each (constraint, block of `chunk_size` targets) pair is one task on the
+/// rayon pool; the masks are written into the cube once all tasks finish.
+pub fn constraint_cube(
+    py: Python,
+    ephemeris: &Bound<'_, PyAny>,
+    constraints: &[PyRef<PyConstraint>],
+    target_ras: &[f64],
+    target_decs: &[f64],
+    chunk_size: usize,
+) -> PyResult<ConstraintCube> {
+    if target_ras.len() != target_decs.len() {
+        return Err(pyo3::exceptions::PyValueError::new_err(
+            "target_ras and target_decs must have the same length",
+        ));
+    }
+    if chunk_size == 0 {
+        return Err(pyo3::exceptions::PyValueError::new_err(
+            "chunk_size must be positive",
+        ));
+    }
+    let constraints: Vec<&PyConstraint> = constraints.iter().map(|c| &**c).collect();
+    let tasks: Vec<(usize, Range<usize>)> = (0..constraints.len())
+        .flat_map(|k| {
+            target_chunks(target_ras.len(), chunk_size)
+                .into_iter()
+                .map(move |chunk| (k, chunk))
+        })
+        .collect();
+    with_ephemeris(ephemeris, |ephem| {
+        let times = ephem.get_times()?;
+        let masks = py.detach(|| {
+            tasks
+                .into_par_iter()
+                .map(|(k, chunk)| {
+                    let mask = constraints[k].violation_mask(
+                        ephem,
+                        &target_ras[chunk.clone()],
+                        &target_decs[chunk.clone()],
+                    )?;
+                    Ok((k, chunk, mask))
+                })
+                .collect::<PyResult<Vec<_>>>()
+        })?;
+        let mut cube = Array3::from_elem((constraints.len(), target_ras.len(), times.len()), false);
+        for (k, chunk, mask) in masks {
+            cube.slice_mut(s![k, chunk, ..]).assign(&mask);
+        }
+        let names = constraints.iter().map(|c| c.name()).collect();
+        Ok(ConstraintCube { cube, names, times })
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_target_chunks() {
+        assert_eq!(target_chunks(5, 2), vec![0..2, 2..4, 4..5]);
+        assert_eq!(target_chunks(4, 4), vec![0..4]);
+        assert_eq!(target_chunks(3, 256), vec![0..3]);
+        assert!(target_chunks(0, 256).is_empty());
+    }
+}
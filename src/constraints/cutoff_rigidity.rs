@@ -0,0 +1,195 @@
+use super::core::{
+    time_only_diagonal, track_violations, ConstraintConfig, ConstraintEvaluator, ConstraintResult,
+};
+/// Geomagnetic cutoff rigidity constraint implementation
+///
+/// Violated while the spacecraft's vertical cutoff rigidity is below a
+/// threshold, i.e. where the geomagnetic field shields it poorly from solar
+/// energetic particles and galactic cosmic rays. Rigidities come from the
+/// centred IGRF dipole (see `utils::geomagnetic`) and do not depend on the
+/// target, so this behaves like the SAA constraint: a pure function of time.
+use crate::constraints::error::{ConstraintError, OrMissing};
+use crate::ephemeris::ephemeris_common::EphemerisBase;
+use chrono::{DateTime, Utc};
+use ndarray::Array2;
+use serde::{Deserialize, Serialize};
+
+/// Configuration for the cutoff rigidity constraint
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CutoffRigidityConfig {
+    /// Minimum vertical cutoff rigidity in GV
+    pub min_rigidity: f64,
+}
+
+impl CutoffRigidityConfig {
+    /// Check the rigidity threshold
+    pub fn validate(&self) -> Result<(), String> {
+        if !(self.min_rigidity.is_finite() && self.min_rigidity > 0.0) {
+            return Err("min_rigidity must be positive".to_string());
+        }
+        Ok(())
+    }
+}
+
+impl ConstraintConfig for CutoffRigidityConfig {
+    fn to_evaluator(&self) -> Box<dyn ConstraintEvaluator> {
+        Box::new(CutoffRigidityEvaluator {
+            min_rigidity: self.min_rigidity,
+        })
+    }
+}
+
+/// Evaluator for the cutoff rigidity constraint
+pub struct CutoffRigidityEvaluator {
+    min_rigidity: f64,
+}
+
+impl CutoffRigidityEvaluator {
+    fn format_name(&self) -> String {
+        format!("CutoffRigidityConstraint(min={:.2} GV)", self.min_rigidity)
+    }
+
+    /// Cutoff rigidities in GV at the selected ephemeris times
+    fn rigidities(
+        ephemeris: &dyn EphemerisBase,
+        time_indices: Option<&[usize]>,
+    ) -> Result<Vec<f64>, ConstraintError> {
+        ephemeris.compute_geomagnetic_caches().or_missing("times")?;
+        let all = ephemeris
+            .data()
+            .cutoff_rigidity_cache
+            .get()
+            .ok_or_else(|| ConstraintError::ephemeris("Cutoff rigidity data not available"))?;
+        Ok(match time_indices {
+            Some(indices) => indices.iter().map(|&i| all[i]).collect(),
+            None => all.to_vec(),
+        })
+    }
+
+    /// Evaluate the constraint with pre-computed cutoff rigidities
+    pub fn evaluate_with_rigidities(
+        &self,
+        times: &[DateTime<Utc>],
+        rigidities: &[f64],
+    ) -> ConstraintResult {
+        let violations = track_violations(
+            times,
+            |i| {
+                let shortfall = self.min_rigidity - rigidities[i];
+                (shortfall > 0.0, shortfall.max(0.0))
+            },
+            |i, _| {
+                format!(
+                    "Cutoff rigidity {:.2} GV below minimum {:.2} GV",
+                    rigidities[i], self.min_rigidity
+                )
+            },
+        );
+
+        let all_satisfied = violations.is_empty();
+        ConstraintResult::new(
+            violations,
+            all_satisfied,
+            self.format_name(),
+            times.to_vec(),
+        )
+    }
+}
+
+impl ConstraintEvaluator for CutoffRigidityEvaluator {
+    fn evaluate(
+        &self,
+        ephemeris: &dyn EphemerisBase,
+        _target_ra: f64,
+        _target_dec: f64,
+        time_indices: Option<&[usize]>,
+    ) -> Result<ConstraintResult, ConstraintError> {
+        let times = ephemeris.get_times().or_missing("times")?;
+        let times_filtered: Vec<DateTime<Utc>> = match time_indices {
+            Some(indices) => indices.iter().map(|&i| times[i]).collect(),
+            None => times,
+        };
+        let rigidities = Self::rigidities(ephemeris, time_indices)?;
+        Ok(self.evaluate_with_rigidities(&times_filtered, &rigidities))
+    }
+
+    fn in_constraint_batch(
+        &self,
+        ephemeris: &dyn EphemerisBase,
+        target_ras: &[f64],
+        _target_decs: &[f64],
+        time_indices: Option<&[usize]>,
+    ) -> Result<Array2<bool>, ConstraintError> {
+        let rigidities = Self::rigidities(ephemeris, time_indices)?;
+        Ok(Array2::from_shape_fn(
+            (target_ras.len(), rigidities.len()),
+            |(_, i)| rigidities[i] < self.min_rigidity,
+        ))
+    }
+
+    fn in_constraint_batch_diagonal(
+        &self,
+        ephemeris: &dyn EphemerisBase,
+        target_ras: &[f64],
+        _target_decs: &[f64],
+    ) -> Result<Vec<bool>, ConstraintError> {
+        time_only_diagonal(
+            target_ras.len(),
+            |indices| Self::rigidities(ephemeris, indices),
+            |&r| r < self.min_rigidity,
+        )
+    }
+
+    /// Vertical cutoff rigidity in GV
+    fn metric(
+        &self,
+        ephemeris: &dyn EphemerisBase,
+        _target_ra: f64,
+        _target_dec: f64,
+        time_indices: Option<&[usize]>,
+    ) -> Result<Option<Vec<f64>>, ConstraintError> {
+        Self::rigidities(ephemeris, time_indices).map(Some)
+    }
+
+    fn name(&self) -> String {
+        self.format_name()
+    }
+
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::{Duration, TimeZone};
+
+    #[test]
+    fn test_validate() {
+        assert!(CutoffRigidityConfig { min_rigidity: 2.0 }
+            .validate()
+            .is_ok());
+        assert!(CutoffRigidityConfig { min_rigidity: 0.0 }
+            .validate()
+            .is_err());
+        assert!(CutoffRigidityConfig {
+            min_rigidity: f64::NAN
+        }
+        .validate()
+        .is_err());
+    }
+
+    #[test]
+    fn test_evaluate_with_rigidities() {
+        let evaluator = CutoffRigidityEvaluator { min_rigidity: 3.0 };
+        let start = Utc.with_ymd_and_hms(2025, 1, 1, 0, 0, 0).unwrap();
+        let times: Vec<DateTime<Utc>> = (0..5).map(|i| start + Duration::minutes(i)).collect();
+        let result = evaluator.evaluate_with_rigidities(&times, &[10.0, 2.5, 1.0, 4.0, 2.0]);
+
+        assert!(!result.all_satisfied);
+        assert_eq!(result.violations.len(), 2);
+        assert_eq!(result.violations[0].start_time_internal, times[1]);
+        assert!((result.violations[0].max_severity - 2.0).abs() < 1e-12);
+    }
+}
@@ -1,7 +1,17 @@
-/// Daytime constraint implementation
 use super::core::{track_violations, ConstraintConfig, ConstraintEvaluator, ConstraintResult};
+use super::eclipse::illumination_factor;
+/// Daytime constraint implementation
+///
+/// "Daytime" means different things for ground and space observers, so the
+/// constraint takes an explicit mode: the Sun above the twilight limit at the
+/// observer itself, the spacecraft in sunlight, a chosen ground site not yet
+/// dark, or the scene directly below the spacecraft lit.
+use crate::constraints::error::{ConstraintError, OrMissing};
+use crate::ephemeris::ephemeris_common::EphemerisBase;
+use crate::ephemeris::ground_access::sun_itrs_positions;
+use crate::utils::geo::{enu_basis, geodetic_to_ecef_km};
+use chrono::{DateTime, Utc};
 use ndarray::Array2;
-use pyo3::PyResult;
 use serde::{Deserialize, Serialize};
 
 /// Twilight type for daytime constraint
@@ -17,17 +27,128 @@ pub enum TwilightType {
     None,
 }
 
+impl TwilightType {
+    /// Sun altitude in degrees above which it counts as daytime
+    pub fn sun_altitude_limit(&self) -> f64 {
+        match self {
+            TwilightType::Civil => -6.0,
+            TwilightType::Nautical => -12.0,
+            TwilightType::Astronomical => -18.0,
+            TwilightType::None => -0.8333, // Approximate angle for Sun just below horizon
+        }
+    }
+
+    /// Lowercase name as accepted by the Python API
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            TwilightType::Civil => "civil",
+            TwilightType::Nautical => "nautical",
+            TwilightType::Astronomical => "astronomical",
+            TwilightType::None => "none",
+        }
+    }
+}
+
+/// What counts as daytime for the Daytime constraint
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum DaytimeMode {
+    /// Sun above the twilight limit at the observer's own position (ground observers)
+    #[default]
+    Observer,
+    /// Observer outside the Earth's umbra, i.e. a sunlit spacecraft; twilight is ignored
+    ObserverSunlit,
+    /// Sun above the twilight limit at a fixed ground site, i.e. the site is not dark
+    GroundSiteNight,
+    /// Sun above the twilight limit at the ground point directly below the observer
+    NadirSceneDaylit,
+}
+
+impl DaytimeMode {
+    /// Name as accepted by the Python API
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            DaytimeMode::Observer => "observer",
+            DaytimeMode::ObserverSunlit => "observer_sunlit",
+            DaytimeMode::GroundSiteNight => "ground_site_night",
+            DaytimeMode::NadirSceneDaylit => "nadir_scene_daylit",
+        }
+    }
+
+    /// Parse a mode name as accepted by the Python API
+    pub fn parse(name: &str) -> Option<Self> {
+        match name.to_lowercase().as_str() {
+            "observer" => Some(DaytimeMode::Observer),
+            "observer_sunlit" => Some(DaytimeMode::ObserverSunlit),
+            "ground_site_night" => Some(DaytimeMode::GroundSiteNight),
+            "nadir_scene_daylit" => Some(DaytimeMode::NadirSceneDaylit),
+            _ => None,
+        }
+    }
+}
+
 /// Configuration for Daytime constraint
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct DaytimeConfig {
     /// Twilight definition to use
     pub twilight: TwilightType,
+    /// What counts as daytime
+    #[serde(default)]
+    pub mode: DaytimeMode,
+    /// Geodetic latitude of the ground site in degrees (`GroundSiteNight` only)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub latitude: Option<f64>,
+    /// Longitude of the ground site in degrees, east positive (`GroundSiteNight` only)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub longitude: Option<f64>,
+    /// Height of the ground site above the WGS84 ellipsoid in metres (default 0)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub height: Option<f64>,
+}
+
+impl DaytimeConfig {
+    /// Check that the ground site is given exactly when the mode needs one
+    pub fn validate(&self) -> Result<(), String> {
+        let has_site = self.latitude.is_some() || self.longitude.is_some() || self.height.is_some();
+        if self.mode != DaytimeMode::GroundSiteNight {
+            if has_site {
+                return Err(
+                    "latitude, longitude and height are only used with mode='ground_site_night'"
+                        .to_string(),
+                );
+            }
+            return Ok(());
+        }
+        let (Some(latitude), Some(longitude)) = (self.latitude, self.longitude) else {
+            return Err("mode='ground_site_night' requires latitude and longitude".to_string());
+        };
+        if !(-90.0..=90.0).contains(&latitude) {
+            return Err("latitude must be between -90 and 90 degrees".to_string());
+        }
+        if !(-180.0..=360.0).contains(&longitude) {
+            return Err("longitude must be between -180 and 360 degrees".to_string());
+        }
+        if self.height.is_some_and(|h| !h.is_finite()) {
+            return Err("height must be finite".to_string());
+        }
+        Ok(())
+    }
 }
 
 impl ConstraintConfig for DaytimeConfig {
     fn to_evaluator(&self) -> Box<dyn ConstraintEvaluator> {
+        let site = match (self.mode, self.latitude, self.longitude) {
+            (DaytimeMode::GroundSiteNight, Some(latitude), Some(longitude)) => Some((
+                latitude,
+                (longitude + 180.0).rem_euclid(360.0) - 180.0,
+                self.height.unwrap_or(0.0),
+            )),
+            _ => None,
+        };
         Box::new(DaytimeEvaluator {
             twilight: self.twilight.clone(),
+            mode: self.mode,
+            site,
         })
     }
 }
@@ -35,76 +156,198 @@ impl ConstraintConfig for DaytimeConfig {
 /// Evaluator for Daytime constraint
 struct DaytimeEvaluator {
     twilight: TwilightType,
+    mode: DaytimeMode,
+    /// Ground site (latitude deg, longitude deg, height m) for `GroundSiteNight`
+    site: Option<(f64, f64, f64)>,
+}
+
+/// Sun elevation in degrees above the geodetic horizon of a ground point (ITRS, km)
+fn sun_elevation_at(sun: [f64; 3], latitude: f64, longitude: f64, height: f64) -> f64 {
+    let point = geodetic_to_ecef_km(latitude, longitude, height);
+    let up = enu_basis(latitude, longitude)[2];
+    let to_sun = [sun[0] - point[0], sun[1] - point[1], sun[2] - point[2]];
+    let dist = (to_sun[0] * to_sun[0] + to_sun[1] * to_sun[1] + to_sun[2] * to_sun[2]).sqrt();
+    ((up[0] * to_sun[0] + up[1] * to_sun[1] + up[2] * to_sun[2]) / dist)
+        .clamp(-1.0, 1.0)
+        .asin()
+        .to_degrees()
 }
 
 impl DaytimeEvaluator {
     /// Calculate the Sun's altitude angle below horizon for twilight definition
     fn twilight_angle(&self) -> f64 {
-        match self.twilight {
-            TwilightType::Civil => -6.0,
-            TwilightType::Nautical => -12.0,
-            TwilightType::Astronomical => -18.0,
-            TwilightType::None => -0.8333, // Approximate angle for Sun just below horizon
-        }
+        self.twilight.sun_altitude_limit()
     }
 
     fn format_name(&self) -> String {
-        let twilight_str = match self.twilight {
-            TwilightType::Civil => "civil",
-            TwilightType::Nautical => "nautical",
-            TwilightType::Astronomical => "astronomical",
-            TwilightType::None => "none",
+        match (self.mode, self.site) {
+            (DaytimeMode::Observer, _) => {
+                format!("DaytimeConstraint(twilight={})", self.twilight.as_str())
+            }
+            (DaytimeMode::ObserverSunlit, _) => {
+                "DaytimeConstraint(mode=observer_sunlit)".to_string()
+            }
+            (DaytimeMode::GroundSiteNight, Some((lat, lon, _))) => format!(
+                "DaytimeConstraint(mode=ground_site_night, twilight={}, lat={:.3}°, lon={:.3}°)",
+                self.twilight.as_str(),
+                lat,
+                lon
+            ),
+            (mode, _) => format!(
+                "DaytimeConstraint(mode={}, twilight={})",
+                mode.as_str(),
+                self.twilight.as_str()
+            ),
+        }
+    }
+
+    fn description(&self) -> &'static str {
+        match self.mode {
+            DaytimeMode::Observer => "Daytime - target not visible during required nighttime hours",
+            DaytimeMode::ObserverSunlit => "Daytime - observer in sunlight",
+            DaytimeMode::GroundSiteNight => "Daytime - ground site not dark",
+            DaytimeMode::NadirSceneDaylit => "Daytime - nadir scene daylit",
+        }
+    }
+
+    /// Sun altitudes at the observer, cached for the full time grid
+    fn observer_sun_altitudes(
+        &self,
+        ephemeris: &dyn EphemerisBase,
+        time_indices: Option<&[usize]>,
+    ) -> ndarray::Array1<f64> {
+        // Use the fast geocentric approximation (good enough for daytime/twilight)
+        if time_indices.is_some() {
+            // When filtering times, we need to compute altitudes for just those indices
+            return crate::utils::celestial::calculate_sun_altitudes_batch_fast(
+                ephemeris,
+                time_indices,
+            );
+        }
+        // When using all times, try to use cache first
+        if let Some(cached) = ephemeris.data().sun_altitudes_cache.get() {
+            cached.clone()
+        } else {
+            // Compute and cache for all times using fast approximation
+            let altitudes =
+                crate::utils::celestial::calculate_sun_altitudes_batch_fast(ephemeris, None);
+            let _ = ephemeris.data().sun_altitudes_cache.set(altitudes.clone());
+            altitudes
+        }
+    }
+
+    /// Whether it is daytime under the configured mode at each selected time
+    fn daytime_mask(
+        &self,
+        ephemeris: &dyn EphemerisBase,
+        time_indices: Option<&[usize]>,
+    ) -> Result<Vec<bool>, ConstraintError> {
+        let threshold = match self.mode {
+            DaytimeMode::ObserverSunlit => 0.0,
+            _ => self.twilight_angle(),
         };
-        format!("DaytimeConstraint(twilight={})", twilight_str)
+        Ok(self
+            .daytime_values(ephemeris, time_indices)?
+            .into_iter()
+            .map(|value| value > threshold)
+            .collect())
+    }
+
+    /// Quantity the mode thresholds: Sun altitude in degrees, or the
+    /// illumination fraction for `ObserverSunlit`
+    fn daytime_values(
+        &self,
+        ephemeris: &dyn EphemerisBase,
+        time_indices: Option<&[usize]>,
+    ) -> Result<Vec<f64>, ConstraintError> {
+        let rows: Vec<usize> = match time_indices {
+            Some(indices) => indices.to_vec(),
+            None => (0..ephemeris.get_times().or_missing("times")?.len()).collect(),
+        };
+
+        match self.mode {
+            DaytimeMode::Observer => Ok(self
+                .observer_sun_altitudes(ephemeris, time_indices)
+                .to_vec()),
+            DaytimeMode::ObserverSunlit => {
+                let sun = ephemeris.get_sun_positions().or_missing("Sun positions")?;
+                let obs = ephemeris
+                    .get_gcrs_positions()
+                    .or_missing("GCRS positions")?;
+                Ok(rows
+                    .iter()
+                    .map(|&row| {
+                        let obs_pos = [obs[[row, 0]], obs[[row, 1]], obs[[row, 2]]];
+                        let sun_pos = [sun[[row, 0]], sun[[row, 1]], sun[[row, 2]]];
+                        illumination_factor(obs_pos, sun_pos)
+                    })
+                    .collect())
+            }
+            DaytimeMode::GroundSiteNight => {
+                let (lat, lon, height) = self.site.ok_or_else(|| {
+                    ConstraintError::invalid_input(
+                        "mode='ground_site_night' requires latitude and longitude",
+                    )
+                })?;
+                let sun_itrs =
+                    sun_itrs_positions(ephemeris, time_indices, ephemeris.applies_polar_motion())
+                        .or_missing("times or Sun positions")?;
+                Ok((0..rows.len())
+                    .map(|k| {
+                        let sun = [sun_itrs[[k, 0]], sun_itrs[[k, 1]], sun_itrs[[k, 2]]];
+                        sun_elevation_at(sun, lat, lon, height)
+                    })
+                    .collect())
+            }
+            DaytimeMode::NadirSceneDaylit => {
+                let geodetic = ephemeris
+                    .geodetic()
+                    .or_missing("times")?
+                    .ok_or_else(|| ConstraintError::invalid_input("No ITRS positions available"))?;
+                let sun_itrs =
+                    sun_itrs_positions(ephemeris, time_indices, ephemeris.applies_polar_motion())
+                        .or_missing("times or Sun positions")?;
+                Ok(rows
+                    .iter()
+                    .enumerate()
+                    .map(|(k, &row)| {
+                        let sun = [sun_itrs[[k, 0]], sun_itrs[[k, 1]], sun_itrs[[k, 2]]];
+                        sun_elevation_at(
+                            sun,
+                            geodetic.latitude_deg[row],
+                            geodetic.longitude_deg[row],
+                            0.0,
+                        )
+                    })
+                    .collect())
+            }
+        }
     }
 }
 
 impl ConstraintEvaluator for DaytimeEvaluator {
     fn evaluate(
         &self,
-        ephemeris: &dyn crate::ephemeris::ephemeris_common::EphemerisBase,
+        ephemeris: &dyn EphemerisBase,
         _target_ra: f64,
         _target_dec: f64,
         time_indices: Option<&[usize]>,
-    ) -> PyResult<ConstraintResult> {
-        // Use cached Sun altitudes if available, otherwise compute them
-        // Use the fast geocentric approximation (good enough for daytime/twilight)
-        let sun_altitudes = if let Some(_indices) = time_indices {
-            // When filtering times, we need to compute altitudes for just those indices
-            crate::utils::celestial::calculate_sun_altitudes_batch_fast(ephemeris, time_indices)
-        } else {
-            // When using all times, try to use cache first
-            if let Some(cached) = ephemeris.data().sun_altitudes_cache.get() {
-                cached.clone()
-            } else {
-                // Compute and cache for all times using fast approximation
-                let altitudes =
-                    crate::utils::celestial::calculate_sun_altitudes_batch_fast(ephemeris, None);
-                let _ = ephemeris.data().sun_altitudes_cache.set(altitudes.clone());
-                altitudes
-            }
-        };
+    ) -> Result<ConstraintResult, ConstraintError> {
+        let daytime = self.daytime_mask(ephemeris, time_indices)?;
 
         // Get filtered times
-        let times = ephemeris.get_times().expect("Ephemeris must have times");
-        let times_filtered = if let Some(indices) = time_indices {
+        let times = ephemeris.get_times().or_missing("times")?;
+        let times_filtered: Vec<DateTime<Utc>> = if let Some(indices) = time_indices {
             indices.iter().map(|&i| times[i]).collect()
         } else {
-            times.to_vec()
+            times
         };
 
-        let twilight_angle = self.twilight_angle();
-
         let violations = track_violations(
             &times_filtered,
-            |i| {
-                let sun_alt = sun_altitudes[i];
-                let is_daytime = sun_alt > twilight_angle;
-                let violated = is_daytime; // Daytime observations are not allowed
-
-                (violated, 1.0)
-            },
-            |_, _| "Daytime - target not visible during required nighttime hours".to_string(),
+            // Daytime observations are not allowed
+            |i| (daytime[i], 1.0),
+            |_, _| self.description().to_string(),
         );
 
         let all_satisfied = violations.is_empty();
@@ -118,63 +361,48 @@ impl ConstraintEvaluator for DaytimeEvaluator {
 
     fn in_constraint_batch(
         &self,
-        ephemeris: &dyn crate::ephemeris::ephemeris_common::EphemerisBase,
-        _target_ras: &[f64],
+        ephemeris: &dyn EphemerisBase,
+        target_ras: &[f64],
         _target_decs: &[f64],
         time_indices: Option<&[usize]>,
-    ) -> PyResult<Array2<bool>> {
-        // Use fast geocentric approximation for daytime constraint
-        let sun_altitudes =
-            crate::utils::celestial::calculate_sun_altitudes_batch_fast(ephemeris, time_indices);
-
-        let n_targets = _target_ras.len();
-        let n_times = sun_altitudes.len();
-
-        // Broadcast Sun altitudes to all targets (Sun position is time-dependent, not target-dependent)
-        let mut result = Array2::from_elem((n_targets, n_times), false);
-
-        let twilight_angle = self.twilight_angle();
-
-        for i in 0..n_times {
-            let is_daytime = sun_altitudes[i] > twilight_angle;
-            let violated = is_daytime; // Daytime observations are not allowed
-
-            for j in 0..n_targets {
-                result[[j, i]] = violated;
-            }
-        }
-
-        Ok(result)
+    ) -> Result<Array2<bool>, ConstraintError> {
+        // Daytime depends only on time, so broadcast it to all targets
+        let daytime = self.daytime_mask(ephemeris, time_indices)?;
+        Ok(Array2::from_shape_fn(
+            (target_ras.len(), daytime.len()),
+            |(_, i)| daytime[i],
+        ))
     }
 
     fn in_constraint_batch_unit_vectors(
         &self,
-        ephemeris: &dyn crate::ephemeris::ephemeris_common::EphemerisBase,
+        ephemeris: &dyn EphemerisBase,
         target_unit_vectors: &Array2<f64>,
         time_indices: Option<&[usize]>,
-    ) -> PyResult<Option<Array2<bool>>> {
+    ) -> Result<Option<Array2<bool>>, ConstraintError> {
         if target_unit_vectors.ncols() != 3 {
-            return Err(pyo3::exceptions::PyValueError::new_err(
+            return Err(ConstraintError::invalid_input(
                 "target_unit_vectors must have shape (N, 3)",
             ));
         }
 
-        let sun_altitudes =
-            crate::utils::celestial::calculate_sun_altitudes_batch_fast(ephemeris, time_indices);
-
-        let n_targets = target_unit_vectors.nrows();
-        let n_times = sun_altitudes.len();
-        let mut result = Array2::from_elem((n_targets, n_times), false);
-
-        let twilight_angle = self.twilight_angle();
-        for i in 0..n_times {
-            let violated = sun_altitudes[i] > twilight_angle;
-            for j in 0..n_targets {
-                result[[j, i]] = violated;
-            }
-        }
+        let daytime = self.daytime_mask(ephemeris, time_indices)?;
+        Ok(Some(Array2::from_shape_fn(
+            (target_unit_vectors.nrows(), daytime.len()),
+            |(_, i)| daytime[i],
+        )))
+    }
 
-        Ok(Some(result))
+    /// Sun altitude in degrees for the configured mode, or the
+    /// illumination fraction for `observer_sunlit`
+    fn metric(
+        &self,
+        ephemeris: &dyn EphemerisBase,
+        _target_ra: f64,
+        _target_dec: f64,
+        time_indices: Option<&[usize]>,
+    ) -> Result<Option<Vec<f64>>, ConstraintError> {
+        self.daytime_values(ephemeris, time_indices).map(Some)
     }
 
     fn name(&self) -> String {
@@ -185,3 +413,67 @@ impl ConstraintEvaluator for DaytimeEvaluator {
         self
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn config(mode: DaytimeMode) -> DaytimeConfig {
+        DaytimeConfig {
+            twilight: TwilightType::Civil,
+            mode,
+            latitude: None,
+            longitude: None,
+            height: None,
+        }
+    }
+
+    #[test]
+    fn test_mode_names_round_trip() {
+        for mode in [
+            DaytimeMode::Observer,
+            DaytimeMode::ObserverSunlit,
+            DaytimeMode::GroundSiteNight,
+            DaytimeMode::NadirSceneDaylit,
+        ] {
+            assert_eq!(DaytimeMode::parse(mode.as_str()), Some(mode));
+        }
+        assert_eq!(DaytimeMode::parse("spacecraft"), None);
+    }
+
+    #[test]
+    fn test_validate_ground_site() {
+        assert!(config(DaytimeMode::Observer).validate().is_ok());
+        assert!(config(DaytimeMode::GroundSiteNight).validate().is_err());
+
+        let site = DaytimeConfig {
+            latitude: Some(-30.17),
+            longitude: Some(-70.8),
+            ..config(DaytimeMode::GroundSiteNight)
+        };
+        assert!(site.validate().is_ok());
+        assert!(DaytimeConfig {
+            latitude: Some(95.0),
+            ..site.clone()
+        }
+        .validate()
+        .is_err());
+        assert!(DaytimeConfig {
+            mode: DaytimeMode::NadirSceneDaylit,
+            ..site
+        }
+        .validate()
+        .is_err());
+    }
+
+    #[test]
+    fn test_sun_elevation_at() {
+        // Sun over the equator at longitude 0, seen from there and from the poles
+        let sun = [1.496e8, 0.0, 0.0];
+        assert!((sun_elevation_at(sun, 0.0, 0.0, 0.0) - 90.0).abs() < 1e-3);
+        assert!(sun_elevation_at(sun, 0.0, 180.0, 0.0) < -89.0);
+        assert!(sun_elevation_at(sun, 90.0, 0.0, 0.0).abs() < 0.01);
+        // 45° east of the subsolar point the Sun is 45° up
+        assert!((sun_elevation_at(sun, 0.0, 45.0, 0.0) - 45.0).abs() < 0.01);
+    }
+}
@@ -1,12 +1,16 @@
-/// Earth limb avoidance constraint implementation
 use super::core::{ConstraintConfig, ConstraintEvaluator, ConstraintResult, ConstraintViolation};
+use super::threshold_schedule::{
+    limits_at, validate_angle_limits, validate_schedule, ThresholdPeriod,
+};
+/// Earth limb avoidance constraint implementation
+use crate::constraints::error::ConstraintError;
+use crate::utils::body_properties::earth_radii;
 use crate::utils::vector_math::{
     dot_product, normalize_vector, radec_to_unit_vector, radec_to_unit_vectors_batch,
     vector_magnitude,
 };
 use chrono::{DateTime, Utc};
 use ndarray::Array2;
-use pyo3::PyResult;
 use serde::{Deserialize, Serialize};
 
 /// Configuration for Earth limb avoidance constraint
@@ -24,6 +28,13 @@ pub struct EarthLimbConfig {
     /// Include geometric horizon dip correction for ground observers (default: true)
     #[serde(default = "default_horizon_dip")]
     pub horizon_dip: bool,
+    /// Widen the margin by this many standard deviations of the ephemeris
+    /// position uncertainty, when the ephemeris provides one (default: 0)
+    #[serde(default)]
+    pub uncertainty_sigma: f64,
+    /// Periods overriding the margin and maximum angle
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub schedule: Vec<ThresholdPeriod>,
 }
 
 fn default_refraction() -> bool {
@@ -34,6 +45,87 @@ fn default_horizon_dip() -> bool {
     false
 }
 
+impl EarthLimbConfig {
+    /// Start a builder with no margin, no maximum and no corrections
+    pub fn builder() -> EarthLimbBuilder {
+        EarthLimbBuilder::default()
+    }
+
+    /// Check the angle limits, uncertainty scaling and schedule
+    pub fn validate(&self) -> Result<(), String> {
+        validate_angle_limits(self.min_angle, self.max_angle)?;
+        if self.uncertainty_sigma < 0.0 || !self.uncertainty_sigma.is_finite() {
+            return Err("uncertainty_sigma must be a non-negative number".to_string());
+        }
+        validate_schedule(&self.schedule, self.min_angle, self.max_angle)
+    }
+}
+
+/// Builder for [`EarthLimbConfig`]
+#[derive(Debug, Clone, Default)]
+pub struct EarthLimbBuilder {
+    config: EarthLimbConfig,
+}
+
+impl Default for EarthLimbConfig {
+    fn default() -> Self {
+        EarthLimbConfig {
+            min_angle: 0.0,
+            max_angle: None,
+            include_refraction: default_refraction(),
+            horizon_dip: default_horizon_dip(),
+            uncertainty_sigma: 0.0,
+            schedule: Vec::new(),
+        }
+    }
+}
+
+impl EarthLimbBuilder {
+    /// Margin beyond the Earth's apparent angular radius in degrees
+    pub fn min_angle(mut self, degrees: f64) -> Self {
+        self.config.min_angle = degrees;
+        self
+    }
+
+    /// Maximum allowed separation from the limb in degrees
+    pub fn max_angle(mut self, degrees: f64) -> Self {
+        self.config.max_angle = Some(degrees);
+        self
+    }
+
+    /// Include atmospheric refraction for ground observers
+    pub fn include_refraction(mut self, include: bool) -> Self {
+        self.config.include_refraction = include;
+        self
+    }
+
+    /// Include the geometric horizon dip for ground observers
+    pub fn horizon_dip(mut self, include: bool) -> Self {
+        self.config.horizon_dip = include;
+        self
+    }
+
+    /// Widen the margin by this many standard deviations of the position uncertainty
+    pub fn uncertainty_sigma(mut self, sigma: f64) -> Self {
+        self.config.uncertainty_sigma = sigma;
+        self
+    }
+
+    /// Add a period overriding the margin and maximum angle
+    pub fn period(mut self, period: ThresholdPeriod) -> Self {
+        self.config.schedule.push(period);
+        self
+    }
+
+    /// Validate and return the config
+    pub fn build(self) -> Result<EarthLimbConfig, ConstraintError> {
+        self.config
+            .validate()
+            .map_err(ConstraintError::InvalidInput)?;
+        Ok(self.config)
+    }
+}
+
 impl ConstraintConfig for EarthLimbConfig {
     fn to_evaluator(&self) -> Box<dyn ConstraintEvaluator> {
         Box::new(EarthLimbEvaluator {
@@ -41,6 +133,9 @@ impl ConstraintConfig for EarthLimbConfig {
             max_angle_deg: self.max_angle,
             include_refraction: self.include_refraction,
             horizon_dip: self.horizon_dip,
+            uncertainty_sigma: self.uncertainty_sigma,
+            schedule: self.schedule.clone(),
+            earth_radius: earth_radii().equatorial,
         })
     }
 }
@@ -51,6 +146,79 @@ struct EarthLimbEvaluator {
     max_angle_deg: Option<f64>,
     include_refraction: bool,
     horizon_dip: bool,
+    uncertainty_sigma: f64,
+    schedule: Vec<ThresholdPeriod>,
+    /// Earth equatorial radius in km
+    earth_radius: f64,
+}
+
+impl EarthLimbEvaluator {
+    /// Margin and maximum angle (degrees) in effect at `time`
+    fn limits_at(&self, time: &DateTime<Utc>) -> (f64, Option<f64>) {
+        limits_at(&self.schedule, self.min_angle_deg, self.max_angle_deg, time)
+    }
+
+    /// Cosine of the maximum angle from the limb in effect at `time`
+    fn cos_max_threshold(&self, time: &DateTime<Utc>) -> Option<f64> {
+        self.limits_at(time).1.map(|max| max.to_radians().cos())
+    }
+
+    /// Minimum allowed angle (degrees) between the target and the Earth's
+    /// center at `time` for an observer `r` km from it
+    ///
+    /// `position_sigma_km` is the observer's total 1-sigma position
+    /// uncertainty. An observer displacement `d` moves the limb by at most
+    /// `atan(d / limb_distance)`, so that angle at `uncertainty_sigma`
+    /// standard deviations is added to the margin.
+    fn threshold_deg(&self, time: &DateTime<Utc>, r: f64, position_sigma_km: f64) -> f64 {
+        let earth_radius = self.earth_radius;
+        let ratio = (earth_radius / r).clamp(-1.0, 1.0);
+        let earth_ang_radius_deg = ratio.asin().to_degrees();
+
+        // For ground observers (r close to the Earth's radius), add horizon dip correction
+        // Horizon dip angle = arccos(R/r), which makes objects visible slightly beyond 90°
+        // For spacecraft (r >> R), this correction is negligible
+        let horizon_dip_correction = if self.horizon_dip && (r - earth_radius).abs() < 100.0 {
+            // True ground observer or very low altitude (<100 km above surface)
+            let dip_angle_deg = (earth_radius / r).clamp(-1.0, 1.0).acos().to_degrees();
+            let refraction = if self.include_refraction { 0.57 } else { 0.0 };
+            dip_angle_deg + refraction
+        } else {
+            // Spacecraft or high altitude - no correction needed
+            0.0
+        };
+
+        let uncertainty_margin = if self.uncertainty_sigma > 0.0 && position_sigma_km > 0.0 {
+            let limb_distance = (r * r - earth_radius * earth_radius).max(1.0).sqrt();
+            (self.uncertainty_sigma * position_sigma_km / limb_distance)
+                .atan()
+                .to_degrees()
+        } else {
+            0.0
+        };
+
+        earth_ang_radius_deg + self.limits_at(time).0 + horizon_dip_correction + uncertainty_margin
+    }
+
+    /// Total 1-sigma position uncertainty (km) at each evaluated time
+    ///
+    /// `None` unless margins are to be widened and the ephemeris has a
+    /// position uncertainty.
+    fn position_sigmas(
+        &self,
+        ephemeris: &dyn crate::ephemeris::ephemeris_common::EphemerisBase,
+        time_indices: Option<&[usize]>,
+    ) -> Option<Vec<f64>> {
+        if self.uncertainty_sigma <= 0.0 {
+            return None;
+        }
+        let sigma = ephemeris.position_uncertainty()?;
+        let total = |i: usize| sigma.row(i).dot(&sigma.row(i)).sqrt();
+        Some(match time_indices {
+            Some(indices) => indices.iter().map(|&i| total(i)).collect(),
+            None => (0..sigma.nrows()).map(total).collect(),
+        })
+    }
 }
 
 impl ConstraintEvaluator for EarthLimbEvaluator {
@@ -60,21 +228,18 @@ impl ConstraintEvaluator for EarthLimbEvaluator {
         target_ra: f64,
         target_dec: f64,
         time_indices: Option<&[usize]>,
-    ) -> PyResult<ConstraintResult> {
+    ) -> Result<ConstraintResult, ConstraintError> {
         // Extract and filter ephemeris data
         let (times_filtered, obs_filtered) =
             extract_observer_ephemeris_data!(ephemeris, time_indices);
+        let sigmas = self.position_sigmas(ephemeris, time_indices);
         let mut violations = Vec::new();
         let mut current_violation: Option<(usize, f64)> = None;
 
-        // Earth radius in km
-        const EARTH_RADIUS: f64 = 6378.137;
-
         // Convert target RA/Dec to unit vector
         let target_vec = radec_to_unit_vector(target_ra, target_dec);
-        let cos_max_threshold = self.max_angle_deg.map(|max| max.to_radians().cos());
 
-        for (i, _time) in times_filtered.iter().enumerate() {
+        for (i, time) in times_filtered.iter().enumerate() {
             // Vector from observer to Earth center is -observer position
             let obs_pos = [
                 obs_filtered[[i, 0]],
@@ -83,36 +248,22 @@ impl ConstraintEvaluator for EarthLimbEvaluator {
             ];
 
             let r = vector_magnitude(&obs_pos);
-            let ratio = (EARTH_RADIUS / r).clamp(-1.0, 1.0);
-            let earth_ang_radius_deg = ratio.asin().to_degrees();
-
-            // For ground observers (r close to EARTH_RADIUS), add horizon dip correction
-            // Horizon dip angle = arccos(R/r), which makes objects visible slightly beyond 90°
-            // For spacecraft (r >> R), this correction is negligible
-            let horizon_dip_correction = if self.horizon_dip && (r - EARTH_RADIUS).abs() < 100.0 {
-                // True ground observer or very low altitude (<100 km above surface)
-                let dip_angle_deg = (EARTH_RADIUS / r).clamp(-1.0, 1.0).acos().to_degrees();
-                let refraction = if self.include_refraction { 0.57 } else { 0.0 };
-                dip_angle_deg + refraction
-            } else {
-                // Spacecraft or high altitude - no correction needed
-                0.0
-            };
-
-            let threshold_deg = earth_ang_radius_deg + self.min_angle_deg + horizon_dip_correction;
+            let sigma_km = sigmas.as_ref().map_or(0.0, |s| s[i]);
+            let threshold_deg = self.threshold_deg(time, r, sigma_km);
+            let max_angle_deg = self.limits_at(time).1;
 
             let center_unit = normalize_vector(&[-obs_pos[0], -obs_pos[1], -obs_pos[2]]);
             let cos_angle = dot_product(&target_vec, &center_unit);
             let angle_deg = cos_angle.clamp(-1.0, 1.0).acos().to_degrees();
 
             let is_min_violation = angle_deg < threshold_deg;
-            let is_max_violation = cos_max_threshold.is_some_and(|cos_max| cos_angle < cos_max);
+            let is_max_violation = max_angle_deg.is_some_and(|max| angle_deg > max);
             let is_violation = is_min_violation || is_max_violation;
 
             if is_violation {
                 let severity = if angle_deg < threshold_deg {
                     (threshold_deg - angle_deg) / threshold_deg.max(1e-9)
-                } else if let Some(max_angle) = self.max_angle_deg {
+                } else if let Some(max_angle) = max_angle_deg {
                     // For max angle violations, severity increases as angle exceeds max
                     (angle_deg - max_angle) / max_angle.max(1e-9)
                 } else {
@@ -152,22 +303,12 @@ impl ConstraintEvaluator for EarthLimbEvaluator {
                 obs_filtered[[times_filtered.len() - 1, 2]],
             ];
             let r = vector_magnitude(&obs_pos);
-            let ratio = (EARTH_RADIUS / r).clamp(-1.0, 1.0);
-            let earth_ang_radius_deg = ratio.asin().to_degrees();
-
-            let horizon_dip_correction = if (r - EARTH_RADIUS).abs() < 100.0 {
-                if self.horizon_dip {
-                    let dip_angle_deg = (EARTH_RADIUS / r).clamp(-1.0, 1.0).acos().to_degrees();
-                    let refraction = if self.include_refraction { 0.57 } else { 0.0 };
-                    dip_angle_deg + refraction
-                } else {
-                    0.0
-                }
-            } else {
-                0.0
-            };
-
-            let threshold_deg = earth_ang_radius_deg + self.min_angle_deg + horizon_dip_correction;
+            let last = times_filtered.len() - 1;
+            let threshold_deg = self.threshold_deg(
+                &times_filtered[last],
+                r,
+                sigmas.as_ref().map_or(0.0, |s| s[last]),
+            );
 
             violations.push(ConstraintViolation {
                 start_time_internal: times_filtered[start_idx],
@@ -193,6 +334,35 @@ impl ConstraintEvaluator for EarthLimbEvaluator {
         ))
     }
 
+    /// Angle between the target and the Earth's limb in degrees, negative
+    /// when the target is behind the Earth
+    fn metric(
+        &self,
+        ephemeris: &dyn crate::ephemeris::ephemeris_common::EphemerisBase,
+        target_ra: f64,
+        target_dec: f64,
+        time_indices: Option<&[usize]>,
+    ) -> Result<Option<Vec<f64>>, ConstraintError> {
+        let (_, obs_filtered) = extract_observer_ephemeris_data!(ephemeris, time_indices);
+        let target_vec = radec_to_unit_vector(target_ra, target_dec);
+        Ok(Some(
+            obs_filtered
+                .rows()
+                .into_iter()
+                .map(|row| {
+                    let obs_pos = [row[0], row[1], row[2]];
+                    let r = vector_magnitude(&obs_pos);
+                    let center_unit = normalize_vector(&[-obs_pos[0], -obs_pos[1], -obs_pos[2]]);
+                    let angle_deg = dot_product(&target_vec, &center_unit)
+                        .clamp(-1.0, 1.0)
+                        .acos()
+                        .to_degrees();
+                    angle_deg - (self.earth_radius / r).clamp(-1.0, 1.0).asin().to_degrees()
+                })
+                .collect(),
+        ))
+    }
+
     fn name(&self) -> String {
         format!("EarthLimb(min={}°)", self.min_angle_deg)
     }
@@ -208,16 +378,15 @@ impl ConstraintEvaluator for EarthLimbEvaluator {
         target_ras: &[f64],
         target_decs: &[f64],
         time_indices: Option<&[usize]>,
-    ) -> PyResult<Array2<bool>> {
+    ) -> Result<Array2<bool>, ConstraintError> {
         // Extract and filter ephemeris data
         let (times_filtered, obs_filtered) =
             extract_observer_ephemeris_data!(ephemeris, time_indices);
-        // Earth radius in km
-        const EARTH_RADIUS: f64 = 6378.137;
+        let sigmas = self.position_sigmas(ephemeris, time_indices);
 
         // Validate inputs
         if target_ras.len() != target_decs.len() {
-            return Err(pyo3::exceptions::PyValueError::new_err(
+            return Err(ConstraintError::invalid_input(
                 "target_ras and target_decs must have the same length",
             ));
         }
@@ -234,7 +403,7 @@ impl ConstraintEvaluator for EarthLimbEvaluator {
         // These only depend on time, not on target, so compute once and reuse
         // Using cosine trick: angle < threshold_deg ⟺ cos(angle) > cos(threshold_deg)
         let mut cos_thresholds = vec![0.0; n_times];
-        let cos_max_threshold = self.max_angle_deg.map(|max| max.to_radians().cos());
+        let mut cos_max_thresholds = vec![None; n_times];
         let mut center_units = vec![[0.0; 3]; n_times];
 
         for t in 0..n_times {
@@ -245,18 +414,9 @@ impl ConstraintEvaluator for EarthLimbEvaluator {
             ];
 
             let r = vector_magnitude(&obs_pos);
-            let ratio = (EARTH_RADIUS / r).clamp(-1.0, 1.0);
-            let earth_ang_radius_deg = ratio.asin().to_degrees();
-
-            let horizon_dip_correction = if self.horizon_dip && (r - EARTH_RADIUS).abs() < 100.0 {
-                let dip_angle_deg = (EARTH_RADIUS / r).clamp(-1.0, 1.0).acos().to_degrees();
-                let refraction = if self.include_refraction { 0.57 } else { 0.0 };
-                dip_angle_deg + refraction
-            } else {
-                0.0
-            };
-
-            let threshold_deg = earth_ang_radius_deg + self.min_angle_deg + horizon_dip_correction;
+            let time = &times_filtered[t];
+            let threshold_deg = self.threshold_deg(time, r, sigmas.as_ref().map_or(0.0, |s| s[t]));
+            cos_max_thresholds[t] = self.cos_max_threshold(time);
             // Pre-compute cosine of threshold (avoids acos() in inner loop)
             cos_thresholds[t] = threshold_deg.to_radians().cos();
 
@@ -279,7 +439,7 @@ impl ConstraintEvaluator for EarthLimbEvaluator {
                 // Check constraint using cosine trick (avoids expensive acos/asin in inner loop)
                 // angle < threshold_deg ⟺ cos(angle) > cos(threshold_deg)
                 // angle > max_angle ⟺ cos(angle) < cos(max_angle)
-                let is_violated = if let Some(cos_max) = cos_max_threshold {
+                let is_violated = if let Some(cos_max) = cos_max_thresholds[t] {
                     // Too close to limb OR too far from limb
                     cos_angle > cos_thresholds[t] || cos_angle < cos_max
                 } else {
@@ -299,15 +459,14 @@ impl ConstraintEvaluator for EarthLimbEvaluator {
         ephemeris: &dyn crate::ephemeris::ephemeris_common::EphemerisBase,
         target_unit_vectors: &Array2<f64>,
         time_indices: Option<&[usize]>,
-    ) -> PyResult<Option<Array2<bool>>> {
+    ) -> Result<Option<Array2<bool>>, ConstraintError> {
         // Extract and filter ephemeris data
         let (times_filtered, obs_filtered) =
             extract_observer_ephemeris_data!(ephemeris, time_indices);
-        // Earth radius in km
-        const EARTH_RADIUS: f64 = 6378.137;
+        let sigmas = self.position_sigmas(ephemeris, time_indices);
 
         if target_unit_vectors.ncols() != 3 {
-            return Err(pyo3::exceptions::PyValueError::new_err(
+            return Err(ConstraintError::invalid_input(
                 "target_unit_vectors must have shape (N, 3)",
             ));
         }
@@ -318,7 +477,7 @@ impl ConstraintEvaluator for EarthLimbEvaluator {
 
         // Pre-compute cosine thresholds and center unit vectors for each time point.
         let mut cos_thresholds = vec![0.0; n_times];
-        let cos_max_threshold = self.max_angle_deg.map(|max| max.to_radians().cos());
+        let mut cos_max_thresholds = vec![None; n_times];
         let mut center_units = vec![[0.0; 3]; n_times];
 
         for t in 0..n_times {
@@ -329,18 +488,9 @@ impl ConstraintEvaluator for EarthLimbEvaluator {
             ];
 
             let r = vector_magnitude(&obs_pos);
-            let ratio = (EARTH_RADIUS / r).clamp(-1.0, 1.0);
-            let earth_ang_radius_deg = ratio.asin().to_degrees();
-
-            let horizon_dip_correction = if self.horizon_dip && (r - EARTH_RADIUS).abs() < 100.0 {
-                let dip_angle_deg = (EARTH_RADIUS / r).clamp(-1.0, 1.0).acos().to_degrees();
-                let refraction = if self.include_refraction { 0.57 } else { 0.0 };
-                dip_angle_deg + refraction
-            } else {
-                0.0
-            };
-
-            let threshold_deg = earth_ang_radius_deg + self.min_angle_deg + horizon_dip_correction;
+            let time = &times_filtered[t];
+            let threshold_deg = self.threshold_deg(time, r, sigmas.as_ref().map_or(0.0, |s| s[t]));
+            cos_max_thresholds[t] = self.cos_max_threshold(time);
             cos_thresholds[t] = threshold_deg.to_radians().cos();
             center_units[t] = normalize_vector(&[-obs_pos[0], -obs_pos[1], -obs_pos[2]]);
         }
@@ -354,7 +504,7 @@ impl ConstraintEvaluator for EarthLimbEvaluator {
 
             for t in 0..n_times {
                 let cos_angle = dot_product(&target_vec, &center_units[t]);
-                let is_violated = if let Some(cos_max) = cos_max_threshold {
+                let is_violated = if let Some(cos_max) = cos_max_thresholds[t] {
                     cos_angle > cos_thresholds[t] || cos_angle < cos_max
                 } else {
                     cos_angle > cos_thresholds[t]
@@ -0,0 +1,292 @@
+use super::core::{track_violations, ConstraintConfig, ConstraintEvaluator, ConstraintResult};
+/// Earth occultation constraint implementation
+///
+/// Violated while a fixed RA/Dec target is hidden behind the Earth as seen
+/// from the observer, i.e. while the line of sight to the target passes
+/// within `grazing_altitude` km of the Earth's surface. Unlike the Earth limb
+/// constraint there is no pointing margin: this is the geometric blockage
+/// used to predict occultation ingress and egress, with the grazing altitude
+/// standing in for the atmosphere.
+use crate::constraints::error::ConstraintError;
+use crate::ephemeris::ephemeris_common::EphemerisBase;
+use crate::utils::body_properties::earth_radii;
+use crate::utils::vector_math::{radec_to_unit_vector, radec_to_unit_vectors_batch};
+use chrono::{DateTime, Utc};
+use ndarray::Array2;
+use serde::{Deserialize, Serialize};
+
+/// Configuration for the Earth occultation constraint
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct EarthOccultationConfig {
+    /// Height above the Earth's surface below which the line of sight counts
+    /// as blocked, in km (default: 0)
+    #[serde(default)]
+    pub grazing_altitude: f64,
+}
+
+impl EarthOccultationConfig {
+    /// Check the grazing altitude
+    pub fn validate(&self) -> Result<(), String> {
+        if !(self.grazing_altitude.is_finite() && self.grazing_altitude >= 0.0) {
+            return Err("grazing_altitude must be a non-negative number of km".to_string());
+        }
+        Ok(())
+    }
+}
+
+impl ConstraintConfig for EarthOccultationConfig {
+    fn to_evaluator(&self) -> Box<dyn ConstraintEvaluator> {
+        Box::new(EarthOccultationEvaluator {
+            grazing_altitude: self.grazing_altitude,
+            earth_radius: earth_radii().equatorial,
+        })
+    }
+}
+
+/// Evaluator for the Earth occultation constraint
+pub struct EarthOccultationEvaluator {
+    grazing_altitude: f64,
+    /// Earth equatorial radius in km
+    earth_radius: f64,
+}
+
+/// Occultation ingress or egress
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct OccultationEvent {
+    pub time: DateTime<Utc>,
+    /// True when the target disappears behind the Earth, false when it reappears
+    pub ingress: bool,
+}
+
+impl EarthOccultationEvaluator {
+    fn format_name(&self) -> String {
+        if self.grazing_altitude > 0.0 {
+            format!(
+                "EarthOccultationConstraint(grazing_altitude={:.1} km)",
+                self.grazing_altitude
+            )
+        } else {
+            "EarthOccultationConstraint".to_string()
+        }
+    }
+
+    /// Height in km above the Earth's surface of the closest point of the
+    /// line of sight from `obs` (GCRS, km) along the unit vector `target`
+    ///
+    /// When the target points away from the Earth the closest point is the
+    /// observer itself. The value is continuous in time, so its crossings of
+    /// the grazing altitude locate ingress and egress between samples.
+    fn ray_height(&self, obs: [f64; 3], target: [f64; 3]) -> f64 {
+        let along = -(obs[0] * target[0] + obs[1] * target[1] + obs[2] * target[2]);
+        let t = along.max(0.0);
+        let closest = [
+            obs[0] + t * target[0],
+            obs[1] + t * target[1],
+            obs[2] + t * target[2],
+        ];
+        (closest[0] * closest[0] + closest[1] * closest[1] + closest[2] * closest[2]).sqrt()
+            - self.earth_radius
+    }
+
+    /// Ray heights of one target at the selected ephemeris times
+    fn ray_heights(&self, obs: &Array2<f64>, target: [f64; 3]) -> Vec<f64> {
+        obs.rows()
+            .into_iter()
+            .map(|row| self.ray_height([row[0], row[1], row[2]], target))
+            .collect()
+    }
+
+    fn is_occulted(&self, height: f64) -> bool {
+        height < self.grazing_altitude
+    }
+
+    /// Depth of the line of sight below the grazing altitude, as a fraction
+    /// of the grazing radius
+    fn severity(&self, height: f64) -> f64 {
+        ((self.grazing_altitude - height) / (self.earth_radius + self.grazing_altitude))
+            .clamp(0.0, 1.0)
+    }
+
+    /// Evaluate the constraint with pre-computed ray heights
+    pub fn evaluate_with_heights(
+        &self,
+        times: &[DateTime<Utc>],
+        heights: &[f64],
+    ) -> ConstraintResult {
+        let violations = track_violations(
+            times,
+            |i| (self.is_occulted(heights[i]), self.severity(heights[i])),
+            |_, _| "Target occulted by the Earth".to_string(),
+        );
+        let all_satisfied = violations.is_empty();
+        ConstraintResult::new(
+            violations,
+            all_satisfied,
+            self.format_name(),
+            times.to_vec(),
+        )
+    }
+
+    /// Ingress and egress times of a target, interpolated between samples
+    ///
+    /// The ray height is interpolated linearly between the two samples that
+    /// bracket each crossing of the grazing altitude. A target already
+    /// occulted at the first sample has no ingress, and one still occulted at
+    /// the last sample has no egress.
+    pub fn events_with_heights(
+        &self,
+        times: &[DateTime<Utc>],
+        heights: &[f64],
+    ) -> Vec<OccultationEvent> {
+        let mut events = Vec::new();
+        for i in 1..times.len() {
+            let (h0, h1) = (heights[i - 1], heights[i]);
+            let ingress = self.is_occulted(h1);
+            if self.is_occulted(h0) == ingress {
+                continue;
+            }
+            let fraction = (h0 - self.grazing_altitude) / (h0 - h1);
+            let step = times[i] - times[i - 1];
+            let offset_ns = step.num_nanoseconds().unwrap_or(0) as f64 * fraction;
+            events.push(OccultationEvent {
+                time: times[i - 1] + chrono::Duration::nanoseconds(offset_ns.round() as i64),
+                ingress,
+            });
+        }
+        events
+    }
+
+    /// Occultation ingress and egress times of a target over an ephemeris
+    pub fn occultation_events(
+        &self,
+        ephemeris: &dyn EphemerisBase,
+        target_ra: f64,
+        target_dec: f64,
+        time_indices: Option<&[usize]>,
+    ) -> Result<Vec<OccultationEvent>, ConstraintError> {
+        let (times_filtered, obs_filtered) =
+            extract_observer_ephemeris_data!(ephemeris, time_indices);
+        let heights = self.ray_heights(&obs_filtered, radec_to_unit_vector(target_ra, target_dec));
+        Ok(self.events_with_heights(&times_filtered, &heights))
+    }
+
+    fn mask(&self, obs: &Array2<f64>, target_vectors: &Array2<f64>) -> Array2<bool> {
+        Array2::from_shape_fn((target_vectors.nrows(), obs.nrows()), |(i, t)| {
+            let target = [
+                target_vectors[[i, 0]],
+                target_vectors[[i, 1]],
+                target_vectors[[i, 2]],
+            ];
+            self.is_occulted(self.ray_height([obs[[t, 0]], obs[[t, 1]], obs[[t, 2]]], target))
+        })
+    }
+}
+
+impl ConstraintEvaluator for EarthOccultationEvaluator {
+    fn evaluate(
+        &self,
+        ephemeris: &dyn EphemerisBase,
+        target_ra: f64,
+        target_dec: f64,
+        time_indices: Option<&[usize]>,
+    ) -> Result<ConstraintResult, ConstraintError> {
+        let (times_filtered, obs_filtered) =
+            extract_observer_ephemeris_data!(ephemeris, time_indices);
+        let heights = self.ray_heights(&obs_filtered, radec_to_unit_vector(target_ra, target_dec));
+        Ok(self.evaluate_with_heights(&times_filtered, &heights))
+    }
+
+    fn in_constraint_batch(
+        &self,
+        ephemeris: &dyn EphemerisBase,
+        target_ras: &[f64],
+        target_decs: &[f64],
+        time_indices: Option<&[usize]>,
+    ) -> Result<Array2<bool>, ConstraintError> {
+        if target_ras.len() != target_decs.len() {
+            return Err(ConstraintError::invalid_input(
+                "target_ras and target_decs must have the same length",
+            ));
+        }
+        let (_, obs_filtered) = extract_observer_ephemeris_data!(ephemeris, time_indices);
+        let target_vectors = radec_to_unit_vectors_batch(target_ras, target_decs);
+        Ok(self.mask(&obs_filtered, &target_vectors))
+    }
+
+    fn in_constraint_batch_unit_vectors(
+        &self,
+        ephemeris: &dyn EphemerisBase,
+        target_unit_vectors: &Array2<f64>,
+        time_indices: Option<&[usize]>,
+    ) -> Result<Option<Array2<bool>>, ConstraintError> {
+        if target_unit_vectors.ncols() != 3 {
+            return Err(ConstraintError::invalid_input(
+                "target_unit_vectors must have shape (N, 3)",
+            ));
+        }
+        let (_, obs_filtered) = extract_observer_ephemeris_data!(ephemeris, time_indices);
+        Ok(Some(self.mask(&obs_filtered, target_unit_vectors)))
+    }
+
+    fn name(&self) -> String {
+        self.format_name()
+    }
+
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::{Duration, TimeZone};
+
+    fn evaluator(grazing_altitude: f64) -> EarthOccultationEvaluator {
+        EarthOccultationEvaluator {
+            grazing_altitude,
+            earth_radius: 6378.137,
+        }
+    }
+
+    #[test]
+    fn test_ray_height() {
+        let eval = evaluator(0.0);
+        let obs = [7000.0, 0.0, 0.0];
+        // Looking straight down, straight up and along the local horizontal
+        assert!(eval.ray_height(obs, [-1.0, 0.0, 0.0]) < -6000.0);
+        assert!((eval.ray_height(obs, [1.0, 0.0, 0.0]) - 621.863).abs() < 1e-9);
+        assert!((eval.ray_height(obs, [0.0, 1.0, 0.0]) - 621.863).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_grazing_altitude_widens_blockage() {
+        let obs = [7000.0, 0.0, 0.0];
+        // Line of sight passing 100 km above the surface
+        let r = 6478.137_f64;
+        let angle = (r / 7000.0).asin();
+        let target = [-angle.cos(), angle.sin(), 0.0];
+        assert!(!evaluator(0.0).is_occulted(evaluator(0.0).ray_height(obs, target)));
+        assert!(evaluator(150.0).is_occulted(evaluator(150.0).ray_height(obs, target)));
+    }
+
+    #[test]
+    fn test_events_interpolate_crossings() {
+        let eval = evaluator(0.0);
+        let start = Utc.with_ymd_and_hms(2024, 1, 1, 0, 0, 0).unwrap();
+        let times: Vec<DateTime<Utc>> = (0..5).map(|i| start + Duration::minutes(i)).collect();
+        let heights = [300.0, 100.0, -100.0, -50.0, 150.0];
+
+        let events = eval.events_with_heights(&times, &heights);
+        assert_eq!(events.len(), 2);
+        assert!(events[0].ingress);
+        assert_eq!(events[0].time, start + Duration::seconds(90));
+        assert!(!events[1].ingress);
+        assert_eq!(events[1].time, start + Duration::seconds(195));
+
+        let result = eval.evaluate_with_heights(&times, &heights);
+        assert_eq!(result.violations.len(), 1);
+        assert_eq!(result.violations[0].start_time_internal, times[2]);
+        assert_eq!(result.violations[0].end_time_internal, times[3]);
+    }
+}
@@ -1,10 +1,10 @@
-/// Eclipse constraint implementation
 use super::core::{ConstraintConfig, ConstraintEvaluator, ConstraintResult, ConstraintViolation};
-use crate::utils::config::{EARTH_RADIUS_KM, SUN_RADIUS_KM};
+/// Eclipse constraint implementation
+use crate::constraints::error::ConstraintError;
+use crate::utils::body_properties::{earth_radii, sun_radii};
 use crate::utils::vector_math::vector_magnitude;
 use chrono::{DateTime, Utc};
 use ndarray::Array2;
-use pyo3::PyResult;
 use serde::{Deserialize, Serialize};
 
 /// Configuration for eclipse constraint
@@ -14,21 +14,145 @@ pub struct EclipseConfig {
     pub umbra_only: bool,
 }
 
+impl EclipseConfig {
+    /// Start a builder counting the umbra only
+    pub fn builder() -> EclipseBuilder {
+        EclipseBuilder { umbra_only: true }
+    }
+}
+
+/// Builder for [`EclipseConfig`]
+#[derive(Debug, Clone)]
+pub struct EclipseBuilder {
+    umbra_only: bool,
+}
+
+impl EclipseBuilder {
+    /// Count only the umbra as eclipse (true) or the penumbra as well (false)
+    pub fn umbra_only(mut self, umbra_only: bool) -> Self {
+        self.umbra_only = umbra_only;
+        self
+    }
+
+    /// Return the config; every setting is valid
+    pub fn build(self) -> EclipseConfig {
+        EclipseConfig {
+            umbra_only: self.umbra_only,
+        }
+    }
+}
+
 impl ConstraintConfig for EclipseConfig {
     fn to_evaluator(&self) -> Box<dyn ConstraintEvaluator> {
-        Box::new(EclipseEvaluator {
-            umbra_only: self.umbra_only,
-        })
+        Box::new(EclipseEvaluator::new(self.umbra_only))
+    }
+}
+
+/// Boundary crossed at a shadow transition
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ShadowTransition {
+    PenumbraEntry,
+    UmbraEntry,
+    UmbraExit,
+    PenumbraExit,
+}
+
+impl ShadowTransition {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            ShadowTransition::PenumbraEntry => "penumbra_entry",
+            ShadowTransition::UmbraEntry => "umbra_entry",
+            ShadowTransition::UmbraExit => "umbra_exit",
+            ShadowTransition::PenumbraExit => "penumbra_exit",
+        }
     }
 }
 
+/// A crossing of the penumbra or umbra boundary, interpolated between samples
+#[derive(Debug, Clone)]
+pub struct ShadowEvent {
+    pub time: DateTime<Utc>,
+    pub transition: ShadowTransition,
+}
+
 /// Evaluator for eclipse constraint
-struct EclipseEvaluator {
+pub struct EclipseEvaluator {
     umbra_only: bool,
+    /// Earth equatorial radius in km
+    earth_radius: f64,
+    /// Sun radius in km
+    sun_radius: f64,
+}
+
+/// Whether an observer at `obs_pos` is in Earth's shadow (GCRS, km)
+///
+/// The penumbra counts as shadow unless `umbra_only` is set.
+pub(crate) fn in_earth_shadow(obs_pos: [f64; 3], sun_pos: [f64; 3], umbra_only: bool) -> bool {
+    EclipseEvaluator::new(umbra_only)
+        .shadow_status(obs_pos, sun_pos)
+        .0
+}
+
+/// Fraction of the solar disk visible from `obs_pos` past the Earth (GCRS, km)
+///
+/// 1.0 in sunlight, 0.0 in the umbra and fractional in the penumbra, from the
+/// overlap of the apparent Sun and Earth disks (Montenbruck & Gill, Satellite
+/// Orbits, section 3.4.2). Uses the Earth and Sun radii currently in effect.
+pub(crate) fn illumination_factor(obs_pos: [f64; 3], sun_pos: [f64; 3]) -> f64 {
+    let sun_rel = [
+        sun_pos[0] - obs_pos[0],
+        sun_pos[1] - obs_pos[1],
+        sun_pos[2] - obs_pos[2],
+    ];
+    let sun_dist = vector_magnitude(&sun_rel);
+    let obs_dist = vector_magnitude(&obs_pos);
+    if sun_dist <= 0.0 || obs_dist <= 0.0 {
+        return 1.0;
+    }
+
+    // Apparent radii of the Sun and the Earth, and their center separation
+    let a = (sun_radii().equatorial / sun_dist).clamp(-1.0, 1.0).asin();
+    let b = (earth_radii().equatorial / obs_dist)
+        .clamp(-1.0, 1.0)
+        .asin();
+    let cos_c = -(obs_pos[0] * sun_rel[0] + obs_pos[1] * sun_rel[1] + obs_pos[2] * sun_rel[2])
+        / (obs_dist * sun_dist);
+    let c = cos_c.clamp(-1.0, 1.0).acos();
+
+    1.0 - covered_fraction(a, b, c)
+}
+
+/// Fraction of a disk of angular radius `a` hidden by a disk of radius `b`
+/// whose centre is `c` away (all in radians)
+pub(crate) fn covered_fraction(a: f64, b: f64, c: f64) -> f64 {
+    if c >= a + b {
+        0.0
+    } else if c <= b - a {
+        1.0
+    } else if c <= a - b {
+        // Occulting disk entirely inside the other
+        (b * b) / (a * a)
+    } else {
+        let x = (c * c + a * a - b * b) / (2.0 * c);
+        let y = (a * a - x * x).max(0.0).sqrt();
+        let overlap = a * a * (x / a).clamp(-1.0, 1.0).acos()
+            + b * b * ((c - x) / b).clamp(-1.0, 1.0).acos()
+            - c * y;
+        (overlap / (std::f64::consts::PI * a * a)).clamp(0.0, 1.0)
+    }
 }
 
 impl EclipseEvaluator {
-    fn shadow_geometry(obs_pos: [f64; 3], sun_pos: [f64; 3]) -> Option<(f64, f64, f64)> {
+    /// Evaluator using the Earth and Sun radii currently in effect
+    fn new(umbra_only: bool) -> Self {
+        EclipseEvaluator {
+            umbra_only,
+            earth_radius: earth_radii().equatorial,
+            sun_radius: sun_radii().equatorial,
+        }
+    }
+
+    fn shadow_geometry(&self, obs_pos: [f64; 3], sun_pos: [f64; 3]) -> Option<(f64, f64, f64)> {
         let sun_dist = vector_magnitude(&sun_pos);
         if sun_dist <= 0.0 {
             return None;
@@ -58,25 +182,26 @@ impl EclipseEvaluator {
         let dist_to_axis = vector_magnitude(&perp);
 
         // Umbra and penumbra cone lengths.
-        let l_umbra = EARTH_RADIUS_KM * sun_dist / (SUN_RADIUS_KM - EARTH_RADIUS_KM);
-        let l_penumbra = EARTH_RADIUS_KM * sun_dist / (SUN_RADIUS_KM + EARTH_RADIUS_KM);
+        let (earth_radius, sun_radius) = (self.earth_radius, self.sun_radius);
+        let l_umbra = earth_radius * sun_dist / (sun_radius - earth_radius);
+        let l_penumbra = earth_radius * sun_dist / (sun_radius + earth_radius);
 
         // Umbra radius decreases linearly to zero at L_umbra.
         let umbra_radius = if s <= l_umbra {
-            EARTH_RADIUS_KM * (1.0 - s / l_umbra)
+            earth_radius * (1.0 - s / l_umbra)
         } else {
             0.0
         };
 
         // Penumbra radius increases linearly with distance.
-        let penumbra_radius = EARTH_RADIUS_KM * (1.0 + s / l_penumbra);
+        let penumbra_radius = earth_radius * (1.0 + s / l_penumbra);
 
         Some((dist_to_axis, umbra_radius, penumbra_radius))
     }
 
     fn shadow_status(&self, obs_pos: [f64; 3], sun_pos: [f64; 3]) -> (bool, f64) {
         if let Some((dist_to_axis, umbra_radius, penumbra_radius)) =
-            Self::shadow_geometry(obs_pos, sun_pos)
+            self.shadow_geometry(obs_pos, sun_pos)
         {
             let in_umbra = umbra_radius > 0.0 && dist_to_axis < umbra_radius;
             if in_umbra {
@@ -94,6 +219,121 @@ impl EclipseEvaluator {
         (false, 0.0)
     }
 
+    /// Signed distances in km inside the penumbra and umbra cones (positive
+    /// inside), or `None` on the sunlit side of the Earth
+    ///
+    /// Both are continuous across the cone boundaries, so their sign changes
+    /// locate transitions between samples.
+    fn shadow_depths(&self, obs_pos: [f64; 3], sun_pos: [f64; 3]) -> Option<(f64, f64)> {
+        self.shadow_geometry(obs_pos, sun_pos).map(
+            |(dist_to_axis, umbra_radius, penumbra_radius)| {
+                (penumbra_radius - dist_to_axis, umbra_radius - dist_to_axis)
+            },
+        )
+    }
+
+    /// `shadow_depths` at every sample, with far-outside values on the sunlit side
+    fn depth_series(
+        &self,
+        sun_positions: &Array2<f64>,
+        observer_positions: &Array2<f64>,
+    ) -> Vec<(f64, f64)> {
+        (0..observer_positions.nrows())
+            .map(|i| {
+                let obs_pos = [
+                    observer_positions[[i, 0]],
+                    observer_positions[[i, 1]],
+                    observer_positions[[i, 2]],
+                ];
+                let sun_pos = [
+                    sun_positions[[i, 0]],
+                    sun_positions[[i, 1]],
+                    sun_positions[[i, 2]],
+                ];
+                self.shadow_depths(obs_pos, sun_pos)
+                    .unwrap_or((-self.earth_radius, -self.earth_radius))
+            })
+            .collect()
+    }
+
+    /// Penumbra and umbra entries and exits over the sampled positions
+    ///
+    /// Reported for both boundaries whatever `umbra_only` is set to. A crossing
+    /// is placed by linear interpolation of the depth inside the cone; when
+    /// one step jumps from sunlight straight into the umbra, both entries are
+    /// reported in time order.
+    pub fn events_with_positions(
+        &self,
+        times: &[DateTime<Utc>],
+        sun_positions: &Array2<f64>,
+        observer_positions: &Array2<f64>,
+    ) -> Vec<ShadowEvent> {
+        let depths = self.depth_series(sun_positions, observer_positions);
+
+        let mut events = Vec::new();
+        for i in 1..times.len() {
+            let step_ns = (times[i] - times[i - 1]).num_nanoseconds().unwrap_or(0) as f64;
+            let mut step_events: Vec<ShadowEvent> = Vec::new();
+            let boundaries = [
+                (
+                    depths[i - 1].0,
+                    depths[i].0,
+                    ShadowTransition::PenumbraEntry,
+                    ShadowTransition::PenumbraExit,
+                ),
+                (
+                    depths[i - 1].1,
+                    depths[i].1,
+                    ShadowTransition::UmbraEntry,
+                    ShadowTransition::UmbraExit,
+                ),
+            ];
+            for (d0, d1, entry, exit) in boundaries {
+                if (d0 > 0.0) == (d1 > 0.0) {
+                    continue;
+                }
+                let fraction = d0 / (d0 - d1);
+                step_events.push(ShadowEvent {
+                    time: times[i - 1]
+                        + chrono::Duration::nanoseconds((step_ns * fraction).round() as i64),
+                    transition: if d1 > 0.0 { entry } else { exit },
+                });
+            }
+            step_events.sort_by_key(|event| event.time);
+            events.extend(step_events);
+        }
+        events
+    }
+
+    /// Penumbra and umbra transitions over an ephemeris
+    pub fn shadow_events(
+        &self,
+        ephemeris: &dyn crate::ephemeris::ephemeris_common::EphemerisBase,
+        time_indices: Option<&[usize]>,
+    ) -> Result<Vec<ShadowEvent>, ConstraintError> {
+        let (times_filtered, sun_filtered, obs_filtered) =
+            extract_standard_ephemeris_data!(ephemeris, time_indices);
+        Ok(self.events_with_positions(&times_filtered, &sun_filtered, &obs_filtered))
+    }
+
+    /// Shadow phases visited in a window, e.g. "penumbra, umbra, penumbra"
+    fn shadow_profile(&self, depths: &[(f64, f64)]) -> String {
+        let mut phases: Vec<&str> = Vec::new();
+        for &(penumbra, umbra) in depths {
+            let phase = if umbra > 0.0 {
+                "umbra"
+            } else if penumbra > 0.0 {
+                "penumbra"
+            } else {
+                continue;
+            };
+            if phases.last() != Some(&phase) {
+                phases.push(phase);
+            }
+        }
+        phases.join(", ")
+    }
+
     /// Compute eclipse mask for all times (returns true where eclipse occurs)
     fn compute_eclipse_mask(
         &self,
@@ -131,10 +371,20 @@ impl ConstraintEvaluator for EclipseEvaluator {
         _target_ra: f64,
         _target_dec: f64,
         time_indices: Option<&[usize]>,
-    ) -> PyResult<ConstraintResult> {
+    ) -> Result<ConstraintResult, ConstraintError> {
         // Extract and filter ephemeris data
         let (times_filtered, sun_filtered, obs_filtered) =
             extract_standard_ephemeris_data!(ephemeris, time_indices);
+        // With the penumbra counted, tag each window with the phases it
+        // passes through so the penumbra/umbra/penumbra profile is visible
+        let depths = (!self.umbra_only).then(|| self.depth_series(&sun_filtered, &obs_filtered));
+        let describe = |start: usize, end: usize| match &depths {
+            None => "Observer in umbra".to_string(),
+            Some(depths) => format!(
+                "Observer in shadow ({})",
+                self.shadow_profile(&depths[start..=end])
+            ),
+        };
         let mut violations = Vec::new();
         let mut current_violation: Option<(usize, f64)> = None;
 
@@ -167,11 +417,7 @@ impl ConstraintEvaluator for EclipseEvaluator {
                     start_time_internal: times_filtered[start_idx],
                     end_time_internal: times_filtered[i - 1],
                     max_severity,
-                    description: if self.umbra_only {
-                        "Observer in umbra".to_string()
-                    } else {
-                        "Observer in shadow".to_string()
-                    },
+                    description: describe(start_idx, i - 1),
                 });
                 current_violation = None;
             }
@@ -183,11 +429,7 @@ impl ConstraintEvaluator for EclipseEvaluator {
                 start_time_internal: times_filtered[start_idx],
                 end_time_internal: times_filtered[times_filtered.len() - 1],
                 max_severity,
-                description: if self.umbra_only {
-                    "Observer in umbra".to_string()
-                } else {
-                    "Observer in shadow".to_string()
-                },
+                description: describe(start_idx, times_filtered.len() - 1),
             });
         }
 
@@ -206,12 +448,12 @@ impl ConstraintEvaluator for EclipseEvaluator {
         target_ras: &[f64],
         target_decs: &[f64],
         time_indices: Option<&[usize]>,
-    ) -> PyResult<Array2<bool>> {
+    ) -> Result<Array2<bool>, ConstraintError> {
         // Extract and filter ephemeris data
         let (times_filtered, sun_filtered, obs_filtered) =
             extract_standard_ephemeris_data!(ephemeris, time_indices);
         if target_ras.len() != target_decs.len() {
-            return Err(pyo3::exceptions::PyValueError::new_err(
+            return Err(ConstraintError::invalid_input(
                 "target_ras and target_decs must have the same length",
             ));
         }
@@ -233,9 +475,9 @@ impl ConstraintEvaluator for EclipseEvaluator {
         ephemeris: &dyn crate::ephemeris::ephemeris_common::EphemerisBase,
         target_unit_vectors: &Array2<f64>,
         time_indices: Option<&[usize]>,
-    ) -> PyResult<Option<Array2<bool>>> {
+    ) -> Result<Option<Array2<bool>>, ConstraintError> {
         if target_unit_vectors.ncols() != 3 {
-            return Err(pyo3::exceptions::PyValueError::new_err(
+            return Err(ConstraintError::invalid_input(
                 "target_unit_vectors must have shape (N, 3)",
             ));
         }
@@ -252,6 +494,36 @@ impl ConstraintEvaluator for EclipseEvaluator {
         Ok(Some(result))
     }
 
+    /// Fraction of the solar disk visible (1 in sunlight, 0 in the umbra)
+    fn metric(
+        &self,
+        ephemeris: &dyn crate::ephemeris::ephemeris_common::EphemerisBase,
+        _target_ra: f64,
+        _target_dec: f64,
+        time_indices: Option<&[usize]>,
+    ) -> Result<Option<Vec<f64>>, ConstraintError> {
+        let (_, sun_filtered, obs_filtered) =
+            extract_standard_ephemeris_data!(ephemeris, time_indices);
+        Ok(Some(
+            (0..obs_filtered.nrows())
+                .map(|i| {
+                    illumination_factor(
+                        [
+                            obs_filtered[[i, 0]],
+                            obs_filtered[[i, 1]],
+                            obs_filtered[[i, 2]],
+                        ],
+                        [
+                            sun_filtered[[i, 0]],
+                            sun_filtered[[i, 1]],
+                            sun_filtered[[i, 2]],
+                        ],
+                    )
+                })
+                .collect(),
+        ))
+    }
+
     fn name(&self) -> String {
         format!(
             "Eclipse({})",
@@ -270,15 +542,16 @@ impl ConstraintEvaluator for EclipseEvaluator {
 
 #[cfg(test)]
 mod tests {
-    use super::EclipseEvaluator;
+    use super::{illumination_factor, EclipseEvaluator, ShadowTransition};
     use crate::utils::config::AU_TO_KM;
 
     #[test]
     fn test_penumbra_wider_than_umbra() {
         let sun_pos = [AU_TO_KM, 0.0, 0.0];
         let obs_pos = [-7000.0, 0.0, 0.0];
-        let (_dist_to_axis, umbra_radius, penumbra_radius) =
-            EclipseEvaluator::shadow_geometry(obs_pos, sun_pos).expect("shadow geometry");
+        let (_dist_to_axis, umbra_radius, penumbra_radius) = EclipseEvaluator::new(false)
+            .shadow_geometry(obs_pos, sun_pos)
+            .expect("shadow geometry");
         assert!(umbra_radius > 0.0, "umbra radius should be positive");
         assert!(
             penumbra_radius > umbra_radius,
@@ -291,13 +564,14 @@ mod tests {
         let sun_pos = [AU_TO_KM, 0.0, 0.0];
         let s = 7000.0;
         let on_axis = [-s, 0.0, 0.0];
-        let (_dist_to_axis, umbra_radius, penumbra_radius) =
-            EclipseEvaluator::shadow_geometry(on_axis, sun_pos).expect("shadow geometry");
+        let (_dist_to_axis, umbra_radius, penumbra_radius) = EclipseEvaluator::new(false)
+            .shadow_geometry(on_axis, sun_pos)
+            .expect("shadow geometry");
         let d = 0.5 * (umbra_radius + penumbra_radius);
         let obs_pos = [-s, d, 0.0];
 
-        let umbra_only = EclipseEvaluator { umbra_only: true };
-        let with_penumbra = EclipseEvaluator { umbra_only: false };
+        let umbra_only = EclipseEvaluator::new(true);
+        let with_penumbra = EclipseEvaluator::new(false);
 
         let (in_umbra_only, _) = umbra_only.shadow_status(obs_pos, sun_pos);
         let (in_penumbra, _) = with_penumbra.shadow_status(obs_pos, sun_pos);
@@ -305,4 +579,65 @@ mod tests {
         assert!(!in_umbra_only, "point should be outside umbra");
         assert!(in_penumbra, "point should be inside penumbra");
     }
+
+    #[test]
+    fn test_illumination_factor_across_shadow() {
+        let sun_pos = [AU_TO_KM, 0.0, 0.0];
+        assert_eq!(illumination_factor([7000.0, 0.0, 0.0], sun_pos), 1.0);
+        assert_eq!(illumination_factor([-7000.0, 0.0, 0.0], sun_pos), 0.0);
+
+        // Halfway across the penumbra the factor is strictly between 0 and 1
+        let s = 7000.0;
+        let (_, umbra_radius, penumbra_radius) = EclipseEvaluator::new(false)
+            .shadow_geometry([-s, 0.0, 0.0], sun_pos)
+            .expect("shadow geometry");
+        let d = 0.5 * (umbra_radius + penumbra_radius);
+        let factor = illumination_factor([-s, d, 0.0], sun_pos);
+        assert!(factor > 0.0 && factor < 1.0, "penumbra factor {factor}");
+
+        // and increases monotonically outward
+        let outer = illumination_factor([-s, d + 5.0, 0.0], sun_pos);
+        assert!(outer > factor);
+    }
+
+    #[test]
+    fn test_shadow_events_full_profile() {
+        use chrono::{Duration, TimeZone, Utc};
+        use ndarray::Array2;
+
+        // Circular equatorial orbit with the Sun along +x: one pass behind the
+        // Earth crosses penumbra, umbra, umbra and penumbra boundaries
+        let radius = 7000.0;
+        let n = 200;
+        let start = Utc.with_ymd_and_hms(2024, 1, 1, 0, 0, 0).unwrap();
+        let times: Vec<_> = (0..n).map(|i| start + Duration::seconds(30 * i)).collect();
+        let mut obs = Array2::<f64>::zeros((n as usize, 3));
+        let mut sun = Array2::<f64>::zeros((n as usize, 3));
+        for i in 0..n as usize {
+            let angle = 1.0 + 4.3 * i as f64 / (n - 1) as f64;
+            obs[[i, 0]] = radius * angle.cos();
+            obs[[i, 1]] = radius * angle.sin();
+            sun[[i, 0]] = AU_TO_KM;
+        }
+
+        let evaluator = EclipseEvaluator::new(false);
+        let events = evaluator.events_with_positions(&times, &sun, &obs);
+        let kinds: Vec<_> = events.iter().map(|event| event.transition).collect();
+        assert_eq!(
+            kinds,
+            vec![
+                ShadowTransition::PenumbraEntry,
+                ShadowTransition::UmbraEntry,
+                ShadowTransition::UmbraExit,
+                ShadowTransition::PenumbraExit,
+            ]
+        );
+        assert!(events.windows(2).all(|pair| pair[0].time < pair[1].time));
+
+        let depths = evaluator.depth_series(&sun, &obs);
+        assert_eq!(
+            evaluator.shadow_profile(&depths),
+            "penumbra, umbra, penumbra"
+        );
+    }
 }
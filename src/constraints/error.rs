@@ -0,0 +1,76 @@
+//! Errors from building and evaluating constraints
+//!
+//! Evaluators report failures as [`ConstraintError`] so they can be used
+//! without a Python interpreter; the Python bindings map the variants onto
+//! `ValueError` and `RuntimeError`.
+
+use pyo3::PyResult;
+use std::fmt;
+
+/// Error returned by constraint builders and evaluators
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ConstraintError {
+    /// A limit, target or array argument is out of range or malformed
+    InvalidInput(String),
+    /// The ephemeris cannot provide the data the constraint needs
+    Ephemeris(String),
+}
+
+impl ConstraintError {
+    /// Invalid input error with `message`
+    pub fn invalid_input(message: impl Into<String>) -> Self {
+        ConstraintError::InvalidInput(message.into())
+    }
+
+    /// Ephemeris error with `message`
+    pub fn ephemeris(message: impl Into<String>) -> Self {
+        ConstraintError::Ephemeris(message.into())
+    }
+
+    /// Error message without the variant
+    pub fn message(&self) -> &str {
+        match self {
+            ConstraintError::InvalidInput(message) | ConstraintError::Ephemeris(message) => message,
+        }
+    }
+}
+
+impl fmt::Display for ConstraintError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.message())
+    }
+}
+
+impl std::error::Error for ConstraintError {}
+
+/// Maps a failed ephemeris accessor onto [`ConstraintError::Ephemeris`]
+///
+/// The accessors only fail when the ephemeris lacks the data asked for, and
+/// report it as a Python exception whose text can't be read without an
+/// interpreter, so the evaluator names the missing data itself.
+pub(crate) trait OrMissing<T> {
+    /// The value, or `Ephemeris("No {what} available")`
+    fn or_missing(self, what: &str) -> Result<T, ConstraintError>;
+}
+
+impl<T> OrMissing<T> for PyResult<T> {
+    fn or_missing(self, what: &str) -> Result<T, ConstraintError> {
+        self.map_err(|_| ConstraintError::Ephemeris(format!("No {} available", what)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pyo3::exceptions::PyValueError;
+
+    #[test]
+    fn test_or_missing_needs_no_interpreter() {
+        let missing: PyResult<()> = Err(PyValueError::new_err("No times available"));
+        assert_eq!(
+            missing.or_missing("times"),
+            Err(ConstraintError::ephemeris("No times available"))
+        );
+        assert_eq!(Ok::<_, pyo3::PyErr>(1).or_missing("times"), Ok(1));
+    }
+}
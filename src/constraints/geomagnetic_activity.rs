@@ -0,0 +1,187 @@
+use super::core::{
+    time_only_diagonal, track_violations, ConstraintConfig, ConstraintEvaluator, ConstraintResult,
+};
+/// Geomagnetic activity constraint implementation
+///
+/// Violated while the planetary Kp index exceeds a threshold, e.g. to avoid
+/// observing through the enhanced particle background of a geomagnetic storm.
+/// Kp comes from the Celestrak space-weather data (see `utils::space_weather`)
+/// and does not depend on the target. Times with no 3-hourly Kp, such as
+/// predicted days or dates outside the data, are never violated.
+use crate::constraints::error::{ConstraintError, OrMissing};
+use crate::ephemeris::ephemeris_common::EphemerisBase;
+use crate::utils::space_weather::space_weather_data;
+use chrono::{DateTime, Utc};
+use ndarray::Array2;
+use serde::{Deserialize, Serialize};
+
+/// Configuration for the geomagnetic activity constraint
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GeomagneticActivityConfig {
+    /// Maximum allowed planetary Kp index (0-9)
+    pub max_kp: f64,
+}
+
+impl GeomagneticActivityConfig {
+    /// Check the Kp threshold
+    pub fn validate(&self) -> Result<(), String> {
+        if !(0.0..=9.0).contains(&self.max_kp) {
+            return Err("max_kp must be between 0 and 9".to_string());
+        }
+        Ok(())
+    }
+}
+
+impl ConstraintConfig for GeomagneticActivityConfig {
+    fn to_evaluator(&self) -> Box<dyn ConstraintEvaluator> {
+        Box::new(GeomagneticActivityEvaluator {
+            max_kp: self.max_kp,
+        })
+    }
+}
+
+/// Evaluator for the geomagnetic activity constraint
+pub struct GeomagneticActivityEvaluator {
+    max_kp: f64,
+}
+
+impl GeomagneticActivityEvaluator {
+    fn format_name(&self) -> String {
+        format!("GeomagneticActivityConstraint(max_kp={:.1})", self.max_kp)
+    }
+
+    /// Kp at the selected ephemeris times, None where there is no data
+    fn kp_values(
+        ephemeris: &dyn EphemerisBase,
+        time_indices: Option<&[usize]>,
+    ) -> Result<Vec<Option<f64>>, ConstraintError> {
+        let times = ephemeris.get_times().or_missing("times")?;
+        let selected: Vec<DateTime<Utc>> = match time_indices {
+            Some(indices) => indices.iter().map(|&i| times[i]).collect(),
+            None => times,
+        };
+        let data = space_weather_data();
+        Ok(selected
+            .iter()
+            .map(|t| data.as_ref().and_then(|d| d.at(t)).and_then(|s| s.kp))
+            .collect())
+    }
+
+    fn violated(&self, kp: Option<f64>) -> bool {
+        kp.is_some_and(|kp| kp > self.max_kp)
+    }
+
+    /// Evaluate the constraint with pre-computed Kp values
+    pub fn evaluate_with_kp(
+        &self,
+        times: &[DateTime<Utc>],
+        kp_values: &[Option<f64>],
+    ) -> ConstraintResult {
+        let violations = track_violations(
+            times,
+            |i| match kp_values[i] {
+                Some(kp) if kp > self.max_kp => (true, kp - self.max_kp),
+                _ => (false, 0.0),
+            },
+            |i, _| {
+                format!(
+                    "Kp {:.1} exceeds maximum {:.1}",
+                    kp_values[i].unwrap_or(f64::NAN),
+                    self.max_kp
+                )
+            },
+        );
+
+        let all_satisfied = violations.is_empty();
+        ConstraintResult::new(
+            violations,
+            all_satisfied,
+            self.format_name(),
+            times.to_vec(),
+        )
+    }
+}
+
+impl ConstraintEvaluator for GeomagneticActivityEvaluator {
+    fn evaluate(
+        &self,
+        ephemeris: &dyn EphemerisBase,
+        _target_ra: f64,
+        _target_dec: f64,
+        time_indices: Option<&[usize]>,
+    ) -> Result<ConstraintResult, ConstraintError> {
+        let times = ephemeris.get_times().or_missing("times")?;
+        let times_filtered: Vec<DateTime<Utc>> = match time_indices {
+            Some(indices) => indices.iter().map(|&i| times[i]).collect(),
+            None => times,
+        };
+        let kp_values = Self::kp_values(ephemeris, time_indices)?;
+        Ok(self.evaluate_with_kp(&times_filtered, &kp_values))
+    }
+
+    fn in_constraint_batch(
+        &self,
+        ephemeris: &dyn EphemerisBase,
+        target_ras: &[f64],
+        _target_decs: &[f64],
+        time_indices: Option<&[usize]>,
+    ) -> Result<Array2<bool>, ConstraintError> {
+        let kp_values = Self::kp_values(ephemeris, time_indices)?;
+        Ok(Array2::from_shape_fn(
+            (target_ras.len(), kp_values.len()),
+            |(_, i)| self.violated(kp_values[i]),
+        ))
+    }
+
+    fn in_constraint_batch_diagonal(
+        &self,
+        ephemeris: &dyn EphemerisBase,
+        target_ras: &[f64],
+        _target_decs: &[f64],
+    ) -> Result<Vec<bool>, ConstraintError> {
+        time_only_diagonal(
+            target_ras.len(),
+            |indices| Self::kp_values(ephemeris, indices),
+            |&kp| self.violated(kp),
+        )
+    }
+
+    fn name(&self) -> String {
+        self.format_name()
+    }
+
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::{Duration, TimeZone};
+
+    #[test]
+    fn test_validate() {
+        assert!(GeomagneticActivityConfig { max_kp: 5.0 }.validate().is_ok());
+        assert!(GeomagneticActivityConfig { max_kp: 9.5 }
+            .validate()
+            .is_err());
+        assert!(GeomagneticActivityConfig { max_kp: f64::NAN }
+            .validate()
+            .is_err());
+    }
+
+    #[test]
+    fn test_evaluate_with_kp() {
+        let evaluator = GeomagneticActivityEvaluator { max_kp: 5.0 };
+        let start = Utc.with_ymd_and_hms(2024, 5, 10, 0, 0, 0).unwrap();
+        let times: Vec<DateTime<Utc>> = (0..5).map(|i| start + Duration::hours(3 * i)).collect();
+        let kp = [Some(4.0), Some(5.7), None, Some(8.3), Some(5.0)];
+        let result = evaluator.evaluate_with_kp(&times, &kp);
+
+        assert!(!result.all_satisfied);
+        assert_eq!(result.violations.len(), 2);
+        assert_eq!(result.violations[0].start_time_internal, times[1]);
+        assert!((result.violations[1].max_severity - 3.3).abs() < 1e-12);
+    }
+}
@@ -0,0 +1,408 @@
+use super::core::{track_violations, ConstraintConfig, ConstraintEvaluator, ConstraintResult};
+/// Two-axis antenna gimbal constraint implementation
+///
+/// Models a ground antenna on an azimuth/elevation mount. The target cannot be
+/// tracked while it is inside the keyhole cone around the mount's singular
+/// direction (the zenith for a classic az/el mount) or while following it needs
+/// a faster azimuth or elevation slew than the mount supports.
+///
+/// Rates are finite differences of the topocentric azimuth and elevation over
+/// neighbouring ephemeris samples, so they resolve only as finely as the
+/// ephemeris step. For a fixed RA/Dec target the neighbours are the adjacent
+/// ephemeris times; for a moving target evaluated along the diagonal (for
+/// example a spacecraft pass through `evaluate_moving_body`) they are the
+/// adjacent positions of the target.
+use crate::constraints::error::{ConstraintError, OrMissing};
+use crate::ephemeris::ephemeris_common::EphemerisBase;
+use chrono::{DateTime, Utc};
+use ndarray::Array2;
+use serde::{Deserialize, Serialize};
+
+/// Configuration for the antenna gimbal constraint
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GimbalConfig {
+    /// Half-angle of the keyhole cone in degrees (optional)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub keyhole_radius: Option<f64>,
+    /// Azimuth of the keyhole centre in degrees (0 = North, 90 = East)
+    pub keyhole_azimuth: f64,
+    /// Elevation of the keyhole centre in degrees (90 = zenith)
+    pub keyhole_elevation: f64,
+    /// Maximum azimuth slew rate in degrees per second (optional)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_azimuth_rate: Option<f64>,
+    /// Maximum elevation slew rate in degrees per second (optional)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_elevation_rate: Option<f64>,
+}
+
+pub fn default_keyhole_elevation() -> f64 {
+    90.0
+}
+
+impl GimbalConfig {
+    /// Check the keyhole geometry and rate limits
+    pub fn validate(&self) -> Result<(), String> {
+        if self.keyhole_radius.is_none()
+            && self.max_azimuth_rate.is_none()
+            && self.max_elevation_rate.is_none()
+        {
+            return Err(
+                "at least one of keyhole_radius, max_azimuth_rate and max_elevation_rate is required"
+                    .to_string(),
+            );
+        }
+        if let Some(radius) = self.keyhole_radius {
+            if !(0.0..=90.0).contains(&radius) {
+                return Err("keyhole_radius must be between 0 and 90 degrees".to_string());
+            }
+        }
+        if !(0.0..=360.0).contains(&self.keyhole_azimuth) {
+            return Err("keyhole_azimuth must be between 0 and 360 degrees".to_string());
+        }
+        if !(-90.0..=90.0).contains(&self.keyhole_elevation) {
+            return Err("keyhole_elevation must be between -90 and 90 degrees".to_string());
+        }
+        for (name, rate) in [
+            ("max_azimuth_rate", self.max_azimuth_rate),
+            ("max_elevation_rate", self.max_elevation_rate),
+        ] {
+            if rate.is_some_and(|r| !(r.is_finite() && r > 0.0)) {
+                return Err(format!("{} must be positive", name));
+            }
+        }
+        Ok(())
+    }
+}
+
+impl ConstraintConfig for GimbalConfig {
+    fn to_evaluator(&self) -> Box<dyn ConstraintEvaluator> {
+        Box::new(GimbalEvaluator {
+            keyhole_radius: self.keyhole_radius,
+            keyhole_azimuth: self.keyhole_azimuth,
+            keyhole_elevation: self.keyhole_elevation,
+            max_azimuth_rate: self.max_azimuth_rate,
+            max_elevation_rate: self.max_elevation_rate,
+        })
+    }
+}
+
+/// Topocentric pointing of the target and the slew rates needed to follow it
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct Track {
+    altitude: f64,
+    azimuth: f64,
+    /// Degrees per second
+    azimuth_rate: f64,
+    /// Degrees per second
+    elevation_rate: f64,
+}
+
+/// Attach central-difference slew rates to a sequence of (altitude, azimuth) samples
+///
+/// End points use one-sided differences; a single sample has zero rates.
+/// Azimuth differences are wrapped to [-180, 180) so crossing north does not
+/// register as a full turn.
+fn tracks(times: &[DateTime<Utc>], altaz: &[(f64, f64)]) -> Vec<Track> {
+    let n = altaz.len();
+    (0..n)
+        .map(|i| {
+            let (lo, hi) = (i.saturating_sub(1), (i + 1).min(n - 1));
+            let dt = (times[hi] - times[lo]).num_milliseconds() as f64 / 1000.0;
+            let (azimuth_rate, elevation_rate) = if hi == lo || dt == 0.0 {
+                (0.0, 0.0)
+            } else {
+                let d_az = (altaz[hi].1 - altaz[lo].1 + 180.0).rem_euclid(360.0) - 180.0;
+                (d_az / dt, (altaz[hi].0 - altaz[lo].0) / dt)
+            };
+            Track {
+                altitude: altaz[i].0,
+                azimuth: altaz[i].1,
+                azimuth_rate,
+                elevation_rate,
+            }
+        })
+        .collect()
+}
+
+/// Reason the gimbal cannot follow the target
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Violation {
+    /// Angular distance from the keyhole centre in degrees
+    Keyhole(f64),
+    /// Required azimuth rate in degrees per second
+    AzimuthRate(f64),
+    /// Required elevation rate in degrees per second
+    ElevationRate(f64),
+}
+
+/// Evaluator for the antenna gimbal constraint
+struct GimbalEvaluator {
+    keyhole_radius: Option<f64>,
+    keyhole_azimuth: f64,
+    keyhole_elevation: f64,
+    max_azimuth_rate: Option<f64>,
+    max_elevation_rate: Option<f64>,
+}
+
+impl GimbalEvaluator {
+    fn format_name(&self) -> String {
+        let mut parts = vec![];
+        if let Some(radius) = self.keyhole_radius {
+            parts.push(format!(
+                "keyhole={:.1}° at (az={:.1}°, el={:.1}°)",
+                radius, self.keyhole_azimuth, self.keyhole_elevation
+            ));
+        }
+        if let Some(rate) = self.max_azimuth_rate {
+            parts.push(format!("max_az_rate={:.2}°/s", rate));
+        }
+        if let Some(rate) = self.max_elevation_rate {
+            parts.push(format!("max_el_rate={:.2}°/s", rate));
+        }
+        format!("GimbalConstraint({})", parts.join(", "))
+    }
+
+    /// Angular distance in degrees between the target and the keyhole centre
+    fn keyhole_distance(&self, altitude: f64, azimuth: f64) -> f64 {
+        let (e1, e2) = (altitude.to_radians(), self.keyhole_elevation.to_radians());
+        let d_az = (azimuth - self.keyhole_azimuth).to_radians();
+        (e1.sin() * e2.sin() + e1.cos() * e2.cos() * d_az.cos())
+            .clamp(-1.0, 1.0)
+            .acos()
+            .to_degrees()
+    }
+
+    /// First limit the track breaks, with its severity
+    fn check(&self, track: &Track) -> Option<(Violation, f64)> {
+        if let Some(radius) = self.keyhole_radius {
+            let distance = self.keyhole_distance(track.altitude, track.azimuth);
+            if distance < radius {
+                return Some((Violation::Keyhole(distance), (radius - distance).min(1.0)));
+            }
+        }
+        if let Some(max) = self.max_azimuth_rate {
+            if track.azimuth_rate.abs() > max {
+                let severity = ((track.azimuth_rate.abs() - max) / max).min(1.0);
+                return Some((Violation::AzimuthRate(track.azimuth_rate), severity));
+            }
+        }
+        if let Some(max) = self.max_elevation_rate {
+            if track.elevation_rate.abs() > max {
+                let severity = ((track.elevation_rate.abs() - max) / max).min(1.0);
+                return Some((Violation::ElevationRate(track.elevation_rate), severity));
+            }
+        }
+        None
+    }
+
+    fn describe(&self, violation: Violation) -> String {
+        match violation {
+            Violation::Keyhole(distance) => format!(
+                "Target {:.1}° from keyhole centre, inside {:.1}° keyhole",
+                distance,
+                self.keyhole_radius.unwrap_or_default()
+            ),
+            Violation::AzimuthRate(rate) => format!(
+                "Azimuth rate {:.2}°/s exceeds max {:.2}°/s",
+                rate.abs(),
+                self.max_azimuth_rate.unwrap_or_default()
+            ),
+            Violation::ElevationRate(rate) => format!(
+                "Elevation rate {:.2}°/s exceeds max {:.2}°/s",
+                rate.abs(),
+                self.max_elevation_rate.unwrap_or_default()
+            ),
+        }
+    }
+
+    /// Track of a fixed target over all ephemeris times
+    fn fixed_target_tracks(
+        &self,
+        ephemeris: &dyn EphemerisBase,
+        times: &[DateTime<Utc>],
+        target_ra: f64,
+        target_dec: f64,
+    ) -> Vec<Track> {
+        let altaz = ephemeris.radec_to_altaz(target_ra, target_dec, None);
+        let samples: Vec<(f64, f64)> = altaz.rows().into_iter().map(|r| (r[0], r[1])).collect();
+        tracks(times, &samples)
+    }
+}
+
+impl ConstraintEvaluator for GimbalEvaluator {
+    fn evaluate(
+        &self,
+        ephemeris: &dyn EphemerisBase,
+        target_ra: f64,
+        target_dec: f64,
+        time_indices: Option<&[usize]>,
+    ) -> Result<ConstraintResult, ConstraintError> {
+        let times = ephemeris.get_times().or_missing("times")?;
+        let all_tracks = self.fixed_target_tracks(ephemeris, &times, target_ra, target_dec);
+        let (times_filtered, tracks_filtered): (Vec<DateTime<Utc>>, Vec<Track>) = match time_indices
+        {
+            Some(indices) => indices.iter().map(|&i| (times[i], all_tracks[i])).unzip(),
+            None => (times, all_tracks),
+        };
+        let checks: Vec<Option<(Violation, f64)>> =
+            tracks_filtered.iter().map(|t| self.check(t)).collect();
+
+        let violations = track_violations(
+            &times_filtered,
+            |i| match checks[i] {
+                Some((_, severity)) => (true, severity),
+                None => (false, 0.0),
+            },
+            |i, _| checks[i].map(|(v, _)| self.describe(v)).unwrap_or_default(),
+        );
+
+        let all_satisfied = violations.is_empty();
+        Ok(ConstraintResult::new(
+            violations,
+            all_satisfied,
+            self.format_name(),
+            times_filtered,
+        ))
+    }
+
+    fn in_constraint_batch(
+        &self,
+        ephemeris: &dyn EphemerisBase,
+        target_ras: &[f64],
+        target_decs: &[f64],
+        time_indices: Option<&[usize]>,
+    ) -> Result<Array2<bool>, ConstraintError> {
+        let times = ephemeris.get_times().or_missing("times")?;
+        let selected: Vec<usize> = match time_indices {
+            Some(indices) => indices.to_vec(),
+            None => (0..times.len()).collect(),
+        };
+
+        let mut result = Array2::from_elem((target_ras.len(), selected.len()), false);
+        for (j, (&ra, &dec)) in target_ras.iter().zip(target_decs).enumerate() {
+            let all_tracks = self.fixed_target_tracks(ephemeris, &times, ra, dec);
+            for (k, &i) in selected.iter().enumerate() {
+                result[[j, k]] = self.check(&all_tracks[i]).is_some();
+            }
+        }
+        Ok(result)
+    }
+
+    /// Moving-target evaluation: rates follow the target from one sample to the next
+    fn in_constraint_batch_diagonal(
+        &self,
+        ephemeris: &dyn EphemerisBase,
+        target_ras: &[f64],
+        target_decs: &[f64],
+    ) -> Result<Vec<bool>, ConstraintError> {
+        let n = target_ras.len();
+        if n == 0 {
+            return Ok(Vec::new());
+        }
+        let times = ephemeris.get_times().or_missing("times")?;
+        let samples: Vec<(f64, f64)> = (0..n)
+            .map(|i| {
+                let altaz = ephemeris.radec_to_altaz(target_ras[i], target_decs[i], Some(&[i][..]));
+                (altaz[[0, 0]], altaz[[0, 1]])
+            })
+            .collect();
+        Ok(tracks(&times[..n], &samples)
+            .iter()
+            .map(|t| self.check(t).is_some())
+            .collect())
+    }
+
+    fn name(&self) -> String {
+        self.format_name()
+    }
+
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+
+    fn evaluator() -> GimbalEvaluator {
+        GimbalEvaluator {
+            keyhole_radius: Some(5.0),
+            keyhole_azimuth: 0.0,
+            keyhole_elevation: 90.0,
+            max_azimuth_rate: Some(1.0),
+            max_elevation_rate: None,
+        }
+    }
+
+    #[test]
+    fn test_tracks_wrap_azimuth_through_north() {
+        let t0 = Utc.with_ymd_and_hms(2024, 1, 1, 0, 0, 0).unwrap();
+        let times: Vec<_> = (0..3)
+            .map(|i| t0 + chrono::Duration::seconds(10 * i))
+            .collect();
+        let t = tracks(&times, &[(10.0, 350.0), (12.0, 0.0), (14.0, 10.0)]);
+        assert!((t[0].azimuth_rate - 1.0).abs() < 1e-12);
+        assert!((t[1].azimuth_rate - 1.0).abs() < 1e-12);
+        assert!((t[1].elevation_rate - 0.2).abs() < 1e-12);
+        assert_eq!(tracks(&times[..1], &[(10.0, 350.0)])[0].azimuth_rate, 0.0);
+    }
+
+    #[test]
+    fn test_check_keyhole_and_rates() {
+        let e = evaluator();
+        let track = |altitude, azimuth_rate| Track {
+            altitude,
+            azimuth: 120.0,
+            azimuth_rate,
+            elevation_rate: 0.0,
+        };
+        assert_eq!(e.check(&track(45.0, 0.5)), None);
+        assert!(matches!(
+            e.check(&track(87.0, 0.5)),
+            Some((Violation::Keyhole(_), _))
+        ));
+        assert!(matches!(
+            e.check(&track(45.0, -1.5)),
+            Some((Violation::AzimuthRate(_), _))
+        ));
+    }
+
+    #[test]
+    fn test_keyhole_distance_off_zenith() {
+        let e = GimbalEvaluator {
+            keyhole_azimuth: 90.0,
+            keyhole_elevation: 0.0,
+            ..evaluator()
+        };
+        assert!((e.keyhole_distance(0.0, 90.0)).abs() < 1e-9);
+        assert!((e.keyhole_distance(0.0, 180.0) - 90.0).abs() < 1e-9);
+        assert!((e.keyhole_distance(90.0, 0.0) - 90.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_validate() {
+        let config = GimbalConfig {
+            keyhole_radius: None,
+            keyhole_azimuth: 0.0,
+            keyhole_elevation: default_keyhole_elevation(),
+            max_azimuth_rate: None,
+            max_elevation_rate: None,
+        };
+        assert!(config.validate().is_err());
+        assert!(GimbalConfig {
+            max_azimuth_rate: Some(2.0),
+            ..config.clone()
+        }
+        .validate()
+        .is_ok());
+        assert!(GimbalConfig {
+            max_azimuth_rate: Some(0.0),
+            ..config
+        }
+        .validate()
+        .is_err());
+    }
+}
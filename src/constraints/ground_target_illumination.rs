@@ -0,0 +1,419 @@
+use super::core::{
+    time_only_diagonal, track_violations, ConstraintConfig, ConstraintEvaluator, ConstraintResult,
+};
+/// Ground-target illumination constraint implementation
+///
+/// For Earth-observation tasking: a fixed point on the ground (the scene) is
+/// observable while the spacecraft has access to it and the scene is lit as
+/// required. Access is the spacecraft's elevation above the scene's local
+/// horizon and, optionally, the off-nadir angle the spacecraft must point at
+/// to see it. Illumination is the Sun's elevation at the scene, which puts
+/// the day/night terminator at 0° (or a twilight depth below it); a maximum
+/// Sun elevation selects night passes instead. All geometry is done in the
+/// Earth-fixed frame, so the check does not depend on the RA/Dec target.
+use crate::constraints::error::{ConstraintError, OrMissing};
+use crate::ephemeris::ephemeris_common::EphemerisBase;
+use crate::ephemeris::ground_access::sun_itrs_positions;
+use crate::utils::geo::{enu_basis, geodetic_to_ecef_km};
+use chrono::{DateTime, Utc};
+use ndarray::Array2;
+use serde::{Deserialize, Serialize};
+
+/// Configuration for the ground-target illumination constraint
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GroundTargetIlluminationConfig {
+    /// Geodetic latitude of the scene in degrees
+    pub latitude: f64,
+    /// Longitude of the scene in degrees, east positive
+    pub longitude: f64,
+    /// Height of the scene above the WGS84 ellipsoid in metres
+    pub height: f64,
+    /// Minimum Sun elevation at the scene in degrees
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub min_sun_elevation: Option<f64>,
+    /// Maximum Sun elevation at the scene in degrees (e.g. -12 for night imaging)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_sun_elevation: Option<f64>,
+    /// Minimum spacecraft elevation seen from the scene in degrees
+    pub min_elevation: f64,
+    /// Maximum off-nadir angle from the spacecraft to the scene in degrees
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_off_nadir: Option<f64>,
+}
+
+impl GroundTargetIlluminationConfig {
+    /// Check the scene location and the elevation limits
+    pub fn validate(&self) -> Result<(), String> {
+        if !(-90.0..=90.0).contains(&self.latitude) {
+            return Err("latitude must be between -90 and 90 degrees".to_string());
+        }
+        if !(-180.0..=360.0).contains(&self.longitude) {
+            return Err("longitude must be between -180 and 360 degrees".to_string());
+        }
+        if !self.height.is_finite() {
+            return Err("height must be finite".to_string());
+        }
+        let elevations = [
+            ("min_sun_elevation", self.min_sun_elevation),
+            ("max_sun_elevation", self.max_sun_elevation),
+            ("min_elevation", Some(self.min_elevation)),
+        ];
+        for (name, value) in elevations {
+            if value.is_some_and(|v| !(-90.0..=90.0).contains(&v)) {
+                return Err(format!("{name} must be between -90 and 90 degrees"));
+            }
+        }
+        if let (Some(min), Some(max)) = (self.min_sun_elevation, self.max_sun_elevation) {
+            if min >= max {
+                return Err("min_sun_elevation must be less than max_sun_elevation".to_string());
+            }
+        }
+        if let Some(max_off_nadir) = self.max_off_nadir {
+            if !(max_off_nadir > 0.0 && max_off_nadir <= 90.0) {
+                return Err("max_off_nadir must be between 0 and 90 degrees".to_string());
+            }
+        }
+        Ok(())
+    }
+}
+
+impl ConstraintConfig for GroundTargetIlluminationConfig {
+    fn to_evaluator(&self) -> Box<dyn ConstraintEvaluator> {
+        Box::new(GroundTargetIlluminationEvaluator {
+            latitude: self.latitude,
+            longitude: (self.longitude + 180.0).rem_euclid(360.0) - 180.0,
+            height: self.height,
+            min_sun_elevation: self.min_sun_elevation,
+            max_sun_elevation: self.max_sun_elevation,
+            min_elevation: self.min_elevation,
+            max_off_nadir: self.max_off_nadir,
+        })
+    }
+}
+
+/// Scene geometry at one time, all angles in degrees
+#[derive(Debug, Clone, Copy)]
+struct SceneGeometry {
+    sun_elevation: f64,
+    spacecraft_elevation: f64,
+    off_nadir: f64,
+}
+
+/// Evaluator for the ground-target illumination constraint
+pub struct GroundTargetIlluminationEvaluator {
+    latitude: f64,
+    longitude: f64,
+    height: f64,
+    min_sun_elevation: Option<f64>,
+    max_sun_elevation: Option<f64>,
+    min_elevation: f64,
+    max_off_nadir: Option<f64>,
+}
+
+/// Angle in degrees between two vectors
+fn angle_between(a: &[f64; 3], b: &[f64; 3]) -> f64 {
+    let dot = a[0] * b[0] + a[1] * b[1] + a[2] * b[2];
+    let norm = |v: &[f64; 3]| (v[0] * v[0] + v[1] * v[1] + v[2] * v[2]).sqrt();
+    (dot / (norm(a) * norm(b)))
+        .clamp(-1.0, 1.0)
+        .acos()
+        .to_degrees()
+}
+
+impl GroundTargetIlluminationEvaluator {
+    fn format_name(&self) -> String {
+        let mut parts = vec![
+            format!("lat={:.3}°", self.latitude),
+            format!("lon={:.3}°", self.longitude),
+        ];
+        if let Some(min) = self.min_sun_elevation {
+            parts.push(format!("min_sun_elevation={:.1}°", min));
+        }
+        if let Some(max) = self.max_sun_elevation {
+            parts.push(format!("max_sun_elevation={:.1}°", max));
+        }
+        parts.push(format!("min_elevation={:.1}°", self.min_elevation));
+        if let Some(max) = self.max_off_nadir {
+            parts.push(format!("max_off_nadir={:.1}°", max));
+        }
+        format!("GroundTargetIlluminationConstraint({})", parts.join(", "))
+    }
+
+    /// Sun and spacecraft geometry at the scene for given ITRS positions (km)
+    fn geometry(&self, sun: &[f64; 3], spacecraft: &[f64; 3]) -> SceneGeometry {
+        let scene = geodetic_to_ecef_km(self.latitude, self.longitude, self.height);
+        let up = enu_basis(self.latitude, self.longitude)[2];
+        let to_sun = [sun[0] - scene[0], sun[1] - scene[1], sun[2] - scene[2]];
+        let to_spacecraft = [
+            spacecraft[0] - scene[0],
+            spacecraft[1] - scene[1],
+            spacecraft[2] - scene[2],
+        ];
+        let nadir = [-spacecraft[0], -spacecraft[1], -spacecraft[2]];
+        let to_scene = [-to_spacecraft[0], -to_spacecraft[1], -to_spacecraft[2]];
+        SceneGeometry {
+            sun_elevation: 90.0 - angle_between(&up, &to_sun),
+            spacecraft_elevation: 90.0 - angle_between(&up, &to_spacecraft),
+            off_nadir: angle_between(&nadir, &to_scene),
+        }
+    }
+
+    /// Scene geometry at the selected ephemeris times
+    fn scene_geometry(
+        &self,
+        ephemeris: &dyn EphemerisBase,
+        time_indices: Option<&[usize]>,
+    ) -> Result<Vec<SceneGeometry>, ConstraintError> {
+        let times = ephemeris.get_times().or_missing("times")?;
+        let itrs = ephemeris
+            .get_itrs_positions()
+            .or_missing("times")?
+            .ok_or_else(|| ConstraintError::invalid_input("No ITRS positions available"))?;
+        let sun_itrs =
+            sun_itrs_positions(ephemeris, time_indices, ephemeris.applies_polar_motion())
+                .or_missing("times or Sun positions")?;
+        let rows: Vec<usize> = match time_indices {
+            Some(indices) => indices.to_vec(),
+            None => (0..times.len()).collect(),
+        };
+
+        Ok(rows
+            .iter()
+            .enumerate()
+            .map(|(k, &row)| {
+                let sun = [sun_itrs[[k, 0]], sun_itrs[[k, 1]], sun_itrs[[k, 2]]];
+                let spacecraft = [itrs[[row, 0]], itrs[[row, 1]], itrs[[row, 2]]];
+                self.geometry(&sun, &spacecraft)
+            })
+            .collect())
+    }
+
+    /// Severity of the worst violated condition, or None if the scene is observable
+    fn violation(&self, geometry: &SceneGeometry) -> Option<f64> {
+        let mut severity: Option<f64> = None;
+        let mut exceed = |excess: f64| {
+            if excess > 0.0 {
+                severity = Some(severity.unwrap_or(0.0).max(excess.min(1.0)));
+            }
+        };
+
+        exceed(self.min_elevation - geometry.spacecraft_elevation);
+        if let Some(max) = self.max_off_nadir {
+            exceed(geometry.off_nadir - max);
+        }
+        if let Some(min) = self.min_sun_elevation {
+            exceed(min - geometry.sun_elevation);
+        }
+        if let Some(max) = self.max_sun_elevation {
+            exceed(geometry.sun_elevation - max);
+        }
+        severity
+    }
+
+    /// Describe every condition violated for the given geometry
+    fn describe(&self, geometry: &SceneGeometry) -> String {
+        let mut reasons = Vec::new();
+        if geometry.spacecraft_elevation < self.min_elevation {
+            reasons.push(format!(
+                "spacecraft elevation {:.1}° below {:.1}°",
+                geometry.spacecraft_elevation, self.min_elevation
+            ));
+        }
+        if let Some(max) = self.max_off_nadir {
+            if geometry.off_nadir > max {
+                reasons.push(format!(
+                    "off-nadir angle {:.1}° > max {:.1}°",
+                    geometry.off_nadir, max
+                ));
+            }
+        }
+        if let Some(min) = self.min_sun_elevation {
+            if geometry.sun_elevation < min {
+                reasons.push(format!(
+                    "Sun elevation at target {:.1}° below {:.1}°",
+                    geometry.sun_elevation, min
+                ));
+            }
+        }
+        if let Some(max) = self.max_sun_elevation {
+            if geometry.sun_elevation > max {
+                reasons.push(format!(
+                    "Sun elevation at target {:.1}° above {:.1}°",
+                    geometry.sun_elevation, max
+                ));
+            }
+        }
+        reasons.join(", ")
+    }
+
+    /// Evaluate the constraint with pre-computed scene geometry
+    fn evaluate_with_geometry(
+        &self,
+        times: &[DateTime<Utc>],
+        geometry: &[SceneGeometry],
+    ) -> ConstraintResult {
+        let violations = track_violations(
+            times,
+            |i| match self.violation(&geometry[i]) {
+                Some(severity) => (true, severity),
+                None => (false, 0.0),
+            },
+            |i, _| self.describe(&geometry[i]),
+        );
+
+        let all_satisfied = violations.is_empty();
+        ConstraintResult::new(
+            violations,
+            all_satisfied,
+            self.format_name(),
+            times.to_vec(),
+        )
+    }
+}
+
+impl ConstraintEvaluator for GroundTargetIlluminationEvaluator {
+    fn evaluate(
+        &self,
+        ephemeris: &dyn EphemerisBase,
+        _target_ra: f64,
+        _target_dec: f64,
+        time_indices: Option<&[usize]>,
+    ) -> Result<ConstraintResult, ConstraintError> {
+        let times = ephemeris.get_times().or_missing("times")?;
+        let times_filtered: Vec<DateTime<Utc>> = match time_indices {
+            Some(indices) => indices.iter().map(|&i| times[i]).collect(),
+            None => times,
+        };
+        let geometry = self.scene_geometry(ephemeris, time_indices)?;
+        Ok(self.evaluate_with_geometry(&times_filtered, &geometry))
+    }
+
+    fn in_constraint_batch(
+        &self,
+        ephemeris: &dyn EphemerisBase,
+        target_ras: &[f64],
+        _target_decs: &[f64],
+        time_indices: Option<&[usize]>,
+    ) -> Result<Array2<bool>, ConstraintError> {
+        let geometry = self.scene_geometry(ephemeris, time_indices)?;
+        Ok(Array2::from_shape_fn(
+            (target_ras.len(), geometry.len()),
+            |(_, i)| self.violation(&geometry[i]).is_some(),
+        ))
+    }
+
+    fn in_constraint_batch_diagonal(
+        &self,
+        ephemeris: &dyn EphemerisBase,
+        target_ras: &[f64],
+        _target_decs: &[f64],
+    ) -> Result<Vec<bool>, ConstraintError> {
+        time_only_diagonal(
+            target_ras.len(),
+            |indices| self.scene_geometry(ephemeris, indices),
+            |geometry| self.violation(geometry).is_some(),
+        )
+    }
+
+    fn name(&self) -> String {
+        self.format_name()
+    }
+
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::{Duration, TimeZone};
+
+    fn evaluator() -> GroundTargetIlluminationEvaluator {
+        GroundTargetIlluminationEvaluator {
+            latitude: 0.0,
+            longitude: 0.0,
+            height: 0.0,
+            min_sun_elevation: Some(20.0),
+            max_sun_elevation: None,
+            min_elevation: 30.0,
+            max_off_nadir: None,
+        }
+    }
+
+    #[test]
+    fn test_validate() {
+        let config = GroundTargetIlluminationConfig {
+            latitude: 48.85,
+            longitude: 2.35,
+            height: 0.0,
+            min_sun_elevation: Some(20.0),
+            max_sun_elevation: None,
+            min_elevation: 30.0,
+            max_off_nadir: Some(45.0),
+        };
+        assert!(config.validate().is_ok());
+        assert!(GroundTargetIlluminationConfig {
+            max_sun_elevation: Some(10.0),
+            ..config.clone()
+        }
+        .validate()
+        .is_err());
+        assert!(GroundTargetIlluminationConfig {
+            latitude: 91.0,
+            ..config.clone()
+        }
+        .validate()
+        .is_err());
+        assert!(GroundTargetIlluminationConfig {
+            max_off_nadir: Some(0.0),
+            ..config
+        }
+        .validate()
+        .is_err());
+    }
+
+    #[test]
+    fn test_geometry_overhead() {
+        let eval = evaluator();
+        let sun = [1.5e8, 0.0, 0.0];
+        let geometry = eval.geometry(&sun, &[7000.0, 0.0, 0.0]);
+        assert!((geometry.sun_elevation - 90.0).abs() < 1e-3);
+        assert!((geometry.spacecraft_elevation - 90.0).abs() < 1e-9);
+        assert!(geometry.off_nadir < 1e-9);
+        assert_eq!(eval.violation(&geometry), None);
+
+        // Sun on the far side of the Earth: the scene is in darkness
+        let geometry = eval.geometry(&[-1.5e8, 0.0, 0.0], &[7000.0, 0.0, 0.0]);
+        assert!((geometry.sun_elevation + 90.0).abs() < 1e-3);
+        assert_eq!(eval.violation(&geometry), Some(1.0));
+        assert!(eval.describe(&geometry).contains("Sun elevation"));
+    }
+
+    #[test]
+    fn test_evaluate_with_geometry() {
+        let eval = GroundTargetIlluminationEvaluator {
+            max_off_nadir: Some(40.0),
+            ..evaluator()
+        };
+        let start = Utc.with_ymd_and_hms(2024, 6, 1, 0, 0, 0).unwrap();
+        let times: Vec<DateTime<Utc>> = (0..4).map(|i| start + Duration::minutes(i)).collect();
+        let scene = |sun_elevation, spacecraft_elevation, off_nadir| SceneGeometry {
+            sun_elevation,
+            spacecraft_elevation,
+            off_nadir,
+        };
+        let geometry = [
+            scene(45.0, 60.0, 20.0),
+            scene(45.0, 35.0, 50.0),
+            scene(10.0, 70.0, 10.0),
+            scene(45.0, 50.0, 30.0),
+        ];
+        let result = eval.evaluate_with_geometry(&times, &geometry);
+
+        assert_eq!(result.violations.len(), 1);
+        assert_eq!(result.violations[0].start_time_internal, times[1]);
+        assert_eq!(result.violations[0].end_time_internal, times[2]);
+        assert!(result.violations[0].description.contains("off-nadir"));
+    }
+}
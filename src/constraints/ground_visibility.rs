@@ -0,0 +1,374 @@
+use super::core::{track_violations, ConstraintConfig, ConstraintEvaluator, ConstraintResult};
+use super::daytime::TwilightType;
+/// Ground-site visibility constraint implementation
+///
+/// Combines the conditions for observing a target from a telescope on the
+/// ground: the target must clear the local horizon (the site's horizon mask
+/// and a minimum altitude), the Sun must be below the twilight limit, and
+/// optionally the airmass and the Moon's altitude are bounded. Sun and Moon
+/// altitudes are topocentric, computed from the direction from the site to
+/// the body rather than from the geocentric Sun/Moon positions, so the Moon's
+/// ~1° parallax is accounted for. Only `GroundEphemeris` observers are
+/// accepted.
+use crate::constraints::error::{ConstraintError, OrMissing};
+use crate::ephemeris::ephemeris_common::EphemerisBase;
+use crate::utils::atmosphere::airmass;
+use crate::utils::celestial::{body_altaz, radec_to_altaz_batch};
+use crate::utils::horizon::HorizonMask;
+use chrono::{DateTime, Utc};
+use ndarray::Array2;
+use serde::{Deserialize, Serialize};
+
+/// Configuration for the ground-site visibility constraint
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GroundVisibilityConfig {
+    /// Minimum target altitude in degrees, on top of the site's horizon mask
+    pub min_altitude: f64,
+    /// Twilight limit for the Sun's altitude
+    pub twilight: TwilightType,
+    /// Maximum allowed target airmass
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_airmass: Option<f64>,
+    /// Maximum allowed Moon altitude in degrees (e.g. -0.5 for moonless sky)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_moon_altitude: Option<f64>,
+}
+
+impl GroundVisibilityConfig {
+    /// Check the altitude and airmass limits
+    pub fn validate(&self) -> Result<(), String> {
+        if !(-90.0..=90.0).contains(&self.min_altitude) {
+            return Err("min_altitude must be between -90 and 90 degrees".to_string());
+        }
+        if let Some(max_airmass) = self.max_airmass {
+            if max_airmass.is_nan() || max_airmass < 1.0 {
+                return Err("max_airmass must be at least 1".to_string());
+            }
+        }
+        if let Some(max_moon_altitude) = self.max_moon_altitude {
+            if !(-90.0..=90.0).contains(&max_moon_altitude) {
+                return Err("max_moon_altitude must be between -90 and 90 degrees".to_string());
+            }
+        }
+        Ok(())
+    }
+}
+
+impl ConstraintConfig for GroundVisibilityConfig {
+    fn to_evaluator(&self) -> Box<dyn ConstraintEvaluator> {
+        Box::new(GroundVisibilityEvaluator {
+            min_altitude: self.min_altitude,
+            twilight: self.twilight.clone(),
+            max_airmass: self.max_airmass,
+            max_moon_altitude: self.max_moon_altitude,
+        })
+    }
+}
+
+/// Target-independent conditions at each selected time
+struct SkyConditions {
+    sun_altitudes: Vec<f64>,
+    moon_altitudes: Option<Vec<f64>>,
+    /// Observer heights in metres, for observers with altitude-dependent airmass
+    observer_heights: Option<Vec<f64>>,
+}
+
+impl SkyConditions {
+    /// Target airmass at altitude `altitude_deg` at time `i`
+    fn airmass(&self, altitude_deg: f64, i: usize) -> f64 {
+        airmass(
+            altitude_deg,
+            self.observer_heights.as_ref().map(|heights| heights[i]),
+        )
+    }
+}
+
+/// Evaluator for the ground-site visibility constraint
+pub struct GroundVisibilityEvaluator {
+    min_altitude: f64,
+    twilight: TwilightType,
+    max_airmass: Option<f64>,
+    max_moon_altitude: Option<f64>,
+}
+
+impl GroundVisibilityEvaluator {
+    fn format_name(&self) -> String {
+        let mut parts = vec![
+            format!("min_altitude={:.1}°", self.min_altitude),
+            format!("twilight={}", self.twilight.as_str()),
+        ];
+        if let Some(max_airmass) = self.max_airmass {
+            parts.push(format!("max_airmass={:.2}", max_airmass));
+        }
+        if let Some(max_moon_altitude) = self.max_moon_altitude {
+            parts.push(format!("max_moon_altitude={:.1}°", max_moon_altitude));
+        }
+        format!("GroundVisibilityConstraint({})", parts.join(", "))
+    }
+
+    /// Topocentric Sun (and, if limited, Moon) altitudes at the selected times
+    fn sky_conditions(
+        &self,
+        ephemeris: &dyn EphemerisBase,
+        time_indices: Option<&[usize]>,
+    ) -> Result<SkyConditions, ConstraintError> {
+        if !ephemeris.is_ground_site() {
+            return Err(ConstraintError::invalid_input(
+                "GroundVisibilityConstraint requires a GroundEphemeris observer",
+            ));
+        }
+        let sun_positions = ephemeris.get_sun_positions().or_missing("Sun positions")?;
+        let sun_altitudes = body_altaz(&sun_positions, ephemeris, time_indices)
+            .column(0)
+            .to_vec();
+        let moon_altitudes = match self.max_moon_altitude {
+            Some(_) => {
+                let moon_positions = ephemeris
+                    .get_moon_positions()
+                    .or_missing("Moon positions")?;
+                Some(
+                    body_altaz(&moon_positions, ephemeris, time_indices)
+                        .column(0)
+                        .to_vec(),
+                )
+            }
+            None => None,
+        };
+        let observer_heights = ephemeris
+            .airmass_heights()
+            .map(|heights| match time_indices {
+                Some(indices) => indices.iter().map(|&i| heights[i]).collect(),
+                None => heights.to_vec(),
+            });
+        Ok(SkyConditions {
+            sun_altitudes,
+            moon_altitudes,
+            observer_heights,
+        })
+    }
+
+    /// Lowest visible altitude in the target's direction
+    fn horizon_altitude(&self, azimuth_deg: f64, horizon: Option<&HorizonMask>) -> f64 {
+        match horizon {
+            Some(mask) => mask.min_elevation(azimuth_deg).max(self.min_altitude),
+            None => self.min_altitude,
+        }
+    }
+
+    /// Severity of the worst violated condition at time `i`, or None if visible
+    fn violation(
+        &self,
+        altitude_deg: f64,
+        azimuth_deg: f64,
+        sky: &SkyConditions,
+        i: usize,
+        horizon: Option<&HorizonMask>,
+    ) -> Option<f64> {
+        let mut severity: Option<f64> = None;
+        let mut exceed = |excess: f64| {
+            if excess > 0.0 {
+                severity = Some(severity.unwrap_or(0.0).max(excess.min(1.0)));
+            }
+        };
+
+        exceed(self.horizon_altitude(azimuth_deg, horizon) - altitude_deg);
+        exceed(sky.sun_altitudes[i] - self.twilight.sun_altitude_limit());
+        if let Some(max_airmass) = self.max_airmass {
+            exceed(sky.airmass(altitude_deg, i) - max_airmass);
+        }
+        if let (Some(max_moon_altitude), Some(moon)) =
+            (self.max_moon_altitude, sky.moon_altitudes.as_ref())
+        {
+            exceed(moon[i] - max_moon_altitude);
+        }
+        severity
+    }
+
+    /// Describe every condition violated at time `i`
+    fn describe(
+        &self,
+        altitude_deg: f64,
+        azimuth_deg: f64,
+        sky: &SkyConditions,
+        i: usize,
+        horizon: Option<&HorizonMask>,
+    ) -> String {
+        let mut reasons = Vec::new();
+
+        let horizon_alt = self.horizon_altitude(azimuth_deg, horizon);
+        if altitude_deg < horizon_alt {
+            reasons.push(format!(
+                "target altitude {:.1}° below horizon {:.1}° at az {:.1}°",
+                altitude_deg, horizon_alt, azimuth_deg
+            ));
+        }
+        let sun_limit = self.twilight.sun_altitude_limit();
+        if sky.sun_altitudes[i] > sun_limit {
+            reasons.push(format!(
+                "Sun altitude {:.1}° above {} twilight limit {:.1}°",
+                sky.sun_altitudes[i],
+                self.twilight.as_str(),
+                sun_limit
+            ));
+        }
+        if let Some(max_airmass) = self.max_airmass {
+            let airmass = sky.airmass(altitude_deg, i);
+            if airmass > max_airmass {
+                reasons.push(format!("airmass {:.2} > max {:.2}", airmass, max_airmass));
+            }
+        }
+        if let (Some(max_moon_altitude), Some(moon)) =
+            (self.max_moon_altitude, sky.moon_altitudes.as_ref())
+        {
+            if moon[i] > max_moon_altitude {
+                reasons.push(format!(
+                    "Moon altitude {:.1}° > max {:.1}°",
+                    moon[i], max_moon_altitude
+                ));
+            }
+        }
+
+        reasons.join(", ")
+    }
+}
+
+impl ConstraintEvaluator for GroundVisibilityEvaluator {
+    fn evaluate(
+        &self,
+        ephemeris: &dyn EphemerisBase,
+        target_ra: f64,
+        target_dec: f64,
+        time_indices: Option<&[usize]>,
+    ) -> Result<ConstraintResult, ConstraintError> {
+        let sky = self.sky_conditions(ephemeris, time_indices)?;
+        let times = ephemeris.get_times().or_missing("times")?;
+        let times_filtered: Vec<DateTime<Utc>> = match time_indices {
+            Some(indices) => indices.iter().map(|&i| times[i]).collect(),
+            None => times,
+        };
+        let altaz = ephemeris.radec_to_altaz(target_ra, target_dec, time_indices);
+        let horizon = ephemeris.horizon_mask();
+
+        let violations = track_violations(
+            &times_filtered,
+            |i| match self.violation(altaz[[i, 0]], altaz[[i, 1]], &sky, i, horizon) {
+                Some(severity) => (true, severity),
+                None => (false, 0.0),
+            },
+            |i, _| self.describe(altaz[[i, 0]], altaz[[i, 1]], &sky, i, horizon),
+        );
+
+        let all_satisfied = violations.is_empty();
+        Ok(ConstraintResult::new(
+            violations,
+            all_satisfied,
+            self.format_name(),
+            times_filtered,
+        ))
+    }
+
+    fn in_constraint_batch(
+        &self,
+        ephemeris: &dyn EphemerisBase,
+        target_ras: &[f64],
+        target_decs: &[f64],
+        time_indices: Option<&[usize]>,
+    ) -> Result<Array2<bool>, ConstraintError> {
+        let sky = self.sky_conditions(ephemeris, time_indices)?;
+        let altaz = radec_to_altaz_batch(target_ras, target_decs, ephemeris, time_indices, None);
+        let horizon = ephemeris.horizon_mask();
+
+        Ok(Array2::from_shape_fn(
+            (target_ras.len(), sky.sun_altitudes.len()),
+            |(j, i)| {
+                self.violation(altaz[[j, i, 0]], altaz[[j, i, 1]], &sky, i, horizon)
+                    .is_some()
+            },
+        ))
+    }
+
+    fn name(&self) -> String {
+        self.format_name()
+    }
+
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn evaluator(
+        max_airmass: Option<f64>,
+        max_moon_altitude: Option<f64>,
+    ) -> GroundVisibilityEvaluator {
+        GroundVisibilityEvaluator {
+            min_altitude: 20.0,
+            twilight: TwilightType::Astronomical,
+            max_airmass,
+            max_moon_altitude,
+        }
+    }
+
+    #[test]
+    fn test_validate() {
+        let config = GroundVisibilityConfig {
+            min_altitude: 20.0,
+            twilight: TwilightType::Nautical,
+            max_airmass: Some(2.0),
+            max_moon_altitude: None,
+        };
+        assert!(config.validate().is_ok());
+        assert!(GroundVisibilityConfig {
+            max_airmass: Some(0.5),
+            ..config.clone()
+        }
+        .validate()
+        .is_err());
+        assert!(GroundVisibilityConfig {
+            min_altitude: 95.0,
+            ..config
+        }
+        .validate()
+        .is_err());
+    }
+
+    #[test]
+    fn test_violation_conditions() {
+        let sky = SkyConditions {
+            sun_altitudes: vec![-30.0, -10.0, -30.0],
+            moon_altitudes: Some(vec![-5.0, -5.0, 30.0]),
+            observer_heights: None,
+        };
+        let eval = evaluator(None, Some(0.0));
+
+        assert_eq!(eval.violation(45.0, 180.0, &sky, 0, None), None);
+        assert_eq!(eval.violation(10.0, 180.0, &sky, 0, None), Some(1.0));
+        assert!(eval.violation(45.0, 180.0, &sky, 1, None).is_some());
+        assert!(eval.violation(45.0, 180.0, &sky, 2, None).is_some());
+        assert!(eval
+            .describe(45.0, 180.0, &sky, 1, None)
+            .contains("astronomical twilight"));
+    }
+
+    #[test]
+    fn test_horizon_mask_and_airmass() {
+        let sky = SkyConditions {
+            sun_altitudes: vec![-30.0],
+            moon_altitudes: None,
+            observer_heights: None,
+        };
+        let mask =
+            HorizonMask::new(&[(0.0, 40.0), (90.0, 0.0), (180.0, 0.0), (270.0, 0.0)]).unwrap();
+
+        let eval = evaluator(None, None);
+        assert!(eval.violation(30.0, 0.0, &sky, 0, Some(&mask)).is_some());
+        assert_eq!(eval.violation(30.0, 180.0, &sky, 0, Some(&mask)), None);
+
+        let eval = evaluator(Some(1.5), None);
+        assert!(eval.violation(30.0, 180.0, &sky, 0, None).is_some());
+        assert_eq!(eval.violation(60.0, 180.0, &sky, 0, None), None);
+    }
+}
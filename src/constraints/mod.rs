@@ -7,27 +7,58 @@
 #[macro_use]
 pub mod core;
 
+// Errors from constraint builders and evaluators
+pub mod error;
+
 // Shared FoV geometry
 pub mod fov_polygon;
 
+// Time-varying angle limits
+pub mod threshold_schedule;
+
 // Constraint implementations
 pub mod airmass;
 pub mod alt_az;
 pub mod body_proximity;
 pub mod bright_star;
+pub mod cutoff_rigidity;
 pub mod daytime;
 pub mod earth_limb;
+pub mod earth_occultation;
 pub mod eclipse;
+pub mod geomagnetic_activity;
+pub mod gimbal;
+pub mod ground_target_illumination;
+pub mod ground_visibility;
 pub mod moon_phase;
 pub mod moon_proximity;
 pub mod orbit_pole;
 pub mod orbit_ram;
+pub mod planets_proximity;
 pub mod saa;
+pub mod sky_region;
+pub mod station_keeping;
+pub mod sun_keepout;
 pub mod sun_proximity;
 
 // Python wrapper
 pub mod constraint_wrapper;
 
+// Consumers of constraint evaluation
+pub mod airmass_summary;
+pub mod attitude;
+pub mod cube;
+pub mod orbit_visibility;
+pub mod report;
+pub mod scheduler;
+pub mod virtual_target;
+
 // Re-export main types for public API
+pub use airmass_summary::AirmassSummary;
+pub use attitude::{AttitudeCheck, AttitudeViolation, FaceIncidence, SlewCheck, SlewIntrusion};
 pub use constraint_wrapper::PyConstraint;
 pub use core::{ConstraintResult, ConstraintViolation, MovingBodyResult, VisibilityWindow};
+pub use cube::ConstraintCube;
+pub use orbit_visibility::OrbitVisibility;
+pub use report::VisibilityReport;
+pub use scheduler::{Schedule, ScheduledObservation};
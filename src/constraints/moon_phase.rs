@@ -1,9 +1,9 @@
-/// Moon phase constraint implementation
 use super::core::{track_violations, ConstraintConfig, ConstraintEvaluator, ConstraintResult};
+/// Moon phase constraint implementation
+use crate::constraints::error::{ConstraintError, OrMissing};
 use chrono::{DateTime, Utc};
 use ndarray::{s, Array2};
 use once_cell::sync::Lazy;
-use pyo3::PyResult;
 use rayon::prelude::*;
 use serde::{Deserialize, Serialize};
 use sofars::astro::atco13;
@@ -145,9 +145,9 @@ impl MoonPhaseEvaluator {
         &self,
         moon_positions: &Array2<f64>,
         observer_positions: &Array2<f64>,
-    ) -> PyResult<Array2<f64>> {
+    ) -> Result<Array2<f64>, ConstraintError> {
         if moon_positions.nrows() != observer_positions.nrows() {
-            return Err(pyo3::exceptions::PyValueError::new_err(
+            return Err(ConstraintError::invalid_input(
                 "Moon and observer position arrays must have the same number of rows.",
             ));
         }
@@ -179,14 +179,14 @@ impl MoonPhaseEvaluator {
         times: &[DateTime<Utc>],
         moon_positions: &Array2<f64>,
         gcrs_full: &Array2<f64>,
-    ) -> PyResult<Vec<f64>> {
+    ) -> Result<Vec<f64>, ConstraintError> {
         if moon_positions.nrows() != gcrs_full.nrows() {
-            return Err(pyo3::exceptions::PyValueError::new_err(
+            return Err(ConstraintError::invalid_input(
                 "Moon and observer data must have the same number of rows.",
             ));
         }
         if gcrs_full.ncols() < 6 {
-            return Err(pyo3::exceptions::PyValueError::new_err(
+            return Err(ConstraintError::invalid_input(
                 "GCRS data must contain position and velocity columns.",
             ));
         }
@@ -233,9 +233,9 @@ impl MoonPhaseEvaluator {
         let lon_rad: Vec<f64> = lons_deg.iter().map(|v| v.to_radians()).collect();
         let height_m: Vec<f64> = heights_km.iter().map(|v| v * 1000.0).collect();
 
-        let altitudes: PyResult<Vec<f64>> = (0..times.len())
+        let altitudes: Result<Vec<f64>, ConstraintError> = (0..times.len())
             .into_par_iter()
-            .map(|i| -> PyResult<f64> {
+            .map(|i| -> Result<f64, ConstraintError> {
                 if distances[i] == 0.0 {
                     return Ok(-90.0);
                 }
@@ -266,7 +266,7 @@ impl MoonPhaseEvaluator {
                     0.55,
                 )
                 .map_err(|e| {
-                    pyo3::exceptions::PyValueError::new_err(format!(
+                    ConstraintError::invalid_input(format!(
                         "SOFA atco13 failed for Moon altitude: {e:?}"
                     ))
                 })?;
@@ -302,18 +302,25 @@ impl ConstraintEvaluator for MoonPhaseEvaluator {
         target_ra: f64,
         target_dec: f64,
         time_indices: Option<&[usize]>,
-    ) -> PyResult<ConstraintResult> {
+    ) -> Result<ConstraintResult, ConstraintError> {
         // Extract and filter time data
         let (times_filtered,) = extract_time_data!(ephemeris, time_indices);
 
-        let illuminations = ephemeris.moon_illumination(time_indices)?;
-
-        let moon_positions_all = ephemeris.get_moon_positions()?;
-        let observer_positions_all = ephemeris.get_gcrs_positions()?;
-        let gcrs_full_all =
-            ephemeris.data().gcrs.as_ref().ok_or_else(|| {
-                pyo3::exceptions::PyValueError::new_err("No GCRS data available.")
-            })?;
+        let illuminations = ephemeris
+            .moon_illumination(time_indices)
+            .or_missing("times")?;
+
+        let moon_positions_all = ephemeris
+            .get_moon_positions()
+            .or_missing("Moon positions")?;
+        let observer_positions_all = ephemeris
+            .get_gcrs_positions()
+            .or_missing("GCRS positions")?;
+        let gcrs_full_all = ephemeris
+            .data()
+            .gcrs
+            .as_ref()
+            .ok_or_else(|| ConstraintError::invalid_input("No GCRS data available."))?;
 
         let moon_positions = if let Some(indices) = time_indices {
             moon_positions_all.select(ndarray::Axis(0), indices)
@@ -465,7 +472,7 @@ impl ConstraintEvaluator for MoonPhaseEvaluator {
         target_ras: &[f64],
         target_decs: &[f64],
         time_indices: Option<&[usize]>,
-    ) -> PyResult<Array2<bool>> {
+    ) -> Result<Array2<bool>, ConstraintError> {
         // Extract and filter time data
         let (times_filtered,) = extract_time_data!(ephemeris, time_indices);
 
@@ -473,13 +480,20 @@ impl ConstraintEvaluator for MoonPhaseEvaluator {
         let n_times = times_filtered.len();
         let mut result = Array2::<bool>::from_elem((n_targets, n_times), false);
 
-        let illuminations = ephemeris.moon_illumination(time_indices)?;
-        let moon_positions_all = ephemeris.get_moon_positions()?;
-        let observer_positions_all = ephemeris.get_gcrs_positions()?;
-        let gcrs_full_all =
-            ephemeris.data().gcrs.as_ref().ok_or_else(|| {
-                pyo3::exceptions::PyValueError::new_err("No GCRS data available.")
-            })?;
+        let illuminations = ephemeris
+            .moon_illumination(time_indices)
+            .or_missing("times")?;
+        let moon_positions_all = ephemeris
+            .get_moon_positions()
+            .or_missing("Moon positions")?;
+        let observer_positions_all = ephemeris
+            .get_gcrs_positions()
+            .or_missing("GCRS positions")?;
+        let gcrs_full_all = ephemeris
+            .data()
+            .gcrs
+            .as_ref()
+            .ok_or_else(|| ConstraintError::invalid_input("No GCRS data available."))?;
 
         let moon_positions = if let Some(indices) = time_indices {
             moon_positions_all.select(ndarray::Axis(0), indices)
@@ -561,6 +575,21 @@ impl ConstraintEvaluator for MoonPhaseEvaluator {
         Ok(result)
     }
 
+    /// Illuminated fraction of the Moon (0 to 1)
+    fn metric(
+        &self,
+        ephemeris: &dyn crate::ephemeris::ephemeris_common::EphemerisBase,
+        _target_ra: f64,
+        _target_dec: f64,
+        time_indices: Option<&[usize]>,
+    ) -> Result<Option<Vec<f64>>, ConstraintError> {
+        Ok(Some(
+            ephemeris
+                .moon_illumination(time_indices)
+                .or_missing("times")?,
+        ))
+    }
+
     fn name(&self) -> String {
         self.format_name()
     }
@@ -1,9 +1,11 @@
-/// Moon proximity constraint implementation
 use super::core::{track_violations, ConstraintConfig, ConstraintEvaluator, ConstraintResult};
+use super::threshold_schedule::ThresholdPeriod;
+/// Moon proximity constraint implementation
+use crate::constraints::error::{ConstraintError, OrMissing};
+use crate::utils::body_properties::moon_radii;
 use crate::utils::vector_math::radec_to_unit_vectors_batch;
 use chrono::{DateTime, Utc};
 use ndarray::Array2;
-use pyo3::PyResult;
 use serde::{Deserialize, Serialize};
 
 /// Configuration for Moon proximity constraint
@@ -14,13 +16,23 @@ pub struct MoonProximityConfig {
     /// Maximum allowed angular separation from Moon in degrees (optional)
     #[serde(skip_serializing_if = "Option::is_none")]
     pub max_angle: Option<f64>,
+    /// Apply the limits to the separation from the Moon's limb instead of its centre
+    #[serde(default)]
+    pub limb_relative: bool,
+    /// Periods overriding the limits, e.g. a wider angle during anneals
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub schedule: Vec<ThresholdPeriod>,
 }
 
+impl_proximity_builder!(MoonProximityConfig, MoonProximityBuilder);
+
 impl ConstraintConfig for MoonProximityConfig {
     fn to_evaluator(&self) -> Box<dyn ConstraintEvaluator> {
         Box::new(MoonProximityEvaluator {
             min_angle_deg: self.min_angle,
             max_angle_deg: self.max_angle,
+            schedule: self.schedule.clone(),
+            limb_radius_km: self.limb_relative.then(|| moon_radii().equatorial),
         })
     }
 }
@@ -29,6 +41,9 @@ impl ConstraintConfig for MoonProximityConfig {
 struct MoonProximityEvaluator {
     min_angle_deg: f64,
     max_angle_deg: Option<f64>,
+    schedule: Vec<ThresholdPeriod>,
+    /// Moon radius in km when the limits apply to its limb
+    limb_radius_km: Option<f64>,
 }
 
 impl_proximity_evaluator!(MoonProximityEvaluator, "Moon", "Moon", moon_positions);
@@ -54,9 +69,19 @@ impl MoonProximityEvaluator {
     }
 
     fn format_name(&self) -> String {
-        match self.max_angle_deg {
-            Some(max) => format!("MoonProximity(min={}°, max={}°)", self.min_angle_deg, max),
-            None => format!("MoonProximity(min={}°)", self.min_angle_deg),
+        let limits = match self.max_angle_deg {
+            Some(max) => format!("MoonProximity(min={}°, max={}°", self.min_angle_deg, max),
+            None => format!("MoonProximity(min={}°", self.min_angle_deg),
+        };
+        let limits = if self.schedule.is_empty() {
+            limits
+        } else {
+            format!("{}, scheduled", limits)
+        };
+        if self.limb_radius_km.is_some() {
+            format!("{}, limb)", limits)
+        } else {
+            format!("{})", limits)
         }
     }
 }
@@ -68,7 +93,7 @@ impl ConstraintEvaluator for MoonProximityEvaluator {
         target_ra: f64,
         target_dec: f64,
         time_indices: Option<&[usize]>,
-    ) -> PyResult<ConstraintResult> {
+    ) -> Result<ConstraintResult, ConstraintError> {
         // Extract data from ephemeris
         let (times_slice, moon_positions_slice, observer_positions_slice) =
             extract_body_ephemeris_data!(ephemeris, time_indices, get_moon_positions);
@@ -90,28 +115,34 @@ impl ConstraintEvaluator for MoonProximityEvaluator {
         target_ras: &[f64],
         target_decs: &[f64],
         time_indices: Option<&[usize]>,
-    ) -> PyResult<Array2<bool>> {
+    ) -> Result<Array2<bool>, ConstraintError> {
         // Extract data from ephemeris
-        let times = ephemeris.get_times()?;
+        let times = ephemeris.get_times().or_missing("times")?;
         let (moon_positions_slice, observer_positions_slice, n_times) =
             if let Some(indices) = time_indices {
                 let moon_filtered = ephemeris
-                    .get_moon_positions()?
+                    .get_moon_positions()
+                    .or_missing("Moon positions")?
                     .select(ndarray::Axis(0), indices);
                 let obs_filtered = ephemeris
-                    .get_gcrs_positions()?
+                    .get_gcrs_positions()
+                    .or_missing("GCRS positions")?
                     .select(ndarray::Axis(0), indices);
                 (moon_filtered, obs_filtered, indices.len())
             } else {
-                let moon_positions = ephemeris.get_moon_positions()?;
-                let observer_positions = ephemeris.get_gcrs_positions()?;
+                let moon_positions = ephemeris
+                    .get_moon_positions()
+                    .or_missing("Moon positions")?;
+                let observer_positions = ephemeris
+                    .get_gcrs_positions()
+                    .or_missing("GCRS positions")?;
                 // moon_positions and observer_positions are already owned (from .to_owned() in getters)
                 // so no need to clone again
                 (moon_positions, observer_positions, times.len())
             };
         // Validate inputs
         if target_ras.len() != target_decs.len() {
-            return Err(pyo3::exceptions::PyValueError::new_err(
+            return Err(ConstraintError::invalid_input(
                 "target_ras and target_decs must have the same length",
             ));
         }
@@ -124,13 +155,9 @@ impl ConstraintEvaluator for MoonProximityEvaluator {
         // Initialize result array: false = not violated (constraint satisfied)
         let mut result = Array2::from_elem((n_targets, n_times), false);
 
-        // Pre-compute cosine thresholds (avoids acos() in inner loop)
-        // For angle comparison: angle < threshold ⟺ cos(angle) > cos(threshold)
-        let min_cos_threshold = self.min_angle_deg.to_radians().cos();
-        let max_cos_threshold = self.max_angle_deg.map(|max| max.to_radians().cos());
-
         // For each time, check all targets
         for t in 0..n_times {
+            let time = time_indices.map_or(times[t], |indices| times[indices[t]]);
             let moon_pos = [
                 moon_positions_slice[[t, 0]],
                 moon_positions_slice[[t, 1]],
@@ -156,6 +183,7 @@ impl ConstraintEvaluator for MoonProximityEvaluator {
                 moon_rel[1] / moon_dist,
                 moon_rel[2] / moon_dist,
             ];
+            let (min_cos_threshold, max_cos_threshold) = self.cos_thresholds(&time, moon_dist);
 
             // Check all targets at this time
             for target_idx in 0..n_targets {
@@ -187,36 +215,40 @@ impl ConstraintEvaluator for MoonProximityEvaluator {
         ephemeris: &dyn crate::ephemeris::ephemeris_common::EphemerisBase,
         target_unit_vectors: &Array2<f64>,
         time_indices: Option<&[usize]>,
-    ) -> PyResult<Option<Array2<bool>>> {
+    ) -> Result<Option<Array2<bool>>, ConstraintError> {
         if target_unit_vectors.ncols() != 3 {
-            return Err(pyo3::exceptions::PyValueError::new_err(
+            return Err(ConstraintError::invalid_input(
                 "target_unit_vectors must have shape (N, 3)",
             ));
         }
 
-        let times = ephemeris.get_times()?;
+        let times = ephemeris.get_times().or_missing("times")?;
         let (moon_positions_slice, observer_positions_slice, n_times) =
             if let Some(indices) = time_indices {
                 let moon_filtered = ephemeris
-                    .get_moon_positions()?
+                    .get_moon_positions()
+                    .or_missing("Moon positions")?
                     .select(ndarray::Axis(0), indices);
                 let obs_filtered = ephemeris
-                    .get_gcrs_positions()?
+                    .get_gcrs_positions()
+                    .or_missing("GCRS positions")?
                     .select(ndarray::Axis(0), indices);
                 (moon_filtered, obs_filtered, indices.len())
             } else {
-                let moon_positions = ephemeris.get_moon_positions()?;
-                let observer_positions = ephemeris.get_gcrs_positions()?;
+                let moon_positions = ephemeris
+                    .get_moon_positions()
+                    .or_missing("Moon positions")?;
+                let observer_positions = ephemeris
+                    .get_gcrs_positions()
+                    .or_missing("GCRS positions")?;
                 (moon_positions, observer_positions, times.len())
             };
 
         let n_targets = target_unit_vectors.nrows();
         let mut result = Array2::from_elem((n_targets, n_times), false);
 
-        let min_cos_threshold = self.min_angle_deg.to_radians().cos();
-        let max_cos_threshold = self.max_angle_deg.map(|max| max.to_radians().cos());
-
         for t in 0..n_times {
+            let time = time_indices.map_or(times[t], |indices| times[indices[t]]);
             let moon_pos = [
                 moon_positions_slice[[t, 0]],
                 moon_positions_slice[[t, 1]],
@@ -241,6 +273,7 @@ impl ConstraintEvaluator for MoonProximityEvaluator {
                 moon_rel[1] / moon_dist,
                 moon_rel[2] / moon_dist,
             ];
+            let (min_cos_threshold, max_cos_threshold) = self.cos_thresholds(&time, moon_dist);
 
             for target_idx in 0..n_targets {
                 let cos_angle = target_unit_vectors[[target_idx, 0]] * moon_unit[0]
@@ -262,28 +295,28 @@ impl ConstraintEvaluator for MoonProximityEvaluator {
         ephemeris: &dyn crate::ephemeris::ephemeris_common::EphemerisBase,
         target_ras: &[f64],
         target_decs: &[f64],
-    ) -> PyResult<Vec<bool>> {
+    ) -> Result<Vec<bool>, ConstraintError> {
         let n = target_ras.len();
         if n == 0 {
             return Ok(Vec::new());
         }
 
-        let moon_positions = ephemeris.get_moon_positions()?;
-        let observer_positions = ephemeris.get_gcrs_positions()?;
+        let moon_positions = ephemeris
+            .get_moon_positions()
+            .or_missing("Moon positions")?;
+        let observer_positions = ephemeris
+            .get_gcrs_positions()
+            .or_missing("GCRS positions")?;
+        let times = ephemeris.get_times().or_missing("times")?;
 
-        if moon_positions.nrows() < n || observer_positions.nrows() < n {
-            return Err(pyo3::exceptions::PyValueError::new_err(
+        if moon_positions.nrows() < n || observer_positions.nrows() < n || times.len() < n {
+            return Err(ConstraintError::invalid_input(
                 "Not enough ephemeris time steps for diagonal evaluation",
             ));
         }
 
         let target_vectors = radec_to_unit_vectors_batch(target_ras, target_decs);
 
-        // Pre-compute cosine thresholds (avoids acos() in inner loop)
-        // For angle comparison: angle < threshold ⟺ cos(angle) > cos(threshold)
-        let min_cos_threshold = self.min_angle_deg.to_radians().cos();
-        let max_cos_threshold = self.max_angle_deg.map(|max| max.to_radians().cos());
-
         let mut result = Vec::with_capacity(n);
 
         for i in 0..n {
@@ -311,6 +344,7 @@ impl ConstraintEvaluator for MoonProximityEvaluator {
                 moon_rel[1] / moon_dist,
                 moon_rel[2] / moon_dist,
             ];
+            let (min_cos_threshold, max_cos_threshold) = self.cos_thresholds(&times[i], moon_dist);
 
             let target_vec = [
                 target_vectors[[i, 0]],
@@ -334,6 +368,23 @@ impl ConstraintEvaluator for MoonProximityEvaluator {
         Ok(result)
     }
 
+    /// Separation from the Moon in degrees
+    fn metric(
+        &self,
+        ephemeris: &dyn crate::ephemeris::ephemeris_common::EphemerisBase,
+        target_ra: f64,
+        target_dec: f64,
+        time_indices: Option<&[usize]>,
+    ) -> Result<Option<Vec<f64>>, ConstraintError> {
+        let (_, moon_filtered, obs_filtered) =
+            extract_body_ephemeris_data!(ephemeris, time_indices, get_moon_positions);
+        Ok(Some(self.separation_deg(
+            (target_ra, target_dec),
+            &moon_filtered,
+            &obs_filtered,
+        )))
+    }
+
     fn name(&self) -> String {
         self.format_name()
     }
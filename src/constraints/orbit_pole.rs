@@ -1,8 +1,9 @@
-/// Orbit pole direction constraint implementation
 use super::core::{track_violations, ConstraintConfig, ConstraintEvaluator, ConstraintResult};
+/// Orbit pole direction constraint implementation
+use crate::constraints::error::ConstraintError;
+use crate::utils::body_properties::earth_radii;
 use crate::utils::vector_math::radec_to_unit_vectors_batch;
 use ndarray::Array2;
-use pyo3::PyResult;
 use serde::{Deserialize, Serialize};
 
 /// Configuration for Orbit Pole constraint
@@ -26,6 +27,7 @@ impl ConstraintConfig for OrbitPoleConfig {
             min_angle_deg: self.min_angle,
             max_angle_deg: self.max_angle,
             earth_limb_pole: self.earth_limb_pole,
+            earth_radius: earth_radii().equatorial,
         })
     }
 }
@@ -35,6 +37,8 @@ struct OrbitPoleEvaluator {
     min_angle_deg: f64,
     max_angle_deg: Option<f64>,
     earth_limb_pole: bool,
+    /// Earth equatorial radius in km, for the limb-pole margin
+    earth_radius: f64,
 }
 
 impl OrbitPoleEvaluator {
@@ -89,7 +93,7 @@ impl ConstraintEvaluator for OrbitPoleEvaluator {
         target_ra: f64,
         target_dec: f64,
         time_indices: Option<&[usize]>,
-    ) -> PyResult<ConstraintResult> {
+    ) -> Result<ConstraintResult, ConstraintError> {
         // Get filtered times
         let times = ephemeris.get_times().expect("Ephemeris must have times");
         let times_filtered = if let Some(indices) = time_indices {
@@ -136,12 +140,12 @@ impl ConstraintEvaluator for OrbitPoleEvaluator {
                 // Calculate effective minimum angle
                 let effective_min_angle = if self.earth_limb_pole {
                     // Calculate Earth angular radius at this time
-                    // Angular radius = arcsin(EARTH_RADIUS_KM / distance_from_earth_center)
+                    // Angular radius = arcsin(earth_radius / distance_from_earth_center)
                     let distance = (position[0] * position[0]
                         + position[1] * position[1]
                         + position[2] * position[2])
                         .sqrt();
-                    let ratio = (6378.137 / distance).min(1.0); // EARTH_RADIUS_KM = 6378.137
+                    let ratio = (self.earth_radius / distance).min(1.0);
                     let earth_radius_deg = ratio.asin().to_degrees();
                     earth_radius_deg + self.min_angle_deg - 90.0
                 } else {
@@ -206,7 +210,7 @@ impl ConstraintEvaluator for OrbitPoleEvaluator {
                         + position[1] * position[1]
                         + position[2] * position[2])
                         .sqrt();
-                    let ratio = (6378.137 / distance).min(1.0);
+                    let ratio = (self.earth_radius / distance).min(1.0);
                     let earth_radius_deg = ratio.asin().to_degrees();
                     earth_radius_deg + self.min_angle_deg - 90.0
                 } else {
@@ -241,7 +245,7 @@ impl ConstraintEvaluator for OrbitPoleEvaluator {
         target_ras: &[f64],
         target_decs: &[f64],
         time_indices: Option<&[usize]>,
-    ) -> PyResult<Array2<bool>> {
+    ) -> Result<Array2<bool>, ConstraintError> {
         // Extract and filter time data
         let (times_filtered,) = extract_time_data!(ephemeris, time_indices);
 
@@ -254,11 +258,11 @@ impl ConstraintEvaluator for OrbitPoleEvaluator {
 
         // Get position and velocity data
         let gcrs_data = ephemeris.data().gcrs.as_ref().ok_or_else(|| {
-            pyo3::exceptions::PyValueError::new_err("GCRS data not available in ephemeris")
+            ConstraintError::invalid_input("GCRS data not available in ephemeris")
         })?;
 
         if gcrs_data.ncols() < 6 {
-            return Err(pyo3::exceptions::PyValueError::new_err(
+            return Err(ConstraintError::invalid_input(
                 "Velocity data not available in ephemeris - orbit pole constraint requires position and velocity data"
             ));
         }
@@ -317,7 +321,7 @@ impl ConstraintEvaluator for OrbitPoleEvaluator {
                     + position[1] * position[1]
                     + position[2] * position[2])
                     .sqrt();
-                let ratio = (6378.137 / distance).min(1.0);
+                let ratio = (self.earth_radius / distance).min(1.0);
                 let earth_radius_deg = ratio.asin().to_degrees();
                 earth_radius_deg + self.min_angle_deg - 90.0
             } else {
@@ -390,11 +394,11 @@ impl ConstraintEvaluator for OrbitPoleEvaluator {
         ephemeris: &dyn crate::ephemeris::ephemeris_common::EphemerisBase,
         target_unit_vectors: &Array2<f64>,
         time_indices: Option<&[usize]>,
-    ) -> PyResult<Option<Array2<bool>>> {
+    ) -> Result<Option<Array2<bool>>, ConstraintError> {
         let (times_filtered,) = extract_time_data!(ephemeris, time_indices);
 
         if target_unit_vectors.ncols() != 3 {
-            return Err(pyo3::exceptions::PyValueError::new_err(
+            return Err(ConstraintError::invalid_input(
                 "target_unit_vectors must have shape (N, 3)",
             ));
         }
@@ -404,11 +408,11 @@ impl ConstraintEvaluator for OrbitPoleEvaluator {
         let mut result = Array2::<bool>::from_elem((n_targets, n_times), false);
 
         let gcrs_data = ephemeris.data().gcrs.as_ref().ok_or_else(|| {
-            pyo3::exceptions::PyValueError::new_err("GCRS data not available in ephemeris")
+            ConstraintError::invalid_input("GCRS data not available in ephemeris")
         })?;
 
         if gcrs_data.ncols() < 6 {
-            return Err(pyo3::exceptions::PyValueError::new_err(
+            return Err(ConstraintError::invalid_input(
                 "Velocity data not available in ephemeris - orbit pole constraint requires position and velocity data"
             ));
         }
@@ -461,7 +465,7 @@ impl ConstraintEvaluator for OrbitPoleEvaluator {
                     + position[1] * position[1]
                     + position[2] * position[2])
                     .sqrt();
-                let ratio = (6378.137 / distance).min(1.0);
+                let ratio = (self.earth_radius / distance).min(1.0);
                 let earth_radius_deg = ratio.asin().to_degrees();
                 earth_radius_deg + self.min_angle_deg - 90.0
             } else {
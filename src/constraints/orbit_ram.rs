@@ -1,8 +1,8 @@
-/// Orbit RAM direction constraint implementation
 use super::core::{track_violations, ConstraintConfig, ConstraintEvaluator, ConstraintResult};
+/// Orbit RAM direction constraint implementation
+use crate::constraints::error::ConstraintError;
 use crate::utils::vector_math::radec_to_unit_vectors_batch;
 use ndarray::Array2;
-use pyo3::PyResult;
 use serde::{Deserialize, Serialize};
 
 /// Configuration for Orbit RAM constraint
@@ -49,7 +49,7 @@ impl ConstraintEvaluator for OrbitRamEvaluator {
         target_ra: f64,
         target_dec: f64,
         time_indices: Option<&[usize]>,
-    ) -> PyResult<ConstraintResult> {
+    ) -> Result<ConstraintResult, ConstraintError> {
         // Get filtered times
         let times = ephemeris.get_times().expect("Ephemeris must have times");
         let times_filtered = if let Some(indices) = time_indices {
@@ -153,7 +153,7 @@ impl ConstraintEvaluator for OrbitRamEvaluator {
         target_ras: &[f64],
         target_decs: &[f64],
         time_indices: Option<&[usize]>,
-    ) -> PyResult<Array2<bool>> {
+    ) -> Result<Array2<bool>, ConstraintError> {
         // Extract and filter time data
         let (times_filtered,) = extract_time_data!(ephemeris, time_indices);
 
@@ -166,11 +166,11 @@ impl ConstraintEvaluator for OrbitRamEvaluator {
 
         // Get velocity data
         let gcrs_data = ephemeris.data().gcrs.as_ref().ok_or_else(|| {
-            pyo3::exceptions::PyValueError::new_err("GCRS data not available in ephemeris")
+            ConstraintError::invalid_input("GCRS data not available in ephemeris")
         })?;
 
         if gcrs_data.ncols() < 6 {
-            return Err(pyo3::exceptions::PyValueError::new_err(
+            return Err(ConstraintError::invalid_input(
                 "Velocity data not available in ephemeris - orbit RAM constraint requires position and velocity data"
             ));
         }
@@ -243,11 +243,11 @@ impl ConstraintEvaluator for OrbitRamEvaluator {
         ephemeris: &dyn crate::ephemeris::ephemeris_common::EphemerisBase,
         target_unit_vectors: &Array2<f64>,
         time_indices: Option<&[usize]>,
-    ) -> PyResult<Option<Array2<bool>>> {
+    ) -> Result<Option<Array2<bool>>, ConstraintError> {
         let (times_filtered,) = extract_time_data!(ephemeris, time_indices);
 
         if target_unit_vectors.ncols() != 3 {
-            return Err(pyo3::exceptions::PyValueError::new_err(
+            return Err(ConstraintError::invalid_input(
                 "target_unit_vectors must have shape (N, 3)",
             ));
         }
@@ -257,11 +257,11 @@ impl ConstraintEvaluator for OrbitRamEvaluator {
         let mut result = Array2::<bool>::from_elem((n_targets, n_times), false);
 
         let gcrs_data = ephemeris.data().gcrs.as_ref().ok_or_else(|| {
-            pyo3::exceptions::PyValueError::new_err("GCRS data not available in ephemeris")
+            ConstraintError::invalid_input("GCRS data not available in ephemeris")
         })?;
 
         if gcrs_data.ncols() < 6 {
-            return Err(pyo3::exceptions::PyValueError::new_err(
+            return Err(ConstraintError::invalid_input(
                 "Velocity data not available in ephemeris - orbit RAM constraint requires position and velocity data"
             ));
         }
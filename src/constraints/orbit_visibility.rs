@@ -0,0 +1,453 @@
+//! Per-orbit visibility efficiency
+//!
+//! Splits an ephemeris into orbits at the ascending node and reports, for
+//! one target under a constraint, the visible fraction of each orbit and
+//! where in the orbit the target is visible. The orbital position is given
+//! both from the ascending node (argument of latitude) and relative to the
+//! Sun (solar phase), so a planner can tell a target visible all orbit from
+//! one only visible near orbit dawn.
+//!
+//! Solar phase is measured in the orbit plane in the direction of motion
+//! from orbit noon, the point nearest the Sun: 90° is the dusk side, 180°
+//! orbit midnight and 270° the dawn side. Orbit boundaries are at sample
+//! times, so their resolution is the ephemeris step size.
+
+use chrono::{DateTime, Utc};
+use ndarray::{Array1, Array2};
+use numpy::IntoPyArray;
+use pyo3::prelude::*;
+use pyo3::types::PyDict;
+
+use crate::constraints::PyConstraint;
+use crate::ephemeris::ground_network::{runs, seconds_between, timestamp_array};
+use crate::ephemeris::with_ephemeris;
+use crate::utils::vector_math::{cross_product, dot_product, normalize_vector};
+
+/// Angle in degrees [0, 360) of `r` from `reference` about `normal`
+fn angle_about(reference: &[f64; 3], r: &[f64; 3], normal: &[f64; 3]) -> f64 {
+    let r = normalize_vector(r);
+    let sin = dot_product(&cross_product(reference, &r), normal);
+    let cos = dot_product(reference, &r);
+    sin.atan2(cos).to_degrees().rem_euclid(360.0)
+}
+
+/// Argument of latitude and solar phase (degrees) of each GCRS state
+///
+/// `states` is N x 6 (km, km/s) and `sun` N x 3 (km). For an equatorial
+/// orbit, where the node is undefined, the argument of latitude is measured
+/// from the GCRS x axis.
+pub(crate) fn orbit_angles(states: &Array2<f64>, sun: &Array2<f64>) -> (Vec<f64>, Vec<f64>) {
+    (0..states.nrows())
+        .map(|i| {
+            let r = [states[[i, 0]], states[[i, 1]], states[[i, 2]]];
+            let v = [states[[i, 3]], states[[i, 4]], states[[i, 5]]];
+            let normal = normalize_vector(&cross_product(&r, &v));
+
+            let node = cross_product(&[0.0, 0.0, 1.0], &normal);
+            let node = if dot_product(&node, &node) < 1e-12 {
+                [1.0, 0.0, 0.0]
+            } else {
+                normalize_vector(&node)
+            };
+
+            let s = [sun[[i, 0]], sun[[i, 1]], sun[[i, 2]]];
+            let out_of_plane = dot_product(&s, &normal);
+            let noon = normalize_vector(&[
+                s[0] - out_of_plane * normal[0],
+                s[1] - out_of_plane * normal[1],
+                s[2] - out_of_plane * normal[2],
+            ]);
+
+            (
+                angle_about(&node, &r, &normal),
+                angle_about(&noon, &r, &normal),
+            )
+        })
+        .unzip()
+}
+
+/// Inclusive sample ranges of orbits, split where the argument of latitude wraps
+fn split_orbits(argument_of_latitude: &[f64]) -> Vec<(usize, usize)> {
+    if argument_of_latitude.is_empty() {
+        return Vec::new();
+    }
+    let mut orbits = Vec::new();
+    let mut start = 0;
+    for i in 1..argument_of_latitude.len() {
+        if argument_of_latitude[i - 1] - argument_of_latitude[i] > 180.0 {
+            orbits.push((start, i - 1));
+            start = i;
+        }
+    }
+    orbits.push((start, argument_of_latitude.len() - 1));
+    orbits
+}
+
+/// Circular mean (degrees, [0, 360)) and mean resultant length of angles
+///
+/// The resultant length is 1 when all angles are equal and near 0 when they
+/// are spread evenly; the mean is NaN without angles.
+fn circular_mean(angles: impl Iterator<Item = f64>) -> (f64, f64) {
+    let (mut sin, mut cos, mut n) = (0.0, 0.0, 0usize);
+    for angle in angles {
+        sin += angle.to_radians().sin();
+        cos += angle.to_radians().cos();
+        n += 1;
+    }
+    if n == 0 {
+        return (f64::NAN, 0.0);
+    }
+    let mean = sin.atan2(cos).to_degrees().rem_euclid(360.0);
+    (mean, (sin * sin + cos * cos).sqrt() / n as f64)
+}
+
+/// Per-orbit visibility of one target under a constraint
+///
+/// Per-orbit columns are available as attributes or as a dict of arrays
+/// with `to_dict()`. The first and last orbits are usually partial; see
+/// `complete`.
+#[pyclass]
+pub struct OrbitVisibility {
+    times: Vec<DateTime<Utc>>,
+    visible: Vec<bool>,
+    argument_of_latitude: Vec<f64>,
+    solar_phase: Vec<f64>,
+    orbits: Vec<(usize, usize)>,
+}
+
+impl OrbitVisibility {
+    fn new(
+        times: Vec<DateTime<Utc>>,
+        visible: Vec<bool>,
+        argument_of_latitude: Vec<f64>,
+        solar_phase: Vec<f64>,
+    ) -> Self {
+        let orbits = split_orbits(&argument_of_latitude);
+        OrbitVisibility {
+            times,
+            visible,
+            argument_of_latitude,
+            solar_phase,
+            orbits,
+        }
+    }
+
+    /// Whether an orbit runs from one ascending node to the next
+    fn is_complete(&self, index: usize) -> bool {
+        index > 0 && index + 1 < self.orbits.len()
+    }
+
+    fn orbit_visible_fraction(&self, (start, end): (usize, usize)) -> f64 {
+        let visible = self.visible[start..=end].iter().filter(|&&v| v).count();
+        visible as f64 / (end - start + 1) as f64
+    }
+
+    fn orbit_visible_seconds(&self, (start, end): (usize, usize)) -> f64 {
+        runs(&self.visible[start..=end])
+            .iter()
+            .map(|&(a, b)| seconds_between(&self.times, (start + a, start + b)))
+            .sum()
+    }
+
+    fn orbit_phase(&self, (start, end): (usize, usize), angles: &[f64]) -> (f64, f64) {
+        circular_mean(
+            (start..=end)
+                .filter(|&i| self.visible[i])
+                .map(|i| angles[i]),
+        )
+    }
+
+    fn column(&self, py: Python, value: impl Fn((usize, usize)) -> f64) -> Py<PyAny> {
+        Array1::from_iter(self.orbits.iter().map(|&orbit| value(orbit)))
+            .into_pyarray(py)
+            .into()
+    }
+
+    /// Per-orbit columns in order
+    fn columns(&self, py: Python) -> PyResult<Vec<(&'static str, Py<PyAny>)>> {
+        Ok(vec![
+            ("orbit_start", self.orbit_start(py)?),
+            ("orbit_end", self.orbit_end(py)?),
+            (
+                "complete",
+                pyo3::types::PyList::new(py, self.complete())?
+                    .into_any()
+                    .unbind(),
+            ),
+            ("visible_fraction", self.visible_fraction(py)),
+            ("visible_seconds", self.visible_seconds(py)),
+            ("mean_solar_phase", self.mean_solar_phase(py)),
+            (
+                "mean_argument_of_latitude",
+                self.mean_argument_of_latitude(py),
+            ),
+        ])
+    }
+}
+
+#[pymethods]
+impl OrbitVisibility {
+    /// Number of orbits, including partial first and last orbits
+    #[getter]
+    fn n_orbits(&self) -> usize {
+        self.orbits.len()
+    }
+
+    /// Start time of each orbit (the first sample after the ascending node)
+    #[getter]
+    fn orbit_start(&self, py: Python) -> PyResult<Py<PyAny>> {
+        let starts: Vec<DateTime<Utc>> = self.orbits.iter().map(|o| self.times[o.0]).collect();
+        timestamp_array(py, &starts)
+    }
+
+    /// End time of each orbit (the last sample before the next ascending node)
+    #[getter]
+    fn orbit_end(&self, py: Python) -> PyResult<Py<PyAny>> {
+        let ends: Vec<DateTime<Utc>> = self.orbits.iter().map(|o| self.times[o.1]).collect();
+        timestamp_array(py, &ends)
+    }
+
+    /// Whether each orbit runs from one ascending node to the next
+    #[getter]
+    fn complete(&self) -> Vec<bool> {
+        (0..self.orbits.len())
+            .map(|i| self.is_complete(i))
+            .collect()
+    }
+
+    /// Fraction of each orbit's samples at which the target is visible
+    #[getter]
+    fn visible_fraction(&self, py: Python) -> Py<PyAny> {
+        self.column(py, |orbit| self.orbit_visible_fraction(orbit))
+    }
+
+    /// Visible time in each orbit in seconds
+    #[getter]
+    fn visible_seconds(&self, py: Python) -> Py<PyAny> {
+        self.column(py, |orbit| self.orbit_visible_seconds(orbit))
+    }
+
+    /// Circular mean solar phase of the visible samples of each orbit, degrees
+    ///
+    /// NaN for orbits without visibility.
+    #[getter]
+    fn mean_solar_phase(&self, py: Python) -> Py<PyAny> {
+        self.column(py, |orbit| self.orbit_phase(orbit, &self.solar_phase).0)
+    }
+
+    /// Circular mean argument of latitude of the visible samples of each orbit, degrees
+    ///
+    /// NaN for orbits without visibility.
+    #[getter]
+    fn mean_argument_of_latitude(&self, py: Python) -> Py<PyAny> {
+        self.column(py, |orbit| {
+            self.orbit_phase(orbit, &self.argument_of_latitude).0
+        })
+    }
+
+    /// Mean visible fraction over complete orbits, or over all orbits if none is complete
+    #[getter]
+    fn mean_visible_fraction(&self) -> f64 {
+        let complete: Vec<usize> = (0..self.orbits.len())
+            .filter(|&i| self.is_complete(i))
+            .collect();
+        let selected: Vec<usize> = if complete.is_empty() {
+            (0..self.orbits.len()).collect()
+        } else {
+            complete
+        };
+        if selected.is_empty() {
+            return 0.0;
+        }
+        selected
+            .iter()
+            .map(|&i| self.orbit_visible_fraction(self.orbits[i]))
+            .sum::<f64>()
+            / selected.len() as f64
+    }
+
+    /// How tightly visibility clusters in solar phase over all orbits (0 to 1)
+    ///
+    /// The mean resultant length of the solar phase of all visible samples:
+    /// near 1 when the target is only ever visible at the same point of the
+    /// orbit (e.g. always near orbit dawn), near 0 when visibility is spread
+    /// around the orbit. 0 if the target is never visible.
+    #[getter]
+    fn solar_phase_concentration(&self) -> f64 {
+        circular_mean(
+            self.solar_phase
+                .iter()
+                .zip(&self.visible)
+                .filter(|(_, &v)| v)
+                .map(|(&p, _)| p),
+        )
+        .1
+    }
+
+    /// Solar phase of the observer at each ephemeris time, degrees
+    #[getter]
+    fn solar_phase(&self, py: Python) -> Py<PyAny> {
+        Array1::from_vec(self.solar_phase.clone())
+            .into_pyarray(py)
+            .into()
+    }
+
+    /// Argument of latitude of the observer at each ephemeris time, degrees
+    #[getter]
+    fn argument_of_latitude(&self, py: Python) -> Py<PyAny> {
+        Array1::from_vec(self.argument_of_latitude.clone())
+            .into_pyarray(py)
+            .into()
+    }
+
+    /// Whether the target is visible at each ephemeris time
+    #[getter]
+    fn visible(&self) -> Vec<bool> {
+        self.visible.clone()
+    }
+
+    /// Visible samples counted by solar phase bin
+    ///
+    /// Returns `(counts, edges)` with `bins` equal bins over [0, 360) degrees.
+    #[pyo3(signature = (bins=12))]
+    fn solar_phase_histogram(&self, py: Python, bins: usize) -> PyResult<(Py<PyAny>, Py<PyAny>)> {
+        if bins == 0 {
+            return Err(pyo3::exceptions::PyValueError::new_err(
+                "bins must be positive",
+            ));
+        }
+        let width = 360.0 / bins as f64;
+        let mut counts = vec![0i64; bins];
+        for (&phase, _) in self
+            .solar_phase
+            .iter()
+            .zip(&self.visible)
+            .filter(|(_, &v)| v)
+        {
+            counts[((phase / width) as usize).min(bins - 1)] += 1;
+        }
+        let edges = Array1::from_iter((0..=bins).map(|i| i as f64 * width));
+        Ok((
+            Array1::from_vec(counts).into_pyarray(py).into(),
+            edges.into_pyarray(py).into(),
+        ))
+    }
+
+    /// Per-orbit columns as a dict of arrays
+    fn to_dict<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyDict>> {
+        let dict = PyDict::new(py);
+        for (name, column) in self.columns(py)? {
+            dict.set_item(name, column)?;
+        }
+        Ok(dict)
+    }
+
+    fn __len__(&self) -> usize {
+        self.orbits.len()
+    }
+
+    fn __repr__(&self) -> String {
+        format!(
+            "OrbitVisibility(n_orbits={}, mean_visible_fraction={:.3}, solar_phase_concentration={:.3})",
+            self.orbits.len(),
+            self.mean_visible_fraction(),
+            self.solar_phase_concentration()
+        )
+    }
+}
+
+/// Per-orbit visibility of a target under `constraint`
+pub fn orbit_visibility(
+    ephemeris: &Bound<'_, PyAny>,
+    target: (f64, f64),
+    constraint: &PyConstraint,
+) -> PyResult<OrbitVisibility> {
+    with_ephemeris(ephemeris, |ephem| {
+        if ephem.is_ground_site() {
+            return Err(pyo3::exceptions::PyValueError::new_err(
+                "orbit_visibility requires an orbiting observer",
+            ));
+        }
+        let times = ephem.get_times()?;
+        let states = ephem.data().gcrs.as_ref().ok_or_else(|| {
+            pyo3::exceptions::PyValueError::new_err("No GCRS positions available")
+        })?;
+        let sun = ephem.get_sun_positions()?;
+        let (argument_of_latitude, solar_phase) = orbit_angles(states, &sun);
+        let mask = constraint.violation_mask(ephem, &[target.0], &[target.1])?;
+        let visible = mask.row(0).iter().map(|&v| !v).collect();
+        Ok(OrbitVisibility::new(
+            times,
+            visible,
+            argument_of_latitude,
+            solar_phase,
+        ))
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::{Duration, TimeZone};
+
+    /// Circular equatorial orbit of radius 7000 km with 100 samples per orbit,
+    /// offset half a step from the node, with the Sun along +x
+    fn circular(n: usize) -> (Array2<f64>, Array2<f64>) {
+        let states = Array2::from_shape_fn((n, 6), |(i, j)| {
+            let u = (i as f64 * 3.6 + 1.8).to_radians();
+            match j {
+                0 => 7000.0 * u.cos(),
+                1 => 7000.0 * u.sin(),
+                3 => -7.5 * u.sin(),
+                4 => 7.5 * u.cos(),
+                _ => 0.0,
+            }
+        });
+        let sun = Array2::from_shape_fn((n, 3), |(_, j)| if j == 0 { 1.5e8 } else { 0.0 });
+        (states, sun)
+    }
+
+    #[test]
+    fn test_orbit_angles() {
+        let (states, sun) = circular(100);
+        let (u, phase) = orbit_angles(&states, &sun);
+        assert!((u[25] - 91.8).abs() < 1e-9);
+        // Sun along +x and the node on +x: noon coincides with the node
+        assert!((phase[75] - 271.8).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_split_orbits() {
+        let (states, sun) = circular(250);
+        let (u, _) = orbit_angles(&states, &sun);
+        assert_eq!(split_orbits(&u), vec![(0, 99), (100, 199), (200, 249)]);
+        assert!(split_orbits(&[]).is_empty());
+    }
+
+    #[test]
+    fn test_circular_mean() {
+        let (mean, r) = circular_mean([350.0, 10.0].into_iter());
+        assert!(mean.abs() < 1e-9 || (mean - 360.0).abs() < 1e-9);
+        assert!((r - 10f64.to_radians().cos()).abs() < 1e-12);
+        let (mean, r) = circular_mean(std::iter::empty());
+        assert!(mean.is_nan());
+        assert_eq!(r, 0.0);
+    }
+
+    #[test]
+    fn test_dawn_only_visibility() {
+        let (states, sun) = circular(300);
+        let (u, phase) = orbit_angles(&states, &sun);
+        // Visible only between 240° and 300° of solar phase
+        let visible: Vec<bool> = phase.iter().map(|&p| (240.0..300.0).contains(&p)).collect();
+        let start = Utc.with_ymd_and_hms(2025, 1, 1, 0, 0, 0).unwrap();
+        let times = (0..300).map(|i| start + Duration::minutes(i)).collect();
+        let report = OrbitVisibility::new(times, visible, u, phase);
+
+        assert_eq!(report.n_orbits(), 3);
+        assert!((report.orbit_visible_fraction(report.orbits[1]) - 0.16).abs() < 1e-9);
+        let (mean, _) = report.orbit_phase(report.orbits[1], &report.solar_phase);
+        assert!((mean - 270.0).abs() < 1e-6);
+        assert!(report.solar_phase_concentration() > 0.9);
+    }
+}
@@ -0,0 +1,343 @@
+use super::core::{track_violations, ConstraintConfig, ConstraintEvaluator, ConstraintResult};
+/// Avoidance constraint for several solar system bodies at once
+use crate::constraints::error::{ConstraintError, OrMissing};
+use crate::utils::celestial::{calculate_bodies_by_id_or_name, BodySource};
+use crate::utils::config::EARTH_NAIF_ID;
+use crate::utils::vector_math::{radec_to_unit_vector, radec_to_unit_vectors_batch};
+use chrono::{DateTime, Utc};
+use ndarray::{Array2, Axis};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+
+/// Planets avoided when none are given
+pub const DEFAULT_PLANETS: [&str; 4] = ["Venus", "Mars", "Jupiter", "Saturn"];
+
+pub fn default_planets() -> Vec<String> {
+    DEFAULT_PLANETS.iter().map(|p| p.to_string()).collect()
+}
+
+/// Configuration for the planets proximity constraint
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PlanetsProximityConfig {
+    /// Minimum allowed angular separation in degrees, shared by all planets
+    pub min_angle: f64,
+    /// Body identifiers (names or NAIF IDs) to avoid
+    #[serde(default = "default_planets")]
+    pub planets: Vec<String>,
+    /// Per-planet minimum separations in degrees, overriding `min_angle`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub min_angles: Option<BTreeMap<String, f64>>,
+    /// Body position source: "spice" (default), "horizons" or "auto"
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub source: Option<String>,
+}
+
+impl PlanetsProximityConfig {
+    /// Check angles, planet list and source
+    pub fn validate(&self) -> Result<(), String> {
+        if self.planets.is_empty() {
+            return Err("planets must contain at least one body".to_string());
+        }
+        if !(0.0..=180.0).contains(&self.min_angle) {
+            return Err("min_angle must be between 0 and 180 degrees".to_string());
+        }
+        for (planet, angle) in self.min_angles.iter().flatten() {
+            if !self.planets.contains(planet) {
+                return Err(format!(
+                    "min_angles has an entry for '{}', which is not in planets",
+                    planet
+                ));
+            }
+            if !(0.0..=180.0).contains(angle) {
+                return Err(format!(
+                    "min_angles['{}'] must be between 0 and 180 degrees",
+                    planet
+                ));
+            }
+        }
+        if let Some(source) = &self.source {
+            BodySource::parse(source)?;
+        }
+        Ok(())
+    }
+
+    /// Minimum separation in degrees for each planet, in `planets` order
+    fn limits(&self) -> Vec<(String, f64)> {
+        self.planets
+            .iter()
+            .map(|planet| {
+                let limit = self
+                    .min_angles
+                    .as_ref()
+                    .and_then(|angles| angles.get(planet))
+                    .copied()
+                    .unwrap_or(self.min_angle);
+                (planet.clone(), limit)
+            })
+            .collect()
+    }
+}
+
+impl ConstraintConfig for PlanetsProximityConfig {
+    fn to_evaluator(&self) -> Box<dyn ConstraintEvaluator> {
+        // The source is validated when the constraint is built
+        let source = self
+            .source
+            .as_deref()
+            .and_then(|s| BodySource::parse(s).ok())
+            .unwrap_or(BodySource::Spice);
+        Box::new(PlanetsProximityEvaluator {
+            limits: self.limits(),
+            source,
+        })
+    }
+}
+
+/// Evaluator for the planets proximity constraint
+///
+/// Each planet's position is looked up once per evaluation on the selected
+/// time grid and shared by all targets.
+struct PlanetsProximityEvaluator {
+    /// (planet, minimum separation in degrees)
+    limits: Vec<(String, f64)>,
+    source: BodySource,
+}
+
+impl PlanetsProximityEvaluator {
+    fn format_name(&self) -> String {
+        let shared = self.limits.first().map(|l| l.1);
+        let names: Vec<&str> = self.limits.iter().map(|l| l.0.as_str()).collect();
+        match shared {
+            Some(min) if self.limits.iter().all(|l| l.1 == min) => format!(
+                "PlanetsProximity(planets=[{}], min={:.1}°)",
+                names.join(", "),
+                min
+            ),
+            _ => {
+                let limits: Vec<String> = self
+                    .limits
+                    .iter()
+                    .map(|(planet, min)| format!("{}: {:.1}°", planet, min))
+                    .collect();
+                format!("PlanetsProximity({})", limits.join(", "))
+            }
+        }
+    }
+
+    /// Selected times and observer-relative unit vectors of each planet, shape (n_times, 3)
+    #[allow(clippy::type_complexity)]
+    fn planet_directions(
+        &self,
+        ephemeris: &dyn crate::ephemeris::ephemeris_common::EphemerisBase,
+        time_indices: Option<&[usize]>,
+    ) -> Result<(Vec<DateTime<Utc>>, Vec<Array2<f64>>), ConstraintError> {
+        let times = ephemeris.get_times().or_missing("times")?;
+        let observer = ephemeris
+            .get_gcrs_positions()
+            .or_missing("GCRS positions")?;
+        let (times, observer) = match time_indices {
+            Some(indices) => (
+                indices.iter().map(|&i| times[i]).collect(),
+                observer.select(Axis(0), indices),
+            ),
+            None => (times, observer),
+        };
+
+        let planets: Vec<&str> = self.limits.iter().map(|(p, _)| p.as_str()).collect();
+        let bodies =
+            calculate_bodies_by_id_or_name(&times, &planets, EARTH_NAIF_ID, None, self.source)
+                .map_err(ConstraintError::InvalidInput)?;
+
+        let mut directions = Vec::with_capacity(self.limits.len());
+        for (body, _) in bodies {
+            let mut unit = Array2::<f64>::zeros((times.len(), 3));
+            for j in 0..times.len() {
+                let rel = [
+                    body[[j, 0]] - observer[[j, 0]],
+                    body[[j, 1]] - observer[[j, 1]],
+                    body[[j, 2]] - observer[[j, 2]],
+                ];
+                let dist = (rel[0] * rel[0] + rel[1] * rel[1] + rel[2] * rel[2]).sqrt();
+                if dist > 0.0 {
+                    for (k, r) in rel.iter().enumerate() {
+                        unit[[j, k]] = r / dist;
+                    }
+                }
+            }
+            directions.push(unit);
+        }
+        Ok((times, directions))
+    }
+
+    /// Planet index and separation (degrees) of the deepest violation at time `j`
+    fn closest_violation(
+        &self,
+        directions: &[Array2<f64>],
+        target: [f64; 3],
+        j: usize,
+    ) -> Option<(usize, f64)> {
+        let mut worst: Option<(usize, f64, f64)> = None;
+        for (k, (_, min_angle)) in self.limits.iter().enumerate() {
+            let dir = &directions[k];
+            let cos_angle =
+                target[0] * dir[[j, 0]] + target[1] * dir[[j, 1]] + target[2] * dir[[j, 2]];
+            let separation = cos_angle.clamp(-1.0, 1.0).acos().to_degrees();
+            let depth = min_angle - separation;
+            if depth > 0.0 && worst.is_none_or(|w| depth > w.2) {
+                worst = Some((k, separation, depth));
+            }
+        }
+        worst.map(|(k, separation, _)| (k, separation))
+    }
+
+    /// Violation grid (n_targets, n_times) for target unit vectors of shape (n_targets, 3)
+    fn violation_grid(
+        &self,
+        directions: &[Array2<f64>],
+        targets: &Array2<f64>,
+        n_times: usize,
+    ) -> Array2<bool> {
+        let thresholds: Vec<f64> = self.limits.iter().map(|l| l.1.to_radians().cos()).collect();
+        Array2::from_shape_fn((targets.nrows(), n_times), |(i, j)| {
+            directions.iter().zip(&thresholds).any(|(dir, &threshold)| {
+                let cos_angle = targets[[i, 0]] * dir[[j, 0]]
+                    + targets[[i, 1]] * dir[[j, 1]]
+                    + targets[[i, 2]] * dir[[j, 2]];
+                cos_angle > threshold
+            })
+        })
+    }
+}
+
+impl ConstraintEvaluator for PlanetsProximityEvaluator {
+    fn evaluate(
+        &self,
+        ephemeris: &dyn crate::ephemeris::ephemeris_common::EphemerisBase,
+        target_ra: f64,
+        target_dec: f64,
+        time_indices: Option<&[usize]>,
+    ) -> Result<ConstraintResult, ConstraintError> {
+        let (times, directions) = self.planet_directions(ephemeris, time_indices)?;
+        let target = radec_to_unit_vector(target_ra, target_dec);
+
+        let violations = track_violations(
+            &times,
+            |j| match self.closest_violation(&directions, target, j) {
+                Some((k, separation)) => (true, self.limits[k].1 - separation),
+                None => (false, 0.0),
+            },
+            |j, _| match self.closest_violation(&directions, target, j) {
+                Some((k, _)) => format!(
+                    "Target too close to {} (min allowed: {:.1}°)",
+                    self.limits[k].0, self.limits[k].1
+                ),
+                None => "Target too close to a planet".to_string(),
+            },
+        );
+
+        let all_satisfied = violations.is_empty();
+        Ok(ConstraintResult::new(
+            violations,
+            all_satisfied,
+            self.format_name(),
+            times,
+        ))
+    }
+
+    fn in_constraint_batch(
+        &self,
+        ephemeris: &dyn crate::ephemeris::ephemeris_common::EphemerisBase,
+        target_ras: &[f64],
+        target_decs: &[f64],
+        time_indices: Option<&[usize]>,
+    ) -> Result<Array2<bool>, ConstraintError> {
+        if target_ras.len() != target_decs.len() {
+            return Err(ConstraintError::invalid_input(
+                "target_ras and target_decs must have the same length",
+            ));
+        }
+        let (times, directions) = self.planet_directions(ephemeris, time_indices)?;
+        let targets = radec_to_unit_vectors_batch(target_ras, target_decs);
+        Ok(self.violation_grid(&directions, &targets, times.len()))
+    }
+
+    fn in_constraint_batch_unit_vectors(
+        &self,
+        ephemeris: &dyn crate::ephemeris::ephemeris_common::EphemerisBase,
+        target_unit_vectors: &Array2<f64>,
+        time_indices: Option<&[usize]>,
+    ) -> Result<Option<Array2<bool>>, ConstraintError> {
+        if target_unit_vectors.ncols() != 3 {
+            return Err(ConstraintError::invalid_input(
+                "target_unit_vectors must have shape (N, 3)",
+            ));
+        }
+        let (times, directions) = self.planet_directions(ephemeris, time_indices)?;
+        Ok(Some(self.violation_grid(
+            &directions,
+            target_unit_vectors,
+            times.len(),
+        )))
+    }
+
+    fn name(&self) -> String {
+        self.format_name()
+    }
+
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn config(min_angles: Option<BTreeMap<String, f64>>) -> PlanetsProximityConfig {
+        PlanetsProximityConfig {
+            min_angle: 5.0,
+            planets: vec!["Venus".to_string(), "Mars".to_string()],
+            min_angles,
+            source: None,
+        }
+    }
+
+    #[test]
+    fn test_limits_shared() {
+        assert_eq!(
+            config(None).limits(),
+            vec![("Venus".to_string(), 5.0), ("Mars".to_string(), 5.0)]
+        );
+    }
+
+    #[test]
+    fn test_limits_override() {
+        let overrides = BTreeMap::from([("Mars".to_string(), 12.0)]);
+        assert_eq!(
+            config(Some(overrides)).limits(),
+            vec![("Venus".to_string(), 5.0), ("Mars".to_string(), 12.0)]
+        );
+    }
+
+    #[test]
+    fn test_violation_grid() {
+        let evaluator = PlanetsProximityEvaluator {
+            limits: vec![("A".to_string(), 10.0), ("B".to_string(), 1.0)],
+            source: BodySource::Spice,
+        };
+        // One time; planet A along +x, planet B along +y
+        let directions = vec![
+            Array2::from_shape_vec((1, 3), vec![1.0, 0.0, 0.0]).unwrap(),
+            Array2::from_shape_vec((1, 3), vec![0.0, 1.0, 0.0]).unwrap(),
+        ];
+        // 5° from A (violates), 5° from B (allowed), far from both
+        let targets = radec_to_unit_vectors_batch(&[5.0, 95.0, 200.0], &[0.0, 0.0, 0.0]);
+        let grid = evaluator.violation_grid(&directions, &targets, 1);
+        assert_eq!(grid.column(0).to_vec(), vec![true, false, false]);
+
+        let target = radec_to_unit_vector(5.0, 0.0);
+        let (k, separation) = evaluator.closest_violation(&directions, target, 0).unwrap();
+        assert_eq!(k, 0);
+        assert!((separation - 5.0).abs() < 1e-9);
+    }
+}
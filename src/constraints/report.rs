@@ -0,0 +1,337 @@
+//! Per-target visibility reports
+//!
+//! Summarizes, for each target of a list, how long and when it is visible
+//! under a constraint over an ephemeris, and which constraints block it for
+//! how long. Window and blocking durations run between ephemeris sample
+//! times, as for `ConstraintResult` windows.
+
+use chrono::{DateTime, Utc};
+use ndarray::Array1;
+use numpy::IntoPyArray;
+use pyo3::prelude::*;
+use pyo3::types::{PyDict, PyList};
+
+use crate::constraints::PyConstraint;
+use crate::ephemeris::ground_network::{runs, seconds_between};
+use crate::ephemeris::with_ephemeris;
+use crate::utils::time_utils::utc_to_python_datetime;
+
+/// Visibility summary of one target
+struct TargetSummary {
+    visible_seconds: f64,
+    n_windows: usize,
+    longest_window_seconds: f64,
+    first_visible: Option<usize>,
+    last_visible: Option<usize>,
+}
+
+/// Summarize the visibility of one target from its violation mask row
+fn summarize(times: &[DateTime<Utc>], violated: &[bool]) -> TargetSummary {
+    let visible: Vec<bool> = violated.iter().map(|&v| !v).collect();
+    let all_runs = runs(&visible);
+    let windows: Vec<f64> = all_runs
+        .iter()
+        .filter(|&&(start, end)| end > start)
+        .map(|&r| seconds_between(times, r))
+        .collect();
+    TargetSummary {
+        visible_seconds: windows.iter().sum(),
+        n_windows: windows.len(),
+        longest_window_seconds: windows.iter().copied().fold(0.0, f64::max),
+        first_visible: all_runs.first().map(|r| r.0),
+        last_visible: all_runs.last().map(|r| r.1),
+    }
+}
+
+/// Total time in seconds covered by runs of `true` samples
+fn run_seconds(times: &[DateTime<Utc>], mask: &[bool]) -> f64 {
+    runs(mask).iter().map(|&r| seconds_between(times, r)).sum()
+}
+
+/// Per-target visibility summary of a target list
+///
+/// Columns are available as attributes, as a dict of arrays with
+/// `to_dict()` (pass it to `pandas.DataFrame` for a table) or as a NumPy
+/// record array with `to_records()`.
+#[pyclass]
+pub struct VisibilityReport {
+    names: Option<Vec<String>>,
+    ras: Vec<f64>,
+    decs: Vec<f64>,
+    times: Vec<DateTime<Utc>>,
+    summaries: Vec<TargetSummary>,
+    blocking: Vec<(String, Vec<f64>)>,
+}
+
+impl VisibilityReport {
+    fn column(&self, py: Python, values: impl Fn(&TargetSummary) -> f64) -> Py<PyAny> {
+        Array1::from_iter(self.summaries.iter().map(values))
+            .into_pyarray(py)
+            .into()
+    }
+
+    fn time_column(
+        &self,
+        py: Python,
+        index: impl Fn(&TargetSummary) -> Option<usize>,
+    ) -> PyResult<Py<PyAny>> {
+        let values = self
+            .summaries
+            .iter()
+            .map(|s| match index(s) {
+                Some(i) => utc_to_python_datetime(py, &self.times[i]),
+                None => Ok(py.None()),
+            })
+            .collect::<PyResult<Vec<_>>>()?;
+        let np = pyo3::types::PyModule::import(py, "numpy")
+            .map_err(|_| pyo3::exceptions::PyImportError::new_err("numpy is required"))?;
+        Ok(np
+            .getattr("array")?
+            .call1((PyList::new(py, values)?, "object"))?
+            .into())
+    }
+
+    /// Report columns in order, names first when given
+    fn columns(&self, py: Python) -> PyResult<Vec<(String, Py<PyAny>)>> {
+        let mut columns = Vec::new();
+        if let Some(names) = &self.names {
+            columns.push((
+                "name".to_string(),
+                PyList::new(py, names)?.into_any().unbind(),
+            ));
+        }
+        columns.push(("ra".to_string(), self.ra(py)));
+        columns.push(("dec".to_string(), self.dec(py)));
+        columns.push(("visible_hours".to_string(), self.visible_hours(py)));
+        columns.push(("n_windows".to_string(), self.n_windows(py)));
+        columns.push((
+            "longest_window_hours".to_string(),
+            self.longest_window_hours(py),
+        ));
+        columns.push(("first_visible".to_string(), self.first_visible(py)?));
+        columns.push(("last_visible".to_string(), self.last_visible(py)?));
+        for (name, seconds) in &self.blocking {
+            let hours = Array1::from_iter(seconds.iter().map(|s| s / 3600.0));
+            columns.push((
+                format!("blocked_hours:{}", name),
+                hours.into_pyarray(py).into(),
+            ));
+        }
+        Ok(columns)
+    }
+}
+
+#[pymethods]
+impl VisibilityReport {
+    /// Target names, if given
+    #[getter]
+    fn names(&self) -> Option<Vec<String>> {
+        self.names.clone()
+    }
+
+    /// Target right ascensions in degrees
+    #[getter]
+    fn ra(&self, py: Python) -> Py<PyAny> {
+        Array1::from_vec(self.ras.clone()).into_pyarray(py).into()
+    }
+
+    /// Target declinations in degrees
+    #[getter]
+    fn dec(&self, py: Python) -> Py<PyAny> {
+        Array1::from_vec(self.decs.clone()).into_pyarray(py).into()
+    }
+
+    /// Total visible time per target in hours
+    #[getter]
+    fn visible_hours(&self, py: Python) -> Py<PyAny> {
+        self.column(py, |s| s.visible_seconds / 3600.0)
+    }
+
+    /// Number of visibility windows per target
+    #[getter]
+    fn n_windows(&self, py: Python) -> Py<PyAny> {
+        Array1::from_iter(self.summaries.iter().map(|s| s.n_windows as i64))
+            .into_pyarray(py)
+            .into()
+    }
+
+    /// Longest visibility window per target in hours
+    #[getter]
+    fn longest_window_hours(&self, py: Python) -> Py<PyAny> {
+        self.column(py, |s| s.longest_window_seconds / 3600.0)
+    }
+
+    /// First visible time per target (None if never visible)
+    #[getter]
+    fn first_visible(&self, py: Python) -> PyResult<Py<PyAny>> {
+        self.time_column(py, |s| s.first_visible)
+    }
+
+    /// Last visible time per target (None if never visible)
+    #[getter]
+    fn last_visible(&self, py: Python) -> PyResult<Py<PyAny>> {
+        self.time_column(py, |s| s.last_visible)
+    }
+
+    /// Hours each blocking constraint is violated, per target, keyed by constraint name
+    ///
+    /// Blocking constraints are the sub-constraints of an OR combination, or
+    /// the constraint itself otherwise. Their times can overlap, so the hours
+    /// need not add up to the time the target is not visible.
+    #[getter]
+    fn blocked_hours<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyDict>> {
+        let dict = PyDict::new(py);
+        for (name, seconds) in &self.blocking {
+            let hours = Array1::from_iter(seconds.iter().map(|s| s / 3600.0));
+            dict.set_item(name, hours.into_pyarray(py))?;
+        }
+        Ok(dict)
+    }
+
+    /// Report columns as a dict of arrays
+    ///
+    /// Blocking constraint columns are named `blocked_hours:<constraint name>`.
+    fn to_dict<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyDict>> {
+        let dict = PyDict::new(py);
+        for (name, column) in self.columns(py)? {
+            dict.set_item(name, column)?;
+        }
+        Ok(dict)
+    }
+
+    /// Report as a NumPy record array with one record per target
+    fn to_records(&self, py: Python) -> PyResult<Py<PyAny>> {
+        let (names, columns): (Vec<String>, Vec<Py<PyAny>>) = self.columns(py)?.into_iter().unzip();
+        let np = pyo3::types::PyModule::import(py, "numpy")
+            .map_err(|_| pyo3::exceptions::PyImportError::new_err("numpy is required"))?;
+        let kwargs = PyDict::new(py);
+        kwargs.set_item("names", names)?;
+        Ok(np
+            .getattr("rec")?
+            .getattr("fromarrays")?
+            .call((columns,), Some(&kwargs))?
+            .into())
+    }
+
+    fn __len__(&self) -> usize {
+        self.summaries.len()
+    }
+
+    fn __repr__(&self) -> String {
+        let visible = self
+            .summaries
+            .iter()
+            .filter(|s| s.first_visible.is_some())
+            .count();
+        format!(
+            "VisibilityReport(targets={}, visible={}, blocking_constraints={})",
+            self.summaries.len(),
+            visible,
+            self.blocking.len()
+        )
+    }
+}
+
+/// Build a visibility report for `targets` under `constraint`
+pub fn visibility_report(
+    ephemeris: &Bound<'_, PyAny>,
+    targets: &[(f64, f64)],
+    constraint: &PyConstraint,
+    names: Option<Vec<String>>,
+) -> PyResult<VisibilityReport> {
+    if names.as_ref().is_some_and(|n| n.len() != targets.len()) {
+        return Err(pyo3::exceptions::PyValueError::new_err(
+            "names must have one entry per target",
+        ));
+    }
+    let ras: Vec<f64> = targets.iter().map(|t| t.0).collect();
+    let decs: Vec<f64> = targets.iter().map(|t| t.1).collect();
+
+    let (times, summaries, blocking) = with_ephemeris(ephemeris, |ephem| {
+        let times = ephem.get_times()?;
+        if targets.is_empty() {
+            return Ok((times, Vec::new(), Vec::new()));
+        }
+        let mask = constraint.violation_mask(ephem, &ras, &decs)?;
+        let summaries = mask
+            .rows()
+            .into_iter()
+            .map(|row| summarize(&times, &row.to_vec()))
+            .collect();
+        let mut blocking: Vec<(String, Vec<f64>)> = Vec::new();
+        for (name, mask) in constraint.blocking_masks(ephem, &ras, &decs)? {
+            // Identical sub-constraints would otherwise share a column name
+            let suffixed = format!("{} #", name);
+            let repeats = blocking
+                .iter()
+                .filter(|(n, _)| *n == name || n.starts_with(&suffixed))
+                .count();
+            let name = match repeats {
+                0 => name,
+                _ => format!("{} #{}", name, repeats + 1),
+            };
+            let seconds = mask
+                .rows()
+                .into_iter()
+                .map(|row| run_seconds(&times, &row.to_vec()))
+                .collect();
+            blocking.push((name, seconds));
+        }
+        Ok((times, summaries, blocking))
+    })?;
+
+    Ok(VisibilityReport {
+        names,
+        ras,
+        decs,
+        times,
+        summaries,
+        blocking,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::{Duration, TimeZone};
+
+    fn times(n: usize) -> Vec<DateTime<Utc>> {
+        let start = Utc.with_ymd_and_hms(2025, 1, 1, 0, 0, 0).unwrap();
+        (0..n)
+            .map(|i| start + Duration::minutes(10 * i as i64))
+            .collect()
+    }
+
+    #[test]
+    fn test_summarize() {
+        let times = times(8);
+        let violated = [true, false, false, false, true, false, true, false];
+        let summary = summarize(&times, &violated);
+        // Windows 1-3 (20 min) plus single-sample runs at 5 and 7
+        assert_eq!(summary.n_windows, 1);
+        assert_eq!(summary.visible_seconds, 1200.0);
+        assert_eq!(summary.longest_window_seconds, 1200.0);
+        assert_eq!(summary.first_visible, Some(1));
+        assert_eq!(summary.last_visible, Some(7));
+    }
+
+    #[test]
+    fn test_summarize_never_visible() {
+        let times = times(3);
+        let summary = summarize(&times, &[true, true, true]);
+        assert_eq!(summary.n_windows, 0);
+        assert_eq!(summary.visible_seconds, 0.0);
+        assert_eq!(summary.first_visible, None);
+        assert_eq!(summary.last_visible, None);
+    }
+
+    #[test]
+    fn test_run_seconds() {
+        let times = times(5);
+        assert_eq!(
+            run_seconds(&times, &[true, true, false, true, true]),
+            1200.0
+        );
+        assert_eq!(run_seconds(&times, &[false; 5]), 0.0);
+    }
+}
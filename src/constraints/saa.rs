@@ -1,16 +1,56 @@
-/// South Atlantic Anomaly constraint implementation
 use super::core::{track_violations, ConstraintConfig, ConstraintEvaluator, ConstraintResult};
-use crate::utils::polygon;
+/// South Atlantic Anomaly constraint implementation
+use crate::constraints::error::ConstraintError;
+use crate::utils::sphere::SphericalPolygon;
 use chrono::{DateTime, Utc};
 use ndarray::Array2;
-use pyo3::PyResult;
 use serde::{Deserialize, Serialize};
 
 /// Configuration for South Atlantic Anomaly constraint
+///
+/// The polygon is checked by [`SAAConfig::new`] and when deserializing, so a
+/// config always holds a valid region.
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(try_from = "SAAConfigFields", into = "SAAConfigFields")]
 pub struct SAAConfig {
+    polygon: SphericalPolygon,
+}
+
+/// Serialized form of [`SAAConfig`]
+#[derive(Serialize, Deserialize)]
+struct SAAConfigFields {
     /// Polygon defining the SAA region as (longitude, latitude) pairs in degrees
-    pub polygon: Vec<(f64, f64)>,
+    ///
+    /// Edges are great-circle arcs, so the region may cross the antimeridian;
+    /// it must fit within a hemisphere.
+    polygon: Vec<(f64, f64)>,
+}
+
+impl SAAConfig {
+    /// Config for the polygon with (longitude, latitude) vertices in degrees
+    ///
+    /// Fails unless the polygon has at least 3 vertices and fits within a
+    /// hemisphere.
+    pub fn new(polygon: &[(f64, f64)]) -> Result<Self, ConstraintError> {
+        let polygon = SphericalPolygon::new(polygon).map_err(ConstraintError::InvalidInput)?;
+        Ok(SAAConfig { polygon })
+    }
+}
+
+impl TryFrom<SAAConfigFields> for SAAConfig {
+    type Error = ConstraintError;
+
+    fn try_from(fields: SAAConfigFields) -> Result<Self, Self::Error> {
+        SAAConfig::new(&fields.polygon)
+    }
+}
+
+impl From<SAAConfig> for SAAConfigFields {
+    fn from(config: SAAConfig) -> Self {
+        SAAConfigFields {
+            polygon: config.polygon.vertices().to_vec(),
+        }
+    }
 }
 
 impl ConstraintConfig for SAAConfig {
@@ -23,18 +63,17 @@ impl ConstraintConfig for SAAConfig {
 
 /// Evaluator for South Atlantic Anomaly constraint
 pub struct SAAEvaluator {
-    polygon: Vec<(f64, f64)>,
+    polygon: SphericalPolygon,
 }
 
 impl SAAEvaluator {
     fn format_name(&self) -> String {
-        format!("SAAConstraint(vertices={})", self.polygon.len())
+        format!("SAAConstraint(vertices={})", self.polygon.vertex_count())
     }
 
-    /// Check if a point is inside the polygon using the winding number algorithm
-    /// This is more robust than ray casting for complex polygons
-    fn point_in_polygon(&self, lon: f64, lat: f64) -> bool {
-        polygon::point_in_polygon(&self.polygon, lon, lat)
+    /// Whether each (lat, lon) point lies inside the SAA polygon
+    fn saa_mask(&self, lats: &[f64], lons: &[f64]) -> Vec<bool> {
+        self.polygon.contains_many(lons, lats)
     }
 }
 
@@ -47,13 +86,11 @@ impl SAAEvaluator {
         lats: &[f64],
         lons: &[f64],
     ) -> ConstraintResult {
+        let in_saa = self.saa_mask(lats, lons);
         let violations = track_violations(
             times,
             |i| {
-                let lat = lats[i];
-                let lon = lons[i];
-                let in_saa = self.point_in_polygon(lon, lat);
-                let violated = in_saa;
+                let violated = in_saa[i];
                 let severity = if violated { 1.0 } else { 0.0 };
                 (violated, severity)
             },
@@ -81,23 +118,9 @@ impl SAAEvaluator {
         lats: &[f64],
         lons: &[f64],
     ) -> Array2<bool> {
-        let n_times = lats.len();
-        let n_targets = target_ras.len();
-
-        let mut result = Array2::<bool>::from_elem((n_targets, n_times), false);
-
-        for i in 0..n_times {
-            let lat = lats[i];
-            let lon = lons[i];
-            let in_saa = self.point_in_polygon(lon, lat);
-            let satisfied = in_saa;
-
-            for target_idx in 0..n_targets {
-                result[[target_idx, i]] = satisfied;
-            }
-        }
-
-        result
+        // SAA depends only on the observer, so every target shares one row
+        let in_saa = self.saa_mask(lats, lons);
+        Array2::from_shape_fn((target_ras.len(), in_saa.len()), |(_, i)| in_saa[i])
     }
 }
 
@@ -108,7 +131,7 @@ impl ConstraintEvaluator for SAAEvaluator {
         _target_ra: f64,
         _target_dec: f64,
         time_indices: Option<&[usize]>,
-    ) -> pyo3::PyResult<ConstraintResult> {
+    ) -> Result<ConstraintResult, ConstraintError> {
         // Extract and filter lat/lon data
         let (times_slice, lats_slice, lons_slice) = extract_latlon_data!(ephemeris, time_indices);
 
@@ -122,7 +145,7 @@ impl ConstraintEvaluator for SAAEvaluator {
         target_ras: &[f64],
         _target_decs: &[f64],
         time_indices: Option<&[usize]>,
-    ) -> PyResult<Array2<bool>> {
+    ) -> Result<Array2<bool>, ConstraintError> {
         // Extract and filter lat/lon data (discard times since we don't need them)
         let (_times_slice, lats_slice, lons_slice) = extract_latlon_data!(ephemeris, time_indices);
 
@@ -135,9 +158,9 @@ impl ConstraintEvaluator for SAAEvaluator {
         ephemeris: &dyn crate::ephemeris::ephemeris_common::EphemerisBase,
         target_unit_vectors: &Array2<f64>,
         time_indices: Option<&[usize]>,
-    ) -> PyResult<Option<Array2<bool>>> {
+    ) -> Result<Option<Array2<bool>>, ConstraintError> {
         if target_unit_vectors.ncols() != 3 {
-            return Err(pyo3::exceptions::PyValueError::new_err(
+            return Err(ConstraintError::invalid_input(
                 "target_unit_vectors must have shape (N, 3)",
             ));
         }
@@ -159,7 +182,7 @@ impl ConstraintEvaluator for SAAEvaluator {
         ephemeris: &dyn crate::ephemeris::ephemeris_common::EphemerisBase,
         target_ras: &[f64],
         _target_decs: &[f64],
-    ) -> PyResult<Vec<bool>> {
+    ) -> Result<Vec<bool>, ConstraintError> {
         let n = target_ras.len();
         if n == 0 {
             return Ok(Vec::new());
@@ -170,14 +193,7 @@ impl ConstraintEvaluator for SAAEvaluator {
         let (_times_slice, lats_slice, lons_slice) =
             extract_latlon_data!(ephemeris, Some(&time_indices[..]));
 
-        // Evaluate SAA at each time
-        let mut result = Vec::with_capacity(n);
-        for i in 0..n {
-            let in_saa = self.point_in_polygon(lons_slice[i], lats_slice[i]);
-            result.push(in_saa);
-        }
-
-        Ok(result)
+        Ok(self.saa_mask(&lats_slice, &lons_slice))
     }
 
     fn name(&self) -> String {
@@ -188,3 +204,54 @@ impl ConstraintEvaluator for SAAEvaluator {
         self
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::{Duration, TimeZone};
+
+    #[test]
+    fn test_new_validates() {
+        let polygon = [(-90.0, -50.0), (-40.0, -50.0), (-40.0, 0.0), (-90.0, 0.0)];
+        assert!(SAAConfig::new(&polygon).is_ok());
+        assert!(SAAConfig::new(&[(0.0, 0.0), (10.0, 0.0)]).is_err());
+        assert!(SAAConfig::new(&[(0.0, 0.0), (120.0, 0.0), (240.0, 0.0)]).is_err());
+    }
+
+    #[test]
+    fn test_deserialize_validates() {
+        let config: SAAConfig =
+            serde_json::from_str(r#"{"polygon": [[-90, -50], [-40, -50], [-40, 0]]}"#).unwrap();
+        assert_eq!(config.to_evaluator().name(), "SAAConstraint(vertices=3)");
+        assert_eq!(
+            serde_json::to_value(&config).unwrap(),
+            serde_json::json!({"polygon": [[-90.0, -50.0], [-40.0, -50.0], [-40.0, 0.0]]})
+        );
+        let err = serde_json::from_str::<SAAConfig>(r#"{"polygon": [[0, 0], [10, 0]]}"#)
+            .unwrap_err()
+            .to_string();
+        assert!(err.contains("at least 3 vertices"), "{err}");
+    }
+
+    #[test]
+    fn test_region_across_antimeridian() {
+        let evaluator = SAAEvaluator {
+            polygon: SphericalPolygon::new(&[
+                (170.0, -20.0),
+                (-170.0, -20.0),
+                (-170.0, 20.0),
+                (170.0, 20.0),
+            ])
+            .unwrap(),
+        };
+        let start = Utc.with_ymd_and_hms(2025, 1, 1, 0, 0, 0).unwrap();
+        let times: Vec<DateTime<Utc>> = (0..4).map(|i| start + Duration::minutes(i)).collect();
+        let lats = [0.0, 10.0, 0.0, -10.0];
+        let lons = [0.0, 179.0, -175.0, 185.0];
+        assert_eq!(evaluator.saa_mask(&lats, &lons), [false, true, true, true]);
+
+        let result = evaluator.evaluate_with_latlon(&times, &lats, &lons);
+        assert_eq!(result.violations.len(), 1);
+        assert_eq!(result.violations[0].start_time_internal, times[1]);
+    }
+}
@@ -0,0 +1,398 @@
+//! Greedy observation scheduling
+//!
+//! Targets are taken in priority order and each is placed at the earliest
+//! start time where its whole exposure lies inside one of its visibility
+//! windows, it does not overlap observations already scheduled, and there is
+//! time to slew from the previous observation and on to the next one.
+//! Observations already placed are never moved, so the plan is greedy rather
+//! than optimal. Visibility windows run between ephemeris sample times, so
+//! their resolution is the ephemeris step size.
+
+use chrono::{DateTime, Duration, Utc};
+use pyo3::prelude::*;
+
+use crate::constraints::PyConstraint;
+use crate::ephemeris::with_ephemeris;
+use crate::utils::time_utils::utc_to_python_datetime;
+use crate::utils::vector_math::{dot_product, radec_to_unit_vector};
+
+/// One observation of a schedule
+#[pyclass]
+#[derive(Clone)]
+pub struct ScheduledObservation {
+    /// Index of the target in the list passed to `schedule`
+    #[pyo3(get)]
+    target_index: usize,
+    /// Target right ascension in degrees
+    #[pyo3(get)]
+    ra: f64,
+    /// Target declination in degrees
+    #[pyo3(get)]
+    dec: f64,
+    /// Target priority
+    #[pyo3(get)]
+    priority: f64,
+    /// Slew and settle time from the previous observation in seconds (0 for the first)
+    #[pyo3(get)]
+    slew_seconds: f64,
+    start: DateTime<Utc>,
+    end: DateTime<Utc>,
+}
+
+#[pymethods]
+impl ScheduledObservation {
+    /// Start of the exposure
+    #[getter]
+    fn start_time(&self, py: Python) -> PyResult<Py<PyAny>> {
+        utc_to_python_datetime(py, &self.start)
+    }
+
+    /// End of the exposure
+    #[getter]
+    fn end_time(&self, py: Python) -> PyResult<Py<PyAny>> {
+        utc_to_python_datetime(py, &self.end)
+    }
+
+    /// Exposure duration in seconds
+    #[getter]
+    fn exposure_seconds(&self) -> f64 {
+        (self.end - self.start).num_milliseconds() as f64 / 1000.0
+    }
+
+    fn __repr__(&self) -> String {
+        format!(
+            "ScheduledObservation(target_index={}, start='{}', exposure_seconds={:.1}, slew_seconds={:.1})",
+            self.target_index,
+            self.start.to_rfc3339(),
+            self.exposure_seconds(),
+            self.slew_seconds
+        )
+    }
+}
+
+/// An ordered observation plan
+#[pyclass]
+pub struct Schedule {
+    observations: Vec<ScheduledObservation>,
+    unscheduled: Vec<usize>,
+}
+
+#[pymethods]
+impl Schedule {
+    /// Scheduled observations in time order
+    #[getter]
+    fn observations(&self) -> Vec<ScheduledObservation> {
+        self.observations.clone()
+    }
+
+    /// Indices of targets that could not be scheduled, in priority order
+    #[getter]
+    fn unscheduled(&self) -> Vec<usize> {
+        self.unscheduled.clone()
+    }
+
+    /// Total exposure time in seconds
+    #[getter]
+    fn total_exposure_seconds(&self) -> f64 {
+        self.observations.iter().map(|o| o.exposure_seconds()).sum()
+    }
+
+    /// Total slew and settle time in seconds
+    #[getter]
+    fn total_slew_seconds(&self) -> f64 {
+        self.observations.iter().map(|o| o.slew_seconds).sum()
+    }
+
+    fn __len__(&self) -> usize {
+        self.observations.len()
+    }
+
+    fn __repr__(&self) -> String {
+        format!(
+            "Schedule(scheduled={}, unscheduled={}, total_exposure_seconds={:.1})",
+            self.observations.len(),
+            self.unscheduled.len(),
+            self.total_exposure_seconds()
+        )
+    }
+}
+
+/// An observation placed on the timeline, in seconds from the first ephemeris time
+struct Placement {
+    target: usize,
+    start: f64,
+    end: f64,
+}
+
+/// Slew model: constant-rate slew plus a fixed settle time
+struct Slew {
+    directions: Vec<[f64; 3]>,
+    rate: f64,
+    settle: f64,
+}
+
+impl Slew {
+    fn seconds(&self, from: usize, to: usize) -> f64 {
+        let cos = dot_product(&self.directions[from], &self.directions[to]).clamp(-1.0, 1.0);
+        cos.acos().to_degrees() / self.rate + self.settle
+    }
+}
+
+/// Visibility windows (start, end) in seconds from the first time
+fn visible_windows(offsets: &[f64], violated: impl Fn(usize) -> bool) -> Vec<(f64, f64)> {
+    let mut windows = Vec::new();
+    let mut start: Option<usize> = None;
+    for i in 0..offsets.len() {
+        match (violated(i), start) {
+            (false, None) => start = Some(i),
+            (true, Some(s)) => {
+                windows.push((offsets[s], offsets[i - 1]));
+                start = None;
+            }
+            _ => {}
+        }
+    }
+    if let Some(s) = start {
+        windows.push((offsets[s], offsets[offsets.len() - 1]));
+    }
+    windows
+}
+
+/// Earliest feasible start of `target` and the position to insert it at
+fn earliest_start(
+    placed: &[Placement],
+    windows: &[(f64, f64)],
+    target: usize,
+    exposure: f64,
+    slew: &Slew,
+) -> Option<(f64, usize)> {
+    for k in 0..=placed.len() {
+        let lower = match k {
+            0 => f64::NEG_INFINITY,
+            _ => placed[k - 1].end + slew.seconds(placed[k - 1].target, target),
+        };
+        let upper = match placed.get(k) {
+            Some(next) => next.start - slew.seconds(target, next.target) - exposure,
+            None => f64::INFINITY,
+        };
+        for &(window_start, window_end) in windows {
+            let start = lower.max(window_start);
+            if start <= upper.min(window_end - exposure) {
+                return Some((start, k));
+            }
+        }
+    }
+    None
+}
+
+/// Per-target values from a scalar or a sequence of length `n`
+fn per_target(obj: &Bound<'_, PyAny>, n: usize, name: &str) -> PyResult<Vec<f64>> {
+    if let Ok(value) = obj.extract::<f64>() {
+        return Ok(vec![value; n]);
+    }
+    let values: Vec<f64> = obj.extract()?;
+    if values.len() != n {
+        return Err(pyo3::exceptions::PyValueError::new_err(format!(
+            "{} must be a number or have one value per target",
+            name
+        )));
+    }
+    Ok(values)
+}
+
+/// Greedily schedule targets on an ephemeris timeline
+///
+/// `constraints` is one `Constraint` for all targets or a list with one per
+/// target. `exposure_times` (seconds) is a number or one value per target.
+/// Higher `priorities` are scheduled first; ties keep the target order.
+pub fn schedule_targets(
+    ephemeris: &Bound<'_, PyAny>,
+    targets: &[(f64, f64)],
+    constraints: &Bound<'_, PyAny>,
+    exposure_times: &Bound<'_, PyAny>,
+    priorities: Option<Vec<f64>>,
+    slew_rate: f64,
+    settle_time: f64,
+) -> PyResult<Schedule> {
+    let n = targets.len();
+    if n == 0 {
+        return Ok(Schedule {
+            observations: Vec::new(),
+            unscheduled: Vec::new(),
+        });
+    }
+    let exposures = per_target(exposure_times, n, "exposure_times")?;
+    if exposures.iter().any(|&e| e <= 0.0 || !e.is_finite()) {
+        return Err(pyo3::exceptions::PyValueError::new_err(
+            "exposure_times must be positive",
+        ));
+    }
+    let priorities = priorities.unwrap_or_else(|| vec![0.0; n]);
+    if priorities.len() != n {
+        return Err(pyo3::exceptions::PyValueError::new_err(
+            "priorities must have one value per target",
+        ));
+    }
+    if slew_rate <= 0.0 || !slew_rate.is_finite() {
+        return Err(pyo3::exceptions::PyValueError::new_err(
+            "slew_rate must be positive",
+        ));
+    }
+    if settle_time < 0.0 || !settle_time.is_finite() {
+        return Err(pyo3::exceptions::PyValueError::new_err(
+            "settle_time must be non-negative",
+        ));
+    }
+
+    let ras: Vec<f64> = targets.iter().map(|t| t.0).collect();
+    let decs: Vec<f64> = targets.iter().map(|t| t.1).collect();
+    let (times, windows) = with_ephemeris(ephemeris, |ephem| {
+        let times = ephem.get_times()?;
+        let offsets: Vec<f64> = times
+            .iter()
+            .map(|t| (*t - times[0]).num_milliseconds() as f64 / 1000.0)
+            .collect();
+        let windows = if let Ok(constraint) = constraints.extract::<PyRef<PyConstraint>>() {
+            let mask = constraint.violation_mask(ephem, &ras, &decs)?;
+            (0..n)
+                .map(|k| visible_windows(&offsets, |i| mask[[k, i]]))
+                .collect()
+        } else {
+            let per_target: Vec<PyRef<PyConstraint>> = constraints.extract()?;
+            if per_target.len() != n {
+                return Err(pyo3::exceptions::PyValueError::new_err(
+                    "constraints must be a Constraint or have one Constraint per target",
+                ));
+            }
+            per_target
+                .iter()
+                .enumerate()
+                .map(|(k, constraint)| {
+                    let mask = constraint.violation_mask(ephem, &ras[k..=k], &decs[k..=k])?;
+                    Ok(visible_windows(&offsets, |i| mask[[0, i]]))
+                })
+                .collect::<PyResult<Vec<_>>>()?
+        };
+        Ok((times, windows))
+    })?;
+
+    let slew = Slew {
+        directions: targets
+            .iter()
+            .map(|&(ra, dec)| radec_to_unit_vector(ra, dec))
+            .collect(),
+        rate: slew_rate,
+        settle: settle_time,
+    };
+
+    let mut order: Vec<usize> = (0..n).collect();
+    order.sort_by(|&a, &b| priorities[b].total_cmp(&priorities[a]));
+
+    let mut placed: Vec<Placement> = Vec::new();
+    let mut unscheduled = Vec::new();
+    for target in order {
+        match earliest_start(&placed, &windows[target], target, exposures[target], &slew) {
+            Some((start, k)) => placed.insert(
+                k,
+                Placement {
+                    target,
+                    start,
+                    end: start + exposures[target],
+                },
+            ),
+            None => unscheduled.push(target),
+        }
+    }
+
+    let at = |offset: f64| times[0] + Duration::milliseconds((offset * 1000.0).round() as i64);
+    let observations = placed
+        .iter()
+        .enumerate()
+        .map(|(k, p)| ScheduledObservation {
+            target_index: p.target,
+            ra: targets[p.target].0,
+            dec: targets[p.target].1,
+            priority: priorities[p.target],
+            slew_seconds: match k {
+                0 => 0.0,
+                _ => slew.seconds(placed[k - 1].target, p.target),
+            },
+            start: at(p.start),
+            end: at(p.end),
+        })
+        .collect();
+
+    Ok(Schedule {
+        observations,
+        unscheduled,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn slew() -> Slew {
+        Slew {
+            directions: vec![
+                radec_to_unit_vector(0.0, 0.0),
+                radec_to_unit_vector(90.0, 0.0),
+                radec_to_unit_vector(0.0, 0.0),
+            ],
+            rate: 1.0,
+            settle: 10.0,
+        }
+    }
+
+    #[test]
+    fn test_visible_windows() {
+        let offsets = [0.0, 60.0, 120.0, 180.0, 240.0];
+        let violated = [true, false, false, true, false];
+        assert_eq!(
+            visible_windows(&offsets, |i| violated[i]),
+            vec![(60.0, 120.0), (240.0, 240.0)]
+        );
+        assert!(visible_windows(&offsets, |_| true).is_empty());
+    }
+
+    #[test]
+    fn test_slew_time() {
+        let slew = slew();
+        assert!((slew.seconds(0, 1) - 100.0).abs() < 1e-9);
+        assert!((slew.seconds(0, 2) - 10.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_earliest_start_respects_slew_and_windows() {
+        let slew = slew();
+        let placed = vec![Placement {
+            target: 0,
+            start: 0.0,
+            end: 100.0,
+        }];
+        // After target 0 plus a 100 s slew
+        assert_eq!(
+            earliest_start(&placed, &[(0.0, 1000.0)], 1, 50.0, &slew),
+            Some((200.0, 1))
+        );
+        // Before target 0 does not fit (slew back would overlap), window too short after
+        assert_eq!(
+            earliest_start(&placed, &[(0.0, 240.0)], 1, 50.0, &slew),
+            None
+        );
+        // Slotting in before a later observation leaves time to slew to it
+        let later = vec![Placement {
+            target: 0,
+            start: 500.0,
+            end: 600.0,
+        }];
+        assert_eq!(
+            earliest_start(&later, &[(0.0, 1000.0)], 2, 100.0, &slew),
+            Some((0.0, 0))
+        );
+        assert_eq!(
+            earliest_start(&later, &[(450.0, 1000.0)], 2, 100.0, &slew),
+            Some((610.0, 1))
+        );
+    }
+}
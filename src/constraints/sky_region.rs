@@ -0,0 +1,195 @@
+use super::core::{track_violations, ConstraintConfig, ConstraintEvaluator, ConstraintResult};
+/// Sky avoidance region constraint implementation
+use crate::constraints::error::ConstraintError;
+use crate::utils::sphere::SphericalPolygon;
+use ndarray::Array2;
+use serde::{Deserialize, Serialize};
+
+/// Configuration for a sky avoidance region constraint
+///
+/// The polygon is checked by [`SkyRegionConfig::new`] and when deserializing, so a
+/// config always holds a valid region.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(try_from = "SkyRegionConfigFields", into = "SkyRegionConfigFields")]
+pub struct SkyRegionConfig {
+    polygon: SphericalPolygon,
+}
+
+/// Serialized form of [`SkyRegionConfig`]
+#[derive(Serialize, Deserialize)]
+struct SkyRegionConfigFields {
+    /// Polygon bounding the region as (RA, Dec) pairs in degrees
+    ///
+    /// Edges are great-circle arcs; the region must fit within a hemisphere.
+    polygon: Vec<(f64, f64)>,
+}
+
+impl SkyRegionConfig {
+    /// Config for the polygon with (RA, Dec) vertices in degrees
+    ///
+    /// Fails unless the polygon has at least 3 vertices and fits within a
+    /// hemisphere.
+    pub fn new(polygon: &[(f64, f64)]) -> Result<Self, ConstraintError> {
+        let polygon = SphericalPolygon::new(polygon).map_err(ConstraintError::InvalidInput)?;
+        Ok(SkyRegionConfig { polygon })
+    }
+}
+
+impl TryFrom<SkyRegionConfigFields> for SkyRegionConfig {
+    type Error = ConstraintError;
+
+    fn try_from(fields: SkyRegionConfigFields) -> Result<Self, Self::Error> {
+        SkyRegionConfig::new(&fields.polygon)
+    }
+}
+
+impl From<SkyRegionConfig> for SkyRegionConfigFields {
+    fn from(config: SkyRegionConfig) -> Self {
+        SkyRegionConfigFields {
+            polygon: config.polygon.vertices().to_vec(),
+        }
+    }
+}
+
+impl ConstraintConfig for SkyRegionConfig {
+    fn to_evaluator(&self) -> Box<dyn ConstraintEvaluator> {
+        Box::new(SkyRegionEvaluator {
+            polygon: self.polygon.clone(),
+        })
+    }
+}
+
+/// Evaluator for sky avoidance regions: violated while the target is inside
+pub struct SkyRegionEvaluator {
+    polygon: SphericalPolygon,
+}
+
+impl SkyRegionEvaluator {
+    fn format_name(&self) -> String {
+        format!(
+            "SkyRegionConstraint(vertices={})",
+            self.polygon.vertex_count()
+        )
+    }
+}
+
+impl ConstraintEvaluator for SkyRegionEvaluator {
+    fn evaluate(
+        &self,
+        ephemeris: &dyn crate::ephemeris::ephemeris_common::EphemerisBase,
+        target_ra: f64,
+        target_dec: f64,
+        time_indices: Option<&[usize]>,
+    ) -> Result<ConstraintResult, ConstraintError> {
+        let (times_filtered,) = extract_time_data!(ephemeris, time_indices);
+
+        // A fixed target is either inside the region at every time or never
+        let inside = self.polygon.contains_point(target_ra, target_dec);
+        let violations = track_violations(
+            &times_filtered,
+            |_| (inside, if inside { 1.0 } else { 0.0 }),
+            |_, _| {
+                format!(
+                    "Target inside sky avoidance region (RA: {:.2}°, Dec: {:.2}°)",
+                    target_ra, target_dec
+                )
+            },
+        );
+
+        let all_satisfied = violations.is_empty();
+        Ok(ConstraintResult::new(
+            violations,
+            all_satisfied,
+            self.format_name(),
+            times_filtered,
+        ))
+    }
+
+    fn in_constraint_batch(
+        &self,
+        ephemeris: &dyn crate::ephemeris::ephemeris_common::EphemerisBase,
+        target_ras: &[f64],
+        target_decs: &[f64],
+        time_indices: Option<&[usize]>,
+    ) -> Result<Array2<bool>, ConstraintError> {
+        let (times_filtered,) = extract_time_data!(ephemeris, time_indices);
+
+        // The region depends only on the target, so every time shares one column
+        let inside = self.polygon.contains_many(target_ras, target_decs);
+        Ok(Array2::from_shape_fn(
+            (inside.len(), times_filtered.len()),
+            |(i, _)| inside[i],
+        ))
+    }
+
+    fn in_constraint_batch_unit_vectors(
+        &self,
+        ephemeris: &dyn crate::ephemeris::ephemeris_common::EphemerisBase,
+        target_unit_vectors: &Array2<f64>,
+        time_indices: Option<&[usize]>,
+    ) -> Result<Option<Array2<bool>>, ConstraintError> {
+        if target_unit_vectors.ncols() != 3 {
+            return Err(ConstraintError::invalid_input(
+                "target_unit_vectors must have shape (N, 3)",
+            ));
+        }
+
+        let (times_filtered,) = extract_time_data!(ephemeris, time_indices);
+        let inside: Vec<bool> = target_unit_vectors
+            .rows()
+            .into_iter()
+            .map(|row| self.polygon.contains_unit(&[row[0], row[1], row[2]]))
+            .collect();
+        Ok(Some(Array2::from_shape_fn(
+            (inside.len(), times_filtered.len()),
+            |(i, _)| inside[i],
+        )))
+    }
+
+    /// O(N) diagonal evaluation: each target is tested once, whatever its time
+    fn in_constraint_batch_diagonal(
+        &self,
+        _ephemeris: &dyn crate::ephemeris::ephemeris_common::EphemerisBase,
+        target_ras: &[f64],
+        target_decs: &[f64],
+    ) -> Result<Vec<bool>, ConstraintError> {
+        Ok(self.polygon.contains_many(target_ras, target_decs))
+    }
+
+    fn name(&self) -> String {
+        self.format_name()
+    }
+
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_new_validates() {
+        let polygon = [(350.0, -5.0), (10.0, -5.0), (10.0, 5.0), (350.0, 5.0)];
+        assert!(SkyRegionConfig::new(&polygon).is_ok());
+        assert!(SkyRegionConfig::new(&[(0.0, 0.0), (10.0, 0.0)]).is_err());
+        let json = r#"{"polygon": [[0, 0], [120, 0], [240, 0]]}"#;
+        assert!(serde_json::from_str::<SkyRegionConfig>(json).is_err());
+    }
+
+    #[test]
+    fn test_name_and_containment() {
+        let polygon = [(350.0, -5.0), (10.0, -5.0), (10.0, 5.0), (350.0, 5.0)];
+        let evaluator = SkyRegionEvaluator {
+            polygon: SphericalPolygon::new(&polygon).unwrap(),
+        };
+        assert_eq!(evaluator.name(), "SkyRegionConstraint(vertices=4)");
+        assert_eq!(
+            evaluator
+                .polygon
+                .contains_many(&[0.0, 355.0, 20.0], &[0.0, 4.0, 0.0]),
+            [true, true, false]
+        );
+    }
+}
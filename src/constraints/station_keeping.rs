@@ -0,0 +1,269 @@
+use super::core::{
+    time_only_diagonal, track_violations, ConstraintConfig, ConstraintEvaluator, ConstraintResult,
+};
+/// Station-keeping box constraint implementation
+///
+/// Violated while the sub-satellite point leaves a longitude/latitude
+/// deadband box, as used to monitor a geostationary satellite against its
+/// assigned slot. Longitude and latitude are geocentric, from the ITRS
+/// position. The check does not depend on the target, so like the SAA
+/// constraint it is a pure function of time.
+use crate::constraints::error::{ConstraintError, OrMissing};
+use crate::ephemeris::ephemeris_common::EphemerisBase;
+use chrono::{DateTime, Utc};
+use ndarray::Array2;
+use serde::{Deserialize, Serialize};
+
+/// Configuration for the station-keeping box constraint
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct StationKeepingConfig {
+    /// Box centre longitude in degrees (-180 to 360)
+    pub longitude: f64,
+    /// Box centre latitude in degrees
+    pub latitude: f64,
+    /// Half-width of the box in longitude, degrees
+    pub longitude_tolerance: f64,
+    /// Half-width of the box in latitude, degrees
+    pub latitude_tolerance: f64,
+}
+
+impl StationKeepingConfig {
+    /// Check the box centre and half-widths
+    pub fn validate(&self) -> Result<(), String> {
+        if !(-180.0..=360.0).contains(&self.longitude) {
+            return Err("longitude must be between -180 and 360 degrees".to_string());
+        }
+        if !(-90.0..=90.0).contains(&self.latitude) {
+            return Err("latitude must be between -90 and 90 degrees".to_string());
+        }
+        if !(self.longitude_tolerance > 0.0 && self.longitude_tolerance <= 180.0) {
+            return Err("longitude_tolerance must be between 0 and 180 degrees".to_string());
+        }
+        if !(self.latitude_tolerance > 0.0 && self.latitude_tolerance <= 90.0) {
+            return Err("latitude_tolerance must be between 0 and 90 degrees".to_string());
+        }
+        Ok(())
+    }
+}
+
+impl ConstraintConfig for StationKeepingConfig {
+    fn to_evaluator(&self) -> Box<dyn ConstraintEvaluator> {
+        Box::new(StationKeepingEvaluator {
+            longitude: wrap_longitude(self.longitude),
+            latitude: self.latitude,
+            longitude_tolerance: self.longitude_tolerance,
+            latitude_tolerance: self.latitude_tolerance,
+        })
+    }
+}
+
+/// Wrap a longitude or longitude difference into [-180, 180)
+fn wrap_longitude(degrees: f64) -> f64 {
+    (degrees + 180.0).rem_euclid(360.0) - 180.0
+}
+
+/// Evaluator for the station-keeping box constraint
+pub struct StationKeepingEvaluator {
+    longitude: f64,
+    latitude: f64,
+    longitude_tolerance: f64,
+    latitude_tolerance: f64,
+}
+
+impl StationKeepingEvaluator {
+    fn format_name(&self) -> String {
+        format!(
+            "StationKeepingConstraint(lon={:.3}°±{:.3}°, lat={:.3}°±{:.3}°)",
+            self.longitude, self.longitude_tolerance, self.latitude, self.latitude_tolerance
+        )
+    }
+
+    /// Geocentric (longitude, latitude) in degrees at the selected ephemeris times
+    fn sub_satellite_points(
+        ephemeris: &dyn EphemerisBase,
+        time_indices: Option<&[usize]>,
+    ) -> Result<Vec<(f64, f64)>, ConstraintError> {
+        let itrs = ephemeris
+            .get_itrs_data()
+            .ok_or_else(|| ConstraintError::invalid_input("No ITRS positions available"))?;
+        let point = |i: usize| {
+            let (x, y, z) = (itrs[[i, 0]], itrs[[i, 1]], itrs[[i, 2]]);
+            let lon = y.atan2(x).to_degrees();
+            let lat = z.atan2((x * x + y * y).sqrt()).to_degrees();
+            (lon, lat)
+        };
+        Ok(match time_indices {
+            Some(indices) => indices.iter().map(|&i| point(i)).collect(),
+            None => (0..itrs.nrows()).map(point).collect(),
+        })
+    }
+
+    /// Offsets from the box centre in degrees, (longitude, latitude)
+    fn offsets(&self, (lon, lat): (f64, f64)) -> (f64, f64) {
+        (wrap_longitude(lon - self.longitude), lat - self.latitude)
+    }
+
+    /// Worst excess beyond the box as a fraction of the half-width, 0 inside
+    fn excess(&self, point: (f64, f64)) -> f64 {
+        let (dlon, dlat) = self.offsets(point);
+        let lon_excess = (dlon.abs() - self.longitude_tolerance) / self.longitude_tolerance;
+        let lat_excess = (dlat.abs() - self.latitude_tolerance) / self.latitude_tolerance;
+        lon_excess.max(lat_excess).max(0.0)
+    }
+
+    fn describe(&self, point: (f64, f64)) -> String {
+        let (lon, lat) = point;
+        let (dlon, dlat) = self.offsets(point);
+        let mut reasons = Vec::new();
+        if dlon.abs() > self.longitude_tolerance {
+            reasons.push(format!(
+                "longitude {:.3}° is {:+.3}° from {:.3}° (tolerance {:.3}°)",
+                lon, dlon, self.longitude, self.longitude_tolerance
+            ));
+        }
+        if dlat.abs() > self.latitude_tolerance {
+            reasons.push(format!(
+                "latitude {:.3}° is {:+.3}° from {:.3}° (tolerance {:.3}°)",
+                lat, dlat, self.latitude, self.latitude_tolerance
+            ));
+        }
+        format!("Outside station-keeping box: {}", reasons.join(", "))
+    }
+
+    /// Evaluate the constraint with pre-computed sub-satellite points
+    pub fn evaluate_with_points(
+        &self,
+        times: &[DateTime<Utc>],
+        points: &[(f64, f64)],
+    ) -> ConstraintResult {
+        let violations = track_violations(
+            times,
+            |i| {
+                let excess = self.excess(points[i]);
+                (excess > 0.0, excess.min(1.0))
+            },
+            |i, _| self.describe(points[i]),
+        );
+
+        let all_satisfied = violations.is_empty();
+        ConstraintResult::new(
+            violations,
+            all_satisfied,
+            self.format_name(),
+            times.to_vec(),
+        )
+    }
+}
+
+impl ConstraintEvaluator for StationKeepingEvaluator {
+    fn evaluate(
+        &self,
+        ephemeris: &dyn EphemerisBase,
+        _target_ra: f64,
+        _target_dec: f64,
+        time_indices: Option<&[usize]>,
+    ) -> Result<ConstraintResult, ConstraintError> {
+        let times = ephemeris.get_times().or_missing("times")?;
+        let times_filtered: Vec<DateTime<Utc>> = match time_indices {
+            Some(indices) => indices.iter().map(|&i| times[i]).collect(),
+            None => times,
+        };
+        let points = Self::sub_satellite_points(ephemeris, time_indices)?;
+        Ok(self.evaluate_with_points(&times_filtered, &points))
+    }
+
+    fn in_constraint_batch(
+        &self,
+        ephemeris: &dyn EphemerisBase,
+        target_ras: &[f64],
+        _target_decs: &[f64],
+        time_indices: Option<&[usize]>,
+    ) -> Result<Array2<bool>, ConstraintError> {
+        let points = Self::sub_satellite_points(ephemeris, time_indices)?;
+        Ok(Array2::from_shape_fn(
+            (target_ras.len(), points.len()),
+            |(_, i)| self.excess(points[i]) > 0.0,
+        ))
+    }
+
+    fn in_constraint_batch_diagonal(
+        &self,
+        ephemeris: &dyn EphemerisBase,
+        target_ras: &[f64],
+        _target_decs: &[f64],
+    ) -> Result<Vec<bool>, ConstraintError> {
+        time_only_diagonal(
+            target_ras.len(),
+            |indices| Self::sub_satellite_points(ephemeris, indices),
+            |&point| self.excess(point) > 0.0,
+        )
+    }
+
+    fn name(&self) -> String {
+        self.format_name()
+    }
+
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::{Duration, TimeZone};
+
+    fn evaluator(longitude: f64) -> StationKeepingEvaluator {
+        StationKeepingEvaluator {
+            longitude: wrap_longitude(longitude),
+            latitude: 0.0,
+            longitude_tolerance: 0.1,
+            latitude_tolerance: 0.05,
+        }
+    }
+
+    #[test]
+    fn test_validate() {
+        let config = StationKeepingConfig {
+            longitude: 285.0,
+            latitude: 0.0,
+            longitude_tolerance: 0.1,
+            latitude_tolerance: 0.1,
+        };
+        assert!(config.validate().is_ok());
+        assert!(StationKeepingConfig {
+            longitude_tolerance: 0.0,
+            ..config.clone()
+        }
+        .validate()
+        .is_err());
+        assert!(StationKeepingConfig {
+            longitude: 400.0,
+            ..config
+        }
+        .validate()
+        .is_err());
+    }
+
+    #[test]
+    fn test_box_wraps_antimeridian() {
+        let eval = evaluator(180.0);
+        assert_eq!(eval.excess((-179.95, 0.0)), 0.0);
+        assert_eq!(eval.excess((179.95, 0.0)), 0.0);
+        assert!((eval.excess((179.8, 0.0)) - 1.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_evaluate_with_points() {
+        let eval = evaluator(-75.0);
+        let start = Utc.with_ymd_and_hms(2024, 1, 1, 0, 0, 0).unwrap();
+        let times: Vec<DateTime<Utc>> = (0..4).map(|i| start + Duration::hours(i)).collect();
+        let points = [(-75.0, 0.0), (-75.05, 0.08), (-74.85, 0.0), (-75.02, 0.01)];
+        let result = eval.evaluate_with_points(&times, &points);
+
+        assert_eq!(result.violations.len(), 1);
+        assert_eq!(result.violations[0].start_time_internal, times[1]);
+        assert_eq!(result.violations[0].end_time_internal, times[2]);
+        assert!(result.violations[0].description.contains("latitude"));
+    }
+}
@@ -0,0 +1,403 @@
+use super::core::{track_violations, ConstraintConfig, ConstraintEvaluator, ConstraintResult};
+/// Sun keep-out constraint with separate limits per spacecraft-frame quadrant
+///
+/// Many observatories do not avoid the Sun with a single cone: baffles,
+/// radiators and solar arrays give a keep-out map whose extent depends on
+/// which side of the boresight the Sun lies. This constraint uses the
+/// boresight frame of the boresight offset constraint: +X is the boresight,
+/// +Z is the roll reference (the Sun or celestial north) projected
+/// perpendicular to +X and rotated by the roll angle, and +Y = Z × X. The
+/// Sun's direction around the boresight picks one of four sectors, each with
+/// its own minimum Sun angle:
+///
+/// - `pitch_plus`: Sun toward -Z, the side a positive pitch moves the boresight to
+/// - `pitch_minus`: Sun toward +Z
+/// - `yaw_plus`: Sun toward +Y, the side a positive yaw moves the boresight to
+/// - `yaw_minus`: Sun toward -Y
+///
+/// The pitch sectors span ±45° around the Z axis and the yaw sectors ±45°
+/// around the Y axis. Without a fixed roll the attitude law is free to turn
+/// the least restrictive sector toward the Sun, so the smallest limit applies.
+use crate::constraints::error::ConstraintError;
+use crate::utils::vector_math::{cross_product, dot_product, radec_to_unit_vectors_batch};
+use chrono::{DateTime, Utc};
+use ndarray::Array2;
+use serde::{Deserialize, Serialize};
+
+/// Threshold below which a vector norm is treated as zero
+const NEAR_ZERO: f64 = 1.0e-12;
+
+/// Direction that defines roll = 0 for the keep-out sectors
+#[derive(Debug, Clone, Copy, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum KeepOutRollReference {
+    /// +Z toward the Sun, as for a Sun-pointing attitude law
+    #[default]
+    Sun,
+    /// +Z toward celestial north
+    North,
+}
+
+/// Configuration for the Sun keep-out constraint
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SunKeepOutConfig {
+    /// Minimum Sun angle in degrees with the Sun in the +pitch sector (toward -Z)
+    pub pitch_plus: f64,
+    /// Minimum Sun angle in degrees with the Sun in the -pitch sector (toward +Z)
+    pub pitch_minus: f64,
+    /// Minimum Sun angle in degrees with the Sun in the +yaw sector (toward +Y)
+    pub yaw_plus: f64,
+    /// Minimum Sun angle in degrees with the Sun in the -yaw sector (toward -Y)
+    pub yaw_minus: f64,
+    /// Spacecraft roll in degrees about the boresight; free when omitted
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub roll_deg: Option<f64>,
+    /// Direction that defines roll = 0
+    #[serde(default)]
+    pub roll_reference: KeepOutRollReference,
+}
+
+impl SunKeepOutConfig {
+    /// Check the sector limits and roll
+    pub fn validate(&self) -> Result<(), String> {
+        let limits = [
+            ("pitch_plus", self.pitch_plus),
+            ("pitch_minus", self.pitch_minus),
+            ("yaw_plus", self.yaw_plus),
+            ("yaw_minus", self.yaw_minus),
+        ];
+        for (name, limit) in limits {
+            if !(0.0..=180.0).contains(&limit) {
+                return Err(format!("{name} must be between 0 and 180 degrees"));
+            }
+        }
+        if self.roll_deg.is_some_and(|roll| !roll.is_finite()) {
+            return Err("roll_deg must be a finite number when provided".to_string());
+        }
+        Ok(())
+    }
+}
+
+impl ConstraintConfig for SunKeepOutConfig {
+    fn to_evaluator(&self) -> Box<dyn ConstraintEvaluator> {
+        Box::new(SunKeepOutEvaluator {
+            pitch_plus: self.pitch_plus,
+            pitch_minus: self.pitch_minus,
+            yaw_plus: self.yaw_plus,
+            yaw_minus: self.yaw_minus,
+            roll: self.roll_deg.map(|roll| roll.to_radians().sin_cos()),
+            roll_reference: self.roll_reference,
+        })
+    }
+}
+
+/// Keep-out sector the Sun lies in
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Sector {
+    PitchPlus,
+    PitchMinus,
+    YawPlus,
+    YawMinus,
+    /// Roll is free, so the least restrictive sector is turned to the Sun
+    AnyRoll,
+    /// Sun on the boresight axis, where every sector meets
+    OnAxis,
+}
+
+impl Sector {
+    fn label(self) -> &'static str {
+        match self {
+            Sector::PitchPlus => "+pitch",
+            Sector::PitchMinus => "-pitch",
+            Sector::YawPlus => "+yaw",
+            Sector::YawMinus => "-yaw",
+            Sector::AnyRoll => "least restrictive",
+            Sector::OnAxis => "on-axis",
+        }
+    }
+}
+
+/// Evaluator for the Sun keep-out constraint
+struct SunKeepOutEvaluator {
+    pitch_plus: f64,
+    pitch_minus: f64,
+    yaw_plus: f64,
+    yaw_minus: f64,
+    /// (sin, cos) of the roll angle, or None when roll is free
+    roll: Option<(f64, f64)>,
+    roll_reference: KeepOutRollReference,
+}
+
+impl SunKeepOutEvaluator {
+    fn format_name(&self) -> String {
+        let limits = format!(
+            "SunKeepOut(pitch=+{}°/-{}°, yaw=+{}°/-{}°",
+            self.pitch_plus, self.pitch_minus, self.yaw_plus, self.yaw_minus
+        );
+        match self.roll {
+            Some((sin_roll, cos_roll)) => format!(
+                "{}, roll={:.1}° from {})",
+                limits,
+                sin_roll.atan2(cos_roll).to_degrees(),
+                match self.roll_reference {
+                    KeepOutRollReference::Sun => "Sun",
+                    KeepOutRollReference::North => "north",
+                }
+            ),
+            None => format!("{})", limits),
+        }
+    }
+
+    /// Sector the Sun lies in and its minimum Sun angle (degrees), for a
+    /// boresight and Sun direction given as unit vectors
+    fn sector_limit(&self, boresight: &[f64; 3], sun: &[f64; 3]) -> (Sector, f64) {
+        let Some((sin_roll, cos_roll)) = self.roll else {
+            let limit = self
+                .pitch_plus
+                .min(self.pitch_minus)
+                .min(self.yaw_plus)
+                .min(self.yaw_minus);
+            return (Sector::AnyRoll, limit);
+        };
+        let on_axis = (
+            Sector::OnAxis,
+            self.pitch_plus
+                .max(self.pitch_minus)
+                .max(self.yaw_plus)
+                .max(self.yaw_minus),
+        );
+
+        let reference = match self.roll_reference {
+            KeepOutRollReference::Sun => *sun,
+            KeepOutRollReference::North => [0.0, 0.0, 1.0],
+        };
+        let z_axis = match perpendicular_unit(&reference, boresight) {
+            Some(z_axis) => z_axis,
+            // Sun reference: the Sun is on the boresight axis
+            None if self.roll_reference == KeepOutRollReference::Sun => return on_axis,
+            // North reference at a celestial pole: fall back to a fixed axis
+            None => match perpendicular_unit(&[0.0, 1.0, 0.0], boresight) {
+                Some(z_axis) => z_axis,
+                None => return on_axis,
+            },
+        };
+        let y_axis = cross_product(&z_axis, boresight);
+
+        // Sun components along the rolled +Y and +Z axes
+        let (sun_y, sun_z) = (dot_product(sun, &y_axis), dot_product(sun, &z_axis));
+        let y = sun_y * cos_roll + sun_z * sin_roll;
+        let z = -sun_y * sin_roll + sun_z * cos_roll;
+        if y.abs() <= NEAR_ZERO && z.abs() <= NEAR_ZERO {
+            return on_axis;
+        }
+        if z.abs() >= y.abs() {
+            if z < 0.0 {
+                (Sector::PitchPlus, self.pitch_plus)
+            } else {
+                (Sector::PitchMinus, self.pitch_minus)
+            }
+        } else if y > 0.0 {
+            (Sector::YawPlus, self.yaw_plus)
+        } else {
+            (Sector::YawMinus, self.yaw_minus)
+        }
+    }
+
+    /// Whether the Sun is inside the keep-out region, with the Sun angle,
+    /// sector and sector limit in degrees
+    fn check(&self, boresight: &[f64; 3], sun: &[f64; 3]) -> (bool, f64, Sector, f64) {
+        let (sector, limit) = self.sector_limit(boresight, sun);
+        let angle = dot_product(boresight, sun)
+            .clamp(-1.0, 1.0)
+            .acos()
+            .to_degrees();
+        (angle < limit, angle, sector, limit)
+    }
+}
+
+/// Unit vector along the part of `v` perpendicular to unit vector `axis`
+fn perpendicular_unit(v: &[f64; 3], axis: &[f64; 3]) -> Option<[f64; 3]> {
+    let along = dot_product(v, axis);
+    let perp = [
+        v[0] - along * axis[0],
+        v[1] - along * axis[1],
+        v[2] - along * axis[2],
+    ];
+    let norm = dot_product(&perp, &perp).sqrt();
+    (norm > NEAR_ZERO).then(|| [perp[0] / norm, perp[1] / norm, perp[2] / norm])
+}
+
+/// Unit vector from the observer to the Sun at one row of the filtered arrays
+fn sun_unit(sun_positions: &Array2<f64>, obs_positions: &Array2<f64>, i: usize) -> [f64; 3] {
+    let rel = [
+        sun_positions[[i, 0]] - obs_positions[[i, 0]],
+        sun_positions[[i, 1]] - obs_positions[[i, 1]],
+        sun_positions[[i, 2]] - obs_positions[[i, 2]],
+    ];
+    let norm = dot_product(&rel, &rel).sqrt();
+    [rel[0] / norm, rel[1] / norm, rel[2] / norm]
+}
+
+impl ConstraintEvaluator for SunKeepOutEvaluator {
+    fn evaluate(
+        &self,
+        ephemeris: &dyn crate::ephemeris::ephemeris_common::EphemerisBase,
+        target_ra: f64,
+        target_dec: f64,
+        time_indices: Option<&[usize]>,
+    ) -> Result<ConstraintResult, ConstraintError> {
+        let (times_filtered, sun_filtered, obs_filtered) =
+            extract_standard_ephemeris_data!(ephemeris, time_indices);
+        let boresight = crate::utils::vector_math::radec_to_unit_vector(target_ra, target_dec);
+
+        let violations = track_violations(
+            &times_filtered,
+            |i| {
+                let sun = sun_unit(&sun_filtered, &obs_filtered, i);
+                let (violated, angle, _, limit) = self.check(&boresight, &sun);
+                (violated, (limit - angle).min(1.0))
+            },
+            |i, violated| {
+                if !violated {
+                    return "".to_string();
+                }
+                let sun = sun_unit(&sun_filtered, &obs_filtered, i);
+                let (_, angle, sector, limit) = self.check(&boresight, &sun);
+                format!(
+                    "Sun in {} keep-out sector ({:.1}° < {:.1}° minimum)",
+                    sector.label(),
+                    angle,
+                    limit
+                )
+            },
+        );
+
+        let all_satisfied = violations.is_empty();
+        Ok(ConstraintResult::new(
+            violations,
+            all_satisfied,
+            self.format_name(),
+            times_filtered,
+        ))
+    }
+
+    fn in_constraint_batch(
+        &self,
+        ephemeris: &dyn crate::ephemeris::ephemeris_common::EphemerisBase,
+        target_ras: &[f64],
+        target_decs: &[f64],
+        time_indices: Option<&[usize]>,
+    ) -> Result<Array2<bool>, ConstraintError> {
+        let (times_filtered, sun_filtered, obs_filtered) =
+            extract_standard_ephemeris_data!(ephemeris, time_indices);
+        if target_ras.len() != target_decs.len() {
+            return Err(ConstraintError::invalid_input(
+                "target_ras and target_decs must have the same length",
+            ));
+        }
+
+        let n_targets = target_ras.len();
+        let n_times = times_filtered.len();
+        let target_vectors = radec_to_unit_vectors_batch(target_ras, target_decs);
+        let suns: Vec<[f64; 3]> = (0..n_times)
+            .map(|i| sun_unit(&sun_filtered, &obs_filtered, i))
+            .collect();
+
+        let mut result = Array2::from_elem((n_targets, n_times), false);
+        for j in 0..n_targets {
+            let boresight = [
+                target_vectors[[j, 0]],
+                target_vectors[[j, 1]],
+                target_vectors[[j, 2]],
+            ];
+            for (i, sun) in suns.iter().enumerate() {
+                result[[j, i]] = self.check(&boresight, sun).0;
+            }
+        }
+        Ok(result)
+    }
+
+    fn name(&self) -> String {
+        self.format_name()
+    }
+
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn evaluator(
+        roll_deg: Option<f64>,
+        roll_reference: KeepOutRollReference,
+    ) -> SunKeepOutEvaluator {
+        SunKeepOutEvaluator {
+            pitch_plus: 40.0,
+            pitch_minus: 90.0,
+            yaw_plus: 60.0,
+            yaw_minus: 70.0,
+            roll: roll_deg.map(|roll: f64| roll.to_radians().sin_cos()),
+            roll_reference,
+        }
+    }
+
+    /// Unit vector `angle_deg` from +X toward the direction `toward`
+    fn offset(angle_deg: f64, toward: [f64; 3]) -> [f64; 3] {
+        let (s, c) = angle_deg.to_radians().sin_cos();
+        [c + s * toward[0], s * toward[1], s * toward[2]]
+    }
+
+    #[test]
+    fn test_north_reference_sectors() {
+        let eval = evaluator(Some(0.0), KeepOutRollReference::North);
+        let boresight = [1.0, 0.0, 0.0];
+        // At RA 0, Dec 0 the north reference puts +Z on celestial north and
+        // +Y = Z × X on +y
+        let cases = [
+            ([0.0, 0.0, -1.0], Sector::PitchPlus, 40.0),
+            ([0.0, 0.0, 1.0], Sector::PitchMinus, 90.0),
+            ([0.0, 1.0, 0.0], Sector::YawPlus, 60.0),
+            ([0.0, -1.0, 0.0], Sector::YawMinus, 70.0),
+        ];
+        for (toward, sector, limit) in cases {
+            let sun = offset(50.0, toward);
+            assert_eq!(eval.sector_limit(&boresight, &sun), (sector, limit));
+        }
+        // 50° off-axis clears the +pitch limit but not the -pitch one
+        assert!(!eval.check(&boresight, &offset(50.0, [0.0, 0.0, -1.0])).0);
+        assert!(eval.check(&boresight, &offset(50.0, [0.0, 0.0, 1.0])).0);
+    }
+
+    #[test]
+    fn test_roll_turns_sectors() {
+        // Rolling by 90° turns +Y onto the unrolled +Z axis
+        let eval = evaluator(Some(90.0), KeepOutRollReference::North);
+        let sun = offset(50.0, [0.0, 0.0, 1.0]);
+        assert_eq!(
+            eval.sector_limit(&[1.0, 0.0, 0.0], &sun),
+            (Sector::YawPlus, 60.0)
+        );
+    }
+
+    #[test]
+    fn test_sun_reference_and_free_roll() {
+        let sun = offset(50.0, [0.0, 1.0, 0.0]);
+        let boresight = [1.0, 0.0, 0.0];
+        // Sun-referenced frame at zero roll always has the Sun toward +Z
+        let eval = evaluator(Some(0.0), KeepOutRollReference::Sun);
+        assert_eq!(
+            eval.sector_limit(&boresight, &sun),
+            (Sector::PitchMinus, 90.0)
+        );
+        // On the boresight every sector meets, so the widest limit applies
+        assert_eq!(
+            eval.sector_limit(&boresight, &boresight),
+            (Sector::OnAxis, 90.0)
+        );
+        let free = evaluator(None, KeepOutRollReference::Sun);
+        assert_eq!(free.sector_limit(&boresight, &sun), (Sector::AnyRoll, 40.0));
+    }
+}
@@ -1,9 +1,11 @@
-/// Sun proximity constraint implementation
 use super::core::{track_violations, ConstraintConfig, ConstraintEvaluator, ConstraintResult};
+use super::threshold_schedule::ThresholdPeriod;
+/// Sun proximity constraint implementation
+use crate::constraints::error::{ConstraintError, OrMissing};
+use crate::utils::body_properties::sun_radii;
 use crate::utils::vector_math::radec_to_unit_vectors_batch;
 use chrono::{DateTime, Utc};
 use ndarray::Array2;
-use pyo3::PyResult;
 use serde::{Deserialize, Serialize};
 
 /// Configuration for Sun proximity constraint
@@ -14,13 +16,23 @@ pub struct SunProximityConfig {
     /// Maximum allowed angular separation from Sun in degrees (optional)
     #[serde(skip_serializing_if = "Option::is_none")]
     pub max_angle: Option<f64>,
+    /// Apply the limits to the separation from the Sun's limb instead of its centre
+    #[serde(default)]
+    pub limb_relative: bool,
+    /// Periods overriding the limits, e.g. a wider angle during anneals
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub schedule: Vec<ThresholdPeriod>,
 }
 
+impl_proximity_builder!(SunProximityConfig, SunProximityBuilder);
+
 impl ConstraintConfig for SunProximityConfig {
     fn to_evaluator(&self) -> Box<dyn ConstraintEvaluator> {
         Box::new(SunProximityEvaluator {
             min_angle_deg: self.min_angle,
             max_angle_deg: self.max_angle,
+            schedule: self.schedule.clone(),
+            limb_radius_km: self.limb_relative.then(|| sun_radii().equatorial),
         })
     }
 }
@@ -29,6 +41,9 @@ impl ConstraintConfig for SunProximityConfig {
 struct SunProximityEvaluator {
     min_angle_deg: f64,
     max_angle_deg: Option<f64>,
+    schedule: Vec<ThresholdPeriod>,
+    /// Sun radius in km when the limits apply to its limb
+    limb_radius_km: Option<f64>,
 }
 
 impl_proximity_evaluator!(SunProximityEvaluator, "Sun", "Sun", sun_positions);
@@ -54,9 +69,19 @@ impl SunProximityEvaluator {
     }
 
     fn format_name(&self) -> String {
-        match self.max_angle_deg {
-            Some(max) => format!("SunProximity(min={}°, max={}°)", self.min_angle_deg, max),
-            None => format!("SunProximity(min={}°)", self.min_angle_deg),
+        let limits = match self.max_angle_deg {
+            Some(max) => format!("SunProximity(min={}°, max={}°", self.min_angle_deg, max),
+            None => format!("SunProximity(min={}°", self.min_angle_deg),
+        };
+        let limits = if self.schedule.is_empty() {
+            limits
+        } else {
+            format!("{}, scheduled", limits)
+        };
+        if self.limb_radius_km.is_some() {
+            format!("{}, limb)", limits)
+        } else {
+            format!("{})", limits)
         }
     }
 }
@@ -68,7 +93,7 @@ impl ConstraintEvaluator for SunProximityEvaluator {
         target_ra: f64,
         target_dec: f64,
         time_indices: Option<&[usize]>,
-    ) -> PyResult<ConstraintResult> {
+    ) -> Result<ConstraintResult, ConstraintError> {
         let (times_filtered, sun_filtered, obs_filtered) =
             extract_standard_ephemeris_data!(ephemeris, time_indices);
 
@@ -89,13 +114,13 @@ impl ConstraintEvaluator for SunProximityEvaluator {
         target_ras: &[f64],
         target_decs: &[f64],
         time_indices: Option<&[usize]>,
-    ) -> PyResult<Array2<bool>> {
+    ) -> Result<Array2<bool>, ConstraintError> {
         // Extract and filter ephemeris data
         let (times_filtered, sun_filtered, obs_filtered) =
             extract_standard_ephemeris_data!(ephemeris, time_indices);
         // Validate inputs
         if target_ras.len() != target_decs.len() {
-            return Err(pyo3::exceptions::PyValueError::new_err(
+            return Err(ConstraintError::invalid_input(
                 "target_ras and target_decs must have the same length",
             ));
         }
@@ -109,11 +134,6 @@ impl ConstraintEvaluator for SunProximityEvaluator {
         // Initialize result array: false = not violated (constraint satisfied)
         let mut result = Array2::from_elem((n_targets, n_times), false);
 
-        // Pre-compute cosine thresholds (avoids acos() in inner loop)
-        // For angle comparison: angle < threshold ⟺ cos(angle) > cos(threshold)
-        let min_cos_threshold = self.min_angle_deg.to_radians().cos();
-        let max_cos_threshold = self.max_angle_deg.map(|max| max.to_radians().cos());
-
         // For each time, check all targets
         for t in 0..n_times {
             let sun_pos = [
@@ -141,6 +161,8 @@ impl ConstraintEvaluator for SunProximityEvaluator {
                 sun_rel[1] / sun_dist,
                 sun_rel[2] / sun_dist,
             ];
+            let (min_cos_threshold, max_cos_threshold) =
+                self.cos_thresholds(&times_filtered[t], sun_dist);
 
             // Check all targets at this time
             for target_idx in 0..n_targets {
@@ -172,9 +194,9 @@ impl ConstraintEvaluator for SunProximityEvaluator {
         ephemeris: &dyn crate::ephemeris::ephemeris_common::EphemerisBase,
         target_unit_vectors: &Array2<f64>,
         time_indices: Option<&[usize]>,
-    ) -> PyResult<Option<Array2<bool>>> {
+    ) -> Result<Option<Array2<bool>>, ConstraintError> {
         if target_unit_vectors.ncols() != 3 {
-            return Err(pyo3::exceptions::PyValueError::new_err(
+            return Err(ConstraintError::invalid_input(
                 "target_unit_vectors must have shape (N, 3)",
             ));
         }
@@ -186,9 +208,6 @@ impl ConstraintEvaluator for SunProximityEvaluator {
         let n_times = times_filtered.len();
         let mut result = Array2::from_elem((n_targets, n_times), false);
 
-        let min_cos_threshold = self.min_angle_deg.to_radians().cos();
-        let max_cos_threshold = self.max_angle_deg.map(|max| max.to_radians().cos());
-
         for t in 0..n_times {
             let sun_pos = [
                 sun_filtered[[t, 0]],
@@ -214,6 +233,8 @@ impl ConstraintEvaluator for SunProximityEvaluator {
                 sun_rel[1] / sun_dist,
                 sun_rel[2] / sun_dist,
             ];
+            let (min_cos_threshold, max_cos_threshold) =
+                self.cos_thresholds(&times_filtered[t], sun_dist);
 
             for target_idx in 0..n_targets {
                 let cos_angle = target_unit_vectors[[target_idx, 0]] * sun_unit[0]
@@ -238,19 +259,22 @@ impl ConstraintEvaluator for SunProximityEvaluator {
         ephemeris: &dyn crate::ephemeris::ephemeris_common::EphemerisBase,
         target_ras: &[f64],
         target_decs: &[f64],
-    ) -> PyResult<Vec<bool>> {
+    ) -> Result<Vec<bool>, ConstraintError> {
         let n = target_ras.len();
         if n == 0 {
             return Ok(Vec::new());
         }
 
         // Get ephemeris data
-        let sun_positions = ephemeris.get_sun_positions()?;
-        let observer_positions = ephemeris.get_gcrs_positions()?;
+        let sun_positions = ephemeris.get_sun_positions().or_missing("Sun positions")?;
+        let observer_positions = ephemeris
+            .get_gcrs_positions()
+            .or_missing("GCRS positions")?;
+        let times = ephemeris.get_times().or_missing("times")?;
 
         // Ensure we have enough time steps
-        if sun_positions.nrows() < n || observer_positions.nrows() < n {
-            return Err(pyo3::exceptions::PyValueError::new_err(
+        if sun_positions.nrows() < n || observer_positions.nrows() < n || times.len() < n {
+            return Err(ConstraintError::invalid_input(
                 "Not enough ephemeris time steps for diagonal evaluation",
             ));
         }
@@ -258,11 +282,6 @@ impl ConstraintEvaluator for SunProximityEvaluator {
         // Convert all target RA/Dec to unit vectors
         let target_vectors = radec_to_unit_vectors_batch(target_ras, target_decs);
 
-        // Pre-compute cosine thresholds (avoids acos() in inner loop)
-        // For angle comparison: angle < threshold ⟺ cos(angle) > cos(threshold)
-        let min_cos_threshold = self.min_angle_deg.to_radians().cos();
-        let max_cos_threshold = self.max_angle_deg.map(|max| max.to_radians().cos());
-
         // Evaluate only diagonal elements: target_i at time_i
         let mut result = Vec::with_capacity(n);
 
@@ -292,6 +311,7 @@ impl ConstraintEvaluator for SunProximityEvaluator {
                 sun_rel[1] / sun_dist,
                 sun_rel[2] / sun_dist,
             ];
+            let (min_cos_threshold, max_cos_threshold) = self.cos_thresholds(&times[i], sun_dist);
 
             // Get target vector at this index
             let target_vec = [
@@ -316,6 +336,23 @@ impl ConstraintEvaluator for SunProximityEvaluator {
         Ok(result)
     }
 
+    /// Separation from the Sun in degrees
+    fn metric(
+        &self,
+        ephemeris: &dyn crate::ephemeris::ephemeris_common::EphemerisBase,
+        target_ra: f64,
+        target_dec: f64,
+        time_indices: Option<&[usize]>,
+    ) -> Result<Option<Vec<f64>>, ConstraintError> {
+        let (_, sun_filtered, obs_filtered) =
+            extract_standard_ephemeris_data!(ephemeris, time_indices);
+        Ok(Some(self.separation_deg(
+            (target_ra, target_dec),
+            &sun_filtered,
+            &obs_filtered,
+        )))
+    }
+
     fn name(&self) -> String {
         self.format_name()
     }
@@ -324,3 +361,48 @@ impl ConstraintEvaluator for SunProximityEvaluator {
         self
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_builder() {
+        let config = SunProximityConfig::builder()
+            .min_angle(45.0)
+            .max_angle(170.0)
+            .build()
+            .unwrap();
+        assert_eq!(config.min_angle, 45.0);
+        assert_eq!(config.max_angle, Some(170.0));
+        assert!(!config.limb_relative);
+        assert_eq!(
+            config.to_evaluator().name(),
+            "SunProximity(min=45°, max=170°)"
+        );
+    }
+
+    #[test]
+    fn test_builder_validates() {
+        let err = SunProximityConfig::builder()
+            .min_angle(90.0)
+            .max_angle(45.0)
+            .build()
+            .unwrap_err();
+        assert_eq!(
+            err,
+            ConstraintError::InvalidInput("max_angle must be greater than min_angle".to_string())
+        );
+        let period = ThresholdPeriod {
+            begin: None,
+            end: None,
+            min_angle: None,
+            max_angle: None,
+        };
+        assert!(SunProximityConfig::builder()
+            .min_angle(45.0)
+            .period(period)
+            .build()
+            .is_err());
+    }
+}
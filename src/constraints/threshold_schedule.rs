@@ -0,0 +1,174 @@
+//! Time-varying angle limits for proximity and limb constraints
+//!
+//! A schedule is an ordered list of periods, each overriding the minimum
+//! and/or maximum angle of a constraint while it is in effect (for example a
+//! wider Sun avoidance angle during detector anneals). A period covers
+//! `[begin, end)`, with a missing bound leaving it open on that side. The
+//! first period containing a time wins; outside every period the
+//! constraint's own limits apply.
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+/// One entry of a threshold schedule
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ThresholdPeriod {
+    /// Start of the period (inclusive); open-ended when omitted
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub begin: Option<DateTime<Utc>>,
+    /// End of the period (exclusive); open-ended when omitted
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub end: Option<DateTime<Utc>>,
+    /// Minimum angle in degrees during the period, if overridden
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub min_angle: Option<f64>,
+    /// Maximum angle in degrees during the period, if overridden
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub max_angle: Option<f64>,
+}
+
+impl ThresholdPeriod {
+    fn contains(&self, time: &DateTime<Utc>) -> bool {
+        self.begin.is_none_or(|begin| *time >= begin) && self.end.is_none_or(|end| *time < end)
+    }
+}
+
+/// Minimum and maximum angle (degrees) in effect at `time`
+pub fn limits_at(
+    schedule: &[ThresholdPeriod],
+    min_angle: f64,
+    max_angle: Option<f64>,
+    time: &DateTime<Utc>,
+) -> (f64, Option<f64>) {
+    match schedule.iter().find(|period| period.contains(time)) {
+        Some(period) => (
+            period.min_angle.unwrap_or(min_angle),
+            period.max_angle.or(max_angle),
+        ),
+        None => (min_angle, max_angle),
+    }
+}
+
+/// Check a constraint's own minimum and optional maximum angle (degrees)
+pub fn validate_angle_limits(min_angle: f64, max_angle: Option<f64>) -> Result<(), String> {
+    if !(0.0..=180.0).contains(&min_angle) {
+        return Err("min_angle must be between 0 and 180 degrees".to_string());
+    }
+    if let Some(max) = max_angle {
+        if !(0.0..=180.0).contains(&max) {
+            return Err("max_angle must be between 0 and 180 degrees".to_string());
+        }
+        if max <= min_angle {
+            return Err("max_angle must be greater than min_angle".to_string());
+        }
+    }
+    Ok(())
+}
+
+/// Check that every period is well formed and leaves `max_angle > min_angle`
+/// once combined with the constraint's own limits
+pub fn validate_schedule(
+    schedule: &[ThresholdPeriod],
+    min_angle: f64,
+    max_angle: Option<f64>,
+) -> Result<(), String> {
+    for (i, period) in schedule.iter().enumerate() {
+        if let (Some(begin), Some(end)) = (period.begin, period.end) {
+            if end <= begin {
+                return Err(format!("schedule period {i}: end must be after begin"));
+            }
+        }
+        if period.min_angle.is_none() && period.max_angle.is_none() {
+            return Err(format!(
+                "schedule period {i}: set min_angle and/or max_angle"
+            ));
+        }
+        for angle in [period.min_angle, period.max_angle].into_iter().flatten() {
+            if !(0.0..=180.0).contains(&angle) {
+                return Err(format!(
+                    "schedule period {i}: angles must be between 0 and 180 degrees"
+                ));
+            }
+        }
+        let min = period.min_angle.unwrap_or(min_angle);
+        if let Some(max) = period.max_angle.or(max_angle) {
+            if max <= min {
+                return Err(format!(
+                    "schedule period {i}: max_angle must be greater than min_angle"
+                ));
+            }
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+
+    fn at(hour: u32) -> DateTime<Utc> {
+        Utc.with_ymd_and_hms(2024, 1, 1, hour, 0, 0).unwrap()
+    }
+
+    fn period(begin: Option<u32>, end: Option<u32>, min_angle: f64) -> ThresholdPeriod {
+        ThresholdPeriod {
+            begin: begin.map(at),
+            end: end.map(at),
+            min_angle: Some(min_angle),
+            max_angle: None,
+        }
+    }
+
+    #[test]
+    fn test_limits_follow_first_matching_period() {
+        let schedule = vec![period(Some(2), Some(4), 60.0), period(Some(3), None, 70.0)];
+        assert_eq!(limits_at(&schedule, 45.0, None, &at(1)), (45.0, None));
+        assert_eq!(limits_at(&schedule, 45.0, None, &at(2)), (60.0, None));
+        assert_eq!(limits_at(&schedule, 45.0, None, &at(3)), (60.0, None));
+        // End is exclusive, so the open-ended period takes over
+        assert_eq!(limits_at(&schedule, 45.0, None, &at(4)), (70.0, None));
+    }
+
+    #[test]
+    fn test_period_keeps_limits_it_does_not_override() {
+        let schedule = vec![ThresholdPeriod {
+            begin: None,
+            end: None,
+            min_angle: None,
+            max_angle: Some(120.0),
+        }];
+        assert_eq!(
+            limits_at(&schedule, 45.0, Some(150.0), &at(0)),
+            (45.0, Some(120.0))
+        );
+    }
+
+    #[test]
+    fn test_validate_schedule() {
+        assert!(validate_schedule(&[period(Some(1), Some(2), 60.0)], 45.0, None).is_ok());
+        assert!(validate_schedule(&[period(Some(2), Some(1), 60.0)], 45.0, None).is_err());
+        assert!(validate_schedule(&[period(None, None, 200.0)], 45.0, None).is_err());
+        assert!(validate_schedule(&[period(None, None, 100.0)], 45.0, Some(90.0)).is_err());
+        let empty = ThresholdPeriod {
+            begin: None,
+            end: None,
+            min_angle: None,
+            max_angle: None,
+        };
+        assert!(validate_schedule(&[empty], 45.0, None).is_err());
+    }
+
+    #[test]
+    fn test_validate_angle_limits() {
+        assert!(validate_angle_limits(45.0, None).is_ok());
+        assert!(validate_angle_limits(45.0, Some(90.0)).is_ok());
+        assert!(validate_angle_limits(-1.0, None).is_err());
+        assert!(validate_angle_limits(f64::NAN, None).is_err());
+        assert!(validate_angle_limits(45.0, Some(200.0)).is_err());
+        assert_eq!(
+            validate_angle_limits(45.0, Some(45.0)),
+            Err("max_angle must be greater than min_angle".to_string())
+        );
+    }
+}
@@ -0,0 +1,221 @@
+//! Virtual targets derived from the observer state
+//!
+//! Common pointing modes (nadir, an Earth limb tangent point, Sun, anti-Sun,
+//! ram and anti-ram) have directions that change along the orbit. They are
+//! resolved here to one ICRS direction per ephemeris time, so they can be
+//! constraint-checked like any moving target.
+
+use ndarray::Array2;
+use pyo3::PyResult;
+
+use crate::ephemeris::ephemeris_common::EphemerisBase;
+use crate::utils::body_properties::earth_radii;
+use crate::utils::vector_math::unit_vectors_to_radec_batch;
+
+/// Pointing direction defined relative to the observer state
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum VirtualTarget {
+    /// Toward the Earth's center
+    Nadir,
+    /// Earth limb tangent point; azimuth in degrees about nadir, 0 toward
+    /// ram and 90 toward the orbit normal (r × v)
+    Limb {
+        azimuth: f64,
+    },
+    Sun,
+    AntiSun,
+    /// Along the observer's GCRS velocity
+    Ram,
+    AntiRam,
+}
+
+impl VirtualTarget {
+    /// Parse a target name; `azimuth` (degrees) is only accepted for "limb"
+    pub fn parse(name: &str, azimuth: Option<f64>) -> Result<Self, String> {
+        let target = match name.trim().to_lowercase().replace('-', "_").as_str() {
+            "nadir" => VirtualTarget::Nadir,
+            "limb" => {
+                let azimuth = azimuth.unwrap_or(0.0);
+                if !azimuth.is_finite() {
+                    return Err("azimuth must be a finite number of degrees".to_string());
+                }
+                return Ok(VirtualTarget::Limb { azimuth });
+            }
+            "sun" => VirtualTarget::Sun,
+            "anti_sun" => VirtualTarget::AntiSun,
+            "ram" => VirtualTarget::Ram,
+            "anti_ram" => VirtualTarget::AntiRam,
+            _ => {
+                return Err(format!(
+                    "Unknown virtual target '{}'; expected 'nadir', 'limb', 'sun', \
+                     'anti_sun', 'ram' or 'anti_ram'",
+                    name
+                ))
+            }
+        };
+        if azimuth.is_some() {
+            return Err("azimuth is only used with the 'limb' target".to_string());
+        }
+        Ok(target)
+    }
+
+    /// ICRS unit vectors toward the target, shape (n_times, 3)
+    pub fn directions(&self, ephemeris: &dyn EphemerisBase) -> PyResult<Array2<f64>> {
+        let observer =
+            ephemeris.data().gcrs.as_ref().ok_or_else(|| {
+                pyo3::exceptions::PyValueError::new_err("Ephemeris has no GCRS data")
+            })?;
+        let needs_velocity = matches!(
+            self,
+            VirtualTarget::Limb { .. } | VirtualTarget::Ram | VirtualTarget::AntiRam
+        );
+        if needs_velocity && observer.ncols() < 6 {
+            return Err(pyo3::exceptions::PyValueError::new_err(
+                "Ephemeris has no velocity data, which ram and limb targets require",
+            ));
+        }
+        let sun = match self {
+            VirtualTarget::Sun | VirtualTarget::AntiSun => Some(ephemeris.get_sun_positions()?),
+            _ => None,
+        };
+
+        let earth_radius = earth_radii().equatorial;
+        let mut directions = Array2::<f64>::zeros((observer.nrows(), 3));
+        for j in 0..observer.nrows() {
+            let r = [observer[[j, 0]], observer[[j, 1]], observer[[j, 2]]];
+            let direction = match self {
+                VirtualTarget::Nadir => scale(r, -1.0),
+                VirtualTarget::Sun | VirtualTarget::AntiSun => {
+                    let sun = sun.as_ref().expect("Sun positions loaded above");
+                    let rel = [sun[[j, 0]] - r[0], sun[[j, 1]] - r[1], sun[[j, 2]] - r[2]];
+                    if *self == VirtualTarget::Sun {
+                        rel
+                    } else {
+                        scale(rel, -1.0)
+                    }
+                }
+                VirtualTarget::Ram => [observer[[j, 3]], observer[[j, 4]], observer[[j, 5]]],
+                VirtualTarget::AntiRam => [-observer[[j, 3]], -observer[[j, 4]], -observer[[j, 5]]],
+                VirtualTarget::Limb { azimuth } => {
+                    let v = [observer[[j, 3]], observer[[j, 4]], observer[[j, 5]]];
+                    limb_direction(r, v, *azimuth, earth_radius).ok_or_else(|| {
+                        pyo3::exceptions::PyValueError::new_err(
+                            "Limb direction is undefined at or below the Earth's surface or without motion across nadir",
+                        )
+                    })?
+                }
+            };
+            let unit = normalize(direction).ok_or_else(|| {
+                pyo3::exceptions::PyValueError::new_err(
+                    "Virtual target direction is undefined (zero vector)",
+                )
+            })?;
+            for (k, c) in unit.iter().enumerate() {
+                directions[[j, k]] = *c;
+            }
+        }
+        Ok(directions)
+    }
+
+    /// Right ascensions and declinations in degrees, one per ephemeris time
+    pub fn radec(&self, ephemeris: &dyn EphemerisBase) -> PyResult<(Vec<f64>, Vec<f64>)> {
+        Ok(unit_vectors_to_radec_batch(&self.directions(ephemeris)?))
+    }
+}
+
+fn scale(v: [f64; 3], s: f64) -> [f64; 3] {
+    [v[0] * s, v[1] * s, v[2] * s]
+}
+
+fn dot(a: [f64; 3], b: [f64; 3]) -> f64 {
+    a[0] * b[0] + a[1] * b[1] + a[2] * b[2]
+}
+
+fn normalize(v: [f64; 3]) -> Option<[f64; 3]> {
+    let norm = dot(v, v).sqrt();
+    if !norm.is_finite() || norm <= 0.0 {
+        return None;
+    }
+    Some(scale(v, 1.0 / norm))
+}
+
+/// Direction of the Earth limb tangent point at `azimuth_deg` about nadir
+///
+/// Azimuth 0 is the ram side (velocity projected perpendicular to nadir) and
+/// 90 the orbit-normal side. `earth_radius` is in km.
+fn limb_direction(
+    r: [f64; 3],
+    v: [f64; 3],
+    azimuth_deg: f64,
+    earth_radius: f64,
+) -> Option<[f64; 3]> {
+    let distance = dot(r, r).sqrt();
+    if distance <= earth_radius {
+        return None;
+    }
+    let nadir = scale(r, -1.0 / distance);
+    let along = normalize([
+        v[0] - dot(v, nadir) * nadir[0],
+        v[1] - dot(v, nadir) * nadir[1],
+        v[2] - dot(v, nadir) * nadir[2],
+    ])?;
+    // along × nadir is the unit orbit normal r × v
+    let normal = [
+        along[1] * nadir[2] - along[2] * nadir[1],
+        along[2] * nadir[0] - along[0] * nadir[2],
+        along[0] * nadir[1] - along[1] * nadir[0],
+    ];
+    let sin_limb = earth_radius / distance;
+    let cos_limb = (1.0 - sin_limb * sin_limb).sqrt();
+    let (sin_az, cos_az) = azimuth_deg.to_radians().sin_cos();
+    Some([
+        cos_limb * nadir[0] + sin_limb * (cos_az * along[0] + sin_az * normal[0]),
+        cos_limb * nadir[1] + sin_limb * (cos_az * along[1] + sin_az * normal[1]),
+        cos_limb * nadir[2] + sin_limb * (cos_az * along[2] + sin_az * normal[2]),
+    ])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse() {
+        assert_eq!(
+            VirtualTarget::parse("Anti-Sun", None),
+            Ok(VirtualTarget::AntiSun)
+        );
+        assert_eq!(
+            VirtualTarget::parse("limb", Some(90.0)),
+            Ok(VirtualTarget::Limb { azimuth: 90.0 })
+        );
+        assert!(VirtualTarget::parse("nadir", Some(10.0)).is_err());
+        assert!(VirtualTarget::parse("zenith", None).is_err());
+    }
+
+    #[test]
+    fn test_limb_direction() {
+        // Observer on +x at twice the Earth radius moving along +y
+        let earth_radius = 6378.137;
+        let r = [2.0 * earth_radius, 0.0, 0.0];
+        let v = [0.0, 7.5, 0.0];
+        // Limb sits 30° from nadir (-x): ram side tilts toward +y, normal side toward +z
+        let ram = limb_direction(r, v, 0.0, earth_radius).unwrap();
+        let expected = [-(3.0f64.sqrt()) / 2.0, 0.5, 0.0];
+        assert!(ram
+            .iter()
+            .zip(&expected)
+            .all(|(a, b)| (a - b).abs() < 1e-12));
+        let normal = limb_direction(r, v, 90.0, earth_radius).unwrap();
+        let expected = [-(3.0f64.sqrt()) / 2.0, 0.0, 0.5];
+        assert!(normal
+            .iter()
+            .zip(&expected)
+            .all(|(a, b)| (a - b).abs() < 1e-12));
+
+        assert_eq!(
+            limb_direction([earth_radius, 0.0, 0.0], v, 0.0, earth_radius),
+            None
+        );
+    }
+}
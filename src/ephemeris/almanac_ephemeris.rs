@@ -0,0 +1,1027 @@
+//! GPS almanac (YUMA/SEM) ephemeris
+//!
+//! Propagates one satellite of a YUMA or SEM almanac with the IS-GPS-200
+//! almanac model, so GNSS constellation geometry can be analysed without first
+//! converting the almanac to TLEs. The model produces WGS 84 Earth-fixed
+//! states, which are stored as ITRS and transformed to GCRS.
+//!
+//! Almanac accuracy is at the kilometre level and degrades away from the time
+//! of applicability; it is intended for visibility and geometry studies.
+
+use chrono::{DateTime, Utc};
+use ndarray::Array2;
+use numpy::IntoPyArray;
+use pyo3::{prelude::*, types::PyDateTime};
+use std::collections::HashMap;
+use std::path::Path;
+use std::sync::OnceLock;
+
+use crate::ephemeris::ephemeris_common::{
+    generate_timestamps, metadata_timestamp, parse_time_scale, EphemerisBase, EphemerisData,
+};
+use crate::ephemeris::ground_access::{self, AccessOpportunity};
+use crate::ephemeris::observation_geometry::{observation_geometry, ObservationGeometry};
+use crate::ephemeris::position_velocity::PositionVelocityData;
+use crate::ephemeris::provenance::{ephemeris_provenance, Provenance};
+use crate::ephemeris::sample::{
+    ephemeris_getitem, ephemeris_len, EphemerisIterator, EphemerisSample,
+};
+use crate::ephemeris::{
+    ephemeris_eq, ephemeris_hash, ephemeris_metadata, ephemeris_repr, ephemeris_summary,
+};
+use crate::utils::conversions::{self, Frame};
+use crate::utils::gnss::{gps_seconds, gps_seconds_to_utc};
+use crate::utils::gps_almanac::{parse_almanac, resolve_gps_week, AlmanacFormat, GpsAlmanacRecord};
+use crate::utils::time_utils::{python_datetime_to_utc, utc_to_python_datetime, GridTimeScale};
+use crate::utils::to_skycoord::AstropyModules;
+
+#[pyclass]
+pub struct AlmanacEphemeris {
+    almanac_path: String,
+    format: AlmanacFormat,
+    record: GpsAlmanacRecord,
+    /// Time of applicability with the GPS week rollover resolved
+    almanac_epoch: DateTime<Utc>,
+    itrs: Option<Array2<f64>>,
+    itrs_skycoord: OnceLock<Py<PyAny>>, // Lazy-initialized cached SkyCoord object for ITRS
+    polar_motion: bool,                 // Whether to apply polar motion correction
+    // Common ephemeris data
+    common_data: EphemerisData,
+}
+
+#[pymethods]
+impl AlmanacEphemeris {
+    /// Create an ephemeris for one PRN of a YUMA or SEM almanac
+    ///
+    /// # Arguments
+    /// * `almanac_path` - Path to the YUMA (`.alm`) or SEM (`.al3`) almanac file
+    /// * `prn` - PRN number of the satellite to propagate
+    /// * `begin` - Start time (Python datetime)
+    /// * `end` - End time (Python datetime)
+    /// * `step_size` - Time step in seconds
+    /// * `format` - "yuma" or "sem"; detected from the file contents when omitted
+    /// * `polar_motion` - Whether to apply polar motion correction (default: false)
+    /// * `time_scale` - Scale in which the grid is uniform: "UTC", "TAI" or "TT"
+    ///   (default: "UTC")
+    #[new]
+    #[allow(clippy::too_many_arguments)]
+    #[pyo3(signature = (almanac_path, prn, begin, end, step_size=60, *, format=None, polar_motion=false, time_scale="UTC"))]
+    fn new(
+        _py: Python,
+        almanac_path: String,
+        prn: u32,
+        begin: &Bound<'_, PyDateTime>,
+        end: &Bound<'_, PyDateTime>,
+        step_size: i64,
+        format: Option<String>,
+        polar_motion: bool,
+        time_scale: &str,
+    ) -> PyResult<Self> {
+        let time_scale = parse_time_scale(time_scale)?;
+        let (format, records) = Self::read_almanac(&almanac_path, format.as_deref())?;
+        let record = records.into_iter().find(|r| r.prn == prn).ok_or_else(|| {
+            pyo3::exceptions::PyValueError::new_err(format!(
+                "PRN {} not found in almanac '{}'",
+                prn, almanac_path
+            ))
+        })?;
+
+        let begin_dt = python_datetime_to_utc(begin)?;
+        let times = generate_timestamps(begin, end, step_size, time_scale)?;
+
+        Self::build(
+            almanac_path,
+            format,
+            record,
+            &begin_dt,
+            times,
+            polar_motion,
+            time_scale,
+        )
+    }
+
+    /// List the PRNs present in an almanac file
+    #[staticmethod]
+    #[pyo3(signature = (almanac_path, *, format=None))]
+    fn prns(almanac_path: String, format: Option<String>) -> PyResult<Vec<u32>> {
+        let (_, records) = Self::read_almanac(&almanac_path, format.as_deref())?;
+        Ok(records.iter().map(|r| r.prn).collect())
+    }
+
+    /// Create ephemerides for every PRN in an almanac, keyed by PRN
+    ///
+    /// Unhealthy satellites (non-zero health word) are skipped when
+    /// `healthy_only` is true.
+    #[staticmethod]
+    #[allow(clippy::too_many_arguments)]
+    #[pyo3(signature = (almanac_path, begin, end, step_size=60, *, format=None, polar_motion=false, time_scale="UTC", healthy_only=false))]
+    fn load_constellation(
+        almanac_path: String,
+        begin: &Bound<'_, PyDateTime>,
+        end: &Bound<'_, PyDateTime>,
+        step_size: i64,
+        format: Option<String>,
+        polar_motion: bool,
+        time_scale: &str,
+        healthy_only: bool,
+    ) -> PyResult<HashMap<u32, AlmanacEphemeris>> {
+        let time_scale = parse_time_scale(time_scale)?;
+        let (format, records) = Self::read_almanac(&almanac_path, format.as_deref())?;
+        let begin_dt = python_datetime_to_utc(begin)?;
+        let times = generate_timestamps(begin, end, step_size, time_scale)?;
+
+        records
+            .into_iter()
+            .filter(|r| !healthy_only || r.health == 0)
+            .map(|record| {
+                let prn = record.prn;
+                let ephem = Self::build(
+                    almanac_path.clone(),
+                    format,
+                    record,
+                    &begin_dt,
+                    times.clone(),
+                    polar_motion,
+                    time_scale,
+                )?;
+                Ok((prn, ephem))
+            })
+            .collect()
+    }
+
+    // ===== Type-specific getters =====
+
+    /// Get the almanac file path
+    #[getter]
+    fn almanac_path(&self) -> &str {
+        &self.almanac_path
+    }
+
+    /// Get the almanac format ("yuma" or "sem")
+    #[getter]
+    fn almanac_format(&self) -> &'static str {
+        self.format.as_str()
+    }
+
+    /// Get the PRN number
+    #[getter]
+    fn prn(&self) -> u32 {
+        self.record.prn
+    }
+
+    /// Get the satellite health word (0 = healthy)
+    #[getter]
+    fn health(&self) -> u32 {
+        self.record.health
+    }
+
+    /// Get the almanac time of applicability as a UTC datetime
+    #[getter]
+    fn almanac_epoch(&self, py: Python) -> PyResult<Py<PyAny>> {
+        utc_to_python_datetime(py, &self.almanac_epoch)
+    }
+
+    /// Get the almanac Keplerian elements
+    ///
+    /// Angles are in radians, `sqrt_a` in m^1/2 and clock terms in s and s/s.
+    #[getter]
+    fn elements(&self) -> HashMap<&'static str, f64> {
+        let r = &self.record;
+        HashMap::from([
+            ("eccentricity", r.eccentricity),
+            ("inclination", r.inclination),
+            ("omega_dot", r.omega_dot),
+            ("sqrt_a", r.sqrt_a),
+            ("omega0", r.omega0),
+            ("arg_perigee", r.arg_perigee),
+            ("mean_anomaly", r.mean_anomaly),
+            ("af0", r.af0),
+            ("af1", r.af1),
+            ("toa", r.toa),
+        ])
+    }
+
+    /// Get whether polar motion correction is applied
+    #[getter]
+    fn polar_motion(&self) -> bool {
+        self.polar_motion
+    }
+
+    /// Get the time scale in which the timestamp grid is uniform
+    #[getter]
+    fn time_scale(&self) -> &'static str {
+        self.common_data.time_scale.name()
+    }
+
+    // ===== Common ephemeris getters (delegating to EphemerisBase trait) =====
+
+    #[getter]
+    fn begin(&self, py: Python) -> PyResult<Py<PyAny>> {
+        crate::ephemeris::ephemeris_common::get_begin_time(&self.common_data.times, py)
+    }
+
+    #[getter]
+    fn end(&self, py: Python) -> PyResult<Py<PyAny>> {
+        crate::ephemeris::ephemeris_common::get_end_time(&self.common_data.times, py)
+    }
+
+    #[getter]
+    fn step_size(&self) -> PyResult<i64> {
+        crate::ephemeris::ephemeris_common::get_step_size(&self.common_data.times)
+    }
+
+    #[getter]
+    fn gcrs_pv(&self, py: Python) -> Option<Py<PositionVelocityData>> {
+        self.get_gcrs_pv(py)
+    }
+
+    /// Observer position/velocity in TEME, converted from GCRS
+    #[getter]
+    fn teme_pv(&self, py: Python) -> Option<Py<PositionVelocityData>> {
+        self.get_teme_pv(py)
+    }
+
+    /// Observer position/velocity in the pseudo-Earth-fixed frame (TEME rotated by GMST)
+    #[getter]
+    fn pef_pv(&self, py: Python) -> Option<Py<PositionVelocityData>> {
+        self.get_pef_pv(py)
+    }
+
+    #[getter]
+    fn itrs_pv(&self, py: Python) -> Option<Py<PositionVelocityData>> {
+        self.get_itrs_pv(py)
+    }
+
+    #[getter]
+    fn itrs(&self, py: Python) -> PyResult<Py<PyAny>> {
+        self.get_itrs(py)
+    }
+
+    #[getter]
+    fn gcrs(&self, py: Python) -> PyResult<Py<PyAny>> {
+        self.get_gcrs(py)
+    }
+
+    #[getter]
+    fn earth(&self, py: Python) -> PyResult<Py<PyAny>> {
+        self.get_earth(py)
+    }
+
+    #[getter]
+    fn sun(&self, py: Python) -> PyResult<Py<PyAny>> {
+        self.get_sun(py)
+    }
+
+    #[getter]
+    fn moon(&self, py: Python) -> PyResult<Py<PyAny>> {
+        self.get_moon(py)
+    }
+
+    #[getter]
+    fn timestamp(&self, py: Python) -> PyResult<Option<Py<PyAny>>> {
+        self.get_timestamp(py)
+    }
+
+    #[getter]
+    fn sun_pv(&self, py: Python) -> Option<Py<PositionVelocityData>> {
+        self.get_sun_pv(py)
+    }
+
+    #[getter]
+    fn moon_pv(&self, py: Python) -> Option<Py<PositionVelocityData>> {
+        self.get_moon_pv(py)
+    }
+
+    #[getter]
+    fn obsgeoloc(&self, py: Python) -> PyResult<Option<Py<PyAny>>> {
+        self.get_obsgeoloc(py)
+    }
+
+    #[getter]
+    fn obsgeovel(&self, py: Python) -> PyResult<Option<Py<PyAny>>> {
+        self.get_obsgeovel(py)
+    }
+
+    #[getter]
+    fn latitude(&self, py: Python) -> PyResult<Option<Py<PyAny>>> {
+        self.get_latitude(py)
+    }
+
+    #[getter]
+    fn latitude_deg(&self, py: Python) -> PyResult<Option<Py<PyAny>>> {
+        self.get_latitude_deg(py)
+    }
+
+    #[getter]
+    fn latitude_rad(&self, py: Python) -> PyResult<Option<Py<PyAny>>> {
+        self.get_latitude_rad(py)
+    }
+
+    #[getter]
+    fn longitude(&self, py: Python) -> PyResult<Option<Py<PyAny>>> {
+        self.get_longitude(py)
+    }
+
+    #[getter]
+    fn longitude_deg(&self, py: Python) -> PyResult<Option<Py<PyAny>>> {
+        self.get_longitude_deg(py)
+    }
+
+    #[getter]
+    fn longitude_rad(&self, py: Python) -> PyResult<Option<Py<PyAny>>> {
+        self.get_longitude_rad(py)
+    }
+
+    #[getter]
+    fn height(&self, py: Python) -> PyResult<Option<Py<PyAny>>> {
+        self.get_height(py)
+    }
+
+    #[getter]
+    fn height_m(&self, py: Python) -> PyResult<Option<Py<PyAny>>> {
+        self.get_height_m(py)
+    }
+
+    #[getter]
+    fn height_km(&self, py: Python) -> PyResult<Option<Py<PyAny>>> {
+        self.get_height_km(py)
+    }
+
+    #[getter]
+    fn geomagnetic_latitude_deg(&self, py: Python) -> PyResult<Option<Py<PyAny>>> {
+        self.get_geomagnetic_latitude_deg(py)
+    }
+
+    #[getter]
+    fn geomagnetic_longitude_deg(&self, py: Python) -> PyResult<Option<Py<PyAny>>> {
+        self.get_geomagnetic_longitude_deg(py)
+    }
+
+    #[getter]
+    fn cutoff_rigidity_gv(&self, py: Python) -> PyResult<Option<Py<PyAny>>> {
+        self.get_cutoff_rigidity_gv(py)
+    }
+
+    #[getter]
+    fn sun_radius(&self, py: Python) -> PyResult<Py<PyAny>> {
+        self.get_sun_radius(py)
+    }
+
+    #[getter]
+    fn sun_radius_deg(&self, py: Python) -> PyResult<Py<PyAny>> {
+        self.get_sun_radius_deg(py)
+    }
+
+    #[getter]
+    fn sun_radius_rad(&self, py: Python) -> PyResult<Py<PyAny>> {
+        self.get_sun_radius_rad(py)
+    }
+
+    #[getter]
+    fn moon_radius(&self, py: Python) -> PyResult<Py<PyAny>> {
+        self.get_moon_radius(py)
+    }
+
+    #[getter]
+    fn moon_radius_deg(&self, py: Python) -> PyResult<Py<PyAny>> {
+        self.get_moon_radius_deg(py)
+    }
+
+    #[getter]
+    fn moon_radius_rad(&self, py: Python) -> PyResult<Py<PyAny>> {
+        self.get_moon_radius_rad(py)
+    }
+
+    #[getter]
+    fn earth_radius(&self, py: Python) -> PyResult<Py<PyAny>> {
+        self.get_earth_radius(py)
+    }
+
+    #[getter]
+    fn earth_radius_deg(&self, py: Python) -> PyResult<Py<PyAny>> {
+        self.get_earth_radius_deg(py)
+    }
+
+    #[getter]
+    fn earth_radius_rad(&self, py: Python) -> PyResult<Py<PyAny>> {
+        self.get_earth_radius_rad(py)
+    }
+
+    #[getter]
+    fn sun_ra_dec_deg(&self, py: Python) -> PyResult<Py<PyAny>> {
+        self.get_sun_ra_dec_deg(py)
+    }
+
+    #[getter]
+    fn moon_ra_dec_deg(&self, py: Python) -> PyResult<Py<PyAny>> {
+        self.get_moon_ra_dec_deg(py)
+    }
+
+    #[getter]
+    fn earth_ra_dec_deg(&self, py: Python) -> PyResult<Py<PyAny>> {
+        self.get_earth_ra_dec_deg(py)
+    }
+
+    #[getter]
+    fn sun_ra_dec_rad(&self, py: Python) -> PyResult<Py<PyAny>> {
+        self.get_sun_ra_dec_rad(py)
+    }
+
+    #[getter]
+    fn moon_ra_dec_rad(&self, py: Python) -> PyResult<Py<PyAny>> {
+        self.get_moon_ra_dec_rad(py)
+    }
+
+    #[getter]
+    fn earth_ra_dec_rad(&self, py: Python) -> PyResult<Py<PyAny>> {
+        self.get_earth_ra_dec_rad(py)
+    }
+
+    #[getter]
+    fn sun_ra_deg(&self, py: Python) -> PyResult<Py<PyAny>> {
+        self.get_sun_ra_deg(py)
+    }
+
+    #[getter]
+    fn sun_dec_deg(&self, py: Python) -> PyResult<Py<PyAny>> {
+        self.get_sun_dec_deg(py)
+    }
+
+    #[getter]
+    fn moon_ra_deg(&self, py: Python) -> PyResult<Py<PyAny>> {
+        self.get_moon_ra_deg(py)
+    }
+
+    #[getter]
+    fn moon_dec_deg(&self, py: Python) -> PyResult<Py<PyAny>> {
+        self.get_moon_dec_deg(py)
+    }
+
+    #[getter]
+    fn earth_ra_deg(&self, py: Python) -> PyResult<Py<PyAny>> {
+        self.get_earth_ra_deg(py)
+    }
+
+    #[getter]
+    fn earth_dec_deg(&self, py: Python) -> PyResult<Py<PyAny>> {
+        self.get_earth_dec_deg(py)
+    }
+
+    #[getter]
+    fn sun_ra_rad(&self, py: Python) -> PyResult<Py<PyAny>> {
+        self.get_sun_ra_rad(py)
+    }
+
+    #[getter]
+    fn sun_dec_rad(&self, py: Python) -> PyResult<Py<PyAny>> {
+        self.get_sun_dec_rad(py)
+    }
+
+    #[getter]
+    fn moon_ra_rad(&self, py: Python) -> PyResult<Py<PyAny>> {
+        self.get_moon_ra_rad(py)
+    }
+
+    #[getter]
+    fn moon_dec_rad(&self, py: Python) -> PyResult<Py<PyAny>> {
+        self.get_moon_dec_rad(py)
+    }
+
+    #[getter]
+    fn earth_ra_rad(&self, py: Python) -> PyResult<Py<PyAny>> {
+        self.get_earth_ra_rad(py)
+    }
+
+    #[getter]
+    fn earth_dec_rad(&self, py: Python) -> PyResult<Py<PyAny>> {
+        self.get_earth_dec_rad(py)
+    }
+
+    /// Fraction of the solar disk visible from the observer (1.0 sunlit, 0.0 umbra)
+    #[getter]
+    fn illumination_factor(&self, py: Python) -> PyResult<Py<PyAny>> {
+        let factors = EphemerisBase::illumination_factor(self)?;
+        Ok(factors.into_pyarray(py).into())
+    }
+
+    /// Geodetic latitude and longitude rates (deg/s) and height rate (km/s), shape (N, 3)
+    #[getter]
+    fn geodetic_rates(&self, py: Python) -> PyResult<Option<Py<PyAny>>> {
+        Ok(EphemerisBase::geodetic_rates(self)?.map(|rates| rates.into_pyarray(py).into()))
+    }
+
+    /// Speed of the sub-observer point over the WGS84 ellipsoid (km/s)
+    #[getter]
+    fn ground_speed(&self, py: Python) -> PyResult<Option<Py<PyAny>>> {
+        Ok(EphemerisBase::ground_speed(self)?.map(|speed| speed.into_pyarray(py).into()))
+    }
+
+    /// Geodetic (latitude, longitude) in degrees below the observer, shape (N, 2)
+    #[getter]
+    fn sub_satellite_point(&self, py: Python) -> PyResult<Option<Py<PyAny>>> {
+        Ok(EphemerisBase::sub_satellite_point(self)?.map(|points| points.into_pyarray(py).into()))
+    }
+
+    /// (latitude, longitude) in degrees with the Sun at the zenith, shape (N, 2)
+    #[getter]
+    fn sub_solar_point(&self, py: Python) -> PyResult<Py<PyAny>> {
+        Ok(EphemerisBase::sub_solar_point(self)?
+            .into_pyarray(py)
+            .into())
+    }
+
+    /// Solar zenith angle at the sub-satellite point in degrees (daylit below 90)
+    #[getter]
+    fn nadir_solar_zenith_angle(&self, py: Python) -> PyResult<Option<Py<PyAny>>> {
+        Ok(EphemerisBase::nadir_solar_zenith_angle(self)?
+            .map(|angles| angles.into_pyarray(py).into()))
+    }
+
+    /// Calculate Moon illumination fraction for all ephemeris times
+    ///
+    /// Returns the fraction of the Moon's illuminated surface as seen from the
+    /// spacecraft observer (0.0 = new moon, 1.0 = full moon).
+    ///
+    /// # Arguments
+    /// * `time_indices` - Optional indices into ephemeris times (default: all times)
+    ///
+    /// # Returns
+    /// List of Moon illumination fractions
+    #[pyo3(signature = (time_indices=None))]
+    fn moon_illumination(&self, time_indices: Option<Vec<usize>>) -> PyResult<Vec<f64>> {
+        EphemerisBase::moon_illumination(self, time_indices.as_deref())
+    }
+
+    /// Imaging opportunities of a ground target
+    ///
+    /// Windows in which the point at (`lat`, `lon`) is above its local horizon,
+    /// within `max_off_nadir_deg` of the spacecraft's nadir and, if
+    /// `min_sun_elev` is given, lit by the Sun at least that high. Each
+    /// opportunity reports the off-nadir angle, azimuth and ground-sample
+    /// geometry at its smallest off-nadir angle.
+    ///
+    /// # Arguments
+    /// * `lat` - Geodetic latitude of the target in degrees
+    /// * `lon` - Longitude of the target in degrees, east positive
+    /// * `max_off_nadir_deg` - Maximum off-nadir angle in degrees
+    /// * `min_sun_elev` - Minimum Sun elevation at the target in degrees (default: no limit)
+    ///
+    /// # Returns
+    /// List of `AccessOpportunity` in time order
+    #[pyo3(signature = (lat, lon, max_off_nadir_deg, min_sun_elev=None))]
+    fn access_to(
+        &self,
+        lat: f64,
+        lon: f64,
+        max_off_nadir_deg: f64,
+        min_sun_elev: Option<f64>,
+    ) -> PyResult<Vec<AccessOpportunity>> {
+        ground_access::access_to(self, lat, lon, max_off_nadir_deg, min_sun_elev)
+    }
+
+    fn index(&self, time: &Bound<'_, PyDateTime>) -> PyResult<usize> {
+        self.find_closest_index(time)
+    }
+
+    /// Stable hex digest of the source, time grid, options and computed states
+    ///
+    /// Ephemerides with equal fingerprints compare equal, so the fingerprint
+    /// can key caches of results computed from this ephemeris.
+    fn fingerprint(&self) -> String {
+        EphemerisBase::fingerprint(self)
+    }
+
+    fn __eq__(&self, other: &Bound<'_, PyAny>) -> bool {
+        ephemeris_eq(self, other)
+    }
+
+    fn __hash__(&self) -> u64 {
+        ephemeris_hash(self)
+    }
+
+    /// Source, time span, computed frames and Earth orientation state
+    ///
+    /// Values are JSON-serializable (times as ISO 8601 strings), so the dict
+    /// can be logged as provenance alongside derived results.
+    #[getter]
+    fn metadata(&self, py: Python) -> PyResult<Py<PyAny>> {
+        ephemeris_metadata(py, self)
+    }
+
+    /// Reproducibility record: library version, source and fingerprint,
+    /// element set, input and kernel file digests, Earth orientation and
+    /// leap-second data
+    #[getter]
+    fn provenance(&self) -> Provenance {
+        ephemeris_provenance(self)
+    }
+
+    /// Human-readable, multi-line description of `metadata`
+    fn summary(&self) -> String {
+        ephemeris_summary(self)
+    }
+
+    fn __repr__(&self) -> String {
+        ephemeris_repr(self)
+    }
+
+    fn __len__(&self) -> usize {
+        ephemeris_len(self)
+    }
+
+    /// State at time step `index`; negative indices count from the end
+    fn __getitem__(&self, index: isize) -> PyResult<EphemerisSample> {
+        ephemeris_getitem(self, index)
+    }
+
+    fn __iter__(slf: Bound<'_, Self>) -> EphemerisIterator {
+        EphemerisIterator::new(slf.into_any().unbind())
+    }
+
+    #[pyo3(signature = (body, spice_kernel=None, use_horizons=false, source=None))]
+    fn get_body_pv(
+        &self,
+        py: Python,
+        body: &str,
+        spice_kernel: Option<String>,
+        use_horizons: bool,
+        source: Option<String>,
+    ) -> PyResult<Py<PositionVelocityData>> {
+        <Self as EphemerisBase>::get_body_pv(
+            self,
+            py,
+            body,
+            spice_kernel.as_deref(),
+            use_horizons,
+            source.as_deref(),
+        )
+    }
+
+    #[pyo3(signature = (bodies, spice_kernel=None, use_horizons=false, source=None))]
+    fn get_bodies(
+        &self,
+        py: Python,
+        bodies: Vec<String>,
+        spice_kernel: Option<String>,
+        use_horizons: bool,
+        source: Option<String>,
+    ) -> PyResult<Py<pyo3::types::PyDict>> {
+        <Self as EphemerisBase>::get_bodies(
+            self,
+            py,
+            &bodies,
+            spice_kernel.as_deref(),
+            use_horizons,
+            source.as_deref(),
+        )
+    }
+
+    /// Angular separation in degrees between two targets at each time step
+    ///
+    /// Each of `a` and `b` may be an `(ra_deg, dec_deg)` pair, a body name,
+    /// observer-relative `PositionVelocityData` or a SkyCoord.
+    fn separation(
+        &self,
+        py: Python,
+        a: &Bound<'_, PyAny>,
+        b: &Bound<'_, PyAny>,
+    ) -> PyResult<Py<PyAny>> {
+        let angles = <Self as EphemerisBase>::separation(self, py, a, b)?;
+        Ok(angles.into_pyarray(py).into())
+    }
+
+    /// Angle in degrees between a target and the Sun at each time step
+    #[pyo3(signature = (ra_deg, dec_deg, time_indices=None))]
+    fn sun_angle(
+        &self,
+        py: Python,
+        ra_deg: f64,
+        dec_deg: f64,
+        time_indices: Option<Vec<usize>>,
+    ) -> PyResult<Py<PyAny>> {
+        let angles = EphemerisBase::sun_angle(self, ra_deg, dec_deg, time_indices.as_deref())?;
+        Ok(angles.into_pyarray(py).into())
+    }
+
+    /// Angle in degrees between a target and the Moon at each time step
+    #[pyo3(signature = (ra_deg, dec_deg, time_indices=None))]
+    fn moon_angle(
+        &self,
+        py: Python,
+        ra_deg: f64,
+        dec_deg: f64,
+        time_indices: Option<Vec<usize>>,
+    ) -> PyResult<Py<PyAny>> {
+        let angles = EphemerisBase::moon_angle(self, ra_deg, dec_deg, time_indices.as_deref())?;
+        Ok(angles.into_pyarray(py).into())
+    }
+
+    /// Angle in degrees of a target above the Earth's limb (negative behind it)
+    #[pyo3(signature = (ra_deg, dec_deg, time_indices=None))]
+    fn earth_limb_angle(
+        &self,
+        py: Python,
+        ra_deg: f64,
+        dec_deg: f64,
+        time_indices: Option<Vec<usize>>,
+    ) -> PyResult<Py<PyAny>> {
+        let angles =
+            EphemerisBase::earth_limb_angle(self, ra_deg, dec_deg, time_indices.as_deref())?;
+        Ok(angles.into_pyarray(py).into())
+    }
+
+    /// Sun, Moon and Earth-limb angles, altitude, eclipse, SAA, orbital phase
+    /// and beta angle for a target at the ephemeris step nearest `time`
+    #[pyo3(signature = (ra_deg, dec_deg, time, *, saa_polygon=None))]
+    fn geometry(
+        &self,
+        ra_deg: f64,
+        dec_deg: f64,
+        time: &Bound<'_, PyDateTime>,
+        saa_polygon: Option<Vec<(f64, f64)>>,
+    ) -> PyResult<ObservationGeometry> {
+        let index = self.find_closest_index(time)?;
+        observation_geometry(self, ra_deg, dec_deg, index, saa_polygon.as_deref())
+    }
+
+    #[pyo3(signature = (body, spice_kernel=None, use_horizons=false, source=None))]
+    fn get_body(
+        &self,
+        py: Python,
+        body: &str,
+        spice_kernel: Option<String>,
+        use_horizons: bool,
+        source: Option<String>,
+    ) -> PyResult<Py<PyAny>> {
+        let modules = AstropyModules::import(py)?;
+        <Self as EphemerisBase>::get_body(
+            self,
+            py,
+            &modules,
+            body,
+            spice_kernel.as_deref(),
+            use_horizons,
+            source.as_deref(),
+        )
+    }
+
+    /// Convert RA/Dec to Altitude/Azimuth for this ephemeris
+    /// Returns NumPy array (N,2): [altitude_deg, azimuth_deg]
+    #[pyo3(signature = (ra_deg, dec_deg, time_indices=None, *, polar_motion=None))]
+    fn radec_to_altaz(
+        &self,
+        py: Python,
+        ra_deg: f64,
+        dec_deg: f64,
+        time_indices: Option<Vec<usize>>,
+        polar_motion: Option<bool>,
+    ) -> PyResult<Py<PyAny>> {
+        let arr = crate::utils::celestial::radec_to_altaz(
+            ra_deg,
+            dec_deg,
+            self,
+            time_indices.as_deref(),
+            polar_motion,
+        );
+        Ok(arr.into_pyarray(py).into())
+    }
+
+    /// Convert many RA/Dec targets to Altitude/Azimuth in one call
+    /// Returns NumPy array (n_targets, N, 2): [altitude_deg, azimuth_deg]
+    #[pyo3(signature = (ra_deg, dec_deg, time_indices=None, *, polar_motion=None))]
+    fn radec_to_altaz_batch(
+        &self,
+        py: Python,
+        ra_deg: Vec<f64>,
+        dec_deg: Vec<f64>,
+        time_indices: Option<Vec<usize>>,
+        polar_motion: Option<bool>,
+    ) -> PyResult<Py<PyAny>> {
+        if ra_deg.len() != dec_deg.len() {
+            return Err(pyo3::exceptions::PyValueError::new_err(
+                "ra_deg and dec_deg must have the same length",
+            ));
+        }
+        let arr = crate::utils::celestial::radec_to_altaz_batch(
+            &ra_deg,
+            &dec_deg,
+            self,
+            time_indices.as_deref(),
+            polar_motion,
+        );
+        Ok(arr.into_pyarray(py).into())
+    }
+
+    /// Calculate airmass for a target at given RA/Dec
+    ///
+    /// Airmass represents the relative path length through Earth's atmosphere compared to
+    /// zenith observation. Lower values indicate better observing conditions.
+    ///
+    /// # Arguments
+    /// * `ra_deg` - Right ascension in degrees (ICRS/J2000)
+    /// * `dec_deg` - Declination in degrees (ICRS/J2000)
+    /// * `time_indices` - Optional indices into ephemeris times (default: all times)
+    ///
+    /// # Returns
+    /// List of airmass values:
+    /// - 1.0 at zenith (directly overhead)
+    /// - ~2.0 at 30° altitude
+    /// - ~5.8 at 10° altitude
+    /// - Infinity for targets below horizon
+    #[pyo3(signature = (ra_deg, dec_deg, time_indices=None))]
+    fn calculate_airmass(
+        &self,
+        ra_deg: f64,
+        dec_deg: f64,
+        time_indices: Option<Vec<usize>>,
+    ) -> PyResult<Vec<f64>> {
+        <Self as crate::ephemeris::ephemeris_common::EphemerisBase>::calculate_airmass(
+            self,
+            ra_deg,
+            dec_deg,
+            time_indices.as_deref(),
+        )
+    }
+
+    /// Calculate airmass for many targets in one call
+    ///
+    /// Uses the vectorized Kasten pipeline of the airmass constraint, without
+    /// refraction or aberration, so there are no per-target Python calls.
+    ///
+    /// # Returns
+    /// NumPy array (n_targets, N) of airmass values, infinity below the horizon
+    #[pyo3(signature = (ras, decs, time_indices=None))]
+    fn airmass_batch(
+        &self,
+        py: Python,
+        ras: Vec<f64>,
+        decs: Vec<f64>,
+        time_indices: Option<Vec<usize>>,
+    ) -> PyResult<Py<PyAny>> {
+        let arr =
+            <Self as crate::ephemeris::ephemeris_common::EphemerisBase>::calculate_airmass_batch(
+                self,
+                &ras,
+                &decs,
+                time_indices.as_deref(),
+            )?;
+        Ok(arr.into_pyarray(py).into())
+    }
+}
+
+impl AlmanacEphemeris {
+    /// Read and parse an almanac file
+    fn read_almanac(
+        almanac_path: &str,
+        format: Option<&str>,
+    ) -> PyResult<(AlmanacFormat, Vec<GpsAlmanacRecord>)> {
+        let format = format
+            .map(AlmanacFormat::parse)
+            .transpose()
+            .map_err(pyo3::exceptions::PyValueError::new_err)?;
+        let text = std::fs::read_to_string(Path::new(almanac_path)).map_err(|e| {
+            pyo3::exceptions::PyIOError::new_err(format!("Failed to read almanac file: {}", e))
+        })?;
+        parse_almanac(&text, format).map_err(pyo3::exceptions::PyValueError::new_err)
+    }
+
+    /// Propagate one almanac record over `times` and pre-compute all frames
+    fn build(
+        almanac_path: String,
+        format: AlmanacFormat,
+        record: GpsAlmanacRecord,
+        reference: &DateTime<Utc>,
+        times: Vec<DateTime<Utc>>,
+        polar_motion: bool,
+        time_scale: GridTimeScale,
+    ) -> PyResult<Self> {
+        let full_week = resolve_gps_week(record.week, gps_seconds(reference));
+        let toa_gps = record.toa_gps_seconds(full_week);
+        let almanac_epoch =
+            gps_seconds_to_utc(toa_gps).map_err(pyo3::exceptions::PyValueError::new_err)?;
+
+        let mut ephemeris = AlmanacEphemeris {
+            almanac_path,
+            format,
+            record,
+            almanac_epoch,
+            itrs: None,
+            itrs_skycoord: OnceLock::new(),
+            polar_motion,
+            common_data: {
+                let mut data = EphemerisData::new();
+                data.times = Some(times);
+                data.time_scale = time_scale;
+                data
+            },
+        };
+
+        ephemeris.propagate_itrs(toa_gps)?;
+        ephemeris.itrs_to_gcrs()?;
+        ephemeris.calculate_sun_moon()?;
+
+        Ok(ephemeris)
+    }
+
+    /// Evaluate the almanac model at every timestamp in the Earth-fixed frame
+    fn propagate_itrs(&mut self, toa_gps: f64) -> PyResult<()> {
+        let times = self
+            .common_data
+            .times
+            .as_ref()
+            .ok_or_else(|| pyo3::exceptions::PyValueError::new_err("No times available."))?;
+
+        let mut itrs = Array2::<f64>::zeros((times.len(), 6));
+        for (i, t) in times.iter().enumerate() {
+            let state = self.record.ecef_state(gps_seconds(t), toa_gps);
+            for (j, value) in state.iter().enumerate() {
+                itrs[[i, j]] = *value;
+            }
+        }
+
+        self.itrs = Some(itrs);
+        Ok(())
+    }
+
+    /// Transform ITRS to GCRS coordinates
+    fn itrs_to_gcrs(&mut self) -> PyResult<()> {
+        let times = self
+            .common_data
+            .times
+            .as_ref()
+            .ok_or_else(|| pyo3::exceptions::PyValueError::new_err("No times available."))?;
+
+        let itrs_data = self
+            .itrs
+            .as_ref()
+            .ok_or_else(|| pyo3::exceptions::PyValueError::new_err("No ITRS data available"))?;
+
+        let gcrs_array = conversions::convert_frames(
+            itrs_data,
+            times,
+            Frame::ITRS,
+            Frame::GCRS,
+            self.polar_motion,
+        );
+
+        self.common_data.gcrs = Some(gcrs_array);
+        Ok(())
+    }
+}
+
+// Implement the EphemerisBase trait for AlmanacEphemeris
+impl EphemerisBase for AlmanacEphemeris {
+    fn data(&self) -> &EphemerisData {
+        &self.common_data
+    }
+
+    fn data_mut(&mut self) -> &mut EphemerisData {
+        &mut self.common_data
+    }
+
+    fn get_itrs_data(&self) -> Option<&Array2<f64>> {
+        self.itrs.as_ref()
+    }
+
+    fn get_itrs_skycoord_ref(&self) -> Option<&Py<PyAny>> {
+        self.itrs_skycoord.get()
+    }
+
+    fn set_itrs_skycoord_cache(&self, skycoord: Py<PyAny>) -> Result<(), Py<PyAny>> {
+        self.itrs_skycoord.set(skycoord)
+    }
+
+    fn source_identity(&self) -> String {
+        format!(
+            "AlmanacEphemeris\nalmanac_path={}\nformat={:?}\nrecord={:?}\npolar_motion={}",
+            self.almanac_path, self.format, self.record, self.polar_motion
+        )
+    }
+
+    fn source_metadata(&self) -> Vec<(&'static str, serde_json::Value)> {
+        vec![
+            ("type", "AlmanacEphemeris".into()),
+            ("almanac_path", self.almanac_path.clone().into()),
+            ("format", format!("{:?}", self.format).to_lowercase().into()),
+            ("prn", self.record.prn.into()),
+            (
+                "almanac_epoch",
+                metadata_timestamp(&self.almanac_epoch).into(),
+            ),
+            ("polar_motion", self.polar_motion.into()),
+        ]
+    }
+
+    fn applies_polar_motion(&self) -> bool {
+        self.polar_motion
+    }
+
+    fn radec_to_altaz(
+        &self,
+        ra_deg: f64,
+        dec_deg: f64,
+        time_indices: Option<&[usize]>,
+    ) -> Array2<f64> {
+        crate::utils::celestial::radec_to_altaz(ra_deg, dec_deg, self, time_indices, None)
+    }
+}
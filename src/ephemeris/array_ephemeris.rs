@@ -0,0 +1,1193 @@
+//! Ephemeris from user-supplied state vectors
+//!
+//! Wraps arbitrary position/velocity arrays, for example the output of an
+//! external propagator, so they gain the full ephemeris interface (Sun/Moon
+//! positions, SkyCoord output and constraint evaluation).
+//!
+//! ## Supported coordinate frames
+//!
+//! - **GCRS**: states are interpolated in GCRS and transformed to ITRS.
+//! - **ITRS**: states are interpolated in ITRS and transformed to GCRS.
+//! - **TEME**: states are interpolated in TEME and transformed to both, as for
+//!   SGP4 output.
+//! - **PEF**: states are interpolated in the pseudo-Earth-fixed frame (TEME
+//!   rotated by GMST) and transformed to both, for legacy Earth-fixed products.
+//!
+//! States are resampled onto a regular grid when `begin`, `end` or `step_size`
+//! are given; otherwise the input times are used as-is. Resampling uses cubic
+//! Hermite interpolation by default, or Hermite/Lagrange over a wider window
+//! of samples, and can refuse to interpolate across gaps wider than `max_gap`.
+
+use chrono::{DateTime, Utc};
+use ndarray::Array2;
+use numpy::IntoPyArray;
+use pyo3::{prelude::*, types::PyDateTime};
+use std::sync::OnceLock;
+
+use crate::ephemeris::ephemeris_common::{
+    check_fast_itrs, generate_timestamps_utc, parse_time_scale, split_pos_vel, EphemerisBase,
+    EphemerisData,
+};
+use crate::ephemeris::geo_ephemeris::GeoSlot;
+use crate::ephemeris::ground_access::{self, AccessOpportunity};
+use crate::ephemeris::observation_geometry::{observation_geometry, ObservationGeometry};
+use crate::ephemeris::position_velocity::PositionVelocityData;
+use crate::ephemeris::provenance::{ephemeris_provenance, Provenance};
+use crate::ephemeris::sample::{
+    ephemeris_getitem, ephemeris_len, EphemerisIterator, EphemerisSample,
+};
+use crate::ephemeris::{
+    ephemeris_eq, ephemeris_hash, ephemeris_metadata, ephemeris_repr, ephemeris_summary,
+};
+use crate::utils::conversions::{self, Frame};
+use crate::utils::interpolation::Interpolation;
+use crate::utils::time_utils::{
+    python_datetime_to_utc, python_times_to_utc, utc_to_python_datetime, GridTimeScale,
+};
+use crate::utils::to_skycoord::AstropyModules;
+
+/// Default resampling step when only `begin` or `end` is given
+const DEFAULT_STEP_SIZE: i64 = 60;
+
+#[pyclass(subclass)]
+pub struct ArrayEphemeris {
+    source_frame: Frame,
+    itrs: Option<Array2<f64>>,
+    itrs_skycoord: OnceLock<Py<PyAny>>, // Lazy-initialized cached SkyCoord object for ITRS
+    polar_motion: bool,                 // Whether to apply polar motion correction
+    // Common ephemeris data
+    common_data: EphemerisData,
+    // Store the input data for reference
+    source_times: Vec<DateTime<Utc>>,
+    source_states: Array2<f64>,
+    // Interpolation used to resample the input states
+    interpolation: Interpolation,
+    // Nominal GEO slot when constructed as a GEOEphemeris
+    geo_slot: Option<GeoSlot>,
+}
+
+#[pymethods]
+impl ArrayEphemeris {
+    /// Create an ephemeris from arrays of times and state vectors
+    ///
+    /// # Arguments
+    /// * `times` - Sequence of datetimes or NumPy datetime64 array (N), strictly increasing
+    /// * `states` - State vectors (N x 6): position in km and velocity in km/s
+    /// * `begin` - Start of the resampled grid (default: first input time)
+    /// * `end` - End of the resampled grid (default: last input time)
+    /// * `step_size` - Grid step in seconds (default: input times are used as-is
+    ///   unless `begin` or `end` is given, in which case 60)
+    /// * `frame` - Frame of the input states: "GCRS", "ITRS", "TEME" or "PEF" (default: "GCRS")
+    /// * `polar_motion` - Whether to apply polar motion correction (default: false)
+    /// * `fast_itrs` - Rotate GCRS to ITRS without the UT1-UTC correction or
+    ///   polar motion, for large grids (default: false); no effect on ITRS input
+    /// * `time_scale` - Scale in which a resampled grid is uniform: "UTC", "TAI"
+    ///   or "TT" (default: "UTC")
+    /// * `interpolation` - Resampling scheme: "hermite" or "lagrange" (default: "hermite")
+    /// * `interpolation_window` - Samples per interpolant (default: 2 for Hermite, 8 for Lagrange)
+    /// * `max_gap` - Largest sample spacing in seconds to interpolate across (default: no limit)
+    #[new]
+    #[allow(clippy::too_many_arguments)]
+    #[pyo3(signature = (times, states, begin=None, end=None, step_size=None, *, frame="GCRS", polar_motion=false, fast_itrs=false, time_scale="UTC", interpolation="hermite", interpolation_window=None, max_gap=None))]
+    fn new(
+        _py: Python,
+        times: &Bound<'_, PyAny>,
+        states: Vec<Vec<f64>>,
+        begin: Option<&Bound<'_, PyDateTime>>,
+        end: Option<&Bound<'_, PyDateTime>>,
+        step_size: Option<i64>,
+        frame: &str,
+        polar_motion: bool,
+        fast_itrs: bool,
+        time_scale: &str,
+        interpolation: &str,
+        interpolation_window: Option<usize>,
+        max_gap: Option<f64>,
+    ) -> PyResult<Self> {
+        check_fast_itrs(fast_itrs, polar_motion)?;
+        let time_scale = parse_time_scale(time_scale)?;
+        let source_frame = Self::parse_frame(frame)?;
+        let interpolation = Interpolation::new(interpolation, interpolation_window, max_gap)
+            .map_err(pyo3::exceptions::PyValueError::new_err)?;
+        let source_times = python_times_to_utc(times)?;
+        let source_states = Self::states_to_array(&states, source_times.len())?;
+
+        Self::check_source_times(&source_times).map_err(pyo3::exceptions::PyValueError::new_err)?;
+
+        let first = source_times[0];
+        let last = source_times[source_times.len() - 1];
+        let resample = begin.is_some() || end.is_some() || step_size.is_some();
+        let query_times = if resample {
+            let begin_dt = begin
+                .map(|b| python_datetime_to_utc(b))
+                .transpose()?
+                .unwrap_or(first);
+            let end_dt = end
+                .map(|e| python_datetime_to_utc(e))
+                .transpose()?
+                .unwrap_or(last);
+            if begin_dt < first || end_dt > last {
+                return Err(pyo3::exceptions::PyValueError::new_err(format!(
+                    "Requested time range [{}, {}] exceeds data range [{}, {}]",
+                    begin_dt, end_dt, first, last
+                )));
+            }
+            generate_timestamps_utc(
+                begin_dt,
+                end_dt,
+                step_size.unwrap_or(DEFAULT_STEP_SIZE),
+                time_scale,
+            )?
+        } else {
+            source_times.clone()
+        };
+
+        let mut ephemeris = ArrayEphemeris {
+            source_frame,
+            itrs: None,
+            itrs_skycoord: OnceLock::new(),
+            polar_motion,
+            common_data: {
+                let mut data = EphemerisData::new();
+                data.times = Some(query_times);
+                data.fast_itrs = fast_itrs;
+                data.time_scale = time_scale;
+                data
+            },
+            source_times,
+            source_states,
+            interpolation,
+            geo_slot: None,
+        };
+
+        // Pre-compute all frames
+        ephemeris
+            .interpolate_and_convert()
+            .map_err(pyo3::exceptions::PyValueError::new_err)?;
+        ephemeris.calculate_sun_moon()?;
+
+        Ok(ephemeris)
+    }
+
+    // ===== Type-specific getters =====
+
+    /// Get the frame of the input states ("GCRS", "ITRS", "TEME" or "PEF")
+    #[getter]
+    fn source_frame(&self) -> &'static str {
+        match self.source_frame {
+            Frame::GCRS => "GCRS",
+            Frame::ITRS => "ITRS",
+            Frame::TEME => "TEME",
+            Frame::PEF => "PEF",
+        }
+    }
+
+    /// Get whether polar motion correction is applied
+    #[getter]
+    fn polar_motion(&self) -> bool {
+        self.polar_motion
+    }
+
+    /// Get whether ITRS skips the UT1-UTC correction and polar motion
+    #[getter]
+    fn fast_itrs(&self) -> bool {
+        self.common_data.fast_itrs
+    }
+
+    /// Get the time scale in which the timestamp grid is uniform
+    #[getter]
+    fn time_scale(&self) -> &'static str {
+        self.common_data.time_scale.name()
+    }
+
+    /// Get the resampling scheme ("hermite" or "lagrange")
+    #[getter]
+    fn interpolation(&self) -> &'static str {
+        self.interpolation.method.name()
+    }
+
+    /// Get the number of samples each interpolant passes through
+    #[getter]
+    fn interpolation_window(&self) -> usize {
+        self.interpolation.window
+    }
+
+    /// Get the largest sample spacing in seconds that may be interpolated across
+    #[getter]
+    fn max_gap(&self) -> Option<f64> {
+        self.interpolation.max_gap
+    }
+
+    /// Estimated largest position interpolation error in km over the ephemeris
+    ///
+    /// The interpolant is compared with one through an extra sample at the
+    /// midpoint of each input interval. None without enough input samples.
+    #[getter]
+    fn max_interpolation_error(&self) -> Option<f64> {
+        let times = self.common_data.times.as_ref()?;
+        self.interpolation.max_error_estimate(
+            times.first()?,
+            times.last()?,
+            &self.source_times,
+            &self.source_states,
+        )
+    }
+
+    /// Get the input position and velocity before resampling
+    #[getter]
+    fn source_pv(&self, py: Python) -> PyResult<Py<PositionVelocityData>> {
+        Py::new(py, split_pos_vel(&self.source_states))
+    }
+
+    /// Get the input timestamps before resampling
+    #[getter]
+    fn source_timestamp(&self, py: Python) -> PyResult<Vec<Py<PyAny>>> {
+        self.source_times
+            .iter()
+            .map(|t| utc_to_python_datetime(py, t))
+            .collect()
+    }
+
+    // ===== Common ephemeris getters (delegating to EphemerisBase trait) =====
+
+    #[getter]
+    fn begin(&self, py: Python) -> PyResult<Py<PyAny>> {
+        crate::ephemeris::ephemeris_common::get_begin_time(&self.common_data.times, py)
+    }
+
+    #[getter]
+    fn end(&self, py: Python) -> PyResult<Py<PyAny>> {
+        crate::ephemeris::ephemeris_common::get_end_time(&self.common_data.times, py)
+    }
+
+    #[getter]
+    fn step_size(&self) -> PyResult<i64> {
+        crate::ephemeris::ephemeris_common::get_step_size(&self.common_data.times)
+    }
+
+    #[getter]
+    fn gcrs_pv(&self, py: Python) -> Option<Py<PositionVelocityData>> {
+        self.get_gcrs_pv(py)
+    }
+
+    /// Observer position/velocity in TEME, converted from GCRS
+    #[getter]
+    fn teme_pv(&self, py: Python) -> Option<Py<PositionVelocityData>> {
+        self.get_teme_pv(py)
+    }
+
+    /// Observer position/velocity in the pseudo-Earth-fixed frame (TEME rotated by GMST)
+    #[getter]
+    fn pef_pv(&self, py: Python) -> Option<Py<PositionVelocityData>> {
+        self.get_pef_pv(py)
+    }
+
+    #[getter]
+    fn itrs_pv(&self, py: Python) -> Option<Py<PositionVelocityData>> {
+        self.get_itrs_pv(py)
+    }
+
+    #[getter]
+    fn itrs(&self, py: Python) -> PyResult<Py<PyAny>> {
+        self.get_itrs(py)
+    }
+
+    #[getter]
+    fn gcrs(&self, py: Python) -> PyResult<Py<PyAny>> {
+        self.get_gcrs(py)
+    }
+
+    #[getter]
+    fn earth(&self, py: Python) -> PyResult<Py<PyAny>> {
+        self.get_earth(py)
+    }
+
+    #[getter]
+    fn sun(&self, py: Python) -> PyResult<Py<PyAny>> {
+        self.get_sun(py)
+    }
+
+    #[getter]
+    fn moon(&self, py: Python) -> PyResult<Py<PyAny>> {
+        self.get_moon(py)
+    }
+
+    #[getter]
+    fn timestamp(&self, py: Python) -> PyResult<Option<Py<PyAny>>> {
+        self.get_timestamp(py)
+    }
+
+    #[getter]
+    fn sun_pv(&self, py: Python) -> Option<Py<PositionVelocityData>> {
+        self.get_sun_pv(py)
+    }
+
+    #[getter]
+    fn moon_pv(&self, py: Python) -> Option<Py<PositionVelocityData>> {
+        self.get_moon_pv(py)
+    }
+
+    #[getter]
+    fn obsgeoloc(&self, py: Python) -> PyResult<Option<Py<PyAny>>> {
+        self.get_obsgeoloc(py)
+    }
+
+    #[getter]
+    fn obsgeovel(&self, py: Python) -> PyResult<Option<Py<PyAny>>> {
+        self.get_obsgeovel(py)
+    }
+
+    #[getter]
+    fn latitude(&self, py: Python) -> PyResult<Option<Py<PyAny>>> {
+        self.get_latitude(py)
+    }
+
+    #[getter]
+    fn latitude_deg(&self, py: Python) -> PyResult<Option<Py<PyAny>>> {
+        self.get_latitude_deg(py)
+    }
+
+    #[getter]
+    fn latitude_rad(&self, py: Python) -> PyResult<Option<Py<PyAny>>> {
+        self.get_latitude_rad(py)
+    }
+
+    #[getter]
+    fn longitude(&self, py: Python) -> PyResult<Option<Py<PyAny>>> {
+        self.get_longitude(py)
+    }
+
+    #[getter]
+    fn longitude_deg(&self, py: Python) -> PyResult<Option<Py<PyAny>>> {
+        self.get_longitude_deg(py)
+    }
+
+    #[getter]
+    fn longitude_rad(&self, py: Python) -> PyResult<Option<Py<PyAny>>> {
+        self.get_longitude_rad(py)
+    }
+
+    #[getter]
+    fn height(&self, py: Python) -> PyResult<Option<Py<PyAny>>> {
+        self.get_height(py)
+    }
+
+    #[getter]
+    fn height_m(&self, py: Python) -> PyResult<Option<Py<PyAny>>> {
+        self.get_height_m(py)
+    }
+
+    #[getter]
+    fn height_km(&self, py: Python) -> PyResult<Option<Py<PyAny>>> {
+        self.get_height_km(py)
+    }
+
+    #[getter]
+    fn geomagnetic_latitude_deg(&self, py: Python) -> PyResult<Option<Py<PyAny>>> {
+        self.get_geomagnetic_latitude_deg(py)
+    }
+
+    #[getter]
+    fn geomagnetic_longitude_deg(&self, py: Python) -> PyResult<Option<Py<PyAny>>> {
+        self.get_geomagnetic_longitude_deg(py)
+    }
+
+    #[getter]
+    fn cutoff_rigidity_gv(&self, py: Python) -> PyResult<Option<Py<PyAny>>> {
+        self.get_cutoff_rigidity_gv(py)
+    }
+
+    #[getter]
+    fn sun_radius(&self, py: Python) -> PyResult<Py<PyAny>> {
+        self.get_sun_radius(py)
+    }
+
+    #[getter]
+    fn sun_radius_deg(&self, py: Python) -> PyResult<Py<PyAny>> {
+        self.get_sun_radius_deg(py)
+    }
+
+    #[getter]
+    fn sun_radius_rad(&self, py: Python) -> PyResult<Py<PyAny>> {
+        self.get_sun_radius_rad(py)
+    }
+
+    #[getter]
+    fn moon_radius(&self, py: Python) -> PyResult<Py<PyAny>> {
+        self.get_moon_radius(py)
+    }
+
+    #[getter]
+    fn moon_radius_deg(&self, py: Python) -> PyResult<Py<PyAny>> {
+        self.get_moon_radius_deg(py)
+    }
+
+    #[getter]
+    fn moon_radius_rad(&self, py: Python) -> PyResult<Py<PyAny>> {
+        self.get_moon_radius_rad(py)
+    }
+
+    #[getter]
+    fn earth_radius(&self, py: Python) -> PyResult<Py<PyAny>> {
+        self.get_earth_radius(py)
+    }
+
+    #[getter]
+    fn earth_radius_deg(&self, py: Python) -> PyResult<Py<PyAny>> {
+        self.get_earth_radius_deg(py)
+    }
+
+    #[getter]
+    fn earth_radius_rad(&self, py: Python) -> PyResult<Py<PyAny>> {
+        self.get_earth_radius_rad(py)
+    }
+
+    #[getter]
+    fn sun_ra_dec_deg(&self, py: Python) -> PyResult<Py<PyAny>> {
+        self.get_sun_ra_dec_deg(py)
+    }
+
+    #[getter]
+    fn moon_ra_dec_deg(&self, py: Python) -> PyResult<Py<PyAny>> {
+        self.get_moon_ra_dec_deg(py)
+    }
+
+    #[getter]
+    fn earth_ra_dec_deg(&self, py: Python) -> PyResult<Py<PyAny>> {
+        self.get_earth_ra_dec_deg(py)
+    }
+
+    #[getter]
+    fn sun_ra_dec_rad(&self, py: Python) -> PyResult<Py<PyAny>> {
+        self.get_sun_ra_dec_rad(py)
+    }
+
+    #[getter]
+    fn moon_ra_dec_rad(&self, py: Python) -> PyResult<Py<PyAny>> {
+        self.get_moon_ra_dec_rad(py)
+    }
+
+    #[getter]
+    fn earth_ra_dec_rad(&self, py: Python) -> PyResult<Py<PyAny>> {
+        self.get_earth_ra_dec_rad(py)
+    }
+
+    #[getter]
+    fn sun_ra_deg(&self, py: Python) -> PyResult<Py<PyAny>> {
+        self.get_sun_ra_deg(py)
+    }
+
+    #[getter]
+    fn sun_dec_deg(&self, py: Python) -> PyResult<Py<PyAny>> {
+        self.get_sun_dec_deg(py)
+    }
+
+    #[getter]
+    fn moon_ra_deg(&self, py: Python) -> PyResult<Py<PyAny>> {
+        self.get_moon_ra_deg(py)
+    }
+
+    #[getter]
+    fn moon_dec_deg(&self, py: Python) -> PyResult<Py<PyAny>> {
+        self.get_moon_dec_deg(py)
+    }
+
+    #[getter]
+    fn earth_ra_deg(&self, py: Python) -> PyResult<Py<PyAny>> {
+        self.get_earth_ra_deg(py)
+    }
+
+    #[getter]
+    fn earth_dec_deg(&self, py: Python) -> PyResult<Py<PyAny>> {
+        self.get_earth_dec_deg(py)
+    }
+
+    #[getter]
+    fn sun_ra_rad(&self, py: Python) -> PyResult<Py<PyAny>> {
+        self.get_sun_ra_rad(py)
+    }
+
+    #[getter]
+    fn sun_dec_rad(&self, py: Python) -> PyResult<Py<PyAny>> {
+        self.get_sun_dec_rad(py)
+    }
+
+    #[getter]
+    fn moon_ra_rad(&self, py: Python) -> PyResult<Py<PyAny>> {
+        self.get_moon_ra_rad(py)
+    }
+
+    #[getter]
+    fn moon_dec_rad(&self, py: Python) -> PyResult<Py<PyAny>> {
+        self.get_moon_dec_rad(py)
+    }
+
+    #[getter]
+    fn earth_ra_rad(&self, py: Python) -> PyResult<Py<PyAny>> {
+        self.get_earth_ra_rad(py)
+    }
+
+    #[getter]
+    fn earth_dec_rad(&self, py: Python) -> PyResult<Py<PyAny>> {
+        self.get_earth_dec_rad(py)
+    }
+
+    /// Fraction of the solar disk visible from the observer (1.0 sunlit, 0.0 umbra)
+    #[getter]
+    fn illumination_factor(&self, py: Python) -> PyResult<Py<PyAny>> {
+        let factors = EphemerisBase::illumination_factor(self)?;
+        Ok(factors.into_pyarray(py).into())
+    }
+
+    /// Geodetic latitude and longitude rates (deg/s) and height rate (km/s), shape (N, 3)
+    #[getter]
+    fn geodetic_rates(&self, py: Python) -> PyResult<Option<Py<PyAny>>> {
+        Ok(EphemerisBase::geodetic_rates(self)?.map(|rates| rates.into_pyarray(py).into()))
+    }
+
+    /// Speed of the sub-observer point over the WGS84 ellipsoid (km/s)
+    #[getter]
+    fn ground_speed(&self, py: Python) -> PyResult<Option<Py<PyAny>>> {
+        Ok(EphemerisBase::ground_speed(self)?.map(|speed| speed.into_pyarray(py).into()))
+    }
+
+    /// Geodetic (latitude, longitude) in degrees below the observer, shape (N, 2)
+    #[getter]
+    fn sub_satellite_point(&self, py: Python) -> PyResult<Option<Py<PyAny>>> {
+        Ok(EphemerisBase::sub_satellite_point(self)?.map(|points| points.into_pyarray(py).into()))
+    }
+
+    /// (latitude, longitude) in degrees with the Sun at the zenith, shape (N, 2)
+    #[getter]
+    fn sub_solar_point(&self, py: Python) -> PyResult<Py<PyAny>> {
+        Ok(EphemerisBase::sub_solar_point(self)?
+            .into_pyarray(py)
+            .into())
+    }
+
+    /// Solar zenith angle at the sub-satellite point in degrees (daylit below 90)
+    #[getter]
+    fn nadir_solar_zenith_angle(&self, py: Python) -> PyResult<Option<Py<PyAny>>> {
+        Ok(EphemerisBase::nadir_solar_zenith_angle(self)?
+            .map(|angles| angles.into_pyarray(py).into()))
+    }
+
+    /// Calculate Moon illumination fraction for all ephemeris times
+    ///
+    /// Returns the fraction of the Moon's illuminated surface as seen from the
+    /// spacecraft observer (0.0 = new moon, 1.0 = full moon).
+    ///
+    /// # Arguments
+    /// * `time_indices` - Optional indices into ephemeris times (default: all times)
+    ///
+    /// # Returns
+    /// List of Moon illumination fractions
+    #[pyo3(signature = (time_indices=None))]
+    fn moon_illumination(&self, time_indices: Option<Vec<usize>>) -> PyResult<Vec<f64>> {
+        EphemerisBase::moon_illumination(self, time_indices.as_deref())
+    }
+
+    /// Imaging opportunities of a ground target
+    ///
+    /// Windows in which the point at (`lat`, `lon`) is above its local horizon,
+    /// within `max_off_nadir_deg` of the spacecraft's nadir and, if
+    /// `min_sun_elev` is given, lit by the Sun at least that high. Each
+    /// opportunity reports the off-nadir angle, azimuth and ground-sample
+    /// geometry at its smallest off-nadir angle.
+    ///
+    /// # Arguments
+    /// * `lat` - Geodetic latitude of the target in degrees
+    /// * `lon` - Longitude of the target in degrees, east positive
+    /// * `max_off_nadir_deg` - Maximum off-nadir angle in degrees
+    /// * `min_sun_elev` - Minimum Sun elevation at the target in degrees (default: no limit)
+    ///
+    /// # Returns
+    /// List of `AccessOpportunity` in time order
+    #[pyo3(signature = (lat, lon, max_off_nadir_deg, min_sun_elev=None))]
+    fn access_to(
+        &self,
+        lat: f64,
+        lon: f64,
+        max_off_nadir_deg: f64,
+        min_sun_elev: Option<f64>,
+    ) -> PyResult<Vec<AccessOpportunity>> {
+        ground_access::access_to(self, lat, lon, max_off_nadir_deg, min_sun_elev)
+    }
+
+    fn index(&self, time: &Bound<'_, PyDateTime>) -> PyResult<usize> {
+        self.find_closest_index(time)
+    }
+
+    /// Stable hex digest of the source, time grid, options and computed states
+    ///
+    /// Ephemerides with equal fingerprints compare equal, so the fingerprint
+    /// can key caches of results computed from this ephemeris.
+    fn fingerprint(&self) -> String {
+        EphemerisBase::fingerprint(self)
+    }
+
+    fn __eq__(&self, other: &Bound<'_, PyAny>) -> bool {
+        ephemeris_eq(self, other)
+    }
+
+    fn __hash__(&self) -> u64 {
+        ephemeris_hash(self)
+    }
+
+    /// Source, time span, computed frames and Earth orientation state
+    ///
+    /// Values are JSON-serializable (times as ISO 8601 strings), so the dict
+    /// can be logged as provenance alongside derived results.
+    #[getter]
+    fn metadata(&self, py: Python) -> PyResult<Py<PyAny>> {
+        ephemeris_metadata(py, self)
+    }
+
+    /// Reproducibility record: library version, source and fingerprint,
+    /// element set, input and kernel file digests, Earth orientation and
+    /// leap-second data
+    #[getter]
+    fn provenance(&self) -> Provenance {
+        ephemeris_provenance(self)
+    }
+
+    /// Human-readable, multi-line description of `metadata`
+    fn summary(&self) -> String {
+        ephemeris_summary(self)
+    }
+
+    fn __repr__(&self) -> String {
+        ephemeris_repr(self)
+    }
+
+    fn __len__(&self) -> usize {
+        ephemeris_len(self)
+    }
+
+    /// State at time step `index`; negative indices count from the end
+    fn __getitem__(&self, index: isize) -> PyResult<EphemerisSample> {
+        ephemeris_getitem(self, index)
+    }
+
+    fn __iter__(slf: Bound<'_, Self>) -> EphemerisIterator {
+        EphemerisIterator::new(slf.into_any().unbind())
+    }
+
+    #[pyo3(signature = (body, spice_kernel=None, use_horizons=false, source=None))]
+    fn get_body_pv(
+        &self,
+        py: Python,
+        body: &str,
+        spice_kernel: Option<String>,
+        use_horizons: bool,
+        source: Option<String>,
+    ) -> PyResult<Py<PositionVelocityData>> {
+        <Self as EphemerisBase>::get_body_pv(
+            self,
+            py,
+            body,
+            spice_kernel.as_deref(),
+            use_horizons,
+            source.as_deref(),
+        )
+    }
+
+    #[pyo3(signature = (bodies, spice_kernel=None, use_horizons=false, source=None))]
+    fn get_bodies(
+        &self,
+        py: Python,
+        bodies: Vec<String>,
+        spice_kernel: Option<String>,
+        use_horizons: bool,
+        source: Option<String>,
+    ) -> PyResult<Py<pyo3::types::PyDict>> {
+        <Self as EphemerisBase>::get_bodies(
+            self,
+            py,
+            &bodies,
+            spice_kernel.as_deref(),
+            use_horizons,
+            source.as_deref(),
+        )
+    }
+
+    /// Angular separation in degrees between two targets at each time step
+    ///
+    /// Each of `a` and `b` may be an `(ra_deg, dec_deg)` pair, a body name,
+    /// observer-relative `PositionVelocityData` or a SkyCoord.
+    fn separation(
+        &self,
+        py: Python,
+        a: &Bound<'_, PyAny>,
+        b: &Bound<'_, PyAny>,
+    ) -> PyResult<Py<PyAny>> {
+        let angles = <Self as EphemerisBase>::separation(self, py, a, b)?;
+        Ok(angles.into_pyarray(py).into())
+    }
+
+    /// Angle in degrees between a target and the Sun at each time step
+    #[pyo3(signature = (ra_deg, dec_deg, time_indices=None))]
+    fn sun_angle(
+        &self,
+        py: Python,
+        ra_deg: f64,
+        dec_deg: f64,
+        time_indices: Option<Vec<usize>>,
+    ) -> PyResult<Py<PyAny>> {
+        let angles = EphemerisBase::sun_angle(self, ra_deg, dec_deg, time_indices.as_deref())?;
+        Ok(angles.into_pyarray(py).into())
+    }
+
+    /// Angle in degrees between a target and the Moon at each time step
+    #[pyo3(signature = (ra_deg, dec_deg, time_indices=None))]
+    fn moon_angle(
+        &self,
+        py: Python,
+        ra_deg: f64,
+        dec_deg: f64,
+        time_indices: Option<Vec<usize>>,
+    ) -> PyResult<Py<PyAny>> {
+        let angles = EphemerisBase::moon_angle(self, ra_deg, dec_deg, time_indices.as_deref())?;
+        Ok(angles.into_pyarray(py).into())
+    }
+
+    /// Angle in degrees of a target above the Earth's limb (negative behind it)
+    #[pyo3(signature = (ra_deg, dec_deg, time_indices=None))]
+    fn earth_limb_angle(
+        &self,
+        py: Python,
+        ra_deg: f64,
+        dec_deg: f64,
+        time_indices: Option<Vec<usize>>,
+    ) -> PyResult<Py<PyAny>> {
+        let angles =
+            EphemerisBase::earth_limb_angle(self, ra_deg, dec_deg, time_indices.as_deref())?;
+        Ok(angles.into_pyarray(py).into())
+    }
+
+    /// Sun, Moon and Earth-limb angles, altitude, eclipse, SAA, orbital phase
+    /// and beta angle for a target at the ephemeris step nearest `time`
+    #[pyo3(signature = (ra_deg, dec_deg, time, *, saa_polygon=None))]
+    fn geometry(
+        &self,
+        ra_deg: f64,
+        dec_deg: f64,
+        time: &Bound<'_, PyDateTime>,
+        saa_polygon: Option<Vec<(f64, f64)>>,
+    ) -> PyResult<ObservationGeometry> {
+        let index = self.find_closest_index(time)?;
+        observation_geometry(self, ra_deg, dec_deg, index, saa_polygon.as_deref())
+    }
+
+    #[pyo3(signature = (body, spice_kernel=None, use_horizons=false, source=None))]
+    fn get_body(
+        &self,
+        py: Python,
+        body: &str,
+        spice_kernel: Option<String>,
+        use_horizons: bool,
+        source: Option<String>,
+    ) -> PyResult<Py<PyAny>> {
+        let modules = AstropyModules::import(py)?;
+        <Self as EphemerisBase>::get_body(
+            self,
+            py,
+            &modules,
+            body,
+            spice_kernel.as_deref(),
+            use_horizons,
+            source.as_deref(),
+        )
+    }
+
+    /// Convert RA/Dec to Altitude/Azimuth for this ephemeris
+    /// Returns NumPy array (N,2): [altitude_deg, azimuth_deg]
+    #[pyo3(signature = (ra_deg, dec_deg, time_indices=None, *, polar_motion=None))]
+    fn radec_to_altaz(
+        &self,
+        py: Python,
+        ra_deg: f64,
+        dec_deg: f64,
+        time_indices: Option<Vec<usize>>,
+        polar_motion: Option<bool>,
+    ) -> PyResult<Py<PyAny>> {
+        let arr = crate::utils::celestial::radec_to_altaz(
+            ra_deg,
+            dec_deg,
+            self,
+            time_indices.as_deref(),
+            polar_motion,
+        );
+        Ok(arr.into_pyarray(py).into())
+    }
+
+    /// Convert many RA/Dec targets to Altitude/Azimuth in one call
+    /// Returns NumPy array (n_targets, N, 2): [altitude_deg, azimuth_deg]
+    #[pyo3(signature = (ra_deg, dec_deg, time_indices=None, *, polar_motion=None))]
+    fn radec_to_altaz_batch(
+        &self,
+        py: Python,
+        ra_deg: Vec<f64>,
+        dec_deg: Vec<f64>,
+        time_indices: Option<Vec<usize>>,
+        polar_motion: Option<bool>,
+    ) -> PyResult<Py<PyAny>> {
+        if ra_deg.len() != dec_deg.len() {
+            return Err(pyo3::exceptions::PyValueError::new_err(
+                "ra_deg and dec_deg must have the same length",
+            ));
+        }
+        let arr = crate::utils::celestial::radec_to_altaz_batch(
+            &ra_deg,
+            &dec_deg,
+            self,
+            time_indices.as_deref(),
+            polar_motion,
+        );
+        Ok(arr.into_pyarray(py).into())
+    }
+
+    /// Calculate airmass for a target at given RA/Dec
+    ///
+    /// Airmass represents the relative path length through Earth's atmosphere compared to
+    /// zenith observation. Lower values indicate better observing conditions.
+    ///
+    /// # Arguments
+    /// * `ra_deg` - Right ascension in degrees (ICRS/J2000)
+    /// * `dec_deg` - Declination in degrees (ICRS/J2000)
+    /// * `time_indices` - Optional indices into ephemeris times (default: all times)
+    ///
+    /// # Returns
+    /// List of airmass values:
+    /// - 1.0 at zenith (directly overhead)
+    /// - ~2.0 at 30° altitude
+    /// - ~5.8 at 10° altitude
+    /// - Infinity for targets below horizon
+    #[pyo3(signature = (ra_deg, dec_deg, time_indices=None))]
+    fn calculate_airmass(
+        &self,
+        ra_deg: f64,
+        dec_deg: f64,
+        time_indices: Option<Vec<usize>>,
+    ) -> PyResult<Vec<f64>> {
+        <Self as crate::ephemeris::ephemeris_common::EphemerisBase>::calculate_airmass(
+            self,
+            ra_deg,
+            dec_deg,
+            time_indices.as_deref(),
+        )
+    }
+
+    /// Calculate airmass for many targets in one call
+    ///
+    /// Uses the vectorized Kasten pipeline of the airmass constraint, without
+    /// refraction or aberration, so there are no per-target Python calls.
+    ///
+    /// # Returns
+    /// NumPy array (n_targets, N) of airmass values, infinity below the horizon
+    #[pyo3(signature = (ras, decs, time_indices=None))]
+    fn airmass_batch(
+        &self,
+        py: Python,
+        ras: Vec<f64>,
+        decs: Vec<f64>,
+        time_indices: Option<Vec<usize>>,
+    ) -> PyResult<Py<PyAny>> {
+        let arr =
+            <Self as crate::ephemeris::ephemeris_common::EphemerisBase>::calculate_airmass_batch(
+                self,
+                &ras,
+                &decs,
+                time_indices.as_deref(),
+            )?;
+        Ok(arr.into_pyarray(py).into())
+    }
+}
+
+impl ArrayEphemeris {
+    /// Ephemeris from GCRS states (N x 6, km and km/s) at strictly increasing
+    /// `times`, for use from Rust
+    ///
+    /// The input times are used as the time grid; ITRS and the Sun and Moon
+    /// are computed as for the Python constructor with its defaults.
+    pub fn from_gcrs_states(
+        times: Vec<DateTime<Utc>>,
+        states: Array2<f64>,
+    ) -> Result<Self, String> {
+        Self::check_source_times(&times)?;
+        if states.dim() != (times.len(), 6) {
+            return Err(format!(
+                "states must have shape ({}, 6), found {:?}",
+                times.len(),
+                states.dim()
+            ));
+        }
+        if states.iter().any(|v| !v.is_finite()) {
+            return Err("states must be finite".to_string());
+        }
+        let mut ephemeris = ArrayEphemeris {
+            source_frame: Frame::GCRS,
+            itrs: None,
+            itrs_skycoord: OnceLock::new(),
+            polar_motion: false,
+            common_data: {
+                let mut data = EphemerisData::new();
+                data.times = Some(times.clone());
+                data
+            },
+            source_times: times,
+            source_states: states,
+            interpolation: Interpolation::default(),
+            geo_slot: None,
+        };
+        ephemeris.interpolate_and_convert()?;
+        ephemeris
+            .calculate_sun_moon()
+            .map_err(|_| "No times available".to_string())?;
+        Ok(ephemeris)
+    }
+
+    /// Interpolate GCRS states onto `query_times`, which must lie within `source_times`
+    pub(crate) fn resampled(
+        source_times: Vec<DateTime<Utc>>,
+        gcrs_states: Array2<f64>,
+        query_times: Vec<DateTime<Utc>>,
+    ) -> PyResult<Self> {
+        let mut ephemeris = ArrayEphemeris {
+            source_frame: Frame::GCRS,
+            itrs: None,
+            itrs_skycoord: OnceLock::new(),
+            polar_motion: false,
+            common_data: {
+                let mut data = EphemerisData::new();
+                data.times = Some(query_times);
+                data
+            },
+            source_times,
+            source_states: gcrs_states,
+            interpolation: Interpolation::default(),
+            geo_slot: None,
+        };
+        ephemeris
+            .interpolate_and_convert()
+            .map_err(pyo3::exceptions::PyValueError::new_err)?;
+        ephemeris.calculate_sun_moon()?;
+        Ok(ephemeris)
+    }
+
+    /// Ephemeris of a satellite held fixed at a nominal GEO slot
+    pub(crate) fn from_geo_slot(
+        times: Vec<DateTime<Utc>>,
+        slot: GeoSlot,
+        polar_motion: bool,
+        time_scale: GridTimeScale,
+    ) -> PyResult<Self> {
+        let [x, y, z] = slot.itrs_position();
+        let states = Array2::from_shape_fn((times.len(), 6), |(_, j)| match j {
+            0 => x,
+            1 => y,
+            2 => z,
+            _ => 0.0,
+        });
+        let mut ephemeris = ArrayEphemeris {
+            source_frame: Frame::ITRS,
+            itrs: None,
+            itrs_skycoord: OnceLock::new(),
+            polar_motion,
+            common_data: {
+                let mut data = EphemerisData::new();
+                data.times = Some(times.clone());
+                data.time_scale = time_scale;
+                data
+            },
+            source_times: times,
+            source_states: states,
+            interpolation: Interpolation::default(),
+            geo_slot: Some(slot),
+        };
+        ephemeris
+            .interpolate_and_convert()
+            .map_err(pyo3::exceptions::PyValueError::new_err)?;
+        ephemeris.calculate_sun_moon()?;
+        Ok(ephemeris)
+    }
+
+    /// Parse the frame name of the input states
+    fn parse_frame(frame: &str) -> PyResult<Frame> {
+        match frame.trim().to_uppercase().as_str() {
+            "GCRS" => Ok(Frame::GCRS),
+            "ITRS" => Ok(Frame::ITRS),
+            "TEME" => Ok(Frame::TEME),
+            "PEF" => Ok(Frame::PEF),
+            other => Err(pyo3::exceptions::PyValueError::new_err(format!(
+                "Unsupported frame '{}'. Expected 'GCRS', 'ITRS', 'TEME' or 'PEF'",
+                other
+            ))),
+        }
+    }
+
+    /// Validate the state rows and pack them into an N x 6 array
+    fn states_to_array(states: &[Vec<f64>], n_times: usize) -> PyResult<Array2<f64>> {
+        if states.len() != n_times {
+            return Err(pyo3::exceptions::PyValueError::new_err(format!(
+                "Number of state vectors ({}) does not match number of times ({})",
+                states.len(),
+                n_times
+            )));
+        }
+        if let Some(row) = states.iter().find(|row| row.len() != 6) {
+            return Err(pyo3::exceptions::PyValueError::new_err(format!(
+                "states must have shape (N, 6), found a row of length {}",
+                row.len()
+            )));
+        }
+        if states.iter().flatten().any(|v| !v.is_finite()) {
+            return Err(pyo3::exceptions::PyValueError::new_err(
+                "states must be finite",
+            ));
+        }
+        Array2::from_shape_vec((states.len(), 6), states.concat())
+            .map_err(|e| pyo3::exceptions::PyValueError::new_err(e.to_string()))
+    }
+
+    /// Check that there are enough input times and that they increase
+    fn check_source_times(times: &[DateTime<Utc>]) -> Result<(), String> {
+        if times.len() < 2 {
+            return Err("At least 2 state vectors are required".to_string());
+        }
+        if times.windows(2).any(|w| w[1] <= w[0]) {
+            return Err("times must be strictly increasing".to_string());
+        }
+        Ok(())
+    }
+
+    /// Resample the input states onto the query grid and fill GCRS and ITRS
+    fn interpolate_and_convert(&mut self) -> Result<(), String> {
+        let times = self
+            .common_data
+            .times
+            .as_ref()
+            .ok_or("No times available for interpolation")?;
+
+        let states = if *times == self.source_times {
+            self.source_states.clone()
+        } else {
+            self.interpolation.check_gaps(times, &self.source_times)?;
+            self.interpolation
+                .interpolate(times, &self.source_times, &self.source_states)
+        };
+
+        let (gcrs, itrs) = match self.source_frame {
+            Frame::GCRS => {
+                let itrs = conversions::gcrs_to_itrs(
+                    &states,
+                    times,
+                    self.polar_motion,
+                    self.common_data.fast_itrs,
+                );
+                (states, itrs)
+            }
+            Frame::ITRS => {
+                let gcrs = conversions::convert_frames(
+                    &states,
+                    times,
+                    Frame::ITRS,
+                    Frame::GCRS,
+                    self.polar_motion,
+                );
+                (gcrs, states)
+            }
+            Frame::TEME | Frame::PEF => {
+                let gcrs = conversions::convert_frames(
+                    &states,
+                    times,
+                    self.source_frame,
+                    Frame::GCRS,
+                    self.polar_motion,
+                );
+                let itrs = if self.common_data.fast_itrs {
+                    conversions::gcrs_to_itrs_fast(&gcrs, times)
+                } else {
+                    conversions::convert_frames(
+                        &states,
+                        times,
+                        self.source_frame,
+                        Frame::ITRS,
+                        self.polar_motion,
+                    )
+                };
+                (gcrs, itrs)
+            }
+        };
+
+        self.common_data.gcrs = Some(gcrs);
+        self.itrs = Some(itrs);
+        Ok(())
+    }
+}
+
+// Implement the EphemerisBase trait for ArrayEphemeris
+impl EphemerisBase for ArrayEphemeris {
+    fn data(&self) -> &EphemerisData {
+        &self.common_data
+    }
+
+    fn data_mut(&mut self) -> &mut EphemerisData {
+        &mut self.common_data
+    }
+
+    fn get_itrs_data(&self) -> Option<&Array2<f64>> {
+        self.itrs.as_ref()
+    }
+
+    fn get_itrs_skycoord_ref(&self) -> Option<&Py<PyAny>> {
+        self.itrs_skycoord.get()
+    }
+
+    fn set_itrs_skycoord_cache(&self, skycoord: Py<PyAny>) -> Result<(), Py<PyAny>> {
+        self.itrs_skycoord.set(skycoord)
+    }
+
+    fn source_identity(&self) -> String {
+        if let Some(slot) = &self.geo_slot {
+            return format!(
+                "GEOEphemeris\nlongitude={}\nlatitude={}\nradius={}\npolar_motion={}",
+                slot.longitude, slot.latitude, slot.radius, self.polar_motion
+            );
+        }
+        format!(
+            "ArrayEphemeris\nframe={:?}\npolar_motion={}",
+            self.source_frame, self.polar_motion
+        )
+    }
+
+    fn source_metadata(&self) -> Vec<(&'static str, serde_json::Value)> {
+        if let Some(slot) = &self.geo_slot {
+            return vec![
+                ("type", "GEOEphemeris".into()),
+                ("longitude", slot.longitude.into()),
+                ("latitude", slot.latitude.into()),
+                ("radius", slot.radius.into()),
+                ("polar_motion", self.polar_motion.into()),
+            ];
+        }
+        vec![
+            ("type", "ArrayEphemeris".into()),
+            ("frame", self.source_frame().into()),
+            ("polar_motion", self.polar_motion.into()),
+        ]
+    }
+
+    fn applies_polar_motion(&self) -> bool {
+        self.polar_motion
+    }
+
+    fn radec_to_altaz(
+        &self,
+        ra_deg: f64,
+        dec_deg: f64,
+        time_indices: Option<&[usize]>,
+    ) -> Array2<f64> {
+        crate::utils::celestial::radec_to_altaz(ra_deg, dec_deg, self, time_indices, None)
+    }
+}
@@ -3,19 +3,36 @@
 //! This module provides support for CCSDS Orbit Ephemeris Messages (OEM)
 //! which are standard formats for exchanging spacecraft orbit data.
 //!
-//! ## Reference Frame Requirements
+//! ## Reference Frames
 //!
-//! The OEM file must specify a reference frame that is compatible with GCRS
-//! (Geocentric Celestial Reference System). Compatible frames include:
-//! - J2000 / EME2000 (Earth Mean Equator and Equinox of J2000.0)
-//! - GCRF (Geocentric Celestial Reference Frame)
-//! - ICRF (International Celestial Reference Frame)
+//! Each segment's REF_FRAME is honored and the states are converted to GCRS
+//! (Geocentric Celestial Reference System) on load. Supported frames:
+//! - J2000 / EME2000, GCRF and ICRF variants, taken as GCRS
+//! - TOD (true equator and equinox of date)
+//! - TEME (true equator, mean equinox, as produced by SGP4)
+//! - ITRF and its realizations (ITRF93 ... ITRF2020), Earth-fixed
 //!
-//! If the OEM file uses a different reference frame, loading will fail with
-//! an error indicating the incompatible frame.
-
-use chrono::{DateTime, TimeZone, Utc};
-use ndarray::Array2;
+//! All segments must share one REF_FRAME and have CENTER_NAME = EARTH. Any
+//! other frame or center fails to load with an error naming it, rather than
+//! being read as GCRS.
+//!
+//! ## Interpolation
+//!
+//! States are resampled with cubic Hermite interpolation by default, or with
+//! Hermite or Lagrange interpolation over a wider window of OEM samples.
+//! `max_gap` rejects grids that would interpolate across a gap in the OEM
+//! data wider than a given spacing.
+//!
+//! ## Covariance
+//!
+//! COVARIANCE_START/COVARIANCE_STOP blocks are read when present. Matrices in
+//! the segment frame or in an orbit-local RTN/RSW frame are supported; they
+//! are propagated linearly to each ephemeris timestamp to give 1-sigma
+//! radial / in-track / cross-track position uncertainties.
+
+use chrono::{DateTime, Utc};
+use erfa::{prenut::pn_matrix_06a, vectors_and_matrices::mat_mul_pvec};
+use ndarray::{Array2, Array3};
 use numpy::IntoPyArray;
 use pyo3::{prelude::*, types::PyDateTime};
 use std::fs::File;
@@ -24,13 +41,111 @@ use std::path::Path;
 use std::sync::OnceLock;
 
 use crate::ephemeris::ephemeris_common::{
-    generate_timestamps, split_pos_vel, EphemerisBase, EphemerisData,
+    check_fast_itrs, generate_timestamps, parse_time_scale, split_pos_vel, EphemerisBase,
+    EphemerisData,
 };
+use crate::ephemeris::ground_access::{self, AccessOpportunity};
+use crate::ephemeris::observation_geometry::{observation_geometry, ObservationGeometry};
 use crate::ephemeris::position_velocity::PositionVelocityData;
+use crate::ephemeris::provenance::{ephemeris_provenance, Provenance};
+use crate::ephemeris::sample::{
+    ephemeris_getitem, ephemeris_len, EphemerisIterator, EphemerisSample,
+};
+use crate::ephemeris::{
+    ephemeris_eq, ephemeris_hash, ephemeris_metadata, ephemeris_repr, ephemeris_summary,
+};
 use crate::utils::conversions;
-use crate::utils::interpolation::hermite_interpolate;
-use crate::utils::time_utils::python_datetime_to_utc;
+use crate::utils::covariance::{self, Matrix6};
+use crate::utils::interpolation::{hermite_interpolate, Interpolation};
+use crate::utils::math_utils::transpose_matrix;
+use crate::utils::time_utils::{
+    datetime_to_jd_tt, python_datetime_to_utc, utc_from_fields, utc_to_python_datetime,
+};
 use crate::utils::to_skycoord::AstropyModules;
+use crate::utils::vector_math::ric_basis;
+
+/// Reference frame declared by an OEM segment's REF_FRAME
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum OemFrame {
+    /// J2000/EME2000, GCRF and ICRF variants, taken as GCRS
+    Gcrs,
+    /// True equator and equinox of date
+    Tod,
+    /// True equator, mean equinox (SGP4 output)
+    Teme,
+    /// Earth-fixed ITRF realizations
+    Itrs,
+}
+
+impl OemFrame {
+    fn parse(frame: &str) -> Option<Self> {
+        match frame.trim().to_uppercase().as_str() {
+            "J2000" | "EME2000" | "GCRF" | "ICRF" | "ICRF2" | "ICRF3" => Some(OemFrame::Gcrs),
+            "TOD" => Some(OemFrame::Tod),
+            "TEME" => Some(OemFrame::Teme),
+            "ITRF" | "ITRF93" | "ITRF97" | "ITRF2000" | "ITRF2005" | "ITRF2008" | "ITRF2014"
+            | "ITRF2020" => Some(OemFrame::Itrs),
+            _ => None,
+        }
+    }
+
+    /// Convert Nx6 states in this frame to GCRS
+    ///
+    /// TOD is rotated by the transpose of the IAU 2006/2000A bias-precession-
+    /// nutation matrix; the slow rotation of the TOD axes is neglected.
+    fn to_gcrs(
+        self,
+        states: &Array2<f64>,
+        times: &[DateTime<Utc>],
+        polar_motion: bool,
+    ) -> Array2<f64> {
+        match self {
+            OemFrame::Gcrs => states.clone(),
+            OemFrame::Teme => conversions::convert_frames(
+                states,
+                times,
+                conversions::Frame::TEME,
+                conversions::Frame::GCRS,
+                false,
+            ),
+            OemFrame::Itrs => conversions::convert_frames(
+                states,
+                times,
+                conversions::Frame::ITRS,
+                conversions::Frame::GCRS,
+                polar_motion,
+            ),
+            OemFrame::Tod => {
+                let mut out = Array2::<f64>::zeros(states.raw_dim());
+                for (i, dt) in times.iter().enumerate() {
+                    let (jd_tt1, jd_tt2) = datetime_to_jd_tt(dt);
+                    let tod_to_gcrs = transpose_matrix(pn_matrix_06a(jd_tt1, jd_tt2));
+                    let pos = mat_mul_pvec(
+                        tod_to_gcrs,
+                        [states[[i, 0]], states[[i, 1]], states[[i, 2]]],
+                    );
+                    let vel = mat_mul_pvec(
+                        tod_to_gcrs,
+                        [states[[i, 3]], states[[i, 4]], states[[i, 5]]],
+                    );
+                    for (j, (p, v)) in pos.iter().zip(vel).enumerate() {
+                        out[[i, j]] = *p;
+                        out[[i, j + 3]] = v;
+                    }
+                }
+                out
+            }
+        }
+    }
+
+    /// Rotation taking vectors in this frame to GCRS at `epoch`
+    fn rotation_to_gcrs(self, epoch: DateTime<Utc>) -> [[f64; 3]; 3] {
+        // Columns are the frame's axes expressed in GCRS
+        let axes = Array2::from_shape_fn((3, 6), |(i, j)| if i == j { 1.0 } else { 0.0 });
+        let gcrs = self.to_gcrs(&axes, &[epoch; 3], false);
+        std::array::from_fn(|i| std::array::from_fn(|j| gcrs[[j, i]]))
+    }
+}
 
 /// A simple OEM state vector record
 #[derive(Debug, Clone)]
@@ -44,24 +159,54 @@ struct StateVectorRecord {
     z_dot: f64,
 }
 
+/// An OEM covariance matrix record
+#[derive(Debug, Clone)]
+struct CovarianceRecord {
+    epoch: DateTime<Utc>,
+    /// COV_REF_FRAME, if given; otherwise the segment REF_FRAME applies
+    frame: Option<String>,
+    matrix: Matrix6,
+}
+
+/// Contents of an OEM file, with the metadata shared by its segments
+struct ParsedOem {
+    records: Vec<StateVectorRecord>,
+    covariances: Vec<CovarianceRecord>,
+    /// REF_FRAME as declared
+    ref_frame: String,
+    frame: OemFrame,
+    /// CENTER_NAME as declared
+    center_name: String,
+}
+
 #[pyclass]
 pub struct OEMEphemeris {
-    #[allow(dead_code)] // Stored for debugging/inspection purposes
     oem_path: String,
+    /// REF_FRAME declared in the OEM metadata
+    ref_frame: String,
+    /// CENTER_NAME declared in the OEM metadata
+    center_name: String,
     itrs: Option<Array2<f64>>,
     itrs_skycoord: OnceLock<Py<PyAny>>, // Lazy-initialized cached SkyCoord object for ITRS
     polar_motion: bool,                 // Whether to apply polar motion correction
     // Common ephemeris data
     common_data: EphemerisData,
-    // Store raw OEM data for reference
+    // Store raw OEM data for reference, converted to GCRS
     oem_times: Vec<DateTime<Utc>>,
     oem_states: Array2<f64>,
+    // Interpolation used to resample the OEM states
+    interpolation: Interpolation,
+    // OEM covariances in the inertial frame, sorted by epoch
+    covariances: Vec<(DateTime<Utc>, Matrix6)>,
+    // 1-sigma [radial, in-track, cross-track] position uncertainty per timestamp (km)
+    position_uncertainty: Option<Array2<f64>>,
 }
 
 #[pymethods]
 impl OEMEphemeris {
     #[new]
-    #[pyo3(signature = (oem_path, begin, end, step_size=60, *, polar_motion=false))]
+    #[allow(clippy::too_many_arguments)]
+    #[pyo3(signature = (oem_path, begin, end, step_size=60, *, polar_motion=false, fast_itrs=false, time_scale="UTC", interpolation="hermite", interpolation_window=None, max_gap=None))]
     fn new(
         _py: Python,
         oem_path: String,
@@ -69,19 +214,32 @@ impl OEMEphemeris {
         end: &Bound<'_, PyDateTime>,
         step_size: i64,
         polar_motion: bool,
+        fast_itrs: bool,
+        time_scale: &str,
+        interpolation: &str,
+        interpolation_window: Option<usize>,
+        max_gap: Option<f64>,
     ) -> PyResult<Self> {
+        check_fast_itrs(fast_itrs, polar_motion)?;
+        let time_scale = parse_time_scale(time_scale)?;
+        let interpolation = Interpolation::new(interpolation, interpolation_window, max_gap)
+            .map_err(pyo3::exceptions::PyValueError::new_err)?;
+
         // Load and parse the OEM file
         let path = Path::new(&oem_path);
-        let records = Self::parse_oem_file(path)?;
+        let parsed = Self::parse_oem_file(path)?;
 
-        if records.is_empty() {
+        if parsed.records.is_empty() {
             return Err(pyo3::exceptions::PyValueError::new_err(
                 "OEM file contains no state vectors",
             ));
         }
 
-        // Extract times and states from OEM records
-        let (oem_times, oem_states) = Self::extract_oem_data(&records)?;
+        // Extract times and states from OEM records, converted to GCRS
+        let (oem_times, oem_states) = Self::extract_oem_data(&parsed.records)?;
+        let oem_states = parsed.frame.to_gcrs(&oem_states, &oem_times, polar_motion);
+        let covariances =
+            Self::inertial_covariances(&parsed.covariances, parsed.frame, &oem_times, &oem_states)?;
 
         // Validate time range
         let begin_dt = python_datetime_to_utc(begin)?;
@@ -98,27 +256,35 @@ impl OEMEphemeris {
         }
 
         // Generate query timestamps
-        let times = generate_timestamps(begin, end, step_size)?;
+        let times = generate_timestamps(begin, end, step_size, time_scale)?;
 
         // Create the OEMEphemeris object
         let mut ephemeris = OEMEphemeris {
             oem_path,
+            ref_frame: parsed.ref_frame,
+            center_name: parsed.center_name,
             itrs: None,
             itrs_skycoord: OnceLock::new(),
             polar_motion,
             common_data: {
                 let mut data = EphemerisData::new();
                 data.times = Some(times);
+                data.fast_itrs = fast_itrs;
+                data.time_scale = time_scale;
                 data
             },
             oem_times,
             oem_states,
+            interpolation,
+            covariances,
+            position_uncertainty: None,
         };
 
         // Pre-compute all frames
         ephemeris.interpolate_to_gcrs()?;
         ephemeris.gcrs_to_itrs()?;
         ephemeris.calculate_sun_moon()?;
+        ephemeris.propagate_covariance()?;
 
         Ok(ephemeris)
     }
@@ -137,9 +303,68 @@ impl OEMEphemeris {
         self.polar_motion
     }
 
+    /// Get whether ITRS skips the UT1-UTC correction and polar motion
+    #[getter]
+    fn fast_itrs(&self) -> bool {
+        self.common_data.fast_itrs
+    }
+
+    /// Get the time scale in which the timestamp grid is uniform
+    #[getter]
+    fn time_scale(&self) -> &'static str {
+        self.common_data.time_scale.name()
+    }
+
+    /// Get the resampling scheme ("hermite" or "lagrange")
+    #[getter]
+    fn interpolation(&self) -> &'static str {
+        self.interpolation.method.name()
+    }
+
+    /// Get the number of OEM samples each interpolant passes through
+    #[getter]
+    fn interpolation_window(&self) -> usize {
+        self.interpolation.window
+    }
+
+    /// Get the largest OEM sample spacing in seconds that may be interpolated across
+    #[getter]
+    fn max_gap(&self) -> Option<f64> {
+        self.interpolation.max_gap
+    }
+
+    /// Estimated largest position interpolation error in km over the ephemeris
+    ///
+    /// The interpolant is compared with one through an extra OEM sample at
+    /// the midpoint of each OEM interval in the requested span. None without
+    /// enough OEM samples.
+    #[getter]
+    fn max_interpolation_error(&self) -> Option<f64> {
+        let times = self.common_data.times.as_ref()?;
+        self.interpolation.max_error_estimate(
+            times.first()?,
+            times.last()?,
+            &self.oem_times,
+            &self.oem_states,
+        )
+    }
+
+    /// Get the REF_FRAME declared in the OEM file
+    #[getter]
+    fn ref_frame(&self) -> &str {
+        &self.ref_frame
+    }
+
+    /// Get the CENTER_NAME declared in the OEM file
+    #[getter]
+    fn center_name(&self) -> &str {
+        &self.center_name
+    }
+
     /// Get OEM raw data position and velocity
     ///
-    /// Returns the raw state vectors from the OEM file without interpolation
+    /// Returns the state vectors from the OEM file without interpolation,
+    /// converted from the declared REF_FRAME to GCRS
     #[getter]
     fn oem_pv(&self, py: Python) -> Py<PositionVelocityData> {
         Py::new(py, split_pos_vel(&self.oem_states)).unwrap()
@@ -161,6 +386,44 @@ impl OEMEphemeris {
             .collect()
     }
 
+    /// Covariance matrices from the OEM file as a (K, 6, 6) array
+    ///
+    /// Units are km and km/s. Matrices are expressed in GCRS, with RTN/RSW,
+    /// TOD and TEME covariances rotated into it. Empty if the file has no
+    /// covariance blocks.
+    #[getter]
+    fn covariance(&self, py: Python) -> Py<PyAny> {
+        let mut arr = Array3::<f64>::zeros((self.covariances.len(), 6, 6));
+        for (k, (_, matrix)) in self.covariances.iter().enumerate() {
+            for (i, row) in matrix.iter().enumerate() {
+                for (j, &value) in row.iter().enumerate() {
+                    arr[[k, i, j]] = value;
+                }
+            }
+        }
+        arr.into_pyarray(py).into()
+    }
+
+    /// Epochs of the OEM covariance matrices as Python datetime objects
+    #[getter]
+    fn covariance_timestamp(&self, py: Python) -> PyResult<Vec<Py<PyAny>>> {
+        self.covariances
+            .iter()
+            .map(|(epoch, _)| utc_to_python_datetime(py, epoch))
+            .collect()
+    }
+
+    /// 1-sigma position uncertainty at each timestamp
+    ///
+    /// Returns an (N, 3) NumPy array of [radial, in-track, cross-track] in km,
+    /// or None if the OEM file has no covariance. Each timestamp is propagated
+    /// from the latest covariance at or before it (the first covariance for
+    /// earlier timestamps) using a two-body state transition matrix.
+    #[getter]
+    fn position_uncertainty(&self, py: Python) -> Option<Py<PyAny>> {
+        EphemerisBase::position_uncertainty(self).map(|sigma| sigma.clone().into_pyarray(py).into())
+    }
+
     // ===== Common ephemeris getters (delegating to EphemerisBase trait) =====
 
     #[getter]
@@ -183,6 +446,18 @@ impl OEMEphemeris {
         self.get_gcrs_pv(py)
     }
 
+    /// Observer position/velocity in TEME, converted from GCRS
+    #[getter]
+    fn teme_pv(&self, py: Python) -> Option<Py<PositionVelocityData>> {
+        self.get_teme_pv(py)
+    }
+
+    /// Observer position/velocity in the pseudo-Earth-fixed frame (TEME rotated by GMST)
+    #[getter]
+    fn pef_pv(&self, py: Python) -> Option<Py<PositionVelocityData>> {
+        self.get_pef_pv(py)
+    }
+
     #[getter]
     fn itrs_pv(&self, py: Python) -> Option<Py<PositionVelocityData>> {
         self.get_itrs_pv(py)
@@ -283,6 +558,21 @@ impl OEMEphemeris {
         self.get_height_km(py)
     }
 
+    #[getter]
+    fn geomagnetic_latitude_deg(&self, py: Python) -> PyResult<Option<Py<PyAny>>> {
+        self.get_geomagnetic_latitude_deg(py)
+    }
+
+    #[getter]
+    fn geomagnetic_longitude_deg(&self, py: Python) -> PyResult<Option<Py<PyAny>>> {
+        self.get_geomagnetic_longitude_deg(py)
+    }
+
+    #[getter]
+    fn cutoff_rigidity_gv(&self, py: Python) -> PyResult<Option<Py<PyAny>>> {
+        self.get_cutoff_rigidity_gv(py)
+    }
+
     #[getter]
     fn sun_radius(&self, py: Python) -> PyResult<Py<PyAny>> {
         self.get_sun_radius(py)
@@ -418,6 +708,46 @@ impl OEMEphemeris {
         self.get_earth_dec_rad(py)
     }
 
+    /// Fraction of the solar disk visible from the observer (1.0 sunlit, 0.0 umbra)
+    #[getter]
+    fn illumination_factor(&self, py: Python) -> PyResult<Py<PyAny>> {
+        let factors = EphemerisBase::illumination_factor(self)?;
+        Ok(factors.into_pyarray(py).into())
+    }
+
+    /// Geodetic latitude and longitude rates (deg/s) and height rate (km/s), shape (N, 3)
+    #[getter]
+    fn geodetic_rates(&self, py: Python) -> PyResult<Option<Py<PyAny>>> {
+        Ok(EphemerisBase::geodetic_rates(self)?.map(|rates| rates.into_pyarray(py).into()))
+    }
+
+    /// Speed of the sub-observer point over the WGS84 ellipsoid (km/s)
+    #[getter]
+    fn ground_speed(&self, py: Python) -> PyResult<Option<Py<PyAny>>> {
+        Ok(EphemerisBase::ground_speed(self)?.map(|speed| speed.into_pyarray(py).into()))
+    }
+
+    /// Geodetic (latitude, longitude) in degrees below the observer, shape (N, 2)
+    #[getter]
+    fn sub_satellite_point(&self, py: Python) -> PyResult<Option<Py<PyAny>>> {
+        Ok(EphemerisBase::sub_satellite_point(self)?.map(|points| points.into_pyarray(py).into()))
+    }
+
+    /// (latitude, longitude) in degrees with the Sun at the zenith, shape (N, 2)
+    #[getter]
+    fn sub_solar_point(&self, py: Python) -> PyResult<Py<PyAny>> {
+        Ok(EphemerisBase::sub_solar_point(self)?
+            .into_pyarray(py)
+            .into())
+    }
+
+    /// Solar zenith angle at the sub-satellite point in degrees (daylit below 90)
+    #[getter]
+    fn nadir_solar_zenith_angle(&self, py: Python) -> PyResult<Option<Py<PyAny>>> {
+        Ok(EphemerisBase::nadir_solar_zenith_angle(self)?
+            .map(|angles| angles.into_pyarray(py).into()))
+    }
+
     /// Calculate Moon illumination fraction for all ephemeris times
     ///
     /// Returns the fraction of the Moon's illuminated surface as seen from the
@@ -433,28 +763,206 @@ impl OEMEphemeris {
         EphemerisBase::moon_illumination(self, time_indices.as_deref())
     }
 
+    /// Imaging opportunities of a ground target
+    ///
+    /// Windows in which the point at (`lat`, `lon`) is above its local horizon,
+    /// within `max_off_nadir_deg` of the spacecraft's nadir and, if
+    /// `min_sun_elev` is given, lit by the Sun at least that high. Each
+    /// opportunity reports the off-nadir angle, azimuth and ground-sample
+    /// geometry at its smallest off-nadir angle.
+    ///
+    /// # Arguments
+    /// * `lat` - Geodetic latitude of the target in degrees
+    /// * `lon` - Longitude of the target in degrees, east positive
+    /// * `max_off_nadir_deg` - Maximum off-nadir angle in degrees
+    /// * `min_sun_elev` - Minimum Sun elevation at the target in degrees (default: no limit)
+    ///
+    /// # Returns
+    /// List of `AccessOpportunity` in time order
+    #[pyo3(signature = (lat, lon, max_off_nadir_deg, min_sun_elev=None))]
+    fn access_to(
+        &self,
+        lat: f64,
+        lon: f64,
+        max_off_nadir_deg: f64,
+        min_sun_elev: Option<f64>,
+    ) -> PyResult<Vec<AccessOpportunity>> {
+        ground_access::access_to(self, lat, lon, max_off_nadir_deg, min_sun_elev)
+    }
+
     fn index(&self, time: &Bound<'_, PyDateTime>) -> PyResult<usize> {
         self.find_closest_index(time)
     }
 
-    #[pyo3(signature = (body, spice_kernel=None, use_horizons=false))]
+    /// Stable hex digest of the source, time grid, options and computed states
+    ///
+    /// Ephemerides with equal fingerprints compare equal, so the fingerprint
+    /// can key caches of results computed from this ephemeris.
+    fn fingerprint(&self) -> String {
+        EphemerisBase::fingerprint(self)
+    }
+
+    fn __eq__(&self, other: &Bound<'_, PyAny>) -> bool {
+        ephemeris_eq(self, other)
+    }
+
+    fn __hash__(&self) -> u64 {
+        ephemeris_hash(self)
+    }
+
+    /// Source, time span, computed frames and Earth orientation state
+    ///
+    /// Values are JSON-serializable (times as ISO 8601 strings), so the dict
+    /// can be logged as provenance alongside derived results.
+    #[getter]
+    fn metadata(&self, py: Python) -> PyResult<Py<PyAny>> {
+        ephemeris_metadata(py, self)
+    }
+
+    /// Reproducibility record: library version, source and fingerprint,
+    /// element set, input and kernel file digests, Earth orientation and
+    /// leap-second data
+    #[getter]
+    fn provenance(&self) -> Provenance {
+        ephemeris_provenance(self)
+    }
+
+    /// Human-readable, multi-line description of `metadata`
+    fn summary(&self) -> String {
+        ephemeris_summary(self)
+    }
+
+    fn __repr__(&self) -> String {
+        ephemeris_repr(self)
+    }
+
+    fn __len__(&self) -> usize {
+        ephemeris_len(self)
+    }
+
+    /// State at time step `index`; negative indices count from the end
+    fn __getitem__(&self, index: isize) -> PyResult<EphemerisSample> {
+        ephemeris_getitem(self, index)
+    }
+
+    fn __iter__(slf: Bound<'_, Self>) -> EphemerisIterator {
+        EphemerisIterator::new(slf.into_any().unbind())
+    }
+
+    #[pyo3(signature = (body, spice_kernel=None, use_horizons=false, source=None))]
     fn get_body_pv(
         &self,
         py: Python,
         body: &str,
         spice_kernel: Option<String>,
         use_horizons: bool,
+        source: Option<String>,
     ) -> PyResult<Py<PositionVelocityData>> {
-        <Self as EphemerisBase>::get_body_pv(self, py, body, spice_kernel.as_deref(), use_horizons)
+        <Self as EphemerisBase>::get_body_pv(
+            self,
+            py,
+            body,
+            spice_kernel.as_deref(),
+            use_horizons,
+            source.as_deref(),
+        )
+    }
+
+    #[pyo3(signature = (bodies, spice_kernel=None, use_horizons=false, source=None))]
+    fn get_bodies(
+        &self,
+        py: Python,
+        bodies: Vec<String>,
+        spice_kernel: Option<String>,
+        use_horizons: bool,
+        source: Option<String>,
+    ) -> PyResult<Py<pyo3::types::PyDict>> {
+        <Self as EphemerisBase>::get_bodies(
+            self,
+            py,
+            &bodies,
+            spice_kernel.as_deref(),
+            use_horizons,
+            source.as_deref(),
+        )
+    }
+
+    /// Angular separation in degrees between two targets at each time step
+    ///
+    /// Each of `a` and `b` may be an `(ra_deg, dec_deg)` pair, a body name,
+    /// observer-relative `PositionVelocityData` or a SkyCoord.
+    fn separation(
+        &self,
+        py: Python,
+        a: &Bound<'_, PyAny>,
+        b: &Bound<'_, PyAny>,
+    ) -> PyResult<Py<PyAny>> {
+        let angles = <Self as EphemerisBase>::separation(self, py, a, b)?;
+        Ok(angles.into_pyarray(py).into())
+    }
+
+    /// Angle in degrees between a target and the Sun at each time step
+    #[pyo3(signature = (ra_deg, dec_deg, time_indices=None))]
+    fn sun_angle(
+        &self,
+        py: Python,
+        ra_deg: f64,
+        dec_deg: f64,
+        time_indices: Option<Vec<usize>>,
+    ) -> PyResult<Py<PyAny>> {
+        let angles = EphemerisBase::sun_angle(self, ra_deg, dec_deg, time_indices.as_deref())?;
+        Ok(angles.into_pyarray(py).into())
     }
 
-    #[pyo3(signature = (body, spice_kernel=None, use_horizons=false))]
+    /// Angle in degrees between a target and the Moon at each time step
+    #[pyo3(signature = (ra_deg, dec_deg, time_indices=None))]
+    fn moon_angle(
+        &self,
+        py: Python,
+        ra_deg: f64,
+        dec_deg: f64,
+        time_indices: Option<Vec<usize>>,
+    ) -> PyResult<Py<PyAny>> {
+        let angles = EphemerisBase::moon_angle(self, ra_deg, dec_deg, time_indices.as_deref())?;
+        Ok(angles.into_pyarray(py).into())
+    }
+
+    /// Angle in degrees of a target above the Earth's limb (negative behind it)
+    #[pyo3(signature = (ra_deg, dec_deg, time_indices=None))]
+    fn earth_limb_angle(
+        &self,
+        py: Python,
+        ra_deg: f64,
+        dec_deg: f64,
+        time_indices: Option<Vec<usize>>,
+    ) -> PyResult<Py<PyAny>> {
+        let angles =
+            EphemerisBase::earth_limb_angle(self, ra_deg, dec_deg, time_indices.as_deref())?;
+        Ok(angles.into_pyarray(py).into())
+    }
+
+    /// Sun, Moon and Earth-limb angles, altitude, eclipse, SAA, orbital phase
+    /// and beta angle for a target at the ephemeris step nearest `time`
+    #[pyo3(signature = (ra_deg, dec_deg, time, *, saa_polygon=None))]
+    fn geometry(
+        &self,
+        ra_deg: f64,
+        dec_deg: f64,
+        time: &Bound<'_, PyDateTime>,
+        saa_polygon: Option<Vec<(f64, f64)>>,
+    ) -> PyResult<ObservationGeometry> {
+        let index = self.find_closest_index(time)?;
+        observation_geometry(self, ra_deg, dec_deg, index, saa_polygon.as_deref())
+    }
+
+    #[pyo3(signature = (body, spice_kernel=None, use_horizons=false, source=None))]
     fn get_body(
         &self,
         py: Python,
         body: &str,
         spice_kernel: Option<String>,
         use_horizons: bool,
+        source: Option<String>,
     ) -> PyResult<Py<PyAny>> {
         let modules = AstropyModules::import(py)?;
         <Self as EphemerisBase>::get_body(
@@ -464,24 +972,53 @@ impl OEMEphemeris {
             body,
             spice_kernel.as_deref(),
             use_horizons,
+            source.as_deref(),
         )
     }
 
     /// Convert RA/Dec to Altitude/Azimuth for this OEM ephemeris
     /// Returns NumPy array (N,2): [altitude_deg, azimuth_deg]
-    #[pyo3(signature = (ra_deg, dec_deg, time_indices=None))]
+    #[pyo3(signature = (ra_deg, dec_deg, time_indices=None, *, polar_motion=None))]
     fn radec_to_altaz(
         &self,
         py: Python,
         ra_deg: f64,
         dec_deg: f64,
         time_indices: Option<Vec<usize>>,
+        polar_motion: Option<bool>,
     ) -> PyResult<Py<PyAny>> {
-        let arr = <Self as crate::ephemeris::ephemeris_common::EphemerisBase>::radec_to_altaz(
-            self,
+        let arr = crate::utils::celestial::radec_to_altaz(
             ra_deg,
             dec_deg,
+            self,
             time_indices.as_deref(),
+            polar_motion,
+        );
+        Ok(arr.into_pyarray(py).into())
+    }
+
+    /// Convert many RA/Dec targets to Altitude/Azimuth in one call
+    /// Returns NumPy array (n_targets, N, 2): [altitude_deg, azimuth_deg]
+    #[pyo3(signature = (ra_deg, dec_deg, time_indices=None, *, polar_motion=None))]
+    fn radec_to_altaz_batch(
+        &self,
+        py: Python,
+        ra_deg: Vec<f64>,
+        dec_deg: Vec<f64>,
+        time_indices: Option<Vec<usize>>,
+        polar_motion: Option<bool>,
+    ) -> PyResult<Py<PyAny>> {
+        if ra_deg.len() != dec_deg.len() {
+            return Err(pyo3::exceptions::PyValueError::new_err(
+                "ra_deg and dec_deg must have the same length",
+            ));
+        }
+        let arr = crate::utils::celestial::radec_to_altaz_batch(
+            &ra_deg,
+            &dec_deg,
+            self,
+            time_indices.as_deref(),
+            polar_motion,
         );
         Ok(arr.into_pyarray(py).into())
     }
@@ -516,14 +1053,40 @@ impl OEMEphemeris {
             time_indices.as_deref(),
         )
     }
+
+    /// Calculate airmass for many targets in one call
+    ///
+    /// Uses the vectorized Kasten pipeline of the airmass constraint, without
+    /// refraction or aberration, so there are no per-target Python calls.
+    ///
+    /// # Returns
+    /// NumPy array (n_targets, N) of airmass values, infinity below the horizon
+    #[pyo3(signature = (ras, decs, time_indices=None))]
+    fn airmass_batch(
+        &self,
+        py: Python,
+        ras: Vec<f64>,
+        decs: Vec<f64>,
+        time_indices: Option<Vec<usize>>,
+    ) -> PyResult<Py<PyAny>> {
+        let arr =
+            <Self as crate::ephemeris::ephemeris_common::EphemerisBase>::calculate_airmass_batch(
+                self,
+                &ras,
+                &decs,
+                time_indices.as_deref(),
+            )?;
+        Ok(arr.into_pyarray(py).into())
+    }
 }
 
 impl OEMEphemeris {
-    /// Parse an OEM file and extract state vector records
+    /// Parse an OEM file and extract state vector and covariance records
     ///
-    /// This parser handles basic OEM format with multiple segments and validates
-    /// that the reference frame is compatible with GCRS (J2000/EME2000, GCRF, or ICRF)
-    fn parse_oem_file(path: &Path) -> PyResult<Vec<StateVectorRecord>> {
+    /// This parser handles basic OEM format with multiple segments. Every
+    /// segment must declare the same supported REF_FRAME and an Earth
+    /// CENTER_NAME.
+    fn parse_oem_file(path: &Path) -> PyResult<ParsedOem> {
         let file = File::open(path).map_err(|e| {
             pyo3::exceptions::PyIOError::new_err(format!("Failed to open OEM file: {}", e))
         })?;
@@ -533,7 +1096,14 @@ impl OEMEphemeris {
         let mut in_data_section = false;
         let mut past_meta = false;
         let mut in_meta_section = false;
-        let mut ref_frame_validated = false;
+        let mut ref_frame: Option<(String, OemFrame)> = None;
+        let mut center_name: Option<String> = None;
+
+        let mut covariances = Vec::new();
+        let mut in_covariance = false;
+        let mut cov_epoch: Option<DateTime<Utc>> = None;
+        let mut cov_frame: Option<String> = None;
+        let mut cov_values: Vec<f64> = Vec::new();
 
         for line in reader.lines() {
             let line = line.map_err(|e| {
@@ -551,25 +1121,43 @@ impl OEMEphemeris {
                 in_data_section = false;
                 past_meta = false;
                 in_meta_section = true;
+                in_covariance = false;
                 continue;
             }
 
             // Parse metadata fields while in META section
             if in_meta_section {
-                // Check and validate REF_FRAME
-                if trimmed.starts_with("REF_FRAME") {
-                    if let Some(frame_value) = trimmed.split('=').nth(1) {
-                        let frame = frame_value.trim();
-                        // Validate that the frame is compatible with GCRS
-                        if !Self::is_gcrs_compatible_frame(frame) {
-                            return Err(pyo3::exceptions::PyValueError::new_err(
-                                format!(
-                                    "Unsupported reference frame '{}'. OEM file must use a GCRS-compatible frame such as J2000, EME2000, GCRF, or ICRF.",
-                                    frame
-                                )
-                            ));
+                if let Some((key, value)) = trimmed.split_once('=') {
+                    let value = value.trim();
+                    match key.trim() {
+                        "REF_FRAME" => {
+                            let frame = OemFrame::parse(value).ok_or_else(|| {
+                                pyo3::exceptions::PyValueError::new_err(format!(
+                                    "Unsupported reference frame '{}'. Supported OEM frames are J2000/EME2000, GCRF, ICRF, TOD, TEME and ITRF.",
+                                    value
+                                ))
+                            })?;
+                            match &ref_frame {
+                                Some((declared, _)) if !declared.eq_ignore_ascii_case(value) => {
+                                    return Err(pyo3::exceptions::PyValueError::new_err(format!(
+                                        "OEM segments use different reference frames ('{}' and '{}'); all segments must share one REF_FRAME.",
+                                        declared, value
+                                    )));
+                                }
+                                Some(_) => {}
+                                None => ref_frame = Some((value.to_string(), frame)),
+                            }
+                        }
+                        "CENTER_NAME" => {
+                            if !value.eq_ignore_ascii_case("EARTH") {
+                                return Err(pyo3::exceptions::PyValueError::new_err(format!(
+                                    "Unsupported CENTER_NAME '{}'. OEM states must be centered on EARTH.",
+                                    value
+                                )));
+                            }
+                            center_name.get_or_insert_with(|| value.to_string());
                         }
-                        ref_frame_validated = true;
+                        _ => {}
                     }
                 }
             }
@@ -594,6 +1182,66 @@ impl OEMEphemeris {
                 continue;
             }
 
+            // Covariance blocks: EPOCH, optional COV_REF_FRAME, then 21
+            // lower-triangular values per matrix
+            if trimmed == "COVARIANCE_START" {
+                in_covariance = true;
+                cov_epoch = None;
+                cov_values.clear();
+                continue;
+            }
+            if trimmed == "COVARIANCE_STOP" {
+                if !cov_values.is_empty() {
+                    return Err(pyo3::exceptions::PyValueError::new_err(
+                        "Incomplete covariance matrix in OEM file",
+                    ));
+                }
+                in_covariance = false;
+                continue;
+            }
+            if in_covariance {
+                if let Some((key, value)) = trimmed.split_once('=') {
+                    match key.trim() {
+                        "EPOCH" => {
+                            if !cov_values.is_empty() {
+                                return Err(pyo3::exceptions::PyValueError::new_err(
+                                    "Incomplete covariance matrix in OEM file",
+                                ));
+                            }
+                            cov_epoch = Some(Self::parse_ccsds_epoch(value.trim())?);
+                            cov_frame = None;
+                        }
+                        "COV_REF_FRAME" => cov_frame = Some(value.trim().to_uppercase()),
+                        _ => {}
+                    }
+                    continue;
+                }
+                for part in trimmed.split_whitespace() {
+                    cov_values.push(part.parse::<f64>().map_err(|_| {
+                        pyo3::exceptions::PyValueError::new_err(format!(
+                            "Invalid covariance value: {}",
+                            part
+                        ))
+                    })?);
+                }
+                if cov_values.len() >= 21 {
+                    let epoch = cov_epoch.take().ok_or_else(|| {
+                        pyo3::exceptions::PyValueError::new_err(
+                            "Covariance matrix in OEM file has no EPOCH",
+                        )
+                    })?;
+                    let matrix = covariance::from_lower_triangle(&cov_values)
+                        .map_err(pyo3::exceptions::PyValueError::new_err)?;
+                    covariances.push(CovarianceRecord {
+                        epoch,
+                        frame: cov_frame.take(),
+                        matrix,
+                    });
+                    cov_values.clear();
+                }
+                continue;
+            }
+
             // Parse state vector records in the data section
             // This includes both explicit DATA_START/STOP sections and
             // data that comes directly after META_STOP
@@ -610,24 +1258,88 @@ impl OEMEphemeris {
             ));
         }
 
-        if !ref_frame_validated {
-            return Err(pyo3::exceptions::PyValueError::new_err(
-                "OEM file does not specify a REF_FRAME. A GCRS-compatible frame (J2000, EME2000, GCRF, or ICRF) is required.",
-            ));
-        }
+        let (ref_frame, frame) = ref_frame.ok_or_else(|| {
+            pyo3::exceptions::PyValueError::new_err(
+                "OEM file does not specify a REF_FRAME. Supported OEM frames are J2000/EME2000, GCRF, ICRF, TOD, TEME and ITRF.",
+            )
+        })?;
+        let center_name = center_name.ok_or_else(|| {
+            pyo3::exceptions::PyValueError::new_err(
+                "OEM file does not specify a CENTER_NAME. OEM states must be centered on EARTH.",
+            )
+        })?;
 
-        Ok(records)
+        Ok(ParsedOem {
+            records,
+            covariances,
+            ref_frame,
+            frame,
+            center_name,
+        })
     }
 
-    /// Check if a reference frame string is compatible with GCRS
+    /// Express covariance records in GCRS, sorted by epoch
     ///
-    /// GCRS-compatible frames include J2000, EME2000, GCRF, and ICRF variants
-    fn is_gcrs_compatible_frame(frame: &str) -> bool {
-        let frame_upper = frame.to_uppercase();
-        matches!(
-            frame_upper.as_str(),
-            "J2000" | "EME2000" | "GCRF" | "ICRF" | "ICRF2" | "ICRF3"
-        )
+    /// Matrices given in an orbit-local frame (RTN/RSW) are rotated using the
+    /// OEM state interpolated to the covariance epoch; ones in TOD or TEME are
+    /// rotated into GCRS at their epoch. Covariances in the rotating ITRF are
+    /// rejected, since rotating them would also need the frame's angular
+    /// velocity terms.
+    fn inertial_covariances(
+        records: &[CovarianceRecord],
+        segment_frame: OemFrame,
+        oem_times: &[DateTime<Utc>],
+        oem_states: &Array2<f64>,
+    ) -> PyResult<Vec<(DateTime<Utc>, Matrix6)>> {
+        let first = oem_times[0];
+        let last = oem_times[oem_times.len() - 1];
+        let mut covariances = Vec::with_capacity(records.len());
+        for record in records {
+            if record.epoch < first || record.epoch > last {
+                return Err(pyo3::exceptions::PyValueError::new_err(format!(
+                    "Covariance epoch {} is outside the OEM data range [{}, {}]",
+                    record.epoch, first, last
+                )));
+            }
+            let frame = match record.frame.as_deref() {
+                None => segment_frame,
+                Some("RTN" | "RSW" | "RIC") => {
+                    let state = hermite_interpolate(&[record.epoch], oem_times, oem_states);
+                    let basis = ric_basis(
+                        &[state[[0, 0]], state[[0, 1]], state[[0, 2]]],
+                        &[state[[0, 3]], state[[0, 4]], state[[0, 5]]],
+                    );
+                    // Basis rows map inertial to local; the transpose maps back
+                    let to_inertial: [[f64; 3]; 3] =
+                        std::array::from_fn(|i| std::array::from_fn(|j| basis[j][i]));
+                    covariances.push((
+                        record.epoch,
+                        covariance::rotate(&record.matrix, &to_inertial),
+                    ));
+                    continue;
+                }
+                Some(frame) => OemFrame::parse(frame).ok_or_else(|| {
+                    pyo3::exceptions::PyValueError::new_err(format!(
+                        "Unsupported covariance reference frame '{}'. Use the segment REF_FRAME, RTN or RSW.",
+                        frame
+                    ))
+                })?,
+            };
+            let matrix = match frame {
+                OemFrame::Gcrs => record.matrix,
+                OemFrame::Tod | OemFrame::Teme => {
+                    covariance::rotate(&record.matrix, &frame.rotation_to_gcrs(record.epoch))
+                }
+                OemFrame::Itrs => {
+                    return Err(pyo3::exceptions::PyValueError::new_err(
+                        "Covariances in the Earth-fixed ITRF are not supported. Give them in RTN/RSW or an inertial frame.",
+                    ))
+                }
+            };
+            covariances.push((record.epoch, matrix));
+        }
+        covariances.sort_by_key(|(epoch, _)| *epoch);
+        Ok(covariances)
     }
 
     /// Parse a single state vector line
@@ -759,35 +1471,89 @@ impl OEMEphemeris {
             0
         };
 
-        Utc.with_ymd_and_hms(year, month, day, hour, minute, second)
-            .earliest()
-            .ok_or_else(|| {
-                pyo3::exceptions::PyValueError::new_err(format!(
-                    "Invalid datetime components: {}-{}-{} {}:{}:{}",
-                    year, month, day, hour, minute, second
-                ))
-            })
-            .map(|dt| dt + chrono::Duration::nanoseconds(nanosecond as i64))
+        // Second 60 is a leap second
+        utc_from_fields(year, month, day, hour, minute, second, nanosecond)
+            .map_err(pyo3::exceptions::PyValueError::new_err)
     }
 
     /// Interpolate OEM data to requested timestamps in GCRS frame
     ///
-    /// Uses Hermite interpolation for smooth position and velocity
+    /// Uses the configured scheme, cubic Hermite by default
     fn interpolate_to_gcrs(&mut self) -> PyResult<()> {
         let times = self.common_data.times.as_ref().ok_or_else(|| {
             pyo3::exceptions::PyValueError::new_err("No times available for interpolation")
         })?;
 
-        // Interpolate using Hermite method
-        let interpolated = hermite_interpolate(times, &self.oem_times, &self.oem_states);
+        self.interpolation
+            .check_gaps(times, &self.oem_times)
+            .map_err(pyo3::exceptions::PyValueError::new_err)?;
+        let interpolated = self
+            .interpolation
+            .interpolate(times, &self.oem_times, &self.oem_states);
 
-        // Store in GCRS (assuming OEM data is in an inertial frame compatible with GCRS)
-        // Note: CCSDS OEM typically uses J2000/GCRF which is essentially GCRS
+        // The OEM states were converted to GCRS on load
         self.common_data.gcrs = Some(interpolated);
 
         Ok(())
     }
 
+    /// Propagate the OEM covariances to the ephemeris timestamps
+    ///
+    /// Each timestamp uses the latest covariance at or before it (the first
+    /// covariance for earlier timestamps), propagated linearly along a two-body
+    /// orbit through the OEM state at the covariance epoch.
+    fn propagate_covariance(&mut self) -> PyResult<()> {
+        if self.covariances.is_empty() {
+            return Ok(());
+        }
+        let times = self
+            .common_data
+            .times
+            .as_ref()
+            .ok_or_else(|| pyo3::exceptions::PyValueError::new_err("No times available"))?;
+        let gcrs = self
+            .common_data
+            .gcrs
+            .as_ref()
+            .ok_or_else(|| pyo3::exceptions::PyValueError::new_err("No GCRS data available"))?;
+
+        let epochs: Vec<DateTime<Utc>> = self.covariances.iter().map(|(epoch, _)| *epoch).collect();
+        let epoch_states = hermite_interpolate(&epochs, &self.oem_times, &self.oem_states);
+
+        // Group timestamps by the covariance they are propagated from
+        let mut groups: Vec<Vec<usize>> = vec![Vec::new(); epochs.len()];
+        for (i, t) in times.iter().enumerate() {
+            let k = epochs.partition_point(|epoch| epoch <= t).saturating_sub(1);
+            groups[k].push(i);
+        }
+
+        let mut sigma = Array2::<f64>::zeros((times.len(), 3));
+        for (k, indices) in groups.iter().enumerate() {
+            if indices.is_empty() {
+                continue;
+            }
+            let state0: [f64; 6] = std::array::from_fn(|j| epoch_states[[k, j]]);
+            let offsets: Vec<f64> = indices
+                .iter()
+                .map(|&i| (times[i] - epochs[k]).num_milliseconds() as f64 / 1000.0)
+                .collect();
+            let stms = covariance::two_body_stms(&state0, &offsets);
+            for (&i, stm) in indices.iter().zip(&stms) {
+                let cov = covariance::propagate(&self.covariances[k].1, stm);
+                let sigma_ric = covariance::position_sigma_ric(
+                    &cov,
+                    &[gcrs[[i, 0]], gcrs[[i, 1]], gcrs[[i, 2]]],
+                    &[gcrs[[i, 3]], gcrs[[i, 4]], gcrs[[i, 5]]],
+                );
+                for (c, value) in sigma_ric.iter().enumerate() {
+                    sigma[[i, c]] = *value;
+                }
+            }
+        }
+        self.position_uncertainty = Some(sigma);
+        Ok(())
+    }
+
     /// Transform GCRS to ITRS coordinates
     fn gcrs_to_itrs(&mut self) -> PyResult<()> {
         let gcrs_data = self
@@ -803,12 +1569,11 @@ impl OEMEphemeris {
             .ok_or_else(|| pyo3::exceptions::PyValueError::new_err("No times available"))?;
 
         // Use the generic conversion function
-        let itrs_result = conversions::convert_frames(
+        let itrs_result = conversions::gcrs_to_itrs(
             gcrs_data,
             times,
-            conversions::Frame::GCRS,
-            conversions::Frame::ITRS,
             self.polar_motion,
+            self.common_data.fast_itrs,
         );
         self.itrs = Some(itrs_result);
         Ok(())
@@ -837,12 +1602,38 @@ impl EphemerisBase for OEMEphemeris {
         self.itrs_skycoord.set(skycoord)
     }
 
+    fn source_identity(&self) -> String {
+        format!(
+            "OEMEphemeris\noem_path={}\npolar_motion={}",
+            self.oem_path, self.polar_motion
+        )
+    }
+
+    fn source_metadata(&self) -> Vec<(&'static str, serde_json::Value)> {
+        vec![
+            ("type", "OEMEphemeris".into()),
+            ("oem_path", self.oem_path.clone().into()),
+            ("ref_frame", self.ref_frame.clone().into()),
+            ("center_name", self.center_name.clone().into()),
+            ("covariance", (!self.covariances.is_empty()).into()),
+            ("polar_motion", self.polar_motion.into()),
+        ]
+    }
+
+    fn position_uncertainty(&self) -> Option<&Array2<f64>> {
+        self.position_uncertainty.as_ref()
+    }
+
+    fn applies_polar_motion(&self) -> bool {
+        self.polar_motion
+    }
+
     fn radec_to_altaz(
         &self,
         ra_deg: f64,
         dec_deg: f64,
         time_indices: Option<&[usize]>,
     ) -> Array2<f64> {
-        crate::utils::celestial::radec_to_altaz(ra_deg, dec_deg, self, time_indices)
+        crate::utils::celestial::radec_to_altaz(ra_deg, dec_deg, self, time_indices, None)
     }
 }
@@ -0,0 +1,313 @@
+//! Ephemeris comparison and residual analysis
+//!
+//! Interpolates two ephemerides onto a common time grid and expresses the
+//! difference between them in the radial / in-track / cross-track (RIC) frame
+//! of the reference trajectory, e.g. to check a TLE against an OEM or an SGP4
+//! propagation against a numerical one.
+//!
+//! The same grid is available on its own through [`align_ephemerides`], which
+//! resamples both ephemerides onto it for relative-motion and other
+//! cross-ephemeris analyses.
+
+use std::collections::HashMap;
+
+use chrono::{DateTime, Utc};
+use ndarray::{Array1, Array2};
+use numpy::IntoPyArray;
+use pyo3::prelude::*;
+
+use crate::ephemeris::array_ephemeris::ArrayEphemeris;
+use crate::ephemeris::ephemeris_common::{generate_timestamps_utc, EphemerisBase};
+use crate::ephemeris::with_ephemeris;
+use crate::utils::interpolation::hermite_interpolate;
+use crate::utils::time_utils::{utc_to_python_datetime, GridTimeScale};
+use crate::utils::vector_math::{dot_product, ric_basis};
+
+/// Residuals of one ephemeris against a reference, in the reference RIC frame
+#[pyclass]
+pub struct EphemerisComparison {
+    times: Vec<DateTime<Utc>>,
+    /// Position residuals (other - reference) as [radial, in-track, cross-track] in km
+    position_ric: Array2<f64>,
+    /// Velocity residuals (other - reference) as [radial, in-track, cross-track] in km/s
+    velocity_ric: Array2<f64>,
+}
+
+/// Per-component and total RMS of RIC residuals
+fn rms_stats(residuals: &Array2<f64>) -> HashMap<&'static str, f64> {
+    let n = residuals.nrows().max(1) as f64;
+    let component = |k: usize| (residuals.column(k).mapv(|v| v * v).sum() / n).sqrt();
+    let total = (residuals.mapv(|v| v * v).sum() / n).sqrt();
+    HashMap::from([
+        ("radial", component(0)),
+        ("in_track", component(1)),
+        ("cross_track", component(2)),
+        ("total", total),
+    ])
+}
+
+/// Per-component maximum absolute value and maximum norm of RIC residuals
+fn max_stats(residuals: &Array2<f64>) -> HashMap<&'static str, f64> {
+    let component = |k: usize| residuals.column(k).fold(0.0_f64, |m, v| m.max(v.abs()));
+    let total = norms(residuals).fold(0.0_f64, |m, &v| m.max(v));
+    HashMap::from([
+        ("radial", component(0)),
+        ("in_track", component(1)),
+        ("cross_track", component(2)),
+        ("total", total),
+    ])
+}
+
+fn norms(residuals: &Array2<f64>) -> Array1<f64> {
+    residuals
+        .rows()
+        .into_iter()
+        .map(|row| row.dot(&row).sqrt())
+        .collect()
+}
+
+/// Project state differences onto the RIC frame of each reference state
+fn ric_residuals(reference: &Array2<f64>, other: &Array2<f64>) -> (Array2<f64>, Array2<f64>) {
+    let n = reference.nrows();
+    let mut position_ric = Array2::<f64>::zeros((n, 3));
+    let mut velocity_ric = Array2::<f64>::zeros((n, 3));
+    for i in 0..n {
+        let r = [reference[[i, 0]], reference[[i, 1]], reference[[i, 2]]];
+        let v = [reference[[i, 3]], reference[[i, 4]], reference[[i, 5]]];
+        let basis = ric_basis(&r, &v);
+
+        let dr = [
+            other[[i, 0]] - r[0],
+            other[[i, 1]] - r[1],
+            other[[i, 2]] - r[2],
+        ];
+        let dv = [
+            other[[i, 3]] - v[0],
+            other[[i, 4]] - v[1],
+            other[[i, 5]] - v[2],
+        ];
+        for (k, axis) in basis.iter().enumerate() {
+            position_ric[[i, k]] = dot_product(&dr, axis);
+            velocity_ric[[i, k]] = dot_product(&dv, axis);
+        }
+    }
+    (position_ric, velocity_ric)
+}
+
+/// GCRS states (N x 6) and times of any supported ephemeris
+fn gcrs_states(obj: &Bound<'_, PyAny>) -> PyResult<(Vec<DateTime<Utc>>, Array2<f64>)> {
+    with_ephemeris(obj, |ephem| {
+        let times = ephem.get_times()?;
+        let gcrs =
+            ephem.data().gcrs.clone().ok_or_else(|| {
+                pyo3::exceptions::PyValueError::new_err("Ephemeris has no GCRS data")
+            })?;
+        if times.len() < 2 {
+            return Err(pyo3::exceptions::PyValueError::new_err(
+                "Ephemerides need at least 2 samples to be interpolated",
+            ));
+        }
+        Ok((times, gcrs))
+    })
+}
+
+/// Time scale of an ephemeris's timestamp grid
+fn time_scale(obj: &Bound<'_, PyAny>) -> PyResult<GridTimeScale> {
+    with_ephemeris(obj, |ephem| Ok(ephem.data().time_scale))
+}
+
+/// Grid over the time span shared by two ephemerides
+///
+/// The `primary` ephemeris's own sample times within the span, or a regular
+/// grid with `step_size` seconds, uniform in `time_scale`, when given.
+fn common_grid(
+    primary: &[DateTime<Utc>],
+    secondary: &[DateTime<Utc>],
+    step_size: Option<i64>,
+    time_scale: GridTimeScale,
+) -> PyResult<Vec<DateTime<Utc>>> {
+    let start = primary[0].max(secondary[0]);
+    let stop = primary[primary.len() - 1].min(secondary[secondary.len() - 1]);
+    if start > stop {
+        return Err(pyo3::exceptions::PyValueError::new_err(
+            "Ephemerides do not overlap in time",
+        ));
+    }
+    match step_size {
+        Some(step) => generate_timestamps_utc(start, stop, step, time_scale),
+        None => Ok(primary
+            .iter()
+            .filter(|t| (start..=stop).contains(*t))
+            .copied()
+            .collect()),
+    }
+}
+
+/// Resample two ephemerides onto a common grid
+///
+/// The grid is that of [`compare_ephemerides`]: the shared time span,
+/// sampled at the first ephemeris's times or every `step_size` seconds in
+/// its time scale. GCRS states are Hermite-interpolated and returned as a
+/// pair of `ArrayEphemeris` with identical times.
+pub fn align_ephemerides(
+    first: &Bound<'_, PyAny>,
+    second: &Bound<'_, PyAny>,
+    step_size: Option<i64>,
+) -> PyResult<(ArrayEphemeris, ArrayEphemeris)> {
+    let (first_times, first_states) = gcrs_states(first)?;
+    let (second_times, second_states) = gcrs_states(second)?;
+    let scale = time_scale(first)?;
+    let times = common_grid(&first_times, &second_times, step_size, scale)?;
+    let mut aligned = (
+        ArrayEphemeris::resampled(first_times, first_states, times.clone())?,
+        ArrayEphemeris::resampled(second_times, second_states, times)?,
+    );
+    aligned.0.data_mut().time_scale = scale;
+    aligned.1.data_mut().time_scale = scale;
+    Ok(aligned)
+}
+
+/// Compare two ephemerides on a common grid
+///
+/// The grid covers the time span shared by both ephemerides. It is the
+/// reference's own sample times within that span unless `step_size` is given,
+/// in which case a regular grid with that step (seconds), uniform in the
+/// reference's time scale, is used. Both
+/// ephemerides are Hermite-interpolated onto the grid.
+pub fn compare_ephemerides(
+    reference: &Bound<'_, PyAny>,
+    other: &Bound<'_, PyAny>,
+    step_size: Option<i64>,
+) -> PyResult<EphemerisComparison> {
+    let (ref_times, ref_states) = gcrs_states(reference)?;
+    let (other_times, other_states) = gcrs_states(other)?;
+    let times = common_grid(&ref_times, &other_times, step_size, time_scale(reference)?)?;
+
+    let interpolate = |data_times: &[DateTime<Utc>], states: &Array2<f64>| {
+        if data_times == times.as_slice() {
+            states.clone()
+        } else {
+            hermite_interpolate(&times, data_times, states)
+        }
+    };
+    let reference_grid = interpolate(&ref_times, &ref_states);
+    let other_grid = interpolate(&other_times, &other_states);
+    let (position_ric, velocity_ric) = ric_residuals(&reference_grid, &other_grid);
+
+    Ok(EphemerisComparison {
+        times,
+        position_ric,
+        velocity_ric,
+    })
+}
+
+#[pymethods]
+impl EphemerisComparison {
+    /// Comparison grid times as a NumPy array of datetimes
+    #[getter]
+    fn timestamp(&self, py: Python) -> PyResult<Py<PyAny>> {
+        let np = pyo3::types::PyModule::import(py, "numpy")
+            .map_err(|_| pyo3::exceptions::PyImportError::new_err("numpy is required"))?;
+        let py_list = pyo3::types::PyList::empty(py);
+        for dt in &self.times {
+            py_list.append(utc_to_python_datetime(py, dt)?)?;
+        }
+        Ok(np.getattr("array")?.call1((py_list,))?.into())
+    }
+
+    /// Position residuals (other - reference) as an (N, 3) array of
+    /// [radial, in-track, cross-track] in km
+    #[getter]
+    fn position_residuals(&self, py: Python) -> Py<PyAny> {
+        self.position_ric.clone().into_pyarray(py).into()
+    }
+
+    /// Velocity residuals (other - reference) as an (N, 3) array of
+    /// [radial, in-track, cross-track] in km/s
+    #[getter]
+    fn velocity_residuals(&self, py: Python) -> Py<PyAny> {
+        self.velocity_ric.clone().into_pyarray(py).into()
+    }
+
+    /// Magnitude of the position residual at each time (km)
+    #[getter]
+    fn position_error(&self, py: Python) -> Py<PyAny> {
+        norms(&self.position_ric).into_pyarray(py).into()
+    }
+
+    /// Magnitude of the velocity residual at each time (km/s)
+    #[getter]
+    fn velocity_error(&self, py: Python) -> Py<PyAny> {
+        norms(&self.velocity_ric).into_pyarray(py).into()
+    }
+
+    /// RMS position residual (km) by component: radial, in_track, cross_track, total
+    #[getter]
+    fn position_rms(&self) -> HashMap<&'static str, f64> {
+        rms_stats(&self.position_ric)
+    }
+
+    /// Maximum absolute position residual (km) by component; total is the largest magnitude
+    #[getter]
+    fn position_max(&self) -> HashMap<&'static str, f64> {
+        max_stats(&self.position_ric)
+    }
+
+    /// RMS velocity residual (km/s) by component: radial, in_track, cross_track, total
+    #[getter]
+    fn velocity_rms(&self) -> HashMap<&'static str, f64> {
+        rms_stats(&self.velocity_ric)
+    }
+
+    /// Maximum absolute velocity residual (km/s) by component; total is the largest magnitude
+    #[getter]
+    fn velocity_max(&self) -> HashMap<&'static str, f64> {
+        max_stats(&self.velocity_ric)
+    }
+
+    fn __len__(&self) -> usize {
+        self.times.len()
+    }
+
+    fn __repr__(&self) -> String {
+        format!(
+            "EphemerisComparison(n={}, position_rms={:.6} km, position_max={:.6} km)",
+            self.times.len(),
+            rms_stats(&self.position_ric)["total"],
+            max_stats(&self.position_ric)["total"]
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_ric_residuals_axes() {
+        // Circular equatorial orbit state at +x moving along +y
+        let reference =
+            Array2::from_shape_vec((1, 6), vec![7000.0, 0.0, 0.0, 0.0, 7.5, 0.0]).unwrap();
+        let other = Array2::from_shape_vec((1, 6), vec![7001.0, 2.0, 3.0, 0.1, 7.7, 0.3]).unwrap();
+        let (dr, dv) = ric_residuals(&reference, &other);
+        assert!((dr[[0, 0]] - 1.0).abs() < 1e-9);
+        assert!((dr[[0, 1]] - 2.0).abs() < 1e-9);
+        assert!((dr[[0, 2]] - 3.0).abs() < 1e-9);
+        assert!((dv[[0, 0]] - 0.1).abs() < 1e-9);
+        assert!((dv[[0, 1]] - 0.2).abs() < 1e-9);
+        assert!((dv[[0, 2]] - 0.3).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_stats() {
+        let residuals =
+            Array2::from_shape_vec((2, 3), vec![3.0, 0.0, -4.0, -1.0, 0.0, 0.0]).unwrap();
+        let rms = rms_stats(&residuals);
+        assert!((rms["radial"] - 5.0_f64.sqrt()).abs() < 1e-12);
+        assert!((rms["total"] - 13.0_f64.sqrt()).abs() < 1e-12);
+        let max = max_stats(&residuals);
+        assert_eq!(max["radial"], 3.0);
+        assert_eq!(max["cross_track"], 4.0);
+        assert_eq!(max["total"], 5.0);
+    }
+}
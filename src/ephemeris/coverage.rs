@@ -0,0 +1,460 @@
+//! Coverage and revisit analysis over a latitude/longitude grid
+//!
+//! A [`CoverageGrid`] holds grid points on the Earth's surface and a sensor
+//! model. For one or more spacecraft ephemerides it finds, for every grid
+//! cell, the access windows during which at least one spacecraft sees the
+//! cell, and derives revisit times and coverage statistics from them.
+//!
+//! The sensor is a nadir-pointing cone: a cell is accessible when it lies
+//! within `half_angle` of the spacecraft's geocentric nadir and the
+//! spacecraft is at least `min_elevation` above the cell's horizon. As for
+//! ground-station contacts, access boundaries are ephemeris sample times.
+
+use chrono::{DateTime, Utc};
+use ndarray::{Array1, Array2};
+use numpy::IntoPyArray;
+use pyo3::prelude::*;
+use rayon::prelude::*;
+
+use crate::constraints::VisibilityWindow;
+use crate::ephemeris::ground_network::{
+    runs, seconds_between, spacecraft_itrs, timestamp_array, to_windows,
+};
+use crate::ephemeris::with_ephemeris;
+use crate::utils::geo::{enu_basis, geodetic_to_ecef_km};
+use crate::utils::vector_math::{dot_product, vector_magnitude};
+
+/// Whether a ground point sees a spacecraft within the sensor cone
+///
+/// `point` and `sat` are ITRS positions in km and `up` is the point's local
+/// vertical. `cos_half_angle` is `None` for an unrestricted sensor.
+fn in_view(
+    point: &[f64; 3],
+    up: &[f64; 3],
+    sat: &[f64; 3],
+    sin_min_elevation: f64,
+    cos_half_angle: Option<f64>,
+) -> bool {
+    let d = [sat[0] - point[0], sat[1] - point[1], sat[2] - point[2]];
+    let range = vector_magnitude(&d);
+    if dot_product(&d, up) < range * sin_min_elevation {
+        return false;
+    }
+    // Angle between nadir (-sat) and the line of sight to the point (-d)
+    cos_half_angle.is_none_or(|c| dot_product(&d, sat) >= c * range * vector_magnitude(sat))
+}
+
+/// Gaps in seconds between consecutive access windows of one cell
+fn revisit_gaps(times: &[DateTime<Utc>], windows: &[(usize, usize)]) -> Vec<f64> {
+    windows
+        .windows(2)
+        .map(|pair| seconds_between(times, (pair[0].1, pair[1].0)))
+        .collect()
+}
+
+/// Per-cell access windows and statistics from a coverage analysis
+#[pyclass]
+pub struct CoverageResult {
+    times: Vec<DateTime<Utc>>,
+    latitudes: Vec<f64>,
+    longitudes: Vec<f64>,
+    /// Access windows of each cell, row-major over (latitude, longitude)
+    windows: Vec<Vec<(usize, usize)>>,
+    n_spacecraft: usize,
+}
+
+impl CoverageResult {
+    fn shape(&self) -> (usize, usize) {
+        (self.latitudes.len(), self.longitudes.len())
+    }
+
+    /// Per-cell statistic as a (n_lat, n_lon) array
+    fn cell_map<T>(&self, f: impl Fn(&[(usize, usize)]) -> T) -> Array2<T> {
+        let values = self.windows.iter().map(|w| f(w.as_slice())).collect();
+        Array2::from_shape_vec(self.shape(), values).expect("one entry per grid cell")
+    }
+
+    fn revisit_map(&self, f: impl Fn(&[f64]) -> f64) -> Array2<f64> {
+        self.cell_map(|windows| {
+            let gaps = revisit_gaps(&self.times, windows);
+            if gaps.is_empty() {
+                f64::NAN
+            } else {
+                f(&gaps)
+            }
+        })
+    }
+}
+
+#[pymethods]
+impl CoverageResult {
+    /// Ephemeris times as a NumPy array of datetimes
+    #[getter]
+    fn timestamp(&self, py: Python) -> PyResult<Py<PyAny>> {
+        timestamp_array(py, &self.times)
+    }
+
+    /// Grid latitudes in degrees
+    #[getter]
+    fn latitudes(&self, py: Python) -> Py<PyAny> {
+        Array1::from_vec(self.latitudes.clone())
+            .into_pyarray(py)
+            .into()
+    }
+
+    /// Grid longitudes in degrees
+    #[getter]
+    fn longitudes(&self, py: Python) -> Py<PyAny> {
+        Array1::from_vec(self.longitudes.clone())
+            .into_pyarray(py)
+            .into()
+    }
+
+    /// Number of spacecraft included in the analysis
+    #[getter]
+    fn n_spacecraft(&self) -> usize {
+        self.n_spacecraft
+    }
+
+    /// Number of access windows per cell, shape (n_lat, n_lon)
+    #[getter]
+    fn access_count(&self, py: Python) -> Py<PyAny> {
+        self.cell_map(|w| w.len()).into_pyarray(py).into()
+    }
+
+    /// Total access time in seconds per cell, shape (n_lat, n_lon)
+    #[getter]
+    fn access_seconds(&self, py: Python) -> Py<PyAny> {
+        self.cell_map(|w| {
+            w.iter()
+                .map(|&r| seconds_between(&self.times, r))
+                .sum::<f64>()
+        })
+        .into_pyarray(py)
+        .into()
+    }
+
+    /// Fraction of ephemeris samples with access per cell, shape (n_lat, n_lon)
+    #[getter]
+    fn coverage_fraction(&self, py: Python) -> Py<PyAny> {
+        let n = self.times.len().max(1) as f64;
+        self.cell_map(|w| w.iter().map(|&(a, b)| b - a + 1).sum::<usize>() as f64 / n)
+            .into_pyarray(py)
+            .into()
+    }
+
+    /// Longest time in seconds between consecutive accesses per cell
+    ///
+    /// NaN for cells with fewer than two access windows.
+    #[getter]
+    fn max_revisit_seconds(&self, py: Python) -> Py<PyAny> {
+        self.revisit_map(|gaps| gaps.iter().copied().fold(0.0, f64::max))
+            .into_pyarray(py)
+            .into()
+    }
+
+    /// Mean time in seconds between consecutive accesses per cell
+    ///
+    /// NaN for cells with fewer than two access windows.
+    #[getter]
+    fn mean_revisit_seconds(&self, py: Python) -> Py<PyAny> {
+        self.revisit_map(|gaps| gaps.iter().sum::<f64>() / gaps.len() as f64)
+            .into_pyarray(py)
+            .into()
+    }
+
+    /// Percentage of the grid accessed at least once, weighting cells by cos(latitude)
+    #[getter]
+    fn percent_covered(&self) -> f64 {
+        let n_lon = self.longitudes.len();
+        let (mut covered, mut total) = (0.0, 0.0);
+        for (k, windows) in self.windows.iter().enumerate() {
+            let weight = self.latitudes[k / n_lon].to_radians().cos().max(0.0);
+            total += weight;
+            if !windows.is_empty() {
+                covered += weight;
+            }
+        }
+        if total > 0.0 {
+            100.0 * covered / total
+        } else {
+            0.0
+        }
+    }
+
+    /// Access windows of one grid cell
+    ///
+    /// # Arguments
+    /// * `lat_index` - Index into `latitudes`
+    /// * `lon_index` - Index into `longitudes`
+    fn access_windows(
+        &self,
+        py: Python,
+        lat_index: usize,
+        lon_index: usize,
+    ) -> PyResult<Vec<VisibilityWindow>> {
+        let (n_lat, n_lon) = self.shape();
+        if lat_index >= n_lat || lon_index >= n_lon {
+            return Err(pyo3::exceptions::PyIndexError::new_err(format!(
+                "Cell ({}, {}) is outside the {}x{} grid",
+                lat_index, lon_index, n_lat, n_lon
+            )));
+        }
+        to_windows(
+            py,
+            &self.times,
+            &self.windows[lat_index * n_lon + lon_index],
+        )
+    }
+
+    fn __repr__(&self) -> String {
+        let (n_lat, n_lon) = self.shape();
+        format!(
+            "CoverageResult(grid={}x{}, spacecraft={}, percent_covered={:.1})",
+            n_lat,
+            n_lon,
+            self.n_spacecraft,
+            self.percent_covered()
+        )
+    }
+}
+
+/// A latitude/longitude grid with a nadir-pointing sensor cone
+#[pyclass]
+pub struct CoverageGrid {
+    latitudes: Vec<f64>,
+    longitudes: Vec<f64>,
+    half_angle: Option<f64>,
+    min_elevation: f64,
+}
+
+#[pymethods]
+impl CoverageGrid {
+    /// Create a grid from latitude and longitude values
+    ///
+    /// # Arguments
+    /// * `latitudes` - Grid latitudes in degrees
+    /// * `longitudes` - Grid longitudes in degrees; the grid is every
+    ///   combination of latitude and longitude
+    /// * `half_angle` - Sensor cone half-angle from nadir in degrees, or None
+    ///   for a sensor limited only by `min_elevation` (default: None)
+    /// * `min_elevation` - Minimum spacecraft elevation above the cell's
+    ///   horizon in degrees (default: 0.0)
+    #[new]
+    #[pyo3(signature = (latitudes, longitudes, *, half_angle=None, min_elevation=0.0))]
+    fn new(
+        latitudes: Vec<f64>,
+        longitudes: Vec<f64>,
+        half_angle: Option<f64>,
+        min_elevation: f64,
+    ) -> PyResult<Self> {
+        if latitudes.is_empty() || longitudes.is_empty() {
+            return Err(pyo3::exceptions::PyValueError::new_err(
+                "CoverageGrid requires at least one latitude and one longitude",
+            ));
+        }
+        if latitudes.iter().any(|lat| !(-90.0..=90.0).contains(lat)) {
+            return Err(pyo3::exceptions::PyValueError::new_err(
+                "latitudes must be between -90 and 90 degrees",
+            ));
+        }
+        if longitudes.iter().any(|lon| !(-180.0..=360.0).contains(lon)) {
+            return Err(pyo3::exceptions::PyValueError::new_err(
+                "longitudes must be between -180 and 360 degrees",
+            ));
+        }
+        if half_angle.is_some_and(|h| h <= 0.0 || h > 90.0) {
+            return Err(pyo3::exceptions::PyValueError::new_err(
+                "half_angle must be greater than 0 and at most 90 degrees",
+            ));
+        }
+        if !(-90.0..=90.0).contains(&min_elevation) {
+            return Err(pyo3::exceptions::PyValueError::new_err(
+                "min_elevation must be between -90 and 90 degrees",
+            ));
+        }
+        Ok(CoverageGrid {
+            latitudes,
+            longitudes,
+            half_angle,
+            min_elevation,
+        })
+    }
+
+    /// Grid latitudes in degrees
+    #[getter]
+    fn latitudes(&self) -> Vec<f64> {
+        self.latitudes.clone()
+    }
+
+    /// Grid longitudes in degrees
+    #[getter]
+    fn longitudes(&self) -> Vec<f64> {
+        self.longitudes.clone()
+    }
+
+    #[getter]
+    fn half_angle(&self) -> Option<f64> {
+        self.half_angle
+    }
+
+    #[getter]
+    fn min_elevation(&self) -> f64 {
+        self.min_elevation
+    }
+
+    /// Grid shape as (n_lat, n_lon)
+    #[getter]
+    fn shape(&self) -> (usize, usize) {
+        (self.latitudes.len(), self.longitudes.len())
+    }
+
+    /// Compute access windows of every cell
+    ///
+    /// # Arguments
+    /// * `ephemerides` - A spacecraft ephemeris or a list of them (any
+    ///   ephemeris type). All must share the same timestamps.
+    ///
+    /// # Returns
+    /// CoverageResult with per-cell access windows, revisit times and
+    /// coverage statistics sampled at the ephemeris times
+    fn analyze(&self, py: Python, ephemerides: &Bound<'_, PyAny>) -> PyResult<CoverageResult> {
+        let objects = match ephemerides.extract::<Vec<Bound<'_, PyAny>>>() {
+            Ok(list) => list,
+            Err(_) => vec![ephemerides.clone()],
+        };
+        if objects.is_empty() {
+            return Err(pyo3::exceptions::PyValueError::new_err(
+                "At least one ephemeris is required",
+            ));
+        }
+
+        let mut times: Option<Vec<DateTime<Utc>>> = None;
+        let mut tracks = Vec::with_capacity(objects.len());
+        for obj in &objects {
+            let (positions, obj_times) = with_ephemeris(obj, |ephem| spacecraft_itrs(ephem, None))?;
+            match &times {
+                Some(t) if *t != obj_times => {
+                    return Err(pyo3::exceptions::PyValueError::new_err(
+                        "All ephemerides must share the same timestamps",
+                    ));
+                }
+                Some(_) => {}
+                None => times = Some(obj_times),
+            }
+            let track: Vec<[f64; 3]> = positions
+                .rows()
+                .into_iter()
+                .map(|row| [row[0], row[1], row[2]])
+                .collect();
+            tracks.push(track);
+        }
+        let times = times.unwrap_or_default();
+
+        let sin_min_elevation = self.min_elevation.to_radians().sin();
+        let cos_half_angle = self.half_angle.map(|h| h.to_radians().cos());
+        let cells: Vec<(f64, f64)> = self
+            .latitudes
+            .iter()
+            .flat_map(|&lat| self.longitudes.iter().map(move |&lon| (lat, lon)))
+            .collect();
+
+        let windows = py.detach(|| {
+            cells
+                .par_iter()
+                .map(|&(lat, lon)| {
+                    let point = geodetic_to_ecef_km(lat, lon, 0.0);
+                    let up = enu_basis(lat, lon)[2];
+                    let access: Vec<bool> = (0..times.len())
+                        .map(|i| {
+                            tracks.iter().any(|track| {
+                                in_view(&point, &up, &track[i], sin_min_elevation, cos_half_angle)
+                            })
+                        })
+                        .collect();
+                    runs(&access)
+                })
+                .collect()
+        });
+
+        Ok(CoverageResult {
+            times,
+            latitudes: self.latitudes.clone(),
+            longitudes: self.longitudes.clone(),
+            windows,
+            n_spacecraft: tracks.len(),
+        })
+    }
+
+    fn __repr__(&self) -> String {
+        let half_angle = self
+            .half_angle
+            .map_or_else(|| "None".to_string(), |h| h.to_string());
+        format!(
+            "CoverageGrid(shape=({}, {}), half_angle={}, min_elevation={})",
+            self.latitudes.len(),
+            self.longitudes.len(),
+            half_angle,
+            self.min_elevation
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_in_view_cone_and_horizon() {
+        let point = geodetic_to_ecef_km(0.0, 0.0, 0.0);
+        let up = enu_basis(0.0, 0.0)[2];
+        let overhead = [7000.0, 0.0, 0.0];
+        assert!(in_view(&point, &up, &overhead, 0.0, Some(1e-3_f64.cos())));
+
+        // 10 degrees of longitude away: visible above the horizon, but
+        // outside a narrow cone
+        let offset = [
+            7000.0 * 10f64.to_radians().cos(),
+            7000.0 * 10f64.to_radians().sin(),
+            0.0,
+        ];
+        assert!(in_view(&point, &up, &offset, 0.0, None));
+        assert!(!in_view(
+            &point,
+            &up,
+            &offset,
+            0.0,
+            Some(5f64.to_radians().cos())
+        ));
+        assert!(in_view(
+            &point,
+            &up,
+            &offset,
+            0.0,
+            Some(60f64.to_radians().cos())
+        ));
+        assert!(!in_view(
+            &point,
+            &up,
+            &offset,
+            80f64.to_radians().sin(),
+            None
+        ));
+
+        // Far side of the Earth
+        assert!(!in_view(&point, &up, &[-7000.0, 0.0, 0.0], 0.0, None));
+    }
+
+    #[test]
+    fn test_revisit_gaps() {
+        let start = DateTime::parse_from_rfc3339("2024-01-01T00:00:00Z")
+            .unwrap()
+            .with_timezone(&Utc);
+        let times: Vec<_> = (0..6)
+            .map(|i| start + chrono::Duration::seconds(60 * i))
+            .collect();
+        let windows = runs(&[true, false, false, true, false, true]);
+        assert_eq!(revisit_gaps(&times, &windows), vec![180.0, 120.0]);
+        assert!(revisit_gaps(&times, &windows[..1]).is_empty());
+    }
+}
@@ -0,0 +1,353 @@
+//! Sanity checks of TLE and OMM mean element sets before propagation
+//!
+//! Malformed or stale element sets from Space-Track, Celestrak or operator
+//! files otherwise show up only as SGP4 errors or silently wrong positions.
+//! [`check_elements`] reports problems as errors (the elements cannot
+//! describe an orbit: eccentricity outside [0, 1), perigee below the
+//! surface, epoch before the first satellite) or warnings (propagation will
+//! run but the result is suspect: perigee low enough to be re-entering,
+//! epoch in the future, BSTAR far outside fitted values).
+
+use chrono::{DateTime, NaiveDate, TimeZone, Utc};
+use pyo3::prelude::*;
+use serde_json::json;
+use sgp4::{parse_2les, Elements};
+
+use crate::utils::config::{EARTH_RADIUS_KM, GM_EARTH, SECONDS_PER_DAY};
+
+/// Perigee altitude (km) below which the elements are reported as re-entering
+pub const MIN_PERIGEE_ALTITUDE_KM: f64 = 100.0;
+
+/// Largest |BSTAR| (1/Earth radii) not reported as an outlier
+///
+/// Fitted values are around 1e-5 to 1e-2 even for decaying objects; larger
+/// ones usually come from a poor fit.
+pub const MAX_BSTAR: f64 = 0.05;
+
+/// How far (days) the epoch may lie ahead of the current time
+pub const MAX_EPOCH_LEAD_DAYS: f64 = 1.0;
+
+/// How serious an [`ElementIssue`] is
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum IssueLevel {
+    /// Propagation would run, but the positions are suspect
+    Warning,
+    /// The elements cannot describe an orbit
+    Error,
+}
+
+impl IssueLevel {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            IssueLevel::Warning => "warning",
+            IssueLevel::Error => "error",
+        }
+    }
+}
+
+/// One problem found in an element set
+#[derive(Debug, Clone, PartialEq)]
+pub struct ElementIssue {
+    pub level: IssueLevel,
+    /// Element the issue concerns, e.g. "eccentricity" or "epoch"
+    pub field: &'static str,
+    pub message: String,
+}
+
+impl ElementIssue {
+    fn error(field: &'static str, message: String) -> Self {
+        Self {
+            level: IssueLevel::Error,
+            field,
+            message,
+        }
+    }
+
+    fn warning(field: &'static str, message: String) -> Self {
+        Self {
+            level: IssueLevel::Warning,
+            field,
+            message,
+        }
+    }
+
+    pub fn to_json(&self) -> serde_json::Value {
+        json!({
+            "level": self.level.as_str(),
+            "field": self.field,
+            "message": self.message,
+        })
+    }
+}
+
+/// Parse TLE lines with SGP4's parser, which also checks the line checksums
+pub fn parse_tle_elements(line1: &str, line2: &str) -> Result<Elements, String> {
+    parse_2les(&format!("{}\n{}", line1.trim(), line2.trim()))
+        .map_err(|e| e.to_string())?
+        .into_iter()
+        .next()
+        .ok_or_else(|| "No elements parsed from TLE".to_string())
+}
+
+/// Parse an OMM in CCSDS JSON form, as served by Celestrak and Space-Track
+pub fn parse_omm_elements(omm_json: &str) -> Result<Elements, String> {
+    serde_json::from_str(omm_json).map_err(|e| format!("Invalid OMM: {}", e))
+}
+
+/// Semi-major axis (km) implied by a mean motion in revolutions per day
+pub fn semi_major_axis_km(mean_motion: f64) -> f64 {
+    let n = mean_motion * 2.0 * std::f64::consts::PI / SECONDS_PER_DAY;
+    (GM_EARTH / (n * n)).cbrt()
+}
+
+/// Check the elements for values SGP4 cannot use or that point to bad data
+///
+/// `now` bounds the epoch; pass the current time.
+pub fn check_elements(elements: &Elements, now: DateTime<Utc>) -> Vec<ElementIssue> {
+    let mut issues = Vec::new();
+
+    let e = elements.eccentricity;
+    if !(0.0..1.0).contains(&e) {
+        issues.push(ElementIssue::error(
+            "eccentricity",
+            format!("Eccentricity {} is outside [0, 1)", e),
+        ));
+    }
+
+    let inclination = elements.inclination;
+    if !(0.0..=180.0).contains(&inclination) {
+        issues.push(ElementIssue::error(
+            "inclination",
+            format!("Inclination {}° is outside [0°, 180°]", inclination),
+        ));
+    }
+
+    let n = elements.mean_motion;
+    if !(n.is_finite() && n > 0.0) {
+        issues.push(ElementIssue::error(
+            "mean_motion",
+            format!("Mean motion {} rev/day must be positive", n),
+        ));
+    } else if (0.0..1.0).contains(&e) {
+        let perigee_km = semi_major_axis_km(n) * (1.0 - e) - EARTH_RADIUS_KM;
+        if perigee_km < 0.0 {
+            issues.push(ElementIssue::error(
+                "mean_motion",
+                format!(
+                    "Mean motion {} rev/day and eccentricity {} put the perigee {:.0} km \
+                     below the Earth's surface",
+                    n, e, -perigee_km
+                ),
+            ));
+        } else if perigee_km < MIN_PERIGEE_ALTITUDE_KM {
+            issues.push(ElementIssue::warning(
+                "mean_motion",
+                format!(
+                    "Perigee altitude {:.0} km is below {} km; the object is re-entering \
+                     and SGP4 positions are unreliable",
+                    perigee_km, MIN_PERIGEE_ALTITUDE_KM
+                ),
+            ));
+        }
+    }
+
+    let epoch = Utc.from_utc_datetime(&elements.datetime);
+    let first_satellite = NaiveDate::from_ymd_opt(1957, 10, 4)
+        .and_then(|date| date.and_hms_opt(0, 0, 0))
+        .map(|naive| Utc.from_utc_datetime(&naive))
+        .expect("valid date");
+    if epoch < first_satellite {
+        issues.push(ElementIssue::error(
+            "epoch",
+            format!(
+                "Epoch {} is before the first satellite launch",
+                epoch.to_rfc3339()
+            ),
+        ));
+    } else {
+        let lead_days = (epoch - now).num_seconds() as f64 / SECONDS_PER_DAY;
+        if lead_days > MAX_EPOCH_LEAD_DAYS {
+            issues.push(ElementIssue::warning(
+                "epoch",
+                format!(
+                    "Epoch {} is {:.1} days in the future",
+                    epoch.to_rfc3339(),
+                    lead_days
+                ),
+            ));
+        }
+    }
+
+    let bstar = elements.drag_term;
+    if !bstar.is_finite() {
+        issues.push(ElementIssue::error(
+            "bstar",
+            format!("BSTAR {} is not a number", bstar),
+        ));
+    } else if bstar.abs() > MAX_BSTAR {
+        issues.push(ElementIssue::warning(
+            "bstar",
+            format!(
+                "BSTAR {:e} is outside ±{}; the drag term is probably a poor fit",
+                bstar, MAX_BSTAR
+            ),
+        ));
+    }
+
+    issues
+}
+
+/// Raise `ValueError` for errors, and for warnings too when `strict`;
+/// otherwise issue each warning as a Python `UserWarning`
+pub fn raise_or_warn(py: Python, issues: &[ElementIssue], strict: bool) -> PyResult<()> {
+    let fatal: Vec<&str> = issues
+        .iter()
+        .filter(|issue| strict || issue.level == IssueLevel::Error)
+        .map(|issue| issue.message.as_str())
+        .collect();
+    if !fatal.is_empty() {
+        return Err(pyo3::exceptions::PyValueError::new_err(format!(
+            "Invalid element set: {}",
+            fatal.join("; ")
+        )));
+    }
+    let warnings = py.import("warnings")?;
+    for issue in issues {
+        warnings.call_method1("warn", (issue.message.as_str(),))?;
+    }
+    Ok(())
+}
+
+/// Elements from a TLE (any form accepted by `estimate_tle_accuracy`), an
+/// OMM mapping with CCSDS keys, or an OMM JSON object string
+///
+/// Unparseable element sets are returned as an error issue rather than raised.
+pub fn elements_from_python(item: &Bound<'_, PyAny>) -> PyResult<Result<Elements, ElementIssue>> {
+    let format_issue = |message: String| ElementIssue::error("format", message);
+    if let Ok(text) = item.extract::<String>() {
+        if text.trim_start().starts_with('{') {
+            return Ok(parse_omm_elements(&text).map_err(format_issue));
+        }
+    } else if item.get_item("MEAN_MOTION").is_ok() {
+        let text: String = item
+            .py()
+            .import("json")?
+            .call_method1("dumps", (item,))?
+            .extract()?;
+        return Ok(parse_omm_elements(&text).map_err(format_issue));
+    }
+    match crate::ephemeris::tle_accuracy::tle_from_python(item) {
+        Ok(tle) => Ok(parse_tle_elements(&tle.line1, &tle.line2).map_err(format_issue)),
+        Err(e) if e.is_instance_of::<pyo3::exceptions::PyValueError>(item.py()) => {
+            Ok(Err(format_issue(e.value(item.py()).to_string())))
+        }
+        Err(e) => Err(e),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const LINE1: &str = "1 25544U 98067A   08264.51782528 -.00002182  00000-0 -11606-4 0  2927";
+    const LINE2: &str = "2 25544  51.6416 247.4627 0006703 130.5360 325.0288 15.72125391563537";
+
+    fn now() -> DateTime<Utc> {
+        Utc.with_ymd_and_hms(2026, 1, 1, 0, 0, 0).unwrap()
+    }
+
+    fn fields(issues: &[ElementIssue]) -> Vec<(&'static str, IssueLevel)> {
+        issues.iter().map(|i| (i.field, i.level)).collect()
+    }
+
+    #[test]
+    fn test_valid_tle_has_no_issues() {
+        let elements = parse_tle_elements(LINE1, LINE2).unwrap();
+        assert!(check_elements(&elements, now()).is_empty());
+    }
+
+    #[test]
+    fn test_bad_checksum_is_a_parse_error() {
+        let line2 = LINE2.replace("563537", "563538");
+        let error = parse_tle_elements(LINE1, &line2).unwrap_err();
+        assert!(error.contains("checksum"), "{error}");
+    }
+
+    #[test]
+    fn test_semi_major_axis() {
+        // One revolution per sidereal day is geostationary
+        let sidereal = 86400.0 / 86164.0905;
+        assert!((semi_major_axis_km(sidereal) - 42164.0).abs() < 1.0);
+    }
+
+    #[test]
+    fn test_physical_checks() {
+        let mut elements = parse_tle_elements(LINE1, LINE2).unwrap();
+        elements.eccentricity = 1.2;
+        elements.inclination = 200.0;
+        elements.drag_term = 0.3;
+        assert_eq!(
+            fields(&check_elements(&elements, now())),
+            [
+                ("eccentricity", IssueLevel::Error),
+                ("inclination", IssueLevel::Error),
+                ("bstar", IssueLevel::Warning),
+            ]
+        );
+
+        // 15.7 rev/day with e = 0.1 dips well below the surface
+        let mut elements = parse_tle_elements(LINE1, LINE2).unwrap();
+        elements.eccentricity = 0.1;
+        assert_eq!(
+            fields(&check_elements(&elements, now())),
+            [("mean_motion", IssueLevel::Error)]
+        );
+
+        // About 75 km circular
+        elements.eccentricity = 0.0;
+        elements.mean_motion = 16.75;
+        assert_eq!(
+            fields(&check_elements(&elements, now())),
+            [("mean_motion", IssueLevel::Warning)]
+        );
+    }
+
+    #[test]
+    fn test_epoch_checks() {
+        let elements = parse_tle_elements(LINE1, LINE2).unwrap();
+        let before_epoch = Utc.with_ymd_and_hms(2008, 9, 1, 0, 0, 0).unwrap();
+        assert_eq!(
+            fields(&check_elements(&elements, before_epoch)),
+            [("epoch", IssueLevel::Warning)]
+        );
+
+        // Year 57 is 1957, before Sputnik on day 277
+        let line1 = "1 25544U 98067A   57001.00000000 -.00002182  00000-0 -11606-4 0  2927";
+        let mut elements = parse_tle_elements(LINE1, LINE2).unwrap();
+        elements.datetime = crate::utils::tle_utils::extract_tle_epoch(line1)
+            .unwrap()
+            .naive_utc();
+        assert_eq!(
+            fields(&check_elements(&elements, now())),
+            [("epoch", IssueLevel::Error)]
+        );
+    }
+
+    #[test]
+    fn test_omm() {
+        let omm = r#"{
+            "OBJECT_NAME": "ISS (ZARYA)", "OBJECT_ID": "1998-067A",
+            "EPOCH": "2020-07-12T01:19:07.402656", "MEAN_MOTION": "15.49560532",
+            "ECCENTRICITY": 0.0001771, "INCLINATION": 51.6435, "RA_OF_ASC_NODE": 225.4004,
+            "ARG_OF_PERICENTER": 44.9625, "MEAN_ANOMALY": 5.1087, "EPHEMERIS_TYPE": 0,
+            "CLASSIFICATION_TYPE": "U", "NORAD_CAT_ID": 25544, "ELEMENT_SET_NO": 999,
+            "REV_AT_EPOCH": 23587, "BSTAR": 0.0049645, "MEAN_MOTION_DOT": 0.00289036,
+            "MEAN_MOTION_DDOT": 0
+        }"#;
+        let elements = parse_omm_elements(omm).unwrap();
+        assert!(check_elements(&elements, now()).is_empty());
+
+        let error = parse_omm_elements(&omm.replace("\"MEAN_MOTION\"", "\"MM\"")).unwrap_err();
+        assert!(error.contains("MEAN_MOTION"), "{error}");
+    }
+}
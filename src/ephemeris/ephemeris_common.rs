@@ -1,18 +1,26 @@
-use chrono::{DateTime, Datelike, Duration, Timelike, Utc};
+use chrono::{DateTime, Datelike, Duration, SecondsFormat, Timelike, Utc};
 use ndarray::{s, Array2};
 use numpy::IntoPyArray;
 use pyo3::{prelude::*, types::PyDateTime};
-use std::sync::OnceLock;
+use std::sync::{Arc, OnceLock};
 
 use crate::ephemeris::position_velocity::PositionVelocityData;
 use crate::utils::celestial::compute_angular_radii_rad;
 use crate::utils::celestial::{calculate_moon_positions, calculate_sun_positions};
 use crate::utils::config::MAX_TIMESTAMPS;
 use crate::utils::conversions::{convert_frames, Frame};
-use crate::utils::geo::{deg_to_rad_array, ecef_to_geodetic_deg};
-use crate::utils::time_utils::{python_datetime_to_utc, utc_to_python_datetime};
+use crate::utils::eop_provider::is_eop_available;
+use crate::utils::geo::{
+    deg_to_rad_array, ecef_to_geodetic_deg, enu_basis, geodetic_rates, ground_speed_km_s,
+};
+use crate::utils::geomagnetic::geomagnetic_coordinates;
+use crate::utils::horizon::HorizonMask;
+use crate::utils::time_utils::{
+    chrono_to_epoch, epoch_to_chrono, python_datetime_to_utc, utc_to_python_datetime, GridTimeScale,
+};
 use crate::utils::to_skycoord::{to_skycoord, AstropyModules, SkyCoordConfig};
-use ndarray::Array1;
+use crate::utils::ut1_provider::is_ut1_available;
+use ndarray::{Array1, ArrayView1};
 
 /// Helper function for getting begin time from ephemeris common data
 #[inline]
@@ -54,6 +62,45 @@ pub fn get_step_size(times: &Option<Vec<DateTime<Utc>>>) -> PyResult<i64> {
     ))
 }
 
+/// ISO 8601 timestamp with microseconds, as parsed by `datetime.fromisoformat`
+pub fn metadata_timestamp(time: &DateTime<Utc>) -> String {
+    time.to_rfc3339_opts(SecondsFormat::Micros, false)
+}
+
+/// Parse the `time_scale` constructor keyword
+pub fn parse_time_scale(time_scale: &str) -> PyResult<GridTimeScale> {
+    GridTimeScale::parse(time_scale).map_err(pyo3::exceptions::PyValueError::new_err)
+}
+
+/// Reject `fast_itrs` combined with `polar_motion`; fast mode skips polar
+/// motion as well as the UT1-UTC correction
+pub fn check_fast_itrs(fast_itrs: bool, polar_motion: bool) -> PyResult<()> {
+    if fast_itrs && polar_motion {
+        return Err(pyo3::exceptions::PyValueError::new_err(
+            "fast_itrs cannot be combined with polar_motion",
+        ));
+    }
+    Ok(())
+}
+
+/// Begin, end, step size (seconds) and number of times, for metadata and reprs
+pub fn time_grid_metadata(
+    times: &Option<Vec<DateTime<Utc>>>,
+) -> Vec<(&'static str, serde_json::Value)> {
+    let times = times.as_deref().unwrap_or_default();
+    let iso = |t: Option<&DateTime<Utc>>| serde_json::Value::from(t.map(metadata_timestamp));
+    let step_size = match times {
+        [first, second, ..] => second.signed_duration_since(*first).num_seconds().into(),
+        _ => serde_json::Value::Null,
+    };
+    vec![
+        ("begin", iso(times.first())),
+        ("end", iso(times.last())),
+        ("step_size", step_size),
+        ("n_times", times.len().into()),
+    ]
+}
+
 /// Splits a stacked position+velocity (N x 6) array into a PositionVelocityData struct.
 ///
 /// # Arguments
@@ -70,10 +117,14 @@ pub(crate) fn split_pos_vel(arr: &Array2<f64>) -> PositionVelocityData {
 /// Generate a vector of timestamps from begin to end (inclusive) with step_size in seconds
 /// This is common logic shared between TLEEphemeris and SPICEEphemeris constructors.
 ///
+/// Steps are UTC clock seconds unless `time_scale` is TAI or TT, in which
+/// case they are SI seconds across leap seconds.
+///
 /// # Arguments
 /// * `begin` - Python datetime for the start of the time range
 /// * `end` - Python datetime for the end of the time range
 /// * `step_size` - Step size in seconds between timestamps
+/// * `time_scale` - Time scale in which the steps are uniform
 ///
 /// # Returns
 /// `Vec<DateTime<Utc>>` of generated timestamps
@@ -87,11 +138,23 @@ pub fn generate_timestamps(
     begin: &Bound<'_, PyDateTime>,
     end: &Bound<'_, PyDateTime>,
     step_size: i64,
+    time_scale: GridTimeScale,
 ) -> PyResult<Vec<DateTime<Utc>>> {
     // Convert Python datetime objects to Rust DateTime<Utc>
     let begin_dt = python_datetime_to_utc(begin)?;
     let end_dt = python_datetime_to_utc(end)?;
+    generate_timestamps_utc(begin_dt, end_dt, step_size, time_scale)
+}
 
+/// Generate timestamps from begin to end (inclusive) for already-converted UTC times
+///
+/// Same validation and limits as [`generate_timestamps`].
+pub fn generate_timestamps_utc(
+    begin_dt: DateTime<Utc>,
+    end_dt: DateTime<Utc>,
+    step_size: i64,
+    time_scale: GridTimeScale,
+) -> PyResult<Vec<DateTime<Utc>>> {
     // Validate inputs
     if begin_dt > end_dt {
         return Err(pyo3::exceptions::PyValueError::new_err(
@@ -104,6 +167,10 @@ pub fn generate_timestamps(
         ));
     }
 
+    if time_scale != GridTimeScale::Utc {
+        return generate_timestamps_si(begin_dt, end_dt, step_size);
+    }
+
     // Calculate expected number of timestamps to prevent excessive memory allocation
     // Using ceiling division: (a + b - 1) / b to handle non-evenly divisible ranges
     let time_range_secs = (end_dt - begin_dt).num_seconds();
@@ -130,6 +197,39 @@ pub fn generate_timestamps(
     Ok(times)
 }
 
+/// Generate timestamps uniform in TAI (and so TT) rather than UTC
+///
+/// Steps are SI seconds, so a grid spanning a leap second keeps its spacing
+/// and includes the leap second's instants rather than skipping them.
+fn generate_timestamps_si(
+    begin_dt: DateTime<Utc>,
+    end_dt: DateTime<Utc>,
+    step_size: i64,
+) -> PyResult<Vec<DateTime<Utc>>> {
+    let begin = chrono_to_epoch(&begin_dt).to_tai_duration();
+    let end = chrono_to_epoch(&end_dt).to_tai_duration();
+    let expected_count = ((end - begin).to_seconds() / step_size as f64).floor() as i64 + 1;
+    if expected_count > MAX_TIMESTAMPS {
+        return Err(pyo3::exceptions::PyValueError::new_err(
+            format!("Time range would generate approximately {expected_count} timestamps (max: {MAX_TIMESTAMPS}). Use a larger step_size.")
+        ));
+    }
+
+    Ok((0..expected_count)
+        .map(|k| {
+            let offset = hifitime::Duration::from_seconds((k * step_size) as f64);
+            epoch_to_chrono(&hifitime::Epoch::from_tai_duration(begin + offset))
+        })
+        .collect())
+}
+
+/// Observer geodetic coordinates borrowed from the ephemeris caches
+pub struct GeodeticView<'a> {
+    pub latitude_deg: ArrayView1<'a, f64>,
+    pub longitude_deg: ArrayView1<'a, f64>,
+    pub height_km: ArrayView1<'a, f64>,
+}
+
 /// Common data structure for ephemeris objects
 /// This holds the shared state between TLEEphemeris and SPICEEphemeris
 pub struct EphemerisData {
@@ -160,6 +260,10 @@ pub struct EphemerisData {
     pub longitude_rad_cache: OnceLock<Array1<f64>>,
     pub height_km_cache: OnceLock<Array1<f64>>,
     pub height_cache: OnceLock<Array1<f64>>,
+    /// Cached centred-dipole geomagnetic coordinates and cutoff rigidity
+    pub geomagnetic_latitude_deg_cache: OnceLock<Array1<f64>>,
+    pub geomagnetic_longitude_deg_cache: OnceLock<Array1<f64>>,
+    pub cutoff_rigidity_cache: OnceLock<Array1<f64>>,
     pub moon_angular_radius_rad_cache: OnceLock<Py<PyAny>>,
     pub earth_angular_radius_rad_cache: OnceLock<Py<PyAny>>,
     /// Cached Sun altitude angles (in degrees) for all times
@@ -172,6 +276,14 @@ pub struct EphemerisData {
     pub sun_ra_dec_rad_cache: OnceLock<Py<PyAny>>,
     pub moon_ra_dec_rad_cache: OnceLock<Py<PyAny>>,
     pub earth_ra_dec_rad_cache: OnceLock<Py<PyAny>>,
+    /// Cached fingerprint (hex digest of source, time grid and states)
+    pub fingerprint_cache: OnceLock<String>,
+    /// Cached provenance record, built on first request
+    pub provenance_cache: OnceLock<Arc<serde_json::Value>>,
+    /// Whether stored ITRS states skip the UT1-UTC correction and polar motion
+    pub fast_itrs: bool,
+    /// Time scale in which the generated timestamp grid is uniform
+    pub time_scale: GridTimeScale,
 }
 
 impl EphemerisData {
@@ -203,6 +315,9 @@ impl EphemerisData {
             longitude_rad_cache: OnceLock::new(),
             height_km_cache: OnceLock::new(),
             height_cache: OnceLock::new(),
+            geomagnetic_latitude_deg_cache: OnceLock::new(),
+            geomagnetic_longitude_deg_cache: OnceLock::new(),
+            cutoff_rigidity_cache: OnceLock::new(),
             sun_altitudes_cache: OnceLock::new(),
             sun_ra_dec_deg_cache: OnceLock::new(),
             moon_ra_dec_deg_cache: OnceLock::new(),
@@ -210,6 +325,10 @@ impl EphemerisData {
             sun_ra_dec_rad_cache: OnceLock::new(),
             moon_ra_dec_rad_cache: OnceLock::new(),
             earth_ra_dec_rad_cache: OnceLock::new(),
+            fingerprint_cache: OnceLock::new(),
+            provenance_cache: OnceLock::new(),
+            fast_itrs: false,
+            time_scale: GridTimeScale::Utc,
         }
     }
 }
@@ -221,7 +340,10 @@ impl Default for EphemerisData {
 }
 
 /// Trait defining common behavior for ephemeris objects
-pub trait EphemerisBase {
+///
+/// Implementors are `Sync` (as every `#[pyclass]` is), so borrowed ephemerides
+/// can be evaluated on worker threads.
+pub trait EphemerisBase: Sync {
     /// Get a reference to the common ephemeris data
     fn data(&self) -> &EphemerisData;
 
@@ -241,6 +363,100 @@ pub trait EphemerisBase {
     /// This must be implemented by each ephemeris type to store in its OnceLock
     fn set_itrs_skycoord_cache(&self, skycoord: Py<PyAny>) -> Result<(), Py<PyAny>>;
 
+    /// Class name plus the source elements and options that define this ephemeris
+    ///
+    /// This must be implemented by each ephemeris type; `fingerprint` hashes it
+    /// together with the time grid and the computed states.
+    fn source_identity(&self) -> String;
+
+    /// Stable hex digest identifying this ephemeris
+    ///
+    /// Covers the source identity, the time grid and the computed GCRS states,
+    /// so ephemerides built from the same source, grid and options share a
+    /// fingerprint across sessions, while a file whose contents changed under
+    /// the same path does not.
+    fn fingerprint(&self) -> String {
+        self.data()
+            .fingerprint_cache
+            .get_or_init(|| {
+                let mut context = md5::Context::new();
+                context.consume(self.source_identity().as_bytes());
+                context.consume([0u8]);
+                for time in self.data().times.iter().flatten() {
+                    context.consume(time.timestamp_micros().to_le_bytes());
+                }
+                for value in self.data().gcrs.iter().flat_map(|gcrs| gcrs.iter()) {
+                    context.consume(value.to_le_bytes());
+                }
+                // Only marked when set, so existing fingerprints are unchanged
+                if self.data().fast_itrs {
+                    context.consume(b"fast_itrs");
+                }
+                format!("{:x}", context.compute())
+            })
+            .clone()
+    }
+
+    /// Class name, then the source description and options, in display order
+    ///
+    /// This must be implemented by each ephemeris type; the first entry is
+    /// `("type", <class name>)`. `metadata` appends the time grid, the
+    /// computed frames and the state of the Earth orientation data.
+    fn source_metadata(&self) -> Vec<(&'static str, serde_json::Value)>;
+
+    /// Whether Earth-fixed outputs of this ephemeris apply polar motion
+    ///
+    /// This is the `polar_motion` constructor flag. It selects the GCRS↔ITRS
+    /// rotation behind ITRS positions, geodetic latitude/longitude/height,
+    /// alt/az and ground-station geometry, so all of them agree; methods with
+    /// a `polar_motion` argument override it for one call. Without EOP data
+    /// the correction is zero whichever way it is set.
+    fn applies_polar_motion(&self) -> bool;
+
+    /// Input files named in `source_metadata` (the entries ending in `_path`)
+    fn source_files(&self) -> Vec<String> {
+        self.source_metadata()
+            .into_iter()
+            .filter(|(key, _)| key.ends_with("_path"))
+            .filter_map(|(_, value)| value.as_str().map(str::to_string))
+            .collect()
+    }
+
+    /// Identifiers of the orbital element set behind this ephemeris, if any
+    ///
+    /// Recorded in `provenance`; sources without element sets return `None`.
+    fn element_set(&self) -> Option<serde_json::Value> {
+        None
+    }
+
+    /// Machine-readable provenance: source, time span, frames and corrections
+    fn metadata(&self) -> Vec<(&'static str, serde_json::Value)> {
+        let data = self.data();
+        let frames: Vec<&str> = [
+            ("gcrs", data.gcrs.is_some()),
+            ("itrs", self.get_itrs_data().is_some()),
+            ("sun", data.sun_gcrs.is_some()),
+            ("moon", data.moon_gcrs.is_some()),
+        ]
+        .into_iter()
+        .filter(|(_, computed)| *computed)
+        .map(|(frame, _)| frame)
+        .collect();
+
+        let mut metadata = self.source_metadata();
+        metadata.extend(time_grid_metadata(&data.times));
+        metadata.push(("frames", frames.into()));
+        metadata.push(("eop_available", is_eop_available().into()));
+        metadata.push((
+            "polar_motion_applied",
+            (self.applies_polar_motion() && is_eop_available()).into(),
+        ));
+        metadata.push(("fast_itrs", data.fast_itrs.into()));
+        metadata.push(("ut1_available", is_ut1_available().into()));
+        metadata.push(("fingerprint", self.fingerprint().into()));
+        metadata
+    }
+
     /// Convert RA/Dec to Altitude/Azimuth for this ephemeris
     ///
     /// This function calculates the topocentric altitude and azimuth of a celestial target
@@ -261,6 +477,40 @@ pub trait EphemerisBase {
         time_indices: Option<&[usize]>,
     ) -> Array2<f64>;
 
+    /// Local horizon profile of the observer, if one was supplied
+    ///
+    /// Only ground sites carry a horizon mask; `None` means a flat 0° horizon.
+    /// Alt/az constraints treat directions below the mask as blocked.
+    fn horizon_mask(&self) -> Option<&HorizonMask> {
+        None
+    }
+
+    /// Whether the observer is a fixed site on the Earth's surface
+    ///
+    /// Ground-only constraints use this to reject spacecraft ephemerides.
+    fn is_ground_site(&self) -> bool {
+        false
+    }
+
+    /// Observer heights in metres for altitude-dependent airmass
+    ///
+    /// `None` (the default) gives the sea-level Kasten & Young airmass.
+    /// Observers high in the atmosphere return their height at each time
+    /// step, and airmass is integrated through the standard atmosphere above
+    /// them instead.
+    fn airmass_heights(&self) -> Option<&Array1<f64>> {
+        None
+    }
+
+    /// 1-sigma position uncertainty per timestamp, if known
+    ///
+    /// Rows are [radial, in-track, cross-track] in km. Only ephemerides
+    /// loaded with a covariance (e.g. OEM files with covariance blocks)
+    /// provide one; constraints use it to widen their margins.
+    fn position_uncertainty(&self) -> Option<&Array2<f64>> {
+        None
+    }
+
     /// Calculate airmass for a target at given RA/Dec
     ///
     /// Airmass represents the relative path length through Earth's atmosphere compared to
@@ -290,23 +540,63 @@ pub trait EphemerisBase {
         dec_deg: f64,
         time_indices: Option<&[usize]>,
     ) -> PyResult<Vec<f64>> {
-        use crate::utils::celestial::calculate_airmass_kasten;
+        use crate::utils::atmosphere::airmass;
 
         // Get altitudes
         let altaz = self.radec_to_altaz(ra_deg, dec_deg, time_indices);
+        let heights = self.airmass_heights();
 
-        // Calculate airmass for each time using Kasten formula
+        // Calculate airmass for each time using Kasten formula, or the
+        // standard atmosphere above observers that give their heights
         // Kasten & Czeplak (1980): accurate to ±0.02 airmass for zenith angles up to ~75°
         let airmass: Vec<f64> = (0..altaz.nrows())
             .map(|i| {
                 let altitude_deg = altaz[[i, 0]];
-                calculate_airmass_kasten(altitude_deg)
+                let row = time_indices.map_or(i, |indices| indices[i]);
+                airmass(altitude_deg, heights.map(|h| h[row]))
             })
             .collect();
 
         Ok(airmass)
     }
 
+    /// Calculate airmass for many targets across the ephemeris times in one pass
+    ///
+    /// Uses the vectorized Kasten pipeline behind the airmass constraint
+    /// (`calculate_airmass_batch_fast`), which skips refraction and aberration
+    /// and so runs far faster than looping over `calculate_airmass`.
+    ///
+    /// # Returns
+    /// Array of shape (n_targets, n_times) with infinity below the horizon
+    fn calculate_airmass_batch(
+        &self,
+        ras_deg: &[f64],
+        decs_deg: &[f64],
+        time_indices: Option<&[usize]>,
+    ) -> PyResult<Array2<f64>>
+    where
+        Self: Sized,
+    {
+        if ras_deg.len() != decs_deg.len() {
+            return Err(pyo3::exceptions::PyValueError::new_err(
+                "ras and decs must have the same length",
+            ));
+        }
+        let n_times = self
+            .data()
+            .gcrs
+            .as_ref()
+            .ok_or_else(|| pyo3::exceptions::PyValueError::new_err("No GCRS data available"))?
+            .nrows();
+        if let Some(&bad) = time_indices.and_then(|indices| indices.iter().find(|&&i| i >= n_times))
+        {
+            return Err(pyo3::exceptions::PyIndexError::new_err(format!(
+                "time index {bad} out of range for {n_times} ephemeris times"
+            )));
+        }
+        crate::utils::celestial::calculate_airmass_batch_fast(ras_deg, decs_deg, self, time_indices)
+    }
+
     /// Get ITRS position and velocity in PositionVelocityData format
     fn get_itrs_pv(&self, py: Python) -> Option<Py<PositionVelocityData>> {
         self.get_itrs_data()
@@ -331,6 +621,25 @@ pub trait EphemerisBase {
             .map(|arr| Py::new(py, split_pos_vel(arr)).unwrap())
     }
 
+    /// Observer position/velocity converted from GCRS to another frame
+    fn get_converted_pv(&self, py: Python, frame: Frame) -> Option<Py<PositionVelocityData>> {
+        let gcrs = self.data().gcrs.as_ref()?;
+        let times = self.data().times.as_ref()?;
+        let converted =
+            convert_frames(gcrs, times, Frame::GCRS, frame, self.applies_polar_motion());
+        Some(Py::new(py, split_pos_vel(&converted)).unwrap())
+    }
+
+    /// Observer position/velocity in TEME, for comparison with SGP4/NORAD products
+    fn get_teme_pv(&self, py: Python) -> Option<Py<PositionVelocityData>> {
+        self.get_converted_pv(py, Frame::TEME)
+    }
+
+    /// Observer position/velocity in the pseudo-Earth-fixed frame (TEME rotated by GMST)
+    fn get_pef_pv(&self, py: Python) -> Option<Py<PositionVelocityData>> {
+        self.get_converted_pv(py, Frame::PEF)
+    }
+
     /// Get cached GCRS SkyCoord object
     fn get_gcrs(&self, py: Python) -> PyResult<Py<PyAny>> {
         // Lazy initialization: create on first access
@@ -587,6 +896,26 @@ pub trait EphemerisBase {
         Ok(illuminations)
     }
 
+    /// Fraction of the solar disk visible from the observer at each time step
+    ///
+    /// 1.0 in sunlight, 0.0 in the Earth's umbra and fractional in the
+    /// penumbra, from the overlap of the apparent Sun and Earth disks. For
+    /// power modeling where the binary eclipse constraint is too coarse.
+    fn illumination_factor(&self) -> PyResult<Vec<f64>> {
+        use crate::constraints::eclipse::illumination_factor;
+
+        let sun = self.get_sun_positions()?;
+        let observer = self.get_gcrs_positions()?;
+        Ok(observer
+            .rows()
+            .into_iter()
+            .zip(sun.rows())
+            .map(|(obs, sun)| {
+                illumination_factor([obs[0], obs[1], obs[2]], [sun[0], sun[1], sun[2]])
+            })
+            .collect())
+    }
+
     /// Get observer (spacecraft/satellite) positions in GCRS (N x 3 array, km) for constraint evaluation
     fn get_gcrs_positions(&self) -> PyResult<Array2<f64>> {
         let gcrs_data = self.data().gcrs.as_ref().ok_or_else(|| {
@@ -652,23 +981,15 @@ pub trait EphemerisBase {
 
     /// Return raw deg float arrays (latitude_deg)
     fn get_latitude_deg(&self, py: Python) -> PyResult<Option<Py<PyAny>>> {
-        self.compute_latlon_caches()?;
-        if let Some(lat_arr) = self.data().latitude_deg_cache.get() {
-            let arr = lat_arr.clone().into_pyarray(py).to_owned();
-            Ok(Some(arr.into()))
-        } else {
-            Ok(None)
-        }
+        Ok(self
+            .geodetic()?
+            .map(|geodetic| geodetic.latitude_deg.to_owned().into_pyarray(py).into()))
     }
 
     fn get_longitude_deg(&self, py: Python) -> PyResult<Option<Py<PyAny>>> {
-        self.compute_latlon_caches()?;
-        if let Some(lon_arr) = self.data().longitude_deg_cache.get() {
-            let arr = lon_arr.clone().into_pyarray(py).to_owned();
-            Ok(Some(arr.into()))
-        } else {
-            Ok(None)
-        }
+        Ok(self
+            .geodetic()?
+            .map(|geodetic| geodetic.longitude_deg.to_owned().into_pyarray(py).into()))
     }
 
     fn get_latitude_rad(&self, py: Python) -> PyResult<Option<Py<PyAny>>> {
@@ -724,10 +1045,8 @@ pub trait EphemerisBase {
 
     /// Return raw height array in kilometers
     fn get_height_km(&self, py: Python) -> PyResult<Option<Py<PyAny>>> {
-        self.compute_latlon_caches()?;
-        if let Some(h_arr) = self.data().height_km_cache.get() {
-            let arr = h_arr.clone().into_pyarray(py).to_owned();
-            Ok(Some(arr.into()))
+        if let Some(geodetic) = self.geodetic()? {
+            Ok(Some(geodetic.height_km.to_owned().into_pyarray(py).into()))
         } else if let Some(h_m_arr) = self.data().height_cache.get() {
             // fallback: convert meters to kilometers if km cache missing
             let h_km = h_m_arr.mapv(|v| v / 1000.0);
@@ -738,6 +1057,36 @@ pub trait EphemerisBase {
         }
     }
 
+    /// Centred-dipole geomagnetic latitude in degrees
+    fn get_geomagnetic_latitude_deg(&self, py: Python) -> PyResult<Option<Py<PyAny>>> {
+        self.compute_geomagnetic_caches()?;
+        Ok(self
+            .data()
+            .geomagnetic_latitude_deg_cache
+            .get()
+            .map(|arr| arr.clone().into_pyarray(py).to_owned().into()))
+    }
+
+    /// Centred-dipole geomagnetic longitude in degrees
+    fn get_geomagnetic_longitude_deg(&self, py: Python) -> PyResult<Option<Py<PyAny>>> {
+        self.compute_geomagnetic_caches()?;
+        Ok(self
+            .data()
+            .geomagnetic_longitude_deg_cache
+            .get()
+            .map(|arr| arr.clone().into_pyarray(py).to_owned().into()))
+    }
+
+    /// Störmer vertical cutoff rigidity in GV
+    fn get_cutoff_rigidity_gv(&self, py: Python) -> PyResult<Option<Py<PyAny>>> {
+        self.compute_geomagnetic_caches()?;
+        Ok(self
+            .data()
+            .cutoff_rigidity_cache
+            .get()
+            .map(|arr| arr.clone().into_pyarray(py).to_owned().into()))
+    }
+
     /// Get observer geocentric velocity (obsgeovel) - alias for GCRS velocity
     fn get_obsgeovel(&self, py: Python) -> PyResult<Option<Py<PyAny>>> {
         Ok(self.data().gcrs.as_ref().map(|arr| {
@@ -746,6 +1095,124 @@ pub trait EphemerisBase {
         }))
     }
 
+    /// Observer geodetic latitude and longitude (degrees) and height (km)
+    ///
+    /// Views into the cached arrays, for Rust callers that would otherwise go
+    /// through the numpy arrays of `get_latitude_deg` and friends. `None` when
+    /// the ephemeris has no ITRS positions.
+    fn geodetic(&self) -> PyResult<Option<GeodeticView<'_>>> {
+        self.compute_latlon_caches()?;
+        let data = self.data();
+        Ok(
+            match (
+                data.latitude_deg_cache.get(),
+                data.longitude_deg_cache.get(),
+                data.height_km_cache.get(),
+            ) {
+                (Some(lat), Some(lon), Some(height)) => Some(GeodeticView {
+                    latitude_deg: lat.view(),
+                    longitude_deg: lon.view(),
+                    height_km: height.view(),
+                }),
+                _ => None,
+            },
+        )
+    }
+
+    /// Geodetic latitude and longitude rates (deg/s) and height rate (km/s)
+    /// as an N x 3 array, from the ITRS velocity
+    fn geodetic_rates(&self) -> PyResult<Option<Array2<f64>>> {
+        let (Some(geodetic), Some(states)) = (self.geodetic()?, self.get_itrs_states()?) else {
+            return Ok(None);
+        };
+        let mut rates = Array2::<f64>::zeros((states.nrows(), 3));
+        for (i, state) in states.rows().into_iter().enumerate() {
+            let row = geodetic_rates(
+                geodetic.latitude_deg[i],
+                geodetic.longitude_deg[i],
+                geodetic.height_km[i],
+                [state[3], state[4], state[5]],
+            );
+            rates.row_mut(i).assign(&ndarray::arr1(&row));
+        }
+        Ok(Some(rates))
+    }
+
+    /// Speed (km/s) of the sub-observer point over the WGS84 ellipsoid
+    fn ground_speed(&self) -> PyResult<Option<Vec<f64>>> {
+        let (Some(geodetic), Some(rates)) = (self.geodetic()?, self.geodetic_rates()?) else {
+            return Ok(None);
+        };
+        Ok(Some(
+            rates
+                .rows()
+                .into_iter()
+                .zip(geodetic.latitude_deg.iter())
+                .map(|(rate, &lat)| ground_speed_km_s(lat, rate[0], rate[1]))
+                .collect(),
+        ))
+    }
+
+    /// Geodetic latitude and longitude (degrees) of the point directly below
+    /// the observer, N x 2
+    fn sub_satellite_point(&self) -> PyResult<Option<Array2<f64>>> {
+        Ok(self.geodetic()?.map(|geodetic| {
+            ndarray::stack(
+                ndarray::Axis(1),
+                &[geodetic.latitude_deg, geodetic.longitude_deg],
+            )
+            .expect("latitude and longitude caches have the same length")
+        }))
+    }
+
+    /// Latitude and longitude (degrees) of the point with the Sun at the
+    /// zenith, N x 2
+    ///
+    /// Geodetic, ignoring the Sun's parallax from the Earth's centre (under 9
+    /// arcseconds).
+    fn sub_solar_point(&self) -> PyResult<Array2<f64>>
+    where
+        Self: Sized,
+    {
+        let sun = crate::ephemeris::ground_access::sun_itrs_positions(
+            self,
+            None,
+            self.applies_polar_motion(),
+        )?;
+        let mut points = Array2::<f64>::zeros((sun.nrows(), 2));
+        for (i, s) in sun.rows().into_iter().enumerate() {
+            let r = (s[0] * s[0] + s[1] * s[1] + s[2] * s[2]).sqrt();
+            points[[i, 0]] = (s[2] / r).asin().to_degrees();
+            points[[i, 1]] = s[1].atan2(s[0]).to_degrees();
+        }
+        Ok(points)
+    }
+
+    /// Solar zenith angle (degrees) at the sub-satellite point; the ground
+    /// below the observer is daylit below 90
+    fn nadir_solar_zenith_angle(&self) -> PyResult<Option<Vec<f64>>>
+    where
+        Self: Sized,
+    {
+        let Some(nadir) = self.sub_satellite_point()? else {
+            return Ok(None);
+        };
+        let sub_solar = self.sub_solar_point()?;
+        Ok(Some(
+            nadir
+                .rows()
+                .into_iter()
+                .zip(sub_solar.rows())
+                .map(|(nadir, sun)| {
+                    let up = enu_basis(nadir[0], nadir[1])[2];
+                    let toward_sun = enu_basis(sun[0], sun[1])[2];
+                    let cos_angle: f64 = (0..3).map(|k| up[k] * toward_sun[k]).sum();
+                    cos_angle.clamp(-1.0, 1.0).acos().to_degrees()
+                })
+                .collect(),
+        ))
+    }
+
     /// Ensure latitude/longitude caches are computed
     fn compute_latlon_caches(&self) -> PyResult<()> {
         // Already computed
@@ -753,23 +1220,7 @@ pub trait EphemerisBase {
             return Ok(());
         }
 
-        // Get ITRS positions if available, otherwise convert from GCRS
-        let positions_itrs_opt: Option<Array2<f64>> = if let Some(itrs) = self.get_itrs_data() {
-            Some(itrs.slice(s![.., 0..3]).to_owned())
-        } else if let Some(gcrs) = self.data().gcrs.as_ref() {
-            // Convert to ITRS
-            let times = self
-                .data()
-                .times
-                .as_ref()
-                .ok_or_else(|| pyo3::exceptions::PyValueError::new_err("No times available"))?;
-            let itrs_array = convert_frames(gcrs, times, Frame::GCRS, Frame::ITRS, false);
-            Some(itrs_array.slice(s![.., 0..3]).to_owned())
-        } else {
-            None
-        };
-
-        if let Some(positions_itrs) = positions_itrs_opt {
+        if let Some(positions_itrs) = self.get_itrs_positions()? {
             let (lats_deg, lons_deg, h_km) = ecef_to_geodetic_deg(&positions_itrs);
             let lats_rad = deg_to_rad_array(&lats_deg);
             let lons_rad = deg_to_rad_array(&lons_deg);
@@ -787,6 +1238,74 @@ pub trait EphemerisBase {
         Ok(())
     }
 
+    /// Observer ITRS positions (N x 3, km), converted from GCRS when no ITRS data is stored
+    fn get_itrs_positions(&self) -> PyResult<Option<Array2<f64>>> {
+        self.get_itrs_positions_with(None)
+    }
+
+    /// Observer ITRS positions and velocities (N x 6, km and km/s), converted
+    /// from GCRS when no ITRS data is stored
+    fn get_itrs_states(&self) -> PyResult<Option<Array2<f64>>> {
+        if let Some(itrs) = self.get_itrs_data().filter(|itrs| itrs.ncols() >= 6) {
+            return Ok(Some(itrs.slice(s![.., 0..6]).to_owned()));
+        }
+        let (Some(gcrs), Some(times)) = (self.data().gcrs.as_ref(), self.data().times.as_ref())
+        else {
+            return Ok(None);
+        };
+        Ok(Some(convert_frames(
+            gcrs,
+            times,
+            Frame::GCRS,
+            Frame::ITRS,
+            self.applies_polar_motion(),
+        )))
+    }
+
+    /// Observer ITRS positions (N x 3, km) with an optional polar motion override
+    ///
+    /// Stored ITRS data already follows the ephemeris's own setting, so it is
+    /// only recomputed from GCRS when `polar_motion` asks for the other one.
+    fn get_itrs_positions_with(&self, polar_motion: Option<bool>) -> PyResult<Option<Array2<f64>>> {
+        let polar_motion = polar_motion.unwrap_or_else(|| self.applies_polar_motion());
+        let stored = self
+            .get_itrs_data()
+            .filter(|_| polar_motion == self.applies_polar_motion());
+        if let Some(itrs) = stored {
+            Ok(Some(itrs.slice(s![.., 0..3]).to_owned()))
+        } else if let Some(gcrs) = self.data().gcrs.as_ref() {
+            let times = self
+                .data()
+                .times
+                .as_ref()
+                .ok_or_else(|| pyo3::exceptions::PyValueError::new_err("No times available"))?;
+            let itrs_array = convert_frames(gcrs, times, Frame::GCRS, Frame::ITRS, polar_motion);
+            Ok(Some(itrs_array.slice(s![.., 0..3]).to_owned()))
+        } else {
+            Ok(self
+                .get_itrs_data()
+                .map(|itrs| itrs.slice(s![.., 0..3]).to_owned()))
+        }
+    }
+
+    /// Compute and cache geomagnetic latitude/longitude and vertical cutoff rigidity
+    fn compute_geomagnetic_caches(&self) -> PyResult<()> {
+        if self.data().geomagnetic_latitude_deg_cache.get().is_some() {
+            return Ok(());
+        }
+
+        if let (Some(positions_itrs), Some(times)) =
+            (self.get_itrs_positions()?, self.data().times.as_ref())
+        {
+            let (lats, lons, rigidities) = geomagnetic_coordinates(&positions_itrs, times);
+            let _ = self.data().geomagnetic_latitude_deg_cache.set(lats);
+            let _ = self.data().geomagnetic_longitude_deg_cache.set(lons);
+            let _ = self.data().cutoff_rigidity_cache.set(rigidities);
+        }
+
+        Ok(())
+    }
+
     /// Helper to build SkyCoordConfig with common data retrieval pattern
     /// This eliminates duplication across all xxx_to_skycoord methods
     fn build_skycoord_config<'a>(
@@ -938,6 +1457,9 @@ pub trait EphemerisBase {
     ///
     /// # Arguments
     /// * `body_identifier` - NAIF ID (as string) or body name (e.g., "Jupiter", "mars", "301")
+    /// * `spice_kernel` - Optional additional SPICE kernel to load
+    /// * `use_horizons` - Legacy flag, equivalent to `source="auto"`
+    /// * `source` - Data source precedence: "spice", "horizons" or "auto"
     ///
     /// # Returns
     /// `PositionVelocityData` containing position and velocity arrays in km and km/s
@@ -954,8 +1476,95 @@ pub trait EphemerisBase {
         body_identifier: &str,
         spice_kernel: Option<&str>,
         use_horizons: bool,
+        source: Option<&str>,
     ) -> PyResult<Py<PositionVelocityData>> {
-        use crate::utils::celestial::calculate_body_by_id_or_name;
+        let body_geocentric =
+            self.body_geocentric_pv(py, body_identifier, spice_kernel, use_horizons, source)?;
+
+        // Get observer's geocentric position
+        let observer_geocentric = self.data().gcrs.as_ref().ok_or_else(|| {
+            pyo3::exceptions::PyValueError::new_err(
+                "No GCRS data available. Ephemeris should compute GCRS during initialization.",
+            )
+        })?;
+
+        // Calculate body position relative to observer: body - observer
+        let body_observer_centric = &body_geocentric - observer_geocentric;
+
+        Py::new(py, split_pos_vel(&body_observer_centric))
+    }
+
+    /// Positions of several bodies relative to the observer, computed in one pass
+    ///
+    /// Equivalent to calling `get_body_pv` for each body, but the SPICE almanac,
+    /// the time conversion and the observer positions are set up once for all
+    /// bodies.
+    ///
+    /// # Returns
+    /// Dict mapping each identifier as given to its `PositionVelocityData`
+    ///
+    /// # Example Python usage
+    /// ```python
+    /// planets = eph.get_bodies(["Venus", "Mars", "Jupiter"])
+    /// mars = planets["Mars"].position
+    /// ```
+    fn get_bodies(
+        &self,
+        py: Python,
+        body_identifiers: &[String],
+        spice_kernel: Option<&str>,
+        use_horizons: bool,
+        source: Option<&str>,
+    ) -> PyResult<Py<pyo3::types::PyDict>> {
+        let ids: Vec<&str> = body_identifiers.iter().map(String::as_str).collect();
+        let bodies_geocentric =
+            self.bodies_geocentric_pv(py, &ids, spice_kernel, use_horizons, source)?;
+
+        let observer_geocentric = self.data().gcrs.as_ref().ok_or_else(|| {
+            pyo3::exceptions::PyValueError::new_err(
+                "No GCRS data available. Ephemeris should compute GCRS during initialization.",
+            )
+        })?;
+
+        let dict = pyo3::types::PyDict::new(py);
+        for (id, body_geocentric) in ids.iter().zip(bodies_geocentric) {
+            let relative = &body_geocentric - observer_geocentric;
+            dict.set_item(*id, Py::new(py, split_pos_vel(&relative))?)?;
+        }
+        Ok(dict.unbind())
+    }
+
+    /// Geocentric position/velocity of a body on the ephemeris time grid
+    ///
+    /// Resolves the data source from `source` ("spice", "horizons" or "auto") and the
+    /// legacy `use_horizons` flag. When "auto" had to fall back from SPICE to JPL
+    /// Horizons, a Python `UserWarning` names the source that was used.
+    fn body_geocentric_pv(
+        &self,
+        py: Python,
+        body_identifier: &str,
+        spice_kernel: Option<&str>,
+        use_horizons: bool,
+        source: Option<&str>,
+    ) -> PyResult<Array2<f64>> {
+        let mut bodies =
+            self.bodies_geocentric_pv(py, &[body_identifier], spice_kernel, use_horizons, source)?;
+        Ok(bodies.remove(0))
+    }
+
+    /// Geocentric position/velocity of several bodies on the ephemeris time grid
+    ///
+    /// Same source resolution and fallback warning as `body_geocentric_pv`,
+    /// with the SPICE lookups sharing one setup.
+    fn bodies_geocentric_pv(
+        &self,
+        py: Python,
+        body_identifiers: &[&str],
+        spice_kernel: Option<&str>,
+        use_horizons: bool,
+        source: Option<&str>,
+    ) -> PyResult<Vec<Array2<f64>>> {
+        use crate::utils::celestial::{calculate_bodies_by_id_or_name, BodySource};
         use crate::utils::config::EARTH_NAIF_ID;
 
         let times = self
@@ -964,27 +1573,36 @@ pub trait EphemerisBase {
             .as_ref()
             .ok_or_else(|| pyo3::exceptions::PyValueError::new_err("No times available."))?;
 
-        // Calculate body position relative to Earth center
-        let body_geocentric = calculate_body_by_id_or_name(
+        let requested = BodySource::from_options(source, use_horizons)
+            .map_err(pyo3::exceptions::PyValueError::new_err)?;
+
+        // Calculate body positions relative to Earth center
+        let results = calculate_bodies_by_id_or_name(
             times,
-            body_identifier,
+            body_identifiers,
             EARTH_NAIF_ID,
             spice_kernel,
-            use_horizons,
+            requested,
         )
         .map_err(pyo3::exceptions::PyValueError::new_err)?;
 
-        // Get observer's geocentric position
-        let observer_geocentric = self.data().gcrs.as_ref().ok_or_else(|| {
-            pyo3::exceptions::PyValueError::new_err(
-                "No GCRS data available. Ephemeris should compute GCRS during initialization.",
-            )
-        })?;
-
-        // Calculate body position relative to observer: body - observer
-        let body_observer_centric = &body_geocentric - observer_geocentric;
+        let mut bodies_geocentric = Vec::with_capacity(results.len());
+        for (body_identifier, (body_geocentric, used)) in body_identifiers.iter().zip(results) {
+            if requested == BodySource::Auto && used == BodySource::Horizons {
+                let warnings = py.import("warnings")?;
+                warnings.call_method1(
+                    "warn",
+                    (format!(
+                        "Body '{}' is not available from the loaded SPICE kernels; \
+                         positions were retrieved from JPL Horizons instead",
+                        body_identifier
+                    ),),
+                )?;
+            }
+            bodies_geocentric.push(body_geocentric);
+        }
 
-        Py::new(py, split_pos_vel(&body_observer_centric))
+        Ok(bodies_geocentric)
     }
 
     /// Get SkyCoord object for any body identified by NAIF ID or name
@@ -995,6 +1613,9 @@ pub trait EphemerisBase {
     ///
     /// # Arguments
     /// * `body_identifier` - NAIF ID (as string) or body name (e.g., "Jupiter", "mars", "301")
+    /// * `spice_kernel` - Optional additional SPICE kernel to load
+    /// * `use_horizons` - Legacy flag, equivalent to `source="auto"`
+    /// * `source` - Data source precedence: "spice", "horizons" or "auto"
     ///
     /// # Returns
     /// Astropy SkyCoord object in GCRS frame with observer location set
@@ -1013,25 +1634,10 @@ pub trait EphemerisBase {
         body_identifier: &str,
         spice_kernel: Option<&str>,
         use_horizons: bool,
+        source: Option<&str>,
     ) -> PyResult<Py<PyAny>> {
-        use crate::utils::celestial::calculate_body_by_id_or_name;
-        use crate::utils::config::EARTH_NAIF_ID;
-
-        let times = self
-            .data()
-            .times
-            .as_ref()
-            .ok_or_else(|| pyo3::exceptions::PyValueError::new_err("No times available."))?;
-
-        // Calculate body position relative to Earth center
-        let body_geocentric = calculate_body_by_id_or_name(
-            times,
-            body_identifier,
-            EARTH_NAIF_ID,
-            spice_kernel,
-            use_horizons,
-        )
-        .map_err(pyo3::exceptions::PyValueError::new_err)?;
+        let body_geocentric =
+            self.body_geocentric_pv(py, body_identifier, spice_kernel, use_horizons, source)?;
 
         // Get observer's geocentric position
         let observer_geocentric = self.data().gcrs.as_ref().ok_or_else(|| {
@@ -1054,6 +1660,174 @@ pub trait EphemerisBase {
         to_skycoord(py, Some(modules), config)
     }
 
+    /// Observer-relative direction of a separation operand at each time step
+    ///
+    /// The operand may be a fixed `(ra_deg, dec_deg)` pair, a body name (the
+    /// cached "sun", "moon" and "earth" products, or anything `get_body_pv`
+    /// resolves), a `PositionVelocityData` of observer-relative positions such
+    /// as `get_body_pv` returns, or a SkyCoord-like object with `ra`/`dec`
+    /// (scalar, or one per time step) such as the cached `sun`/`moon` products.
+    ///
+    /// # Returns
+    /// Array2 with shape (N, 3) of direction vectors, not normalized
+    fn separation_directions(
+        &self,
+        py: Python,
+        target: &Bound<'_, PyAny>,
+    ) -> PyResult<Array2<f64>> {
+        use crate::utils::vector_math::{radec_to_unit_vector, radec_to_unit_vectors_batch};
+
+        let observer = self.get_gcrs_positions()?;
+        let n = observer.nrows();
+        let fixed = |ra: f64, dec: f64| {
+            let unit = radec_to_unit_vector(ra, dec);
+            Array2::from_shape_fn((n, 3), |(_, k)| unit[k])
+        };
+
+        if let Ok(name) = target.extract::<String>() {
+            return match name.trim().to_ascii_lowercase().as_str() {
+                "sun" => Ok(self.get_sun_positions()? - &observer),
+                "moon" => Ok(self.get_moon_positions()? - &observer),
+                "earth" => Ok(-observer),
+                _ => {
+                    let body = self.body_geocentric_pv(py, &name, None, false, None)?;
+                    Ok(body.slice(s![.., 0..3]).to_owned() - &observer)
+                }
+            };
+        }
+        if let Ok(pv) = target.downcast::<PositionVelocityData>() {
+            let position = pv.borrow().position.clone();
+            if position.nrows() != n {
+                return Err(pyo3::exceptions::PyValueError::new_err(format!(
+                    "PositionVelocityData has {} rows but the ephemeris has {} times",
+                    position.nrows(),
+                    n
+                )));
+            }
+            return Ok(position);
+        }
+        if let Ok(radec) = target.extract::<Vec<f64>>() {
+            if let [ra, dec] = radec[..] {
+                return Ok(fixed(ra, dec));
+            }
+        }
+        if target.hasattr("ra")? && target.hasattr("dec")? {
+            let np = py.import("numpy")?;
+            let degrees = |attr: &str| -> PyResult<Vec<f64>> {
+                np.getattr("ravel")?
+                    .call1((target.getattr(attr)?.getattr("deg")?,))?
+                    .extract()
+            };
+            let (ras, decs) = (degrees("ra")?, degrees("dec")?);
+            return match ras.len() {
+                1 => Ok(fixed(ras[0], decs[0])),
+                len if len == n => Ok(radec_to_unit_vectors_batch(&ras, &decs)),
+                len => Err(pyo3::exceptions::PyValueError::new_err(format!(
+                    "Coordinate has {len} entries but the ephemeris has {n} times"
+                ))),
+            };
+        }
+        Err(pyo3::exceptions::PyTypeError::new_err(
+            "Separation operands must be an (ra_deg, dec_deg) pair, a body name, \
+             PositionVelocityData or a SkyCoord",
+        ))
+    }
+
+    /// Angular separation in degrees between two operands at each time step
+    ///
+    /// See `separation_directions` for the accepted operands. The angles are
+    /// computed in Rust from observer-relative vectors, without astropy.
+    fn separation(
+        &self,
+        py: Python,
+        a: &Bound<'_, PyAny>,
+        b: &Bound<'_, PyAny>,
+    ) -> PyResult<Vec<f64>> {
+        use crate::utils::vector_math::separation_angles_deg;
+
+        let a = self.separation_directions(py, a)?;
+        let b = self.separation_directions(py, b)?;
+        Ok(separation_angles_deg(&a, &b))
+    }
+
+    /// Angle in degrees between a fixed target and the observer-relative
+    /// direction of a body with geocentric positions `body_positions`
+    fn target_body_angles(
+        &self,
+        body_positions: &Array2<f64>,
+        ra_deg: f64,
+        dec_deg: f64,
+        time_indices: Option<&[usize]>,
+    ) -> PyResult<Vec<f64>> {
+        use crate::utils::vector_math::{radec_to_unit_vector, separation_angles_deg};
+
+        let observer = self.get_gcrs_positions()?;
+        let indices: Vec<usize> = match time_indices {
+            Some(indices) => indices.to_vec(),
+            None => (0..observer.nrows()).collect(),
+        };
+        if let Some(&bad) = indices.iter().find(|&&i| i >= observer.nrows()) {
+            return Err(pyo3::exceptions::PyValueError::new_err(format!(
+                "time index {bad} out of range for {} times",
+                observer.nrows()
+            )));
+        }
+
+        let relative = Array2::from_shape_fn((indices.len(), 3), |(j, k)| {
+            body_positions[[indices[j], k]] - observer[[indices[j], k]]
+        });
+        let unit = radec_to_unit_vector(ra_deg, dec_deg);
+        let target = Array2::from_shape_fn((indices.len(), 3), |(_, k)| unit[k]);
+        Ok(separation_angles_deg(&target, &relative))
+    }
+
+    /// Angle in degrees between a target and the Sun at each time step
+    fn sun_angle(
+        &self,
+        ra_deg: f64,
+        dec_deg: f64,
+        time_indices: Option<&[usize]>,
+    ) -> PyResult<Vec<f64>> {
+        self.target_body_angles(&self.get_sun_positions()?, ra_deg, dec_deg, time_indices)
+    }
+
+    /// Angle in degrees between a target and the Moon at each time step
+    fn moon_angle(
+        &self,
+        ra_deg: f64,
+        dec_deg: f64,
+        time_indices: Option<&[usize]>,
+    ) -> PyResult<Vec<f64>> {
+        self.target_body_angles(&self.get_moon_positions()?, ra_deg, dec_deg, time_indices)
+    }
+
+    /// Angle in degrees of a target above the Earth's limb at each time step
+    ///
+    /// The angle from the Earth's center minus the Earth's angular radius;
+    /// negative while the target is behind the limb. Uses the same equatorial
+    /// radius as `earth_radius_deg` and the Earth limb constraint, without
+    /// refraction or horizon masks.
+    fn earth_limb_angle(
+        &self,
+        ra_deg: f64,
+        dec_deg: f64,
+        time_indices: Option<&[usize]>,
+    ) -> PyResult<Vec<f64>> {
+        let n = self.get_gcrs_positions()?.nrows();
+        let center =
+            self.target_body_angles(&Array2::zeros((n, 3)), ra_deg, dec_deg, time_indices)?;
+        let radii = self.compute_earth_angular_radii()?;
+        let indices: Vec<usize> = match time_indices {
+            Some(indices) => indices.to_vec(),
+            None => (0..n).collect(),
+        };
+        Ok(center
+            .iter()
+            .zip(indices)
+            .map(|(angle, i)| angle - radii[i].to_degrees())
+            .collect())
+    }
+
     fn body_observer_distances(&self, body_data: &Array2<f64>) -> PyResult<Vec<f64>> {
         let observer_data = self.data().gcrs.as_ref().ok_or_else(|| {
             pyo3::exceptions::PyValueError::new_err(
@@ -1085,8 +1859,6 @@ pub trait EphemerisBase {
     /// Helper to compute sun angular radii in radians
     /// Returns a Vec<f64> of angular radii for each timestamp
     fn compute_sun_angular_radii(&self) -> PyResult<Vec<f64>> {
-        use crate::utils::config::SUN_RADIUS_KM;
-
         let sun_geocentric = self
             .data()
             .sun_gcrs
@@ -1094,28 +1866,30 @@ pub trait EphemerisBase {
             .ok_or_else(|| pyo3::exceptions::PyValueError::new_err("No Sun data available."))?;
 
         let distances = self.body_observer_distances(sun_geocentric)?;
-        Ok(compute_angular_radii_rad(SUN_RADIUS_KM, distances))
+        Ok(compute_angular_radii_rad(
+            crate::utils::body_properties::sun_radii().equatorial,
+            distances,
+        ))
     }
 
     /// Helper to compute moon angular radii in radians
     /// Returns a Vec<f64> of angular radii for each timestamp
     fn compute_moon_angular_radii(&self) -> PyResult<Vec<f64>> {
-        use crate::utils::config::MOON_RADIUS_KM;
-
         let moon_geocentric =
             self.data().moon_gcrs.as_ref().ok_or_else(|| {
                 pyo3::exceptions::PyValueError::new_err("No Moon data available.")
             })?;
 
         let distances = self.body_observer_distances(moon_geocentric)?;
-        Ok(compute_angular_radii_rad(MOON_RADIUS_KM, distances))
+        Ok(compute_angular_radii_rad(
+            crate::utils::body_properties::moon_radii().equatorial,
+            distances,
+        ))
     }
 
     /// Helper to compute earth angular radii in radians
     /// Returns a Vec<f64> of angular radii for each timestamp
     fn compute_earth_angular_radii(&self) -> PyResult<Vec<f64>> {
-        use crate::utils::config::EARTH_RADIUS_KM;
-
         let gcrs_data =
             self.data().gcrs.as_ref().ok_or_else(|| {
                 pyo3::exceptions::PyValueError::new_err("No GCRS data available.")
@@ -1130,7 +1904,10 @@ pub trait EphemerisBase {
             (x * x + y * y + z * z).sqrt()
         });
 
-        Ok(compute_angular_radii_rad(EARTH_RADIUS_KM, distances))
+        Ok(compute_angular_radii_rad(
+            crate::utils::body_properties::earth_radii().equatorial,
+            distances,
+        ))
     }
 
     /// Get angular radius of the Sun as seen from the observer (in degrees)
@@ -24,6 +24,14 @@
 //! Position: `"km"` (default), `"m"`, `"cm"`.
 //! Velocity: `"km/s"` (default), `"m/s"`, `"cm/s"`.
 //!
+//! ## Table layout
+//!
+//! By default each data row is `<time> <x> <y> <z> <vx> <vy> <vz>`. Rows are split
+//! on commas when the line contains one and on whitespace otherwise; an explicit
+//! `delimiter` may be given instead. The `columns` parameter maps the time and
+//! state components to other positions, either by zero-based index or by name
+//! from a header row, so ad-hoc CSV exports can be read without reformatting.
+//!
 //! The frame, epoch and units are auto-detected from the file header when possible
 //! and may be overridden via constructor parameters.
 
@@ -31,15 +39,26 @@ use chrono::{DateTime, NaiveDate, NaiveDateTime, NaiveTime, Utc};
 use ndarray::Array2;
 use numpy::IntoPyArray;
 use pyo3::{prelude::*, types::PyDateTime};
+use std::collections::HashMap;
 use std::fs::File;
 use std::io::{BufRead, BufReader};
 use std::path::Path;
 use std::sync::OnceLock;
 
 use crate::ephemeris::ephemeris_common::{
-    generate_timestamps, split_pos_vel, EphemerisBase, EphemerisData,
+    check_fast_itrs, generate_timestamps, parse_time_scale, split_pos_vel, EphemerisBase,
+    EphemerisData,
 };
+use crate::ephemeris::ground_access::{self, AccessOpportunity};
+use crate::ephemeris::observation_geometry::{observation_geometry, ObservationGeometry};
 use crate::ephemeris::position_velocity::PositionVelocityData;
+use crate::ephemeris::provenance::{ephemeris_provenance, Provenance};
+use crate::ephemeris::sample::{
+    ephemeris_getitem, ephemeris_len, EphemerisIterator, EphemerisSample,
+};
+use crate::ephemeris::{
+    ephemeris_eq, ephemeris_hash, ephemeris_metadata, ephemeris_repr, ephemeris_summary,
+};
 use crate::utils::conversions::{self, Frame};
 use crate::utils::interpolation::hermite_interpolate;
 use crate::utils::time_utils::python_datetime_to_utc;
@@ -56,6 +75,123 @@ struct ParsedFileData {
     detected_velocity_unit: String,
 }
 
+/// Names accepted as keys of the `columns` mapping, in storage order.
+const COLUMN_KEYS: [&str; 7] = ["time", "x", "y", "z", "vx", "vy", "vz"];
+
+/// Reference to a table column, by zero-based position or by header name.
+#[derive(Debug, Clone)]
+enum ColumnRef {
+    Index(usize),
+    Name(String),
+}
+
+/// How data rows are split into fields.
+#[derive(Debug, Clone, Copy)]
+enum Delimiter {
+    /// Comma if the line contains one, whitespace otherwise.
+    Auto,
+    Whitespace,
+    Char(char),
+}
+
+impl Delimiter {
+    fn parse(value: Option<&str>) -> PyResult<Self> {
+        match value {
+            None => Ok(Delimiter::Auto),
+            Some(v) if v.eq_ignore_ascii_case("auto") => Ok(Delimiter::Auto),
+            Some(v) if v.eq_ignore_ascii_case("whitespace") || v == " " => {
+                Ok(Delimiter::Whitespace)
+            }
+            Some(v) if v.chars().count() == 1 => Ok(Delimiter::Char(v.chars().next().unwrap())),
+            Some(v) => Err(pyo3::exceptions::PyValueError::new_err(format!(
+                "Invalid delimiter '{v}'. Use a single character, 'whitespace' or 'auto'"
+            ))),
+        }
+    }
+
+    fn split<'a>(&self, line: &'a str) -> Vec<&'a str> {
+        match self {
+            Delimiter::Auto if line.contains(',') => line.split(',').map(str::trim).collect(),
+            Delimiter::Auto | Delimiter::Whitespace => line.split_whitespace().collect(),
+            Delimiter::Char(c) => line.split(*c).map(str::trim).collect(),
+        }
+    }
+}
+
+/// Column layout of the data table.
+#[derive(Debug, Clone)]
+struct ColumnSpec {
+    refs: [ColumnRef; 7],
+}
+
+impl ColumnSpec {
+    /// `<time> <x> <y> <z> <vx> <vy> <vz>`
+    fn positional() -> Self {
+        ColumnSpec {
+            refs: std::array::from_fn(ColumnRef::Index),
+        }
+    }
+
+    /// Build from the user `columns` mapping; unmapped keys keep their default position.
+    fn from_mapping(mapping: &HashMap<String, Bound<'_, PyAny>>) -> PyResult<Self> {
+        let mut spec = Self::positional();
+        for (key, value) in mapping {
+            let slot = COLUMN_KEYS
+                .iter()
+                .position(|k| k.eq_ignore_ascii_case(key.trim()))
+                .ok_or_else(|| {
+                    pyo3::exceptions::PyValueError::new_err(format!(
+                        "Unknown column key '{key}'. Expected one of: {}",
+                        COLUMN_KEYS.join(", ")
+                    ))
+                })?;
+            spec.refs[slot] = if let Ok(index) = value.extract::<usize>() {
+                ColumnRef::Index(index)
+            } else if let Ok(name) = value.extract::<String>() {
+                ColumnRef::Name(name.trim().to_string())
+            } else {
+                return Err(pyo3::exceptions::PyTypeError::new_err(format!(
+                    "Column '{key}' must be a non-negative index or a header name"
+                )));
+            };
+        }
+        Ok(spec)
+    }
+
+    fn needs_header(&self) -> bool {
+        self.refs.iter().any(|r| matches!(r, ColumnRef::Name(_)))
+    }
+
+    /// True if `fields` is a header row naming every named column.
+    fn matches_header(&self, fields: &[&str]) -> bool {
+        self.refs.iter().all(|r| match r {
+            ColumnRef::Index(_) => true,
+            ColumnRef::Name(name) => fields.iter().any(|f| f.eq_ignore_ascii_case(name)),
+        })
+    }
+
+    /// Resolve to field indices, looking names up in the header row if given.
+    fn resolve(&self, header: &[&str]) -> [usize; 7] {
+        std::array::from_fn(|slot| match &self.refs[slot] {
+            ColumnRef::Index(i) => *i,
+            ColumnRef::Name(name) => header
+                .iter()
+                .position(|f| f.eq_ignore_ascii_case(name))
+                .unwrap_or(usize::MAX),
+        })
+    }
+
+    fn names(&self) -> Vec<&str> {
+        self.refs
+            .iter()
+            .filter_map(|r| match r {
+                ColumnRef::Name(name) => Some(name.as_str()),
+                ColumnRef::Index(_) => None,
+            })
+            .collect()
+    }
+}
+
 // ─── Public struct ────────────────────────────────────────────────────────────
 
 #[pyclass]
@@ -80,7 +216,7 @@ pub struct FileEphemeris {
 impl FileEphemeris {
     #[new]
     #[allow(clippy::too_many_arguments)]
-    #[pyo3(signature = (file_path, begin, end, step_size=60, *, polar_motion=false, position_unit=None, velocity_unit=None, frame=None, epoch=None, time_format=None))]
+    #[pyo3(signature = (file_path, begin, end, step_size=60, *, polar_motion=false, fast_itrs=false, time_scale="UTC", position_unit=None, velocity_unit=None, frame=None, epoch=None, time_format=None, delimiter=None, columns=None))]
     fn new(
         _py: Python,
         file_path: String,
@@ -88,12 +224,18 @@ impl FileEphemeris {
         end: &Bound<'_, PyDateTime>,
         step_size: i64,
         polar_motion: bool,
+        fast_itrs: bool,
+        time_scale: &str,
         position_unit: Option<String>,
         velocity_unit: Option<String>,
         frame: Option<String>,
         epoch: Option<&Bound<'_, PyDateTime>>,
         time_format: Option<String>,
+        delimiter: Option<String>,
+        columns: Option<HashMap<String, Bound<'_, PyAny>>>,
     ) -> PyResult<Self> {
+        check_fast_itrs(fast_itrs, polar_motion)?;
+        let time_scale = parse_time_scale(time_scale)?;
         let path = Path::new(&file_path);
 
         // Parse an explicit override epoch if supplied.
@@ -101,9 +243,15 @@ impl FileEphemeris {
             epoch.map(|e| python_datetime_to_utc(e)).transpose()?;
 
         let time_fmt = time_format.as_deref().unwrap_or("auto");
+        let delimiter = Delimiter::parse(delimiter.as_deref())?;
+        let columns = match columns {
+            Some(mapping) => ColumnSpec::from_mapping(&mapping)?,
+            None => ColumnSpec::positional(),
+        };
 
         // ── Parse the file ──────────────────────────────────────────────────
-        let parsed = Self::parse_file(path, override_epoch.as_ref(), time_fmt)?;
+        let parsed =
+            Self::parse_file(path, override_epoch.as_ref(), time_fmt, delimiter, &columns)?;
 
         if parsed.times.is_empty() {
             return Err(pyo3::exceptions::PyValueError::new_err(
@@ -136,7 +284,7 @@ impl FileEphemeris {
         }
 
         // ── Build query time grid ───────────────────────────────────────────
-        let times = generate_timestamps(begin, end, step_size)?;
+        let times = generate_timestamps(begin, end, step_size, time_scale)?;
 
         let mut ephemeris = FileEphemeris {
             file_path,
@@ -146,6 +294,8 @@ impl FileEphemeris {
             common_data: {
                 let mut data = EphemerisData::new();
                 data.times = Some(times);
+                data.fast_itrs = fast_itrs;
+                data.time_scale = time_scale;
                 data
             },
             file_times: parsed.times,
@@ -190,6 +340,18 @@ impl FileEphemeris {
         self.polar_motion
     }
 
+    /// Whether ITRS skips the UT1-UTC correction and polar motion.
+    #[getter]
+    fn fast_itrs(&self) -> bool {
+        self.common_data.fast_itrs
+    }
+
+    /// Get the time scale in which the timestamp grid is uniform
+    #[getter]
+    fn time_scale(&self) -> &'static str {
+        self.common_data.time_scale.name()
+    }
+
     /// Position unit as found/specified (before conversion to km).
     #[getter]
     fn source_position_unit(&self) -> &str {
@@ -250,6 +412,18 @@ impl FileEphemeris {
         self.get_gcrs_pv(py)
     }
 
+    /// Observer position/velocity in TEME, converted from GCRS
+    #[getter]
+    fn teme_pv(&self, py: Python) -> Option<Py<PositionVelocityData>> {
+        self.get_teme_pv(py)
+    }
+
+    /// Observer position/velocity in the pseudo-Earth-fixed frame (TEME rotated by GMST)
+    #[getter]
+    fn pef_pv(&self, py: Python) -> Option<Py<PositionVelocityData>> {
+        self.get_pef_pv(py)
+    }
+
     #[getter]
     fn itrs_pv(&self, py: Python) -> Option<Py<PositionVelocityData>> {
         self.get_itrs_pv(py)
@@ -350,6 +524,21 @@ impl FileEphemeris {
         self.get_height_km(py)
     }
 
+    #[getter]
+    fn geomagnetic_latitude_deg(&self, py: Python) -> PyResult<Option<Py<PyAny>>> {
+        self.get_geomagnetic_latitude_deg(py)
+    }
+
+    #[getter]
+    fn geomagnetic_longitude_deg(&self, py: Python) -> PyResult<Option<Py<PyAny>>> {
+        self.get_geomagnetic_longitude_deg(py)
+    }
+
+    #[getter]
+    fn cutoff_rigidity_gv(&self, py: Python) -> PyResult<Option<Py<PyAny>>> {
+        self.get_cutoff_rigidity_gv(py)
+    }
+
     #[getter]
     fn sun_radius(&self, py: Python) -> PyResult<Py<PyAny>> {
         self.get_sun_radius(py)
@@ -489,29 +678,247 @@ impl FileEphemeris {
         self.find_closest_index(time)
     }
 
+    /// Stable hex digest of the source, time grid, options and computed states
+    ///
+    /// Ephemerides with equal fingerprints compare equal, so the fingerprint
+    /// can key caches of results computed from this ephemeris.
+    fn fingerprint(&self) -> String {
+        EphemerisBase::fingerprint(self)
+    }
+
+    fn __eq__(&self, other: &Bound<'_, PyAny>) -> bool {
+        ephemeris_eq(self, other)
+    }
+
+    fn __hash__(&self) -> u64 {
+        ephemeris_hash(self)
+    }
+
+    /// Source, time span, computed frames and Earth orientation state
+    ///
+    /// Values are JSON-serializable (times as ISO 8601 strings), so the dict
+    /// can be logged as provenance alongside derived results.
+    #[getter]
+    fn metadata(&self, py: Python) -> PyResult<Py<PyAny>> {
+        ephemeris_metadata(py, self)
+    }
+
+    /// Reproducibility record: library version, source and fingerprint,
+    /// element set, input and kernel file digests, Earth orientation and
+    /// leap-second data
+    #[getter]
+    fn provenance(&self) -> Provenance {
+        ephemeris_provenance(self)
+    }
+
+    /// Human-readable, multi-line description of `metadata`
+    fn summary(&self) -> String {
+        ephemeris_summary(self)
+    }
+
+    fn __repr__(&self) -> String {
+        ephemeris_repr(self)
+    }
+
+    fn __len__(&self) -> usize {
+        ephemeris_len(self)
+    }
+
+    /// State at time step `index`; negative indices count from the end
+    fn __getitem__(&self, index: isize) -> PyResult<EphemerisSample> {
+        ephemeris_getitem(self, index)
+    }
+
+    fn __iter__(slf: Bound<'_, Self>) -> EphemerisIterator {
+        EphemerisIterator::new(slf.into_any().unbind())
+    }
+
+    /// Fraction of the solar disk visible from the observer (1.0 sunlit, 0.0 umbra)
+    #[getter]
+    fn illumination_factor(&self, py: Python) -> PyResult<Py<PyAny>> {
+        let factors = EphemerisBase::illumination_factor(self)?;
+        Ok(factors.into_pyarray(py).into())
+    }
+
+    /// Geodetic latitude and longitude rates (deg/s) and height rate (km/s), shape (N, 3)
+    #[getter]
+    fn geodetic_rates(&self, py: Python) -> PyResult<Option<Py<PyAny>>> {
+        Ok(EphemerisBase::geodetic_rates(self)?.map(|rates| rates.into_pyarray(py).into()))
+    }
+
+    /// Speed of the sub-observer point over the WGS84 ellipsoid (km/s)
+    #[getter]
+    fn ground_speed(&self, py: Python) -> PyResult<Option<Py<PyAny>>> {
+        Ok(EphemerisBase::ground_speed(self)?.map(|speed| speed.into_pyarray(py).into()))
+    }
+
+    /// Geodetic (latitude, longitude) in degrees below the observer, shape (N, 2)
+    #[getter]
+    fn sub_satellite_point(&self, py: Python) -> PyResult<Option<Py<PyAny>>> {
+        Ok(EphemerisBase::sub_satellite_point(self)?.map(|points| points.into_pyarray(py).into()))
+    }
+
+    /// (latitude, longitude) in degrees with the Sun at the zenith, shape (N, 2)
+    #[getter]
+    fn sub_solar_point(&self, py: Python) -> PyResult<Py<PyAny>> {
+        Ok(EphemerisBase::sub_solar_point(self)?
+            .into_pyarray(py)
+            .into())
+    }
+
+    /// Solar zenith angle at the sub-satellite point in degrees (daylit below 90)
+    #[getter]
+    fn nadir_solar_zenith_angle(&self, py: Python) -> PyResult<Option<Py<PyAny>>> {
+        Ok(EphemerisBase::nadir_solar_zenith_angle(self)?
+            .map(|angles| angles.into_pyarray(py).into()))
+    }
+
     #[pyo3(signature = (time_indices=None))]
     fn moon_illumination(&self, time_indices: Option<Vec<usize>>) -> PyResult<Vec<f64>> {
         EphemerisBase::moon_illumination(self, time_indices.as_deref())
     }
 
-    #[pyo3(signature = (body, spice_kernel=None, use_horizons=false))]
+    /// Imaging opportunities of a ground target
+    ///
+    /// Windows in which the point at (`lat`, `lon`) is above its local horizon,
+    /// within `max_off_nadir_deg` of the spacecraft's nadir and, if
+    /// `min_sun_elev` is given, lit by the Sun at least that high. Each
+    /// opportunity reports the off-nadir angle, azimuth and ground-sample
+    /// geometry at its smallest off-nadir angle.
+    ///
+    /// # Arguments
+    /// * `lat` - Geodetic latitude of the target in degrees
+    /// * `lon` - Longitude of the target in degrees, east positive
+    /// * `max_off_nadir_deg` - Maximum off-nadir angle in degrees
+    /// * `min_sun_elev` - Minimum Sun elevation at the target in degrees (default: no limit)
+    ///
+    /// # Returns
+    /// List of `AccessOpportunity` in time order
+    #[pyo3(signature = (lat, lon, max_off_nadir_deg, min_sun_elev=None))]
+    fn access_to(
+        &self,
+        lat: f64,
+        lon: f64,
+        max_off_nadir_deg: f64,
+        min_sun_elev: Option<f64>,
+    ) -> PyResult<Vec<AccessOpportunity>> {
+        ground_access::access_to(self, lat, lon, max_off_nadir_deg, min_sun_elev)
+    }
+
+    #[pyo3(signature = (body, spice_kernel=None, use_horizons=false, source=None))]
     fn get_body_pv(
         &self,
         py: Python,
         body: &str,
         spice_kernel: Option<String>,
         use_horizons: bool,
+        source: Option<String>,
     ) -> PyResult<Py<PositionVelocityData>> {
-        <Self as EphemerisBase>::get_body_pv(self, py, body, spice_kernel.as_deref(), use_horizons)
+        <Self as EphemerisBase>::get_body_pv(
+            self,
+            py,
+            body,
+            spice_kernel.as_deref(),
+            use_horizons,
+            source.as_deref(),
+        )
+    }
+
+    #[pyo3(signature = (bodies, spice_kernel=None, use_horizons=false, source=None))]
+    fn get_bodies(
+        &self,
+        py: Python,
+        bodies: Vec<String>,
+        spice_kernel: Option<String>,
+        use_horizons: bool,
+        source: Option<String>,
+    ) -> PyResult<Py<pyo3::types::PyDict>> {
+        <Self as EphemerisBase>::get_bodies(
+            self,
+            py,
+            &bodies,
+            spice_kernel.as_deref(),
+            use_horizons,
+            source.as_deref(),
+        )
+    }
+
+    /// Angular separation in degrees between two targets at each time step
+    ///
+    /// Each of `a` and `b` may be an `(ra_deg, dec_deg)` pair, a body name,
+    /// observer-relative `PositionVelocityData` or a SkyCoord.
+    fn separation(
+        &self,
+        py: Python,
+        a: &Bound<'_, PyAny>,
+        b: &Bound<'_, PyAny>,
+    ) -> PyResult<Py<PyAny>> {
+        let angles = <Self as EphemerisBase>::separation(self, py, a, b)?;
+        Ok(angles.into_pyarray(py).into())
     }
 
-    #[pyo3(signature = (body, spice_kernel=None, use_horizons=false))]
+    /// Angle in degrees between a target and the Sun at each time step
+    #[pyo3(signature = (ra_deg, dec_deg, time_indices=None))]
+    fn sun_angle(
+        &self,
+        py: Python,
+        ra_deg: f64,
+        dec_deg: f64,
+        time_indices: Option<Vec<usize>>,
+    ) -> PyResult<Py<PyAny>> {
+        let angles = EphemerisBase::sun_angle(self, ra_deg, dec_deg, time_indices.as_deref())?;
+        Ok(angles.into_pyarray(py).into())
+    }
+
+    /// Angle in degrees between a target and the Moon at each time step
+    #[pyo3(signature = (ra_deg, dec_deg, time_indices=None))]
+    fn moon_angle(
+        &self,
+        py: Python,
+        ra_deg: f64,
+        dec_deg: f64,
+        time_indices: Option<Vec<usize>>,
+    ) -> PyResult<Py<PyAny>> {
+        let angles = EphemerisBase::moon_angle(self, ra_deg, dec_deg, time_indices.as_deref())?;
+        Ok(angles.into_pyarray(py).into())
+    }
+
+    /// Angle in degrees of a target above the Earth's limb (negative behind it)
+    #[pyo3(signature = (ra_deg, dec_deg, time_indices=None))]
+    fn earth_limb_angle(
+        &self,
+        py: Python,
+        ra_deg: f64,
+        dec_deg: f64,
+        time_indices: Option<Vec<usize>>,
+    ) -> PyResult<Py<PyAny>> {
+        let angles =
+            EphemerisBase::earth_limb_angle(self, ra_deg, dec_deg, time_indices.as_deref())?;
+        Ok(angles.into_pyarray(py).into())
+    }
+
+    /// Sun, Moon and Earth-limb angles, altitude, eclipse, SAA, orbital phase
+    /// and beta angle for a target at the ephemeris step nearest `time`
+    #[pyo3(signature = (ra_deg, dec_deg, time, *, saa_polygon=None))]
+    fn geometry(
+        &self,
+        ra_deg: f64,
+        dec_deg: f64,
+        time: &Bound<'_, PyDateTime>,
+        saa_polygon: Option<Vec<(f64, f64)>>,
+    ) -> PyResult<ObservationGeometry> {
+        let index = self.find_closest_index(time)?;
+        observation_geometry(self, ra_deg, dec_deg, index, saa_polygon.as_deref())
+    }
+
+    #[pyo3(signature = (body, spice_kernel=None, use_horizons=false, source=None))]
     fn get_body(
         &self,
         py: Python,
         body: &str,
         spice_kernel: Option<String>,
         use_horizons: bool,
+        source: Option<String>,
     ) -> PyResult<Py<PyAny>> {
         let modules = AstropyModules::import(py)?;
         <Self as EphemerisBase>::get_body(
@@ -521,20 +928,53 @@ impl FileEphemeris {
             body,
             spice_kernel.as_deref(),
             use_horizons,
+            source.as_deref(),
         )
     }
 
     /// Convert RA/Dec to Altitude/Azimuth. Returns a NumPy array (N, 2): [alt_deg, az_deg].
-    #[pyo3(signature = (ra_deg, dec_deg, time_indices=None))]
+    #[pyo3(signature = (ra_deg, dec_deg, time_indices=None, *, polar_motion=None))]
     fn radec_to_altaz(
         &self,
         py: Python,
         ra_deg: f64,
         dec_deg: f64,
         time_indices: Option<Vec<usize>>,
+        polar_motion: Option<bool>,
     ) -> PyResult<Py<PyAny>> {
-        let arr =
-            <Self as EphemerisBase>::radec_to_altaz(self, ra_deg, dec_deg, time_indices.as_deref());
+        let arr = crate::utils::celestial::radec_to_altaz(
+            ra_deg,
+            dec_deg,
+            self,
+            time_indices.as_deref(),
+            polar_motion,
+        );
+        Ok(arr.into_pyarray(py).into())
+    }
+
+    /// Convert many RA/Dec targets to Altitude/Azimuth in one call
+    /// Returns NumPy array (n_targets, N, 2): [altitude_deg, azimuth_deg]
+    #[pyo3(signature = (ra_deg, dec_deg, time_indices=None, *, polar_motion=None))]
+    fn radec_to_altaz_batch(
+        &self,
+        py: Python,
+        ra_deg: Vec<f64>,
+        dec_deg: Vec<f64>,
+        time_indices: Option<Vec<usize>>,
+        polar_motion: Option<bool>,
+    ) -> PyResult<Py<PyAny>> {
+        if ra_deg.len() != dec_deg.len() {
+            return Err(pyo3::exceptions::PyValueError::new_err(
+                "ra_deg and dec_deg must have the same length",
+            ));
+        }
+        let arr = crate::utils::celestial::radec_to_altaz_batch(
+            &ra_deg,
+            &dec_deg,
+            self,
+            time_indices.as_deref(),
+            polar_motion,
+        );
         Ok(arr.into_pyarray(py).into())
     }
 
@@ -547,6 +987,31 @@ impl FileEphemeris {
     ) -> PyResult<Vec<f64>> {
         <Self as EphemerisBase>::calculate_airmass(self, ra_deg, dec_deg, time_indices.as_deref())
     }
+
+    /// Calculate airmass for many targets in one call
+    ///
+    /// Uses the vectorized Kasten pipeline of the airmass constraint, without
+    /// refraction or aberration, so there are no per-target Python calls.
+    ///
+    /// # Returns
+    /// NumPy array (n_targets, N) of airmass values, infinity below the horizon
+    #[pyo3(signature = (ras, decs, time_indices=None))]
+    fn airmass_batch(
+        &self,
+        py: Python,
+        ras: Vec<f64>,
+        decs: Vec<f64>,
+        time_indices: Option<Vec<usize>>,
+    ) -> PyResult<Py<PyAny>> {
+        let arr =
+            <Self as crate::ephemeris::ephemeris_common::EphemerisBase>::calculate_airmass_batch(
+                self,
+                &ras,
+                &decs,
+                time_indices.as_deref(),
+            )?;
+        Ok(arr.into_pyarray(py).into())
+    }
 }
 
 // ─── Private implementation ───────────────────────────────────────────────────
@@ -599,12 +1064,11 @@ impl FileEphemeris {
             .times
             .as_ref()
             .ok_or_else(|| pyo3::exceptions::PyValueError::new_err("No times available"))?;
-        let itrs = conversions::convert_frames(
+        let itrs = conversions::gcrs_to_itrs(
             gcrs_data,
             times,
-            Frame::GCRS,
-            Frame::ITRS,
             self.polar_motion,
+            self.common_data.fast_itrs,
         );
         self.itrs = Some(itrs);
         Ok(())
@@ -691,12 +1155,17 @@ impl FileEphemeris {
     /// The parser performs two logical passes in a single read:
     /// 1. **Header phase**: collect key-value metadata until the first data-like line
     ///    or an explicit data-section marker (`EphemerisTimePosVel`, `DATA_START`, etc.).
-    /// 2. **Data phase**: parse lines with 7+ whitespace-separated tokens where the
-    ///    columns 2–7 are all valid floating-point numbers.
+    /// 2. **Data phase**: parse lines whose state columns (by default columns 2–7)
+    ///    are all valid floating-point numbers.
+    ///
+    /// When `columns` refers to columns by name, the first row containing all of
+    /// those names is taken as the table header.
     fn parse_file(
         path: &Path,
         override_epoch: Option<&DateTime<Utc>>,
         time_format: &str,
+        delimiter: Delimiter,
+        columns: &ColumnSpec,
     ) -> PyResult<ParsedFileData> {
         let file = File::open(path).map_err(|e| {
             pyo3::exceptions::PyIOError::new_err(format!(
@@ -711,6 +1180,9 @@ impl FileEphemeris {
         let mut detected_position_unit = "km".to_string();
         let mut detected_velocity_unit = "km/s".to_string();
 
+        let mut column_indices: Option<[usize; 7]> =
+            (!columns.needs_header()).then(|| columns.resolve(&[]));
+
         let mut in_data_section = false;
         let mut data_times: Vec<DateTime<Utc>> = Vec::new();
         let mut data_rows: Vec<[f64; 6]> = Vec::new();
@@ -758,26 +1230,28 @@ impl FileEphemeris {
                 continue;
             }
 
+            let fields = delimiter.split(trimmed);
+
+            // ── Header row naming the table columns ─────────────────────────
+            if column_indices.is_none() && columns.matches_header(&fields) {
+                column_indices = Some(columns.resolve(&fields));
+                continue;
+            }
+
             // ── Detect whether this line looks like a state-vector row ───────
-            // Columns 2–7 (0-indexed 1..7) must all be parseable as f64.
-            let cols_numeric =
-                parts.len() >= 7 && parts[1..7].iter().all(|s| s.parse::<f64>().is_ok());
+            // The mapped state columns must all be parseable as f64.
+            let row = column_indices
+                .as_ref()
+                .and_then(|indices| Self::parse_state_row(&fields, indices));
 
-            if in_data_section || cols_numeric {
-                if cols_numeric {
+            if in_data_section || row.is_some() {
+                if let Some((time_field, state)) = row {
                     // Auto-enter data section on first numeric row
                     in_data_section = true;
 
-                    match Self::parse_time_column(parts[0], detected_epoch.as_ref(), time_format)? {
+                    match Self::parse_time_column(time_field, detected_epoch.as_ref(), time_format)?
+                    {
                         Some(t) => {
-                            let state = [
-                                parts[1].parse::<f64>().unwrap(),
-                                parts[2].parse::<f64>().unwrap(),
-                                parts[3].parse::<f64>().unwrap(),
-                                parts[4].parse::<f64>().unwrap(),
-                                parts[5].parse::<f64>().unwrap(),
-                                parts[6].parse::<f64>().unwrap(),
-                            ];
                             data_times.push(t);
                             data_rows.push(state);
                         }
@@ -856,11 +1330,18 @@ impl FileEphemeris {
             }
         }
 
+        if column_indices.is_none() {
+            return Err(pyo3::exceptions::PyValueError::new_err(format!(
+                "No header row found containing the columns {:?}",
+                columns.names()
+            )));
+        }
+
         if data_times.is_empty() {
             return Err(pyo3::exceptions::PyValueError::new_err(
                 "No state vector data found in file. \
-                Expected lines with at least 7 whitespace-separated values: \
-                <time> <x> <y> <z> <vx> <vy> <vz>",
+                Expected lines with at least 7 whitespace- or comma-separated values: \
+                <time> <x> <y> <z> <vx> <vy> <vz>, or a 'columns' mapping for other layouts",
             ));
         }
 
@@ -882,6 +1363,21 @@ impl FileEphemeris {
         })
     }
 
+    /// Extract the time field and state vector from a split data row.
+    ///
+    /// Returns `None` if a mapped column is missing or a state value is not numeric.
+    fn parse_state_row<'a>(
+        fields: &[&'a str],
+        indices: &[usize; 7],
+    ) -> Option<(&'a str, [f64; 6])> {
+        let time_field = *fields.get(indices[0])?;
+        let mut state = [0.0; 6];
+        for (value, &index) in state.iter_mut().zip(&indices[1..]) {
+            *value = fields.get(index)?.parse::<f64>().ok()?;
+        }
+        Some((time_field, state))
+    }
+
     /// Parse the time column of a data row according to `time_format`.
     ///
     /// Returns `Ok(None)` when the value cannot be interpreted (row should be skipped).
@@ -1029,12 +1525,38 @@ impl EphemerisBase for FileEphemeris {
         self.itrs_skycoord.set(skycoord)
     }
 
+    fn source_identity(&self) -> String {
+        format!(
+            "FileEphemeris\nfile_path={}\nposition_unit={}\nvelocity_unit={}\nframe={}\npolar_motion={}",
+            self.file_path,
+            self.source_position_unit,
+            self.source_velocity_unit,
+            self.source_frame,
+            self.polar_motion
+        )
+    }
+
+    fn source_metadata(&self) -> Vec<(&'static str, serde_json::Value)> {
+        vec![
+            ("type", "FileEphemeris".into()),
+            ("file_path", self.file_path.clone().into()),
+            ("frame", self.source_frame.clone().into()),
+            ("position_unit", self.source_position_unit.clone().into()),
+            ("velocity_unit", self.source_velocity_unit.clone().into()),
+            ("polar_motion", self.polar_motion.into()),
+        ]
+    }
+
+    fn applies_polar_motion(&self) -> bool {
+        self.polar_motion
+    }
+
     fn radec_to_altaz(
         &self,
         ra_deg: f64,
         dec_deg: f64,
         time_indices: Option<&[usize]>,
     ) -> Array2<f64> {
-        crate::utils::celestial::radec_to_altaz(ra_deg, dec_deg, self, time_indices)
+        crate::utils::celestial::radec_to_altaz(ra_deg, dec_deg, self, time_indices, None)
     }
 }
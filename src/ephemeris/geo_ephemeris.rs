@@ -0,0 +1,144 @@
+//! Ephemeris of an ideal geostationary satellite at a longitude slot
+//!
+//! `GEOEphemeris` holds a satellite fixed at its nominal slot in the
+//! Earth-fixed frame: a point on the geostationary radius at the slot
+//! longitude (and, optionally, a small latitude offset). It is an
+//! `ArrayEphemeris` underneath, so it has the full ephemeris interface and is
+//! accepted everywhere an `ArrayEphemeris` is. Pair it with the
+//! station-keeping constraint to compare an operator's OEM or TLE against
+//! the slot.
+
+use pyo3::{prelude::*, types::PyDateTime};
+
+use crate::ephemeris::array_ephemeris::ArrayEphemeris;
+use crate::ephemeris::ephemeris_common::{generate_timestamps, parse_time_scale};
+use crate::utils::config::{EARTH_RADIUS_KM, GEO_RADIUS_KM};
+
+/// Nominal position of a GEO slot in geocentric Earth-fixed coordinates
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct GeoSlot {
+    /// Geocentric longitude in degrees (-180 to 180)
+    pub longitude: f64,
+    /// Geocentric latitude in degrees
+    pub latitude: f64,
+    /// Distance from the Earth's centre in km
+    pub radius: f64,
+}
+
+impl GeoSlot {
+    /// ITRS position of the slot in km
+    pub fn itrs_position(&self) -> [f64; 3] {
+        let lat = self.latitude.to_radians();
+        let lon = self.longitude.to_radians();
+        [
+            self.radius * lat.cos() * lon.cos(),
+            self.radius * lat.cos() * lon.sin(),
+            self.radius * lat.sin(),
+        ]
+    }
+}
+
+/// Ideal geostationary satellite held at a longitude slot
+#[pyclass(extends=ArrayEphemeris)]
+pub struct GEOEphemeris {
+    slot: GeoSlot,
+}
+
+#[pymethods]
+impl GEOEphemeris {
+    /// Create an ephemeris for a satellite fixed at a GEO slot
+    ///
+    /// # Arguments
+    /// * `longitude_deg` - Slot longitude in degrees, east positive (-180 to 360)
+    /// * `begin` - Start time
+    /// * `end` - End time
+    /// * `step_size` - Step in seconds (default: 60)
+    /// * `latitude_deg` - Geocentric latitude offset in degrees (default: 0)
+    /// * `radius_km` - Orbit radius in km (default: geostationary radius, 42164.17)
+    /// * `polar_motion` - Whether to apply polar motion correction (default: false)
+    /// * `time_scale` - Scale in which the grid is uniform: "UTC", "TAI" or "TT"
+    ///   (default: "UTC")
+    #[new]
+    #[allow(clippy::too_many_arguments)]
+    #[pyo3(signature = (longitude_deg, begin, end, step_size=60, *, latitude_deg=0.0, radius_km=None, polar_motion=false, time_scale="UTC"))]
+    fn new(
+        longitude_deg: f64,
+        begin: &Bound<'_, PyDateTime>,
+        end: &Bound<'_, PyDateTime>,
+        step_size: i64,
+        latitude_deg: f64,
+        radius_km: Option<f64>,
+        polar_motion: bool,
+        time_scale: &str,
+    ) -> PyResult<(Self, ArrayEphemeris)> {
+        let time_scale = parse_time_scale(time_scale)?;
+        if !(-180.0..=360.0).contains(&longitude_deg) {
+            return Err(pyo3::exceptions::PyValueError::new_err(
+                "longitude_deg must be between -180 and 360 degrees",
+            ));
+        }
+        if !(-90.0..=90.0).contains(&latitude_deg) {
+            return Err(pyo3::exceptions::PyValueError::new_err(
+                "latitude_deg must be between -90 and 90 degrees",
+            ));
+        }
+        let radius = radius_km.unwrap_or(GEO_RADIUS_KM);
+        if radius.is_nan() || radius <= EARTH_RADIUS_KM {
+            return Err(pyo3::exceptions::PyValueError::new_err(
+                "radius_km must be larger than the Earth's radius",
+            ));
+        }
+
+        let slot = GeoSlot {
+            longitude: (longitude_deg + 180.0).rem_euclid(360.0) - 180.0,
+            latitude: latitude_deg,
+            radius,
+        };
+        let times = generate_timestamps(begin, end, step_size, time_scale)?;
+        let base = ArrayEphemeris::from_geo_slot(times, slot, polar_motion, time_scale)?;
+        Ok((GEOEphemeris { slot }, base))
+    }
+
+    /// Slot longitude in degrees (-180 to 180)
+    #[getter]
+    fn slot_longitude_deg(&self) -> f64 {
+        self.slot.longitude
+    }
+
+    /// Slot latitude in degrees
+    #[getter]
+    fn slot_latitude_deg(&self) -> f64 {
+        self.slot.latitude
+    }
+
+    /// Orbit radius in km
+    #[getter]
+    fn radius_km(&self) -> f64 {
+        self.slot.radius
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_slot_position() {
+        let slot = GeoSlot {
+            longitude: 90.0,
+            latitude: 0.0,
+            radius: GEO_RADIUS_KM,
+        };
+        let [x, y, z] = slot.itrs_position();
+        assert!(x.abs() < 1e-9);
+        assert!((y - GEO_RADIUS_KM).abs() < 1e-9);
+        assert!(z.abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_geo_radius_matches_sidereal_rate() {
+        use crate::utils::config::{GM_EARTH, OMEGA_EARTH};
+        let radius = (GM_EARTH / (OMEGA_EARTH * OMEGA_EARTH)).cbrt();
+        assert!((radius - GEO_RADIUS_KM).abs() < 0.1);
+    }
+}
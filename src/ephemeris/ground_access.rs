@@ -0,0 +1,322 @@
+//! Imaging opportunities of a ground target from an orbiting spacecraft
+//!
+//! [`access_to`] finds the windows in which a point on the ground can be
+//! imaged: the point must be on the visible side of the Earth's limb (above
+//! its local horizon), within the sensor's off-nadir reach, and optionally lit
+//! by the Sun. Each window is summarised at its best sample, the one with the
+//! smallest off-nadir angle, together with the ground-sample geometry there.
+//!
+//! Ground-sample distance (GSD) grows away from nadir. Relative to the nadir
+//! GSD at the spacecraft's altitude `h`, a pixel at slant range `R` seen at
+//! elevation `e` from the ground is stretched by `R / h` across the look
+//! direction and by `R / (h sin e)` along it.
+
+use chrono::{DateTime, Utc};
+use ndarray::{s, Array2};
+use pyo3::prelude::*;
+
+use crate::ephemeris::ephemeris_common::EphemerisBase;
+use crate::ephemeris::ground_network::{runs, seconds_between, spacecraft_itrs};
+use crate::utils::conversions::{convert_frames, Frame};
+use crate::utils::geo::{enu_basis, geodetic_to_ecef_km};
+use crate::utils::time_utils::utc_to_python_datetime;
+
+/// Sun positions in ITRS (km, Nx3) at the selected ephemeris times
+pub(crate) fn sun_itrs_positions(
+    ephemeris: &dyn EphemerisBase,
+    time_indices: Option<&[usize]>,
+    polar_motion: bool,
+) -> PyResult<Array2<f64>> {
+    let times = ephemeris.get_times()?;
+    let sun_gcrs = ephemeris.get_sun_positions()?;
+    let rows: Vec<usize> = match time_indices {
+        Some(indices) => indices.to_vec(),
+        None => (0..times.len()).collect(),
+    };
+    let times_filtered: Vec<DateTime<Utc>> = rows.iter().map(|&i| times[i]).collect();
+
+    // convert_frames expects position and velocity columns; the Sun's
+    // velocity is not needed, so it is left at zero
+    let mut sun_states = Array2::<f64>::zeros((rows.len(), 6));
+    for (k, &row) in rows.iter().enumerate() {
+        sun_states
+            .slice_mut(s![k, 0..3])
+            .assign(&sun_gcrs.slice(s![row, 0..3]));
+    }
+    Ok(convert_frames(
+        &sun_states,
+        &times_filtered,
+        Frame::GCRS,
+        Frame::ITRS,
+        polar_motion,
+    )
+    .slice(s![.., 0..3])
+    .to_owned())
+}
+
+/// Viewing geometry between a ground point and the spacecraft at one time
+#[derive(Debug, Clone, Copy)]
+struct LookGeometry {
+    /// Angle between the spacecraft's nadir and the line of sight, degrees
+    off_nadir: f64,
+    /// Spacecraft azimuth seen from the ground point, degrees east of north
+    azimuth: f64,
+    /// Spacecraft elevation seen from the ground point, degrees
+    elevation: f64,
+    /// Ground point to spacecraft distance, km
+    range: f64,
+    /// Spacecraft height above the ground point's geocentric radius, km
+    altitude: f64,
+    /// Sun elevation at the ground point, degrees
+    sun_elevation: f64,
+}
+
+fn dot(a: &[f64; 3], b: &[f64; 3]) -> f64 {
+    a[0] * b[0] + a[1] * b[1] + a[2] * b[2]
+}
+
+/// Viewing geometry from ITRS positions (km) of the ground point, spacecraft and Sun
+fn look_geometry(
+    point: &[f64; 3],
+    enu: &[[f64; 3]; 3],
+    spacecraft: &[f64; 3],
+    sun: &[f64; 3],
+) -> LookGeometry {
+    let [east, north, up] = enu;
+    let d = [
+        spacecraft[0] - point[0],
+        spacecraft[1] - point[1],
+        spacecraft[2] - point[2],
+    ];
+    let to_sun = [sun[0] - point[0], sun[1] - point[1], sun[2] - point[2]];
+    let range = dot(&d, &d).sqrt();
+    let spacecraft_radius = dot(spacecraft, spacecraft).sqrt();
+    // Nadir is -spacecraft and the line of sight is -d
+    let cos_off_nadir = dot(&d, spacecraft) / (range * spacecraft_radius);
+    LookGeometry {
+        off_nadir: cos_off_nadir.clamp(-1.0, 1.0).acos().to_degrees(),
+        azimuth: dot(&d, east)
+            .atan2(dot(&d, north))
+            .to_degrees()
+            .rem_euclid(360.0),
+        elevation: (dot(&d, up) / range).clamp(-1.0, 1.0).asin().to_degrees(),
+        range,
+        altitude: spacecraft_radius - dot(point, point).sqrt(),
+        sun_elevation: (dot(&to_sun, up) / dot(&to_sun, &to_sun).sqrt())
+            .clamp(-1.0, 1.0)
+            .asin()
+            .to_degrees(),
+    }
+}
+
+/// One imaging opportunity of a ground target
+#[pyclass]
+pub struct AccessOpportunity {
+    start: DateTime<Utc>,
+    end: DateTime<Utc>,
+    peak: DateTime<Utc>,
+    /// Duration in seconds
+    #[pyo3(get)]
+    duration_seconds: f64,
+    /// Smallest off-nadir angle in the window, degrees
+    #[pyo3(get)]
+    off_nadir_deg: f64,
+    /// Spacecraft azimuth seen from the target at `peak_time`, degrees east of north
+    #[pyo3(get)]
+    azimuth_deg: f64,
+    /// Spacecraft elevation seen from the target at `peak_time`, degrees
+    #[pyo3(get)]
+    elevation_deg: f64,
+    /// Target-to-spacecraft distance at `peak_time`, km
+    #[pyo3(get)]
+    slant_range_km: f64,
+    /// Sun elevation at the target at `peak_time`, degrees
+    #[pyo3(get)]
+    sun_elevation_deg: f64,
+    /// GSD across the look direction relative to nadir at `peak_time`
+    #[pyo3(get)]
+    gsd_factor_across: f64,
+    /// GSD along the look direction relative to nadir at `peak_time`
+    #[pyo3(get)]
+    gsd_factor_along: f64,
+}
+
+#[pymethods]
+impl AccessOpportunity {
+    /// Start of the opportunity
+    #[getter]
+    fn start_time(&self, py: Python) -> PyResult<Py<PyAny>> {
+        utc_to_python_datetime(py, &self.start)
+    }
+
+    /// End of the opportunity
+    #[getter]
+    fn end_time(&self, py: Python) -> PyResult<Py<PyAny>> {
+        utc_to_python_datetime(py, &self.end)
+    }
+
+    /// Time of the smallest off-nadir angle
+    #[getter]
+    fn peak_time(&self, py: Python) -> PyResult<Py<PyAny>> {
+        utc_to_python_datetime(py, &self.peak)
+    }
+
+    /// Incidence angle at the target at `peak_time` (90° - elevation), degrees
+    #[getter]
+    fn incidence_deg(&self) -> f64 {
+        90.0 - self.elevation_deg
+    }
+
+    fn __repr__(&self) -> String {
+        format!(
+            "AccessOpportunity(start_time={}, duration_seconds={:.1}, off_nadir_deg={:.2}, azimuth_deg={:.1})",
+            self.start.to_rfc3339(),
+            self.duration_seconds,
+            self.off_nadir_deg,
+            self.azimuth_deg
+        )
+    }
+}
+
+/// Summarise one run of accessible samples at its smallest off-nadir angle
+fn opportunity(
+    times: &[DateTime<Utc>],
+    geometry: &[LookGeometry],
+    (start, end): (usize, usize),
+) -> AccessOpportunity {
+    let best = (start..=end)
+        .min_by(|&a, &b| geometry[a].off_nadir.total_cmp(&geometry[b].off_nadir))
+        .unwrap_or(start);
+    let g = geometry[best];
+    let across = g.range / g.altitude;
+    AccessOpportunity {
+        start: times[start],
+        end: times[end],
+        peak: times[best],
+        duration_seconds: seconds_between(times, (start, end)),
+        off_nadir_deg: g.off_nadir,
+        azimuth_deg: g.azimuth,
+        elevation_deg: g.elevation,
+        slant_range_km: g.range,
+        sun_elevation_deg: g.sun_elevation,
+        gsd_factor_across: across,
+        gsd_factor_along: across / g.elevation.to_radians().sin(),
+    }
+}
+
+/// Imaging opportunities of the ground point at (`lat`, `lon`)
+///
+/// A sample is accessible when the point is above its local horizon, within
+/// `max_off_nadir_deg` of the spacecraft's nadir and, if `min_sun_elev` is
+/// given, the Sun is at least that high at the point.
+pub(crate) fn access_to(
+    ephemeris: &dyn EphemerisBase,
+    lat: f64,
+    lon: f64,
+    max_off_nadir_deg: f64,
+    min_sun_elev: Option<f64>,
+) -> PyResult<Vec<AccessOpportunity>> {
+    if !(-90.0..=90.0).contains(&lat) {
+        return Err(pyo3::exceptions::PyValueError::new_err(
+            "lat must be between -90 and 90 degrees",
+        ));
+    }
+    if !(-180.0..=360.0).contains(&lon) {
+        return Err(pyo3::exceptions::PyValueError::new_err(
+            "lon must be between -180 and 360 degrees",
+        ));
+    }
+    if !(max_off_nadir_deg > 0.0 && max_off_nadir_deg <= 90.0) {
+        return Err(pyo3::exceptions::PyValueError::new_err(
+            "max_off_nadir_deg must be between 0 and 90 degrees",
+        ));
+    }
+    if min_sun_elev.is_some_and(|e| !(-90.0..=90.0).contains(&e)) {
+        return Err(pyo3::exceptions::PyValueError::new_err(
+            "min_sun_elev must be between -90 and 90 degrees",
+        ));
+    }
+
+    let (positions, times) = spacecraft_itrs(ephemeris, None)?;
+    let sun = sun_itrs_positions(ephemeris, None, ephemeris.applies_polar_motion())?;
+    let point = geodetic_to_ecef_km(lat, lon, 0.0);
+    let enu = enu_basis(lat, lon);
+
+    let geometry: Vec<LookGeometry> = (0..times.len())
+        .map(|i| {
+            let spacecraft = [positions[[i, 0]], positions[[i, 1]], positions[[i, 2]]];
+            let sun = [sun[[i, 0]], sun[[i, 1]], sun[[i, 2]]];
+            look_geometry(&point, &enu, &spacecraft, &sun)
+        })
+        .collect();
+    let accessible: Vec<bool> = geometry
+        .iter()
+        .map(|g| {
+            g.elevation > 0.0
+                && g.off_nadir <= max_off_nadir_deg
+                && min_sun_elev.is_none_or(|min| g.sun_elevation >= min)
+        })
+        .collect();
+
+    Ok(runs(&accessible)
+        .into_iter()
+        .map(|window| opportunity(&times, &geometry, window))
+        .collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SUN_OVERHEAD: [f64; 3] = [1.5e8, 0.0, 0.0];
+
+    #[test]
+    fn test_look_geometry_nadir() {
+        let point = geodetic_to_ecef_km(0.0, 0.0, 0.0);
+        let enu = enu_basis(0.0, 0.0);
+        let g = look_geometry(&point, &enu, &[7000.0, 0.0, 0.0], &SUN_OVERHEAD);
+        assert!(g.off_nadir < 1e-9);
+        assert!((g.elevation - 90.0).abs() < 1e-9);
+        assert!((g.range - g.altitude).abs() < 1e-9);
+        assert!((g.sun_elevation - 90.0).abs() < 1e-3);
+    }
+
+    #[test]
+    fn test_look_geometry_off_nadir() {
+        let point = geodetic_to_ecef_km(0.0, 0.0, 0.0);
+        let enu = enu_basis(0.0, 0.0);
+        // Spacecraft 5° of longitude east: seen from the point it is to the east
+        let lon = 5f64.to_radians();
+        let spacecraft = [7000.0 * lon.cos(), 7000.0 * lon.sin(), 0.0];
+        let g = look_geometry(&point, &enu, &spacecraft, &SUN_OVERHEAD);
+        assert!((g.azimuth - 90.0).abs() < 1e-6);
+        assert!(g.off_nadir > 5.0 && g.off_nadir < 90.0);
+        // Angles of the centre/point/spacecraft triangle: 5° at the centre,
+        // 90° + elevation at the point and the off-nadir angle at the spacecraft
+        assert!((g.off_nadir + g.elevation - 85.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_opportunity_peak() {
+        use chrono::{Duration, TimeZone};
+        let start = Utc.with_ymd_and_hms(2024, 1, 1, 0, 0, 0).unwrap();
+        let times: Vec<DateTime<Utc>> = (0..5).map(|i| start + Duration::seconds(10 * i)).collect();
+        let geometry: Vec<LookGeometry> = [30.0, 10.0, 5.0, 12.0, 40.0]
+            .iter()
+            .map(|&off_nadir| LookGeometry {
+                off_nadir,
+                azimuth: 0.0,
+                elevation: 30.0,
+                range: 1000.0,
+                altitude: 500.0,
+                sun_elevation: 45.0,
+            })
+            .collect();
+        let opp = opportunity(&times, &geometry, (1, 3));
+        assert_eq!(opp.peak, times[2]);
+        assert_eq!(opp.off_nadir_deg, 5.0);
+        assert_eq!(opp.duration_seconds, 20.0);
+        assert!((opp.gsd_factor_across - 2.0).abs() < 1e-12);
+        assert!((opp.gsd_factor_along - 4.0).abs() < 1e-12);
+    }
+}
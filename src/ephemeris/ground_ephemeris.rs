@@ -3,9 +3,23 @@ use numpy::IntoPyArray;
 use pyo3::{prelude::*, types::PyDateTime};
 use std::sync::OnceLock;
 
-use crate::ephemeris::ephemeris_common::{generate_timestamps, EphemerisBase, EphemerisData};
+use crate::ephemeris::ephemeris_common::{
+    generate_timestamps, parse_time_scale, EphemerisBase, EphemerisData,
+};
+use crate::ephemeris::observation_geometry::{observation_geometry, ObservationGeometry};
 use crate::ephemeris::position_velocity::PositionVelocityData;
+use crate::ephemeris::provenance::{ephemeris_provenance, Provenance};
+use crate::ephemeris::sample::{
+    ephemeris_getitem, ephemeris_len, EphemerisIterator, EphemerisSample,
+};
+use crate::ephemeris::solar_eclipse::{self, SolarEclipse};
+use crate::ephemeris::{
+    ephemeris_eq, ephemeris_hash, ephemeris_metadata, ephemeris_repr, ephemeris_summary,
+};
 use crate::utils::conversions::{self, Frame};
+use crate::utils::geo::{ecef_to_geodetic_deg, geodetic_to_ecef_km};
+use crate::utils::horizon::HorizonMask;
+use crate::utils::sites;
 use crate::utils::to_skycoord::AstropyModules;
 
 /// Ground-based observatory ephemeris
@@ -17,7 +31,9 @@ pub struct GroundEphemeris {
     height: f64,    // meters above WGS84 ellipsoid
     itrs: Option<Array2<f64>>,
     itrs_skycoord: OnceLock<Py<PyAny>>,
-    polar_motion: bool, // Whether to apply polar motion correction
+    polar_motion: bool,           // Whether to apply polar motion correction
+    horizon: Option<HorizonMask>, // Local horizon profile (flat 0° if None)
+    site: Option<String>,         // Registry site name, if constructed from a site
     // Common ephemeris data
     common_data: EphemerisData,
 }
@@ -26,6 +42,10 @@ pub struct GroundEphemeris {
 impl GroundEphemeris {
     /// Create a new GroundEphemeris for a ground-based observatory
     ///
+    /// The location is given as `latitude`/`longitude`/`height`, as a `site`
+    /// name from the site registry, as an astropy `EarthLocation`, or as
+    /// geocentric (ITRS) coordinates.
+    ///
     /// # Arguments
     /// * `latitude` - Geodetic latitude in degrees (-90 to 90)
     /// * `longitude` - Geodetic longitude in degrees (-180 to 180)
@@ -34,19 +54,81 @@ impl GroundEphemeris {
     /// * `end` - End time (Python datetime)
     /// * `step_size` - Time step in seconds
     /// * `polar_motion` - Whether to apply polar motion correction (default: false)
+    /// * `time_scale` - Scale in which the grid is uniform: "UTC", "TAI" or "TT"
+    ///   (default: "UTC")
+    /// * `horizon` - Optional local horizon profile as (azimuth_deg, min_elevation_deg)
+    ///   pairs, interpolated linearly in azimuth (default: flat 0° horizon)
+    /// * `site` - Observatory or ground-station name, alias or IAU code (e.g. "Paranal",
+    ///   "568", "DSS-43") used instead of latitude/longitude/height
+    /// * `location` - Scalar astropy `EarthLocation` used instead of latitude/longitude/height
+    /// * `geocentric` - Geocentric (x, y, z) ITRS coordinates in meters used instead of
+    ///   latitude/longitude/height
+    ///
+    /// `location` and `geocentric` are converted to geodetic coordinates on the
+    /// WGS84 ellipsoid, whatever ellipsoid an `EarthLocation` was created with.
     #[new]
-    #[pyo3(signature = (latitude, longitude, height, begin, end, step_size=60, *, polar_motion=false))]
+    #[pyo3(signature = (latitude=None, longitude=None, height=None, begin=None, end=None, step_size=60, *, polar_motion=false, time_scale="UTC", horizon=None, site=None, location=None, geocentric=None))]
     #[allow(clippy::too_many_arguments)]
     fn new(
         _py: Python,
-        latitude: f64,
-        longitude: f64,
-        height: f64,
-        begin: &Bound<'_, PyDateTime>,
-        end: &Bound<'_, PyDateTime>,
+        latitude: Option<f64>,
+        longitude: Option<f64>,
+        height: Option<f64>,
+        begin: Option<&Bound<'_, PyDateTime>>,
+        end: Option<&Bound<'_, PyDateTime>>,
         step_size: i64,
         polar_motion: bool,
+        time_scale: &str,
+        horizon: Option<Vec<(f64, f64)>>,
+        site: Option<String>,
+        location: Option<&Bound<'_, PyAny>>,
+        geocentric: Option<(f64, f64, f64)>,
     ) -> PyResult<Self> {
+        let time_scale = parse_time_scale(time_scale)?;
+        let has_coordinates = latitude.is_some() || longitude.is_some() || height.is_some();
+        let n_sources = [
+            has_coordinates,
+            site.is_some(),
+            location.is_some(),
+            geocentric.is_some(),
+        ]
+        .iter()
+        .filter(|&&given| given)
+        .count();
+        if n_sources > 1 {
+            return Err(pyo3::exceptions::PyValueError::new_err(
+                "Specify only one of latitude/longitude/height, site, location or geocentric",
+            ));
+        }
+        let geocentric = match location {
+            Some(location) => Some(earth_location_geocentric_m(location)?),
+            None => geocentric,
+        };
+        let (latitude, longitude, height, site) = match (site, geocentric) {
+            (Some(name), _) => {
+                let site =
+                    sites::resolve_site(&name).map_err(pyo3::exceptions::PyValueError::new_err)?;
+                (site.latitude, site.longitude, site.height, Some(site.name))
+            }
+            (_, Some((x, y, z))) => {
+                let (lat, lon, h) = geocentric_m_to_geodetic(x, y, z)?;
+                (lat, lon, h, None)
+            }
+            (None, None) => match (latitude, longitude, height) {
+                (Some(lat), Some(lon), Some(h)) => (lat, lon, h, None),
+                _ => {
+                    return Err(pyo3::exceptions::PyValueError::new_err(
+                        "latitude, longitude and height are required unless site, location or geocentric is given",
+                    ))
+                }
+            },
+        };
+        let begin = begin.ok_or_else(|| {
+            pyo3::exceptions::PyValueError::new_err("begin parameter is required")
+        })?;
+        let end = end
+            .ok_or_else(|| pyo3::exceptions::PyValueError::new_err("end parameter is required"))?;
+
         // Validate latitude and longitude
         if !(-90.0..=90.0).contains(&latitude) {
             return Err(pyo3::exceptions::PyValueError::new_err(
@@ -59,8 +141,13 @@ impl GroundEphemeris {
             ));
         }
 
+        let horizon = horizon
+            .map(|points| HorizonMask::new(&points))
+            .transpose()
+            .map_err(pyo3::exceptions::PyValueError::new_err)?;
+
         // Use common timestamp generation logic
-        let times = generate_timestamps(begin, end, step_size)?;
+        let times = generate_timestamps(begin, end, step_size, time_scale)?;
 
         // Create the GroundEphemeris object
         let mut ephemeris = GroundEphemeris {
@@ -70,9 +157,12 @@ impl GroundEphemeris {
             itrs: None,
             itrs_skycoord: OnceLock::new(),
             polar_motion,
+            horizon,
+            site,
             common_data: {
                 let mut data = EphemerisData::new();
                 data.times = Some(times);
+                data.time_scale = time_scale;
                 data
             },
         };
@@ -122,21 +212,102 @@ impl GroundEphemeris {
     /// Convert RA/Dec to Altitude/Azimuth for this ground site
     ///
     /// Returns a NumPy array with shape (N, 2) of [altitude_deg, azimuth_deg]
-    #[pyo3(signature = (ra_deg, dec_deg, time_indices=None))]
+    #[pyo3(signature = (ra_deg, dec_deg, time_indices=None, *, polar_motion=None))]
     fn radec_to_altaz(
         &self,
         py: Python,
         ra_deg: f64,
         dec_deg: f64,
         time_indices: Option<Vec<usize>>,
+        polar_motion: Option<bool>,
     ) -> PyResult<Py<PyAny>> {
-        let result = <Self as crate::ephemeris::ephemeris_common::EphemerisBase>::radec_to_altaz(
-            self,
+        let arr = crate::utils::celestial::radec_to_altaz(
             ra_deg,
             dec_deg,
+            self,
+            time_indices.as_deref(),
+            polar_motion,
+        );
+        Ok(arr.into_pyarray(py).into())
+    }
+
+    /// Convert many RA/Dec targets to Altitude/Azimuth in one call
+    /// Returns NumPy array (n_targets, N, 2): [altitude_deg, azimuth_deg]
+    #[pyo3(signature = (ra_deg, dec_deg, time_indices=None, *, polar_motion=None))]
+    fn radec_to_altaz_batch(
+        &self,
+        py: Python,
+        ra_deg: Vec<f64>,
+        dec_deg: Vec<f64>,
+        time_indices: Option<Vec<usize>>,
+        polar_motion: Option<bool>,
+    ) -> PyResult<Py<PyAny>> {
+        if ra_deg.len() != dec_deg.len() {
+            return Err(pyo3::exceptions::PyValueError::new_err(
+                "ra_deg and dec_deg must have the same length",
+            ));
+        }
+        let arr = crate::utils::celestial::radec_to_altaz_batch(
+            &ra_deg,
+            &dec_deg,
+            self,
             time_indices.as_deref(),
+            polar_motion,
         );
-        Ok(result.into_pyarray(py).into())
+        Ok(arr.into_pyarray(py).into())
+    }
+
+    /// Get the registry site name, or None if constructed from coordinates
+    #[getter]
+    fn site(&self) -> Option<&str> {
+        self.site.as_deref()
+    }
+
+    /// Get the site location as an astropy EarthLocation
+    #[getter]
+    fn earth_location(&self, py: Python) -> PyResult<Py<PyAny>> {
+        let [x, y, z] = geodetic_to_ecef_km(self.latitude, self.longitude, self.height);
+        let kwargs = pyo3::types::PyDict::new(py);
+        kwargs.set_item("unit", "km")?;
+        let location = py
+            .import("astropy.coordinates")?
+            .getattr("EarthLocation")?
+            .call_method("from_geocentric", (x, y, z), Some(&kwargs))?;
+        Ok(location.unbind())
+    }
+
+    /// Get the horizon profile as (azimuth_deg, min_elevation_deg) pairs sorted by azimuth
+    ///
+    /// Returns None when the site uses a flat 0° horizon.
+    #[getter]
+    fn horizon(&self) -> Option<Vec<(f64, f64)>> {
+        self.horizon.as_ref().map(|mask| mask.points().to_vec())
+    }
+
+    /// Minimum visible elevation in degrees towards the given azimuth
+    ///
+    /// Interpolates the horizon profile; 0.0 when no profile was supplied.
+    fn horizon_elevation(&self, azimuth_deg: f64) -> f64 {
+        self.horizon
+            .as_ref()
+            .map_or(0.0, |mask| mask.min_elevation(azimuth_deg))
+    }
+
+    /// Whether a target at given RA/Dec is above the local horizon at each time
+    #[pyo3(signature = (ra_deg, dec_deg, time_indices=None))]
+    fn is_above_horizon(
+        &self,
+        ra_deg: f64,
+        dec_deg: f64,
+        time_indices: Option<Vec<usize>>,
+    ) -> Vec<bool> {
+        let altaz =
+            <Self as EphemerisBase>::radec_to_altaz(self, ra_deg, dec_deg, time_indices.as_deref());
+        altaz
+            .rows()
+            .into_iter()
+            .map(|row| row[0] >= self.horizon_elevation(row[1]))
+            .collect()
     }
 
     /// Calculate airmass for a target at given RA/Dec
@@ -170,6 +341,31 @@ impl GroundEphemeris {
         )
     }
 
+    /// Calculate airmass for many targets in one call
+    ///
+    /// Uses the vectorized Kasten pipeline of the airmass constraint, without
+    /// refraction or aberration, so there are no per-target Python calls.
+    ///
+    /// # Returns
+    /// NumPy array (n_targets, N) of airmass values, infinity below the horizon
+    #[pyo3(signature = (ras, decs, time_indices=None))]
+    fn airmass_batch(
+        &self,
+        py: Python,
+        ras: Vec<f64>,
+        decs: Vec<f64>,
+        time_indices: Option<Vec<usize>>,
+    ) -> PyResult<Py<PyAny>> {
+        let arr =
+            <Self as crate::ephemeris::ephemeris_common::EphemerisBase>::calculate_airmass_batch(
+                self,
+                &ras,
+                &decs,
+                time_indices.as_deref(),
+            )?;
+        Ok(arr.into_pyarray(py).into())
+    }
+
     /// Get the input height in meters (constructor argument)
     #[getter]
     fn input_height(&self) -> f64 {
@@ -182,6 +378,12 @@ impl GroundEphemeris {
         self.polar_motion
     }
 
+    /// Get the time scale in which the timestamp grid is uniform
+    #[getter]
+    fn time_scale(&self) -> &'static str {
+        self.common_data.time_scale.name()
+    }
+
     // ===== Common ephemeris getters (delegating to EphemerisBase trait) =====
 
     #[getter]
@@ -204,6 +406,18 @@ impl GroundEphemeris {
         self.get_gcrs_pv(py)
     }
 
+    /// Observer position/velocity in TEME, converted from GCRS
+    #[getter]
+    fn teme_pv(&self, py: Python) -> Option<Py<PositionVelocityData>> {
+        self.get_teme_pv(py)
+    }
+
+    /// Observer position/velocity in the pseudo-Earth-fixed frame (TEME rotated by GMST)
+    #[getter]
+    fn pef_pv(&self, py: Python) -> Option<Py<PositionVelocityData>> {
+        self.get_pef_pv(py)
+    }
+
     #[getter]
     fn itrs_pv(&self, py: Python) -> Option<Py<PositionVelocityData>> {
         self.get_itrs_pv(py)
@@ -311,6 +525,21 @@ impl GroundEphemeris {
         Ok(Some(arr.into_pyarray(py).to_owned().into()))
     }
 
+    #[getter]
+    fn geomagnetic_latitude_deg(&self, py: Python) -> PyResult<Option<Py<PyAny>>> {
+        self.get_geomagnetic_latitude_deg(py)
+    }
+
+    #[getter]
+    fn geomagnetic_longitude_deg(&self, py: Python) -> PyResult<Option<Py<PyAny>>> {
+        self.get_geomagnetic_longitude_deg(py)
+    }
+
+    #[getter]
+    fn cutoff_rigidity_gv(&self, py: Python) -> PyResult<Option<Py<PyAny>>> {
+        self.get_cutoff_rigidity_gv(py)
+    }
+
     #[getter]
     fn sun_radius(&self, py: Python) -> PyResult<Py<PyAny>> {
         self.get_sun_radius(py)
@@ -446,6 +675,75 @@ impl GroundEphemeris {
         self.get_earth_dec_rad(py)
     }
 
+    /// Fraction of the solar disk visible from the observer (1.0 sunlit, 0.0 umbra)
+    #[getter]
+    fn illumination_factor(&self, py: Python) -> PyResult<Py<PyAny>> {
+        let factors = EphemerisBase::illumination_factor(self)?;
+        Ok(factors.into_pyarray(py).into())
+    }
+
+    /// Geodetic latitude and longitude rates (deg/s) and height rate (km/s), shape (N, 3)
+    #[getter]
+    fn geodetic_rates(&self, py: Python) -> PyResult<Option<Py<PyAny>>> {
+        Ok(EphemerisBase::geodetic_rates(self)?.map(|rates| rates.into_pyarray(py).into()))
+    }
+
+    /// Speed of the sub-observer point over the WGS84 ellipsoid (km/s)
+    #[getter]
+    fn ground_speed(&self, py: Python) -> PyResult<Option<Py<PyAny>>> {
+        Ok(EphemerisBase::ground_speed(self)?.map(|speed| speed.into_pyarray(py).into()))
+    }
+
+    /// Geodetic (latitude, longitude) in degrees below the observer, shape (N, 2)
+    #[getter]
+    fn sub_satellite_point(&self, py: Python) -> PyResult<Option<Py<PyAny>>> {
+        Ok(EphemerisBase::sub_satellite_point(self)?.map(|points| points.into_pyarray(py).into()))
+    }
+
+    /// (latitude, longitude) in degrees with the Sun at the zenith, shape (N, 2)
+    #[getter]
+    fn sub_solar_point(&self, py: Python) -> PyResult<Py<PyAny>> {
+        Ok(EphemerisBase::sub_solar_point(self)?
+            .into_pyarray(py)
+            .into())
+    }
+
+    /// Solar zenith angle at the sub-satellite point in degrees (daylit below 90)
+    #[getter]
+    fn nadir_solar_zenith_angle(&self, py: Python) -> PyResult<Option<Py<PyAny>>> {
+        Ok(EphemerisBase::nadir_solar_zenith_angle(self)?
+            .map(|angles| angles.into_pyarray(py).into()))
+    }
+
+    /// Fraction of the solar disk area hidden by the Moon at each time step
+    ///
+    /// Geometric: nonzero during an eclipse even while the Sun is below the
+    /// horizon.
+    #[getter]
+    fn solar_obscuration(&self, py: Python) -> PyResult<Py<PyAny>> {
+        Ok(solar_eclipse::solar_obscuration(self)?
+            .into_pyarray(py)
+            .into())
+    }
+
+    /// Solar eclipses (Moon over the Sun) seen from the site over the span
+    ///
+    /// # Arguments
+    /// * `min_obscuration` - Drop eclipses whose largest obscuration is below this fraction
+    /// * `include_below_horizon` - Also count samples with the Sun below the horizon
+    ///
+    /// # Returns
+    /// `SolarEclipse` objects in time order, each classified as "partial",
+    /// "annular" or "total" by the most central phase it reaches
+    #[pyo3(signature = (min_obscuration=0.0, *, include_below_horizon=false))]
+    fn solar_eclipses(
+        &self,
+        min_obscuration: f64,
+        include_below_horizon: bool,
+    ) -> PyResult<Vec<SolarEclipse>> {
+        solar_eclipse::solar_eclipses(self, min_obscuration, include_below_horizon)
+    }
+
     /// Calculate Moon illumination fraction for all ephemeris times
     ///
     /// Returns the fraction of the Moon's illuminated surface as seen from the
@@ -465,24 +763,175 @@ impl GroundEphemeris {
         self.find_closest_index(time)
     }
 
-    #[pyo3(signature = (body, spice_kernel=None, use_horizons=false))]
+    /// Stable hex digest of the source, time grid, options and computed states
+    ///
+    /// Ephemerides with equal fingerprints compare equal, so the fingerprint
+    /// can key caches of results computed from this ephemeris.
+    fn fingerprint(&self) -> String {
+        EphemerisBase::fingerprint(self)
+    }
+
+    fn __eq__(&self, other: &Bound<'_, PyAny>) -> bool {
+        ephemeris_eq(self, other)
+    }
+
+    fn __hash__(&self) -> u64 {
+        ephemeris_hash(self)
+    }
+
+    /// Source, time span, computed frames and Earth orientation state
+    ///
+    /// Values are JSON-serializable (times as ISO 8601 strings), so the dict
+    /// can be logged as provenance alongside derived results.
+    #[getter]
+    fn metadata(&self, py: Python) -> PyResult<Py<PyAny>> {
+        ephemeris_metadata(py, self)
+    }
+
+    /// Reproducibility record: library version, source and fingerprint,
+    /// element set, input and kernel file digests, Earth orientation and
+    /// leap-second data
+    #[getter]
+    fn provenance(&self) -> Provenance {
+        ephemeris_provenance(self)
+    }
+
+    /// Human-readable, multi-line description of `metadata`
+    fn summary(&self) -> String {
+        ephemeris_summary(self)
+    }
+
+    fn __repr__(&self) -> String {
+        ephemeris_repr(self)
+    }
+
+    fn __len__(&self) -> usize {
+        ephemeris_len(self)
+    }
+
+    /// State at time step `index`; negative indices count from the end
+    fn __getitem__(&self, index: isize) -> PyResult<EphemerisSample> {
+        ephemeris_getitem(self, index)
+    }
+
+    fn __iter__(slf: Bound<'_, Self>) -> EphemerisIterator {
+        EphemerisIterator::new(slf.into_any().unbind())
+    }
+
+    #[pyo3(signature = (body, spice_kernel=None, use_horizons=false, source=None))]
     fn get_body_pv(
         &self,
         py: Python,
         body: &str,
         spice_kernel: Option<String>,
         use_horizons: bool,
+        source: Option<String>,
     ) -> PyResult<Py<PositionVelocityData>> {
-        <Self as EphemerisBase>::get_body_pv(self, py, body, spice_kernel.as_deref(), use_horizons)
+        <Self as EphemerisBase>::get_body_pv(
+            self,
+            py,
+            body,
+            spice_kernel.as_deref(),
+            use_horizons,
+            source.as_deref(),
+        )
+    }
+
+    #[pyo3(signature = (bodies, spice_kernel=None, use_horizons=false, source=None))]
+    fn get_bodies(
+        &self,
+        py: Python,
+        bodies: Vec<String>,
+        spice_kernel: Option<String>,
+        use_horizons: bool,
+        source: Option<String>,
+    ) -> PyResult<Py<pyo3::types::PyDict>> {
+        <Self as EphemerisBase>::get_bodies(
+            self,
+            py,
+            &bodies,
+            spice_kernel.as_deref(),
+            use_horizons,
+            source.as_deref(),
+        )
     }
 
-    #[pyo3(signature = (body, spice_kernel=None, use_horizons=false))]
+    /// Angular separation in degrees between two targets at each time step
+    ///
+    /// Each of `a` and `b` may be an `(ra_deg, dec_deg)` pair, a body name,
+    /// observer-relative `PositionVelocityData` or a SkyCoord.
+    fn separation(
+        &self,
+        py: Python,
+        a: &Bound<'_, PyAny>,
+        b: &Bound<'_, PyAny>,
+    ) -> PyResult<Py<PyAny>> {
+        let angles = <Self as EphemerisBase>::separation(self, py, a, b)?;
+        Ok(angles.into_pyarray(py).into())
+    }
+
+    /// Angle in degrees between a target and the Sun at each time step
+    #[pyo3(signature = (ra_deg, dec_deg, time_indices=None))]
+    fn sun_angle(
+        &self,
+        py: Python,
+        ra_deg: f64,
+        dec_deg: f64,
+        time_indices: Option<Vec<usize>>,
+    ) -> PyResult<Py<PyAny>> {
+        let angles = EphemerisBase::sun_angle(self, ra_deg, dec_deg, time_indices.as_deref())?;
+        Ok(angles.into_pyarray(py).into())
+    }
+
+    /// Angle in degrees between a target and the Moon at each time step
+    #[pyo3(signature = (ra_deg, dec_deg, time_indices=None))]
+    fn moon_angle(
+        &self,
+        py: Python,
+        ra_deg: f64,
+        dec_deg: f64,
+        time_indices: Option<Vec<usize>>,
+    ) -> PyResult<Py<PyAny>> {
+        let angles = EphemerisBase::moon_angle(self, ra_deg, dec_deg, time_indices.as_deref())?;
+        Ok(angles.into_pyarray(py).into())
+    }
+
+    /// Angle in degrees of a target above the Earth's limb (negative behind it)
+    #[pyo3(signature = (ra_deg, dec_deg, time_indices=None))]
+    fn earth_limb_angle(
+        &self,
+        py: Python,
+        ra_deg: f64,
+        dec_deg: f64,
+        time_indices: Option<Vec<usize>>,
+    ) -> PyResult<Py<PyAny>> {
+        let angles =
+            EphemerisBase::earth_limb_angle(self, ra_deg, dec_deg, time_indices.as_deref())?;
+        Ok(angles.into_pyarray(py).into())
+    }
+
+    /// Sun, Moon and Earth-limb angles, altitude, eclipse, SAA, orbital phase
+    /// and beta angle for a target at the ephemeris step nearest `time`
+    #[pyo3(signature = (ra_deg, dec_deg, time, *, saa_polygon=None))]
+    fn geometry(
+        &self,
+        ra_deg: f64,
+        dec_deg: f64,
+        time: &Bound<'_, PyDateTime>,
+        saa_polygon: Option<Vec<(f64, f64)>>,
+    ) -> PyResult<ObservationGeometry> {
+        let index = self.find_closest_index(time)?;
+        observation_geometry(self, ra_deg, dec_deg, index, saa_polygon.as_deref())
+    }
+
+    #[pyo3(signature = (body, spice_kernel=None, use_horizons=false, source=None))]
     fn get_body(
         &self,
         py: Python,
         body: &str,
         spice_kernel: Option<String>,
         use_horizons: bool,
+        source: Option<String>,
     ) -> PyResult<Py<PyAny>> {
         let modules = AstropyModules::import(py)?;
         <Self as EphemerisBase>::get_body(
@@ -492,11 +941,22 @@ impl GroundEphemeris {
             body,
             spice_kernel.as_deref(),
             use_horizons,
+            source.as_deref(),
         )
     }
 }
 
 impl GroundEphemeris {
+    /// Geodetic location as (latitude_deg, longitude_deg, height_m)
+    pub(crate) fn location(&self) -> (f64, f64, f64) {
+        (self.latitude, self.longitude, self.height)
+    }
+
+    /// Registry site name, if constructed from a site
+    pub(crate) fn site_name(&self) -> Option<&str> {
+        self.site.as_deref()
+    }
+
     /// Compute ITRS position and velocity for the ground station
     /// Position is computed from geodetic coordinates (lat, lon, alt)
     /// Velocity is zero in ITRS for a fixed site; Earth's rotation is handled
@@ -510,27 +970,8 @@ impl GroundEphemeris {
 
         let n_times = times.len();
 
-        // Convert geodetic coordinates to ITRS Cartesian coordinates
-        // Using WGS84 ellipsoid parameters
-        let lat_rad = self.latitude.to_radians();
-        let lon_rad = self.longitude.to_radians();
-
-        // WGS84 parameters
-        const A: f64 = 6378.137; // Semi-major axis in km
-        const F: f64 = 1.0 / 298.257223563; // Flattening
-        const E_SQ: f64 = 2.0 * F - F * F; // First eccentricity squared
-
-        // Radius of curvature in the prime vertical
-        let sin_lat = lat_rad.sin();
-        let n = A / (1.0 - E_SQ * sin_lat * sin_lat).sqrt();
-
-        // Convert height from meters to km
-        let h_km = self.height / 1000.0;
-
-        // ITRS Cartesian coordinates (km)
-        let x = (n + h_km) * lat_rad.cos() * lon_rad.cos();
-        let y = (n + h_km) * lat_rad.cos() * lon_rad.sin();
-        let z = (n * (1.0 - E_SQ) + h_km) * lat_rad.sin();
+        // Convert geodetic coordinates to ITRS Cartesian coordinates (km, WGS84)
+        let [x, y, z] = geodetic_to_ecef_km(self.latitude, self.longitude, self.height);
 
         // Velocity in the rotating ITRS frame for a fixed site is zero.
         let vx = 0.0;
@@ -581,6 +1022,35 @@ impl GroundEphemeris {
     }
 }
 
+/// Geocentric (x, y, z) in meters of a scalar astropy EarthLocation
+fn earth_location_geocentric_m(location: &Bound<'_, PyAny>) -> PyResult<(f64, f64, f64)> {
+    let component = |axis: &str| -> PyResult<f64> {
+        location
+            .getattr(axis)
+            .and_then(|q| q.call_method1("to_value", ("m",)))
+            .and_then(|v| v.extract::<f64>())
+            .map_err(|_| {
+                pyo3::exceptions::PyTypeError::new_err(
+                    "location must be a scalar astropy EarthLocation",
+                )
+            })
+    };
+    Ok((component("x")?, component("y")?, component("z")?))
+}
+
+/// WGS84 geodetic (latitude_deg, longitude_deg, height_m) of geocentric coordinates in meters
+fn geocentric_m_to_geodetic(x: f64, y: f64, z: f64) -> PyResult<(f64, f64, f64)> {
+    if ![x, y, z].iter().all(|v| v.is_finite()) || (x == 0.0 && y == 0.0 && z == 0.0) {
+        return Err(pyo3::exceptions::PyValueError::new_err(
+            "geocentric coordinates must be finite and not at the geocenter",
+        ));
+    }
+    let ecef_km = Array2::from_shape_vec((1, 3), vec![x / 1000.0, y / 1000.0, z / 1000.0])
+        .expect("1x3 shape matches 3 elements");
+    let (lats, lons, heights_km) = ecef_to_geodetic_deg(&ecef_km);
+    Ok((lats[0], lons[0], heights_km[0] * 1000.0))
+}
+
 // Implement the EphemerisBase trait for GroundEphemeris
 impl EphemerisBase for GroundEphemeris {
     fn data(&self) -> &EphemerisData {
@@ -603,12 +1073,43 @@ impl EphemerisBase for GroundEphemeris {
         self.itrs_skycoord.set(skycoord)
     }
 
+    fn source_identity(&self) -> String {
+        format!(
+            "GroundEphemeris\nlatitude={:?}\nlongitude={:?}\nheight={:?}\nsite={:?}\nhorizon={:?}\npolar_motion={}",
+            self.latitude, self.longitude, self.height, self.site, self.horizon, self.polar_motion
+        )
+    }
+
+    fn source_metadata(&self) -> Vec<(&'static str, serde_json::Value)> {
+        vec![
+            ("type", "GroundEphemeris".into()),
+            ("site", self.site.clone().into()),
+            ("latitude", self.latitude.into()),
+            ("longitude", self.longitude.into()),
+            ("height", self.height.into()),
+            ("horizon", self.horizon.is_some().into()),
+            ("polar_motion", self.polar_motion.into()),
+        ]
+    }
+
+    fn applies_polar_motion(&self) -> bool {
+        self.polar_motion
+    }
+
     fn radec_to_altaz(
         &self,
         ra_deg: f64,
         dec_deg: f64,
         time_indices: Option<&[usize]>,
     ) -> Array2<f64> {
-        crate::utils::celestial::radec_to_altaz(ra_deg, dec_deg, self, time_indices)
+        crate::utils::celestial::radec_to_altaz(ra_deg, dec_deg, self, time_indices, None)
+    }
+
+    fn horizon_mask(&self) -> Option<&HorizonMask> {
+        self.horizon.as_ref()
+    }
+
+    fn is_ground_site(&self) -> bool {
+        true
     }
 }
@@ -0,0 +1,579 @@
+//! Ground-station network contact analysis
+//!
+//! A [`GroundNetwork`] groups several ground sites and computes, for a
+//! spacecraft ephemeris, when each station can see the spacecraft and how the
+//! network covers the ephemeris span as a whole: combined contact windows,
+//! coverage gaps and the handovers between stations.
+//!
+//! A station is in contact when the spacecraft's topocentric elevation is at
+//! least the network's `min_elevation` and above the station's local horizon
+//! profile, if it has one. Contact and gap boundaries are reported at the
+//! ephemeris sample times, so their resolution is the ephemeris step size.
+
+use chrono::{DateTime, Utc};
+use ndarray::{Array1, Array2};
+use numpy::IntoPyArray;
+use pyo3::prelude::*;
+
+use crate::constraints::VisibilityWindow;
+use crate::ephemeris::ephemeris_common::EphemerisBase;
+use crate::ephemeris::{with_ephemeris, GroundEphemeris};
+use crate::utils::geo::{enu_basis, geodetic_to_ecef_km};
+use crate::utils::horizon::HorizonMask;
+use crate::utils::sites;
+use crate::utils::time_utils::utc_to_python_datetime;
+
+/// A single ground station
+#[derive(Clone)]
+pub(crate) struct Station {
+    pub(crate) name: String,
+    latitude: f64,
+    longitude: f64,
+    height: f64,
+    pub(crate) horizon: Option<HorizonMask>,
+}
+
+impl Station {
+    /// Topocentric (elevation_deg, azimuth_deg, range_km) of ITRS positions (km, Nx3)
+    pub(crate) fn topocentric(&self, positions: &Array2<f64>) -> Vec<(f64, f64, f64)> {
+        let origin = geodetic_to_ecef_km(self.latitude, self.longitude, self.height);
+        let [east, north, up] = enu_basis(self.latitude, self.longitude);
+        positions
+            .rows()
+            .into_iter()
+            .map(|row| {
+                let d = [row[0] - origin[0], row[1] - origin[1], row[2] - origin[2]];
+                let dot = |u: &[f64; 3]| d[0] * u[0] + d[1] * u[1] + d[2] * u[2];
+                let range = dot(&d).sqrt();
+                let elevation = (dot(&up) / range).clamp(-1.0, 1.0).asin();
+                let azimuth = dot(&east).atan2(dot(&north)).to_degrees().rem_euclid(360.0);
+                (elevation.to_degrees(), azimuth, range)
+            })
+            .collect()
+    }
+
+    /// Topocentric (elevation_deg, azimuth_deg) of ITRS positions (km, Nx3)
+    fn look_angles(&self, positions: &Array2<f64>) -> Vec<(f64, f64)> {
+        self.topocentric(positions)
+            .into_iter()
+            .map(|(el, az, _)| (el, az))
+            .collect()
+    }
+}
+
+/// Build a station from a GroundEphemeris, a site name or a (name, lat, lon, height) tuple
+pub(crate) fn extract_station(obj: &Bound<'_, PyAny>) -> PyResult<Station> {
+    if let Ok(ground) = obj.extract::<PyRef<GroundEphemeris>>() {
+        let (latitude, longitude, height) = ground.location();
+        let name = ground
+            .site_name()
+            .map(str::to_string)
+            .unwrap_or_else(|| format!("({:.4}, {:.4})", latitude, longitude));
+        return Ok(Station {
+            name,
+            latitude,
+            longitude,
+            height,
+            horizon: ground.horizon_mask().cloned(),
+        });
+    }
+    if let Ok(name) = obj.extract::<String>() {
+        let site = sites::resolve_site(&name).map_err(pyo3::exceptions::PyValueError::new_err)?;
+        return Ok(Station {
+            name: site.name,
+            latitude: site.latitude,
+            longitude: site.longitude,
+            height: site.height,
+            horizon: None,
+        });
+    }
+    if let Ok((name, latitude, longitude, height)) = obj.extract::<(String, f64, f64, f64)>() {
+        if !(-90.0..=90.0).contains(&latitude) {
+            return Err(pyo3::exceptions::PyValueError::new_err(
+                "latitude must be between -90 and 90 degrees",
+            ));
+        }
+        if !(-180.0..=180.0).contains(&longitude) {
+            return Err(pyo3::exceptions::PyValueError::new_err(
+                "longitude must be between -180 and 180 degrees",
+            ));
+        }
+        return Ok(Station {
+            name,
+            latitude,
+            longitude,
+            height,
+            horizon: None,
+        });
+    }
+    Err(pyo3::exceptions::PyTypeError::new_err(
+        "Stations must be GroundEphemeris objects, site names, or (name, latitude, longitude, height) tuples",
+    ))
+}
+
+/// Inclusive index ranges of consecutive `true` samples
+pub(crate) fn runs(mask: &[bool]) -> Vec<(usize, usize)> {
+    let mut runs = Vec::new();
+    let mut start: Option<usize> = None;
+    for (i, &value) in mask.iter().enumerate() {
+        match (value, start) {
+            (true, None) => start = Some(i),
+            (false, Some(s)) => {
+                runs.push((s, i - 1));
+                start = None;
+            }
+            _ => {}
+        }
+    }
+    if let Some(s) = start {
+        runs.push((s, mask.len() - 1));
+    }
+    runs
+}
+
+/// Index ranges not covered by `contacts`, bounded by the neighbouring contact samples
+///
+/// A gap runs from the last sample of one contact to the first sample of the
+/// next, so gap durations measure the time without any station in contact.
+/// Leading and trailing gaps start and end at the ephemeris bounds.
+fn gaps(contacts: &[(usize, usize)], n: usize) -> Vec<(usize, usize)> {
+    if n == 0 {
+        return Vec::new();
+    }
+    if contacts.is_empty() {
+        return vec![(0, n - 1)];
+    }
+    let mut gaps = Vec::new();
+    if contacts[0].0 > 0 {
+        gaps.push((0, contacts[0].0));
+    }
+    for pair in contacts.windows(2) {
+        gaps.push((pair[0].1, pair[1].0));
+    }
+    let last = contacts[contacts.len() - 1].1;
+    if last < n - 1 {
+        gaps.push((last, n - 1));
+    }
+    gaps
+}
+
+pub(crate) fn seconds_between(times: &[DateTime<Utc>], (start, end): (usize, usize)) -> f64 {
+    (times[end] - times[start]).num_milliseconds() as f64 / 1000.0
+}
+
+pub(crate) fn to_windows(
+    py: Python,
+    times: &[DateTime<Utc>],
+    ranges: &[(usize, usize)],
+) -> PyResult<Vec<VisibilityWindow>> {
+    ranges
+        .iter()
+        .map(|&(start, end)| {
+            Ok(VisibilityWindow {
+                start_time: utc_to_python_datetime(py, &times[start])?,
+                end_time: utc_to_python_datetime(py, &times[end])?,
+            })
+        })
+        .collect()
+}
+
+pub(crate) fn timestamp_array(py: Python, times: &[DateTime<Utc>]) -> PyResult<Py<PyAny>> {
+    let np = pyo3::types::PyModule::import(py, "numpy")
+        .map_err(|_| pyo3::exceptions::PyImportError::new_err("numpy is required"))?;
+    let py_list = pyo3::types::PyList::empty(py);
+    for dt in times {
+        py_list.append(utc_to_python_datetime(py, dt)?)?;
+    }
+    Ok(np.getattr("array")?.call1((py_list,))?.into())
+}
+
+/// Spacecraft ITRS positions (km) and times from any supported ephemeris
+///
+/// `polar_motion` overrides the ephemeris's own setting when given.
+pub(crate) fn spacecraft_itrs(
+    ephem: &dyn EphemerisBase,
+    polar_motion: Option<bool>,
+) -> PyResult<(Array2<f64>, Vec<DateTime<Utc>>)> {
+    let times = ephem.get_times()?;
+    let positions = ephem
+        .get_itrs_positions_with(polar_motion)?
+        .ok_or_else(|| {
+            pyo3::exceptions::PyValueError::new_err("Spacecraft ephemeris has no position data")
+        })?;
+    Ok((positions, times))
+}
+
+/// Contact statistics of one station of a network
+#[pyclass]
+#[derive(Clone)]
+pub struct StationContacts {
+    /// Station name
+    #[pyo3(get)]
+    name: String,
+    times: Vec<DateTime<Utc>>,
+    elevation: Vec<f64>,
+    in_contact: Vec<bool>,
+    windows: Vec<(usize, usize)>,
+}
+
+#[pymethods]
+impl StationContacts {
+    /// Contact windows of this station
+    #[getter]
+    fn contacts(&self, py: Python) -> PyResult<Vec<VisibilityWindow>> {
+        to_windows(py, &self.times, &self.windows)
+    }
+
+    /// Number of contact windows
+    #[getter]
+    fn n_contacts(&self) -> usize {
+        self.windows.len()
+    }
+
+    /// Total contact time in seconds
+    #[getter]
+    fn total_contact_seconds(&self) -> f64 {
+        self.windows
+            .iter()
+            .map(|&w| seconds_between(&self.times, w))
+            .sum()
+    }
+
+    /// Mean contact duration in seconds, or 0.0 without contacts
+    #[getter]
+    fn mean_contact_seconds(&self) -> f64 {
+        if self.windows.is_empty() {
+            0.0
+        } else {
+            self.total_contact_seconds() / self.windows.len() as f64
+        }
+    }
+
+    /// Highest elevation in degrees reached during contact, or None without contacts
+    #[getter]
+    fn max_elevation(&self) -> Option<f64> {
+        self.elevation
+            .iter()
+            .zip(&self.in_contact)
+            .filter(|(_, visible)| **visible)
+            .map(|(&el, _)| el)
+            .reduce(f64::max)
+    }
+
+    /// Spacecraft elevation in degrees at each ephemeris time
+    #[getter]
+    fn elevation(&self, py: Python) -> Py<PyAny> {
+        Array1::from_vec(self.elevation.clone())
+            .into_pyarray(py)
+            .into()
+    }
+
+    /// Whether the station is in contact at each ephemeris time
+    #[getter]
+    fn in_contact(&self) -> Vec<bool> {
+        self.in_contact.clone()
+    }
+
+    fn __repr__(&self) -> String {
+        format!(
+            "StationContacts('{}', n_contacts={}, total_contact_seconds={:.1})",
+            self.name,
+            self.windows.len(),
+            self.total_contact_seconds()
+        )
+    }
+}
+
+/// Combined contact analysis of a ground network for one spacecraft
+#[pyclass]
+pub struct NetworkContacts {
+    times: Vec<DateTime<Utc>>,
+    stations: Vec<StationContacts>,
+    /// Station in contact at each time (index into `stations`), if any
+    serving: Vec<Option<usize>>,
+    contacts: Vec<(usize, usize)>,
+    gaps: Vec<(usize, usize)>,
+    /// (time index, from station, to station)
+    handovers: Vec<(usize, usize, usize)>,
+}
+
+#[pymethods]
+impl NetworkContacts {
+    /// Ephemeris times as a NumPy array of datetimes
+    #[getter]
+    fn timestamp(&self, py: Python) -> PyResult<Py<PyAny>> {
+        timestamp_array(py, &self.times)
+    }
+
+    /// Whether any station is in contact at each time
+    #[getter]
+    fn in_contact(&self) -> Vec<bool> {
+        self.serving.iter().map(Option::is_some).collect()
+    }
+
+    /// Name of the station serving the spacecraft at each time, or None
+    ///
+    /// A station keeps the spacecraft until it loses contact; the visible
+    /// station with the highest elevation then takes over.
+    #[getter]
+    fn serving_station(&self) -> Vec<Option<String>> {
+        self.serving
+            .iter()
+            .map(|s| s.map(|k| self.stations[k].name.clone()))
+            .collect()
+    }
+
+    /// Windows during which at least one station is in contact
+    #[getter]
+    fn coverage(&self, py: Python) -> PyResult<Vec<VisibilityWindow>> {
+        to_windows(py, &self.times, &self.contacts)
+    }
+
+    /// Windows during which no station is in contact
+    #[getter]
+    fn gaps(&self, py: Python) -> PyResult<Vec<VisibilityWindow>> {
+        to_windows(py, &self.times, &self.gaps)
+    }
+
+    /// Handovers as (time, from_station, to_station) tuples
+    #[getter]
+    fn handovers(&self, py: Python) -> PyResult<Vec<(Py<PyAny>, String, String)>> {
+        self.handovers
+            .iter()
+            .map(|&(i, from, to)| {
+                Ok((
+                    utc_to_python_datetime(py, &self.times[i])?,
+                    self.stations[from].name.clone(),
+                    self.stations[to].name.clone(),
+                ))
+            })
+            .collect()
+    }
+
+    /// Per-station contact statistics, in network order
+    #[getter]
+    fn stations(&self) -> Vec<StationContacts> {
+        self.stations.clone()
+    }
+
+    /// Fraction of ephemeris samples with at least one station in contact
+    #[getter]
+    fn coverage_fraction(&self) -> f64 {
+        if self.serving.is_empty() {
+            return 0.0;
+        }
+        self.serving.iter().filter(|s| s.is_some()).count() as f64 / self.serving.len() as f64
+    }
+
+    /// Longest gap in seconds, or 0.0 when coverage is continuous
+    #[getter]
+    fn max_gap_seconds(&self) -> f64 {
+        self.gaps
+            .iter()
+            .map(|&g| seconds_between(&self.times, g))
+            .fold(0.0, f64::max)
+    }
+
+    /// Contact statistics of the named station
+    fn station(&self, name: &str) -> PyResult<StationContacts> {
+        self.stations
+            .iter()
+            .find(|s| s.name == name)
+            .cloned()
+            .ok_or_else(|| {
+                pyo3::exceptions::PyKeyError::new_err(format!("No station named '{}'", name))
+            })
+    }
+
+    fn __repr__(&self) -> String {
+        format!(
+            "NetworkContacts(stations={}, coverage_fraction={:.3}, gaps={}, handovers={})",
+            self.stations.len(),
+            self.coverage_fraction(),
+            self.gaps.len(),
+            self.handovers.len()
+        )
+    }
+}
+
+/// A network of ground stations
+#[pyclass]
+pub struct GroundNetwork {
+    stations: Vec<Station>,
+    min_elevation: f64,
+}
+
+#[pymethods]
+impl GroundNetwork {
+    /// Create a network from a list of stations
+    ///
+    /// # Arguments
+    /// * `stations` - GroundEphemeris objects (their location, site name and
+    ///   horizon profile are used), site names resolved through the site
+    ///   registry, or `(name, latitude, longitude, height)` tuples
+    /// * `min_elevation` - Minimum elevation in degrees for contact, applied in
+    ///   addition to any station horizon profile (default: 0.0)
+    #[new]
+    #[pyo3(signature = (stations, *, min_elevation=0.0))]
+    fn new(stations: Vec<Bound<'_, PyAny>>, min_elevation: f64) -> PyResult<Self> {
+        if stations.is_empty() {
+            return Err(pyo3::exceptions::PyValueError::new_err(
+                "GroundNetwork requires at least one station",
+            ));
+        }
+        if !(-90.0..=90.0).contains(&min_elevation) {
+            return Err(pyo3::exceptions::PyValueError::new_err(
+                "min_elevation must be between -90 and 90 degrees",
+            ));
+        }
+        let stations = stations
+            .iter()
+            .map(extract_station)
+            .collect::<PyResult<Vec<_>>>()?;
+        for (i, station) in stations.iter().enumerate() {
+            if stations[..i].iter().any(|s| s.name == station.name) {
+                return Err(pyo3::exceptions::PyValueError::new_err(format!(
+                    "Duplicate station name '{}'",
+                    station.name
+                )));
+            }
+        }
+        Ok(GroundNetwork {
+            stations,
+            min_elevation,
+        })
+    }
+
+    /// Station names, in network order
+    #[getter]
+    fn station_names(&self) -> Vec<String> {
+        self.stations.iter().map(|s| s.name.clone()).collect()
+    }
+
+    #[getter]
+    fn min_elevation(&self) -> f64 {
+        self.min_elevation
+    }
+
+    fn __len__(&self) -> usize {
+        self.stations.len()
+    }
+
+    /// Compute contacts between every station and a spacecraft
+    ///
+    /// # Arguments
+    /// * `ephemeris` - Spacecraft ephemeris (any ephemeris type)
+    /// * `polar_motion` - Override the ephemeris's polar motion setting
+    ///
+    /// # Returns
+    /// NetworkContacts with combined coverage, gaps, handovers and
+    /// per-station statistics sampled at the ephemeris times
+    #[pyo3(signature = (ephemeris, *, polar_motion=None))]
+    fn contacts(
+        &self,
+        ephemeris: &Bound<'_, PyAny>,
+        polar_motion: Option<bool>,
+    ) -> PyResult<NetworkContacts> {
+        let (positions, times) =
+            with_ephemeris(ephemeris, |ephem| spacecraft_itrs(ephem, polar_motion))?;
+
+        let mut station_results = Vec::with_capacity(self.stations.len());
+        for station in &self.stations {
+            let look = station.look_angles(&positions);
+            let in_contact: Vec<bool> = look
+                .iter()
+                .map(|&(el, az)| {
+                    el >= self.min_elevation
+                        && station
+                            .horizon
+                            .as_ref()
+                            .is_none_or(|h| h.is_visible(el, az))
+                })
+                .collect();
+            station_results.push(StationContacts {
+                name: station.name.clone(),
+                times: times.clone(),
+                elevation: look.iter().map(|&(el, _)| el).collect(),
+                windows: runs(&in_contact),
+                in_contact,
+            });
+        }
+
+        // Keep the current station while it stays in contact, otherwise hand
+        // over to the visible station with the highest elevation
+        let mut serving: Vec<Option<usize>> = Vec::with_capacity(times.len());
+        let mut handovers = Vec::new();
+        for i in 0..times.len() {
+            let previous = serving.last().copied().flatten();
+            let current = match previous {
+                Some(k) if station_results[k].in_contact[i] => Some(k),
+                _ => station_results
+                    .iter()
+                    .enumerate()
+                    .filter(|(_, s)| s.in_contact[i])
+                    .max_by(|a, b| a.1.elevation[i].total_cmp(&b.1.elevation[i]))
+                    .map(|(k, _)| k),
+            };
+            if let (Some(from), Some(to)) = (previous, current) {
+                if from != to {
+                    handovers.push((i, from, to));
+                }
+            }
+            serving.push(current);
+        }
+
+        let covered: Vec<bool> = serving.iter().map(Option::is_some).collect();
+        let contacts = runs(&covered);
+        let gaps = gaps(&contacts, times.len());
+
+        Ok(NetworkContacts {
+            times,
+            stations: station_results,
+            serving,
+            contacts,
+            gaps,
+            handovers,
+        })
+    }
+
+    fn __repr__(&self) -> String {
+        format!(
+            "GroundNetwork({}, min_elevation={})",
+            self.station_names().join(", "),
+            self.min_elevation
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_runs_and_gaps() {
+        let mask = [false, true, true, false, false, true, false];
+        let contacts = runs(&mask);
+        assert_eq!(contacts, vec![(1, 2), (5, 5)]);
+        assert_eq!(gaps(&contacts, mask.len()), vec![(0, 1), (2, 5), (5, 6)]);
+        assert_eq!(gaps(&[], 4), vec![(0, 3)]);
+        assert_eq!(gaps(&runs(&[true, true]), 2), Vec::<(usize, usize)>::new());
+    }
+
+    #[test]
+    fn test_look_angles_zenith_and_horizon() {
+        let station = Station {
+            name: "test".to_string(),
+            latitude: 0.0,
+            longitude: 0.0,
+            height: 0.0,
+            horizon: None,
+        };
+        let positions =
+            Array2::from_shape_vec((2, 3), vec![7000.0, 0.0, 0.0, 6378.137, 0.0, 1000.0]).unwrap();
+        let look = station.look_angles(&positions);
+        assert!((look[0].0 - 90.0).abs() < 1e-9);
+        // Due north along the local horizon
+        assert!(look[1].0.abs() < 1e-9);
+        assert!(look[1].1.abs() < 1e-9 || (look[1].1 - 360.0).abs() < 1e-9);
+    }
+}
@@ -0,0 +1,281 @@
+//! Ground-station link geometry and path loss
+//!
+//! [`link_geometry`] samples the slant range, elevation and azimuth of a
+//! spacecraft from one ground station and turns them into the propagation
+//! losses used for a first-cut link budget:
+//!
+//! * free-space path loss, `20 log10(d_km) + 20 log10(f_MHz) + 32.45` dB
+//! * an atmospheric penalty that scales a zenith attenuation with the path
+//!   length through a spherical-shell atmosphere, so it stays finite at the
+//!   horizon instead of following the flat-Earth cosecant law
+//!
+//! Rain, scintillation and antenna gains are out of scope; the zenith
+//! attenuation is a caller-supplied figure for the band and climate of interest.
+
+use chrono::{DateTime, Utc};
+use ndarray::Array1;
+use numpy::IntoPyArray;
+use pyo3::prelude::*;
+
+use crate::constraints::VisibilityWindow;
+use crate::ephemeris::ground_network::{
+    extract_station, runs, spacecraft_itrs, timestamp_array, to_windows,
+};
+use crate::ephemeris::with_ephemeris;
+use crate::utils::config::EARTH_RADIUS_KM;
+
+/// Free-space path loss constant for distances in km and frequencies in MHz (dB)
+const FSPL_KM_MHZ_DB: f64 = 32.447_78;
+
+/// Equivalent height of the absorbing atmosphere (km)
+const ATMOSPHERE_HEIGHT_KM: f64 = 6.0;
+
+/// Free-space path loss in dB
+pub(crate) fn free_space_loss_db(range_km: f64, frequency_mhz: f64) -> f64 {
+    20.0 * range_km.log10() + 20.0 * frequency_mhz.log10() + FSPL_KM_MHZ_DB
+}
+
+/// Atmospheric path length relative to zenith at an elevation in degrees
+///
+/// Follows the spherical-shell mapping of ITU-R P.618, normalised to 1 at
+/// zenith. Elevations below the horizon are treated as 0 degrees.
+pub(crate) fn atmospheric_path_factor(elevation_deg: f64) -> f64 {
+    let shell = |sin_el: f64| {
+        2.0 / (sin_el + (sin_el * sin_el + 2.0 * ATMOSPHERE_HEIGHT_KM / EARTH_RADIUS_KM).sqrt())
+    };
+    shell(elevation_deg.max(0.0).to_radians().sin()) / shell(1.0)
+}
+
+/// Per-timestamp link geometry and path loss between a ground station and a spacecraft
+#[pyclass]
+pub struct LinkGeometry {
+    /// Station name
+    #[pyo3(get)]
+    station: String,
+    /// Carrier frequency in MHz
+    #[pyo3(get)]
+    frequency_mhz: f64,
+    /// Zenith atmospheric attenuation in dB
+    #[pyo3(get)]
+    zenith_attenuation_db: f64,
+    /// Minimum elevation in degrees for the spacecraft to count as in view
+    #[pyo3(get)]
+    min_elevation: f64,
+    times: Vec<DateTime<Utc>>,
+    elevation: Vec<f64>,
+    azimuth: Vec<f64>,
+    slant_range: Vec<f64>,
+    in_view: Vec<bool>,
+}
+
+impl LinkGeometry {
+    fn free_space_losses(&self) -> Vec<f64> {
+        self.slant_range
+            .iter()
+            .map(|&d| free_space_loss_db(d, self.frequency_mhz))
+            .collect()
+    }
+
+    fn atmospheric_losses(&self) -> Vec<f64> {
+        self.elevation
+            .iter()
+            .map(|&el| self.zenith_attenuation_db * atmospheric_path_factor(el))
+            .collect()
+    }
+
+    fn total_losses(&self) -> Vec<f64> {
+        self.free_space_losses()
+            .iter()
+            .zip(self.atmospheric_losses())
+            .map(|(fspl, atm)| fspl + atm)
+            .collect()
+    }
+
+    /// Total loss over the in-view samples, reduced with `f`
+    fn in_view_loss(&self, f: fn(f64, f64) -> f64) -> Option<f64> {
+        self.total_losses()
+            .into_iter()
+            .zip(&self.in_view)
+            .filter(|(_, visible)| **visible)
+            .map(|(loss, _)| loss)
+            .reduce(f)
+    }
+}
+
+#[pymethods]
+impl LinkGeometry {
+    /// Ephemeris times as a NumPy array of datetimes
+    #[getter]
+    fn timestamp(&self, py: Python) -> PyResult<Py<PyAny>> {
+        timestamp_array(py, &self.times)
+    }
+
+    /// Spacecraft elevation in degrees at each ephemeris time
+    #[getter]
+    fn elevation(&self, py: Python) -> Py<PyAny> {
+        Array1::from_vec(self.elevation.clone())
+            .into_pyarray(py)
+            .into()
+    }
+
+    /// Spacecraft azimuth in degrees (east of north) at each ephemeris time
+    #[getter]
+    fn azimuth(&self, py: Python) -> Py<PyAny> {
+        Array1::from_vec(self.azimuth.clone())
+            .into_pyarray(py)
+            .into()
+    }
+
+    /// Station-to-spacecraft distance in km at each ephemeris time
+    #[getter]
+    fn slant_range_km(&self, py: Python) -> Py<PyAny> {
+        Array1::from_vec(self.slant_range.clone())
+            .into_pyarray(py)
+            .into()
+    }
+
+    /// Free-space path loss in dB at each ephemeris time
+    #[getter]
+    fn free_space_loss_db(&self, py: Python) -> Py<PyAny> {
+        Array1::from_vec(self.free_space_losses())
+            .into_pyarray(py)
+            .into()
+    }
+
+    /// Elevation-dependent atmospheric loss in dB at each ephemeris time
+    #[getter]
+    fn atmospheric_loss_db(&self, py: Python) -> Py<PyAny> {
+        Array1::from_vec(self.atmospheric_losses())
+            .into_pyarray(py)
+            .into()
+    }
+
+    /// Free-space plus atmospheric loss in dB at each ephemeris time
+    #[getter]
+    fn total_loss_db(&self, py: Python) -> Py<PyAny> {
+        Array1::from_vec(self.total_losses())
+            .into_pyarray(py)
+            .into()
+    }
+
+    /// Whether the spacecraft is in view of the station at each ephemeris time
+    #[getter]
+    fn in_view(&self) -> Vec<bool> {
+        self.in_view.clone()
+    }
+
+    /// Passes over the station
+    #[getter]
+    fn passes(&self, py: Python) -> PyResult<Vec<VisibilityWindow>> {
+        to_windows(py, &self.times, &runs(&self.in_view))
+    }
+
+    /// Lowest total loss in dB while in view, or None without passes
+    #[getter]
+    fn min_total_loss_db(&self) -> Option<f64> {
+        self.in_view_loss(f64::min)
+    }
+
+    /// Highest total loss in dB while in view, or None without passes
+    #[getter]
+    fn max_total_loss_db(&self) -> Option<f64> {
+        self.in_view_loss(f64::max)
+    }
+
+    fn __len__(&self) -> usize {
+        self.times.len()
+    }
+
+    fn __repr__(&self) -> String {
+        format!(
+            "LinkGeometry('{}', frequency_mhz={}, n_passes={})",
+            self.station,
+            self.frequency_mhz,
+            runs(&self.in_view).len()
+        )
+    }
+}
+
+/// Compute link geometry and path loss between a ground station and a spacecraft
+///
+/// `station` is anything accepted as a `GroundNetwork` station. The
+/// spacecraft is in view while its elevation is at least `min_elevation`
+/// and above the station's horizon profile, if it has one. `polar_motion`
+/// overrides the spacecraft ephemeris's own setting when given.
+pub fn link_geometry(
+    ephemeris: &Bound<'_, PyAny>,
+    station: &Bound<'_, PyAny>,
+    frequency_mhz: f64,
+    zenith_attenuation_db: f64,
+    min_elevation: f64,
+    polar_motion: Option<bool>,
+) -> PyResult<LinkGeometry> {
+    if !(frequency_mhz.is_finite() && frequency_mhz > 0.0) {
+        return Err(pyo3::exceptions::PyValueError::new_err(
+            "frequency_mhz must be positive",
+        ));
+    }
+    if !(zenith_attenuation_db.is_finite() && zenith_attenuation_db >= 0.0) {
+        return Err(pyo3::exceptions::PyValueError::new_err(
+            "zenith_attenuation_db must be non-negative",
+        ));
+    }
+    if !(-90.0..=90.0).contains(&min_elevation) {
+        return Err(pyo3::exceptions::PyValueError::new_err(
+            "min_elevation must be between -90 and 90 degrees",
+        ));
+    }
+    let station = extract_station(station)?;
+    let (positions, times) =
+        with_ephemeris(ephemeris, |ephem| spacecraft_itrs(ephem, polar_motion))?;
+
+    let look = station.topocentric(&positions);
+    let in_view = look
+        .iter()
+        .map(|&(el, az, _)| {
+            el >= min_elevation
+                && station
+                    .horizon
+                    .as_ref()
+                    .is_none_or(|h| h.is_visible(el, az))
+        })
+        .collect();
+
+    Ok(LinkGeometry {
+        station: station.name,
+        frequency_mhz,
+        zenith_attenuation_db,
+        min_elevation,
+        times,
+        elevation: look.iter().map(|&(el, _, _)| el).collect(),
+        azimuth: look.iter().map(|&(_, az, _)| az).collect(),
+        slant_range: look.iter().map(|&(_, _, range)| range).collect(),
+        in_view,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_free_space_loss() {
+        // 1000 km at 2.2 GHz
+        let loss = free_space_loss_db(1000.0, 2200.0);
+        assert!((loss - 159.2962).abs() < 1e-3);
+        // Doubling the range adds ~6 dB
+        let doubled = free_space_loss_db(2000.0, 2200.0);
+        assert!((doubled - loss - 6.0206).abs() < 1e-3);
+    }
+
+    #[test]
+    fn test_atmospheric_path_factor() {
+        assert!((atmospheric_path_factor(90.0) - 1.0).abs() < 1e-12);
+        // Close to the cosecant law well above the horizon
+        assert!((atmospheric_path_factor(30.0) - 2.0).abs() < 1e-2);
+        // Finite and largest at and below the horizon
+        let horizon = atmospheric_path_factor(0.0);
+        assert!(horizon.is_finite() && horizon > atmospheric_path_factor(5.0));
+        assert_eq!(atmospheric_path_factor(-10.0), horizon);
+    }
+}
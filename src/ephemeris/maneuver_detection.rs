@@ -0,0 +1,434 @@
+//! Maneuver detection in state and TLE histories
+//!
+//! Two detectors look for changes that natural dynamics do not explain:
+//!
+//! - **States** (an OEM or any other ephemeris): each sample is propagated to
+//!   the next under two-body plus J2 gravity, and the velocity mismatch with
+//!   the next sample is the residual delta-v. Consecutive steps above the
+//!   threshold are merged into one candidate whose delta-v is the vector sum
+//!   of their residuals, reported in the pre-burn radial / in-track /
+//!   cross-track (RIC) frame.
+//! - **TLE history**: the mean semi-major axis implied by each TLE's mean
+//!   motion is differenced between consecutive TLEs. The median rate of
+//!   change over the history stands in for drag decay; a jump left after
+//!   removing it that exceeds the threshold is a candidate, with the
+//!   tangential delta-v `Δv = v Δa / 2a` that would produce it. That is the
+//!   smallest delta-v for the change, so a lower bound.
+//!
+//! Unmodelled forces (drag, third bodies, higher harmonics) and, for TLEs,
+//! fit noise set the floor of what can be detected; thresholds should sit
+//! well above it.
+
+use chrono::{DateTime, Utc};
+use ndarray::Array2;
+use pyo3::prelude::*;
+use sgp4::parse_2les;
+
+use crate::ephemeris::with_ephemeris;
+use crate::utils::config::{GM_EARTH, SECONDS_PER_DAY};
+use crate::utils::time_utils::utc_to_python_datetime;
+use crate::utils::tle_utils::TLEData;
+use crate::utils::vector_math::{dot_product, ric_basis, vector_magnitude};
+
+/// Earth J2 zonal harmonic (EGM2008)
+const J2: f64 = 1.082_626_68e-3;
+
+/// Reference radius of the J2 term (km)
+const J2_RADIUS_KM: f64 = 6378.1363;
+
+/// Longest RK4 step when propagating between samples (s)
+const MAX_STEP_SECONDS: f64 = 10.0;
+
+/// A candidate maneuver
+#[pyclass]
+#[derive(Debug, Clone)]
+pub struct ManeuverCandidate {
+    /// "states" or "tle"
+    source: &'static str,
+    /// Best estimate of the maneuver time
+    epoch: DateTime<Utc>,
+    /// Last sample or TLE epoch before the maneuver
+    window_start: DateTime<Utc>,
+    /// First sample or TLE epoch after the maneuver
+    window_end: DateTime<Utc>,
+    /// Delta-v magnitude (km/s)
+    delta_v: f64,
+    /// Delta-v as [radial, in-track, cross-track] (km/s), for state histories
+    delta_v_ric: Option<[f64; 3]>,
+    /// Change of semi-major axis (km)
+    delta_sma: f64,
+}
+
+/// Two-body plus J2 acceleration (km/s²) at `r` (km)
+fn acceleration(r: &[f64; 3]) -> [f64; 3] {
+    let r2 = dot_product(r, r);
+    let rn = r2.sqrt();
+    let mu_r3 = GM_EARTH / (r2 * rn);
+    let j2 = 1.5 * J2 * J2_RADIUS_KM * J2_RADIUS_KM / r2;
+    let z2 = r[2] * r[2] / r2;
+    [
+        -mu_r3 * r[0] * (1.0 + j2 * (1.0 - 5.0 * z2)),
+        -mu_r3 * r[1] * (1.0 + j2 * (1.0 - 5.0 * z2)),
+        -mu_r3 * r[2] * (1.0 + j2 * (3.0 - 5.0 * z2)),
+    ]
+}
+
+fn derivative(state: &[f64; 6]) -> [f64; 6] {
+    let a = acceleration(&[state[0], state[1], state[2]]);
+    [state[3], state[4], state[5], a[0], a[1], a[2]]
+}
+
+/// Propagate a GCRS state (km, km/s) by `dt` seconds with fixed-step RK4
+pub(crate) fn propagate(state: &[f64; 6], dt: f64) -> [f64; 6] {
+    let steps = (dt.abs() / MAX_STEP_SECONDS).ceil().max(1.0);
+    let h = dt / steps;
+    let offset = |s: &[f64; 6], k: &[f64; 6], scale: f64| {
+        let mut out = *s;
+        for (o, d) in out.iter_mut().zip(k) {
+            *o += scale * d;
+        }
+        out
+    };
+    let mut s = *state;
+    for _ in 0..steps as usize {
+        let k1 = derivative(&s);
+        let k2 = derivative(&offset(&s, &k1, h / 2.0));
+        let k3 = derivative(&offset(&s, &k2, h / 2.0));
+        let k4 = derivative(&offset(&s, &k3, h));
+        for (i, value) in s.iter_mut().enumerate() {
+            *value += h / 6.0 * (k1[i] + 2.0 * k2[i] + 2.0 * k3[i] + k4[i]);
+        }
+    }
+    s
+}
+
+/// Osculating semi-major axis (km) of a state, from the vis-viva equation
+fn osculating_sma(state: &[f64; 6]) -> f64 {
+    let r = vector_magnitude(&[state[0], state[1], state[2]]);
+    let v2 = state[3] * state[3] + state[4] * state[4] + state[5] * state[5];
+    1.0 / (2.0 / r - v2 / GM_EARTH)
+}
+
+/// Mean semi-major axis (km) for a mean motion in revolutions per day
+fn sma_from_mean_motion(mean_motion: f64) -> f64 {
+    let n = mean_motion * 2.0 * std::f64::consts::PI / SECONDS_PER_DAY;
+    (GM_EARTH / (n * n)).cbrt()
+}
+
+fn seconds_between(start: &DateTime<Utc>, end: &DateTime<Utc>) -> f64 {
+    (*end - *start).num_nanoseconds().unwrap_or(0) as f64 / 1e9
+}
+
+fn midpoint(start: &DateTime<Utc>, end: &DateTime<Utc>) -> DateTime<Utc> {
+    *start + (*end - *start) / 2
+}
+
+/// Residual delta-v of each step of a state history
+///
+/// Element `i` is the velocity of sample `i + 1` minus that of sample `i`
+/// propagated to it, together with the matching change of osculating
+/// semi-major axis.
+fn step_residuals(times: &[DateTime<Utc>], states: &Array2<f64>) -> Vec<([f64; 3], f64)> {
+    let row = |i: usize| {
+        let mut s = [0.0; 6];
+        for (k, value) in s.iter_mut().enumerate() {
+            *value = states[[i, k]];
+        }
+        s
+    };
+    (1..times.len())
+        .map(|i| {
+            let predicted = propagate(&row(i - 1), seconds_between(&times[i - 1], &times[i]));
+            let actual = row(i);
+            let dv = [
+                actual[3] - predicted[3],
+                actual[4] - predicted[4],
+                actual[5] - predicted[5],
+            ];
+            (dv, osculating_sma(&actual) - osculating_sma(&predicted))
+        })
+        .collect()
+}
+
+/// Detect maneuvers in a GCRS state history (km, km/s)
+///
+/// `delta_v_threshold` is in km/s. Runs of consecutive steps above it become
+/// one candidate; its epoch is the middle of the step with the largest
+/// residual and its RIC frame that of the sample starting that step.
+pub fn detect_from_states(
+    times: &[DateTime<Utc>],
+    states: &Array2<f64>,
+    delta_v_threshold: f64,
+) -> PyResult<Vec<ManeuverCandidate>> {
+    if !(delta_v_threshold.is_finite() && delta_v_threshold > 0.0) {
+        return Err(pyo3::exceptions::PyValueError::new_err(
+            "delta_v_threshold must be positive",
+        ));
+    }
+    if times.len() < 2 {
+        return Err(pyo3::exceptions::PyValueError::new_err(
+            "At least two states are needed to detect maneuvers",
+        ));
+    }
+
+    let residuals = step_residuals(times, states);
+    let mut candidates = Vec::new();
+    let mut step = 0;
+    while step < residuals.len() {
+        if vector_magnitude(&residuals[step].0) <= delta_v_threshold {
+            step += 1;
+            continue;
+        }
+        let first = step;
+        while step < residuals.len() && vector_magnitude(&residuals[step].0) > delta_v_threshold {
+            step += 1;
+        }
+        let run = &residuals[first..step];
+
+        let mut total = [0.0; 3];
+        let mut delta_sma = 0.0;
+        for (dv, da) in run {
+            for (t, d) in total.iter_mut().zip(dv) {
+                *t += d;
+            }
+            delta_sma += da;
+        }
+        let peak = first
+            + (0..run.len())
+                .max_by(|&a, &b| {
+                    vector_magnitude(&run[a].0).total_cmp(&vector_magnitude(&run[b].0))
+                })
+                .unwrap_or(0);
+        let basis = ric_basis(
+            &[states[[peak, 0]], states[[peak, 1]], states[[peak, 2]]],
+            &[states[[peak, 3]], states[[peak, 4]], states[[peak, 5]]],
+        );
+        candidates.push(ManeuverCandidate {
+            source: "states",
+            epoch: midpoint(&times[peak], &times[peak + 1]),
+            window_start: times[first],
+            window_end: times[step],
+            delta_v: vector_magnitude(&total),
+            delta_v_ric: Some([
+                dot_product(&total, &basis[0]),
+                dot_product(&total, &basis[1]),
+                dot_product(&total, &basis[2]),
+            ]),
+            delta_sma,
+        });
+    }
+    Ok(candidates)
+}
+
+/// Detect maneuvers from jumps in the mean semi-major axis of a TLE history
+///
+/// TLEs are sorted by epoch and duplicate epochs dropped. `sma_threshold_km`
+/// applies to the jump between consecutive TLEs after removing the median
+/// drift rate of the history.
+pub fn detect_from_tles(
+    tles: &[TLEData],
+    sma_threshold_km: f64,
+) -> PyResult<Vec<ManeuverCandidate>> {
+    if !(sma_threshold_km.is_finite() && sma_threshold_km > 0.0) {
+        return Err(pyo3::exceptions::PyValueError::new_err(
+            "sma_threshold_km must be positive",
+        ));
+    }
+    let mut sorted: Vec<&TLEData> = tles.iter().collect();
+    sorted.sort_by_key(|tle| tle.epoch);
+    sorted.dedup_by_key(|tle| tle.epoch);
+    if sorted.len() < 3 {
+        return Err(pyo3::exceptions::PyValueError::new_err(
+            "At least three TLEs with distinct epochs are needed to detect maneuvers",
+        ));
+    }
+    let sma = sorted
+        .iter()
+        .map(|tle| {
+            let elements = parse_2les(&format!("{}\n{}", tle.line1, tle.line2))
+                .map_err(|e| {
+                    pyo3::exceptions::PyValueError::new_err(format!("TLE parse error: {e:?}"))
+                })?
+                .into_iter()
+                .next()
+                .ok_or_else(|| {
+                    pyo3::exceptions::PyValueError::new_err("No elements parsed from TLE")
+                })?;
+            Ok(sma_from_mean_motion(elements.mean_motion))
+        })
+        .collect::<PyResult<Vec<f64>>>()?;
+
+    let intervals: Vec<f64> = sorted
+        .windows(2)
+        .map(|pair| seconds_between(&pair[0].epoch, &pair[1].epoch))
+        .collect();
+    let mut rates: Vec<f64> = intervals
+        .iter()
+        .enumerate()
+        .map(|(i, dt)| (sma[i + 1] - sma[i]) / dt)
+        .collect();
+    rates.sort_by(f64::total_cmp);
+    let drift_rate = rates[rates.len() / 2];
+
+    let mut candidates = Vec::new();
+    for (i, dt) in intervals.iter().enumerate() {
+        let jump = sma[i + 1] - sma[i] - drift_rate * dt;
+        if jump.abs() <= sma_threshold_km {
+            continue;
+        }
+        let a = sma[i];
+        candidates.push(ManeuverCandidate {
+            source: "tle",
+            epoch: midpoint(&sorted[i].epoch, &sorted[i + 1].epoch),
+            window_start: sorted[i].epoch,
+            window_end: sorted[i + 1].epoch,
+            delta_v: (GM_EARTH / a).sqrt() * jump.abs() / (2.0 * a),
+            delta_v_ric: None,
+            delta_sma: jump,
+        });
+    }
+    Ok(candidates)
+}
+
+/// GCRS states (N x 6) and times of any supported ephemeris
+pub fn ephemeris_states(obj: &Bound<'_, PyAny>) -> PyResult<(Vec<DateTime<Utc>>, Array2<f64>)> {
+    with_ephemeris(obj, |ephem| {
+        let times = ephem.get_times()?;
+        let gcrs =
+            ephem.data().gcrs.clone().ok_or_else(|| {
+                pyo3::exceptions::PyValueError::new_err("Ephemeris has no GCRS data")
+            })?;
+        Ok((times, gcrs))
+    })
+}
+
+#[pymethods]
+impl ManeuverCandidate {
+    /// "states" for a state history, "tle" for a TLE history
+    #[getter]
+    fn source(&self) -> &str {
+        self.source
+    }
+
+    /// Best estimate of the maneuver time
+    #[getter]
+    fn epoch(&self, py: Python) -> PyResult<Py<PyAny>> {
+        utc_to_python_datetime(py, &self.epoch)
+    }
+
+    /// Last sample or TLE epoch before the maneuver
+    #[getter]
+    fn window_start(&self, py: Python) -> PyResult<Py<PyAny>> {
+        utc_to_python_datetime(py, &self.window_start)
+    }
+
+    /// First sample or TLE epoch after the maneuver
+    #[getter]
+    fn window_end(&self, py: Python) -> PyResult<Py<PyAny>> {
+        utc_to_python_datetime(py, &self.window_end)
+    }
+
+    /// Estimated delta-v magnitude in m/s (a lower bound for TLE histories)
+    #[getter]
+    fn delta_v_m_s(&self) -> f64 {
+        self.delta_v * 1000.0
+    }
+
+    /// Delta-v as [radial, in-track, cross-track] in m/s, or None for TLE histories
+    #[getter]
+    fn delta_v_ric_m_s(&self) -> Option<Vec<f64>> {
+        self.delta_v_ric
+            .map(|ric| ric.iter().map(|v| v * 1000.0).collect())
+    }
+
+    /// Change of semi-major axis in km
+    #[getter]
+    fn delta_sma_km(&self) -> f64 {
+        self.delta_sma
+    }
+
+    fn __repr__(&self) -> String {
+        format!(
+            "ManeuverCandidate(epoch={}, delta_v_m_s={:.3}, delta_sma_km={:.3}, source={})",
+            self.epoch.to_rfc3339(),
+            self.delta_v * 1000.0,
+            self.delta_sma,
+            self.source
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::{Duration, TimeZone};
+
+    fn start() -> DateTime<Utc> {
+        Utc.with_ymd_and_hms(2024, 1, 1, 0, 0, 0).unwrap()
+    }
+
+    /// Propagated history of an inclined LEO with an optional in-track burn
+    fn history(burn: Option<(usize, f64)>) -> (Vec<DateTime<Utc>>, Array2<f64>) {
+        let r = 6878.0;
+        let v = (GM_EARTH / r).sqrt();
+        let incl = 51.6_f64.to_radians();
+        let mut state = [r, 0.0, 0.0, 0.0, v * incl.cos(), v * incl.sin()];
+        let n = 120;
+        let mut states = Array2::zeros((n, 6));
+        let mut times = Vec::with_capacity(n);
+        for i in 0..n {
+            if let Some((at, dv)) = burn {
+                if i == at {
+                    let speed = vector_magnitude(&[state[3], state[4], state[5]]);
+                    for value in &mut state[3..] {
+                        *value += dv * *value / speed;
+                    }
+                }
+            }
+            times.push(start() + Duration::seconds(60 * i as i64));
+            for (k, value) in state.iter().enumerate() {
+                states[[i, k]] = *value;
+            }
+            state = propagate(&state, 60.0);
+        }
+        (times, states)
+    }
+
+    #[test]
+    fn test_quiet_history_has_no_candidates() {
+        let (times, states) = history(None);
+        assert!(detect_from_states(&times, &states, 1e-5)
+            .unwrap()
+            .is_empty());
+    }
+
+    #[test]
+    fn test_burn_is_detected() {
+        // 1 m/s prograde burn applied just before sample 50
+        let (times, states) = history(Some((50, 1e-3)));
+        let found = detect_from_states(&times, &states, 1e-5).unwrap();
+        assert_eq!(found.len(), 1);
+        let burn = &found[0];
+        assert_eq!(burn.window_start, times[49]);
+        assert_eq!(burn.window_end, times[50]);
+        assert!((burn.delta_v - 1e-3).abs() < 1e-6);
+        let ric = burn.delta_v_ric.unwrap();
+        assert!(ric[1] > 0.99e-3);
+        // Δa = 2 a² v Δv / μ for a circular orbit, about 1.8 km
+        assert!(burn.delta_sma > 1.7 && burn.delta_sma < 1.9);
+    }
+
+    #[test]
+    fn test_sma_from_mean_motion() {
+        // ISS-like 15.5 rev/day orbit
+        let a = sma_from_mean_motion(15.5);
+        assert!((a - 6796.0).abs() < 5.0, "a = {a}");
+    }
+
+    #[test]
+    fn test_invalid_threshold() {
+        let (times, states) = history(None);
+        assert!(detect_from_states(&times, &states, 0.0).is_err());
+        assert!(detect_from_states(&times[..1], &states, 1e-5).is_err());
+    }
+}
@@ -0,0 +1,153 @@
+//! Minor planets from Minor Planet Center orbital elements
+//!
+//! Wraps [`MpcOrbit`] for Python. Registered minor planets are resolved by
+//! name or designation in `get_body`, `get_body_pv` and moving-body
+//! constraint evaluation, in the same way as SPICE or Horizons bodies.
+
+use std::path::Path;
+
+use pyo3::prelude::*;
+
+use crate::ephemeris::ephemeris_common::split_pos_vel;
+use crate::ephemeris::position_velocity::PositionVelocityData;
+use crate::utils::mpc::{parse_mpcorb, register_minor_planet, MpcOrbit};
+use crate::utils::time_utils::{python_times_to_utc, utc_to_python_datetime};
+
+#[pyclass]
+#[derive(Clone)]
+pub struct MinorPlanet {
+    orbit: MpcOrbit,
+}
+
+#[pymethods]
+impl MinorPlanet {
+    /// Parse a single MPCORB-format orbit line
+    #[staticmethod]
+    fn from_mpcorb_line(line: &str) -> PyResult<Self> {
+        MpcOrbit::from_line(line)
+            .map(|orbit| MinorPlanet { orbit })
+            .map_err(pyo3::exceptions::PyValueError::new_err)
+    }
+
+    /// Permanent number, or None for unnumbered objects
+    #[getter]
+    fn number(&self) -> Option<u32> {
+        self.orbit.number
+    }
+
+    /// Readable designation, e.g. "(433) Eros" or "2007 TA418"
+    #[getter]
+    fn designation(&self) -> &str {
+        &self.orbit.designation
+    }
+
+    /// Name of a numbered object, or None
+    #[getter]
+    fn name(&self) -> Option<&str> {
+        self.orbit.name.as_deref()
+    }
+
+    /// Absolute magnitude H, or None if not given
+    #[getter]
+    fn h(&self) -> Option<f64> {
+        self.orbit.h
+    }
+
+    /// Slope parameter G, or None if not given
+    #[getter]
+    fn g(&self) -> Option<f64> {
+        self.orbit.g
+    }
+
+    /// Epoch of osculation (UTC datetime)
+    #[getter]
+    fn epoch(&self, py: Python) -> PyResult<Py<PyAny>> {
+        let epoch = self
+            .orbit
+            .epoch_utc()
+            .map_err(pyo3::exceptions::PyValueError::new_err)?;
+        utc_to_python_datetime(py, &epoch)
+    }
+
+    /// Semi-major axis (AU)
+    #[getter]
+    fn semimajor_axis(&self) -> f64 {
+        self.orbit.semimajor_axis
+    }
+
+    #[getter]
+    fn eccentricity(&self) -> f64 {
+        self.orbit.eccentricity
+    }
+
+    /// Inclination to the J2000 ecliptic (degrees)
+    #[getter]
+    fn inclination(&self) -> f64 {
+        self.orbit.inclination
+    }
+
+    /// Longitude of the ascending node (degrees)
+    #[getter]
+    fn ascending_node(&self) -> f64 {
+        self.orbit.ascending_node
+    }
+
+    /// Argument of perihelion (degrees)
+    #[getter]
+    fn argument_of_perihelion(&self) -> f64 {
+        self.orbit.arg_perihelion
+    }
+
+    /// Mean anomaly at the epoch (degrees)
+    #[getter]
+    fn mean_anomaly(&self) -> f64 {
+        self.orbit.mean_anomaly
+    }
+
+    /// Mean daily motion (degrees/day)
+    #[getter]
+    fn mean_motion(&self) -> f64 {
+        self.orbit.mean_motion
+    }
+
+    /// Heliocentric position and velocity at the given times
+    ///
+    /// Returns a PositionVelocityData in km and km/s, in the ICRS-aligned
+    /// equatorial frame, without light-time correction.
+    fn heliocentric_pv(&self, times: &Bound<'_, PyAny>) -> PyResult<PositionVelocityData> {
+        let times = python_times_to_utc(times)?;
+        Ok(split_pos_vel(&self.orbit.heliocentric_states(&times)))
+    }
+
+    /// Make this object resolvable by name or designation in body lookups
+    fn register(&self) {
+        register_minor_planet(&self.orbit);
+    }
+
+    fn __repr__(&self) -> String {
+        format!(
+            "MinorPlanet('{}', a={:.6} AU, e={:.6}, i={:.4} deg)",
+            self.orbit.designation,
+            self.orbit.semimajor_axis,
+            self.orbit.eccentricity,
+            self.orbit.inclination
+        )
+    }
+}
+
+/// Read an MPCORB-format file, optionally registering every orbit in it
+pub fn load_mpcorb_file(path: &str, register: bool) -> PyResult<Vec<MinorPlanet>> {
+    let text = std::fs::read_to_string(Path::new(path)).map_err(|e| {
+        pyo3::exceptions::PyIOError::new_err(format!("Failed to read MPC orbit file: {}", e))
+    })?;
+    let orbits = parse_mpcorb(&text).map_err(pyo3::exceptions::PyValueError::new_err)?;
+    if register {
+        for orbit in &orbits {
+            register_minor_planet(orbit);
+        }
+    }
+    Ok(orbits
+        .into_iter()
+        .map(|orbit| MinorPlanet { orbit })
+        .collect())
+}
@@ -1,20 +1,198 @@
 //! Ephemeris computation modules
 //!
 //! This module contains implementations for computing celestial body positions
-//! and velocities using various data sources (SPICE, TLE, ground stations, CCSDS).
+//! and velocities using various data sources (SPICE, TLE, ground stations,
+//! moving ground platforms, CCSDS, JPL Horizons).
 
+pub mod almanac_ephemeris;
+pub mod array_ephemeris;
 pub mod ccsds_ephemeris;
+pub mod comparison;
+pub mod coverage;
+pub mod element_validation;
 pub mod ephemeris_common;
 pub mod file_ephemeris;
+pub mod geo_ephemeris;
+pub mod ground_access;
 pub mod ground_ephemeris;
+pub mod ground_network;
+pub mod horizons_ephemeris;
+pub mod link_geometry;
+pub mod maneuver_detection;
+pub mod minor_planet;
+pub mod nodal_analysis;
+pub mod observation_geometry;
+pub mod orbit_decay;
 pub mod position_velocity;
+pub mod provenance;
+pub mod rinex_nav_ephemeris;
+pub mod sample;
+pub mod solar_eclipse;
 pub mod spice_ephemeris;
 pub mod spice_manager;
+pub mod tle_accuracy;
 pub mod tle_ephemeris;
+pub mod trajectory_ground_ephemeris;
 
 // Re-export main types
+pub use almanac_ephemeris::AlmanacEphemeris;
+pub use array_ephemeris::ArrayEphemeris;
 pub use ccsds_ephemeris::OEMEphemeris;
+pub use comparison::EphemerisComparison;
+pub use coverage::{CoverageGrid, CoverageResult};
 pub use file_ephemeris::FileEphemeris;
+pub use geo_ephemeris::GEOEphemeris;
+pub use ground_access::AccessOpportunity;
 pub use ground_ephemeris::GroundEphemeris;
+pub use ground_network::{GroundNetwork, NetworkContacts, StationContacts};
+pub use horizons_ephemeris::HorizonsEphemeris;
+pub use link_geometry::LinkGeometry;
+pub use maneuver_detection::ManeuverCandidate;
+pub use minor_planet::MinorPlanet;
+pub use nodal_analysis::NodalAnalysis;
+pub use observation_geometry::ObservationGeometry;
+pub use orbit_decay::DecayEstimate;
+pub use provenance::Provenance;
+pub use rinex_nav_ephemeris::RinexNavEphemeris;
+pub use sample::EphemerisSample;
+pub use solar_eclipse::SolarEclipse;
 pub use spice_ephemeris::SPICEEphemeris;
+pub use tle_accuracy::TLEAccuracy;
 pub use tle_ephemeris::TLEEphemeris;
+pub use trajectory_ground_ephemeris::TrajectoryGroundEphemeris;
+
+use ephemeris_common::{time_grid_metadata, EphemerisBase};
+use pyo3::prelude::*;
+use pyo3::types::PyDict;
+
+use crate::utils::json_to_py::json_to_pyobject;
+
+/// Borrow any supported ephemeris Python object as `&dyn EphemerisBase`
+/// and run `f` on it.
+///
+/// This is the single dispatch point used by the constraint wrappers, so a
+/// new ephemeris class only needs to be added here to gain constraint support.
+pub fn with_ephemeris<R>(
+    obj: &Bound<'_, PyAny>,
+    f: impl FnOnce(&dyn EphemerisBase) -> PyResult<R>,
+) -> PyResult<R> {
+    if let Ok(ephem) = obj.extract::<PyRef<TLEEphemeris>>() {
+        f(&*ephem)
+    } else if let Ok(ephem) = obj.extract::<PyRef<SPICEEphemeris>>() {
+        f(&*ephem)
+    } else if let Ok(ephem) = obj.extract::<PyRef<GroundEphemeris>>() {
+        f(&*ephem)
+    } else if let Ok(ephem) = obj.extract::<PyRef<TrajectoryGroundEphemeris>>() {
+        f(&*ephem)
+    } else if let Ok(ephem) = obj.extract::<PyRef<OEMEphemeris>>() {
+        f(&*ephem)
+    } else if let Ok(ephem) = obj.extract::<PyRef<FileEphemeris>>() {
+        f(&*ephem)
+    } else if let Ok(ephem) = obj.extract::<PyRef<HorizonsEphemeris>>() {
+        f(&*ephem)
+    } else if let Ok(ephem) = obj.extract::<PyRef<AlmanacEphemeris>>() {
+        f(&*ephem)
+    } else if let Ok(ephem) = obj.extract::<PyRef<RinexNavEphemeris>>() {
+        f(&*ephem)
+    } else if let Ok(ephem) = obj.extract::<PyRef<ArrayEphemeris>>() {
+        f(&*ephem)
+    } else {
+        Err(pyo3::exceptions::PyTypeError::new_err(
+            "Unsupported ephemeris type. Expected TLEEphemeris, SPICEEphemeris, GroundEphemeris, TrajectoryGroundEphemeris, OEMEphemeris, FileEphemeris, HorizonsEphemeris, AlmanacEphemeris, RinexNavEphemeris, or ArrayEphemeris",
+        ))
+    }
+}
+
+/// `__eq__` for ephemeris classes: equal when the fingerprints match
+///
+/// Objects that are not ephemerides compare unequal.
+pub(crate) fn ephemeris_eq(ephem: &dyn EphemerisBase, other: &Bound<'_, PyAny>) -> bool {
+    with_ephemeris(
+        other,
+        |other| Ok(other.fingerprint() == ephem.fingerprint()),
+    )
+    .unwrap_or(false)
+}
+
+/// `__hash__` for ephemeris classes, consistent with `ephemeris_eq`
+pub(crate) fn ephemeris_hash(ephem: &dyn EphemerisBase) -> u64 {
+    u64::from_str_radix(&ephem.fingerprint()[..16], 16).unwrap_or_default()
+}
+
+/// `metadata` property for ephemeris classes, as an ordered dict
+pub(crate) fn ephemeris_metadata(py: Python, ephem: &dyn EphemerisBase) -> PyResult<Py<PyAny>> {
+    let dict = PyDict::new(py);
+    for (key, value) in ephem.metadata() {
+        dict.set_item(key, json_to_pyobject(py, &value)?)?;
+    }
+    Ok(dict.into_any().unbind())
+}
+
+/// `__repr__` for ephemeris classes: the source and the time grid
+pub(crate) fn ephemeris_repr(ephem: &dyn EphemerisBase) -> String {
+    let mut entries = ephem.source_metadata();
+    entries.extend(time_grid_metadata(&ephem.data().times));
+    let class = entries
+        .iter()
+        .find(|(key, _)| *key == "type")
+        .map(|(_, value)| display_value(value))
+        .unwrap_or_default();
+    let fields: Vec<String> = entries
+        .iter()
+        .filter(|(key, _)| *key != "type")
+        .map(|(key, value)| format!("{}={}", key, python_literal(value)))
+        .collect();
+    format!("{}({})", class, fields.join(", "))
+}
+
+/// `summary()` for ephemeris classes: one aligned `key: value` line per metadata entry
+pub(crate) fn ephemeris_summary(ephem: &dyn EphemerisBase) -> String {
+    let metadata = ephem.metadata();
+    let width = metadata.iter().map(|(key, _)| key.len()).max().unwrap_or(0);
+    let mut lines = Vec::with_capacity(metadata.len());
+    for (key, value) in &metadata {
+        if *key == "type" {
+            lines.insert(0, display_value(value));
+        } else {
+            let label = format!("{}:", key);
+            lines.push(format!(
+                "  {:<width$} {}",
+                label,
+                display_value(value),
+                width = width + 1
+            ));
+        }
+    }
+    lines.join("\n")
+}
+
+/// Render a metadata value as a Python literal
+fn python_literal(value: &serde_json::Value) -> String {
+    match value {
+        serde_json::Value::Null => "None".to_string(),
+        serde_json::Value::Bool(b) => if *b { "True" } else { "False" }.to_string(),
+        serde_json::Value::String(s) => format!("'{}'", s),
+        serde_json::Value::Array(items) => format!(
+            "[{}]",
+            items
+                .iter()
+                .map(python_literal)
+                .collect::<Vec<_>>()
+                .join(", ")
+        ),
+        other => other.to_string(),
+    }
+}
+
+/// Render a metadata value for human-readable text (strings unquoted)
+fn display_value(value: &serde_json::Value) -> String {
+    match value {
+        serde_json::Value::String(s) => s.clone(),
+        serde_json::Value::Array(items) => items
+            .iter()
+            .map(display_value)
+            .collect::<Vec<_>>()
+            .join(", "),
+        other => python_literal(other),
+    }
+}
@@ -0,0 +1,428 @@
+//! Nodal period, node drift and repeat ground-track analysis
+//!
+//! [`nodal_analysis`] finds the ascending node crossings of an orbit on the
+//! true equator of date, where the J2 secular rates act, and derives:
+//!
+//! * the nodal period of every revolution and their mean
+//! * the RAAN drift rate, a least-squares fit through the node longitudes,
+//!   and its offset from the sun-synchronous rate
+//! * the number of revolutions per nodal day, `2π / (ω⊕ - Ω̇)`, and the
+//!   shortest repeat ground-track cycle within a tolerance
+//!
+//! Crossings are located with cubic Hermite interpolation of the height
+//! above the equator, so the sample spacing only has to be well under half
+//! an orbit. Sampling nodes at the same argument of latitude every orbit
+//! cancels the short-period J2 terms, so a day or two of data gives mean
+//! rates good enough to confirm sun-synchronous and repeat-orbit designs.
+
+use chrono::{DateTime, Utc};
+use erfa::{prenut::pn_matrix_06a, vectors_and_matrices::mat_mul_pvec};
+use ndarray::Array2;
+use numpy::IntoPyArray;
+use pyo3::prelude::*;
+
+use crate::ephemeris::maneuver_detection::ephemeris_states;
+use crate::utils::config::{EARTH_RADIUS_KM, OMEGA_EARTH, SECONDS_PER_DAY};
+use crate::utils::time_utils::{datetime_to_jd_tt, utc_to_python_datetime};
+use crate::utils::vector_math::cross_product;
+
+/// Node drift of a sun-synchronous orbit: the Sun's mean motion (deg/day)
+pub const SUN_SYNCHRONOUS_RATE_DEG_PER_DAY: f64 = 360.0 / 365.242_189_7;
+
+/// Bisection steps when locating a crossing within a sample interval
+const CROSSING_ITERATIONS: usize = 50;
+
+/// An ascending node crossing on the true equator of date
+#[derive(Debug, Clone, Copy)]
+pub struct NodeCrossing {
+    pub time: DateTime<Utc>,
+    /// Right ascension of the ascending node (rad)
+    pub raan: f64,
+    /// Inclination (rad)
+    pub inclination: f64,
+}
+
+/// Repeat ground-track cycle
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct RepeatCycle {
+    /// Nodal days per cycle
+    pub days: u32,
+    /// Revolutions per cycle
+    pub revolutions: u32,
+    /// Eastward shift of the ascending node at the equator after one cycle (km)
+    pub shift_km: f64,
+}
+
+/// Nodal period, drift and repeat-cycle analysis of an orbit
+#[pyclass]
+pub struct NodalAnalysis {
+    nodes: Vec<NodeCrossing>,
+    /// Duration of each revolution, node to node (s)
+    nodal_periods: Vec<f64>,
+    mean_nodal_period: f64,
+    /// RAAN drift (rad/s)
+    raan_drift: f64,
+    repeat: Option<RepeatCycle>,
+}
+
+/// GCRS states rotated to the true equator and equinox of date
+fn tod_states(times: &[DateTime<Utc>], gcrs: &Array2<f64>) -> Array2<f64> {
+    let mut out = Array2::<f64>::zeros(gcrs.raw_dim());
+    for (i, time) in times.iter().enumerate() {
+        let (jd_tt1, jd_tt2) = datetime_to_jd_tt(time);
+        let gcrs_to_tod = pn_matrix_06a(jd_tt1, jd_tt2);
+        let pos = mat_mul_pvec(gcrs_to_tod, [gcrs[[i, 0]], gcrs[[i, 1]], gcrs[[i, 2]]]);
+        let vel = mat_mul_pvec(gcrs_to_tod, [gcrs[[i, 3]], gcrs[[i, 4]], gcrs[[i, 5]]]);
+        for (j, (p, v)) in pos.iter().zip(vel).enumerate() {
+            out[[i, j]] = *p;
+            out[[i, j + 3]] = v;
+        }
+    }
+    out
+}
+
+/// Fraction of the interval at which the cubic Hermite through `(z0, vz0)`
+/// and `(z1, vz1)` crosses zero, given `z0 < 0 <= z1`
+fn crossing_fraction(z0: f64, vz0: f64, z1: f64, vz1: f64, dt: f64) -> f64 {
+    let z = |s: f64| {
+        let s2 = s * s;
+        let s3 = s2 * s;
+        (2.0 * s3 - 3.0 * s2 + 1.0) * z0
+            + (s3 - 2.0 * s2 + s) * dt * vz0
+            + (-2.0 * s3 + 3.0 * s2) * z1
+            + (s3 - s2) * dt * vz1
+    };
+    let (mut lo, mut hi) = (0.0, 1.0);
+    for _ in 0..CROSSING_ITERATIONS {
+        let mid = 0.5 * (lo + hi);
+        if z(mid) < 0.0 {
+            lo = mid;
+        } else {
+            hi = mid;
+        }
+    }
+    0.5 * (lo + hi)
+}
+
+/// Ascending node crossings of a state history on the equator of its frame
+pub fn ascending_nodes(times: &[DateTime<Utc>], states: &Array2<f64>) -> Vec<NodeCrossing> {
+    let mut nodes = Vec::new();
+    for i in 1..times.len() {
+        let (z0, z1) = (states[[i - 1, 2]], states[[i, 2]]);
+        if !(z0 < 0.0 && z1 >= 0.0) {
+            continue;
+        }
+        let dt = (times[i] - times[i - 1]).num_nanoseconds().unwrap_or(0) as f64 / 1e9;
+        let s = crossing_fraction(z0, states[[i - 1, 5]], z1, states[[i, 5]], dt);
+
+        let momentum = |k: usize| {
+            cross_product(
+                &[states[[k, 0]], states[[k, 1]], states[[k, 2]]],
+                &[states[[k, 3]], states[[k, 4]], states[[k, 5]]],
+            )
+        };
+        let (h0, h1) = (momentum(i - 1), momentum(i));
+        let h: [f64; 3] = std::array::from_fn(|k| h0[k] + s * (h1[k] - h0[k]));
+        let h_norm = (h[0] * h[0] + h[1] * h[1] + h[2] * h[2]).sqrt();
+        nodes.push(NodeCrossing {
+            time: times[i - 1] + chrono::Duration::nanoseconds((s * dt * 1e9).round() as i64),
+            raan: h[0].atan2(-h[1]).rem_euclid(std::f64::consts::TAU),
+            inclination: (h[2] / h_norm).acos(),
+        });
+    }
+    nodes
+}
+
+/// Least-squares slope (rad/s) of the unwrapped node longitudes
+fn raan_drift_rate(nodes: &[NodeCrossing]) -> f64 {
+    let t0 = nodes[0].time;
+    let mut raan = nodes[0].raan;
+    let mut points = Vec::with_capacity(nodes.len());
+    for (i, node) in nodes.iter().enumerate() {
+        if i > 0 {
+            let step = (node.raan - nodes[i - 1].raan + std::f64::consts::PI)
+                .rem_euclid(std::f64::consts::TAU)
+                - std::f64::consts::PI;
+            raan += step;
+        }
+        let t = (node.time - t0).num_nanoseconds().unwrap_or(0) as f64 / 1e9;
+        points.push((t, raan));
+    }
+    let n = points.len() as f64;
+    let mean_t = points.iter().map(|p| p.0).sum::<f64>() / n;
+    let mean_raan = points.iter().map(|p| p.1).sum::<f64>() / n;
+    let sxy: f64 = points
+        .iter()
+        .map(|(t, r)| (t - mean_t) * (r - mean_raan))
+        .sum();
+    let sxx: f64 = points.iter().map(|(t, _)| (t - mean_t).powi(2)).sum();
+    sxy / sxx
+}
+
+/// Length of a nodal day (s): one turn of the Earth relative to the node line
+fn nodal_day(raan_drift: f64) -> f64 {
+    std::f64::consts::TAU / (OMEGA_EARTH - raan_drift)
+}
+
+/// Shortest repeat ground-track cycle of at most `max_days` nodal days
+///
+/// A cycle of `D` days and `N` revolutions repeats when the ascending node
+/// returns to within `tolerance_km` of its starting longitude at the
+/// equator, i.e. when `N T - D T_day` is small.
+pub fn repeat_cycle(
+    nodal_period: f64,
+    raan_drift: f64,
+    max_days: u32,
+    tolerance_km: f64,
+) -> Option<RepeatCycle> {
+    let day = nodal_day(raan_drift);
+    let revolutions_per_day = day / nodal_period;
+    (1..=max_days).find_map(|days| {
+        let revolutions = (revolutions_per_day * days as f64).round();
+        if revolutions < 1.0 {
+            return None;
+        }
+        let lag = revolutions * nodal_period - days as f64 * day;
+        let shift_km = -(OMEGA_EARTH - raan_drift) * lag * EARTH_RADIUS_KM;
+        (shift_km.abs() <= tolerance_km).then_some(RepeatCycle {
+            days,
+            revolutions: revolutions as u32,
+            shift_km,
+        })
+    })
+}
+
+/// Nodal analysis of an ascending-node sequence
+pub fn analyze_nodes(
+    nodes: Vec<NodeCrossing>,
+    max_repeat_days: u32,
+    repeat_tolerance_km: f64,
+) -> PyResult<NodalAnalysis> {
+    if !(repeat_tolerance_km.is_finite() && repeat_tolerance_km > 0.0) {
+        return Err(pyo3::exceptions::PyValueError::new_err(
+            "repeat_tolerance_km must be positive",
+        ));
+    }
+    if nodes.len() < 2 {
+        return Err(pyo3::exceptions::PyValueError::new_err(format!(
+            "Found {} ascending node crossing(s); at least two are needed. Use an ephemeris spanning more than one orbit, sampled well under half an orbit apart",
+            nodes.len()
+        )));
+    }
+    let seconds = |a: &DateTime<Utc>, b: &DateTime<Utc>| {
+        (*b - *a).num_nanoseconds().unwrap_or(0) as f64 / 1e9
+    };
+    let nodal_periods: Vec<f64> = nodes
+        .windows(2)
+        .map(|pair| seconds(&pair[0].time, &pair[1].time))
+        .collect();
+    let mean_nodal_period =
+        seconds(&nodes[0].time, &nodes[nodes.len() - 1].time) / nodal_periods.len() as f64;
+    let raan_drift = raan_drift_rate(&nodes);
+    Ok(NodalAnalysis {
+        repeat: repeat_cycle(
+            mean_nodal_period,
+            raan_drift,
+            max_repeat_days,
+            repeat_tolerance_km,
+        ),
+        nodes,
+        nodal_periods,
+        mean_nodal_period,
+        raan_drift,
+    })
+}
+
+/// Nodal analysis of any supported ephemeris
+pub fn nodal_analysis(
+    ephemeris: &Bound<'_, PyAny>,
+    max_repeat_days: u32,
+    repeat_tolerance_km: f64,
+) -> PyResult<NodalAnalysis> {
+    let (times, gcrs) = ephemeris_states(ephemeris)?;
+    let nodes = ascending_nodes(&times, &tod_states(&times, &gcrs));
+    analyze_nodes(nodes, max_repeat_days, repeat_tolerance_km)
+}
+
+fn rad_per_s_to_deg_per_day(rate: f64) -> f64 {
+    rate.to_degrees() * SECONDS_PER_DAY
+}
+
+#[pymethods]
+impl NodalAnalysis {
+    /// Ascending node crossing times
+    #[getter]
+    fn node_times(&self, py: Python) -> PyResult<Vec<Py<PyAny>>> {
+        self.nodes
+            .iter()
+            .map(|node| utc_to_python_datetime(py, &node.time))
+            .collect()
+    }
+
+    /// Right ascension of the ascending node at each crossing, true equator
+    /// and equinox of date (degrees)
+    #[getter]
+    fn raan_deg(&self, py: Python) -> Py<PyAny> {
+        let raan: Vec<f64> = self.nodes.iter().map(|n| n.raan.to_degrees()).collect();
+        raan.into_pyarray(py).into()
+    }
+
+    /// Inclination to the true equator of date at each crossing (degrees)
+    #[getter]
+    fn inclination_deg(&self, py: Python) -> Py<PyAny> {
+        let inclination: Vec<f64> = self
+            .nodes
+            .iter()
+            .map(|n| n.inclination.to_degrees())
+            .collect();
+        inclination.into_pyarray(py).into()
+    }
+
+    /// Duration of each revolution between consecutive crossings (s)
+    #[getter]
+    fn nodal_periods(&self, py: Python) -> Py<PyAny> {
+        self.nodal_periods.clone().into_pyarray(py).into()
+    }
+
+    /// Mean nodal period over the ephemeris (s)
+    #[getter]
+    fn mean_nodal_period(&self) -> f64 {
+        self.mean_nodal_period
+    }
+
+    /// Fitted RAAN drift rate (degrees per day)
+    #[getter]
+    fn raan_drift_deg_per_day(&self) -> f64 {
+        rad_per_s_to_deg_per_day(self.raan_drift)
+    }
+
+    /// RAAN drift minus the sun-synchronous rate (degrees per day)
+    #[getter]
+    fn sun_synchronous_offset_deg_per_day(&self) -> f64 {
+        rad_per_s_to_deg_per_day(self.raan_drift) - SUN_SYNCHRONOUS_RATE_DEG_PER_DAY
+    }
+
+    /// Length of a nodal day, one Earth turn relative to the node line (s)
+    #[getter]
+    fn nodal_day(&self) -> f64 {
+        nodal_day(self.raan_drift)
+    }
+
+    /// Revolutions per nodal day
+    #[getter]
+    fn revolutions_per_day(&self) -> f64 {
+        nodal_day(self.raan_drift) / self.mean_nodal_period
+    }
+
+    /// Nodal days in the repeat ground-track cycle, or None if none was found
+    #[getter]
+    fn repeat_cycle_days(&self) -> Option<u32> {
+        self.repeat.map(|r| r.days)
+    }
+
+    /// Revolutions in the repeat ground-track cycle, or None if none was found
+    #[getter]
+    fn repeat_cycle_revolutions(&self) -> Option<u32> {
+        self.repeat.map(|r| r.revolutions)
+    }
+
+    /// Eastward shift of the ascending node at the equator after one repeat
+    /// cycle (km), or None if none was found
+    #[getter]
+    fn repeat_shift_km(&self) -> Option<f64> {
+        self.repeat.map(|r| r.shift_km)
+    }
+
+    fn __len__(&self) -> usize {
+        self.nodes.len()
+    }
+
+    fn __repr__(&self) -> String {
+        let repeat = match self.repeat {
+            Some(r) => format!("{} days/{} revolutions", r.days, r.revolutions),
+            None => "None".to_string(),
+        };
+        format!(
+            "NodalAnalysis(nodes={}, mean_nodal_period={:.1} s, raan_drift={:.4} deg/day, repeat={})",
+            self.nodes.len(),
+            self.mean_nodal_period,
+            rad_per_s_to_deg_per_day(self.raan_drift),
+            repeat
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ephemeris::maneuver_detection::propagate;
+    use crate::utils::config::GM_EARTH;
+    use chrono::{Duration, TimeZone};
+
+    /// Two-body plus J2 history of a circular orbit, sampled every minute
+    fn history(a: f64, inclination_deg: f64, hours: i64) -> (Vec<DateTime<Utc>>, Array2<f64>) {
+        let start = Utc.with_ymd_and_hms(2024, 1, 1, 0, 0, 0).unwrap();
+        let v = (GM_EARTH / a).sqrt();
+        let incl = inclination_deg.to_radians();
+        // Start just south of the equator so the first node is in the data
+        let mut state = [a, 0.0, -10.0, 0.0, v * incl.cos(), v * incl.sin()];
+        let n = (hours * 60) as usize;
+        let mut states = Array2::zeros((n, 6));
+        let mut times = Vec::with_capacity(n);
+        for i in 0..n {
+            times.push(start + Duration::minutes(i as i64));
+            for (k, value) in state.iter().enumerate() {
+                states[[i, k]] = *value;
+            }
+            state = propagate(&state, 60.0);
+        }
+        (times, states)
+    }
+
+    #[test]
+    fn test_crossing_fraction() {
+        // Straight line through zero at a quarter of the interval
+        let s = crossing_fraction(-1.0, 4.0 / 60.0, 3.0, 4.0 / 60.0, 60.0);
+        assert!((s - 0.25).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_sun_synchronous_drift() {
+        // 700 km at 98.19 deg precesses eastward with the mean Sun
+        let (times, states) = history(7078.137, 98.19, 48);
+        let analysis = analyze_nodes(ascending_nodes(&times, &states), 30, 10.0).unwrap();
+        assert!(analysis.nodes.len() >= 28);
+        let drift = rad_per_s_to_deg_per_day(analysis.raan_drift);
+        assert!(
+            (drift - SUN_SYNCHRONOUS_RATE_DEG_PER_DAY).abs() < 0.02,
+            "drift = {drift}"
+        );
+        assert!((analysis.nodes[0].inclination.to_degrees() - 98.19).abs() < 0.05);
+        let period = analysis.mean_nodal_period;
+        assert!(analysis
+            .nodal_periods
+            .iter()
+            .all(|p| (p - period).abs() < 1.0));
+    }
+
+    #[test]
+    fn test_repeat_cycle() {
+        // 233 revolutions in 16 nodal days, as for Landsat
+        let drift = (SUN_SYNCHRONOUS_RATE_DEG_PER_DAY / SECONDS_PER_DAY).to_radians();
+        let period = 16.0 * nodal_day(drift) / 233.0;
+        let cycle = repeat_cycle(period, drift, 30, 1.0).unwrap();
+        assert_eq!((cycle.days, cycle.revolutions), (16, 233));
+        assert!(cycle.shift_km.abs() < 1e-6);
+        assert!(repeat_cycle(period, drift, 15, 1.0).is_none());
+        // A slightly longer period leaves the node west of its start
+        let late = repeat_cycle(period + 0.01, drift, 30, 10.0).unwrap();
+        assert!(late.shift_km < 0.0);
+    }
+
+    #[test]
+    fn test_too_few_nodes() {
+        let (times, states) = history(7000.0, 51.6, 1);
+        let nodes = ascending_nodes(&times, &states);
+        assert!(analyze_nodes(nodes, 30, 10.0).is_err());
+    }
+}
@@ -0,0 +1,248 @@
+//! Observation geometry of one target at one ephemeris time
+//!
+//! Backs `geometry(ra_deg, dec_deg, time)` on the ephemeris classes: a
+//! snapshot of the quantities a planner checks first (Sun, Moon and Earth
+//! limb separations, altitude and airmass, eclipse and SAA flags, orbital
+//! phase and beta angle) without building and running constraints. Values
+//! are taken at the ephemeris time step nearest to the requested time.
+//!
+//! Altitude, azimuth and airmass only apply to ground sites, and the
+//! eclipse flag, orbital phase and beta angle only to orbiting observers;
+//! the others are `None`.
+
+use chrono::{DateTime, Utc};
+use ndarray::s;
+use pyo3::prelude::*;
+use pyo3::types::PyDict;
+
+use crate::constraints::eclipse::{illumination_factor, in_earth_shadow};
+use crate::constraints::orbit_visibility::orbit_angles;
+use crate::ephemeris::ephemeris_common::EphemerisBase;
+use crate::utils::polygon;
+use crate::utils::time_utils::utc_to_python_datetime;
+use crate::utils::vector_math::{cross_product, dot_product, normalize_vector};
+
+/// Geometry of a target seen from the observer at one time step
+#[pyclass]
+#[derive(Clone)]
+pub struct ObservationGeometry {
+    /// Position of the time step within the ephemeris
+    #[pyo3(get)]
+    index: usize,
+    time_internal: DateTime<Utc>,
+    /// Target right ascension in degrees
+    #[pyo3(get)]
+    ra_deg: f64,
+    /// Target declination in degrees
+    #[pyo3(get)]
+    dec_deg: f64,
+    /// Angle between the target and the Sun in degrees
+    #[pyo3(get)]
+    sun_angle: f64,
+    /// Angle between the target and the Moon in degrees
+    #[pyo3(get)]
+    moon_angle: f64,
+    /// Angle of the target above the Earth's limb in degrees (negative behind it)
+    #[pyo3(get)]
+    earth_limb_angle: f64,
+    /// Target altitude above the horizon in degrees (ground sites)
+    #[pyo3(get)]
+    altitude_deg: Option<f64>,
+    /// Target azimuth in degrees, east of north (ground sites)
+    #[pyo3(get)]
+    azimuth_deg: Option<f64>,
+    /// Target airmass, infinite below the horizon (ground sites)
+    #[pyo3(get)]
+    airmass: Option<f64>,
+    /// Whether any of the solar disk is hidden by the Earth (orbiting observers)
+    #[pyo3(get)]
+    in_eclipse: Option<bool>,
+    /// Fraction of the solar disk visible past the Earth (orbiting observers)
+    #[pyo3(get)]
+    sunlit_fraction: Option<f64>,
+    /// Whether the observer is inside `saa_polygon`, if one was given
+    #[pyo3(get)]
+    in_saa: Option<bool>,
+    /// Angle in degrees from the ascending node in the direction of motion
+    #[pyo3(get)]
+    argument_of_latitude: Option<f64>,
+    /// Angle in degrees from orbit noon in the direction of motion
+    #[pyo3(get)]
+    solar_phase: Option<f64>,
+    /// Angle in degrees between the Sun direction and the orbit plane
+    #[pyo3(get)]
+    beta_angle: Option<f64>,
+}
+
+#[pymethods]
+impl ObservationGeometry {
+    /// Ephemeris time of the snapshot (the step nearest the requested time)
+    #[getter]
+    fn time(&self, py: Python) -> PyResult<Py<PyAny>> {
+        utc_to_python_datetime(py, &self.time_internal)
+    }
+
+    /// All fields as a dict
+    fn to_dict<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyDict>> {
+        let dict = PyDict::new(py);
+        dict.set_item("index", self.index)?;
+        dict.set_item("time", self.time(py)?)?;
+        dict.set_item("ra_deg", self.ra_deg)?;
+        dict.set_item("dec_deg", self.dec_deg)?;
+        dict.set_item("sun_angle", self.sun_angle)?;
+        dict.set_item("moon_angle", self.moon_angle)?;
+        dict.set_item("earth_limb_angle", self.earth_limb_angle)?;
+        dict.set_item("altitude_deg", self.altitude_deg)?;
+        dict.set_item("azimuth_deg", self.azimuth_deg)?;
+        dict.set_item("airmass", self.airmass)?;
+        dict.set_item("in_eclipse", self.in_eclipse)?;
+        dict.set_item("sunlit_fraction", self.sunlit_fraction)?;
+        dict.set_item("in_saa", self.in_saa)?;
+        dict.set_item("argument_of_latitude", self.argument_of_latitude)?;
+        dict.set_item("solar_phase", self.solar_phase)?;
+        dict.set_item("beta_angle", self.beta_angle)?;
+        Ok(dict)
+    }
+
+    fn __repr__(&self) -> String {
+        let mut extra = String::new();
+        if let Some(altitude) = self.altitude_deg {
+            extra.push_str(&format!(", altitude_deg={:.2}", altitude));
+        }
+        if let Some(in_eclipse) = self.in_eclipse {
+            extra.push_str(&format!(", in_eclipse={}", in_eclipse));
+        }
+        if let Some(beta) = self.beta_angle {
+            extra.push_str(&format!(", beta_angle={:.2}", beta));
+        }
+        format!(
+            "ObservationGeometry(time='{}', sun_angle={:.2}, moon_angle={:.2}, earth_limb_angle={:.2}{})",
+            self.time_internal.to_rfc3339(),
+            self.sun_angle,
+            self.moon_angle,
+            self.earth_limb_angle,
+            extra
+        )
+    }
+}
+
+/// Angle in degrees between the geocentric Sun direction and the plane of a
+/// GCRS state (km, km/s)
+fn beta_angle(state: &[f64; 6], sun: &[f64; 3]) -> f64 {
+    let normal = normalize_vector(&cross_product(
+        &[state[0], state[1], state[2]],
+        &[state[3], state[4], state[5]],
+    ));
+    dot_product(&normalize_vector(sun), &normal)
+        .clamp(-1.0, 1.0)
+        .asin()
+        .to_degrees()
+}
+
+/// `geometry` for ephemeris classes: the snapshot at time step `index`
+pub(crate) fn observation_geometry(
+    ephem: &dyn EphemerisBase,
+    ra_deg: f64,
+    dec_deg: f64,
+    index: usize,
+    saa_polygon: Option<&[(f64, f64)]>,
+) -> PyResult<ObservationGeometry> {
+    if saa_polygon.is_some_and(|polygon| polygon.len() < 3) {
+        return Err(pyo3::exceptions::PyValueError::new_err(
+            "saa_polygon must have at least 3 vertices",
+        ));
+    }
+    let times = ephem.get_times()?;
+    let step = [index];
+    let at = Some(&step[..]);
+
+    let sun_angle = ephem.sun_angle(ra_deg, dec_deg, at)?[0];
+    let moon_angle = ephem.moon_angle(ra_deg, dec_deg, at)?[0];
+    let earth_limb_angle = ephem.earth_limb_angle(ra_deg, dec_deg, at)?[0];
+
+    let in_saa = match saa_polygon {
+        Some(vertices) => ephem.geodetic()?.map(|geodetic| {
+            polygon::point_in_polygon(
+                vertices,
+                geodetic.longitude_deg[index],
+                geodetic.latitude_deg[index],
+            )
+        }),
+        None => None,
+    };
+
+    let mut geometry = ObservationGeometry {
+        index,
+        time_internal: times[index],
+        ra_deg,
+        dec_deg,
+        sun_angle,
+        moon_angle,
+        earth_limb_angle,
+        altitude_deg: None,
+        azimuth_deg: None,
+        airmass: None,
+        in_eclipse: None,
+        sunlit_fraction: None,
+        in_saa,
+        argument_of_latitude: None,
+        solar_phase: None,
+        beta_angle: None,
+    };
+
+    if ephem.is_ground_site() {
+        let altaz = ephem.radec_to_altaz(ra_deg, dec_deg, at);
+        geometry.altitude_deg = Some(altaz[[0, 0]]);
+        geometry.azimuth_deg = Some(altaz[[0, 1]]);
+        geometry.airmass = Some(ephem.calculate_airmass(ra_deg, dec_deg, at)?[0]);
+        return Ok(geometry);
+    }
+
+    let states = ephem
+        .data()
+        .gcrs
+        .as_ref()
+        .ok_or_else(|| pyo3::exceptions::PyValueError::new_err("No GCRS data available"))?;
+    let sun = ephem.get_sun_positions()?;
+    let obs_pos = [states[[index, 0]], states[[index, 1]], states[[index, 2]]];
+    let sun_pos = [sun[[index, 0]], sun[[index, 1]], sun[[index, 2]]];
+    geometry.in_eclipse = Some(in_earth_shadow(obs_pos, sun_pos, false));
+    geometry.sunlit_fraction = Some(illumination_factor(obs_pos, sun_pos));
+
+    // Orbital phase needs the velocity as well as the position
+    if states.ncols() >= 6 {
+        let state = states.slice(s![index..index + 1, ..]).to_owned();
+        let (argument_of_latitude, solar_phase) =
+            orbit_angles(&state, &sun.slice(s![index..index + 1, ..]).to_owned());
+        geometry.argument_of_latitude = Some(argument_of_latitude[0]);
+        geometry.solar_phase = Some(solar_phase[0]);
+        let row = [
+            state[[0, 0]],
+            state[[0, 1]],
+            state[[0, 2]],
+            state[[0, 3]],
+            state[[0, 4]],
+            state[[0, 5]],
+        ];
+        geometry.beta_angle = Some(beta_angle(&row, &sun_pos));
+    }
+    Ok(geometry)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_beta_angle() {
+        // Equatorial orbit: the orbit normal is +z
+        let state = [7000.0, 0.0, 0.0, 0.0, 7.5, 0.0];
+        assert!(beta_angle(&state, &[1.5e8, 0.0, 0.0]).abs() < 1e-12);
+        let (s, c) = 23.44_f64.to_radians().sin_cos();
+        let beta = beta_angle(&state, &[1.5e8 * c, 0.0, 1.5e8 * s]);
+        assert!((beta - 23.44).abs() < 1e-9);
+        // Retrograde motion flips the normal and the sign
+        let retrograde = [7000.0, 0.0, 0.0, 0.0, -7.5, 0.0];
+        assert!((beta_angle(&retrograde, &[1.5e8 * c, 0.0, 1.5e8 * s]) + 23.44).abs() < 1e-9);
+    }
+}
@@ -0,0 +1,486 @@
+//! Orbit decay and re-entry estimation for LEO end-of-life planning
+//!
+//! The orbit is treated as circular at the altitude implied by the mean
+//! motion and shrinks under drag as `da/dt = -ρ √(μa) / B`, with `B = m/(Cd A)`
+//! the ballistic coefficient. Density comes from the simple exponential model
+//! of the IPS Radio and Space Services ("Satellite Orbital Decay
+//! Calculations"), whose scale height grows with exospheric temperature:
+//!
+//! ```text
+//! T = 930 + 3.6 (F10.7 - 70) + 1.5 Ap      (K)
+//! M = 27 - 0.012 (h - 200)                  (molecular mass, 180 < h < 500 km)
+//! ρ = 3e-10 exp(-(h - 175) M / T)           (kg/m³)
+//! ```
+//!
+//! The temperature and density constants are refitted to Jacchia (1971) with
+//! a global-mean exospheric temperature, which they match to within 30%
+//! between 250 and 500 km for F10.7 of 70-250. IPS's original constants
+//! (900 + 2.5 (F10.7 - 70), 6e-10) halve the density change between solar
+//! minimum and maximum. Below 250 km the model runs low, by up to 40% at
+//! 200 km, which only affects the last day or so of a decay.
+//!
+//! F10.7 (81-day centred average when available) and daily Ap come from the
+//! Celestrak space-weather data, so NOAA's long-range predictions drive the
+//! forecast; dates outside the data use mean solar-cycle values. The model is
+//! calibrated for 180-500 km and is a planning estimate: expect lifetimes to
+//! be uncertain by a factor of two or so, mostly through solar activity.
+
+use chrono::{DateTime, Duration, NaiveDateTime, TimeZone, Utc};
+use numpy::IntoPyArray;
+use pyo3::prelude::*;
+use sgp4::parse_2les;
+
+use crate::ephemeris::tle_accuracy::tle_from_python;
+use crate::utils::config::{EARTH_RADIUS_KM, GM_EARTH, SECONDS_PER_DAY};
+use crate::utils::space_weather::{space_weather_data, SpaceWeatherData};
+use crate::utils::time_utils::utc_to_python_datetime;
+
+/// Reference density of the BSTAR drag term, kg/(m² · Earth radius)
+const BSTAR_REFERENCE_DENSITY: f64 = 0.157;
+
+/// F10.7 (sfu) used where there is no space-weather data: a solar-cycle mean
+pub const DEFAULT_F107: f64 = 150.0;
+
+/// Daily Ap used where there is no space-weather data
+pub const DEFAULT_AP: f64 = 15.0;
+
+/// Largest eccentricity for which the circular-orbit model is used
+const MAX_ECCENTRICITY: f64 = 0.1;
+
+/// Longest integration step (days)
+const MAX_STEP_DAYS: f64 = 1.0;
+
+/// Shortest integration step (days)
+const MIN_STEP_DAYS: f64 = 1.0 / 1440.0;
+
+/// Altitude change allowed per step (km)
+const STEP_ALTITUDE_KM: f64 = 1.0;
+
+/// Mean elements needed for the decay model
+#[derive(Debug, Clone, PartialEq)]
+pub struct DecayElements {
+    pub epoch: DateTime<Utc>,
+    /// Mean motion in revolutions per day
+    pub mean_motion: f64,
+    pub eccentricity: f64,
+    /// SGP4 drag term in 1/Earth radii
+    pub bstar: f64,
+}
+
+impl DecayElements {
+    /// Mean altitude above the equatorial radius (km)
+    fn altitude_km(&self) -> f64 {
+        let n = self.mean_motion * 2.0 * std::f64::consts::PI / SECONDS_PER_DAY;
+        (GM_EARTH / (n * n)).cbrt() - EARTH_RADIUS_KM
+    }
+}
+
+/// Ballistic coefficient `m/(Cd A)` in kg/m² implied by a BSTAR drag term
+pub fn ballistic_coefficient_from_bstar(bstar: f64) -> f64 {
+    BSTAR_REFERENCE_DENSITY / (2.0 * bstar)
+}
+
+/// Atmospheric density (kg/m³) at `altitude_km` for the given solar and geomagnetic activity
+pub fn density(altitude_km: f64, f107: f64, ap: f64) -> f64 {
+    let temperature = 930.0 + 3.6 * (f107 - 70.0) + 1.5 * ap;
+    let molecular_mass = 27.0 - 0.012 * (altitude_km - 200.0);
+    let scale_height = temperature / molecular_mass;
+    3e-10 * (-(altitude_km - 175.0) / scale_height).exp()
+}
+
+/// Solar and geomagnetic activity for the decay integration
+///
+/// Values left as None are taken from the space-weather data.
+pub struct Activity {
+    /// Fixed F10.7 (sfu)
+    pub f107: Option<f64>,
+    /// Fixed daily Ap
+    pub ap: Option<f64>,
+}
+
+impl Activity {
+    /// F10.7 and daily Ap in effect at `time`
+    fn at(&self, data: Option<&SpaceWeatherData>, time: &DateTime<Utc>) -> (f64, f64) {
+        let sample = data.and_then(|d| d.at(time));
+        let f107 = self.f107.unwrap_or_else(|| {
+            sample
+                .as_ref()
+                .and_then(|s| s.f107_obs_center81.or(s.f107_obs))
+                .unwrap_or(DEFAULT_F107)
+        });
+        let ap = self.ap.unwrap_or_else(|| {
+            sample
+                .as_ref()
+                .and_then(|s| s.ap_daily)
+                .unwrap_or(DEFAULT_AP)
+        });
+        (f107, ap)
+    }
+}
+
+/// Estimated altitude history and re-entry of a decaying orbit
+#[pyclass]
+pub struct DecayEstimate {
+    epoch: DateTime<Utc>,
+    ballistic_coefficient: f64,
+    reentry_altitude_km: f64,
+    times: Vec<DateTime<Utc>>,
+    altitude_km: Vec<f64>,
+    /// Altitude lost per day at each sample (km/day)
+    decay_rate: Vec<f64>,
+    f107: Vec<f64>,
+    ap: Vec<f64>,
+    reentry: Option<DateTime<Utc>>,
+}
+
+/// Altitude loss rate (km/day) of a circular orbit
+fn decay_rate_km_per_day(altitude_km: f64, ballistic_coefficient: f64, f107: f64, ap: f64) -> f64 {
+    let a_m = (EARTH_RADIUS_KM + altitude_km) * 1e3;
+    let rate_m_s =
+        density(altitude_km, f107, ap) * (GM_EARTH * 1e9 * a_m).sqrt() / ballistic_coefficient;
+    rate_m_s * SECONDS_PER_DAY / 1e3
+}
+
+/// Integrate the decay of `elements` until re-entry or `max_days`
+///
+/// The ballistic coefficient (kg/m²) defaults to the one implied by BSTAR.
+/// Steps are up to a day long and shrink so that the altitude changes by at
+/// most a kilometre per step; the re-entry time is interpolated within the
+/// step that crosses `reentry_altitude_km`.
+pub fn estimate_decay(
+    elements: &DecayElements,
+    ballistic_coefficient: Option<f64>,
+    reentry_altitude_km: f64,
+    max_days: f64,
+    activity: &Activity,
+) -> PyResult<DecayEstimate> {
+    let positive = |x: f64| x.is_finite() && x > 0.0;
+    if !positive(reentry_altitude_km) || !positive(max_days) {
+        return Err(pyo3::exceptions::PyValueError::new_err(
+            "reentry_altitude_km and max_days must be positive",
+        ));
+    }
+    if activity.f107.is_some_and(|f| !positive(f))
+        || activity.ap.is_some_and(|a| a.is_nan() || a < 0.0)
+    {
+        return Err(pyo3::exceptions::PyValueError::new_err(
+            "f107 must be positive and ap non-negative",
+        ));
+    }
+    if elements.eccentricity > MAX_ECCENTRICITY {
+        return Err(pyo3::exceptions::PyValueError::new_err(format!(
+            "Decay estimation assumes a near-circular orbit (eccentricity {:.3} > {})",
+            elements.eccentricity, MAX_ECCENTRICITY
+        )));
+    }
+    let ballistic_coefficient = match ballistic_coefficient {
+        Some(bc) if positive(bc) => bc,
+        Some(_) => {
+            return Err(pyo3::exceptions::PyValueError::new_err(
+                "ballistic_coefficient must be positive",
+            ))
+        }
+        None if elements.bstar > 0.0 => ballistic_coefficient_from_bstar(elements.bstar),
+        None => {
+            return Err(pyo3::exceptions::PyValueError::new_err(
+                "BSTAR is not positive; provide ballistic_coefficient",
+            ))
+        }
+    };
+    let mut altitude = elements.altitude_km();
+    if altitude <= reentry_altitude_km {
+        return Err(pyo3::exceptions::PyValueError::new_err(format!(
+            "Mean altitude {:.1} km is already below reentry_altitude_km",
+            altitude
+        )));
+    }
+
+    let mut estimate = DecayEstimate {
+        epoch: elements.epoch,
+        ballistic_coefficient,
+        reentry_altitude_km,
+        times: Vec::new(),
+        altitude_km: Vec::new(),
+        decay_rate: Vec::new(),
+        f107: Vec::new(),
+        ap: Vec::new(),
+        reentry: None,
+    };
+    let data = match (activity.f107, activity.ap) {
+        (Some(_), Some(_)) => None,
+        _ => space_weather_data(),
+    };
+    let mut elapsed_days = 0.0;
+    loop {
+        let time = elements.epoch + days(elapsed_days);
+        let (f107, ap) = activity.at(data.as_deref(), &time);
+        let rate = decay_rate_km_per_day(altitude, ballistic_coefficient, f107, ap);
+        estimate.times.push(time);
+        estimate.altitude_km.push(altitude);
+        estimate.decay_rate.push(rate);
+        estimate.f107.push(f107);
+        estimate.ap.push(ap);
+
+        if elapsed_days >= max_days {
+            break;
+        }
+        let step = (STEP_ALTITUDE_KM / rate)
+            .clamp(MIN_STEP_DAYS, MAX_STEP_DAYS)
+            .min(max_days - elapsed_days);
+        let next_altitude = altitude - rate * step;
+        if next_altitude <= reentry_altitude_km {
+            let fraction = (altitude - reentry_altitude_km) / (altitude - next_altitude);
+            estimate.reentry = Some(elements.epoch + days(elapsed_days + fraction * step));
+            break;
+        }
+        altitude = next_altitude;
+        elapsed_days += step;
+    }
+    Ok(estimate)
+}
+
+fn days(d: f64) -> Duration {
+    Duration::milliseconds((d * 86_400_000.0).round() as i64)
+}
+
+/// Parse a CCSDS OMM epoch such as `2024-05-10T12:34:56.789`
+fn parse_omm_epoch(epoch: &str) -> PyResult<DateTime<Utc>> {
+    let trimmed = epoch.trim().trim_end_matches('Z');
+    NaiveDateTime::parse_from_str(trimmed, "%Y-%m-%dT%H:%M:%S%.f")
+        .map(|naive| Utc.from_utc_datetime(&naive))
+        .map_err(|e| {
+            pyo3::exceptions::PyValueError::new_err(format!("Invalid OMM EPOCH '{}': {}", epoch, e))
+        })
+}
+
+/// A numeric OMM field, given as a number or a string (as Space-Track does)
+fn omm_number(omm: &Bound<'_, PyAny>, key: &str) -> PyResult<f64> {
+    let value = omm
+        .get_item(key)
+        .map_err(|_| pyo3::exceptions::PyValueError::new_err(format!("OMM is missing {}", key)))?;
+    if let Ok(number) = value.extract::<f64>() {
+        return Ok(number);
+    }
+    value
+        .extract::<String>()?
+        .trim()
+        .parse::<f64>()
+        .map_err(|e| pyo3::exceptions::PyValueError::new_err(format!("Invalid OMM {}: {}", key, e)))
+}
+
+/// Mean elements from a TLE (any form accepted by `estimate_tle_accuracy`) or
+/// an OMM mapping with CCSDS keys (`EPOCH`, `MEAN_MOTION`, `ECCENTRICITY`, `BSTAR`)
+pub fn decay_elements_from_python(tle: &Bound<'_, PyAny>) -> PyResult<DecayElements> {
+    if tle.get_item("MEAN_MOTION").is_ok() {
+        let epoch = tle
+            .get_item("EPOCH")
+            .map_err(|_| pyo3::exceptions::PyValueError::new_err("OMM is missing EPOCH"))?;
+        return Ok(DecayElements {
+            epoch: parse_omm_epoch(&epoch.extract::<String>()?)?,
+            mean_motion: omm_number(tle, "MEAN_MOTION")?,
+            eccentricity: omm_number(tle, "ECCENTRICITY")?,
+            bstar: omm_number(tle, "BSTAR")?,
+        });
+    }
+    let data = tle_from_python(tle)?;
+    let elements = parse_2les(&format!("{}\n{}", data.line1, data.line2))
+        .map_err(|e| pyo3::exceptions::PyValueError::new_err(format!("TLE parse error: {e:?}")))?
+        .into_iter()
+        .next()
+        .ok_or_else(|| pyo3::exceptions::PyValueError::new_err("No elements parsed from TLE"))?;
+    Ok(DecayElements {
+        epoch: data.epoch,
+        mean_motion: elements.mean_motion,
+        eccentricity: elements.eccentricity,
+        bstar: elements.drag_term,
+    })
+}
+
+#[pymethods]
+impl DecayEstimate {
+    /// Epoch of the input elements
+    #[getter]
+    fn epoch(&self, py: Python) -> PyResult<Py<PyAny>> {
+        utc_to_python_datetime(py, &self.epoch)
+    }
+
+    /// Ballistic coefficient m/(Cd A) used, in kg/m²
+    #[getter]
+    fn ballistic_coefficient(&self) -> f64 {
+        self.ballistic_coefficient
+    }
+
+    /// Altitude at which the object is considered re-entered (km)
+    #[getter]
+    fn reentry_altitude_km(&self) -> f64 {
+        self.reentry_altitude_km
+    }
+
+    /// Sample times of the integration, as a list of datetimes
+    #[getter]
+    fn times(&self, py: Python) -> PyResult<Vec<Py<PyAny>>> {
+        self.times
+            .iter()
+            .map(|time| utc_to_python_datetime(py, time))
+            .collect()
+    }
+
+    /// Mean altitude above the equatorial radius at each sample (km)
+    #[getter]
+    fn altitude_km(&self, py: Python) -> Py<PyAny> {
+        self.altitude_km.clone().into_pyarray(py).into()
+    }
+
+    /// Altitude lost per day at each sample (km/day)
+    #[getter]
+    fn decay_rate_km_per_day(&self, py: Python) -> Py<PyAny> {
+        self.decay_rate.clone().into_pyarray(py).into()
+    }
+
+    /// Altitude loss rate at the epoch (km/day)
+    #[getter]
+    fn initial_decay_rate_km_per_day(&self) -> f64 {
+        self.decay_rate[0]
+    }
+
+    /// F10.7 (sfu) used at each sample
+    #[getter]
+    fn f107(&self, py: Python) -> Py<PyAny> {
+        self.f107.clone().into_pyarray(py).into()
+    }
+
+    /// Daily Ap used at each sample
+    #[getter]
+    fn ap(&self, py: Python) -> Py<PyAny> {
+        self.ap.clone().into_pyarray(py).into()
+    }
+
+    /// Estimated re-entry time, or None if the orbit outlives `max_days`
+    #[getter]
+    fn reentry_time(&self, py: Python) -> PyResult<Option<Py<PyAny>>> {
+        self.reentry
+            .map(|time| utc_to_python_datetime(py, &time))
+            .transpose()
+    }
+
+    /// Days from the epoch to re-entry, or None if the orbit outlives `max_days`
+    #[getter]
+    fn lifetime_days(&self) -> Option<f64> {
+        self.reentry
+            .map(|time| (time - self.epoch).num_milliseconds() as f64 / 86_400_000.0)
+    }
+
+    fn __len__(&self) -> usize {
+        self.times.len()
+    }
+
+    fn __repr__(&self) -> String {
+        match self.lifetime_days() {
+            Some(days) => format!(
+                "DecayEstimate(altitude={:.1} km, lifetime={:.1} days)",
+                self.altitude_km[0], days
+            ),
+            None => format!(
+                "DecayEstimate(altitude={:.1} km, no re-entry within {:.0} days)",
+                self.altitude_km[0],
+                (self.times[self.times.len() - 1] - self.epoch).num_milliseconds() as f64
+                    / 86_400_000.0
+            ),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const QUIET: Activity = Activity {
+        f107: Some(DEFAULT_F107),
+        ap: Some(DEFAULT_AP),
+    };
+
+    fn circular(altitude_km: f64, bstar: f64) -> DecayElements {
+        let a = EARTH_RADIUS_KM + altitude_km;
+        let n = (GM_EARTH / (a * a * a)).sqrt();
+        DecayElements {
+            epoch: Utc.with_ymd_and_hms(2025, 1, 1, 0, 0, 0).unwrap(),
+            mean_motion: n * SECONDS_PER_DAY / (2.0 * std::f64::consts::PI),
+            eccentricity: 0.0005,
+            bstar,
+        }
+    }
+
+    #[test]
+    fn test_density_model() {
+        // Jacchia (1971) at 420 km and Ap = 15, exospheric temperature
+        // 1.15 (379 + 3.24 F10.7) + 86 K: 5.1e-13, 2.4e-12 and 7.0e-12 kg/m³
+        // for F10.7 = 70, 150 and 250
+        for (f107, reference) in [(70.0, 5.1e-13), (150.0, 2.4e-12), (250.0, 7.0e-12)] {
+            let rho = density(420.0, f107, 15.0);
+            assert!((rho / reference - 1.0).abs() < 0.15, "F10.7 {f107}: {rho}");
+        }
+        // Higher solar activity heats and inflates the thermosphere; Jacchia
+        // gives 2.9 times the density for F10.7 = 250 as for 150
+        let ratio = density(420.0, 250.0, 15.0) / density(420.0, 150.0, 15.0);
+        assert!((ratio / 2.9 - 1.0).abs() < 0.1, "ratio = {ratio}");
+        assert!(density(300.0, 150.0, 15.0) > density(400.0, 150.0, 15.0));
+    }
+
+    #[test]
+    fn test_ballistic_coefficient_from_bstar() {
+        // The usual rule of thumb: B = 1 / (12.74 B*)
+        let bc = ballistic_coefficient_from_bstar(1e-4);
+        assert!((bc - 1.0 / (12.74 * 1e-4)).abs() / bc < 1e-3);
+    }
+
+    #[test]
+    fn test_altitude_from_mean_motion() {
+        assert!((circular(400.0, 1e-4).altitude_km() - 400.0).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_low_orbit_reenters() {
+        let elements = circular(250.0, 0.0);
+        let estimate = estimate_decay(&elements, Some(50.0), 180.0, 365.0, &QUIET).unwrap();
+        let reentry = estimate
+            .reentry
+            .expect("250 km with B = 50 kg/m² decays within a year");
+        assert!(reentry > elements.epoch && reentry < elements.epoch + Duration::days(60));
+        // Altitude falls monotonically and the decay accelerates
+        assert!(estimate.altitude_km.windows(2).all(|w| w[1] < w[0]));
+        assert!(estimate.decay_rate.windows(2).all(|w| w[1] > w[0]));
+    }
+
+    #[test]
+    fn test_high_orbit_survives() {
+        let elements = circular(700.0, 1e-5);
+        let estimate = estimate_decay(&elements, None, 180.0, 365.0, &QUIET).unwrap();
+        assert!(estimate.reentry.is_none());
+        let last = estimate.times[estimate.times.len() - 1];
+        assert_eq!(last, elements.epoch + Duration::days(365));
+        assert!(estimate.altitude_km[estimate.altitude_km.len() - 1] < 700.0);
+    }
+
+    #[test]
+    fn test_heavier_objects_last_longer() {
+        let elements = circular(300.0, 0.0);
+        let lifetime = |bc: f64| {
+            let estimate = estimate_decay(&elements, Some(bc), 180.0, 3650.0, &QUIET).unwrap();
+            estimate.reentry.unwrap() - elements.epoch
+        };
+        assert!(lifetime(100.0) > lifetime(50.0));
+    }
+
+    #[test]
+    fn test_invalid_inputs() {
+        let elements = circular(400.0, 0.0);
+        assert!(estimate_decay(&elements, None, 180.0, 365.0, &QUIET).is_err());
+        assert!(estimate_decay(&elements, Some(-1.0), 180.0, 365.0, &QUIET).is_err());
+        assert!(estimate_decay(&elements, Some(50.0), 450.0, 365.0, &QUIET).is_err());
+        let eccentric = DecayElements {
+            eccentricity: 0.3,
+            ..circular(400.0, 1e-4)
+        };
+        assert!(estimate_decay(&eccentric, None, 180.0, 365.0, &QUIET).is_err());
+    }
+}
@@ -1,5 +1,5 @@
-use ndarray::Array2;
-use numpy::{IntoPyArray, PyArray2};
+use ndarray::{Array1, Array2, Axis};
+use numpy::{IntoPyArray, PyArray1, PyArray2};
 use pyo3::prelude::*;
 
 #[pyclass]
@@ -9,6 +9,30 @@ pub struct PositionVelocityData {
     pub velocity: Array2<f64>,
 }
 
+impl PositionVelocityData {
+    /// Element-wise combination with another state set of the same shape
+    fn combine(&self, other: &Self, sign: f64) -> PyResult<Self> {
+        if self.position.dim() != other.position.dim()
+            || self.velocity.dim() != other.velocity.dim()
+        {
+            return Err(pyo3::exceptions::PyValueError::new_err(format!(
+                "PositionVelocityData shapes differ: {:?} and {:?}",
+                self.position.dim(),
+                other.position.dim()
+            )));
+        }
+        Ok(PositionVelocityData {
+            position: &self.position + &(&other.position * sign),
+            velocity: &self.velocity + &(&other.velocity * sign),
+        })
+    }
+
+    /// Euclidean norm of each row
+    fn row_norms(array: &Array2<f64>) -> Array1<f64> {
+        array.map_axis(Axis(1), |row| row.dot(&row).sqrt())
+    }
+}
+
 #[pymethods]
 impl PositionVelocityData {
     #[getter]
@@ -29,4 +53,118 @@ impl PositionVelocityData {
     fn velocity_unit(&self) -> &str {
         "km/s" // kilometers per second
     }
+
+    /// Position in meters
+    #[getter]
+    fn position_m(&self, py: Python) -> Py<PyArray2<f64>> {
+        (&self.position * 1000.0).into_pyarray(py).to_owned().into()
+    }
+
+    /// Velocity in meters per second
+    #[getter]
+    fn velocity_m_s(&self, py: Python) -> Py<PyArray2<f64>> {
+        (&self.velocity * 1000.0).into_pyarray(py).to_owned().into()
+    }
+
+    /// Length of each position vector in km
+    #[getter]
+    fn distance(&self, py: Python) -> Py<PyArray1<f64>> {
+        Self::row_norms(&self.position)
+            .into_pyarray(py)
+            .to_owned()
+            .into()
+    }
+
+    /// Length of each velocity vector in km/s
+    #[getter]
+    fn speed(&self, py: Python) -> Py<PyArray1<f64>> {
+        Self::row_norms(&self.velocity)
+            .into_pyarray(py)
+            .to_owned()
+            .into()
+    }
+
+    /// Distance in km from another state set at each time step
+    fn distance_to(&self, py: Python, other: PyRef<Self>) -> PyResult<Py<PyArray1<f64>>> {
+        let relative = self.combine(&other, -1.0)?;
+        Ok(Self::row_norms(&relative.position)
+            .into_pyarray(py)
+            .to_owned()
+            .into())
+    }
+
+    /// Position with velocity differentials as astropy representations
+    ///
+    /// Returns a `CartesianRepresentation` in km with a `CartesianDifferential`
+    /// in km/s attached under the "s" key.
+    fn to_astropy(&self, py: Python) -> PyResult<Py<PyAny>> {
+        let coords = py.import("astropy.coordinates")?;
+        let units = py.import("astropy.units")?;
+        let km = units.getattr("km")?;
+        let km_per_s = km.call_method1("__truediv__", (units.getattr("s")?,))?;
+
+        let position =
+            km.call_method1("__rmul__", (self.position.t().to_owned().into_pyarray(py),))?;
+        let velocity =
+            km_per_s.call_method1("__rmul__", (self.velocity.t().to_owned().into_pyarray(py),))?;
+
+        let differential = coords
+            .getattr("CartesianDifferential")?
+            .call1((velocity,))?;
+        let kwargs = pyo3::types::PyDict::new(py);
+        kwargs.set_item("differentials", differential)?;
+        Ok(coords
+            .getattr("CartesianRepresentation")?
+            .call((position,), Some(&kwargs))?
+            .unbind())
+    }
+
+    fn __len__(&self) -> usize {
+        self.position.nrows()
+    }
+
+    fn __add__(&self, other: PyRef<Self>) -> PyResult<Self> {
+        self.combine(&other, 1.0)
+    }
+
+    fn __sub__(&self, other: PyRef<Self>) -> PyResult<Self> {
+        self.combine(&other, -1.0)
+    }
+
+    fn __neg__(&self) -> Self {
+        PositionVelocityData {
+            position: -&self.position,
+            velocity: -&self.velocity,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ndarray::array;
+
+    fn state(position: Array2<f64>, velocity: Array2<f64>) -> PositionVelocityData {
+        PositionVelocityData { position, velocity }
+    }
+
+    #[test]
+    fn test_combine_and_norms() {
+        let a = state(array![[3.0, 4.0, 0.0]], array![[0.0, 0.0, 2.0]]);
+        let b = state(array![[1.0, 1.0, 0.0]], array![[0.0, 0.0, 1.0]]);
+
+        let diff = a.combine(&b, -1.0).unwrap();
+        assert_eq!(diff.position, array![[2.0, 3.0, 0.0]]);
+        assert_eq!(diff.velocity, array![[0.0, 0.0, 1.0]]);
+
+        assert_eq!(PositionVelocityData::row_norms(&a.position), array![5.0]);
+        assert_eq!(PositionVelocityData::row_norms(&a.velocity), array![2.0]);
+    }
+
+    #[test]
+    fn test_combine_rejects_shape_mismatch() {
+        let a = state(Array2::zeros((2, 3)), Array2::zeros((2, 3)));
+        let b = state(Array2::zeros((3, 3)), Array2::zeros((3, 3)));
+        assert!(a.combine(&b, 1.0).is_err());
+    }
 }
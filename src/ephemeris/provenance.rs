@@ -0,0 +1,306 @@
+//! Reproducibility records for ephemerides and constraint results
+//!
+//! A provenance record lists everything outside the call arguments that
+//! determines a result: the library version, the ephemeris source and its
+//! fingerprint, the element set, the input and planetary kernel files with
+//! their MD5 digests, and the Earth orientation and leap-second data in use.
+//! It holds no wall-clock time, so the same inputs give the same record and
+//! two planning products can be compared field by field.
+//!
+//! Records are built once per ephemeris, on first request, and shared by the
+//! constraint results evaluated against it. File digests are filled in when
+//! a `Provenance` object is created, so evaluations never read kernels; a
+//! file whose size or modification time changed since then gets no digest.
+
+use std::collections::HashMap;
+use std::io::Read;
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex};
+
+use chrono::{DateTime, Utc};
+use once_cell::sync::Lazy;
+use pyo3::prelude::*;
+use serde_json::{json, Value};
+
+use crate::ephemeris::ephemeris_common::{metadata_timestamp, EphemerisBase};
+use crate::ephemeris::spice_manager::planetary_ephemeris_path;
+use crate::utils::config::{DEFAULT_EOP_PATH, EOP2_URL};
+use crate::utils::eop_provider::{eop_checksum, eop_mjd_range, is_eop_available};
+use crate::utils::json_to_py::json_to_pyobject;
+use crate::utils::time_utils::get_tai_utc_offset;
+use crate::utils::ut1_provider::is_ut1_available;
+
+/// Identifier of provenance JSON documents
+pub const PROVENANCE_FORMAT: &str = "rust_ephem.Provenance";
+
+/// File digests keyed by path, size and modification time, so unchanged
+/// kernels are hashed once per session
+type DigestKey = (PathBuf, u64, String);
+static FILE_DIGESTS: Lazy<Mutex<HashMap<DigestKey, String>>> =
+    Lazy::new(|| Mutex::new(HashMap::new()));
+
+/// Reproducibility record of an ephemeris or constraint result
+#[pyclass]
+#[derive(Clone)]
+pub struct Provenance {
+    record: Value,
+}
+
+impl Provenance {
+    /// The record with file digests filled in
+    pub fn new(record: &Value) -> Self {
+        Self::with_constraint(record, None)
+    }
+
+    /// The record with file digests filled in and the constraint definition
+    /// added, for constraint results
+    pub fn with_constraint(record: &Value, spec_json: Option<&str>) -> Self {
+        let mut record = record.clone();
+        add_file_digests(&mut record);
+        if let (Some(object), Some(spec)) = (record.as_object_mut(), spec_json) {
+            let constraint = serde_json::from_str(spec).unwrap_or(Value::Null);
+            object.insert("constraint".to_string(), constraint);
+        }
+        Self { record }
+    }
+
+    pub fn record(&self) -> &Value {
+        &self.record
+    }
+}
+
+#[pymethods]
+impl Provenance {
+    /// Version of rust_ephem that produced the result
+    #[getter]
+    fn library_version(&self) -> Option<&str> {
+        self.record["library"]["version"].as_str()
+    }
+
+    /// Fingerprint of the ephemeris the result was computed from
+    #[getter]
+    fn fingerprint(&self) -> Option<&str> {
+        self.record["ephemeris"]["fingerprint"].as_str()
+    }
+
+    /// The whole record as nested dicts and lists
+    fn to_dict(&self, py: Python) -> PyResult<Py<PyAny>> {
+        json_to_pyobject(py, &self.record)
+    }
+
+    /// The record as a JSON string, pretty-printed when `indent` is given
+    #[pyo3(signature = (indent=None))]
+    fn to_json(&self, indent: Option<usize>) -> PyResult<String> {
+        let text = match indent {
+            None => serde_json::to_string(&self.record),
+            Some(width) => {
+                let indent = " ".repeat(width);
+                let formatter = serde_json::ser::PrettyFormatter::with_indent(indent.as_bytes());
+                let mut out = Vec::new();
+                let mut serializer = serde_json::Serializer::with_formatter(&mut out, formatter);
+                serde::Serialize::serialize(&self.record, &mut serializer)
+                    .map(|_| String::from_utf8(out).unwrap_or_default())
+            }
+        };
+        text.map_err(|e| pyo3::exceptions::PyValueError::new_err(e.to_string()))
+    }
+
+    /// Read a record written by `to_json`
+    #[staticmethod]
+    fn from_json(text: &str) -> PyResult<Self> {
+        let record: Value = serde_json::from_str(text).map_err(|e| {
+            pyo3::exceptions::PyValueError::new_err(format!("Invalid provenance JSON: {e}"))
+        })?;
+        if record["format"] != PROVENANCE_FORMAT {
+            return Err(pyo3::exceptions::PyValueError::new_err(format!(
+                "Invalid provenance JSON: format must be '{PROVENANCE_FORMAT}'"
+            )));
+        }
+        Ok(Self { record })
+    }
+
+    fn __eq__(&self, other: &Self) -> bool {
+        self.record == other.record
+    }
+
+    fn __repr__(&self) -> String {
+        format!(
+            "Provenance(library_version='{}', ephemeris='{}', fingerprint='{}')",
+            self.library_version().unwrap_or_default(),
+            self.record["ephemeris"]["type"]
+                .as_str()
+                .unwrap_or_default(),
+            self.fingerprint().unwrap_or_default()
+        )
+    }
+}
+
+/// MD5 digest of a file, read in blocks so large kernels are not loaded whole
+fn md5_file(path: &Path) -> std::io::Result<String> {
+    let mut file = std::fs::File::open(path)?;
+    let mut context = md5::Context::new();
+    let mut buffer = vec![0u8; 1 << 20];
+    loop {
+        let n = file.read(&mut buffer)?;
+        if n == 0 {
+            break;
+        }
+        context.consume(&buffer[..n]);
+    }
+    Ok(format!("{:x}", context.compute()))
+}
+
+/// Modification time of a file as an RFC 3339 string
+fn modified_timestamp(meta: &std::fs::Metadata) -> Option<String> {
+    meta.modified()
+        .ok()
+        .map(|modified| metadata_timestamp(&DateTime::<Utc>::from(modified)))
+}
+
+/// Path, size and modification time of a file, or `exists: false`
+fn file_record(path: &Path) -> Value {
+    match std::fs::metadata(path) {
+        Ok(meta) => json!({
+            "path": path.display().to_string(),
+            "exists": true,
+            "size": meta.len(),
+            "modified": modified_timestamp(&meta),
+        }),
+        Err(_) => json!({ "path": path.display().to_string(), "exists": false }),
+    }
+}
+
+/// MD5 digest of the file behind `record`, if it still has the recorded
+/// size and modification time
+fn file_digest(record: &Value) -> Option<String> {
+    let path = PathBuf::from(record["path"].as_str()?);
+    let meta = std::fs::metadata(&path).ok()?;
+    let modified = modified_timestamp(&meta)?;
+    if record["size"].as_u64() != Some(meta.len()) || record["modified"] != modified.as_str() {
+        return None;
+    }
+    let key = (path, meta.len(), modified);
+    if let Some(digest) = FILE_DIGESTS.lock().unwrap().get(&key) {
+        return Some(digest.clone());
+    }
+    let digest = md5_file(&key.0).ok()?;
+    FILE_DIGESTS.lock().unwrap().insert(key, digest.clone());
+    Some(digest)
+}
+
+/// Add `md5` to the file entries of a record that do not have one yet
+fn add_file_digests(record: &mut Value) {
+    if let Some(inputs) = record["input_files"].as_array_mut() {
+        inputs.iter_mut().for_each(add_file_digest);
+    }
+    if record["solar_system"]["kernel"].is_object() {
+        add_file_digest(&mut record["solar_system"]["kernel"]);
+    }
+}
+
+fn add_file_digest(file: &mut Value) {
+    if file["exists"] == true && file.get("md5").is_none() {
+        file["md5"] = file_digest(file).into();
+    }
+}
+
+/// Provenance record of an ephemeris, built on first request and cached
+pub(crate) fn ephemeris_provenance_record(ephem: &dyn EphemerisBase) -> Arc<Value> {
+    ephem
+        .data()
+        .provenance_cache
+        .get_or_init(|| Arc::new(build_record(ephem)))
+        .clone()
+}
+
+/// `provenance` property for ephemeris classes
+pub(crate) fn ephemeris_provenance(ephem: &dyn EphemerisBase) -> Provenance {
+    Provenance::new(&ephemeris_provenance_record(ephem))
+}
+
+fn build_record(ephem: &dyn EphemerisBase) -> Value {
+    let metadata: serde_json::Map<String, Value> = ephem
+        .metadata()
+        .into_iter()
+        .map(|(key, value)| (key.to_string(), value))
+        .collect();
+    let input_files: Vec<Value> = ephem
+        .source_files()
+        .iter()
+        .map(|path| file_record(Path::new(path)))
+        .collect();
+
+    let planetary_kernel = planetary_ephemeris_path().map(|path| file_record(&path));
+    let solar_system = json!({
+        "source": if planetary_kernel.is_some() { "spice" } else { "erfa" },
+        "kernel": planetary_kernel,
+    });
+
+    let earth_orientation = json!({
+        "eop_available": is_eop_available(),
+        "eop_url": EOP2_URL.as_str(),
+        "eop_cache_path": DEFAULT_EOP_PATH.display().to_string(),
+        "eop_md5": eop_checksum(),
+        "eop_mjd_range": eop_mjd_range().map(|(first, last)| vec![first, last]),
+        "ut1_available": is_ut1_available(),
+    });
+
+    // TAI-UTC over the time span identifies the leap-second table in effect
+    let times = ephem.data().times.as_deref().unwrap_or_default();
+    let leap_seconds = json!({
+        "source": "hifitime",
+        "tai_utc_begin": times.first().and_then(get_tai_utc_offset),
+        "tai_utc_end": times.last().and_then(get_tai_utc_offset),
+    });
+
+    json!({
+        "format": PROVENANCE_FORMAT,
+        "library": { "name": "rust_ephem", "version": env!("CARGO_PKG_VERSION") },
+        "ephemeris": metadata,
+        "element_set": ephem.element_set(),
+        "input_files": input_files,
+        "solar_system": solar_system,
+        "earth_orientation": earth_orientation,
+        "leap_seconds": leap_seconds,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_file_digests() {
+        let dir =
+            std::env::temp_dir().join(format!("rust_ephem_provenance_{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        let path = dir.join("kernel.bsp");
+        std::fs::write(&path, b"abc").unwrap();
+        let mut record = json!({ "input_files": [file_record(&path)] });
+        assert_eq!(record["input_files"][0]["size"], 3);
+        assert!(record["input_files"][0].get("md5").is_none());
+        add_file_digests(&mut record);
+        assert_eq!(
+            record["input_files"][0]["md5"],
+            "900150983cd24fb0d6963f7d28e17f72"
+        );
+
+        // A file that changed since the record was made gets no digest
+        let mut stale = json!({ "input_files": [file_record(&path)] });
+        std::fs::write(&path, b"abcd").unwrap();
+        add_file_digests(&mut stale);
+        assert!(stale["input_files"][0]["md5"].is_null());
+        std::fs::remove_dir_all(&dir).unwrap();
+
+        assert_eq!(file_record(&path)["exists"], false);
+    }
+
+    #[test]
+    fn test_with_constraint() {
+        let record = json!({ "format": PROVENANCE_FORMAT });
+        let provenance = Provenance::with_constraint(&record, Some(r#"{"type": "sun"}"#));
+        assert_eq!(provenance.record()["constraint"]["type"], "sun");
+        let provenance = Provenance::with_constraint(&record, None);
+        assert!(provenance.record().get("constraint").is_none());
+    }
+}
@@ -0,0 +1,181 @@
+//! Per-time-step access to ephemeris states
+//!
+//! Backs `len()`, integer indexing and iteration on the ephemeris classes, so
+//! per-step processing in Python does not have to slice several parallel
+//! arrays in lockstep.
+
+use chrono::{DateTime, Utc};
+use ndarray::{s, Array1};
+use numpy::IntoPyArray;
+use pyo3::prelude::*;
+
+use crate::ephemeris::ephemeris_common::EphemerisBase;
+use crate::ephemeris::with_ephemeris;
+use crate::utils::time_utils::utc_to_python_datetime;
+
+/// State of an ephemeris at one time step
+#[pyclass]
+#[derive(Clone)]
+pub struct EphemerisSample {
+    /// Position of the step within the ephemeris
+    #[pyo3(get)]
+    index: usize,
+    time_internal: DateTime<Utc>,
+    gcrs_internal: Array1<f64>,
+    itrs_internal: Option<Array1<f64>>,
+    sun_internal: Option<Array1<f64>>,
+    moon_internal: Option<Array1<f64>>,
+    /// Geodetic latitude of the observer in degrees
+    #[pyo3(get)]
+    latitude_deg: Option<f64>,
+    /// Geodetic longitude of the observer in degrees
+    #[pyo3(get)]
+    longitude_deg: Option<f64>,
+    /// Height of the observer above the WGS84 ellipsoid in km
+    #[pyo3(get)]
+    height_km: Option<f64>,
+}
+
+#[pymethods]
+impl EphemerisSample {
+    #[getter]
+    fn time(&self, py: Python) -> PyResult<Py<PyAny>> {
+        utc_to_python_datetime(py, &self.time_internal)
+    }
+
+    /// Observer GCRS state [x, y, z, vx, vy, vz] in km and km/s
+    #[getter]
+    fn gcrs(&self, py: Python) -> Py<PyAny> {
+        self.gcrs_internal.clone().into_pyarray(py).into()
+    }
+
+    /// Observer ITRS state [x, y, z, vx, vy, vz] in km and km/s
+    #[getter]
+    fn itrs(&self, py: Python) -> Option<Py<PyAny>> {
+        self.itrs_internal
+            .clone()
+            .map(|itrs| itrs.into_pyarray(py).into())
+    }
+
+    /// Geocentric GCRS position of the Sun in km
+    #[getter]
+    fn sun(&self, py: Python) -> Option<Py<PyAny>> {
+        self.sun_internal
+            .clone()
+            .map(|sun| sun.into_pyarray(py).into())
+    }
+
+    /// Geocentric GCRS position of the Moon in km
+    #[getter]
+    fn moon(&self, py: Python) -> Option<Py<PyAny>> {
+        self.moon_internal
+            .clone()
+            .map(|moon| moon.into_pyarray(py).into())
+    }
+
+    fn __repr__(&self) -> String {
+        let geodetic = match (self.latitude_deg, self.longitude_deg, self.height_km) {
+            (Some(lat), Some(lon), Some(height)) => format!(
+                ", latitude_deg={:.4}, longitude_deg={:.4}, height_km={:.3}",
+                lat, lon, height
+            ),
+            _ => String::new(),
+        };
+        format!(
+            "EphemerisSample(index={}, time='{}'{})",
+            self.index,
+            self.time_internal.to_rfc3339(),
+            geodetic
+        )
+    }
+}
+
+/// Iterator over the time steps of an ephemeris
+#[pyclass]
+pub struct EphemerisIterator {
+    ephemeris: Py<PyAny>,
+    index: usize,
+}
+
+impl EphemerisIterator {
+    pub(crate) fn new(ephemeris: Py<PyAny>) -> Self {
+        EphemerisIterator {
+            ephemeris,
+            index: 0,
+        }
+    }
+}
+
+#[pymethods]
+impl EphemerisIterator {
+    fn __iter__(slf: PyRef<'_, Self>) -> PyRef<'_, Self> {
+        slf
+    }
+
+    fn __next__(&mut self, py: Python) -> PyResult<Option<EphemerisSample>> {
+        let index = self.index;
+        let sample = with_ephemeris(self.ephemeris.bind(py), |ephem| {
+            if index < ephemeris_len(ephem) {
+                sample_at(ephem, index).map(Some)
+            } else {
+                Ok(None)
+            }
+        })?;
+        self.index += 1;
+        Ok(sample)
+    }
+}
+
+/// `__len__` for ephemeris classes: the number of time steps
+pub(crate) fn ephemeris_len(ephem: &dyn EphemerisBase) -> usize {
+    ephem.data().times.as_ref().map_or(0, |times| times.len())
+}
+
+/// `__getitem__` for ephemeris classes; negative indices count from the end
+pub(crate) fn ephemeris_getitem(
+    ephem: &dyn EphemerisBase,
+    index: isize,
+) -> PyResult<EphemerisSample> {
+    let len = ephemeris_len(ephem) as isize;
+    let resolved = if index < 0 { index + len } else { index };
+    if !(0..len).contains(&resolved) {
+        return Err(pyo3::exceptions::PyIndexError::new_err(format!(
+            "ephemeris index {} out of range for {} time steps",
+            index, len
+        )));
+    }
+    sample_at(ephem, resolved as usize)
+}
+
+/// Collect the state at time step `index`, which must be in range
+fn sample_at(ephem: &dyn EphemerisBase, index: usize) -> PyResult<EphemerisSample> {
+    let data = ephem.data();
+    let times = data
+        .times
+        .as_ref()
+        .ok_or_else(|| pyo3::exceptions::PyValueError::new_err("No times available"))?;
+    let gcrs = data
+        .gcrs
+        .as_ref()
+        .ok_or_else(|| pyo3::exceptions::PyValueError::new_err("No GCRS data available"))?;
+    // Geodetic coordinates are computed once for all steps and cached
+    ephem.compute_latlon_caches()?;
+
+    Ok(EphemerisSample {
+        index,
+        time_internal: times[index],
+        gcrs_internal: gcrs.row(index).to_owned(),
+        itrs_internal: ephem.get_itrs_data().map(|itrs| itrs.row(index).to_owned()),
+        sun_internal: data
+            .sun_gcrs
+            .as_ref()
+            .map(|sun| sun.slice(s![index, 0..3]).to_owned()),
+        moon_internal: data
+            .moon_gcrs
+            .as_ref()
+            .map(|moon| moon.slice(s![index, 0..3]).to_owned()),
+        latitude_deg: data.latitude_deg_cache.get().map(|lat| lat[index]),
+        longitude_deg: data.longitude_deg_cache.get().map(|lon| lon[index]),
+        height_km: data.height_km_cache.get().map(|height| height[index]),
+    })
+}
@@ -0,0 +1,301 @@
+//! Solar eclipses seen from the observer
+//!
+//! [`solar_obscuration`] gives the fraction of the solar disk hidden by the
+//! Moon at each time step, from the apparent topocentric disks of the two
+//! bodies. [`solar_eclipses`] groups the steps with the Moon over the Sun into
+//! eclipses and classifies each by the most central phase it reaches: total
+//! when the Moon covers the whole disk, annular when it sits wholly inside
+//! it, and partial otherwise.
+//!
+//! Limb irregularities and atmospheric refraction are ignored, so contact
+//! times near the edge of the path of totality are only approximate.
+
+use chrono::{DateTime, Utc};
+use pyo3::prelude::*;
+
+use crate::constraints::eclipse::covered_fraction;
+use crate::ephemeris::ephemeris_common::EphemerisBase;
+use crate::ephemeris::ground_access::sun_itrs_positions;
+use crate::ephemeris::ground_network::{runs, seconds_between};
+use crate::utils::body_properties::{moon_radii, sun_radii};
+use crate::utils::geo::enu_basis;
+use crate::utils::time_utils::utc_to_python_datetime;
+
+/// Phase of a solar eclipse at one instant
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+enum EclipsePhase {
+    None,
+    Partial,
+    Annular,
+    Total,
+}
+
+impl EclipsePhase {
+    fn as_str(self) -> &'static str {
+        match self {
+            EclipsePhase::None => "none",
+            EclipsePhase::Partial => "partial",
+            EclipsePhase::Annular => "annular",
+            EclipsePhase::Total => "total",
+        }
+    }
+}
+
+/// Apparent Sun and Moon disks seen from the observer at one time
+#[derive(Debug, Clone, Copy)]
+struct DiskGeometry {
+    /// Angular radius of the Sun, radians
+    sun: f64,
+    /// Angular radius of the Moon, radians
+    moon: f64,
+    /// Angle between the centres, radians
+    separation: f64,
+}
+
+impl DiskGeometry {
+    fn from_positions(observer: [f64; 3], sun: [f64; 3], moon: [f64; 3]) -> Self {
+        let to_sun = [
+            sun[0] - observer[0],
+            sun[1] - observer[1],
+            sun[2] - observer[2],
+        ];
+        let to_moon = [
+            moon[0] - observer[0],
+            moon[1] - observer[1],
+            moon[2] - observer[2],
+        ];
+        let sun_dist = norm(&to_sun);
+        let moon_dist = norm(&to_moon);
+        let cos_sep = dot(&to_sun, &to_moon) / (sun_dist * moon_dist);
+        DiskGeometry {
+            sun: (sun_radii().equatorial / sun_dist).clamp(-1.0, 1.0).asin(),
+            moon: (moon_radii().equatorial / moon_dist)
+                .clamp(-1.0, 1.0)
+                .asin(),
+            separation: cos_sep.clamp(-1.0, 1.0).acos(),
+        }
+    }
+
+    fn obscuration(&self) -> f64 {
+        covered_fraction(self.sun, self.moon, self.separation)
+    }
+
+    fn phase(&self) -> EclipsePhase {
+        if self.separation >= self.sun + self.moon {
+            EclipsePhase::None
+        } else if self.separation <= self.moon - self.sun {
+            EclipsePhase::Total
+        } else if self.separation <= self.sun - self.moon {
+            EclipsePhase::Annular
+        } else {
+            EclipsePhase::Partial
+        }
+    }
+}
+
+fn dot(a: &[f64; 3], b: &[f64; 3]) -> f64 {
+    a[0] * b[0] + a[1] * b[1] + a[2] * b[2]
+}
+
+fn norm(a: &[f64; 3]) -> f64 {
+    dot(a, a).sqrt()
+}
+
+/// One solar eclipse seen from the observer
+#[pyclass]
+pub struct SolarEclipse {
+    start: DateTime<Utc>,
+    end: DateTime<Utc>,
+    peak: DateTime<Utc>,
+    /// "partial", "annular" or "total": the most central phase reached
+    #[pyo3(get)]
+    kind: &'static str,
+    /// Duration in seconds between the first and last eclipsed samples
+    #[pyo3(get)]
+    duration_seconds: f64,
+    /// Largest fraction of the solar disk area hidden by the Moon
+    #[pyo3(get)]
+    max_obscuration: f64,
+    /// Geometric Sun altitude at `peak_time`, degrees
+    #[pyo3(get)]
+    sun_altitude_deg: f64,
+}
+
+#[pymethods]
+impl SolarEclipse {
+    /// First eclipsed sample
+    #[getter]
+    fn start_time(&self, py: Python) -> PyResult<Py<PyAny>> {
+        utc_to_python_datetime(py, &self.start)
+    }
+
+    /// Last eclipsed sample
+    #[getter]
+    fn end_time(&self, py: Python) -> PyResult<Py<PyAny>> {
+        utc_to_python_datetime(py, &self.end)
+    }
+
+    /// Time of the largest obscuration
+    #[getter]
+    fn peak_time(&self, py: Python) -> PyResult<Py<PyAny>> {
+        utc_to_python_datetime(py, &self.peak)
+    }
+
+    fn __repr__(&self) -> String {
+        format!(
+            "SolarEclipse(kind='{}', peak_time={}, max_obscuration={:.3}, duration_seconds={:.1})",
+            self.kind,
+            self.peak.to_rfc3339(),
+            self.max_obscuration,
+            self.duration_seconds
+        )
+    }
+}
+
+/// Sun and Moon disk geometry at every ephemeris time
+fn disk_geometry(ephemeris: &dyn EphemerisBase) -> PyResult<Vec<DiskGeometry>> {
+    let observer = ephemeris.get_gcrs_positions()?;
+    let sun = ephemeris.get_sun_positions()?;
+    let moon = ephemeris.get_moon_positions()?;
+    Ok((0..observer.nrows())
+        .map(|i| {
+            DiskGeometry::from_positions(
+                [observer[[i, 0]], observer[[i, 1]], observer[[i, 2]]],
+                [sun[[i, 0]], sun[[i, 1]], sun[[i, 2]]],
+                [moon[[i, 0]], moon[[i, 1]], moon[[i, 2]]],
+            )
+        })
+        .collect())
+}
+
+/// Geometric altitude of the Sun's centre above the observer's horizon, degrees
+fn sun_altitudes(ephemeris: &dyn EphemerisBase) -> PyResult<Vec<f64>> {
+    let geodetic = ephemeris
+        .geodetic()?
+        .ok_or_else(|| pyo3::exceptions::PyValueError::new_err("No ITRS positions available"))?;
+    let observer = ephemeris
+        .get_itrs_positions()?
+        .ok_or_else(|| pyo3::exceptions::PyValueError::new_err("No ITRS positions available"))?;
+    let sun = sun_itrs_positions(ephemeris, None, ephemeris.applies_polar_motion())?;
+    Ok((0..observer.nrows())
+        .map(|i| {
+            let up = enu_basis(geodetic.latitude_deg[i], geodetic.longitude_deg[i])[2];
+            let to_sun = [
+                sun[[i, 0]] - observer[[i, 0]],
+                sun[[i, 1]] - observer[[i, 1]],
+                sun[[i, 2]] - observer[[i, 2]],
+            ];
+            (dot(&to_sun, &up) / norm(&to_sun))
+                .clamp(-1.0, 1.0)
+                .asin()
+                .to_degrees()
+        })
+        .collect())
+}
+
+/// Fraction of the solar disk area hidden by the Moon at each time step
+///
+/// Pure geometry: the value does not depend on whether the Sun is above the
+/// observer's horizon.
+pub(crate) fn solar_obscuration(ephemeris: &dyn EphemerisBase) -> PyResult<Vec<f64>> {
+    Ok(disk_geometry(ephemeris)?
+        .iter()
+        .map(DiskGeometry::obscuration)
+        .collect())
+}
+
+/// Solar eclipses over the ephemeris span
+///
+/// An eclipse is a run of samples with the Moon over the solar disk and,
+/// unless `include_below_horizon` is set, the Sun's centre above the horizon.
+/// Runs whose largest obscuration stays below `min_obscuration` are dropped.
+pub(crate) fn solar_eclipses(
+    ephemeris: &dyn EphemerisBase,
+    min_obscuration: f64,
+    include_below_horizon: bool,
+) -> PyResult<Vec<SolarEclipse>> {
+    if !(0.0..=1.0).contains(&min_obscuration) {
+        return Err(pyo3::exceptions::PyValueError::new_err(
+            "min_obscuration must be between 0 and 1",
+        ));
+    }
+    let times = ephemeris.get_times()?;
+    let geometry = disk_geometry(ephemeris)?;
+    let altitudes = sun_altitudes(ephemeris)?;
+
+    let eclipsed: Vec<bool> = geometry
+        .iter()
+        .zip(&altitudes)
+        .map(|(g, &altitude)| {
+            g.phase() != EclipsePhase::None && (include_below_horizon || altitude > 0.0)
+        })
+        .collect();
+
+    Ok(runs(&eclipsed)
+        .into_iter()
+        .filter_map(|(start, end)| {
+            // Ties at full coverage go to the most central sample
+            let peak = (start..=end).max_by(|&a, &b| {
+                geometry[a]
+                    .obscuration()
+                    .total_cmp(&geometry[b].obscuration())
+                    .then(geometry[b].separation.total_cmp(&geometry[a].separation))
+            })?;
+            let max_obscuration = geometry[peak].obscuration();
+            if max_obscuration < min_obscuration {
+                return None;
+            }
+            let phase = (start..=end)
+                .map(|i| geometry[i].phase())
+                .max()
+                .unwrap_or(EclipsePhase::Partial);
+            Some(SolarEclipse {
+                start: times[start],
+                end: times[end],
+                peak: times[peak],
+                kind: phase.as_str(),
+                duration_seconds: seconds_between(&times, (start, end)),
+                max_obscuration,
+                sun_altitude_deg: altitudes[peak],
+            })
+        })
+        .collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const AU: f64 = 1.495978707e8;
+
+    fn geometry_at(offset_km: f64, moon_dist: f64) -> DiskGeometry {
+        // Observer at the origin, Sun along +x and the Moon displaced sideways
+        let sun = [AU, 0.0, 0.0];
+        let moon = [moon_dist, offset_km, 0.0];
+        DiskGeometry::from_positions([0.0, 0.0, 0.0], sun, moon)
+    }
+
+    #[test]
+    fn test_phases() {
+        assert_eq!(geometry_at(0.0, 370_000.0).phase(), EclipsePhase::Total);
+        assert_eq!(geometry_at(0.0, 400_000.0).phase(), EclipsePhase::Annular);
+        assert_eq!(
+            geometry_at(3_000.0, 384_400.0).phase(),
+            EclipsePhase::Partial
+        );
+        assert_eq!(geometry_at(20_000.0, 384_400.0).phase(), EclipsePhase::None);
+    }
+
+    #[test]
+    fn test_obscuration() {
+        assert_eq!(geometry_at(0.0, 370_000.0).obscuration(), 1.0);
+        assert_eq!(geometry_at(20_000.0, 384_400.0).obscuration(), 0.0);
+        let annular = geometry_at(0.0, 400_000.0);
+        let expected = (annular.moon / annular.sun).powi(2);
+        assert!((annular.obscuration() - expected).abs() < 1e-12);
+        // Partial coverage shrinks as the Moon moves off the Sun
+        let near = geometry_at(1_000.0, 384_400.0).obscuration();
+        let far = geometry_at(3_000.0, 384_400.0).obscuration();
+        assert!(near > far && far > 0.0);
+    }
+}
@@ -1,4 +1,4 @@
-use std::path::Path;
+use std::path::{Path, PathBuf};
 use std::sync::{Arc, Mutex};
 
 use anise::prelude::*;
@@ -9,6 +9,9 @@ use url::Url;
 /// This is NOT used for spacecraft-specific ephemeris.
 static PLANETARY_EPHEMERIS: OnceCell<Mutex<Option<Arc<Almanac>>>> = OnceCell::new();
 
+/// Path of the SPK file behind `PLANETARY_EPHEMERIS`, for provenance records
+static PLANETARY_EPHEMERIS_PATH: Mutex<Option<PathBuf>> = Mutex::new(None);
+
 /// Initialize the planetary almanac with the SPK at `path`. If already initialized, replaces it.
 /// This should be used for planetary ephemeris files like de440s.bsp, NOT spacecraft kernels.
 pub fn init_planetary_ephemeris<P: AsRef<Path>>(path: P) -> Result<(), Box<dyn std::error::Error>> {
@@ -24,9 +27,15 @@ pub fn init_planetary_ephemeris<P: AsRef<Path>>(path: P) -> Result<(), Box<dyn s
     let cell = PLANETARY_EPHEMERIS.get_or_init(|| Mutex::new(None));
     let mut guard = cell.lock().unwrap();
     *guard = Some(Arc::new(almanac));
+    *PLANETARY_EPHEMERIS_PATH.lock().unwrap() = Some(path.as_ref().to_path_buf());
     Ok(())
 }
 
+/// Path of the SPK file the planetary almanac was loaded from, if initialized
+pub fn planetary_ephemeris_path() -> Option<PathBuf> {
+    PLANETARY_EPHEMERIS_PATH.lock().unwrap().clone()
+}
+
 /// Try to get a clone of the planetary almanac if initialized
 pub fn get_planetary_ephemeris() -> Option<Arc<Almanac>> {
     PLANETARY_EPHEMERIS
@@ -60,8 +69,7 @@ pub fn download_planetary_ephemeris(
     }
     // Write response body to file
     let mut reader = resp.body_mut().as_reader();
-    let mut file = std::fs::File::create(dest_path)?;
-    std::io::copy(&mut reader, &mut file)?;
+    crate::utils::cache_io::write_atomic_from(dest_path, &mut reader)?;
     Ok(())
 }
 
@@ -0,0 +1,451 @@
+//! Empirical TLE accuracy from consecutive element sets
+//!
+//! Each older TLE in a history is propagated with SGP4 to the epochs of the
+//! newer ones, and the difference from the newer TLE is expressed in its
+//! radial / in-track / cross-track (RIC) frame. Binning these residuals by
+//! prediction age (time since the older TLE's epoch) gives an error-growth
+//! envelope that can be used as a position uncertainty for conjunction or
+//! pointing margins.
+
+use std::collections::HashMap;
+
+use chrono::{DateTime, Utc};
+use ndarray::{Array1, Array2};
+use numpy::IntoPyArray;
+use pyo3::prelude::*;
+use sgp4::{parse_2les, Constants, Elements};
+
+use crate::utils::time_utils::{python_datetime_to_utc, utc_to_python_datetime};
+use crate::utils::tle_utils::{self, TLEData};
+use crate::utils::vector_math::{dot_product, ric_basis};
+
+/// RIC component names, in column order of the residuals
+const COMPONENTS: [&str; 3] = ["radial", "in_track", "cross_track"];
+
+/// Default history span fetched from Space-Track when `begin` is not given
+const DEFAULT_HISTORY_DAYS: i64 = 30;
+
+/// A TLE ready for SGP4 propagation
+struct PreparedTLE {
+    epoch: DateTime<Utc>,
+    elements: Elements,
+    constants: Constants,
+}
+
+impl PreparedTLE {
+    fn new(tle: &TLEData) -> PyResult<Self> {
+        let elements = parse_2les(&format!("{}\n{}", tle.line1, tle.line2))
+            .map_err(|e| {
+                pyo3::exceptions::PyValueError::new_err(format!("TLE parse error: {e:?}"))
+            })?
+            .into_iter()
+            .next()
+            .ok_or_else(|| {
+                pyo3::exceptions::PyValueError::new_err("No elements parsed from TLE")
+            })?;
+        let constants = Constants::from_elements(&elements).map_err(|e| {
+            pyo3::exceptions::PyRuntimeError::new_err(format!("SGP4 constants error: {e:?}"))
+        })?;
+        Ok(PreparedTLE {
+            epoch: tle.epoch,
+            elements,
+            constants,
+        })
+    }
+
+    /// TEME position and velocity (km, km/s) at `time`
+    fn state_at(&self, time: &DateTime<Utc>) -> PyResult<([f64; 3], [f64; 3])> {
+        let minutes = self
+            .elements
+            .datetime_to_minutes_since_epoch(&time.naive_utc())
+            .map_err(|e| {
+                pyo3::exceptions::PyValueError::new_err(format!("Invalid propagation time: {e:?}"))
+            })?;
+        let prediction = self.constants.propagate(minutes).map_err(|e| {
+            pyo3::exceptions::PyRuntimeError::new_err(format!("Propagation error: {e:?}"))
+        })?;
+        Ok((prediction.position, prediction.velocity))
+    }
+}
+
+/// Error-growth statistics from cross-propagating a TLE history
+#[pyclass]
+pub struct TLEAccuracy {
+    epochs: Vec<DateTime<Utc>>,
+    /// Prediction age of each residual (days)
+    ages: Array1<f64>,
+    /// Residuals (older TLE - newer TLE) as [radial, in-track, cross-track] in km
+    residuals: Array2<f64>,
+    bin_days: f64,
+    n_bins: usize,
+}
+
+/// Cross-propagate a TLE history
+///
+/// TLEs are sorted by epoch and duplicate epochs dropped. Every pair whose
+/// epochs are at most `max_age_days` apart contributes one residual: the
+/// older TLE propagated to the newer epoch, minus the newer TLE at its own
+/// epoch, in the newer TLE's RIC frame.
+pub fn estimate_accuracy(
+    tles: &[TLEData],
+    max_age_days: f64,
+    bin_days: f64,
+) -> PyResult<TLEAccuracy> {
+    let valid = max_age_days > 0.0 && bin_days > 0.0;
+    if !valid {
+        return Err(pyo3::exceptions::PyValueError::new_err(
+            "max_age_days and bin_days must be positive",
+        ));
+    }
+
+    let mut sorted: Vec<&TLEData> = tles.iter().collect();
+    sorted.sort_by_key(|tle| tle.epoch);
+    sorted.dedup_by_key(|tle| tle.epoch);
+    if sorted.len() < 2 {
+        return Err(pyo3::exceptions::PyValueError::new_err(
+            "At least two TLEs with distinct epochs are needed",
+        ));
+    }
+    let prepared = sorted
+        .iter()
+        .map(|tle| PreparedTLE::new(tle))
+        .collect::<PyResult<Vec<_>>>()?;
+
+    let mut ages = Vec::new();
+    let mut rows = Vec::new();
+    for (j, newer) in prepared.iter().enumerate() {
+        let (r_ref, v_ref) = newer.state_at(&newer.epoch)?;
+        let basis = ric_basis(&r_ref, &v_ref);
+        for older in &prepared[..j] {
+            let age_days = (newer.epoch - older.epoch).num_milliseconds() as f64 / 86_400_000.0;
+            if age_days > max_age_days {
+                continue;
+            }
+            let (r, _) = older.state_at(&newer.epoch)?;
+            let dr = [r[0] - r_ref[0], r[1] - r_ref[1], r[2] - r_ref[2]];
+            ages.push(age_days);
+            rows.extend(basis.iter().map(|axis| dot_product(&dr, axis)));
+        }
+    }
+    if ages.is_empty() {
+        return Err(pyo3::exceptions::PyValueError::new_err(
+            "No TLE pairs are within max_age_days of each other",
+        ));
+    }
+
+    let residuals = Array2::from_shape_vec((ages.len(), 3), rows)
+        .map_err(|e| pyo3::exceptions::PyRuntimeError::new_err(e.to_string()))?;
+    Ok(TLEAccuracy {
+        epochs: prepared.iter().map(|tle| tle.epoch).collect(),
+        ages: Array1::from(ages),
+        residuals,
+        bin_days,
+        n_bins: (max_age_days / bin_days).ceil() as usize,
+    })
+}
+
+/// Convert one item of a Python TLE sequence to `TLEData`
+///
+/// Accepts a 2- or 3-line TLE string, a `(line1, line2)` pair, a mapping with
+/// `line1` and `line2` keys (the dict returned by the low-level `fetch_tle`)
+/// or an object with `line1` and `line2` attributes such as `TLERecord`.
+pub(crate) fn tle_from_python(item: &Bound<'_, PyAny>) -> PyResult<TLEData> {
+    let lines = if let Ok(text) = item.extract::<String>() {
+        Some(text)
+    } else if let Ok((line1, line2)) = item.extract::<(String, String)>() {
+        Some(format!("{}\n{}", line1, line2))
+    } else if let (Ok(line1), Ok(line2)) = (item.get_item("line1"), item.get_item("line2")) {
+        Some(format!(
+            "{}\n{}",
+            line1.extract::<String>()?,
+            line2.extract::<String>()?
+        ))
+    } else if let (Ok(line1), Ok(line2)) = (item.getattr("line1"), item.getattr("line2")) {
+        Some(format!(
+            "{}\n{}",
+            line1.extract::<String>()?,
+            line2.extract::<String>()?
+        ))
+    } else {
+        None
+    };
+    let text = lines.ok_or_else(|| {
+        pyo3::exceptions::PyTypeError::new_err(
+            "TLEs must be strings, (line1, line2) pairs, or have line1 and line2",
+        )
+    })?;
+    tle_utils::parse_tle_string(&text)
+        .map_err(|e| pyo3::exceptions::PyValueError::new_err(e.to_string()))
+}
+
+/// Collect a TLE history from Python arguments
+///
+/// `tles` is either a file path / URL holding several TLEs, or a sequence of
+/// TLEs. Otherwise the history of `norad_id` between `begin` and `end` is
+/// downloaded from Space-Track.org.
+pub fn collect_tle_history(
+    py: Python,
+    tles: Option<&Bound<'_, PyAny>>,
+    norad_id: Option<u32>,
+    begin: Option<&Bound<'_, PyAny>>,
+    end: Option<&Bound<'_, PyAny>>,
+    credentials: Option<tle_utils::SpaceTrackCredentials>,
+) -> PyResult<Vec<TLEData>> {
+    match (tles, norad_id) {
+        (Some(_), Some(_)) => Err(pyo3::exceptions::PyValueError::new_err(
+            "Provide either tles or norad_id, not both",
+        )),
+        (None, None) => Err(pyo3::exceptions::PyValueError::new_err(
+            "Provide tles or norad_id",
+        )),
+        (Some(tles), None) => {
+            if let Ok(source) = tles.extract::<String>() {
+                py.detach(|| tle_utils::read_tle_sets(&source).map_err(|e| e.to_string()))
+                    .map_err(pyo3::exceptions::PyValueError::new_err)
+            } else {
+                tles.try_iter()?
+                    .map(|item| tle_from_python(&item?))
+                    .collect()
+            }
+        }
+        (None, Some(norad_id)) => {
+            let end = match end {
+                Some(end) => python_datetime_to_utc(end)?,
+                None => Utc::now(),
+            };
+            let begin = match begin {
+                Some(begin) => python_datetime_to_utc(begin)?,
+                None => end - chrono::Duration::days(DEFAULT_HISTORY_DAYS),
+            };
+            if begin >= end {
+                return Err(pyo3::exceptions::PyValueError::new_err(
+                    "begin must be before end",
+                ));
+            }
+            py.detach(|| {
+                tle_utils::fetch_tle_history_from_spacetrack(norad_id, &begin, &end, credentials)
+                    .map_err(|e| e.to_string())
+            })
+            .map_err(pyo3::exceptions::PyValueError::new_err)
+        }
+    }
+}
+
+impl TLEAccuracy {
+    fn bin_of(&self, age_days: f64) -> usize {
+        ((age_days / self.bin_days) as usize).min(self.n_bins - 1)
+    }
+
+    /// Number of residuals in each age bin
+    fn bin_counts(&self) -> Vec<usize> {
+        let mut counts = vec![0; self.n_bins];
+        for &age in &self.ages {
+            counts[self.bin_of(age)] += 1;
+        }
+        counts
+    }
+
+    /// RMS of a component per age bin (NaN for empty bins)
+    ///
+    /// Column indices 0-2 select radial, in-track and cross-track; `None`
+    /// gives the total position error.
+    fn bin_rms(&self, column: Option<usize>) -> Vec<f64> {
+        let mut sums = vec![0.0; self.n_bins];
+        for (k, row) in self.residuals.rows().into_iter().enumerate() {
+            let square = match column {
+                Some(c) => row[c] * row[c],
+                None => row.dot(&row),
+            };
+            sums[self.bin_of(self.ages[k])] += square;
+        }
+        sums.iter()
+            .zip(self.bin_counts())
+            .map(|(sum, n)| {
+                if n > 0 {
+                    (sum / n as f64).sqrt()
+                } else {
+                    f64::NAN
+                }
+            })
+            .collect()
+    }
+
+    fn component_column(component: &str) -> PyResult<Option<usize>> {
+        match component {
+            "total" => Ok(None),
+            _ => COMPONENTS
+                .iter()
+                .position(|c| *c == component)
+                .map(Some)
+                .ok_or_else(|| {
+                    pyo3::exceptions::PyValueError::new_err(format!(
+                        "Unknown component '{}'. Use radial, in_track, cross_track or total",
+                        component
+                    ))
+                }),
+        }
+    }
+}
+
+#[pymethods]
+impl TLEAccuracy {
+    /// Epochs of the TLEs used, sorted, as a list of datetimes
+    #[getter]
+    fn tle_epochs(&self, py: Python) -> PyResult<Vec<Py<PyAny>>> {
+        self.epochs
+            .iter()
+            .map(|epoch| utc_to_python_datetime(py, epoch))
+            .collect()
+    }
+
+    /// Prediction age of each residual in days
+    #[getter]
+    fn age_days(&self, py: Python) -> Py<PyAny> {
+        self.ages.clone().into_pyarray(py).into()
+    }
+
+    /// Residuals as an (M, 3) array of [radial, in-track, cross-track] in km
+    #[getter]
+    fn residuals(&self, py: Python) -> Py<PyAny> {
+        self.residuals.clone().into_pyarray(py).into()
+    }
+
+    /// Edges of the prediction-age bins in days
+    #[getter]
+    fn bin_edges(&self, py: Python) -> Py<PyAny> {
+        Array1::from_iter((0..=self.n_bins).map(|k| k as f64 * self.bin_days))
+            .into_pyarray(py)
+            .into()
+    }
+
+    /// Number of residuals in each age bin
+    #[getter]
+    fn counts(&self) -> Vec<usize> {
+        self.bin_counts()
+    }
+
+    /// RMS error (km) per age bin by component: radial, in_track, cross_track, total
+    ///
+    /// Bins without residuals are NaN.
+    #[getter]
+    fn rms(&self, py: Python) -> HashMap<&'static str, Py<PyAny>> {
+        let mut stats = HashMap::new();
+        for (c, name) in COMPONENTS.iter().enumerate() {
+            stats.insert(
+                *name,
+                Array1::from(self.bin_rms(Some(c))).into_pyarray(py).into(),
+            );
+        }
+        stats.insert(
+            "total",
+            Array1::from(self.bin_rms(None)).into_pyarray(py).into(),
+        );
+        stats
+    }
+
+    /// RMS error (km) expected at a prediction age
+    ///
+    /// Interpolates linearly between the centers of non-empty bins and is
+    /// held constant beyond the first and last of them.
+    #[pyo3(signature = (age_days, component="total"))]
+    fn envelope(&self, age_days: f64, component: &str) -> PyResult<f64> {
+        let column = Self::component_column(component)?;
+        let points: Vec<(f64, f64)> = self
+            .bin_rms(column)
+            .into_iter()
+            .enumerate()
+            .filter(|(_, rms)| !rms.is_nan())
+            .map(|(k, rms)| ((k as f64 + 0.5) * self.bin_days, rms))
+            .collect();
+
+        let next = points.partition_point(|(center, _)| *center <= age_days);
+        Ok(if next == 0 {
+            points[0].1
+        } else if next == points.len() {
+            points[points.len() - 1].1
+        } else {
+            let (x0, y0) = points[next - 1];
+            let (x1, y1) = points[next];
+            y0 + (y1 - y0) * (age_days - x0) / (x1 - x0)
+        })
+    }
+
+    fn __len__(&self) -> usize {
+        self.ages.len()
+    }
+
+    fn __repr__(&self) -> String {
+        format!(
+            "TLEAccuracy(n_tles={}, n_pairs={}, max_age_days={:.1})",
+            self.epochs.len(),
+            self.ages.len(),
+            self.ages.fold(0.0_f64, |m, &a| m.max(a))
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::tle_utils::parse_tle_string;
+
+    fn iss(line1: &str, line2: &str) -> TLEData {
+        parse_tle_string(&format!("{}\n{}", line1, line2)).unwrap()
+    }
+
+    fn history() -> Vec<TLEData> {
+        vec![
+            iss(
+                "1 25544U 98067A   08264.51782528 -.00002182  00000-0 -11606-4 0  2927",
+                "2 25544  51.6416 247.4627 0006703 130.5360 325.0288 15.72125391563537",
+            ),
+            iss(
+                "1 25544U 98067A   08265.51782528 -.00002182  00000-0 -11606-4 0  2928",
+                "2 25544  51.6416 242.4627 0006703 130.5360 325.0288 15.72125391563532",
+            ),
+            iss(
+                "1 25544U 98067A   08266.51782528 -.00002182  00000-0 -11606-4 0  2929",
+                "2 25544  51.6416 237.4627 0006703 130.5360 325.0288 15.72125391563536",
+            ),
+        ]
+    }
+
+    #[test]
+    fn test_pairs_within_max_age() {
+        let accuracy = estimate_accuracy(&history(), 1.5, 0.4).unwrap();
+        // (0,1) and (1,2) are one day apart; (0,2) is two days apart
+        assert_eq!(accuracy.ages.len(), 2);
+        assert!(accuracy.ages.iter().all(|&a| (a - 1.0).abs() < 1e-6));
+        assert_eq!(accuracy.n_bins, 4);
+        assert_eq!(accuracy.bin_counts(), vec![0, 0, 2, 0]);
+
+        let all = estimate_accuracy(&history(), 3.0, 1.0).unwrap();
+        assert_eq!(all.ages.len(), 3);
+    }
+
+    #[test]
+    fn test_duplicate_epochs_and_too_few_tles() {
+        let tles = history();
+        let duplicated = vec![tles[0].clone(), tles[0].clone()];
+        assert!(estimate_accuracy(&duplicated, 1.0, 1.0).is_err());
+        assert!(estimate_accuracy(&tles, 0.5, 0.5).is_err());
+        assert!(estimate_accuracy(&tles, 0.0, 0.5).is_err());
+    }
+
+    #[test]
+    fn test_envelope_interpolation() {
+        let accuracy = TLEAccuracy {
+            epochs: vec![],
+            ages: Array1::from(vec![0.5, 2.5]),
+            residuals: Array2::from_shape_vec((2, 3), vec![1.0, 0.0, 0.0, 3.0, 0.0, 0.0]).unwrap(),
+            bin_days: 1.0,
+            n_bins: 3,
+        };
+        assert!(accuracy.bin_rms(None)[1].is_nan());
+        assert_eq!(accuracy.envelope(0.0, "total").unwrap(), 1.0);
+        assert!((accuracy.envelope(1.5, "radial").unwrap() - 2.0).abs() < 1e-12);
+        assert_eq!(accuracy.envelope(10.0, "total").unwrap(), 3.0);
+        assert_eq!(accuracy.envelope(1.0, "in_track").unwrap(), 0.0);
+        assert!(accuracy.envelope(1.0, "along").is_err());
+    }
+}
@@ -1,15 +1,28 @@
-use chrono::{Datelike, Timelike};
-use ndarray::Array2;
+use chrono::{DateTime, Datelike, NaiveDate, Timelike, Utc};
+use ndarray::{s, Array2, ArrayViewMut2};
 use numpy::IntoPyArray;
 use pyo3::{prelude::*, types::PyDateTime};
-use sgp4::{parse_2les, Constants};
+use sgp4::{parse_2les, Constants, Elements};
 use std::sync::OnceLock;
 
+use crate::ephemeris::element_validation;
 use crate::ephemeris::ephemeris_common::{
-    generate_timestamps, split_pos_vel, EphemerisBase, EphemerisData,
+    check_fast_itrs, generate_timestamps, metadata_timestamp, parse_time_scale, split_pos_vel,
+    EphemerisBase, EphemerisData,
 };
+use crate::ephemeris::ground_access::{self, AccessOpportunity};
+use crate::ephemeris::observation_geometry::{observation_geometry, ObservationGeometry};
 use crate::ephemeris::position_velocity::PositionVelocityData;
+use crate::ephemeris::provenance::{ephemeris_provenance, Provenance};
+use crate::ephemeris::sample::{
+    ephemeris_getitem, ephemeris_len, EphemerisIterator, EphemerisSample,
+};
+use crate::ephemeris::{
+    ephemeris_eq, ephemeris_hash, ephemeris_metadata, ephemeris_repr, ephemeris_summary,
+};
+use crate::utils::config::DEFAULT_MAX_EPOCH_OFFSET_DAYS;
 use crate::utils::conversions;
+use crate::utils::progress::{CancellationToken, Progress, PROPAGATION_CHUNK_SIZE};
 use crate::utils::tle_utils;
 use crate::utils::to_skycoord::AstropyModules;
 
@@ -18,6 +31,7 @@ pub struct TLEEphemeris {
     tle1: String,
     tle2: String,
     tle_epoch: chrono::DateTime<chrono::Utc>, // TLE epoch timestamp
+    decay_date: Option<NaiveDate>,            // SATCAT decay date, if known
     teme: Option<Array2<f64>>,
     itrs: Option<Array2<f64>>,
     itrs_skycoord: OnceLock<Py<PyAny>>, // Lazy-initialized cached SkyCoord object for ITRS
@@ -29,7 +43,7 @@ pub struct TLEEphemeris {
 #[pymethods]
 impl TLEEphemeris {
     #[new]
-    #[pyo3(signature = (tle1=None, tle2=None, begin=None, end=None, step_size=60, *, polar_motion=false, tle=None, norad_id=None, norad_name=None, spacetrack_username=None, spacetrack_password=None, epoch_tolerance_days=None, enforce_source=None))]
+    #[pyo3(signature = (tle1=None, tle2=None, begin=None, end=None, step_size=60, *, polar_motion=false, fast_itrs=false, time_scale="UTC", tle=None, norad_id=None, norad_name=None, spacetrack_username=None, spacetrack_password=None, epoch_tolerance_days=None, enforce_source=None, max_epoch_offset_days=None, strict=false, cache_ttl=None, progress=None, progress_every=1, cancel=None))]
     #[allow(clippy::too_many_arguments)]
     fn new(
         _py: Python,
@@ -39,6 +53,8 @@ impl TLEEphemeris {
         end: Option<&Bound<'_, PyDateTime>>,
         step_size: i64,
         polar_motion: bool,
+        fast_itrs: bool,
+        time_scale: &str,
         tle: Option<&Bound<'_, pyo3::PyAny>>,
         norad_id: Option<u32>,
         norad_name: Option<String>,
@@ -46,7 +62,18 @@ impl TLEEphemeris {
         spacetrack_password: Option<String>,
         epoch_tolerance_days: Option<f64>,
         enforce_source: Option<String>,
+        max_epoch_offset_days: Option<f64>,
+        strict: bool,
+        cache_ttl: Option<u64>,
+        progress: Option<&Bound<'_, pyo3::PyAny>>,
+        progress_every: usize,
+        cancel: Option<CancellationToken>,
     ) -> PyResult<Self> {
+        let cache = tle_utils::TleCacheOptions {
+            cache_ttl,
+            epoch_tolerance_days,
+        };
+
         // For Space-Track, we need begin time first to calculate target epoch
         let begin_for_epoch =
             begin.and_then(|b| crate::utils::time_utils::python_datetime_to_utc(b).ok());
@@ -60,7 +87,7 @@ impl TLEEphemeris {
             // tle parameter: can be a string (file path/URL) or a TLERecord object
             if let Ok(tle_string) = tle_obj.extract::<String>() {
                 // String: file path or URL - use unified function
-                tle_utils::fetch_tle_unified(Some(&tle_string), None, None, None, None, None, None)
+                tle_utils::fetch_tle_unified(Some(&tle_string), None, None, None, None, cache, None)
                     .map_err(|e| pyo3::exceptions::PyValueError::new_err(e.to_string()))?
             } else if tle_obj.hasattr("line1")? && tle_obj.hasattr("line2")? {
                 // Object with line1/line2 attributes (TLERecord or similar)
@@ -76,8 +103,10 @@ impl TLEEphemeris {
                 } else {
                     None
                 };
-                tle_utils::FetchedTLE::from_lines(line1, line2, None, epoch)
-                    .map_err(|e| pyo3::exceptions::PyValueError::new_err(e.to_string()))?
+                let mut fetched = tle_utils::FetchedTLE::from_lines(line1, line2, None, epoch)
+                    .map_err(|e| pyo3::exceptions::PyValueError::new_err(e.to_string()))?;
+                fetched.decay_date = decay_date_from_python(tle_obj)?;
+                fetched
             } else {
                 return Err(pyo3::exceptions::PyTypeError::new_err(
                     "tle parameter must be a string (file path or URL) or an object with line1/line2 attributes"
@@ -108,7 +137,7 @@ impl TLEEphemeris {
                 norad_name.as_deref(),
                 target_epoch.as_ref(),
                 credentials,
-                epoch_tolerance_days,
+                cache,
                 enforce_source.as_deref(),
             )
             .map_err(|e| pyo3::exceptions::PyValueError::new_err(e.to_string()))?
@@ -125,35 +154,94 @@ impl TLEEphemeris {
         let end = end
             .ok_or_else(|| pyo3::exceptions::PyValueError::new_err("end parameter is required"))?;
 
-        // Use common timestamp generation logic
-        let times = generate_timestamps(begin, end, step_size)?;
-
-        // Create the TLEEphemeris object
-        let mut ephemeris: TLEEphemeris = TLEEphemeris {
-            tle1: fetched.line1,
-            tle2: fetched.line2,
-            tle_epoch: fetched.epoch,
-            teme: None,
-            itrs: None,
-            itrs_skycoord: OnceLock::new(),
+        Self::build(
+            fetched,
+            begin,
+            end,
+            step_size,
             polar_motion,
-            common_data: {
-                let mut data = EphemerisData::new();
-                data.times = Some(times);
-                data
-            },
-        };
-
-        // Pre-compute all frames
-        ephemeris.propagate_to_teme()?;
-        ephemeris.teme_to_itrs()?;
-        ephemeris.teme_to_gcrs()?;
-        ephemeris.calculate_sun_moon()?;
+            fast_itrs,
+            time_scale,
+            max_epoch_offset_days,
+            strict,
+            progress,
+            progress_every,
+            cancel,
+        )
+    }
 
-        // Note: SkyCoords are now created lazily on first access
+    /// Create a TLEEphemeris from an sgp4 `Satrec` object
+    ///
+    /// The element set is exported back to TLE lines with `sgp4.exporter`,
+    /// so any satellite record created by the `sgp4` package can be used.
+    #[staticmethod]
+    #[allow(clippy::too_many_arguments)]
+    #[pyo3(signature = (satrec, begin, end, step_size=60, *, polar_motion=false, fast_itrs=false, time_scale="UTC", max_epoch_offset_days=None, strict=false))]
+    fn from_sgp4_satrec(
+        satrec: &Bound<'_, PyAny>,
+        begin: &Bound<'_, PyDateTime>,
+        end: &Bound<'_, PyDateTime>,
+        step_size: i64,
+        polar_motion: bool,
+        fast_itrs: bool,
+        time_scale: &str,
+        max_epoch_offset_days: Option<f64>,
+        strict: bool,
+    ) -> PyResult<Self> {
+        let fetched = satrec_to_fetched_tle(satrec)?;
+        Self::build(
+            fetched,
+            begin,
+            end,
+            step_size,
+            polar_motion,
+            fast_itrs,
+            time_scale,
+            max_epoch_offset_days,
+            strict,
+            None,
+            1,
+            None,
+        )
+    }
 
-        // Return the TLEEphemeris object
-        Ok(ephemeris)
+    /// Create a TLEEphemeris from a Skyfield `EarthSatellite`
+    ///
+    /// Uses the satellite's underlying sgp4 record (`satellite.model`).
+    #[staticmethod]
+    #[allow(clippy::too_many_arguments)]
+    #[pyo3(signature = (satellite, begin, end, step_size=60, *, polar_motion=false, fast_itrs=false, time_scale="UTC", max_epoch_offset_days=None, strict=false))]
+    fn from_skyfield(
+        satellite: &Bound<'_, PyAny>,
+        begin: &Bound<'_, PyDateTime>,
+        end: &Bound<'_, PyDateTime>,
+        step_size: i64,
+        polar_motion: bool,
+        fast_itrs: bool,
+        time_scale: &str,
+        max_epoch_offset_days: Option<f64>,
+        strict: bool,
+    ) -> PyResult<Self> {
+        let satrec = satellite.getattr("model").map_err(|_| {
+            pyo3::exceptions::PyTypeError::new_err(
+                "satellite must be a Skyfield EarthSatellite (object with a 'model' attribute)",
+            )
+        })?;
+        let fetched = satrec_to_fetched_tle(&satrec)?;
+        Self::build(
+            fetched,
+            begin,
+            end,
+            step_size,
+            polar_motion,
+            fast_itrs,
+            time_scale,
+            max_epoch_offset_days,
+            strict,
+            None,
+            1,
+            None,
+        )
     }
 
     /// Get the epoch of the TLE as a Python datetime object
@@ -184,6 +272,27 @@ impl TLEEphemeris {
         Ok(dt_with_tz.into())
     }
 
+    /// Signed offsets in days of `begin` and `end` from the TLE epoch
+    #[getter]
+    fn epoch_offset_days(&self) -> (f64, f64) {
+        epoch_offsets_days(
+            self.tle_epoch,
+            self.common_data.times.as_deref().unwrap_or_default(),
+        )
+    }
+
+    /// SATCAT decay date of the object as a Python date, or None if it is
+    /// not known to have decayed
+    #[getter]
+    fn decay_date(&self, py: Python) -> PyResult<Option<Py<PyAny>>> {
+        self.decay_date
+            .map(|date| {
+                pyo3::types::PyDate::new(py, date.year(), date.month() as u8, date.day() as u8)
+                    .map(|date| date.into_any().unbind())
+            })
+            .transpose()
+    }
+
     /// Get the first TLE line
     #[getter]
     fn tle1(&self) -> &str {
@@ -220,6 +329,18 @@ impl TLEEphemeris {
         self.polar_motion
     }
 
+    /// Get whether ITRS skips the UT1-UTC correction and polar motion
+    #[getter]
+    fn fast_itrs(&self) -> bool {
+        self.common_data.fast_itrs
+    }
+
+    /// Get the time scale in which the timestamp grid is uniform
+    #[getter]
+    fn time_scale(&self) -> &'static str {
+        self.common_data.time_scale.name()
+    }
+
     #[getter]
     fn teme_pv(&self, py: Python) -> Option<Py<PositionVelocityData>> {
         self.teme
@@ -263,19 +384,53 @@ impl TLEEphemeris {
     /// * `ra_deg` - Right ascension in degrees
     /// * `dec_deg` - Declination in degrees
     /// * `time_indices` - Optional indices into ephemeris times (default: all times)
+    /// * `polar_motion` - Override the ephemeris's polar motion setting for this call
     ///
     /// # Returns
     /// Numpy array with shape (N, 2) containing [altitude_deg, azimuth_deg]
+    #[pyo3(signature = (ra_deg, dec_deg, time_indices=None, *, polar_motion=None))]
     fn radec_to_altaz(
         &self,
         py: Python,
         ra_deg: f64,
         dec_deg: f64,
         time_indices: Option<Vec<usize>>,
+        polar_motion: Option<bool>,
+    ) -> PyResult<Py<PyAny>> {
+        let arr = crate::utils::celestial::radec_to_altaz(
+            ra_deg,
+            dec_deg,
+            self,
+            time_indices.as_deref(),
+            polar_motion,
+        );
+        Ok(arr.into_pyarray(py).into())
+    }
+
+    /// Convert many RA/Dec targets to Altitude/Azimuth in one call
+    /// Returns NumPy array (n_targets, N, 2): [altitude_deg, azimuth_deg]
+    #[pyo3(signature = (ra_deg, dec_deg, time_indices=None, *, polar_motion=None))]
+    fn radec_to_altaz_batch(
+        &self,
+        py: Python,
+        ra_deg: Vec<f64>,
+        dec_deg: Vec<f64>,
+        time_indices: Option<Vec<usize>>,
+        polar_motion: Option<bool>,
     ) -> PyResult<Py<PyAny>> {
-        use crate::utils::celestial::radec_to_altaz;
-        let result = radec_to_altaz(ra_deg, dec_deg, self, time_indices.as_deref());
-        Ok(result.into_pyarray(py).into())
+        if ra_deg.len() != dec_deg.len() {
+            return Err(pyo3::exceptions::PyValueError::new_err(
+                "ra_deg and dec_deg must have the same length",
+            ));
+        }
+        let arr = crate::utils::celestial::radec_to_altaz_batch(
+            &ra_deg,
+            &dec_deg,
+            self,
+            time_indices.as_deref(),
+            polar_motion,
+        );
+        Ok(arr.into_pyarray(py).into())
     }
 
     /// Calculate airmass for a target at given RA/Dec
@@ -304,11 +459,42 @@ impl TLEEphemeris {
         EphemerisBase::calculate_airmass(self, ra_deg, dec_deg, time_indices.as_deref())
     }
 
+    /// Calculate airmass for many targets in one call
+    ///
+    /// Uses the vectorized Kasten pipeline of the airmass constraint, without
+    /// refraction or aberration, so there are no per-target Python calls.
+    ///
+    /// # Returns
+    /// NumPy array (n_targets, N) of airmass values, infinity below the horizon
+    #[pyo3(signature = (ras, decs, time_indices=None))]
+    fn airmass_batch(
+        &self,
+        py: Python,
+        ras: Vec<f64>,
+        decs: Vec<f64>,
+        time_indices: Option<Vec<usize>>,
+    ) -> PyResult<Py<PyAny>> {
+        let arr =
+            <Self as crate::ephemeris::ephemeris_common::EphemerisBase>::calculate_airmass_batch(
+                self,
+                &ras,
+                &decs,
+                time_indices.as_deref(),
+            )?;
+        Ok(arr.into_pyarray(py).into())
+    }
+
     #[getter]
     fn gcrs_pv(&self, py: Python) -> Option<Py<PositionVelocityData>> {
         self.get_gcrs_pv(py)
     }
 
+    /// Observer position/velocity in the pseudo-Earth-fixed frame (TEME rotated by GMST)
+    #[getter]
+    fn pef_pv(&self, py: Python) -> Option<Py<PositionVelocityData>> {
+        self.get_pef_pv(py)
+    }
+
     #[getter]
     fn timestamp(&self, py: Python) -> PyResult<Option<Py<PyAny>>> {
         self.get_timestamp(py)
@@ -379,6 +565,21 @@ impl TLEEphemeris {
         self.get_height_km(py)
     }
 
+    #[getter]
+    fn geomagnetic_latitude_deg(&self, py: Python) -> PyResult<Option<Py<PyAny>>> {
+        self.get_geomagnetic_latitude_deg(py)
+    }
+
+    #[getter]
+    fn geomagnetic_longitude_deg(&self, py: Python) -> PyResult<Option<Py<PyAny>>> {
+        self.get_geomagnetic_longitude_deg(py)
+    }
+
+    #[getter]
+    fn cutoff_rigidity_gv(&self, py: Python) -> PyResult<Option<Py<PyAny>>> {
+        self.get_cutoff_rigidity_gv(py)
+    }
+
     #[getter]
     fn sun_radius(&self, py: Python) -> PyResult<Py<PyAny>> {
         self.get_sun_radius(py)
@@ -514,6 +715,46 @@ impl TLEEphemeris {
         self.get_earth_dec_rad(py)
     }
 
+    /// Fraction of the solar disk visible from the observer (1.0 sunlit, 0.0 umbra)
+    #[getter]
+    fn illumination_factor(&self, py: Python) -> PyResult<Py<PyAny>> {
+        let factors = EphemerisBase::illumination_factor(self)?;
+        Ok(factors.into_pyarray(py).into())
+    }
+
+    /// Geodetic latitude and longitude rates (deg/s) and height rate (km/s), shape (N, 3)
+    #[getter]
+    fn geodetic_rates(&self, py: Python) -> PyResult<Option<Py<PyAny>>> {
+        Ok(EphemerisBase::geodetic_rates(self)?.map(|rates| rates.into_pyarray(py).into()))
+    }
+
+    /// Speed of the sub-observer point over the WGS84 ellipsoid (km/s)
+    #[getter]
+    fn ground_speed(&self, py: Python) -> PyResult<Option<Py<PyAny>>> {
+        Ok(EphemerisBase::ground_speed(self)?.map(|speed| speed.into_pyarray(py).into()))
+    }
+
+    /// Geodetic (latitude, longitude) in degrees below the observer, shape (N, 2)
+    #[getter]
+    fn sub_satellite_point(&self, py: Python) -> PyResult<Option<Py<PyAny>>> {
+        Ok(EphemerisBase::sub_satellite_point(self)?.map(|points| points.into_pyarray(py).into()))
+    }
+
+    /// (latitude, longitude) in degrees with the Sun at the zenith, shape (N, 2)
+    #[getter]
+    fn sub_solar_point(&self, py: Python) -> PyResult<Py<PyAny>> {
+        Ok(EphemerisBase::sub_solar_point(self)?
+            .into_pyarray(py)
+            .into())
+    }
+
+    /// Solar zenith angle at the sub-satellite point in degrees (daylit below 90)
+    #[getter]
+    fn nadir_solar_zenith_angle(&self, py: Python) -> PyResult<Option<Py<PyAny>>> {
+        Ok(EphemerisBase::nadir_solar_zenith_angle(self)?
+            .map(|angles| angles.into_pyarray(py).into()))
+    }
+
     /// Calculate Moon illumination fraction for all ephemeris times
     ///
     /// Returns the fraction of the Moon's illuminated surface as seen from the
@@ -529,28 +770,206 @@ impl TLEEphemeris {
         EphemerisBase::moon_illumination(self, time_indices.as_deref())
     }
 
+    /// Imaging opportunities of a ground target
+    ///
+    /// Windows in which the point at (`lat`, `lon`) is above its local horizon,
+    /// within `max_off_nadir_deg` of the spacecraft's nadir and, if
+    /// `min_sun_elev` is given, lit by the Sun at least that high. Each
+    /// opportunity reports the off-nadir angle, azimuth and ground-sample
+    /// geometry at its smallest off-nadir angle.
+    ///
+    /// # Arguments
+    /// * `lat` - Geodetic latitude of the target in degrees
+    /// * `lon` - Longitude of the target in degrees, east positive
+    /// * `max_off_nadir_deg` - Maximum off-nadir angle in degrees
+    /// * `min_sun_elev` - Minimum Sun elevation at the target in degrees (default: no limit)
+    ///
+    /// # Returns
+    /// List of `AccessOpportunity` in time order
+    #[pyo3(signature = (lat, lon, max_off_nadir_deg, min_sun_elev=None))]
+    fn access_to(
+        &self,
+        lat: f64,
+        lon: f64,
+        max_off_nadir_deg: f64,
+        min_sun_elev: Option<f64>,
+    ) -> PyResult<Vec<AccessOpportunity>> {
+        ground_access::access_to(self, lat, lon, max_off_nadir_deg, min_sun_elev)
+    }
+
     fn index(&self, time: &Bound<'_, PyDateTime>) -> PyResult<usize> {
         self.find_closest_index(time)
     }
 
-    #[pyo3(signature = (body, spice_kernel=None, use_horizons=false))]
+    /// Stable hex digest of the source, time grid, options and computed states
+    ///
+    /// Ephemerides with equal fingerprints compare equal, so the fingerprint
+    /// can key caches of results computed from this ephemeris.
+    fn fingerprint(&self) -> String {
+        EphemerisBase::fingerprint(self)
+    }
+
+    fn __eq__(&self, other: &Bound<'_, PyAny>) -> bool {
+        ephemeris_eq(self, other)
+    }
+
+    fn __hash__(&self) -> u64 {
+        ephemeris_hash(self)
+    }
+
+    /// Source, time span, computed frames and Earth orientation state
+    ///
+    /// Values are JSON-serializable (times as ISO 8601 strings), so the dict
+    /// can be logged as provenance alongside derived results.
+    #[getter]
+    fn metadata(&self, py: Python) -> PyResult<Py<PyAny>> {
+        ephemeris_metadata(py, self)
+    }
+
+    /// Reproducibility record: library version, source and fingerprint,
+    /// element set, input and kernel file digests, Earth orientation and
+    /// leap-second data
+    #[getter]
+    fn provenance(&self) -> Provenance {
+        ephemeris_provenance(self)
+    }
+
+    /// Human-readable, multi-line description of `metadata`
+    fn summary(&self) -> String {
+        ephemeris_summary(self)
+    }
+
+    fn __repr__(&self) -> String {
+        ephemeris_repr(self)
+    }
+
+    fn __len__(&self) -> usize {
+        ephemeris_len(self)
+    }
+
+    /// State at time step `index`; negative indices count from the end
+    fn __getitem__(&self, index: isize) -> PyResult<EphemerisSample> {
+        ephemeris_getitem(self, index)
+    }
+
+    fn __iter__(slf: Bound<'_, Self>) -> EphemerisIterator {
+        EphemerisIterator::new(slf.into_any().unbind())
+    }
+
+    #[pyo3(signature = (body, spice_kernel=None, use_horizons=false, source=None))]
     fn get_body_pv(
         &self,
         py: Python,
         body: &str,
         spice_kernel: Option<String>,
         use_horizons: bool,
+        source: Option<String>,
     ) -> PyResult<Py<PositionVelocityData>> {
-        <Self as EphemerisBase>::get_body_pv(self, py, body, spice_kernel.as_deref(), use_horizons)
+        <Self as EphemerisBase>::get_body_pv(
+            self,
+            py,
+            body,
+            spice_kernel.as_deref(),
+            use_horizons,
+            source.as_deref(),
+        )
+    }
+
+    #[pyo3(signature = (bodies, spice_kernel=None, use_horizons=false, source=None))]
+    fn get_bodies(
+        &self,
+        py: Python,
+        bodies: Vec<String>,
+        spice_kernel: Option<String>,
+        use_horizons: bool,
+        source: Option<String>,
+    ) -> PyResult<Py<pyo3::types::PyDict>> {
+        <Self as EphemerisBase>::get_bodies(
+            self,
+            py,
+            &bodies,
+            spice_kernel.as_deref(),
+            use_horizons,
+            source.as_deref(),
+        )
+    }
+
+    /// Angular separation in degrees between two targets at each time step
+    ///
+    /// Each of `a` and `b` may be an `(ra_deg, dec_deg)` pair, a body name,
+    /// observer-relative `PositionVelocityData` or a SkyCoord.
+    fn separation(
+        &self,
+        py: Python,
+        a: &Bound<'_, PyAny>,
+        b: &Bound<'_, PyAny>,
+    ) -> PyResult<Py<PyAny>> {
+        let angles = <Self as EphemerisBase>::separation(self, py, a, b)?;
+        Ok(angles.into_pyarray(py).into())
+    }
+
+    /// Angle in degrees between a target and the Sun at each time step
+    #[pyo3(signature = (ra_deg, dec_deg, time_indices=None))]
+    fn sun_angle(
+        &self,
+        py: Python,
+        ra_deg: f64,
+        dec_deg: f64,
+        time_indices: Option<Vec<usize>>,
+    ) -> PyResult<Py<PyAny>> {
+        let angles = EphemerisBase::sun_angle(self, ra_deg, dec_deg, time_indices.as_deref())?;
+        Ok(angles.into_pyarray(py).into())
     }
 
-    #[pyo3(signature = (body, spice_kernel=None, use_horizons=false))]
+    /// Angle in degrees between a target and the Moon at each time step
+    #[pyo3(signature = (ra_deg, dec_deg, time_indices=None))]
+    fn moon_angle(
+        &self,
+        py: Python,
+        ra_deg: f64,
+        dec_deg: f64,
+        time_indices: Option<Vec<usize>>,
+    ) -> PyResult<Py<PyAny>> {
+        let angles = EphemerisBase::moon_angle(self, ra_deg, dec_deg, time_indices.as_deref())?;
+        Ok(angles.into_pyarray(py).into())
+    }
+
+    /// Angle in degrees of a target above the Earth's limb (negative behind it)
+    #[pyo3(signature = (ra_deg, dec_deg, time_indices=None))]
+    fn earth_limb_angle(
+        &self,
+        py: Python,
+        ra_deg: f64,
+        dec_deg: f64,
+        time_indices: Option<Vec<usize>>,
+    ) -> PyResult<Py<PyAny>> {
+        let angles =
+            EphemerisBase::earth_limb_angle(self, ra_deg, dec_deg, time_indices.as_deref())?;
+        Ok(angles.into_pyarray(py).into())
+    }
+
+    /// Sun, Moon and Earth-limb angles, altitude, eclipse, SAA, orbital phase
+    /// and beta angle for a target at the ephemeris step nearest `time`
+    #[pyo3(signature = (ra_deg, dec_deg, time, *, saa_polygon=None))]
+    fn geometry(
+        &self,
+        ra_deg: f64,
+        dec_deg: f64,
+        time: &Bound<'_, PyDateTime>,
+        saa_polygon: Option<Vec<(f64, f64)>>,
+    ) -> PyResult<ObservationGeometry> {
+        let index = self.find_closest_index(time)?;
+        observation_geometry(self, ra_deg, dec_deg, index, saa_polygon.as_deref())
+    }
+
+    #[pyo3(signature = (body, spice_kernel=None, use_horizons=false, source=None))]
     fn get_body(
         &self,
         py: Python,
         body: &str,
         spice_kernel: Option<String>,
         use_horizons: bool,
+        source: Option<String>,
     ) -> PyResult<Py<PyAny>> {
         let modules = AstropyModules::import(py)?;
         <Self as EphemerisBase>::get_body(
@@ -560,6 +979,7 @@ impl TLEEphemeris {
             body,
             spice_kernel.as_deref(),
             use_horizons,
+            source.as_deref(),
         )
     }
 
@@ -567,62 +987,8 @@ impl TLEEphemeris {
     ///
     /// Propagates the satellite to the times specified during initialization.
     /// Returns [x,y,z,vx,vy,vz] in TEME coordinates (km, km/s).
-    fn propagate_to_teme(&mut self) -> PyResult<()> {
-        // Get the internally stored times
-        let times = self.common_data.times.as_ref().ok_or_else(|| {
-            pyo3::exceptions::PyValueError::new_err(
-                "TLEEphemeris object was not properly initialized. Please create a new TLEEphemeris instance with begin, end, and step_size parameters.",
-            )
-        })?;
-
-        // Parse TLE - concatenate with newlines (parse_2les expects newline-separated format)
-        let tle_string = format!("{}\n{}", self.tle1, self.tle2);
-        let elements_vec = parse_2les(&tle_string).map_err(|e| {
-            pyo3::exceptions::PyValueError::new_err(format!("TLE parse error: {e:?}"))
-        })?;
-        // Use the first set of elements
-        if elements_vec.is_empty() {
-            return Err(pyo3::exceptions::PyValueError::new_err(
-                "No elements parsed from TLE",
-            ));
-        }
-        let elements = elements_vec.into_iter().next().unwrap();
-
-        // Create SGP4 constants
-        let constants = Constants::from_elements(&elements).map_err(|e| {
-            pyo3::exceptions::PyRuntimeError::new_err(format!("SGP4 constants error: {e:?}"))
-        })?;
-
-        // Prepare output array
-        let n = times.len();
-        let mut out = Array2::<f64>::zeros((n, 6));
-
-        for (i, dt) in times.iter().enumerate() {
-            // Convert to NaiveDateTime for sgp4 compatibility
-            let naive_dt = dt.naive_utc();
-
-            // Calculate minutes since epoch
-            // Use unwrap() since time conversions should always succeed for valid timestamps
-            let minutes_since_epoch = elements.datetime_to_minutes_since_epoch(&naive_dt).unwrap();
-
-            // Propagate to get position and velocity in TEME
-            let pred = constants.propagate(minutes_since_epoch).map_err(|e| {
-                pyo3::exceptions::PyRuntimeError::new_err(format!("Propagation error: {e:?}"))
-            })?;
-
-            // Store results - use direct assignment for better performance
-            let mut row = out.row_mut(i);
-            row[0] = pred.position[0];
-            row[1] = pred.position[1];
-            row[2] = pred.position[2];
-            row[3] = pred.velocity[0];
-            row[4] = pred.velocity[1];
-            row[5] = pred.velocity[2];
-        }
-
-        // Store results
-        self.teme = Some(out);
-        Ok(())
+    fn propagate_to_teme(&mut self, py: Python) -> PyResult<()> {
+        self.propagate_teme(py, &mut Progress::none())
     }
 
     /// teme_to_itrs() -> np.ndarray
@@ -631,6 +997,9 @@ impl TLEEphemeris {
     /// Returns [x,y,z,vx,vy,vz] in ITRS frame (km, km/s).
     /// Requires propagate_to_teme to be called first.
     fn teme_to_itrs(&mut self) -> PyResult<()> {
+        if self.common_data.fast_itrs {
+            return self.gcrs_to_fast_itrs();
+        }
         // Access stored TEME data
         let teme_data = self.teme.as_ref().ok_or_else(|| {
             pyo3::exceptions::PyValueError::new_err(
@@ -702,6 +1071,307 @@ impl TLEEphemeris {
     }
 }
 
+impl TLEEphemeris {
+    /// ITRS rotated from the stored GCRS states without UT1-UTC or polar motion (`fast_itrs`)
+    fn gcrs_to_fast_itrs(&mut self) -> PyResult<()> {
+        let gcrs_data = self.common_data.gcrs.as_ref().ok_or_else(|| {
+            pyo3::exceptions::PyValueError::new_err(
+                "No GCRS data available. Call teme_to_gcrs first.",
+            )
+        })?;
+        let times = self.common_data.times.as_ref().ok_or_else(|| {
+            pyo3::exceptions::PyValueError::new_err(
+                "No times available. Call propagate_to_teme first.",
+            )
+        })?;
+        self.itrs = Some(conversions::gcrs_to_itrs_fast(gcrs_data, times));
+        Ok(())
+    }
+
+    /// Catalog number from line 1: an integer, or the raw field for Alpha-5 ids
+    fn norad_id_value(&self) -> serde_json::Value {
+        let catalog = self.tle1.get(2..7).unwrap_or_default().trim();
+        catalog.parse::<u64>().map_or_else(
+            |_| serde_json::Value::from(catalog),
+            serde_json::Value::from,
+        )
+    }
+
+    /// SGP4-propagate to the stored times, reporting each chunk of steps to `progress`
+    fn propagate_teme(&mut self, py: Python, progress: &mut Progress) -> PyResult<()> {
+        // Get the internally stored times
+        let times = self.common_data.times.as_ref().ok_or_else(|| {
+            pyo3::exceptions::PyValueError::new_err(
+                "TLEEphemeris object was not properly initialized. Please create a new TLEEphemeris instance with begin, end, and step_size parameters.",
+            )
+        })?;
+
+        // Parse TLE - concatenate with newlines (parse_2les expects newline-separated format)
+        let tle_string = format!("{}\n{}", self.tle1, self.tle2);
+        let elements_vec = parse_2les(&tle_string).map_err(|e| {
+            pyo3::exceptions::PyValueError::new_err(format!("TLE parse error: {e:?}"))
+        })?;
+        // Use the first set of elements
+        if elements_vec.is_empty() {
+            return Err(pyo3::exceptions::PyValueError::new_err(
+                "No elements parsed from TLE",
+            ));
+        }
+        let elements = elements_vec.into_iter().next().unwrap();
+
+        // Create SGP4 constants
+        let constants = Constants::from_elements(&elements).map_err(|e| {
+            pyo3::exceptions::PyRuntimeError::new_err(format!("SGP4 constants error: {e:?}"))
+        })?;
+
+        // Prepare output array
+        let n = times.len();
+        let mut out = Array2::<f64>::zeros((n, 6));
+
+        for chunk in progress.chunks(n, PROPAGATION_CHUNK_SIZE) {
+            let steps = &times[chunk.clone()];
+            let rows = out.slice_mut(s![chunk.clone(), ..]);
+            if progress.is_active() {
+                py.detach(|| propagate_rows(&elements, &constants, steps, rows))?;
+            } else {
+                propagate_rows(&elements, &constants, steps, rows)?;
+            }
+            progress.advance(py, chunk.len())?;
+        }
+
+        // Store results
+        self.teme = Some(out);
+        Ok(())
+    }
+
+    /// Build the ephemeris from an already-parsed TLE and propagate all frames
+    #[allow(clippy::too_many_arguments)]
+    fn build(
+        fetched: tle_utils::FetchedTLE,
+        begin: &Bound<'_, PyDateTime>,
+        end: &Bound<'_, PyDateTime>,
+        step_size: i64,
+        polar_motion: bool,
+        fast_itrs: bool,
+        time_scale: &str,
+        max_epoch_offset_days: Option<f64>,
+        strict: bool,
+        progress: Option<&Bound<'_, PyAny>>,
+        progress_every: usize,
+        cancel: Option<CancellationToken>,
+    ) -> PyResult<Self> {
+        check_fast_itrs(fast_itrs, polar_motion)?;
+        let time_scale = parse_time_scale(time_scale)?;
+        // Use common timestamp generation logic
+        let times = generate_timestamps(begin, end, step_size, time_scale)?;
+        let mut progress = Progress::new(progress, progress_every, times.len(), cancel)?;
+        check_epoch_offset(
+            begin.py(),
+            fetched.epoch,
+            epoch_offsets_days(fetched.epoch, &times),
+            max_epoch_offset_days.unwrap_or(*DEFAULT_MAX_EPOCH_OFFSET_DAYS),
+            strict,
+        )?;
+        check_element_set(begin.py(), &fetched, strict)?;
+        check_decay_date(begin.py(), fetched.decay_date, &times, strict)?;
+
+        // Create the TLEEphemeris object
+        let mut ephemeris: TLEEphemeris = TLEEphemeris {
+            tle1: fetched.line1,
+            tle2: fetched.line2,
+            tle_epoch: fetched.epoch,
+            decay_date: fetched.decay_date,
+            teme: None,
+            itrs: None,
+            itrs_skycoord: OnceLock::new(),
+            polar_motion,
+            common_data: {
+                let mut data = EphemerisData::new();
+                data.times = Some(times);
+                data.fast_itrs = fast_itrs;
+                data.time_scale = time_scale;
+                data
+            },
+        };
+
+        // Pre-compute all frames; fast ITRS is rotated from GCRS, so GCRS goes first
+        ephemeris.propagate_teme(begin.py(), &mut progress)?;
+        ephemeris.teme_to_gcrs()?;
+        ephemeris.teme_to_itrs()?;
+        ephemeris.calculate_sun_moon()?;
+
+        // Note: SkyCoords are now created lazily on first access
+
+        // Return the TLEEphemeris object
+        Ok(ephemeris)
+    }
+}
+
+/// SGP4 TEME states (km, km/s) at `times`, written to the rows of `out`
+fn propagate_rows(
+    elements: &Elements,
+    constants: &Constants,
+    times: &[DateTime<Utc>],
+    mut out: ArrayViewMut2<f64>,
+) -> PyResult<()> {
+    for (i, dt) in times.iter().enumerate() {
+        // Convert to NaiveDateTime for sgp4 compatibility
+        let naive_dt = dt.naive_utc();
+
+        // Calculate minutes since epoch
+        // Use unwrap() since time conversions should always succeed for valid timestamps
+        let minutes_since_epoch = elements.datetime_to_minutes_since_epoch(&naive_dt).unwrap();
+
+        // Propagate to get position and velocity in TEME
+        let pred = constants.propagate(minutes_since_epoch).map_err(|e| {
+            pyo3::exceptions::PyRuntimeError::new_err(format!("Propagation error: {e:?}"))
+        })?;
+
+        // Store results - use direct assignment for better performance
+        let mut row = out.row_mut(i);
+        row[0] = pred.position[0];
+        row[1] = pred.position[1];
+        row[2] = pred.position[2];
+        row[3] = pred.velocity[0];
+        row[4] = pred.velocity[1];
+        row[5] = pred.velocity[2];
+    }
+    Ok(())
+}
+
+/// Signed offsets in days of the first and last time from the element epoch
+fn epoch_offsets_days(epoch: DateTime<Utc>, times: &[DateTime<Utc>]) -> (f64, f64) {
+    let offset = |t: Option<&DateTime<Utc>>| {
+        t.map_or(0.0, |t| {
+            (*t - epoch).num_milliseconds() as f64 / 86_400_000.0
+        })
+    };
+    (offset(times.first()), offset(times.last()))
+}
+
+/// Warn, or raise when `strict`, if the range reaches beyond `max_days` from the epoch
+///
+/// SGP4 errors grow quickly away from the element epoch, so silently
+/// extrapolating a TLE by weeks is a common source of bad positions.
+fn check_epoch_offset(
+    py: Python,
+    epoch: DateTime<Utc>,
+    (begin_offset, end_offset): (f64, f64),
+    max_days: f64,
+    strict: bool,
+) -> PyResult<()> {
+    if max_days.is_nan() || max_days < 0.0 {
+        return Err(pyo3::exceptions::PyValueError::new_err(
+            "max_epoch_offset_days must be non-negative",
+        ));
+    }
+    if begin_offset.abs().max(end_offset.abs()) <= max_days {
+        return Ok(());
+    }
+    let message = format!(
+        "Requested time range is {:+.2} to {:+.2} days from the TLE epoch {} \
+         (limit: {} days); SGP4 accuracy degrades away from the element epoch",
+        begin_offset,
+        end_offset,
+        epoch.to_rfc3339(),
+        max_days
+    );
+    if strict {
+        return Err(pyo3::exceptions::PyValueError::new_err(message));
+    }
+    py.import("warnings")?.call_method1("warn", (message,))?;
+    Ok(())
+}
+
+/// Raise if the range starts after the object decayed, and warn (or raise
+/// when `strict`) if it ends after
+///
+/// SGP4 propagates the last elements of a re-entered object without error,
+/// giving trajectories that run underground.
+fn check_decay_date(
+    py: Python,
+    decay_date: Option<NaiveDate>,
+    times: &[DateTime<Utc>],
+    strict: bool,
+) -> PyResult<()> {
+    let Some(decay_date) = decay_date else {
+        return Ok(());
+    };
+    let after_decay = |t: Option<&DateTime<Utc>>| t.is_some_and(|t| t.date_naive() > decay_date);
+    if after_decay(times.first()) {
+        return Err(pyo3::exceptions::PyValueError::new_err(format!(
+            "The object decayed on {} according to the SATCAT; \
+             the requested time range starts after it",
+            decay_date
+        )));
+    }
+    if !after_decay(times.last()) {
+        return Ok(());
+    }
+    let message = format!(
+        "The object decayed on {} according to the SATCAT; \
+         positions after that date are not meaningful",
+        decay_date
+    );
+    if strict {
+        return Err(pyo3::exceptions::PyValueError::new_err(message));
+    }
+    py.import("warnings")?.call_method1("warn", (message,))?;
+    Ok(())
+}
+
+/// Decay date from the `decay_date` attribute of a `TLERecord`-like object
+fn decay_date_from_python(tle: &Bound<'_, PyAny>) -> PyResult<Option<NaiveDate>> {
+    let Ok(value) = tle.getattr("decay_date") else {
+        return Ok(None);
+    };
+    if value.is_none() {
+        return Ok(None);
+    }
+    let date = value.downcast::<pyo3::types::PyDate>().map_err(|_| {
+        pyo3::exceptions::PyTypeError::new_err("decay_date must be a datetime.date or None")
+    })?;
+    let date = (
+        date.getattr("year")?.extract::<i32>()?,
+        date.getattr("month")?.extract::<u32>()?,
+        date.getattr("day")?.extract::<u32>()?,
+    );
+    NaiveDate::from_ymd_opt(date.0, date.1, date.2)
+        .map(Some)
+        .ok_or_else(|| pyo3::exceptions::PyValueError::new_err("Invalid decay_date"))
+}
+
+/// Warn about, or raise for, elements that fail the sanity checks
+///
+/// Errors always raise; warnings raise too when `strict`. Catches malformed
+/// element sets before they surface as SGP4 errors.
+fn check_element_set(py: Python, tle: &tle_utils::FetchedTLE, strict: bool) -> PyResult<()> {
+    let elements = element_validation::parse_tle_elements(&tle.line1, &tle.line2)
+        .map_err(pyo3::exceptions::PyValueError::new_err)?;
+    let issues = element_validation::check_elements(&elements, Utc::now());
+    element_validation::raise_or_warn(py, &issues, strict)
+}
+
+/// Export an sgp4 `Satrec` to TLE lines with `sgp4.exporter.export_tle`
+fn satrec_to_fetched_tle(satrec: &Bound<'_, PyAny>) -> PyResult<tle_utils::FetchedTLE> {
+    let exporter = satrec.py().import("sgp4.exporter").map_err(|_| {
+        pyo3::exceptions::PyImportError::new_err(
+            "The sgp4 package is required to convert sgp4/Skyfield satellites",
+        )
+    })?;
+    let (line1, line2): (String, String) = exporter
+        .call_method1("export_tle", (satrec,))
+        .and_then(|lines| lines.extract())
+        .map_err(|e| {
+            pyo3::exceptions::PyTypeError::new_err(format!(
+                "Could not export TLE lines from sgp4 Satrec: {}",
+                e
+            ))
+        })?;
+    tle_utils::FetchedTLE::from_lines(line1, line2, None, None)
+        .map_err(|e| pyo3::exceptions::PyValueError::new_err(e.to_string()))
+}
+
 // Implement the EphemerisBase trait for TLEEphemeris
 impl EphemerisBase for TLEEphemeris {
     fn data(&self) -> &EphemerisData {
@@ -724,12 +1394,43 @@ impl EphemerisBase for TLEEphemeris {
         self.itrs_skycoord.set(skycoord)
     }
 
+    fn source_identity(&self) -> String {
+        format!(
+            "TLEEphemeris\n{}\n{}\npolar_motion={}",
+            self.tle1, self.tle2, self.polar_motion
+        )
+    }
+
+    fn source_metadata(&self) -> Vec<(&'static str, serde_json::Value)> {
+        vec![
+            ("type", "TLEEphemeris".into()),
+            ("norad_id", self.norad_id_value()),
+            ("tle_epoch", metadata_timestamp(&self.tle_epoch).into()),
+            ("polar_motion", self.polar_motion.into()),
+        ]
+    }
+
+    fn element_set(&self) -> Option<serde_json::Value> {
+        let element_set_number = self.tle1.get(64..68).unwrap_or_default().trim();
+        Some(serde_json::json!({
+            "norad_id": self.norad_id_value(),
+            "epoch": metadata_timestamp(&self.tle_epoch),
+            "element_set_number": element_set_number.parse::<u32>().ok(),
+            "line1": self.tle1,
+            "line2": self.tle2,
+        }))
+    }
+
+    fn applies_polar_motion(&self) -> bool {
+        self.polar_motion
+    }
+
     fn radec_to_altaz(
         &self,
         ra_deg: f64,
         dec_deg: f64,
         time_indices: Option<&[usize]>,
     ) -> Array2<f64> {
-        crate::utils::celestial::radec_to_altaz(ra_deg, dec_deg, self, time_indices)
+        crate::utils::celestial::radec_to_altaz(ra_deg, dec_deg, self, time_indices, None)
     }
 }
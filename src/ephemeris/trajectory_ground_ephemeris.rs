@@ -0,0 +1,1235 @@
+//! Ground observer moving along a track (ships, aircraft, balloons)
+//!
+//! Takes a time series of geodetic latitude, longitude and height, such as a
+//! flight plan or a ship's log, and exposes the same observer interface as
+//! `GroundEphemeris`: alt/az and airmass follow the platform, and all
+//! constraints evaluate against it.
+//!
+//! The track is resampled onto a regular grid when `begin`, `end` or
+//! `step_size` are given; otherwise the input times are used as-is.
+//! Resampling interpolates latitude, longitude and height linearly between
+//! track points, taking the short way across the antimeridian. Earth-fixed
+//! velocities are finite differences of the resampled positions.
+//!
+//! Airmass follows the sea-level formula of `GroundEphemeris` unless
+//! `atmosphere="standard"` is given, which integrates through the standard
+//! atmosphere above the platform's height; `balloon()` builds a
+//! stratospheric balloon track with that model.
+
+use chrono::{DateTime, Utc};
+use ndarray::{Array1, Array2};
+use numpy::IntoPyArray;
+use pyo3::{prelude::*, types::PyDateTime};
+use std::sync::OnceLock;
+
+use crate::ephemeris::ephemeris_common::{
+    generate_timestamps, generate_timestamps_utc, parse_time_scale, EphemerisBase, EphemerisData,
+};
+use crate::ephemeris::observation_geometry::{observation_geometry, ObservationGeometry};
+use crate::ephemeris::position_velocity::PositionVelocityData;
+use crate::ephemeris::provenance::{ephemeris_provenance, Provenance};
+use crate::ephemeris::sample::{
+    ephemeris_getitem, ephemeris_len, EphemerisIterator, EphemerisSample,
+};
+use crate::ephemeris::{
+    ephemeris_eq, ephemeris_hash, ephemeris_metadata, ephemeris_repr, ephemeris_summary,
+};
+use crate::utils::atmosphere::{refraction_at_height, AtmosphereModel, ATMOSPHERE_TOP_KM};
+use crate::utils::conversions::{self, Frame};
+use crate::utils::geo::geodetic_to_ecef_km;
+use crate::utils::time_utils::{
+    python_datetime_to_utc, python_times_to_utc, utc_to_python_datetime,
+};
+use crate::utils::to_skycoord::AstropyModules;
+
+/// Default resampling step when only `begin` or `end` is given
+const DEFAULT_STEP_SIZE: i64 = 60;
+
+/// Ground observer on a moving platform
+/// Represents a point near Earth's surface following a track of geodetic coordinates
+#[pyclass]
+pub struct TrajectoryGroundEphemeris {
+    source_times: Vec<DateTime<Utc>>,
+    source_latitude: Vec<f64>,  // degrees
+    source_longitude: Vec<f64>, // degrees
+    source_height: Vec<f64>,    // meters above WGS84 ellipsoid
+    itrs: Option<Array2<f64>>,
+    itrs_skycoord: OnceLock<Py<PyAny>>,
+    polar_motion: bool, // Whether to apply polar motion correction
+    atmosphere: AtmosphereModel,
+    // Common ephemeris data
+    common_data: EphemerisData,
+}
+
+#[pymethods]
+impl TrajectoryGroundEphemeris {
+    /// Create an ephemeris for an observer moving along a track
+    ///
+    /// # Arguments
+    /// * `times` - Sequence of datetimes or NumPy datetime64 array (N), strictly increasing
+    /// * `latitude` - Geodetic latitude in degrees at each time (-90 to 90)
+    /// * `longitude` - Geodetic longitude in degrees at each time (-180 to 180)
+    /// * `height` - Altitude in meters above the WGS84 ellipsoid at each time
+    /// * `begin` - Start of the resampled grid (default: first track time)
+    /// * `end` - End of the resampled grid (default: last track time)
+    /// * `step_size` - Grid step in seconds (default: track times are used as-is
+    ///   unless `begin` or `end` is given, in which case 60)
+    /// * `polar_motion` - Whether to apply polar motion correction (default: false)
+    /// * `time_scale` - Scale in which a resampled grid is uniform: "UTC", "TAI"
+    ///   or "TT" (default: "UTC")
+    /// * `atmosphere` - Airmass model: "sea_level" (Kasten & Young, as for
+    ///   `GroundEphemeris`) or "standard" (standard atmosphere above the
+    ///   platform's height, for aircraft and balloons) (default: "sea_level")
+    #[new]
+    #[allow(clippy::too_many_arguments)]
+    #[pyo3(signature = (times, latitude, longitude, height, begin=None, end=None, step_size=None, *, polar_motion=false, time_scale="UTC", atmosphere="sea_level"))]
+    fn new(
+        times: &Bound<'_, PyAny>,
+        latitude: Vec<f64>,
+        longitude: Vec<f64>,
+        height: Vec<f64>,
+        begin: Option<&Bound<'_, PyDateTime>>,
+        end: Option<&Bound<'_, PyDateTime>>,
+        step_size: Option<i64>,
+        polar_motion: bool,
+        time_scale: &str,
+        atmosphere: &str,
+    ) -> PyResult<Self> {
+        let time_scale = parse_time_scale(time_scale)?;
+        let atmosphere =
+            AtmosphereModel::parse(atmosphere).map_err(pyo3::exceptions::PyValueError::new_err)?;
+        let source_times = python_times_to_utc(times)?;
+        let n = source_times.len();
+        if latitude.len() != n || longitude.len() != n || height.len() != n {
+            return Err(pyo3::exceptions::PyValueError::new_err(format!(
+                "latitude, longitude and height must each have one value per time ({})",
+                n
+            )));
+        }
+        if n < 2 {
+            return Err(pyo3::exceptions::PyValueError::new_err(
+                "At least 2 track points are required",
+            ));
+        }
+        if source_times.windows(2).any(|w| w[1] <= w[0]) {
+            return Err(pyo3::exceptions::PyValueError::new_err(
+                "times must be strictly increasing",
+            ));
+        }
+        if !latitude.iter().all(|lat| (-90.0..=90.0).contains(lat)) {
+            return Err(pyo3::exceptions::PyValueError::new_err(
+                "latitude must be between -90 and 90 degrees",
+            ));
+        }
+        if !longitude.iter().all(|lon| (-180.0..=180.0).contains(lon)) {
+            return Err(pyo3::exceptions::PyValueError::new_err(
+                "longitude must be between -180 and 180 degrees",
+            ));
+        }
+        if !height.iter().all(|h| h.is_finite()) {
+            return Err(pyo3::exceptions::PyValueError::new_err(
+                "height must be finite",
+            ));
+        }
+        if atmosphere == AtmosphereModel::Standard
+            && height.iter().any(|&h| h >= ATMOSPHERE_TOP_KM * 1000.0)
+        {
+            return Err(pyo3::exceptions::PyValueError::new_err(format!(
+                "height must be below {} km for the standard atmosphere",
+                ATMOSPHERE_TOP_KM
+            )));
+        }
+
+        let first = source_times[0];
+        let last = source_times[n - 1];
+        let resample = begin.is_some() || end.is_some() || step_size.is_some();
+        let query_times = if resample {
+            let begin_dt = begin
+                .map(|b| python_datetime_to_utc(b))
+                .transpose()?
+                .unwrap_or(first);
+            let end_dt = end
+                .map(|e| python_datetime_to_utc(e))
+                .transpose()?
+                .unwrap_or(last);
+            if begin_dt < first || end_dt > last {
+                return Err(pyo3::exceptions::PyValueError::new_err(format!(
+                    "Requested time range [{}, {}] exceeds track range [{}, {}]",
+                    begin_dt, end_dt, first, last
+                )));
+            }
+            generate_timestamps_utc(
+                begin_dt,
+                end_dt,
+                step_size.unwrap_or(DEFAULT_STEP_SIZE),
+                time_scale,
+            )?
+        } else {
+            source_times.clone()
+        };
+
+        let mut ephemeris = Self::from_track(
+            source_times,
+            latitude,
+            longitude,
+            height,
+            query_times,
+            polar_motion,
+            atmosphere,
+        )?;
+        ephemeris.common_data.time_scale = time_scale;
+        Ok(ephemeris)
+    }
+
+    /// Create an ephemeris for a stratospheric balloon
+    ///
+    /// The balloon floats at a constant altitude and drifts at constant
+    /// rates in latitude and longitude from its position at `begin`. Airmass
+    /// uses the standard atmosphere above the float altitude.
+    ///
+    /// # Arguments
+    /// * `latitude` - Geodetic latitude in degrees at `begin` (-90 to 90)
+    /// * `longitude` - Geodetic longitude in degrees at `begin` (-180 to 180)
+    /// * `begin` - Start time
+    /// * `end` - End time
+    /// * `step_size` - Step in seconds (default: 60)
+    /// * `altitude_km` - Float altitude in km above the WGS84 ellipsoid (default: 35)
+    /// * `latitude_rate` - Drift in latitude in degrees per hour, north positive (default: 0)
+    /// * `longitude_rate` - Drift in longitude in degrees per hour, east positive (default: 0)
+    /// * `polar_motion` - Whether to apply polar motion correction (default: false)
+    /// * `time_scale` - Scale in which the grid is uniform: "UTC", "TAI" or "TT"
+    ///   (default: "UTC")
+    #[staticmethod]
+    #[allow(clippy::too_many_arguments)]
+    #[pyo3(signature = (latitude, longitude, begin, end, step_size=60, *, altitude_km=35.0, latitude_rate=0.0, longitude_rate=0.0, polar_motion=false, time_scale="UTC"))]
+    fn balloon(
+        latitude: f64,
+        longitude: f64,
+        begin: &Bound<'_, PyDateTime>,
+        end: &Bound<'_, PyDateTime>,
+        step_size: i64,
+        altitude_km: f64,
+        latitude_rate: f64,
+        longitude_rate: f64,
+        polar_motion: bool,
+        time_scale: &str,
+    ) -> PyResult<Self> {
+        let time_scale = parse_time_scale(time_scale)?;
+        if !(-90.0..=90.0).contains(&latitude) {
+            return Err(pyo3::exceptions::PyValueError::new_err(
+                "latitude must be between -90 and 90 degrees",
+            ));
+        }
+        if !(-180.0..=180.0).contains(&longitude) {
+            return Err(pyo3::exceptions::PyValueError::new_err(
+                "longitude must be between -180 and 180 degrees",
+            ));
+        }
+        if !(altitude_km > 0.0 && altitude_km < ATMOSPHERE_TOP_KM) {
+            return Err(pyo3::exceptions::PyValueError::new_err(format!(
+                "altitude_km must be between 0 and {} km",
+                ATMOSPHERE_TOP_KM
+            )));
+        }
+        if !latitude_rate.is_finite() || !longitude_rate.is_finite() {
+            return Err(pyo3::exceptions::PyValueError::new_err(
+                "latitude_rate and longitude_rate must be finite",
+            ));
+        }
+
+        let times = generate_timestamps(begin, end, step_size, time_scale)?;
+        let hours: Vec<f64> = times
+            .iter()
+            .map(|t| (*t - times[0]).num_microseconds().unwrap_or(0) as f64 / 3.6e9)
+            .collect();
+        let track_latitude: Vec<f64> = hours.iter().map(|h| latitude + latitude_rate * h).collect();
+        if !track_latitude
+            .iter()
+            .all(|lat| (-90.0..=90.0).contains(lat))
+        {
+            return Err(pyo3::exceptions::PyValueError::new_err(
+                "latitude_rate takes the balloon past a pole before end",
+            ));
+        }
+        let track_longitude = hours
+            .iter()
+            .map(|h| wrap_longitude(longitude + longitude_rate * h))
+            .collect();
+        let track_height = vec![altitude_km * 1000.0; times.len()];
+
+        let mut ephemeris = Self::from_track(
+            times.clone(),
+            track_latitude,
+            track_longitude,
+            track_height,
+            times,
+            polar_motion,
+            AtmosphereModel::Standard,
+        )?;
+        ephemeris.common_data.time_scale = time_scale;
+        Ok(ephemeris)
+    }
+
+    // ===== Type-specific getters =====
+
+    /// Get the track timestamps before resampling
+    #[getter]
+    fn source_timestamp(&self, py: Python) -> PyResult<Vec<Py<PyAny>>> {
+        self.source_times
+            .iter()
+            .map(|t| utc_to_python_datetime(py, t))
+            .collect()
+    }
+
+    /// Get the track latitudes in degrees before resampling
+    #[getter]
+    fn source_latitude(&self) -> Vec<f64> {
+        self.source_latitude.clone()
+    }
+
+    /// Get the track longitudes in degrees before resampling
+    #[getter]
+    fn source_longitude(&self) -> Vec<f64> {
+        self.source_longitude.clone()
+    }
+
+    /// Get the track heights in meters before resampling
+    #[getter]
+    fn source_height(&self) -> Vec<f64> {
+        self.source_height.clone()
+    }
+
+    /// Convert RA/Dec to Altitude/Azimuth from the platform at each time
+    ///
+    /// Returns a NumPy array with shape (N, 2) of [altitude_deg, azimuth_deg]
+    #[pyo3(signature = (ra_deg, dec_deg, time_indices=None, *, polar_motion=None))]
+    fn radec_to_altaz(
+        &self,
+        py: Python,
+        ra_deg: f64,
+        dec_deg: f64,
+        time_indices: Option<Vec<usize>>,
+        polar_motion: Option<bool>,
+    ) -> PyResult<Py<PyAny>> {
+        let arr = crate::utils::celestial::radec_to_altaz(
+            ra_deg,
+            dec_deg,
+            self,
+            time_indices.as_deref(),
+            polar_motion,
+        );
+        Ok(arr.into_pyarray(py).into())
+    }
+
+    /// Convert many RA/Dec targets to Altitude/Azimuth in one call
+    /// Returns NumPy array (n_targets, N, 2): [altitude_deg, azimuth_deg]
+    #[pyo3(signature = (ra_deg, dec_deg, time_indices=None, *, polar_motion=None))]
+    fn radec_to_altaz_batch(
+        &self,
+        py: Python,
+        ra_deg: Vec<f64>,
+        dec_deg: Vec<f64>,
+        time_indices: Option<Vec<usize>>,
+        polar_motion: Option<bool>,
+    ) -> PyResult<Py<PyAny>> {
+        if ra_deg.len() != dec_deg.len() {
+            return Err(pyo3::exceptions::PyValueError::new_err(
+                "ra_deg and dec_deg must have the same length",
+            ));
+        }
+        let arr = crate::utils::celestial::radec_to_altaz_batch(
+            &ra_deg,
+            &dec_deg,
+            self,
+            time_indices.as_deref(),
+            polar_motion,
+        );
+        Ok(arr.into_pyarray(py).into())
+    }
+
+    /// Calculate airmass for a target at given RA/Dec
+    ///
+    /// Same as `GroundEphemeris.calculate_airmass`, from the platform position
+    /// at each time: 1.0 at zenith, infinity below the horizon. With the
+    /// "standard" atmosphere the column is integrated above the platform's
+    /// height, and stays finite below the horizon down to the Earth's limb.
+    #[pyo3(signature = (ra_deg, dec_deg, time_indices=None))]
+    fn calculate_airmass(
+        &self,
+        ra_deg: f64,
+        dec_deg: f64,
+        time_indices: Option<Vec<usize>>,
+    ) -> PyResult<Vec<f64>> {
+        <Self as EphemerisBase>::calculate_airmass(self, ra_deg, dec_deg, time_indices.as_deref())
+    }
+
+    /// Calculate airmass for many targets in one call
+    ///
+    /// Uses the vectorized Kasten pipeline of the airmass constraint, without
+    /// refraction or aberration, so there are no per-target Python calls.
+    ///
+    /// # Returns
+    /// NumPy array (n_targets, N) of airmass values, infinity below the horizon
+    #[pyo3(signature = (ras, decs, time_indices=None))]
+    fn airmass_batch(
+        &self,
+        py: Python,
+        ras: Vec<f64>,
+        decs: Vec<f64>,
+        time_indices: Option<Vec<usize>>,
+    ) -> PyResult<Py<PyAny>> {
+        let arr =
+            <Self as crate::ephemeris::ephemeris_common::EphemerisBase>::calculate_airmass_batch(
+                self,
+                &ras,
+                &decs,
+                time_indices.as_deref(),
+            )?;
+        Ok(arr.into_pyarray(py).into())
+    }
+
+    /// Atmospheric refraction in degrees for a target at given RA/Dec
+    ///
+    /// The amount the target appears raised above its true altitude, from
+    /// the standard atmosphere above the platform at each time (~1' at 45°
+    /// at sea level, ~1% of that at 35 km). NaN where the line of sight
+    /// meets the ground.
+    #[pyo3(signature = (ra_deg, dec_deg, time_indices=None))]
+    fn calculate_refraction(
+        &self,
+        ra_deg: f64,
+        dec_deg: f64,
+        time_indices: Option<Vec<usize>>,
+    ) -> PyResult<Vec<f64>> {
+        let time_indices = time_indices.as_deref();
+        let altaz = EphemerisBase::radec_to_altaz(self, ra_deg, dec_deg, time_indices);
+        let heights =
+            self.common_data.height_cache.get().ok_or_else(|| {
+                pyo3::exceptions::PyValueError::new_err("No height data available")
+            })?;
+        Ok((0..altaz.nrows())
+            .map(|i| {
+                let row = time_indices.map_or(i, |indices| indices[i]);
+                refraction_at_height(altaz[[i, 0]], heights[row])
+            })
+            .collect())
+    }
+
+    /// Get whether polar motion correction is applied
+    #[getter]
+    fn polar_motion(&self) -> bool {
+        self.polar_motion
+    }
+
+    /// Get the time scale in which the timestamp grid is uniform
+    #[getter]
+    fn time_scale(&self) -> &'static str {
+        self.common_data.time_scale.name()
+    }
+
+    /// Get the airmass model: "sea_level" or "standard"
+    #[getter]
+    fn atmosphere(&self) -> &'static str {
+        self.atmosphere.as_str()
+    }
+
+    // ===== Common ephemeris getters (delegating to EphemerisBase trait) =====
+
+    #[getter]
+    fn begin(&self, py: Python) -> PyResult<Py<PyAny>> {
+        crate::ephemeris::ephemeris_common::get_begin_time(&self.common_data.times, py)
+    }
+
+    #[getter]
+    fn end(&self, py: Python) -> PyResult<Py<PyAny>> {
+        crate::ephemeris::ephemeris_common::get_end_time(&self.common_data.times, py)
+    }
+
+    #[getter]
+    fn step_size(&self) -> PyResult<i64> {
+        crate::ephemeris::ephemeris_common::get_step_size(&self.common_data.times)
+    }
+
+    #[getter]
+    fn gcrs_pv(&self, py: Python) -> Option<Py<PositionVelocityData>> {
+        self.get_gcrs_pv(py)
+    }
+
+    /// Observer position/velocity in TEME, converted from GCRS
+    #[getter]
+    fn teme_pv(&self, py: Python) -> Option<Py<PositionVelocityData>> {
+        self.get_teme_pv(py)
+    }
+
+    /// Observer position/velocity in the pseudo-Earth-fixed frame (TEME rotated by GMST)
+    #[getter]
+    fn pef_pv(&self, py: Python) -> Option<Py<PositionVelocityData>> {
+        self.get_pef_pv(py)
+    }
+
+    #[getter]
+    fn itrs_pv(&self, py: Python) -> Option<Py<PositionVelocityData>> {
+        self.get_itrs_pv(py)
+    }
+
+    #[getter]
+    fn itrs(&self, py: Python) -> PyResult<Py<PyAny>> {
+        self.get_itrs(py)
+    }
+
+    #[getter]
+    fn gcrs(&self, py: Python) -> PyResult<Py<PyAny>> {
+        self.get_gcrs(py)
+    }
+
+    #[getter]
+    fn earth(&self, py: Python) -> PyResult<Py<PyAny>> {
+        self.get_earth(py)
+    }
+
+    #[getter]
+    fn sun(&self, py: Python) -> PyResult<Py<PyAny>> {
+        self.get_sun(py)
+    }
+
+    #[getter]
+    fn moon(&self, py: Python) -> PyResult<Py<PyAny>> {
+        self.get_moon(py)
+    }
+
+    #[getter]
+    fn timestamp(&self, py: Python) -> PyResult<Option<Py<PyAny>>> {
+        self.get_timestamp(py)
+    }
+
+    #[getter]
+    fn sun_pv(&self, py: Python) -> Option<Py<PositionVelocityData>> {
+        self.get_sun_pv(py)
+    }
+
+    #[getter]
+    fn moon_pv(&self, py: Python) -> Option<Py<PositionVelocityData>> {
+        self.get_moon_pv(py)
+    }
+
+    #[getter]
+    fn obsgeoloc(&self, py: Python) -> PyResult<Option<Py<PyAny>>> {
+        self.get_obsgeoloc(py)
+    }
+
+    #[getter]
+    fn obsgeovel(&self, py: Python) -> PyResult<Option<Py<PyAny>>> {
+        self.get_obsgeovel(py)
+    }
+
+    #[getter]
+    fn latitude(&self, py: Python) -> PyResult<Option<Py<PyAny>>> {
+        self.get_latitude(py)
+    }
+
+    #[getter]
+    fn latitude_deg(&self, py: Python) -> PyResult<Option<Py<PyAny>>> {
+        self.get_latitude_deg(py)
+    }
+
+    #[getter]
+    fn latitude_rad(&self, py: Python) -> PyResult<Option<Py<PyAny>>> {
+        self.get_latitude_rad(py)
+    }
+
+    #[getter]
+    fn longitude(&self, py: Python) -> PyResult<Option<Py<PyAny>>> {
+        self.get_longitude(py)
+    }
+
+    #[getter]
+    fn longitude_deg(&self, py: Python) -> PyResult<Option<Py<PyAny>>> {
+        self.get_longitude_deg(py)
+    }
+
+    #[getter]
+    fn longitude_rad(&self, py: Python) -> PyResult<Option<Py<PyAny>>> {
+        self.get_longitude_rad(py)
+    }
+
+    #[getter]
+    fn height(&self, py: Python) -> PyResult<Option<Py<PyAny>>> {
+        self.get_height(py)
+    }
+
+    #[getter]
+    fn height_m(&self, py: Python) -> PyResult<Option<Py<PyAny>>> {
+        self.get_height_m(py)
+    }
+
+    #[getter]
+    fn height_km(&self, py: Python) -> PyResult<Option<Py<PyAny>>> {
+        self.get_height_km(py)
+    }
+
+    #[getter]
+    fn geomagnetic_latitude_deg(&self, py: Python) -> PyResult<Option<Py<PyAny>>> {
+        self.get_geomagnetic_latitude_deg(py)
+    }
+
+    #[getter]
+    fn geomagnetic_longitude_deg(&self, py: Python) -> PyResult<Option<Py<PyAny>>> {
+        self.get_geomagnetic_longitude_deg(py)
+    }
+
+    #[getter]
+    fn cutoff_rigidity_gv(&self, py: Python) -> PyResult<Option<Py<PyAny>>> {
+        self.get_cutoff_rigidity_gv(py)
+    }
+
+    #[getter]
+    fn sun_radius(&self, py: Python) -> PyResult<Py<PyAny>> {
+        self.get_sun_radius(py)
+    }
+
+    #[getter]
+    fn sun_radius_deg(&self, py: Python) -> PyResult<Py<PyAny>> {
+        self.get_sun_radius_deg(py)
+    }
+
+    #[getter]
+    fn sun_radius_rad(&self, py: Python) -> PyResult<Py<PyAny>> {
+        self.get_sun_radius_rad(py)
+    }
+
+    #[getter]
+    fn moon_radius(&self, py: Python) -> PyResult<Py<PyAny>> {
+        self.get_moon_radius(py)
+    }
+
+    #[getter]
+    fn moon_radius_deg(&self, py: Python) -> PyResult<Py<PyAny>> {
+        self.get_moon_radius_deg(py)
+    }
+
+    #[getter]
+    fn moon_radius_rad(&self, py: Python) -> PyResult<Py<PyAny>> {
+        self.get_moon_radius_rad(py)
+    }
+
+    #[getter]
+    fn earth_radius(&self, py: Python) -> PyResult<Py<PyAny>> {
+        self.get_earth_radius(py)
+    }
+
+    #[getter]
+    fn earth_radius_deg(&self, py: Python) -> PyResult<Py<PyAny>> {
+        self.get_earth_radius_deg(py)
+    }
+
+    #[getter]
+    fn earth_radius_rad(&self, py: Python) -> PyResult<Py<PyAny>> {
+        self.get_earth_radius_rad(py)
+    }
+
+    #[getter]
+    fn sun_ra_dec_deg(&self, py: Python) -> PyResult<Py<PyAny>> {
+        self.get_sun_ra_dec_deg(py)
+    }
+
+    #[getter]
+    fn moon_ra_dec_deg(&self, py: Python) -> PyResult<Py<PyAny>> {
+        self.get_moon_ra_dec_deg(py)
+    }
+
+    #[getter]
+    fn earth_ra_dec_deg(&self, py: Python) -> PyResult<Py<PyAny>> {
+        self.get_earth_ra_dec_deg(py)
+    }
+
+    #[getter]
+    fn sun_ra_dec_rad(&self, py: Python) -> PyResult<Py<PyAny>> {
+        self.get_sun_ra_dec_rad(py)
+    }
+
+    #[getter]
+    fn moon_ra_dec_rad(&self, py: Python) -> PyResult<Py<PyAny>> {
+        self.get_moon_ra_dec_rad(py)
+    }
+
+    #[getter]
+    fn earth_ra_dec_rad(&self, py: Python) -> PyResult<Py<PyAny>> {
+        self.get_earth_ra_dec_rad(py)
+    }
+
+    #[getter]
+    fn sun_ra_deg(&self, py: Python) -> PyResult<Py<PyAny>> {
+        self.get_sun_ra_deg(py)
+    }
+
+    #[getter]
+    fn sun_dec_deg(&self, py: Python) -> PyResult<Py<PyAny>> {
+        self.get_sun_dec_deg(py)
+    }
+
+    #[getter]
+    fn moon_ra_deg(&self, py: Python) -> PyResult<Py<PyAny>> {
+        self.get_moon_ra_deg(py)
+    }
+
+    #[getter]
+    fn moon_dec_deg(&self, py: Python) -> PyResult<Py<PyAny>> {
+        self.get_moon_dec_deg(py)
+    }
+
+    #[getter]
+    fn earth_ra_deg(&self, py: Python) -> PyResult<Py<PyAny>> {
+        self.get_earth_ra_deg(py)
+    }
+
+    #[getter]
+    fn earth_dec_deg(&self, py: Python) -> PyResult<Py<PyAny>> {
+        self.get_earth_dec_deg(py)
+    }
+
+    #[getter]
+    fn sun_ra_rad(&self, py: Python) -> PyResult<Py<PyAny>> {
+        self.get_sun_ra_rad(py)
+    }
+
+    #[getter]
+    fn sun_dec_rad(&self, py: Python) -> PyResult<Py<PyAny>> {
+        self.get_sun_dec_rad(py)
+    }
+
+    #[getter]
+    fn moon_ra_rad(&self, py: Python) -> PyResult<Py<PyAny>> {
+        self.get_moon_ra_rad(py)
+    }
+
+    #[getter]
+    fn moon_dec_rad(&self, py: Python) -> PyResult<Py<PyAny>> {
+        self.get_moon_dec_rad(py)
+    }
+
+    #[getter]
+    fn earth_ra_rad(&self, py: Python) -> PyResult<Py<PyAny>> {
+        self.get_earth_ra_rad(py)
+    }
+
+    #[getter]
+    fn earth_dec_rad(&self, py: Python) -> PyResult<Py<PyAny>> {
+        self.get_earth_dec_rad(py)
+    }
+
+    /// Fraction of the solar disk visible from the observer (1.0 sunlit, 0.0 umbra)
+    #[getter]
+    fn illumination_factor(&self, py: Python) -> PyResult<Py<PyAny>> {
+        let factors = EphemerisBase::illumination_factor(self)?;
+        Ok(factors.into_pyarray(py).into())
+    }
+
+    /// Geodetic latitude and longitude rates (deg/s) and height rate (km/s), shape (N, 3)
+    #[getter]
+    fn geodetic_rates(&self, py: Python) -> PyResult<Option<Py<PyAny>>> {
+        Ok(EphemerisBase::geodetic_rates(self)?.map(|rates| rates.into_pyarray(py).into()))
+    }
+
+    /// Speed of the sub-observer point over the WGS84 ellipsoid (km/s)
+    #[getter]
+    fn ground_speed(&self, py: Python) -> PyResult<Option<Py<PyAny>>> {
+        Ok(EphemerisBase::ground_speed(self)?.map(|speed| speed.into_pyarray(py).into()))
+    }
+
+    /// Geodetic (latitude, longitude) in degrees below the observer, shape (N, 2)
+    #[getter]
+    fn sub_satellite_point(&self, py: Python) -> PyResult<Option<Py<PyAny>>> {
+        Ok(EphemerisBase::sub_satellite_point(self)?.map(|points| points.into_pyarray(py).into()))
+    }
+
+    /// (latitude, longitude) in degrees with the Sun at the zenith, shape (N, 2)
+    #[getter]
+    fn sub_solar_point(&self, py: Python) -> PyResult<Py<PyAny>> {
+        Ok(EphemerisBase::sub_solar_point(self)?
+            .into_pyarray(py)
+            .into())
+    }
+
+    /// Solar zenith angle at the sub-satellite point in degrees (daylit below 90)
+    #[getter]
+    fn nadir_solar_zenith_angle(&self, py: Python) -> PyResult<Option<Py<PyAny>>> {
+        Ok(EphemerisBase::nadir_solar_zenith_angle(self)?
+            .map(|angles| angles.into_pyarray(py).into()))
+    }
+
+    /// Calculate Moon illumination fraction for all ephemeris times
+    ///
+    /// Returns the fraction of the Moon's illuminated surface as seen from the
+    /// spacecraft observer (0.0 = new moon, 1.0 = full moon).
+    ///
+    /// # Arguments
+    /// * `time_indices` - Optional indices into ephemeris times (default: all times)
+    ///
+    /// # Returns
+    /// List of Moon illumination fractions
+    #[pyo3(signature = (time_indices=None))]
+    fn moon_illumination(&self, time_indices: Option<Vec<usize>>) -> PyResult<Vec<f64>> {
+        EphemerisBase::moon_illumination(self, time_indices.as_deref())
+    }
+
+    fn index(&self, time: &Bound<'_, PyDateTime>) -> PyResult<usize> {
+        self.find_closest_index(time)
+    }
+
+    /// Stable hex digest of the source, time grid, options and computed states
+    ///
+    /// Ephemerides with equal fingerprints compare equal, so the fingerprint
+    /// can key caches of results computed from this ephemeris.
+    fn fingerprint(&self) -> String {
+        EphemerisBase::fingerprint(self)
+    }
+
+    fn __eq__(&self, other: &Bound<'_, PyAny>) -> bool {
+        ephemeris_eq(self, other)
+    }
+
+    fn __hash__(&self) -> u64 {
+        ephemeris_hash(self)
+    }
+
+    /// Source, time span, computed frames and Earth orientation state
+    ///
+    /// Values are JSON-serializable (times as ISO 8601 strings), so the dict
+    /// can be logged as provenance alongside derived results.
+    #[getter]
+    fn metadata(&self, py: Python) -> PyResult<Py<PyAny>> {
+        ephemeris_metadata(py, self)
+    }
+
+    /// Reproducibility record: library version, source and fingerprint,
+    /// element set, input and kernel file digests, Earth orientation and
+    /// leap-second data
+    #[getter]
+    fn provenance(&self) -> Provenance {
+        ephemeris_provenance(self)
+    }
+
+    /// Human-readable, multi-line description of `metadata`
+    fn summary(&self) -> String {
+        ephemeris_summary(self)
+    }
+
+    fn __repr__(&self) -> String {
+        ephemeris_repr(self)
+    }
+
+    fn __len__(&self) -> usize {
+        ephemeris_len(self)
+    }
+
+    /// State at time step `index`; negative indices count from the end
+    fn __getitem__(&self, index: isize) -> PyResult<EphemerisSample> {
+        ephemeris_getitem(self, index)
+    }
+
+    fn __iter__(slf: Bound<'_, Self>) -> EphemerisIterator {
+        EphemerisIterator::new(slf.into_any().unbind())
+    }
+
+    #[pyo3(signature = (body, spice_kernel=None, use_horizons=false, source=None))]
+    fn get_body_pv(
+        &self,
+        py: Python,
+        body: &str,
+        spice_kernel: Option<String>,
+        use_horizons: bool,
+        source: Option<String>,
+    ) -> PyResult<Py<PositionVelocityData>> {
+        <Self as EphemerisBase>::get_body_pv(
+            self,
+            py,
+            body,
+            spice_kernel.as_deref(),
+            use_horizons,
+            source.as_deref(),
+        )
+    }
+
+    #[pyo3(signature = (bodies, spice_kernel=None, use_horizons=false, source=None))]
+    fn get_bodies(
+        &self,
+        py: Python,
+        bodies: Vec<String>,
+        spice_kernel: Option<String>,
+        use_horizons: bool,
+        source: Option<String>,
+    ) -> PyResult<Py<pyo3::types::PyDict>> {
+        <Self as EphemerisBase>::get_bodies(
+            self,
+            py,
+            &bodies,
+            spice_kernel.as_deref(),
+            use_horizons,
+            source.as_deref(),
+        )
+    }
+
+    /// Angular separation in degrees between two targets at each time step
+    ///
+    /// Each of `a` and `b` may be an `(ra_deg, dec_deg)` pair, a body name,
+    /// observer-relative `PositionVelocityData` or a SkyCoord.
+    fn separation(
+        &self,
+        py: Python,
+        a: &Bound<'_, PyAny>,
+        b: &Bound<'_, PyAny>,
+    ) -> PyResult<Py<PyAny>> {
+        let angles = <Self as EphemerisBase>::separation(self, py, a, b)?;
+        Ok(angles.into_pyarray(py).into())
+    }
+
+    /// Angle in degrees between a target and the Sun at each time step
+    #[pyo3(signature = (ra_deg, dec_deg, time_indices=None))]
+    fn sun_angle(
+        &self,
+        py: Python,
+        ra_deg: f64,
+        dec_deg: f64,
+        time_indices: Option<Vec<usize>>,
+    ) -> PyResult<Py<PyAny>> {
+        let angles = EphemerisBase::sun_angle(self, ra_deg, dec_deg, time_indices.as_deref())?;
+        Ok(angles.into_pyarray(py).into())
+    }
+
+    /// Angle in degrees between a target and the Moon at each time step
+    #[pyo3(signature = (ra_deg, dec_deg, time_indices=None))]
+    fn moon_angle(
+        &self,
+        py: Python,
+        ra_deg: f64,
+        dec_deg: f64,
+        time_indices: Option<Vec<usize>>,
+    ) -> PyResult<Py<PyAny>> {
+        let angles = EphemerisBase::moon_angle(self, ra_deg, dec_deg, time_indices.as_deref())?;
+        Ok(angles.into_pyarray(py).into())
+    }
+
+    /// Angle in degrees of a target above the Earth's limb (negative behind it)
+    #[pyo3(signature = (ra_deg, dec_deg, time_indices=None))]
+    fn earth_limb_angle(
+        &self,
+        py: Python,
+        ra_deg: f64,
+        dec_deg: f64,
+        time_indices: Option<Vec<usize>>,
+    ) -> PyResult<Py<PyAny>> {
+        let angles =
+            EphemerisBase::earth_limb_angle(self, ra_deg, dec_deg, time_indices.as_deref())?;
+        Ok(angles.into_pyarray(py).into())
+    }
+
+    /// Sun, Moon and Earth-limb angles, altitude, eclipse, SAA, orbital phase
+    /// and beta angle for a target at the ephemeris step nearest `time`
+    #[pyo3(signature = (ra_deg, dec_deg, time, *, saa_polygon=None))]
+    fn geometry(
+        &self,
+        ra_deg: f64,
+        dec_deg: f64,
+        time: &Bound<'_, PyDateTime>,
+        saa_polygon: Option<Vec<(f64, f64)>>,
+    ) -> PyResult<ObservationGeometry> {
+        let index = self.find_closest_index(time)?;
+        observation_geometry(self, ra_deg, dec_deg, index, saa_polygon.as_deref())
+    }
+
+    #[pyo3(signature = (body, spice_kernel=None, use_horizons=false, source=None))]
+    fn get_body(
+        &self,
+        py: Python,
+        body: &str,
+        spice_kernel: Option<String>,
+        use_horizons: bool,
+        source: Option<String>,
+    ) -> PyResult<Py<PyAny>> {
+        let modules = AstropyModules::import(py)?;
+        <Self as EphemerisBase>::get_body(
+            self,
+            py,
+            &modules,
+            body,
+            spice_kernel.as_deref(),
+            use_horizons,
+            source.as_deref(),
+        )
+    }
+}
+
+impl TrajectoryGroundEphemeris {
+    /// Build from a validated track and the query grid, and pre-compute all
+    /// frames
+    fn from_track(
+        source_times: Vec<DateTime<Utc>>,
+        latitude: Vec<f64>,
+        longitude: Vec<f64>,
+        height: Vec<f64>,
+        query_times: Vec<DateTime<Utc>>,
+        polar_motion: bool,
+        atmosphere: AtmosphereModel,
+    ) -> PyResult<Self> {
+        let mut ephemeris = TrajectoryGroundEphemeris {
+            source_times,
+            source_latitude: latitude,
+            source_longitude: longitude,
+            source_height: height,
+            itrs: None,
+            itrs_skycoord: OnceLock::new(),
+            polar_motion,
+            atmosphere,
+            common_data: {
+                let mut data = EphemerisData::new();
+                data.times = Some(query_times);
+                data
+            },
+        };
+
+        // Pre-compute all frames
+        ephemeris.compute_itrs_track()?;
+        ephemeris.calculate_sun_moon()?;
+
+        Ok(ephemeris)
+    }
+
+    /// Resample the track onto the query grid and fill ITRS, GCRS and the
+    /// geodetic caches
+    fn compute_itrs_track(&mut self) -> PyResult<()> {
+        let times = self.common_data.times.as_ref().ok_or_else(|| {
+            pyo3::exceptions::PyValueError::new_err(
+                "TrajectoryGroundEphemeris was not properly initialized with times.",
+            )
+        })?;
+
+        let (latitude, longitude, height) = if *times == self.source_times {
+            (
+                self.source_latitude.clone(),
+                self.source_longitude.clone(),
+                self.source_height.clone(),
+            )
+        } else {
+            let longitude = unwrap_longitudes(&self.source_longitude);
+            (
+                interpolate_linear(times, &self.source_times, &self.source_latitude),
+                interpolate_linear(times, &self.source_times, &longitude)
+                    .into_iter()
+                    .map(wrap_longitude)
+                    .collect(),
+                interpolate_linear(times, &self.source_times, &self.source_height),
+            )
+        };
+
+        let positions: Vec<[f64; 3]> = latitude
+            .iter()
+            .zip(&longitude)
+            .zip(&height)
+            .map(|((&lat, &lon), &h)| geodetic_to_ecef_km(lat, lon, h))
+            .collect();
+        let velocities = finite_difference_velocities(times, &positions);
+        let itrs = Array2::from_shape_fn((times.len(), 6), |(i, j)| {
+            if j < 3 {
+                positions[i][j]
+            } else {
+                velocities[i][j - 3]
+            }
+        });
+
+        let gcrs =
+            conversions::convert_frames(&itrs, times, Frame::ITRS, Frame::GCRS, self.polar_motion);
+        self.common_data.gcrs = Some(gcrs);
+        self.itrs = Some(itrs);
+
+        // Pre-populate geodetic caches with the track values to preserve precision
+        let lat_deg = Array1::from_vec(latitude);
+        let lon_deg = Array1::from_vec(longitude);
+        let h_m = Array1::from_vec(height);
+        let _ = self
+            .common_data
+            .latitude_rad_cache
+            .set(lat_deg.mapv(f64::to_radians));
+        let _ = self
+            .common_data
+            .longitude_rad_cache
+            .set(lon_deg.mapv(f64::to_radians));
+        let _ = self
+            .common_data
+            .height_km_cache
+            .set(h_m.mapv(|v| v / 1000.0));
+        let _ = self.common_data.latitude_deg_cache.set(lat_deg);
+        let _ = self.common_data.longitude_deg_cache.set(lon_deg);
+        let _ = self.common_data.height_cache.set(h_m);
+        Ok(())
+    }
+}
+
+/// Longitudes in degrees with 360° steps removed, so consecutive values
+/// differ by at most 180° and interpolation takes the short way round
+fn unwrap_longitudes(longitudes: &[f64]) -> Vec<f64> {
+    let mut unwrapped = Vec::with_capacity(longitudes.len());
+    for &lon in longitudes {
+        let value = match unwrapped.last() {
+            Some(&previous) => previous + wrap_longitude(lon - previous),
+            None => lon,
+        };
+        unwrapped.push(value);
+    }
+    unwrapped
+}
+
+/// Longitude in degrees wrapped into [-180, 180)
+fn wrap_longitude(lon: f64) -> f64 {
+    (lon + 180.0).rem_euclid(360.0) - 180.0
+}
+
+/// Piecewise-linear interpolation of `values` at `source_times` onto `times`,
+/// which must lie within the source range
+fn interpolate_linear(
+    times: &[DateTime<Utc>],
+    source_times: &[DateTime<Utc>],
+    values: &[f64],
+) -> Vec<f64> {
+    times
+        .iter()
+        .map(|t| {
+            // Segment [i-1, i] containing t
+            let i = source_times
+                .partition_point(|s| s < t)
+                .clamp(1, source_times.len() - 1);
+            let (t0, t1) = (source_times[i - 1], source_times[i]);
+            let span = (t1 - t0).num_microseconds().unwrap_or(1) as f64;
+            let fraction = (*t - t0).num_microseconds().unwrap_or(0) as f64 / span;
+            values[i - 1] + fraction * (values[i] - values[i - 1])
+        })
+        .collect()
+}
+
+/// Earth-fixed velocities in km/s from central differences of positions in
+/// km (one-sided at the ends)
+fn finite_difference_velocities(times: &[DateTime<Utc>], positions: &[[f64; 3]]) -> Vec<[f64; 3]> {
+    let n = positions.len();
+    (0..n)
+        .map(|i| {
+            if n < 2 {
+                return [0.0; 3];
+            }
+            let (a, b) = (i.saturating_sub(1), (i + 1).min(n - 1));
+            let dt = (times[b] - times[a]).num_microseconds().unwrap_or(0) as f64 / 1e6;
+            if dt <= 0.0 {
+                return [0.0; 3];
+            }
+            [
+                (positions[b][0] - positions[a][0]) / dt,
+                (positions[b][1] - positions[a][1]) / dt,
+                (positions[b][2] - positions[a][2]) / dt,
+            ]
+        })
+        .collect()
+}
+
+// Implement the EphemerisBase trait for TrajectoryGroundEphemeris
+impl EphemerisBase for TrajectoryGroundEphemeris {
+    fn data(&self) -> &EphemerisData {
+        &self.common_data
+    }
+
+    fn data_mut(&mut self) -> &mut EphemerisData {
+        &mut self.common_data
+    }
+
+    fn get_itrs_data(&self) -> Option<&Array2<f64>> {
+        self.itrs.as_ref()
+    }
+
+    fn get_itrs_skycoord_ref(&self) -> Option<&Py<PyAny>> {
+        self.itrs_skycoord.get()
+    }
+
+    fn set_itrs_skycoord_cache(&self, skycoord: Py<PyAny>) -> Result<(), Py<PyAny>> {
+        self.itrs_skycoord.set(skycoord)
+    }
+
+    fn source_identity(&self) -> String {
+        format!(
+            "TrajectoryGroundEphemeris\ntimes={:?}\nlatitude={:?}\nlongitude={:?}\nheight={:?}\npolar_motion={}\natmosphere={}",
+            self.source_times,
+            self.source_latitude,
+            self.source_longitude,
+            self.source_height,
+            self.polar_motion,
+            self.atmosphere.as_str()
+        )
+    }
+
+    fn source_metadata(&self) -> Vec<(&'static str, serde_json::Value)> {
+        vec![
+            ("type", "TrajectoryGroundEphemeris".into()),
+            ("track_points", self.source_times.len().into()),
+            ("polar_motion", self.polar_motion.into()),
+            ("atmosphere", self.atmosphere.as_str().into()),
+        ]
+    }
+
+    fn applies_polar_motion(&self) -> bool {
+        self.polar_motion
+    }
+
+    fn radec_to_altaz(
+        &self,
+        ra_deg: f64,
+        dec_deg: f64,
+        time_indices: Option<&[usize]>,
+    ) -> Array2<f64> {
+        crate::utils::celestial::radec_to_altaz(ra_deg, dec_deg, self, time_indices, None)
+    }
+
+    fn is_ground_site(&self) -> bool {
+        true
+    }
+
+    fn airmass_heights(&self) -> Option<&Array1<f64>> {
+        match self.atmosphere {
+            AtmosphereModel::Standard => self.common_data.height_cache.get(),
+            AtmosphereModel::SeaLevel => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+
+    #[test]
+    fn test_unwrap_longitudes() {
+        // A flight across the antimeridian goes the short way
+        let unwrapped = unwrap_longitudes(&[170.0, 179.0, -178.0, -170.0]);
+        assert_eq!(unwrapped, vec![170.0, 179.0, 182.0, 190.0]);
+        assert_eq!(wrap_longitude(182.0), -178.0);
+        assert_eq!(wrap_longitude(180.0), -180.0);
+        assert_eq!(wrap_longitude(-45.0), -45.0);
+    }
+
+    #[test]
+    fn test_interpolate_linear() {
+        let t0 = Utc.with_ymd_and_hms(2024, 1, 1, 0, 0, 0).unwrap();
+        let source = vec![t0, t0 + chrono::Duration::seconds(100)];
+        let times = vec![t0, t0 + chrono::Duration::seconds(25), source[1]];
+        let values = interpolate_linear(&times, &source, &[10.0, 20.0]);
+        assert_eq!(values, vec![10.0, 12.5, 20.0]);
+    }
+
+    #[test]
+    fn test_finite_difference_velocities() {
+        let t0 = Utc.with_ymd_and_hms(2024, 1, 1, 0, 0, 0).unwrap();
+        let times: Vec<_> = (0..3)
+            .map(|i| t0 + chrono::Duration::seconds(10 * i))
+            .collect();
+        let positions = [[0.0, 0.0, 0.0], [2.0, 0.0, 0.0], [4.0, 1.0, 0.0]];
+        let velocities = finite_difference_velocities(&times, &positions);
+        assert_eq!(velocities[0], [0.2, 0.0, 0.0]);
+        assert_eq!(velocities[1], [0.2, 0.05, 0.0]);
+        assert_eq!(velocities[2], [0.2, 0.1, 0.0]);
+    }
+}
@@ -4,16 +4,60 @@ mod ephemeris;
 mod utils;
 
 // Re-export public API from ephemeris
+pub use ephemeris::ephemeris_common::EphemerisBase;
 pub use ephemeris::position_velocity::PositionVelocityData;
-pub use ephemeris::{FileEphemeris, GroundEphemeris, OEMEphemeris, SPICEEphemeris, TLEEphemeris};
+pub use ephemeris::{
+    AccessOpportunity, AlmanacEphemeris, ArrayEphemeris, CoverageGrid, CoverageResult,
+    DecayEstimate, EphemerisComparison, EphemerisSample, FileEphemeris, GEOEphemeris,
+    GroundEphemeris, GroundNetwork, HorizonsEphemeris, LinkGeometry, ManeuverCandidate,
+    MinorPlanet, NetworkContacts, NodalAnalysis, OEMEphemeris, ObservationGeometry, Provenance,
+    RinexNavEphemeris, SPICEEphemeris, SolarEclipse, StationContacts, TLEAccuracy, TLEEphemeris,
+    TrajectoryGroundEphemeris,
+};
 
 // Re-export constraint types
 pub use constraints::{
-    ConstraintResult, ConstraintViolation, MovingBodyResult, PyConstraint, VisibilityWindow,
+    AirmassSummary, AttitudeCheck, AttitudeViolation, ConstraintCube, ConstraintResult,
+    ConstraintViolation, FaceIncidence, MovingBodyResult, OrbitVisibility, PyConstraint, Schedule,
+    ScheduledObservation, SlewCheck, SlewIntrusion, VisibilityReport, VisibilityWindow,
 };
 
+// Constraint configs and evaluators for use from Rust
+pub use constraints::airmass::{AirmassBuilder, AirmassConfig};
+pub use constraints::alt_az::AltAzConfig;
+pub use constraints::body_proximity::BodyProximityConfig;
+pub use constraints::bright_star::BrightStarConfig;
+pub use constraints::core::{ConstraintConfig, ConstraintEvaluator};
+pub use constraints::cutoff_rigidity::CutoffRigidityConfig;
+pub use constraints::daytime::{DaytimeConfig, DaytimeMode, TwilightType};
+pub use constraints::earth_limb::{EarthLimbBuilder, EarthLimbConfig};
+pub use constraints::earth_occultation::EarthOccultationConfig;
+pub use constraints::eclipse::{EclipseBuilder, EclipseConfig};
+pub use constraints::error::ConstraintError;
+pub use constraints::geomagnetic_activity::GeomagneticActivityConfig;
+pub use constraints::gimbal::GimbalConfig;
+pub use constraints::ground_target_illumination::GroundTargetIlluminationConfig;
+pub use constraints::ground_visibility::GroundVisibilityConfig;
+pub use constraints::moon_phase::MoonPhaseConfig;
+pub use constraints::moon_proximity::{MoonProximityBuilder, MoonProximityConfig};
+pub use constraints::orbit_pole::OrbitPoleConfig;
+pub use constraints::orbit_ram::OrbitRamConfig;
+pub use constraints::planets_proximity::PlanetsProximityConfig;
+pub use constraints::saa::SAAConfig;
+pub use constraints::sky_region::SkyRegionConfig;
+pub use constraints::station_keeping::StationKeepingConfig;
+pub use constraints::sun_keepout::{KeepOutRollReference, SunKeepOutConfig};
+pub use constraints::sun_proximity::{SunProximityBuilder, SunProximityConfig};
+pub use constraints::threshold_schedule::ThresholdPeriod;
+
 // Make certain utils modules public for external access
-pub use utils::{eop_provider, naif_ids, ut1_provider};
+pub use utils::{eop_provider, naif_ids, sphere, ut1_provider};
+
+// Cooperative cancellation of long computations
+pub use utils::progress::CancellationToken;
+
+// Great-circle geometry
+pub use utils::sphere::SphericalPolygon;
 
 use pyo3::prelude::*;
 use pyo3::wrap_pyfunction;
@@ -225,17 +269,759 @@ fn init_eop_provider() -> bool {
     utils::eop_provider::init_eop_provider()
 }
 
+/// Expected error of the GCRS↔ITRS transformation at an epoch
+///
+/// Breaks the error down into UT1, polar motion, the uncorrected
+/// precession-nutation and not-yet-announced leap seconds, for the UT1, EOP
+/// and leap-second data currently loaded. `polar_motion` says whether the
+/// ephemerides in question apply the polar motion correction.
+#[pyfunction]
+#[pyo3(signature = (time, *, polar_motion=true))]
+fn transformation_accuracy(
+    py: Python,
+    time: &Bound<'_, pyo3::types::PyDateTime>,
+    polar_motion: bool,
+) -> PyResult<Py<PyAny>> {
+    let dt = pydatetime_to_chrono(time)?;
+    let report = utils::transform_accuracy::transform_accuracy(&dt, polar_motion);
+    utils::json_to_py::json_to_pyobject(py, &report.to_json())
+}
+
+/// Space-weather indices in effect at a time, or None without data for that day
+///
+/// Returns a dict with keys: date, kp, ap, ap_daily, f107_obs, f107_adj,
+/// f107_obs_previous, f107_obs_center81, f107_obs_last81, data_type
+#[pyfunction]
+fn get_space_weather(
+    py: Python,
+    py_datetime: &Bound<'_, pyo3::types::PyDateTime>,
+) -> PyResult<Option<pyo3::Py<pyo3::types::PyDict>>> {
+    let dt = pydatetime_to_chrono(py_datetime)?;
+    let Some(sample) = utils::space_weather::space_weather_at(&dt) else {
+        return Ok(None);
+    };
+    let dict = pyo3::types::PyDict::new(py);
+    dict.set_item("date", sample.date.format("%Y-%m-%d").to_string())?;
+    dict.set_item("kp", sample.kp)?;
+    dict.set_item("ap", sample.ap)?;
+    dict.set_item("ap_daily", sample.ap_daily)?;
+    dict.set_item("f107_obs", sample.f107_obs)?;
+    dict.set_item("f107_adj", sample.f107_adj)?;
+    dict.set_item("f107_obs_previous", sample.f107_obs_previous)?;
+    dict.set_item("f107_obs_center81", sample.f107_obs_center81)?;
+    dict.set_item("f107_obs_last81", sample.f107_obs_last81)?;
+    dict.set_item("data_type", sample.data_type)?;
+    Ok(Some(dict.into()))
+}
+
+#[pyfunction]
+fn is_space_weather_available() -> bool {
+    utils::space_weather::space_weather_data().is_some()
+}
+
+#[pyfunction]
+fn init_space_weather() -> bool {
+    utils::space_weather::init_space_weather()
+}
+
+/// Replace the space-weather data with a local Celestrak SW-All.csv file
+///
+/// Returns the number of days loaded.
+#[pyfunction]
+fn load_space_weather(path: &str) -> PyResult<usize> {
+    utils::space_weather::load_space_weather_file(path)
+        .map_err(pyo3::exceptions::PyValueError::new_err)
+}
+
 /// Returns the cache directory path used by rust_ephem for storing data files
 #[pyfunction]
 fn get_cache_dir() -> String {
     utils::config::CACHE_DIR.to_string_lossy().to_string()
 }
 
+/// Returns the configuration in effect, from config.toml and RUST_EPHEM_* variables
+///
+/// The Space-Track.org password is redacted; `config_path` is the file read, if any.
+#[pyfunction]
+fn get_config(py: Python) -> PyResult<Py<PyAny>> {
+    utils::json_to_py::json_to_pyobject(py, &utils::settings::settings_summary())
+}
+
+/// Start or stop timing constraint evaluators, data extraction and frame conversions
+///
+/// Timings recorded so far are kept; see `profiling_report`.
+#[pyfunction]
+#[pyo3(signature = (enabled=true))]
+fn enable_profiling(enabled: bool) {
+    utils::profiling::set_enabled(enabled);
+}
+
+/// Counts and wall times recorded since profiling was enabled or last reset
+///
+/// With `reset`, the timings are discarded after the report is taken.
+#[pyfunction]
+#[pyo3(signature = (*, reset=false))]
+fn profiling_report(py: Python, reset: bool) -> PyResult<Py<PyAny>> {
+    let report = utils::profiling::report();
+    if reset {
+        utils::profiling::reset();
+    }
+    utils::json_to_py::json_to_pyobject(py, &report)
+}
+
+/// Discard the recorded profiling timings
+#[pyfunction]
+fn reset_profiling() {
+    utils::profiling::reset();
+}
+
+/// Set the TLE cache TTL and Space-Track epoch tolerance used when a call gives none
+///
+/// `None` restores the value from the configuration.
+#[pyfunction]
+#[pyo3(signature = (cache_ttl=None, epoch_tolerance_days=None))]
+fn set_tle_cache_defaults(
+    cache_ttl: Option<u64>,
+    epoch_tolerance_days: Option<f64>,
+) -> PyResult<()> {
+    utils::tle_utils::set_cache_defaults(cache_ttl, epoch_tolerance_days)
+        .map_err(pyo3::exceptions::PyValueError::new_err)
+}
+
+/// Returns the TLE cache TTL (seconds) and Space-Track epoch tolerance (days) in effect
+#[pyfunction]
+fn get_tle_cache_defaults(py: Python) -> PyResult<Py<pyo3::types::PyDict>> {
+    let dict = pyo3::types::PyDict::new(py);
+    dict.set_item("cache_ttl", utils::tle_utils::default_cache_ttl())?;
+    dict.set_item(
+        "epoch_tolerance_days",
+        utils::tle_utils::default_epoch_tolerance_days(),
+    )?;
+    Ok(dict.into())
+}
+
+/// Clear the in-memory cache of JPL Horizons responses
+#[pyfunction]
+fn clear_horizons_cache() {
+    utils::horizons::clear_horizons_cache()
+}
+
+/// Load minor planet orbits from an MPCORB-format file
+///
+/// When `register` is true, every orbit becomes resolvable by name or
+/// designation in `get_body` and moving-body constraints.
+#[pyfunction]
+#[pyo3(signature = (path, *, register=true))]
+fn load_mpcorb(path: String, register: bool) -> PyResult<Vec<MinorPlanet>> {
+    ephemeris::minor_planet::load_mpcorb_file(&path, register)
+}
+
+/// Compare two ephemerides on a common grid
+///
+/// Returns position and velocity residuals of `other` relative to
+/// `reference` in the reference's radial / in-track / cross-track frame.
+#[pyfunction]
+#[pyo3(signature = (reference, other, *, step_size=None))]
+fn compare(
+    reference: &Bound<'_, PyAny>,
+    other: &Bound<'_, PyAny>,
+    step_size: Option<i64>,
+) -> PyResult<EphemerisComparison> {
+    ephemeris::comparison::compare_ephemerides(reference, other, step_size)
+}
+
+/// Resample two ephemerides onto a common time grid
+///
+/// Returns a pair of `ArrayEphemeris` over the span both cover, sampled at
+/// the first ephemeris's times or every `step_size` seconds.
+#[pyfunction]
+#[pyo3(signature = (first, second, *, step_size=None))]
+fn align(
+    first: &Bound<'_, PyAny>,
+    second: &Bound<'_, PyAny>,
+    step_size: Option<i64>,
+) -> PyResult<(ArrayEphemeris, ArrayEphemeris)> {
+    ephemeris::comparison::align_ephemerides(first, second, step_size)
+}
+
+/// Estimate TLE prediction accuracy from consecutive element sets
+///
+/// Each older TLE is propagated to the epochs of newer ones and the residuals
+/// are binned by prediction age. TLEs come from `tles` (a file path or URL
+/// with several TLEs, or a sequence of TLEs) or, for `norad_id`, from the
+/// Space-Track.org history between `begin` and `end` (default: last 30 days).
+#[pyfunction]
+#[pyo3(signature = (*, tles=None, norad_id=None, begin=None, end=None, max_age_days=7.0, bin_days=0.5, spacetrack_username=None, spacetrack_password=None))]
+#[allow(clippy::too_many_arguments)]
+fn estimate_tle_accuracy(
+    py: Python,
+    tles: Option<&Bound<'_, PyAny>>,
+    norad_id: Option<u32>,
+    begin: Option<&Bound<'_, PyAny>>,
+    end: Option<&Bound<'_, PyAny>>,
+    max_age_days: f64,
+    bin_days: f64,
+    spacetrack_username: Option<String>,
+    spacetrack_password: Option<String>,
+) -> PyResult<TLEAccuracy> {
+    let credentials = utils::tle_utils::build_credentials(
+        spacetrack_username.as_deref(),
+        spacetrack_password.as_deref(),
+    )
+    .map_err(pyo3::exceptions::PyValueError::new_err)?;
+    let history =
+        ephemeris::tle_accuracy::collect_tle_history(py, tles, norad_id, begin, end, credentials)?;
+    ephemeris::tle_accuracy::estimate_accuracy(&history, max_age_days, bin_days)
+}
+
+/// Estimate orbit decay and re-entry from a TLE or OMM under atmospheric drag
+///
+/// `tle` is a TLE in any form accepted by `estimate_tle_accuracy`, or an OMM
+/// mapping with CCSDS keys such as a Celestrak or Space-Track JSON record.
+/// The ballistic coefficient m/(Cd A) in kg/m² defaults to the one implied by
+/// BSTAR. F10.7 and Ap come from the space-weather data unless fixed with
+/// `f107` and `ap`. Integration stops at `reentry_altitude_km` or after
+/// `max_days` (default 25 years).
+#[pyfunction]
+#[pyo3(signature = (tle, *, ballistic_coefficient=None, reentry_altitude_km=180.0, max_days=9131.0, f107=None, ap=None))]
+fn estimate_decay(
+    py: Python,
+    tle: &Bound<'_, PyAny>,
+    ballistic_coefficient: Option<f64>,
+    reentry_altitude_km: f64,
+    max_days: f64,
+    f107: Option<f64>,
+    ap: Option<f64>,
+) -> PyResult<DecayEstimate> {
+    let elements = ephemeris::orbit_decay::decay_elements_from_python(tle)?;
+    let activity = ephemeris::orbit_decay::Activity { f107, ap };
+    py.detach(|| {
+        ephemeris::orbit_decay::estimate_decay(
+            &elements,
+            ballistic_coefficient,
+            reentry_altitude_km,
+            max_days,
+            &activity,
+        )
+    })
+}
+
+/// Sanity-check a TLE or OMM element set
+///
+/// `elements` is a TLE in any form accepted by `estimate_tle_accuracy`, an
+/// OMM mapping with CCSDS keys, or an OMM JSON object string. Returns one
+/// dict per problem with `level` ("error" or "warning"), `field` and
+/// `message`; an empty list means the elements look sound. Unparseable
+/// element sets give a single error with field "format". The epoch is
+/// checked against `now` (default: the current time).
+#[pyfunction]
+#[pyo3(signature = (elements, *, now=None))]
+fn validate_elements(
+    py: Python,
+    elements: &Bound<'_, PyAny>,
+    now: Option<&Bound<'_, pyo3::types::PyDateTime>>,
+) -> PyResult<Py<PyAny>> {
+    let now = match now {
+        Some(now) => crate::utils::time_utils::python_datetime_to_utc(now)?,
+        None => chrono::Utc::now(),
+    };
+    let issues = match ephemeris::element_validation::elements_from_python(elements)? {
+        Ok(elements) => ephemeris::element_validation::check_elements(&elements, now),
+        Err(issue) => vec![issue],
+    };
+    let issues: Vec<serde_json::Value> = issues.iter().map(|issue| issue.to_json()).collect();
+    utils::json_to_py::json_to_pyobject(py, &serde_json::Value::Array(issues))
+}
+
+/// Detect candidate maneuvers in an ephemeris or a TLE history
+///
+/// With `ephemeris` (an OEM or any other ephemeris), each sample is propagated
+/// to the next under two-body plus J2 gravity and velocity mismatches above
+/// `delta_v_threshold` (m/s) are reported. Otherwise TLEs come from `tles` or
+/// `norad_id` as in `estimate_tle_accuracy`, and jumps of the mean
+/// semi-major axis larger than `sma_threshold_km` after removing the median
+/// drift are reported.
+#[pyfunction]
+#[pyo3(signature = (ephemeris=None, *, tles=None, norad_id=None, begin=None, end=None, delta_v_threshold=0.1, sma_threshold_km=0.2, spacetrack_username=None, spacetrack_password=None))]
+#[allow(clippy::too_many_arguments)]
+fn detect_maneuvers(
+    py: Python,
+    ephemeris: Option<&Bound<'_, PyAny>>,
+    tles: Option<&Bound<'_, PyAny>>,
+    norad_id: Option<u32>,
+    begin: Option<&Bound<'_, PyAny>>,
+    end: Option<&Bound<'_, PyAny>>,
+    delta_v_threshold: f64,
+    sma_threshold_km: f64,
+    spacetrack_username: Option<String>,
+    spacetrack_password: Option<String>,
+) -> PyResult<Vec<ManeuverCandidate>> {
+    if let Some(ephemeris) = ephemeris {
+        if tles.is_some() || norad_id.is_some() {
+            return Err(pyo3::exceptions::PyValueError::new_err(
+                "Provide either ephemeris or a TLE history, not both",
+            ));
+        }
+        let (times, states) = ephemeris::maneuver_detection::ephemeris_states(ephemeris)?;
+        return py.detach(|| {
+            ephemeris::maneuver_detection::detect_from_states(
+                &times,
+                &states,
+                delta_v_threshold / 1000.0,
+            )
+        });
+    }
+    let credentials = utils::tle_utils::build_credentials(
+        spacetrack_username.as_deref(),
+        spacetrack_password.as_deref(),
+    )
+    .map_err(pyo3::exceptions::PyValueError::new_err)?;
+    let history =
+        ephemeris::tle_accuracy::collect_tle_history(py, tles, norad_id, begin, end, credentials)?;
+    ephemeris::maneuver_detection::detect_from_tles(&history, sma_threshold_km)
+}
+
+/// Nodal period, RAAN drift and repeat ground-track cycle of an orbit
+///
+/// Ascending node crossings on the true equator of date give the nodal
+/// period of each revolution and a fitted RAAN drift rate. The shortest
+/// repeat cycle of at most `max_repeat_days` nodal days whose ascending node
+/// returns within `repeat_tolerance_km` of its start is reported too.
+#[pyfunction]
+#[pyo3(signature = (ephemeris, *, max_repeat_days=60, repeat_tolerance_km=10.0))]
+fn nodal_analysis(
+    ephemeris: &Bound<'_, PyAny>,
+    max_repeat_days: u32,
+    repeat_tolerance_km: f64,
+) -> PyResult<NodalAnalysis> {
+    ephemeris::nodal_analysis::nodal_analysis(ephemeris, max_repeat_days, repeat_tolerance_km)
+}
+
+/// Link geometry and path loss between a ground station and a spacecraft
+///
+/// Samples slant range, elevation and azimuth at the ephemeris times and
+/// derives the free-space path loss at `frequency_mhz` plus an atmospheric
+/// penalty that scales `zenith_attenuation_db` with elevation. `station` is a
+/// `GroundEphemeris`, a site name or a `(name, latitude, longitude, height)`
+/// tuple, as for `GroundNetwork`. `polar_motion` overrides the ephemeris's
+/// own polar motion setting for this call.
+#[pyfunction]
+#[pyo3(signature = (ephemeris, station, frequency_mhz, *, zenith_attenuation_db=0.0, min_elevation=0.0, polar_motion=None))]
+fn link_geometry(
+    ephemeris: &Bound<'_, PyAny>,
+    station: &Bound<'_, PyAny>,
+    frequency_mhz: f64,
+    zenith_attenuation_db: f64,
+    min_elevation: f64,
+    polar_motion: Option<bool>,
+) -> PyResult<LinkGeometry> {
+    ephemeris::link_geometry::link_geometry(
+        ephemeris,
+        station,
+        frequency_mhz,
+        zenith_attenuation_db,
+        min_elevation,
+        polar_motion,
+    )
+}
+
+/// Resolve an object name to ICRS (RA, Dec) in degrees through CDS Sesame
+///
+/// Results are cached in memory and on disk. With `allow_network=False` only
+/// cached names resolve; others raise `ValueError`.
+#[pyfunction]
+#[pyo3(signature = (name, *, allow_network=true))]
+fn resolve_name(name: &str, allow_network: bool) -> PyResult<(f64, f64)> {
+    utils::sesame::resolve_name(name, allow_network)
+        .map_err(pyo3::exceptions::PyValueError::new_err)
+}
+
+/// Clear the cache of resolved object names
+#[pyfunction]
+fn clear_name_cache() {
+    utils::sesame::clear_name_cache()
+}
+
+/// Target list entries as (RA, Dec), resolving object names through Sesame
+fn target_coordinates(targets: Vec<Bound<'_, PyAny>>) -> PyResult<Vec<(f64, f64)>> {
+    targets
+        .iter()
+        .map(|target| match target.extract::<String>() {
+            Ok(name) => resolve_name(&name, true),
+            Err(_) => target.extract::<(f64, f64)>(),
+        })
+        .collect()
+}
+
+/// Greedily schedule observations of targets on an ephemeris timeline
+///
+/// Targets are placed in priority order at the earliest time their exposure
+/// fits inside a visibility window without overlapping earlier placements,
+/// leaving time to slew at `slew_rate` (deg/s) plus `settle_time` (s)
+/// between consecutive observations. Targets are (RA, Dec) pairs or object
+/// names resolved with `resolve_name`.
+#[pyfunction]
+#[pyo3(signature = (ephemeris, targets, constraints, exposure_times, priorities=None, *, slew_rate=1.0, settle_time=0.0))]
+#[allow(clippy::too_many_arguments)]
+fn schedule(
+    ephemeris: &Bound<'_, PyAny>,
+    targets: Vec<Bound<'_, PyAny>>,
+    constraints: &Bound<'_, PyAny>,
+    exposure_times: &Bound<'_, PyAny>,
+    priorities: Option<Vec<f64>>,
+    slew_rate: f64,
+    settle_time: f64,
+) -> PyResult<Schedule> {
+    let targets = target_coordinates(targets)?;
+    constraints::scheduler::schedule_targets(
+        ephemeris,
+        &targets,
+        constraints,
+        exposure_times,
+        priorities,
+        slew_rate,
+        settle_time,
+    )
+}
+
+/// Summarize the visibility of each target under a constraint
+///
+/// Reports total visible time, the longest window, first and last visible
+/// times and the time each blocking constraint is violated, per target.
+/// Targets are (RA, Dec) pairs or object names resolved with `resolve_name`.
+#[pyfunction]
+#[pyo3(signature = (ephemeris, targets, constraint, *, names=None))]
+fn visibility_report(
+    ephemeris: &Bound<'_, PyAny>,
+    targets: Vec<Bound<'_, PyAny>>,
+    constraint: PyRef<PyConstraint>,
+    names: Option<Vec<String>>,
+) -> PyResult<VisibilityReport> {
+    let targets = target_coordinates(targets)?;
+    constraints::report::visibility_report(ephemeris, &targets, &constraint, names)
+}
+
+/// Per-orbit visibility of a target under a constraint
+///
+/// Splits the ephemeris into orbits at the ascending node and reports the
+/// visible fraction of each orbit and where in the orbit the target is
+/// visible, by argument of latitude and by solar phase (0° at orbit noon,
+/// 270° on the dawn side). The target is an (RA, Dec) pair or an object
+/// name resolved with `resolve_name`.
+#[pyfunction]
+fn orbit_visibility(
+    ephemeris: &Bound<'_, PyAny>,
+    target: Bound<'_, PyAny>,
+    constraint: PyRef<PyConstraint>,
+) -> PyResult<OrbitVisibility> {
+    let target = target_coordinates(vec![target])?[0];
+    constraints::orbit_visibility::orbit_visibility(ephemeris, target, &constraint)
+}
+
+/// Evaluate several constraints for many targets as one boolean cube
+///
+/// Returns a `ConstraintCube` holding the violation masks with shape
+/// (n_constraints, n_targets, n_times) over all ephemeris times. The
+/// (constraint, block of `chunk_size` targets) tasks run in parallel with the
+/// GIL released.
+#[pyfunction]
+#[pyo3(signature = (ephemeris, constraints, target_ras, target_decs, *, chunk_size=crate::constraints::cube::DEFAULT_CHUNK_SIZE))]
+fn constraint_cube(
+    py: Python,
+    ephemeris: &Bound<'_, PyAny>,
+    constraints: Vec<PyRef<PyConstraint>>,
+    target_ras: Vec<f64>,
+    target_decs: Vec<f64>,
+    chunk_size: usize,
+) -> PyResult<ConstraintCube> {
+    constraints::cube::constraint_cube(
+        py,
+        ephemeris,
+        &constraints,
+        &target_ras,
+        &target_decs,
+        chunk_size,
+    )
+}
+
+/// Directions of a virtual target over an ephemeris
+///
+/// Returns (ras, decs) arrays in degrees, one per ephemeris time, for
+/// "nadir", "limb", "sun", "anti_sun", "ram" or "anti_ram". `azimuth`
+/// (degrees, "limb" only) places the limb tangent point about nadir, 0
+/// toward ram and 90 toward the orbit normal.
+#[pyfunction]
+#[pyo3(signature = (ephemeris, target, *, azimuth=None))]
+fn virtual_target_radec(
+    py: Python,
+    ephemeris: &Bound<'_, PyAny>,
+    target: &str,
+    azimuth: Option<f64>,
+) -> PyResult<(Py<PyAny>, Py<PyAny>)> {
+    use numpy::IntoPyArray;
+    let target = constraints::virtual_target::VirtualTarget::parse(target, azimuth)
+        .map_err(pyo3::exceptions::PyValueError::new_err)?;
+    let (ras, decs) = crate::ephemeris::with_ephemeris(ephemeris, |ephem| target.radec(ephem))?;
+    Ok((ras.into_pyarray(py).into(), decs.into_pyarray(py).into()))
+}
+
+/// Great-circle separations in degrees between corresponding points
+#[pyfunction]
+fn great_circle_separation(
+    py: Python,
+    lon1: Vec<f64>,
+    lat1: Vec<f64>,
+    lon2: Vec<f64>,
+    lat2: Vec<f64>,
+) -> PyResult<Py<PyAny>> {
+    use numpy::IntoPyArray;
+    utils::sphere::check_lengths(&[&lon1, &lat1, &lon2, &lat2])?;
+    let values: Vec<f64> = (0..lon1.len())
+        .map(|i| utils::sphere::separation(lon1[i], lat1[i], lon2[i], lat2[i]))
+        .collect();
+    Ok(ndarray::Array1::from(values).into_pyarray(py).into())
+}
+
+/// Position angles (degrees east of north) of the second points from the first
+#[pyfunction]
+fn position_angle(
+    py: Python,
+    lon1: Vec<f64>,
+    lat1: Vec<f64>,
+    lon2: Vec<f64>,
+    lat2: Vec<f64>,
+) -> PyResult<Py<PyAny>> {
+    use numpy::IntoPyArray;
+    utils::sphere::check_lengths(&[&lon1, &lat1, &lon2, &lat2])?;
+    let values: Vec<f64> = (0..lon1.len())
+        .map(|i| utils::sphere::position_angle(lon1[i], lat1[i], lon2[i], lat2[i]))
+        .collect();
+    Ok(ndarray::Array1::from(values).into_pyarray(py).into())
+}
+
+/// Whether corresponding pairs of cones (centres and half-angles in degrees) overlap
+#[pyfunction]
+fn cones_overlap(
+    py: Python,
+    lon1: Vec<f64>,
+    lat1: Vec<f64>,
+    radius1: Vec<f64>,
+    lon2: Vec<f64>,
+    lat2: Vec<f64>,
+    radius2: Vec<f64>,
+) -> PyResult<Py<PyAny>> {
+    use numpy::IntoPyArray;
+    utils::sphere::check_lengths(&[&lon1, &lat1, &radius1, &lon2, &lat2, &radius2])?;
+    if radius1
+        .iter()
+        .chain(&radius2)
+        .any(|&r| r < 0.0 || r.is_nan())
+    {
+        return Err(pyo3::exceptions::PyValueError::new_err(
+            "Cone radii must be non-negative",
+        ));
+    }
+    let values: Vec<bool> = (0..lon1.len())
+        .map(|i| {
+            utils::sphere::cones_overlap(lon1[i], lat1[i], radius1[i], lon2[i], lat2[i], radius2[i])
+        })
+        .collect();
+    Ok(ndarray::Array1::from(values).into_pyarray(py).into())
+}
+
+/// Check a planned attitude history against pointing constraints
+///
+/// `quaternions` has one scalar-last (x, y, z, w) body-to-ICRS quaternion
+/// per ephemeris time. Each body axis in `axes` (name to body-frame vector,
+/// default `{"boresight": (1, 0, 0)}`) is evaluated against every
+/// constraint, and violation windows are tagged with the axis and constraint.
+#[pyfunction]
+#[pyo3(signature = (ephemeris, quaternions, constraints, *, axes=None))]
+fn check_attitude(
+    ephemeris: &Bound<'_, PyAny>,
+    quaternions: Vec<[f64; 4]>,
+    constraints: Vec<PyRef<PyConstraint>>,
+    axes: Option<Bound<'_, pyo3::types::PyDict>>,
+) -> PyResult<AttitudeCheck> {
+    let axes = match axes {
+        Some(axes) => axes
+            .iter()
+            .map(|(name, vector)| Ok((name.extract()?, vector.extract()?)))
+            .collect::<PyResult<Vec<(String, [f64; 3])>>>()?,
+        None => {
+            let (name, vector) = constraints::attitude::DEFAULT_AXIS;
+            vec![(name.to_string(), vector)]
+        }
+    };
+    constraints::attitude::check_attitude(ephemeris, &quaternions, &constraints, &axes)
+}
+
+/// Sun incidence angle on each body face over a planned attitude history
+///
+/// `quaternions` has one scalar-last (x, y, z, w) body-to-ICRS quaternion
+/// per ephemeris time and `faces` maps face names to outward body-frame
+/// normals. A face is sunlit when the Sun is less than 90 degrees from its
+/// normal and the spacecraft is outside Earth's shadow (umbra and penumbra,
+/// or the umbra only with `umbra_only`).
+#[pyfunction]
+#[pyo3(signature = (ephemeris, quaternions, faces, *, umbra_only=false))]
+fn face_sun_incidence(
+    ephemeris: &Bound<'_, PyAny>,
+    quaternions: Vec<[f64; 4]>,
+    faces: Bound<'_, pyo3::types::PyDict>,
+    umbra_only: bool,
+) -> PyResult<FaceIncidence> {
+    let faces = faces
+        .iter()
+        .map(|(name, normal)| Ok((name.extract()?, normal.extract()?)))
+        .collect::<PyResult<Vec<(String, [f64; 3])>>>()?;
+    constraints::attitude::face_sun_incidence(ephemeris, &quaternions, &faces, umbra_only)
+}
+
+/// Check a slew for Sun and Moon intrusions into star-tracker exclusion cones
+///
+/// The slew starts at `start_time` and follows the eigen-axis rotation from
+/// `start_quaternion` to `end_quaternion` (scalar-last, body-to-ICRS) at
+/// `slew_rate` degrees per second. `trackers` maps tracker names to
+/// body-frame boresights; the Sun and Moon must stay more than `sun_angle`
+/// and `moon_angle` degrees from each.
+#[pyfunction]
+#[pyo3(signature = (ephemeris, start_time, start_quaternion, end_quaternion, slew_rate, trackers, *, sun_angle, moon_angle, step_seconds=1.0))]
+#[allow(clippy::too_many_arguments)]
+fn check_slew(
+    ephemeris: &Bound<'_, PyAny>,
+    start_time: &Bound<'_, PyAny>,
+    start_quaternion: [f64; 4],
+    end_quaternion: [f64; 4],
+    slew_rate: f64,
+    trackers: Bound<'_, pyo3::types::PyDict>,
+    sun_angle: f64,
+    moon_angle: f64,
+    step_seconds: f64,
+) -> PyResult<SlewCheck> {
+    let trackers = trackers
+        .iter()
+        .map(|(name, axis)| Ok((name.extract()?, axis.extract()?)))
+        .collect::<PyResult<Vec<(String, [f64; 3])>>>()?;
+    constraints::attitude::check_slew(
+        ephemeris,
+        crate::utils::time_utils::python_datetime_to_utc(start_time)?,
+        start_quaternion,
+        end_quaternion,
+        slew_rate,
+        &trackers,
+        sun_angle,
+        moon_angle,
+        step_seconds,
+    )
+}
+
+/// Summarize the airmass of each target over an ephemeris
+///
+/// Returns an `AirmassSummary` with the minimum airmass per target, the time
+/// it is reached and the hours spent at or below `max_airmass`, using the
+/// fast (Kasten) airmass approximation.
+#[pyfunction]
+#[pyo3(signature = (ephemeris, ras, decs, *, max_airmass=2.0))]
+fn airmass_summary(
+    ephemeris: &Bound<'_, PyAny>,
+    ras: Vec<f64>,
+    decs: Vec<f64>,
+    max_airmass: f64,
+) -> PyResult<AirmassSummary> {
+    constraints::airmass_summary::airmass_summary(ephemeris, &ras, &decs, max_airmass)
+}
+
+/// Remove all registered minor planet orbits
+#[pyfunction]
+fn clear_minor_planets() {
+    utils::mpc::clear_minor_planets()
+}
+
+/// Look up an observatory or ground-station site by name, alias or IAU code
+///
+/// Returns a dict with keys: name, code, latitude, longitude, height, aliases
+#[pyfunction]
+fn get_site(py: Python, name: &str) -> PyResult<pyo3::Py<pyo3::types::PyDict>> {
+    let site = utils::sites::resolve_site(name).map_err(pyo3::exceptions::PyValueError::new_err)?;
+    let dict = pyo3::types::PyDict::new(py);
+    dict.set_item("name", site.name)?;
+    dict.set_item("code", site.code)?;
+    dict.set_item("latitude", site.latitude)?;
+    dict.set_item("longitude", site.longitude)?;
+    dict.set_item("height", site.height)?;
+    dict.set_item("aliases", site.aliases)?;
+    Ok(dict.into())
+}
+
+/// Names of all known sites, sorted
+#[pyfunction]
+fn list_sites() -> Vec<String> {
+    utils::sites::site_names()
+}
+
+/// Add a site to the registry (replacing any site with the same name)
+#[pyfunction]
+#[pyo3(signature = (name, latitude, longitude, height, *, code=None, aliases=None))]
+fn register_site(
+    name: String,
+    latitude: f64,
+    longitude: f64,
+    height: f64,
+    code: Option<String>,
+    aliases: Option<Vec<String>>,
+) -> PyResult<()> {
+    utils::sites::register_site(utils::sites::Site {
+        name,
+        code,
+        latitude,
+        longitude,
+        height,
+        aliases: aliases.unwrap_or_default(),
+    })
+    .map_err(pyo3::exceptions::PyValueError::new_err)
+}
+
+/// Set the radii of a body used by limb, eclipse and angular-radius calculations
+///
+/// `body` is a name or NAIF ID; bodies unknown to SPICE may be given a shape
+/// too. `polar_km` defaults to `equatorial_km`. Overrides apply to constraints
+/// created and angular radii first computed after the call.
+#[pyfunction]
+#[pyo3(signature = (body, equatorial_km, polar_km=None))]
+fn set_body_radii(body: &str, equatorial_km: f64, polar_km: Option<f64>) -> PyResult<()> {
+    utils::body_properties::set_body_radii(body, equatorial_km, polar_km)
+        .map_err(pyo3::exceptions::PyValueError::new_err)
+}
+
+/// Returns the radii (km) of a body in effect as a dict with keys: equatorial_km, polar_km
+#[pyfunction]
+fn get_body_radii(py: Python, body: &str) -> PyResult<Py<pyo3::types::PyDict>> {
+    let radii = utils::body_properties::body_radii(body).ok_or_else(|| {
+        pyo3::exceptions::PyValueError::new_err(format!(
+            "No radii known for body '{}'. Use set_body_radii() to add them",
+            body
+        ))
+    })?;
+    let dict = pyo3::types::PyDict::new(py);
+    dict.set_item("equatorial_km", radii.equatorial)?;
+    dict.set_item("polar_km", radii.polar)?;
+    Ok(dict.into())
+}
+
+/// Restore the built-in radii of one body, or of all bodies when `body` is None
+#[pyfunction]
+#[pyo3(signature = (body=None))]
+fn reset_body_radii(body: Option<&str>) {
+    utils::body_properties::reset_body_radii(body)
+}
+
 /// Fetch a TLE from various sources (file, URL, Celestrak, Space-Track.org)
 ///
-/// Returns a dict with keys: line1, line2, name (optional), epoch, source
+/// Returns a dict with keys: line1, line2, name (optional), epoch, source,
+/// decay_date (the SATCAT decay date for NORAD ID and name lookups, or None)
 #[pyfunction]
-#[pyo3(signature = (*, tle=None, norad_id=None, norad_name=None, epoch=None, spacetrack_username=None, spacetrack_password=None, epoch_tolerance_days=None, enforce_source=None))]
+#[pyo3(signature = (*, tle=None, norad_id=None, norad_name=None, epoch=None, spacetrack_username=None, spacetrack_password=None, epoch_tolerance_days=None, enforce_source=None, cache_ttl=None))]
 #[allow(clippy::too_many_arguments)]
 fn fetch_tle(
     py: Python,
@@ -247,6 +1033,7 @@ fn fetch_tle(
     spacetrack_password: Option<String>,
     epoch_tolerance_days: Option<f64>,
     enforce_source: Option<String>,
+    cache_ttl: Option<u64>,
 ) -> PyResult<pyo3::Py<pyo3::types::PyDict>> {
     use crate::utils::tle_utils;
 
@@ -272,7 +1059,10 @@ fn fetch_tle(
                 norad_name.as_deref(),
                 epoch_chrono.as_ref(),
                 credentials,
-                epoch_tolerance_days,
+                tle_utils::TleCacheOptions {
+                    cache_ttl,
+                    epoch_tolerance_days,
+                },
                 enforce_source.as_deref(),
             )
             .map_err(|e| e.to_string())
@@ -308,6 +1098,11 @@ fn fetch_tle(
 
     dict.set_item("epoch", dt_with_tz)?;
     dict.set_item("source", fetched.source)?;
+    let decay_date = fetched
+        .decay_date
+        .map(|date| pyo3::types::PyDate::new(py, date.year(), date.month() as u8, date.day() as u8))
+        .transpose()?;
+    dict.set_item("decay_date", decay_date)?;
 
     Ok(dict.into())
 }
@@ -318,13 +1113,48 @@ fn _rust_ephem(_py: Python, m: &Bound<'_, PyModule>) -> PyResult<()> {
     m.add_class::<SPICEEphemeris>()?;
     m.add_class::<OEMEphemeris>()?;
     m.add_class::<GroundEphemeris>()?;
+    m.add_class::<TrajectoryGroundEphemeris>()?;
     m.add_class::<FileEphemeris>()?;
+    m.add_class::<HorizonsEphemeris>()?;
+    m.add_class::<AlmanacEphemeris>()?;
+    m.add_class::<RinexNavEphemeris>()?;
+    m.add_class::<ArrayEphemeris>()?;
+    m.add_class::<GEOEphemeris>()?;
+    m.add_class::<MinorPlanet>()?;
+    m.add_class::<GroundNetwork>()?;
+    m.add_class::<NetworkContacts>()?;
+    m.add_class::<StationContacts>()?;
+    m.add_class::<LinkGeometry>()?;
+    m.add_class::<AccessOpportunity>()?;
+    m.add_class::<SolarEclipse>()?;
+    m.add_class::<CoverageGrid>()?;
+    m.add_class::<CoverageResult>()?;
+    m.add_class::<EphemerisComparison>()?;
+    m.add_class::<TLEAccuracy>()?;
+    m.add_class::<DecayEstimate>()?;
+    m.add_class::<ManeuverCandidate>()?;
+    m.add_class::<NodalAnalysis>()?;
     m.add_class::<PositionVelocityData>()?;
+    m.add_class::<EphemerisSample>()?;
+    m.add_class::<ObservationGeometry>()?;
+    m.add_class::<Provenance>()?;
     m.add_class::<PyConstraint>()?;
     m.add_class::<ConstraintResult>()?;
     m.add_class::<ConstraintViolation>()?;
     m.add_class::<VisibilityWindow>()?;
     m.add_class::<MovingBodyResult>()?;
+    m.add_class::<Schedule>()?;
+    m.add_class::<ScheduledObservation>()?;
+    m.add_class::<VisibilityReport>()?;
+    m.add_class::<OrbitVisibility>()?;
+    m.add_class::<ConstraintCube>()?;
+    m.add_class::<AttitudeCheck>()?;
+    m.add_class::<AttitudeViolation>()?;
+    m.add_class::<SlewCheck>()?;
+    m.add_class::<SlewIntrusion>()?;
+    m.add_class::<FaceIncidence>()?;
+    m.add_class::<AirmassSummary>()?;
+    m.add_class::<CancellationToken>()?;
     m.add_function(wrap_pyfunction!(init_planetary_ephemeris, m)?)?;
     m.add_function(wrap_pyfunction!(download_planetary_ephemeris, m)?)?;
     m.add_function(wrap_pyfunction!(ensure_planetary_ephemeris, m)?)?;
@@ -336,7 +1166,50 @@ fn _rust_ephem(_py: Python, m: &Bound<'_, PyModule>) -> PyResult<()> {
     m.add_function(wrap_pyfunction!(get_polar_motion, m)?)?;
     m.add_function(wrap_pyfunction!(is_eop_available, m)?)?;
     m.add_function(wrap_pyfunction!(init_eop_provider, m)?)?;
+    m.add_function(wrap_pyfunction!(transformation_accuracy, m)?)?;
+    m.add_function(wrap_pyfunction!(get_space_weather, m)?)?;
+    m.add_function(wrap_pyfunction!(is_space_weather_available, m)?)?;
+    m.add_function(wrap_pyfunction!(init_space_weather, m)?)?;
+    m.add_function(wrap_pyfunction!(load_space_weather, m)?)?;
     m.add_function(wrap_pyfunction!(get_cache_dir, m)?)?;
+    m.add_function(wrap_pyfunction!(get_config, m)?)?;
+    m.add_function(wrap_pyfunction!(enable_profiling, m)?)?;
+    m.add_function(wrap_pyfunction!(profiling_report, m)?)?;
+    m.add_function(wrap_pyfunction!(reset_profiling, m)?)?;
+    m.add_function(wrap_pyfunction!(clear_horizons_cache, m)?)?;
+    m.add_function(wrap_pyfunction!(set_tle_cache_defaults, m)?)?;
+    m.add_function(wrap_pyfunction!(get_tle_cache_defaults, m)?)?;
+    m.add_function(wrap_pyfunction!(resolve_name, m)?)?;
+    m.add_function(wrap_pyfunction!(clear_name_cache, m)?)?;
+    m.add_function(wrap_pyfunction!(load_mpcorb, m)?)?;
+    m.add_function(wrap_pyfunction!(clear_minor_planets, m)?)?;
+    m.add_function(wrap_pyfunction!(compare, m)?)?;
+    m.add_function(wrap_pyfunction!(align, m)?)?;
+    m.add_function(wrap_pyfunction!(estimate_tle_accuracy, m)?)?;
+    m.add_function(wrap_pyfunction!(estimate_decay, m)?)?;
+    m.add_function(wrap_pyfunction!(validate_elements, m)?)?;
+    m.add_function(wrap_pyfunction!(detect_maneuvers, m)?)?;
+    m.add_function(wrap_pyfunction!(nodal_analysis, m)?)?;
+    m.add_function(wrap_pyfunction!(link_geometry, m)?)?;
+    m.add_function(wrap_pyfunction!(schedule, m)?)?;
+    m.add_function(wrap_pyfunction!(visibility_report, m)?)?;
+    m.add_function(wrap_pyfunction!(orbit_visibility, m)?)?;
+    m.add_function(wrap_pyfunction!(constraint_cube, m)?)?;
+    m.add_function(wrap_pyfunction!(great_circle_separation, m)?)?;
+    m.add_function(wrap_pyfunction!(position_angle, m)?)?;
+    m.add_function(wrap_pyfunction!(cones_overlap, m)?)?;
+    m.add_class::<SphericalPolygon>()?;
+    m.add_function(wrap_pyfunction!(check_attitude, m)?)?;
+    m.add_function(wrap_pyfunction!(check_slew, m)?)?;
+    m.add_function(wrap_pyfunction!(face_sun_incidence, m)?)?;
+    m.add_function(wrap_pyfunction!(virtual_target_radec, m)?)?;
+    m.add_function(wrap_pyfunction!(airmass_summary, m)?)?;
+    m.add_function(wrap_pyfunction!(get_site, m)?)?;
+    m.add_function(wrap_pyfunction!(list_sites, m)?)?;
+    m.add_function(wrap_pyfunction!(register_site, m)?)?;
+    m.add_function(wrap_pyfunction!(set_body_radii, m)?)?;
+    m.add_function(wrap_pyfunction!(get_body_radii, m)?)?;
+    m.add_function(wrap_pyfunction!(reset_body_radii, m)?)?;
     m.add_function(wrap_pyfunction!(fetch_tle, m)?)?;
     Ok(())
 }
@@ -0,0 +1,291 @@
+//! Airmass and refraction for observers above sea level
+//!
+//! Ground observers use the Kasten & Young airmass formula, a fit to the
+//! sea-level atmosphere. It does not carry over to platforms high in the
+//! atmosphere: from a stratospheric balloon at 35 km the air above is a
+//! thin shell of about 7 km scale height, targets below the astronomical
+//! horizon (down to the ~6° dip of the Earth's limb) are still seen through
+//! a finite column, and refraction is ~1% of its sea-level value.
+//!
+//! The `standard` model integrates along the line of sight through the US
+//! Standard Atmosphere 1976 (up to 86 km) from the observer's height.
+//! Airmass is the column along the line of sight relative to the column
+//! straight up from the observer, so it is 1.0 at the zenith as for ground
+//! sites. Refraction is the total bending of the ray between the observer
+//! and space, to first order in the refractivity of air. The line of sight
+//! is taken as straight: at sea level this matches the Kasten & Young
+//! airmass to 1% above 10° altitude, and within a few degrees of grazing
+//! the ground both airmass and refraction come out up to ~10% low.
+
+use crate::utils::celestial::calculate_airmass_kasten;
+use crate::utils::config::EARTH_RADIUS_KM;
+
+/// Top of the US Standard Atmosphere 1976 lower layers, geometric km
+pub const ATMOSPHERE_TOP_KM: f64 = 86.0;
+
+/// Effective Earth radius for geopotential height, km
+const GEOPOTENTIAL_RADIUS_KM: f64 = 6356.766;
+
+/// g0 * M / R* in K/km
+const HYDROSTATIC_CONSTANT: f64 = 34.1632;
+
+/// Sea-level pressure (Pa) and temperature (K)
+const SEA_LEVEL_PRESSURE: f64 = 101325.0;
+const SEA_LEVEL_TEMPERATURE: f64 = 288.15;
+
+/// Refractivity (n - 1) of sea-level standard air for visible light
+const SEA_LEVEL_REFRACTIVITY: f64 = 2.77e-4;
+
+/// Layer bases as (geopotential km, temperature K, lapse rate K/km, pressure Pa)
+const LAYERS: [(f64, f64, f64, f64); 7] = [
+    (0.0, 288.15, -6.5, 101325.0),
+    (11.0, 216.65, 0.0, 22632.06),
+    (20.0, 216.65, 1.0, 5474.889),
+    (32.0, 228.65, 2.8, 868.0187),
+    (47.0, 270.65, 0.0, 110.9063),
+    (51.0, 270.65, -2.8, 66.93887),
+    (71.0, 214.65, -2.0, 3.956420),
+];
+
+/// Integration step along the line of sight, as a change of height in km
+const STEP_KM: f64 = 0.5;
+
+/// Longest integration step in km, where the line of sight runs nearly
+/// horizontal
+const MAX_STEP_KM: f64 = 20.0;
+
+/// Atmosphere used for airmass at an observer
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AtmosphereModel {
+    /// Kasten & Young sea-level formula, as for `GroundEphemeris`
+    SeaLevel,
+    /// US Standard Atmosphere above the observer's height
+    Standard,
+}
+
+impl AtmosphereModel {
+    pub fn parse(name: &str) -> Result<Self, String> {
+        match name.to_lowercase().as_str() {
+            "sea_level" => Ok(AtmosphereModel::SeaLevel),
+            "standard" => Ok(AtmosphereModel::Standard),
+            _ => Err(format!(
+                "Invalid atmosphere '{name}'. Use 'sea_level' or 'standard'"
+            )),
+        }
+    }
+
+    /// Lowercase name as accepted by the Python API
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            AtmosphereModel::SeaLevel => "sea_level",
+            AtmosphereModel::Standard => "standard",
+        }
+    }
+}
+
+/// Layer containing a geopotential height, clamped to the model range
+fn layer(geopotential_km: f64) -> (f64, f64, f64, f64) {
+    let index = LAYERS
+        .iter()
+        .rposition(|&(base, ..)| geopotential_km >= base)
+        .unwrap_or(0);
+    LAYERS[index]
+}
+
+/// Pressure (Pa), temperature (K) and lapse rate (K per geopotential km) at
+/// a geometric height in km
+fn standard_atmosphere(height_km: f64) -> (f64, f64, f64) {
+    let z = height_km.clamp(0.0, ATMOSPHERE_TOP_KM);
+    let h = GEOPOTENTIAL_RADIUS_KM * z / (GEOPOTENTIAL_RADIUS_KM + z);
+    let (base, base_temperature, lapse, base_pressure) = layer(h);
+    let temperature = base_temperature + lapse * (h - base);
+    let pressure = if lapse == 0.0 {
+        base_pressure * (-HYDROSTATIC_CONSTANT * (h - base) / base_temperature).exp()
+    } else {
+        base_pressure * (base_temperature / temperature).powf(HYDROSTATIC_CONSTANT / lapse)
+    };
+    (pressure, temperature, lapse)
+}
+
+/// Air density relative to sea level at a geometric height in km
+fn relative_density(height_km: f64) -> f64 {
+    if height_km > ATMOSPHERE_TOP_KM {
+        return 0.0;
+    }
+    let (pressure, temperature, _) = standard_atmosphere(height_km);
+    (pressure / SEA_LEVEL_PRESSURE) * (SEA_LEVEL_TEMPERATURE / temperature)
+}
+
+/// Decrease of the refractivity of air per km of geometric height
+fn refractivity_gradient(height_km: f64) -> f64 {
+    if height_km > ATMOSPHERE_TOP_KM {
+        return 0.0;
+    }
+    let z = height_km.max(0.0);
+    let (pressure, temperature, lapse) = standard_atmosphere(z);
+    let density = (pressure / SEA_LEVEL_PRESSURE) * (SEA_LEVEL_TEMPERATURE / temperature);
+    // d(ln rho)/dh = -(g0 M / R* + L) / T per geopotential km
+    let geopotential_per_km = (GEOPOTENTIAL_RADIUS_KM / (GEOPOTENTIAL_RADIUS_KM + z)).powi(2);
+    SEA_LEVEL_REFRACTIVITY * density * (HYDROSTATIC_CONSTANT + lapse) / temperature
+        * geopotential_per_km
+}
+
+/// Integrate `f(height_km, impact / r)` along the straight line of sight
+/// leaving an observer at `height_km` at `elevation_deg`, out to the top of
+/// the atmosphere, or `None` if the line of sight meets the ground
+fn line_of_sight_integral(
+    elevation_deg: f64,
+    height_km: f64,
+    f: impl Fn(f64, f64) -> f64,
+) -> Option<f64> {
+    let r0 = EARTH_RADIUS_KM + height_km;
+    let (sin_e, cos_e) = elevation_deg.to_radians().sin_cos();
+    // Closest approach of the line to the Earth's centre, and the observer's
+    // position along the line measured from it (negative looking down)
+    let impact = r0 * cos_e;
+    let mut u = r0 * sin_e;
+    if u < 0.0 && impact <= EARTH_RADIUS_KM {
+        return None;
+    }
+    let r_top = EARTH_RADIUS_KM + ATMOSPHERE_TOP_KM;
+    let u_end = (r_top * r_top - impact * impact).max(0.0).sqrt();
+    let sample = |u: f64| {
+        let r = (impact * impact + u * u).sqrt();
+        f(r - EARTH_RADIUS_KM, impact / r)
+    };
+
+    // Simpson's rule over steps of about STEP_KM in height
+    let mut total = 0.0;
+    let mut f0 = sample(u);
+    while u < u_end {
+        let r = (impact * impact + u * u).sqrt();
+        let step = (STEP_KM * r / u.abs().max(1e-9))
+            .min(MAX_STEP_KM)
+            .min(u_end - u);
+        let mid = sample(u + 0.5 * step);
+        let f1 = sample(u + step);
+        total += step / 6.0 * (f0 + 4.0 * mid + f1);
+        u += step;
+        f0 = f1;
+    }
+    Some(total)
+}
+
+/// Airmass of a line of sight at `altitude_deg` from an observer at
+/// `height_m` above sea level, relative to the observer's zenith column
+///
+/// Finite down to the dip of the Earth's limb; infinite where the line of
+/// sight meets the ground or the observer is above the atmosphere.
+pub fn airmass_at_height(altitude_deg: f64, height_m: f64) -> f64 {
+    let height_km = height_m.max(0.0) / 1000.0;
+    let density = |h: f64, _: f64| relative_density(h);
+    match (
+        line_of_sight_integral(altitude_deg, height_km, density),
+        line_of_sight_integral(90.0, height_km, density),
+    ) {
+        (Some(path), Some(zenith)) if zenith > 0.0 => path / zenith,
+        _ => f64::INFINITY,
+    }
+}
+
+/// Atmospheric refraction in degrees for a target at `altitude_deg` seen
+/// from an observer at `height_m` above sea level
+///
+/// The apparent altitude is higher than the true altitude by this amount.
+/// NaN where the line of sight meets the ground.
+pub fn refraction_at_height(altitude_deg: f64, height_m: f64) -> f64 {
+    let height_km = height_m.max(0.0) / 1000.0;
+    line_of_sight_integral(altitude_deg, height_km, |h, sin_angle| {
+        refractivity_gradient(h) * sin_angle
+    })
+    .map_or(f64::NAN, f64::to_degrees)
+}
+
+/// Airmass at `altitude_deg`: from the standard atmosphere above `height_m`
+/// when a height is given, from the sea-level Kasten & Young formula
+/// otherwise
+pub fn airmass(altitude_deg: f64, height_m: Option<f64>) -> f64 {
+    match height_m {
+        Some(height_m) => airmass_at_height(altitude_deg, height_m),
+        None => calculate_airmass_kasten(altitude_deg),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn zenith_column(height_km: f64) -> f64 {
+        line_of_sight_integral(90.0, height_km, |h, _| relative_density(h)).unwrap()
+    }
+
+    #[test]
+    fn test_standard_atmosphere() {
+        // US Standard Atmosphere 1976 table values at geometric heights
+        let cases = [
+            (0.0, 101325.0),
+            (11.0, 22700.0),
+            (30.0, 1197.0),
+            (35.0, 574.59),
+        ];
+        for (height_km, expected) in cases {
+            let (pressure, _, _) = standard_atmosphere(height_km);
+            assert!(
+                (pressure / expected - 1.0).abs() < 0.01,
+                "{height_km} km: {pressure} Pa"
+            );
+        }
+        let (_, temperature, _) = standard_atmosphere(35.0);
+        assert!((temperature - 236.5).abs() < 0.5);
+    }
+
+    #[test]
+    fn test_sea_level_airmass_matches_kasten() {
+        assert!((airmass_at_height(90.0, 0.0) - 1.0).abs() < 1e-9);
+        for altitude in [60.0, 30.0, 10.0] {
+            let expected = calculate_airmass_kasten(altitude);
+            let airmass = airmass_at_height(altitude, 0.0);
+            assert!(
+                (airmass / expected - 1.0).abs() < 0.02,
+                "{altitude}°: {airmass} vs {expected}"
+            );
+        }
+        assert!(airmass_at_height(-1.0, 0.0).is_infinite());
+    }
+
+    #[test]
+    fn test_balloon_airmass() {
+        // Zenith column at 35 km is ~0.6% of the sea-level column
+        let ratio = zenith_column(35.0) / zenith_column(0.0);
+        assert!((ratio - 0.0057).abs() < 0.0005);
+
+        // Finite below the astronomical horizon, down to the limb
+        assert!((airmass_at_height(30.0, 35000.0) - 2.0).abs() < 0.02);
+        assert!(airmass_at_height(0.0, 35000.0).is_finite());
+        assert!(airmass_at_height(-5.0, 35000.0) > airmass_at_height(0.0, 35000.0));
+        assert!(airmass_at_height(-7.0, 35000.0).is_infinite());
+    }
+
+    #[test]
+    fn test_refraction() {
+        // ~1' at 45° and ~30' at the horizon at sea level (the straight
+        // line of sight misses the last few arcminutes of a grazing ray)
+        let arcmin = |deg: f64| deg * 60.0;
+        assert!((arcmin(refraction_at_height(45.0, 0.0)) - 0.95).abs() < 0.05);
+        assert!((28.0..36.0).contains(&arcmin(refraction_at_height(0.0, 0.0))));
+        // Scaled down by the air density at 35 km
+        let ratio = refraction_at_height(45.0, 35000.0) / refraction_at_height(45.0, 0.0);
+        assert!(ratio > 0.005 && ratio < 0.01);
+        assert!(refraction_at_height(-1.0, 0.0).is_nan());
+    }
+
+    #[test]
+    fn test_parse_model() {
+        assert_eq!(
+            AtmosphereModel::parse("standard"),
+            Ok(AtmosphereModel::Standard)
+        );
+        assert_eq!(AtmosphereModel::SeaLevel.as_str(), "sea_level");
+        assert!(AtmosphereModel::parse("mars").is_err());
+    }
+}
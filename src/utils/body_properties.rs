@@ -0,0 +1,157 @@
+//! Registry of physical body radii
+//!
+//! Limb, eclipse and angular-radius calculations read the Sun, Earth and Moon
+//! radii from here rather than from scattered constants, so one override
+//! changes them consistently. Bodies are looked up by name or NAIF ID; other
+//! bodies (including ones unknown to SPICE) can be given a shape as well.
+//!
+//! Overrides are read when a constraint is created and when an ephemeris first
+//! computes its angular radii, so set them before building either. Limb,
+//! eclipse and angular-radius geometry uses the equatorial radius, the
+//! conservative choice for avoidance; inflating it adds a planning margin.
+
+use crate::utils::config::{MOON_RADIUS_KM, SUN_RADIUS_KM};
+use crate::utils::naif_ids::parse_body_identifier;
+use once_cell::sync::Lazy;
+use std::collections::HashMap;
+use std::sync::RwLock;
+
+/// Equatorial and polar radius of a body in km
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct BodyRadii {
+    pub equatorial: f64,
+    pub polar: f64,
+}
+
+impl BodyRadii {
+    pub const fn new(equatorial: f64, polar: f64) -> Self {
+        BodyRadii { equatorial, polar }
+    }
+
+    /// Flattening (a - c) / a
+    #[allow(dead_code)]
+    pub fn flattening(&self) -> f64 {
+        (self.equatorial - self.polar) / self.equatorial
+    }
+}
+
+/// Earth radii (WGS84)
+pub const EARTH_WGS84: BodyRadii = BodyRadii::new(6378.137, 6_356.752_314_245);
+
+/// Built-in radii by NAIF ID (IAU 2015 for the planets)
+const DEFAULT_RADII: &[(i32, BodyRadii)] = &[
+    (10, BodyRadii::new(SUN_RADIUS_KM, SUN_RADIUS_KM)),
+    (399, EARTH_WGS84),
+    (301, BodyRadii::new(MOON_RADIUS_KM, MOON_RADIUS_KM)),
+    (199, BodyRadii::new(2440.53, 2438.26)),
+    (299, BodyRadii::new(6051.8, 6051.8)),
+    (499, BodyRadii::new(3396.19, 3376.20)),
+    (599, BodyRadii::new(71492.0, 66854.0)),
+    (699, BodyRadii::new(60268.0, 54364.0)),
+    (799, BodyRadii::new(25559.0, 24973.0)),
+    (899, BodyRadii::new(24764.0, 24341.0)),
+];
+
+static OVERRIDES: Lazy<RwLock<HashMap<String, BodyRadii>>> =
+    Lazy::new(|| RwLock::new(HashMap::new()));
+
+/// Registry key: the NAIF ID when the name resolves to one, else the lowercase name
+fn key(body: &str) -> String {
+    match parse_body_identifier(body.trim()) {
+        Some(id) => id.to_string(),
+        None => body.trim().to_lowercase(),
+    }
+}
+
+/// Radii of a body, with any override applied
+pub fn body_radii(body: &str) -> Option<BodyRadii> {
+    let key = key(body);
+    if let Some(radii) = OVERRIDES
+        .read()
+        .unwrap_or_else(|e| e.into_inner())
+        .get(&key)
+    {
+        return Some(*radii);
+    }
+    let id: i32 = key.parse().ok()?;
+    DEFAULT_RADII
+        .iter()
+        .find(|(naif_id, _)| *naif_id == id)
+        .map(|(_, radii)| *radii)
+}
+
+fn builtin(body: &str) -> BodyRadii {
+    body_radii(body).expect("built-in body radii")
+}
+
+/// Radii of the Sun in effect
+pub fn sun_radii() -> BodyRadii {
+    builtin("sun")
+}
+
+/// Radii of the Earth in effect
+pub fn earth_radii() -> BodyRadii {
+    builtin("earth")
+}
+
+/// Radii of the Moon in effect
+pub fn moon_radii() -> BodyRadii {
+    builtin("moon")
+}
+
+/// Override a body's radii; `polar` defaults to `equatorial` (a sphere)
+pub fn set_body_radii(body: &str, equatorial: f64, polar: Option<f64>) -> Result<(), String> {
+    let polar = polar.unwrap_or(equatorial);
+    if !(equatorial.is_finite() && equatorial > 0.0) {
+        return Err("equatorial_km must be a positive number".to_string());
+    }
+    if !(polar.is_finite() && polar > 0.0) {
+        return Err("polar_km must be a positive number".to_string());
+    }
+    let key = key(body);
+    if key.is_empty() {
+        return Err("body must not be empty".to_string());
+    }
+    OVERRIDES
+        .write()
+        .unwrap_or_else(|e| e.into_inner())
+        .insert(key, BodyRadii::new(equatorial, polar));
+    Ok(())
+}
+
+/// Remove the override for one body, or all overrides when `body` is None
+pub fn reset_body_radii(body: Option<&str>) {
+    let mut overrides = OVERRIDES.write().unwrap_or_else(|e| e.into_inner());
+    match body {
+        Some(body) => {
+            overrides.remove(&key(body));
+        }
+        None => overrides.clear(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_defaults_by_name_and_id() {
+        assert_eq!(body_radii("Earth"), Some(EARTH_WGS84));
+        assert_eq!(body_radii("399"), Some(EARTH_WGS84));
+        assert_eq!(body_radii("SUN").unwrap().equatorial, SUN_RADIUS_KM);
+        assert!(body_radii("Jupiter").unwrap().flattening() > 0.06);
+        assert_eq!(body_radii("Arrokoth"), None);
+    }
+
+    #[test]
+    fn test_override_and_reset() {
+        // A body no other test touches, since the registry is process-wide
+        set_body_radii("Arrokoth", 18.0, Some(10.0)).unwrap();
+        assert_eq!(body_radii("arrokoth"), Some(BodyRadii::new(18.0, 10.0)));
+        reset_body_radii(Some("ARROKOTH"));
+        assert_eq!(body_radii("Arrokoth"), None);
+
+        assert!(set_body_radii("Arrokoth", 0.0, None).is_err());
+        assert!(set_body_radii("Arrokoth", 18.0, Some(f64::NAN)).is_err());
+    }
+}
@@ -0,0 +1,171 @@
+//! Concurrency-safe cache file writes
+//!
+//! Cache files are written to a uniquely named temporary file in the same
+//! directory and then renamed over the target, so readers never see a
+//! partially written file and two processes fetching the same object leave
+//! one complete copy. Read-modify-write updates (such as the name cache) also
+//! hold a `<file>.lock` file, created exclusively next to the target, for the
+//! duration of the update.
+
+use std::fs::{self, File, OpenOptions};
+use std::io::{self, Read};
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::time::{Duration, Instant, SystemTime};
+
+/// Longest wait for another writer's lock before giving up
+const LOCK_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// Age after which a lock file is assumed to be left behind by a crashed process
+const STALE_LOCK_AGE: Duration = Duration::from_secs(60);
+
+const LOCK_POLL_INTERVAL: Duration = Duration::from_millis(20);
+
+/// Distinguishes temporary files of threads in the same process
+static TEMP_COUNTER: AtomicU64 = AtomicU64::new(0);
+
+/// `path` with `prefix` and `suffix` added around its file name
+fn sibling(path: &Path, prefix: &str, suffix: &str) -> PathBuf {
+    let name = path
+        .file_name()
+        .map(|n| n.to_string_lossy().to_string())
+        .unwrap_or_default();
+    path.with_file_name(format!("{}{}{}", prefix, name, suffix))
+}
+
+fn copy_to_temp(tmp: &Path, path: &Path, reader: &mut dyn Read) -> io::Result<u64> {
+    let mut file = File::create(tmp)?;
+    let written = io::copy(reader, &mut file)?;
+    file.sync_all()?;
+    fs::rename(tmp, path)?;
+    Ok(written)
+}
+
+/// Write `contents` to `path` atomically, creating parent directories as needed
+pub fn write_atomic(path: &Path, contents: &[u8]) -> io::Result<()> {
+    write_atomic_from(path, &mut &contents[..]).map(|_| ())
+}
+
+/// Stream `reader` into `path` atomically; returns the number of bytes written
+///
+/// On failure the target is left untouched and the temporary file is removed.
+pub fn write_atomic_from(path: &Path, reader: &mut dyn Read) -> io::Result<u64> {
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent)?;
+    }
+    // Hidden, with a .tmp extension, so cache scans skip it
+    let tmp = sibling(
+        path,
+        ".",
+        &format!(
+            ".{}.{}.tmp",
+            std::process::id(),
+            TEMP_COUNTER.fetch_add(1, Ordering::Relaxed)
+        ),
+    );
+    let result = copy_to_temp(&tmp, path, reader);
+    if result.is_err() {
+        let _ = fs::remove_file(&tmp);
+    }
+    result
+}
+
+/// Exclusive lock on a cache file, released when dropped
+#[derive(Debug)]
+pub struct CacheLock {
+    path: PathBuf,
+}
+
+impl Drop for CacheLock {
+    fn drop(&mut self) {
+        let _ = fs::remove_file(&self.path);
+    }
+}
+
+/// Take the lock for `path`, waiting up to `LOCK_TIMEOUT` for other writers
+///
+/// A lock file older than `STALE_LOCK_AGE` is removed and taken over.
+pub fn lock(path: &Path) -> io::Result<CacheLock> {
+    let lock_path = sibling(path, "", ".lock");
+    if let Some(parent) = lock_path.parent() {
+        fs::create_dir_all(parent)?;
+    }
+    let start = Instant::now();
+    loop {
+        match OpenOptions::new()
+            .write(true)
+            .create_new(true)
+            .open(&lock_path)
+        {
+            Ok(_) => return Ok(CacheLock { path: lock_path }),
+            Err(e) if e.kind() == io::ErrorKind::AlreadyExists => {
+                let stale = fs::metadata(&lock_path)
+                    .and_then(|m| m.modified())
+                    .ok()
+                    .and_then(|t| SystemTime::now().duration_since(t).ok())
+                    .is_some_and(|age| age > STALE_LOCK_AGE);
+                if stale {
+                    let _ = fs::remove_file(&lock_path);
+                    continue;
+                }
+                if start.elapsed() > LOCK_TIMEOUT {
+                    return Err(io::Error::new(
+                        io::ErrorKind::TimedOut,
+                        format!("Timed out waiting for cache lock {}", lock_path.display()),
+                    ));
+                }
+                std::thread::sleep(LOCK_POLL_INTERVAL);
+            }
+            Err(e) => return Err(e),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn test_dir(name: &str) -> PathBuf {
+        let dir = std::env::temp_dir().join(format!(
+            "rust_ephem_cache_io_{}_{}",
+            name,
+            std::process::id()
+        ));
+        let _ = fs::remove_dir_all(&dir);
+        dir
+    }
+
+    #[test]
+    fn test_write_atomic_replaces_and_leaves_no_temp() {
+        let dir = test_dir("write");
+        let path = dir.join("nested").join("data.txt");
+        write_atomic(&path, b"first").unwrap();
+        write_atomic(&path, b"second").unwrap();
+        assert_eq!(fs::read_to_string(&path).unwrap(), "second");
+        let entries: Vec<_> = fs::read_dir(path.parent().unwrap())
+            .unwrap()
+            .flatten()
+            .map(|e| e.file_name())
+            .collect();
+        assert_eq!(entries, vec![std::ffi::OsString::from("data.txt")]);
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn test_lock_is_exclusive_and_released() {
+        let dir = test_dir("lock");
+        let path = dir.join("names.json");
+        let held = lock(&path).unwrap();
+        let lock_path = dir.join("names.json.lock");
+        assert!(lock_path.exists());
+        assert!(OpenOptions::new()
+            .write(true)
+            .create_new(true)
+            .open(&lock_path)
+            .is_err());
+        drop(held);
+        assert!(!lock_path.exists());
+        drop(lock(&path).unwrap());
+        fs::remove_dir_all(&dir).unwrap();
+    }
+}
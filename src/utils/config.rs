@@ -2,37 +2,58 @@
 // Put shared constants here so they're defined in one place.
 
 use once_cell::sync::Lazy;
-use std::env;
 use std::path::PathBuf;
+use std::time::Duration;
+
+use crate::utils::settings::SETTINGS;
+
+// User-configurable values come from `utils::settings` (config.toml and
+// RUST_EPHEM_* environment variables); see that module for the keys.
 
 /// Cache directory for rust_ephem data files
 pub static CACHE_DIR: Lazy<PathBuf> = Lazy::new(|| {
-    if let Some(home) = dirs::home_dir() {
-        let mut p = home;
-        p.push(".cache");
-        p.push("rust_ephem");
-        if !p.exists() {
-            std::fs::create_dir_all(&p).expect("Failed to create cache directory");
-        }
-        p
-    } else {
-        // Fallback to current directory if home dir not available
-        env::current_dir().unwrap_or_else(|_| PathBuf::from("."))
+    let p = SETTINGS.settings.cache.dir.clone();
+    if !p.exists() {
+        std::fs::create_dir_all(&p).expect("Failed to create cache directory");
     }
+    p
 });
 
 /// Configuration for planetary ephemeris paths
-pub static DEFAULT_DE440S_PATH: Lazy<PathBuf> = Lazy::new(|| CACHE_DIR.join("de440s.bsp"));
-pub static DEFAULT_DE440_PATH: Lazy<PathBuf> = Lazy::new(|| CACHE_DIR.join("de440.bsp"));
-pub const DE440S_URL: &str =
-    "https://naif.jpl.nasa.gov/pub/naif/generic_kernels/spk/planets/de440s.bsp";
-pub const DE440_URL: &str =
-    "https://naif.jpl.nasa.gov/pub/naif/generic_kernels/spk/planets/de440.bsp";
+pub static DEFAULT_DE440S_PATH: Lazy<PathBuf> = Lazy::new(|| {
+    SETTINGS
+        .settings
+        .kernels
+        .de440s_path
+        .clone()
+        .unwrap_or_else(|| CACHE_DIR.join("de440s.bsp"))
+});
+pub static DEFAULT_DE440_PATH: Lazy<PathBuf> = Lazy::new(|| {
+    SETTINGS
+        .settings
+        .kernels
+        .de440_path
+        .clone()
+        .unwrap_or_else(|| CACHE_DIR.join("de440.bsp"))
+});
+pub static DE440S_URL: Lazy<String> = Lazy::new(|| SETTINGS.settings.kernels.de440s_url.clone());
+pub static DE440_URL: Lazy<String> = Lazy::new(|| SETTINGS.settings.kernels.de440_url.clone());
 
 /// Configuration for Earth Orientation Parameters (EOP) data
 pub static DEFAULT_EOP_PATH: Lazy<PathBuf> = Lazy::new(|| CACHE_DIR.join("latest_eop2.short"));
-pub static DEFAULT_EOP_TTL: u64 = 86_400; // default 1 day in seconds
-pub const EOP2_URL: &str = "https://eop2-external.jpl.nasa.gov/eop2/latest_eop2.short";
+pub static DEFAULT_EOP_TTL: Lazy<u64> = Lazy::new(|| SETTINGS.settings.cache.eop_ttl);
+pub static EOP2_URL: Lazy<String> = Lazy::new(|| SETTINGS.settings.network.eop2_url.clone());
+
+/// Configuration for Celestrak space-weather data (Kp, Ap, F10.7)
+pub static DEFAULT_SPACE_WEATHER_PATH: Lazy<PathBuf> = Lazy::new(|| CACHE_DIR.join("SW-All.csv"));
+pub static DEFAULT_SPACE_WEATHER_TTL: Lazy<u64> =
+    Lazy::new(|| SETTINGS.settings.cache.space_weather_ttl);
+pub static SPACE_WEATHER_URL: Lazy<String> =
+    Lazy::new(|| SETTINGS.settings.network.space_weather_url.clone());
+
+/// Timeout for TLE, Space-Track and Sesame requests
+pub static NETWORK_TIMEOUT: Lazy<Duration> =
+    Lazy::new(|| Duration::from_secs(SETTINGS.settings.network.timeout));
 
 // Distance/time conversions
 pub const AU_TO_KM: f64 = 149597870.7;
@@ -49,6 +70,7 @@ pub const GM_EARTH: f64 = 398600.4418;
 pub const JD_J2000: f64 = 2451545.0;
 pub const DAYS_PER_CENTURY: f64 = 36525.0;
 pub const OMEGA_EARTH: f64 = 7.292115e-5; // rad/s
+pub const GEO_RADIUS_KM: f64 = 42164.17; // Geostationary orbit radius, (GM / sidereal rate^2)^(1/3)
 
 // NAIF IDs
 pub const MOON_NAIF_ID: i32 = 301;
@@ -71,33 +93,82 @@ pub const GMST_COEFF_2: f64 = 0.093104;
 pub const GMST_COEFF_3: f64 = -6.2e-6;
 
 /// Celestrak GP TLE API endpoint
-pub const CELESTRAK_API_BASE: &str = "https://celestrak.org/NORAD/elements/gp.php";
+pub static CELESTRAK_API_BASE: Lazy<String> =
+    Lazy::new(|| SETTINGS.settings.network.celestrak_url.clone());
+
+/// Celestrak SATCAT records endpoint
+pub static SATCAT_URL: Lazy<String> = Lazy::new(|| SETTINGS.settings.network.satcat_url.clone());
+
+/// CDS Sesame name resolver (plain-text output, all resolvers)
+pub static SESAME_URL: Lazy<String> = Lazy::new(|| SETTINGS.settings.network.sesame_url.clone());
+
+/// JPL Horizons API endpoint
+pub static HORIZONS_API_URL: Lazy<String> =
+    Lazy::new(|| SETTINGS.settings.network.horizons_url.clone());
 
 /// Space-Track.org API endpoints
-pub const SPACETRACK_API_BASE: &str = "https://www.space-track.org";
-pub const SPACETRACK_LOGIN_URL: &str = "https://www.space-track.org/ajaxauth/login";
+pub static SPACETRACK_API_BASE: Lazy<String> = Lazy::new(|| {
+    SETTINGS
+        .settings
+        .network
+        .spacetrack_url
+        .trim_end_matches('/')
+        .to_string()
+});
+pub static SPACETRACK_LOGIN_URL: Lazy<String> =
+    Lazy::new(|| format!("{}/ajaxauth/login", *SPACETRACK_API_BASE));
 
-/// Space-Track.org credentials loaded from the environment, default to None if not set
-pub static SPACETRACK_USERNAME_ENV: Lazy<Option<String>> =
-    Lazy::new(|| env::var("SPACETRACK_USERNAME").ok());
-pub static SPACETRACK_PASSWORD_ENV: Lazy<Option<String>> =
-    Lazy::new(|| env::var("SPACETRACK_PASSWORD").ok());
+/// Default TLE epoch tolerance (days) for Space-Track.org caching
+pub static DEFAULT_EPOCH_TOLERANCE_DAYS: Lazy<f64> =
+    Lazy::new(|| SETTINGS.settings.spacetrack.epoch_tolerance_days);
 
-/// Default TLE epoch tolerance for Space-Track.org caching (2 days)
+/// Default limit (days) on how far a TLE propagation range may extend from the epoch
 ///
-/// Override by setting SPACETRACK_EPOCH_TOLERANCE_DAYS to a float value.
-pub static DEFAULT_EPOCH_TOLERANCE_DAYS: Lazy<f64> = Lazy::new(|| {
-    env::var("SPACETRACK_EPOCH_TOLERANCE_DAYS")
-        .ok()
-        .and_then(|v| v.parse().ok())
-        .unwrap_or(4.0)
-});
+/// Beyond it `TLEEphemeris` warns, or raises with `strict=True`.
+pub static DEFAULT_MAX_EPOCH_OFFSET_DAYS: Lazy<f64> =
+    Lazy::new(|| SETTINGS.settings.tle.max_epoch_offset_days);
+
+/// Whether TLEs fetched by NORAD ID or name get a SATCAT decay date lookup
+pub static TLE_CHECK_DECAY: Lazy<bool> = Lazy::new(|| SETTINGS.settings.tle.check_decay);
 
 /// Maximum number of cached TLEs per key for Celestrak and URL caches
-pub const TLE_CACHE_MAX_ENTRIES: usize = 10;
+pub static TLE_CACHE_MAX_ENTRIES: Lazy<usize> =
+    Lazy::new(|| SETTINGS.settings.cache.tle_max_entries);
 
 /// Maximum number of cached Space-Track TLEs per NORAD ID (one per historical epoch queried)
-pub const SPACETRACK_CACHE_MAX_ENTRIES: usize = 1000;
-
-/// TTL for cached TLE downloads (24 hours)
-pub const TLE_CACHE_TTL: u64 = 86_400;
+pub static SPACETRACK_CACHE_MAX_ENTRIES: Lazy<usize> =
+    Lazy::new(|| SETTINGS.settings.cache.spacetrack_max_entries);
+
+/// TTL (seconds) for cached TLE downloads
+pub static TLE_CACHE_TTL: Lazy<u64> = Lazy::new(|| SETTINGS.settings.cache.tle_ttl);
+
+/// Maximum number of Horizons responses kept in the in-memory query cache
+pub const HORIZONS_CACHE_MAX_ENTRIES: usize = 64;
+
+/// Largest Horizons step (minutes) chosen from the ephemeris grid spacing
+pub const HORIZONS_MAX_STEP_MINUTES: i64 = 60;
+
+/// Upper bound on rows requested from Horizons in a single query
+pub const HORIZONS_MAX_ROWS: i64 = 20_000;
+
+// GPS interface specification (IS-GPS-200) constants used for almanac propagation
+/// WGS 84 Earth gravitational parameter as used by GPS (m^3/s^2)
+pub const GPS_MU: f64 = 3.986005e14;
+/// WGS 84 Earth rotation rate as used by GPS (rad/s)
+pub const GPS_OMEGA_EARTH: f64 = 7.292_115_146_7e-5;
+/// Number of weeks before the broadcast 10-bit GPS week number rolls over
+pub const GPS_WEEK_ROLLOVER: i64 = 1024;
+/// Seconds in one GPS week
+pub const GPS_SECONDS_PER_WEEK: f64 = 604_800.0;
+/// Galileo Earth gravitational parameter (m^3/s^2)
+pub const GALILEO_MU: f64 = 3.986004418e14;
+/// Largest distance (s) from a broadcast message reference time at which it is used
+pub const BROADCAST_EPHEMERIS_MAX_AGE: f64 = 4.0 * 3600.0;
+
+// Heliocentric two-body constants used for minor planet orbits
+/// Gaussian gravitational constant (rad/day for a = 1 AU)
+pub const GAUSSIAN_GRAVITATIONAL_CONSTANT: f64 = 0.017_202_098_95;
+/// Obliquity of the ecliptic at J2000 used by the MPC (arcsec, IAU 1976)
+pub const OBLIQUITY_J2000_ARCSEC: f64 = 84381.448;
+/// Speed of light in vacuum (km/s)
+pub const SPEED_OF_LIGHT_KM_S: f64 = 299_792.458;
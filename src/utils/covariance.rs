@@ -0,0 +1,284 @@
+//! State covariance handling and linear propagation
+//!
+//! Covariances are 6x6 matrices over [x, y, z, vx, vy, vz] in km and km/s,
+//! the units used by CCSDS OEM covariance blocks. Propagation is linear: the
+//! state transition matrix (STM) is integrated along a two-body reference
+//! orbit and applied as P(t) = Φ P(t0) Φᵀ. This ignores perturbations in the
+//! error dynamics, which is adequate for spans of a few orbits.
+
+use crate::utils::config::GM_EARTH;
+use crate::utils::vector_math::ric_basis;
+
+/// 6x6 state covariance or state transition matrix
+pub type Matrix6 = [[f64; 6]; 6];
+
+/// Largest RK4 step (seconds) used when integrating the STM
+const MAX_STEP_SECONDS: f64 = 10.0;
+
+/// 6x6 identity matrix
+pub fn identity6() -> Matrix6 {
+    let mut m = [[0.0; 6]; 6];
+    for (i, row) in m.iter_mut().enumerate() {
+        row[i] = 1.0;
+    }
+    m
+}
+
+fn mat_mul6(a: &Matrix6, b: &Matrix6) -> Matrix6 {
+    std::array::from_fn(|i| std::array::from_fn(|j| (0..6).map(|k| a[i][k] * b[k][j]).sum()))
+}
+
+fn transpose6(a: &Matrix6) -> Matrix6 {
+    std::array::from_fn(|i| std::array::from_fn(|j| a[j][i]))
+}
+
+/// Build a symmetric matrix from the 21 lower-triangular values, row by row
+///
+/// This is the order used by CCSDS OEM covariance blocks.
+pub fn from_lower_triangle(values: &[f64]) -> Result<Matrix6, String> {
+    if values.len() != 21 {
+        return Err(format!(
+            "Covariance matrix needs 21 lower-triangular values, got {}",
+            values.len()
+        ));
+    }
+    let mut m = [[0.0; 6]; 6];
+    let lower = (0..6).flat_map(|i| (0..=i).map(move |j| (i, j)));
+    for ((i, j), &value) in lower.zip(values) {
+        m[i][j] = value;
+        m[j][i] = value;
+    }
+    Ok(m)
+}
+
+/// Apply a 3x3 rotation to the position and velocity blocks: T P Tᵀ
+///
+/// Used to move a covariance between an orbit-local frame and the inertial
+/// frame. The rotation rate of the local frame is neglected, as is
+/// conventional for RTN covariances.
+pub fn rotate(cov: &Matrix6, rotation: &[[f64; 3]; 3]) -> Matrix6 {
+    let mut t = [[0.0; 6]; 6];
+    for i in 0..3 {
+        for j in 0..3 {
+            t[i][j] = rotation[i][j];
+            t[i + 3][j + 3] = rotation[i][j];
+        }
+    }
+    mat_mul6(&mat_mul6(&t, cov), &transpose6(&t))
+}
+
+/// Propagate a covariance through a state transition matrix: Φ P Φᵀ
+pub fn propagate(cov: &Matrix6, stm: &Matrix6) -> Matrix6 {
+    mat_mul6(&mat_mul6(stm, cov), &transpose6(stm))
+}
+
+/// 1-sigma position uncertainty [radial, in-track, cross-track] (km)
+///
+/// The RIC frame is taken from the state (`r`, `v`) the covariance refers to.
+pub fn position_sigma_ric(cov: &Matrix6, r: &[f64; 3], v: &[f64; 3]) -> [f64; 3] {
+    let basis = ric_basis(r, v);
+    let mut sigma = [0.0; 3];
+    for (k, axis) in basis.iter().enumerate() {
+        let variance: f64 = (0..3)
+            .flat_map(|i| (0..3).map(move |j| axis[i] * cov[i][j] * axis[j]))
+            .sum();
+        sigma[k] = variance.max(0.0).sqrt();
+    }
+    sigma
+}
+
+/// Time derivatives of a two-body state and its STM
+fn derivatives(state: &[f64; 6], stm: &Matrix6) -> ([f64; 6], Matrix6) {
+    let r2 = state[0] * state[0] + state[1] * state[1] + state[2] * state[2];
+    let r = r2.sqrt();
+    let mu_r3 = GM_EARTH / (r2 * r);
+    let mu_r5 = mu_r3 / r2;
+
+    let d_state = [
+        state[3],
+        state[4],
+        state[5],
+        -mu_r3 * state[0],
+        -mu_r3 * state[1],
+        -mu_r3 * state[2],
+    ];
+
+    // A = [[0, I], [G, 0]] with gravity gradient G = μ/r⁵ (3 r rᵀ - r² I)
+    let mut a = [[0.0; 6]; 6];
+    for i in 0..3 {
+        a[i][i + 3] = 1.0;
+        for (j, &x_j) in state[..3].iter().enumerate() {
+            let delta = if i == j { r2 } else { 0.0 };
+            a[i + 3][j] = mu_r5 * (3.0 * state[i] * x_j - delta);
+        }
+    }
+    (d_state, mat_mul6(&a, stm))
+}
+
+fn rk4_step(state: &[f64; 6], stm: &Matrix6, h: f64) -> ([f64; 6], Matrix6) {
+    let offset = |k: &([f64; 6], Matrix6), scale: f64| {
+        let mut s = *state;
+        let mut m = *stm;
+        for i in 0..6 {
+            s[i] += scale * k.0[i];
+            for (mij, kij) in m[i].iter_mut().zip(&k.1[i]) {
+                *mij += scale * kij;
+            }
+        }
+        (s, m)
+    };
+    let k1 = derivatives(state, stm);
+    let (s2, m2) = offset(&k1, h / 2.0);
+    let k2 = derivatives(&s2, &m2);
+    let (s3, m3) = offset(&k2, h / 2.0);
+    let k3 = derivatives(&s3, &m3);
+    let (s4, m4) = offset(&k3, h);
+    let k4 = derivatives(&s4, &m4);
+
+    let mut s = *state;
+    let mut m = *stm;
+    for i in 0..6 {
+        s[i] += h / 6.0 * (k1.0[i] + 2.0 * k2.0[i] + 2.0 * k3.0[i] + k4.0[i]);
+        for (j, mij) in m[i].iter_mut().enumerate() {
+            *mij += h / 6.0 * (k1.1[i][j] + 2.0 * k2.1[i][j] + 2.0 * k3.1[i][j] + k4.1[i][j]);
+        }
+    }
+    (s, m)
+}
+
+fn integrate(state: &[f64; 6], stm: &Matrix6, dt: f64) -> ([f64; 6], Matrix6) {
+    let steps = (dt.abs() / MAX_STEP_SECONDS).ceil().max(1.0);
+    let h = dt / steps;
+    let (mut s, mut m) = (*state, *stm);
+    for _ in 0..steps as usize {
+        (s, m) = rk4_step(&s, &m, h);
+    }
+    (s, m)
+}
+
+/// Two-body state transition matrices from `state0` to each time offset
+///
+/// `offsets` are seconds relative to the epoch of `state0` and may be
+/// negative or unordered; the orbit is integrated once forwards and once
+/// backwards through them.
+pub fn two_body_stms(state0: &[f64; 6], offsets: &[f64]) -> Vec<Matrix6> {
+    let mut stms = vec![identity6(); offsets.len()];
+    let mut order: Vec<usize> = (0..offsets.len()).collect();
+    order.sort_by(|&a, &b| offsets[a].total_cmp(&offsets[b]));
+
+    let forward = order.iter().filter(|&&i| offsets[i] >= 0.0);
+    let backward = order.iter().rev().filter(|&&i| offsets[i] < 0.0);
+    for sweep in [
+        forward.copied().collect::<Vec<_>>(),
+        backward.copied().collect::<Vec<_>>(),
+    ] {
+        let (mut state, mut stm, mut t) = (*state0, identity6(), 0.0);
+        for i in sweep {
+            if offsets[i] != t {
+                (state, stm) = integrate(&state, &stm, offsets[i] - t);
+                t = offsets[i];
+            }
+            stms[i] = stm;
+        }
+    }
+    stms
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Circular equatorial LEO state at +x moving along +y
+    fn circular_state() -> [f64; 6] {
+        let r = 7000.0;
+        [r, 0.0, 0.0, 0.0, (GM_EARTH / r).sqrt(), 0.0]
+    }
+
+    #[test]
+    fn test_from_lower_triangle() {
+        let values: Vec<f64> = (1..=21).map(f64::from).collect();
+        let m = from_lower_triangle(&values).unwrap();
+        assert_eq!(m[0][0], 1.0);
+        assert_eq!(m[1][0], 2.0);
+        assert_eq!(m[0][1], 2.0);
+        assert_eq!(m[5][5], 21.0);
+        assert_eq!(m[5][0], 16.0);
+        assert!(from_lower_triangle(&values[..20]).is_err());
+    }
+
+    #[test]
+    fn test_stm_matches_finite_difference() {
+        let state = circular_state();
+        let dt = 1800.0;
+        let stm = two_body_stms(&state, &[dt])[0];
+        let (base, _) = integrate(&state, &identity6(), dt);
+        for j in 0..6 {
+            let eps = if j < 3 { 1e-3 } else { 1e-6 };
+            let mut perturbed = state;
+            perturbed[j] += eps;
+            let (shifted, _) = integrate(&perturbed, &identity6(), dt);
+            for i in 0..6 {
+                let fd = (shifted[i] - base[i]) / eps;
+                assert!(
+                    (fd - stm[i][j]).abs() < 1e-3 * (1.0 + fd.abs()),
+                    "STM[{}][{}] = {}, finite difference {}",
+                    i,
+                    j,
+                    stm[i][j],
+                    fd
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_forward_and_backward_offsets() {
+        let state = circular_state();
+        let stms = two_body_stms(&state, &[600.0, -600.0, 0.0]);
+        assert_eq!(stms[2], identity6());
+        // Backward then forward over the same span returns to identity
+        let (back_state, _) = integrate(&state, &identity6(), -600.0);
+        let there_and_back = mat_mul6(&two_body_stms(&back_state, &[600.0])[0], &stms[1]);
+        let identity = identity6();
+        for i in 0..6 {
+            for j in 0..6 {
+                assert!((there_and_back[i][j] - identity[i][j]).abs() < 1e-6);
+            }
+        }
+    }
+
+    #[test]
+    fn test_in_track_uncertainty_grows() {
+        let state = circular_state();
+        let mut cov = [[0.0; 6]; 6];
+        for i in 0..3 {
+            cov[i][i] = 0.01; // 100 m 1-sigma
+            cov[i + 3][i + 3] = 1e-8; // 0.1 m/s 1-sigma
+        }
+        let stm = two_body_stms(&state, &[3000.0])[0];
+        let (later, _) = integrate(&state, &identity6(), 3000.0);
+        let sigma = position_sigma_ric(
+            &propagate(&cov, &stm),
+            &[later[0], later[1], later[2]],
+            &[later[3], later[4], later[5]],
+        );
+        let initial = position_sigma_ric(&cov, &[state[0], 0.0, 0.0], &[0.0, state[4], 0.0]);
+        assert!((initial[0] - 0.1).abs() < 1e-12);
+        assert!(sigma[1] > 3.0 * sigma[0]);
+        assert!(sigma[1] > initial[1]);
+    }
+
+    #[test]
+    fn test_rotate_round_trip() {
+        let values: Vec<f64> = (1..=21).map(|v| v as f64 * 0.1).collect();
+        let cov = from_lower_triangle(&values).unwrap();
+        let basis = ric_basis(&[1.0, 2.0, 3.0], &[-3.0, 1.0, 0.5]);
+        let inverse: [[f64; 3]; 3] = std::array::from_fn(|i| std::array::from_fn(|j| basis[j][i]));
+        let back = rotate(&rotate(&cov, &basis), &inverse);
+        for i in 0..6 {
+            for j in 0..6 {
+                assert!((back[i][j] - cov[i][j]).abs() < 1e-12);
+            }
+        }
+    }
+}
@@ -4,21 +4,31 @@
 //! store it in a local cache, and read it back with a TTL.
 //! Both UT1 and polar motion providers can consume the returned text.
 
+use crate::utils::cache_io;
 use crate::utils::config::DEFAULT_EOP_PATH;
 use crate::utils::config::DEFAULT_EOP_TTL;
 use crate::utils::config::EOP2_URL;
 use std::error::Error;
 use std::fs;
 use std::io::Read;
-use std::io::Write;
 use std::path::Path;
 use std::time::{Duration, SystemTime};
 
 fn fetch_eop2_text() -> Result<String, Box<dyn Error>> {
-    let mut response = ureq::get(EOP2_URL).call()?;
+    let mut response = ureq::get(EOP2_URL.as_str()).call()?;
     Ok(response.body_mut().read_to_string()?)
 }
 
+/// True if `text` has at least one EOP2 data line (MJD, PMx, PMy, ...)
+///
+/// Guards against caching or using an error page or a truncated download.
+fn looks_like_eop2(text: &str) -> bool {
+    text.lines().any(|line| {
+        let fields: Vec<&str> = line.trim().split(',').map(str::trim).collect();
+        fields.len() >= 3 && fields[..3].iter().all(|f| f.parse::<f64>().is_ok())
+    })
+}
+
 fn try_read_fresh_cache(path: &Path, ttl: Duration) -> Option<String> {
     let meta = fs::metadata(path).ok()?;
     if let Ok(modified) = meta.modified() {
@@ -26,7 +36,7 @@ fn try_read_fresh_cache(path: &Path, ttl: Duration) -> Option<String> {
             if age <= ttl {
                 if let Ok(mut f) = fs::File::open(path) {
                     let mut buf = String::new();
-                    if f.read_to_string(&mut buf).is_ok() {
+                    if f.read_to_string(&mut buf).is_ok() && looks_like_eop2(&buf) {
                         eprintln!(
                             "EOP2 text loaded from cache: {} (age: {}s)",
                             path.display(),
@@ -42,11 +52,8 @@ fn try_read_fresh_cache(path: &Path, ttl: Duration) -> Option<String> {
 }
 
 fn save_cache(path: &Path, body: &str) {
-    if let Some(parent) = path.parent() {
-        let _ = fs::create_dir_all(parent);
-    }
-    if let Ok(mut f) = fs::File::create(path) {
-        let _ = f.write_all(body.as_bytes());
+    if let Err(e) = cache_io::write_atomic(path, body.as_bytes()) {
+        eprintln!("Warning: failed to cache EOP2 file: {e}");
     }
 }
 
@@ -54,7 +61,7 @@ fn try_read_stale_cache(path: &Path) -> Option<String> {
     if path.exists() {
         if let Ok(mut f) = fs::File::open(path) {
             let mut buf = String::new();
-            if f.read_to_string(&mut buf).is_ok() {
+            if f.read_to_string(&mut buf).is_ok() && looks_like_eop2(&buf) {
                 eprintln!("EOP2 text loaded from STALE cache: {}", path.display());
                 return Some(buf);
             }
@@ -67,11 +74,17 @@ fn try_read_stale_cache(path: &Path) -> Option<String> {
 /// Falls back to stale cache if network fails.
 pub fn load_or_download_eop2_text() -> Result<String, Box<dyn Error>> {
     let path = DEFAULT_EOP_PATH.clone();
-    let ttl = Duration::from_secs(DEFAULT_EOP_TTL);
+    let ttl = Duration::from_secs(*DEFAULT_EOP_TTL);
     if let Some(text) = try_read_fresh_cache(&path, ttl) {
         return Ok(text);
     }
-    match fetch_eop2_text() {
+    match fetch_eop2_text().and_then(|text| {
+        if looks_like_eop2(&text) {
+            Ok(text)
+        } else {
+            Err("downloaded EOP2 file has no data lines".into())
+        }
+    }) {
         Ok(text) => {
             save_cache(&path, &text);
             Ok(text)
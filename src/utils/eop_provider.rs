@@ -27,6 +27,8 @@ pub struct EopRecord {
 #[derive(Debug, Clone)]
 pub struct EopProvider {
     records: Vec<EopRecord>,
+    /// MD5 digest of the EOP2 text the records were parsed from
+    checksum: String,
 }
 
 // EopRecord is a plain data holder; conversions are provided by EopProvider/free functions.
@@ -43,6 +45,7 @@ impl EopProvider {
     /// Format: MJD, PMx(mas), PMy(mas), TAI-UT1(ms), ... (additional columns ignored)
     /// Lines starting with '#' or '$' are comments
     pub fn from_eop2_data(data: String) -> Result<Self, Box<dyn std::error::Error>> {
+        let checksum = format!("{:x}", md5::compute(data.as_bytes()));
         let mut records = Vec::new();
 
         for raw_line in data.lines() {
@@ -114,7 +117,7 @@ impl EopProvider {
         // Sort by MJD for efficient lookup (ignore partial_cmp None since MJD parsed as f64)
         records.sort_by(|a, b| a.mjd.partial_cmp(&b.mjd).unwrap());
 
-        Ok(EopProvider { records })
+        Ok(EopProvider { records, checksum })
     }
 
     /// Get polar motion (xp, yp) for a given datetime
@@ -177,6 +180,11 @@ impl EopProvider {
         self.records.is_empty()
     }
 
+    /// MD5 digest of the EOP2 text the records were parsed from
+    pub fn checksum(&self) -> &str {
+        &self.checksum
+    }
+
     /// Get the MJD range of available data
     pub fn mjd_range(&self) -> Option<(f64, f64)> {
         if self.records.is_empty() {
@@ -265,4 +273,22 @@ pub fn is_eop_available() -> bool {
     EOP_PROVIDER.lock().unwrap().is_some()
 }
 
+/// MJD range covered by the loaded EOP data, if any
+pub fn eop_mjd_range() -> Option<(f64, f64)> {
+    EOP_PROVIDER
+        .lock()
+        .unwrap()
+        .as_ref()
+        .and_then(|provider| provider.mjd_range())
+}
+
+/// MD5 digest of the loaded EOP data, if any
+pub fn eop_checksum() -> Option<String> {
+    EOP_PROVIDER
+        .lock()
+        .unwrap()
+        .as_ref()
+        .map(|provider| provider.checksum().to_string())
+}
+
 // Tests moved to tests/eop_provider_tests.rs
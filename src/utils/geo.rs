@@ -51,3 +51,143 @@ pub fn ecef_to_geodetic_deg(positions: &Array2<f64>) -> (Array1<f64>, Array1<f64
 pub fn deg_to_rad_array(deg: &Array1<f64>) -> Array1<f64> {
     deg * (PI / 180.0)
 }
+
+/// Convert a geodetic position (degrees, degrees, metres) to ECEF (ITRS) coordinates in kilometers
+/// using WGS84 ellipsoid parameters (a, f).
+pub fn geodetic_to_ecef_km(lat_deg: f64, lon_deg: f64, height_m: f64) -> [f64; 3] {
+    // WGS84 parameters in km
+    let a = 6378.137_f64;
+    let f = 1.0 / 298.257223563_f64;
+    let e_sq = f * (2.0 - f);
+
+    let lat = lat_deg.to_radians();
+    let lon = lon_deg.to_radians();
+    let h = height_m / 1000.0;
+
+    // Radius of curvature in prime vertical
+    let sin_lat = lat.sin();
+    let n_phi = a / (1.0 - e_sq * sin_lat * sin_lat).sqrt();
+
+    [
+        (n_phi + h) * lat.cos() * lon.cos(),
+        (n_phi + h) * lat.cos() * lon.sin(),
+        (n_phi * (1.0 - e_sq) + h) * sin_lat,
+    ]
+}
+
+/// Local (east, north, up) unit vectors in ECEF for a geodetic latitude and longitude in degrees
+pub fn enu_basis(lat_deg: f64, lon_deg: f64) -> [[f64; 3]; 3] {
+    let (sin_lat, cos_lat) = lat_deg.to_radians().sin_cos();
+    let (sin_lon, cos_lon) = lon_deg.to_radians().sin_cos();
+    [
+        [-sin_lon, cos_lon, 0.0],
+        [-sin_lat * cos_lon, -sin_lat * sin_lon, cos_lat],
+        [cos_lat * cos_lon, cos_lat * sin_lon, sin_lat],
+    ]
+}
+
+/// Geodetic latitude and longitude rates (deg/s) and height rate (km/s) of a
+/// point at the given geodetic position moving with an ITRS velocity (km/s),
+/// on the WGS84 ellipsoid. The longitude rate diverges at the poles.
+pub fn geodetic_rates(lat_deg: f64, lon_deg: f64, height_km: f64, velocity: [f64; 3]) -> [f64; 3] {
+    // WGS84 parameters in km
+    let a = 6378.137_f64;
+    let f = 1.0 / 298.257223563_f64;
+    let e_sq = f * (2.0 - f);
+
+    let [east, north, up] = enu_basis(lat_deg, lon_deg);
+    let along = |axis: [f64; 3]| -> f64 { (0..3).map(|k| axis[k] * velocity[k]).sum() };
+
+    let (sin_lat, cos_lat) = lat_deg.to_radians().sin_cos();
+    let w = 1.0 - e_sq * sin_lat * sin_lat;
+    // Radii of curvature in the prime vertical and the meridian
+    let n_phi = a / w.sqrt();
+    let m_phi = a * (1.0 - e_sq) / (w * w.sqrt());
+
+    [
+        (along(north) / (m_phi + height_km)).to_degrees(),
+        (along(east) / ((n_phi + height_km) * cos_lat)).to_degrees(),
+        along(up),
+    ]
+}
+
+/// Speed (km/s) of the point on the WGS84 ellipsoid directly below an observer
+/// with the given geodetic latitude and latitude/longitude rates (deg/s)
+pub fn ground_speed_km_s(lat_deg: f64, lat_rate_deg_s: f64, lon_rate_deg_s: f64) -> f64 {
+    let a = 6378.137_f64;
+    let f = 1.0 / 298.257223563_f64;
+    let e_sq = f * (2.0 - f);
+
+    let (sin_lat, cos_lat) = lat_deg.to_radians().sin_cos();
+    let w = 1.0 - e_sq * sin_lat * sin_lat;
+    let n_phi = a / w.sqrt();
+    let m_phi = a * (1.0 - e_sq) / (w * w.sqrt());
+
+    let north = m_phi * lat_rate_deg_s.to_radians();
+    let east = n_phi * cos_lat * lon_rate_deg_s.to_radians();
+    north.hypot(east)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_geodetic_ecef_round_trip() {
+        let points = [
+            (0.0, 0.0, 0.0),
+            (-24.6272, -70.4042, 2635.0),
+            (67.8571, 20.9644, 402.0),
+        ];
+        let mut ecef = Array2::<f64>::zeros((points.len(), 3));
+        for (i, &(lat, lon, h)) in points.iter().enumerate() {
+            let xyz = geodetic_to_ecef_km(lat, lon, h);
+            for (k, &v) in xyz.iter().enumerate() {
+                ecef[[i, k]] = v;
+            }
+        }
+        assert!((ecef[[0, 0]] - 6378.137).abs() < 1e-9);
+        let (lats, lons, hs) = ecef_to_geodetic_deg(&ecef);
+        for (i, &(lat, lon, h)) in points.iter().enumerate() {
+            assert!((lats[i] - lat).abs() < 1e-8);
+            assert!((lons[i] - lon).abs() < 1e-8);
+            assert!((hs[i] * 1000.0 - h).abs() < 1e-3);
+        }
+    }
+
+    #[test]
+    fn test_enu_basis_up_matches_normal() {
+        let [east, north, up] = enu_basis(0.0, 90.0);
+        assert!((east[0] + 1.0).abs() < 1e-12);
+        assert!((north[2] - 1.0).abs() < 1e-12);
+        assert!((up[1] - 1.0).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_geodetic_rates_match_finite_difference() {
+        let (lat, lon, h) = (37.0, -122.0, 500.0);
+        let velocity = [3.1, -5.2, 4.4];
+        // Central difference over +/- 1 s
+        let dt = 1.0;
+        let start = geodetic_to_ecef_km(lat, lon, h * 1000.0);
+        let mut moved = Array2::<f64>::zeros((2, 3));
+        for k in 0..3 {
+            moved[[0, k]] = start[k] - velocity[k] * dt;
+            moved[[1, k]] = start[k] + velocity[k] * dt;
+        }
+        let (lats, lons, hs) = ecef_to_geodetic_deg(&moved);
+        let [lat_rate, lon_rate, h_rate] = geodetic_rates(lat, lon, h, velocity);
+        assert!(((lats[1] - lats[0]) / (2.0 * dt) - lat_rate).abs() < 1e-6);
+        assert!(((lons[1] - lons[0]) / (2.0 * dt) - lon_rate).abs() < 1e-6);
+        assert!(((hs[1] - hs[0]) / (2.0 * dt) - h_rate).abs() < 1e-3);
+    }
+
+    #[test]
+    fn test_ground_speed_scales_to_surface() {
+        // Horizontal 7.5 km/s eastward at the equator, 500 km up
+        let [lat_rate, lon_rate, h_rate] = geodetic_rates(0.0, 0.0, 500.0, [0.0, 7.5, 0.0]);
+        assert!(lat_rate.abs() < 1e-12 && h_rate.abs() < 1e-12);
+        let speed = ground_speed_km_s(0.0, lat_rate, lon_rate);
+        assert!((speed - 7.5 * 6378.137 / 6878.137).abs() < 1e-9);
+    }
+}
@@ -0,0 +1,212 @@
+//! Centred-dipole geomagnetic coordinates and Störmer cutoff rigidity
+//!
+//! The dipole is the degree-1 part of the IGRF-14 main field. Coefficients
+//! are interpolated linearly between the five-yearly model epochs and
+//! extrapolated with the published secular variation after the last one;
+//! times before the first tabulated epoch use its coefficients unchanged.
+//!
+//! Geomagnetic latitude and longitude follow the usual MAG convention: the
+//! Z axis points to the northern geomagnetic pole and the Y axis is
+//! perpendicular to both the geographic and geomagnetic axes. The vertical
+//! cutoff rigidity uses the Störmer approximation for a centred dipole,
+//! which ignores the non-dipole field and the day/night asymmetry of the
+//! magnetosphere, so treat it as a first-order estimate for screening.
+
+use chrono::{DateTime, TimeZone, Utc};
+use ndarray::{Array1, Array2};
+
+use crate::utils::config::SPEED_OF_LIGHT_KM_S;
+
+/// IGRF reference radius (km)
+pub const IGRF_REFERENCE_RADIUS_KM: f64 = 6371.2;
+
+/// Degree-1 IGRF-14 coefficients `(epoch, g10, g11, h11)` in nT
+const DIPOLE_COEFFICIENTS: [(f64, f64, f64, f64); 6] = [
+    (2000.0, -29619.4, -1728.2, 5186.1),
+    (2005.0, -29554.63, -1669.05, 5077.99),
+    (2010.0, -29496.57, -1586.42, 4944.26),
+    (2015.0, -29441.46, -1501.77, 4795.99),
+    (2020.0, -29403.41, -1451.37, 4653.35),
+    (2025.0, -29350.0, -1410.3, 4545.5),
+];
+
+/// Secular variation of `(g10, g11, h11)` after the last epoch in nT/year
+const DIPOLE_SECULAR_VARIATION: (f64, f64, f64) = (12.6, 10.0, -21.5);
+
+/// Decimal year of a UTC time
+fn decimal_year(time: &DateTime<Utc>) -> f64 {
+    let j2000 = Utc.with_ymd_and_hms(2000, 1, 1, 12, 0, 0).unwrap();
+    2000.0 + (*time - j2000).num_milliseconds() as f64 / (365.25 * 86_400_000.0)
+}
+
+/// Degree-1 coefficients `(g10, g11, h11)` in nT at a decimal year
+fn dipole_coefficients(year: f64) -> (f64, f64, f64) {
+    let (first_epoch, g10, g11, h11) = DIPOLE_COEFFICIENTS[0];
+    if year <= first_epoch {
+        return (g10, g11, h11);
+    }
+    for pair in DIPOLE_COEFFICIENTS.windows(2) {
+        let (t0, a0, b0, c0) = pair[0];
+        let (t1, a1, b1, c1) = pair[1];
+        if year <= t1 {
+            let f = (year - t0) / (t1 - t0);
+            return (a0 + f * (a1 - a0), b0 + f * (b1 - b0), c0 + f * (c1 - c0));
+        }
+    }
+    let (last_epoch, g10, g11, h11) = DIPOLE_COEFFICIENTS[DIPOLE_COEFFICIENTS.len() - 1];
+    let (dg10, dg11, dh11) = DIPOLE_SECULAR_VARIATION;
+    let dt = year - last_epoch;
+    (g10 + dg10 * dt, g11 + dg11 * dt, h11 + dh11 * dt)
+}
+
+/// Orientation and strength of the centred dipole at one epoch
+#[derive(Debug, Clone, Copy)]
+pub struct Dipole {
+    /// MAG frame axes expressed in ITRS (rows: X, Y, Z)
+    axes: [[f64; 3]; 3],
+    /// Equatorial field strength at the reference radius (nT)
+    b0_nt: f64,
+}
+
+impl Dipole {
+    /// Dipole of the IGRF main field at `time`
+    pub fn at(time: &DateTime<Utc>) -> Self {
+        let (g10, g11, h11) = dipole_coefficients(decimal_year(time));
+        let b0_nt = (g10 * g10 + g11 * g11 + h11 * h11).sqrt();
+        // The northern geomagnetic pole is the antipode of the dipole moment
+        let z = [-g11 / b0_nt, -h11 / b0_nt, -g10 / b0_nt];
+        let y_len = (z[0] * z[0] + z[1] * z[1]).sqrt();
+        let y = [-z[1] / y_len, z[0] / y_len, 0.0];
+        let x = [
+            y[1] * z[2] - y[2] * z[1],
+            y[2] * z[0] - y[0] * z[2],
+            y[0] * z[1] - y[1] * z[0],
+        ];
+        Dipole {
+            axes: [x, y, z],
+            b0_nt,
+        }
+    }
+
+    /// Geographic latitude and longitude of the northern geomagnetic pole in degrees
+    #[allow(dead_code)]
+    pub fn north_pole_deg(&self) -> (f64, f64) {
+        let z = self.axes[2];
+        (z[2].asin().to_degrees(), z[1].atan2(z[0]).to_degrees())
+    }
+
+    /// Vertical cutoff rigidity at the geomagnetic equator on the reference sphere (GV)
+    ///
+    /// This is a quarter of the Störmer constant, `B0 R c / 4`.
+    pub fn equatorial_cutoff_gv(&self) -> f64 {
+        // nT * km * km/s = 1e-3 V
+        self.b0_nt * IGRF_REFERENCE_RADIUS_KM * SPEED_OF_LIGHT_KM_S * 1e-12 / 4.0
+    }
+
+    /// Geomagnetic latitude and longitude in degrees of an ITRS position in km
+    pub fn coordinates_deg(&self, position: [f64; 3]) -> (f64, f64) {
+        let r = (position[0].powi(2) + position[1].powi(2) + position[2].powi(2)).sqrt();
+        let [x, y, z] = self.axes.map(|axis| {
+            (axis[0] * position[0] + axis[1] * position[1] + axis[2] * position[2]) / r
+        });
+        (
+            z.clamp(-1.0, 1.0).asin().to_degrees(),
+            y.atan2(x).to_degrees(),
+        )
+    }
+
+    /// Störmer vertical cutoff rigidity in GV at an ITRS position in km
+    pub fn cutoff_rigidity_gv(&self, position: [f64; 3]) -> f64 {
+        let r = (position[0].powi(2) + position[1].powi(2) + position[2].powi(2)).sqrt();
+        let (lat_deg, _) = self.coordinates_deg(position);
+        let cos_lat = lat_deg.to_radians().cos();
+        self.equatorial_cutoff_gv() * cos_lat.powi(4) * (IGRF_REFERENCE_RADIUS_KM / r).powi(2)
+    }
+}
+
+/// Geomagnetic latitude (deg), longitude (deg) and vertical cutoff rigidity (GV)
+/// for ITRS positions (Nx3, km) at the matching times
+pub fn geomagnetic_coordinates(
+    positions: &Array2<f64>,
+    times: &[DateTime<Utc>],
+) -> (Array1<f64>, Array1<f64>, Array1<f64>) {
+    let n = positions.nrows();
+    let mut lats = Array1::<f64>::zeros(n);
+    let mut lons = Array1::<f64>::zeros(n);
+    let mut rigidities = Array1::<f64>::zeros(n);
+
+    for (i, (row, time)) in positions.rows().into_iter().zip(times).enumerate() {
+        let dipole = Dipole::at(time);
+        let position = [row[0], row[1], row[2]];
+        let (lat, lon) = dipole.coordinates_deg(position);
+        lats[i] = lat;
+        lons[i] = lon;
+        rigidities[i] = dipole.cutoff_rigidity_gv(position);
+    }
+
+    (lats, lons, rigidities)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn epoch(year: i32) -> DateTime<Utc> {
+        Utc.with_ymd_and_hms(year, 1, 1, 12, 0, 0).unwrap()
+    }
+
+    #[test]
+    fn test_dipole_coefficients_at_epochs() {
+        assert_eq!(dipole_coefficients(2015.0), (-29441.46, -1501.77, 4795.99));
+        assert_eq!(dipole_coefficients(1990.0), (-29619.4, -1728.2, 5186.1));
+        let (g10, _, h11) = dipole_coefficients(2027.0);
+        assert!((g10 - (-29350.0 + 2.0 * 12.6)).abs() < 1e-9);
+        assert!((h11 - (4545.5 - 2.0 * 21.5)).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_north_pole_location() {
+        // IGRF-14 geomagnetic north pole for 2025 is near 80.8N, 72.8W
+        let (lat, lon) = Dipole::at(&epoch(2025)).north_pole_deg();
+        assert!((lat - 80.8).abs() < 0.1, "lat = {lat}");
+        assert!((lon + 72.8).abs() < 0.1, "lon = {lon}");
+    }
+
+    #[test]
+    fn test_pole_coordinates() {
+        let dipole = Dipole::at(&epoch(2025));
+        let (pole_lat, pole_lon) = dipole.north_pole_deg();
+        let (plat, plon) = (pole_lat.to_radians(), pole_lon.to_radians());
+        let r = 7000.0;
+        let pole = [
+            r * plat.cos() * plon.cos(),
+            r * plat.cos() * plon.sin(),
+            r * plat.sin(),
+        ];
+        assert!((dipole.coordinates_deg(pole).0 - 90.0).abs() < 1e-6);
+        assert!(dipole.cutoff_rigidity_gv(pole).abs() < 1e-9);
+
+        // The geographic north pole lies on the MAG X-Z plane, at longitude 180
+        let (lat, lon) = dipole.coordinates_deg([0.0, 0.0, r]);
+        assert!((lat - pole_lat).abs() < 1e-9);
+        assert!((lon.abs() - 180.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_cutoff_rigidity_scaling() {
+        let dipole = Dipole::at(&epoch(2025));
+        // ~14.2 GV at the geomagnetic equator on the reference sphere
+        assert!((dipole.equatorial_cutoff_gv() - 14.2).abs() < 0.1);
+
+        // Falls as 1/r^2 along a radial line
+        let direction = [1.0, 0.3, -0.2];
+        let at = |scale: f64| {
+            dipole.cutoff_rigidity_gv([
+                direction[0] * scale,
+                direction[1] * scale,
+                direction[2] * scale,
+            ])
+        };
+        assert!((at(2.0) / at(1.0) - 0.25).abs() < 1e-12);
+    }
+}
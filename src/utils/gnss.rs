@@ -0,0 +1,204 @@
+//! Shared GNSS time and broadcast orbit utilities
+//!
+//! GPS and Galileo broadcast ephemerides and almanacs use the same Keplerian
+//! orbit model (IS-GPS-200 Table 20-IV, Galileo OS SIS ICD §5.1.1), differing
+//! only in the gravitational parameter and in which harmonic corrections are
+//! transmitted. States are produced in the Earth-fixed frame of the system
+//! (WGS 84 / GTRF), which is treated as ITRS.
+
+use chrono::{DateTime, TimeZone, Utc};
+use hifitime::Epoch;
+
+use crate::utils::config::GPS_OMEGA_EARTH;
+use crate::utils::time_utils::chrono_to_epoch;
+
+/// GPS seconds (since 1980-01-06) of a UTC time
+pub fn gps_seconds(time: &DateTime<Utc>) -> f64 {
+    chrono_to_epoch(time).to_gpst_seconds()
+}
+
+/// Convert GPS seconds (since 1980-01-06) to a UTC time
+pub fn gps_seconds_to_utc(seconds: f64) -> Result<DateTime<Utc>, String> {
+    let unix = Epoch::from_gpst_seconds(seconds).to_unix_seconds();
+    let secs = unix.floor();
+    let nanos = ((unix - secs) * 1e9).round().min(999_999_999.0);
+    Utc.timestamp_opt(secs as i64, nanos as u32)
+        .single()
+        .ok_or_else(|| format!("Invalid GPS time {}", seconds))
+}
+
+/// Keplerian broadcast orbit with second-harmonic corrections
+///
+/// Angles are in radians, rates in rad/s and radial corrections in metres.
+/// Almanacs use the same model with `delta_n`, `idot` and all harmonic
+/// corrections set to zero.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct BroadcastOrbit {
+    /// Gravitational parameter of the system (m^3/s^2)
+    pub mu: f64,
+    pub sqrt_a: f64,
+    pub eccentricity: f64,
+    /// Inclination at reference time
+    pub inclination: f64,
+    /// Rate of inclination
+    pub idot: f64,
+    /// Longitude of ascending node at weekly epoch
+    pub omega0: f64,
+    /// Rate of right ascension
+    pub omega_dot: f64,
+    pub arg_perigee: f64,
+    /// Mean anomaly at reference time
+    pub mean_anomaly: f64,
+    /// Mean motion difference from computed value
+    pub delta_n: f64,
+    pub cuc: f64,
+    pub cus: f64,
+    pub crc: f64,
+    pub crs: f64,
+    pub cic: f64,
+    pub cis: f64,
+    /// Reference time, seconds into the week
+    pub toe: f64,
+}
+
+impl BroadcastOrbit {
+    /// Earth-fixed position and velocity `tk` seconds after the reference time
+    ///
+    /// Returns [x, y, z, vx, vy, vz] in km and km/s.
+    pub fn ecef_state(&self, tk: f64) -> [f64; 6] {
+        let a = self.sqrt_a * self.sqrt_a;
+        let e = self.eccentricity;
+        let n = (self.mu / (a * a * a)).sqrt() + self.delta_n;
+
+        // Kepler's equation
+        let mk = self.mean_anomaly + n * tk;
+        let mut ek = mk;
+        for _ in 0..30 {
+            let delta = (ek - e * ek.sin() - mk) / (1.0 - e * ek.cos());
+            ek -= delta;
+            if delta.abs() < 1e-14 {
+                break;
+            }
+        }
+
+        let (sin_e, cos_e) = ek.sin_cos();
+        let one_minus_ecos = 1.0 - e * cos_e;
+        let nu = ((1.0 - e * e).sqrt() * sin_e).atan2(cos_e - e);
+        let phi = nu + self.arg_perigee;
+        let (sin_2phi, cos_2phi) = (2.0 * phi).sin_cos();
+
+        // Second-harmonic perturbations
+        let u = phi + self.cus * sin_2phi + self.cuc * cos_2phi;
+        let r = a * one_minus_ecos + self.crs * sin_2phi + self.crc * cos_2phi;
+        let i = self.inclination + self.idot * tk + self.cis * sin_2phi + self.cic * cos_2phi;
+
+        let e_dot = n / one_minus_ecos;
+        let phi_dot = e_dot * (1.0 - e * e).sqrt() / one_minus_ecos;
+        let u_dot = phi_dot * (1.0 + 2.0 * (self.cus * cos_2phi - self.cuc * sin_2phi));
+        let r_dot =
+            a * e * sin_e * e_dot + 2.0 * phi_dot * (self.crs * cos_2phi - self.crc * sin_2phi);
+        let i_dot = self.idot + 2.0 * phi_dot * (self.cis * cos_2phi - self.cic * sin_2phi);
+
+        let omega_k_dot = self.omega_dot - GPS_OMEGA_EARTH;
+        let omega_k = self.omega0 + omega_k_dot * tk - GPS_OMEGA_EARTH * self.toe;
+
+        let (sin_u, cos_u) = u.sin_cos();
+        let (sin_i, cos_i) = i.sin_cos();
+        let (sin_o, cos_o) = omega_k.sin_cos();
+
+        // Position in the orbital plane and its rate
+        let xp = r * cos_u;
+        let yp = r * sin_u;
+        let xp_dot = r_dot * cos_u - r * sin_u * u_dot;
+        let yp_dot = r_dot * sin_u + r * cos_u * u_dot;
+
+        let x = xp * cos_o - yp * cos_i * sin_o;
+        let y = xp * sin_o + yp * cos_i * cos_o;
+        let z = yp * sin_i;
+
+        let vx =
+            xp_dot * cos_o - yp_dot * cos_i * sin_o + yp * sin_i * sin_o * i_dot - y * omega_k_dot;
+        let vy =
+            xp_dot * sin_o + yp_dot * cos_i * cos_o - yp * sin_i * cos_o * i_dot + x * omega_k_dot;
+        let vz = yp_dot * sin_i + yp * cos_i * i_dot;
+
+        [
+            x / 1000.0,
+            y / 1000.0,
+            z / 1000.0,
+            vx / 1000.0,
+            vy / 1000.0,
+            vz / 1000.0,
+        ]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::config::GPS_MU;
+
+    fn sample_orbit() -> BroadcastOrbit {
+        BroadcastOrbit {
+            mu: GPS_MU,
+            sqrt_a: 5153.65531,
+            eccentricity: 0.005912038265,
+            inclination: 0.9848407943,
+            idot: 1.342913098e-10,
+            omega0: -1.6732193420,
+            omega_dot: -8.386063264e-9,
+            arg_perigee: 0.4122218083,
+            mean_anomaly: 0.1432852298,
+            delta_n: 4.249105564e-9,
+            cuc: -6.0126185417e-7,
+            cus: 5.6810677052e-6,
+            crc: 2.7871875e2,
+            crs: -1.159375e1,
+            cic: -1.1175870896e-7,
+            cis: 1.6763806343e-7,
+            toe: 86400.0,
+        }
+    }
+
+    #[test]
+    fn test_broadcast_velocity_matches_position_derivative() {
+        let orbit = sample_orbit();
+        let tk = 5400.0;
+        let s = orbit.ecef_state(tk);
+        let dt = 0.5;
+        let before = orbit.ecef_state(tk - dt);
+        let after = orbit.ecef_state(tk + dt);
+        for (j, (a, b)) in after.iter().zip(before.iter()).take(3).enumerate() {
+            let numeric = (a - b) / (2.0 * dt);
+            assert!((numeric - s[j + 3]).abs() < 1e-6, "axis {}", j);
+        }
+    }
+
+    #[test]
+    fn test_corrections_are_small() {
+        let orbit = sample_orbit();
+        let plain = BroadcastOrbit {
+            idot: 0.0,
+            delta_n: 0.0,
+            cuc: 0.0,
+            cus: 0.0,
+            crc: 0.0,
+            crs: 0.0,
+            cic: 0.0,
+            cis: 0.0,
+            ..orbit.clone()
+        };
+        let a = orbit.ecef_state(0.0);
+        let b = plain.ecef_state(0.0);
+        let diff = ((a[0] - b[0]).powi(2) + (a[1] - b[1]).powi(2) + (a[2] - b[2]).powi(2)).sqrt();
+        // Harmonic corrections amount to at most a few hundred metres
+        assert!(diff > 0.0 && diff < 1.0, "diff {} km", diff);
+    }
+
+    #[test]
+    fn test_gps_seconds_roundtrip() {
+        let dt = Utc.with_ymd_and_hms(2024, 3, 1, 12, 0, 0).unwrap();
+        let back = gps_seconds_to_utc(gps_seconds(&dt)).unwrap();
+        assert!((back - dt).num_milliseconds().abs() <= 1);
+    }
+}
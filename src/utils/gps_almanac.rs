@@ -0,0 +1,375 @@
+//! GPS almanac (YUMA and SEM) parsing and propagation
+//!
+//! Almanacs carry a reduced set of Keplerian elements per PRN which are
+//! propagated with the IS-GPS-200 almanac model (Table 20-IV without the
+//! harmonic corrections). Positions are produced in the WGS 84 Earth-fixed
+//! frame, which is treated as ITRS.
+//!
+//! ## Supported formats
+//!
+//! - **YUMA** (`.alm`): one labelled block per PRN, angles in radians.
+//! - **SEM** (`.al3`): compact numeric records, angles in semicircles and the
+//!   inclination given as an offset from 0.30 semicircles.
+//!
+//! Both formats broadcast a 10-bit GPS week number; the full week is resolved
+//! against a reference time (normally the start of the requested ephemeris).
+
+use std::f64::consts::PI;
+
+use crate::utils::config::{GPS_MU, GPS_SECONDS_PER_WEEK, GPS_WEEK_ROLLOVER};
+use crate::utils::gnss::BroadcastOrbit;
+
+/// Almanac file format
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AlmanacFormat {
+    Yuma,
+    Sem,
+}
+
+impl AlmanacFormat {
+    /// Parse a user-supplied format name ("yuma" or "sem", case-insensitive)
+    pub fn parse(name: &str) -> Result<Self, String> {
+        match name.trim().to_ascii_lowercase().as_str() {
+            "yuma" => Ok(AlmanacFormat::Yuma),
+            "sem" => Ok(AlmanacFormat::Sem),
+            other => Err(format!(
+                "Unknown almanac format '{}'. Expected 'yuma' or 'sem'",
+                other
+            )),
+        }
+    }
+
+    /// Guess the format from file contents
+    pub fn detect(text: &str) -> Self {
+        if text.lines().any(|l| l.trim_start().starts_with("ID:")) {
+            AlmanacFormat::Yuma
+        } else {
+            AlmanacFormat::Sem
+        }
+    }
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            AlmanacFormat::Yuma => "yuma",
+            AlmanacFormat::Sem => "sem",
+        }
+    }
+}
+
+/// Keplerian almanac elements for one GPS satellite
+///
+/// Angles are stored in radians regardless of the source format.
+#[derive(Debug, Clone, PartialEq)]
+pub struct GpsAlmanacRecord {
+    pub prn: u32,
+    pub health: u32,
+    pub eccentricity: f64,
+    /// Time of applicability, seconds into the GPS week
+    pub toa: f64,
+    /// Inclination (rad)
+    pub inclination: f64,
+    /// Rate of right ascension (rad/s)
+    pub omega_dot: f64,
+    /// Square root of the semi-major axis (m^1/2)
+    pub sqrt_a: f64,
+    /// Longitude of ascending node at weekly epoch (rad)
+    pub omega0: f64,
+    /// Argument of perigee (rad)
+    pub arg_perigee: f64,
+    /// Mean anomaly at reference time (rad)
+    pub mean_anomaly: f64,
+    /// Clock bias (s)
+    pub af0: f64,
+    /// Clock drift (s/s)
+    pub af1: f64,
+    /// GPS week number as written in the file (usually modulo 1024)
+    pub week: i64,
+}
+
+/// Parse almanac text, detecting the format when `format` is `None`
+pub fn parse_almanac(
+    text: &str,
+    format: Option<AlmanacFormat>,
+) -> Result<(AlmanacFormat, Vec<GpsAlmanacRecord>), String> {
+    let format = format.unwrap_or_else(|| AlmanacFormat::detect(text));
+    let records = match format {
+        AlmanacFormat::Yuma => parse_yuma(text)?,
+        AlmanacFormat::Sem => parse_sem(text)?,
+    };
+    if records.is_empty() {
+        return Err(format!(
+            "No satellite records found in {} almanac",
+            format.as_str().to_uppercase()
+        ));
+    }
+    Ok((format, records))
+}
+
+/// Parse a YUMA almanac
+pub fn parse_yuma(text: &str) -> Result<Vec<GpsAlmanacRecord>, String> {
+    let mut records = Vec::new();
+    let mut fields: Vec<(String, String)> = Vec::new();
+
+    for line in text.lines() {
+        let Some((key, value)) = line.split_once(':') else {
+            continue;
+        };
+        let key = key.trim().to_ascii_lowercase();
+        if key == "id" && !fields.is_empty() {
+            records.push(yuma_record(&fields)?);
+            fields.clear();
+        }
+        fields.push((key, value.trim().to_string()));
+    }
+    if !fields.is_empty() {
+        records.push(yuma_record(&fields)?);
+    }
+
+    Ok(records)
+}
+
+fn yuma_record(fields: &[(String, String)]) -> Result<GpsAlmanacRecord, String> {
+    let get = |prefix: &str| -> Result<f64, String> {
+        let (_, value) = fields
+            .iter()
+            .find(|(k, _)| k.starts_with(prefix))
+            .ok_or_else(|| format!("YUMA record is missing '{}'", prefix))?;
+        value
+            .parse::<f64>()
+            .map_err(|_| format!("Invalid YUMA value for '{}': '{}'", prefix, value))
+    };
+
+    Ok(GpsAlmanacRecord {
+        prn: get("id")? as u32,
+        health: get("health")? as u32,
+        eccentricity: get("eccentricity")?,
+        toa: get("time of applicability")?,
+        inclination: get("orbital inclination")?,
+        omega_dot: get("rate of right ascen")?,
+        sqrt_a: get("sqrt(a)")?,
+        omega0: get("right ascen at week")?,
+        arg_perigee: get("argument of perigee")?,
+        mean_anomaly: get("mean anom")?,
+        af0: get("af0")?,
+        af1: get("af1")?,
+        week: get("week")? as i64,
+    })
+}
+
+/// Parse a SEM almanac
+///
+/// The header holds the record count and almanac name, followed by the week
+/// number and time of applicability shared by all records.
+pub fn parse_sem(text: &str) -> Result<Vec<GpsAlmanacRecord>, String> {
+    let mut lines = text.lines().filter(|l| !l.trim().is_empty());
+
+    let count: usize = lines
+        .next()
+        .and_then(|l| l.split_whitespace().next())
+        .and_then(|t| t.parse().ok())
+        .ok_or("SEM almanac header is missing the record count")?;
+    let mut week_line = lines
+        .next()
+        .ok_or("SEM almanac header is missing the week and time of applicability")?
+        .split_whitespace();
+    let week: i64 = week_line
+        .next()
+        .and_then(|t| t.parse().ok())
+        .ok_or("Invalid SEM week number")?;
+    let toa: f64 = week_line
+        .next()
+        .and_then(|t| t.parse().ok())
+        .ok_or("Invalid SEM time of applicability")?;
+
+    let tokens: Vec<&str> = lines.flat_map(|l| l.split_whitespace()).collect();
+    const FIELDS: usize = 14;
+    if tokens.len() < count * FIELDS {
+        return Err(format!(
+            "SEM almanac declares {} records but only contains data for {}",
+            count,
+            tokens.len() / FIELDS
+        ));
+    }
+
+    tokens
+        .chunks_exact(FIELDS)
+        .take(count)
+        .map(|t| {
+            let num = |i: usize| -> Result<f64, String> {
+                t[i].parse::<f64>()
+                    .map_err(|_| format!("Invalid SEM value '{}'", t[i]))
+            };
+            Ok(GpsAlmanacRecord {
+                prn: num(0)? as u32,
+                health: num(12)? as u32,
+                eccentricity: num(3)?,
+                toa,
+                // Inclination is an offset from the 0.30 semicircle reference
+                inclination: (0.30 + num(4)?) * PI,
+                omega_dot: num(5)? * PI,
+                sqrt_a: num(6)?,
+                omega0: num(7)? * PI,
+                arg_perigee: num(8)? * PI,
+                mean_anomaly: num(9)? * PI,
+                af0: num(10)?,
+                af1: num(11)?,
+                week,
+            })
+        })
+        .collect()
+}
+
+/// Resolve a possibly truncated (10-bit) GPS week against a reference time
+///
+/// Weeks of 1024 or more are taken as already complete. Otherwise the rollover
+/// that places the week closest to `reference_gps_seconds` is chosen.
+pub fn resolve_gps_week(week: i64, reference_gps_seconds: f64) -> i64 {
+    if week >= GPS_WEEK_ROLLOVER {
+        return week;
+    }
+    let reference_week = (reference_gps_seconds / GPS_SECONDS_PER_WEEK).floor() as i64;
+    let rollovers = ((reference_week - week) as f64 / GPS_WEEK_ROLLOVER as f64).round() as i64;
+    week + rollovers.max(0) * GPS_WEEK_ROLLOVER
+}
+
+impl GpsAlmanacRecord {
+    /// GPS seconds of the time of applicability, using the given full week number
+    pub fn toa_gps_seconds(&self, full_week: i64) -> f64 {
+        full_week as f64 * GPS_SECONDS_PER_WEEK + self.toa
+    }
+
+    /// Broadcast orbit model equivalent to these almanac elements
+    pub fn orbit(&self) -> BroadcastOrbit {
+        BroadcastOrbit {
+            mu: GPS_MU,
+            sqrt_a: self.sqrt_a,
+            eccentricity: self.eccentricity,
+            inclination: self.inclination,
+            omega0: self.omega0,
+            omega_dot: self.omega_dot,
+            arg_perigee: self.arg_perigee,
+            mean_anomaly: self.mean_anomaly,
+            toe: self.toa,
+            ..BroadcastOrbit::default()
+        }
+    }
+
+    /// Earth-fixed (WGS 84) position and velocity at a GPS time
+    ///
+    /// `toa_gps_seconds` is the resolved time of applicability. Returns
+    /// [x, y, z, vx, vy, vz] in km and km/s.
+    pub fn ecef_state(&self, gps_seconds: f64, toa_gps_seconds: f64) -> [f64; 6] {
+        self.orbit().ecef_state(gps_seconds - toa_gps_seconds)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const YUMA_SAMPLE: &str = "\
+******** Week 167 almanac for PRN-01 ********
+ID:                         01
+Health:                     000
+Eccentricity:               0.1154422760E-001
+Time of Applicability(s):  589824.0000
+Orbital Inclination(rad):   0.9874515300
+Rate of Right Ascen(r/s):  -0.7657461295E-008
+SQRT(A)  (m 1/2):           5153.613770
+Right Ascen at Week(rad):   0.8782711029E+000
+Argument of Perigee(rad):   0.878271103
+Mean Anom(rad):            -0.2487392340E+001
+Af0(s):                     0.4959106445E-003
+Af1(s/s):                   0.3637978807E-011
+week:                        167
+
+******** Week 167 almanac for PRN-02 ********
+ID:                         02
+Health:                     000
+Eccentricity:               0.2007770538E-001
+Time of Applicability(s):  589824.0000
+Orbital Inclination(rad):   0.9563442945
+Rate of Right Ascen(r/s):  -0.7874613724E-008
+SQRT(A)  (m 1/2):           5153.597168
+Right Ascen at Week(rad):  -0.1218045890E+001
+Argument of Perigee(rad):  -1.761436343
+Mean Anom(rad):             0.1531521440E+001
+Af0(s):                    -0.2555847168E-003
+Af1(s/s):                   0.0000000000E+000
+week:                        167
+";
+
+    #[test]
+    fn test_parse_yuma() {
+        let (format, records) = parse_almanac(YUMA_SAMPLE, None).unwrap();
+        assert_eq!(format, AlmanacFormat::Yuma);
+        assert_eq!(records.len(), 2);
+        assert_eq!(records[0].prn, 1);
+        assert_eq!(records[1].prn, 2);
+        assert_eq!(records[0].week, 167);
+        assert!((records[0].toa - 589824.0).abs() < 1e-9);
+        assert!((records[1].arg_perigee + 1.761436343).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_parse_sem_matches_yuma() {
+        let yuma = &parse_yuma(YUMA_SAMPLE).unwrap()[0];
+        let sem = format!(
+            "1 CURRENT.ALM\n167 589824\n\n1\n63\n0\n{:e} {:e} {:e}\n{:e} {:e} {:e}\n{:e} {:e} {:e}\n0\n9\n",
+            yuma.eccentricity,
+            yuma.inclination / PI - 0.30,
+            yuma.omega_dot / PI,
+            yuma.sqrt_a,
+            yuma.omega0 / PI,
+            yuma.arg_perigee / PI,
+            yuma.mean_anomaly / PI,
+            yuma.af0,
+            yuma.af1,
+        );
+        let (format, records) = parse_almanac(&sem, None).unwrap();
+        assert_eq!(format, AlmanacFormat::Sem);
+        assert_eq!(records.len(), 1);
+        let rec = &records[0];
+        assert_eq!(rec.prn, 1);
+        assert_eq!(rec.week, 167);
+        assert!((rec.inclination - yuma.inclination).abs() < 1e-12);
+        assert!((rec.mean_anomaly - yuma.mean_anomaly).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_sem_record_count_mismatch() {
+        let sem = "2 CURRENT.ALM\n167 589824\n1 63 0 0.01 0.0 0.0 5153.6 0.0 0.0 0.0 0.0 0.0 0 9\n";
+        assert!(parse_sem(sem).is_err());
+    }
+
+    #[test]
+    fn test_resolve_gps_week() {
+        // Reference in week 2300 (third rollover era)
+        let reference = 2300.0 * GPS_SECONDS_PER_WEEK;
+        assert_eq!(resolve_gps_week(2300 - 2048, reference), 2300);
+        assert_eq!(resolve_gps_week(2301, reference), 2301);
+        // Week 1023 before the reference rolls forward by one era only
+        assert_eq!(resolve_gps_week(1023, 2050.0 * GPS_SECONDS_PER_WEEK), 2047);
+    }
+
+    #[test]
+    fn test_ecef_state_radius_and_velocity() {
+        let rec = &parse_yuma(YUMA_SAMPLE).unwrap()[0];
+        let toa = rec.toa_gps_seconds(2215);
+        let t = toa + 3600.0;
+        let s = rec.ecef_state(t, toa);
+
+        // GPS orbits are ~26,560 km in radius
+        let r = (s[0] * s[0] + s[1] * s[1] + s[2] * s[2]).sqrt();
+        assert!((r - 26_560.0).abs() < 400.0, "radius {}", r);
+
+        // Velocity is the time derivative of the Earth-fixed position
+        let dt = 0.5;
+        let before = rec.ecef_state(t - dt, toa);
+        let after = rec.ecef_state(t + dt, toa);
+        for (j, (a, b)) in after.iter().zip(before.iter()).take(3).enumerate() {
+            let numeric = (a - b) / (2.0 * dt);
+            assert!((numeric - s[j + 3]).abs() < 1e-6, "axis {}", j);
+        }
+    }
+}
@@ -0,0 +1,115 @@
+//! Azimuth-dependent local horizon profiles for ground sites
+//!
+//! A horizon mask is a list of (azimuth, minimum elevation) points in
+//! degrees. Between points the minimum elevation is interpolated linearly in
+//! azimuth, wrapping through north, so a few points are enough to describe
+//! mountains or buildings around an observatory.
+
+/// Local horizon profile as (azimuth_deg, min_elevation_deg) points
+#[derive(Debug, Clone, PartialEq)]
+pub struct HorizonMask {
+    /// Points sorted by azimuth, with azimuths normalized to [0, 360)
+    points: Vec<(f64, f64)>,
+}
+
+impl HorizonMask {
+    /// Build a mask from (azimuth, min_elevation) pairs in degrees
+    ///
+    /// Azimuths are measured from north through east and may be given in any
+    /// order; they are normalized to [0, 360).
+    pub fn new(points: &[(f64, f64)]) -> Result<Self, String> {
+        if points.is_empty() {
+            return Err("horizon must contain at least one (azimuth, elevation) point".to_string());
+        }
+        let mut normalized = Vec::with_capacity(points.len());
+        for &(azimuth, elevation) in points {
+            if !azimuth.is_finite() || !elevation.is_finite() {
+                return Err(format!(
+                    "horizon point ({}, {}) must be finite",
+                    azimuth, elevation
+                ));
+            }
+            if !(-90.0..=90.0).contains(&elevation) {
+                return Err(format!(
+                    "horizon elevation {} must be between -90 and 90 degrees",
+                    elevation
+                ));
+            }
+            normalized.push((azimuth.rem_euclid(360.0), elevation));
+        }
+        normalized.sort_by(|a, b| a.0.total_cmp(&b.0));
+        Ok(HorizonMask { points: normalized })
+    }
+
+    /// Points sorted by azimuth
+    pub fn points(&self) -> &[(f64, f64)] {
+        &self.points
+    }
+
+    /// Minimum visible elevation (degrees) in the direction of `azimuth_deg`
+    pub fn min_elevation(&self, azimuth_deg: f64) -> f64 {
+        let azimuth = azimuth_deg.rem_euclid(360.0);
+        let n = self.points.len();
+        if n == 1 {
+            return self.points[0].1;
+        }
+
+        // Segment [k, k+1] containing the azimuth; the last segment wraps to the first point
+        let next = self.points.partition_point(|p| p.0 <= azimuth);
+        let ((az0, el0), (az1, el1), az) = if next == 0 || next == n {
+            let (az_first, el_first) = self.points[0];
+            let az = if next == 0 { azimuth + 360.0 } else { azimuth };
+            (self.points[n - 1], (az_first + 360.0, el_first), az)
+        } else {
+            (self.points[next - 1], self.points[next], azimuth)
+        };
+        el0 + (el1 - el0) * (az - az0) / (az1 - az0)
+    }
+
+    /// Whether a direction is above the local horizon
+    pub fn is_visible(&self, altitude_deg: f64, azimuth_deg: f64) -> bool {
+        altitude_deg >= self.min_elevation(azimuth_deg)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_interpolation_and_wrap() {
+        let mask = HorizonMask::new(&[(0.0, 10.0), (90.0, 20.0), (270.0, 0.0)]).unwrap();
+        assert!((mask.min_elevation(0.0) - 10.0).abs() < 1e-12);
+        assert!((mask.min_elevation(45.0) - 15.0).abs() < 1e-12);
+        assert!((mask.min_elevation(180.0) - 10.0).abs() < 1e-12);
+        // Between 270 and 360 (= first point at 0)
+        assert!((mask.min_elevation(315.0) - 5.0).abs() < 1e-12);
+        assert!((mask.min_elevation(-45.0) - 5.0).abs() < 1e-12);
+        assert!((mask.min_elevation(405.0) - 15.0).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_unsorted_and_unnormalized_points() {
+        let mask = HorizonMask::new(&[(350.0, 4.0), (-180.0, 8.0), (10.0, 6.0)]).unwrap();
+        assert_eq!(mask.points()[0], (10.0, 6.0));
+        assert_eq!(mask.points()[1], (180.0, 8.0));
+        // Wrap segment from 350 to 370 (= 10)
+        assert!((mask.min_elevation(0.0) - 5.0).abs() < 1e-12);
+        assert!((mask.min_elevation(355.0) - 4.5).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_single_point_is_flat() {
+        let mask = HorizonMask::new(&[(123.0, 7.5)]).unwrap();
+        assert_eq!(mask.min_elevation(0.0), 7.5);
+        assert!(mask.is_visible(7.5, 300.0));
+        assert!(!mask.is_visible(7.4, 300.0));
+    }
+
+    #[test]
+    fn test_invalid_points() {
+        assert!(HorizonMask::new(&[]).is_err());
+        assert!(HorizonMask::new(&[(0.0, 95.0)]).is_err());
+        assert!(HorizonMask::new(&[(f64::NAN, 0.0)]).is_err());
+    }
+}
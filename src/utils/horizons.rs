@@ -3,12 +3,18 @@
 /// This module provides functions to query NASA's JPL Horizons system
 /// for solar system body positions and velocities when SPICE kernels
 /// are not available or do not contain the required data.
-use chrono::{DateTime, Datelike, TimeZone, Utc};
+use chrono::{DateTime, Datelike, Utc};
 use hifitime::Epoch;
 use ndarray::Array2;
+use once_cell::sync::Lazy;
 use std::cmp::Ordering;
+use std::collections::{HashMap, VecDeque};
+use std::sync::{Arc, Mutex};
 
-use crate::utils::time_utils::chrono_to_epoch;
+use crate::utils::config::{
+    HORIZONS_API_URL, HORIZONS_CACHE_MAX_ENTRIES, HORIZONS_MAX_ROWS, HORIZONS_MAX_STEP_MINUTES,
+};
+use crate::utils::time_utils::{chrono_to_epoch, epoch_to_chrono};
 
 /// Query JPL Horizons for body ephemeris data by NAIF ID in GCRS frame
 ///
@@ -33,60 +39,171 @@ pub fn query_horizons_body(times: &[DateTime<Utc>], body_id: i32) -> Result<Arra
         return Err("No times provided for Horizons query".to_string());
     }
 
-    let start_time = times[0];
-    let end_time = times[times.len() - 1];
+    let body = fetch_horizons_vectors(&body_id.to_string(), times)?;
 
-    // Query Horizons API for GCRS vectors (observer = Earth = 399)
-    // Format timestamps for Horizons: YYYY-MM-DD (date only to avoid URL encoding issues with spaces)
-    let start_str = format!(
-        "{}-{:02}-{:02}",
-        start_time.year(),
-        start_time.month(),
-        start_time.day()
-    );
+    // Debug: check if response contains error
+    if body.contains("ERROR") || body.contains("error") {
+        eprintln!("Horizons error response for body {}:", body_id);
+        eprintln!("{}", body);
+        return Err("Horizons returned error".to_string());
+    }
 
-    // Add one day to end_time to ensure we get data even when start/end are the same day
-    // Horizons needs different start/end dates to return ephemeris data
-    let end_plus_day = end_time + chrono::Duration::days(1);
-    let end_str = format!(
-        "{}-{:02}-{:02}",
-        end_plus_day.year(),
-        end_plus_day.month(),
-        end_plus_day.day()
-    );
+    // Parse the response
+    parse_horizons_csv_response(&body, times)
+}
+
+/// Parameters identifying one Horizons VECTORS request; also the response cache key
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+struct HorizonsRequest {
+    command: String,
+    start: String,
+    stop: String,
+    step_minutes: i64,
+}
 
-    // Step size in minutes - use 1 minute steps for good coverage
-    let step_minutes = 1;
+impl HorizonsRequest {
+    /// Build a request covering `times` with a step matched to their spacing
+    ///
+    /// The window is padded by one step plus two minutes on each side so that
+    /// every requested time is bracketed by Horizons rows for interpolation, even
+    /// allowing for the TDB-UTC offset of the Horizons time tags.
+    fn for_times(command: &str, times: &[DateTime<Utc>]) -> Self {
+        let step_minutes = horizons_step_minutes(times);
+        let pad = chrono::Duration::minutes(step_minutes + 2);
+        let start = times[0] - pad;
+        let stop = times[times.len() - 1] + pad;
+        HorizonsRequest {
+            command: command.to_string(),
+            start: start.format("%Y-%m-%d %H:%M").to_string(),
+            stop: stop.format("%Y-%m-%d %H:%M").to_string(),
+            step_minutes,
+        }
+    }
+}
 
-    // Build Horizons API URL with ICRF/equatorial output (VECTORS format)
+/// Choose the Horizons step (whole minutes) for a set of requested times
+///
+/// Uses the median spacing of `times`, clamped to
+/// [1, `HORIZONS_MAX_STEP_MINUTES`] so linear interpolation stays accurate, then
+/// increased if necessary to keep the table below `HORIZONS_MAX_ROWS` rows.
+fn horizons_step_minutes(times: &[DateTime<Utc>]) -> i64 {
+    if times.len() < 2 {
+        return 1;
+    }
+
+    let mut spacings: Vec<i64> = times
+        .windows(2)
+        .map(|w| (w[1] - w[0]).num_seconds())
+        .filter(|&dt| dt > 0)
+        .collect();
+    if spacings.is_empty() {
+        return 1;
+    }
+    spacings.sort_unstable();
+    let median_seconds = spacings[spacings.len() / 2];
+
+    let step = (median_seconds / 60).clamp(1, HORIZONS_MAX_STEP_MINUTES);
+
+    let span_minutes = (times[times.len() - 1] - times[0]).num_minutes();
+    let min_step_for_rows = span_minutes / HORIZONS_MAX_ROWS + 1;
+    step.max(min_step_for_rows)
+}
+
+/// In-memory cache of successful Horizons responses, keyed on the full request
+struct HorizonsCache {
+    entries: HashMap<HorizonsRequest, Arc<String>>,
+    order: VecDeque<HorizonsRequest>,
+}
+
+static HORIZONS_CACHE: Lazy<Mutex<HorizonsCache>> = Lazy::new(|| {
+    Mutex::new(HorizonsCache {
+        entries: HashMap::new(),
+        order: VecDeque::new(),
+    })
+});
+
+fn horizons_cache_get(request: &HorizonsRequest) -> Option<Arc<String>> {
+    HORIZONS_CACHE
+        .lock()
+        .ok()
+        .and_then(|cache| cache.entries.get(request).cloned())
+}
+
+fn horizons_cache_insert(request: HorizonsRequest, body: Arc<String>) {
+    if let Ok(mut cache) = HORIZONS_CACHE.lock() {
+        if cache.entries.contains_key(&request) {
+            return;
+        }
+        while cache.order.len() >= HORIZONS_CACHE_MAX_ENTRIES {
+            if let Some(oldest) = cache.order.pop_front() {
+                cache.entries.remove(&oldest);
+            }
+        }
+        cache.order.push_back(request.clone());
+        cache.entries.insert(request, body);
+    }
+}
+
+/// Clear all cached Horizons responses
+pub fn clear_horizons_cache() {
+    if let Ok(mut cache) = HORIZONS_CACHE.lock() {
+        cache.entries.clear();
+        cache.order.clear();
+    }
+}
+
+/// Issue a Horizons VECTORS request for `command` covering `times`
+///
+/// `command` is passed verbatim as the Horizons COMMAND parameter, so it may be a
+/// NAIF ID, a small-body record number (`"90000030;"`) or a name/designation search
+/// string. Query parameters are percent-encoded, which allows designations with
+/// spaces and slashes such as `"C/2023 A3"`. Returns the raw response text.
+///
+/// Responses containing ephemeris data are cached in memory keyed on
+/// (command, time range, step), so repeated lookups over the same grid do not
+/// hit the network again.
+fn fetch_horizons_vectors(command: &str, times: &[DateTime<Utc>]) -> Result<Arc<String>, String> {
+    if times.is_empty() {
+        return Err("No times provided for Horizons query".to_string());
+    }
+
+    let request = HorizonsRequest::for_times(command, times);
+    if let Some(body) = horizons_cache_get(&request) {
+        return Ok(body);
+    }
+
+    // Build Horizons API request with ICRF/equatorial output (VECTORS format)
     // CENTER='@399' = geocentric
     // REF_PLANE='FRAME' = use ICRF reference frame (equatorial, not ecliptic)
     // VEC_TABLE='2' = position and velocity
-    let url = format!(
-        "https://ssd.jpl.nasa.gov/api/horizons.api?format=text&COMMAND='{}'&MAKE_EPHEM='YES'&EPHEM_TYPE='VECTORS'&VEC_TABLE='2'&CENTER='@399'&REF_PLANE='FRAME'&START_TIME='{}'&STOP_TIME='{}'&STEP_SIZE='{}m'&OUT_UNITS='KM-S'&CSV_FORMAT='YES'",
-        body_id, start_str, end_str, step_minutes
-    );
-
-    // Query the API
-    let response = ureq::get(&url)
+    let response = ureq::get(HORIZONS_API_URL.as_str())
+        .query("format", "text")
+        .query("COMMAND", format!("'{}'", request.command))
+        .query("MAKE_EPHEM", "'YES'")
+        .query("EPHEM_TYPE", "'VECTORS'")
+        .query("VEC_TABLE", "'2'")
+        .query("CENTER", "'@399'")
+        .query("REF_PLANE", "'FRAME'")
+        .query("START_TIME", format!("'{}'", request.start))
+        .query("STOP_TIME", format!("'{}'", request.stop))
+        .query("STEP_SIZE", format!("'{}m'", request.step_minutes))
+        .query("OUT_UNITS", "'KM-S'")
+        .query("CSV_FORMAT", "'YES'")
         .call()
         .map_err(|e| format!("Horizons API request failed: {}", e))?;
 
     // Read response body as string
-    let body = response
-        .into_body()
-        .read_to_string()
-        .map_err(|e| format!("Failed to read Horizons response: {}", e))?;
+    let body = Arc::new(
+        response
+            .into_body()
+            .read_to_string()
+            .map_err(|e| format!("Failed to read Horizons response: {}", e))?,
+    );
 
-    // Debug: check if response contains error
-    if body.contains("ERROR") || body.contains("error") {
-        eprintln!("Horizons error response for body {}:", body_id);
-        eprintln!("{}", body);
-        return Err("Horizons returned error".to_string());
+    if body.contains("$$SOE") {
+        horizons_cache_insert(request, body.clone());
     }
-
-    // Parse the response
-    parse_horizons_csv_response(&body, times)
+    Ok(body)
 }
 
 /// Parse Horizons CSV response and interpolate to requested times
@@ -94,6 +211,19 @@ fn parse_horizons_csv_response(
     response: &str,
     times: &[DateTime<Utc>],
 ) -> Result<Array2<f64>, String> {
+    let (horizons_times, horizons_data) = parse_horizons_vector_table(response)?;
+
+    // Interpolate to requested times
+    interpolate_horizons_data(&horizons_times, &horizons_data, times)
+}
+
+/// Parse the `$$SOE`/`$$EOE` block of a Horizons VECTORS CSV response
+///
+/// Returns the tabulated UTC times and [x, y, z, vx, vy, vz] rows in km and km/s.
+#[allow(clippy::type_complexity)]
+fn parse_horizons_vector_table(
+    response: &str,
+) -> Result<(Vec<DateTime<Utc>>, Vec<Vec<f64>>), String> {
     let mut data_lines = Vec::new();
 
     // Skip header lines until we find the data section
@@ -150,8 +280,7 @@ fn parse_horizons_csv_response(
         return Err("Could not parse any ephemeris data from Horizons".to_string());
     }
 
-    // Interpolate to requested times
-    interpolate_horizons_data(&horizons_times, &horizons_data, times)
+    Ok((horizons_times, horizons_data))
 }
 
 /// Parse Horizons JDTDB to DateTime<Utc>
@@ -161,27 +290,13 @@ fn parse_horizons_datetime(s: &str) -> Result<DateTime<Utc>, String> {
         .parse()
         .map_err(|_| format!("Invalid Julian Date: {}", s))?;
 
-    // Horizons times are in TDB; convert to UTC via hifitime.
+    // Horizons times are in TDB; convert to UTC via hifitime, keeping any
+    // leap second distinct from the following second
     let epoch = Epoch::from_jde_tdb(jd_tdb);
-    let unix_seconds = epoch.to_unix_seconds();
-    if !unix_seconds.is_finite() {
+    if !epoch.to_unix_seconds().is_finite() {
         return Err(format!("Invalid Unix seconds from JD {}", jd_tdb));
     }
-
-    let mut secs = unix_seconds.floor() as i64;
-    let mut nsecs = ((unix_seconds - secs as f64) * 1e9).round() as i64;
-    if nsecs == 1_000_000_000 {
-        secs += 1;
-        nsecs = 0;
-    }
-    if nsecs < 0 {
-        secs -= 1;
-        nsecs += 1_000_000_000;
-    }
-
-    Utc.timestamp_opt(secs, nsecs as u32)
-        .single()
-        .ok_or_else(|| format!("Invalid timestamp from JD {}", jd_tdb))
+    Ok(epoch_to_chrono(&epoch))
 }
 
 /// Interpolate Horizons data to requested times using linear interpolation
@@ -251,35 +366,317 @@ fn interpolate_horizons_data(
 
 /// Query JPL Horizons for body ephemeris data by name (for comets and other named objects)
 ///
-/// This function allows querying comets and other non-standard objects by name,
-/// such as "Halley", "67P", "NEOWISE", etc.
+/// This function allows querying comets, asteroids and spacecraft by name or
+/// designation, such as "Halley", "67P", "Ceres", "C/2023 A3" or "JWST".
 ///
 /// # Arguments
-/// * `_times` - Vector of timestamps for which to calculate positions
-/// * `body_name` - Name of the comet or object (e.g., "Halley", "C/2020 F3", "67P")
+/// * `times` - Vector of timestamps for which to calculate positions
+/// * `body_name` - Name or designation of the object (e.g., "Halley", "C/2020 F3", "67P")
 ///
 /// # Returns
-/// `Ok(Array2<f64>)` with shape (N, 6) containing heliocentric [x, y, z, vx, vy, vz]
+/// `Ok(Array2<f64>)` with shape (N, 6) containing GCRS [x, y, z, vx, vy, vz]
 /// or `Err(String)` if the query fails or the body name is not recognized by Horizons
 ///
 /// # Note
-/// This uses Horizons' name-based object search. For well-known comets, use common
-/// names like "Halley" or "Hale-Bopp". For SOHO discoveries, use the full designation
-/// like "C/2020 F3 (NEOWISE)".
-///
-/// Currently, this function requires a numeric NAIF ID to query. For pure name-based
-/// queries, please use the JPL Horizons web interface to find the NAIF ID first, then
-/// use the ID with query_horizons_body().
+/// Comet designations (e.g. "C/2023 A3", "67P", "1P/Halley") are searched with
+/// `DES=...;CAP;NOFRAG`, which selects the apparition closest to the present and
+/// excludes fragments. Other names are searched as given. When Horizons reports
+/// several matches, a record is selected automatically if the name matches one
+/// entry exactly, or if all entries are apparitions of the same object (the one
+/// whose epoch is closest to the requested times is used). Otherwise an error
+/// listing the candidate records is returned; pass the record number (followed
+/// by `;` for small bodies) to select one explicitly.
 pub fn query_horizons_body_by_name(
-    _times: &[DateTime<Utc>],
+    times: &[DateTime<Utc>],
     body_name: &str,
 ) -> Result<Array2<f64>, String> {
-    Err(format!(
-        "Comet/object name-based query '{}' is not yet fully supported. \
-         Please look up the NAIF ID at https://ssd.jpl.nasa.gov/horizons/ \
-         and use query_horizons_body() with the numeric ID instead.",
-        body_name
-    ))
+    if times.is_empty() {
+        return Err("No times provided for Horizons query".to_string());
+    }
+
+    let name = body_name.trim();
+    if name.is_empty() {
+        return Err("Empty body name provided for Horizons query".to_string());
+    }
+
+    let body = fetch_horizons_vectors_by_name(times, name)?;
+    parse_horizons_csv_response(&body, times)
+}
+
+/// Fetch the Horizons VECTORS response for a name or designation
+///
+/// Resolves multiple-match listings with [`select_horizons_match`] and returns a
+/// response that is guaranteed to contain an ephemeris table.
+fn fetch_horizons_vectors_by_name(
+    times: &[DateTime<Utc>],
+    name: &str,
+) -> Result<Arc<String>, String> {
+    let command = horizons_name_command(name);
+    let body = fetch_horizons_vectors(&command, times)?;
+    if body.contains("$$SOE") {
+        return Ok(body);
+    }
+
+    // No ephemeris: Horizons either listed several matching records or found nothing
+    let matches = parse_horizons_match_list(&body);
+    if matches.is_empty() {
+        return Err(format!(
+            "Horizons could not resolve object name '{}': {}",
+            name,
+            horizons_error_summary(&body)
+        ));
+    }
+
+    let mid_year = times[times.len() / 2].year();
+    let selected = select_horizons_match(name, &matches, mid_year).ok_or_else(|| {
+        let candidates: Vec<String> = matches
+            .iter()
+            .take(10)
+            .map(|m| format!("{} ({})", m.record, m.description))
+            .collect();
+        format!(
+            "Object name '{}' is ambiguous in Horizons ({} matches). \
+             Use a more specific designation or one of these records: {}",
+            name,
+            matches.len(),
+            candidates.join(", ")
+        )
+    })?;
+
+    let body = fetch_horizons_vectors(&selected.command(), times)?;
+    if !body.contains("$$SOE") {
+        return Err(format!(
+            "Horizons returned no ephemeris for '{}' (record {}): {}",
+            name,
+            selected.record,
+            horizons_error_summary(&body)
+        ));
+    }
+    Ok(body)
+}
+
+/// Query the raw Horizons state table for a target over the span of `times`
+///
+/// Unlike [`query_horizons_body`], the tabulated Horizons rows are returned without
+/// resampling so that callers can apply their own interpolation. The step is matched
+/// to the spacing of `times` and the window padded by one step on each side, so the
+/// table brackets every requested time.
+///
+/// # Arguments
+/// * `times` - Timestamps the table must cover
+/// * `target` - NAIF ID (e.g. `"-170"` for JWST), name or designation
+///
+/// # Returns
+/// `Ok((times, states))` with states of shape (M, 6) containing geocentric ICRF
+/// [x, y, z, vx, vy, vz] in km and km/s, or `Err(String)` if the query fails
+pub fn query_horizons_state_table(
+    times: &[DateTime<Utc>],
+    target: &str,
+) -> Result<(Vec<DateTime<Utc>>, Array2<f64>), String> {
+    if times.is_empty() {
+        return Err("No times provided for Horizons query".to_string());
+    }
+
+    let target = target.trim();
+    if target.is_empty() {
+        return Err("Empty target provided for Horizons query".to_string());
+    }
+
+    let body = if let Ok(id) = target.parse::<i32>() {
+        let body = fetch_horizons_vectors(&id.to_string(), times)?;
+        if !body.contains("$$SOE") {
+            return Err(format!(
+                "Horizons returned no ephemeris for target {}: {}",
+                id,
+                horizons_error_summary(&body)
+            ));
+        }
+        body
+    } else {
+        fetch_horizons_vectors_by_name(times, target)?
+    };
+
+    let (table_times, rows) = parse_horizons_vector_table(&body)?;
+    let states = Array2::from_shape_vec((rows.len(), 6), rows.concat())
+        .map_err(|e| format!("Malformed Horizons state table: {}", e))?;
+
+    Ok((table_times, states))
+}
+
+/// One row of a Horizons "multiple matches" listing
+#[derive(Debug, Clone, PartialEq)]
+struct HorizonsMatch {
+    /// Record number (small bodies) or ID# (major bodies)
+    record: String,
+    /// Epoch year of the orbit solution, only present for small-body records
+    epoch_year: Option<i32>,
+    /// Remaining columns of the row (designations and names), spacing preserved
+    description: String,
+    /// True for small-body index results, which need a trailing ';' on selection
+    small_body: bool,
+}
+
+impl HorizonsMatch {
+    /// COMMAND string that selects exactly this record
+    fn command(&self) -> String {
+        if self.small_body {
+            format!("{};", self.record)
+        } else {
+            self.record.clone()
+        }
+    }
+}
+
+/// Build the Horizons COMMAND string for a name or designation search
+///
+/// Numbered periodic comets are searched by number alone, so "1P/Halley"
+/// becomes `DES=1P;`; Horizons does not match the name suffix.
+fn horizons_name_command(name: &str) -> String {
+    if !is_comet_designation(name) {
+        return name.to_string();
+    }
+    let designation = match name.split_once('/') {
+        Some((number, _)) if number.starts_with(|c: char| c.is_ascii_digit()) => number,
+        _ => name,
+    };
+    format!("DES={};CAP;NOFRAG;", designation)
+}
+
+/// Check whether `name` looks like a comet designation ("C/2023 A3", "67P", "1P/Halley")
+fn is_comet_designation(name: &str) -> bool {
+    let upper = name.to_ascii_uppercase();
+    let bytes = upper.as_bytes();
+
+    // Provisional designations: C/, P/, D/, X/, I/, A/ followed by a year
+    if bytes.len() > 2
+        && matches!(bytes[0], b'C' | b'P' | b'D' | b'X' | b'I' | b'A')
+        && bytes[1] == b'/'
+        && bytes[2].is_ascii_digit()
+    {
+        return true;
+    }
+
+    // Numbered periodic comets: digits followed by P, D or I ("67P", "1P/Halley")
+    let digits = bytes.iter().take_while(|b| b.is_ascii_digit()).count();
+    digits > 0
+        && matches!(bytes.get(digits), Some(b'P' | b'D' | b'I'))
+        && matches!(bytes.get(digits + 1), None | Some(b'/'))
+}
+
+/// Parse the table of matching records Horizons returns for ambiguous searches
+///
+/// Handles both the major-body listing (`ID#  Name ...`) and the small-body index
+/// listing (`Record #  Epoch-yr  >MATCH DESIG<  Primary Desig  Name`).
+fn parse_horizons_match_list(response: &str) -> Vec<HorizonsMatch> {
+    let mut matches = Vec::new();
+    let mut lines = response.lines();
+
+    while let Some(line) = lines.next() {
+        let small_body = line.contains("Record #");
+        if !small_body && !line.contains("ID#") {
+            continue;
+        }
+        // Header is followed by a dashed separator line
+        match lines.next() {
+            Some(sep) if sep.trim_start().starts_with("--") => {}
+            _ => continue,
+        }
+        for row in lines.by_ref() {
+            let row = row.trim();
+            if row.is_empty() {
+                break;
+            }
+            let mut parts = row.split_whitespace();
+            let record = match parts.next() {
+                Some(r)
+                    if r.trim_start_matches('-')
+                        .chars()
+                        .all(|c| c.is_ascii_digit()) =>
+                {
+                    r
+                }
+                _ => break,
+            };
+            let rest = row[record.len()..].trim_start();
+            let (epoch_year, description) = if small_body {
+                let mut rest_parts = rest.splitn(2, char::is_whitespace);
+                let first = rest_parts.next().unwrap_or("");
+                match first.parse::<i32>() {
+                    Ok(year) => (Some(year), rest_parts.next().unwrap_or("").trim()),
+                    Err(_) => (None, rest),
+                }
+            } else {
+                (None, rest)
+            };
+            matches.push(HorizonsMatch {
+                record: record.to_string(),
+                epoch_year,
+                description: description.trim().to_string(),
+                small_body,
+            });
+        }
+        break;
+    }
+
+    matches
+}
+
+/// Pick a single record from an ambiguous Horizons match list
+///
+/// Returns the only match, the match with a column exactly equal to `name`, or, when every match is an apparition of the same object,
+/// the apparition whose epoch is closest to `target_year`.
+fn select_horizons_match<'a>(
+    name: &str,
+    matches: &'a [HorizonsMatch],
+    target_year: i32,
+) -> Option<&'a HorizonsMatch> {
+    if matches.len() == 1 {
+        return matches.first();
+    }
+
+    // Columns in the listing are separated by runs of two or more spaces
+    let wanted = name.to_ascii_lowercase();
+    let exact: Vec<&HorizonsMatch> = matches
+        .iter()
+        .filter(|m| {
+            m.description
+                .split("  ")
+                .any(|col| col.trim().to_ascii_lowercase() == wanted)
+        })
+        .collect();
+    if exact.len() == 1 {
+        return exact.first().copied();
+    }
+
+    // Apparitions of one comet share the same description (designation and name)
+    let first = &matches[0];
+    let same_object = matches
+        .iter()
+        .all(|m| m.small_body && m.description == first.description);
+    if same_object {
+        return matches
+            .iter()
+            .filter(|m| m.epoch_year.is_some())
+            .min_by_key(|m| (m.epoch_year.unwrap_or(target_year) - target_year).abs());
+    }
+
+    None
+}
+
+/// Extract a short human-readable reason from a Horizons response without ephemeris
+fn horizons_error_summary(response: &str) -> String {
+    response
+        .lines()
+        .map(str::trim)
+        .find(|line| {
+            let lower = line.to_ascii_lowercase();
+            lower.contains("no matches")
+                || lower.contains("error")
+                || lower.contains("cannot")
+                || lower.contains("not found")
+        })
+        .unwrap_or("no ephemeris data in response")
+        .to_string()
 }
 
 #[cfg(test)]
@@ -318,6 +715,17 @@ mod tests {
         assert!(diff_ns < 1_000_000);
     }
 
+    #[test]
+    fn test_parse_horizons_datetime_leap_second() {
+        use crate::utils::time_utils::utc_from_fields;
+        let leap = utc_from_fields(2016, 12, 31, 23, 59, 60, 500_000_000).unwrap();
+        let jd_tdb = chrono_to_epoch(&leap).to_jde_tdb_days();
+        let parsed = parse_horizons_datetime(&format!("{:.12}", jd_tdb))
+            .expect("parse_horizons_datetime failed");
+        assert_eq!(parsed.timestamp(), leap.timestamp());
+        assert!(parsed.timestamp_subsec_nanos() >= 1_000_000_000);
+    }
+
     #[test]
     fn test_parse_horizons_datetime_offsets_from_naive_jd() {
         let dt = Utc.with_ymd_and_hms(2024, 6, 1, 12, 34, 56).unwrap();
@@ -371,4 +779,202 @@ mod tests {
         assert!((result[[0, 4]] - 4.0).abs() < 1e-9);
         assert!((result[[0, 5]] - 5.5).abs() < 1e-9);
     }
+
+    #[test]
+    fn test_parse_horizons_vector_table() {
+        let response = "header\n$$SOE\n\
+            2460310.500000000, A.D. 2024-Jan-01 00:00:00.0000, 1.0, 2.0, 3.0, 0.1, 0.2, 0.3,\n\
+            2460310.541666667, A.D. 2024-Jan-01 01:00:00.0000, 4.0, 5.0, 6.0, 0.4, 0.5, 0.6,\n\
+            $$EOE\nfooter\n";
+        let (times, rows) = parse_horizons_vector_table(response).unwrap();
+        assert_eq!(times.len(), 2);
+        assert_eq!(rows[1], vec![4.0, 5.0, 6.0, 0.4, 0.5, 0.6]);
+        let spacing = (times[1] - times[0]).num_seconds();
+        assert!((spacing - 3600).abs() <= 1);
+
+        assert!(parse_horizons_vector_table("no data here").is_err());
+    }
+
+    #[test]
+    #[ignore] // Requires network access
+    fn test_query_horizons_state_table_spacecraft() {
+        let t0 = Utc.with_ymd_and_hms(2024, 1, 1, 0, 0, 0).unwrap();
+        let times: Vec<DateTime<Utc>> = (0..6).map(|i| t0 + Duration::minutes(10 * i)).collect();
+        let (table_times, states) = query_horizons_state_table(&times, "-170").unwrap();
+        assert_eq!(states.ncols(), 6);
+        assert!(table_times[0] <= times[0]);
+        assert!(*table_times.last().unwrap() >= times[5]);
+        // JWST orbits L2, roughly 1.5 million km from Earth
+        let r = (states[[0, 0]].powi(2) + states[[0, 1]].powi(2) + states[[0, 2]].powi(2)).sqrt();
+        assert!(r > 1.0e6 && r < 2.0e6);
+    }
+
+    #[test]
+    fn test_is_comet_designation() {
+        assert!(is_comet_designation("C/2023 A3"));
+        assert!(is_comet_designation("c/2020 F3"));
+        assert!(is_comet_designation("67P"));
+        assert!(is_comet_designation("1P/Halley"));
+        assert!(is_comet_designation("2I"));
+        assert!(!is_comet_designation("Halley"));
+        assert!(!is_comet_designation("433"));
+        assert!(!is_comet_designation("Ceres"));
+        assert!(!is_comet_designation("2024 PT5"));
+    }
+
+    #[test]
+    fn test_horizons_name_command() {
+        assert_eq!(
+            horizons_name_command("C/2023 A3"),
+            "DES=C/2023 A3;CAP;NOFRAG;"
+        );
+        assert_eq!(horizons_name_command("1P/Halley"), "DES=1P;CAP;NOFRAG;");
+        assert_eq!(horizons_name_command("67P"), "DES=67P;CAP;NOFRAG;");
+        assert_eq!(horizons_name_command("Ceres"), "Ceres");
+    }
+
+    #[test]
+    fn test_parse_small_body_match_list_and_select_apparition() {
+        let response = "\
+ Matching small-bodies:
+
+    Record #  Epoch-yr  >MATCH NAME<   Primary Desig  Name
+    --------  --------  -------------  -------------  -------------------------
+    90000030    1835    1P             1P             Halley
+    90000031    1910    1P             1P             Halley
+    90000033    1986    1P             1P             Halley
+
+ (3 matches. To SELECT, enter record # (integer), followed by semi-colon.)
+";
+        let matches = parse_horizons_match_list(response);
+        assert_eq!(matches.len(), 3);
+        assert_eq!(matches[0].record, "90000030");
+        assert_eq!(matches[0].epoch_year, Some(1835));
+        assert!(matches[0].small_body);
+
+        let selected = select_horizons_match("halley", &matches, 1990).unwrap();
+        assert_eq!(selected.record, "90000033");
+        assert_eq!(selected.command(), "90000033;");
+    }
+
+    #[test]
+    fn test_parse_major_body_match_list_exact_name() {
+        let response = "\
+ Multiple major-bodies match string \"JUPITER*\"
+
+  ID#      Name                               Designation  IAU/aliases/other
+  -------  ---------------------------------- -----------  -------------------
+        5  Jupiter Barycenter
+      599  Jupiter
+
+   Number of matches =   2. Use ID# to make unique selection.
+";
+        let matches = parse_horizons_match_list(response);
+        assert_eq!(matches.len(), 2);
+        assert!(!matches[1].small_body);
+
+        let selected = select_horizons_match("Jupiter", &matches, 2024).unwrap();
+        assert_eq!(selected.command(), "599");
+    }
+
+    #[test]
+    fn test_select_horizons_match_ambiguous() {
+        let response = "\
+  ID#      Name                               Designation  IAU/aliases/other
+  -------  ---------------------------------- -----------  -------------------
+      -48  Hubble Space Telescope                          HST
+     -170  James Webb Space Telescope (spacec              JWST
+";
+        let matches = parse_horizons_match_list(response);
+        assert_eq!(matches.len(), 2);
+        assert_eq!(matches[1].record, "-170");
+        assert!(select_horizons_match("Telescope", &matches, 2024).is_none());
+        assert_eq!(
+            select_horizons_match("JWST", &matches, 2024)
+                .unwrap()
+                .record,
+            "-170"
+        );
+    }
+
+    #[test]
+    fn test_parse_horizons_match_list_no_matches() {
+        let response = "No matches found.\n";
+        assert!(parse_horizons_match_list(response).is_empty());
+        assert_eq!(horizons_error_summary(response), "No matches found.");
+    }
+
+    #[test]
+    #[ignore] // Ignore by default since it requires network access
+    fn test_query_horizons_comet_by_designation() {
+        let times = vec![
+            Utc.with_ymd_and_hms(2024, 10, 1, 0, 0, 0).unwrap(),
+            Utc.with_ymd_and_hms(2024, 10, 2, 0, 0, 0).unwrap(),
+        ];
+
+        let data = query_horizons_body_by_name(&times, "C/2023 A3").unwrap();
+        assert_eq!(data.shape(), &[2, 6]);
+    }
+
+    #[test]
+    fn test_horizons_step_matches_grid() {
+        let t0 = Utc.with_ymd_and_hms(2024, 1, 1, 0, 0, 0).unwrap();
+
+        // 10-minute grid -> 10-minute Horizons step
+        let times: Vec<_> = (0..50).map(|i| t0 + Duration::minutes(10 * i)).collect();
+        assert_eq!(horizons_step_minutes(&times), 10);
+
+        // Sub-minute grid -> 1-minute floor
+        let times: Vec<_> = (0..50).map(|i| t0 + Duration::seconds(10 * i)).collect();
+        assert_eq!(horizons_step_minutes(&times), 1);
+
+        // Daily grid -> capped for interpolation accuracy
+        let times: Vec<_> = (0..30).map(|i| t0 + Duration::days(i)).collect();
+        assert_eq!(horizons_step_minutes(&times), HORIZONS_MAX_STEP_MINUTES);
+
+        // Long span with fine grid -> row cap forces a coarser step
+        let times: Vec<_> = (0..=365 * 24)
+            .map(|i| t0 + Duration::minutes(60 * i))
+            .collect();
+        let span_minutes = 365 * 24 * 60;
+        let step = horizons_step_minutes(&times);
+        assert!(span_minutes / step <= HORIZONS_MAX_ROWS);
+
+        // Single time -> 1-minute step
+        assert_eq!(horizons_step_minutes(&[t0]), 1);
+    }
+
+    #[test]
+    fn test_horizons_request_window_brackets_times() {
+        let t0 = Utc.with_ymd_and_hms(2024, 1, 1, 12, 0, 30).unwrap();
+        let times: Vec<_> = (0..4).map(|i| t0 + Duration::minutes(5 * i)).collect();
+        let request = HorizonsRequest::for_times("499", &times);
+        assert_eq!(request.step_minutes, 5);
+        assert_eq!(request.start, "2024-01-01 11:53");
+        assert_eq!(request.stop, "2024-01-01 12:22");
+        assert_eq!(request, HorizonsRequest::for_times("499", &times));
+        assert_ne!(request, HorizonsRequest::for_times("599", &times));
+    }
+
+    #[test]
+    fn test_horizons_cache_eviction() {
+        clear_horizons_cache();
+        let t0 = Utc.with_ymd_and_hms(2024, 1, 1, 0, 0, 0).unwrap();
+        let times = vec![t0, t0 + Duration::minutes(1)];
+        let first = HorizonsRequest::for_times("cache-test-0", &times);
+        for i in 0..=HORIZONS_CACHE_MAX_ENTRIES {
+            let request = HorizonsRequest::for_times(&format!("cache-test-{i}"), &times);
+            horizons_cache_insert(request, Arc::new(format!("body {i}")));
+        }
+        assert!(horizons_cache_get(&first).is_none());
+        let last = HorizonsRequest::for_times(
+            &format!("cache-test-{}", HORIZONS_CACHE_MAX_ENTRIES),
+            &times,
+        );
+        assert_eq!(
+            horizons_cache_get(&last).unwrap().as_str(),
+            format!("body {}", HORIZONS_CACHE_MAX_ENTRIES)
+        );
+        clear_horizons_cache();
+    }
 }
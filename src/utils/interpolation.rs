@@ -1,6 +1,8 @@
 //! Interpolation utilities for ephemeris data
 //!
-//! Provides Hermite interpolation for state vectors (position and velocity)
+//! Provides Hermite interpolation for state vectors (position and velocity),
+//! plus a configurable scheme (Hermite or Lagrange over a sliding window of
+//! samples) with gap checking and an interpolation error estimate.
 
 use crate::utils::time_utils::chrono_to_epoch;
 use chrono::{DateTime, Utc};
@@ -85,6 +87,260 @@ pub fn hermite_interpolate(
     result
 }
 
+/// Interpolation scheme for state vectors
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum InterpolationMethod {
+    /// Matches positions and velocities at each sample; velocity is the
+    /// derivative of the position polynomial
+    Hermite,
+    /// Interpolates positions and velocities separately through the samples
+    Lagrange,
+}
+
+impl InterpolationMethod {
+    pub fn name(self) -> &'static str {
+        match self {
+            InterpolationMethod::Hermite => "hermite",
+            InterpolationMethod::Lagrange => "lagrange",
+        }
+    }
+}
+
+/// Interpolation scheme, window and gap tolerance for resampling state vectors
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Interpolation {
+    pub method: InterpolationMethod,
+    /// Number of samples each interpolant passes through
+    pub window: usize,
+    /// Largest sample spacing in seconds that may be interpolated across
+    pub max_gap: Option<f64>,
+}
+
+impl Default for Interpolation {
+    /// Two-sample cubic Hermite, with no gap limit
+    fn default() -> Self {
+        Interpolation {
+            method: InterpolationMethod::Hermite,
+            window: 2,
+            max_gap: None,
+        }
+    }
+}
+
+impl Interpolation {
+    /// Build from user options
+    ///
+    /// The window defaults to 2 samples for Hermite (cubic) and 8 for
+    /// Lagrange (degree 7).
+    pub fn new(method: &str, window: Option<usize>, max_gap: Option<f64>) -> Result<Self, String> {
+        let method = match method.trim().to_lowercase().as_str() {
+            "hermite" => InterpolationMethod::Hermite,
+            "lagrange" => InterpolationMethod::Lagrange,
+            other => {
+                return Err(format!(
+                    "Unknown interpolation '{}'. Expected 'hermite' or 'lagrange'",
+                    other
+                ))
+            }
+        };
+        let window = window.unwrap_or(match method {
+            InterpolationMethod::Hermite => 2,
+            InterpolationMethod::Lagrange => 8,
+        });
+        if window < 2 {
+            return Err("interpolation_window must be at least 2".to_string());
+        }
+        if let Some(gap) = max_gap {
+            if !(gap.is_finite() && gap > 0.0) {
+                return Err("max_gap must be a positive number of seconds".to_string());
+            }
+        }
+        Ok(Interpolation {
+            method,
+            window,
+            max_gap,
+        })
+    }
+
+    /// Interpolate state vectors at the query times
+    pub fn interpolate(
+        &self,
+        query_times: &[DateTime<Utc>],
+        data_times: &[DateTime<Utc>],
+        data_states: &Array2<f64>,
+    ) -> Array2<f64> {
+        if *self == Interpolation::default() {
+            return hermite_interpolate(query_times, data_times, data_states);
+        }
+        let t0 = &data_times[0];
+        let data_t_secs: Vec<f64> = data_times.iter().map(|t| diff_seconds(t, t0)).collect();
+        let query_t_secs: Vec<f64> = query_times.iter().map(|t| diff_seconds(t, t0)).collect();
+        self.interpolate_secs(&query_t_secs, &data_t_secs, data_states, self.window)
+    }
+
+    /// Check that no query time falls inside a data gap wider than `max_gap`
+    pub fn check_gaps(
+        &self,
+        query_times: &[DateTime<Utc>],
+        data_times: &[DateTime<Utc>],
+    ) -> Result<(), String> {
+        let Some(max_gap) = self.max_gap else {
+            return Ok(());
+        };
+        let t0 = &data_times[0];
+        let data_t_secs: Vec<f64> = data_times.iter().map(|t| diff_seconds(t, t0)).collect();
+        for query_time in query_times {
+            let q = diff_seconds(query_time, t0);
+            let i = find_interval(&data_t_secs, q);
+            let gap = data_t_secs[i + 1] - data_t_secs[i];
+            if gap > max_gap && q > data_t_secs[i] && q < data_t_secs[i + 1] {
+                return Err(format!(
+                    "Interpolating at {} across a {:.1} s gap in the data ({} to {}), which exceeds max_gap={} s",
+                    query_time,
+                    gap,
+                    data_times[i],
+                    data_times[i + 1],
+                    max_gap
+                ));
+            }
+        }
+        Ok(())
+    }
+
+    /// Estimated largest position interpolation error in km over a time span
+    ///
+    /// Compares the interpolant with one through an extra sample at the
+    /// midpoint of every data interval overlapping `[begin, end]`. Returns
+    /// None when there are too few samples for the comparison.
+    pub fn max_error_estimate(
+        &self,
+        begin: &DateTime<Utc>,
+        end: &DateTime<Utc>,
+        data_times: &[DateTime<Utc>],
+        data_states: &Array2<f64>,
+    ) -> Option<f64> {
+        if data_times.len() < self.window + 1 {
+            return None;
+        }
+        let t0 = &data_times[0];
+        let data_t_secs: Vec<f64> = data_times.iter().map(|t| diff_seconds(t, t0)).collect();
+        let (first, last) = (diff_seconds(begin, t0), diff_seconds(end, t0));
+        let midpoints: Vec<f64> = data_t_secs
+            .windows(2)
+            .filter(|w| w[1] > first && w[0] < last)
+            .map(|w| 0.5 * (w[0] + w[1]))
+            .collect();
+        if midpoints.is_empty() {
+            return Some(0.0);
+        }
+        let estimate = self.interpolate_secs(&midpoints, &data_t_secs, data_states, self.window);
+        let reference =
+            self.interpolate_secs(&midpoints, &data_t_secs, data_states, self.window + 1);
+        Some(
+            (0..midpoints.len())
+                .map(|k| {
+                    (0..3)
+                        .map(|c| (estimate[[k, c]] - reference[[k, c]]).powi(2))
+                        .sum::<f64>()
+                        .sqrt()
+                })
+                .fold(0.0, f64::max),
+        )
+    }
+
+    /// Interpolate with times given in seconds from the first sample
+    fn interpolate_secs(
+        &self,
+        query_t_secs: &[f64],
+        data_t_secs: &[f64],
+        data_states: &Array2<f64>,
+        window: usize,
+    ) -> Array2<f64> {
+        let n = data_t_secs.len();
+        let window = window.min(n);
+        let mut result = Array2::<f64>::zeros((query_t_secs.len(), 6));
+
+        for (out_idx, &q) in query_t_secs.iter().enumerate() {
+            // Center the window on the interval containing the query time
+            let idx = find_interval(data_t_secs, q);
+            let start = (idx + 1).saturating_sub(window / 2).min(n - window);
+            let ts = &data_t_secs[start..start + window];
+            // Work in time relative to the window center for conditioning
+            let center = 0.5 * (ts[0] + ts[window - 1]);
+            let ts: Vec<f64> = ts.iter().map(|t| t - center).collect();
+            let q = q - center;
+
+            match self.method {
+                InterpolationMethod::Hermite => {
+                    for c in 0..3 {
+                        let positions: Vec<f64> = (start..start + window)
+                            .map(|k| data_states[[k, c]])
+                            .collect();
+                        let velocities: Vec<f64> = (start..start + window)
+                            .map(|k| data_states[[k, c + 3]])
+                            .collect();
+                        let (p, v) = hermite_window(&ts, &positions, &velocities, q);
+                        result[[out_idx, c]] = p;
+                        result[[out_idx, c + 3]] = v;
+                    }
+                }
+                InterpolationMethod::Lagrange => {
+                    let weights = lagrange_weights(&ts, q);
+                    for c in 0..6 {
+                        result[[out_idx, c]] = weights
+                            .iter()
+                            .enumerate()
+                            .map(|(k, w)| w * data_states[[start + k, c]])
+                            .sum();
+                    }
+                }
+            }
+        }
+
+        result
+    }
+}
+
+/// Hermite interpolation through values and derivatives at `ts`
+///
+/// Builds the Newton form over doubled nodes with divided differences and
+/// returns the value and derivative at `q`.
+fn hermite_window(ts: &[f64], values: &[f64], derivatives: &[f64], q: f64) -> (f64, f64) {
+    let m = 2 * ts.len();
+    let z: Vec<f64> = (0..m).map(|i| ts[i / 2]).collect();
+    let mut coef: Vec<f64> = (0..m).map(|i| values[i / 2]).collect();
+    for j in 1..m {
+        for i in (j..m).rev() {
+            coef[i] = if j == 1 && i % 2 == 1 {
+                derivatives[i / 2]
+            } else {
+                (coef[i] - coef[i - 1]) / (z[i] - z[i - j])
+            };
+        }
+    }
+
+    let mut value = coef[m - 1];
+    let mut derivative = 0.0;
+    for i in (0..m - 1).rev() {
+        derivative = derivative * (q - z[i]) + value;
+        value = value * (q - z[i]) + coef[i];
+    }
+    (value, derivative)
+}
+
+/// Lagrange basis weights for the nodes `ts` at `q`
+fn lagrange_weights(ts: &[f64], q: f64) -> Vec<f64> {
+    (0..ts.len())
+        .map(|k| {
+            ts.iter()
+                .enumerate()
+                .filter(|(j, _)| *j != k)
+                .map(|(_, tj)| (q - tj) / (ts[k] - tj))
+                .product()
+        })
+        .collect()
+}
+
 /// Find the interval index for a query time
 ///
 /// Returns the index i such that data_times[i] <= query_time < data_times[i+1]
@@ -150,6 +406,56 @@ mod tests {
         assert!((result[[0, 3]] - 1.0).abs() < 0.1);
     }
 
+    #[test]
+    fn test_windowed_schemes_reproduce_polynomials() {
+        // x = t^3 is exact for cubic Hermite and for Lagrange through 4 points
+        let t0 = Utc::now();
+        let data_times: Vec<_> = (0..8).map(|k| t0 + Duration::seconds(10 * k)).collect();
+        let mut data_states = Array2::<f64>::zeros((8, 6));
+        for k in 0..8 {
+            let t = 10.0 * k as f64;
+            data_states[[k, 0]] = t.powi(3);
+            data_states[[k, 3]] = 3.0 * t.powi(2);
+        }
+        let query = vec![t0 + Duration::seconds(25)];
+
+        for (method, window) in [
+            ("hermite", 2),
+            ("hermite", 4),
+            ("lagrange", 4),
+            ("lagrange", 6),
+        ] {
+            let interp = Interpolation::new(method, Some(window), None).unwrap();
+            let result = interp.interpolate(&query, &data_times, &data_states);
+            assert!((result[[0, 0]] - 15625.0).abs() < 1e-6, "{method} {window}");
+            assert!((result[[0, 3]] - 1875.0).abs() < 1e-6, "{method} {window}");
+            let error = interp
+                .max_error_estimate(&data_times[0], &data_times[7], &data_times, &data_states)
+                .unwrap();
+            assert!(error < 1e-6, "{method} {window}");
+        }
+    }
+
+    #[test]
+    fn test_gap_check() {
+        let t0 = Utc::now();
+        let data_times = vec![t0, t0 + Duration::seconds(10), t0 + Duration::seconds(100)];
+        let interp = Interpolation::new("hermite", None, Some(30.0)).unwrap();
+        assert!(interp
+            .check_gaps(&[t0 + Duration::seconds(5)], &data_times)
+            .is_ok());
+        assert!(interp
+            .check_gaps(&[t0 + Duration::seconds(10)], &data_times)
+            .is_ok());
+        assert!(interp
+            .check_gaps(&[t0 + Duration::seconds(50)], &data_times)
+            .is_err());
+
+        assert!(Interpolation::new("spline", None, None).is_err());
+        assert!(Interpolation::new("lagrange", Some(1), None).is_err());
+        assert!(Interpolation::new("hermite", None, Some(0.0)).is_err());
+    }
+
     #[test]
     fn test_find_interval() {
         let times = vec![0.0, 10.0, 20.0, 30.0];
@@ -4,20 +4,41 @@
 //! including time conversions, coordinate transformations, EOP data handling,
 //! and mathematical operations.
 
+pub mod atmosphere;
+pub mod body_properties;
+pub mod cache_io;
 pub mod celestial;
 pub mod config;
 pub mod conversions;
+pub mod covariance;
 pub mod eop_cache;
 pub mod eop_provider;
 pub mod geo;
+pub mod geomagnetic;
+pub mod gnss;
+pub mod gps_almanac;
+pub mod horizon;
 pub mod horizons;
 pub mod interpolation;
+pub mod json_to_py;
 pub mod math_utils;
 pub mod moon;
+pub mod mpc;
 pub mod naif_ids;
 pub mod polygon;
+pub mod profiling;
+pub mod progress;
+pub mod pseudo_bodies;
+pub mod rinex_nav;
+pub mod satcat;
+pub mod sesame;
+pub mod settings;
+pub mod sites;
+pub mod space_weather;
+pub mod sphere;
 pub mod time_utils;
 pub mod tle_utils;
 pub mod to_skycoord;
+pub mod transform_accuracy;
 pub mod ut1_provider;
 pub mod vector_math;
@@ -0,0 +1,512 @@
+//! Minor Planet Center orbit (MPCORB) parsing and two-body propagation
+//!
+//! MPCORB.DAT and the smaller MPC orbit exports use a fixed-column, one-line
+//! format with osculating heliocentric elements referred to the J2000
+//! ecliptic and equinox. Orbits are propagated as unperturbed Keplerian
+//! ellipses around the Sun, which is adequate for pointing and proximity
+//! checks within a few months of the element epoch.
+//!
+//! Parsed orbits can be registered by name or designation so that the
+//! solar system body lookups (`get_body`, moving-body constraints) resolve
+//! them without SPICE kernels or a JPL Horizons query.
+//!
+//! ## Columns (1-based, inclusive)
+//!
+//! | Columns | Field                                 |
+//! |---------|---------------------------------------|
+//! | 1-7     | Packed number or provisional designation |
+//! | 9-13    | Absolute magnitude H                  |
+//! | 15-19   | Slope parameter G                     |
+//! | 21-25   | Packed epoch (TT)                     |
+//! | 27-35   | Mean anomaly (deg)                    |
+//! | 38-46   | Argument of perihelion (deg)          |
+//! | 49-57   | Longitude of ascending node (deg)     |
+//! | 60-68   | Inclination (deg)                     |
+//! | 71-79   | Eccentricity                          |
+//! | 81-91   | Mean daily motion (deg/day)           |
+//! | 93-103  | Semi-major axis (AU)                  |
+//! | 167-194 | Readable designation, e.g. "(433) Eros" |
+
+use std::collections::HashMap;
+use std::sync::RwLock;
+
+use chrono::{DateTime, NaiveDate, TimeZone, Utc};
+use hifitime::{Epoch, TimeScale};
+use ndarray::Array2;
+use once_cell::sync::Lazy;
+
+use crate::utils::config::{
+    ARCSEC_TO_RAD, AU_PER_DAY_TO_KM_PER_SEC, AU_TO_KM, GAUSSIAN_GRAVITATIONAL_CONSTANT, JD_J2000,
+    OBLIQUITY_J2000_ARCSEC, SPEED_OF_LIGHT_KM_S,
+};
+use crate::utils::time_utils::datetime_to_jd_tt;
+
+/// Osculating heliocentric elements of one minor planet
+///
+/// Angles are stored in degrees as given by the MPC.
+#[derive(Debug, Clone, PartialEq)]
+pub struct MpcOrbit {
+    /// Permanent number, if the object is numbered
+    pub number: Option<u32>,
+    /// Readable designation, e.g. "(433) Eros" or "2007 TA418"
+    pub designation: String,
+    /// Name of a numbered object, e.g. "Eros"
+    pub name: Option<String>,
+    /// Absolute magnitude H
+    pub h: Option<f64>,
+    /// Slope parameter G
+    pub g: Option<f64>,
+    /// Epoch of osculation (JD, TT)
+    pub epoch_jd_tt: f64,
+    pub mean_anomaly: f64,
+    pub arg_perihelion: f64,
+    pub ascending_node: f64,
+    pub inclination: f64,
+    pub eccentricity: f64,
+    /// Mean daily motion (deg/day)
+    pub mean_motion: f64,
+    /// Semi-major axis (AU)
+    pub semimajor_axis: f64,
+}
+
+/// Extract 1-based inclusive columns from a line, or "" if it is too short
+fn columns(line: &str, first: usize, last: usize) -> &str {
+    let start = first - 1;
+    if start >= line.len() {
+        return "";
+    }
+    line.get(start..last.min(line.len())).unwrap_or("").trim()
+}
+
+fn required_field(line: &str, first: usize, last: usize, what: &str) -> Result<f64, String> {
+    let text = columns(line, first, last);
+    text.parse::<f64>()
+        .map_err(|_| format!("Invalid {} '{}' in MPC orbit line", what, text))
+}
+
+fn optional_field(line: &str, first: usize, last: usize) -> Option<f64> {
+    columns(line, first, last).parse::<f64>().ok()
+}
+
+/// Value of an MPC packed digit: 0-9, A-Z = 10-35, a-z = 36-61
+fn packed_digit(c: char) -> Option<u32> {
+    match c {
+        '0'..='9' => Some(c as u32 - '0' as u32),
+        'A'..='Z' => Some(c as u32 - 'A' as u32 + 10),
+        'a'..='z' => Some(c as u32 - 'a' as u32 + 36),
+        _ => None,
+    }
+}
+
+/// Unpack a packed permanent number ("00433", "A0001", "~0000")
+pub fn unpack_number(packed: &str) -> Option<u32> {
+    if !packed.is_ascii() {
+        return None;
+    }
+    let chars: Vec<char> = packed.chars().collect();
+    if chars.len() != 5 {
+        return None;
+    }
+    if chars[0] == '~' {
+        // Numbers from 620000 onwards are four base-62 digits after a tilde
+        let mut value = 0u32;
+        for &c in &chars[1..] {
+            value = value * 62 + packed_digit(c)?;
+        }
+        return Some(620_000 + value);
+    }
+    if !chars[1..].iter().all(|c| c.is_ascii_digit()) {
+        return None;
+    }
+    let rest: u32 = packed[1..].parse().ok()?;
+    Some(packed_digit(chars[0])? * 10_000 + rest)
+}
+
+/// Unpack a packed provisional designation ("K07Tf8A" -> "2007 TA418")
+pub fn unpack_provisional(packed: &str) -> Option<String> {
+    if !packed.is_ascii() {
+        return None;
+    }
+    let chars: Vec<char> = packed.chars().collect();
+    if chars.len() != 7 {
+        return None;
+    }
+    let century = match chars[0] {
+        'I' => 18,
+        'J' => 19,
+        'K' => 20,
+        _ => return None,
+    };
+    let year: u32 = packed[1..3].parse().ok()?;
+    let half_month = chars[3];
+    let second_letter = chars[6];
+    if !half_month.is_ascii_uppercase() || !second_letter.is_ascii_uppercase() {
+        return None;
+    }
+    let cycle = packed_digit(chars[4])? * 10 + chars[5].to_digit(10)?;
+    let mut designation = format!("{}{:02} {}{}", century, year, half_month, second_letter);
+    if cycle > 0 {
+        designation.push_str(&cycle.to_string());
+    }
+    Some(designation)
+}
+
+/// Unpack a packed epoch ("K205V" -> 2020-05-31) to a Julian Date in TT
+///
+/// MPC epochs are at 0h TT on the given calendar date.
+pub fn unpack_epoch(packed: &str) -> Option<f64> {
+    if !packed.is_ascii() {
+        return None;
+    }
+    let chars: Vec<char> = packed.chars().collect();
+    if chars.len() != 5 {
+        return None;
+    }
+    let century = match chars[0] {
+        'I' => 1800,
+        'J' => 1900,
+        'K' => 2000,
+        _ => return None,
+    };
+    let year = century + packed[1..3].parse::<i32>().ok()?;
+    let month = packed_digit(chars[3])?;
+    let day = packed_digit(chars[4])?;
+    let date = NaiveDate::from_ymd_opt(year, month, day)?;
+    let j2000 = NaiveDate::from_ymd_opt(2000, 1, 1)?;
+    // 2000-01-01 0h is JD 2451544.5
+    Some(JD_J2000 - 0.5 + (date - j2000).num_days() as f64)
+}
+
+/// Split a readable designation into (number, name)
+///
+/// "(433) Eros" gives (Some(433), Some("Eros")); "2007 TA418" gives (None, None).
+fn split_readable(readable: &str) -> (Option<u32>, Option<String>) {
+    let Some(rest) = readable.strip_prefix('(') else {
+        return (None, None);
+    };
+    let Some((number, name)) = rest.split_once(')') else {
+        return (None, None);
+    };
+    let name = name.trim();
+    (
+        number.trim().parse().ok(),
+        (!name.is_empty()).then(|| name.to_string()),
+    )
+}
+
+impl MpcOrbit {
+    /// Parse one MPCORB line
+    pub fn from_line(line: &str) -> Result<Self, String> {
+        let line = line.trim_end();
+        if line.len() < 103 {
+            return Err(format!(
+                "MPC orbit line is too short ({} characters, expected at least 103)",
+                line.len()
+            ));
+        }
+        let packed = columns(line, 1, 7);
+        let epoch_text = columns(line, 21, 25);
+        let epoch_jd_tt = unpack_epoch(epoch_text)
+            .ok_or_else(|| format!("Invalid packed epoch '{}' in MPC orbit line", epoch_text))?;
+
+        let eccentricity = required_field(line, 71, 79, "eccentricity")?;
+        if !(0.0..1.0).contains(&eccentricity) {
+            return Err(format!(
+                "Only elliptic orbits are supported (eccentricity {} for '{}')",
+                eccentricity, packed
+            ));
+        }
+        let mean_motion = required_field(line, 81, 91, "mean daily motion")?;
+        let semimajor_axis = match optional_field(line, 93, 103) {
+            Some(a) if a > 0.0 => a,
+            // Derive the semi-major axis from the mean motion when it is blank
+            _ => (GAUSSIAN_GRAVITATIONAL_CONSTANT / mean_motion.to_radians()).powf(2.0 / 3.0),
+        };
+
+        let readable = columns(line, 167, 194);
+        let (mut number, name) = split_readable(readable);
+        if number.is_none() {
+            number = unpack_number(packed);
+        }
+        let designation = if !readable.is_empty() {
+            readable.to_string()
+        } else if let Some(n) = number {
+            format!("({})", n)
+        } else {
+            unpack_provisional(packed).unwrap_or_else(|| packed.to_string())
+        };
+
+        Ok(MpcOrbit {
+            number,
+            designation,
+            name,
+            h: optional_field(line, 9, 13),
+            g: optional_field(line, 15, 19),
+            epoch_jd_tt,
+            mean_anomaly: required_field(line, 27, 35, "mean anomaly")?,
+            arg_perihelion: required_field(line, 38, 46, "argument of perihelion")?,
+            ascending_node: required_field(line, 49, 57, "longitude of ascending node")?,
+            inclination: required_field(line, 60, 68, "inclination")?,
+            eccentricity,
+            mean_motion,
+            semimajor_axis,
+        })
+    }
+
+    /// Epoch of osculation as a UTC time
+    pub fn epoch_utc(&self) -> Result<DateTime<Utc>, String> {
+        let unix = Epoch::from_jde_in_time_scale(self.epoch_jd_tt, TimeScale::TT).to_unix_seconds();
+        let secs = unix.floor();
+        let nanos = ((unix - secs) * 1e9).round().min(999_999_999.0);
+        Utc.timestamp_opt(secs as i64, nanos as u32)
+            .single()
+            .ok_or_else(|| format!("Invalid MPC epoch JD {}", self.epoch_jd_tt))
+    }
+
+    /// Heliocentric state at a Julian Date (TT)
+    ///
+    /// Returns [x, y, z, vx, vy, vz] in km and km/s, in the equatorial
+    /// (ICRS-aligned) frame.
+    pub fn heliocentric_state(&self, jd_tt: f64) -> [f64; 6] {
+        let a = self.semimajor_axis;
+        let e = self.eccentricity;
+        // Mean motion consistent with the semi-major axis (rad/day)
+        let n = GAUSSIAN_GRAVITATIONAL_CONSTANT / (a * a * a).sqrt();
+
+        let mean_anomaly = self.mean_anomaly.to_radians() + n * (jd_tt - self.epoch_jd_tt);
+        let mut ecc_anomaly = mean_anomaly;
+        for _ in 0..50 {
+            let delta = (ecc_anomaly - e * ecc_anomaly.sin() - mean_anomaly)
+                / (1.0 - e * ecc_anomaly.cos());
+            ecc_anomaly -= delta;
+            if delta.abs() < 1e-14 {
+                break;
+            }
+        }
+
+        let (sin_e, cos_e) = ecc_anomaly.sin_cos();
+        let sqrt_one_minus_e2 = (1.0 - e * e).sqrt();
+        let e_dot = n / (1.0 - e * cos_e);
+
+        // Position (AU) and velocity (AU/day) in the orbital plane
+        let xp = a * (cos_e - e);
+        let yp = a * sqrt_one_minus_e2 * sin_e;
+        let vxp = -a * sin_e * e_dot;
+        let vyp = a * sqrt_one_minus_e2 * cos_e * e_dot;
+
+        let (sin_w, cos_w) = self.arg_perihelion.to_radians().sin_cos();
+        let (sin_o, cos_o) = self.ascending_node.to_radians().sin_cos();
+        let (sin_i, cos_i) = self.inclination.to_radians().sin_cos();
+        let (sin_eps, cos_eps) = (OBLIQUITY_J2000_ARCSEC * ARCSEC_TO_RAD).sin_cos();
+
+        // Orbital plane -> ecliptic -> equatorial
+        let rotate = |x: f64, y: f64| -> [f64; 3] {
+            let xe = (cos_w * cos_o - sin_w * sin_o * cos_i) * x
+                + (-sin_w * cos_o - cos_w * sin_o * cos_i) * y;
+            let ye = (cos_w * sin_o + sin_w * cos_o * cos_i) * x
+                + (-sin_w * sin_o + cos_w * cos_o * cos_i) * y;
+            let ze = sin_w * sin_i * x + cos_w * sin_i * y;
+            [xe, ye * cos_eps - ze * sin_eps, ye * sin_eps + ze * cos_eps]
+        };
+        let r = rotate(xp, yp);
+        let v = rotate(vxp, vyp);
+
+        [
+            r[0] * AU_TO_KM,
+            r[1] * AU_TO_KM,
+            r[2] * AU_TO_KM,
+            v[0] * AU_PER_DAY_TO_KM_PER_SEC,
+            v[1] * AU_PER_DAY_TO_KM_PER_SEC,
+            v[2] * AU_PER_DAY_TO_KM_PER_SEC,
+        ]
+    }
+
+    /// Heliocentric states at each time, shape (N, 6) in km and km/s
+    pub fn heliocentric_states(&self, times: &[DateTime<Utc>]) -> Array2<f64> {
+        let mut out = Array2::<f64>::zeros((times.len(), 6));
+        for (i, t) in times.iter().enumerate() {
+            let (jd1, jd2) = datetime_to_jd_tt(t);
+            let state = self.heliocentric_state(jd1 + jd2);
+            for (j, value) in state.iter().enumerate() {
+                out[[i, j]] = *value;
+            }
+        }
+        out
+    }
+
+    /// Observer-centred states given the observer's Sun-relative offset
+    ///
+    /// `sun_from_observer` holds the Sun position and velocity relative to the
+    /// observer at each time (e.g. geocentric Sun states). The asteroid
+    /// position is corrected for light travel time.
+    pub fn relative_states(
+        &self,
+        times: &[DateTime<Utc>],
+        sun_from_observer: &Array2<f64>,
+    ) -> Array2<f64> {
+        let mut out = Array2::<f64>::zeros((times.len(), 6));
+        for (i, t) in times.iter().enumerate() {
+            let (jd1, jd2) = datetime_to_jd_tt(t);
+            let jd = jd1 + jd2;
+            let sun = sun_from_observer.row(i);
+            let mut light_time = 0.0;
+            let mut state = [0.0; 6];
+            for _ in 0..3 {
+                let helio = self.heliocentric_state(jd - light_time / 86_400.0);
+                for (j, value) in state.iter_mut().enumerate() {
+                    *value = helio[j] + sun[j];
+                }
+                let range = (state[0].powi(2) + state[1].powi(2) + state[2].powi(2)).sqrt();
+                light_time = range / SPEED_OF_LIGHT_KM_S;
+            }
+            for (j, value) in state.iter().enumerate() {
+                out[[i, j]] = *value;
+            }
+        }
+        out
+    }
+
+    /// Lookup keys under which this orbit is registered (lowercase)
+    fn keys(&self) -> Vec<String> {
+        let mut keys = vec![self.designation.to_lowercase()];
+        if let Some(name) = &self.name {
+            keys.push(name.to_lowercase());
+        }
+        if let Some(number) = self.number {
+            keys.push(format!("({})", number));
+        }
+        keys
+    }
+}
+
+/// Parse MPCORB text, skipping the file header and blank or short lines
+///
+/// MPCORB.DAT starts with a free-text preamble terminated by a line of
+/// dashes; orbit lines are recognized by their packed epoch so that other
+/// exports without the preamble are also accepted.
+pub fn parse_mpcorb(text: &str) -> Result<Vec<MpcOrbit>, String> {
+    let mut orbits = Vec::new();
+    for (index, line) in text.lines().enumerate() {
+        if line.trim_end().len() < 103 || unpack_epoch(columns(line, 21, 25)).is_none() {
+            continue;
+        }
+        let orbit = MpcOrbit::from_line(line).map_err(|e| format!("Line {}: {}", index + 1, e))?;
+        orbits.push(orbit);
+    }
+    if orbits.is_empty() {
+        return Err("No minor planet orbits found in MPC orbit data".to_string());
+    }
+    Ok(orbits)
+}
+
+static MINOR_PLANETS: Lazy<RwLock<HashMap<String, MpcOrbit>>> =
+    Lazy::new(|| RwLock::new(HashMap::new()));
+
+/// Register an orbit so that body lookups resolve it by name or designation
+pub fn register_minor_planet(orbit: &MpcOrbit) {
+    let mut registry = MINOR_PLANETS.write().unwrap_or_else(|e| e.into_inner());
+    for key in orbit.keys() {
+        registry.insert(key, orbit.clone());
+    }
+}
+
+/// Find a registered orbit by name, designation or "(number)", case-insensitively
+pub fn find_minor_planet(identifier: &str) -> Option<MpcOrbit> {
+    let registry = MINOR_PLANETS.read().unwrap_or_else(|e| e.into_inner());
+    if registry.is_empty() {
+        return None;
+    }
+    registry.get(&identifier.trim().to_lowercase()).cloned()
+}
+
+/// Remove all registered minor planet orbits
+pub fn clear_minor_planets() {
+    MINOR_PLANETS
+        .write()
+        .unwrap_or_else(|e| e.into_inner())
+        .clear();
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const CERES: &str = "00001    3.34  0.15 K205V 162.68631   73.73161   80.28698   10.58862  0.0775571  0.21406009   2.7676569  0 MPO492748  6751 115 1801-2019 0.60 M-v 30h Williams   0000 (1) Ceres                   20190915";
+    const PROVISIONAL: &str = "K07Tf8A 17.20  0.15 K205V  12.34567  123.45678  234.56789    5.43210  0.1234567  0.25000000   2.5097800  0 MPO492748  6751 115 1801-2019 0.60 M-v 30h Williams   0000 2007 TA418                  20190915";
+
+    #[test]
+    fn test_unpack_packed_fields() {
+        assert_eq!(unpack_number("00433"), Some(433));
+        assert_eq!(unpack_number("A0001"), Some(100_001));
+        assert_eq!(unpack_number("~0000"), Some(620_000));
+        assert_eq!(unpack_provisional("K07Tf8A").as_deref(), Some("2007 TA418"));
+        assert_eq!(unpack_provisional("J95X00A").as_deref(), Some("1995 XA"));
+        assert_eq!(unpack_epoch("K205V"), Some(2_459_000.5));
+        assert_eq!(unpack_epoch("J9611"), Some(2_450_083.5));
+    }
+
+    #[test]
+    fn test_parse_numbered_and_provisional() {
+        let orbits = parse_mpcorb(&format!(
+            "header text\n-----\n{}\n\n{}\n",
+            CERES, PROVISIONAL
+        ))
+        .unwrap();
+        assert_eq!(orbits.len(), 2);
+        assert_eq!(orbits[0].number, Some(1));
+        assert_eq!(orbits[0].name.as_deref(), Some("Ceres"));
+        assert_eq!(orbits[0].designation, "(1) Ceres");
+        assert_eq!(orbits[0].h, Some(3.34));
+        assert!((orbits[0].semimajor_axis - 2.7676569).abs() < 1e-12);
+        assert_eq!(orbits[1].number, None);
+        assert_eq!(orbits[1].designation, "2007 TA418");
+    }
+
+    #[test]
+    fn test_hyperbolic_orbit_rejected() {
+        let line = CERES.replacen("0.0775571", "1.0775571", 1);
+        assert!(MpcOrbit::from_line(&line).unwrap_err().contains("elliptic"));
+    }
+
+    #[test]
+    fn test_state_obeys_vis_viva() {
+        let orbit = MpcOrbit::from_line(CERES).unwrap();
+        let jd = orbit.epoch_jd_tt + 123.4;
+        let s = orbit.heliocentric_state(jd);
+        let r = (s[0].powi(2) + s[1].powi(2) + s[2].powi(2)).sqrt() / AU_TO_KM;
+        assert!(r > 2.7676569 * (1.0 - 0.0775571) - 1e-9);
+        assert!(r < 2.7676569 * (1.0 + 0.0775571) + 1e-9);
+
+        let v = (s[3].powi(2) + s[4].powi(2) + s[5].powi(2)).sqrt() / AU_PER_DAY_TO_KM_PER_SEC;
+        let k2 = GAUSSIAN_GRAVITATIONAL_CONSTANT.powi(2);
+        let expected = (k2 * (2.0 / r - 1.0 / orbit.semimajor_axis)).sqrt();
+        assert!((v - expected).abs() / expected < 1e-9);
+    }
+
+    #[test]
+    fn test_velocity_matches_position_derivative() {
+        let orbit = MpcOrbit::from_line(PROVISIONAL).unwrap();
+        let jd = orbit.epoch_jd_tt + 10.0;
+        let dt = 1.0 / 1440.0;
+        let s = orbit.heliocentric_state(jd);
+        let before = orbit.heliocentric_state(jd - dt);
+        let after = orbit.heliocentric_state(jd + dt);
+        // Julian dates near 2.46e6 resolve time only to ~40 µs, so divide by
+        // the step the two dates actually span rather than the nominal 2 dt
+        let step = ((jd + dt) - (jd - dt)) * 86_400.0;
+        for (j, (a, b)) in after.iter().zip(before.iter()).take(3).enumerate() {
+            let numeric = (a - b) / step;
+            assert!((numeric - s[j + 3]).abs() < 1e-6, "axis {}", j);
+        }
+    }
+
+    #[test]
+    fn test_registry_lookup() {
+        let orbit = MpcOrbit::from_line(CERES).unwrap();
+        register_minor_planet(&orbit);
+        assert!(find_minor_planet("CERES").is_some());
+        assert!(find_minor_planet("(1)").is_some());
+        assert!(find_minor_planet("(1) ceres").is_some());
+        assert!(find_minor_planet("Vesta").is_none());
+    }
+}
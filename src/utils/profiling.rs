@@ -0,0 +1,221 @@
+//! Opt-in timing of constraint evaluation
+//!
+//! When enabled, evaluator calls, ephemeris data extraction and frame
+//! conversions record their wall time under a category and a name, and
+//! [`report`] aggregates the calls into counts, total, mean and largest
+//! durations. Disabled (the default), each hook costs a single atomic load.
+//!
+//! Times are inclusive: a logical combination's time contains the time of
+//! its sub-constraints, and an evaluator's time contains the data extraction
+//! it does. Calls made from parallel threads add up, so totals can exceed the
+//! elapsed wall-clock time.
+
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+use once_cell::sync::Lazy;
+use serde_json::json;
+
+/// What a timed call does
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Category {
+    /// A constraint evaluator call, named after the constraint
+    Evaluator,
+    /// Reading positions and times out of an ephemeris for an evaluator
+    Extraction,
+    /// A coordinate frame conversion, named after the frames
+    Frame,
+}
+
+impl Category {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Category::Evaluator => "evaluator",
+            Category::Extraction => "extraction",
+            Category::Frame => "frame",
+        }
+    }
+}
+
+/// Aggregated timings of one category and name
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct Stat {
+    pub count: u64,
+    pub total: Duration,
+    pub max: Duration,
+}
+
+impl Stat {
+    fn add(&mut self, elapsed: Duration) {
+        self.count += 1;
+        self.total += elapsed;
+        self.max = self.max.max(elapsed);
+    }
+}
+
+static ENABLED: AtomicBool = AtomicBool::new(false);
+
+static STATS: Lazy<Mutex<HashMap<(Category, String), Stat>>> =
+    Lazy::new(|| Mutex::new(HashMap::new()));
+
+/// Whether calls are being timed
+pub fn is_enabled() -> bool {
+    ENABLED.load(Ordering::Relaxed)
+}
+
+/// Start or stop timing calls; timings recorded so far are kept
+pub fn set_enabled(enabled: bool) {
+    ENABLED.store(enabled, Ordering::Relaxed);
+}
+
+/// Discard the timings recorded so far
+pub fn reset() {
+    STATS.lock().unwrap().clear();
+}
+
+fn record(category: Category, name: String, elapsed: Duration) {
+    STATS
+        .lock()
+        .unwrap()
+        .entry((category, name))
+        .or_default()
+        .add(elapsed);
+}
+
+/// Guard recording the time from its creation until it is dropped
+pub struct Span {
+    category: Category,
+    name: String,
+    start: Instant,
+}
+
+impl Drop for Span {
+    fn drop(&mut self) {
+        record(
+            self.category,
+            std::mem::take(&mut self.name),
+            self.start.elapsed(),
+        );
+    }
+}
+
+/// Start timing under `category` and `name()`, or `None` when disabled
+///
+/// `name` is only called when profiling is on, so it may format freely.
+/// Useful where the timed code returns early with `?`.
+pub fn span(category: Category, name: impl FnOnce() -> String) -> Option<Span> {
+    is_enabled().then(|| Span {
+        category,
+        name: name(),
+        start: Instant::now(),
+    })
+}
+
+/// Run `f`, recording its wall time under `category` and `name()` when enabled
+pub fn timed<T>(category: Category, name: impl FnOnce() -> String, f: impl FnOnce() -> T) -> T {
+    let _span = span(category, name);
+    f()
+}
+
+/// Recorded timings, by category then by decreasing total time
+pub fn snapshot() -> Vec<(Category, String, Stat)> {
+    let mut entries: Vec<_> = STATS
+        .lock()
+        .unwrap()
+        .iter()
+        .map(|((category, name), stat)| (*category, name.clone(), *stat))
+        .collect();
+    entries.sort_by(|a, b| {
+        a.0.cmp(&b.0)
+            .then(b.2.total.cmp(&a.2.total))
+            .then(a.1.cmp(&b.1))
+    });
+    entries
+}
+
+/// Structured report of the recorded timings
+///
+/// `entries` holds one object per category and name with `count`,
+/// `total_seconds`, `mean_seconds` and `max_seconds`; `totals` sums
+/// `total_seconds` per category.
+pub fn report() -> serde_json::Value {
+    let entries = snapshot();
+    let mut totals = serde_json::Map::new();
+    for category in [Category::Evaluator, Category::Extraction, Category::Frame] {
+        let seconds: f64 = entries
+            .iter()
+            .filter(|e| e.0 == category)
+            .map(|e| e.2.total.as_secs_f64())
+            .sum();
+        totals.insert(category.as_str().to_string(), json!(seconds));
+    }
+    let entries: Vec<_> = entries
+        .iter()
+        .map(|(category, name, stat)| {
+            let total = stat.total.as_secs_f64();
+            json!({
+                "category": category.as_str(),
+                "name": name,
+                "count": stat.count,
+                "total_seconds": total,
+                "mean_seconds": total / stat.count as f64,
+                "max_seconds": stat.max.as_secs_f64(),
+            })
+        })
+        .collect();
+    json!({
+        "enabled": is_enabled(),
+        "totals": totals,
+        "entries": entries,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_stat_aggregates_calls() {
+        let mut stat = Stat::default();
+        stat.add(Duration::from_millis(3));
+        stat.add(Duration::from_millis(5));
+        assert_eq!(stat.count, 2);
+        assert_eq!(stat.total, Duration::from_millis(8));
+        assert_eq!(stat.max, Duration::from_millis(5));
+    }
+
+    #[test]
+    fn test_timed_records_only_when_enabled() {
+        // The one test touching the global state, so parallel tests cannot race
+        let label = "test_timed_records_only_when_enabled";
+        let find = || {
+            snapshot()
+                .into_iter()
+                .find(|e| e.0 == Category::Frame && e.1 == label)
+                .map(|e| e.2.count)
+        };
+
+        set_enabled(false);
+        assert_eq!(timed(Category::Frame, || label.to_string(), || 1 + 1), 2);
+        assert_eq!(find(), None);
+
+        set_enabled(true);
+        timed(Category::Frame, || label.to_string(), || ());
+        timed(Category::Frame, || label.to_string(), || ());
+        set_enabled(false);
+        assert_eq!(find(), Some(2));
+
+        let report = report();
+        let entry = report["entries"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .find(|e| e["name"] == label)
+            .unwrap();
+        assert_eq!(entry["category"], "frame");
+        assert_eq!(entry["count"], 2);
+        assert!(report["totals"]["frame"].as_f64().unwrap() >= 0.0);
+    }
+}
@@ -0,0 +1,255 @@
+//! Progress callbacks for long computations
+//!
+//! Long propagations and batch constraint evaluations accept an optional
+//! `progress` callable. The work then runs in chunks with the GIL released,
+//! and the GIL is reacquired after every `progress_every` chunks (and after
+//! the last one) to call `progress(done, total)`, where `done` and `total`
+//! count time steps or targets. The callable cancels the computation by
+//! raising, which propagates to the caller, or by returning `False`, which
+//! raises `InterruptedError`.
+//!
+//! The same computations accept a `cancel` [`CancellationToken`], checked
+//! between chunks, so another thread or a service deadline can stop them
+//! without waiting for the whole grid. Pending signals are checked between
+//! chunks too, so Ctrl-C raises `KeyboardInterrupt` at the next chunk
+//! boundary. Without a callable or token the chunks run with the GIL held.
+
+use pyo3::prelude::*;
+use pyo3::types::PyBool;
+use std::ops::Range;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+/// Time steps propagated between progress reports
+pub const PROPAGATION_CHUNK_SIZE: usize = 4096;
+
+/// Targets evaluated between progress reports
+pub const TARGET_CHUNK_SIZE: usize = 256;
+
+/// Cooperative cancellation flag for long computations
+///
+/// Pass it as `cancel=` and call `cancel()` from another thread (or create
+/// it with a `timeout` in seconds) to stop the computation at the next chunk
+/// boundary. Cancelling raises `InterruptedError`, an expired timeout
+/// `TimeoutError`. A token stays cancelled until `reset()`.
+#[pyclass]
+#[derive(Clone)]
+pub struct CancellationToken {
+    flag: Arc<AtomicBool>,
+    deadline: Option<Instant>,
+}
+
+#[pymethods]
+impl CancellationToken {
+    #[new]
+    #[pyo3(signature = (timeout=None))]
+    fn new(timeout: Option<f64>) -> PyResult<Self> {
+        let deadline = match timeout {
+            Some(seconds) if !(seconds.is_finite() && seconds >= 0.0) => {
+                return Err(pyo3::exceptions::PyValueError::new_err(
+                    "timeout must be a non-negative number of seconds",
+                ))
+            }
+            // A timeout too long to represent never expires
+            Some(seconds) => Duration::try_from_secs_f64(seconds)
+                .ok()
+                .and_then(|timeout| Instant::now().checked_add(timeout)),
+            None => None,
+        };
+        Ok(Self {
+            flag: Arc::new(AtomicBool::new(false)),
+            deadline,
+        })
+    }
+
+    /// Request cancellation; safe to call from any thread
+    fn cancel(&self) {
+        self.flag.store(true, Ordering::Relaxed);
+    }
+
+    /// Clear an explicit cancellation so the token can be reused
+    fn reset(&self) {
+        self.flag.store(false, Ordering::Relaxed);
+    }
+
+    /// Whether `cancel()` was called or the timeout has expired
+    #[getter]
+    fn cancelled(&self) -> bool {
+        self.check().is_err()
+    }
+
+    fn __repr__(&self) -> String {
+        format!("CancellationToken(cancelled={})", self.cancelled())
+    }
+}
+
+impl CancellationToken {
+    /// `InterruptedError` once cancelled, `TimeoutError` once the deadline passed
+    pub fn check(&self) -> PyResult<()> {
+        if self.flag.load(Ordering::Relaxed) {
+            return Err(pyo3::exceptions::PyInterruptedError::new_err(
+                "cancelled by cancellation token",
+            ));
+        }
+        if self
+            .deadline
+            .is_some_and(|deadline| Instant::now() >= deadline)
+        {
+            return Err(pyo3::exceptions::PyTimeoutError::new_err(
+                "cancellation token timeout expired",
+            ));
+        }
+        Ok(())
+    }
+}
+
+/// Progress of a chunked computation, reported to an optional Python callable
+pub struct Progress {
+    callback: Option<Py<PyAny>>,
+    cancel: Option<CancellationToken>,
+    every: usize,
+    total: usize,
+    done: usize,
+    chunks: usize,
+}
+
+impl Progress {
+    /// Report to `callback` every `every` chunks of a computation of `total`
+    /// items, stopping between chunks once `cancel` is cancelled
+    pub fn new(
+        callback: Option<&Bound<'_, PyAny>>,
+        every: usize,
+        total: usize,
+        cancel: Option<CancellationToken>,
+    ) -> PyResult<Self> {
+        if every == 0 {
+            return Err(pyo3::exceptions::PyValueError::new_err(
+                "progress_every must be a positive number of chunks",
+            ));
+        }
+        if callback.is_some_and(|callback| !callback.is_callable()) {
+            return Err(pyo3::exceptions::PyTypeError::new_err(
+                "progress must be callable as progress(done, total)",
+            ));
+        }
+        Ok(Self {
+            callback: callback.map(|callback| callback.clone().unbind()),
+            cancel,
+            every,
+            total,
+            done: 0,
+            chunks: 0,
+        })
+    }
+
+    /// No reporting or cancellation token
+    pub fn none() -> Self {
+        Self {
+            callback: None,
+            cancel: None,
+            every: 1,
+            total: 0,
+            done: 0,
+            chunks: 0,
+        }
+    }
+
+    /// Whether a callback or token was given, so chunks should run with the
+    /// GIL released
+    pub fn is_active(&self) -> bool {
+        self.callback.is_some() || self.cancel.is_some()
+    }
+
+    /// Consecutive chunks of `n` items
+    pub fn chunks(&self, n: usize, chunk_size: usize) -> Vec<Range<usize>> {
+        chunk_ranges(n, chunk_size)
+    }
+
+    /// Stop if the token was cancelled or a signal handler raised (Ctrl-C)
+    fn check(&self, py: Python) -> PyResult<()> {
+        py.check_signals()?;
+        match &self.cancel {
+            Some(cancel) => cancel.check(),
+            None => Ok(()),
+        }
+    }
+
+    /// Record `n` finished items, stopping if cancelled and calling the
+    /// callback when a report is due
+    pub fn advance(&mut self, py: Python, n: usize) -> PyResult<()> {
+        self.done = (self.done + n).min(self.total);
+        self.chunks += 1;
+        self.check(py)?;
+        let Some(callback) = &self.callback else {
+            return Ok(());
+        };
+        if !report_due(self.chunks, self.every, self.done, self.total) {
+            return Ok(());
+        }
+        let reply = callback.call1(py, (self.done, self.total))?;
+        let reply = reply.bind(py);
+        if reply
+            .downcast::<PyBool>()
+            .is_ok_and(|reply| !reply.is_true())
+        {
+            return Err(pyo3::exceptions::PyInterruptedError::new_err(
+                "cancelled by progress callback",
+            ));
+        }
+        Ok(())
+    }
+}
+
+/// Consecutive ranges of at most `size` (at least 1) covering `0..n`
+fn chunk_ranges(n: usize, size: usize) -> Vec<Range<usize>> {
+    let size = size.max(1);
+    (0..n)
+        .step_by(size)
+        .map(|start| start..(start + size).min(n))
+        .collect()
+}
+
+/// Whether to report after `chunks` chunks with `done` of `total` items finished
+fn report_due(chunks: usize, every: usize, done: usize, total: usize) -> bool {
+    chunks.is_multiple_of(every) || done >= total
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_chunk_ranges() {
+        assert_eq!(chunk_ranges(5, 2), vec![0..2, 2..4, 4..5]);
+        assert_eq!(chunk_ranges(4, 4), vec![0..4]);
+        assert_eq!(chunk_ranges(3, 0), vec![0..1, 1..2, 2..3]);
+        assert!(chunk_ranges(0, 16).is_empty());
+    }
+
+    #[test]
+    fn test_report_due() {
+        // Every third chunk, and always after the last one
+        assert!(!report_due(1, 3, 10, 100));
+        assert!(report_due(3, 3, 30, 100));
+        assert!(report_due(4, 3, 100, 100));
+        assert!(report_due(1, 1, 10, 100));
+    }
+
+    #[test]
+    fn test_cancellation_token() {
+        let token = CancellationToken::new(None).unwrap();
+        assert!(token.check().is_ok());
+        // Clones share the flag, as the copy held by a computation must
+        let shared = token.clone();
+        token.cancel();
+        assert!(shared.check().is_err());
+        shared.reset();
+        assert!(token.check().is_ok());
+
+        let expired = CancellationToken::new(Some(0.0)).unwrap();
+        assert!(expired.check().is_err());
+        assert!(CancellationToken::new(Some(-1.0)).is_err());
+        assert!(CancellationToken::new(Some(f64::NAN)).is_err());
+    }
+}
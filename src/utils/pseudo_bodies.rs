@@ -0,0 +1,150 @@
+//! Pseudo-bodies derived from the Sun and Moon positions
+//!
+//! Points such as the Earth-Moon barycenter and the collinear Lagrange points
+//! of the Sun-Earth and Earth-Moon systems are not in the planetary kernels,
+//! but follow from the geocentric Sun and Moon states. They are resolved by
+//! name wherever a body identifier is accepted (`get_body`, `BodyProximity`).
+//! The solar system barycenter is not derived here; "SSB" resolves to NAIF ID
+//! 0 and is read from the kernels like any other body.
+
+use crate::utils::celestial::{calculate_moon_positions, calculate_sun_positions};
+use chrono::{DateTime, Utc};
+use ndarray::Array2;
+
+/// Moon/Earth mass ratio (DE440)
+pub const MOON_EARTH_MASS_RATIO: f64 = 0.012_300_037_1;
+
+/// Sun/(Earth+Moon) mass ratio (DE440)
+pub const SUN_EMB_MASS_RATIO: f64 = 328_900.56;
+
+/// A point resolvable as a body that is derived from the Sun and Moon states
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PseudoBody {
+    /// Earth-Moon barycenter
+    EarthMoonBarycenter,
+    /// Sun-Earth L1, between the Sun and the Earth-Moon barycenter
+    SunEarthL1,
+    /// Sun-Earth L2, beyond the Earth-Moon barycenter away from the Sun
+    SunEarthL2,
+    /// Earth-Moon L1, between the Earth and the Moon
+    EarthMoonL1,
+    /// Earth-Moon L2, beyond the Moon away from the Earth
+    EarthMoonL2,
+}
+
+impl PseudoBody {
+    /// Look up a pseudo-body by name, case-insensitively
+    pub fn parse(identifier: &str) -> Option<Self> {
+        match identifier.trim().to_ascii_lowercase().as_str() {
+            "emb" | "earth-moon barycenter" | "earth moon barycenter" => {
+                Some(PseudoBody::EarthMoonBarycenter)
+            }
+            "sel1" | "sun-earth l1" => Some(PseudoBody::SunEarthL1),
+            "sel2" | "sun-earth l2" => Some(PseudoBody::SunEarthL2),
+            "eml1" | "earth-moon l1" => Some(PseudoBody::EarthMoonL1),
+            "eml2" | "earth-moon l2" => Some(PseudoBody::EarthMoonL2),
+            _ => None,
+        }
+    }
+}
+
+/// Distance of a collinear Lagrange point from the smaller primary, as a
+/// fraction of the primary separation
+///
+/// Solves the quintic of the circular restricted three-body problem by Newton
+/// iteration, starting from the Hill-sphere estimate (mu/3)^(1/3).
+///
+/// # Arguments
+/// * `mu` - Mass of the smaller primary over the total mass
+/// * `beyond` - false for L1 (between the primaries), true for L2
+pub fn collinear_lagrange_fraction(mu: f64, beyond: bool) -> f64 {
+    let s = if beyond { 1.0 } else { -1.0 };
+    let mut gamma = (mu / 3.0).cbrt();
+    for _ in 0..50 {
+        let g2 = gamma * gamma;
+        let g3 = g2 * gamma;
+        let g4 = g3 * gamma;
+        let f = g4 * gamma + s * (3.0 - mu) * g4 + (3.0 - 2.0 * mu) * g3
+            - mu * g2
+            - s * 2.0 * mu * gamma
+            - mu;
+        let df = 5.0 * g4 + s * 4.0 * (3.0 - mu) * g3 + 3.0 * (3.0 - 2.0 * mu) * g2
+            - 2.0 * mu * gamma
+            - s * 2.0 * mu;
+        let step = f / df;
+        gamma -= step;
+        if step.abs() < 1e-15 {
+            break;
+        }
+    }
+    gamma
+}
+
+/// Geocentric state of a pseudo-body on the given times
+///
+/// Each state is a fixed linear combination of the geocentric Sun and Moon
+/// states, so velocities follow the same combination as positions.
+///
+/// # Returns
+/// Array2 with shape (N, 6) containing [x, y, z, vx, vy, vz] in GCRS (km, km/s)
+pub fn pseudo_body_positions(times: &[DateTime<Utc>], body: PseudoBody) -> Array2<f64> {
+    let moon = calculate_moon_positions(times);
+    let emb_fraction = MOON_EARTH_MASS_RATIO / (1.0 + MOON_EARTH_MASS_RATIO);
+
+    match body {
+        PseudoBody::EarthMoonBarycenter => moon * emb_fraction,
+        PseudoBody::SunEarthL1 | PseudoBody::SunEarthL2 => {
+            let sun = calculate_sun_positions(times);
+            let emb = moon * emb_fraction;
+            let mu = 1.0 / (1.0 + SUN_EMB_MASS_RATIO);
+            let beyond = body == PseudoBody::SunEarthL2;
+            let gamma = collinear_lagrange_fraction(mu, beyond);
+            let offset = if beyond { -gamma } else { gamma };
+            // Along the EMB->Sun line, gamma of the Sun-EMB distance from the EMB
+            &emb + &((sun - &emb) * offset)
+        }
+        PseudoBody::EarthMoonL1 | PseudoBody::EarthMoonL2 => {
+            let beyond = body == PseudoBody::EarthMoonL2;
+            let gamma = collinear_lagrange_fraction(emb_fraction, beyond);
+            let scale = if beyond { 1.0 + gamma } else { 1.0 - gamma };
+            // The Earth-Moon line passes through the geocentre
+            moon * scale
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_pseudo_body() {
+        assert_eq!(
+            PseudoBody::parse("EMB"),
+            Some(PseudoBody::EarthMoonBarycenter)
+        );
+        assert_eq!(PseudoBody::parse("sel2"), Some(PseudoBody::SunEarthL2));
+        assert_eq!(
+            PseudoBody::parse("Earth-Moon L1"),
+            Some(PseudoBody::EarthMoonL1)
+        );
+        assert_eq!(PseudoBody::parse("SSB"), None);
+        assert_eq!(PseudoBody::parse("Mars"), None);
+    }
+
+    #[test]
+    fn test_collinear_lagrange_fractions() {
+        // Sun-Earth L1/L2 lie about 0.01 AU (1.5 million km) from the EMB
+        let mu = 1.0 / (1.0 + SUN_EMB_MASS_RATIO);
+        let l1 = collinear_lagrange_fraction(mu, false);
+        let l2 = collinear_lagrange_fraction(mu, true);
+        assert!((l1 - 0.010_01).abs() < 1e-4);
+        assert!((l2 - 0.010_08).abs() < 1e-4);
+        assert!(l1 < l2);
+
+        // Earth-Moon L1 at about 0.849 and L2 at about 1.168 of the Moon's distance
+        let mu = MOON_EARTH_MASS_RATIO / (1.0 + MOON_EARTH_MASS_RATIO);
+        assert!((collinear_lagrange_fraction(mu, false) - 0.1509).abs() < 1e-3);
+        assert!((collinear_lagrange_fraction(mu, true) - 0.1679).abs() < 1e-3);
+    }
+}
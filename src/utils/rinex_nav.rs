@@ -0,0 +1,340 @@
+//! RINEX navigation file parsing (GPS and Galileo broadcast ephemerides)
+//!
+//! Supports RINEX 2.x GPS navigation files and RINEX 3.x GPS, Galileo and
+//! mixed navigation files. Records for other constellations are skipped.
+//! Each message is converted to a [`BroadcastOrbit`] which is evaluated with
+//! the standard broadcast Keplerian model.
+//!
+//! Week numbers in RINEX navigation files are continuous (Galileo weeks are
+//! aligned with GPS weeks), so message reference times are expressed directly
+//! in GPS seconds.
+
+use crate::utils::config::{GALILEO_MU, GPS_MU, GPS_SECONDS_PER_WEEK};
+use crate::utils::gnss::BroadcastOrbit;
+
+/// Width of a RINEX navigation data field (D19.12)
+const FIELD_WIDTH: usize = 19;
+
+/// GNSS constellations supported for broadcast orbit evaluation
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum GnssSystem {
+    Gps,
+    Galileo,
+}
+
+impl GnssSystem {
+    fn from_code(code: char) -> Option<Self> {
+        match code.to_ascii_uppercase() {
+            'G' => Some(GnssSystem::Gps),
+            'E' => Some(GnssSystem::Galileo),
+            _ => None,
+        }
+    }
+
+    #[allow(dead_code)]
+    pub fn code(&self) -> char {
+        match self {
+            GnssSystem::Gps => 'G',
+            GnssSystem::Galileo => 'E',
+        }
+    }
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            GnssSystem::Gps => "GPS",
+            GnssSystem::Galileo => "Galileo",
+        }
+    }
+
+    fn mu(&self) -> f64 {
+        match self {
+            GnssSystem::Gps => GPS_MU,
+            GnssSystem::Galileo => GALILEO_MU,
+        }
+    }
+}
+
+/// One broadcast navigation message
+#[derive(Debug, Clone, PartialEq)]
+pub struct NavMessage {
+    /// Satellite identifier such as "G05" or "E11"
+    pub satellite: String,
+    pub system: GnssSystem,
+    /// Week of the reference time (GPS week numbering)
+    pub week: i64,
+    /// Health word (0 = healthy)
+    pub health: u32,
+    pub orbit: BroadcastOrbit,
+}
+
+impl NavMessage {
+    /// Reference time of ephemeris in GPS seconds
+    pub fn toe_gps_seconds(&self) -> f64 {
+        self.week as f64 * GPS_SECONDS_PER_WEEK + self.orbit.toe
+    }
+
+    /// Earth-fixed position and velocity at a GPS time, in km and km/s
+    pub fn ecef_state(&self, gps_seconds: f64) -> [f64; 6] {
+        self.orbit.ecef_state(gps_seconds - self.toe_gps_seconds())
+    }
+}
+
+/// Contents of a RINEX navigation file
+#[derive(Debug, Clone)]
+pub struct RinexNav {
+    pub version: f64,
+    pub messages: Vec<NavMessage>,
+}
+
+/// Normalise a satellite identifier: "5" and "g5" become "G05", "e11" becomes "E11"
+///
+/// Bare numbers are taken as GPS PRNs.
+pub fn normalize_satellite_id(id: &str) -> Result<String, String> {
+    let id = id.trim();
+    let (code, number) = match id.chars().next() {
+        Some(c) if c.is_ascii_alphabetic() => (c.to_ascii_uppercase(), &id[1..]),
+        Some(_) => ('G', id),
+        None => return Err("Empty satellite identifier".to_string()),
+    };
+    let prn: u32 = number
+        .trim()
+        .parse()
+        .map_err(|_| format!("Invalid satellite identifier '{}'", id))?;
+    if GnssSystem::from_code(code).is_none() {
+        return Err(format!(
+            "Unsupported constellation in satellite '{}'. Only GPS (G) and Galileo (E) are supported",
+            id
+        ));
+    }
+    Ok(format!("{}{:02}", code, prn))
+}
+
+/// Parse a RINEX 2/3 navigation file
+pub fn parse_rinex_nav(text: &str) -> Result<RinexNav, String> {
+    let mut lines = text.lines();
+
+    // Header
+    let mut version: Option<f64> = None;
+    let mut default_system: Option<char> = None;
+    let mut header_done = false;
+    for line in lines.by_ref() {
+        let label = line.get(60..).unwrap_or("").trim();
+        if label.starts_with("RINEX VERSION / TYPE") {
+            version = line.get(0..9).and_then(|v| v.trim().parse().ok());
+            let file_type = line.get(20..21).unwrap_or(" ");
+            if !file_type.eq_ignore_ascii_case("N") {
+                return Err(format!(
+                    "Not a GPS/Galileo navigation file (RINEX file type '{}')",
+                    file_type.trim()
+                ));
+            }
+            default_system = line.get(40..41).and_then(|s| s.chars().next());
+        } else if label.starts_with("END OF HEADER") {
+            header_done = true;
+            break;
+        }
+    }
+    let version = version.ok_or("Missing 'RINEX VERSION / TYPE' header line")?;
+    if !header_done {
+        return Err("Missing 'END OF HEADER' in RINEX navigation file".to_string());
+    }
+    if version >= 4.0 {
+        return Err(format!(
+            "RINEX {:.2} navigation files are not supported; use RINEX 2 or 3",
+            version
+        ));
+    }
+
+    let body: Vec<&str> = lines.filter(|l| !l.trim().is_empty()).collect();
+    let messages = if version < 3.0 {
+        parse_v2_records(&body)?
+    } else {
+        parse_v3_records(&body, default_system)?
+    };
+
+    Ok(RinexNav { version, messages })
+}
+
+/// Parse a D19.12 field starting at `start`, treating blank fields as zero
+fn nav_field(line: &str, start: usize) -> Result<f64, String> {
+    let end = (start + FIELD_WIDTH).min(line.len());
+    let raw = line.get(start..end).unwrap_or("").trim();
+    if raw.is_empty() {
+        return Ok(0.0);
+    }
+    raw.replace(['D', 'd'], "E")
+        .parse::<f64>()
+        .map_err(|_| format!("Invalid RINEX navigation value '{}'", raw))
+}
+
+/// Collect the four fields of each broadcast-orbit line
+fn orbit_values(lines: &[&str], indent: usize) -> Result<Vec<f64>, String> {
+    let mut values = Vec::with_capacity(lines.len() * 4);
+    for line in lines {
+        for k in 0..4 {
+            values.push(nav_field(line, indent + k * FIELD_WIDTH)?);
+        }
+    }
+    Ok(values)
+}
+
+/// Build a message from the seven broadcast-orbit lines (28 values)
+fn build_message(satellite: String, system: GnssSystem, v: &[f64]) -> NavMessage {
+    NavMessage {
+        satellite,
+        system,
+        week: v[18] as i64,
+        health: v[21] as u32,
+        orbit: BroadcastOrbit {
+            mu: system.mu(),
+            crs: v[1],
+            delta_n: v[2],
+            mean_anomaly: v[3],
+            cuc: v[4],
+            eccentricity: v[5],
+            cus: v[6],
+            sqrt_a: v[7],
+            toe: v[8],
+            cic: v[9],
+            omega0: v[10],
+            cis: v[11],
+            inclination: v[12],
+            crc: v[13],
+            arg_perigee: v[14],
+            omega_dot: v[15],
+            idot: v[16],
+        },
+    }
+}
+
+fn parse_v2_records(lines: &[&str]) -> Result<Vec<NavMessage>, String> {
+    let mut messages = Vec::new();
+    for record in lines.chunks(8) {
+        if record.len() < 8 {
+            return Err("Truncated record in RINEX navigation file".to_string());
+        }
+        let prn: u32 = record[0]
+            .get(0..2)
+            .and_then(|p| p.trim().parse().ok())
+            .ok_or_else(|| format!("Invalid PRN in record '{}'", record[0].trim()))?;
+        let values = orbit_values(&record[1..8], 3)?;
+        messages.push(build_message(
+            format!("G{:02}", prn),
+            GnssSystem::Gps,
+            &values,
+        ));
+    }
+    Ok(messages)
+}
+
+fn parse_v3_records(
+    lines: &[&str],
+    default_system: Option<char>,
+) -> Result<Vec<NavMessage>, String> {
+    let mut messages = Vec::new();
+    let mut i = 0;
+    while i < lines.len() {
+        let line = lines[i];
+        let code = match line.chars().next() {
+            Some(c) if c.is_ascii_alphabetic() => c.to_ascii_uppercase(),
+            // RINEX 3.00 single-system files may omit the system letter
+            _ => default_system.unwrap_or('G').to_ascii_uppercase(),
+        };
+        // GLONASS and SBAS records have three continuation lines, the others seven
+        let n_lines = if matches!(code, 'R' | 'S') { 4 } else { 8 };
+        if i + n_lines > lines.len() {
+            return Err("Truncated record in RINEX navigation file".to_string());
+        }
+
+        if let Some(system) = GnssSystem::from_code(code) {
+            let prn: u32 = line
+                .get(1..3)
+                .and_then(|p| p.trim().parse().ok())
+                .ok_or_else(|| format!("Invalid satellite in record '{}'", line.trim()))?;
+            let values = orbit_values(&lines[i + 1..i + n_lines], 4)?;
+            messages.push(build_message(
+                format!("{}{:02}", code, prn),
+                system,
+                &values,
+            ));
+        }
+        i += n_lines;
+    }
+    Ok(messages)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const RINEX2_SAMPLE: &str =
+        "     2.10           N: GPS NAV DATA                         RINEX VERSION / TYPE
+XXRINEXN V2.10      AIUB                3-SEP-99 15:22      PGM / RUN BY / DATE
+                                                            END OF HEADER
+ 6 99  9  2 17 51 44.0-8.397013880310D-04-1.659827830740D-11 0.000000000000D+00
+    9.100000000000D+01 9.340625000000D+01 1.160405478400D-09 1.620923048010D-01
+    4.841014742850D-06 6.267404183750D-03 6.521120667460D-06 5.153654890060D+03
+    4.099040000000D+05-2.421438694000D-08 3.292370034600D-01-5.960464477540D-08
+    1.115416631360D+00 3.265937500000D+02 2.069587263350D+00-6.383123025550D-09
+    3.071556514090D-10 0.000000000000D+00 1.025000000000D+03 0.000000000000D+00
+    0.000000000000D+00 0.000000000000D+00 0.000000000000D+00 9.100000000000D+01
+    4.068000000000D+05 0.000000000000D+00
+";
+
+    #[test]
+    fn test_parse_rinex2_gps() {
+        let nav = parse_rinex_nav(RINEX2_SAMPLE).unwrap();
+        assert!((nav.version - 2.10).abs() < 1e-9);
+        assert_eq!(nav.messages.len(), 1);
+        let msg = &nav.messages[0];
+        assert_eq!(msg.satellite, "G06");
+        assert_eq!(msg.system, GnssSystem::Gps);
+        assert_eq!(msg.week, 1025);
+        assert_eq!(msg.health, 0);
+        assert!((msg.orbit.sqrt_a - 5153.65489006).abs() < 1e-6);
+        assert!((msg.orbit.toe - 409904.0).abs() < 1e-6);
+        assert!((msg.orbit.omega_dot + 0.638312302555e-8).abs() < 1e-18);
+
+        let state = msg.ecef_state(msg.toe_gps_seconds());
+        let r = (state[0].powi(2) + state[1].powi(2) + state[2].powi(2)).sqrt();
+        assert!((r - 26_560.0).abs() < 400.0, "radius {}", r);
+    }
+
+    #[test]
+    fn test_parse_rinex3_skips_other_systems() {
+        let header = "     3.04           N: GNSS NAV DATA    M: MIXED            RINEX VERSION / TYPE\n                                                            END OF HEADER\n";
+        let gps_body: Vec<&str> = RINEX2_SAMPLE.lines().skip(4).collect();
+        let orbit_lines: String = gps_body.iter().map(|l| format!(" {}\n", l)).collect();
+        let glonass = "R01 2024 01 01 00 15 00 1.234567890123E-05 0.000000000000E+00 0.000000000000E+00\n    1.0E+04 0.0E+00 0.0E+00 0.0E+00\n    1.0E+04 0.0E+00 0.0E+00 1.0E+00\n    1.0E+04 0.0E+00 0.0E+00 0.0E+00\n";
+        let gps = format!(
+            "G06 1999 09 02 17 51 44-8.397013880310D-04-1.659827830740D-11 0.000000000000D+00\n{}",
+            orbit_lines
+        );
+        let galileo = gps.replacen("G06", "E11", 1);
+        let text = format!("{}{}{}{}", header, glonass, gps, galileo);
+
+        let nav = parse_rinex_nav(&text).unwrap();
+        assert_eq!(nav.messages.len(), 2);
+        assert_eq!(nav.messages[0].satellite, "G06");
+        assert_eq!(nav.messages[1].satellite, "E11");
+        assert_eq!(nav.messages[1].system, GnssSystem::Galileo);
+        assert!((nav.messages[0].orbit.mu - GPS_MU).abs() < 1.0);
+        assert!((nav.messages[1].orbit.mu - GALILEO_MU).abs() < 1.0);
+        assert!((nav.messages[0].orbit.sqrt_a - nav.messages[1].orbit.sqrt_a).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_rinex4_rejected() {
+        let text = "     4.00           N: GNSS NAV DATA    M: MIXED            RINEX VERSION / TYPE\n                                                            END OF HEADER\n";
+        assert!(parse_rinex_nav(text).unwrap_err().contains("not supported"));
+    }
+
+    #[test]
+    fn test_normalize_satellite_id() {
+        assert_eq!(normalize_satellite_id("5").unwrap(), "G05");
+        assert_eq!(normalize_satellite_id("g5").unwrap(), "G05");
+        assert_eq!(normalize_satellite_id("E11").unwrap(), "E11");
+        assert!(normalize_satellite_id("R01").is_err());
+        assert!(normalize_satellite_id("").is_err());
+    }
+}
@@ -0,0 +1,211 @@
+//! Object decay dates from the satellite catalogue (SATCAT)
+//!
+//! Element sets of an object that has re-entered still propagate without
+//! error, giving trajectories that run underground. Objects fetched by NORAD
+//! ID or name are looked up in the Celestrak SATCAT, or in Space-Track's
+//! `satcat` class when the elements came from Space-Track.org, so callers can
+//! refuse to propagate past the decay date.
+//!
+//! Lookups are cached in `satcat_cache/<norad_id>.json`. A decay date never
+//! changes, so decayed objects are cached for good. Objects still in orbit,
+//! objects without a SATCAT record and failed lookups are cached too, and
+//! looked up again once the entry is older than the TLE cache TTL.
+
+use chrono::NaiveDate;
+use std::error::Error;
+use std::fs;
+use std::path::PathBuf;
+use std::time::{Duration, SystemTime};
+
+use crate::utils::cache_io;
+use crate::utils::config::{CACHE_DIR, NETWORK_TIMEOUT, SATCAT_URL, SPACETRACK_API_BASE};
+use crate::utils::tle_utils::{self, SpaceTrackCredentials};
+
+fn cache_path(norad_id: u32) -> PathBuf {
+    CACHE_DIR
+        .join("satcat_cache")
+        .join(format!("{}.json", norad_id))
+}
+
+/// Parse a `YYYY-MM-DD` decay date; null or empty means still in orbit
+fn parse_decay_value(value: Option<&serde_json::Value>) -> Result<Option<NaiveDate>, String> {
+    match value.and_then(|v| v.as_str()).map(str::trim) {
+        None | Some("") => Ok(None),
+        Some(text) => NaiveDate::parse_from_str(text.get(..10).unwrap_or(text), "%Y-%m-%d")
+            .map(Some)
+            .map_err(|e| format!("Invalid SATCAT decay date '{}': {}", text, e)),
+    }
+}
+
+/// Decay date from a Celestrak (`DECAY_DATE`) or Space-Track (`DECAY`) JSON response
+fn parse_satcat_response(body: &str, norad_id: u32) -> Result<Option<NaiveDate>, String> {
+    let records: serde_json::Value = serde_json::from_str(body)
+        .map_err(|_| format!("No SATCAT record found for NORAD ID {}", norad_id))?;
+    let record = records
+        .as_array()
+        .and_then(|records| records.first())
+        .ok_or_else(|| format!("No SATCAT record found for NORAD ID {}", norad_id))?;
+    parse_decay_value(record.get("DECAY_DATE").or_else(|| record.get("DECAY")))
+}
+
+/// Cached decay date, if the entry is decayed or younger than `ttl`
+///
+/// With no `ttl` any entry is used, however old.
+fn read_cache(norad_id: u32, ttl: Option<Duration>) -> Option<Option<NaiveDate>> {
+    let path = cache_path(norad_id);
+    let text = fs::read_to_string(&path).ok()?;
+    let decay_date = match serde_json::from_str::<serde_json::Value>(&text)
+        .map_err(|e| e.to_string())
+        .and_then(|entry| parse_decay_value(entry.get("decay_date")))
+    {
+        Ok(decay_date) => decay_date,
+        Err(_e) => {
+            #[cfg(debug_assertions)]
+            eprintln!(
+                "Removing corrupt SATCAT cache file {}: {}",
+                path.display(),
+                _e
+            );
+            let _ = fs::remove_file(&path);
+            return None;
+        }
+    };
+    let Some(ttl) = ttl.filter(|_| decay_date.is_none()) else {
+        return Some(decay_date);
+    };
+    let age = SystemTime::now()
+        .duration_since(path.metadata().and_then(|m| m.modified()).ok()?)
+        .ok()?;
+    (age <= ttl).then_some(None)
+}
+
+/// Cache a lookup; `error` records why a failed lookup found no decay date
+fn write_cache(norad_id: u32, decay_date: Option<NaiveDate>, error: Option<String>) {
+    let entry = serde_json::json!({
+        "norad_id": norad_id,
+        "decay_date": decay_date.map(|date| date.format("%Y-%m-%d").to_string()),
+        "error": error,
+    });
+    if let Err(_e) = cache_io::write_atomic(&cache_path(norad_id), entry.to_string().as_bytes()) {
+        #[cfg(debug_assertions)]
+        eprintln!("Warning: Failed to write SATCAT cache: {}", _e);
+    }
+}
+
+fn query_celestrak(norad_id: u32) -> Result<String, Box<dyn Error>> {
+    let agent: ureq::Agent = ureq::Agent::config_builder()
+        .timeout_global(Some(*NETWORK_TIMEOUT))
+        .build()
+        .into();
+    let url = format!("{}?CATNR={}&FORMAT=JSON", *SATCAT_URL, norad_id);
+    Ok(agent.get(&url).call()?.body_mut().read_to_string()?)
+}
+
+fn query_spacetrack(
+    norad_id: u32,
+    credentials: &SpaceTrackCredentials,
+) -> Result<String, Box<dyn Error>> {
+    let agent = tle_utils::create_spacetrack_agent(credentials)?;
+    let url = format!(
+        "{}/basicspacedata/query/class/satcat/NORAD_CAT_ID/{}/format/json",
+        *SPACETRACK_API_BASE, norad_id
+    );
+    Ok(agent.get(&url).call()?.body_mut().read_to_string()?)
+}
+
+/// Cached decay date of `norad_id`, without querying the SATCAT
+///
+/// Used when the TLE itself came from the cache, so that working from cached
+/// data never touches the network.
+pub fn cached_decay_date(norad_id: u32) -> Option<NaiveDate> {
+    read_cache(norad_id, None).flatten()
+}
+
+/// Date on which `norad_id` decayed, or `None` if it is still in orbit
+///
+/// Space-Track.org is queried when `credentials` are given and Celestrak
+/// otherwise. Entries without a decay date older than `cache_ttl` seconds
+/// (default: the TLE cache TTL) are looked up again. A failed lookup is
+/// cached as well, so it is not retried before the TTL runs out.
+pub fn decay_date(
+    norad_id: u32,
+    credentials: Option<&SpaceTrackCredentials>,
+    cache_ttl: Option<u64>,
+) -> Result<Option<NaiveDate>, Box<dyn Error>> {
+    let ttl = Duration::from_secs(cache_ttl.unwrap_or_else(tle_utils::default_cache_ttl));
+    if let Some(decay_date) = read_cache(norad_id, Some(ttl)) {
+        return Ok(decay_date);
+    }
+    let lookup = match credentials {
+        Some(credentials) => query_spacetrack(norad_id, credentials),
+        None => query_celestrak(norad_id),
+    }
+    .and_then(|body| Ok(parse_satcat_response(&body, norad_id)?));
+    match lookup {
+        Ok(decay_date) => {
+            write_cache(norad_id, decay_date, None);
+            Ok(decay_date)
+        }
+        Err(err) => {
+            write_cache(norad_id, None, Some(err.to_string()));
+            Err(err)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_celestrak_response() {
+        let body = r#"[{"OBJECT_NAME":"TIANGONG 1","NORAD_CAT_ID":37820,
+            "LAUNCH_DATE":"2011-09-29","DECAY_DATE":"2018-04-02"}]"#;
+        assert_eq!(
+            parse_satcat_response(body, 37820).unwrap(),
+            NaiveDate::from_ymd_opt(2018, 4, 2)
+        );
+        let body = r#"[{"OBJECT_NAME":"ISS (ZARYA)","NORAD_CAT_ID":25544,"DECAY_DATE":null}]"#;
+        assert_eq!(parse_satcat_response(body, 25544).unwrap(), None);
+    }
+
+    #[test]
+    fn test_parse_spacetrack_response() {
+        let body = r#"[{"NORAD_CAT_ID":"37820","DECAY":"2018-04-02"}]"#;
+        assert_eq!(
+            parse_satcat_response(body, 37820).unwrap(),
+            NaiveDate::from_ymd_opt(2018, 4, 2)
+        );
+        let body = r#"[{"NORAD_CAT_ID":"25544","DECAY":""}]"#;
+        assert_eq!(parse_satcat_response(body, 25544).unwrap(), None);
+    }
+
+    #[test]
+    fn test_cache_entries() {
+        // Fake NORAD IDs that never have real SATCAT entries
+        let (failed, decayed) = (99_991, 99_990);
+        let hour = Duration::from_secs(3600);
+        write_cache(failed, None, Some("offline".to_string()));
+        assert_eq!(read_cache(failed, Some(hour)), Some(None));
+        assert_eq!(cached_decay_date(failed), None);
+
+        let date = NaiveDate::from_ymd_opt(2018, 4, 2);
+        write_cache(decayed, date, None);
+        assert_eq!(read_cache(decayed, Some(Duration::ZERO)), Some(date));
+        assert_eq!(cached_decay_date(decayed), date);
+
+        for norad_id in [failed, decayed] {
+            fs::remove_file(cache_path(norad_id)).unwrap();
+        }
+        assert_eq!(read_cache(failed, None), None);
+    }
+
+    #[test]
+    fn test_parse_missing_record() {
+        let error = parse_satcat_response("No SATCAT records found", 99999).unwrap_err();
+        assert!(error.contains("99999"), "{error}");
+        assert!(parse_satcat_response("[]", 99999).is_err());
+        let body = r#"[{"DECAY_DATE":"April 2018"}]"#;
+        assert!(parse_satcat_response(body, 99999).is_err());
+    }
+}
@@ -0,0 +1,168 @@
+//! Target name resolution through the CDS Sesame service
+//!
+//! Sesame resolves object names ("M31", "Vega", "NGC 1976") through SIMBAD,
+//! NED and VizieR. Resolved ICRS coordinates are cached in memory and in
+//! `sesame_names.json` in the cache directory, so each name only hits the
+//! network once. The file is rewritten atomically under a lock, merging names
+//! resolved by other processes, and invalid entries are ignored when read.
+
+use once_cell::sync::Lazy;
+use std::collections::HashMap;
+use std::sync::Mutex;
+
+use crate::utils::cache_io;
+use crate::utils::config::{CACHE_DIR, NETWORK_TIMEOUT, SESAME_URL};
+
+/// Resolved (RA, Dec) in degrees keyed by normalized name
+static NAME_CACHE: Lazy<Mutex<HashMap<String, (f64, f64)>>> =
+    Lazy::new(|| Mutex::new(load_disk_cache().unwrap_or_default()));
+
+fn disk_cache_path() -> std::path::PathBuf {
+    CACHE_DIR.join("sesame_names.json")
+}
+
+/// Names cached on disk; a corrupt file is removed so names are resolved again
+fn load_disk_cache() -> Option<HashMap<String, (f64, f64)>> {
+    let path = disk_cache_path();
+    let text = std::fs::read_to_string(&path).ok()?;
+    match serde_json::from_str::<HashMap<String, (f64, f64)>>(&text) {
+        Ok(names) => Some(
+            names
+                .into_iter()
+                .filter(|(_, (ra, dec))| valid_coordinates(*ra, *dec))
+                .collect(),
+        ),
+        Err(_e) => {
+            #[cfg(debug_assertions)]
+            eprintln!("Removing corrupt name cache {}: {}", path.display(), _e);
+            let _ = std::fs::remove_file(&path);
+            None
+        }
+    }
+}
+
+fn valid_coordinates(ra: f64, dec: f64) -> bool {
+    (0.0..=360.0).contains(&ra) && (-90.0..=90.0).contains(&dec)
+}
+
+/// Merge names cached by other processes into `cache` and write it back
+fn save_disk_cache(cache: &mut HashMap<String, (f64, f64)>) -> std::io::Result<()> {
+    let path = disk_cache_path();
+    let _lock = cache_io::lock(&path)?;
+    for (key, coords) in load_disk_cache().unwrap_or_default() {
+        cache.entry(key).or_insert(coords);
+    }
+    let text = serde_json::to_string(&*cache).map_err(std::io::Error::other)?;
+    cache_io::write_atomic(&path, text.as_bytes())
+}
+
+/// Cache key: lowercase with runs of whitespace collapsed
+fn normalize(name: &str) -> String {
+    name.split_whitespace()
+        .collect::<Vec<_>>()
+        .join(" ")
+        .to_lowercase()
+}
+
+/// Extract ICRS J2000 coordinates from a Sesame plain-text response
+///
+/// The first `%J <ra> <dec>` line (decimal degrees) wins; Sesame lists the
+/// resolvers in the order they were queried.
+fn parse_sesame_response(body: &str) -> Option<(f64, f64)> {
+    body.lines().find_map(|line| {
+        let mut fields = line.strip_prefix("%J ")?.split_whitespace();
+        let ra = fields.next()?.parse().ok()?;
+        let dec = fields.next()?.parse().ok()?;
+        Some((ra, dec))
+    })
+}
+
+fn query_sesame(name: &str) -> Result<(f64, f64), String> {
+    let mut url = url::Url::parse(&SESAME_URL).map_err(|e| e.to_string())?;
+    url.set_query(Some(name));
+    let agent: ureq::Agent = ureq::Agent::config_builder()
+        .timeout_global(Some(*NETWORK_TIMEOUT))
+        .build()
+        .into();
+    let body = agent
+        .get(url.as_str())
+        .call()
+        .map_err(|e| format!("Sesame request failed: {}", e))?
+        .into_body()
+        .read_to_string()
+        .map_err(|e| format!("Failed to read Sesame response: {}", e))?;
+    parse_sesame_response(&body).ok_or_else(|| format!("Sesame could not resolve '{}'", name))
+}
+
+/// Resolve an object name to ICRS (RA, Dec) in degrees
+///
+/// Cached names are returned without network access. Otherwise Sesame is
+/// queried, unless `allow_network` is false, in which case an error is returned.
+pub fn resolve_name(name: &str, allow_network: bool) -> Result<(f64, f64), String> {
+    let key = normalize(name);
+    if key.is_empty() {
+        return Err("Target name must not be empty".to_string());
+    }
+    let cached = NAME_CACHE.lock().ok().and_then(|c| c.get(&key).copied());
+    if let Some(coords) = cached {
+        return Ok(coords);
+    }
+    if !allow_network {
+        return Err(format!(
+            "'{}' is not in the name cache and network access is disabled",
+            name
+        ));
+    }
+    let coords = query_sesame(name.trim())?;
+    if let Ok(mut cache) = NAME_CACHE.lock() {
+        cache.insert(key, coords);
+        // The disk cache is best effort; resolution already succeeded
+        let _ = save_disk_cache(&mut cache);
+    }
+    Ok(coords)
+}
+
+/// Clear the in-memory and on-disk name cache
+pub fn clear_name_cache() {
+    if let Ok(mut cache) = NAME_CACHE.lock() {
+        cache.clear();
+    }
+    let path = disk_cache_path();
+    let _lock = cache_io::lock(&path);
+    let _ = std::fs::remove_file(&path);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const M31_RESPONSE: &str = "# M31\t#Q22583744
+#=Si=Simbad (via url):    1
+%@ 503731
+%I.0 M  31
+%C.0 AGN
+%J 010.684708 +41.268750 = 00:42:44.33 +41:16:07.5
+%J.E [1.36 1.36 0] 2006AJ....131.1163S
+%V z -0.00100 [0.00000] 2008ApJ...676..184T
+#====Done (2024-Jan-01,00:00:00z)====
+";
+
+    #[test]
+    fn test_parse_sesame_response() {
+        let (ra, dec) = parse_sesame_response(M31_RESPONSE).unwrap();
+        assert!((ra - 10.684708).abs() < 1e-9);
+        assert!((dec - 41.26875).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_parse_sesame_response_unresolved() {
+        let body = "# NotAnObject\t#Q1\n#! *** Nothing found *** \n#====Done====\n";
+        assert_eq!(parse_sesame_response(body), None);
+    }
+
+    #[test]
+    fn test_normalize() {
+        assert_eq!(normalize("  NGC   1976 "), "ngc 1976");
+        assert_eq!(normalize("M31"), normalize("m31"));
+    }
+}
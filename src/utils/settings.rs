@@ -0,0 +1,471 @@
+//! User configuration from a TOML file and environment variables
+//!
+//! Settings are read once, on first use, from the file named by
+//! `RUST_EPHEM_CONFIG`, or else from `config.toml` in the cache directory
+//! (`~/.cache/rust_ephem` unless `RUST_EPHEM_CACHE_DIR` is set). Every key can
+//! be overridden by a `RUST_EPHEM_<SECTION>_<KEY>` environment variable, which
+//! may also come from a `.env` file in the working or home directory. Keys left
+//! unset keep their built-in defaults:
+//!
+//! ```toml
+//! [cache]
+//! dir = "~/.cache/rust_ephem"     # RUST_EPHEM_CACHE_DIR
+//! eop_ttl = 86400                 # seconds
+//! space_weather_ttl = 86400       # seconds
+//! tle_ttl = 86400                 # seconds
+//! tle_max_entries = 10
+//! spacetrack_max_entries = 1000
+//!
+//! [kernels]
+//! de440s_path = "/data/de440s.bsp"  # default: <cache dir>/de440s.bsp
+//! de440_path = "/data/de440.bsp"    # default: <cache dir>/de440.bsp
+//! de440s_url = "https://..."
+//! de440_url = "https://..."
+//!
+//! [network]
+//! timeout = 30                    # seconds, for TLE, Space-Track and Sesame requests
+//! eop2_url = "https://..."
+//! space_weather_url = "https://..."
+//! celestrak_url = "https://..."
+//! satcat_url = "https://..."
+//! sesame_url = "https://..."
+//! horizons_url = "https://..."
+//! spacetrack_url = "https://..."
+//!
+//! [spacetrack]
+//! username = "..."                # also SPACETRACK_USERNAME
+//! password = "..."                # also SPACETRACK_PASSWORD
+//! epoch_tolerance_days = 4.0      # also SPACETRACK_EPOCH_TOLERANCE_DAYS
+//!
+//! [tle]
+//! max_epoch_offset_days = 14.0
+//! check_decay = true              # look up SATCAT decay dates of fetched TLEs
+//! ```
+
+use once_cell::sync::Lazy;
+use serde::{Deserialize, Serialize};
+use std::env;
+use std::path::{Path, PathBuf};
+
+/// Environment variable naming the configuration file
+pub const CONFIG_ENV_VAR: &str = "RUST_EPHEM_CONFIG";
+
+/// Configuration file looked up in the cache directory
+pub const CONFIG_FILE_NAME: &str = "config.toml";
+
+/// Local storage of downloaded and derived data
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct CacheSettings {
+    /// Directory for kernels, EOP data, TLEs and resolved names
+    pub dir: PathBuf,
+    /// Seconds before the cached EOP2 file is downloaded again
+    pub eop_ttl: u64,
+    /// Seconds before the cached space-weather file is downloaded again
+    pub space_weather_ttl: u64,
+    /// Seconds before cached Celestrak and URL TLEs are downloaded again
+    pub tle_ttl: u64,
+    /// Cached TLEs kept per Celestrak query or URL
+    pub tle_max_entries: usize,
+    /// Cached Space-Track TLEs kept per NORAD ID
+    pub spacetrack_max_entries: usize,
+}
+
+impl Default for CacheSettings {
+    fn default() -> Self {
+        CacheSettings {
+            dir: default_cache_dir(),
+            eop_ttl: 86_400,
+            space_weather_ttl: 86_400,
+            tle_ttl: 86_400,
+            tle_max_entries: 10,
+            spacetrack_max_entries: 1000,
+        }
+    }
+}
+
+/// Default planetary kernels
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct KernelSettings {
+    /// Local DE440s path; `None` keeps it in the cache directory
+    pub de440s_path: Option<PathBuf>,
+    /// Local DE440 path; `None` keeps it in the cache directory
+    pub de440_path: Option<PathBuf>,
+    pub de440s_url: String,
+    pub de440_url: String,
+}
+
+impl Default for KernelSettings {
+    fn default() -> Self {
+        KernelSettings {
+            de440s_path: None,
+            de440_path: None,
+            de440s_url: "https://naif.jpl.nasa.gov/pub/naif/generic_kernels/spk/planets/de440s.bsp"
+                .to_string(),
+            de440_url: "https://naif.jpl.nasa.gov/pub/naif/generic_kernels/spk/planets/de440.bsp"
+                .to_string(),
+        }
+    }
+}
+
+/// Remote services and request limits
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct NetworkSettings {
+    /// Timeout in seconds for TLE, Space-Track and Sesame requests
+    pub timeout: u64,
+    pub eop2_url: String,
+    /// Celestrak space-weather CSV (Kp, Ap and F10.7 since 1957)
+    pub space_weather_url: String,
+    /// Celestrak GP TLE API endpoint
+    pub celestrak_url: String,
+    /// Celestrak SATCAT records endpoint, for object decay dates
+    pub satcat_url: String,
+    /// CDS Sesame name resolver (plain-text output, all resolvers)
+    pub sesame_url: String,
+    pub horizons_url: String,
+    /// Space-Track.org base URL; the API and login paths are appended
+    pub spacetrack_url: String,
+}
+
+impl Default for NetworkSettings {
+    fn default() -> Self {
+        NetworkSettings {
+            timeout: 30,
+            eop2_url: "https://eop2-external.jpl.nasa.gov/eop2/latest_eop2.short".to_string(),
+            space_weather_url: "https://celestrak.org/SpaceData/SW-All.csv".to_string(),
+            celestrak_url: "https://celestrak.org/NORAD/elements/gp.php".to_string(),
+            satcat_url: "https://celestrak.org/satcat/records.php".to_string(),
+            sesame_url: "https://cds.unistra.fr/cgi-bin/nph-sesame/-oI/SNV".to_string(),
+            horizons_url: "https://ssd.jpl.nasa.gov/api/horizons.api".to_string(),
+            spacetrack_url: "https://www.space-track.org".to_string(),
+        }
+    }
+}
+
+/// Space-Track.org account and TLE cache matching
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct SpaceTrackSettings {
+    pub username: Option<String>,
+    pub password: Option<String>,
+    /// How far (days) a cached TLE epoch may be from the requested epoch
+    pub epoch_tolerance_days: f64,
+}
+
+impl Default for SpaceTrackSettings {
+    fn default() -> Self {
+        SpaceTrackSettings {
+            username: None,
+            password: None,
+            epoch_tolerance_days: 4.0,
+        }
+    }
+}
+
+/// TLE propagation checks
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct TleSettings {
+    /// Distance (days) from the TLE epoch beyond which propagation warns
+    pub max_epoch_offset_days: f64,
+    /// Look up the SATCAT decay date of TLEs fetched by NORAD ID or name
+    pub check_decay: bool,
+}
+
+impl Default for TleSettings {
+    fn default() -> Self {
+        TleSettings {
+            max_epoch_offset_days: 14.0,
+            check_decay: true,
+        }
+    }
+}
+
+/// All user-configurable settings
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct Settings {
+    pub cache: CacheSettings,
+    pub kernels: KernelSettings,
+    pub network: NetworkSettings,
+    pub spacetrack: SpaceTrackSettings,
+    pub tle: TleSettings,
+}
+
+/// Settings in effect and the file they were read from
+#[derive(Debug, Clone)]
+pub struct LoadedSettings {
+    pub settings: Settings,
+    pub path: Option<PathBuf>,
+}
+
+/// Value type of a key, used to parse environment overrides
+#[derive(Debug, Clone, Copy)]
+enum Kind {
+    Text,
+    Integer,
+    Float,
+    Bool,
+}
+
+/// Keys that can be overridden by `RUST_EPHEM_<SECTION>_<KEY>`
+const ENV_KEYS: &[(&str, &str, Kind)] = &[
+    ("cache", "dir", Kind::Text),
+    ("cache", "eop_ttl", Kind::Integer),
+    ("cache", "space_weather_ttl", Kind::Integer),
+    ("cache", "tle_ttl", Kind::Integer),
+    ("cache", "tle_max_entries", Kind::Integer),
+    ("cache", "spacetrack_max_entries", Kind::Integer),
+    ("kernels", "de440s_path", Kind::Text),
+    ("kernels", "de440_path", Kind::Text),
+    ("kernels", "de440s_url", Kind::Text),
+    ("kernels", "de440_url", Kind::Text),
+    ("network", "timeout", Kind::Integer),
+    ("network", "eop2_url", Kind::Text),
+    ("network", "space_weather_url", Kind::Text),
+    ("network", "celestrak_url", Kind::Text),
+    ("network", "satcat_url", Kind::Text),
+    ("network", "sesame_url", Kind::Text),
+    ("network", "horizons_url", Kind::Text),
+    ("network", "spacetrack_url", Kind::Text),
+    ("spacetrack", "username", Kind::Text),
+    ("spacetrack", "password", Kind::Text),
+    ("spacetrack", "epoch_tolerance_days", Kind::Float),
+    ("tle", "max_epoch_offset_days", Kind::Float),
+    ("tle", "check_decay", Kind::Bool),
+];
+
+/// Older variable names, consulted after `RUST_EPHEM_<SECTION>_<KEY>`
+const LEGACY_ENV_VARS: &[(&str, &str, &str)] = &[
+    ("spacetrack", "username", "SPACETRACK_USERNAME"),
+    ("spacetrack", "password", "SPACETRACK_PASSWORD"),
+    (
+        "spacetrack",
+        "epoch_tolerance_days",
+        "SPACETRACK_EPOCH_TOLERANCE_DAYS",
+    ),
+];
+
+/// Settings loaded on first use
+///
+/// An unreadable or invalid configuration is reported on stderr and the
+/// built-in defaults are used instead, so a bad file never prevents import.
+pub static SETTINGS: Lazy<LoadedSettings> = Lazy::new(|| {
+    // .env files may hold credentials and overrides (silently ignored if not found)
+    let _ = dotenvy::dotenv();
+    if let Some(home_dir) = dirs::home_dir() {
+        let _ = dotenvy::from_path(home_dir.join(".env"));
+    }
+
+    let var = |name: &str| env::var(name).ok();
+    let path = config_file_path(var);
+    match load(path.as_deref(), var) {
+        Ok(settings) => LoadedSettings { settings, path },
+        Err(e) => {
+            eprintln!("Ignoring invalid rust_ephem configuration: {e}");
+            LoadedSettings {
+                settings: Settings::default(),
+                path: None,
+            }
+        }
+    }
+});
+
+/// `~/.cache/rust_ephem`, or the working directory if there is no home directory
+fn default_cache_dir() -> PathBuf {
+    match dirs::home_dir() {
+        Some(home) => home.join(".cache").join("rust_ephem"),
+        None => env::current_dir().unwrap_or_else(|_| PathBuf::from(".")),
+    }
+}
+
+/// Replace a leading `~` with the home directory
+fn expand_home(path: &Path) -> PathBuf {
+    match (path.strip_prefix("~"), dirs::home_dir()) {
+        (Ok(rest), Some(home)) => home.join(rest),
+        _ => path.to_path_buf(),
+    }
+}
+
+/// The file named by `RUST_EPHEM_CONFIG`, else `config.toml` in the cache directory if present
+fn config_file_path(var: impl Fn(&str) -> Option<String>) -> Option<PathBuf> {
+    if let Some(path) = var(CONFIG_ENV_VAR) {
+        return Some(expand_home(Path::new(&path)));
+    }
+    let dir = var("RUST_EPHEM_CACHE_DIR")
+        .map(|dir| expand_home(Path::new(&dir)))
+        .unwrap_or_else(default_cache_dir);
+    let path = dir.join(CONFIG_FILE_NAME);
+    path.is_file().then_some(path)
+}
+
+/// Read `path` (if any), apply environment overrides and fill in defaults
+fn load(path: Option<&Path>, var: impl Fn(&str) -> Option<String>) -> Result<Settings, String> {
+    let mut table = match path {
+        Some(path) => std::fs::read_to_string(path)
+            .map_err(|e| e.to_string())
+            .and_then(|text| text.parse::<toml::Table>().map_err(|e| e.to_string()))
+            .map_err(|e| format!("{}: {}", path.display(), e))?,
+        None => toml::Table::new(),
+    };
+
+    for &(section, key, kind) in ENV_KEYS {
+        let names = std::iter::once(format!("RUST_EPHEM_{}_{}", section, key).to_uppercase())
+            .chain(
+                LEGACY_ENV_VARS
+                    .iter()
+                    .filter(|(s, k, _)| *s == section && *k == key)
+                    .map(|(_, _, name)| name.to_string()),
+            );
+        let Some((name, raw)) = names
+            .into_iter()
+            .find_map(|name| var(&name).map(|raw| (name, raw)))
+        else {
+            continue;
+        };
+        let value = match kind {
+            Kind::Text => Some(toml::Value::String(raw.clone())),
+            Kind::Integer => raw.trim().parse().ok().map(toml::Value::Integer),
+            Kind::Float => raw.trim().parse().ok().map(toml::Value::Float),
+            Kind::Bool => match raw.trim().to_lowercase().as_str() {
+                "1" | "true" | "yes" | "on" => Some(toml::Value::Boolean(true)),
+                "0" | "false" | "no" | "off" => Some(toml::Value::Boolean(false)),
+                _ => None,
+            },
+        }
+        .ok_or_else(|| match kind {
+            Kind::Bool => format!("{} must be true or false, got '{}'", name, raw),
+            _ => format!("{} must be a number, got '{}'", name, raw),
+        })?;
+        table
+            .entry(section)
+            .or_insert(toml::Value::Table(toml::Table::new()))
+            .as_table_mut()
+            .ok_or_else(|| format!("[{}] must be a table", section))?
+            .insert(key.to_string(), value);
+    }
+
+    let mut settings: Settings = toml::Value::Table(table)
+        .try_into()
+        .map_err(|e: toml::de::Error| e.to_string())?;
+    settings.cache.dir = expand_home(&settings.cache.dir);
+    for path in [
+        &mut settings.kernels.de440s_path,
+        &mut settings.kernels.de440_path,
+    ]
+    .into_iter()
+    .flatten()
+    {
+        *path = expand_home(path);
+    }
+    Ok(settings)
+}
+
+/// Settings in effect as JSON, with the password redacted, plus the file path
+pub fn settings_summary() -> serde_json::Value {
+    let mut settings = SETTINGS.settings.clone();
+    if settings.spacetrack.password.is_some() {
+        settings.spacetrack.password = Some("<redacted>".to_string());
+    }
+    let mut summary = serde_json::to_value(&settings).unwrap_or_default();
+    if let Some(map) = summary.as_object_mut() {
+        map.insert(
+            "config_path".to_string(),
+            SETTINGS
+                .path
+                .as_ref()
+                .map(|p| p.to_string_lossy().to_string())
+                .into(),
+        );
+    }
+    summary
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashMap;
+
+    fn vars(pairs: &[(&str, &str)]) -> impl Fn(&str) -> Option<String> {
+        let map: HashMap<String, String> = pairs
+            .iter()
+            .map(|(k, v)| (k.to_string(), v.to_string()))
+            .collect();
+        move |name| map.get(name).cloned()
+    }
+
+    fn write_config(name: &str, text: &str) -> PathBuf {
+        let path = env::temp_dir().join(format!("rust_ephem_{}_{}.toml", name, std::process::id()));
+        std::fs::write(&path, text).unwrap();
+        path
+    }
+
+    #[test]
+    fn test_defaults() {
+        assert_eq!(load(None, vars(&[])).unwrap(), Settings::default());
+    }
+
+    #[test]
+    fn test_file_and_env_precedence() {
+        let path = write_config(
+            "precedence",
+            "[cache]\neop_ttl = 60\n\n[spacetrack]\nusername = \"file\"\nepoch_tolerance_days = 2\n",
+        );
+        let settings = load(
+            Some(&path),
+            vars(&[
+                ("RUST_EPHEM_SPACETRACK_USERNAME", "env"),
+                ("SPACETRACK_USERNAME", "legacy"),
+                ("SPACETRACK_PASSWORD", "secret"),
+                ("RUST_EPHEM_TLE_MAX_EPOCH_OFFSET_DAYS", "3.5"),
+                ("RUST_EPHEM_TLE_CHECK_DECAY", "false"),
+            ]),
+        )
+        .unwrap();
+        std::fs::remove_file(&path).unwrap();
+
+        assert_eq!(settings.cache.eop_ttl, 60);
+        assert_eq!(settings.cache.tle_ttl, 86_400);
+        assert_eq!(settings.spacetrack.username.as_deref(), Some("env"));
+        assert_eq!(settings.spacetrack.password.as_deref(), Some("secret"));
+        assert_eq!(settings.spacetrack.epoch_tolerance_days, 2.0);
+        assert_eq!(settings.tle.max_epoch_offset_days, 3.5);
+        assert!(!settings.tle.check_decay);
+    }
+
+    #[test]
+    fn test_invalid_values() {
+        let err = load(None, vars(&[("RUST_EPHEM_NETWORK_TIMEOUT", "soon")])).unwrap_err();
+        assert!(err.contains("RUST_EPHEM_NETWORK_TIMEOUT"), "{}", err);
+        let err = load(None, vars(&[("RUST_EPHEM_TLE_CHECK_DECAY", "maybe")])).unwrap_err();
+        assert!(err.contains("true or false"), "{}", err);
+
+        let path = write_config("unknown", "[cache]\nttl = 5\n");
+        let err = load(Some(&path), vars(&[])).unwrap_err();
+        std::fs::remove_file(&path).unwrap();
+        assert!(err.contains("ttl"), "{}", err);
+    }
+
+    #[test]
+    fn test_config_file_path() {
+        assert_eq!(
+            config_file_path(vars(&[(CONFIG_ENV_VAR, "/etc/rust_ephem.toml")])),
+            Some(PathBuf::from("/etc/rust_ephem.toml"))
+        );
+        let dir = env::temp_dir().join(format!("rust_ephem_cfg_{}", std::process::id()));
+        let dir_str = dir.to_string_lossy().to_string();
+        std::fs::create_dir_all(&dir).unwrap();
+        assert_eq!(
+            config_file_path(vars(&[("RUST_EPHEM_CACHE_DIR", dir_str.as_str())])),
+            None
+        );
+        std::fs::write(dir.join(CONFIG_FILE_NAME), "").unwrap();
+        assert_eq!(
+            config_file_path(vars(&[("RUST_EPHEM_CACHE_DIR", dir_str.as_str())])),
+            Some(dir.join(CONFIG_FILE_NAME))
+        );
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+}
@@ -0,0 +1,242 @@
+//! Observatory and ground-station site database
+//!
+//! Bundles geodetic (WGS 84) coordinates for major optical and radio
+//! observatories, keyed by name and IAU/MPC observatory code, together with
+//! the NASA Deep Space Network and ESA ESTRACK tracking stations. Coordinates
+//! are representative of each site (typically within ~100 m), which is ample
+//! for visibility and contact planning but not for precise geodesy.
+//!
+//! Lookups ignore case, spaces, hyphens and other punctuation, so
+//! "Cerro Paranal", "paranal" and "309" all resolve to the same site. The
+//! registry can be extended or overridden at runtime with [`register_site`].
+
+use std::collections::HashMap;
+use std::sync::RwLock;
+
+use once_cell::sync::Lazy;
+
+/// A named ground site
+#[derive(Debug, Clone, PartialEq)]
+pub struct Site {
+    pub name: String,
+    /// IAU/MPC observatory code, if the site has one
+    pub code: Option<String>,
+    /// Geodetic latitude (degrees)
+    pub latitude: f64,
+    /// Geodetic longitude (degrees, east positive)
+    pub longitude: f64,
+    /// Height above the WGS 84 ellipsoid (metres)
+    pub height: f64,
+    /// Alternative names the site can be looked up by
+    pub aliases: Vec<String>,
+}
+
+/// (name, code, latitude, longitude, height, aliases)
+type BundledSite = (
+    &'static str,
+    Option<&'static str>,
+    f64,
+    f64,
+    f64,
+    &'static [&'static str],
+);
+
+#[rustfmt::skip]
+const BUNDLED_SITES: &[BundledSite] = &[
+    // Optical and infrared observatories
+    ("Greenwich", Some("000"), 51.4769, -0.0005, 46.0, &["Royal Observatory Greenwich"]),
+    ("Las Campanas", Some("304"), -29.0146, -70.6926, 2380.0, &["LCO", "Magellan"]),
+    ("Paranal", Some("309"), -24.6272, -70.4042, 2635.0, &["Cerro Paranal", "VLT", "ESO Paranal"]),
+    ("Siding Spring", Some("413"), -31.2733, 149.0611, 1165.0, &["SSO", "AAT"]),
+    ("Calar Alto", Some("493"), 37.2236, -2.5463, 2168.0, &["CAHA"]),
+    ("Haleakala", Some("566"), 20.7083, -156.2571, 3055.0, &["Pan-STARRS"]),
+    ("Mauna Kea", Some("568"), 19.8207, -155.4681, 4205.0, &["Maunakea", "Keck", "Subaru", "Gemini North"]),
+    ("Lick", Some("662"), 37.3414, -121.6429, 1283.0, &["Lick Observatory", "Mount Hamilton"]),
+    ("Palomar", Some("675"), 33.3563, -116.8650, 1712.0, &["Palomar Mountain", "ZTF"]),
+    ("Kitt Peak", Some("695"), 31.9583, -111.5967, 2096.0, &["KPNO"]),
+    ("Apache Point", Some("705"), 32.7803, -105.8203, 2788.0, &["APO", "SDSS"]),
+    ("McDonald", Some("711"), 30.6717, -104.0217, 2076.0, &["McDonald Observatory", "HET"]),
+    ("Cerro Tololo", Some("807"), -30.1690, -70.8063, 2207.0, &["CTIO"]),
+    ("La Silla", Some("809"), -29.2567, -70.7300, 2400.0, &["ESO La Silla"]),
+    ("Roque de los Muchachos", Some("950"), 28.7606, -17.8814, 2326.0, &["La Palma", "ORM", "GTC"]),
+    ("Teide", Some("954"), 28.3008, -16.5122, 2390.0, &["Observatorio del Teide"]),
+    ("Mount Graham", Some("G83"), 32.7016, -109.8719, 3221.0, &["LBT", "Mt Graham"]),
+    ("Gemini South", Some("I11"), -30.2408, -70.7367, 2722.0, &["Cerro Pachon"]),
+    ("Rubin", Some("X05"), -30.2444, -70.7494, 2663.0, &["Rubin Observatory", "LSST", "Vera Rubin"]),
+    ("Sutherland", None, -32.3794, 20.8107, 1798.0, &["SAAO", "SALT"]),
+    // Radio observatories
+    ("ALMA", None, -23.0229, -67.7552, 5058.0, &["Chajnantor", "Llano de Chajnantor"]),
+    ("Green Bank", None, 38.4331, -79.8398, 807.0, &["GBT", "Green Bank Observatory"]),
+    ("VLA", None, 34.0784, -107.6184, 2124.0, &["Very Large Array", "Karl G. Jansky VLA"]),
+    // NASA Deep Space Network
+    ("Goldstone", None, 35.4259, -116.8895, 1002.0, &["DSS-14", "DSN Goldstone", "GDSCC"]),
+    ("Canberra", None, -35.4020, 148.9813, 689.0, &["DSS-43", "DSN Canberra", "CDSCC", "Tidbinbilla"]),
+    ("Madrid", None, 40.4313, -4.2480, 865.0, &["DSS-63", "DSN Madrid", "MDSCC", "Robledo"]),
+    // ESA ESTRACK
+    ("New Norcia", None, -31.0482, 116.1915, 252.0, &["DSA-1", "NNO"]),
+    ("Cebreros", None, 40.4527, -4.3676, 794.0, &["DSA-2", "CEB"]),
+    ("Malargue", None, -35.7760, -69.3982, 1550.0, &["DSA-3", "Malargüe", "MLG"]),
+    ("Kourou", None, 5.2515, -52.8047, 14.0, &["KRU"]),
+    ("Kiruna", None, 67.8571, 20.9644, 402.0, &["KIR", "Esrange"]),
+    ("Redu", None, 50.0014, 5.1452, 385.0, &["RED"]),
+    ("Santa Maria", None, 36.9971, -25.1358, 276.0, &["SMA", "Azores"]),
+    ("Maspalomas", None, 27.7629, -15.6338, 205.0, &["MSP"]),
+];
+
+/// Canonical lookup key: lowercase ASCII letters and digits only
+fn normalize(name: &str) -> String {
+    name.chars()
+        .filter(|c| c.is_alphanumeric())
+        .flat_map(char::to_lowercase)
+        .collect()
+}
+
+struct SiteRegistry {
+    /// Sites by lowercase canonical name
+    sites: HashMap<String, Site>,
+    /// Normalized name, code or alias -> canonical name key
+    index: HashMap<String, String>,
+}
+
+impl SiteRegistry {
+    fn insert(&mut self, site: Site) {
+        let key = normalize(&site.name);
+        // Drop lookups that pointed at a site being replaced under the same name
+        if let Some(old) = self.sites.remove(&key) {
+            for alias in old.aliases.iter().chain(old.code.iter()) {
+                if self.index.get(&normalize(alias)) == Some(&key) {
+                    self.index.remove(&normalize(alias));
+                }
+            }
+        }
+        for alias in site.aliases.iter().chain(site.code.iter()) {
+            self.index.insert(normalize(alias), key.clone());
+        }
+        self.index.insert(key.clone(), key.clone());
+        self.sites.insert(key, site);
+    }
+}
+
+static SITES: Lazy<RwLock<SiteRegistry>> = Lazy::new(|| {
+    let mut registry = SiteRegistry {
+        sites: HashMap::new(),
+        index: HashMap::new(),
+    };
+    for &(name, code, latitude, longitude, height, aliases) in BUNDLED_SITES {
+        registry.insert(Site {
+            name: name.to_string(),
+            code: code.map(str::to_string),
+            latitude,
+            longitude,
+            height,
+            aliases: aliases.iter().map(|a| a.to_string()).collect(),
+        });
+    }
+    RwLock::new(registry)
+});
+
+/// Look up a site by name, alias or observatory code
+pub fn find_site(name: &str) -> Option<Site> {
+    let registry = SITES.read().unwrap_or_else(|e| e.into_inner());
+    let key = registry.index.get(&normalize(name))?;
+    registry.sites.get(key).cloned()
+}
+
+/// Look up a site, with an error listing how to find valid names
+pub fn resolve_site(name: &str) -> Result<Site, String> {
+    find_site(name).ok_or_else(|| {
+        format!(
+            "Unknown site '{}'. Use list_sites() to see known sites or register_site() to add one",
+            name
+        )
+    })
+}
+
+/// Add a site to the registry, replacing any site with the same name
+///
+/// Aliases and codes of the new site take precedence over existing entries.
+pub fn register_site(site: Site) -> Result<(), String> {
+    if normalize(&site.name).is_empty() {
+        return Err("Site name must contain at least one letter or digit".to_string());
+    }
+    if !(-90.0..=90.0).contains(&site.latitude) {
+        return Err("latitude must be between -90 and 90 degrees".to_string());
+    }
+    if !(-180.0..=180.0).contains(&site.longitude) {
+        return Err("longitude must be between -180 and 180 degrees".to_string());
+    }
+    SITES
+        .write()
+        .unwrap_or_else(|e| e.into_inner())
+        .insert(site);
+    Ok(())
+}
+
+/// Canonical names of all known sites, sorted
+pub fn site_names() -> Vec<String> {
+    let registry = SITES.read().unwrap_or_else(|e| e.into_inner());
+    let mut names: Vec<String> = registry.sites.values().map(|s| s.name.clone()).collect();
+    names.sort();
+    names
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_lookup_by_name_alias_and_code() {
+        let paranal = find_site("Paranal").unwrap();
+        assert_eq!(paranal.code.as_deref(), Some("309"));
+        assert_eq!(find_site("cerro paranal").unwrap(), paranal);
+        assert_eq!(find_site("VLT").unwrap(), paranal);
+        assert_eq!(find_site("309").unwrap(), paranal);
+        assert_eq!(find_site("dss43").unwrap().name, "Canberra");
+        assert!(find_site("Atlantis").is_none());
+    }
+
+    #[test]
+    fn test_bundled_sites_are_valid() {
+        for &(name, _, lat, lon, height, _) in BUNDLED_SITES {
+            assert!((-90.0..=90.0).contains(&lat), "{}", name);
+            assert!((-180.0..=180.0).contains(&lon), "{}", name);
+            assert!((0.0..6000.0).contains(&height), "{}", name);
+            assert_eq!(find_site(name).unwrap().name, name);
+        }
+    }
+
+    #[test]
+    fn test_register_and_replace_site() {
+        let site = Site {
+            name: "Test Site Alpha".to_string(),
+            code: Some("ZZ1".to_string()),
+            latitude: 10.0,
+            longitude: 20.0,
+            height: 30.0,
+            aliases: vec!["alpha".to_string()],
+        };
+        register_site(site.clone()).unwrap();
+        assert_eq!(find_site("test-site-alpha").unwrap(), site);
+        assert_eq!(find_site("ZZ1").unwrap(), site);
+
+        let replacement = Site {
+            aliases: vec![],
+            latitude: 11.0,
+            ..site
+        };
+        register_site(replacement).unwrap();
+        assert_eq!(find_site("Test Site Alpha").unwrap().latitude, 11.0);
+        assert!(find_site("alpha").is_none());
+        assert!(site_names().contains(&"Test Site Alpha".to_string()));
+
+        assert!(register_site(Site {
+            name: "--".to_string(),
+            code: None,
+            latitude: 0.0,
+            longitude: 0.0,
+            height: 0.0,
+            aliases: vec![],
+        })
+        .is_err());
+    }
+}
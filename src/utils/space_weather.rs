@@ -0,0 +1,359 @@
+//! Solar and geomagnetic activity from Celestrak space-weather data
+//!
+//! Celestrak's `SW-All.csv` holds one row per day since 1957: the eight
+//! 3-hourly Kp and Ap indices, the daily Ap average, and the observed and
+//! adjusted 10.7 cm solar flux with its 81-day averages. Rows after the last
+//! observation are NOAA predictions, flagged in `F10.7_DATA_TYPE` (`OBS`,
+//! `INT`erpolated, daily `PRD` or monthly `PRM`); predicted rows carry no
+//! 3-hourly indices.
+//!
+//! The file is downloaded on first use, cached in the cache directory and
+//! refreshed after the configured TTL, falling back to a stale copy when the
+//! network is unavailable. `load_space_weather_file` replaces the data with
+//! a local copy, for offline use.
+
+use crate::utils::cache_io;
+use crate::utils::config::{
+    DEFAULT_SPACE_WEATHER_PATH, DEFAULT_SPACE_WEATHER_TTL, NETWORK_TIMEOUT, SPACE_WEATHER_URL,
+};
+use chrono::{DateTime, Duration, NaiveDate, Timelike, Utc};
+use once_cell::sync::Lazy;
+use std::error::Error;
+use std::fs;
+use std::path::Path;
+use std::sync::{Arc, Mutex};
+use std::time::SystemTime;
+
+/// One day of Celestrak space-weather data
+#[derive(Debug, Clone, PartialEq)]
+struct DailySpaceWeather {
+    date: NaiveDate,
+    kp: [Option<f64>; 8],
+    ap: [Option<f64>; 8],
+    ap_daily: Option<f64>,
+    f107_obs: Option<f64>,
+    f107_adj: Option<f64>,
+    f107_obs_center81: Option<f64>,
+    f107_obs_last81: Option<f64>,
+    data_type: String,
+}
+
+/// Space-weather indices in effect at one instant
+#[derive(Debug, Clone, PartialEq)]
+pub struct SpaceWeatherSample {
+    /// UTC day of the record
+    pub date: NaiveDate,
+    /// Planetary Kp for the 3-hour interval (0-9)
+    pub kp: Option<f64>,
+    /// Planetary ap for the 3-hour interval (nT)
+    pub ap: Option<f64>,
+    /// Daily Ap (nT)
+    pub ap_daily: Option<f64>,
+    /// Observed F10.7 for the day (sfu)
+    pub f107_obs: Option<f64>,
+    /// F10.7 adjusted to 1 AU (sfu)
+    pub f107_adj: Option<f64>,
+    /// Observed F10.7 of the previous day (sfu), as used by MSIS-class density models
+    pub f107_obs_previous: Option<f64>,
+    /// 81-day centred average of observed F10.7 (sfu)
+    pub f107_obs_center81: Option<f64>,
+    /// 81-day trailing average of observed F10.7 (sfu)
+    pub f107_obs_last81: Option<f64>,
+    /// `OBS`, `INT`, `PRD` or `PRM`
+    pub data_type: String,
+}
+
+/// Parsed space-weather table, sorted by date
+#[derive(Debug, Clone, Default)]
+pub struct SpaceWeatherData {
+    days: Vec<DailySpaceWeather>,
+}
+
+impl SpaceWeatherData {
+    /// Parse the Celestrak CSV format
+    ///
+    /// Columns are located by their header names, so extra or reordered
+    /// columns are tolerated. Empty fields become `None`.
+    pub fn parse(text: &str) -> Result<Self, String> {
+        let mut lines = text.lines().filter(|l| !l.trim().is_empty());
+        let header: Vec<&str> = lines
+            .next()
+            .ok_or("space-weather file is empty")?
+            .split(',')
+            .map(str::trim)
+            .collect();
+        let column = |name: &str| header.iter().position(|h| *h == name);
+        let date_col = column("DATE").ok_or("space-weather file has no DATE column")?;
+        let kp_cols: Vec<Option<usize>> = (1..=8).map(|i| column(&format!("KP{}", i))).collect();
+        let ap_cols: Vec<Option<usize>> = (1..=8).map(|i| column(&format!("AP{}", i))).collect();
+        let ap_avg_col = column("AP_AVG");
+        let f107_obs_col = column("F10.7_OBS");
+        let f107_adj_col = column("F10.7_ADJ");
+        let data_type_col = column("F10.7_DATA_TYPE");
+        let center81_col = column("F10.7_OBS_CENTER81");
+        let last81_col = column("F10.7_OBS_LAST81");
+
+        let mut days = Vec::new();
+        for line in lines {
+            let fields: Vec<&str> = line.split(',').map(str::trim).collect();
+            let Some(date) = fields
+                .get(date_col)
+                .and_then(|d| NaiveDate::parse_from_str(d, "%Y-%m-%d").ok())
+            else {
+                continue;
+            };
+            let number = |col: Option<usize>| {
+                col.and_then(|c| fields.get(c))
+                    .and_then(|f| f.parse::<f64>().ok())
+            };
+            let mut kp = [None; 8];
+            let mut ap = [None; 8];
+            for i in 0..8 {
+                // Kp is stored in tenths (e.g. 23 for 2+)
+                kp[i] = number(kp_cols[i]).map(|v| v / 10.0);
+                ap[i] = number(ap_cols[i]);
+            }
+            days.push(DailySpaceWeather {
+                date,
+                kp,
+                ap,
+                ap_daily: number(ap_avg_col),
+                f107_obs: number(f107_obs_col),
+                f107_adj: number(f107_adj_col),
+                f107_obs_center81: number(center81_col),
+                f107_obs_last81: number(last81_col),
+                data_type: data_type_col
+                    .and_then(|c| fields.get(c))
+                    .map(|s| s.to_string())
+                    .unwrap_or_default(),
+            });
+        }
+        if days.is_empty() {
+            return Err("space-weather file has no data rows".to_string());
+        }
+        days.sort_by_key(|d| d.date);
+        days.dedup_by_key(|d| d.date);
+        Ok(SpaceWeatherData { days })
+    }
+
+    fn day(&self, date: NaiveDate) -> Option<&DailySpaceWeather> {
+        self.days
+            .binary_search_by_key(&date, |d| d.date)
+            .ok()
+            .map(|i| &self.days[i])
+    }
+
+    /// Indices in effect at `time`, or None outside the table
+    pub fn at(&self, time: &DateTime<Utc>) -> Option<SpaceWeatherSample> {
+        let date = time.date_naive();
+        let day = self.day(date)?;
+        let interval = (time.hour() / 3) as usize;
+        Some(SpaceWeatherSample {
+            date,
+            kp: day.kp[interval],
+            ap: day.ap[interval],
+            ap_daily: day.ap_daily,
+            f107_obs: day.f107_obs,
+            f107_adj: day.f107_adj,
+            f107_obs_previous: self.day(date - Duration::days(1)).and_then(|d| d.f107_obs),
+            f107_obs_center81: day.f107_obs_center81,
+            f107_obs_last81: day.f107_obs_last81,
+            data_type: day.data_type.clone(),
+        })
+    }
+
+    /// First and last day in the table
+    #[allow(dead_code)]
+    pub fn date_range(&self) -> Option<(NaiveDate, NaiveDate)> {
+        Some((self.days.first()?.date, self.days.last()?.date))
+    }
+
+    /// Number of days in the table
+    pub fn len(&self) -> usize {
+        self.days.len()
+    }
+
+    /// True if the table has no days
+    #[allow(dead_code)]
+    pub fn is_empty(&self) -> bool {
+        self.days.is_empty()
+    }
+}
+
+fn download_space_weather() -> Result<String, Box<dyn Error>> {
+    let agent: ureq::Agent = ureq::Agent::config_builder()
+        .timeout_global(Some(*NETWORK_TIMEOUT))
+        .build()
+        .into();
+    let mut response = agent.get(SPACE_WEATHER_URL.as_str()).call()?;
+    Ok(response.body_mut().read_to_string()?)
+}
+
+/// Cached file contents if it parses and, when `ttl` is given, is younger than it
+fn read_cache(path: &Path, ttl: Option<std::time::Duration>) -> Option<SpaceWeatherData> {
+    if let Some(ttl) = ttl {
+        let age = SystemTime::now()
+            .duration_since(fs::metadata(path).ok()?.modified().ok()?)
+            .ok()?;
+        if age > ttl {
+            return None;
+        }
+    }
+    SpaceWeatherData::parse(&fs::read_to_string(path).ok()?).ok()
+}
+
+/// Load from the cache if fresh, else download and update the cache
+///
+/// Falls back to a stale cache if the download fails.
+fn load_or_download(force: bool) -> Result<SpaceWeatherData, Box<dyn Error>> {
+    let path = DEFAULT_SPACE_WEATHER_PATH.clone();
+    let ttl = std::time::Duration::from_secs(*DEFAULT_SPACE_WEATHER_TTL);
+    if !force {
+        if let Some(data) = read_cache(&path, Some(ttl)) {
+            return Ok(data);
+        }
+    }
+    let downloaded =
+        download_space_weather().and_then(|text| Ok((SpaceWeatherData::parse(&text)?, text)));
+    match downloaded {
+        Ok((data, text)) => {
+            if let Err(e) = cache_io::write_atomic(&path, text.as_bytes()) {
+                eprintln!("Warning: failed to cache space-weather file: {e}");
+            }
+            Ok(data)
+        }
+        Err(e) => {
+            eprintln!("Warning: failed to download space-weather data ({e}).");
+            read_cache(&path, None)
+                .inspect(|_| {
+                    eprintln!(
+                        "Space-weather data loaded from STALE cache: {}",
+                        path.display()
+                    )
+                })
+                .ok_or_else(|| "Unable to load space-weather data from cache or network".into())
+        }
+    }
+}
+
+/// Loaded table, and whether loading has been attempted
+#[derive(Default)]
+struct State {
+    data: Option<Arc<SpaceWeatherData>>,
+    attempted: bool,
+}
+
+static STATE: Lazy<Mutex<State>> = Lazy::new(|| Mutex::new(State::default()));
+
+/// The space-weather table, loading it on first use
+///
+/// A failed load is not retried until `init_space_weather` is called.
+pub fn space_weather_data() -> Option<Arc<SpaceWeatherData>> {
+    let mut state = STATE.lock().unwrap();
+    if state.data.is_none() && !state.attempted {
+        state.attempted = true;
+        match load_or_download(false) {
+            Ok(data) => state.data = Some(Arc::new(data)),
+            Err(e) => eprintln!("Warning: space-weather data unavailable: {e}"),
+        }
+    }
+    state.data.clone()
+}
+
+/// Space-weather indices at `time`, or None without data for that day
+pub fn space_weather_at(time: &DateTime<Utc>) -> Option<SpaceWeatherSample> {
+    space_weather_data()?.at(time)
+}
+
+/// Download the space-weather file again, bypassing the cache TTL
+///
+/// Returns true if data is available afterwards.
+pub fn init_space_weather() -> bool {
+    let mut state = STATE.lock().unwrap();
+    state.attempted = true;
+    match load_or_download(true) {
+        Ok(data) => {
+            state.data = Some(Arc::new(data));
+            true
+        }
+        Err(e) => {
+            eprintln!("Error initializing space-weather data: {e}");
+            state.data.is_some()
+        }
+    }
+}
+
+/// Replace the space-weather data with a local file in the Celestrak CSV format
+///
+/// Returns the number of days loaded.
+pub fn load_space_weather_file(path: &str) -> Result<usize, String> {
+    let text = fs::read_to_string(path).map_err(|e| format!("{}: {}", path, e))?;
+    let data = SpaceWeatherData::parse(&text).map_err(|e| format!("{}: {}", path, e))?;
+    let n = data.len();
+    let mut state = STATE.lock().unwrap();
+    state.data = Some(Arc::new(data));
+    state.attempted = true;
+    Ok(n)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+
+    const SAMPLE: &str = "\
+DATE,BSRN,ND,KP1,KP2,KP3,KP4,KP5,KP6,KP7,KP8,KP_SUM,AP1,AP2,AP3,AP4,AP5,AP6,AP7,AP8,AP_AVG,CP,C9,ISN,F10.7_OBS,F10.7_ADJ,F10.7_DATA_TYPE,F10.7_OBS_CENTER81,F10.7_OBS_LAST81,F10.7_ADJ_CENTER81,F10.7_ADJ_LAST81
+2024-05-10,2606,1,40,53,57,70,83,87,90,90,570,27,56,67,132,236,300,400,400,202,2.4,9,196,190.4,196.1,OBS,170.5,168.2,175.0,172.3
+2024-05-11,2606,2,87,87,83,77,70,67,57,53,581,300,300,236,179,132,111,67,56,173,2.3,9,180,176.2,181.5,OBS,171.0,168.9,175.4,173.0
+2024-06-01,2607,23,,,,,,,,,,,,,,,,,,12,,,,165.0,169.8,PRD,160.1,,,
+";
+
+    #[test]
+    fn test_parse_and_lookup() {
+        let data = SpaceWeatherData::parse(SAMPLE).unwrap();
+        assert_eq!(data.len(), 3);
+
+        let t = Utc.with_ymd_and_hms(2024, 5, 11, 4, 30, 0).unwrap();
+        let sample = data.at(&t).unwrap();
+        assert_eq!(sample.kp, Some(8.7));
+        assert_eq!(sample.ap, Some(300.0));
+        assert_eq!(sample.ap_daily, Some(173.0));
+        assert_eq!(sample.f107_obs, Some(176.2));
+        assert_eq!(sample.f107_obs_previous, Some(190.4));
+        assert_eq!(sample.f107_obs_center81, Some(171.0));
+        assert_eq!(sample.data_type, "OBS");
+
+        let last_interval = Utc.with_ymd_and_hms(2024, 5, 10, 23, 59, 0).unwrap();
+        assert_eq!(data.at(&last_interval).unwrap().kp, Some(9.0));
+    }
+
+    #[test]
+    fn test_predicted_rows_and_gaps() {
+        let data = SpaceWeatherData::parse(SAMPLE).unwrap();
+        let predicted = data
+            .at(&Utc.with_ymd_and_hms(2024, 6, 1, 12, 0, 0).unwrap())
+            .unwrap();
+        assert_eq!(predicted.kp, None);
+        assert_eq!(predicted.ap_daily, Some(12.0));
+        assert_eq!(predicted.f107_obs_previous, None);
+        assert_eq!(predicted.data_type, "PRD");
+
+        assert!(data
+            .at(&Utc.with_ymd_and_hms(2024, 5, 20, 0, 0, 0).unwrap())
+            .is_none());
+        assert_eq!(
+            data.date_range(),
+            Some((
+                NaiveDate::from_ymd_opt(2024, 5, 10).unwrap(),
+                NaiveDate::from_ymd_opt(2024, 6, 1).unwrap()
+            ))
+        );
+    }
+
+    #[test]
+    fn test_parse_rejects_non_tables() {
+        assert!(SpaceWeatherData::parse("").is_err());
+        assert!(SpaceWeatherData::parse("<html>Service unavailable</html>").is_err());
+        assert!(SpaceWeatherData::parse("DATE,KP1\n").is_err());
+    }
+}
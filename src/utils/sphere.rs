@@ -0,0 +1,336 @@
+//! Great-circle geometry on the unit sphere
+//!
+//! Points are (longitude, latitude) pairs in degrees: RA/Dec on the sky or
+//! geodetic longitude/latitude on the Earth. Polygon edges are great-circle
+//! arcs, so regions may span the longitude wrap or enclose a pole; the SAA
+//! constraint and sky avoidance regions share [`SphericalPolygon`].
+
+use pyo3::prelude::*;
+use rayon::prelude::*;
+
+use crate::utils::vector_math::{cross_product, dot_product, radec_to_unit_vector};
+
+/// Below this many points a batch test runs serially; rayon's scheduling
+/// costs more than the polygon tests themselves
+const PARALLEL_MIN_POINTS: usize = 4096;
+
+/// Great-circle angle between two points, degrees
+///
+/// Vincenty's formula, accurate for coincident and antipodal points alike.
+pub fn separation(lon1: f64, lat1: f64, lon2: f64, lat2: f64) -> f64 {
+    let (sin_lat1, cos_lat1) = lat1.to_radians().sin_cos();
+    let (sin_lat2, cos_lat2) = lat2.to_radians().sin_cos();
+    let (sin_dlon, cos_dlon) = (lon2 - lon1).to_radians().sin_cos();
+    let num = (cos_lat2 * sin_dlon).hypot(cos_lat1 * sin_lat2 - sin_lat1 * cos_lat2 * cos_dlon);
+    let den = sin_lat1 * sin_lat2 + cos_lat1 * cos_lat2 * cos_dlon;
+    num.atan2(den).to_degrees()
+}
+
+/// Direction of point 2 seen from point 1, degrees from north through east
+///
+/// On the sky this is the position angle; on the Earth, the initial bearing
+/// of the great circle from point 1 to point 2. In [0, 360); 0 when the
+/// points coincide.
+pub fn position_angle(lon1: f64, lat1: f64, lon2: f64, lat2: f64) -> f64 {
+    let (sin_lat1, cos_lat1) = lat1.to_radians().sin_cos();
+    let (sin_lat2, cos_lat2) = lat2.to_radians().sin_cos();
+    let (sin_dlon, cos_dlon) = (lon2 - lon1).to_radians().sin_cos();
+    (cos_lat2 * sin_dlon)
+        .atan2(cos_lat1 * sin_lat2 - sin_lat1 * cos_lat2 * cos_dlon)
+        .to_degrees()
+        .rem_euclid(360.0)
+}
+
+/// Whether two circular cones (centre and half-angle in degrees) overlap
+///
+/// Cones that only touch do not overlap.
+pub fn cones_overlap(
+    lon1: f64,
+    lat1: f64,
+    radius1: f64,
+    lon2: f64,
+    lat2: f64,
+    radius2: f64,
+) -> bool {
+    separation(lon1, lat1, lon2, lat2) < radius1 + radius2
+}
+
+/// Signed angle at `p` from the direction of `a` to the direction of `b`
+fn swept_angle(p: &[f64; 3], a: &[f64; 3], b: &[f64; 3]) -> f64 {
+    let sin = dot_product(p, &cross_product(a, b));
+    let cos = dot_product(a, b) - dot_product(a, p) * dot_product(b, p);
+    sin.atan2(cos)
+}
+
+/// Polygon with great-circle edges, prepared for repeated containment tests
+///
+/// The interior is the smaller of the two regions the edges bound, so the
+/// vertices may be listed in either direction but must fit within a
+/// hemisphere.
+#[pyclass]
+#[derive(Debug, Clone)]
+pub struct SphericalPolygon {
+    vertices: Vec<(f64, f64)>,
+    units: Vec<[f64; 3]>,
+    /// +1 when the vertices run anticlockwise about the interior, -1 otherwise
+    orientation: f64,
+    /// Steradians
+    solid_angle: f64,
+    /// Axis of a cap enclosing the polygon, for rejecting distant points early
+    centre: [f64; 3],
+    /// Cosine of that cap's radius
+    cap_cos: f64,
+}
+
+impl SphericalPolygon {
+    pub fn new(vertices: &[(f64, f64)]) -> Result<Self, String> {
+        let mut vertices = vertices.to_vec();
+        if vertices.len() > 1 && vertices.first() == vertices.last() {
+            vertices.pop();
+        }
+        if vertices.len() < 3 {
+            return Err("A spherical polygon needs at least 3 vertices".to_string());
+        }
+        let units: Vec<[f64; 3]> = vertices
+            .iter()
+            .map(|&(lon, lat)| radec_to_unit_vector(lon, lat))
+            .collect();
+
+        // Signed area from triangles fanned out of the vertex centroid
+        let sum = units.iter().fold([0.0; 3], |acc, u| {
+            [acc[0] + u[0], acc[1] + u[1], acc[2] + u[2]]
+        });
+        let norm = dot_product(&sum, &sum).sqrt();
+        let centre = [sum[0] / norm, sum[1] / norm, sum[2] / norm];
+        if norm < 1e-9 || units.iter().any(|u| dot_product(u, &centre) <= 0.0) {
+            return Err("Spherical polygon vertices must lie within a hemisphere".to_string());
+        }
+        let signed_area: f64 = (0..units.len())
+            .map(|i| {
+                let (a, b) = (&units[i], &units[(i + 1) % units.len()]);
+                let num = dot_product(&centre, &cross_product(a, b));
+                let den =
+                    1.0 + dot_product(&centre, a) + dot_product(a, b) + dot_product(b, &centre);
+                2.0 * num.atan2(den)
+            })
+            .sum();
+        if signed_area.abs() < 1e-12 {
+            return Err("Spherical polygon has no area".to_string());
+        }
+
+        // The cap through the farthest vertex is convex, so it holds every
+        // edge and the interior too
+        let cap_cos = units
+            .iter()
+            .map(|u| dot_product(u, &centre))
+            .fold(1.0, f64::min);
+
+        Ok(Self {
+            vertices,
+            units,
+            orientation: signed_area.signum(),
+            solid_angle: signed_area.abs(),
+            centre,
+            cap_cos,
+        })
+    }
+
+    /// Vertices as (longitude, latitude) pairs in degrees, without a closing repeat
+    pub fn vertices(&self) -> &[(f64, f64)] {
+        &self.vertices
+    }
+
+    pub fn vertex_count(&self) -> usize {
+        self.units.len()
+    }
+
+    /// Whether the point is inside the polygon
+    pub fn contains_point(&self, lon: f64, lat: f64) -> bool {
+        self.contains_unit(&radec_to_unit_vector(lon, lat))
+    }
+
+    /// Whether the point with unit vector `p` is inside the polygon
+    pub fn contains_unit(&self, p: &[f64; 3]) -> bool {
+        if dot_product(p, &self.centre) < self.cap_cos - 1e-12 {
+            return false;
+        }
+        let n = self.units.len();
+        let winding: f64 = (0..n)
+            .map(|i| swept_angle(p, &self.units[i], &self.units[(i + 1) % n]))
+            .sum();
+        // ±2π around interior points, ∓2π around their antipodes, 0 elsewhere
+        winding * self.orientation > std::f64::consts::PI
+    }
+
+    /// Test many points at once, in parallel for long inputs
+    pub fn contains_many(&self, lons: &[f64], lats: &[f64]) -> Vec<bool> {
+        if lons.len() < PARALLEL_MIN_POINTS {
+            return lons
+                .iter()
+                .zip(lats)
+                .map(|(&lon, &lat)| self.contains_point(lon, lat))
+                .collect();
+        }
+        lons.par_iter()
+            .zip(lats.par_iter())
+            .with_min_len(PARALLEL_MIN_POINTS / 4)
+            .map(|(&lon, &lat)| self.contains_point(lon, lat))
+            .collect()
+    }
+}
+
+#[pymethods]
+impl SphericalPolygon {
+    #[new]
+    fn py_new(vertices: Vec<(f64, f64)>) -> PyResult<Self> {
+        Self::new(&vertices).map_err(pyo3::exceptions::PyValueError::new_err)
+    }
+
+    /// Vertices as (longitude, latitude) pairs in degrees, without a closing repeat
+    #[getter(vertices)]
+    fn py_vertices(&self) -> Vec<(f64, f64)> {
+        self.vertices.clone()
+    }
+
+    /// Area of the interior in steradians
+    #[getter]
+    fn solid_angle(&self) -> f64 {
+        self.solid_angle
+    }
+
+    /// Whether a point, or each of arrays of points, is inside the polygon
+    fn contains(
+        &self,
+        py: Python,
+        lon: &Bound<'_, PyAny>,
+        lat: &Bound<'_, PyAny>,
+    ) -> PyResult<Py<PyAny>> {
+        use numpy::IntoPyArray;
+        if let (Ok(lon), Ok(lat)) = (lon.extract::<f64>(), lat.extract::<f64>()) {
+            return Ok(pyo3::types::PyBool::new(py, self.contains_point(lon, lat))
+                .to_owned()
+                .into_any()
+                .unbind());
+        }
+        let (lon, lat): (Vec<f64>, Vec<f64>) = (lon.extract()?, lat.extract()?);
+        check_lengths(&[&lon, &lat])?;
+        Ok(ndarray::Array1::from(self.contains_many(&lon, &lat))
+            .into_pyarray(py)
+            .into())
+    }
+
+    fn __repr__(&self) -> String {
+        format!(
+            "SphericalPolygon(vertices={}, solid_angle={:.6})",
+            self.vertices.len(),
+            self.solid_angle
+        )
+    }
+}
+
+/// Error unless all coordinate arrays have the same length
+pub(crate) fn check_lengths(arrays: &[&[f64]]) -> PyResult<()> {
+    if arrays.windows(2).any(|w| w[0].len() != w[1].len()) {
+        return Err(pyo3::exceptions::PyValueError::new_err(
+            "Coordinate arrays must have the same length",
+        ));
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_separation() {
+        assert!((separation(10.0, 20.0, 10.0, 20.0)).abs() < 1e-12);
+        assert!((separation(0.0, 0.0, 90.0, 0.0) - 90.0).abs() < 1e-12);
+        assert!((separation(0.0, 0.0, 180.0, 0.0) - 180.0).abs() < 1e-12);
+        assert!((separation(0.0, -90.0, 123.0, 90.0) - 180.0).abs() < 1e-12);
+        // Tiny separations keep full precision
+        let arcsec = 1.0 / 3600.0;
+        assert!((separation(45.0, 30.0, 45.0, 30.0 + arcsec) / arcsec - 1.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_position_angle() {
+        assert!((position_angle(0.0, 0.0, 0.0, 10.0)).abs() < 1e-12);
+        assert!((position_angle(0.0, 0.0, 10.0, 0.0) - 90.0).abs() < 1e-12);
+        assert!((position_angle(0.0, 0.0, 0.0, -10.0) - 180.0).abs() < 1e-12);
+        assert!((position_angle(0.0, 0.0, -10.0, 0.0) - 270.0).abs() < 1e-12);
+        // Across the longitude wrap
+        assert!((position_angle(359.0, 0.0, 1.0, 0.0) - 90.0).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_cones_overlap() {
+        assert!(cones_overlap(0.0, 0.0, 5.0, 8.0, 0.0, 5.0));
+        assert!(!cones_overlap(0.0, 0.0, 5.0, 12.0, 0.0, 5.0));
+        assert!(cones_overlap(0.0, 89.0, 1.5, 180.0, 89.0, 1.5));
+    }
+
+    #[test]
+    fn test_polygon_containment_either_orientation() {
+        let square = [(-10.0, -10.0), (10.0, -10.0), (10.0, 10.0), (-10.0, 10.0)];
+        let mut reversed = square;
+        reversed.reverse();
+        for vertices in [&square[..], &reversed[..]] {
+            let polygon = SphericalPolygon::new(vertices).unwrap();
+            assert!(polygon.contains_point(0.0, 0.0));
+            assert!(polygon.contains_point(359.0, 9.0));
+            assert!(!polygon.contains_point(20.0, 0.0));
+            // The antipode of an interior point is outside
+            assert!(!polygon.contains_point(180.0, 0.0));
+        }
+    }
+
+    #[test]
+    fn test_polygon_edges_are_great_circles() {
+        // The edge from (0, 60) to (90, 60) bulges poleward of latitude 60
+        let polygon = SphericalPolygon::new(&[(0.0, 60.0), (90.0, 60.0), (45.0, 30.0)]).unwrap();
+        assert!(polygon.contains_point(45.0, 62.0));
+        assert!(!polygon.contains_point(45.0, 72.0));
+    }
+
+    #[test]
+    fn test_polygon_around_pole_and_area() {
+        let cap: Vec<(f64, f64)> = (0..36).map(|k| (k as f64 * 10.0, 80.0)).collect();
+        let polygon = SphericalPolygon::new(&cap).unwrap();
+        assert!(polygon.contains_point(0.0, 90.0));
+        assert!(polygon.contains_point(123.0, 85.0));
+        assert!(!polygon.contains_point(123.0, 70.0));
+        // Close to the spherical cap of 10 degrees radius
+        let cap_area = 2.0 * std::f64::consts::PI * (1.0 - 10f64.to_radians().cos());
+        assert!((polygon.solid_angle - cap_area).abs() / cap_area < 0.01);
+
+        let octant = SphericalPolygon::new(&[(0.0, 0.0), (90.0, 0.0), (0.0, 90.0)]).unwrap();
+        assert!((octant.solid_angle - std::f64::consts::PI / 2.0).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_polygon_rejects_bad_vertices() {
+        assert!(SphericalPolygon::new(&[(0.0, 0.0), (10.0, 0.0)]).is_err());
+        // A closing repeat of the first vertex is dropped
+        assert!(SphericalPolygon::new(&[(0.0, 0.0), (10.0, 0.0), (0.0, 0.0)]).is_err());
+        assert!(SphericalPolygon::new(&[(0.0, 0.0), (10.0, 0.0), (20.0, 0.0)]).is_err());
+        assert!(SphericalPolygon::new(&[(0.0, 0.0), (120.0, 0.0), (240.0, 0.0)]).is_err());
+    }
+
+    #[test]
+    fn test_contains_many_parallel_matches_serial() {
+        let polygon = SphericalPolygon::new(&[(0.0, -30.0), (60.0, -30.0), (30.0, 40.0)]).unwrap();
+        let n = PARALLEL_MIN_POINTS * 2;
+        let lons: Vec<f64> = (0..n).map(|i| (i as f64 * 37.1) % 360.0).collect();
+        let lats: Vec<f64> = (0..n).map(|i| (i as f64 * 0.071) % 180.0 - 90.0).collect();
+        assert!(polygon.contains_point(30.0, 0.0));
+        let expected: Vec<bool> = lons
+            .iter()
+            .zip(&lats)
+            .map(|(&lon, &lat)| polygon.contains_point(lon, lat))
+            .collect();
+        let mask = polygon.contains_many(&lons, &lats);
+        assert_eq!(mask, expected);
+        assert!(mask.iter().any(|&inside| inside));
+    }
+}
@@ -4,7 +4,8 @@
 //! Julian Date calculations in various time scales, and Python datetime interop.
 
 use chrono::{DateTime, Datelike, Timelike, Utc};
-use hifitime::{Duration, Epoch};
+use hifitime::{Duration, Epoch, UNIX_REF_EPOCH};
+use numpy::IntoPyArray;
 use pyo3::prelude::*;
 
 use crate::utils::config::{JD_EPOCH, SECONDS_PER_DAY};
@@ -15,12 +16,160 @@ use crate::utils::ut1_provider;
 // ============================================================================
 
 /// Convert chrono `DateTime<Utc>` to hifitime `Epoch`
+///
+/// chrono's leap-second representation (23:59:59 with a nanosecond field of
+/// at least 1e9, i.e. 23:59:60) maps to the instant one SI second after
+/// 23:59:59, inside the leap second.
 #[inline]
 pub fn chrono_to_epoch(dt: &DateTime<Utc>) -> Epoch {
-    let nanos = (dt.timestamp() as i128) * 1_000_000_000 + (dt.timestamp_subsec_nanos() as i128);
+    let subsec = dt.timestamp_subsec_nanos();
+    if subsec >= NANOS_PER_SECOND {
+        let before = chrono_to_epoch_unchecked(dt.timestamp(), subsec - NANOS_PER_SECOND);
+        return Epoch::from_tai_duration(before.to_tai_duration() + Duration::from_seconds(1.0));
+    }
+    chrono_to_epoch_unchecked(dt.timestamp(), subsec)
+}
+
+const NANOS_PER_SECOND: u32 = 1_000_000_000;
+
+#[inline]
+fn chrono_to_epoch_unchecked(secs: i64, subsec: u32) -> Epoch {
+    let nanos = (secs as i128) * NANOS_PER_SECOND as i128 + subsec as i128;
     Epoch::from_unix_duration(Duration::from_total_nanoseconds(nanos))
 }
 
+/// Convert hifitime `Epoch` to chrono `DateTime<Utc>`
+///
+/// An instant inside a leap second is returned in chrono's leap-second
+/// representation, so it stays distinct from the following second.
+pub fn epoch_to_chrono(epoch: &Epoch) -> DateTime<Utc> {
+    let nanos = (epoch.to_utc_duration() - UNIX_REF_EPOCH.to_utc_duration()).total_nanoseconds();
+    let per_second = NANOS_PER_SECOND as i128;
+    let dt = DateTime::from_timestamp(
+        nanos.div_euclid(per_second) as i64,
+        nanos.rem_euclid(per_second) as u32,
+    )
+    .unwrap_or_default();
+
+    // Outside leap seconds the round trip is exact; inside one, UTC has no
+    // label for the instant and the round trip lands a second away
+    let offset = (chrono_to_epoch(&dt).to_tai_duration() - epoch.to_tai_duration()).to_seconds();
+    if offset.abs() < 0.5 {
+        return dt;
+    }
+    let before = if offset > 0.0 {
+        dt - chrono::Duration::seconds(1)
+    } else {
+        dt
+    };
+    if before.second() != 59 {
+        return dt;
+    }
+    before
+        .date_naive()
+        .and_hms_nano_opt(
+            before.hour(),
+            before.minute(),
+            59,
+            before.nanosecond() + NANOS_PER_SECOND,
+        )
+        .map(|naive| naive.and_utc())
+        .unwrap_or(dt)
+}
+
+/// Build a UTC datetime from calendar fields, accepting second 60
+///
+/// A second of 60 is only valid at 23:59 on a day that ends in a leap second
+/// of hifitime's leap-second table; it is returned in chrono's leap-second
+/// representation.
+pub fn utc_from_fields(
+    year: i32,
+    month: u32,
+    day: u32,
+    hour: u32,
+    minute: u32,
+    second: u32,
+    nanosecond: u32,
+) -> Result<DateTime<Utc>, String> {
+    let invalid = || {
+        format!(
+            "Invalid datetime components: {}-{}-{} {}:{}:{}",
+            year, month, day, hour, minute, second
+        )
+    };
+    let date = chrono::NaiveDate::from_ymd_opt(year, month, day).ok_or_else(invalid)?;
+    if second != 60 {
+        return date
+            .and_hms_nano_opt(hour, minute, second, nanosecond)
+            .map(|naive| naive.and_utc())
+            .ok_or_else(invalid);
+    }
+    if (hour, minute) != (23, 59) || !ends_in_leap_second(date) {
+        return Err(format!(
+            "{:04}-{:02}-{:02} {:02}:{:02}:60 is not a leap second",
+            year, month, day, hour, minute
+        ));
+    }
+    date.and_hms_nano_opt(23, 59, 59, nanosecond + NANOS_PER_SECOND)
+        .map(|naive| naive.and_utc())
+        .ok_or_else(invalid)
+}
+
+/// Whether the UTC day of `date` ends in a leap second, i.e. TAI-UTC is one
+/// second larger on the following day
+fn ends_in_leap_second(date: chrono::NaiveDate) -> bool {
+    let offset_at_noon = |date: chrono::NaiveDate| {
+        date.and_hms_opt(12, 0, 0)
+            .and_then(|naive| get_tai_utc_offset(&naive.and_utc()))
+    };
+    match (
+        offset_at_noon(date),
+        date.succ_opt().and_then(offset_at_noon),
+    ) {
+        (Some(today), Some(tomorrow)) => (tomorrow - today - 1.0).abs() < 1e-9,
+        _ => false,
+    }
+}
+
+// ============================================================================
+// Timestamp grid time scale
+// ============================================================================
+
+/// Time scale in which generated timestamp grids are uniform
+///
+/// Chosen per ephemeris with the `time_scale` constructor keyword.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum GridTimeScale {
+    /// Steps of UTC clock time (leap seconds are skipped)
+    #[default]
+    Utc,
+    /// Steps of SI seconds; identical grids whether labelled TAI or TT
+    Tai,
+    Tt,
+}
+
+impl GridTimeScale {
+    pub fn parse(scale: &str) -> Result<Self, String> {
+        match scale.trim().to_uppercase().as_str() {
+            "UTC" => Ok(GridTimeScale::Utc),
+            "TAI" => Ok(GridTimeScale::Tai),
+            "TT" => Ok(GridTimeScale::Tt),
+            _ => Err(format!(
+                "Unknown time scale '{}'. Use 'UTC', 'TAI' or 'TT'.",
+                scale
+            )),
+        }
+    }
+
+    pub fn name(self) -> &'static str {
+        match self {
+            GridTimeScale::Utc => "UTC",
+            GridTimeScale::Tai => "TAI",
+            GridTimeScale::Tt => "TT",
+        }
+    }
+}
+
 /// Get TAI-UTC offset in seconds (leap seconds) for a DateTime
 #[inline]
 pub fn get_tai_utc_offset(dt: &DateTime<Utc>) -> Option<f64> {
@@ -99,8 +248,74 @@ pub fn utc_to_python_datetime(py: Python, dt: &DateTime<Utc>) -> PyResult<Py<PyA
             dt.hour(),
             dt.minute(),
             dt.second(),
-            dt.timestamp_subsec_micros(),
+            // Python datetimes cannot hold a leap second; clamp to its end
+            dt.timestamp_subsec_micros().min(999_999),
             tz_utc,
         ))?
         .into())
 }
+
+/// Convert chrono DateTime<Utc> values to a NumPy `datetime64[us]` array (UTC)
+pub fn utc_to_datetime64_array(
+    py: Python,
+    times: impl IntoIterator<Item = DateTime<Utc>>,
+) -> PyResult<Py<PyAny>> {
+    // A leap second is clamped to the end of 23:59:59, as NumPy has none
+    let micros =
+        ndarray::Array1::from_iter(times.into_iter().map(|t| {
+            t.timestamp() * 1_000_000 + i64::from(t.timestamp_subsec_micros().min(999_999))
+        }));
+    Ok(micros
+        .into_pyarray(py)
+        .call_method1("astype", ("datetime64[us]",))?
+        .into())
+}
+
+/// Convert a sequence of times to chrono DateTime<Utc>
+///
+/// Accepts any iterable of Python datetimes as well as NumPy `datetime64`
+/// arrays (as returned by the `timestamp` property of every ephemeris).
+pub fn python_times_to_utc(times: &Bound<PyAny>) -> PyResult<Vec<DateTime<Utc>>> {
+    let times = if times.hasattr("astype")? {
+        times
+            .call_method1("astype", ("datetime64[us]",))?
+            .call_method0("tolist")?
+    } else {
+        times.clone()
+    };
+    pyo3::types::PyIterator::from_object(&times)?
+        .map(|item| python_datetime_to_utc(&item?))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+
+    #[test]
+    fn test_leap_second_round_trip() {
+        let leap = utc_from_fields(2016, 12, 31, 23, 59, 60, 500_000_000).unwrap();
+        let before = Utc.with_ymd_and_hms(2016, 12, 31, 23, 59, 59).unwrap();
+        let after = Utc.with_ymd_and_hms(2017, 1, 1, 0, 0, 0).unwrap();
+
+        let epoch = chrono_to_epoch(&leap);
+        assert!(((epoch - chrono_to_epoch(&before)).to_seconds() - 1.5).abs() < 1e-9);
+        assert!(((chrono_to_epoch(&after) - epoch).to_seconds() - 0.5).abs() < 1e-9);
+        assert_eq!(epoch_to_chrono(&epoch), leap);
+        assert_eq!(epoch_to_chrono(&chrono_to_epoch(&after)), after);
+    }
+
+    #[test]
+    fn test_second_60_only_on_leap_seconds() {
+        assert!(utc_from_fields(2016, 12, 31, 23, 59, 60, 0).is_ok());
+        assert!(utc_from_fields(2015, 6, 30, 23, 59, 60, 0).is_ok());
+        // Minute ends that are not in the leap-second table
+        assert!(utc_from_fields(2016, 12, 31, 23, 58, 60, 0).is_err());
+        assert!(utc_from_fields(2016, 12, 31, 12, 59, 60, 0).is_err());
+        assert!(utc_from_fields(2016, 6, 30, 23, 59, 60, 0).is_err());
+        assert!(utc_from_fields(2017, 12, 31, 23, 59, 60, 0).is_err());
+        assert!(utc_from_fields(2016, 12, 31, 23, 59, 61, 0).is_err());
+        assert!(utc_from_fields(2016, 2, 30, 0, 0, 0, 0).is_err());
+    }
+}
@@ -6,21 +6,78 @@
 //! - Downloading TLEs from URLs with caching
 //! - Fetching TLEs from Celestrak by NORAD ID or name
 //! - Fetching TLEs from Space-Track.org by NORAD ID with epoch support
+//! - Reading TLE histories (several element sets) from files, URLs or Space-Track.org
 //! - Extracting TLE epoch information
-//! - Unified TLE fetching from multiple sources
+//! - Unified TLE fetching from multiple sources, with the SATCAT decay date
 
+use crate::utils::cache_io;
 use crate::utils::config::{
-    CACHE_DIR, CELESTRAK_API_BASE, DEFAULT_EPOCH_TOLERANCE_DAYS, SPACETRACK_API_BASE,
-    SPACETRACK_CACHE_MAX_ENTRIES, SPACETRACK_LOGIN_URL, SPACETRACK_PASSWORD_ENV,
-    SPACETRACK_USERNAME_ENV, TLE_CACHE_MAX_ENTRIES, TLE_CACHE_TTL,
+    CACHE_DIR, CELESTRAK_API_BASE, DEFAULT_EPOCH_TOLERANCE_DAYS, NETWORK_TIMEOUT,
+    SPACETRACK_API_BASE, SPACETRACK_CACHE_MAX_ENTRIES, SPACETRACK_LOGIN_URL, TLE_CACHE_MAX_ENTRIES,
+    TLE_CACHE_TTL, TLE_CHECK_DECAY,
 };
+use crate::utils::satcat;
+use crate::utils::settings::SETTINGS;
 #[allow(unused_imports)]
 use chrono::{DateTime, Datelike, NaiveDate, NaiveDateTime, Utc};
 use std::error::Error;
 use std::fs;
-use std::io::Write;
 use std::path::{Path, PathBuf};
+use std::sync::Mutex;
 use std::time::{Duration, SystemTime};
+
+/// Per-call cache options; `None` fields fall back to the module-level defaults
+#[derive(Debug, Clone, Copy, Default)]
+pub struct TleCacheOptions {
+    /// Largest age (seconds) of a cached Celestrak or URL download; 0 forces a refresh
+    pub cache_ttl: Option<u64>,
+    /// How far (days) a cached Space-Track TLE epoch may be from the requested epoch
+    pub epoch_tolerance_days: Option<f64>,
+}
+
+/// Module-level defaults set at runtime, taking precedence over the configuration
+static CACHE_TTL_DEFAULT: Mutex<Option<u64>> = Mutex::new(None);
+static EPOCH_TOLERANCE_DEFAULT: Mutex<Option<f64>> = Mutex::new(None);
+
+/// Cache TTL (seconds) used when a call does not give one
+pub fn default_cache_ttl() -> u64 {
+    CACHE_TTL_DEFAULT
+        .lock()
+        .ok()
+        .and_then(|ttl| *ttl)
+        .unwrap_or(*TLE_CACHE_TTL)
+}
+
+/// Space-Track epoch tolerance (days) used when a call does not give one
+pub fn default_epoch_tolerance_days() -> f64 {
+    EPOCH_TOLERANCE_DEFAULT
+        .lock()
+        .ok()
+        .and_then(|days| *days)
+        .unwrap_or(*DEFAULT_EPOCH_TOLERANCE_DAYS)
+}
+
+/// Replace the module-level defaults; `None` restores the configured value
+pub fn set_cache_defaults(
+    cache_ttl: Option<u64>,
+    epoch_tolerance_days: Option<f64>,
+) -> Result<(), String> {
+    if let Some(days) = epoch_tolerance_days {
+        if !days.is_finite() || days < 0.0 {
+            return Err(format!(
+                "epoch_tolerance_days must be a non-negative number of days, got {}",
+                days
+            ));
+        }
+    }
+    if let Ok(mut ttl) = CACHE_TTL_DEFAULT.lock() {
+        *ttl = cache_ttl;
+    }
+    if let Ok(mut tolerance) = EPOCH_TOLERANCE_DEFAULT.lock() {
+        *tolerance = epoch_tolerance_days;
+    }
+    Ok(())
+}
 /// Result of parsing a TLE - contains the two lines, optional satellite name, and epoch
 #[derive(Debug, Clone)]
 pub struct TLEData {
@@ -29,6 +86,8 @@ pub struct TLEData {
     #[allow(dead_code)]
     pub name: Option<String>,
     pub epoch: DateTime<Utc>,
+    /// Read from the local TLE cache rather than downloaded
+    pub from_cache: bool,
 }
 
 /// Parse TLE from a string that may be 2 or 3 lines
@@ -75,6 +134,7 @@ fn make_tle_data(
         line2: line2.to_string(),
         name,
         epoch,
+        from_cache: false,
     })
 }
 
@@ -117,10 +177,57 @@ pub fn read_tle_file(path: &str) -> Result<TLEData, Box<dyn Error>> {
     parse_tle_string(&content)
 }
 
+/// Parse every TLE in a block of text
+///
+/// Accepts any mix of 2-line and 3-line entries, as found in Space-Track
+/// history responses and multi-object TLE files. A non-TLE line directly
+/// before line 1 is taken as the satellite name; unparseable entries are
+/// skipped.
+pub fn parse_tle_sets(content: &str) -> Vec<TLEData> {
+    let normalized = content.replace("\r\n", "\n");
+    let lines: Vec<&str> = normalized
+        .split('\n')
+        .map(|s| s.trim())
+        .filter(|s| !s.is_empty())
+        .collect();
+
+    let mut tles = Vec::new();
+    let mut i = 0;
+    while i + 1 < lines.len() {
+        let line1 = lines[i];
+        let line2 = lines[i + 1];
+        if line1.starts_with('1') && line2.starts_with('2') {
+            let name = (i > 0 && !lines[i - 1].starts_with('2')).then(|| lines[i - 1].to_string());
+            if validate_tle_lines(line1, line2).is_ok() {
+                if let Ok(tle) = make_tle_data(line1, line2, name) {
+                    tles.push(tle);
+                }
+            }
+            i += 2;
+        } else {
+            i += 1;
+        }
+    }
+    tles
+}
+
+/// Read all TLEs from a file path or URL
+///
+/// URLs are downloaded without caching, since histories are usually
+/// requested once per analysis.
+pub fn read_tle_sets(source: &str) -> Result<Vec<TLEData>, Box<dyn Error>> {
+    let content = if source.starts_with("http://") || source.starts_with("https://") {
+        download_tle(source)?
+    } else {
+        fs::read_to_string(source)?
+    };
+    Ok(parse_tle_sets(&content))
+}
+
 /// Download TLE from a URL (no caching)
 fn download_tle(url: &str) -> Result<String, Box<dyn Error>> {
     let agent: ureq::Agent = ureq::Agent::config_builder()
-        .timeout_global(Some(std::time::Duration::from_secs(30)))
+        .timeout_global(Some(*NETWORK_TIMEOUT))
         .build()
         .into();
     let mut response = agent.get(url).call()?;
@@ -130,17 +237,17 @@ fn download_tle(url: &str) -> Result<String, Box<dyn Error>> {
 /// Fetch TLE from a URL, using a TTL-based disk cache.
 ///
 /// Cache key is the MD5 hash of the URL, stored in `url_cache/<md5>/`.
-/// Cache TTL is controlled by `TLE_CACHE_TTL`.
-fn fetch_tle_from_url(url: &str) -> Result<TLEData, Box<dyn Error>> {
+/// Downloads older than `cache_ttl` seconds (default: `default_cache_ttl()`) are refreshed.
+fn fetch_tle_from_url(url: &str, cache_ttl: Option<u64>) -> Result<TLEData, Box<dyn Error>> {
     let hash = format!("{:x}", md5::compute(url));
     let cache_dir = epoch_cache_dir("url_cache", &hash);
-    if let Some(tle) = try_read_celestrak_cache(&cache_dir) {
+    if let Some(tle) = try_read_celestrak_cache(&cache_dir, cache_ttl) {
         return Ok(tle);
     }
     let content = download_tle(url)?;
     let tle = parse_tle_string(&content)?;
     save_tle_cache(&epoch_cache_path(&cache_dir, &tle.epoch), &content);
-    prune_tle_cache(&cache_dir, TLE_CACHE_MAX_ENTRIES);
+    prune_tle_cache(&cache_dir, *TLE_CACHE_MAX_ENTRIES);
     Ok(tle)
 }
 
@@ -158,16 +265,9 @@ fn epoch_cache_path(dir: &Path, epoch: &DateTime<Utc>) -> PathBuf {
     dir.join(format!("{}.tle", epoch.format("%Y%m%dT%H%M%S")))
 }
 
-/// Save TLE content to a cache path, creating parent directories as needed
+/// Save TLE content to a cache path atomically, creating parent directories as needed
 fn save_tle_cache(path: &Path, content: &str) {
-    if let Some(parent) = path.parent() {
-        if let Err(_e) = fs::create_dir_all(parent) {
-            #[cfg(debug_assertions)]
-            eprintln!("Warning: Failed to create TLE cache directory: {}", _e);
-            return;
-        }
-    }
-    if let Err(_e) = fs::File::create(path).and_then(|mut f| f.write_all(content.as_bytes())) {
+    if let Err(_e) = cache_io::write_atomic(path, content.as_bytes()) {
         #[cfg(debug_assertions)]
         eprintln!("Warning: Failed to write TLE to cache: {}", _e);
     }
@@ -232,7 +332,12 @@ fn try_read_epoch_cache(
             .ok()
             .and_then(|c| parse_tle_string(&c).ok())
         {
-            Some(tle) => return Some(tle),
+            Some(tle) => {
+                return Some(TLEData {
+                    from_cache: true,
+                    ..tle
+                })
+            }
             None => {
                 #[cfg(debug_assertions)]
                 eprintln!("Removing corrupt TLE cache file: {}", path.display());
@@ -244,8 +349,8 @@ fn try_read_epoch_cache(
 }
 
 /// Return the most recently downloaded TLE in `cache_dir` if its file is within TTL
-fn try_read_celestrak_cache(cache_dir: &Path) -> Option<TLEData> {
-    let ttl = Duration::from_secs(TLE_CACHE_TTL);
+fn try_read_celestrak_cache(cache_dir: &Path, cache_ttl: Option<u64>) -> Option<TLEData> {
+    let ttl = Duration::from_secs(cache_ttl.unwrap_or_else(default_cache_ttl));
     let mut best: Option<(SystemTime, PathBuf)> = None;
     for entry in fs::read_dir(cache_dir).ok()?.flatten() {
         let path = entry.path();
@@ -265,7 +370,10 @@ fn try_read_celestrak_cache(cache_dir: &Path) -> Option<TLEData> {
     }
     let content = fs::read_to_string(&path).ok()?;
     match parse_tle_string(&content) {
-        Ok(tle) => Some(tle),
+        Ok(tle) => Some(TLEData {
+            from_cache: true,
+            ..tle
+        }),
         Err(_) => {
             #[cfg(debug_assertions)]
             eprintln!("Removing corrupt TLE cache file: {}", path.display());
@@ -279,28 +387,31 @@ fn try_read_celestrak_cache(cache_dir: &Path) -> Option<TLEData> {
 // Celestrak TLE fetching with epoch-based caching
 // ============================================================================
 
-/// Fetch TLE from Celestrak by NORAD ID
-pub fn fetch_tle_by_norad_id(norad_id: u32) -> Result<TLEData, Box<dyn Error>> {
+/// Fetch TLE from Celestrak by NORAD ID, reusing a download younger than `cache_ttl` seconds
+pub fn fetch_tle_by_norad_id(
+    norad_id: u32,
+    cache_ttl: Option<u64>,
+) -> Result<TLEData, Box<dyn Error>> {
     let cache_dir = epoch_cache_dir("celestrak_cache", &norad_id.to_string());
-    if let Some(tle) = try_read_celestrak_cache(&cache_dir) {
+    if let Some(tle) = try_read_celestrak_cache(&cache_dir, cache_ttl) {
         return Ok(tle);
     }
-    let url = format!("{}?CATNR={}&FORMAT=TLE", CELESTRAK_API_BASE, norad_id);
+    let url = format!("{}?CATNR={}&FORMAT=TLE", *CELESTRAK_API_BASE, norad_id);
     let content = download_tle(&url)?;
     let tle = parse_tle_string(&content)?;
     save_tle_cache(&epoch_cache_path(&cache_dir, &tle.epoch), &content);
-    prune_tle_cache(&cache_dir, TLE_CACHE_MAX_ENTRIES);
+    prune_tle_cache(&cache_dir, *TLE_CACHE_MAX_ENTRIES);
     Ok(tle)
 }
 
-/// Fetch TLE from Celestrak by satellite name
-pub fn fetch_tle_by_name(name: &str) -> Result<TLEData, Box<dyn Error>> {
+/// Fetch TLE from Celestrak by satellite name, reusing a download younger than `cache_ttl` seconds
+pub fn fetch_tle_by_name(name: &str, cache_ttl: Option<u64>) -> Result<TLEData, Box<dyn Error>> {
     let key: String = name
         .chars()
         .map(|c| if c.is_alphanumeric() { c } else { '_' })
         .collect();
     let cache_dir = epoch_cache_dir("celestrak_cache", &key);
-    if let Some(tle) = try_read_celestrak_cache(&cache_dir) {
+    if let Some(tle) = try_read_celestrak_cache(&cache_dir, cache_ttl) {
         return Ok(tle);
     }
     let encoded = name
@@ -308,11 +419,11 @@ pub fn fetch_tle_by_name(name: &str) -> Result<TLEData, Box<dyn Error>> {
         .replace('&', "%26")
         .replace('=', "%3D")
         .replace('#', "%23");
-    let url = format!("{}?NAME={}&FORMAT=TLE", CELESTRAK_API_BASE, encoded);
+    let url = format!("{}?NAME={}&FORMAT=TLE", *CELESTRAK_API_BASE, encoded);
     let content = download_tle(&url)?;
     let tle = parse_tle_string(&content)?;
     save_tle_cache(&epoch_cache_path(&cache_dir, &tle.epoch), &content);
-    prune_tle_cache(&cache_dir, TLE_CACHE_MAX_ENTRIES);
+    prune_tle_cache(&cache_dir, *TLE_CACHE_MAX_ENTRIES);
     Ok(tle)
 }
 
@@ -342,18 +453,14 @@ impl SpaceTrackCredentials {
         Self { username, password }
     }
 
+    /// Credentials from the rust_ephem configuration (file, environment or .env)
     pub fn from_env() -> Result<Self, Box<dyn Error>> {
-        // Try to load .env files (silently ignore if not found)
-        let _ = dotenvy::dotenv();
-        if let Some(home_dir) = dirs::home_dir() {
-            let _ = dotenvy::from_path(home_dir.join(".env"));
-        }
-
-        let username = SPACETRACK_USERNAME_ENV.as_ref().cloned().ok_or(
-            "Space-Track.org username not found. Set SPACETRACK_USERNAME environment variable or pass credentials explicitly."
+        let spacetrack = &SETTINGS.settings.spacetrack;
+        let username = spacetrack.username.clone().ok_or(
+            "Space-Track.org username not found. Set SPACETRACK_USERNAME environment variable, [spacetrack] username in config.toml, or pass credentials explicitly."
         )?;
-        let password = SPACETRACK_PASSWORD_ENV.as_ref().cloned().ok_or(
-            "Space-Track.org password not found. Set SPACETRACK_PASSWORD environment variable or pass credentials explicitly."
+        let password = spacetrack.password.clone().ok_or(
+            "Space-Track.org password not found. Set SPACETRACK_PASSWORD environment variable, [spacetrack] password in config.toml, or pass credentials explicitly."
         )?;
         Ok(Self { username, password })
     }
@@ -362,11 +469,11 @@ impl SpaceTrackCredentials {
 /// Authenticate with Space-Track.org and create an authenticated agent
 ///
 /// Space-Track uses cookie-based session authentication.
-fn create_spacetrack_agent(
+pub(crate) fn create_spacetrack_agent(
     credentials: &SpaceTrackCredentials,
 ) -> Result<ureq::Agent, Box<dyn Error>> {
     let agent: ureq::Agent = ureq::Agent::config_builder()
-        .timeout_global(Some(std::time::Duration::from_secs(30)))
+        .timeout_global(Some(*NETWORK_TIMEOUT))
         .build()
         .into();
 
@@ -375,7 +482,9 @@ fn create_spacetrack_agent(
         ("identity", credentials.username.as_str()),
         ("password", credentials.password.as_str()),
     ];
-    let mut login_response = agent.post(SPACETRACK_LOGIN_URL).send_form(form_data)?;
+    let mut login_response = agent
+        .post(SPACETRACK_LOGIN_URL.as_str())
+        .send_form(form_data)?;
 
     if login_response.status() != 200 {
         return Err(format!(
@@ -403,7 +512,7 @@ fn create_spacetrack_agent(
 /// * `norad_id` - NORAD catalog ID of the satellite
 /// * `target_epoch` - The epoch for which to find the closest TLE
 /// * `credentials` - Optional credentials (will try env vars if None)
-/// * `epoch_tolerance_days` - How many days tolerance for cache matching (default: 2.0)
+/// * `epoch_tolerance_days` - How many days tolerance for cache matching (default: `default_epoch_tolerance_days()`)
 ///
 /// # Returns
 /// TLEData containing the TLE lines and epoch
@@ -413,7 +522,7 @@ pub fn fetch_tle_from_spacetrack(
     credentials: Option<SpaceTrackCredentials>,
     epoch_tolerance_days: Option<f64>,
 ) -> Result<TLEData, Box<dyn Error>> {
-    let tolerance = epoch_tolerance_days.unwrap_or(*DEFAULT_EPOCH_TOLERANCE_DAYS);
+    let tolerance = epoch_tolerance_days.unwrap_or_else(default_epoch_tolerance_days);
     let cache_dir = epoch_cache_dir("spacetrack_cache", &norad_id.to_string());
 
     // Try to use cached version if epoch is within tolerance
@@ -441,7 +550,7 @@ pub fn fetch_tle_from_spacetrack(
     // We order by epoch descending and get the one closest to our target
     let query_url = format!(
         "{}/basicspacedata/query/class/gp_history/NORAD_CAT_ID/{}/EPOCH/{}--{}/orderby/EPOCH%20desc/format/tle",
-        SPACETRACK_API_BASE, norad_id, start_str, end_str
+        *SPACETRACK_API_BASE, norad_id, start_str, end_str
     );
 
     #[cfg(debug_assertions)]
@@ -476,7 +585,7 @@ pub fn fetch_tle_from_spacetrack(
         &epoch_cache_path(&cache_dir, &best_tle.epoch),
         &cache_content,
     );
-    prune_tle_cache(&cache_dir, SPACETRACK_CACHE_MAX_ENTRIES);
+    prune_tle_cache(&cache_dir, *SPACETRACK_CACHE_MAX_ENTRIES);
 
     Ok(best_tle)
 }
@@ -486,39 +595,47 @@ fn find_closest_tle_to_epoch(
     content: &str,
     target_epoch: &DateTime<Utc>,
 ) -> Result<TLEData, Box<dyn Error>> {
-    let normalized = content.replace("\r\n", "\n");
-    let lines: Vec<&str> = normalized
-        .split('\n')
-        .map(|s| s.trim())
-        .filter(|s| !s.is_empty())
-        .collect();
+    parse_tle_sets(content)
+        .into_iter()
+        .min_by_key(|tle| (*target_epoch - tle.epoch).num_seconds().abs())
+        .ok_or_else(|| "No valid TLE found in Space-Track response".into())
+}
 
-    let mut best_tle: Option<TLEData> = None;
-    let mut best_diff = i64::MAX;
+/// Fetch every TLE for an object with an epoch between `start` and `end`
+///
+/// Queries Space-Track.org's GP history class, which keeps all element sets
+/// ever published. Results are sorted by epoch and are not cached.
+pub fn fetch_tle_history_from_spacetrack(
+    norad_id: u32,
+    start: &DateTime<Utc>,
+    end: &DateTime<Utc>,
+    credentials: Option<SpaceTrackCredentials>,
+) -> Result<Vec<TLEData>, Box<dyn Error>> {
+    let creds = credentials
+        .map(Ok)
+        .unwrap_or_else(SpaceTrackCredentials::from_env)?;
+    let agent = create_spacetrack_agent(&creds)?;
 
-    // Process lines in pairs (TLE line 1 and line 2)
-    let mut i = 0;
-    while i + 1 < lines.len() {
-        let line1 = lines[i];
-        let line2 = lines[i + 1];
+    let query_url = format!(
+        "{}/basicspacedata/query/class/gp_history/NORAD_CAT_ID/{}/EPOCH/{}--{}/orderby/EPOCH%20asc/format/tle",
+        *SPACETRACK_API_BASE,
+        norad_id,
+        start.format("%Y-%m-%dT%H:%M:%S"),
+        end.format("%Y-%m-%dT%H:%M:%S")
+    );
 
-        // Check if these are valid TLE lines
-        if line1.starts_with('1') && line2.starts_with('2') {
-            if let Ok(tle) = parse_tle_string(&format!("{}\n{}", line1, line2)) {
-                let diff = (*target_epoch - tle.epoch).num_seconds().abs();
-                if diff < best_diff {
-                    best_diff = diff;
-                    best_tle = Some(tle);
-                }
-            }
-            i += 2;
-        } else {
-            // Skip non-TLE lines (might be a 3-line format with name)
-            i += 1;
-        }
+    let mut response = agent.get(&query_url).call()?;
+    if response.status() != 200 {
+        return Err(format!(
+            "Space-Track.org query failed with status: {}",
+            response.status()
+        )
+        .into());
     }
 
-    best_tle.ok_or_else(|| "No valid TLE found in Space-Track response".into())
+    let mut tles = parse_tle_sets(&response.body_mut().read_to_string()?);
+    tles.sort_by_key(|tle| tle.epoch);
+    Ok(tles)
 }
 
 // ============================================================================
@@ -533,6 +650,10 @@ pub struct FetchedTLE {
     pub name: Option<String>,
     pub epoch: DateTime<Utc>,
     pub source: &'static str,
+    /// SATCAT decay date of the object, looked up for NORAD ID and name fetches
+    pub decay_date: Option<NaiveDate>,
+    /// Read from the local TLE cache rather than downloaded
+    pub from_cache: bool,
 }
 
 impl FetchedTLE {
@@ -559,6 +680,8 @@ impl TLEData {
             name: self.name,
             epoch: self.epoch,
             source,
+            decay_date: None,
+            from_cache: self.from_cache,
         }
     }
 }
@@ -604,18 +727,19 @@ pub fn build_credentials(
 /// * `norad_name` - Optional satellite name for Celestrak lookup
 /// * `target_epoch` - Optional target epoch for Space-Track lookups
 /// * `credentials` - Optional Space-Track.org credentials
-/// * `epoch_tolerance_days` - Optional tolerance for Space-Track cache matching
+/// * `cache` - Cache TTL and Space-Track epoch tolerance overrides
 /// * `enforce_source` - Optional source enforcement: "celestrak", "spacetrack", or None for default behavior
 ///
 /// # Returns
-/// `FetchedTLE` containing the TLE data, epoch, and source information
+/// `FetchedTLE` containing the TLE data, epoch, and source information. For
+/// NORAD ID and name lookups the SATCAT decay date is attached when known.
 pub fn fetch_tle_unified(
     tle_path: Option<&str>,
     norad_id: Option<u32>,
     norad_name: Option<&str>,
     target_epoch: Option<&DateTime<Utc>>,
     credentials: Option<SpaceTrackCredentials>,
-    epoch_tolerance_days: Option<f64>,
+    cache: TleCacheOptions,
     enforce_source: Option<&str>,
 ) -> Result<FetchedTLE, Box<dyn Error>> {
     if let Some(tle_param) = tle_path {
@@ -625,23 +749,31 @@ pub fn fetch_tle_unified(
             "file"
         };
         let tle_data = if src == "url" {
-            fetch_tle_from_url(tle_param)?
+            fetch_tle_from_url(tle_param, cache.cache_ttl)?
         } else {
             read_tle_file(tle_param)?
         };
-        Ok(tle_data.into_fetched(src))
-    } else if let Some(nid) = norad_id {
+        return Ok(tle_data.into_fetched(src));
+    }
+
+    let satcat_credentials = credentials.clone();
+    let fetched: Result<FetchedTLE, Box<dyn Error>> = if let Some(nid) = norad_id {
         match enforce_source {
-            Some("celestrak") => Ok(fetch_tle_by_norad_id(nid)?.into_fetched("celestrak")),
+            Some("celestrak") => {
+                Ok(fetch_tle_by_norad_id(nid, cache.cache_ttl)?.into_fetched("celestrak"))
+            }
             Some("spacetrack") => {
                 let creds = credentials.ok_or(
                     "Space-Track.org credentials required when enforce_source='spacetrack'",
                 )?;
                 let target = target_epoch.cloned().unwrap_or_else(chrono::Utc::now);
-                Ok(
-                    fetch_tle_from_spacetrack(nid, &target, Some(creds), epoch_tolerance_days)?
-                        .into_fetched("spacetrack"),
-                )
+                Ok(fetch_tle_from_spacetrack(
+                    nid,
+                    &target,
+                    Some(creds),
+                    cache.epoch_tolerance_days,
+                )?
+                .into_fetched("spacetrack"))
             }
             Some(other) => Err(format!(
                 "Invalid enforce_source value: {}. Must be 'celestrak', 'spacetrack', or None",
@@ -651,8 +783,12 @@ pub fn fetch_tle_unified(
             None => {
                 if let Some(creds) = credentials {
                     let target = target_epoch.cloned().unwrap_or_else(chrono::Utc::now);
-                    match fetch_tle_from_spacetrack(nid, &target, Some(creds), epoch_tolerance_days)
-                    {
+                    match fetch_tle_from_spacetrack(
+                        nid,
+                        &target,
+                        Some(creds),
+                        cache.epoch_tolerance_days,
+                    ) {
                         Ok(tle) => Ok(tle.into_fetched("spacetrack")),
                         Err(_err) => {
                             #[cfg(debug_assertions)]
@@ -660,19 +796,62 @@ pub fn fetch_tle_unified(
                                 "Space-Track.org fetch failed, falling back to Celestrak: {}",
                                 _err
                             );
-                            Ok(fetch_tle_by_norad_id(nid)?.into_fetched("celestrak"))
+                            Ok(fetch_tle_by_norad_id(nid, cache.cache_ttl)?
+                                .into_fetched("celestrak"))
                         }
                     }
                 } else {
-                    Ok(fetch_tle_by_norad_id(nid)?.into_fetched("celestrak"))
+                    Ok(fetch_tle_by_norad_id(nid, cache.cache_ttl)?.into_fetched("celestrak"))
                 }
             }
         }
     } else if let Some(name_query) = norad_name {
-        Ok(fetch_tle_by_name(name_query)?.into_fetched("celestrak"))
+        Ok(fetch_tle_by_name(name_query, cache.cache_ttl)?.into_fetched("celestrak"))
     } else {
         Err("Must provide one of: tle path/URL, norad_id, or norad_name".into())
+    };
+    let fetched = fetched?;
+    let credentials = satcat_credentials.filter(|_| fetched.source == "spacetrack");
+    Ok(with_decay_date(
+        fetched,
+        credentials.as_ref(),
+        cache.cache_ttl,
+    ))
+}
+
+/// Attach the SATCAT decay date of the fetched object
+///
+/// Skipped when disabled by the `[tle] check_decay` setting. A TLE read from
+/// the cache only gets a decay date already in the SATCAT cache, so cached
+/// fetches stay offline. A failed lookup only skips the decay check, so it is
+/// not an error.
+fn with_decay_date(
+    mut fetched: FetchedTLE,
+    credentials: Option<&SpaceTrackCredentials>,
+    cache_ttl: Option<u64>,
+) -> FetchedTLE {
+    if !*TLE_CHECK_DECAY {
+        return fetched;
+    }
+    let norad_id = fetched
+        .line1
+        .get(2..7)
+        .and_then(|id| id.trim().parse().ok());
+    let Some(norad_id) = norad_id else {
+        return fetched;
+    };
+    if fetched.from_cache {
+        fetched.decay_date = satcat::cached_decay_date(norad_id);
+        return fetched;
     }
+    match satcat::decay_date(norad_id, credentials, cache_ttl) {
+        Ok(decay_date) => fetched.decay_date = decay_date,
+        Err(_err) => {
+            #[cfg(debug_assertions)]
+            eprintln!("SATCAT lookup failed for NORAD ID {}: {}", norad_id, _err);
+        }
+    }
+    fetched
 }
 
 /// Extract TLE epoch from TLE lines
@@ -746,6 +925,16 @@ mod tests {
         assert_eq!(result.line2.len(), 69);
     }
 
+    #[test]
+    fn test_parse_tle_sets() {
+        let content = "ISS (ZARYA)\n1 25544U 98067A   08264.51782528 -.00002182  00000-0 -11606-4 0  2927\n2 25544  51.6416 247.4627 0006703 130.5360 325.0288 15.72125391563537\n\n1 28485U 04047A   25287.56748435  .00035474  00000+0  70906-3 0  9995\r\n2 28485  20.5535 247.0048 0005179 187.1586 172.8782 15.44937919148530\nnot a tle\n";
+        let tles = parse_tle_sets(content);
+        assert_eq!(tles.len(), 2);
+        assert_eq!(tles[0].name.as_deref(), Some("ISS (ZARYA)"));
+        assert!(tles[1].name.is_none());
+        assert_eq!(tles[1].epoch.year(), 2025);
+    }
+
     #[test]
     fn test_extract_epoch() {
         let line1 = "1 28485U 04047A   25287.56748435  .00035474  00000+0  70906-3 0  9995";
@@ -0,0 +1,277 @@
+//! Error budget of the GCRS↔ITRS transformation
+//!
+//! Reports how far the Earth-fixed frame produced by `convert_frames` may be
+//! rotated from the true ITRS at an epoch, given the Earth orientation data
+//! that is loaded. The terms are angles, so they apply equally to the
+//! direction of a celestial target seen from a ground site and, scaled by the
+//! Earth's radius, to the geodetic position of a satellite sub-point:
+//!
+//! - `dut1`: error in UT1 and hence in the Earth rotation angle. Tabulated
+//!   observed values are good to tens of microseconds; IERS predictions grow
+//!   as 0.00025 D^0.75 s for D days ahead (IERS Bulletin A); without data
+//!   UT1 is taken as UTC, which is kept within 0.9 s.
+//! - `polar_motion`: error in the pole position, the whole polar motion when
+//!   it is not applied or no EOP data is loaded, otherwise the Bulletin A
+//!   prediction error 0.00068 D^0.80″ (x) and 0.00049 D^0.80″ (y).
+//! - `precession_nutation`: the GCRS↔ITRS rotation uses the Earth rotation
+//!   angle without the celestial-to-intermediate (IAU 2006/2000A
+//!   precession-nutation) matrix, so the offset of the celestial
+//!   intermediate pole from the GCRS pole is not corrected.
+//! - `leap_seconds`: more than about six months ahead a leap second may yet
+//!   be announced, shifting UTC and the predicted UT1 by a second.
+//!
+//! UT1 and polar motion come from the same EOP2 file, so its MJD range is
+//! taken as the coverage of both; days after the current date count as
+//! predictions.
+
+use chrono::{DateTime, Utc};
+use erfa::prenut::pn_matrix_06a;
+use serde_json::json;
+
+use crate::utils::config::{ARCSEC_TO_RAD, EARTH_RADIUS_KM};
+use crate::utils::eop_provider::{eop_mjd_range, get_polar_motion, is_eop_available};
+use crate::utils::time_utils::{datetime_to_jd_tt, datetime_to_mjd, get_tai_utc_offset};
+use crate::utils::ut1_provider::{get_ut1_utc_offset, is_ut1_available};
+
+/// Earth rotation angle per second of UT1, in arcseconds
+const ARCSEC_PER_UT1_SECOND: f64 = 15.0 * 1.002_737_811_911_354_5;
+
+/// Largest |UT1-UTC| allowed by the leap-second rule, in seconds
+const MAX_DUT1_S: f64 = 0.9;
+
+/// Accuracy of observed UT1-UTC values, in seconds
+const OBSERVED_DUT1_S: f64 = 2.0e-5;
+
+/// Bound on the polar motion amplitude, in arcseconds
+const MAX_POLAR_MOTION_ARCSEC: f64 = 0.6;
+
+/// Accuracy of observed polar motion values, in arcseconds
+const OBSERVED_POLAR_MOTION_ARCSEC: f64 = 3.0e-5;
+
+/// How far ahead leap seconds are announced (IERS Bulletin C), in days
+const LEAP_SECOND_NOTICE_DAYS: f64 = 183.0;
+
+/// Earth orientation data available for an assessment
+#[derive(Debug, Clone, PartialEq)]
+pub struct EopState {
+    pub ut1_available: bool,
+    /// MJD range of the EOP data, None when no EOP data is loaded
+    pub eop_mjd_range: Option<(f64, f64)>,
+    /// Polar motion (xp, yp) in arcseconds at the epoch
+    pub polar_motion_arcsec: (f64, f64),
+    /// UT1-UTC in seconds at the epoch
+    pub ut1_utc_s: f64,
+    /// TAI-UTC in seconds at the epoch, if the leap-second table covers it
+    pub tai_utc_s: Option<f64>,
+}
+
+impl EopState {
+    /// State of the global UT1 and EOP providers at an epoch
+    pub fn current(dt: &DateTime<Utc>) -> Self {
+        EopState {
+            ut1_available: is_ut1_available(),
+            eop_mjd_range: if is_eop_available() {
+                eop_mjd_range()
+            } else {
+                None
+            },
+            polar_motion_arcsec: get_polar_motion(dt),
+            ut1_utc_s: get_ut1_utc_offset(dt),
+            tai_utc_s: get_tai_utc_offset(dt),
+        }
+    }
+}
+
+/// Error contributions of the GCRS↔ITRS transformation at one epoch
+#[derive(Debug, Clone, PartialEq)]
+pub struct TransformAccuracy {
+    pub epoch: DateTime<Utc>,
+    pub polar_motion: bool,
+    pub state: EopState,
+    /// Days past the last observed EOP value (0 for observed epochs)
+    pub days_predicted: f64,
+    /// Whether the epoch lies outside the EOP data range
+    pub beyond_eop_data: bool,
+    pub dut1_arcsec: f64,
+    pub polar_motion_arcsec: f64,
+    pub precession_nutation_arcsec: f64,
+    pub leap_seconds_arcsec: f64,
+}
+
+impl TransformAccuracy {
+    /// Root-sum-square of the contributions, in arcseconds
+    pub fn total_arcsec(&self) -> f64 {
+        [
+            self.dut1_arcsec,
+            self.polar_motion_arcsec,
+            self.precession_nutation_arcsec,
+            self.leap_seconds_arcsec,
+        ]
+        .iter()
+        .map(|term| term * term)
+        .sum::<f64>()
+        .sqrt()
+    }
+
+    pub fn to_json(&self) -> serde_json::Value {
+        let to_m = |arcsec: f64| arcsec * ARCSEC_TO_RAD * EARTH_RADIUS_KM * 1000.0;
+        let total = self.total_arcsec();
+        json!({
+            "epoch": self.epoch.to_rfc3339(),
+            "polar_motion": self.polar_motion,
+            "ut1_available": self.state.ut1_available,
+            "eop_available": self.state.eop_mjd_range.is_some(),
+            "eop_mjd_range": self.state.eop_mjd_range.map(|(a, b)| vec![a, b]),
+            "beyond_eop_data": self.beyond_eop_data,
+            "days_predicted": self.days_predicted,
+            "ut1_utc_s": self.state.ut1_utc_s,
+            "tai_utc_s": self.state.tai_utc_s,
+            "xp_arcsec": self.state.polar_motion_arcsec.0,
+            "yp_arcsec": self.state.polar_motion_arcsec.1,
+            "contributions_arcsec": {
+                "dut1": self.dut1_arcsec,
+                "polar_motion": self.polar_motion_arcsec,
+                "precession_nutation": self.precession_nutation_arcsec,
+                "leap_seconds": self.leap_seconds_arcsec,
+            },
+            "total_arcsec": total,
+            "total_m": to_m(total),
+        })
+    }
+}
+
+/// Offset of the celestial intermediate pole from the GCRS pole, in arcseconds
+fn cip_offset_arcsec(dt: &DateTime<Utc>) -> f64 {
+    let (jd_tt1, jd_tt2) = datetime_to_jd_tt(dt);
+    let bpn = pn_matrix_06a(jd_tt1, jd_tt2);
+    bpn[2][0].hypot(bpn[2][1]) / ARCSEC_TO_RAD
+}
+
+/// Assess the transformation at `dt` for the given data, with `now` the
+/// boundary between observed and predicted EOP values
+pub fn assess(
+    dt: &DateTime<Utc>,
+    polar_motion: bool,
+    state: EopState,
+    now: &DateTime<Utc>,
+) -> TransformAccuracy {
+    let mjd = datetime_to_mjd(dt);
+    let now_mjd = datetime_to_mjd(now);
+    let beyond_eop_data = state
+        .eop_mjd_range
+        .is_none_or(|(first, last)| mjd < first || mjd > last);
+    // Predictions start after the last observation; past the end of the
+    // data the held edge value ages from the last record instead
+    let last_observed = match state.eop_mjd_range {
+        Some((_, last)) => now_mjd.min(last),
+        None => now_mjd,
+    };
+    let days_predicted = (mjd - last_observed).max(0.0);
+
+    let dut1_s = if !state.ut1_available || beyond_eop_data {
+        MAX_DUT1_S
+    } else if days_predicted > 0.0 {
+        (0.00025 * days_predicted.powf(0.75)).min(MAX_DUT1_S)
+    } else {
+        OBSERVED_DUT1_S
+    };
+
+    let polar_motion_arcsec = if !polar_motion || state.eop_mjd_range.is_none() {
+        let (xp, yp) = state.polar_motion_arcsec;
+        if state.eop_mjd_range.is_some() && !beyond_eop_data {
+            xp.hypot(yp)
+        } else {
+            MAX_POLAR_MOTION_ARCSEC
+        }
+    } else if days_predicted > 0.0 {
+        let growth = days_predicted.powf(0.80);
+        (0.00068 * growth)
+            .hypot(0.00049 * growth)
+            .min(MAX_POLAR_MOTION_ARCSEC)
+    } else {
+        OBSERVED_POLAR_MOTION_ARCSEC
+    };
+
+    let leap_seconds_arcsec = if mjd - now_mjd > LEAP_SECOND_NOTICE_DAYS {
+        ARCSEC_PER_UT1_SECOND
+    } else {
+        0.0
+    };
+
+    TransformAccuracy {
+        epoch: *dt,
+        polar_motion,
+        state,
+        days_predicted,
+        beyond_eop_data,
+        dut1_arcsec: dut1_s * ARCSEC_PER_UT1_SECOND,
+        polar_motion_arcsec,
+        precession_nutation_arcsec: cip_offset_arcsec(dt),
+        leap_seconds_arcsec,
+    }
+}
+
+/// Assess the transformation at `dt` with the data currently loaded
+pub fn transform_accuracy(dt: &DateTime<Utc>, polar_motion: bool) -> TransformAccuracy {
+    assess(dt, polar_motion, EopState::current(dt), &Utc::now())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::{Duration, TimeZone};
+
+    fn state(range: Option<(f64, f64)>) -> EopState {
+        EopState {
+            ut1_available: range.is_some(),
+            eop_mjd_range: range,
+            polar_motion_arcsec: (0.1, 0.3),
+            ut1_utc_s: 0.01,
+            tai_utc_s: Some(37.0),
+        }
+    }
+
+    #[test]
+    fn test_without_data_uses_bounds() {
+        let now = Utc.with_ymd_and_hms(2024, 6, 1, 0, 0, 0).unwrap();
+        let report = assess(&now, true, state(None), &now);
+        assert!(report.beyond_eop_data);
+        assert!((report.dut1_arcsec - 0.9 * ARCSEC_PER_UT1_SECOND).abs() < 1e-9);
+        assert_eq!(report.polar_motion_arcsec, MAX_POLAR_MOTION_ARCSEC);
+        assert_eq!(report.leap_seconds_arcsec, 0.0);
+    }
+
+    #[test]
+    fn test_observed_and_predicted_epochs() {
+        let now = Utc.with_ymd_and_hms(2024, 6, 1, 0, 0, 0).unwrap();
+        let now_mjd = datetime_to_mjd(&now);
+        let range = Some((now_mjd - 400.0, now_mjd + 365.0));
+
+        let past = assess(&(now - Duration::days(30)), true, state(range), &now);
+        assert_eq!(past.days_predicted, 0.0);
+        assert!(past.dut1_arcsec < 1e-3);
+        assert_eq!(past.polar_motion_arcsec, OBSERVED_POLAR_MOTION_ARCSEC);
+
+        let ahead = assess(&(now + Duration::days(100)), true, state(range), &now);
+        assert!((ahead.days_predicted - 100.0).abs() < 1e-6);
+        // Bulletin A: about 8 ms of UT1 and 27 mas of polar motion after 100 days
+        let dut1_s = ahead.dut1_arcsec / ARCSEC_PER_UT1_SECOND;
+        assert!((dut1_s - 0.00025 * 100f64.powf(0.75)).abs() < 1e-9);
+        assert!(ahead.polar_motion_arcsec > 0.02 && ahead.polar_motion_arcsec < 0.04);
+
+        // Not applying polar motion leaves the whole offset as error
+        let off = assess(&(now - Duration::days(30)), false, state(range), &now);
+        assert!((off.polar_motion_arcsec - 0.1f64.hypot(0.3)).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_leap_second_and_precession_terms() {
+        let now = Utc.with_ymd_and_hms(2024, 1, 1, 0, 0, 0).unwrap();
+        let report = assess(&(now + Duration::days(365)), true, state(None), &now);
+        assert_eq!(report.leap_seconds_arcsec, ARCSEC_PER_UT1_SECOND);
+        // About 2004″ of precession in X per century since J2000
+        assert!(report.precession_nutation_arcsec > 450.0);
+        assert!(report.precession_nutation_arcsec < 550.0);
+        assert!(report.total_arcsec() >= report.precession_nutation_arcsec);
+    }
+}
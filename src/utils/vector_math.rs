@@ -50,6 +50,40 @@ pub fn dot_product(a: &[f64; 3], b: &[f64; 3]) -> f64 {
     a[0] * b[0] + a[1] * b[1] + a[2] * b[2]
 }
 
+/// Calculate the cross product of two 3D vectors
+///
+/// # Arguments
+/// * `a` - First vector [x, y, z]
+/// * `b` - Second vector [x, y, z]
+///
+/// # Returns
+/// Vector a×b
+pub fn cross_product(a: &[f64; 3], b: &[f64; 3]) -> [f64; 3] {
+    [
+        a[1] * b[2] - a[2] * b[1],
+        a[2] * b[0] - a[0] * b[2],
+        a[0] * b[1] - a[1] * b[0],
+    ]
+}
+
+/// Radial / in-track / cross-track unit vectors of an orbital state
+///
+/// Radial is along the position, cross-track along the orbital angular
+/// momentum, and in-track completes the right-handed triad.
+///
+/// # Arguments
+/// * `r` - Position vector [x, y, z]
+/// * `v` - Velocity vector [vx, vy, vz]
+///
+/// # Returns
+/// Rows [radial, in_track, cross_track]
+pub fn ric_basis(r: &[f64; 3], v: &[f64; 3]) -> [[f64; 3]; 3] {
+    let radial = normalize_vector(r);
+    let cross_track = normalize_vector(&cross_product(r, v));
+    let in_track = cross_product(&cross_track, &radial);
+    [radial, in_track, cross_track]
+}
+
 /// Calculate the magnitude (length) of a 3D vector
 ///
 /// # Arguments
@@ -216,6 +250,37 @@ pub fn unit_vectors_to_radec_batch(unit_vectors: &Array2<f64>) -> (Vec<f64>, Vec
     }
 }
 
+/// Angle between corresponding rows of two direction arrays (vectorized)
+///
+/// Rows need not be unit vectors. Uses atan2(|a x b|, a . b), which stays
+/// accurate for nearly parallel and nearly opposite directions where acos of
+/// the dot product loses precision.
+///
+/// # Arguments
+/// * `a`, `b` - Array2 with shape (N, 3) or wider; only the first three columns are used
+///
+/// # Returns
+/// Separation angles in degrees, length N
+pub fn separation_angles_deg(a: &Array2<f64>, b: &Array2<f64>) -> Vec<f64> {
+    assert_eq!(
+        a.nrows(),
+        b.nrows(),
+        "direction arrays must have equal rows"
+    );
+
+    a.rows()
+        .into_iter()
+        .zip(b.rows())
+        .map(|(ra, rb)| {
+            let u = [ra[0], ra[1], ra[2]];
+            let v = [rb[0], rb[1], rb[2]];
+            vector_magnitude(&cross_product(&u, &v))
+                .atan2(dot_product(&u, &v))
+                .to_degrees()
+        })
+        .collect()
+}
+
 /// Build a 3x3 rotation matrix from intrinsic Z-Y-X Euler angles in degrees.
 ///
 /// Rotation order is yaw (Z), pitch (Y), roll (X):
@@ -155,3 +155,22 @@ class TestAirmassConstraint:
                 err_msg=f"Batch result row {i} doesn't match single evaluation for "
                 f"target (RA={target_ras[i]}, Dec={target_decs[i]})",
             )
+
+    def test_airmass_constraint_matches_topocentric_airmass(
+        self, ground_ephemeris: "rust_ephem.GroundEphemeris"
+    ) -> None:
+        """Test that violations follow the topocentric airmass of the site."""
+        constraint = AirmassConstraint(max_airmass=2.0)
+        target_ras = [0.0, 90.0, 200.0]
+        target_decs = [35.0, 10.0, -20.0]
+        result = constraint.in_constraint_batch(
+            ground_ephemeris, target_ras, target_decs
+        )
+        for i, (ra, dec) in enumerate(zip(target_ras, target_decs)):
+            airmass = np.array(ground_ephemeris.calculate_airmass(ra, dec))
+            # The constraint skips aberration and light deflection (under 22",
+            # about 1.07e-4 rad), and at airmass 2 (altitude 30 deg) airmass
+            # changes by cos(h) / sin(h)^2 = 3.46 per radian, so the two can
+            # only disagree within 3.7e-4 of the limit
+            clear = np.abs(airmass - 2.0) > 4e-4
+            np.testing.assert_array_equal(result[i, clear], airmass[clear] > 2.0)
@@ -0,0 +1,21 @@
+"""Fixtures for airmass summary tests."""
+
+import datetime
+
+import pytest
+
+from rust_ephem import GroundEphemeris
+
+BEGIN = datetime.datetime(2024, 1, 1, 0, 0, 0, tzinfo=datetime.timezone.utc)
+END = datetime.datetime(2024, 1, 2, 0, 0, 0, tzinfo=datetime.timezone.utc)
+STEP_SIZE = 600
+
+# Orion Nebula and Vega rise from 35 N; the south celestial pole never does
+RAS = [83.82, 279.23, 0.0]
+DECS = [-5.39, 38.78, -89.0]
+NEVER_RISES = 2
+
+
+@pytest.fixture
+def site() -> GroundEphemeris:
+    return GroundEphemeris(35.0, -120.0, 500.0, BEGIN, END, STEP_SIZE)
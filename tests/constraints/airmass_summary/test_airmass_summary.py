@@ -0,0 +1,46 @@
+"""Tests for airmass_summary."""
+
+import numpy as np
+import pytest
+
+from rust_ephem import GroundEphemeris, airmass_summary
+
+from .conftest import DECS, NEVER_RISES, RAS, STEP_SIZE
+
+
+def test_one_entry_per_target(site: GroundEphemeris) -> None:
+    summary = airmass_summary(site, RAS, DECS)
+    assert len(summary) == len(RAS)
+    assert summary.min_airmass.shape == (len(RAS),)
+    assert summary.hours_below.shape == (len(RAS),)
+    assert summary.max_airmass == 2.0
+
+
+def test_min_airmass_matches_calculate_airmass(site: GroundEphemeris) -> None:
+    summary = airmass_summary(site, RAS, DECS)
+    for i, (ra, dec) in enumerate(zip(RAS[:NEVER_RISES], DECS[:NEVER_RISES])):
+        airmass = np.array(site.calculate_airmass(ra, dec))
+        assert summary.min_airmass[i] == pytest.approx(airmass.min(), rel=0.02)
+        assert summary.min_airmass_time[i] is not None
+
+
+def test_never_rising_target(site: GroundEphemeris) -> None:
+    summary = airmass_summary(site, RAS, DECS)
+    assert np.isinf(summary.min_airmass[NEVER_RISES])
+    assert summary.min_airmass_time[NEVER_RISES] is None
+    assert summary.hours_below[NEVER_RISES] == 0.0
+
+
+def test_hours_below_grows_with_limit(site: GroundEphemeris) -> None:
+    strict = airmass_summary(site, RAS, DECS, max_airmass=1.5).hours_below
+    loose = airmass_summary(site, RAS, DECS, max_airmass=3.0).hours_below
+    assert np.all(loose >= strict)
+    assert loose[0] > 0.0
+    assert np.all(loose <= 24.0 - STEP_SIZE / 3600.0)
+
+
+def test_invalid_arguments(site: GroundEphemeris) -> None:
+    with pytest.raises(ValueError, match="same length"):
+        airmass_summary(site, [0.0, 1.0], [0.0])
+    with pytest.raises(ValueError, match="at least 1"):
+        airmass_summary(site, RAS, DECS, max_airmass=0.5)
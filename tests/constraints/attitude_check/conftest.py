@@ -0,0 +1,40 @@
+"""Fixtures for attitude check tests."""
+
+from datetime import datetime, timedelta, timezone
+
+import numpy as np
+import pytest
+
+from rust_ephem import ArrayEphemeris, Constraint
+
+BEGIN = datetime(2024, 1, 1, 0, 0, 0, tzinfo=timezone.utc)
+N_POINTS = 6
+# The spacecraft turns its boresight (+X) from zenith to nadir after this sample
+LAST_ZENITH = 2
+
+IDENTITY = [0.0, 0.0, 0.0, 1.0]
+# 180 degrees about +Z, scalar last
+FLIP_Z = [0.0, 0.0, 1.0, 0.0]
+
+
+@pytest.fixture
+def spacecraft() -> ArrayEphemeris:
+    """Spacecraft parked at 7000 km on +x, so +y is about 24 degrees above the limb"""
+    times = [BEGIN + timedelta(minutes=i) for i in range(N_POINTS)]
+    states = np.zeros((N_POINTS, 6))
+    states[:, 0] = 7000.0
+    return ArrayEphemeris(times, states)
+
+
+@pytest.fixture
+def quaternions() -> list[list[float]]:
+    return [IDENTITY] * (LAST_ZENITH + 1) + [FLIP_Z] * (N_POINTS - LAST_ZENITH - 1)
+
+
+@pytest.fixture
+def constraints() -> list[Constraint]:
+    """Earth limb at 10 degrees, and at 30 degrees which also blocks +y"""
+    return [
+        Constraint.earth_limb(min_angle=10.0),
+        Constraint.earth_limb(min_angle=30.0),
+    ]
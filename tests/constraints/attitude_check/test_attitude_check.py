@@ -0,0 +1,82 @@
+"""Tests for check_attitude."""
+
+import numpy as np
+import pytest
+
+from rust_ephem import ArrayEphemeris, Constraint, check_attitude
+
+from .conftest import IDENTITY, LAST_ZENITH, N_POINTS
+
+
+def test_boresight_turned_to_earth_is_violated(
+    spacecraft: ArrayEphemeris,
+    quaternions: list[list[float]],
+    constraints: list[Constraint],
+) -> None:
+    check = check_attitude(spacecraft, quaternions, constraints[:1])
+    assert not check.all_satisfied
+    assert check.axis_names == ["boresight"]
+    assert check.mask.shape == (1, 1, N_POINTS)
+    expected = np.arange(N_POINTS) > LAST_ZENITH
+    np.testing.assert_array_equal(check.mask[0, 0], expected)
+
+    (violation,) = check.violations
+    assert violation.axis == "boresight"
+    assert violation.constraint == check.constraint_names[0]
+    assert violation.start_time == check.timestamp[LAST_ZENITH + 1]
+    assert violation.end_time == check.timestamp[-1]
+
+
+def test_violations_tagged_by_axis(
+    spacecraft: ArrayEphemeris,
+    quaternions: list[list[float]],
+    constraints: list[Constraint],
+) -> None:
+    check = check_attitude(
+        spacecraft,
+        quaternions,
+        constraints,
+        axes={"boresight": (1.0, 0.0, 0.0), "star_tracker": (0.0, 1.0, 0.0)},
+    )
+    assert check.mask.shape == (2, 2, N_POINTS)
+    # The star tracker stays 24 degrees above the limb: only the 30 degree
+    # limit is violated, throughout
+    assert not check.mask[1, 0].any()
+    assert check.mask[1, 1].all()
+    tagged = [(v.axis, v.constraint) for v in check.violations]
+    assert tagged == [
+        ("boresight", check.constraint_names[0]),
+        ("boresight", check.constraint_names[1]),
+        ("star_tracker", check.constraint_names[1]),
+    ]
+
+
+def test_all_satisfied(
+    spacecraft: ArrayEphemeris, constraints: list[Constraint]
+) -> None:
+    # Quaternions need not be normalized
+    quaternions = np.tile([0.0, 0.0, 0.0, 2.0], (N_POINTS, 1))
+    check = check_attitude(spacecraft, quaternions, constraints[:1])
+    assert check.all_satisfied
+    assert len(check) == 0
+
+
+def test_quaternion_count_must_match(
+    spacecraft: ArrayEphemeris, constraints: list[Constraint]
+) -> None:
+    with pytest.raises(ValueError, match="one quaternion per ephemeris time"):
+        check_attitude(spacecraft, [IDENTITY] * (N_POINTS - 1), constraints)
+
+
+def test_invalid_inputs(
+    spacecraft: ArrayEphemeris, constraints: list[Constraint]
+) -> None:
+    with pytest.raises(ValueError, match="non-zero norm"):
+        check_attitude(spacecraft, [[0.0] * 4] * N_POINTS, constraints)
+    with pytest.raises(ValueError, match="axis 'bad'"):
+        check_attitude(
+            spacecraft,
+            [IDENTITY] * N_POINTS,
+            constraints,
+            axes={"bad": (0.0, 0.0, 0.0)},
+        )
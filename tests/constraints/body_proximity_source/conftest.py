@@ -0,0 +1,40 @@
+"""Fixtures for body proximity constraints that look up the body themselves."""
+
+import datetime
+import os
+
+import numpy as np
+import pytest
+
+import rust_ephem
+
+BEGIN = datetime.datetime(2024, 1, 1, 0, 0, 0, tzinfo=datetime.timezone.utc)
+END = datetime.datetime(2024, 1, 1, 2, 0, 0, tzinfo=datetime.timezone.utc)
+STEP_SIZE = 600
+
+
+@pytest.fixture(scope="module")
+def ensure_planetary_data() -> None:
+    """Ensure planetary ephemeris is loaded once for all tests"""
+    test_data_path = os.path.join(
+        os.path.dirname(os.path.dirname(os.path.dirname(__file__))),
+        "test_data",
+        "de440s.bsp",
+    )
+    rust_ephem.ensure_planetary_ephemeris(
+        py_path=test_data_path, download_if_missing=not os.path.exists(test_data_path)
+    )
+
+
+@pytest.fixture
+def site(ensure_planetary_data: None) -> rust_ephem.GroundEphemeris:
+    return rust_ephem.GroundEphemeris(35.0, -120.0, 500.0, BEGIN, END, STEP_SIZE)
+
+
+@pytest.fixture
+def jupiter_radec(site: rust_ephem.GroundEphemeris) -> tuple[float, float]:
+    """Observer-relative RA/Dec of Jupiter at the first sample, in degrees"""
+    rel = site.get_body_pv("Jupiter").position[0] - site.gcrs_pv.position[0]
+    ra = np.degrees(np.arctan2(rel[1], rel[0])) % 360.0
+    dec = np.degrees(np.arcsin(rel[2] / np.linalg.norm(rel)))
+    return float(ra), float(dec)
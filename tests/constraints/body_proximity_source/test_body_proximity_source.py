@@ -0,0 +1,76 @@
+"""Tests for the body source option and position reuse of body proximity."""
+
+import datetime
+import json
+
+import numpy as np
+import pytest
+
+import rust_ephem
+from rust_ephem import BodyConstraint
+
+from .conftest import BEGIN, END, STEP_SIZE
+
+ONE_DAY = datetime.timedelta(days=1)
+
+
+def test_target_at_body_is_violated(
+    site: rust_ephem.GroundEphemeris, jupiter_radec: tuple[float, float]
+) -> None:
+    constraint = rust_ephem.Constraint.body_proximity(
+        "Jupiter", min_angle=5.0, source="spice"
+    )
+    assert not constraint.evaluate(site, *jupiter_radec).all_satisfied
+
+
+def test_repeated_evaluations_agree(
+    site: rust_ephem.GroundEphemeris, jupiter_radec: tuple[float, float]
+) -> None:
+    ra, dec = jupiter_radec
+    constraint = rust_ephem.Constraint.body_proximity("Jupiter", min_angle=5.0)
+    ras = [ra, (ra + 180.0) % 360.0]
+    decs = [dec, -dec]
+    first = constraint.in_constraint_batch(site, ras, decs)
+    second = constraint.in_constraint_batch(site, ras, decs)
+    np.testing.assert_array_equal(first, second)
+    assert first[0].all()
+    assert not first[1].any()
+
+
+def test_time_subset_matches_full_grid(
+    site: rust_ephem.GroundEphemeris, jupiter_radec: tuple[float, float]
+) -> None:
+    ra, dec = jupiter_radec
+    offset_ra = (ra + 5.0 / np.cos(np.radians(dec))) % 360.0
+    constraint = rust_ephem.Constraint.body_proximity("Jupiter", min_angle=5.0)
+    full = constraint.in_constraint_batch(site, [offset_ra], [dec])
+    subset = constraint.in_constraint_batch(site, [offset_ra], [dec], indices=[1, 3])
+    np.testing.assert_array_equal(subset[0], full[0, [1, 3]])
+
+
+def test_other_ephemeris_is_looked_up_again(
+    site: rust_ephem.GroundEphemeris, jupiter_radec: tuple[float, float]
+) -> None:
+    constraint = rust_ephem.Constraint.body_proximity("Jupiter", min_angle=5.0)
+    constraint.in_constraint_batch(site, [jupiter_radec[0]], [jupiter_radec[1]])
+    later = rust_ephem.GroundEphemeris(
+        35.0, -120.0, 500.0, BEGIN + ONE_DAY, END + ONE_DAY, STEP_SIZE
+    )
+    result = constraint.in_constraint_batch(
+        later, [jupiter_radec[0]], [jupiter_radec[1]]
+    )
+    assert result.shape == (1, len(later.timestamp))
+
+
+def test_invalid_source_rejected() -> None:
+    with pytest.raises(ValueError, match="Invalid body source"):
+        rust_ephem.Constraint.body_proximity("Jupiter", min_angle=5.0, source="jpl")
+
+
+def test_source_round_trips() -> None:
+    constraint = rust_ephem.Constraint.body_proximity(
+        "Jupiter", min_angle=5.0, source="auto"
+    )
+    assert json.loads(constraint.to_json())["source"] == "auto"
+    model = BodyConstraint(body="Jupiter", min_angle=5.0, source="horizons")
+    assert json.loads(model.model_dump_json())["source"] == "horizons"
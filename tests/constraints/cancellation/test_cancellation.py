@@ -0,0 +1,125 @@
+"""Tests for cooperative cancellation of propagation and batch evaluations."""
+
+import numpy as np
+import pytest
+
+from rust_ephem import (
+    ArrayEphemeris,
+    CancellationToken,
+    Constraint,
+    EarthLimbConstraint,
+    TLEEphemeris,
+)
+
+from .conftest import TLE1, TLE2, TLE_BEGIN, TLE_END, TLE_STEP_SIZE
+
+
+class TestCancellationToken:
+    def test_cancel_and_reset(self) -> None:
+        token = CancellationToken()
+        assert not token.cancelled
+        token.cancel()
+        assert token.cancelled
+        token.reset()
+        assert not token.cancelled
+
+    def test_timeout(self) -> None:
+        assert CancellationToken(timeout=0.0).cancelled
+        assert not CancellationToken(timeout=3600.0).cancelled
+
+    @pytest.mark.parametrize("timeout", [-1.0, float("nan"), float("inf")])
+    def test_invalid_timeout(self, timeout: float) -> None:
+        with pytest.raises(ValueError, match="timeout"):
+            CancellationToken(timeout=timeout)
+
+    def test_repr(self) -> None:
+        assert repr(CancellationToken()) == "CancellationToken(cancelled=false)"
+
+
+class TestBatchEvaluation:
+    def test_uncancelled_token_matches_plain_run(
+        self,
+        spacecraft: ArrayEphemeris,
+        earth_limb: Constraint,
+        targets: tuple[list[float], list[float]],
+    ) -> None:
+        ras, decs = targets
+        mask = earth_limb.in_constraint_batch(
+            spacecraft, ras, decs, cancel=CancellationToken()
+        )
+        np.testing.assert_array_equal(
+            mask, earth_limb.in_constraint_batch(spacecraft, ras, decs)
+        )
+
+    def test_cancel_stops_after_current_block(
+        self,
+        spacecraft: ArrayEphemeris,
+        earth_limb: Constraint,
+        targets: tuple[list[float], list[float]],
+    ) -> None:
+        ras, decs = targets
+        token = CancellationToken()
+        calls: list[tuple[int, int]] = []
+
+        def report(done: int, total: int) -> None:
+            calls.append((done, total))
+            token.cancel()
+
+        with pytest.raises(InterruptedError):
+            earth_limb.in_constraint_batch(
+                spacecraft, ras, decs, progress=report, cancel=token
+            )
+        assert calls == [(256, 600)]
+
+    def test_expired_timeout(
+        self,
+        spacecraft: ArrayEphemeris,
+        earth_limb: Constraint,
+        targets: tuple[list[float], list[float]],
+    ) -> None:
+        ras, decs = targets
+        with pytest.raises(TimeoutError):
+            earth_limb.in_constraint_batch(
+                spacecraft, ras, decs, cancel=CancellationToken(timeout=0.0)
+            )
+
+    def test_evaluate_batch(
+        self,
+        spacecraft: ArrayEphemeris,
+        earth_limb: Constraint,
+        targets: tuple[list[float], list[float]],
+    ) -> None:
+        ras, decs = targets
+        token = CancellationToken()
+        token.cancel()
+        with pytest.raises(InterruptedError):
+            earth_limb.evaluate_batch(spacecraft, ras, decs, cancel=token)
+
+    def test_pydantic_model(
+        self,
+        spacecraft: ArrayEphemeris,
+        earth_limb_model: EarthLimbConstraint,
+        targets: tuple[list[float], list[float]],
+    ) -> None:
+        ras, decs = targets
+        token = CancellationToken()
+        token.cancel()
+        with pytest.raises(InterruptedError):
+            earth_limb_model.evaluate_batch(spacecraft, ras, decs, cancel=token)
+        with pytest.raises(InterruptedError):
+            earth_limb_model.in_constraint_batch(spacecraft, ras, decs, cancel=token)
+
+
+class TestTLEPropagation:
+    def test_cancelled_token(self) -> None:
+        token = CancellationToken()
+        token.cancel()
+        with pytest.raises(InterruptedError):
+            TLEEphemeris(TLE1, TLE2, TLE_BEGIN, TLE_END, TLE_STEP_SIZE, cancel=token)
+
+    def test_uncancelled_token(self) -> None:
+        ephem = TLEEphemeris(
+            TLE1, TLE2, TLE_BEGIN, TLE_END, TLE_STEP_SIZE, cancel=CancellationToken()
+        )
+        plain = TLEEphemeris(TLE1, TLE2, TLE_BEGIN, TLE_END, TLE_STEP_SIZE)
+        np.testing.assert_array_equal(ephem.teme_pv.position, plain.teme_pv.position)
@@ -142,6 +142,8 @@ class DummyConstraintBackend:
         target_dec: object,
         times: object,
         indices: object,
+        between: object = None,
+        at_times: object = None,
     ) -> DummyRustResult:
         self.evaluate_calls.append((ephemeris, target_ra, target_dec, times, indices))
         return DummyRustResult()
@@ -153,6 +155,8 @@ class DummyConstraintBackend:
         target_decs: list[object],
         times: object,
         indices: object,
+        between: object = None,
+        at_times: object = None,
     ) -> list[DummyRustResult]:
         self.evaluate_batch_calls.append(
             (ephemeris, target_ras, target_decs, times, indices)
@@ -166,6 +170,8 @@ class DummyConstraintBackend:
         target_decs: object,
         times: object,
         indices: object,
+        between: object = None,
+        at_times: object = None,
     ) -> npt.NDArray[np.bool_]:
         self.batch_calls.append((ephemeris, target_ras, target_decs, times, indices))
         return np.array([[True], [False]])
@@ -0,0 +1,36 @@
+"""Fixtures for constraint cube tests."""
+
+from datetime import datetime, timedelta, timezone
+
+import numpy as np
+import pytest
+
+from rust_ephem import ArrayEphemeris, Constraint
+
+BEGIN = datetime(2024, 1, 1, 0, 0, 0, tzinfo=timezone.utc)
+N_POINTS = 6
+# The spacecraft jumps from +x to -x after this sample
+LAST_PLUS_X = 2
+
+# Targets along +x (visible from the +x side only) and +y (about 24 degrees
+# above the Earth limb throughout)
+RAS = [0.0, 90.0]
+DECS = [0.0, 0.0]
+
+
+@pytest.fixture
+def spacecraft() -> ArrayEphemeris:
+    times = [BEGIN + timedelta(minutes=i) for i in range(N_POINTS)]
+    states = np.zeros((N_POINTS, 6))
+    states[: LAST_PLUS_X + 1, 0] = 7000.0
+    states[LAST_PLUS_X + 1 :, 0] = -7000.0
+    return ArrayEphemeris(times, states)
+
+
+@pytest.fixture
+def constraints() -> list[Constraint]:
+    """Earth limb at 10 degrees, and at 30 degrees which also blocks +y"""
+    return [
+        Constraint.earth_limb(min_angle=10.0),
+        Constraint.earth_limb(min_angle=30.0),
+    ]
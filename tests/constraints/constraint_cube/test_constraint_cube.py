@@ -0,0 +1,88 @@
+"""Tests for multi-constraint, multi-target constraint cubes"""
+
+import numpy as np
+import pytest
+
+from rust_ephem import ArrayEphemeris, Constraint, ConstraintCube, constraint_cube
+
+from .conftest import BEGIN, DECS, LAST_PLUS_X, N_POINTS, RAS
+
+PLUS_X_BLOCKED = [False] * (LAST_PLUS_X + 1) + [True] * (N_POINTS - LAST_PLUS_X - 1)
+
+
+def test_cube_shape_and_values(
+    spacecraft: ArrayEphemeris, constraints: list[Constraint]
+) -> None:
+    cube = constraint_cube(spacecraft, constraints, RAS, DECS)
+    assert isinstance(cube, ConstraintCube)
+    assert cube.shape == (2, 2, N_POINTS)
+    values = cube.cube
+    assert values.dtype == np.bool_
+    assert values.shape == (2, 2, N_POINTS)
+    assert values[0, 0].tolist() == PLUS_X_BLOCKED
+    assert not values[0, 1].any()
+    assert values[1, 0].tolist() == PLUS_X_BLOCKED
+    assert values[1, 1].all()
+
+
+def test_matches_in_constraint_batch(
+    spacecraft: ArrayEphemeris, constraints: list[Constraint]
+) -> None:
+    cube = constraint_cube(spacecraft, constraints, RAS, DECS)
+    for k, constraint in enumerate(constraints):
+        expected = constraint.in_constraint_batch(spacecraft, RAS, DECS)
+        np.testing.assert_array_equal(cube.cube[k], expected)
+
+
+def test_reductions(spacecraft: ArrayEphemeris, constraints: list[Constraint]) -> None:
+    cube = constraint_cube(spacecraft, constraints, RAS, DECS)
+    observable = cube.all_constraints()
+    assert observable.shape == (2, N_POINTS)
+    assert observable[0].tolist() == [not v for v in PLUS_X_BLOCKED]
+    assert not observable[1].any()
+    np.testing.assert_array_equal(cube.any_violation(), ~observable)
+    np.testing.assert_array_equal(cube.any_violation(), cube.cube.any(axis=0))
+
+
+def test_names_and_timestamps(
+    spacecraft: ArrayEphemeris, constraints: list[Constraint]
+) -> None:
+    cube = constraint_cube(spacecraft, constraints, RAS, DECS)
+    names = cube.constraint_names
+    assert len(names) == 2
+    assert names[0] != names[1]
+    assert len(cube.timestamp) == N_POINTS
+    assert cube.timestamp[0] == BEGIN
+
+
+def test_no_targets(spacecraft: ArrayEphemeris, constraints: list[Constraint]) -> None:
+    cube = constraint_cube(spacecraft, constraints, [], [])
+    assert cube.shape == (2, 0, N_POINTS)
+    assert cube.all_constraints().shape == (0, N_POINTS)
+
+
+def test_length_mismatch(
+    spacecraft: ArrayEphemeris, constraints: list[Constraint]
+) -> None:
+    with pytest.raises(ValueError, match="same length"):
+        constraint_cube(spacecraft, constraints, RAS, [0.0])
+
+
+@pytest.mark.parametrize("chunk_size", [1, 3, 256])
+def test_chunk_size_does_not_change_result(
+    spacecraft: ArrayEphemeris, constraints: list[Constraint], chunk_size: int
+) -> None:
+    # Many targets so that small chunks give many parallel tasks
+    ras = np.linspace(0.0, 350.0, 36)
+    decs = np.tile([-60.0, 0.0, 60.0], 12)
+    cube = constraint_cube(spacecraft, constraints, ras, decs, chunk_size=chunk_size)
+    for k, constraint in enumerate(constraints):
+        expected = constraint.in_constraint_batch(spacecraft, ras, decs)
+        np.testing.assert_array_equal(cube.cube[k], expected)
+
+
+def test_invalid_chunk_size(
+    spacecraft: ArrayEphemeris, constraints: list[Constraint]
+) -> None:
+    with pytest.raises(ValueError, match="chunk_size"):
+        constraint_cube(spacecraft, constraints, RAS, DECS, chunk_size=0)
@@ -0,0 +1,28 @@
+"""Fixtures for the constraint metric tests."""
+
+from datetime import datetime, timezone
+from typing import Any
+
+import pytest
+
+from rust_ephem import GroundEphemeris, TLEEphemeris
+
+VALID_TLE1 = "1 25544U 98067A   08264.51782528 -.00002182  00000-0 -11606-4 0  2927"
+VALID_TLE2 = "2 25544  51.6416 247.4627 0006703 130.5360 325.0288 15.72125391563537"
+
+BEGIN = datetime(2024, 3, 1, 0, 0, 0, tzinfo=timezone.utc)
+END = datetime(2024, 3, 2, 0, 0, 0, tzinfo=timezone.utc)
+STEP_SIZE = 600
+
+TARGET_RA = 83.63
+TARGET_DEC = 22.01
+
+
+@pytest.fixture
+def spacecraft(ensure_planetary_data: Any) -> TLEEphemeris:
+    return TLEEphemeris(VALID_TLE1, VALID_TLE2, BEGIN, END, STEP_SIZE)
+
+
+@pytest.fixture
+def site(ensure_planetary_data: Any) -> GroundEphemeris:
+    return GroundEphemeris(-30.17, -70.8, 2200.0, BEGIN, END, STEP_SIZE)
@@ -0,0 +1,57 @@
+"""Tests for Constraint.metric."""
+
+import numpy as np
+import pytest
+
+from rust_ephem import Constraint, GroundEphemeris, TLEEphemeris
+from rust_ephem.constraints import SunConstraint
+
+from .conftest import TARGET_DEC, TARGET_RA
+
+
+def violated(constraint: Constraint, ephemeris: object) -> np.ndarray:
+    return constraint.in_constraint_batch(ephemeris, [TARGET_RA], [TARGET_DEC])[0]
+
+
+def test_sun_proximity_metric_matches_threshold(spacecraft: TLEEphemeris) -> None:
+    constraint = Constraint.sun_proximity(100.0)
+    separation = constraint.metric(spacecraft, TARGET_RA, TARGET_DEC)
+    assert separation.shape == (len(spacecraft.timestamp),)
+    np.testing.assert_array_equal(separation < 100.0, violated(constraint, spacecraft))
+
+
+def test_alt_az_metric_is_altitude(site: GroundEphemeris) -> None:
+    altitude = Constraint.alt_az(min_altitude=20.0).metric(site, TARGET_RA, TARGET_DEC)
+    expected = site.radec_to_altaz(TARGET_RA, TARGET_DEC)[:, 0]
+    np.testing.assert_allclose(altitude, expected)
+
+
+def test_eclipse_metric_is_illumination(spacecraft: TLEEphemeris) -> None:
+    visible = Constraint.eclipse().metric(spacecraft, TARGET_RA, TARGET_DEC)
+    np.testing.assert_allclose(visible, spacecraft.illumination_factor())
+
+
+def test_moon_phase_metric_is_illumination(site: GroundEphemeris) -> None:
+    fraction = Constraint.moon_phase(max_illumination=0.5).metric(
+        site, TARGET_RA, TARGET_DEC
+    )
+    np.testing.assert_allclose(fraction, site.moon_illumination())
+
+
+def test_metric_respects_indices(spacecraft: TLEEphemeris) -> None:
+    constraint = Constraint.sun_proximity(45.0)
+    full = constraint.metric(spacecraft, TARGET_RA, TARGET_DEC)
+    subset = constraint.metric(spacecraft, TARGET_RA, TARGET_DEC, indices=[0, 5, 10])
+    np.testing.assert_allclose(subset, full[[0, 5, 10]])
+
+
+def test_pydantic_metric_matches_rust(spacecraft: TLEEphemeris) -> None:
+    model = SunConstraint(min_angle=45.0).metric(spacecraft, TARGET_RA, TARGET_DEC)
+    rust = Constraint.sun_proximity(45.0).metric(spacecraft, TARGET_RA, TARGET_DEC)
+    np.testing.assert_allclose(model, rust)
+
+
+def test_combination_has_no_metric(spacecraft: TLEEphemeris) -> None:
+    combined = Constraint.and_(Constraint.sun_proximity(45.0), Constraint.eclipse())
+    with pytest.raises(ValueError, match="no single underlying metric"):
+        combined.metric(spacecraft, TARGET_RA, TARGET_DEC)
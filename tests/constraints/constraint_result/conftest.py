@@ -16,7 +16,7 @@ class DummyRustResult:
         self._in_constraint_calls: list[datetime] = []
         self._in_constraint_return: bool = True
 
-    def in_constraint(self, time: datetime) -> bool:
+    def in_constraint(self, time: datetime, mode: str = "exact") -> bool:
         self._in_constraint_calls.append(time)
         return self._in_constraint_return
 
@@ -1,9 +1,13 @@
-from datetime import datetime, timezone
+from datetime import datetime, timedelta, timezone
 from typing import Any
 
+import numpy as np
 import pytest
 
-from rust_ephem.constraints import ConstraintResult
+from rust_ephem import ArrayEphemeris, Constraint, EarthLimbConstraint
+from rust_ephem.constraints import ConstraintResult, ConstraintViolation
+
+BEGIN = datetime(2024, 1, 1, tzinfo=timezone.utc)
 
 
 class TestConstraintResult:
@@ -69,3 +73,178 @@ class TestConstraintResult:
         )
         with pytest.raises(ValueError):
             result.in_constraint(datetime.now(timezone.utc))
+
+    def test_violation_times_datetime64(
+        self, constraint_result_without_rust_ref: ConstraintResult
+    ) -> None:
+        result = constraint_result_without_rust_ref
+        starts = result.violation_start_times
+        assert starts.dtype == np.dtype("datetime64[us]")
+        assert list(starts) == [
+            np.datetime64("2024-01-01T00:00:00", "us"),
+            np.datetime64("2024-01-01T00:00:10", "us"),
+        ]
+        assert result.violation_end_times[1] == np.datetime64("2024-01-01T00:00:15")
+
+    def test_violation_times_converted_to_utc(self) -> None:
+        plus_two = timezone(timedelta(hours=2))
+        result = ConstraintResult(
+            violations=[
+                ConstraintViolation(
+                    start_time=datetime(2024, 1, 1, 2, 0, 0, tzinfo=plus_two),
+                    end_time=datetime(2024, 1, 1, 2, 0, 5, tzinfo=plus_two),
+                    max_severity=1.0,
+                    description="v",
+                )
+            ],
+            all_satisfied=False,
+            constraint_name="test",
+        )
+        assert result.violation_start_times[0] == np.datetime64("2024-01-01T00:00:00")
+
+    def test_without_violations_datetime64(self) -> None:
+        result: ConstraintResult = ConstraintResult(
+            violations=[], all_satisfied=True, constraint_name="empty"
+        )
+        assert len(result.violation_start_times) == 0
+        assert result.violation_end_times.dtype == np.dtype("datetime64[us]")
+
+
+def sign_ephemeris(signs: list[int]) -> ArrayEphemeris:
+    """One-minute ephemeris at +x or -x (7000 km); +x sees target (0, 0)"""
+    times = [BEGIN + timedelta(minutes=i) for i in range(len(signs))]
+    states = np.zeros((len(signs), 6))
+    states[:, 0] = 7000.0 * np.array(signs)
+    return ArrayEphemeris(times, states)
+
+
+def test_rust_result_violation_times() -> None:
+    """Violation times from Rust are timezone-aware and available as datetime64"""
+    ephem = sign_ephemeris([1, 1, -1, -1])
+    result = Constraint.earth_limb(min_angle=10.0).evaluate(ephem, 0.0, 0.0)
+    (violation,) = result.violations
+    assert violation.start_time == BEGIN + timedelta(minutes=2)
+    assert violation.start_time.tzinfo is not None
+    assert violation.end_time == BEGIN + timedelta(minutes=3)
+    assert list(result.violation_start_times) == [np.datetime64("2024-01-01T00:02")]
+    assert list(result.violation_end_times) == [np.datetime64("2024-01-01T00:03")]
+
+
+def test_visibility_windows_complement_violations() -> None:
+    ephem = sign_ephemeris([1, 1, -1, 1, -1, -1, 1, 1])
+    result = Constraint.earth_limb(min_angle=10.0).evaluate(ephem, 0.0, 0.0)
+    assert len(result.violations) == 2
+    windows = result.visibility_windows
+    # The single satisfied sample between the violations has no duration
+    assert [(w.start_time, w.end_time) for w in windows] == [
+        (BEGIN, BEGIN + timedelta(minutes=1)),
+        (BEGIN + timedelta(minutes=6), BEGIN + timedelta(minutes=7)),
+    ]
+    assert [w.duration_seconds for w in windows] == [60.0, 60.0]
+
+
+def test_visibility_windows_edges() -> None:
+    constraint = Constraint.earth_limb(min_angle=10.0)
+    always = constraint.evaluate(sign_ephemeris([1, 1, 1]), 0.0, 0.0)
+    (window,) = always.visibility_windows
+    assert window.start_time == BEGIN
+    assert window.end_time == BEGIN + timedelta(minutes=2)
+    never = constraint.evaluate(sign_ephemeris([-1, -1, -1]), 0.0, 0.0)
+    assert never.visibility_windows == []
+
+
+def test_visibility_windows_trailing_single_sample() -> None:
+    ephem = sign_ephemeris([1, -1, -1, 1])
+    result = Constraint.earth_limb(min_angle=10.0).evaluate(ephem, 0.0, 0.0)
+    # The leading sample has no duration; the trailing one is kept because
+    # the target is still visible when the evaluation ends
+    (window,) = result.visibility_windows
+    assert window.start_time == BEGIN + timedelta(minutes=3)
+    assert window.end_time == BEGIN + timedelta(minutes=3)
+    assert [(w.start_time, w.end_time) for w in result.visibility] == [
+        (window.start_time, window.end_time)
+    ]
+
+
+def test_pydantic_result_visibility_windows() -> None:
+    ephem = sign_ephemeris([-1, 1, 1, 1])
+    result = EarthLimbConstraint(min_angle=10.0).evaluate(ephem, 0.0, 0.0)
+    (window,) = result.visibility_windows
+    assert window.start_time == BEGIN + timedelta(minutes=1)
+    assert window.end_time == BEGIN + timedelta(minutes=3)
+
+
+def test_without_rust_ref_visibility_windows() -> None:
+    result = ConstraintResult(violations=[], all_satisfied=True, constraint_name="x")
+    assert result.visibility_windows == []
+
+
+class TestInConstraintLookup:
+    """Violated at minutes 2 and 3 of a 0-3 minute grid"""
+
+    @pytest.fixture
+    def result(self) -> Any:
+        ephem = sign_ephemeris([1, 1, -1, -1])
+        return Constraint.earth_limb(min_angle=10.0).evaluate(ephem, 0.0, 0.0)
+
+    def test_exact(self, result: Any) -> None:
+        assert result.in_constraint(BEGIN + timedelta(minutes=2)) is True
+        assert result.in_constraint(BEGIN.replace(tzinfo=None)) is False
+        with pytest.raises(ValueError, match="not found"):
+            result.in_constraint(BEGIN + timedelta(seconds=90))
+
+    def test_nearest(self, result: Any) -> None:
+        assert result.in_constraint(BEGIN + timedelta(seconds=89), "nearest") is False
+        assert result.in_constraint(BEGIN + timedelta(seconds=91), "nearest") is True
+
+    def test_previous(self, result: Any) -> None:
+        assert result.in_constraint(BEGIN + timedelta(seconds=119), "previous") is False
+        assert result.in_constraint(BEGIN + timedelta(seconds=121), "previous") is True
+
+    def test_outside_range(self, result: Any) -> None:
+        with pytest.raises(ValueError, match="outside"):
+            result.in_constraint(BEGIN - timedelta(seconds=1), "nearest")
+        with pytest.raises(ValueError, match="outside"):
+            result.in_constraint(BEGIN + timedelta(minutes=4), "previous")
+
+    def test_invalid_mode(self, result: Any) -> None:
+        with pytest.raises(ValueError, match="mode"):
+            result.in_constraint(BEGIN, "closest")
+
+    def test_array(self, result: Any) -> None:
+        times = [BEGIN + timedelta(seconds=s) for s in (0, 70, 100, 180)]
+        values = result.in_constraint_array(times, "nearest")
+        assert values.dtype == np.bool_
+        assert values.tolist() == [False, False, True, True]
+
+    def test_array_datetime64(self, result: Any) -> None:
+        times = np.array(
+            ["2024-01-01T00:01:30", "2024-01-01T00:02:59"], dtype="datetime64[s]"
+        )
+        assert result.in_constraint_array(times, "previous").tolist() == [False, True]
+
+    def test_pydantic_result(self) -> None:
+        ephem = sign_ephemeris([1, 1, -1, -1])
+        result = EarthLimbConstraint(min_angle=10.0).evaluate(ephem, 0.0, 0.0)
+        assert result.in_constraint(BEGIN + timedelta(seconds=100), "nearest") is True
+        times = [BEGIN, BEGIN + timedelta(minutes=3)]
+        assert result.in_constraint_array(times).tolist() == [False, True]
+
+    def test_swept_result(self) -> None:
+        result = ConstraintResult(
+            violations=[],
+            all_satisfied=False,
+            constraint_name="swept",
+            _swept_timestamps=[BEGIN + timedelta(minutes=i) for i in range(3)],  # type: ignore
+            _swept_array=[False, True, False],  # type: ignore
+        )
+        assert result.in_constraint(BEGIN + timedelta(minutes=1)) is True
+        assert result.in_constraint(BEGIN + timedelta(seconds=100), "nearest") is False
+        times = [BEGIN + timedelta(seconds=s) for s in (30, 61, 120)]
+        assert result.in_constraint_array(times, "previous").tolist() == [
+            False,
+            True,
+            False,
+        ]
+        with pytest.raises(ValueError, match="not found"):
+            result.in_constraint(BEGIN + timedelta(seconds=30))
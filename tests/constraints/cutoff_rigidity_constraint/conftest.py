@@ -0,0 +1,31 @@
+"""Fixtures for geomagnetic cutoff rigidity constraint tests."""
+
+import math
+from datetime import datetime, timedelta, timezone
+
+import numpy as np
+import pytest
+
+from rust_ephem import ArrayEphemeris
+
+BEGIN = datetime(2025, 1, 1, 12, 0, 0, tzinfo=timezone.utc)
+ORBIT_RADIUS_KM = 6900.0
+MIN_RIGIDITY = 2.0
+
+# Geographic latitudes along the meridian of the 2025 geomagnetic pole
+# (72.8 W), where geomagnetic latitude is about latitude + 9.2 degrees
+LATITUDES = [-9.2, 10.0, 30.0, 50.0, 70.0, 50.0, 30.0]
+
+
+@pytest.fixture
+def spacecraft() -> ArrayEphemeris:
+    """ITRS track climbing from the geomagnetic equator towards the pole"""
+    lon = math.radians(-72.8)
+    times = [BEGIN + timedelta(minutes=i) for i in range(len(LATITUDES))]
+    states = np.zeros((len(LATITUDES), 6))
+    for i, lat_deg in enumerate(LATITUDES):
+        lat = math.radians(lat_deg)
+        states[i, 0] = ORBIT_RADIUS_KM * math.cos(lat) * math.cos(lon)
+        states[i, 1] = ORBIT_RADIUS_KM * math.cos(lat) * math.sin(lon)
+        states[i, 2] = ORBIT_RADIUS_KM * math.sin(lat)
+    return ArrayEphemeris(times, states, frame="ITRS")
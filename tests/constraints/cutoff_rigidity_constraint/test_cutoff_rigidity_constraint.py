@@ -0,0 +1,80 @@
+"""Tests for the geomagnetic cutoff rigidity constraint."""
+
+import json
+
+import numpy as np
+import pytest
+from pydantic import ValidationError
+
+import rust_ephem
+from rust_ephem.constraints import CutoffRigidityConstraint
+
+from .conftest import LATITUDES, MIN_RIGIDITY
+
+
+def test_high_latitudes_violate(spacecraft: rust_ephem.ArrayEphemeris) -> None:
+    result = rust_ephem.Constraint.cutoff_rigidity(MIN_RIGIDITY).evaluate(
+        spacecraft, 0.0, 0.0
+    )
+    assert result.constraint_array == [False, False, False, True, True, True, False]
+    assert len(result.violations) == 1
+    assert "below minimum" in result.violations[0].description
+
+
+def test_matches_ephemeris_rigidity(spacecraft: rust_ephem.ArrayEphemeris) -> None:
+    result = rust_ephem.Constraint.cutoff_rigidity(MIN_RIGIDITY).evaluate(
+        spacecraft, 0.0, 0.0
+    )
+    expected = spacecraft.cutoff_rigidity_gv < MIN_RIGIDITY
+    np.testing.assert_array_equal(result.constraint_array, expected)
+
+
+def test_independent_of_target(spacecraft: rust_ephem.ArrayEphemeris) -> None:
+    constraint = rust_ephem.Constraint.cutoff_rigidity(MIN_RIGIDITY)
+    batch = constraint.in_constraint_batch(
+        spacecraft, [0.0, 120.0, 250.0], [0.0, 45.0, -60.0]
+    )
+    assert batch.shape == (3, len(LATITUDES))
+    assert (batch == batch[0]).all()
+    np.testing.assert_array_equal(
+        batch[0], constraint.evaluate(spacecraft, 0.0, 0.0).constraint_array
+    )
+
+
+def test_time_subset(spacecraft: rust_ephem.ArrayEphemeris) -> None:
+    constraint = rust_ephem.Constraint.cutoff_rigidity(MIN_RIGIDITY)
+    result = constraint.evaluate(spacecraft, 0.0, 0.0, indices=[0, 4])
+    assert result.constraint_array == [False, True]
+
+
+def test_combines_with_saa(spacecraft: rust_ephem.ArrayEphemeris) -> None:
+    saa = rust_ephem.Constraint.saa([(-90.0, -50.0), (-40.0, -50.0), (-40.0, 0.0)])
+    combined = rust_ephem.Constraint.cutoff_rigidity(MIN_RIGIDITY) | saa
+    result = combined.evaluate(spacecraft, 0.0, 0.0)
+    assert result.constraint_array[3:6] == [True, True, True]
+
+
+class TestConfiguration:
+    @pytest.mark.parametrize("min_rigidity", [0.0, -1.0])
+    def test_invalid_threshold(self, min_rigidity: float) -> None:
+        with pytest.raises(ValueError, match="min_rigidity"):
+            rust_ephem.Constraint.cutoff_rigidity(min_rigidity)
+        with pytest.raises(ValidationError):
+            CutoffRigidityConstraint(min_rigidity=min_rigidity)
+
+    def test_json_round_trip(self) -> None:
+        constraint = rust_ephem.Constraint.cutoff_rigidity(MIN_RIGIDITY)
+        config = json.loads(constraint.to_json())
+        assert config == {"type": "cutoff_rigidity", "min_rigidity": MIN_RIGIDITY}
+        restored = rust_ephem.Constraint.from_json(constraint.to_json())
+        assert "CutoffRigidityConstraint(min=2.00 GV)" in str(restored)
+
+    def test_pydantic_matches_factory(
+        self, spacecraft: rust_ephem.ArrayEphemeris
+    ) -> None:
+        model = CutoffRigidityConstraint(min_rigidity=MIN_RIGIDITY)
+        factory = rust_ephem.Constraint.cutoff_rigidity(MIN_RIGIDITY)
+        assert (
+            model.evaluate(spacecraft, 0.0, 0.0).constraint_array
+            == factory.evaluate(spacecraft, 0.0, 0.0).constraint_array
+        )
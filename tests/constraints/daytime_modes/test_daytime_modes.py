@@ -0,0 +1,101 @@
+"""Tests for the Daytime constraint modes."""
+
+import numpy as np
+import pytest
+
+from rust_ephem import Constraint, GroundEphemeris, TLEEphemeris
+from rust_ephem.constraints import DaytimeConstraint
+
+from .conftest import SITE_HEIGHT, SITE_LAT, SITE_LON
+
+
+def daytime_mask(constraint: Constraint, ephemeris: object) -> np.ndarray:
+    return constraint.in_constraint_batch(ephemeris, [0.0], [0.0])[0]
+
+
+def test_default_mode_unchanged(site: GroundEphemeris) -> None:
+    default = daytime_mask(Constraint.daytime("civil"), site)
+    explicit = daytime_mask(Constraint.daytime("civil", mode="observer"), site)
+    np.testing.assert_array_equal(default, explicit)
+
+
+def test_observer_sunlit_follows_illumination(spacecraft: TLEEphemeris) -> None:
+    mask = daytime_mask(Constraint.daytime(mode="observer_sunlit"), spacecraft)
+    np.testing.assert_array_equal(mask, spacecraft.illumination_factor() > 0.0)
+    # The orbit passes through the Earth's shadow several times a day
+    assert mask.any() and not mask.all()
+
+
+def test_observer_sunlit_ignores_twilight(spacecraft: TLEEphemeris) -> None:
+    civil = daytime_mask(Constraint.daytime("civil", mode="observer_sunlit"), spacecraft)
+    none = daytime_mask(Constraint.daytime("none", mode="observer_sunlit"), spacecraft)
+    np.testing.assert_array_equal(civil, none)
+
+
+def test_ground_site_night_matches_site_observer(
+    spacecraft: TLEEphemeris, site: GroundEphemeris
+) -> None:
+    """The site's darkness does not depend on where the spacecraft is"""
+    remote = daytime_mask(
+        Constraint.daytime(
+            "astronomical",
+            mode="ground_site_night",
+            latitude=SITE_LAT,
+            longitude=SITE_LON,
+            height=SITE_HEIGHT,
+        ),
+        spacecraft,
+    )
+    local = daytime_mask(Constraint.daytime("astronomical"), site)
+    assert remote.any() and not remote.all()
+    # The observer mode uses a faster approximation, so allow a step or two
+    # of disagreement at dusk and dawn
+    assert np.count_nonzero(remote != local) <= 4
+
+
+def test_nadir_scene_daylit_differs_from_sunlit(spacecraft: TLEEphemeris) -> None:
+    nadir = daytime_mask(Constraint.daytime("none", mode="nadir_scene_daylit"), spacecraft)
+    sunlit = daytime_mask(Constraint.daytime(mode="observer_sunlit"), spacecraft)
+    assert nadir.any() and not nadir.all()
+    # A spacecraft stays sunlit for a while after the ground below it goes dark
+    assert np.all(sunlit[nadir])
+    assert np.count_nonzero(sunlit) > np.count_nonzero(nadir)
+
+
+def test_evaluate_matches_batch(spacecraft: TLEEphemeris) -> None:
+    constraint = DaytimeConstraint(mode="nadir_scene_daylit")
+    result = constraint.evaluate(spacecraft, 0.0, 0.0)
+    batch = constraint.in_constraint_batch(spacecraft, [0.0], [0.0])
+    np.testing.assert_array_equal(result.constraint_array, batch[0])
+
+
+def test_pydantic_round_trip(spacecraft: TLEEphemeris) -> None:
+    constraint = DaytimeConstraint(
+        mode="ground_site_night", latitude=SITE_LAT, longitude=SITE_LON
+    )
+    rust = Constraint.daytime(
+        mode="ground_site_night", latitude=SITE_LAT, longitude=SITE_LON
+    )
+    np.testing.assert_array_equal(
+        constraint.in_constraint_batch(spacecraft, [0.0], [0.0]),
+        rust.in_constraint_batch(spacecraft, [0.0], [0.0]),
+    )
+
+
+def test_ground_site_required() -> None:
+    with pytest.raises(ValueError, match="latitude and longitude"):
+        Constraint.daytime(mode="ground_site_night", latitude=SITE_LAT)
+    with pytest.raises(ValueError):
+        DaytimeConstraint(mode="ground_site_night")
+
+
+def test_site_rejected_for_other_modes() -> None:
+    with pytest.raises(ValueError, match="ground_site_night"):
+        Constraint.daytime(mode="observer_sunlit", latitude=SITE_LAT, longitude=0.0)
+    with pytest.raises(ValueError):
+        DaytimeConstraint(latitude=SITE_LAT, longitude=SITE_LON)
+
+
+def test_invalid_mode() -> None:
+    with pytest.raises(ValueError, match="mode must be one of"):
+        Constraint.daytime(mode="spacecraft")  # type: ignore[arg-type]
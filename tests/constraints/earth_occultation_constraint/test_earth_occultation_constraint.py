@@ -0,0 +1,105 @@
+"""Tests for the Earth occultation constraint and its ingress/egress events."""
+
+from datetime import timedelta
+
+import numpy as np
+import pytest
+from pydantic import ValidationError
+
+import rust_ephem
+from rust_ephem.constraints import EarthOccultationConstraint
+
+from .conftest import BEGIN, END, TARGET_DEC, TARGET_RA
+
+
+class TestEarthOccultationConstraint:
+    def test_target_behind_earth_is_occulted(
+        self, tle_ephem: rust_ephem.TLEEphemeris
+    ) -> None:
+        earth = tle_ephem.earth[0]
+        constraint = rust_ephem.Constraint.earth_occultation()
+        result = constraint.evaluate(tle_ephem, earth.ra.deg, earth.dec.deg)
+        assert result.constraint_array[0]
+        zenith = constraint.evaluate(
+            tle_ephem, (earth.ra.deg + 180.0) % 360.0, -earth.dec.deg
+        )
+        assert not zenith.constraint_array[0]
+
+    def test_no_margin_compared_with_earth_limb(
+        self, tle_ephem: rust_ephem.TLEEphemeris
+    ) -> None:
+        occulted = np.array(
+            rust_ephem.Constraint.earth_occultation()
+            .evaluate(tle_ephem, TARGET_RA, TARGET_DEC)
+            .constraint_array
+        )
+        limb = np.array(
+            rust_ephem.Constraint.earth_limb(10.0)
+            .evaluate(tle_ephem, TARGET_RA, TARGET_DEC)
+            .constraint_array
+        )
+        assert occulted.any()
+        assert np.all(limb[occulted])
+        assert limb.sum() > occulted.sum()
+
+    def test_grazing_altitude_widens_occultation(
+        self, tle_ephem: rust_ephem.TLEEphemeris
+    ) -> None:
+        surface = rust_ephem.Constraint.earth_occultation().evaluate(
+            tle_ephem, TARGET_RA, TARGET_DEC
+        )
+        atmosphere = rust_ephem.Constraint.earth_occultation(
+            grazing_altitude=100.0
+        ).evaluate(tle_ephem, TARGET_RA, TARGET_DEC)
+        assert sum(atmosphere.constraint_array) > sum(surface.constraint_array)
+
+    def test_negative_grazing_altitude_rejected(self) -> None:
+        with pytest.raises(ValueError, match="grazing_altitude"):
+            rust_ephem.Constraint.earth_occultation(grazing_altitude=-1.0)
+        with pytest.raises(ValidationError):
+            EarthOccultationConstraint(grazing_altitude=-1.0)
+
+    def test_json_round_trip(self) -> None:
+        constraint = EarthOccultationConstraint(grazing_altitude=50.0)
+        rust = rust_ephem.Constraint.from_json(constraint.model_dump_json())
+        assert "50.0 km" in str(rust)
+
+
+class TestOccultationEvents:
+    def test_events_alternate(self, tle_ephem: rust_ephem.TLEEphemeris) -> None:
+        events = rust_ephem.Constraint.earth_occultation().occultation_events(
+            tle_ephem, TARGET_RA, TARGET_DEC
+        )
+        # Six hours of a ~93 minute orbit
+        assert len(events) >= 6
+        kinds = [kind for _, kind in events]
+        assert all(a != b for a, b in zip(kinds, kinds[1:]))
+        times = [time for time, _ in events]
+        assert times == sorted(times)
+
+    def test_events_bracket_violation_windows(
+        self, tle_ephem: rust_ephem.TLEEphemeris
+    ) -> None:
+        constraint = EarthOccultationConstraint()
+        events = constraint.occultation_events(tle_ephem, TARGET_RA, TARGET_DEC)
+        ingress = [time for time, kind in events if kind == "ingress"]
+        egress = [time for time, kind in events if kind == "egress"]
+        result = constraint.evaluate(tle_ephem, TARGET_RA, TARGET_DEC)
+        step = timedelta(seconds=60)
+        for window in result.violations:
+            if window.start_time > BEGIN:
+                assert any(
+                    window.start_time - step < t <= window.start_time for t in ingress
+                )
+            if window.end_time < END:
+                assert any(
+                    window.end_time <= t < window.end_time + step for t in egress
+                )
+
+    def test_requires_occultation_constraint(
+        self, tle_ephem: rust_ephem.TLEEphemeris
+    ) -> None:
+        with pytest.raises(TypeError, match="occultation"):
+            rust_ephem.Constraint.earth_limb(10.0).occultation_events(
+                tle_ephem, TARGET_RA, TARGET_DEC
+            )
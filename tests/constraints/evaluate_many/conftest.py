@@ -0,0 +1,30 @@
+"""Fixtures for evaluate_many tests."""
+
+from datetime import datetime, timezone
+from typing import Any
+
+import pytest
+
+from rust_ephem import GroundEphemeris, SunConstraint, TLEEphemeris
+
+BEGIN = datetime(2025, 9, 23, 0, 0, 0, tzinfo=timezone.utc)
+END = datetime(2025, 9, 23, 6, 0, 0, tzinfo=timezone.utc)
+
+# Target well away from the Sun in late September
+TARGET_RA = 0.0
+TARGET_DEC = -60.0
+
+
+@pytest.fixture
+def fleet(tle: tuple[str, str]) -> dict[str, Any]:
+    return {
+        "sat": TLEEphemeris(tle[0], tle[1], BEGIN, END, 60),
+        "sat_coarse": TLEEphemeris(tle[0], tle[1], BEGIN, END, 600),
+        "north": GroundEphemeris(52.0, 0.0, 0.0, BEGIN, END, 300),
+        "south": GroundEphemeris(-30.0, -70.0, 2000.0, BEGIN, END, 300),
+    }
+
+
+@pytest.fixture
+def sun_constraint_45() -> SunConstraint:
+    return SunConstraint(min_angle=45.0)
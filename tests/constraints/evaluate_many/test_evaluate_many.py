@@ -0,0 +1,84 @@
+"""Tests for evaluating one constraint against several ephemerides."""
+
+from datetime import timedelta
+from typing import Any
+
+import pytest
+
+from rust_ephem import Constraint, SunConstraint
+
+from .conftest import BEGIN, TARGET_DEC, TARGET_RA
+
+
+def test_keys_follow_mapping(
+    fleet: dict[str, Any], sun_constraint_45: SunConstraint
+) -> None:
+    results = sun_constraint_45.evaluate_many(fleet, TARGET_RA, TARGET_DEC)
+    assert list(results) == list(fleet)
+
+
+def test_sequence_is_keyed_by_position(
+    fleet: dict[str, Any], sun_constraint_45: SunConstraint
+) -> None:
+    results = sun_constraint_45.evaluate_many(
+        list(fleet.values()), TARGET_RA, TARGET_DEC
+    )
+    assert list(results) == [0, 1, 2, 3]
+
+
+def test_matches_individual_evaluation(
+    fleet: dict[str, Any], sun_constraint_45: SunConstraint
+) -> None:
+    results = sun_constraint_45.evaluate_many(fleet, TARGET_RA, TARGET_DEC)
+    for name, ephem in fleet.items():
+        single = sun_constraint_45.evaluate(ephem, TARGET_RA, TARGET_DEC)
+        assert results[name].constraint_array == single.constraint_array
+        assert results[name].all_satisfied == single.all_satisfied
+
+
+def test_grids_may_differ(
+    fleet: dict[str, Any], sun_constraint_45: SunConstraint
+) -> None:
+    results = sun_constraint_45.evaluate_many(fleet, TARGET_RA, TARGET_DEC)
+    assert len(results["sat"].constraint_array) == 361
+    assert len(results["sat_coarse"].constraint_array) == 37
+    assert len(results["north"].constraint_array) == 73
+
+
+def test_between_applies_to_each_ephemeris(
+    fleet: dict[str, Any], sun_constraint_45: SunConstraint
+) -> None:
+    between = (BEGIN, BEGIN + timedelta(minutes=30))
+    results = sun_constraint_45.evaluate_many(
+        fleet, TARGET_RA, TARGET_DEC, between=between
+    )
+    assert len(results["sat"].constraint_array) == 31
+    assert len(results["sat_coarse"].constraint_array) == 4
+    assert len(results["south"].constraint_array) == 7
+
+
+def test_at_times_shared_across_grids(
+    fleet: dict[str, Any], sun_constraint_45: SunConstraint
+) -> None:
+    at_times = [BEGIN + timedelta(minutes=7), BEGIN + timedelta(minutes=42)]
+    results = sun_constraint_45.evaluate_many(
+        fleet, TARGET_RA, TARGET_DEC, at_times=at_times
+    )
+    for result in results.values():
+        assert list(result.timestamps) == at_times
+
+
+def test_rust_constraint_returns_dict(fleet: dict[str, Any]) -> None:
+    constraint = Constraint.sun_proximity(45.0)
+    results = constraint.evaluate_many(fleet, TARGET_RA, TARGET_DEC)
+    assert set(results) == set(fleet)
+    assert all(hasattr(r, "constraint_array") for r in results.values())
+
+
+def test_empty_input(sun_constraint_45: SunConstraint) -> None:
+    assert sun_constraint_45.evaluate_many({}, TARGET_RA, TARGET_DEC) == {}
+
+
+def test_unsupported_ephemeris_raises(sun_constraint_45: SunConstraint) -> None:
+    with pytest.raises(TypeError):
+        sun_constraint_45.evaluate_many({"bad": object()}, TARGET_RA, TARGET_DEC)
@@ -0,0 +1,48 @@
+"""Fixtures for face Sun incidence tests."""
+
+from datetime import datetime, timedelta, timezone
+
+import numpy as np
+import numpy.typing as npt
+import pytest
+
+from rust_ephem import ArrayEphemeris
+
+BEGIN = datetime(2024, 1, 1, 0, 0, 0, tzinfo=timezone.utc)
+N_POINTS = 4
+ORBIT_RADIUS_KM = 7000.0
+
+IDENTITY = [0.0, 0.0, 0.0, 1.0]
+
+
+def parked(direction: npt.NDArray[np.float64]) -> ArrayEphemeris:
+    """Spacecraft held at ORBIT_RADIUS_KM along a GCRS direction"""
+    times = [BEGIN + timedelta(minutes=i) for i in range(N_POINTS)]
+    states = np.zeros((N_POINTS, 6))
+    states[:, :3] = ORBIT_RADIUS_KM * direction
+    return ArrayEphemeris(times, states)
+
+
+@pytest.fixture
+def sun_unit() -> npt.NDArray[np.float64]:
+    """Geocentric direction of the Sun at BEGIN"""
+    probe = parked(np.array([1.0, 0.0, 0.0]))
+    sun = np.asarray(probe.sun_pv.position[0])
+    return sun / np.linalg.norm(sun)
+
+
+@pytest.fixture
+def sunward(sun_unit: npt.NDArray[np.float64]) -> ArrayEphemeris:
+    """Spacecraft between the Earth and the Sun, always lit"""
+    return parked(sun_unit)
+
+
+@pytest.fixture
+def behind_earth(sun_unit: npt.NDArray[np.float64]) -> ArrayEphemeris:
+    """Spacecraft in the Earth's umbra"""
+    return parked(-sun_unit)
+
+
+@pytest.fixture
+def identity_quaternions() -> list[list[float]]:
+    return [IDENTITY] * N_POINTS
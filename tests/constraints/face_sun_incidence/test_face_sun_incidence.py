@@ -0,0 +1,108 @@
+"""Tests for face_sun_incidence()."""
+
+import numpy as np
+import numpy.typing as npt
+import pytest
+
+from rust_ephem import ArrayEphemeris, face_sun_incidence
+
+from .conftest import N_POINTS
+
+
+def faces(sun_unit: npt.NDArray[np.float64]) -> dict[str, list[float]]:
+    side = np.cross(sun_unit, [0.0, 0.0, 1.0])
+    return {
+        "front": list(sun_unit),
+        "back": list(-sun_unit),
+        "side": list(side / np.linalg.norm(side)),
+    }
+
+
+class TestSunlit:
+    def test_incidence_angles(
+        self,
+        sunward: ArrayEphemeris,
+        identity_quaternions: list[list[float]],
+        sun_unit: npt.NDArray[np.float64],
+    ) -> None:
+        inc = face_sun_incidence(sunward, identity_quaternions, faces(sun_unit))
+        assert inc.face_names == ["front", "back", "side"]
+        assert inc.incidence_deg.shape == (3, N_POINTS)
+        np.testing.assert_allclose(inc.incidence_deg[0], 0.0, atol=0.01)
+        np.testing.assert_allclose(inc.incidence_deg[1], 180.0, atol=0.01)
+        np.testing.assert_allclose(inc.incidence_deg[2], 90.0, atol=0.01)
+
+    def test_sunlit_flags(
+        self,
+        sunward: ArrayEphemeris,
+        identity_quaternions: list[list[float]],
+        sun_unit: npt.NDArray[np.float64],
+    ) -> None:
+        inc = face_sun_incidence(sunward, identity_quaternions, faces(sun_unit))
+        assert inc.in_eclipse == [False] * N_POINTS
+        assert inc.sunlit[0].all()
+        assert not inc.sunlit[1].any()
+
+    def test_illumination(
+        self,
+        sunward: ArrayEphemeris,
+        identity_quaternions: list[list[float]],
+        sun_unit: npt.NDArray[np.float64],
+    ) -> None:
+        inc = face_sun_incidence(sunward, identity_quaternions, faces(sun_unit))
+        np.testing.assert_allclose(inc.illumination[0], 1.0, atol=1e-6)
+        np.testing.assert_array_equal(inc.illumination[1], 0.0)
+
+    def test_attitude_rotates_faces(
+        self,
+        sunward: ArrayEphemeris,
+        sun_unit: npt.NDArray[np.float64],
+    ) -> None:
+        # 180 degrees about an axis perpendicular to the Sun turns front to back
+        axis = np.cross(sun_unit, [0.0, 0.0, 1.0])
+        axis /= np.linalg.norm(axis)
+        flip = [*axis, 0.0]
+        inc = face_sun_incidence(sunward, [flip] * N_POINTS, faces(sun_unit))
+        np.testing.assert_allclose(inc.incidence_deg[0], 180.0, atol=0.01)
+        np.testing.assert_allclose(inc.incidence_deg[1], 0.0, atol=0.01)
+
+
+def test_eclipse_masks_insolation(
+    behind_earth: ArrayEphemeris,
+    identity_quaternions: list[list[float]],
+    sun_unit: npt.NDArray[np.float64],
+) -> None:
+    inc = face_sun_incidence(behind_earth, identity_quaternions, faces(sun_unit))
+    assert inc.in_eclipse == [True] * N_POINTS
+    assert not inc.sunlit.any()
+    np.testing.assert_allclose(inc.incidence_deg[0], 0.0, atol=0.1)
+
+
+def test_timestamp_and_len(
+    sunward: ArrayEphemeris,
+    identity_quaternions: list[list[float]],
+    sun_unit: npt.NDArray[np.float64],
+) -> None:
+    inc = face_sun_incidence(sunward, identity_quaternions, faces(sun_unit))
+    assert len(inc) == 3
+    assert len(inc.timestamp) == N_POINTS
+
+
+class TestValidation:
+    def test_wrong_quaternion_count(
+        self, sunward: ArrayEphemeris, sun_unit: npt.NDArray[np.float64]
+    ) -> None:
+        with pytest.raises(ValueError, match="one quaternion per ephemeris time"):
+            face_sun_incidence(sunward, [[0.0, 0.0, 0.0, 1.0]], faces(sun_unit))
+
+    def test_zero_normal(
+        self, sunward: ArrayEphemeris, identity_quaternions: list[list[float]]
+    ) -> None:
+        with pytest.raises(ValueError, match="face 'bad'"):
+            face_sun_incidence(sunward, identity_quaternions, {"bad": (0, 0, 0)})
+
+    def test_no_faces(
+        self, sunward: ArrayEphemeris, identity_quaternions: list[list[float]]
+    ) -> None:
+        with pytest.raises(ValueError, match="at least one face"):
+            face_sun_incidence(sunward, identity_quaternions, {})
@@ -0,0 +1,42 @@
+"""Fixtures for geomagnetic activity constraint tests."""
+
+from datetime import datetime, timedelta, timezone
+from pathlib import Path
+
+import numpy as np
+import pytest
+
+import rust_ephem
+from rust_ephem import ArrayEphemeris
+
+MAX_KP = 5.0
+
+# Kp for 2024-05-09 and the storm of 2024-05-10, in tenths as Celestrak stores it
+SW_CSV = """\
+DATE,KP1,KP2,KP3,KP4,KP5,KP6,KP7,KP8,AP_AVG,F10.7_OBS,F10.7_DATA_TYPE
+2024-05-09,7,10,13,17,20,23,27,30,8,209.3,OBS
+2024-05-10,40,53,57,70,83,87,90,90,202,190.4,OBS
+2024-06-01,,,,,,,,,12,165.0,PRD
+"""
+
+# 3-hourly samples across the storm onset, then a predicted day without Kp
+TIMES = [
+    datetime(2024, 5, 9, 18, tzinfo=timezone.utc) + timedelta(hours=3 * i)
+    for i in range(5)
+] + [datetime(2024, 6, 1, 12, tzinfo=timezone.utc)]
+
+
+@pytest.fixture
+def storm_data(tmp_path: Path) -> None:
+    """Load the sample space-weather file"""
+    path = tmp_path / "SW-All.csv"
+    path.write_text(SW_CSV)
+    rust_ephem.load_space_weather(str(path))
+
+
+@pytest.fixture
+def spacecraft(storm_data: None) -> ArrayEphemeris:
+    """Fixed ITRS position at the sample times"""
+    states = np.zeros((len(TIMES), 6))
+    states[:, 0] = 7000.0
+    return ArrayEphemeris(TIMES, states, frame="ITRS")
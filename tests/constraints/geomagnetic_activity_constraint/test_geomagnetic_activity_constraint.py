@@ -0,0 +1,60 @@
+"""Tests for the geomagnetic activity constraint."""
+
+import json
+
+import pytest
+from pydantic import ValidationError
+
+import rust_ephem
+from rust_ephem.constraints import GeomagneticActivityConstraint
+
+from .conftest import MAX_KP, TIMES
+
+
+def test_storm_violates(spacecraft: rust_ephem.ArrayEphemeris) -> None:
+    result = rust_ephem.Constraint.geomagnetic_activity(MAX_KP).evaluate(
+        spacecraft, 0.0, 0.0
+    )
+    assert result.constraint_array == [False, False, False, True, True, False]
+    assert len(result.violations) == 1
+    assert "exceeds maximum" in result.violations[0].description
+
+
+def test_independent_of_target(spacecraft: rust_ephem.ArrayEphemeris) -> None:
+    constraint = rust_ephem.Constraint.geomagnetic_activity(MAX_KP)
+    batch = constraint.in_constraint_batch(spacecraft, [0.0, 200.0], [0.0, -45.0])
+    assert batch.shape == (2, len(TIMES))
+    assert (batch == batch[0]).all()
+    assert list(batch[0]) == [False, False, False, True, True, False]
+
+
+def test_time_subset(spacecraft: rust_ephem.ArrayEphemeris) -> None:
+    constraint = rust_ephem.Constraint.geomagnetic_activity(MAX_KP)
+    result = constraint.evaluate(spacecraft, 0.0, 0.0, indices=[1, 4])
+    assert result.constraint_array == [False, True]
+
+
+class TestConfiguration:
+    @pytest.mark.parametrize("max_kp", [-1.0, 9.5])
+    def test_invalid_threshold(self, max_kp: float) -> None:
+        with pytest.raises(ValueError, match="max_kp"):
+            rust_ephem.Constraint.geomagnetic_activity(max_kp)
+        with pytest.raises(ValidationError):
+            GeomagneticActivityConstraint(max_kp=max_kp)
+
+    def test_json_round_trip(self) -> None:
+        constraint = rust_ephem.Constraint.geomagnetic_activity(MAX_KP)
+        config = json.loads(constraint.to_json())
+        assert config == {"type": "geomagnetic_activity", "max_kp": MAX_KP}
+        restored = rust_ephem.Constraint.from_json(constraint.to_json())
+        assert "GeomagneticActivityConstraint(max_kp=5.0)" in str(restored)
+
+    def test_pydantic_matches_factory(
+        self, spacecraft: rust_ephem.ArrayEphemeris
+    ) -> None:
+        model = GeomagneticActivityConstraint(max_kp=MAX_KP)
+        factory = rust_ephem.Constraint.geomagnetic_activity(MAX_KP)
+        assert (
+            model.evaluate(spacecraft, 0.0, 0.0).constraint_array
+            == factory.evaluate(spacecraft, 0.0, 0.0).constraint_array
+        )
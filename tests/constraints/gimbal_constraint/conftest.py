@@ -0,0 +1,22 @@
+"""Fixtures for antenna gimbal constraint tests."""
+
+import datetime
+
+import pytest
+
+import rust_ephem
+
+BEGIN = datetime.datetime(2024, 1, 1, 0, 0, 0, tzinfo=datetime.timezone.utc)
+END = datetime.datetime(2024, 1, 2, 0, 0, 0, tzinfo=datetime.timezone.utc)
+STEP_SIZE = 60
+SITE_LATITUDE = 30.0
+
+# A star at the site's latitude transits through the zenith once a day
+ZENITH_TRANSIT_DEC = SITE_LATITUDE
+# ...while one 50 degrees further south culminates at 40 degrees altitude
+LOW_DEC = SITE_LATITUDE - 50.0
+
+
+@pytest.fixture
+def site() -> rust_ephem.GroundEphemeris:
+    return rust_ephem.GroundEphemeris(SITE_LATITUDE, 0.0, 0.0, BEGIN, END, STEP_SIZE)
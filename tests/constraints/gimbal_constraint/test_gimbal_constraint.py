@@ -0,0 +1,130 @@
+"""Tests for the antenna gimbal constraint."""
+
+import json
+
+import numpy as np
+import pytest
+from pydantic import ValidationError
+
+import rust_ephem
+from rust_ephem import GimbalConstraint
+
+from .conftest import LOW_DEC, ZENITH_TRANSIT_DEC
+
+
+class TestKeyhole:
+    def test_zenith_transit_enters_keyhole(
+        self, site: rust_ephem.GroundEphemeris
+    ) -> None:
+        constraint = rust_ephem.Constraint.gimbal(keyhole_radius=3.0)
+        result = constraint.evaluate(site, 0.0, ZENITH_TRANSIT_DEC)
+        assert len(result.violations) == 1
+        assert "keyhole" in result.violations[0].description
+        # Sidereal motion crosses a 6 degree wide keyhole in under an hour
+        assert 0 < result.total_violation_duration() < 3600
+
+    def test_low_target_clears_keyhole(self, site: rust_ephem.GroundEphemeris) -> None:
+        constraint = rust_ephem.Constraint.gimbal(keyhole_radius=3.0)
+        assert constraint.evaluate(site, 0.0, LOW_DEC).all_satisfied
+
+    def test_keyhole_centre_off_zenith(self, site: rust_ephem.GroundEphemeris) -> None:
+        # A horizon keyhole due south catches the low target as it culminates
+        constraint = rust_ephem.Constraint.gimbal(
+            keyhole_radius=45.0, keyhole_azimuth=180.0, keyhole_elevation=0.0
+        )
+        assert not constraint.evaluate(site, 0.0, LOW_DEC).all_satisfied
+        assert constraint.evaluate(site, 0.0, ZENITH_TRANSIT_DEC).all_satisfied
+
+
+class TestRates:
+    def test_azimuth_rate_near_zenith(self, site: rust_ephem.GroundEphemeris) -> None:
+        constraint = rust_ephem.Constraint.gimbal(max_azimuth_rate=0.1)
+        result = constraint.evaluate(site, 0.0, ZENITH_TRANSIT_DEC)
+        assert not result.all_satisfied
+        assert "Azimuth rate" in result.violations[0].description
+        assert constraint.evaluate(site, 0.0, LOW_DEC).all_satisfied
+
+    def test_elevation_rate(self, site: rust_ephem.GroundEphemeris) -> None:
+        # Sidereal motion never needs more than 0.0042 deg/s in elevation
+        constraint = rust_ephem.Constraint.gimbal(max_elevation_rate=0.005)
+        assert constraint.evaluate(site, 0.0, LOW_DEC).all_satisfied
+        tight = rust_ephem.Constraint.gimbal(max_elevation_rate=0.001)
+        assert not tight.evaluate(site, 0.0, LOW_DEC).all_satisfied
+
+    def test_moving_target_rates_follow_target(
+        self, site: rust_ephem.GroundEphemeris
+    ) -> None:
+        n = len(site.timestamp)
+        constraint = rust_ephem.Constraint.gimbal(max_azimuth_rate=0.05)
+        still = constraint.evaluate_moving_body(
+            site, target_ras=[0.0] * n, target_decs=[LOW_DEC] * n
+        )
+        assert still.all_satisfied
+        # A target sweeping 10 degrees of RA per minute outruns the mount
+        sweeping = constraint.evaluate_moving_body(
+            site,
+            target_ras=list(np.arange(n) * 10.0 % 360.0),
+            target_decs=[0.0] * n,
+        )
+        assert not sweeping.all_satisfied
+
+
+def test_batch_matches_single(site: rust_ephem.GroundEphemeris) -> None:
+    constraint = rust_ephem.Constraint.gimbal(keyhole_radius=3.0, max_azimuth_rate=0.1)
+    batch = constraint.in_constraint_batch(
+        site, [0.0, 0.0], [ZENITH_TRANSIT_DEC, LOW_DEC]
+    )
+    for row, dec in zip(batch, [ZENITH_TRANSIT_DEC, LOW_DEC]):
+        single = constraint.evaluate(site, 0.0, dec)
+        np.testing.assert_array_equal(row, single.constraint_array)
+
+
+def test_subset_uses_full_grid_rates(site: rust_ephem.GroundEphemeris) -> None:
+    constraint = rust_ephem.Constraint.gimbal(max_azimuth_rate=0.1)
+    full = constraint.evaluate(site, 0.0, ZENITH_TRANSIT_DEC)
+    violated = int(np.argmax(full.constraint_array))
+    subset = constraint.evaluate(site, 0.0, ZENITH_TRANSIT_DEC, indices=[violated])
+    assert not subset.all_satisfied
+
+
+class TestConfiguration:
+    def test_requires_a_limit(self) -> None:
+        with pytest.raises(ValueError, match="at least one"):
+            rust_ephem.Constraint.gimbal()
+        with pytest.raises(ValidationError):
+            GimbalConstraint()
+
+    @pytest.mark.parametrize(
+        "kwargs",
+        [
+            {"keyhole_radius": 95.0},
+            {"keyhole_radius": 3.0, "keyhole_azimuth": 400.0},
+            {"keyhole_radius": 3.0, "keyhole_elevation": -95.0},
+            {"max_azimuth_rate": 0.0},
+            {"max_elevation_rate": -1.0},
+        ],
+    )
+    def test_invalid_limits(self, kwargs: dict[str, float]) -> None:
+        with pytest.raises(ValueError):
+            rust_ephem.Constraint.gimbal(**kwargs)
+        with pytest.raises(ValidationError):
+            GimbalConstraint(**kwargs)
+
+    def test_json_round_trip(self) -> None:
+        constraint = rust_ephem.Constraint.gimbal(
+            keyhole_radius=3.0, max_azimuth_rate=2.0
+        )
+        config = json.loads(constraint.to_json())
+        assert config["type"] == "gimbal"
+        assert config["keyhole_elevation"] == 90.0
+        restored = rust_ephem.Constraint.from_json(constraint.to_json())
+        assert "keyhole=3.0°" in str(restored)
+
+    def test_pydantic_matches_factory(self, site: rust_ephem.GroundEphemeris) -> None:
+        model = GimbalConstraint(keyhole_radius=3.0)
+        factory = rust_ephem.Constraint.gimbal(keyhole_radius=3.0)
+        assert model.keyhole_elevation == 90.0
+        np.testing.assert_array_equal(
+            model.evaluate(site, 0.0, ZENITH_TRANSIT_DEC).constraint_array,
+            factory.evaluate(site, 0.0, ZENITH_TRANSIT_DEC).constraint_array,
+        )
@@ -0,0 +1,23 @@
+"""Fixtures for ground-target illumination constraint tests."""
+
+from datetime import datetime, timedelta, timezone
+
+import pytest
+
+import rust_ephem
+
+# Equinox day, so the Sun crosses the equator and the scene at (0°, 0°) is
+# lit from about 06:00 to 18:00 UTC with local noon near 12:00 UTC
+BEGIN = datetime(2024, 3, 20, tzinfo=timezone.utc)
+END = BEGIN + timedelta(days=1)
+STEP_SIZE = 600
+
+# Sample indices of 00:00 and 12:00 UTC
+MIDNIGHT = 0
+NOON = 12 * 3600 // STEP_SIZE
+
+
+@pytest.fixture
+def geo() -> rust_ephem.GEOEphemeris:
+    """Geostationary satellite above the scene at (0°, 0°)"""
+    return rust_ephem.GEOEphemeris(0.0, BEGIN, END, STEP_SIZE)
@@ -0,0 +1,115 @@
+"""Tests for the ground-target illumination constraint."""
+
+import json
+
+import numpy as np
+import pytest
+from pydantic import ValidationError
+
+import rust_ephem
+from rust_ephem import GroundTargetIlluminationConstraint
+
+from .conftest import MIDNIGHT, NOON
+
+
+class TestIllumination:
+    def test_daylight_only(self, geo: rust_ephem.GEOEphemeris) -> None:
+        constraint = rust_ephem.Constraint.ground_target_illumination(
+            0.0, 0.0, min_sun_elevation=20.0
+        )
+        result = constraint.evaluate(geo, 0.0, 0.0)
+        violated = np.asarray(result.constraint_array)
+        assert violated[MIDNIGHT]
+        assert not violated[NOON]
+        # One dark stretch before sunrise and one after sunset
+        assert len(result.violations) == 2
+        assert "Sun elevation at target" in result.violations[0].description
+
+    def test_night_imaging(self, geo: rust_ephem.GEOEphemeris) -> None:
+        constraint = rust_ephem.Constraint.ground_target_illumination(
+            0.0, 0.0, min_sun_elevation=None, max_sun_elevation=-12.0
+        )
+        violated = np.asarray(constraint.evaluate(geo, 0.0, 0.0).constraint_array)
+        assert not violated[MIDNIGHT]
+        assert violated[NOON]
+
+    def test_terminator_splits_the_day(self, geo: rust_ephem.GEOEphemeris) -> None:
+        day = rust_ephem.Constraint.ground_target_illumination(0.0, 0.0)
+        night = rust_ephem.Constraint.ground_target_illumination(
+            0.0, 0.0, min_sun_elevation=None, max_sun_elevation=0.0
+        )
+        day_violated = np.asarray(day.evaluate(geo, 0.0, 0.0).constraint_array)
+        night_violated = np.asarray(night.evaluate(geo, 0.0, 0.0).constraint_array)
+        np.testing.assert_array_equal(day_violated, ~night_violated)
+
+
+class TestAccess:
+    def test_scene_below_horizon(self, geo: rust_ephem.GEOEphemeris) -> None:
+        # 100° of longitude away the satellite is below the scene's horizon
+        constraint = rust_ephem.Constraint.ground_target_illumination(
+            0.0, 100.0, min_sun_elevation=None
+        )
+        result = constraint.evaluate(geo, 0.0, 0.0)
+        assert all(result.constraint_array)
+        assert "spacecraft elevation" in result.violations[0].description
+
+    def test_off_nadir_limit(self, geo: rust_ephem.GEOEphemeris) -> None:
+        # A scene 30° east is about 5° off nadir from GEO
+        narrow = rust_ephem.Constraint.ground_target_illumination(
+            0.0, 30.0, min_sun_elevation=None, max_off_nadir=3.0
+        )
+        wide = rust_ephem.Constraint.ground_target_illumination(
+            0.0, 30.0, min_sun_elevation=None, max_off_nadir=6.0
+        )
+        result = narrow.evaluate(geo, 0.0, 0.0)
+        assert all(result.constraint_array)
+        assert "off-nadir" in result.violations[0].description
+        assert wide.evaluate(geo, 0.0, 0.0).all_satisfied
+
+
+def test_batch_is_target_independent(geo: rust_ephem.GEOEphemeris) -> None:
+    constraint = rust_ephem.Constraint.ground_target_illumination(0.0, 0.0)
+    batch = constraint.in_constraint_batch(geo, [0.0, 120.0], [0.0, -40.0])
+    single = constraint.evaluate(geo, 0.0, 0.0)
+    for row in batch:
+        np.testing.assert_array_equal(row, single.constraint_array)
+
+
+class TestConfiguration:
+    @pytest.mark.parametrize(
+        "kwargs",
+        [
+            {"latitude": 95.0, "longitude": 0.0},
+            {"latitude": 0.0, "longitude": 400.0},
+            {"latitude": 0.0, "longitude": 0.0, "max_sun_elevation": -5.0},
+            {"latitude": 0.0, "longitude": 0.0, "max_off_nadir": 0.0},
+        ],
+    )
+    def test_invalid_limits(self, kwargs: dict[str, float]) -> None:
+        with pytest.raises(ValueError):
+            rust_ephem.Constraint.ground_target_illumination(**kwargs)
+        with pytest.raises(ValidationError):
+            GroundTargetIlluminationConstraint(**kwargs)
+
+    def test_json_round_trip(self) -> None:
+        constraint = rust_ephem.Constraint.ground_target_illumination(
+            48.86, 2.35, min_sun_elevation=20.0, max_off_nadir=30.0
+        )
+        config = json.loads(constraint.to_json())
+        assert config["type"] == "ground_target_illumination"
+        assert config["min_sun_elevation"] == 20.0
+        assert "max_sun_elevation" not in config
+        restored = rust_ephem.Constraint.from_json(constraint.to_json())
+        assert "max_off_nadir=30.0°" in str(restored)
+
+    def test_pydantic_matches_factory(self, geo: rust_ephem.GEOEphemeris) -> None:
+        model = GroundTargetIlluminationConstraint(
+            latitude=0.0, longitude=0.0, min_sun_elevation=20.0
+        )
+        factory = rust_ephem.Constraint.ground_target_illumination(
+            0.0, 0.0, min_sun_elevation=20.0
+        )
+        np.testing.assert_array_equal(
+            model.evaluate(geo, 0.0, 0.0).constraint_array,
+            factory.evaluate(geo, 0.0, 0.0).constraint_array,
+        )
@@ -0,0 +1,39 @@
+"""Fixtures for ground-site visibility constraint tests."""
+
+import datetime
+
+import numpy as np
+import pytest
+
+import rust_ephem
+
+BEGIN = datetime.datetime(2024, 1, 1, 0, 0, 0, tzinfo=datetime.timezone.utc)
+END = datetime.datetime(2024, 1, 2, 0, 0, 0, tzinfo=datetime.timezone.utc)
+STEP_SIZE = 120
+SITE_LATITUDE = 30.0
+
+# Close to the celestial pole: always ~30 degrees up, due north
+POLAR_DEC = 89.0
+
+# Horizon profile with a 40 degree ridge to the north
+NORTH_RIDGE = [(0.0, 40.0), (45.0, 0.0), (315.0, 0.0)]
+
+
+@pytest.fixture
+def site() -> rust_ephem.GroundEphemeris:
+    return rust_ephem.GroundEphemeris(SITE_LATITUDE, 0.0, 0.0, BEGIN, END, STEP_SIZE)
+
+
+@pytest.fixture
+def ridge_site() -> rust_ephem.GroundEphemeris:
+    return rust_ephem.GroundEphemeris(
+        SITE_LATITUDE, 0.0, 0.0, BEGIN, END, STEP_SIZE, horizon=NORTH_RIDGE
+    )
+
+
+@pytest.fixture
+def spacecraft() -> rust_ephem.ArrayEphemeris:
+    times = [BEGIN + datetime.timedelta(minutes=i) for i in range(3)]
+    states = np.zeros((3, 6))
+    states[:, 0] = 7000.0
+    return rust_ephem.ArrayEphemeris(times, states, frame="ITRS")
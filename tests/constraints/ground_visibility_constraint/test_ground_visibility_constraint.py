@@ -0,0 +1,165 @@
+"""Tests for the ground-site visibility constraint."""
+
+import json
+
+import numpy as np
+import pytest
+from pydantic import ValidationError
+
+import rust_ephem
+from rust_ephem import GroundVisibilityConstraint
+
+from .conftest import POLAR_DEC, STEP_SIZE
+
+
+def _dark_hours(result: rust_ephem.ConstraintResult) -> float:
+    return (len(result.constraint_array) - sum(result.constraint_array)) * (
+        STEP_SIZE / 3600.0
+    )
+
+
+class TestTwilight:
+    def test_polar_target_limited_by_sun_only(
+        self, site: rust_ephem.GroundEphemeris
+    ) -> None:
+        constraint = rust_ephem.Constraint.ground_visibility()
+        result = constraint.evaluate(site, 0.0, POLAR_DEC)
+        assert len(result.violations) in (1, 2)
+        assert "astronomical twilight" in result.violations[0].description
+        # Around 10.5 hours of astronomical darkness at 30N in January
+        assert 9.5 < _dark_hours(result) < 11.5
+
+    def test_matches_daytime_constraint(self, site: rust_ephem.GroundEphemeris) -> None:
+        for twilight in ("none", "civil", "nautical", "astronomical"):
+            ground = rust_ephem.Constraint.ground_visibility(twilight=twilight)
+            daytime = rust_ephem.Constraint.daytime(twilight=twilight)
+            mismatched = np.sum(
+                np.asarray(ground.evaluate(site, 0.0, POLAR_DEC).constraint_array)
+                != np.asarray(daytime.evaluate(site, 0.0, POLAR_DEC).constraint_array)
+            )
+            # The daytime constraint's geocentric approximation can move each
+            # twilight boundary by a few minutes
+            assert mismatched <= 8
+
+    def test_deeper_twilight_is_shorter_night(
+        self, site: rust_ephem.GroundEphemeris
+    ) -> None:
+        hours = [
+            _dark_hours(
+                rust_ephem.Constraint.ground_visibility(twilight=twilight).evaluate(
+                    site, 0.0, POLAR_DEC
+                )
+            )
+            for twilight in ("none", "civil", "nautical", "astronomical")
+        ]
+        assert hours == sorted(hours, reverse=True)
+        assert hours[0] > hours[-1] + 2.0
+
+
+class TestHorizon:
+    def test_horizon_mask_blocks_target(
+        self, ridge_site: rust_ephem.GroundEphemeris
+    ) -> None:
+        constraint = rust_ephem.Constraint.ground_visibility()
+        result = constraint.evaluate(ridge_site, 0.0, POLAR_DEC)
+        assert all(result.constraint_array)
+        assert "below horizon" in result.violations[0].description
+
+    def test_target_clear_of_mask(self, ridge_site: rust_ephem.GroundEphemeris) -> None:
+        constraint = rust_ephem.Constraint.ground_visibility(twilight="none")
+        # An equatorial target spends part of the night well south of the ridge
+        result = constraint.evaluate(ridge_site, 100.0, 0.0)
+        assert not all(result.constraint_array)
+
+    def test_min_altitude(self, site: rust_ephem.GroundEphemeris) -> None:
+        constraint = rust_ephem.Constraint.ground_visibility(min_altitude=45.0)
+        assert all(constraint.evaluate(site, 0.0, POLAR_DEC).constraint_array)
+
+    def test_max_airmass(self, site: rust_ephem.GroundEphemeris) -> None:
+        baseline = rust_ephem.Constraint.ground_visibility().evaluate(
+            site, 0.0, POLAR_DEC
+        )
+        # At ~30 degrees altitude the airmass is ~2
+        loose = rust_ephem.Constraint.ground_visibility(max_airmass=3.0)
+        tight = rust_ephem.Constraint.ground_visibility(max_airmass=1.5)
+        np.testing.assert_array_equal(
+            loose.evaluate(site, 0.0, POLAR_DEC).constraint_array,
+            baseline.constraint_array,
+        )
+        assert all(tight.evaluate(site, 0.0, POLAR_DEC).constraint_array)
+
+
+class TestMoon:
+    def test_moon_altitude_limit(self, site: rust_ephem.GroundEphemeris) -> None:
+        baseline = rust_ephem.Constraint.ground_visibility().evaluate(
+            site, 0.0, POLAR_DEC
+        )
+        moon_down = rust_ephem.Constraint.ground_visibility(max_moon_altitude=0.0)
+        result = moon_down.evaluate(site, 0.0, POLAR_DEC)
+        # The Moon is up for part of every day, so some dark time is lost
+        assert _dark_hours(result) < _dark_hours(baseline)
+
+    def test_unreachable_limit(self, site: rust_ephem.GroundEphemeris) -> None:
+        constraint = rust_ephem.Constraint.ground_visibility(max_moon_altitude=-90.0)
+        assert all(constraint.evaluate(site, 0.0, POLAR_DEC).constraint_array)
+
+
+def test_batch_matches_single(ridge_site: rust_ephem.GroundEphemeris) -> None:
+    constraint = rust_ephem.Constraint.ground_visibility(
+        min_altitude=10.0, max_airmass=2.5
+    )
+    ras = [0.0, 100.0, 250.0]
+    decs = [POLAR_DEC, 0.0, -20.0]
+    batch = constraint.in_constraint_batch(ridge_site, ras, decs)
+    for row, ra, dec in zip(batch, ras, decs):
+        single = constraint.evaluate(ridge_site, ra, dec)
+        np.testing.assert_array_equal(row, single.constraint_array)
+
+
+def test_rejects_spacecraft_observer(
+    spacecraft: rust_ephem.ArrayEphemeris,
+) -> None:
+    constraint = rust_ephem.Constraint.ground_visibility()
+    with pytest.raises(ValueError, match="GroundEphemeris"):
+        constraint.evaluate(spacecraft, 0.0, 0.0)
+
+
+class TestConfiguration:
+    @pytest.mark.parametrize(
+        "kwargs",
+        [
+            {"min_altitude": 95.0},
+            {"max_airmass": 0.5},
+            {"max_moon_altitude": -95.0},
+        ],
+    )
+    def test_invalid_limits(self, kwargs: dict[str, float]) -> None:
+        with pytest.raises(ValueError):
+            rust_ephem.Constraint.ground_visibility(**kwargs)
+        with pytest.raises(ValidationError):
+            GroundVisibilityConstraint(**kwargs)
+
+    def test_invalid_twilight(self) -> None:
+        with pytest.raises(ValueError, match="twilight"):
+            rust_ephem.Constraint.ground_visibility(twilight="dusk")
+
+    def test_json_round_trip(self) -> None:
+        constraint = rust_ephem.Constraint.ground_visibility(
+            min_altitude=20.0, max_airmass=2.0
+        )
+        config = json.loads(constraint.to_json())
+        assert config["type"] == "ground_visibility"
+        assert config["twilight"] == "astronomical"
+        assert "max_moon_altitude" not in config
+        restored = rust_ephem.Constraint.from_json(constraint.to_json())
+        assert "max_airmass=2.00" in str(restored)
+
+    def test_pydantic_matches_factory(self, site: rust_ephem.GroundEphemeris) -> None:
+        model = GroundVisibilityConstraint(min_altitude=20.0, twilight="nautical")
+        factory = rust_ephem.Constraint.ground_visibility(
+            min_altitude=20.0, twilight="nautical"
+        )
+        np.testing.assert_array_equal(
+            model.evaluate(site, 0.0, POLAR_DEC).constraint_array,
+            factory.evaluate(site, 0.0, POLAR_DEC).constraint_array,
+        )
@@ -0,0 +1,87 @@
+"""Tests for limb-relative Sun, Moon and body proximity constraints."""
+
+from typing import Any
+
+import pytest
+from pydantic import ValidationError
+
+import rust_ephem
+from rust_ephem.constraints import BodyConstraint, MoonConstraint, SunConstraint
+
+
+def in_constraint_at_offset(
+    constraint: Any,
+    ephem: rust_ephem.TLEEphemeris,
+    timestamp: Any,
+    body: Any,
+    offset: float,
+) -> bool:
+    """Evaluate with the target offset in RA from a body near the equator"""
+    return bool(
+        constraint.in_constraint(
+            ephemeris=ephem,
+            time=timestamp,
+            target_ra=(body.ra.deg + offset) % 360,
+            target_dec=body.dec.deg,
+        )
+    )
+
+
+class TestSunLimbRelative:
+    def test_limb_adds_angular_radius(
+        self, tle_ephem: rust_ephem.TLEEphemeris, timestamp: Any
+    ) -> None:
+        sun = tle_ephem.sun[0]
+        # 45.1 deg from the center is only ~44.83 deg from the limb
+        center = SunConstraint(min_angle=45.0)
+        limb = SunConstraint(min_angle=45.0, limb_relative=True)
+        assert not in_constraint_at_offset(center, tle_ephem, timestamp, sun, 45.1)
+        assert in_constraint_at_offset(limb, tle_ephem, timestamp, sun, 45.1)
+        assert not in_constraint_at_offset(limb, tle_ephem, timestamp, sun, 45.4)
+
+    def test_max_angle_is_limb_relative(
+        self, tle_ephem: rust_ephem.TLEEphemeris, timestamp: Any
+    ) -> None:
+        sun = tle_ephem.sun[0]
+        limb = SunConstraint(min_angle=10.0, max_angle=90.0, limb_relative=True)
+        assert not in_constraint_at_offset(limb, tle_ephem, timestamp, sun, 90.1)
+        assert in_constraint_at_offset(limb, tle_ephem, timestamp, sun, 90.4)
+
+    def test_name_marks_limb(self) -> None:
+        constraint = rust_ephem.Constraint.sun_proximity(45.0, limb_relative=True)
+        assert "limb" in str(constraint)
+
+    def test_json_round_trip(self) -> None:
+        constraint = SunConstraint(min_angle=45.0, limb_relative=True)
+        restored = SunConstraint.model_validate_json(constraint.model_dump_json())
+        assert restored.limb_relative is True
+
+
+class TestMoonLimbRelative:
+    def test_limb_adds_angular_radius(
+        self, tle_ephem: rust_ephem.TLEEphemeris, timestamp: Any
+    ) -> None:
+        moon = tle_ephem.moon[0]
+        center = MoonConstraint(min_angle=20.0)
+        limb = MoonConstraint(min_angle=20.0, limb_relative=True)
+        # The Moon's angular radius is ~0.25 deg
+        assert not in_constraint_at_offset(center, tle_ephem, timestamp, moon, 20.1)
+        assert in_constraint_at_offset(limb, tle_ephem, timestamp, moon, 20.1)
+
+
+class TestBodyLimbRelative:
+    def test_planet_with_known_radius(self) -> None:
+        constraint = BodyConstraint(body="Jupiter", min_angle=5.0, limb_relative=True)
+        assert constraint.limb_relative is True
+
+    def test_unknown_radius_rejected(self) -> None:
+        with pytest.raises(ValueError, match="set_body_radii"):
+            rust_ephem.Constraint.body_proximity("SEL2", 5.0, limb_relative=True)
+
+    def test_polygon_rejected(self) -> None:
+        with pytest.raises(ValidationError, match="limb_relative"):
+            BodyConstraint(
+                body="Mars",
+                limb_relative=True,
+                fov_polygon=[(1.0, 1.0), (-1.0, 1.0), (-1.0, -1.0)],
+            )
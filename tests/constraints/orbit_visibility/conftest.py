@@ -0,0 +1,40 @@
+"""Fixtures for orbit visibility tests."""
+
+from datetime import datetime, timedelta, timezone
+
+import numpy as np
+import pytest
+
+from rust_ephem import ArrayEphemeris, Constraint
+
+BEGIN = datetime(2024, 1, 1, 0, 0, 0, tzinfo=timezone.utc)
+STEP = 60
+# 100-minute circular equatorial orbit sampled every minute, starting half a
+# step past the descending node, so 300 samples make a partial first orbit, two
+# complete orbits and a partial last orbit
+N_POINTS = 300
+SAMPLES_PER_ORBIT = 100
+RADIUS_KM = 7000.0
+
+# +x lies in the orbit plane and is visible around the node; the pole is
+# visible all orbit
+IN_PLANE = (0.0, 0.0)
+POLE = (0.0, 90.0)
+
+
+@pytest.fixture
+def spacecraft() -> ArrayEphemeris:
+    times = [BEGIN + timedelta(seconds=STEP * i) for i in range(N_POINTS)]
+    rate = 2.0 * np.pi / (SAMPLES_PER_ORBIT * STEP)
+    u = rate * STEP * (np.arange(N_POINTS) - 50 + 0.5)
+    states = np.zeros((N_POINTS, 6))
+    states[:, 0] = RADIUS_KM * np.cos(u)
+    states[:, 1] = RADIUS_KM * np.sin(u)
+    states[:, 3] = -RADIUS_KM * rate * np.sin(u)
+    states[:, 4] = RADIUS_KM * rate * np.cos(u)
+    return ArrayEphemeris(times, states)
+
+
+@pytest.fixture
+def earth_limb() -> Constraint:
+    return Constraint.earth_limb(min_angle=10.0)
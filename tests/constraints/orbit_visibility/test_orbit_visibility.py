@@ -0,0 +1,61 @@
+"""Tests for per-orbit visibility efficiency."""
+
+import numpy as np
+import pytest
+
+from rust_ephem import ArrayEphemeris, Constraint, orbit_visibility
+
+from .conftest import IN_PLANE, POLE
+
+
+class TestOrbitVisibility:
+    def test_orbits_split_at_ascending_node(
+        self, spacecraft: ArrayEphemeris, earth_limb: Constraint
+    ) -> None:
+        orbits = orbit_visibility(spacecraft, IN_PLANE, earth_limb)
+        assert orbits.n_orbits == 4
+        assert len(orbits) == 4
+        assert orbits.complete == [False, True, True, False]
+        # The first orbit starts half an orbit before the first node
+        assert orbits.orbit_end[0] < orbits.orbit_start[1]
+
+    def test_in_plane_target_visible_near_node(
+        self, spacecraft: ArrayEphemeris, earth_limb: Constraint
+    ) -> None:
+        orbits = orbit_visibility(spacecraft, IN_PLANE, earth_limb)
+        # Visible while within ~104 degrees of +x: about 58% of each orbit
+        assert orbits.mean_visible_fraction == pytest.approx(0.58, abs=0.02)
+        complete = np.array(orbits.complete)
+        mean_u = orbits.mean_argument_of_latitude[complete]
+        assert np.all(np.minimum(mean_u, 360.0 - mean_u) < 2.0)
+        assert 0.4 < orbits.solar_phase_concentration < 0.7
+
+    def test_pole_visible_all_orbit(
+        self, spacecraft: ArrayEphemeris, earth_limb: Constraint
+    ) -> None:
+        orbits = orbit_visibility(spacecraft, POLE, earth_limb)
+        np.testing.assert_allclose(orbits.visible_fraction, 1.0)
+        assert orbits.solar_phase_concentration < 0.05
+
+    def test_solar_phase_histogram(
+        self, spacecraft: ArrayEphemeris, earth_limb: Constraint
+    ) -> None:
+        orbits = orbit_visibility(spacecraft, IN_PLANE, earth_limb)
+        counts, edges = orbits.solar_phase_histogram(bins=8)
+        assert counts.sum() == sum(orbits.visible)
+        np.testing.assert_allclose(edges, np.linspace(0.0, 360.0, 9))
+        with pytest.raises(ValueError):
+            orbits.solar_phase_histogram(bins=0)
+
+    def test_to_dict(self, spacecraft: ArrayEphemeris, earth_limb: Constraint) -> None:
+        columns = orbit_visibility(spacecraft, IN_PLANE, earth_limb).to_dict()
+        assert list(columns) == [
+            "orbit_start",
+            "orbit_end",
+            "complete",
+            "visible_fraction",
+            "visible_seconds",
+            "mean_solar_phase",
+            "mean_argument_of_latitude",
+        ]
+        assert all(len(column) == 4 for column in columns.values())
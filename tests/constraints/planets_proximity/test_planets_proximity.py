@@ -0,0 +1,79 @@
+"""Tests for the planets proximity constraint."""
+
+import json
+
+import numpy as np
+import pytest
+from pydantic import ValidationError
+
+import rust_ephem
+from rust_ephem import PlanetsConstraint
+
+
+def test_target_at_mars_is_violated(
+    site: rust_ephem.GroundEphemeris, mars_radec: tuple[float, float]
+) -> None:
+    constraint = rust_ephem.Constraint.planets_proximity(5.0)
+    result = constraint.evaluate(site, *mars_radec)
+    assert not result.all_satisfied
+    assert "Mars" in result.violations[0].description
+
+
+def test_opposite_target_is_satisfied(
+    site: rust_ephem.GroundEphemeris, mars_radec: tuple[float, float]
+) -> None:
+    ra, dec = mars_radec
+    constraint = rust_ephem.Constraint.planets_proximity(1.0, planets=["Mars"])
+    result = constraint.evaluate(site, (ra + 180.0) % 360.0, -dec)
+    assert result.all_satisfied
+
+
+def test_per_planet_override(
+    site: rust_ephem.GroundEphemeris, mars_radec: tuple[float, float]
+) -> None:
+    ra, dec = mars_radec
+    offset_ra = (ra + 8.0 / np.cos(np.radians(dec))) % 360.0
+    shared = rust_ephem.Constraint.planets_proximity(5.0, planets=["Mars"])
+    wider = rust_ephem.Constraint.planets_proximity(
+        5.0, planets=["Mars"], min_angles={"Mars": 15.0}
+    )
+    assert shared.evaluate(site, offset_ra, dec).all_satisfied
+    assert not wider.evaluate(site, offset_ra, dec).all_satisfied
+
+
+def test_batch_matches_single(
+    site: rust_ephem.GroundEphemeris, mars_radec: tuple[float, float]
+) -> None:
+    ra, dec = mars_radec
+    constraint = rust_ephem.Constraint.planets_proximity(5.0)
+    ras = [ra, (ra + 180.0) % 360.0]
+    decs = [dec, -dec]
+    batch = constraint.in_constraint_batch(site, ras, decs)
+    assert batch.shape == (2, len(site.timestamp))
+    for i in range(2):
+        single = constraint.evaluate(site, ras[i], decs[i])
+        assert batch[i].all() == single.all_satisfied
+
+
+def test_unknown_override_rejected() -> None:
+    with pytest.raises(ValueError, match="not in planets"):
+        rust_ephem.Constraint.planets_proximity(5.0, min_angles={"Pluto": 3.0})
+    with pytest.raises(ValidationError):
+        PlanetsConstraint(min_angle=5.0, min_angles={"Pluto": 3.0})
+
+
+def test_invalid_angles_rejected() -> None:
+    with pytest.raises(ValueError):
+        rust_ephem.Constraint.planets_proximity(200.0)
+    with pytest.raises(ValidationError):
+        PlanetsConstraint(min_angle=5.0, min_angles={"Venus": -1.0})
+
+
+def test_pydantic_round_trip() -> None:
+    model = PlanetsConstraint(min_angle=5.0, min_angles={"Venus": 10.0})
+    assert model.planets == ["Venus", "Mars", "Jupiter", "Saturn"]
+    config = json.loads(
+        rust_ephem.Constraint.from_json(model.model_dump_json()).to_json()
+    )
+    assert config["type"] == "planets"
+    assert config["min_angles"] == {"Venus": 10.0}
@@ -0,0 +1,54 @@
+"""Tests for coarse pre-screening of constraint evaluation."""
+
+import pytest
+
+import rust_ephem
+from rust_ephem.constraints import EarthLimbConstraint, SunConstraint
+
+TARGETS = [(0.0, 0.0), (120.0, -30.0), (250.0, 60.0)]
+
+
+class TestPrescreen:
+    def test_matches_full_evaluation(self, tle_ephem: rust_ephem.TLEEphemeris) -> None:
+        # Earth limb windows last tens of minutes, well above the 5-step stride
+        for constraint in (
+            EarthLimbConstraint(min_angle=10.0),
+            SunConstraint(min_angle=45.0),
+        ):
+            for ra, dec in TARGETS:
+                full = constraint.evaluate(tle_ephem, ra, dec)
+                coarse = constraint.evaluate(tle_ephem, ra, dec, prescreen=5)
+                assert list(coarse.constraint_array) == list(full.constraint_array)
+                assert len(coarse.violations) == len(full.violations)
+
+    def test_batch_matches_full_evaluation(
+        self, tle_ephem: rust_ephem.TLEEphemeris
+    ) -> None:
+        constraint = EarthLimbConstraint(min_angle=10.0)
+        ras = [ra for ra, _ in TARGETS]
+        decs = [dec for _, dec in TARGETS]
+        full = constraint.evaluate_batch(tle_ephem, ras, decs)
+        coarse = constraint.evaluate_batch(tle_ephem, ras, decs, prescreen=5)
+        for a, b in zip(full, coarse):
+            assert list(a.constraint_array) == list(b.constraint_array)
+
+    def test_window_edges_are_exact(self, tle_ephem: rust_ephem.TLEEphemeris) -> None:
+        constraint = EarthLimbConstraint(min_angle=10.0)
+        full = constraint.evaluate(tle_ephem, 0.0, 0.0)
+        coarse = constraint.evaluate(tle_ephem, 0.0, 0.0, prescreen=7)
+        assert full.violations
+        assert [(v.start_time, v.end_time) for v in coarse.violations] == [
+            (v.start_time, v.end_time) for v in full.violations
+        ]
+
+    def test_stride_beyond_grid(self, tle_ephem: rust_ephem.TLEEphemeris) -> None:
+        # Only the first and last steps are sampled; the result keeps every step
+        result = SunConstraint(min_angle=45.0).evaluate(
+            tle_ephem, 0.0, 0.0, prescreen=10_000
+        )
+        assert len(result.constraint_array) == 361
+
+    def test_zero_rejected(self, tle_ephem: rust_ephem.TLEEphemeris) -> None:
+        constraint = rust_ephem.Constraint.sun_proximity(45.0)
+        with pytest.raises(ValueError, match="prescreen"):
+            constraint.evaluate(tle_ephem, 0.0, 0.0, prescreen=0)
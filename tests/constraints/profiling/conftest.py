@@ -0,0 +1,27 @@
+"""Fixtures for constraint profiling tests."""
+
+from collections.abc import Iterator
+from datetime import datetime, timezone
+
+import pytest
+
+import rust_ephem
+
+TLE1 = "1 28485U 04047A   25317.24527149  .00068512  00000+0  12522-2 0  9999"
+TLE2 = "2 28485  20.5556  25.5469 0004740 206.7882 153.2316 15.47667717153136"
+BEGIN = datetime(2025, 9, 23, 0, 0, 0, tzinfo=timezone.utc)
+END = datetime(2025, 9, 23, 2, 0, 0, tzinfo=timezone.utc)
+
+
+@pytest.fixture(autouse=True)
+def clean_profiling() -> Iterator[None]:
+    """Start each test with no timings; profiling is process-wide"""
+    rust_ephem.enable_profiling(False)
+    rust_ephem.reset_profiling()
+    yield
+    rust_ephem.enable_profiling(False)
+    rust_ephem.reset_profiling()
+
+
+def make_ephem() -> rust_ephem.TLEEphemeris:
+    return rust_ephem.TLEEphemeris(TLE1, TLE2, BEGIN, END, 60)
@@ -0,0 +1,81 @@
+"""Tests for opt-in profiling of constraint evaluation."""
+
+import pytest
+
+import rust_ephem
+
+from .conftest import make_ephem
+
+RAS = [0.0, 120.0, 250.0]
+DECS = [0.0, -30.0, 60.0]
+
+
+def entries(report: dict, category: str) -> list[dict]:
+    return [e for e in report["entries"] if e["category"] == category]
+
+
+class TestProfiling:
+    def test_disabled_records_nothing(self) -> None:
+        ephem = make_ephem()
+        rust_ephem.Constraint.sun_proximity(45.0).in_constraint_batch(
+            ephem, RAS, DECS
+        )
+        report = rust_ephem.profiling_report()
+        assert report["enabled"] is False
+        assert report["entries"] == []
+        assert report["totals"] == {"evaluator": 0.0, "extraction": 0.0, "frame": 0.0}
+
+    def test_records_all_categories(self) -> None:
+        rust_ephem.enable_profiling()
+        ephem = make_ephem()
+        sun = rust_ephem.Constraint.sun_proximity(45.0)
+        limb = rust_ephem.Constraint.earth_limb(10.0)
+        rust_ephem.Constraint.and_(sun, limb).in_constraint_batch(ephem, RAS, DECS)
+        report = rust_ephem.profiling_report()
+
+        assert report["enabled"] is True
+        # The combination and each of its sub-constraints
+        assert len(entries(report, "evaluator")) == 3
+        assert "sun_positions" in [e["name"] for e in entries(report, "extraction")]
+        assert any(e["name"].startswith("TEME->") for e in entries(report, "frame"))
+        for entry in report["entries"]:
+            assert entry["count"] >= 1
+            assert entry["max_seconds"] <= entry["total_seconds"]
+            mean_total = entry["mean_seconds"] * entry["count"]
+            assert mean_total == pytest.approx(entry["total_seconds"])
+
+    def test_counts_calls(self) -> None:
+        ephem = make_ephem()
+        constraint = rust_ephem.Constraint.sun_proximity(45.0)
+        rust_ephem.enable_profiling()
+        for _ in range(3):
+            constraint.in_constraint_batch(ephem, RAS, DECS)
+        (entry,) = entries(rust_ephem.profiling_report(), "evaluator")
+        assert entry["count"] == 3
+
+    def test_sorted_by_total_time(self) -> None:
+        rust_ephem.enable_profiling()
+        ephem = make_ephem()
+        rust_ephem.Constraint.sun_proximity(45.0).in_constraint_batch(
+            ephem, RAS, DECS
+        )
+        report = rust_ephem.profiling_report()
+        for category in ("evaluator", "extraction", "frame"):
+            totals = [e["total_seconds"] for e in entries(report, category)]
+            assert totals == sorted(totals, reverse=True)
+            assert report["totals"][category] == pytest.approx(sum(totals))
+
+    def test_reset(self) -> None:
+        ephem = make_ephem()
+        constraint = rust_ephem.Constraint.sun_proximity(45.0)
+        rust_ephem.enable_profiling()
+        constraint.in_constraint_batch(ephem, RAS, DECS)
+        rust_ephem.enable_profiling(False)
+
+        assert rust_ephem.profiling_report(reset=True)["entries"]
+        assert rust_ephem.profiling_report()["entries"] == []
+
+        rust_ephem.enable_profiling()
+        constraint.in_constraint_batch(ephem, RAS, DECS)
+        rust_ephem.reset_profiling()
+        assert rust_ephem.profiling_report()["entries"] == []
@@ -0,0 +1,39 @@
+"""Fixtures for progress reporting of batch constraint evaluations."""
+
+from datetime import datetime, timedelta, timezone
+
+import numpy as np
+import pytest
+
+from rust_ephem import ArrayEphemeris, Constraint, EarthLimbConstraint
+
+BEGIN = datetime(2024, 1, 1, 0, 0, 0, tzinfo=timezone.utc)
+N_POINTS = 6
+# Three blocks of 256, 256 and 88 targets
+N_TARGETS = 600
+
+
+@pytest.fixture
+def spacecraft() -> ArrayEphemeris:
+    times = [BEGIN + timedelta(minutes=i) for i in range(N_POINTS)]
+    states = np.zeros((N_POINTS, 6))
+    states[:, 0] = 7000.0
+    states[2:4, 0] = -7000.0
+    return ArrayEphemeris(times, states)
+
+
+@pytest.fixture
+def targets() -> tuple[list[float], list[float]]:
+    ras = np.linspace(0.0, 359.0, N_TARGETS).tolist()
+    decs = np.linspace(-80.0, 80.0, N_TARGETS).tolist()
+    return ras, decs
+
+
+@pytest.fixture
+def earth_limb() -> Constraint:
+    return Constraint.earth_limb(min_angle=10.0)
+
+
+@pytest.fixture
+def earth_limb_model() -> EarthLimbConstraint:
+    return EarthLimbConstraint(min_angle=10.0)
@@ -0,0 +1,139 @@
+"""Tests for progress callbacks of batch constraint evaluations."""
+
+import numpy as np
+import pytest
+
+from rust_ephem import ArrayEphemeris, Constraint, EarthLimbConstraint
+
+from .conftest import N_TARGETS
+
+
+class Recorder:
+    def __init__(self, cancel_after: int | None = None) -> None:
+        self.calls: list[tuple[int, int]] = []
+        self.cancel_after = cancel_after
+
+    def __call__(self, done: int, total: int) -> bool:
+        self.calls.append((done, total))
+        return self.cancel_after is None or len(self.calls) < self.cancel_after
+
+
+class TestInConstraintBatch:
+    def test_reports_each_block(
+        self,
+        spacecraft: ArrayEphemeris,
+        earth_limb: Constraint,
+        targets: tuple[list[float], list[float]],
+    ) -> None:
+        ras, decs = targets
+        recorder = Recorder()
+        mask = earth_limb.in_constraint_batch(spacecraft, ras, decs, progress=recorder)
+        assert recorder.calls == [(256, 600), (512, 600), (600, 600)]
+        np.testing.assert_array_equal(
+            mask, earth_limb.in_constraint_batch(spacecraft, ras, decs)
+        )
+
+    def test_progress_every(
+        self,
+        spacecraft: ArrayEphemeris,
+        earth_limb: Constraint,
+        targets: tuple[list[float], list[float]],
+    ) -> None:
+        ras, decs = targets
+        recorder = Recorder()
+        earth_limb.in_constraint_batch(
+            spacecraft, ras, decs, progress=recorder, progress_every=2
+        )
+        # The last block is always reported
+        assert recorder.calls == [(512, 600), (600, 600)]
+
+    def test_cancel(
+        self,
+        spacecraft: ArrayEphemeris,
+        earth_limb: Constraint,
+        targets: tuple[list[float], list[float]],
+    ) -> None:
+        ras, decs = targets
+        recorder = Recorder(cancel_after=1)
+        with pytest.raises(InterruptedError):
+            earth_limb.in_constraint_batch(spacecraft, ras, decs, progress=recorder)
+        assert recorder.calls == [(256, 600)]
+
+    def test_callback_exception_propagates(
+        self,
+        spacecraft: ArrayEphemeris,
+        earth_limb: Constraint,
+        targets: tuple[list[float], list[float]],
+    ) -> None:
+        ras, decs = targets
+
+        def fail(done: int, total: int) -> None:
+            raise RuntimeError("stop")
+
+        with pytest.raises(RuntimeError, match="stop"):
+            earth_limb.in_constraint_batch(spacecraft, ras, decs, progress=fail)
+
+    def test_invalid_arguments(
+        self,
+        spacecraft: ArrayEphemeris,
+        earth_limb: Constraint,
+        targets: tuple[list[float], list[float]],
+    ) -> None:
+        ras, decs = targets
+        with pytest.raises(TypeError, match="callable"):
+            earth_limb.in_constraint_batch(spacecraft, ras, decs, progress=42)
+        with pytest.raises(ValueError, match="progress_every"):
+            earth_limb.in_constraint_batch(
+                spacecraft, ras, decs, progress=Recorder(), progress_every=0
+            )
+
+
+class TestEvaluateBatch:
+    def test_reports_targets(
+        self,
+        spacecraft: ArrayEphemeris,
+        earth_limb: Constraint,
+        targets: tuple[list[float], list[float]],
+    ) -> None:
+        ras, decs = targets
+        recorder = Recorder()
+        results = earth_limb.evaluate_batch(spacecraft, ras, decs, progress=recorder)
+        assert len(results) == N_TARGETS
+        assert recorder.calls[-1] == (600, 600)
+        plain = earth_limb.evaluate_batch(spacecraft, ras, decs)
+        assert [r.constraint_array for r in results] == [
+            r.constraint_array for r in plain
+        ]
+
+    def test_roll_groups_share_one_count(
+        self,
+        spacecraft: ArrayEphemeris,
+        earth_limb: Constraint,
+        targets: tuple[list[float], list[float]],
+    ) -> None:
+        ras, decs = targets
+        rolls = [0.0 if i % 2 else 90.0 for i in range(N_TARGETS)]
+        recorder = Recorder()
+        earth_limb.evaluate_batch(
+            spacecraft, ras, decs, target_rolls=rolls, progress=recorder
+        )
+        done = [d for d, _ in recorder.calls]
+        assert done == sorted(done)
+        assert recorder.calls[-1] == (600, 600)
+
+    def test_pydantic_model(
+        self,
+        spacecraft: ArrayEphemeris,
+        earth_limb_model: EarthLimbConstraint,
+        targets: tuple[list[float], list[float]],
+    ) -> None:
+        ras, decs = targets
+        rolls = [0.0 if i % 2 else 90.0 for i in range(N_TARGETS)]
+        recorder = Recorder()
+        earth_limb_model.evaluate_batch(
+            spacecraft, ras, decs, target_rolls=rolls, progress=recorder
+        )
+        done = [d for d, _ in recorder.calls]
+        assert done == sorted(done)
+        assert all(total == N_TARGETS for _, total in recorder.calls)
+        assert recorder.calls[-1] == (600, 600)
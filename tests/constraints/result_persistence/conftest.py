@@ -0,0 +1,34 @@
+"""Fixtures for saving and loading constraint results."""
+
+from datetime import datetime, timedelta, timezone
+
+import numpy as np
+import pytest
+
+from rust_ephem import ArrayEphemeris, Constraint, EarthLimbConstraint
+
+BEGIN = datetime(2024, 1, 1, 0, 0, 0, tzinfo=timezone.utc)
+N_POINTS = 8
+STEP = timedelta(minutes=1)
+# The spacecraft is on the +x side except for samples 3 and 4, blocking a
+# target along +x
+BLOCKED = [3, 4]
+
+
+@pytest.fixture
+def spacecraft() -> ArrayEphemeris:
+    times = [BEGIN + STEP * i for i in range(N_POINTS)]
+    states = np.zeros((N_POINTS, 6))
+    states[:, 0] = 7000.0
+    states[BLOCKED, 0] = -7000.0
+    return ArrayEphemeris(times, states)
+
+
+@pytest.fixture
+def earth_limb() -> Constraint:
+    return Constraint.earth_limb(min_angle=10.0)
+
+
+@pytest.fixture
+def earth_limb_model() -> EarthLimbConstraint:
+    return EarthLimbConstraint(min_angle=10.0)
@@ -0,0 +1,156 @@
+"""Tests for ConstraintResult.save and ConstraintResult.load."""
+
+import json
+from pathlib import Path
+
+import pytest
+
+from rust_ephem import (
+    ArrayEphemeris,
+    Constraint,
+    ConstraintResult,
+    EarthLimbConstraint,
+)
+
+from .conftest import BEGIN, N_POINTS, STEP
+
+
+def window_bounds(windows: list) -> list:
+    return [(w.start_time, w.end_time) for w in windows]
+
+
+def violation_fields(violations: list) -> list:
+    return [
+        (v.start_time, v.end_time, v.max_severity, v.description) for v in violations
+    ]
+
+
+class TestRustResult:
+    @pytest.mark.parametrize("storage", ["full", "compressed", "windows"])
+    def test_round_trip(
+        self,
+        tmp_path: Path,
+        spacecraft: ArrayEphemeris,
+        earth_limb: Constraint,
+        storage: str,
+    ) -> None:
+        result = earth_limb.evaluate(spacecraft, 0.0, 0.0, storage=storage)
+        path = tmp_path / "result.json"
+        result.save(str(path))
+        loaded = type(result).load(str(path))
+
+        assert loaded.storage == storage
+        assert loaded.n_times == N_POINTS
+        assert loaded.constraint_name == result.constraint_name
+        assert loaded.all_satisfied == result.all_satisfied
+        assert violation_fields(loaded.violations) == violation_fields(
+            result.violations
+        )
+        assert window_bounds(loaded.visibility_windows) == window_bounds(
+            result.visibility_windows
+        )
+        assert loaded.time_grid == result.time_grid
+
+    def test_loaded_result_answers_lookups(
+        self, tmp_path: Path, spacecraft: ArrayEphemeris, earth_limb: Constraint
+    ) -> None:
+        result = earth_limb.evaluate(spacecraft, 0.0, 0.0)
+        path = tmp_path / "result.json"
+        result.save(str(path))
+        loaded = type(result).load(str(path))
+
+        assert list(loaded.timestamp) == list(result.timestamp)
+        assert loaded.constraint_array == result.constraint_array
+        assert loaded.in_constraint(BEGIN + 3 * STEP)
+        assert not loaded.in_constraint(BEGIN)
+
+    def test_records_constraint(
+        self, tmp_path: Path, spacecraft: ArrayEphemeris, earth_limb: Constraint
+    ) -> None:
+        result = earth_limb.evaluate(spacecraft, 0.0, 0.0)
+        path = tmp_path / "result.json"
+        result.save(str(path))
+        loaded = type(result).load(str(path))
+
+        spec = loaded.constraint_spec
+        assert spec is not None
+        assert json.loads(spec) == json.loads(earth_limb.to_json())
+        rebuilt = Constraint.from_json(spec)
+        again = rebuilt.evaluate(spacecraft, 0.0, 0.0)
+        assert again.constraint_array == result.constraint_array
+
+    def test_batch_results_record_constraint(
+        self, spacecraft: ArrayEphemeris, earth_limb: Constraint
+    ) -> None:
+        results = earth_limb.evaluate_batch(spacecraft, [0.0, 180.0], [0.0, 0.0])
+        for result in results:
+            assert result.constraint_spec is not None
+
+    def test_file_is_json(
+        self, tmp_path: Path, spacecraft: ArrayEphemeris, earth_limb: Constraint
+    ) -> None:
+        result = earth_limb.evaluate(spacecraft, 0.0, 0.0, storage="compressed")
+        path = tmp_path / "result.json"
+        result.save(str(path))
+        saved = json.loads(path.read_text())
+
+        assert saved["format"] == "rust_ephem.ConstraintResult"
+        assert saved["times"]["kind"] == "uniform"
+        assert saved["times"]["count"] == N_POINTS
+        assert len(saved["violations"]) == 1
+
+    def test_not_a_result(self, tmp_path: Path, earth_limb: Constraint) -> None:
+        path = tmp_path / "other.json"
+        path.write_text(json.dumps({"type": "earth_limb"}))
+        with pytest.raises(ValueError, match="Invalid constraint result file"):
+            ConstraintResult.load(path)
+
+    def test_missing_file(self, tmp_path: Path) -> None:
+        with pytest.raises(OSError):
+            ConstraintResult.load(tmp_path / "missing.json")
+
+
+class TestPydanticResult:
+    def test_round_trip(
+        self,
+        tmp_path: Path,
+        spacecraft: ArrayEphemeris,
+        earth_limb_model: EarthLimbConstraint,
+    ) -> None:
+        result = earth_limb_model.evaluate(spacecraft, 0.0, 0.0)
+        path = tmp_path / "result.json"
+        result.save(path)
+        loaded = ConstraintResult.load(path)
+
+        assert isinstance(loaded, ConstraintResult)
+        assert loaded.violations == result.violations
+        assert loaded.all_satisfied == result.all_satisfied
+        assert loaded.constraint_array == result.constraint_array
+        assert window_bounds(loaded.visibility_windows) == window_bounds(
+            result.visibility_windows
+        )
+        assert loaded.constraint_spec == result.constraint_spec
+
+    def test_constraint_spec_rebuilds_model(
+        self,
+        tmp_path: Path,
+        spacecraft: ArrayEphemeris,
+        earth_limb_model: EarthLimbConstraint,
+    ) -> None:
+        result = earth_limb_model.evaluate(spacecraft, 0.0, 0.0)
+        path = tmp_path / "result.json"
+        result.save(path)
+        spec = ConstraintResult.load(path).constraint_spec
+        assert spec is not None
+        assert EarthLimbConstraint.model_validate_json(spec) == earth_limb_model
+
+    def test_swept_result_cannot_be_saved(self, tmp_path: Path) -> None:
+        swept = ConstraintResult(
+            violations=[],
+            all_satisfied=True,
+            constraint_name="swept",
+            _swept_timestamps=[BEGIN],
+            _swept_array=[False],
+        )
+        with pytest.raises(ValueError, match="target_roll"):
+            swept.save(tmp_path / "result.json")
@@ -0,0 +1,124 @@
+"""Tests for compressed and windows-only constraint result storage."""
+
+from datetime import timedelta
+
+import numpy as np
+import pytest
+
+from rust_ephem import ArrayEphemeris, Constraint, EarthLimbConstraint
+
+from .conftest import BEGIN, N_POINTS, STEP
+
+
+def window_bounds(windows: list) -> list:
+    return [(w.start_time, w.end_time) for w in windows]
+
+
+class TestCompressedStorage:
+    def test_matches_full(
+        self, spacecraft: ArrayEphemeris, earth_limb: Constraint
+    ) -> None:
+        full = earth_limb.evaluate(spacecraft, 0.0, 0.0)
+        compressed = earth_limb.evaluate(spacecraft, 0.0, 0.0, storage="compressed")
+        assert compressed.storage == "compressed"
+        assert compressed.n_times == N_POINTS
+        assert list(compressed.timestamp) == list(full.timestamp)
+        assert compressed.constraint_array == full.constraint_array
+        assert window_bounds(compressed.visibility) == window_bounds(full.visibility)
+
+    def test_time_grid(
+        self, spacecraft: ArrayEphemeris, earth_limb: Constraint
+    ) -> None:
+        result = earth_limb.evaluate(spacecraft, 0.0, 0.0, storage="compressed")
+        assert result.time_grid == (BEGIN, 60.0, N_POINTS)
+        assert earth_limb.evaluate(spacecraft, 0.0, 0.0).time_grid is None
+
+    def test_lookup_off_grid(
+        self, spacecraft: ArrayEphemeris, earth_limb: Constraint
+    ) -> None:
+        full = earth_limb.evaluate(spacecraft, 0.0, 0.0)
+        compressed = earth_limb.evaluate(spacecraft, 0.0, 0.0, storage="compressed")
+        for seconds in (0, 150, 170, 270, 420):
+            time = BEGIN + timedelta(seconds=seconds)
+            for mode in ("nearest", "previous"):
+                assert compressed.in_constraint(time, mode) == full.in_constraint(
+                    time, mode
+                )
+        with pytest.raises(ValueError, match="not found"):
+            compressed.in_constraint(BEGIN + timedelta(seconds=30))
+        with pytest.raises(ValueError, match="outside"):
+            compressed.in_constraint(BEGIN - STEP, "nearest")
+
+    def test_uneven_grid_kept_in_full(
+        self, uneven_spacecraft: ArrayEphemeris, earth_limb: Constraint
+    ) -> None:
+        result = earth_limb.evaluate(uneven_spacecraft, 0.0, 0.0, storage="compressed")
+        assert result.time_grid is None
+        assert len(result.timestamp) == N_POINTS
+
+
+class TestWindowsStorage:
+    def test_keeps_windows(
+        self, spacecraft: ArrayEphemeris, earth_limb: Constraint
+    ) -> None:
+        full = earth_limb.evaluate(spacecraft, 0.0, 0.0)
+        windows = earth_limb.evaluate(spacecraft, 0.0, 0.0, storage="windows")
+        assert windows.storage == "windows"
+        assert windows.n_times == N_POINTS
+        assert len(windows.violations) == 1
+        assert windows.total_violation_duration() == full.total_violation_duration()
+        assert window_bounds(windows.visibility_windows) == window_bounds(
+            full.visibility_windows
+        )
+        assert window_bounds(windows.visibility) == window_bounds(
+            full.visibility_windows
+        )
+
+    def test_drops_per_time_arrays(
+        self, spacecraft: ArrayEphemeris, earth_limb: Constraint
+    ) -> None:
+        result = earth_limb.evaluate(spacecraft, 0.0, 0.0, storage="windows")
+        with pytest.raises(ValueError, match="storage='windows'"):
+            result.timestamp
+        with pytest.raises(ValueError, match="storage='windows'"):
+            result.constraint_array
+        with pytest.raises(ValueError, match="storage='windows'"):
+            result.in_constraint(BEGIN)
+
+    def test_batch(self, spacecraft: ArrayEphemeris, earth_limb: Constraint) -> None:
+        results = earth_limb.evaluate_batch(
+            spacecraft, [0.0, 0.0], [0.0, 90.0], storage="windows"
+        )
+        assert [r.storage for r in results] == ["windows", "windows"]
+        assert not results[0].all_satisfied
+        assert results[1].all_satisfied
+        assert len(results[1].visibility_windows) == 1
+
+    def test_many(self, spacecraft: ArrayEphemeris, earth_limb: Constraint) -> None:
+        results = earth_limb.evaluate_many([spacecraft], 0.0, 0.0, storage="windows")
+        assert results[0].storage == "windows"
+        assert results[0].n_times == N_POINTS
+
+
+class TestStorageOption:
+    def test_invalid_storage(
+        self, spacecraft: ArrayEphemeris, earth_limb: Constraint
+    ) -> None:
+        with pytest.raises(ValueError, match="storage must be"):
+            earth_limb.evaluate(spacecraft, 0.0, 0.0, storage="sparse")
+
+    def test_default_is_full(
+        self, spacecraft: ArrayEphemeris, earth_limb: Constraint
+    ) -> None:
+        result = earth_limb.evaluate(spacecraft, 0.0, 0.0)
+        assert result.storage == "full"
+        assert np.asarray(result.constraint_array).sum() == 2
+
+    def test_pydantic_model(self, spacecraft: ArrayEphemeris) -> None:
+        constraint = EarthLimbConstraint(min_angle=10.0)
+        result = constraint.evaluate(spacecraft, 0.0, 0.0, storage="windows")
+        assert result.storage == "windows"
+        assert result.n_times == N_POINTS
+        assert len(result.visibility_windows) == 2
+        with pytest.raises(ValueError):
+            result.constraint_array
@@ -1,7 +1,16 @@
 """Fixtures for saa_constraint tests."""
 
+import math
+from datetime import datetime, timedelta, timezone
+
+import numpy as np
 import pytest
 
+from rust_ephem import ArrayEphemeris
+
+# Equatorial ground track crossing the antimeridian
+TRACK_LONGITUDES = [165.0, 172.0, 178.0, -178.0, -172.0, -165.0]
+
 
 @pytest.fixture
 def saa_polygon() -> list[tuple[float, float]]:
@@ -12,3 +21,16 @@ def saa_polygon() -> list[tuple[float, float]]:
         (-40.0, 0.0),  # Northeast
         (-90.0, 0.0),  # Northwest
     ]
+
+
+@pytest.fixture
+def antimeridian_track() -> ArrayEphemeris:
+    """ITRS positions along the equator across longitude 180"""
+    begin = datetime(2025, 1, 1, 12, 0, 0, tzinfo=timezone.utc)
+    times = [begin + timedelta(minutes=i) for i in range(len(TRACK_LONGITUDES))]
+    states = np.zeros((len(TRACK_LONGITUDES), 6))
+    for i, lon_deg in enumerate(TRACK_LONGITUDES):
+        lon = math.radians(lon_deg)
+        states[i, 0] = 6900.0 * math.cos(lon)
+        states[i, 1] = 6900.0 * math.sin(lon)
+    return ArrayEphemeris(times, states, frame="ITRS")
@@ -8,11 +8,15 @@ import numpy as np
 import pytest
 
 import rust_ephem
+from rust_ephem import sphere
 from rust_ephem.constraints import SAAConstraint
 
 if TYPE_CHECKING:
     import rust_ephem
 
+# Region straddling the antimeridian, between longitudes 175 and -175
+ANTIMERIDIAN_POLYGON = [(175.0, -5.0), (-175.0, -5.0), (-175.0, 5.0), (175.0, 5.0)]
+
 
 class TestSAAConstraint:
     """Test SAAConstraint functionality."""
@@ -101,6 +105,48 @@ class TestSAAConstraint:
                 f"target (RA={target_ras[i]}, Dec={target_decs[i]})",
             )
 
+    def test_saa_mask_matches_subsatellite_point(
+        self,
+        tle_ephemeris: "rust_ephem.TLEEphemeris",
+        saa_polygon: list[tuple[float, float]],
+    ) -> None:
+        """Test the SAA mask against the spherical polygon on the ephemeris lat/lon."""
+        constraint = SAAConstraint(polygon=saa_polygon)
+        mask = constraint.in_constraint_batch(tle_ephemeris, [0.0], [0.0])[0]
+
+        lat = np.asarray(tle_ephemeris.latitude_deg)
+        lon = np.asarray(tle_ephemeris.longitude_deg)
+        inside = sphere.point_in_polygon(saa_polygon, lon, lat)
+        np.testing.assert_array_equal(mask, inside)
+
+    def test_saa_edges_are_great_circles(
+        self, saa_polygon: list[tuple[float, float]]
+    ) -> None:
+        """Test the southern edge bulges poleward of latitude -50."""
+        assert sphere.point_in_polygon(saa_polygon, -65.0, -51.0)
+        assert not sphere.point_in_polygon(saa_polygon, -65.0, -55.0)
+
+    def test_saa_region_across_antimeridian(
+        self, antimeridian_track: "rust_ephem.ArrayEphemeris"
+    ) -> None:
+        """Test a region straddling longitude 180 catches the crossing."""
+        constraint = SAAConstraint(polygon=ANTIMERIDIAN_POLYGON)
+        result = constraint.evaluate(antimeridian_track, 0.0, 0.0)
+        assert list(result.constraint_array) == [
+            False,
+            False,
+            True,
+            True,
+            False,
+            False,
+        ]
+        assert len(result.violations) == 1
+
+    def test_saa_polygon_beyond_hemisphere_rejected(self) -> None:
+        """Test a polygon that does not fit within a hemisphere is rejected."""
+        with pytest.raises(ValueError, match="hemisphere"):
+            rust_ephem.Constraint.saa([(0.0, 0.0), (120.0, 0.0), (240.0, 0.0)])
+
     def test_saa_point_in_polygon_logic_polygon_length(
         self, saa_polygon: list[tuple[float, float]]
     ) -> None:
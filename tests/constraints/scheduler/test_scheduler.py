@@ -0,0 +1,121 @@
+"""Tests for the greedy observation scheduler"""
+
+from datetime import timedelta
+from typing import Any
+
+import pytest
+
+from rust_ephem import ArrayEphemeris, Constraint, Schedule, schedule
+
+from .conftest import BEGIN, LAST_PLUS_X, MINUS_X, PLUS_X, PLUS_Y, STEP
+
+
+def test_single_target(spacecraft: ArrayEphemeris, earth_limb: Constraint) -> None:
+    plan = schedule(spacecraft, [PLUS_X], earth_limb, 300.0)
+    assert isinstance(plan, Schedule)
+    assert len(plan) == 1
+    obs = plan.observations[0]
+    assert obs.target_index == 0
+    assert obs.start_time == BEGIN
+    assert obs.end_time == BEGIN + timedelta(seconds=300)
+    assert obs.exposure_seconds == 300.0
+    assert obs.slew_seconds == 0.0
+    assert plan.unscheduled == []
+
+
+def test_waits_for_visibility(
+    spacecraft: ArrayEphemeris, earth_limb: Constraint
+) -> None:
+    plan = schedule(spacecraft, [MINUS_X], earth_limb, 300.0)
+    assert plan.observations[0].start_time == BEGIN + timedelta(
+        seconds=STEP * (LAST_PLUS_X + 1)
+    )
+
+
+def test_slew_between_observations(
+    spacecraft: ArrayEphemeris, earth_limb: Constraint
+) -> None:
+    plan = schedule(spacecraft, [PLUS_X, PLUS_Y], earth_limb, 300.0, slew_rate=1.0)
+    first, second = plan.observations
+    assert first.target_index == 0
+    assert second.target_index == 1
+    # 90 degrees at 1 deg/s
+    assert second.slew_seconds == pytest.approx(90.0)
+    assert second.start_time == BEGIN + timedelta(seconds=390)
+    assert plan.total_exposure_seconds == 600.0
+    assert plan.total_slew_seconds == pytest.approx(90.0)
+
+
+def test_settle_time(spacecraft: ArrayEphemeris, earth_limb: Constraint) -> None:
+    plan = schedule(
+        spacecraft, [PLUS_X, PLUS_Y], earth_limb, 300.0, settle_time=30.0
+    )
+    assert plan.observations[1].start_time == BEGIN + timedelta(seconds=420)
+
+
+def test_priority_order(spacecraft: ArrayEphemeris, earth_limb: Constraint) -> None:
+    # +y goes first and leaves no room to slew back for +x before it sets
+    plan = schedule(
+        spacecraft, [PLUS_X, PLUS_Y], earth_limb, 300.0, priorities=[1.0, 2.0]
+    )
+    assert [o.target_index for o in plan.observations] == [1]
+    assert plan.unscheduled == [0]
+
+
+def test_observations_are_time_ordered(
+    spacecraft: ArrayEphemeris, earth_limb: Constraint
+) -> None:
+    # -x is placed first but ends up after +x on the timeline
+    plan = schedule(
+        spacecraft,
+        [PLUS_X, MINUS_X],
+        earth_limb,
+        [120.0, 300.0],
+        priorities=[0.0, 5.0],
+    )
+    assert [o.target_index for o in plan.observations] == [0, 1]
+    starts = [o.start_time for o in plan.observations]
+    assert starts == sorted(starts)
+    assert plan.observations[1].slew_seconds == pytest.approx(180.0)
+
+
+def test_exposure_longer_than_window(
+    spacecraft: ArrayEphemeris, earth_limb: Constraint
+) -> None:
+    plan = schedule(spacecraft, [PLUS_X], earth_limb, 700.0)
+    assert len(plan) == 0
+    assert plan.unscheduled == [0]
+
+
+def test_per_target_constraints(
+    spacecraft: ArrayEphemeris, earth_limb: Constraint
+) -> None:
+    strict = Constraint.earth_limb(min_angle=40.0)
+    plan = schedule(spacecraft, [PLUS_X, PLUS_Y], [earth_limb, strict], 300.0)
+    # +y is only about 24 degrees above the limb
+    assert plan.unscheduled == [1]
+
+
+@pytest.mark.parametrize(
+    "kwargs",
+    [
+        {"exposure_times": 0.0},
+        {"exposure_times": [300.0]},
+        {"priorities": [1.0]},
+        {"slew_rate": 0.0},
+        {"settle_time": -1.0},
+    ],
+)
+def test_invalid_arguments(
+    spacecraft: ArrayEphemeris, earth_limb: Constraint, kwargs: dict[str, Any]
+) -> None:
+    args = {"exposure_times": 300.0, **kwargs}
+    with pytest.raises(ValueError):
+        schedule(spacecraft, [PLUS_X, PLUS_Y], earth_limb, **args)
+
+
+def test_constraint_count_mismatch(
+    spacecraft: ArrayEphemeris, earth_limb: Constraint
+) -> None:
+    with pytest.raises(ValueError, match="one Constraint per target"):
+        schedule(spacecraft, [PLUS_X, PLUS_Y], [earth_limb], 300.0)
@@ -0,0 +1,52 @@
+"""Tests for penumbra/umbra transition typing of the eclipse constraint."""
+
+import pytest
+
+from rust_ephem import Constraint, TLEEphemeris
+from rust_ephem.constraints import EclipseConstraint
+
+CYCLE = ["penumbra_entry", "umbra_entry", "umbra_exit", "penumbra_exit"]
+
+
+def test_events_cycle_through_profile(spacecraft: TLEEphemeris) -> None:
+    events = Constraint.eclipse(umbra_only=False).shadow_events(spacecraft)
+    kinds = [kind for _, kind in events]
+    # Start from the first penumbra entry in case the run begins in shadow
+    kinds = kinds[kinds.index("penumbra_entry") :]
+    assert len(kinds) >= 8
+    for i, kind in enumerate(kinds):
+        assert kind == CYCLE[i % 4]
+    times = [time for time, _ in events]
+    assert times == sorted(times)
+
+
+def test_penumbra_lasts_seconds(spacecraft: TLEEphemeris) -> None:
+    events = Constraint.eclipse().shadow_events(spacecraft)
+    start = [kind for _, kind in events].index("penumbra_entry")
+    (entry, _), (umbra, kind) = events[start], events[start + 1]
+    assert kind == "umbra_entry"
+    # A LEO penumbra crossing takes several seconds, well under a minute
+    assert 2.0 < (umbra - entry).total_seconds() < 60.0
+
+
+def test_windows_list_shadow_phases(spacecraft: TLEEphemeris) -> None:
+    penumbra = Constraint.eclipse(umbra_only=False).evaluate(spacecraft, 0.0, 0.0)
+    # The first and last windows may be cut off by the ends of the run
+    interior = penumbra.violations[1:-1]
+    assert interior
+    for violation in interior:
+        assert violation.description == "Observer in shadow (penumbra, umbra, penumbra)"
+
+    umbra = Constraint.eclipse(umbra_only=True).evaluate(spacecraft, 0.0, 0.0)
+    assert all(v.description == "Observer in umbra" for v in umbra.violations)
+
+
+def test_pydantic_model_matches_rust(spacecraft: TLEEphemeris) -> None:
+    model = EclipseConstraint(umbra_only=False).shadow_events(spacecraft)
+    rust = Constraint.eclipse(umbra_only=False).shadow_events(spacecraft)
+    assert model == rust
+
+
+def test_requires_eclipse_constraint(spacecraft: TLEEphemeris) -> None:
+    with pytest.raises(TypeError, match="eclipse constraint"):
+        Constraint.sun_proximity(45.0).shadow_events(spacecraft)
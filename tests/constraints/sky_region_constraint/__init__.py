@@ -0,0 +1 @@
+"""Sky region constraint tests module."""
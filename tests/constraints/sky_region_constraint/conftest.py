@@ -0,0 +1,9 @@
+"""Fixtures for sky region constraint tests."""
+
+import pytest
+
+
+@pytest.fixture
+def region_across_ra_zero() -> list[tuple[float, float]]:
+    """Ten-degree square straddling RA 0 on the celestial equator."""
+    return [(355.0, -5.0), (5.0, -5.0), (5.0, 5.0), (355.0, 5.0)]
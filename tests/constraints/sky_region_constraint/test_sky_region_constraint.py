@@ -0,0 +1,79 @@
+"""Tests for the sky avoidance region constraint."""
+
+import json
+from typing import Any
+
+import numpy as np
+import pytest
+from pydantic import ValidationError
+
+import rust_ephem
+from rust_ephem.constraints import SkyRegionConstraint, SunConstraint
+
+RAS = [0.0, 358.0, 3.0, 10.0, 180.0]
+DECS = [0.0, 4.0, -4.0, 0.0, 0.0]
+INSIDE = [True, True, True, False, False]
+
+
+def test_targets_inside_violate_at_every_time(
+    tle_ephemeris: Any, region_across_ra_zero: list[tuple[float, float]]
+) -> None:
+    constraint = SkyRegionConstraint(polygon=region_across_ra_zero)
+    batch = constraint.in_constraint_batch(tle_ephemeris, RAS, DECS)
+    assert batch.shape == (len(RAS), len(tle_ephemeris.timestamp))
+    np.testing.assert_array_equal(batch.all(axis=1), INSIDE)
+    np.testing.assert_array_equal(batch.any(axis=1), INSIDE)
+
+
+def test_evaluate_matches_batch(
+    tle_ephemeris: Any, region_across_ra_zero: list[tuple[float, float]]
+) -> None:
+    constraint = SkyRegionConstraint(polygon=region_across_ra_zero)
+    inside = constraint.evaluate(tle_ephemeris, 0.0, 0.0)
+    assert not inside.all_satisfied
+    assert len(inside.violations) == 1
+    assert "sky avoidance region" in inside.violations[0].description
+    assert constraint.evaluate(tle_ephemeris, 10.0, 0.0).all_satisfied
+
+
+def test_matches_spherical_polygon(
+    region_across_ra_zero: list[tuple[float, float]],
+) -> None:
+    polygon = rust_ephem.SphericalPolygon(region_across_ra_zero)
+    np.testing.assert_array_equal(polygon.contains(RAS, DECS), INSIDE)
+
+
+def test_combines_with_other_constraints(
+    tle_ephemeris: Any, region_across_ra_zero: list[tuple[float, float]]
+) -> None:
+    sun = SunConstraint(min_angle=45.0)
+    combined = sun | SkyRegionConstraint(polygon=region_across_ra_zero)
+    result = combined.evaluate(tle_ephemeris, 0.0, 0.0)
+    assert all(result.constraint_array)
+
+
+def test_serialization_round_trip(
+    region_across_ra_zero: list[tuple[float, float]],
+) -> None:
+    constraint = SkyRegionConstraint(polygon=region_across_ra_zero)
+    data = json.loads(constraint.model_dump_json())
+    assert data["type"] == "sky_region"
+    assert SkyRegionConstraint.model_validate(data) == constraint
+
+    rust = rust_ephem.Constraint.from_json(constraint.model_dump_json())
+    assert json.loads(rust.to_json())["type"] == "sky_region"
+
+
+def test_factory_method(region_across_ra_zero: list[tuple[float, float]]) -> None:
+    constraint = rust_ephem.Constraint.sky_region(region_across_ra_zero)
+    assert json.loads(constraint.to_json()) == {
+        "type": "sky_region",
+        "polygon": [list(vertex) for vertex in region_across_ra_zero],
+    }
+
+
+def test_invalid_polygons_rejected() -> None:
+    with pytest.raises(ValidationError):
+        SkyRegionConstraint(polygon=[(0.0, 0.0), (10.0, 0.0)])
+    with pytest.raises(ValueError, match="hemisphere"):
+        rust_ephem.Constraint.sky_region([(0.0, 0.0), (120.0, 0.0), (240.0, 0.0)])
@@ -0,0 +1,45 @@
+"""Fixtures for slew star-tracker checks."""
+
+from datetime import datetime, timedelta, timezone
+
+import numpy as np
+import pytest
+
+from rust_ephem import ArrayEphemeris
+
+BEGIN = datetime(2024, 1, 1, 0, 0, 0, tzinfo=timezone.utc)
+N_POINTS = 6
+SLEW_START = BEGIN + timedelta(seconds=30)
+# The tracker starts and ends this far from the Sun, passing through it halfway
+HALF_SWEEP_DEG = 60.0
+SLEW_RATE = 1.0
+
+
+def axis_angle_quaternion(axis: np.ndarray, angle_deg: float) -> list[float]:
+    """Scalar-last quaternion for a rotation about a unit axis"""
+    half = np.radians(angle_deg) / 2.0
+    return [*(axis * np.sin(half)), float(np.cos(half))]
+
+
+@pytest.fixture
+def spacecraft() -> ArrayEphemeris:
+    """Spacecraft parked at 7000 km on +x"""
+    times = [BEGIN + timedelta(minutes=i) for i in range(N_POINTS)]
+    states = np.zeros((N_POINTS, 6))
+    states[:, 0] = 7000.0
+    return ArrayEphemeris(times, states)
+
+
+@pytest.fixture
+def sweep(spacecraft: ArrayEphemeris) -> tuple[list[float], list[float]]:
+    """Start and end attitudes of a slew that sweeps body +X across the Sun"""
+    to_sun = spacecraft.sun_pv.position[0] - spacecraft.gcrs_pv.position[0]
+    to_sun /= np.linalg.norm(to_sun)
+    x = np.array([1.0, 0.0, 0.0])
+    axis = np.cross(x, to_sun)
+    axis /= np.linalg.norm(axis)
+    theta = np.degrees(np.arccos(np.dot(x, to_sun)))
+    return (
+        axis_angle_quaternion(axis, theta - HALF_SWEEP_DEG),
+        axis_angle_quaternion(axis, theta + HALF_SWEEP_DEG),
+    )
@@ -0,0 +1,108 @@
+"""Tests for check_slew."""
+
+from datetime import timedelta
+
+import numpy as np
+import pytest
+
+from rust_ephem import ArrayEphemeris, check_slew
+
+from .conftest import BEGIN, HALF_SWEEP_DEG, SLEW_RATE, SLEW_START
+
+TRACKERS = {"st_plus_x": (1.0, 0.0, 0.0), "st_minus_x": (-1.0, 0.0, 0.0)}
+
+
+def test_sun_crossing_detected(
+    spacecraft: ArrayEphemeris, sweep: tuple[list[float], list[float]]
+) -> None:
+    start, end = sweep
+    check = check_slew(
+        spacecraft,
+        SLEW_START,
+        start,
+        end,
+        SLEW_RATE,
+        TRACKERS,
+        sun_angle=40.0,
+        moon_angle=0.0,
+    )
+    assert not check.clear
+    assert check.slew_angle_deg == pytest.approx(2 * HALF_SWEEP_DEG)
+    assert check.duration_seconds == pytest.approx(2 * HALF_SWEEP_DEG / SLEW_RATE)
+    assert check.tracker_names == list(TRACKERS)
+    n_samples = len(check.timestamp)
+    assert check.quaternions.shape == (n_samples, 4)
+    assert check.sun_angle_deg.shape == (2, n_samples)
+    assert check.moon_angle_deg.shape == (2, n_samples)
+
+    # Both end attitudes keep the tracker well clear of the Sun
+    assert check.sun_angle_deg[0, 0] == pytest.approx(HALF_SWEEP_DEG, abs=0.01)
+    assert check.sun_angle_deg[0, -1] == pytest.approx(HALF_SWEEP_DEG, abs=0.01)
+    # The opposite tracker never comes near
+    assert check.sun_angle_deg[1].min() > 100.0
+
+    (intrusion,) = check.intrusions
+    assert intrusion.tracker == "st_plus_x"
+    assert intrusion.body == "sun"
+    assert intrusion.min_angle_deg < 1.0
+    # Inside the 40 degree cone from 20 s to 100 s into the slew
+    enter = (intrusion.start_time - SLEW_START).total_seconds()
+    leave = (intrusion.end_time - SLEW_START).total_seconds()
+    assert enter == pytest.approx(21.0, abs=1.0)
+    assert leave == pytest.approx(99.0, abs=1.0)
+
+
+def test_timestamps_span_slew(
+    spacecraft: ArrayEphemeris, sweep: tuple[list[float], list[float]]
+) -> None:
+    start, end = sweep
+    check = check_slew(
+        spacecraft,
+        SLEW_START,
+        start,
+        end,
+        SLEW_RATE,
+        TRACKERS,
+        sun_angle=10.0,
+        moon_angle=0.0,
+        step_seconds=10.0,
+    )
+    assert check.timestamp[0] == SLEW_START
+    assert check.timestamp[-1] == SLEW_START + timedelta(seconds=120)
+    assert len(check.timestamp) == 13
+    np.testing.assert_allclose(np.linalg.norm(check.quaternions, axis=1), 1.0)
+
+
+def test_zero_slew(spacecraft: ArrayEphemeris) -> None:
+    identity = [0.0, 0.0, 0.0, 1.0]
+    check = check_slew(
+        spacecraft,
+        SLEW_START,
+        identity,
+        identity,
+        SLEW_RATE,
+        TRACKERS,
+        sun_angle=0.0,
+        moon_angle=0.0,
+    )
+    assert check.clear
+    assert len(check) == 0
+    assert check.slew_angle_deg == 0.0
+    assert len(check.timestamp) == 1
+
+
+def test_invalid_inputs(
+    spacecraft: ArrayEphemeris, sweep: tuple[list[float], list[float]]
+) -> None:
+    start, end = sweep
+    kwargs = {"sun_angle": 40.0, "moon_angle": 20.0}
+    with pytest.raises(ValueError, match="within the ephemeris"):
+        check_slew(spacecraft, BEGIN, start, end, 0.1, TRACKERS, **kwargs)
+    with pytest.raises(ValueError, match="slew_rate"):
+        check_slew(spacecraft, SLEW_START, start, end, 0.0, TRACKERS, **kwargs)
+    with pytest.raises(ValueError, match="non-zero norm"):
+        check_slew(spacecraft, SLEW_START, [0.0] * 4, end, 1.0, TRACKERS, **kwargs)
+    with pytest.raises(ValueError, match="tracker 'bad'"):
+        check_slew(
+            spacecraft, SLEW_START, start, end, 1.0, {"bad": (0.0, 0.0, 0.0)}, **kwargs
+        )
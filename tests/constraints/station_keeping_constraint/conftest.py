@@ -0,0 +1,36 @@
+"""Fixtures for station-keeping box constraint tests."""
+
+import math
+from datetime import datetime, timedelta, timezone
+
+import numpy as np
+import pytest
+
+import rust_ephem
+
+BEGIN = datetime(2024, 1, 1, tzinfo=timezone.utc)
+END = BEGIN + timedelta(hours=2)
+STEP_SIZE = 600
+SLOT_LONGITUDE = -75.0
+GEO_RADIUS_KM = 42164.17
+
+# Longitude drift per sample of the drifting satellite, degrees
+DRIFT_PER_STEP = 0.02
+N_DRIFT = 20
+
+
+@pytest.fixture
+def slot() -> rust_ephem.GEOEphemeris:
+    return rust_ephem.GEOEphemeris(SLOT_LONGITUDE, BEGIN, END, STEP_SIZE)
+
+
+@pytest.fixture
+def drifting() -> rust_ephem.ArrayEphemeris:
+    """GEO satellite drifting east from the slot centre"""
+    times = [BEGIN + timedelta(seconds=STEP_SIZE * i) for i in range(N_DRIFT)]
+    states = np.zeros((N_DRIFT, 6))
+    for i in range(N_DRIFT):
+        lon = math.radians(SLOT_LONGITUDE + DRIFT_PER_STEP * i)
+        states[i, 0] = GEO_RADIUS_KM * math.cos(lon)
+        states[i, 1] = GEO_RADIUS_KM * math.sin(lon)
+    return rust_ephem.ArrayEphemeris(times, states, frame="ITRS")
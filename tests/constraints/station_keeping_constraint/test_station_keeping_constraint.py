@@ -0,0 +1,97 @@
+"""Tests for the station-keeping box constraint."""
+
+import json
+
+import numpy as np
+import pytest
+from pydantic import ValidationError
+
+import rust_ephem
+from rust_ephem import StationKeepingConstraint
+
+from .conftest import BEGIN, END, SLOT_LONGITUDE, STEP_SIZE
+
+
+class TestGeoSlot:
+    def test_slot_inside_its_box(self, slot: rust_ephem.GEOEphemeris) -> None:
+        constraint = rust_ephem.Constraint.station_keeping(SLOT_LONGITUDE, 0.05, 0.05)
+        assert constraint.evaluate(slot, 0.0, 0.0).all_satisfied
+
+    def test_east_longitude_centre(self, slot: rust_ephem.GEOEphemeris) -> None:
+        constraint = rust_ephem.Constraint.station_keeping(285.0)
+        assert constraint.evaluate(slot, 0.0, 0.0).all_satisfied
+
+    def test_neighbouring_slot(self, slot: rust_ephem.GEOEphemeris) -> None:
+        constraint = rust_ephem.Constraint.station_keeping(SLOT_LONGITUDE + 0.5)
+        result = constraint.evaluate(slot, 0.0, 0.0)
+        assert all(result.constraint_array)
+        assert "longitude" in result.violations[0].description
+        assert result.violations[0].max_severity == 1.0
+
+    def test_latitude_offset(self) -> None:
+        inclined = rust_ephem.GEOEphemeris(
+            SLOT_LONGITUDE, BEGIN, END, STEP_SIZE, latitude_deg=0.2
+        )
+        constraint = rust_ephem.Constraint.station_keeping(SLOT_LONGITUDE)
+        result = constraint.evaluate(inclined, 0.0, 0.0)
+        assert all(result.constraint_array)
+        assert "latitude" in result.violations[0].description
+        loose = rust_ephem.Constraint.station_keeping(
+            SLOT_LONGITUDE, latitude_tolerance=0.3
+        )
+        assert loose.evaluate(inclined, 0.0, 0.0).all_satisfied
+
+
+def test_drift_out_of_box(drifting: rust_ephem.ArrayEphemeris) -> None:
+    constraint = rust_ephem.Constraint.station_keeping(SLOT_LONGITUDE)
+    result = constraint.evaluate(drifting, 0.0, 0.0)
+    violated = np.asarray(result.constraint_array)
+    # 0.02 degrees per step leaves the 0.1 degree box after step 5
+    assert not violated[:5].any()
+    assert violated[6:].all()
+    assert len(result.violations) == 1
+    assert "+0.1" in result.violations[0].description
+
+
+def test_batch_is_target_independent(drifting: rust_ephem.ArrayEphemeris) -> None:
+    constraint = rust_ephem.Constraint.station_keeping(SLOT_LONGITUDE)
+    batch = constraint.in_constraint_batch(drifting, [0.0, 120.0], [0.0, -40.0])
+    single = constraint.evaluate(drifting, 0.0, 0.0)
+    for row in batch:
+        np.testing.assert_array_equal(row, single.constraint_array)
+
+
+class TestConfiguration:
+    @pytest.mark.parametrize(
+        "kwargs",
+        [
+            {"longitude": 400.0},
+            {"longitude": 0.0, "latitude": 95.0},
+            {"longitude": 0.0, "longitude_tolerance": 0.0},
+            {"longitude": 0.0, "latitude_tolerance": -0.1},
+        ],
+    )
+    def test_invalid_limits(self, kwargs: dict[str, float]) -> None:
+        with pytest.raises(ValueError):
+            rust_ephem.Constraint.station_keeping(**kwargs)
+        with pytest.raises(ValidationError):
+            StationKeepingConstraint(**kwargs)
+
+    def test_json_round_trip(self) -> None:
+        constraint = rust_ephem.Constraint.station_keeping(SLOT_LONGITUDE, 0.05)
+        config = json.loads(constraint.to_json())
+        assert config["type"] == "station_keeping"
+        assert config["latitude"] == 0.0
+        assert config["latitude_tolerance"] == 0.1
+        restored = rust_ephem.Constraint.from_json(constraint.to_json())
+        assert "lon=-75.000°±0.050°" in str(restored)
+
+    def test_pydantic_matches_factory(
+        self, drifting: rust_ephem.ArrayEphemeris
+    ) -> None:
+        model = StationKeepingConstraint(longitude=SLOT_LONGITUDE)
+        factory = rust_ephem.Constraint.station_keeping(SLOT_LONGITUDE)
+        np.testing.assert_array_equal(
+            model.evaluate(drifting, 0.0, 0.0).constraint_array,
+            factory.evaluate(drifting, 0.0, 0.0).constraint_array,
+        )
@@ -0,0 +1,108 @@
+"""Tests for the per-quadrant Sun keep-out constraint."""
+
+import pytest
+from pydantic import ValidationError
+
+import rust_ephem
+from rust_ephem.constraints import SunKeepOutConstraint
+
+
+def keepout(**overrides: float | None) -> SunKeepOutConstraint:
+    limits: dict[str, float | None] = {
+        "pitch_plus": 40.0,
+        "pitch_minus": 40.0,
+        "yaw_plus": 40.0,
+        "yaw_minus": 40.0,
+    }
+    limits.update(overrides)
+    return SunKeepOutConstraint(**limits)
+
+
+class TestSunKeepOutConstraint:
+    def test_sun_reference_puts_sun_in_minus_pitch_sector(
+        self,
+        tle_ephem: rust_ephem.TLEEphemeris,
+        sun_offset_target: tuple[float, float],
+    ) -> None:
+        ra, dec = sun_offset_target
+        # The Sun is about 50 degrees off the boresight, toward +Z at roll 0
+        wide = keepout(pitch_minus=60.0, roll_deg=0.0)
+        result = wide.evaluate(tle_ephem, target_ra=ra, target_dec=dec)
+        assert all(result.constraint_array)
+        narrow = keepout(pitch_plus=60.0, yaw_plus=60.0, roll_deg=0.0)
+        result = narrow.evaluate(tle_ephem, target_ra=ra, target_dec=dec)
+        assert result.all_satisfied
+
+    @pytest.mark.parametrize(
+        ("target_roll", "sector"),
+        [(0.0, "pitch_minus"), (90.0, "yaw_plus"), (180.0, "pitch_plus")],
+    )
+    def test_target_roll_turns_sectors(
+        self,
+        tle_ephem: rust_ephem.TLEEphemeris,
+        sun_offset_target: tuple[float, float],
+        target_roll: float,
+        sector: str,
+    ) -> None:
+        ra, dec = sun_offset_target
+        constraint = keepout(**{sector: 60.0}, roll_deg=45.0)
+        result = constraint.evaluate(
+            tle_ephem, target_ra=ra, target_dec=dec, target_roll=target_roll
+        )
+        assert all(result.constraint_array)
+
+    def test_free_roll_uses_least_restrictive_sector(
+        self,
+        tle_ephem: rust_ephem.TLEEphemeris,
+        sun_offset_target: tuple[float, float],
+    ) -> None:
+        ra, dec = sun_offset_target
+        constraint = keepout(pitch_plus=60.0, pitch_minus=60.0, yaw_plus=60.0)
+        result = constraint.evaluate(tle_ephem, target_ra=ra, target_dec=dec)
+        assert result.all_satisfied
+        constraint = keepout(
+            pitch_plus=60.0, pitch_minus=60.0, yaw_plus=60.0, yaw_minus=60.0
+        )
+        result = constraint.evaluate(tle_ephem, target_ra=ra, target_dec=dec)
+        assert all(result.constraint_array)
+
+    def test_batch_matches_evaluate(
+        self,
+        tle_ephem: rust_ephem.TLEEphemeris,
+        sun_offset_target: tuple[float, float],
+    ) -> None:
+        ra, dec = sun_offset_target
+        constraint = keepout(pitch_minus=60.0, roll_reference="north", roll_deg=0.0)
+        batch = constraint.in_constraint_batch(tle_ephem, [ra], [dec])
+        result = constraint.evaluate(tle_ephem, target_ra=ra, target_dec=dec)
+        assert list(batch[0]) == list(result.constraint_array)
+
+    def test_rust_constructor(
+        self,
+        tle_ephem: rust_ephem.TLEEphemeris,
+        sun_offset_target: tuple[float, float],
+    ) -> None:
+        ra, dec = sun_offset_target
+        constraint = rust_ephem.Constraint.sun_keepout(40.0, 60.0, 40.0, 40.0, 0.0)
+        assert "SunKeepOut" in str(constraint)
+        result = constraint.evaluate(tle_ephem, ra, dec)
+        assert all(result.constraint_array)
+
+    def test_rust_constructor_rejects_bad_input(self) -> None:
+        with pytest.raises(ValueError, match="roll_reference"):
+            rust_ephem.Constraint.sun_keepout(
+                40.0, 40.0, 40.0, 40.0, roll_reference="ecliptic"
+            )
+        with pytest.raises(ValueError, match="yaw_minus"):
+            rust_ephem.Constraint.sun_keepout(40.0, 40.0, 40.0, 200.0)
+
+    def test_model_validation(self) -> None:
+        with pytest.raises(ValidationError):
+            keepout(pitch_plus=200.0)
+
+    def test_json_round_trip(self) -> None:
+        constraint = keepout(yaw_plus=70.0, roll_deg=15.0, roll_reference="north")
+        restored = SunKeepOutConstraint.model_validate_json(
+            constraint.model_dump_json()
+        )
+        assert restored == constraint
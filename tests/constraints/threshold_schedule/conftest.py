@@ -0,0 +1,29 @@
+"""Fixtures for threshold_schedule tests."""
+
+from datetime import datetime, timezone
+
+import pytest
+
+import rust_ephem
+
+BEGIN = datetime(2025, 9, 23, 0, 0, 0, tzinfo=timezone.utc)
+
+
+@pytest.fixture
+def tle_ephem() -> rust_ephem.TLEEphemeris:
+    tle1 = "1 28485U 04047A   25317.24527149  .00068512  00000+0  12522-2 0  9999"
+    tle2 = "2 28485  20.5556  25.5469 0004740 206.7882 153.2316 15.47667717153136"
+    return rust_ephem.TLEEphemeris(
+        tle1,
+        tle2,
+        BEGIN,
+        BEGIN.replace(hour=1),
+        60,
+    )
+
+
+@pytest.fixture
+def sun_offset_target(tle_ephem: rust_ephem.TLEEphemeris) -> tuple[float, float]:
+    """Target 50 degrees east of the Sun along its declination"""
+    sun = tle_ephem.sun[0]
+    return ((sun.ra.deg + 50.0) % 360, sun.dec.deg)
@@ -0,0 +1,116 @@
+"""Tests for time-varying proximity and limb constraint thresholds."""
+
+import pytest
+from pydantic import ValidationError
+
+import rust_ephem
+from rust_ephem.constraints import (
+    EarthLimbConstraint,
+    SunConstraint,
+    ThresholdPeriod,
+    threshold_schedule,
+)
+
+from .conftest import BEGIN
+
+ANNEAL = ThresholdPeriod(
+    begin=BEGIN.replace(minute=20), end=BEGIN.replace(minute=40), min_angle=60.0
+)
+
+
+class TestScheduledSunConstraint:
+    def test_violated_only_during_period(
+        self,
+        tle_ephem: rust_ephem.TLEEphemeris,
+        sun_offset_target: tuple[float, float],
+    ) -> None:
+        ra, dec = sun_offset_target
+        constraint = SunConstraint(min_angle=45.0, schedule=[ANNEAL])
+        result = constraint.evaluate(tle_ephem, target_ra=ra, target_dec=dec)
+        assert len(result.violations) == 1
+        assert result.violations[0].start_time == BEGIN.replace(minute=20)
+        assert result.violations[0].end_time == BEGIN.replace(minute=39)
+
+    def test_batch_matches_evaluate(
+        self,
+        tle_ephem: rust_ephem.TLEEphemeris,
+        sun_offset_target: tuple[float, float],
+    ) -> None:
+        ra, dec = sun_offset_target
+        constraint = SunConstraint(min_angle=45.0, schedule=[ANNEAL])
+        batch = constraint.in_constraint_batch(tle_ephem, [ra], [dec])
+        result = constraint.evaluate(tle_ephem, target_ra=ra, target_dec=dec)
+        assert list(batch[0]) == list(result.constraint_array)
+
+    def test_unscheduled_constraint_is_satisfied(
+        self,
+        tle_ephem: rust_ephem.TLEEphemeris,
+        sun_offset_target: tuple[float, float],
+    ) -> None:
+        ra, dec = sun_offset_target
+        result = SunConstraint(min_angle=45.0).evaluate(
+            tle_ephem, target_ra=ra, target_dec=dec
+        )
+        assert result.all_satisfied
+
+    def test_rust_constructor_takes_mappings(
+        self,
+        tle_ephem: rust_ephem.TLEEphemeris,
+        sun_offset_target: tuple[float, float],
+    ) -> None:
+        ra, dec = sun_offset_target
+        constraint = rust_ephem.Constraint.sun_proximity(
+            45.0, schedule=[ANNEAL.model_dump()]
+        )
+        assert "scheduled" in str(constraint)
+        result = constraint.evaluate(tle_ephem, ra, dec)
+        assert len(result.violations) == 1
+
+    def test_json_round_trip(self) -> None:
+        constraint = SunConstraint(min_angle=45.0, schedule=[ANNEAL])
+        restored = SunConstraint.model_validate_json(constraint.model_dump_json())
+        assert restored.schedule == [ANNEAL]
+
+
+class TestThresholdSchedule:
+    def test_series_becomes_consecutive_periods(self) -> None:
+        times = [BEGIN, BEGIN.replace(minute=30)]
+        periods = threshold_schedule(times, min_angle=[50.0, 60.0])
+        assert periods[0].end == periods[1].begin
+        assert periods[1].end is None
+        assert [p.min_angle for p in periods] == [50.0, 60.0]
+
+    def test_series_length_checked(self) -> None:
+        with pytest.raises(ValueError, match="one value per time"):
+            threshold_schedule([BEGIN], min_angle=[50.0, 60.0])
+
+    def test_series_on_earth_limb(self, tle_ephem: rust_ephem.TLEEphemeris) -> None:
+        # A margin no target can meet, from half way through the run
+        periods = threshold_schedule([BEGIN.replace(minute=30)], min_angle=[179.0])
+        constraint = EarthLimbConstraint(min_angle=0.0, schedule=periods)
+        result = constraint.evaluate(tle_ephem, target_ra=0.0, target_dec=90.0)
+        assert all(result.constraint_array[30:])
+
+
+class TestScheduleValidation:
+    def test_period_needs_a_limit(self) -> None:
+        with pytest.raises(ValidationError, match="min_angle and/or max_angle"):
+            ThresholdPeriod(begin=BEGIN)
+
+    def test_period_end_after_begin(self) -> None:
+        with pytest.raises(ValidationError, match="end must be after begin"):
+            ThresholdPeriod(begin=BEGIN, end=BEGIN, min_angle=50.0)
+
+    def test_max_above_scheduled_min(self) -> None:
+        with pytest.raises(ValidationError, match="greater than min_angle"):
+            SunConstraint(
+                min_angle=45.0,
+                max_angle=90.0,
+                schedule=[ThresholdPeriod(min_angle=95.0)],
+            )
+
+    def test_rust_constructor_validates(self) -> None:
+        with pytest.raises(ValueError, match="end must be after begin"):
+            rust_ephem.Constraint.moon_proximity(
+                10.0, schedule=[{"begin": BEGIN, "end": BEGIN, "min_angle": 20.0}]
+            )
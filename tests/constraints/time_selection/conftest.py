@@ -0,0 +1,20 @@
+"""Fixtures for time_selection tests."""
+
+from datetime import datetime, timedelta, timezone
+
+import pytest
+
+from rust_ephem import SunConstraint
+
+# Target well away from the Sun in late September
+TARGET_RA = 0.0
+TARGET_DEC = -60.0
+
+# Steps of the tle_ephem fixture (1-minute grid starting 2025-09-23)
+BEGIN = datetime(2025, 9, 23, 0, 0, 0, tzinfo=timezone.utc)
+STEP = timedelta(seconds=60)
+
+
+@pytest.fixture
+def sun_constraint_45() -> SunConstraint:
+    return SunConstraint(min_angle=45.0)
@@ -0,0 +1,142 @@
+"""Tests for evaluating constraints over ``between`` and ``at_times`` subsets."""
+
+from datetime import timedelta
+from typing import Any
+
+import pytest
+
+from rust_ephem import SunConstraint
+
+from .conftest import BEGIN, STEP, TARGET_DEC, TARGET_RA
+
+
+class TestBetween:
+    def test_matches_index_range(
+        self, tle_ephem: Any, sun_constraint_45: SunConstraint
+    ) -> None:
+        between = (BEGIN + 10 * STEP, BEGIN + 20 * STEP)
+        by_range = sun_constraint_45.evaluate(
+            tle_ephem, TARGET_RA, TARGET_DEC, between=between
+        )
+        by_index = sun_constraint_45.evaluate(
+            tle_ephem, TARGET_RA, TARGET_DEC, indices=list(range(10, 21))
+        )
+        assert list(by_range.timestamps) == list(by_index.timestamps)
+        assert by_range.constraint_array == by_index.constraint_array
+
+    def test_endpoints_off_grid_select_inner_steps(
+        self, tle_ephem: Any, sun_constraint_45: SunConstraint
+    ) -> None:
+        between = (BEGIN + 10.5 * STEP, BEGIN + 12.5 * STEP)
+        result = sun_constraint_45.evaluate(
+            tle_ephem, TARGET_RA, TARGET_DEC, between=between
+        )
+        assert len(result.constraint_array) == 2
+
+    def test_batch_shape(
+        self, tle_ephem: Any, sun_constraint_45: SunConstraint
+    ) -> None:
+        between = (BEGIN, BEGIN + 4 * STEP)
+        violations = sun_constraint_45.in_constraint_batch(
+            tle_ephem, [0.0, 90.0, 180.0], [-60.0, 0.0, 30.0], between=between
+        )
+        assert violations.shape == (3, 5)
+
+    def test_empty_range_raises(
+        self, tle_ephem: Any, sun_constraint_45: SunConstraint
+    ) -> None:
+        between = (BEGIN + 10.2 * STEP, BEGIN + 10.8 * STEP)
+        with pytest.raises(ValueError, match="No ephemeris timestamps"):
+            sun_constraint_45.evaluate(
+                tle_ephem, TARGET_RA, TARGET_DEC, between=between
+            )
+
+    def test_reversed_range_raises(
+        self, tle_ephem: Any, sun_constraint_45: SunConstraint
+    ) -> None:
+        between = (BEGIN + 20 * STEP, BEGIN + 10 * STEP)
+        with pytest.raises(ValueError, match="after end"):
+            sun_constraint_45.evaluate(
+                tle_ephem, TARGET_RA, TARGET_DEC, between=between
+            )
+
+
+class TestAtTimes:
+    def test_on_grid_matches_times(
+        self, tle_ephem: Any, sun_constraint_45: SunConstraint
+    ) -> None:
+        times = [BEGIN + 5 * STEP, BEGIN + 30 * STEP]
+        at = sun_constraint_45.evaluate(
+            tle_ephem, TARGET_RA, TARGET_DEC, at_times=times
+        )
+        exact = sun_constraint_45.evaluate(
+            tle_ephem, TARGET_RA, TARGET_DEC, times=times
+        )
+        assert at.constraint_array == exact.constraint_array
+
+    def test_off_grid_times_are_evaluated(
+        self, tle_ephem: Any, sun_constraint_45: SunConstraint
+    ) -> None:
+        times = [BEGIN + 5.5 * STEP, BEGIN + timedelta(hours=3, seconds=17)]
+        result = sun_constraint_45.evaluate(
+            tle_ephem, TARGET_RA, TARGET_DEC, at_times=times
+        )
+        assert len(result.constraint_array) == 2
+        assert list(result.timestamps) == times
+
+    def test_off_grid_agrees_with_neighbouring_steps(
+        self, tle_ephem: Any, sun_constraint_45: SunConstraint
+    ) -> None:
+        # The Sun moves ~0.04 deg per minute, so the result cannot change between
+        # steps far from the constraint boundary
+        neighbours = sun_constraint_45.evaluate(
+            tle_ephem, TARGET_RA, TARGET_DEC, times=[BEGIN + 100 * STEP]
+        ).constraint_array
+        result = sun_constraint_45.evaluate(
+            tle_ephem, TARGET_RA, TARGET_DEC, at_times=[BEGIN + 100.5 * STEP]
+        )
+        assert result.constraint_array == neighbours
+
+    def test_batch_off_grid_shape(
+        self, tle_ephem: Any, sun_constraint_45: SunConstraint
+    ) -> None:
+        times = [BEGIN + 0.25 * STEP, BEGIN + 0.75 * STEP, BEGIN + 2 * STEP]
+        violations = sun_constraint_45.in_constraint_batch(
+            tle_ephem, [0.0, 90.0], [-60.0, 0.0], at_times=times
+        )
+        assert violations.shape == (2, 3)
+
+    def test_outside_range_raises(
+        self, tle_ephem: Any, sun_constraint_45: SunConstraint
+    ) -> None:
+        with pytest.raises(ValueError, match="within the ephemeris range"):
+            sun_constraint_45.evaluate(
+                tle_ephem,
+                TARGET_RA,
+                TARGET_DEC,
+                at_times=[BEGIN - 0.5 * STEP],
+            )
+
+    def test_unordered_off_grid_raises(
+        self, tle_ephem: Any, sun_constraint_45: SunConstraint
+    ) -> None:
+        with pytest.raises(ValueError, match="strictly increasing"):
+            sun_constraint_45.evaluate(
+                tle_ephem,
+                TARGET_RA,
+                TARGET_DEC,
+                at_times=[BEGIN + 3.5 * STEP, BEGIN + 1.5 * STEP],
+            )
+
+
+def test_multiple_selections_raise(
+    tle_ephem: Any, sun_constraint_45: SunConstraint
+) -> None:
+    with pytest.raises(ValueError, match="at most one"):
+        sun_constraint_45.evaluate(
+            tle_ephem,
+            TARGET_RA,
+            TARGET_DEC,
+            indices=[0],
+            between=(BEGIN, BEGIN + STEP),
+        )
@@ -0,0 +1,25 @@
+"""Fixtures for virtual target tests."""
+
+from datetime import datetime, timedelta, timezone
+
+import numpy as np
+import pytest
+
+from rust_ephem import ArrayEphemeris
+
+BEGIN = datetime(2024, 1, 1, 0, 0, 0, tzinfo=timezone.utc)
+N_POINTS = 4
+RADIUS_KM = 7000.0
+EARTH_RADIUS_KM = 6378.137
+# Angle between nadir and the Earth limb seen from RADIUS_KM
+LIMB_FROM_NADIR = np.degrees(np.arcsin(EARTH_RADIUS_KM / RADIUS_KM))
+
+
+@pytest.fixture
+def spacecraft() -> ArrayEphemeris:
+    """Spacecraft held on +x moving along +y, so the orbit normal is +z"""
+    times = [BEGIN + timedelta(minutes=i) for i in range(N_POINTS)]
+    states = np.zeros((N_POINTS, 6))
+    states[:, 0] = RADIUS_KM
+    states[:, 4] = 7.5
+    return ArrayEphemeris(times, states)
@@ -0,0 +1,57 @@
+"""Tests for virtual targets."""
+
+import numpy as np
+import pytest
+
+from rust_ephem import ArrayEphemeris, Constraint, virtual_target_radec
+from rust_ephem.constraints import EarthLimbConstraint
+
+from .conftest import LIMB_FROM_NADIR, N_POINTS
+
+
+@pytest.mark.parametrize(
+    ("target", "ra", "dec"),
+    [("nadir", 180.0, 0.0), ("ram", 90.0, 0.0), ("anti_ram", 270.0, 0.0)],
+)
+def test_fixed_directions(
+    spacecraft: ArrayEphemeris, target: str, ra: float, dec: float
+) -> None:
+    ras, decs = virtual_target_radec(spacecraft, target)
+    assert ras.shape == (N_POINTS,)
+    np.testing.assert_allclose(ras, ra, atol=1e-9)
+    np.testing.assert_allclose(decs, dec, atol=1e-9)
+
+
+def test_limb_azimuth(spacecraft: ArrayEphemeris) -> None:
+    # Azimuth 0 tilts from nadir toward ram (+y)
+    ras, decs = virtual_target_radec(spacecraft, "limb")
+    np.testing.assert_allclose(ras, 180.0 - LIMB_FROM_NADIR, atol=1e-9)
+    np.testing.assert_allclose(decs, 0.0, atol=1e-9)
+    # Azimuth 90 tilts toward the orbit normal (+z)
+    ras, decs = virtual_target_radec(spacecraft, "limb", azimuth=90.0)
+    np.testing.assert_allclose(ras, 180.0, atol=1e-9)
+    np.testing.assert_allclose(decs, 90.0 - LIMB_FROM_NADIR, atol=1e-9)
+
+
+def test_evaluate_virtual_target(spacecraft: ArrayEphemeris) -> None:
+    constraint = Constraint.earth_limb(min_angle=10.0)
+    assert not constraint.evaluate_virtual_target(spacecraft, "nadir").all_satisfied
+    # Ram is about 24 degrees above the limb
+    result = constraint.evaluate_virtual_target(spacecraft, "ram")
+    assert result.all_satisfied
+    np.testing.assert_allclose(result.ras, 90.0, atol=1e-9)
+
+
+def test_pydantic_evaluate_virtual_target(spacecraft: ArrayEphemeris) -> None:
+    result = EarthLimbConstraint(min_angle=10.0).evaluate_virtual_target(
+        spacecraft, "nadir"
+    )
+    assert not result.all_satisfied
+    assert not any(result.visibility_flags)
+
+
+def test_invalid_targets(spacecraft: ArrayEphemeris) -> None:
+    with pytest.raises(ValueError, match="Unknown virtual target"):
+        virtual_target_radec(spacecraft, "zenith")  # type: ignore[arg-type]
+    with pytest.raises(ValueError, match="only used with the 'limb' target"):
+        virtual_target_radec(spacecraft, "nadir", azimuth=10.0)
@@ -0,0 +1,44 @@
+"""Fixtures for visibility report tests."""
+
+from datetime import datetime, timedelta, timezone
+
+import numpy as np
+import pytest
+
+from rust_ephem import ArrayEphemeris, Constraint
+
+BEGIN = datetime(2024, 1, 1, 0, 0, 0, tzinfo=timezone.utc)
+STEP = 60
+N_POINTS = 21
+# The spacecraft jumps from +x to -x after this sample
+LAST_PLUS_X = 10
+RADIUS_KM = 7000.0
+
+# RA/Dec of targets along +x, -x and +y. +x is visible while the spacecraft
+# is on the +x side, -x afterwards, and +y (about 24 degrees above the
+# Earth limb) throughout.
+PLUS_X = (0.0, 0.0)
+MINUS_X = (180.0, 0.0)
+PLUS_Y = (90.0, 0.0)
+
+
+@pytest.fixture
+def spacecraft() -> ArrayEphemeris:
+    times = [BEGIN + timedelta(seconds=STEP * i) for i in range(N_POINTS)]
+    states = np.zeros((N_POINTS, 6))
+    states[: LAST_PLUS_X + 1, 0] = RADIUS_KM
+    states[LAST_PLUS_X + 1 :, 0] = -RADIUS_KM
+    return ArrayEphemeris(times, states)
+
+
+@pytest.fixture
+def earth_limb() -> Constraint:
+    return Constraint.earth_limb(min_angle=10.0)
+
+
+@pytest.fixture
+def limb_or_wide_limb() -> Constraint:
+    """Blocks +y throughout through the 30 degree limb constraint only"""
+    return Constraint.or_(
+        Constraint.earth_limb(min_angle=10.0), Constraint.earth_limb(min_angle=30.0)
+    )
@@ -0,0 +1,119 @@
+"""Tests for per-target visibility reports"""
+
+from datetime import timedelta
+
+import numpy as np
+import pytest
+
+from rust_ephem import ArrayEphemeris, Constraint, VisibilityReport, visibility_report
+
+from .conftest import BEGIN, LAST_PLUS_X, MINUS_X, N_POINTS, PLUS_X, PLUS_Y, STEP
+
+PLUS_X_HOURS = LAST_PLUS_X * STEP / 3600
+MINUS_X_HOURS = (N_POINTS - LAST_PLUS_X - 2) * STEP / 3600
+SPAN_HOURS = (N_POINTS - 1) * STEP / 3600
+
+
+def test_summary_columns(spacecraft: ArrayEphemeris, earth_limb: Constraint) -> None:
+    report = visibility_report(spacecraft, [PLUS_X, MINUS_X, PLUS_Y], earth_limb)
+    assert isinstance(report, VisibilityReport)
+    assert len(report) == 3
+    assert report.names is None
+    np.testing.assert_allclose(report.ra, [0.0, 180.0, 90.0])
+    np.testing.assert_allclose(
+        report.visible_hours, [PLUS_X_HOURS, MINUS_X_HOURS, SPAN_HOURS]
+    )
+    np.testing.assert_allclose(
+        report.longest_window_hours, [PLUS_X_HOURS, MINUS_X_HOURS, SPAN_HOURS]
+    )
+    assert list(report.n_windows) == [1, 1, 1]
+    last = BEGIN + timedelta(seconds=STEP * (N_POINTS - 1))
+    after_jump = BEGIN + timedelta(seconds=STEP * (LAST_PLUS_X + 1))
+    assert list(report.first_visible) == [BEGIN, after_jump, BEGIN]
+    assert list(report.last_visible) == [
+        BEGIN + timedelta(seconds=STEP * LAST_PLUS_X),
+        last,
+        last,
+    ]
+
+
+def test_never_visible(
+    spacecraft: ArrayEphemeris, limb_or_wide_limb: Constraint
+) -> None:
+    report = visibility_report(spacecraft, [PLUS_Y], limb_or_wide_limb)
+    assert report.visible_hours[0] == 0.0
+    assert report.n_windows[0] == 0
+    assert report.first_visible[0] is None
+    assert report.last_visible[0] is None
+
+
+def test_blocking_breakdown(
+    spacecraft: ArrayEphemeris, limb_or_wide_limb: Constraint
+) -> None:
+    report = visibility_report(spacecraft, [PLUS_X, PLUS_Y], limb_or_wide_limb)
+    blocked = report.blocked_hours
+    assert len(blocked) == 2
+    narrow, wide = blocked.values()
+    np.testing.assert_allclose(narrow, [MINUS_X_HOURS, 0.0])
+    np.testing.assert_allclose(wide, [MINUS_X_HOURS, SPAN_HOURS])
+
+
+def test_single_constraint_blocks(
+    spacecraft: ArrayEphemeris, earth_limb: Constraint
+) -> None:
+    report = visibility_report(spacecraft, [PLUS_X], earth_limb)
+    (hours,) = report.blocked_hours.values()
+    np.testing.assert_allclose(hours, [MINUS_X_HOURS])
+
+
+def test_duplicate_constraint_names(spacecraft: ArrayEphemeris) -> None:
+    limb = Constraint.earth_limb(min_angle=10.0)
+    report = visibility_report(spacecraft, [PLUS_X], Constraint.or_(limb, limb))
+    names = list(report.blocked_hours)
+    assert len(names) == 2
+    assert names[1] == f"{names[0]} #2"
+
+
+def test_to_dict(spacecraft: ArrayEphemeris, limb_or_wide_limb: Constraint) -> None:
+    report = visibility_report(
+        spacecraft, [PLUS_X, PLUS_Y], limb_or_wide_limb, names=["a", "b"]
+    )
+    columns = report.to_dict()
+    assert list(columns)[:8] == [
+        "name",
+        "ra",
+        "dec",
+        "visible_hours",
+        "n_windows",
+        "longest_window_hours",
+        "first_visible",
+        "last_visible",
+    ]
+    blocking = [c for c in columns if c.startswith("blocked_hours:")]
+    assert len(blocking) == 2
+    assert all(len(column) == 2 for column in columns.values())
+
+
+def test_to_records(spacecraft: ArrayEphemeris, earth_limb: Constraint) -> None:
+    report = visibility_report(
+        spacecraft, [PLUS_X, MINUS_X], earth_limb, names=["a", "b"]
+    )
+    records = report.to_records()
+    assert isinstance(records, np.recarray)
+    assert len(records) == 2
+    assert list(records.name) == ["a", "b"]
+    np.testing.assert_allclose(records.visible_hours, [PLUS_X_HOURS, MINUS_X_HOURS])
+    assert records[0].first_visible == BEGIN
+
+
+def test_empty_targets(spacecraft: ArrayEphemeris, earth_limb: Constraint) -> None:
+    report = visibility_report(spacecraft, [], earth_limb)
+    assert len(report) == 0
+    assert len(report.visible_hours) == 0
+
+
+def test_names_length_mismatch(
+    spacecraft: ArrayEphemeris, earth_limb: Constraint
+) -> None:
+    with pytest.raises(ValueError, match="names"):
+        visibility_report(spacecraft, [PLUS_X], earth_limb, names=["a", "b"])
@@ -0,0 +1,30 @@
+"""Fixtures for visibility plot data tests."""
+
+from datetime import datetime, timedelta, timezone
+from typing import Any
+
+import pytest
+
+BEGIN = datetime(2024, 1, 1, 0, 0, 0, tzinfo=timezone.utc)
+
+
+def at(minutes: float) -> datetime:
+    return BEGIN + timedelta(minutes=minutes)
+
+
+class Window:
+    """Stand-in for VisibilityWindow"""
+
+    def __init__(self, start: datetime, end: datetime) -> None:
+        self.start_time = start
+        self.end_time = end
+
+
+@pytest.fixture
+def windows() -> list[list[Any]]:
+    """Two windows (10 and 20 min), one window (30 min) and no windows"""
+    return [
+        [Window(at(0), at(10)), Window(at(30), at(50))],
+        [(at(20), at(50))],
+        [],
+    ]
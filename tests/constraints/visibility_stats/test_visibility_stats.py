@@ -0,0 +1,58 @@
+"""Tests for plot-ready visibility statistics"""
+
+from typing import Any
+
+import numpy as np
+import pytest
+
+from rust_ephem import cumulative_visibility, gantt_rows, window_duration_histogram
+
+from .conftest import BEGIN, at
+
+
+def test_gantt_rows(windows: list[list[Any]]) -> None:
+    rows, starts, ends = gantt_rows(windows)
+    assert list(rows) == [0, 0, 1]
+    assert starts.dtype == np.dtype("datetime64[us]")
+    assert starts[0] == np.datetime64(BEGIN.replace(tzinfo=None), "us")
+    durations = (ends - starts) / np.timedelta64(1, "m")
+    np.testing.assert_allclose(durations, [10.0, 20.0, 30.0])
+
+
+def test_gantt_rows_empty() -> None:
+    rows, starts, ends = gantt_rows([])
+    assert len(rows) == len(starts) == len(ends) == 0
+
+
+def test_cumulative_visibility(windows: list[list[Any]]) -> None:
+    times = [at(m) for m in (0, 5, 10, 40, 60)]
+    curves = cumulative_visibility(windows, times)
+    assert curves.shape == (3, 5)
+    np.testing.assert_allclose(curves[0], np.array([0, 5, 10, 20, 30]) * 60.0)
+    np.testing.assert_allclose(curves[1], np.array([0, 0, 0, 20, 30]) * 60.0)
+    np.testing.assert_allclose(curves[2], 0.0)
+
+
+def test_cumulative_visibility_datetime64(windows: list[list[Any]]) -> None:
+    times = np.array(["2024-01-01T00:05", "2024-01-01T01:00"], dtype="datetime64[us]")
+    curves = cumulative_visibility(windows, times)
+    np.testing.assert_allclose(curves[:, 0], [300.0, 0.0, 0.0])
+
+
+def test_window_duration_histogram(windows: list[list[Any]]) -> None:
+    counts, edges = window_duration_histogram(windows, bins=2)
+    np.testing.assert_allclose(edges, [600.0, 1200.0, 1800.0])
+    assert counts.tolist() == [[1, 1], [0, 1], [0, 0]]
+
+
+def test_window_duration_histogram_range(windows: list[list[Any]]) -> None:
+    counts, edges = window_duration_histogram(
+        windows, bins=4, duration_range=(0.0, 2400.0)
+    )
+    assert len(edges) == 5
+    assert counts.sum(axis=0).tolist() == [0, 1, 1, 1]
+
+
+def test_window_ending_before_start_rejected() -> None:
+    with pytest.raises(ValueError, match="end before"):
+        gantt_rows([[(at(10), at(0))]])
@@ -0,0 +1,49 @@
+"""Tests for airmass_batch."""
+
+import numpy as np
+import pytest
+
+from rust_ephem import GroundEphemeris
+
+from .conftest import TARGET_DECS, TARGET_RAS
+
+
+def test_shape(site: GroundEphemeris) -> None:
+    airmass = site.airmass_batch(TARGET_RAS, TARGET_DECS)
+    assert airmass.shape == (len(TARGET_RAS), len(site.timestamp))
+
+
+def test_matches_calculate_airmass(site: GroundEphemeris) -> None:
+    airmass = site.airmass_batch(np.array(TARGET_RAS), np.array(TARGET_DECS))
+    for i, (ra, dec) in enumerate(zip(TARGET_RAS, TARGET_DECS)):
+        single = np.array(site.calculate_airmass(ra, dec))
+        # Away from the horizon, where refraction is small
+        high = single < 3.0
+        assert high.any()
+        np.testing.assert_allclose(airmass[i, high], single[high], rtol=0.02)
+
+
+def test_infinite_below_horizon(site: GroundEphemeris) -> None:
+    airmass = site.airmass_batch([0.0], [-89.0])
+    assert np.all(np.isinf(airmass))
+
+
+def test_time_indices(site: GroundEphemeris) -> None:
+    full = site.airmass_batch(TARGET_RAS, TARGET_DECS)
+    subset = site.airmass_batch(TARGET_RAS, TARGET_DECS, time_indices=[0, 3])
+    np.testing.assert_allclose(subset, full[:, [0, 3]])
+
+
+def test_no_targets(site: GroundEphemeris) -> None:
+    airmass = site.airmass_batch([], [])
+    assert airmass.shape == (0, len(site.timestamp))
+
+
+def test_length_mismatch_raises(site: GroundEphemeris) -> None:
+    with pytest.raises(ValueError, match="same length"):
+        site.airmass_batch([0.0, 1.0], [0.0])
+
+
+def test_time_index_out_of_range_raises(site: GroundEphemeris) -> None:
+    with pytest.raises(IndexError):
+        site.airmass_batch(TARGET_RAS, TARGET_DECS, time_indices=[10_000])
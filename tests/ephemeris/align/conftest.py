@@ -0,0 +1,36 @@
+"""Fixtures for ephemeris alignment tests."""
+
+import math
+from datetime import datetime, timedelta, timezone
+
+import numpy as np
+import numpy.typing as npt
+
+BEGIN = datetime(2024, 1, 1, 0, 0, 0, tzinfo=timezone.utc)
+STEP = 60
+N_POINTS = 31
+
+# Circular equatorial orbit
+RADIUS_KM = 7000.0
+MU_EARTH = 398600.4418
+OMEGA = math.sqrt(MU_EARTH / RADIUS_KM**3)
+
+
+def sample_times(offset: float = 0.0, n: int = N_POINTS) -> list[datetime]:
+    return [BEGIN + timedelta(seconds=offset + STEP * k) for k in range(n)]
+
+
+def circular_states(times: list[datetime]) -> npt.NDArray[np.float64]:
+    """GCRS states (km, km/s) on a circular orbit"""
+    t = np.array([(time - BEGIN).total_seconds() for time in times])
+    v = RADIUS_KM * OMEGA
+    return np.column_stack(
+        [
+            RADIUS_KM * np.cos(OMEGA * t),
+            RADIUS_KM * np.sin(OMEGA * t),
+            np.zeros_like(t),
+            -v * np.sin(OMEGA * t),
+            v * np.cos(OMEGA * t),
+            np.zeros_like(t),
+        ]
+    )
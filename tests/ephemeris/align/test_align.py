@@ -0,0 +1,50 @@
+"""Tests for align() onto a common time grid"""
+
+from datetime import timedelta
+
+import numpy as np
+import pytest
+
+from rust_ephem import ArrayEphemeris, align
+
+from .conftest import BEGIN, N_POINTS, STEP, circular_states, sample_times
+
+
+def make(offset: float = 0.0, n: int = N_POINTS) -> ArrayEphemeris:
+    times = sample_times(offset, n)
+    return ArrayEphemeris(times, circular_states(times))
+
+
+def test_default_grid_is_first_times_in_overlap() -> None:
+    first = make()
+    second = make(offset=30.0)
+    a, b = align(first, second)
+    assert isinstance(a, ArrayEphemeris)
+    assert isinstance(b, ArrayEphemeris)
+    # The second starts 30 s later, so the first's initial sample is dropped
+    expected = sample_times()[1:]
+    assert list(a.timestamp) == expected
+    assert list(b.timestamp) == expected
+
+
+def test_interpolated_states() -> None:
+    a, b = align(make(), make(offset=30.0))
+    truth = circular_states(list(b.timestamp))
+    np.testing.assert_allclose(a.gcrs_pv.position, truth[:, :3], atol=1e-6)
+    np.testing.assert_allclose(b.gcrs_pv.position, truth[:, :3], atol=1e-2)
+    np.testing.assert_allclose(b.gcrs_pv.velocity, truth[:, 3:], atol=1e-5)
+
+
+def test_step_size_grid() -> None:
+    a, b = align(make(), make(offset=30.0), step_size=10)
+    times = list(a.timestamp)
+    assert times[0] == BEGIN + timedelta(seconds=30)
+    assert times[-1] == BEGIN + timedelta(seconds=STEP * (N_POINTS - 1))
+    assert all(t1 - t0 == timedelta(seconds=10) for t0, t1 in zip(times, times[1:]))
+    assert list(b.timestamp) == times
+
+
+def test_no_overlap() -> None:
+    later = make(offset=STEP * N_POINTS)
+    with pytest.raises(ValueError, match="do not overlap"):
+        align(make(), later)
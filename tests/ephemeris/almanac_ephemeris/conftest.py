@@ -0,0 +1,91 @@
+"""Fixtures for almanac_ephemeris tests."""
+
+import math
+from datetime import datetime, timezone
+from typing import Any
+
+import pytest
+
+BEGIN = datetime(2024, 1, 1, 0, 0, 0, tzinfo=timezone.utc)
+END = datetime(2024, 1, 1, 2, 0, 0, tzinfo=timezone.utc)
+STEP_SIZE = 600
+
+# GPS week 2295 (starting 2023-12-31) broadcast modulo 1024
+ALMANAC_WEEK = 247
+ALMANAC_TOA = 61440.0
+
+# PRN -> (health, e, i [rad], OMEGA_DOT [rad/s], sqrt(A) [m^1/2],
+#         OMEGA0 [rad], omega [rad], M0 [rad], af0 [s], af1 [s/s])
+# fmt: off
+ALMANAC_ELEMENTS: dict[int, tuple[float, ...]] = {
+    1: (0, 0.01154422760, 0.9874515300, -7.657461295e-9, 5153.613770,
+        0.8782711029, 0.878271103, -2.487392340, 4.959106445e-4, 3.637978807e-12),
+    2: (0, 0.02007770538, 0.9563442945, -7.874613724e-9, 5153.597168,
+        -1.218045890, -1.761436343, 1.531521440, -2.555847168e-4, 0.0),
+    3: (63, 0.00460529327, 0.9683604240, -7.760612643e-9, 5153.704590,
+        2.917264938, 0.825217247, -0.367186546, 2.384185791e-4, 0.0),
+}
+# fmt: on
+
+
+def yuma_text() -> str:
+    """YUMA almanac for the sample elements"""
+    blocks = []
+    for prn, elements in ALMANAC_ELEMENTS.items():
+        health, e, i, odot, sqrt_a, o0, w, m0, af0, af1 = elements
+        blocks.append(
+            f"******** Week {ALMANAC_WEEK} almanac for PRN-{prn:02d} ********\n"
+            f"ID:                         {prn:02d}\n"
+            f"Health:                     {int(health):03d}\n"
+            f"Eccentricity:               {e:.10E}\n"
+            f"Time of Applicability(s):  {ALMANAC_TOA:.4f}\n"
+            f"Orbital Inclination(rad):   {i:.10f}\n"
+            f"Rate of Right Ascen(r/s):  {odot:.10E}\n"
+            f"SQRT(A)  (m 1/2):           {sqrt_a:.6f}\n"
+            f"Right Ascen at Week(rad):   {o0:.10E}\n"
+            f"Argument of Perigee(rad):   {w:.9f}\n"
+            f"Mean Anom(rad):             {m0:.10E}\n"
+            f"Af0(s):                     {af0:.10E}\n"
+            f"Af1(s/s):                   {af1:.10E}\n"
+            f"week:                        {ALMANAC_WEEK}\n"
+        )
+    return "\n".join(blocks)
+
+
+def sem_text() -> str:
+    """SEM almanac for the sample elements (angles in semicircles)"""
+    lines = [
+        f"{len(ALMANAC_ELEMENTS)} CURRENT.ALM",
+        f"{ALMANAC_WEEK} {int(ALMANAC_TOA)}",
+        "",
+    ]
+    for svn, (prn, elements) in enumerate(ALMANAC_ELEMENTS.items(), start=40):
+        health, e, i, odot, sqrt_a, o0, w, m0, af0, af1 = elements
+        lines += [
+            str(prn),
+            str(svn),
+            "0",
+            f" {e:.14E} {i / math.pi - 0.30:.14E} {odot / math.pi:.14E}",
+            f" {sqrt_a:.14E} {o0 / math.pi:.14E} {w / math.pi:.14E}",
+            f" {m0 / math.pi:.14E} {af0:.14E} {af1:.14E}",
+            str(int(health)),
+            "9",
+            "",
+        ]
+    return "\n".join(lines)
+
+
+@pytest.fixture
+def yuma_path(tmp_path: Any) -> str:
+    """Temporary YUMA almanac file"""
+    path = tmp_path / "current.alm"
+    path.write_text(yuma_text())
+    return str(path)
+
+
+@pytest.fixture
+def sem_path(tmp_path: Any) -> str:
+    """Temporary SEM almanac file"""
+    path = tmp_path / "current.al3"
+    path.write_text(sem_text())
+    return str(path)
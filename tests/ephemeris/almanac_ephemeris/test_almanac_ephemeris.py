@@ -0,0 +1,86 @@
+"""Tests for GPS almanac (YUMA/SEM) ephemeris"""
+
+from datetime import datetime, timezone
+
+import numpy as np
+import pytest
+
+import rust_ephem
+from rust_ephem import AlmanacEphemeris, Ephemeris
+
+from .conftest import ALMANAC_ELEMENTS, BEGIN, END, STEP_SIZE
+
+GPS_RADIUS_KM = 26_560.0
+
+
+def test_almanac_ephemeris_yuma(yuma_path: str) -> None:
+    eph = AlmanacEphemeris(yuma_path, 1, BEGIN, END, step_size=STEP_SIZE)
+
+    assert isinstance(eph, Ephemeris)
+    assert eph.almanac_format == "yuma"
+    assert eph.prn == 1
+    assert eph.health == 0
+    assert len(eph.timestamp) == 13
+
+    radius = np.linalg.norm(eph.gcrs_pv.position, axis=1)
+    assert np.all(np.abs(radius - GPS_RADIUS_KM) < 400.0)
+
+    # GPS satellites move at roughly 3.9 km/s inertially
+    speed = np.linalg.norm(eph.gcrs_pv.velocity, axis=1)
+    assert np.all(np.abs(speed - 3.87) < 0.15)
+
+
+def test_almanac_epoch_resolves_week_rollover(yuma_path: str) -> None:
+    eph = AlmanacEphemeris(yuma_path, 1, BEGIN, END, step_size=STEP_SIZE)
+    # Week 247 + 2 rollovers = 2295 (starting 2023-12-31), toa 61440 s; GPS-UTC = 18 s
+    assert eph.almanac_epoch == datetime(2023, 12, 31, 17, 3, 42, tzinfo=timezone.utc)
+    assert eph.elements["toa"] == pytest.approx(61440.0)
+    assert eph.elements["inclination"] == pytest.approx(ALMANAC_ELEMENTS[1][2])
+
+
+def test_sem_matches_yuma(yuma_path: str, sem_path: str) -> None:
+    yuma = AlmanacEphemeris(yuma_path, 2, BEGIN, END, step_size=STEP_SIZE)
+    sem = AlmanacEphemeris(sem_path, 2, BEGIN, END, step_size=STEP_SIZE)
+
+    assert sem.almanac_format == "sem"
+    np.testing.assert_allclose(
+        sem.itrs_pv.position, yuma.itrs_pv.position, rtol=0, atol=1e-3
+    )
+
+
+def test_explicit_format(sem_path: str) -> None:
+    eph = AlmanacEphemeris(sem_path, 1, BEGIN, END, step_size=STEP_SIZE, format="SEM")
+    assert eph.almanac_format == "sem"
+
+    with pytest.raises(ValueError, match="Unknown almanac format"):
+        AlmanacEphemeris(sem_path, 1, BEGIN, END, format="rinex")
+
+
+def test_missing_prn_raises(yuma_path: str) -> None:
+    with pytest.raises(ValueError, match="PRN 32 not found"):
+        AlmanacEphemeris(yuma_path, 32, BEGIN, END)
+
+
+def test_prns(yuma_path: str, sem_path: str) -> None:
+    assert AlmanacEphemeris.prns(yuma_path) == [1, 2, 3]
+    assert AlmanacEphemeris.prns(sem_path) == [1, 2, 3]
+
+
+def test_load_constellation(yuma_path: str) -> None:
+    constellation = AlmanacEphemeris.load_constellation(
+        yuma_path, BEGIN, END, step_size=STEP_SIZE
+    )
+    assert sorted(constellation) == [1, 2, 3]
+    assert all(eph.prn == prn for prn, eph in constellation.items())
+
+    healthy = AlmanacEphemeris.load_constellation(
+        yuma_path, BEGIN, END, step_size=STEP_SIZE, healthy_only=True
+    )
+    assert sorted(healthy) == [1, 2]
+
+
+def test_constraint_evaluation(yuma_path: str) -> None:
+    eph = AlmanacEphemeris(yuma_path, 1, BEGIN, END, step_size=STEP_SIZE)
+    constraint = rust_ephem.EarthLimbConstraint(min_angle=10.0)
+    result = constraint.evaluate(eph, target_ra=0.0, target_dec=90.0)
+    assert len(result.timestamp) == 13
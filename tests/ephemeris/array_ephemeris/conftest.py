@@ -0,0 +1,45 @@
+"""Fixtures for array_ephemeris tests."""
+
+import math
+from datetime import datetime, timedelta, timezone
+
+import numpy as np
+import numpy.typing as npt
+import pytest
+
+BEGIN = datetime(2024, 1, 1, 0, 0, 0, tzinfo=timezone.utc)
+DATA_STEP = 60
+N_POINTS = 61
+
+# Circular equatorial orbit
+RADIUS_KM = 7000.0
+MU_EARTH = 398600.4418
+OMEGA = math.sqrt(MU_EARTH / RADIUS_KM**3)
+
+
+def circular_states(times: list[datetime]) -> npt.NDArray[np.float64]:
+    """Analytic GCRS states (km, km/s) of the circular orbit"""
+    t = np.array([(time - BEGIN).total_seconds() for time in times])
+    v = RADIUS_KM * OMEGA
+    return np.column_stack(
+        [
+            RADIUS_KM * np.cos(OMEGA * t),
+            RADIUS_KM * np.sin(OMEGA * t),
+            np.zeros_like(t),
+            -v * np.sin(OMEGA * t),
+            v * np.cos(OMEGA * t),
+            np.zeros_like(t),
+        ]
+    )
+
+
+@pytest.fixture
+def data_times() -> list[datetime]:
+    """One hour of input times at 60 s spacing"""
+    return [BEGIN + timedelta(seconds=DATA_STEP * k) for k in range(N_POINTS)]
+
+
+@pytest.fixture
+def data_states(data_times: list[datetime]) -> npt.NDArray[np.float64]:
+    """Circular-orbit GCRS states at the input times"""
+    return circular_states(data_times)
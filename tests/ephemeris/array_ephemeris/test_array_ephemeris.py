@@ -0,0 +1,97 @@
+"""Tests for ArrayEphemeris (user-supplied state vectors)"""
+
+from datetime import datetime, timedelta
+
+import numpy as np
+import numpy.typing as npt
+import pytest
+
+import rust_ephem
+from rust_ephem import ArrayEphemeris, Ephemeris
+
+from .conftest import BEGIN, N_POINTS, circular_states
+
+
+def test_input_times_used_as_is(
+    data_times: list[datetime], data_states: npt.NDArray[np.float64]
+) -> None:
+    eph = ArrayEphemeris(data_times, data_states)
+
+    assert isinstance(eph, Ephemeris)
+    assert eph.source_frame == "GCRS"
+    assert len(eph.timestamp) == N_POINTS
+    np.testing.assert_allclose(eph.gcrs_pv.position, data_states[:, :3])
+    np.testing.assert_allclose(eph.gcrs_pv.velocity, data_states[:, 3:])
+    assert eph.source_timestamp == data_times
+
+
+def test_resampled_grid_is_interpolated(
+    data_times: list[datetime], data_states: npt.NDArray[np.float64]
+) -> None:
+    begin = BEGIN + timedelta(seconds=15)
+    end = BEGIN + timedelta(minutes=50)
+    eph = ArrayEphemeris(data_times, data_states, begin, end, step_size=30)
+
+    grid = [begin + timedelta(seconds=30 * k) for k in range(len(eph.timestamp))]
+    expected = circular_states(grid)
+    assert eph.begin == begin
+    assert eph.step_size == 30
+    np.testing.assert_allclose(eph.gcrs_pv.position, expected[:, :3], atol=1e-3)
+    np.testing.assert_allclose(eph.gcrs_pv.velocity, expected[:, 3:], atol=1e-6)
+    assert len(eph.source_pv.position) == N_POINTS
+
+
+def test_numpy_datetime64_times(
+    data_times: list[datetime], data_states: npt.NDArray[np.float64]
+) -> None:
+    reference = ArrayEphemeris(data_times, data_states)
+    eph = ArrayEphemeris(reference.timestamp, data_states)
+    np.testing.assert_allclose(eph.gcrs_pv.position, reference.gcrs_pv.position)
+
+
+def test_itrs_roundtrip(
+    data_times: list[datetime], data_states: npt.NDArray[np.float64]
+) -> None:
+    gcrs = ArrayEphemeris(data_times, data_states)
+    itrs_states = np.hstack([gcrs.itrs_pv.position, gcrs.itrs_pv.velocity])
+
+    itrs = ArrayEphemeris(data_times, itrs_states, frame="itrs")
+    assert itrs.source_frame == "ITRS"
+    np.testing.assert_allclose(itrs.gcrs_pv.position, data_states[:, :3], atol=1e-6)
+
+
+def test_teme_matches_tle_ephemeris(tle_ephemeris: rust_ephem.TLEEphemeris) -> None:
+    tle = tle_ephemeris
+    teme = np.hstack([tle.teme_pv.position, tle.teme_pv.velocity])
+
+    eph = ArrayEphemeris(tle.timestamp, teme, frame="TEME")
+    np.testing.assert_allclose(eph.gcrs_pv.position, tle.gcrs_pv.position, atol=1e-6)
+    np.testing.assert_allclose(eph.itrs_pv.position, tle.itrs_pv.position, atol=1e-6)
+
+
+def test_invalid_inputs(
+    data_times: list[datetime], data_states: npt.NDArray[np.float64]
+) -> None:
+    with pytest.raises(ValueError, match="does not match number of times"):
+        ArrayEphemeris(data_times[:-1], data_states)
+
+    with pytest.raises(ValueError, match=r"shape \(N, 6\)"):
+        ArrayEphemeris(data_times, data_states[:, :3])
+
+    with pytest.raises(ValueError, match="strictly increasing"):
+        ArrayEphemeris(data_times[::-1], data_states)
+
+    with pytest.raises(ValueError, match="Unsupported frame"):
+        ArrayEphemeris(data_times, data_states, frame="ECI")
+
+    with pytest.raises(ValueError, match="exceeds data range"):
+        ArrayEphemeris(data_times, data_states, end=BEGIN + timedelta(hours=2))
+
+
+def test_constraint_evaluation(
+    data_times: list[datetime], data_states: npt.NDArray[np.float64]
+) -> None:
+    eph = ArrayEphemeris(data_times, data_states)
+    constraint = rust_ephem.SunConstraint(min_angle=45.0)
+    result = constraint.evaluate(eph, target_ra=0.0, target_dec=0.0)
+    assert len(result.timestamp) == N_POINTS
@@ -0,0 +1,44 @@
+"""Fixtures for stratospheric balloon tests."""
+
+from datetime import datetime, timedelta, timezone
+
+import pytest
+
+from rust_ephem import TrajectoryGroundEphemeris
+
+BEGIN = datetime(2025, 12, 20, 0, 0, 0, tzinfo=timezone.utc)
+END = BEGIN + timedelta(days=1)
+# Launch site near McMurdo Station
+LATITUDE = -77.85
+LONGITUDE = 166.67
+ALTITUDE_KM = 35.0
+# Westward circumpolar drift
+LONGITUDE_RATE = -0.5
+# Target that culminates ~2° above the balloon's horizon
+TARGET_RA = 83.63
+TARGET_DEC = 10.0
+
+
+@pytest.fixture
+def balloon() -> TrajectoryGroundEphemeris:
+    """Balloon drifting for a day, sampled every 10 minutes"""
+    return TrajectoryGroundEphemeris.balloon(
+        LATITUDE,
+        LONGITUDE,
+        BEGIN,
+        END,
+        600,
+        altitude_km=ALTITUDE_KM,
+        longitude_rate=LONGITUDE_RATE,
+    )
+
+
+@pytest.fixture
+def sea_level_track(balloon: TrajectoryGroundEphemeris) -> TrajectoryGroundEphemeris:
+    """The balloon's track with the sea-level airmass formula"""
+    return TrajectoryGroundEphemeris(
+        balloon.timestamp,
+        balloon.source_latitude,
+        balloon.source_longitude,
+        balloon.source_height,
+    )
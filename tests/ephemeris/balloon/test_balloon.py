@@ -0,0 +1,137 @@
+"""Tests for stratospheric balloon observers."""
+
+import numpy as np
+import pytest
+
+from rust_ephem import Constraint, TrajectoryGroundEphemeris
+
+from .conftest import (
+    ALTITUDE_KM,
+    BEGIN,
+    END,
+    LATITUDE,
+    LONGITUDE,
+    LONGITUDE_RATE,
+    TARGET_DEC,
+    TARGET_RA,
+)
+
+
+class TestBalloonTrack:
+    def test_drift(self, balloon: TrajectoryGroundEphemeris) -> None:
+        assert len(balloon) == 145
+        np.testing.assert_allclose(balloon.latitude_deg, LATITUDE)
+        np.testing.assert_allclose(balloon.height_m, ALTITUDE_KM * 1000.0)
+        # Ten minutes of drift per step, wrapped across the antimeridian
+        expected = (LONGITUDE + LONGITUDE_RATE * np.arange(145) / 6 + 180) % 360 - 180
+        np.testing.assert_allclose(balloon.longitude_deg, expected, atol=1e-9)
+
+    def test_standard_atmosphere(self, balloon: TrajectoryGroundEphemeris) -> None:
+        assert balloon.atmosphere == "standard"
+        assert balloon.metadata["atmosphere"] == "standard"
+
+    def test_sea_level_default(
+        self, sea_level_track: TrajectoryGroundEphemeris
+    ) -> None:
+        assert sea_level_track.atmosphere == "sea_level"
+
+    def test_atmosphere_changes_fingerprint(
+        self,
+        balloon: TrajectoryGroundEphemeris,
+        sea_level_track: TrajectoryGroundEphemeris,
+    ) -> None:
+        assert balloon.fingerprint() != sea_level_track.fingerprint()
+
+
+class TestValidation:
+    def test_altitude_range(self) -> None:
+        with pytest.raises(ValueError, match="altitude_km"):
+            TrajectoryGroundEphemeris.balloon(
+                LATITUDE, LONGITUDE, BEGIN, END, altitude_km=100.0
+            )
+
+    def test_drift_past_pole(self) -> None:
+        with pytest.raises(ValueError, match="past a pole"):
+            TrajectoryGroundEphemeris.balloon(
+                LATITUDE, LONGITUDE, BEGIN, END, latitude_rate=-1.0
+            )
+
+    def test_unknown_atmosphere(self) -> None:
+        with pytest.raises(ValueError, match="Invalid atmosphere"):
+            TrajectoryGroundEphemeris(
+                [BEGIN, END], [0.0, 0.0], [0.0, 0.0], [0.0, 0.0], atmosphere="mars"
+            )
+
+    def test_height_above_standard_atmosphere(self) -> None:
+        with pytest.raises(ValueError, match="below 86 km"):
+            TrajectoryGroundEphemeris(
+                [BEGIN, END],
+                [0.0, 0.0],
+                [0.0, 0.0],
+                [90000.0, 90000.0],
+                atmosphere="standard",
+            )
+
+
+class TestAirmass:
+    def test_matches_sea_level_high_in_sky(
+        self,
+        balloon: TrajectoryGroundEphemeris,
+        sea_level_track: TrajectoryGroundEphemeris,
+    ) -> None:
+        # A target near the south celestial pole stays ~78° up
+        airmass = np.array(balloon.calculate_airmass(0.0, -89.0))
+        sea_level = np.array(sea_level_track.calculate_airmass(0.0, -89.0))
+        np.testing.assert_allclose(airmass, sea_level, rtol=0.01)
+
+    def test_finite_below_horizon(
+        self,
+        balloon: TrajectoryGroundEphemeris,
+        sea_level_track: TrajectoryGroundEphemeris,
+    ) -> None:
+        altitude = balloon.radec_to_altaz(TARGET_RA, TARGET_DEC)[:, 0]
+        airmass = np.array(balloon.calculate_airmass(TARGET_RA, TARGET_DEC))
+        sea_level = np.array(sea_level_track.calculate_airmass(TARGET_RA, TARGET_DEC))
+        below = (altitude < 0.0) & (altitude > -5.0)
+        assert below.any()
+        assert np.isfinite(airmass[below]).all()
+        assert (airmass[below] > 30.0).all()
+        assert np.isinf(sea_level[below]).all()
+        # Past the Earth's limb the line of sight meets the ground
+        assert np.isinf(airmass[altitude < -7.0]).all()
+
+    def test_airmass_constraint(self, balloon: TrajectoryGroundEphemeris) -> None:
+        altitude = balloon.radec_to_altaz(TARGET_RA, TARGET_DEC)[:, 0]
+        airmass = np.array(balloon.calculate_airmass(TARGET_RA, TARGET_DEC))
+        result = Constraint.airmass(max_airmass=1000.0).evaluate(
+            balloon, TARGET_RA, TARGET_DEC
+        )
+        violated = np.array(result.constraint_array)
+        np.testing.assert_array_equal(violated, airmass > 1000.0)
+        assert not violated[altitude > -1.0].any()
+
+
+class TestRefraction:
+    def test_small_at_float_altitude(
+        self,
+        balloon: TrajectoryGroundEphemeris,
+        sea_level_track: TrajectoryGroundEphemeris,
+    ) -> None:
+        altitude = balloon.radec_to_altaz(TARGET_RA, TARGET_DEC)[:, 0]
+        refraction = np.array(balloon.calculate_refraction(TARGET_RA, TARGET_DEC))
+        up = altitude > 0.5
+        assert up.any()
+        assert (refraction[up] > 0.0).all()
+        # A few arcseconds at most, against half a degree at sea level
+        assert (refraction[up] < 0.01).all()
+        assert np.isnan(refraction[altitude < -7.0]).all()
+
+    def test_sea_level_height(self) -> None:
+        ephem = TrajectoryGroundEphemeris(
+            [BEGIN, END], [LATITUDE] * 2, [LONGITUDE] * 2, [0.0, 0.0]
+        )
+        altitude = ephem.radec_to_altaz(0.0, -89.0)[:, 0]
+        refraction = np.array(ephem.calculate_refraction(0.0, -89.0))
+        # ~1' / tan(altitude)
+        expected = 0.95 / 60.0 / np.tan(np.radians(altitude))
+        np.testing.assert_allclose(refraction, expected, rtol=0.05)
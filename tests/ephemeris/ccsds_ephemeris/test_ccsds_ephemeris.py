@@ -206,7 +206,7 @@ def test_ccsds_ephemeris_oem_timestamp(sample_oem_path: Any) -> None:
 
 def test_ccsds_ephemeris_invalid_reference_frame(tmp_path: Any) -> None:
     """Test that invalid reference frames are rejected"""
-    # Create OEM with ITRF (Earth-fixed) frame instead of inertial
+    # Create OEM with a true-of-date rotating frame, which is not supported
     oem_content = """CCSDS_OEM_VERS = 2.0
 CREATION_DATE = 2024-01-01T00:00:00.000
 ORIGINATOR = TEST
@@ -215,7 +215,7 @@ META_START
 OBJECT_NAME = TEST_SAT
 OBJECT_ID = 2024-001A
 CENTER_NAME = EARTH
-REF_FRAME = ITRF
+REF_FRAME = TDR
 TIME_SYSTEM = UTC
 START_TIME = 2024-01-01T00:00:00.000
 STOP_TIME = 2024-01-01T01:00:00.000
@@ -233,7 +233,7 @@ META_STOP
     end = datetime(2024, 1, 1, 0, 10, 0)
 
     # Should raise ValueError for unsupported frame
-    with pytest.raises(ValueError, match="Unsupported reference frame 'ITRF'"):
+    with pytest.raises(ValueError, match="Unsupported reference frame 'TDR'"):
         OEMEphemeris(str(oem_path), begin=begin, end=end, step_size=60)
 
 
@@ -0,0 +1,38 @@
+"""Fixtures for ephemeris comparison tests."""
+
+import math
+from datetime import datetime, timedelta, timezone
+
+import numpy as np
+import numpy.typing as npt
+
+BEGIN = datetime(2024, 1, 1, 0, 0, 0, tzinfo=timezone.utc)
+STEP = 60
+N_POINTS = 31
+
+# Circular equatorial orbit
+RADIUS_KM = 7000.0
+MU_EARTH = 398600.4418
+OMEGA = math.sqrt(MU_EARTH / RADIUS_KM**3)
+
+
+def sample_times(offset: float = 0.0, n: int = N_POINTS) -> list[datetime]:
+    return [BEGIN + timedelta(seconds=offset + STEP * k) for k in range(n)]
+
+
+def circular_states(
+    times: list[datetime], radius: float = RADIUS_KM
+) -> npt.NDArray[np.float64]:
+    """GCRS states (km, km/s) on a circular orbit with the reference phase"""
+    t = np.array([(time - BEGIN).total_seconds() for time in times])
+    v = radius * OMEGA
+    return np.column_stack(
+        [
+            radius * np.cos(OMEGA * t),
+            radius * np.sin(OMEGA * t),
+            np.zeros_like(t),
+            -v * np.sin(OMEGA * t),
+            v * np.cos(OMEGA * t),
+            np.zeros_like(t),
+        ]
+    )
@@ -0,0 +1,87 @@
+"""Tests for compare() ephemeris residual analysis"""
+
+from datetime import timedelta
+
+import numpy as np
+import pytest
+
+from rust_ephem import ArrayEphemeris, compare
+
+from .conftest import (
+    BEGIN,
+    N_POINTS,
+    OMEGA,
+    RADIUS_KM,
+    STEP,
+    circular_states,
+    sample_times,
+)
+
+
+def make(times: list, states: np.ndarray) -> ArrayEphemeris:
+    return ArrayEphemeris(times, states)
+
+
+def test_identical_ephemerides() -> None:
+    times = sample_times()
+    ephem = make(times, circular_states(times))
+    result = compare(ephem, ephem)
+    assert len(result) == N_POINTS
+    assert result.position_residuals.shape == (N_POINTS, 3)
+    assert result.position_rms["total"] == pytest.approx(0.0, abs=1e-9)
+    assert result.velocity_max["total"] == pytest.approx(0.0, abs=1e-12)
+
+
+def test_radial_offset() -> None:
+    times = sample_times()
+    reference = make(times, circular_states(times))
+    raised = circular_states(times, RADIUS_KM + 1.0)
+    # Keep the reference velocity so only the position differs
+    raised[:, 3:] = circular_states(times)[:, 3:]
+    result = compare(reference, make(times, raised))
+
+    np.testing.assert_allclose(result.position_residuals[:, 0], 1.0, atol=1e-9)
+    np.testing.assert_allclose(result.position_residuals[:, 1:], 0.0, atol=1e-9)
+    assert result.position_rms["radial"] == pytest.approx(1.0)
+    assert result.position_max["in_track"] == pytest.approx(0.0, abs=1e-9)
+    np.testing.assert_allclose(result.position_error, 1.0, atol=1e-9)
+
+
+def test_in_track_offset() -> None:
+    times = sample_times()
+    reference = make(times, circular_states(times))
+    # Same orbit, 1 second ahead
+    ahead = make(times, circular_states([t + timedelta(seconds=1) for t in times]))
+    result = compare(reference, ahead)
+
+    expected = RADIUS_KM * OMEGA  # km travelled in one second
+    np.testing.assert_allclose(result.position_residuals[:, 1], expected, rtol=1e-4)
+    # Chord of the arc: R (cos(wt) - 1) inward
+    assert abs(result.position_residuals[:, 0]).max() < 1e-2
+    assert result.position_max["cross_track"] == pytest.approx(0.0, abs=1e-9)
+
+
+def test_interpolates_onto_common_grid() -> None:
+    ref_times = sample_times()
+    other_times = sample_times(offset=STEP / 2, n=N_POINTS - 1)
+    reference = make(ref_times, circular_states(ref_times))
+    other = make(other_times, circular_states(other_times))
+
+    # Default grid: reference samples inside the shared span
+    result = compare(reference, other)
+    assert len(result) == N_POINTS - 2
+    assert result.timestamp[0] == BEGIN + timedelta(seconds=STEP)
+    assert result.position_max["total"] < 1e-3
+
+    regular = compare(reference, other, step_size=10)
+    assert regular.timestamp[0] == other_times[0]
+    assert regular.position_max["total"] < 1e-3
+
+
+def test_no_overlap() -> None:
+    times = sample_times()
+    later = sample_times(offset=STEP * N_POINTS * 2)
+    with pytest.raises(ValueError, match="overlap"):
+        compare(
+            make(times, circular_states(times)), make(later, circular_states(later))
+        )
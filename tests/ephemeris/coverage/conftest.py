@@ -0,0 +1,40 @@
+"""Fixtures for coverage grid tests."""
+
+import math
+from datetime import datetime, timedelta, timezone
+
+import numpy as np
+import pytest
+
+from rust_ephem import ArrayEphemeris
+
+BEGIN = datetime(2024, 1, 1, 0, 0, 0, tzinfo=timezone.utc)
+STEP = 60
+
+LATITUDES = [0.0, 10.0, 80.0]
+LONGITUDES = [0.0, 90.0, 180.0]
+
+# Equatorial sub-satellite longitude at each sample, at 7000 km radius
+TRACK = [0.0, 0.0, 180.0, 180.0, 0.0, 180.0, 180.0, 180.0, 0.0]
+RADIUS_KM = 7000.0
+
+
+def equatorial_ephemeris(longitudes: list[float]) -> ArrayEphemeris:
+    times = [BEGIN + timedelta(seconds=STEP * i) for i in range(len(longitudes))]
+    states = np.zeros((len(longitudes), 6))
+    for i, lon in enumerate(longitudes):
+        states[i, 0] = RADIUS_KM * math.cos(math.radians(lon))
+        states[i, 1] = RADIUS_KM * math.sin(math.radians(lon))
+    return ArrayEphemeris(times, states, frame="ITRS")
+
+
+@pytest.fixture
+def spacecraft() -> ArrayEphemeris:
+    """Equatorial ITRS track that jumps between longitudes 0 and 180"""
+    return equatorial_ephemeris(TRACK)
+
+
+@pytest.fixture
+def stationary() -> ArrayEphemeris:
+    """Spacecraft fixed over longitude 90 on the same time grid"""
+    return equatorial_ephemeris([90.0] * len(TRACK))
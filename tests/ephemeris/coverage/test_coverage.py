@@ -0,0 +1,113 @@
+"""Tests for CoverageGrid access, revisit and coverage analysis"""
+
+import math
+from typing import Any
+
+import numpy as np
+import pytest
+
+from rust_ephem import ArrayEphemeris, CoverageGrid, CoverageResult
+
+from .conftest import (
+    BEGIN,
+    LATITUDES,
+    LONGITUDES,
+    STEP,
+    TRACK,
+    equatorial_ephemeris,
+)
+
+
+def test_grid_properties() -> None:
+    grid = CoverageGrid(LATITUDES, LONGITUDES, half_angle=30.0, min_elevation=5.0)
+    assert grid.shape == (3, 3)
+    assert grid.latitudes == LATITUDES
+    assert grid.half_angle == 30.0
+    assert grid.min_elevation == 5.0
+    assert CoverageGrid(np.array([0.0]), np.array([0.0])).half_angle is None
+
+
+def test_access_counts(spacecraft: ArrayEphemeris) -> None:
+    result = CoverageGrid(LATITUDES, LONGITUDES).analyze(spacecraft)
+    assert isinstance(result, CoverageResult)
+    assert result.n_spacecraft == 1
+    np.testing.assert_array_equal(
+        result.access_count, [[3, 0, 2], [3, 0, 2], [0, 0, 0]]
+    )
+    assert result.coverage_fraction[0, 0] == pytest.approx(4 / len(TRACK))
+    assert result.access_seconds[0, 2] == pytest.approx(STEP + 2 * STEP)
+
+
+def test_access_windows(spacecraft: ArrayEphemeris) -> None:
+    result = CoverageGrid(LATITUDES, LONGITUDES).analyze(spacecraft)
+    windows = result.access_windows(0, 2)
+    assert len(windows) == 2
+    assert windows[0].start_time == BEGIN.replace(minute=2)
+    assert windows[1].end_time == BEGIN.replace(minute=7)
+    assert result.access_windows(2, 0) == []
+    with pytest.raises(IndexError):
+        result.access_windows(3, 0)
+
+
+def test_revisit_times(spacecraft: ArrayEphemeris) -> None:
+    result = CoverageGrid(LATITUDES, LONGITUDES).analyze(spacecraft)
+    # Longitude 0 is seen at samples 0-1, 4 and 8
+    assert result.max_revisit_seconds[0, 0] == pytest.approx(4 * STEP)
+    assert result.mean_revisit_seconds[0, 0] == pytest.approx(3.5 * STEP)
+    # Longitude 180 is seen at samples 2-3 and 5-7
+    assert result.max_revisit_seconds[0, 2] == pytest.approx(2 * STEP)
+    # Never or once seen
+    assert math.isnan(result.max_revisit_seconds[0, 1])
+    assert math.isnan(result.mean_revisit_seconds[2, 0])
+
+
+def test_percent_covered(spacecraft: ArrayEphemeris) -> None:
+    result = CoverageGrid(LATITUDES, LONGITUDES).analyze(spacecraft)
+    weights = np.cos(np.radians(LATITUDES))
+    expected = 100.0 * 2 * (weights[0] + weights[1]) / (3 * weights.sum())
+    assert result.percent_covered == pytest.approx(expected)
+
+
+def test_sensor_cone_limits_access(spacecraft: ArrayEphemeris) -> None:
+    # Latitude 10 is about 57 degrees off nadir from the equatorial track
+    result = CoverageGrid(LATITUDES, LONGITUDES, half_angle=30.0).analyze(spacecraft)
+    np.testing.assert_array_equal(result.access_count[0], [3, 0, 2])
+    np.testing.assert_array_equal(result.access_count[1], [0, 0, 0])
+
+
+def test_min_elevation_limits_access(spacecraft: ArrayEphemeris) -> None:
+    # Latitude 10 sees the spacecraft about 23 degrees above the horizon
+    result = CoverageGrid(LATITUDES, LONGITUDES, min_elevation=30.0).analyze(
+        spacecraft
+    )
+    np.testing.assert_array_equal(result.access_count[1], [0, 0, 0])
+
+
+def test_multiple_spacecraft(
+    spacecraft: ArrayEphemeris, stationary: ArrayEphemeris
+) -> None:
+    result = CoverageGrid(LATITUDES, LONGITUDES).analyze([spacecraft, stationary])
+    assert result.n_spacecraft == 2
+    assert result.access_count[0, 1] == 1
+    assert result.coverage_fraction[0, 1] == pytest.approx(1.0)
+
+
+def test_mismatched_timestamps(spacecraft: ArrayEphemeris) -> None:
+    shorter = equatorial_ephemeris(TRACK[:-1])
+    with pytest.raises(ValueError, match="same timestamps"):
+        CoverageGrid(LATITUDES, LONGITUDES).analyze([spacecraft, shorter])
+
+
+@pytest.mark.parametrize(
+    "kwargs",
+    [
+        {"latitudes": [], "longitudes": [0.0]},
+        {"latitudes": [95.0], "longitudes": [0.0]},
+        {"latitudes": [0.0], "longitudes": [400.0]},
+        {"latitudes": [0.0], "longitudes": [0.0], "half_angle": 0.0},
+        {"latitudes": [0.0], "longitudes": [0.0], "min_elevation": 91.0},
+    ],
+)
+def test_invalid_grid(kwargs: dict[str, Any]) -> None:
+    with pytest.raises(ValueError):
+        CoverageGrid(**kwargs)
@@ -0,0 +1 @@
+"""Tests for SATCAT decay date awareness in TLE fetching."""
@@ -0,0 +1,88 @@
+"""Fixtures for decay awareness tests, seeding the TLE and SATCAT caches."""
+
+import json
+from datetime import date
+from pathlib import Path
+from typing import Generator
+
+import pytest
+
+import rust_ephem
+
+# Fake NORAD IDs that will never have real cached TLEs or SATCAT entries
+DECAYED_NORAD_ID = 99995
+ORBITING_NORAD_ID = 99994
+UNLOOKED_NORAD_ID = 99992
+
+# Re-entry a few days after the TLE epoch (2025-10-14)
+DECAY_DATE = date(2025, 10, 20)
+
+
+def checksum(line: str) -> int:
+    """TLE modulo-10 checksum of the first 68 characters"""
+    return sum(int(c) if c.isdigit() else c == "-" for c in line[:68]) % 10
+
+
+def with_checksum(line: str) -> str:
+    return f"{line[:68]}{checksum(line)}"
+
+
+def make_tle(norad_id: int) -> tuple[str, str]:
+    line1 = (
+        f"1 {norad_id}U 04047A   25287.56748435  .00035474  00000+0  70906-3 0  999 "
+    )
+    line2 = (
+        f"2 {norad_id}  20.5535 247.0048 0005179 187.1586 172.8782 15.44937919148530"
+    )
+    return with_checksum(line1), with_checksum(line2)
+
+
+def _seed(
+    norad_id: int, decay_date: date | None, satcat: bool = True
+) -> list[Path]:
+    """Cache a Celestrak download and, with `satcat`, a SATCAT entry"""
+    cache_dir = Path(rust_ephem.get_cache_dir())
+    tle_dir = cache_dir / "celestrak_cache" / str(norad_id)
+    if tle_dir.exists():
+        for f in tle_dir.iterdir():
+            f.unlink()
+    tle_dir.mkdir(parents=True, exist_ok=True)
+    tle_path = tle_dir / "20251014T133711.tle"
+    tle_path.write_text("\n".join(make_tle(norad_id)) + "\n")
+
+    satcat_path = cache_dir / "satcat_cache" / f"{norad_id}.json"
+    satcat_path.unlink(missing_ok=True)
+    if not satcat:
+        return [tle_path, satcat_path]
+    satcat_path.parent.mkdir(parents=True, exist_ok=True)
+    entry = {
+        "norad_id": norad_id,
+        "decay_date": decay_date.isoformat() if decay_date else None,
+    }
+    satcat_path.write_text(json.dumps(entry))
+    return [tle_path, satcat_path]
+
+
+@pytest.fixture
+def decayed_object() -> Generator[int, None, None]:
+    paths = _seed(DECAYED_NORAD_ID, DECAY_DATE)
+    yield DECAYED_NORAD_ID
+    for path in paths:
+        path.unlink(missing_ok=True)
+
+
+@pytest.fixture
+def orbiting_object() -> Generator[int, None, None]:
+    paths = _seed(ORBITING_NORAD_ID, None)
+    yield ORBITING_NORAD_ID
+    for path in paths:
+        path.unlink(missing_ok=True)
+
+
+@pytest.fixture
+def unlooked_object() -> Generator[int, None, None]:
+    """A cached TLE whose object has never been looked up in the SATCAT"""
+    paths = _seed(UNLOOKED_NORAD_ID, None, satcat=False)
+    yield UNLOOKED_NORAD_ID
+    for path in paths:
+        path.unlink(missing_ok=True)
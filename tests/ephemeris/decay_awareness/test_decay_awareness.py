@@ -0,0 +1,88 @@
+"""Tests for SATCAT decay date awareness in TLE fetching."""
+
+import warnings
+from datetime import date, datetime, timezone
+from pathlib import Path
+
+import pytest
+
+import rust_ephem
+
+from .conftest import DECAY_DATE, make_tle
+
+BEFORE_DECAY = datetime(2025, 10, 18, tzinfo=timezone.utc)
+DECAY_DAY = datetime(2025, 10, 20, 12, tzinfo=timezone.utc)
+AFTER_DECAY = datetime(2025, 10, 21, 12, tzinfo=timezone.utc)
+
+
+def record(decay_date: date | None) -> rust_ephem.TLERecord:
+    line1, line2 = make_tle(99993)
+    return rust_ephem.TLERecord(line1=line1, line2=line2, decay_date=decay_date)
+
+
+class TestFetchTLE:
+    def test_decay_date_from_satcat(self, decayed_object: int) -> None:
+        tle = rust_ephem.fetch_tle(norad_id=decayed_object, enforce_source="celestrak")
+        assert tle.decay_date == DECAY_DATE
+
+    def test_no_decay_date_in_orbit(self, orbiting_object: int) -> None:
+        tle = rust_ephem.fetch_tle(norad_id=orbiting_object, enforce_source="celestrak")
+        assert tle.decay_date is None
+
+    def test_cached_tle_skips_lookup(self, unlooked_object: int) -> None:
+        tle = rust_ephem.fetch_tle(norad_id=unlooked_object, enforce_source="celestrak")
+        assert tle.decay_date is None
+        # Only the SATCAT cache is consulted for a cached TLE
+        cache_dir = Path(rust_ephem.get_cache_dir())
+        assert not (cache_dir / "satcat_cache" / f"{unlooked_object}.json").exists()
+
+    def test_record_round_trip(self) -> None:
+        tle = record(DECAY_DATE)
+        restored = rust_ephem.TLERecord.model_validate_json(tle.model_dump_json())
+        assert restored.decay_date == DECAY_DATE
+
+
+class TestTLEEphemeris:
+    def test_range_before_decay(self) -> None:
+        with warnings.catch_warnings():
+            warnings.simplefilter("error")
+            ephem = rust_ephem.TLEEphemeris(
+                tle=record(DECAY_DATE), begin=BEFORE_DECAY, end=DECAY_DAY
+            )
+        assert ephem.decay_date == DECAY_DATE
+
+    def test_range_past_decay_warns(self) -> None:
+        with pytest.warns(UserWarning, match="decayed on 2025-10-20"):
+            rust_ephem.TLEEphemeris(
+                tle=record(DECAY_DATE), begin=BEFORE_DECAY, end=AFTER_DECAY
+            )
+
+    def test_range_past_decay_strict(self) -> None:
+        with pytest.raises(ValueError, match="decayed on 2025-10-20"):
+            rust_ephem.TLEEphemeris(
+                tle=record(DECAY_DATE),
+                begin=BEFORE_DECAY,
+                end=AFTER_DECAY,
+                strict=True,
+            )
+
+    def test_range_after_decay_raises(self) -> None:
+        with pytest.raises(ValueError, match="starts after it"):
+            rust_ephem.TLEEphemeris(
+                tle=record(DECAY_DATE), begin=AFTER_DECAY, end=AFTER_DECAY
+            )
+
+    def test_fetched_by_norad_id(self, decayed_object: int) -> None:
+        with pytest.raises(ValueError, match="starts after it"):
+            rust_ephem.TLEEphemeris(
+                norad_id=decayed_object,
+                enforce_source="celestrak",
+                begin=AFTER_DECAY,
+                end=AFTER_DECAY,
+            )
+
+    def test_direct_lines_not_checked(self) -> None:
+        ephem = rust_ephem.TLEEphemeris(
+            *make_tle(99993), begin=AFTER_DECAY, end=AFTER_DECAY
+        )
+        assert ephem.decay_date is None
@@ -0,0 +1 @@
+"""Tests for TLE and OMM element set validation."""
@@ -0,0 +1,63 @@
+"""Fixtures for element set validation tests."""
+
+from typing import Any
+
+import pytest
+
+LINE1 = "1 25544U 98067A   08264.51782528 -.00002182  00000-0 -11606-4 0  292 "
+LINE2 = "2 25544  51.6416 247.4627 0006703 130.5360 325.0288 15.72125391563537"
+
+
+def checksum(line: str) -> int:
+    """TLE modulo-10 checksum of the first 68 characters"""
+    return sum(int(c) if c.isdigit() else c == "-" for c in line[:68]) % 10
+
+
+def with_checksum(line: str) -> str:
+    return f"{line[:68]}{checksum(line)}"
+
+
+def make_tle(line2: str = LINE2) -> tuple[str, str]:
+    """An ISS TLE from 2008, with line 2 replaced to break a check"""
+    return with_checksum(LINE1), with_checksum(line2)
+
+
+@pytest.fixture
+def iss_tle() -> tuple[str, str]:
+    return make_tle()
+
+
+@pytest.fixture
+def low_perigee_tle() -> tuple[str, str]:
+    """A circular orbit about 75 km up"""
+    return make_tle(LINE2.replace("15.72125391", "16.75000000"))
+
+
+@pytest.fixture
+def bad_inclination_tle() -> tuple[str, str]:
+    """An impossible inclination of 200 degrees"""
+    return make_tle(LINE2.replace(" 51.6416", "200.0000"))
+
+
+@pytest.fixture
+def iss_omm() -> dict[str, Any]:
+    """The same elements as an OMM record with Space-Track style string values"""
+    return {
+        "OBJECT_NAME": "ISS (ZARYA)",
+        "OBJECT_ID": "1998-067A",
+        "EPOCH": "2008-09-20T12:25:40.104192",
+        "MEAN_MOTION": "15.72125391",
+        "ECCENTRICITY": "0.0006703",
+        "INCLINATION": "51.6416",
+        "RA_OF_ASC_NODE": "247.4627",
+        "ARG_OF_PERICENTER": "130.5360",
+        "MEAN_ANOMALY": "325.0288",
+        "EPHEMERIS_TYPE": "0",
+        "CLASSIFICATION_TYPE": "U",
+        "NORAD_CAT_ID": "25544",
+        "ELEMENT_SET_NO": "292",
+        "REV_AT_EPOCH": "56353",
+        "BSTAR": "-0.000011606",
+        "MEAN_MOTION_DOT": "-0.00002182",
+        "MEAN_MOTION_DDOT": "0",
+    }
@@ -0,0 +1,90 @@
+"""Tests for TLE and OMM element set validation."""
+
+import json
+import warnings
+from datetime import datetime, timezone
+from typing import Any
+
+import pytest
+
+import rust_ephem
+
+from .conftest import LINE2, make_tle
+
+BEGIN = datetime(2008, 9, 20, 13, 0, 0, tzinfo=timezone.utc)
+END = datetime(2008, 9, 20, 14, 0, 0, tzinfo=timezone.utc)
+AFTER_EPOCH = datetime(2008, 9, 21, tzinfo=timezone.utc)
+
+
+def fields(issues: list[dict[str, Any]]) -> list[tuple[str, str]]:
+    return [(issue["field"], issue["level"]) for issue in issues]
+
+
+class TestValidateElements:
+    def test_valid_tle(self, iss_tle: tuple[str, str]) -> None:
+        assert rust_ephem.validate_elements(iss_tle, now=AFTER_EPOCH) == []
+        assert rust_ephem.validate_elements("\n".join(iss_tle), now=AFTER_EPOCH) == []
+
+    def test_valid_omm(self, iss_omm: dict[str, Any]) -> None:
+        assert rust_ephem.validate_elements(iss_omm, now=AFTER_EPOCH) == []
+        as_json = json.dumps(iss_omm)
+        assert rust_ephem.validate_elements(as_json, now=AFTER_EPOCH) == []
+
+    def test_bad_checksum(self, iss_tle: tuple[str, str]) -> None:
+        line1, line2 = iss_tle
+        bad = line2[:-1] + str((int(line2[-1]) + 1) % 10)
+        issues = rust_ephem.validate_elements((line1, bad), now=AFTER_EPOCH)
+        assert fields(issues) == [("format", "error")]
+
+    def test_incomplete_omm(self, iss_omm: dict[str, Any]) -> None:
+        del iss_omm["EPOCH"]
+        issues = rust_ephem.validate_elements(iss_omm, now=AFTER_EPOCH)
+        assert fields(issues) == [("format", "error")]
+        assert "EPOCH" in issues[0]["message"]
+
+    def test_bad_inclination(self, bad_inclination_tle: tuple[str, str]) -> None:
+        issues = rust_ephem.validate_elements(bad_inclination_tle, now=AFTER_EPOCH)
+        assert fields(issues) == [("inclination", "error")]
+
+    def test_low_perigee(self, low_perigee_tle: tuple[str, str]) -> None:
+        issues = rust_ephem.validate_elements(low_perigee_tle, now=AFTER_EPOCH)
+        assert fields(issues) == [("mean_motion", "warning")]
+        assert "100 km" in issues[0]["message"]
+
+    def test_perigee_below_surface(self) -> None:
+        tle = make_tle(LINE2.replace("0006703", "5000000"))
+        issues = rust_ephem.validate_elements(tle, now=AFTER_EPOCH)
+        assert fields(issues) == [("mean_motion", "error")]
+
+    def test_epoch_in_future(self, iss_tle: tuple[str, str]) -> None:
+        before = datetime(2008, 9, 1, tzinfo=timezone.utc)
+        issues = rust_ephem.validate_elements(iss_tle, now=before)
+        assert fields(issues) == [("epoch", "warning")]
+
+    def test_large_bstar(self, iss_omm: dict[str, Any]) -> None:
+        iss_omm["BSTAR"] = "0.2"
+        issues = rust_ephem.validate_elements(iss_omm, now=AFTER_EPOCH)
+        assert fields(issues) == [("bstar", "warning")]
+
+
+class TestTLEEphemerisChecks:
+    def test_error_raises(self, bad_inclination_tle: tuple[str, str]) -> None:
+        with pytest.raises(ValueError, match="Invalid element set: Inclination"):
+            rust_ephem.TLEEphemeris(*bad_inclination_tle, begin=BEGIN, end=END)
+
+    def test_warning_is_issued(self, low_perigee_tle: tuple[str, str]) -> None:
+        with pytest.warns(UserWarning, match="Perigee altitude"):
+            rust_ephem.TLEEphemeris(*low_perigee_tle, begin=BEGIN, end=END)
+
+    def test_strict_raises_for_warning(
+        self, low_perigee_tle: tuple[str, str]
+    ) -> None:
+        with pytest.raises(ValueError, match="Perigee altitude"):
+            rust_ephem.TLEEphemeris(
+                *low_perigee_tle, begin=BEGIN, end=END, strict=True
+            )
+
+    def test_sound_elements_do_not_warn(self, iss_tle: tuple[str, str]) -> None:
+        with warnings.catch_warnings():
+            warnings.simplefilter("error")
+            rust_ephem.TLEEphemeris(*iss_tle, begin=BEGIN, end=END)
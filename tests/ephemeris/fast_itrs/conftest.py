@@ -0,0 +1,34 @@
+"""Fixtures for the reduced-accuracy ITRS mode tests."""
+
+from datetime import datetime, timedelta, timezone
+
+import numpy as np
+import pytest
+
+BEGIN = datetime(2025, 3, 1, 0, 0, 0, tzinfo=timezone.utc)
+N_POINTS = 90
+STEP_MINUTES = 1
+
+
+@pytest.fixture
+def times() -> list[datetime]:
+    return [BEGIN + timedelta(minutes=STEP_MINUTES * i) for i in range(N_POINTS)]
+
+
+@pytest.fixture
+def states() -> np.ndarray:
+    """Inclined circular LEO orbit in GCRS"""
+    period = 5554.0
+    t = np.arange(N_POINTS) * STEP_MINUTES * 60.0
+    phase = 2 * np.pi * t / period
+    radius = 6878.0
+    speed = 2 * np.pi * radius / period
+    inclination = np.radians(51.6)
+    out = np.zeros((N_POINTS, 6))
+    out[:, 0] = radius * np.cos(phase)
+    out[:, 1] = radius * np.sin(phase) * np.cos(inclination)
+    out[:, 2] = radius * np.sin(phase) * np.sin(inclination)
+    out[:, 3] = -speed * np.sin(phase)
+    out[:, 4] = speed * np.cos(phase) * np.cos(inclination)
+    out[:, 5] = speed * np.cos(phase) * np.sin(inclination)
+    return out
@@ -0,0 +1,46 @@
+"""Tests for the Earth-rotation-angle-only ITRS mode."""
+
+from datetime import datetime
+
+import numpy as np
+import pytest
+
+from rust_ephem import ArrayEphemeris
+
+# UT1-UTC is under 0.9 s, i.e. 0.004 degrees of Earth rotation
+MAX_SHIFT_DEG = 0.01
+
+
+def test_geodetic_close_to_full_rotation(
+    times: list[datetime], states: np.ndarray
+) -> None:
+    full = ArrayEphemeris(times, states)
+    fast = ArrayEphemeris(times, states, fast_itrs=True)
+    assert fast.fast_itrs is True
+    assert full.fast_itrs is False
+    np.testing.assert_allclose(
+        fast.latitude_deg, full.latitude_deg, atol=MAX_SHIFT_DEG
+    )
+    lon_diff = (fast.longitude_deg - full.longitude_deg + 180.0) % 360.0 - 180.0
+    assert np.max(np.abs(lon_diff)) < MAX_SHIFT_DEG
+
+
+def test_gcrs_unchanged(times: list[datetime], states: np.ndarray) -> None:
+    full = ArrayEphemeris(times, states)
+    fast = ArrayEphemeris(times, states, fast_itrs=True)
+    np.testing.assert_array_equal(fast.gcrs_pv.position, full.gcrs_pv.position)
+
+
+def test_recorded_in_metadata_and_fingerprint(
+    times: list[datetime], states: np.ndarray
+) -> None:
+    full = ArrayEphemeris(times, states)
+    fast = ArrayEphemeris(times, states, fast_itrs=True)
+    assert fast.metadata["fast_itrs"] is True
+    assert full.metadata["fast_itrs"] is False
+    assert fast.fingerprint() != full.fingerprint()
+
+
+def test_rejects_polar_motion(times: list[datetime], states: np.ndarray) -> None:
+    with pytest.raises(ValueError, match="polar_motion"):
+        ArrayEphemeris(times, states, fast_itrs=True, polar_motion=True)
@@ -156,3 +156,23 @@ def no_epoch_file(tmp_path: Any) -> str:
     p = tmp_path / "orbit_no_epoch.txt"
     p.write_text("\n".join(lines))
     return str(p)
+
+
+@pytest.fixture
+def csv_file(tmp_path: Any) -> str:
+    """CSV table with a header row, ISO timestamps and metre units."""
+    rows = []
+    for t, x, y, z, vx, vy, vz in _ROWS:
+        ts = EPOCH + timedelta(seconds=t)
+        rows.append(
+            f"{x * 1000:.1f},{y * 1000:.1f},{z * 1000:.1f},"
+            f"{vx * 1000:.2f},{vy * 1000:.2f},{vz * 1000:.2f},"
+            f"{ts.strftime('%Y-%m-%d %H:%M:%S')}"
+        )
+    lines = [
+        "X_m,Y_m,Z_m,VX_mps,VY_mps,VZ_mps,UTC",
+        *rows,
+    ]
+    p = tmp_path / "orbit.csv"
+    p.write_text("\n".join(lines))
+    return str(p)
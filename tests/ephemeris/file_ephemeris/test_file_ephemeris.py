@@ -293,6 +293,79 @@ def test_comment_lines_skipped(tmp_path: Any) -> None:
     assert eph.file_pv.position.shape[0] == 7
 
 
+# ── Table layout ──────────────────────────────────────────────────────────────
+
+_CSV_COLUMNS = {
+    "time": "UTC",
+    "x": "X_m",
+    "y": "Y_m",
+    "z": "Z_m",
+    "vx": "VX_mps",
+    "vy": "VY_mps",
+    "vz": "VZ_mps",
+}
+
+
+def test_csv_columns_by_name(csv_file: str, seconds_file: str) -> None:
+    eph = FileEphemeris(
+        csv_file,
+        begin=BEGIN,
+        end=END,
+        step_size=STEP,
+        position_unit="m",
+        velocity_unit="m/s",
+        columns=_CSV_COLUMNS,
+    )
+    reference = FileEphemeris(seconds_file, begin=BEGIN, end=END, step_size=STEP)
+    assert eph.file_pv.position.shape[0] == 7
+    np.testing.assert_allclose(
+        eph.gcrs_pv.position, reference.gcrs_pv.position, atol=1e-2
+    )
+
+
+def test_csv_columns_by_index(csv_file: str) -> None:
+    eph = FileEphemeris(
+        csv_file,
+        begin=BEGIN,
+        end=END,
+        step_size=STEP,
+        position_unit="m",
+        velocity_unit="m/s",
+        columns={"time": 6, "x": 0, "y": 1, "z": 2, "vx": 3, "vy": 4, "vz": 5},
+    )
+    assert eph.file_pv.position[0, 0] == pytest.approx(7000.0)
+
+
+def test_explicit_delimiter(tmp_path: Any) -> None:
+    lines = [
+        "ScenarioEpoch  2024-01-01T00:00:00",
+        "0.0;7000.0;0.0;0.0;0.0;7.5;0.0",
+        "3600.0;6640.0;25500.0;0.0;-1.9;6.82;0.0",
+    ]
+    p = tmp_path / "semicolon.txt"
+    p.write_text("\n".join(lines))
+    eph = FileEphemeris(str(p), begin=BEGIN, end=END, step_size=STEP, delimiter=";")
+    assert eph.file_pv.position.shape[0] == 2
+
+
+def test_missing_header_column_raises(csv_file: str) -> None:
+    with pytest.raises(ValueError, match="No header row found"):
+        FileEphemeris(
+            csv_file,
+            begin=BEGIN,
+            end=END,
+            step_size=STEP,
+            columns={**_CSV_COLUMNS, "vz": "VZ_kms"},
+        )
+
+
+def test_unknown_column_key_raises(csv_file: str) -> None:
+    with pytest.raises(ValueError, match="Unknown column key"):
+        FileEphemeris(
+            csv_file, begin=BEGIN, end=END, step_size=STEP, columns={"epoch": 6}
+        )
+
+
 # ── Error cases ───────────────────────────────────────────────────────────────
 
 
@@ -0,0 +1,25 @@
+"""Fixtures for ephemeris fingerprint tests."""
+
+from datetime import datetime, timedelta, timezone
+
+import numpy as np
+import pytest
+
+from rust_ephem import ArrayEphemeris
+
+BEGIN = datetime(2024, 1, 1, 0, 0, 0, tzinfo=timezone.utc)
+END = datetime(2024, 1, 1, 1, 0, 0, tzinfo=timezone.utc)
+N_POINTS = 5
+
+
+def make_array_ephemeris(x_km: float = 7000.0) -> ArrayEphemeris:
+    times = [BEGIN + timedelta(minutes=i) for i in range(N_POINTS)]
+    states = np.zeros((N_POINTS, 6))
+    states[:, 0] = x_km
+    states[:, 4] = 7.5
+    return ArrayEphemeris(times, states)
+
+
+@pytest.fixture
+def array_ephemeris() -> ArrayEphemeris:
+    return make_array_ephemeris()
@@ -0,0 +1,39 @@
+"""Tests for ephemeris fingerprints, equality and hashing."""
+
+from rust_ephem import ArrayEphemeris, GroundEphemeris
+
+from .conftest import BEGIN, END, make_array_ephemeris
+
+
+def test_same_inputs_share_fingerprint(array_ephemeris: ArrayEphemeris) -> None:
+    other = make_array_ephemeris()
+    fingerprint = array_ephemeris.fingerprint()
+    assert len(fingerprint) == 32
+    assert fingerprint == other.fingerprint()
+    assert array_ephemeris == other
+    assert hash(array_ephemeris) == hash(other)
+
+
+def test_different_states_differ(array_ephemeris: ArrayEphemeris) -> None:
+    other = make_array_ephemeris(x_km=7100.0)
+    assert array_ephemeris.fingerprint() != other.fingerprint()
+    assert array_ephemeris != other
+
+
+def test_options_and_grid_change_fingerprint() -> None:
+    site = GroundEphemeris(35.0, -120.0, 500.0, BEGIN, END, 600)
+    assert site == GroundEphemeris(35.0, -120.0, 500.0, BEGIN, END, 600)
+    assert site != GroundEphemeris(35.0, -120.0, 500.0, BEGIN, END, 300)
+    assert site != GroundEphemeris(
+        35.0, -120.0, 500.0, BEGIN, END, 600, polar_motion=True
+    )
+
+
+def test_usable_as_cache_key(array_ephemeris: ArrayEphemeris) -> None:
+    cache = {array_ephemeris: "result"}
+    assert cache[make_array_ephemeris()] == "result"
+
+
+def test_not_equal_to_other_objects(array_ephemeris: ArrayEphemeris) -> None:
+    assert array_ephemeris != "ephemeris"
+    assert array_ephemeris != GroundEphemeris(35.0, -120.0, 500.0, BEGIN, END, 600)
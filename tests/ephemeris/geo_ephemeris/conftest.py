@@ -0,0 +1,18 @@
+"""Fixtures for GEOEphemeris tests"""
+
+from datetime import datetime, timedelta, timezone
+
+import pytest
+
+from rust_ephem import GEOEphemeris
+
+BEGIN = datetime(2024, 1, 1, tzinfo=timezone.utc)
+END = BEGIN + timedelta(hours=6)
+STEP_SIZE = 600
+SLOT_LONGITUDE = -75.0
+GEO_RADIUS_KM = 42164.17
+
+
+@pytest.fixture
+def slot() -> GEOEphemeris:
+    return GEOEphemeris(SLOT_LONGITUDE, BEGIN, END, STEP_SIZE)
@@ -0,0 +1,83 @@
+"""Tests for GEOEphemeris (ideal geostationary slot)"""
+
+import numpy as np
+import pytest
+
+import rust_ephem
+from rust_ephem import ArrayEphemeris, Ephemeris, GEOEphemeris
+
+from .conftest import BEGIN, END, GEO_RADIUS_KM, SLOT_LONGITUDE, STEP_SIZE
+
+
+def test_slot_properties(slot: GEOEphemeris) -> None:
+    assert isinstance(slot, ArrayEphemeris)
+    assert isinstance(slot, Ephemeris)
+    assert slot.slot_longitude_deg == SLOT_LONGITUDE
+    assert slot.slot_latitude_deg == 0.0
+    assert slot.radius_km == pytest.approx(GEO_RADIUS_KM)
+    assert len(slot) == 37
+    assert slot.source_frame == "ITRS"
+
+
+def test_fixed_in_earth_frame(slot: GEOEphemeris) -> None:
+    itrs = slot.itrs_pv.position
+    np.testing.assert_allclose(itrs, np.repeat(itrs[:1], len(itrs), axis=0))
+    np.testing.assert_allclose(np.asarray(slot.longitude_deg), SLOT_LONGITUDE)
+    np.testing.assert_allclose(np.asarray(slot.latitude_deg), 0.0, atol=1e-9)
+
+
+def test_geostationary_orbit(slot: GEOEphemeris) -> None:
+    gcrs = slot.gcrs_pv
+    np.testing.assert_allclose(
+        np.linalg.norm(gcrs.position, axis=1), GEO_RADIUS_KM, rtol=1e-6
+    )
+    # Co-rotating with the Earth at ~3.07 km/s
+    np.testing.assert_allclose(np.linalg.norm(gcrs.velocity, axis=1), 3.0747, atol=1e-3)
+    # A quarter of a sidereal day moves the inertial position by ~90 degrees
+    start, end = gcrs.position[0], gcrs.position[-1]
+    angle = np.degrees(
+        np.arccos(np.dot(start, end) / (np.linalg.norm(start) * np.linalg.norm(end)))
+    )
+    assert angle == pytest.approx(90.25, abs=0.1)
+
+
+def test_east_longitudes_are_wrapped() -> None:
+    slot = GEOEphemeris(285.0, BEGIN, END, STEP_SIZE)
+    assert slot.slot_longitude_deg == pytest.approx(SLOT_LONGITUDE)
+
+
+def test_latitude_and_radius() -> None:
+    slot = GEOEphemeris(
+        SLOT_LONGITUDE, BEGIN, END, STEP_SIZE, latitude_deg=0.5, radius_km=42200.0
+    )
+    np.testing.assert_allclose(np.asarray(slot.latitude_deg), 0.5, atol=0.01)
+    np.testing.assert_allclose(
+        np.linalg.norm(slot.itrs_pv.position, axis=1), 42200.0, rtol=1e-9
+    )
+
+
+def test_metadata_and_repr(slot: GEOEphemeris) -> None:
+    assert slot.metadata["type"] == "GEOEphemeris"
+    assert slot.metadata["longitude"] == SLOT_LONGITUDE
+    assert repr(slot).startswith("GEOEphemeris(longitude=-75.0, latitude=0.0")
+    other = GEOEphemeris(SLOT_LONGITUDE + 1.0, BEGIN, END, STEP_SIZE)
+    assert slot != other
+    assert slot == GEOEphemeris(SLOT_LONGITUDE, BEGIN, END, STEP_SIZE)
+
+
+def test_constraints_accept_geo_ephemeris(slot: GEOEphemeris) -> None:
+    result = rust_ephem.Constraint.sun_proximity(45.0).evaluate(slot, 0.0, 0.0)
+    assert len(result.timestamp) == len(slot)
+
+
+@pytest.mark.parametrize(
+    ("longitude", "kwargs"),
+    [
+        (400.0, {}),
+        (0.0, {"latitude_deg": 95.0}),
+        (0.0, {"radius_km": 6000.0}),
+    ],
+)
+def test_invalid_arguments(longitude: float, kwargs: dict[str, float]) -> None:
+    with pytest.raises(ValueError):
+        GEOEphemeris(longitude, BEGIN, END, STEP_SIZE, **kwargs)
@@ -0,0 +1,24 @@
+"""Fixtures for geodetic rate tests."""
+
+import datetime
+
+import pytest
+
+from rust_ephem import GroundEphemeris, TLEEphemeris
+
+VALID_TLE1 = "1 25544U 98067A   08264.51782528 -.00002182  00000-0 -11606-4 0  2927"
+VALID_TLE2 = "2 25544  51.6416 247.4627 0006703 130.5360 325.0288 15.72125391563537"
+
+BEGIN = datetime.datetime(2024, 1, 1, 0, 0, 0, tzinfo=datetime.timezone.utc)
+END = datetime.datetime(2024, 1, 1, 1, 30, 0, tzinfo=datetime.timezone.utc)
+STEP_SIZE = 10
+
+
+@pytest.fixture
+def spacecraft() -> TLEEphemeris:
+    return TLEEphemeris(VALID_TLE1, VALID_TLE2, BEGIN, END, STEP_SIZE)
+
+
+@pytest.fixture
+def site() -> GroundEphemeris:
+    return GroundEphemeris(35.0, -120.0, 500.0, BEGIN, END, 600)
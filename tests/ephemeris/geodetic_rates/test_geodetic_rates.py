@@ -0,0 +1,42 @@
+"""Tests for geodetic_rates and ground_speed."""
+
+import numpy as np
+
+from rust_ephem import GroundEphemeris, TLEEphemeris
+
+from .conftest import STEP_SIZE
+
+
+def test_shape(spacecraft: TLEEphemeris) -> None:
+    rates = spacecraft.geodetic_rates
+    assert rates is not None
+    assert rates.shape == (len(spacecraft.timestamp), 3)
+
+
+def test_rates_match_finite_differences(spacecraft: TLEEphemeris) -> None:
+    rates = spacecraft.geodetic_rates
+    assert rates is not None
+    lat_rate = np.gradient(np.asarray(spacecraft.latitude_deg), STEP_SIZE)
+    lon = np.unwrap(np.radians(np.asarray(spacecraft.longitude_deg)))
+    lon_rate = np.degrees(np.gradient(lon, STEP_SIZE))
+    height_rate = np.gradient(np.asarray(spacecraft.height_km), STEP_SIZE)
+    # Skip the one-sided differences at the ends
+    inner = slice(1, -1)
+    np.testing.assert_allclose(rates[inner, 0], lat_rate[inner], atol=1e-4)
+    np.testing.assert_allclose(rates[inner, 1], lon_rate[inner], atol=1e-4)
+    np.testing.assert_allclose(rates[inner, 2], height_rate[inner], atol=1e-3)
+
+
+def test_leo_ground_speed(spacecraft: TLEEphemeris) -> None:
+    speed = spacecraft.ground_speed
+    assert speed is not None
+    # ~7.7 km/s in orbit scaled down to the surface, give or take Earth rotation
+    assert np.all((speed > 6.5) & (speed < 7.5))
+
+
+def test_ground_site_is_stationary(site: GroundEphemeris) -> None:
+    rates = site.geodetic_rates
+    speed = site.ground_speed
+    assert rates is not None and speed is not None
+    np.testing.assert_allclose(rates, 0.0, atol=1e-9)
+    np.testing.assert_allclose(speed, 0.0, atol=1e-9)
@@ -0,0 +1,48 @@
+"""Fixtures for geomagnetic coordinate tests."""
+
+import math
+from datetime import datetime, timedelta, timezone
+
+import numpy as np
+import pytest
+
+from rust_ephem import ArrayEphemeris
+
+BEGIN = datetime(2025, 1, 1, 12, 0, 0, tzinfo=timezone.utc)
+
+# IGRF-14 northern geomagnetic pole for 2025 (geographic lat, lon in degrees)
+POLE_LATITUDE = 80.8
+POLE_LONGITUDE = -72.8
+
+# Quarter of the Störmer constant for the 2025 dipole (GV)
+EQUATORIAL_CUTOFF_GV = 14.2
+IGRF_REFERENCE_RADIUS_KM = 6371.2
+
+
+def itrs_point(lat_deg: float, lon_deg: float, radius_km: float) -> list[float]:
+    lat, lon = math.radians(lat_deg), math.radians(lon_deg)
+    return [
+        radius_km * math.cos(lat) * math.cos(lon),
+        radius_km * math.cos(lat) * math.sin(lon),
+        radius_km * math.sin(lat),
+    ]
+
+
+# Spacecraft positions (geographic lat, lon, radius km) at each sample: over the
+# geomagnetic pole, over the geographic pole, and twice along one radial line
+# near the geomagnetic equator
+POSITIONS = [
+    (POLE_LATITUDE, POLE_LONGITUDE, 7000.0),
+    (90.0, 0.0, 7000.0),
+    (POLE_LATITUDE - 90.0, POLE_LONGITUDE, 7000.0),
+    (POLE_LATITUDE - 90.0, POLE_LONGITUDE, 14000.0),
+]
+
+
+@pytest.fixture
+def spacecraft() -> ArrayEphemeris:
+    times = [BEGIN + timedelta(minutes=i) for i in range(len(POSITIONS))]
+    states = np.zeros((len(POSITIONS), 6))
+    for i, position in enumerate(POSITIONS):
+        states[i, :3] = itrs_point(*position)
+    return ArrayEphemeris(times, states, frame="ITRS")
@@ -0,0 +1,55 @@
+"""Tests for geomagnetic coordinates and cutoff rigidity on ephemerides."""
+
+import numpy as np
+import pytest
+
+from rust_ephem import ArrayEphemeris
+
+from .conftest import (
+    EQUATORIAL_CUTOFF_GV,
+    IGRF_REFERENCE_RADIUS_KM,
+    POLE_LATITUDE,
+    POSITIONS,
+)
+
+
+def test_one_value_per_timestamp(spacecraft: ArrayEphemeris) -> None:
+    assert len(spacecraft.geomagnetic_latitude_deg) == len(POSITIONS)
+    assert len(spacecraft.geomagnetic_longitude_deg) == len(POSITIONS)
+    assert len(spacecraft.cutoff_rigidity_gv) == len(POSITIONS)
+
+
+def test_over_geomagnetic_pole(spacecraft: ArrayEphemeris) -> None:
+    assert spacecraft.geomagnetic_latitude_deg[0] == pytest.approx(90.0, abs=0.2)
+    assert spacecraft.cutoff_rigidity_gv[0] == pytest.approx(0.0, abs=1e-3)
+
+
+def test_geographic_pole_on_antimeridian(spacecraft: ArrayEphemeris) -> None:
+    assert spacecraft.geomagnetic_latitude_deg[1] == pytest.approx(
+        POLE_LATITUDE, abs=0.1
+    )
+    assert abs(spacecraft.geomagnetic_longitude_deg[1]) == pytest.approx(180.0)
+
+
+def test_rigidity_near_geomagnetic_equator(spacecraft: ArrayEphemeris) -> None:
+    mlat = np.radians(spacecraft.geomagnetic_latitude_deg[2])
+    assert abs(np.degrees(mlat)) < 1.0
+    expected = (
+        EQUATORIAL_CUTOFF_GV
+        * np.cos(mlat) ** 4
+        * (IGRF_REFERENCE_RADIUS_KM / POSITIONS[2][2]) ** 2
+    )
+    assert spacecraft.cutoff_rigidity_gv[2] == pytest.approx(expected, rel=0.01)
+
+
+def test_rigidity_falls_with_distance_squared(spacecraft: ArrayEphemeris) -> None:
+    rigidity = spacecraft.cutoff_rigidity_gv
+    assert rigidity[3] / rigidity[2] == pytest.approx(0.25)
+    np.testing.assert_allclose(
+        spacecraft.geomagnetic_latitude_deg[2], spacecraft.geomagnetic_latitude_deg[3]
+    )
+
+
+def test_longitude_range(spacecraft: ArrayEphemeris) -> None:
+    lons = spacecraft.geomagnetic_longitude_deg
+    assert np.all((lons >= -180.0) & (lons <= 180.0))
@@ -0,0 +1,45 @@
+"""Tests for batch body lookups with get_bodies."""
+
+import numpy as np
+import pytest
+
+import rust_ephem
+
+BODIES = ["Sun", "Moon", "Jupiter barycenter", "EML1"]
+
+
+class TestGetBodies:
+    def test_returns_one_entry_per_body(
+        self, tle_ephemeris: rust_ephem.TLEEphemeris
+    ) -> None:
+        bodies = tle_ephemeris.get_bodies(BODIES)
+        assert list(bodies) == BODIES
+
+    def test_matches_get_body_pv(self, tle_ephemeris: rust_ephem.TLEEphemeris) -> None:
+        bodies = tle_ephemeris.get_bodies(BODIES)
+        for name in BODIES:
+            single = tle_ephemeris.get_body_pv(name)
+            np.testing.assert_allclose(bodies[name].position, single.position)
+            np.testing.assert_allclose(bodies[name].velocity, single.velocity)
+
+    def test_keys_keep_given_spelling(
+        self, tle_ephemeris: rust_ephem.TLEEphemeris
+    ) -> None:
+        bodies = tle_ephemeris.get_bodies(["moon", "301"])
+        np.testing.assert_allclose(bodies["moon"].position, bodies["301"].position)
+
+    def test_ground_ephemeris(
+        self, ground_ephemeris: rust_ephem.GroundEphemeris
+    ) -> None:
+        bodies = ground_ephemeris.get_bodies(["Sun", "Moon"])
+        single = ground_ephemeris.get_body_pv("Sun")
+        np.testing.assert_allclose(bodies["Sun"].position, single.position)
+
+    def test_empty_list(self, tle_ephemeris: rust_ephem.TLEEphemeris) -> None:
+        assert tle_ephemeris.get_bodies([]) == {}
+
+    def test_unknown_body_raises(
+        self, tle_ephemeris: rust_ephem.TLEEphemeris
+    ) -> None:
+        with pytest.raises(ValueError):
+            tle_ephemeris.get_bodies(["Sun", "NotARealBody"], source="spice")
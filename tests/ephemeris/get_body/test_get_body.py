@@ -10,6 +10,8 @@ This tests that:
 4. Works for all major planets and moons
 """
 
+import warnings
+
 import numpy as np
 import pytest
 
@@ -246,3 +248,46 @@ class TestBodyNameVariations:
         moon = tle_ephemeris.get_body_pv("Moon")
         luna = tle_ephemeris.get_body_pv("Luna")
         assert np.allclose(moon.position, luna.position)
+
+
+class TestBodySource:
+    """Test the source= precedence flag on get_body_pv()/get_body()"""
+
+    def test_explicit_spice_matches_default(
+        self, tle_ephemeris: rust_ephem.TLEEphemeris
+    ) -> None:
+        default = tle_ephemeris.get_body_pv("Sun")
+        spice = tle_ephemeris.get_body_pv("Sun", source="spice")
+        assert np.allclose(default.position, spice.position)
+
+    def test_auto_uses_spice_without_warning(
+        self, tle_ephemeris: rust_ephem.TLEEphemeris
+    ) -> None:
+        with warnings.catch_warnings():
+            warnings.simplefilter("error")
+            moon = tle_ephemeris.get_body_pv("Moon", source="auto")
+        assert moon.position.shape[1] == 3
+
+    def test_source_is_case_insensitive(
+        self, tle_ephemeris: rust_ephem.TLEEphemeris
+    ) -> None:
+        moon = tle_ephemeris.get_body_pv("Moon", source="SPICE")
+        assert moon.position.shape[1] == 3
+
+    def test_invalid_source_raises(
+        self, tle_ephemeris: rust_ephem.TLEEphemeris
+    ) -> None:
+        with pytest.raises(ValueError, match="Invalid body source"):
+            tle_ephemeris.get_body_pv("Sun", source="skyfield")
+
+    def test_spice_source_conflicts_with_use_horizons(
+        self, tle_ephemeris: rust_ephem.TLEEphemeris
+    ) -> None:
+        with pytest.raises(ValueError, match="conflicts"):
+            tle_ephemeris.get_body("Sun", use_horizons=True, source="spice")
+
+    def test_unknown_name_with_spice_source_raises(
+        self, tle_ephemeris: rust_ephem.TLEEphemeris
+    ) -> None:
+        with pytest.raises(ValueError, match="Unknown body identifier"):
+            tle_ephemeris.get_body_pv("NotARealBody", source="spice")
@@ -0,0 +1,91 @@
+"""Tests for pseudo-bodies (EMB, SSB and Lagrange points) in get_body."""
+
+import numpy as np
+
+import rust_ephem
+
+
+def geocentric(ephem: rust_ephem.TLEEphemeris, body: str) -> np.ndarray:
+    """Geocentric position of a body, from its observer-relative position"""
+    return ephem.get_body_pv(body).position + ephem.gcrs_pv.position
+
+
+def unit(vectors: np.ndarray) -> np.ndarray:
+    return vectors / np.linalg.norm(vectors, axis=1, keepdims=True)
+
+
+class TestPseudoBodies:
+    def test_emb_distance_from_earth(
+        self, tle_ephemeris: rust_ephem.TLEEphemeris
+    ) -> None:
+        distance = np.linalg.norm(geocentric(tle_ephemeris, "EMB"), axis=1)
+        assert np.all((distance > 4000.0) & (distance < 5000.0))
+
+    def test_emb_matches_spice_earth_barycenter(
+        self, tle_ephemeris: rust_ephem.TLEEphemeris
+    ) -> None:
+        derived = geocentric(tle_ephemeris, "EMB")
+        spice = geocentric(tle_ephemeris, "Earth barycenter")
+        assert np.max(np.linalg.norm(derived - spice, axis=1)) < 1.0
+
+    def test_names_are_case_insensitive(
+        self, tle_ephemeris: rust_ephem.TLEEphemeris
+    ) -> None:
+        upper = tle_ephemeris.get_body_pv("SEL2").position
+        lower = tle_ephemeris.get_body_pv("sel2").position
+        np.testing.assert_allclose(upper, lower)
+
+    def test_sun_earth_l1_is_sunward(
+        self, tle_ephemeris: rust_ephem.TLEEphemeris
+    ) -> None:
+        l1 = geocentric(tle_ephemeris, "SEL1")
+        sun = geocentric(tle_ephemeris, "Sun")
+        distance = np.linalg.norm(l1, axis=1)
+        assert np.all((distance > 1.4e6) & (distance < 1.6e6))
+        cos_angle = np.sum(unit(l1) * unit(sun), axis=1)
+        assert np.all(cos_angle > 0.999)
+
+    def test_sun_earth_l2_is_anti_sunward(
+        self, tle_ephemeris: rust_ephem.TLEEphemeris
+    ) -> None:
+        l2 = geocentric(tle_ephemeris, "SEL2")
+        sun = geocentric(tle_ephemeris, "Sun")
+        distance = np.linalg.norm(l2, axis=1)
+        assert np.all((distance > 1.4e6) & (distance < 1.6e6))
+        cos_angle = np.sum(unit(l2) * unit(sun), axis=1)
+        assert np.all(cos_angle < -0.999)
+
+    def test_earth_moon_points_bracket_moon(
+        self, tle_ephemeris: rust_ephem.TLEEphemeris
+    ) -> None:
+        moon = np.linalg.norm(geocentric(tle_ephemeris, "Moon"), axis=1)
+        l1 = np.linalg.norm(geocentric(tle_ephemeris, "EML1"), axis=1)
+        l2 = np.linalg.norm(geocentric(tle_ephemeris, "EML2"), axis=1)
+        np.testing.assert_allclose(l1 / moon, 0.849, atol=1e-3)
+        np.testing.assert_allclose(l2 / moon, 1.168, atol=1e-3)
+
+    def test_ssb_resolves(self, tle_ephemeris: rust_ephem.TLEEphemeris) -> None:
+        distance = np.linalg.norm(geocentric(tle_ephemeris, "SSB"), axis=1)
+        assert np.all((distance > 1.4e8) & (distance < 1.6e8))
+
+
+class TestPseudoBodyProximity:
+    def test_pointing_at_l2_violates_avoidance(
+        self, tle_ephemeris: rust_ephem.TLEEphemeris
+    ) -> None:
+        l2 = tle_ephemeris.get_body("SEL2")
+        ra, dec = float(l2[0].ra.deg), float(l2[0].dec.deg)
+        constraint = rust_ephem.Constraint.body_proximity("SEL2", 10.0)
+        assert constraint.evaluate(tle_ephemeris, ra, dec).constraint_array[0]
+        away = constraint.evaluate(tle_ephemeris, (ra + 180.0) % 360.0, -dec)
+        assert not any(away.constraint_array)
+
+    def test_pointing_away_from_l2_violates_max_angle(
+        self, tle_ephemeris: rust_ephem.TLEEphemeris
+    ) -> None:
+        l2 = tle_ephemeris.get_body("SEL2")
+        ra, dec = float(l2[0].ra.deg), float(l2[0].dec.deg)
+        constraint = rust_ephem.Constraint.body_proximity("SEL2", 0.0, 20.0)
+        assert not constraint.evaluate(tle_ephemeris, ra, dec).constraint_array[0]
+        away = constraint.evaluate(tle_ephemeris, (ra + 180.0) % 360.0, -dec)
+        assert all(away.constraint_array)
@@ -0,0 +1,38 @@
+"""Fixtures for ground-target access tests"""
+
+import math
+from datetime import datetime, timedelta, timezone
+
+import numpy as np
+import pytest
+
+from rust_ephem import ArrayEphemeris, GEOEphemeris
+
+# Equinox day: the Sun is over the equator, near longitude 0 at 12:00 UTC
+BEGIN = datetime(2024, 3, 20, tzinfo=timezone.utc)
+STEP_SIZE = 60
+
+# Equatorial LEO ground track crossing longitude 0 at sample CROSSING
+ORBIT_RADIUS_KM = 7000.0
+START_LONGITUDE = -20.0
+LONGITUDE_STEP = 0.5
+N_TRACK = 81
+CROSSING = 40
+
+
+@pytest.fixture
+def track() -> ArrayEphemeris:
+    """Equatorial spacecraft moving east over longitude 0 around 00:40 UTC"""
+    times = [BEGIN + timedelta(seconds=STEP_SIZE * i) for i in range(N_TRACK)]
+    states = np.zeros((N_TRACK, 6))
+    for i in range(N_TRACK):
+        lon = math.radians(START_LONGITUDE + LONGITUDE_STEP * i)
+        states[i, 0] = ORBIT_RADIUS_KM * math.cos(lon)
+        states[i, 1] = ORBIT_RADIUS_KM * math.sin(lon)
+    return ArrayEphemeris(times, states, frame="ITRS")
+
+
+@pytest.fixture
+def geo() -> GEOEphemeris:
+    """Geostationary spacecraft above (0°, 0°) for a full day"""
+    return GEOEphemeris(0.0, BEGIN, BEGIN + timedelta(days=1), 600)
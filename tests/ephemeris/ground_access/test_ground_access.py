@@ -0,0 +1,71 @@
+"""Tests for access_to imaging opportunities"""
+
+from datetime import timedelta
+
+import pytest
+
+from rust_ephem import AccessOpportunity, ArrayEphemeris, GEOEphemeris
+
+from .conftest import BEGIN, CROSSING, STEP_SIZE
+
+
+class TestGroundTrack:
+    def test_overhead_pass(self, track: ArrayEphemeris) -> None:
+        opportunities = track.access_to(0.0, 0.0, 30.0)
+        assert len(opportunities) == 1
+        opp = opportunities[0]
+        assert isinstance(opp, AccessOpportunity)
+        assert opp.peak_time == BEGIN + timedelta(seconds=STEP_SIZE * CROSSING)
+        assert opp.off_nadir_deg == pytest.approx(0.0, abs=1e-6)
+        assert opp.elevation_deg == pytest.approx(90.0)
+        assert opp.gsd_factor_across == pytest.approx(1.0)
+        assert opp.gsd_factor_along == pytest.approx(1.0)
+        # The pass is symmetric about the crossing
+        assert opp.peak_time - opp.start_time == opp.end_time - opp.peak_time
+        assert opp.duration_seconds > 0
+
+    def test_off_track_target(self, track: ArrayEphemeris) -> None:
+        # 10° north of the track the spacecraft is seen to the south, about
+        # 57° off nadir and 23° above the horizon
+        assert track.access_to(10.0, 0.0, 30.0) == []
+        (opp,) = track.access_to(10.0, 0.0, 60.0)
+        assert opp.azimuth_deg == pytest.approx(180.0, abs=1e-6)
+        assert opp.off_nadir_deg == pytest.approx(57.0, abs=0.5)
+        assert opp.incidence_deg == pytest.approx(90.0 - opp.elevation_deg)
+        assert opp.gsd_factor_along > opp.gsd_factor_across > 1.0
+
+    def test_beyond_the_limb(self, track: ArrayEphemeris) -> None:
+        # The off-nadir limit alone would allow it, but the target is
+        # below the horizon
+        assert track.access_to(0.0, 120.0, 90.0) == []
+
+    def test_night_pass_fails_illumination(self, track: ArrayEphemeris) -> None:
+        # Around 00:40 UTC longitude 0 is in darkness
+        assert track.access_to(0.0, 0.0, 30.0, min_sun_elev=0.0) == []
+
+
+class TestIllumination:
+    def test_daylight_window(self, geo: GEOEphemeris) -> None:
+        (always,) = geo.access_to(0.0, 0.0, 5.0)
+        assert always.duration_seconds == pytest.approx(86400.0)
+
+        (lit,) = geo.access_to(0.0, 0.0, 5.0, min_sun_elev=20.0)
+        assert lit.sun_elevation_deg >= 20.0
+        assert BEGIN + timedelta(hours=6) < lit.start_time
+        assert lit.end_time < BEGIN + timedelta(hours=18)
+
+
+@pytest.mark.parametrize(
+    "args",
+    [
+        (95.0, 0.0, 30.0, None),
+        (0.0, 400.0, 30.0, None),
+        (0.0, 0.0, 0.0, None),
+        (0.0, 0.0, 30.0, 95.0),
+    ],
+)
+def test_invalid_arguments(
+    track: ArrayEphemeris, args: tuple[float, float, float, float | None]
+) -> None:
+    with pytest.raises(ValueError):
+        track.access_to(*args)
@@ -0,0 +1,12 @@
+"""Fixtures for GroundEphemeris location input tests."""
+
+import datetime
+
+BEGIN = datetime.datetime(2024, 1, 1, 0, 0, 0, tzinfo=datetime.timezone.utc)
+END = datetime.datetime(2024, 1, 1, 1, 0, 0, tzinfo=datetime.timezone.utc)
+STEP_SIZE = 600
+
+# Roque de los Muchachos
+LATITUDE = 28.7606
+LONGITUDE = -17.8814
+HEIGHT = 2326.0
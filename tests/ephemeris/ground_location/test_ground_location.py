@@ -0,0 +1,92 @@
+"""Tests for constructing GroundEphemeris from EarthLocation and geocentric XYZ"""
+
+import astropy.units as u  # type: ignore[import-untyped]
+import numpy as np
+import pytest
+from astropy.coordinates import EarthLocation  # type: ignore[import-untyped]
+
+from rust_ephem import GroundEphemeris
+
+from .conftest import BEGIN, END, HEIGHT, LATITUDE, LONGITUDE, STEP_SIZE
+
+
+def reference() -> GroundEphemeris:
+    return GroundEphemeris(LATITUDE, LONGITUDE, HEIGHT, BEGIN, END, STEP_SIZE)
+
+
+def test_from_earth_location() -> None:
+    loc = EarthLocation.from_geodetic(
+        lon=LONGITUDE * u.deg, lat=LATITUDE * u.deg, height=HEIGHT * u.m
+    )
+    ground = GroundEphemeris(location=loc, begin=BEGIN, end=END, step_size=STEP_SIZE)
+    assert ground.input_latitude == pytest.approx(LATITUDE, abs=1e-9)
+    assert ground.input_longitude == pytest.approx(LONGITUDE, abs=1e-9)
+    assert ground.input_height == pytest.approx(HEIGHT, abs=1e-4)
+    np.testing.assert_allclose(
+        ground.itrs_pv.position, reference().itrs_pv.position, atol=1e-6
+    )
+
+
+def test_from_geocentric() -> None:
+    loc = reference().earth_location
+    xyz = tuple(float(c.to_value(u.m)) for c in loc.geocentric)
+    ground = GroundEphemeris(geocentric=xyz, begin=BEGIN, end=END, step_size=STEP_SIZE)
+    assert ground.input_latitude == pytest.approx(LATITUDE, abs=1e-9)
+    assert ground.input_longitude == pytest.approx(LONGITUDE, abs=1e-9)
+    assert ground.input_height == pytest.approx(HEIGHT, abs=1e-4)
+
+
+def test_earth_location_round_trip() -> None:
+    loc = reference().earth_location
+    lon, lat, height = loc.to_geodetic("WGS84")
+    assert lat.to_value(u.deg) == pytest.approx(LATITUDE, abs=1e-9)
+    assert lon.to_value(u.deg) == pytest.approx(LONGITUDE, abs=1e-9)
+    assert height.to_value(u.m) == pytest.approx(HEIGHT, abs=1e-4)
+
+
+def test_other_ellipsoid_is_converted_to_wgs84() -> None:
+    loc = EarthLocation.from_geodetic(
+        lon=LONGITUDE * u.deg,
+        lat=LATITUDE * u.deg,
+        height=HEIGHT * u.m,
+        ellipsoid="WGS72",
+    )
+    ground = GroundEphemeris(location=loc, begin=BEGIN, end=END, step_size=STEP_SIZE)
+    # Same point in space, expressed on WGS84
+    _, _, wgs84_height = loc.to_geodetic("WGS84")
+    assert ground.input_height == pytest.approx(wgs84_height.to_value(u.m), abs=1e-3)
+    assert ground.input_height != pytest.approx(HEIGHT, abs=0.1)
+    position_m = ground.itrs_pv.position[0] * 1000.0
+    expected = [c.to_value(u.m) for c in loc.geocentric]
+    np.testing.assert_allclose(position_m, expected, atol=1e-3)
+
+
+@pytest.mark.parametrize(
+    "kwargs, error",
+    [
+        ({"latitude": LATITUDE, "geocentric": (6378137.0, 0.0, 0.0)}, ValueError),
+        ({"site": "Paranal", "geocentric": (6378137.0, 0.0, 0.0)}, ValueError),
+        ({"location": "La Palma"}, TypeError),
+        ({"geocentric": (0.0, 0.0, 0.0)}, ValueError),
+    ],
+)
+def test_invalid_location_inputs(kwargs: dict, error: type) -> None:
+    with pytest.raises(error):
+        GroundEphemeris(begin=BEGIN, end=END, step_size=STEP_SIZE, **kwargs)
+
+
+def test_location_and_geocentric_are_exclusive() -> None:
+    loc = reference().earth_location
+    with pytest.raises(ValueError, match="only one of"):
+        GroundEphemeris(
+            location=loc,
+            geocentric=(6378137.0, 0.0, 0.0),
+            begin=BEGIN,
+            end=END,
+        )
+
+
+def test_array_earth_location_rejected() -> None:
+    loc = EarthLocation.from_geodetic(lon=[0, 1] * u.deg, lat=[0, 1] * u.deg)
+    with pytest.raises(TypeError, match="scalar"):
+        GroundEphemeris(location=loc, begin=BEGIN, end=END)
@@ -0,0 +1,39 @@
+"""Fixtures for ground network tests."""
+
+import math
+from datetime import datetime, timedelta, timezone
+
+import numpy as np
+import pytest
+
+from rust_ephem import ArrayEphemeris
+
+BEGIN = datetime(2024, 1, 1, 0, 0, 0, tzinfo=timezone.utc)
+STEP = 60
+
+# Stations on the equator at longitudes 0 and 90 degrees
+STATIONS = [("East", 0.0, 90.0, 0.0), ("Prime", 0.0, 0.0, 0.0)]
+
+# Spacecraft (longitude_deg, radius_km) in the Earth-fixed frame at each sample:
+# over Prime, between both stations at high altitude, over East, then out of view
+TRACK = [
+    (0.0, 7000.0),
+    (0.0, 7000.0),
+    (45.0, 20000.0),
+    (90.0, 7000.0),
+    (90.0, 7000.0),
+    (180.0, 7000.0),
+    (180.0, 7000.0),
+    (90.0, 7000.0),
+]
+
+
+@pytest.fixture
+def spacecraft() -> ArrayEphemeris:
+    """Equatorial ITRS track that hands over from Prime to East"""
+    times = [BEGIN + timedelta(seconds=STEP * i) for i in range(len(TRACK))]
+    states = np.zeros((len(TRACK), 6))
+    for i, (lon, radius) in enumerate(TRACK):
+        states[i, 0] = radius * math.cos(math.radians(lon))
+        states[i, 1] = radius * math.sin(math.radians(lon))
+    return ArrayEphemeris(times, states, frame="ITRS")
@@ -0,0 +1,88 @@
+"""Tests for GroundNetwork contact analysis"""
+
+import pytest
+
+from rust_ephem import ArrayEphemeris, GroundEphemeris, GroundNetwork
+
+from .conftest import BEGIN, STATIONS, STEP
+
+
+def test_station_contacts(spacecraft: ArrayEphemeris) -> None:
+    result = GroundNetwork([STATIONS[1], STATIONS[0]]).contacts(spacecraft)
+
+    prime = result.station("Prime")
+    assert prime.in_contact == [True, True, True, False, False, False, False, False]
+    assert prime.n_contacts == 1
+    assert prime.total_contact_seconds == 2 * STEP
+    assert prime.max_elevation == pytest.approx(90.0)
+    assert len(prime.elevation) == 8
+
+    east = result.station("East")
+    assert east.n_contacts == 2
+    assert east.total_contact_seconds == 2 * STEP
+    assert east.mean_contact_seconds == STEP
+    assert east.contacts[0].start_time == BEGIN.replace(minute=2)
+
+
+def test_coverage_gaps_and_handovers(spacecraft: ArrayEphemeris) -> None:
+    result = GroundNetwork([STATIONS[1], STATIONS[0]]).contacts(spacecraft)
+
+    assert result.in_contact == [True] * 5 + [False, False, True]
+    assert result.serving_station == (
+        ["Prime"] * 3 + ["East", "East", None, None, "East"]
+    )
+    assert result.coverage_fraction == pytest.approx(6 / 8)
+    assert [w.duration_seconds for w in result.coverage] == [4 * STEP, 0]
+    assert len(result.gaps) == 1
+    assert result.max_gap_seconds == 3 * STEP
+    assert result.handovers == [(BEGIN.replace(minute=3), "Prime", "East")]
+    assert len(result.timestamp) == 8
+
+
+def test_min_elevation(spacecraft: ArrayEphemeris) -> None:
+    network = GroundNetwork([STATIONS[1], STATIONS[0]], min_elevation=30.0)
+    result = network.contacts(spacecraft)
+
+    # Neither station sees the high sample between them above 30 degrees
+    assert result.in_contact[2] is False
+    assert result.handovers == []
+    assert len(result.gaps) == 2
+
+
+def test_ground_ephemeris_station_uses_horizon(spacecraft: ArrayEphemeris) -> None:
+    east = GroundEphemeris(
+        0.0, 90.0, 0.0, BEGIN, BEGIN.replace(minute=7), STEP, horizon=[(0.0, 30.0)]
+    )
+    result = GroundNetwork([east]).contacts(spacecraft)
+    station = result.stations[0]
+    assert station.name == "(0.0000, 90.0000)"
+    assert station.in_contact[2] is False
+    assert station.in_contact[3] is True
+
+
+def test_site_name_stations() -> None:
+    network = GroundNetwork(["dss-14", "Canberra", "robledo"], min_elevation=10.0)
+    assert network.station_names == ["Goldstone", "Canberra", "Madrid"]
+    assert len(network) == 3
+    assert network.min_elevation == 10.0
+
+
+def test_station_lookup_error(spacecraft: ArrayEphemeris) -> None:
+    result = GroundNetwork(STATIONS).contacts(spacecraft)
+    with pytest.raises(KeyError):
+        result.station("Nowhere")
+
+
+@pytest.mark.parametrize(
+    "stations, error",
+    [
+        ([], ValueError),
+        (["Atlantis"], ValueError),
+        ([STATIONS[0], STATIONS[0]], ValueError),
+        ([("Bad", 95.0, 0.0, 0.0)], ValueError),
+        ([42], TypeError),
+    ],
+)
+def test_invalid_stations(stations: list, error: type) -> None:
+    with pytest.raises(error):
+        GroundNetwork(stations)
@@ -0,0 +1,28 @@
+"""Fixtures for GroundEphemeris horizon mask tests."""
+
+import datetime
+
+import pytest
+
+from rust_ephem import GroundEphemeris
+
+BEGIN = datetime.datetime(2024, 1, 1, 0, 0, 0, tzinfo=datetime.timezone.utc)
+END = datetime.datetime(2024, 1, 2, 0, 0, 0, tzinfo=datetime.timezone.utc)
+STEP_SIZE = 1800
+
+# Mountains to the east, a low horizon to the west
+HORIZON = [(0.0, 5.0), (90.0, 25.0), (180.0, 10.0), (270.0, 0.0)]
+
+
+@pytest.fixture
+def masked_site() -> GroundEphemeris:
+    """Ground site with an azimuth-dependent horizon profile"""
+    return GroundEphemeris(
+        35.0, -120.0, 500.0, BEGIN, END, STEP_SIZE, horizon=HORIZON
+    )
+
+
+@pytest.fixture
+def flat_site() -> GroundEphemeris:
+    """Same ground site without a horizon profile"""
+    return GroundEphemeris(35.0, -120.0, 500.0, BEGIN, END, STEP_SIZE)
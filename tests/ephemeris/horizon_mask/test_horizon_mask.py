@@ -0,0 +1,82 @@
+"""Tests for azimuth-dependent horizon masks on GroundEphemeris"""
+
+import numpy as np
+import pytest
+
+from rust_ephem import GroundEphemeris
+from rust_ephem.constraints import AltAzConstraint
+
+from .conftest import BEGIN, END, HORIZON, STEP_SIZE
+
+TARGET_RA = 83.63
+TARGET_DEC = 22.01
+
+
+def test_horizon_defaults_to_flat(flat_site: GroundEphemeris) -> None:
+    assert flat_site.horizon is None
+    assert flat_site.horizon_elevation(123.0) == 0.0
+
+
+def test_horizon_points_sorted_and_normalized() -> None:
+    site = GroundEphemeris(
+        35.0, -120.0, 500.0, BEGIN, END, STEP_SIZE, horizon=[(-90.0, 2.0), (45.0, 8.0)]
+    )
+    assert site.horizon == [(45.0, 8.0), (270.0, 2.0)]
+
+
+def test_horizon_elevation_interpolates(masked_site: GroundEphemeris) -> None:
+    assert masked_site.horizon == HORIZON
+    assert masked_site.horizon_elevation(0.0) == pytest.approx(5.0)
+    assert masked_site.horizon_elevation(45.0) == pytest.approx(15.0)
+    assert masked_site.horizon_elevation(135.0) == pytest.approx(17.5)
+    # Wraps from 270 deg back to the first point at 360 deg
+    assert masked_site.horizon_elevation(315.0) == pytest.approx(2.5)
+    assert masked_site.horizon_elevation(-45.0) == pytest.approx(2.5)
+
+
+@pytest.mark.parametrize(
+    "horizon", [[], [(0.0, 95.0)], [(0.0, -91.0)], [(float("nan"), 0.0)]]
+)
+def test_invalid_horizon_rejected(horizon: list[tuple[float, float]]) -> None:
+    with pytest.raises(ValueError, match="horizon"):
+        GroundEphemeris(35.0, -120.0, 500.0, BEGIN, END, STEP_SIZE, horizon=horizon)
+
+
+def test_is_above_horizon_matches_altaz(masked_site: GroundEphemeris) -> None:
+    altaz = masked_site.radec_to_altaz(TARGET_RA, TARGET_DEC)
+    expected = [alt >= masked_site.horizon_elevation(az) for alt, az in altaz]
+    visible = masked_site.is_above_horizon(TARGET_RA, TARGET_DEC)
+    assert visible == expected
+    # The target rises and sets during the day
+    assert any(visible) and not all(visible)
+
+
+def test_is_above_horizon_time_indices(masked_site: GroundEphemeris) -> None:
+    full = masked_site.is_above_horizon(TARGET_RA, TARGET_DEC)
+    subset = masked_site.is_above_horizon(TARGET_RA, TARGET_DEC, time_indices=[0, 5])
+    assert subset == [full[0], full[5]]
+
+
+def test_altaz_constraint_applies_horizon(
+    masked_site: GroundEphemeris, flat_site: GroundEphemeris
+) -> None:
+    constraint = AltAzConstraint(min_altitude=0.0)
+    masked = constraint.evaluate(masked_site, target_ra=TARGET_RA, target_dec=TARGET_DEC)
+    flat = constraint.evaluate(flat_site, target_ra=TARGET_RA, target_dec=TARGET_DEC)
+
+    visible = np.array(masked_site.is_above_horizon(TARGET_RA, TARGET_DEC))
+    assert np.array_equal(masked.constraint_array, ~visible)
+    # The mask only ever removes visibility
+    assert np.all(masked.constraint_array | ~flat.constraint_array)
+    assert masked.constraint_array.sum() > flat.constraint_array.sum()
+
+
+def test_altaz_constraint_batch_applies_horizon(masked_site: GroundEphemeris) -> None:
+    constraint = AltAzConstraint(min_altitude=0.0)
+    batch = constraint.in_constraint_batch(
+        masked_site, [TARGET_RA, 0.0], [TARGET_DEC, 89.0]
+    )
+    visible = np.array(masked_site.is_above_horizon(TARGET_RA, TARGET_DEC))
+    assert np.array_equal(batch[0], ~visible)
+    # Near the pole the target stays at ~35 deg, above every part of the mask
+    assert not batch[1].any()
@@ -0,0 +1,20 @@
+"""Fixtures for horizons_ephemeris tests."""
+
+from datetime import datetime, timezone
+
+import pytest
+
+import rust_ephem
+
+BEGIN = datetime(2024, 1, 1, 0, 0, 0, tzinfo=timezone.utc)
+END = datetime(2024, 1, 1, 2, 0, 0, tzinfo=timezone.utc)
+STEP_SIZE = 600
+
+# NAIF ID of the James Webb Space Telescope in JPL Horizons
+JWST_TARGET = "-170"
+
+
+@pytest.fixture
+def jwst_ephemeris() -> rust_ephem.HorizonsEphemeris:
+    """HorizonsEphemeris for JWST (requires network access)"""
+    return rust_ephem.HorizonsEphemeris(JWST_TARGET, BEGIN, END, step_size=STEP_SIZE)
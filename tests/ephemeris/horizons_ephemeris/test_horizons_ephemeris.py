@@ -0,0 +1,58 @@
+"""Tests for HorizonsEphemeris."""
+
+from typing import get_args
+
+import numpy as np
+import pytest
+
+import rust_ephem
+from rust_ephem import Ephemeris, HorizonsEphemeris
+
+from .conftest import BEGIN, END, JWST_TARGET, STEP_SIZE
+
+
+class TestHorizonsEphemerisType:
+    def test_registered_as_ephemeris(self) -> None:
+        assert issubclass(HorizonsEphemeris, Ephemeris)
+
+    def test_exported_from_package(self) -> None:
+        assert rust_ephem.HorizonsEphemeris is HorizonsEphemeris
+        assert "HorizonsEphemeris" in rust_ephem.__all__
+
+    def test_ephemeris_type_union(self) -> None:
+        assert HorizonsEphemeris in get_args(rust_ephem.EphemerisType)
+
+
+@pytest.mark.skip(reason="Requires network access")
+class TestHorizonsEphemerisNetwork:
+    def test_jwst_trajectory(self, jwst_ephemeris: HorizonsEphemeris) -> None:
+        assert jwst_ephemeris.target == JWST_TARGET
+        assert jwst_ephemeris.begin == BEGIN
+        assert jwst_ephemeris.end == END
+        assert jwst_ephemeris.step_size == STEP_SIZE
+
+        positions = jwst_ephemeris.gcrs_pv.position
+        assert positions.shape == (13, 3)
+        # JWST orbits the Sun-Earth L2 point, roughly 1.5 million km away
+        distances = np.linalg.norm(positions, axis=1)
+        assert np.all((distances > 1.0e6) & (distances < 2.0e6))
+
+    def test_raw_table_brackets_grid(self, jwst_ephemeris: HorizonsEphemeris) -> None:
+        raw_times = jwst_ephemeris.horizons_timestamp
+        assert raw_times[0] <= BEGIN
+        assert raw_times[-1] >= END
+        assert len(raw_times) == jwst_ephemeris.horizons_pv.position.shape[0]
+
+    def test_skycoord_outputs(self, jwst_ephemeris: HorizonsEphemeris) -> None:
+        assert len(jwst_ephemeris.gcrs) == 13
+        assert len(jwst_ephemeris.sun) == 13
+        assert jwst_ephemeris.earth_radius_deg.shape == (13,)
+
+    def test_constraint_evaluation(self, jwst_ephemeris: HorizonsEphemeris) -> None:
+        constraint = rust_ephem.SunConstraint(min_angle=85.0)
+        result = constraint.evaluate(jwst_ephemeris, target_ra=0.0, target_dec=0.0)
+        assert len(result.timestamp) == 13
+
+    def test_unknown_target_raises(self) -> None:
+        with pytest.raises(ValueError, match="Horizons"):
+            HorizonsEphemeris("no such object xyzzy", BEGIN, END, step_size=STEP_SIZE)
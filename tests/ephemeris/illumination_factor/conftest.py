@@ -0,0 +1,17 @@
+"""Fixtures for illumination factor tests."""
+
+import datetime
+
+import pytest
+
+from rust_ephem import TLEEphemeris
+
+TLE1 = "1 25544U 98067A   25315.25818480  .00012468  00000-0  22984-3 0  9991"
+TLE2 = "2 25544  51.6338 298.3179 0004133  57.8977 302.2413 15.49525392537972"
+BEGIN = datetime.datetime(2024, 1, 1, 0, 0, 0, tzinfo=datetime.timezone.utc)
+END = datetime.datetime(2024, 1, 1, 2, 0, 0, tzinfo=datetime.timezone.utc)
+
+
+@pytest.fixture
+def ephem() -> TLEEphemeris:
+    return TLEEphemeris(TLE1, TLE2, BEGIN, END, step_size=5)
@@ -0,0 +1,31 @@
+"""Tests for the penumbra-aware illumination factor."""
+
+import numpy as np
+
+from rust_ephem import Constraint, TLEEphemeris
+
+
+class TestIlluminationFactor:
+    def test_shape_and_range(self, ephem: TLEEphemeris) -> None:
+        factor = ephem.illumination_factor
+        assert factor.shape == (len(ephem),)
+        assert np.all((factor >= 0.0) & (factor <= 1.0))
+
+    def test_orbit_has_sunlight_and_umbra(self, ephem: TLEEphemeris) -> None:
+        factor = ephem.illumination_factor
+        assert np.any(factor == 1.0)
+        assert np.any(factor == 0.0)
+
+    def test_penumbra_is_fractional(self, ephem: TLEEphemeris) -> None:
+        factor = ephem.illumination_factor
+        partial = (factor > 0.0) & (factor < 1.0)
+        assert np.any(partial)
+        # Penumbra passages last seconds, far shorter than sunlight or umbra
+        assert partial.sum() < 0.1 * len(factor)
+
+    def test_agrees_with_eclipse_constraint(self, ephem: TLEEphemeris) -> None:
+        factor = ephem.illumination_factor
+        umbra = Constraint.eclipse(umbra_only=True).evaluate(ephem, 0.0, 0.0)
+        shadow = Constraint.eclipse(umbra_only=False).evaluate(ephem, 0.0, 0.0)
+        np.testing.assert_array_equal(np.asarray(umbra.constraint_array), factor == 0.0)
+        np.testing.assert_array_equal(np.asarray(shadow.constraint_array), factor < 1.0)
@@ -0,0 +1,65 @@
+"""Fixtures for interpolation quality control tests."""
+
+import math
+from datetime import datetime, timedelta, timezone
+from typing import Any
+
+import numpy as np
+import numpy.typing as npt
+import pytest
+
+BEGIN = datetime(2024, 1, 1, 0, 0, 0, tzinfo=timezone.utc)
+# Sparse samples, about a tenth of an orbit apart
+DATA_STEP = 600
+N_POINTS = 19
+END = BEGIN + timedelta(seconds=DATA_STEP * (N_POINTS - 1))
+GRID_STEP = 60
+
+# Circular equatorial orbit
+RADIUS_KM = 7000.0
+MU_EARTH = 398600.4418
+OMEGA = math.sqrt(MU_EARTH / RADIUS_KM**3)
+
+
+def circular_states(times: list[datetime]) -> npt.NDArray[np.float64]:
+    """Analytic GCRS states (km, km/s) of the circular orbit"""
+    t = np.array([(time - BEGIN).total_seconds() for time in times])
+    v = RADIUS_KM * OMEGA
+    return np.column_stack(
+        [
+            RADIUS_KM * np.cos(OMEGA * t),
+            RADIUS_KM * np.sin(OMEGA * t),
+            np.zeros_like(t),
+            -v * np.sin(OMEGA * t),
+            v * np.cos(OMEGA * t),
+            np.zeros_like(t),
+        ]
+    )
+
+
+def grid_times() -> list[datetime]:
+    n = (N_POINTS - 1) * DATA_STEP // GRID_STEP + 1
+    return [BEGIN + timedelta(seconds=GRID_STEP * k) for k in range(n)]
+
+
+@pytest.fixture
+def data_times() -> list[datetime]:
+    return [BEGIN + timedelta(seconds=DATA_STEP * k) for k in range(N_POINTS)]
+
+
+@pytest.fixture
+def oem_path(tmp_path: Any, data_times: list[datetime]) -> str:
+    lines = [
+        "CCSDS_OEM_VERS = 2.0",
+        "META_START",
+        "CENTER_NAME = EARTH",
+        "REF_FRAME = EME2000",
+        "TIME_SYSTEM = UTC",
+        "META_STOP",
+    ]
+    for time, row in zip(data_times, circular_states(data_times)):
+        values = " ".join(f"{x:.12f}" for x in row)
+        lines.append(f"{time.strftime('%Y-%m-%dT%H:%M:%S.000')} {values}")
+    path = tmp_path / "sparse.oem"
+    path.write_text("\n".join(lines) + "\n")
+    return str(path)
@@ -0,0 +1,99 @@
+"""Tests for interpolation scheme, gap and error estimate controls"""
+
+from datetime import datetime
+from typing import Any
+
+import numpy as np
+import pytest
+
+from rust_ephem import ArrayEphemeris, OEMEphemeris
+
+from .conftest import BEGIN, END, GRID_STEP, circular_states, grid_times
+
+
+def resample(data_times: list[datetime], **kwargs: Any) -> ArrayEphemeris:
+    return ArrayEphemeris(
+        data_times, circular_states(data_times), step_size=GRID_STEP, **kwargs
+    )
+
+
+def max_position_error(eph: Any) -> float:
+    truth = circular_states(grid_times())[:, :3]
+    return float(np.max(np.linalg.norm(eph.gcrs_pv.position - truth, axis=1)))
+
+
+class TestArrayInterpolation:
+    def test_defaults(self, data_times: list[datetime]) -> None:
+        eph = resample(data_times)
+        assert eph.interpolation == "hermite"
+        assert eph.interpolation_window == 2
+        assert eph.max_gap is None
+
+    def test_lagrange_default_window(self, data_times: list[datetime]) -> None:
+        eph = resample(data_times, interpolation="Lagrange")
+        assert eph.interpolation == "lagrange"
+        assert eph.interpolation_window == 8
+
+    @pytest.mark.parametrize(
+        "interpolation,window", [("hermite", 4), ("lagrange", 8)]
+    )
+    def test_wider_window_is_more_accurate(
+        self, data_times: list[datetime], interpolation: str, window: int
+    ) -> None:
+        cubic = max_position_error(resample(data_times))
+        wide = max_position_error(
+            resample(
+                data_times, interpolation=interpolation, interpolation_window=window
+            )
+        )
+        assert wide < cubic / 10
+
+    def test_error_estimate_tracks_actual_error(
+        self, data_times: list[datetime]
+    ) -> None:
+        eph = resample(data_times)
+        actual = max_position_error(eph)
+        estimate = eph.max_interpolation_error
+        assert estimate is not None
+        assert actual / 3 < estimate < actual * 3
+
+    def test_max_gap_rejects_grid_across_gap(self, data_times: list[datetime]) -> None:
+        times = data_times[:5] + data_times[8:]
+        with pytest.raises(ValueError, match="exceeds max_gap"):
+            resample(times, max_gap=900.0)
+        # Without a limit, or with a wide enough one, the gap is interpolated
+        resample(times)
+        assert resample(times, max_gap=1800.0).max_gap == 1800.0
+
+    def test_invalid_options_raise(self, data_times: list[datetime]) -> None:
+        with pytest.raises(ValueError, match="Unknown interpolation"):
+            resample(data_times, interpolation="spline")
+        with pytest.raises(ValueError, match="at least 2"):
+            resample(data_times, interpolation_window=1)
+        with pytest.raises(ValueError, match="max_gap"):
+            resample(data_times, max_gap=-1.0)
+
+
+class TestOEMInterpolation:
+    def test_lagrange_is_more_accurate(self, oem_path: str) -> None:
+        cubic = OEMEphemeris(oem_path, begin=BEGIN, end=END, step_size=GRID_STEP)
+        lagrange = OEMEphemeris(
+            oem_path,
+            begin=BEGIN,
+            end=END,
+            step_size=GRID_STEP,
+            interpolation="lagrange",
+        )
+        assert lagrange.interpolation == "lagrange"
+        assert max_position_error(lagrange) < max_position_error(cubic) / 10
+        assert lagrange.max_interpolation_error < cubic.max_interpolation_error
+
+    def test_max_gap(self, oem_path: str) -> None:
+        with pytest.raises(ValueError, match="exceeds max_gap"):
+            OEMEphemeris(
+                oem_path, begin=BEGIN, end=END, step_size=GRID_STEP, max_gap=300.0
+            )
+        eph = OEMEphemeris(
+            oem_path, begin=BEGIN, end=END, step_size=GRID_STEP, max_gap=600.0
+        )
+        assert eph.max_gap == 600.0
@@ -0,0 +1,31 @@
+"""Fixtures for link geometry tests."""
+
+import math
+from datetime import datetime, timedelta, timezone
+
+import numpy as np
+import pytest
+
+from rust_ephem import ArrayEphemeris
+
+BEGIN = datetime(2024, 1, 1, 0, 0, 0, tzinfo=timezone.utc)
+STEP = 60
+
+# Station on the equator at the prime meridian
+STATION = ("Prime", 0.0, 0.0, 0.0)
+EARTH_EQUATORIAL_RADIUS_KM = 6378.137
+
+# Spacecraft (longitude_deg, radius_km) in the Earth-fixed frame at each sample:
+# overhead, lower in the sky, then below the horizon
+TRACK = [(0.0, 7000.0), (10.0, 7000.0), (20.0, 7000.0), (180.0, 7000.0)]
+
+
+@pytest.fixture
+def spacecraft() -> ArrayEphemeris:
+    """Equatorial ITRS track passing over the station"""
+    times = [BEGIN + timedelta(seconds=STEP * i) for i in range(len(TRACK))]
+    states = np.zeros((len(TRACK), 6))
+    for i, (lon, radius) in enumerate(TRACK):
+        states[i, 0] = radius * math.cos(math.radians(lon))
+        states[i, 1] = radius * math.sin(math.radians(lon))
+    return ArrayEphemeris(times, states, frame="ITRS")
@@ -0,0 +1,106 @@
+"""Tests for link_geometry"""
+
+import math
+from typing import Any
+
+import numpy as np
+import pytest
+
+from rust_ephem import ArrayEphemeris, GroundEphemeris, link_geometry
+
+from .conftest import BEGIN, EARTH_EQUATORIAL_RADIUS_KM, STATION, STEP
+
+FREQUENCY_MHZ = 2200.0
+
+
+def test_geometry(spacecraft: ArrayEphemeris) -> None:
+    link = link_geometry(spacecraft, STATION, FREQUENCY_MHZ)
+
+    assert link.station == "Prime"
+    assert len(link) == 4
+    assert len(link.timestamp) == 4
+    assert link.elevation[0] == pytest.approx(90.0)
+    assert link.slant_range_km[0] == pytest.approx(
+        7000.0 - EARTH_EQUATORIAL_RADIUS_KM, abs=1e-6
+    )
+    # Moving east lowers the elevation and lengthens the path
+    assert link.elevation[1] < 90.0
+    assert link.azimuth[1] == pytest.approx(90.0)
+    assert np.all(np.diff(link.slant_range_km[:3]) > 0)
+    assert link.in_view == [True, True, True, False]
+
+
+def test_free_space_loss(spacecraft: ArrayEphemeris) -> None:
+    link = link_geometry(spacecraft, STATION, FREQUENCY_MHZ)
+    expected = (
+        20 * np.log10(link.slant_range_km) + 20 * math.log10(FREQUENCY_MHZ) + 32.45
+    )
+    np.testing.assert_allclose(link.free_space_loss_db, expected, atol=0.01)
+    # Without a zenith attenuation there is no atmospheric penalty
+    np.testing.assert_array_equal(link.atmospheric_loss_db, 0.0)
+    np.testing.assert_allclose(link.total_loss_db, link.free_space_loss_db)
+
+
+def test_atmospheric_loss(spacecraft: ArrayEphemeris) -> None:
+    link = link_geometry(spacecraft, STATION, FREQUENCY_MHZ, zenith_attenuation_db=0.5)
+    assert link.zenith_attenuation_db == 0.5
+    assert link.atmospheric_loss_db[0] == pytest.approx(0.5)
+    elevation = math.radians(link.elevation[1])
+    assert link.atmospheric_loss_db[1] == pytest.approx(
+        0.5 / math.sin(elevation), rel=0.05
+    )
+    # Below the horizon the penalty stays finite at its horizon value
+    assert math.isfinite(link.atmospheric_loss_db[3])
+    np.testing.assert_allclose(
+        link.total_loss_db, link.free_space_loss_db + link.atmospheric_loss_db
+    )
+
+
+def test_passes_and_loss_extremes(spacecraft: ArrayEphemeris) -> None:
+    link = link_geometry(spacecraft, STATION, FREQUENCY_MHZ)
+    assert len(link.passes) == 1
+    assert link.passes[0].start_time == BEGIN
+    assert link.passes[0].duration_seconds == 2 * STEP
+    assert link.min_total_loss_db == pytest.approx(link.total_loss_db[0])
+    assert link.max_total_loss_db == pytest.approx(link.total_loss_db[2])
+
+
+def test_min_elevation(spacecraft: ArrayEphemeris) -> None:
+    link = link_geometry(spacecraft, STATION, FREQUENCY_MHZ, min_elevation=89.0)
+    assert link.in_view == [True, False, False, False]
+    assert link.min_total_loss_db == link.max_total_loss_db
+
+
+def test_horizon_profile(spacecraft: ArrayEphemeris) -> None:
+    station = GroundEphemeris(
+        0.0, 0.0, 0.0, BEGIN, BEGIN.replace(minute=3), STEP, horizon=[(0.0, 30.0)]
+    )
+    link = link_geometry(spacecraft, station, FREQUENCY_MHZ)
+    assert link.in_view == [True, False, False, False]
+
+
+def test_no_passes(spacecraft: ArrayEphemeris) -> None:
+    link = link_geometry(spacecraft, ("East", 0.0, 90.0, 0.0), FREQUENCY_MHZ)
+    assert link.in_view == [False] * 4
+    assert link.passes == []
+    assert link.min_total_loss_db is None
+    assert link.max_total_loss_db is None
+
+
+@pytest.mark.parametrize(
+    "kwargs",
+    [
+        {"frequency_mhz": 0.0},
+        {"frequency_mhz": -1.0},
+        {"frequency_mhz": 2200.0, "zenith_attenuation_db": -0.1},
+        {"frequency_mhz": 2200.0, "min_elevation": 91.0},
+    ],
+)
+def test_invalid_arguments(spacecraft: ArrayEphemeris, kwargs: dict[str, Any]) -> None:
+    with pytest.raises(ValueError):
+        link_geometry(spacecraft, STATION, **kwargs)
+
+
+def test_unknown_site(spacecraft: ArrayEphemeris) -> None:
+    with pytest.raises(ValueError):
+        link_geometry(spacecraft, "Atlantis", FREQUENCY_MHZ)
@@ -0,0 +1,103 @@
+"""Fixtures for maneuver detection tests."""
+
+from datetime import datetime, timedelta, timezone
+
+import numpy as np
+import numpy.typing as npt
+import pytest
+
+# Two-body plus J2 constants matching the detector
+MU_EARTH = 398600.4418
+J2 = 1.08262668e-3
+J2_RADIUS_KM = 6378.1363
+
+BEGIN = datetime(2024, 1, 1, 0, 0, 0, tzinfo=timezone.utc)
+STEP = 60
+N_STATES = 120
+BURN_INDEX = 50
+BURN_M_S = 0.5
+
+N_TLES = 8
+SPACING_DAYS = 0.5
+FIRST_DAY = 264.51782528
+MEAN_MOTION = 15.72125391
+# Mean motion change per TLE from drag, and the jump of a reboost after TLE 3
+DECAY_PER_TLE = 0.0002
+REBOOST_TLE = 4
+REBOOST_MEAN_MOTION = -0.005
+
+
+def acceleration(r: npt.NDArray[np.float64]) -> npt.NDArray[np.float64]:
+    r2 = float(r @ r)
+    mu_r3 = MU_EARTH / r2**1.5
+    j2 = 1.5 * J2 * J2_RADIUS_KM**2 / r2
+    z2 = r[2] ** 2 / r2
+    factor = np.array([1.0 - 5.0 * z2, 1.0 - 5.0 * z2, 3.0 - 5.0 * z2])
+    return -mu_r3 * r * (1.0 + j2 * factor)
+
+
+def propagate(state: npt.NDArray[np.float64], dt: float) -> npt.NDArray[np.float64]:
+    """RK4 with 10 s steps"""
+
+    def derivative(s: npt.NDArray[np.float64]) -> npt.NDArray[np.float64]:
+        return np.concatenate([s[3:], acceleration(s[:3])])
+
+    h = dt / 6
+    for _ in range(6):
+        k1 = derivative(state)
+        k2 = derivative(state + h / 2 * k1)
+        k3 = derivative(state + h / 2 * k2)
+        k4 = derivative(state + h * k3)
+        state = state + h / 6 * (k1 + 2 * k2 + 2 * k3 + k4)
+    return state
+
+
+def state_history(burn_m_s: float) -> npt.NDArray[np.float64]:
+    """Inclined LEO states with a prograde burn just before BURN_INDEX"""
+    r = 6878.0
+    v = np.sqrt(MU_EARTH / r)
+    incl = np.radians(51.6)
+    state = np.array([r, 0.0, 0.0, 0.0, v * np.cos(incl), v * np.sin(incl)])
+    states = []
+    for i in range(N_STATES):
+        if i == BURN_INDEX:
+            velocity = state[3:]
+            state[3:] = velocity * (1 + burn_m_s / 1000 / np.linalg.norm(velocity))
+        states.append(state.copy())
+        state = propagate(state, STEP)
+    return np.array(states)
+
+
+@pytest.fixture
+def times() -> list[datetime]:
+    return [BEGIN + timedelta(seconds=STEP * i) for i in range(N_STATES)]
+
+
+def checksum(line: str) -> int:
+    """TLE modulo-10 checksum of the first 68 characters"""
+    return sum(int(c) if c.isdigit() else c == "-" for c in line[:68]) % 10
+
+
+def with_checksum(line: str) -> str:
+    return f"{line[:68]}{checksum(line)}"
+
+
+def make_tle(k: int) -> tuple[str, str]:
+    """The k-th TLE of a synthetic decaying history with one reboost"""
+    day = FIRST_DAY + k * SPACING_DAYS
+    mean_motion = MEAN_MOTION + DECAY_PER_TLE * k
+    if k >= REBOOST_TLE:
+        mean_motion += REBOOST_MEAN_MOTION
+    line1 = (
+        f"1 25544U 98067A   08{day:012.8f} -.00002182  00000-0 -11606-4 0  292 "
+    )
+    line2 = (
+        f"2 25544  51.6416 247.4627 0006703 130.5360 325.0288 {mean_motion:11.8f}"
+        "56353 "
+    )
+    return with_checksum(line1), with_checksum(line2)
+
+
+@pytest.fixture
+def tle_history() -> list[tuple[str, str]]:
+    return [make_tle(k) for k in range(N_TLES)]
@@ -0,0 +1,96 @@
+"""Tests for maneuver detection in state and TLE histories"""
+
+from datetime import datetime, timedelta
+
+import numpy as np
+import pytest
+
+from rust_ephem import ArrayEphemeris, ManeuverCandidate, detect_maneuvers
+
+from .conftest import (
+    BURN_INDEX,
+    BURN_M_S,
+    FIRST_DAY,
+    MEAN_MOTION,
+    MU_EARTH,
+    REBOOST_MEAN_MOTION,
+    REBOOST_TLE,
+    SPACING_DAYS,
+    make_tle,
+    state_history,
+)
+
+
+class TestStateHistory:
+    def test_quiet_orbit_has_no_candidates(self, times: list[datetime]) -> None:
+        eph = ArrayEphemeris(times, state_history(0.0))
+        assert detect_maneuvers(eph, delta_v_threshold=0.01) == []
+
+    def test_burn_is_detected(self, times: list[datetime]) -> None:
+        eph = ArrayEphemeris(times, state_history(BURN_M_S))
+        found = detect_maneuvers(eph, delta_v_threshold=0.01)
+        assert len(found) == 1
+        burn = found[0]
+        assert isinstance(burn, ManeuverCandidate)
+        assert burn.source == "states"
+        assert burn.window_start == times[BURN_INDEX - 1]
+        assert burn.window_end == times[BURN_INDEX]
+        assert burn.window_start < burn.epoch < burn.window_end
+        assert burn.delta_v_m_s == pytest.approx(BURN_M_S, rel=1e-3)
+        radial, in_track, cross_track = burn.delta_v_ric_m_s
+        assert in_track == pytest.approx(BURN_M_S, rel=1e-2)
+        assert abs(radial) < 0.05 and abs(cross_track) < 0.05
+        # Prograde burns raise the orbit
+        assert burn.delta_sma_km > 0
+
+    def test_threshold_above_burn(self, times: list[datetime]) -> None:
+        eph = ArrayEphemeris(times, state_history(BURN_M_S))
+        assert detect_maneuvers(eph, delta_v_threshold=1.0) == []
+
+
+class TestTLEHistory:
+    def test_reboost_is_detected(self, tle_history: list[tuple[str, str]]) -> None:
+        found = detect_maneuvers(tles=tle_history)
+        assert len(found) == 1
+        burn = found[0]
+        assert burn.source == "tle"
+        assert burn.delta_v_ric_m_s is None
+        day = FIRST_DAY + (REBOOST_TLE - 0.5) * SPACING_DAYS
+        expected = datetime(2008, 1, 1) + timedelta(days=day - 1)
+        assert abs(burn.epoch.replace(tzinfo=None) - expected) < timedelta(seconds=1)
+
+        # A lower mean motion means a higher orbit: Δa = -2a Δn / 3n
+        n = MEAN_MOTION * 2 * np.pi / 86400
+        a = (MU_EARTH / n**2) ** (1 / 3)
+        delta_sma = -2 * a * REBOOST_MEAN_MOTION / (3 * MEAN_MOTION)
+        assert burn.delta_sma_km == pytest.approx(delta_sma, rel=0.05)
+        v = np.sqrt(MU_EARTH / a)
+        assert burn.delta_v_m_s == pytest.approx(
+            1000 * v * delta_sma / (2 * a), rel=0.05
+        )
+
+    def test_steady_decay_is_not_a_maneuver(self) -> None:
+        tles = [make_tle(k) for k in range(REBOOST_TLE)]
+        assert detect_maneuvers(tles=tles) == []
+
+
+class TestInvalidInputs:
+    def test_ephemeris_and_tles(
+        self, times: list[datetime], tle_history: list[tuple[str, str]]
+    ) -> None:
+        eph = ArrayEphemeris(times, state_history(0.0))
+        with pytest.raises(ValueError, match="not both"):
+            detect_maneuvers(eph, tles=tle_history)
+
+    def test_too_few_tles(self, tle_history: list[tuple[str, str]]) -> None:
+        with pytest.raises(ValueError, match="three TLEs"):
+            detect_maneuvers(tles=tle_history[:2])
+
+    def test_thresholds_must_be_positive(
+        self, times: list[datetime], tle_history: list[tuple[str, str]]
+    ) -> None:
+        eph = ArrayEphemeris(times, state_history(0.0))
+        with pytest.raises(ValueError, match="delta_v_threshold"):
+            detect_maneuvers(eph, delta_v_threshold=0.0)
+        with pytest.raises(ValueError, match="sma_threshold_km"):
+            detect_maneuvers(tles=tle_history, sma_threshold_km=-1.0)
@@ -0,0 +1,21 @@
+"""Fixtures for ephemeris metadata tests."""
+
+from datetime import datetime, timedelta, timezone
+
+import numpy as np
+import pytest
+
+from rust_ephem import ArrayEphemeris
+
+BEGIN = datetime(2024, 1, 1, 0, 0, 0, tzinfo=timezone.utc)
+N_POINTS = 5
+STEP_MINUTES = 1
+
+
+@pytest.fixture
+def array_ephemeris() -> ArrayEphemeris:
+    times = [BEGIN + timedelta(minutes=STEP_MINUTES * i) for i in range(N_POINTS)]
+    states = np.zeros((N_POINTS, 6))
+    states[:, 0] = 7000.0
+    states[:, 4] = 7.5
+    return ArrayEphemeris(times, states)
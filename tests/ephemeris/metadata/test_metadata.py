@@ -0,0 +1,64 @@
+"""Tests for ephemeris metadata, summary and repr."""
+
+import json
+from datetime import datetime
+from typing import Any
+
+from rust_ephem import ArrayEphemeris
+
+from .conftest import BEGIN, N_POINTS, STEP_MINUTES
+
+
+class TestMetadata:
+    def test_keys_in_order(self, array_ephemeris: ArrayEphemeris) -> None:
+        assert list(array_ephemeris.metadata) == [
+            "type",
+            "frame",
+            "polar_motion",
+            "begin",
+            "end",
+            "step_size",
+            "n_times",
+            "frames",
+            "eop_available",
+            "ut1_available",
+            "fingerprint",
+        ]
+
+    def test_values(self, array_ephemeris: ArrayEphemeris) -> None:
+        metadata = array_ephemeris.metadata
+        assert metadata["type"] == "ArrayEphemeris"
+        assert metadata["frame"] == "GCRS"
+        assert metadata["polar_motion"] is False
+        assert datetime.fromisoformat(metadata["begin"]) == BEGIN
+        assert datetime.fromisoformat(metadata["end"]) == array_ephemeris.end
+        assert metadata["step_size"] == 60 * STEP_MINUTES
+        assert metadata["n_times"] == N_POINTS
+        assert "gcrs" in metadata["frames"]
+        assert metadata["fingerprint"] == array_ephemeris.fingerprint()
+
+    def test_json_serializable(self, array_ephemeris: ArrayEphemeris) -> None:
+        metadata = array_ephemeris.metadata
+        assert json.loads(json.dumps(metadata)) == metadata
+
+    def test_tle_source(self, tle_ephemeris: Any) -> None:
+        metadata = tle_ephemeris.metadata
+        assert metadata["type"] == "TLEEphemeris"
+        assert metadata["norad_id"] == 25544
+        assert datetime.fromisoformat(metadata["tle_epoch"]) == tle_ephemeris.tle_epoch
+
+
+class TestSummaryAndRepr:
+    def test_repr(self, array_ephemeris: ArrayEphemeris) -> None:
+        text = repr(array_ephemeris)
+        assert text.startswith("ArrayEphemeris(frame='GCRS', polar_motion=False, ")
+        assert f"n_times={N_POINTS})" in text
+
+    def test_tle_repr(self, tle_ephemeris: Any) -> None:
+        assert repr(tle_ephemeris).startswith("TLEEphemeris(norad_id=25544, ")
+
+    def test_summary(self, array_ephemeris: ArrayEphemeris) -> None:
+        lines = array_ephemeris.summary().splitlines()
+        assert lines[0] == "ArrayEphemeris"
+        assert len(lines) == len(array_ephemeris.metadata)
+        assert any(line.split() == ["n_times:", str(N_POINTS)] for line in lines[1:])
@@ -0,0 +1,89 @@
+"""Fixtures for minor planet (MPCORB) tests."""
+
+from collections.abc import Iterator
+from typing import Any
+
+import pytest
+
+import rust_ephem
+
+CERES_A = 2.7676569
+CERES_E = 0.0775571
+
+
+def mpcorb_line(
+    packed: str,
+    epoch: str,
+    elements: tuple[float, float, float, float, float, float, float],
+    readable: str,
+    h: float = 3.34,
+    g: float = 0.15,
+) -> str:
+    """Build a fixed-column MPCORB line
+
+    ``elements`` are (M, peri, node, incl, e, n, a) in MPC units.
+    """
+    m, peri, node, incl, e, n, a = elements
+    columns = [" "] * 202
+
+    def put(col: int, text: str) -> None:
+        columns[col - 1 : col - 1 + len(text)] = list(text)
+
+    put(1, f"{packed:<7}")
+    put(9, f"{h:5.2f}")
+    put(15, f"{g:5.2f}")
+    put(21, epoch)
+    put(27, f"{m:9.5f}")
+    put(38, f"{peri:9.5f}")
+    put(49, f"{node:9.5f}")
+    put(60, f"{incl:9.5f}")
+    put(71, f"{e:9.7f}")
+    put(81, f"{n:11.8f}")
+    put(93, f"{a:11.7f}")
+    put(106, "0 MPO492748  6751 115 1801-2019 0.60 M-v 30h Williams   0000")
+    put(167, f"{readable:<28}")
+    put(195, "20190915")
+    return "".join(columns)
+
+
+CERES_LINE = mpcorb_line(
+    "00001",
+    "K205V",
+    (162.68631, 73.73161, 80.28698, 10.58862, CERES_E, 0.21406009, CERES_A),
+    "(1) Ceres",
+)
+PROVISIONAL_LINE = mpcorb_line(
+    "K07Tf8A",
+    "K205V",
+    (12.34567, 123.45678, 234.56789, 5.43210, 0.1234567, 0.24790000, 2.5097800),
+    "2007 TA418",
+    h=17.2,
+)
+
+MPCORB_TEXT = (
+    "MINOR PLANET CENTER ORBIT DATABASE (MPCORB)\n"
+    "\n"
+    "Des'n     H     G   Epoch     M        Peri.      Node       Incl.       e            n           a        Reference #Obs #Opp    Arc    rms  Perts   Computer\n"
+    + "-" * 160
+    + "\n"
+    + CERES_LINE
+    + "\n\n"
+    + PROVISIONAL_LINE
+    + "\n"
+)
+
+
+@pytest.fixture
+def mpcorb_path(tmp_path: Any) -> str:
+    """Temporary MPCORB file with Ceres and one unnumbered object"""
+    path = tmp_path / "MPCORB.DAT"
+    path.write_text(MPCORB_TEXT)
+    return str(path)
+
+
+@pytest.fixture(autouse=True)
+def clean_registry() -> Iterator[None]:
+    """Keep registered minor planets from leaking between tests"""
+    rust_ephem.clear_minor_planets()
+    yield
+    rust_ephem.clear_minor_planets()
@@ -0,0 +1,108 @@
+"""Tests for minor planets loaded from MPC orbital elements"""
+
+from datetime import datetime, timedelta, timezone
+
+import numpy as np
+import pytest
+
+import rust_ephem
+from rust_ephem import MinorPlanet, SunConstraint
+
+from .conftest import CERES_A, CERES_E, CERES_LINE, PROVISIONAL_LINE
+
+AU_KM = 149_597_870.7
+
+
+def test_load_mpcorb_skips_header(mpcorb_path: str) -> None:
+    planets = rust_ephem.load_mpcorb(mpcorb_path, register=False)
+    assert [p.designation for p in planets] == ["(1) Ceres", "2007 TA418"]
+
+
+def test_numbered_object_attributes() -> None:
+    ceres = MinorPlanet.from_mpcorb_line(CERES_LINE)
+    assert ceres.number == 1
+    assert ceres.name == "Ceres"
+    assert ceres.h == pytest.approx(3.34)
+    assert ceres.g == pytest.approx(0.15)
+    assert ceres.semimajor_axis == pytest.approx(CERES_A)
+    assert ceres.eccentricity == pytest.approx(CERES_E)
+    assert ceres.inclination == pytest.approx(10.58862)
+    assert ceres.ascending_node == pytest.approx(80.28698)
+    assert ceres.argument_of_perihelion == pytest.approx(73.73161)
+    assert ceres.mean_anomaly == pytest.approx(162.68631)
+    # Epoch 2020-05-31 0h TT is 69.184 s earlier in UTC
+    expected = datetime(2020, 5, 31, tzinfo=timezone.utc) - timedelta(seconds=69.184)
+    assert abs(ceres.epoch - expected) < timedelta(milliseconds=1)
+
+
+def test_provisional_designation_unpacked() -> None:
+    planet = MinorPlanet.from_mpcorb_line(PROVISIONAL_LINE)
+    assert planet.number is None
+    assert planet.name is None
+    assert planet.designation == "2007 TA418"
+
+
+def test_heliocentric_distance_within_orbit() -> None:
+    ceres = MinorPlanet.from_mpcorb_line(CERES_LINE)
+    times = [ceres.epoch + timedelta(days=30 * k) for k in range(12)]
+    pv = ceres.heliocentric_pv(times)
+    assert pv.position.shape == (12, 3)
+    distance = np.linalg.norm(pv.position, axis=1) / AU_KM
+    assert np.all(distance > CERES_A * (1 - CERES_E) - 1e-6)
+    assert np.all(distance < CERES_A * (1 + CERES_E) + 1e-6)
+    # Ceres moves at roughly 16-19 km/s
+    speed = np.linalg.norm(pv.velocity, axis=1)
+    assert np.all((speed > 15.0) & (speed < 20.0))
+
+
+def test_invalid_lines_rejected() -> None:
+    with pytest.raises(ValueError, match="too short"):
+        MinorPlanet.from_mpcorb_line(CERES_LINE[:60])
+    hyperbolic = CERES_LINE.replace(f"{CERES_E:9.7f}", "1.0775571", 1)
+    with pytest.raises(ValueError, match="elliptic"):
+        MinorPlanet.from_mpcorb_line(hyperbolic)
+
+
+def test_registered_body_lookup(
+    mpcorb_path: str, tle_ephemeris: rust_ephem.TLEEphemeris
+) -> None:
+    planets = rust_ephem.load_mpcorb(mpcorb_path)
+    by_name = tle_ephemeris.get_body_pv("ceres")
+    by_number = tle_ephemeris.get_body_pv("(1)")
+    assert np.allclose(by_name.position, by_number.position)
+
+    # Observer-relative position is the heliocentric position plus the Sun's
+    # observer-relative position (up to light time, ~20,000 km)
+    sun = tle_ephemeris.get_body_pv("Sun")
+    helio = planets[0].heliocentric_pv(list(tle_ephemeris.timestamp))
+    diff = np.linalg.norm(by_name.position - (helio.position + sun.position), axis=1)
+    assert np.all(diff < 1e5)
+
+    unnamed = tle_ephemeris.get_body("2007 TA418")
+    assert len(unnamed) == len(tle_ephemeris.timestamp)
+
+
+def test_unregistered_body_unknown(
+    mpcorb_path: str, tle_ephemeris: rust_ephem.TLEEphemeris
+) -> None:
+    rust_ephem.load_mpcorb(mpcorb_path, register=False)
+    with pytest.raises(ValueError):
+        tle_ephemeris.get_body_pv("2007 TA418")
+
+    MinorPlanet.from_mpcorb_line(PROVISIONAL_LINE).register()
+    assert tle_ephemeris.get_body_pv("2007 TA418").position.shape[1] == 3
+
+    rust_ephem.clear_minor_planets()
+    with pytest.raises(ValueError):
+        tle_ephemeris.get_body_pv("2007 TA418")
+
+
+def test_moving_body_constraint(
+    mpcorb_path: str, tle_ephemeris: rust_ephem.TLEEphemeris
+) -> None:
+    rust_ephem.load_mpcorb(mpcorb_path)
+    result = SunConstraint(min_angle=45.0).evaluate_moving_body(
+        tle_ephemeris, body="Ceres"
+    )
+    assert len(result.ras) == len(tle_ephemeris.timestamp)
+    assert all(0.0 <= ra < 360.0 for ra in result.ras)
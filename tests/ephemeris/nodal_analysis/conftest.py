@@ -0,0 +1,53 @@
+"""Fixtures for nodal analysis tests."""
+
+from datetime import datetime, timedelta, timezone
+
+import pytest
+
+import rust_ephem
+
+ISS_TLE1 = "1 25544U 98067A   08264.51782528 -.00002182  00000-0 -11606-4 0  2927"
+ISS_TLE2 = "2 25544  51.6416 247.4627 0006703 130.5360 325.0288 15.72125391563537"
+ISS_MEAN_MOTION = 15.72125391
+ISS_BEGIN = datetime(2008, 9, 21, 0, 0, 0, tzinfo=timezone.utc)
+
+# Landsat-like 705 km sun-synchronous orbit
+SSO_INCLINATION = 98.2
+SSO_MEAN_MOTION = 14.571
+SSO_BEGIN = datetime(2024, 1, 1, 0, 0, 0, tzinfo=timezone.utc)
+
+DAYS = 2
+STEP = 60
+
+
+def checksum(line: str) -> int:
+    """TLE modulo-10 checksum of the first 68 characters"""
+    return sum(int(c) if c.isdigit() else c == "-" for c in line[:68]) % 10
+
+
+def with_checksum(line: str) -> str:
+    return f"{line[:68]}{checksum(line)}"
+
+
+def sso_tle() -> tuple[str, str]:
+    line1 = "1 39084U 13008A   24001.00000000  .00000000  00000-0  00000-0 0  999"
+    line2 = (
+        f"2 39084 {SSO_INCLINATION:8.4f}  60.0000 0001000  90.0000 270.0000 "
+        f"{SSO_MEAN_MOTION:11.8f}    1"
+    )
+    return with_checksum(line1), with_checksum(line2)
+
+
+@pytest.fixture
+def iss_ephemeris() -> rust_ephem.TLEEphemeris:
+    return rust_ephem.TLEEphemeris(
+        ISS_TLE1, ISS_TLE2, ISS_BEGIN, ISS_BEGIN + timedelta(days=DAYS), STEP
+    )
+
+
+@pytest.fixture
+def sso_ephemeris() -> rust_ephem.TLEEphemeris:
+    line1, line2 = sso_tle()
+    return rust_ephem.TLEEphemeris(
+        line1, line2, SSO_BEGIN, SSO_BEGIN + timedelta(days=DAYS), STEP
+    )
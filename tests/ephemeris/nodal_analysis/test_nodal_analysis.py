@@ -0,0 +1,97 @@
+"""Tests for nodal period, RAAN drift and repeat-cycle analysis"""
+
+from datetime import timedelta
+
+import numpy as np
+import pytest
+
+import rust_ephem
+from rust_ephem import NodalAnalysis, nodal_analysis
+
+from .conftest import (
+    ISS_BEGIN,
+    ISS_MEAN_MOTION,
+    ISS_TLE1,
+    ISS_TLE2,
+    SSO_INCLINATION,
+    STEP,
+)
+
+
+class TestISS:
+    def test_nodes_and_periods(self, iss_ephemeris: rust_ephem.TLEEphemeris) -> None:
+        nodes = nodal_analysis(iss_ephemeris)
+        assert isinstance(nodes, NodalAnalysis)
+        # About 15.7 revolutions per day
+        assert len(nodes) in (31, 32)
+        assert len(nodes.node_times) == len(nodes)
+        assert nodes.nodal_periods.shape == (len(nodes) - 1,)
+        assert nodes.mean_nodal_period == pytest.approx(
+            86400 / ISS_MEAN_MOTION, rel=0.01
+        )
+        np.testing.assert_allclose(
+            nodes.nodal_periods, nodes.mean_nodal_period, atol=5.0
+        )
+        assert nodes.mean_nodal_period == pytest.approx(
+            float(np.mean(nodes.nodal_periods))
+        )
+
+    def test_drift_and_inclination(
+        self, iss_ephemeris: rust_ephem.TLEEphemeris
+    ) -> None:
+        nodes = nodal_analysis(iss_ephemeris)
+        # J2 regresses the node of a 51.6 degree orbit by about 5 deg/day
+        assert -5.4 < nodes.raan_drift_deg_per_day < -4.8
+        np.testing.assert_allclose(nodes.inclination_deg, 51.64, atol=0.1)
+        assert np.all((nodes.raan_deg >= 0) & (nodes.raan_deg < 360))
+
+    def test_revolutions_per_day(self, iss_ephemeris: rust_ephem.TLEEphemeris) -> None:
+        nodes = nodal_analysis(iss_ephemeris)
+        assert nodes.revolutions_per_day == pytest.approx(
+            nodes.nodal_day / nodes.mean_nodal_period
+        )
+        assert nodes.revolutions_per_day == pytest.approx(ISS_MEAN_MOTION, rel=0.01)
+
+    def test_repeat_cycle_is_consistent(
+        self, iss_ephemeris: rust_ephem.TLEEphemeris
+    ) -> None:
+        nodes = nodal_analysis(iss_ephemeris, repeat_tolerance_km=50.0)
+        if nodes.repeat_cycle_days is None:
+            assert nodes.repeat_cycle_revolutions is None
+            assert nodes.repeat_shift_km is None
+        else:
+            assert abs(nodes.repeat_shift_km) <= 50.0
+            assert nodes.repeat_cycle_revolutions == round(
+                nodes.revolutions_per_day * nodes.repeat_cycle_days
+            )
+
+
+class TestSunSynchronous:
+    def test_drift_matches_sun(self, sso_ephemeris: rust_ephem.TLEEphemeris) -> None:
+        nodes = nodal_analysis(sso_ephemeris)
+        assert abs(nodes.sun_synchronous_offset_deg_per_day) < 0.03
+        assert nodes.raan_drift_deg_per_day == pytest.approx(0.9856, abs=0.03)
+        np.testing.assert_allclose(nodes.inclination_deg, SSO_INCLINATION, atol=0.1)
+
+    def test_max_repeat_days_limits_search(
+        self, sso_ephemeris: rust_ephem.TLEEphemeris
+    ) -> None:
+        nodes = nodal_analysis(
+            sso_ephemeris, max_repeat_days=1, repeat_tolerance_km=1.0
+        )
+        assert nodes.repeat_cycle_days is None
+
+
+class TestInvalidInputs:
+    def test_less_than_an_orbit(self) -> None:
+        eph = rust_ephem.TLEEphemeris(
+            ISS_TLE1, ISS_TLE2, ISS_BEGIN, ISS_BEGIN + timedelta(minutes=30), STEP
+        )
+        with pytest.raises(ValueError, match="at least two"):
+            nodal_analysis(eph)
+
+    def test_tolerance_must_be_positive(
+        self, iss_ephemeris: rust_ephem.TLEEphemeris
+    ) -> None:
+        with pytest.raises(ValueError, match="repeat_tolerance_km"):
+            nodal_analysis(iss_ephemeris, repeat_tolerance_km=0.0)
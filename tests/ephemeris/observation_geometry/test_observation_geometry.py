@@ -0,0 +1,86 @@
+"""Tests for the single-time observation geometry snapshot."""
+
+import datetime
+
+import numpy as np
+import pytest
+
+from rust_ephem import GroundEphemeris, TLEEphemeris
+
+from .conftest import BEGIN, TARGET_DEC, TARGET_RA
+
+# Whole globe, so the observer is always inside
+EVERYWHERE = [(-180.0, -90.0), (180.0, -90.0), (180.0, 90.0), (-180.0, 90.0)]
+
+
+class TestOrbitingObserver:
+    def test_angles_match_arrays(self, ephem: TLEEphemeris) -> None:
+        time = BEGIN + datetime.timedelta(minutes=30)
+        index = ephem.index(time)
+        geometry = ephem.geometry(TARGET_RA, TARGET_DEC, time)
+        assert geometry.index == index
+        assert geometry.time == time
+        assert geometry.sun_angle == pytest.approx(
+            ephem.sun_angle(TARGET_RA, TARGET_DEC)[index]
+        )
+        assert geometry.moon_angle == pytest.approx(
+            ephem.moon_angle(TARGET_RA, TARGET_DEC)[index]
+        )
+        assert geometry.earth_limb_angle == pytest.approx(
+            ephem.earth_limb_angle(TARGET_RA, TARGET_DEC)[index]
+        )
+
+    def test_nearest_step_is_used(self, ephem: TLEEphemeris) -> None:
+        time = BEGIN + datetime.timedelta(seconds=130)
+        assert ephem.geometry(TARGET_RA, TARGET_DEC, time).time == (
+            BEGIN + datetime.timedelta(seconds=120)
+        )
+
+    def test_orbit_fields(self, ephem: TLEEphemeris) -> None:
+        geometry = ephem.geometry(TARGET_RA, TARGET_DEC, BEGIN)
+        assert geometry.altitude_deg is None
+        assert geometry.airmass is None
+        assert geometry.in_eclipse == (geometry.sunlit_fraction < 1.0)
+        assert 0.0 <= geometry.argument_of_latitude < 360.0
+        assert 0.0 <= geometry.solar_phase < 360.0
+        # The ISS orbit is inclined 51.6 deg, so |beta| stays below ~75 deg
+        assert abs(geometry.beta_angle) < 75.0
+
+    def test_eclipse_matches_illumination(self, ephem: TLEEphemeris) -> None:
+        factor = ephem.illumination_factor
+        index = int(np.argmin(factor))
+        time = BEGIN + datetime.timedelta(seconds=120 * index)
+        geometry = ephem.geometry(TARGET_RA, TARGET_DEC, time)
+        assert geometry.in_eclipse
+        assert geometry.sunlit_fraction == pytest.approx(factor[index])
+
+    def test_saa_flag(self, ephem: TLEEphemeris) -> None:
+        assert ephem.geometry(TARGET_RA, TARGET_DEC, BEGIN).in_saa is None
+        geometry = ephem.geometry(
+            TARGET_RA, TARGET_DEC, BEGIN, saa_polygon=EVERYWHERE
+        )
+        assert geometry.in_saa is True
+        with pytest.raises(ValueError, match="saa_polygon"):
+            ephem.geometry(TARGET_RA, TARGET_DEC, BEGIN, saa_polygon=EVERYWHERE[:2])
+
+
+class TestGroundSite:
+    def test_altitude_and_airmass(self, site: GroundEphemeris) -> None:
+        time = BEGIN + datetime.timedelta(hours=1)
+        index = site.index(time)
+        geometry = site.geometry(TARGET_RA, TARGET_DEC, time)
+        altaz = site.radec_to_altaz(TARGET_RA, TARGET_DEC)
+        assert geometry.altitude_deg == pytest.approx(altaz[index, 0])
+        assert geometry.azimuth_deg == pytest.approx(altaz[index, 1])
+        assert geometry.airmass == pytest.approx(
+            site.calculate_airmass(TARGET_RA, TARGET_DEC)[index]
+        )
+        assert geometry.in_eclipse is None
+        assert geometry.beta_angle is None
+
+    def test_to_dict(self, site: GroundEphemeris) -> None:
+        geometry = site.geometry(TARGET_RA, TARGET_DEC, BEGIN)
+        fields = geometry.to_dict()
+        assert fields["sun_angle"] == geometry.sun_angle
+        assert fields["argument_of_latitude"] is None
+        assert "ObservationGeometry" in repr(geometry)
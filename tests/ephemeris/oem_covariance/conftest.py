@@ -0,0 +1,87 @@
+"""Fixtures for OEM covariance tests."""
+
+import math
+from datetime import datetime, timedelta, timezone
+from typing import Any
+
+import pytest
+
+BEGIN = datetime(2024, 1, 1, 0, 0, 0, tzinfo=timezone.utc)
+STEP = 60
+N_POINTS = 121
+
+# Circular equatorial orbit
+RADIUS_KM = 7000.0
+MU_EARTH = 398600.4418
+OMEGA = math.sqrt(MU_EARTH / RADIUS_KM**3)
+
+# 1-sigma RTN position uncertainty at the covariance epoch (km)
+SIGMA_RTN = (0.1, 0.2, 0.3)
+
+HEADER = """CCSDS_OEM_VERS = 2.0
+CREATION_DATE = 2024-01-01T00:00:00.000
+ORIGINATOR = TEST
+
+META_START
+OBJECT_NAME = TEST_SAT
+OBJECT_ID = 2024-001A
+CENTER_NAME = EARTH
+REF_FRAME = EME2000
+TIME_SYSTEM = UTC
+START_TIME = 2024-01-01T00:00:00.000
+STOP_TIME = 2024-01-01T02:00:00.000
+META_STOP
+
+"""
+
+
+def epoch(offset: float) -> str:
+    return (BEGIN + timedelta(seconds=offset)).strftime("%Y-%m-%dT%H:%M:%S.000")
+
+
+def state_lines() -> str:
+    """State vectors on a circular two-body orbit"""
+    v = RADIUS_KM * OMEGA
+    lines = []
+    for k in range(N_POINTS):
+        t = STEP * k
+        c, s = math.cos(OMEGA * t), math.sin(OMEGA * t)
+        lines.append(
+            f"{epoch(t)} {RADIUS_KM * c:.9f} {RADIUS_KM * s:.9f} 0.0 "
+            f"{-v * s:.12f} {v * c:.12f} 0.0"
+        )
+    return "\n".join(lines) + "\n"
+
+
+def covariance_block(
+    sigma: tuple[float, float, float] = SIGMA_RTN,
+    frame: str | None = "RTN",
+    n_values: int = 21,
+) -> str:
+    """Diagonal covariance matrix at BEGIN as lower-triangular rows"""
+    variances = [s**2 for s in sigma] + [1e-8, 1e-8, 1e-8]
+    values = []
+    for i in range(6):
+        values.append([variances[i] if i == j else 0.0 for j in range(i + 1)])
+    rows = [" ".join(f"{v:.6e}" for v in row) for row in values]
+    body = "\n".join(rows[:-1]) + "\n" + " ".join(rows[-1].split()[: n_values - 15])
+    frame_line = f"COV_REF_FRAME = {frame}\n" if frame else ""
+    return (
+        f"\nCOVARIANCE_START\nEPOCH = {epoch(0)}\n{frame_line}{body}\n"
+        "COVARIANCE_STOP\n"
+    )
+
+
+def write_oem(path: Any, covariance: str = "") -> str:
+    path.write_text(HEADER + state_lines() + covariance)
+    return str(path)
+
+
+@pytest.fixture
+def covariance_oem_path(tmp_path: Any) -> str:
+    return write_oem(tmp_path / "covariance.oem", covariance_block())
+
+
+@pytest.fixture
+def plain_oem_path(tmp_path: Any) -> str:
+    return write_oem(tmp_path / "plain.oem")
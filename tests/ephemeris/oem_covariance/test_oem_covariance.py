@@ -0,0 +1,104 @@
+"""Tests for OEM covariance parsing and position uncertainty propagation"""
+
+from datetime import timedelta
+from typing import Any
+
+import numpy as np
+import pytest
+
+from rust_ephem import Constraint, OEMEphemeris
+from rust_ephem.constraints import EarthLimbConstraint
+
+from .conftest import BEGIN, SIGMA_RTN, covariance_block, write_oem
+
+END = BEGIN + timedelta(hours=2)
+
+
+def test_covariance_is_parsed(covariance_oem_path: str) -> None:
+    eph = OEMEphemeris(covariance_oem_path, begin=BEGIN, end=END, step_size=60)
+    cov = eph.covariance
+    assert cov.shape == (1, 6, 6)
+    np.testing.assert_allclose(cov[0], cov[0].T)
+    assert eph.covariance_timestamp == [BEGIN]
+
+
+def test_uncertainty_at_covariance_epoch(covariance_oem_path: str) -> None:
+    eph = OEMEphemeris(covariance_oem_path, begin=BEGIN, end=END, step_size=60)
+    sigma = eph.position_uncertainty
+    assert sigma.shape == (len(eph.timestamp), 3)
+    np.testing.assert_allclose(sigma[0], SIGMA_RTN, rtol=1e-9)
+
+
+def test_rtn_covariance_rotated_to_inertial(covariance_oem_path: str) -> None:
+    eph = OEMEphemeris(covariance_oem_path, begin=BEGIN, end=END, step_size=60)
+    # At BEGIN the orbit is at +x moving along +y, so RTN maps to x, y, z
+    np.testing.assert_allclose(
+        np.sqrt(np.diag(eph.covariance[0])[:3]), SIGMA_RTN, rtol=1e-9
+    )
+
+
+def test_in_track_uncertainty_grows(covariance_oem_path: str) -> None:
+    eph = OEMEphemeris(covariance_oem_path, begin=BEGIN, end=END, step_size=60)
+    in_track = eph.position_uncertainty[:, 1]
+    assert in_track[-1] > 5 * in_track[0]
+    assert np.all(np.isfinite(eph.position_uncertainty))
+
+
+def test_inertial_covariance_frame(tmp_path: Any) -> None:
+    path = write_oem(tmp_path / "eme.oem", covariance_block(frame="EME2000"))
+    eph = OEMEphemeris(path, begin=BEGIN, end=END, step_size=60)
+    np.testing.assert_allclose(eph.position_uncertainty[0], SIGMA_RTN, rtol=1e-9)
+
+
+def test_without_covariance(plain_oem_path: str) -> None:
+    eph = OEMEphemeris(plain_oem_path, begin=BEGIN, end=END, step_size=60)
+    assert eph.position_uncertainty is None
+    assert eph.covariance.shape == (0, 6, 6)
+    assert eph.covariance_timestamp == []
+
+
+def test_incomplete_covariance_raises(tmp_path: Any) -> None:
+    path = write_oem(tmp_path / "short.oem", covariance_block(n_values=20))
+    with pytest.raises(ValueError, match="Incomplete covariance"):
+        OEMEphemeris(path, begin=BEGIN, end=END, step_size=60)
+
+
+def test_unsupported_covariance_frame_raises(tmp_path: Any) -> None:
+    path = write_oem(tmp_path / "tnw.oem", covariance_block(frame="TNW"))
+    with pytest.raises(ValueError, match="Unsupported covariance reference frame"):
+        OEMEphemeris(path, begin=BEGIN, end=END, step_size=60)
+
+
+class TestEarthLimbUncertaintyMargin:
+    def test_margin_widens_violations(self, tmp_path: Any) -> None:
+        path = write_oem(
+            tmp_path / "large.oem", covariance_block(sigma=(100.0, 100.0, 100.0))
+        )
+        eph = OEMEphemeris(path, begin=BEGIN, end=END, step_size=60)
+        plain = Constraint.earth_limb(min_angle=5.0)
+        inflated = Constraint.earth_limb(min_angle=5.0, uncertainty_sigma=3.0)
+        plain_result = plain.evaluate(eph, target_ra=90.0, target_dec=0.0)
+        inflated_result = inflated.evaluate(eph, target_ra=90.0, target_dec=0.0)
+        plain_violated = plain_result.constraint_array
+        inflated_violated = inflated_result.constraint_array
+        assert np.all(inflated_violated >= plain_violated)
+        assert inflated_violated.sum() > plain_violated.sum()
+
+    def test_no_effect_without_covariance(self, plain_oem_path: str) -> None:
+        eph = OEMEphemeris(plain_oem_path, begin=BEGIN, end=END, step_size=60)
+        plain = Constraint.earth_limb(min_angle=5.0)
+        inflated = Constraint.earth_limb(min_angle=5.0, uncertainty_sigma=3.0)
+        np.testing.assert_array_equal(
+            plain.evaluate(eph, 90.0, 0.0).constraint_array,
+            inflated.evaluate(eph, 90.0, 0.0).constraint_array,
+        )
+
+    def test_negative_sigma_rejected(self) -> None:
+        with pytest.raises(ValueError, match="uncertainty_sigma"):
+            Constraint.earth_limb(min_angle=5.0, uncertainty_sigma=-1.0)
+
+    def test_pydantic_model(self) -> None:
+        constraint = EarthLimbConstraint(min_angle=5.0, uncertainty_sigma=2.0)
+        assert constraint.uncertainty_sigma == 2.0
+        rebuilt = Constraint.from_json(constraint.model_dump_json())
+        assert "EarthLimb" in repr(rebuilt)
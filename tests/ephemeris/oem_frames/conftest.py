@@ -0,0 +1,84 @@
+"""Fixtures for OEM reference frame tests."""
+
+import math
+from datetime import datetime, timedelta, timezone
+from typing import Any
+
+import numpy as np
+import pytest
+
+from rust_ephem import OEMEphemeris
+
+BEGIN = datetime(2024, 1, 1, 0, 0, 0, tzinfo=timezone.utc)
+STEP = 60
+N_POINTS = 61
+END = BEGIN + timedelta(seconds=STEP * (N_POINTS - 1))
+
+# Inclined circular orbit
+RADIUS_KM = 7000.0
+MU_EARTH = 398600.4418
+OMEGA = math.sqrt(MU_EARTH / RADIUS_KM**3)
+INCLINATION = math.radians(51.6)
+
+
+def epoch(offset: float) -> str:
+    return (BEGIN + timedelta(seconds=offset)).strftime("%Y-%m-%dT%H:%M:%S.000")
+
+
+def circular_states() -> np.ndarray:
+    """Two-body states at each OEM epoch as an (N, 6) array"""
+    v = RADIUS_KM * OMEGA
+    ci, si = math.cos(INCLINATION), math.sin(INCLINATION)
+    rows = []
+    for k in range(N_POINTS):
+        c, s = math.cos(OMEGA * STEP * k), math.sin(OMEGA * STEP * k)
+        rows.append(
+            [
+                RADIUS_KM * c,
+                RADIUS_KM * s * ci,
+                RADIUS_KM * s * si,
+                -v * s,
+                v * c * ci,
+                v * c * si,
+            ]
+        )
+    return np.array(rows)
+
+
+def segment(
+    states: np.ndarray,
+    ref_frame: str,
+    center_name: str = "EARTH",
+    first: int = 0,
+) -> str:
+    lines = [
+        "META_START",
+        "OBJECT_NAME = TEST_SAT",
+        "OBJECT_ID = 2024-001A",
+        f"CENTER_NAME = {center_name}",
+        f"REF_FRAME = {ref_frame}",
+        "TIME_SYSTEM = UTC",
+        "META_STOP",
+        "",
+    ]
+    for k, row in enumerate(states):
+        values = " ".join(f"{x:.12f}" for x in row)
+        lines.append(f"{epoch(STEP * (first + k))} {values}")
+    return "\n".join(lines) + "\n\n"
+
+
+def write_oem(path: Any, *segments: str) -> str:
+    header = "CCSDS_OEM_VERS = 2.0\nCREATION_DATE = 2024-01-01T00:00:00.000\n"
+    path.write_text(header + "ORIGINATOR = TEST\n\n" + "".join(segments))
+    return str(path)
+
+
+def load(path: str) -> OEMEphemeris:
+    return OEMEphemeris(path, begin=BEGIN, end=END, step_size=STEP)
+
+
+@pytest.fixture
+def gcrs_ephemeris(tmp_path: Any) -> OEMEphemeris:
+    """Reference ephemeris declared in EME2000, sampled at the OEM epochs"""
+    path = write_oem(tmp_path / "eme2000.oem", segment(circular_states(), "EME2000"))
+    return load(path)
@@ -0,0 +1,104 @@
+"""Tests for honoring the REF_FRAME and CENTER_NAME declared in OEM files"""
+
+from typing import Any
+
+import numpy as np
+import pytest
+
+from rust_ephem import OEMEphemeris
+
+from .conftest import circular_states, load, segment, write_oem
+
+
+def states_of(pv: Any) -> np.ndarray:
+    return np.hstack([pv.position, pv.velocity])
+
+
+def test_declared_metadata(gcrs_ephemeris: OEMEphemeris) -> None:
+    assert gcrs_ephemeris.ref_frame == "EME2000"
+    assert gcrs_ephemeris.center_name == "EARTH"
+    assert gcrs_ephemeris.metadata["ref_frame"] == "EME2000"
+    assert gcrs_ephemeris.metadata["center_name"] == "EARTH"
+
+
+def test_gcrs_frames_are_not_rotated(gcrs_ephemeris: OEMEphemeris) -> None:
+    np.testing.assert_allclose(
+        states_of(gcrs_ephemeris.gcrs_pv), circular_states(), atol=1e-9
+    )
+
+
+def test_itrf_is_converted_to_gcrs(
+    gcrs_ephemeris: OEMEphemeris, tmp_path: Any
+) -> None:
+    itrs = states_of(gcrs_ephemeris.itrs_pv)
+    eph = load(write_oem(tmp_path / "itrf.oem", segment(itrs, "ITRF2014")))
+    assert eph.ref_frame == "ITRF2014"
+    np.testing.assert_allclose(
+        states_of(eph.gcrs_pv), states_of(gcrs_ephemeris.gcrs_pv), atol=1e-6
+    )
+
+
+def test_teme_is_converted_to_gcrs(
+    gcrs_ephemeris: OEMEphemeris, tmp_path: Any
+) -> None:
+    teme = states_of(gcrs_ephemeris.teme_pv)
+    eph = load(write_oem(tmp_path / "teme.oem", segment(teme, "TEME")))
+    np.testing.assert_allclose(
+        states_of(eph.gcrs_pv), states_of(gcrs_ephemeris.gcrs_pv), atol=1e-6
+    )
+
+
+def test_tod_is_rotated_by_precession(
+    gcrs_ephemeris: OEMEphemeris, tmp_path: Any
+) -> None:
+    eph = load(write_oem(tmp_path / "tod.oem", segment(circular_states(), "TOD")))
+    gcrs = eph.gcrs_pv.position
+    reference = gcrs_ephemeris.gcrs_pv.position
+    # A pure rotation: radii are unchanged
+    np.testing.assert_allclose(
+        np.linalg.norm(gcrs, axis=1), np.linalg.norm(reference, axis=1), rtol=1e-12
+    )
+    # Roughly 24 years of precession since J2000 (about 0.33 degrees)
+    cos_angle = np.sum(gcrs * reference, axis=1) / np.linalg.norm(gcrs, axis=1) ** 2
+    angle = np.degrees(np.arccos(np.clip(cos_angle, -1.0, 1.0)))
+    assert np.all((angle > 0.02) & (angle < 0.5))
+
+
+def test_frame_names_are_case_insensitive(tmp_path: Any) -> None:
+    eph = load(write_oem(tmp_path / "lower.oem", segment(circular_states(), "gcrf")))
+    assert eph.ref_frame == "gcrf"
+
+
+def test_unsupported_frame_raises(tmp_path: Any) -> None:
+    path = write_oem(tmp_path / "mci.oem", segment(circular_states(), "MCI"))
+    with pytest.raises(ValueError, match="Unsupported reference frame 'MCI'"):
+        load(path)
+
+
+def test_non_earth_center_raises(tmp_path: Any) -> None:
+    path = write_oem(
+        tmp_path / "moon.oem", segment(circular_states(), "ICRF", center_name="MOON")
+    )
+    with pytest.raises(ValueError, match="Unsupported CENTER_NAME 'MOON'"):
+        load(path)
+
+
+def test_mixed_segment_frames_raise(tmp_path: Any) -> None:
+    states = circular_states()
+    path = write_oem(
+        tmp_path / "mixed.oem",
+        segment(states[:30], "EME2000"),
+        segment(states[30:], "ITRF", first=30),
+    )
+    with pytest.raises(ValueError, match="different reference frames"):
+        load(path)
+
+
+def test_matching_segment_frames_load(tmp_path: Any) -> None:
+    states = circular_states()
+    path = write_oem(
+        tmp_path / "segments.oem",
+        segment(states[:30], "J2000"),
+        segment(states[30:], "J2000", first=30),
+    )
+    assert len(load(path).oem_timestamp) == len(states)
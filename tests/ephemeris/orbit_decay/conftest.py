@@ -0,0 +1,52 @@
+"""Fixtures for orbit decay estimation tests."""
+
+from typing import Any
+
+import pytest
+
+# Fixed solar and geomagnetic activity, so the tests need no space-weather data
+ACTIVITY = {"f107": 150.0, "ap": 15.0}
+
+BSTAR = 1.1606e-5
+MEAN_MOTION = 15.72125391
+
+
+def checksum(line: str) -> int:
+    """TLE modulo-10 checksum of the first 68 characters"""
+    return sum(int(c) if c.isdigit() else c == "-" for c in line[:68]) % 10
+
+
+def with_checksum(line: str) -> str:
+    return f"{line[:68]}{checksum(line)}"
+
+
+def make_tle(bstar_field: str) -> tuple[str, str]:
+    """An ISS TLE (about 350 km) with the given BSTAR field"""
+    line1 = (
+        f"1 25544U 98067A   08264.51782528 -.00002182  00000-0 {bstar_field} 0  292 "
+    )
+    line2 = "2 25544  51.6416 247.4627 0006703 130.5360 325.0288 15.72125391563537"
+    return with_checksum(line1), with_checksum(line2)
+
+
+@pytest.fixture
+def iss_tle() -> tuple[str, str]:
+    return make_tle(" 11606-4")
+
+
+@pytest.fixture
+def iss_tle_negative_bstar() -> tuple[str, str]:
+    return make_tle("-11606-4")
+
+
+@pytest.fixture
+def iss_omm() -> dict[str, Any]:
+    """The same elements as an OMM record with Space-Track style string values"""
+    return {
+        "OBJECT_NAME": "ISS (ZARYA)",
+        "EPOCH": "2008-09-20T12:25:40.104192",
+        "MEAN_MOTION": str(MEAN_MOTION),
+        "ECCENTRICITY": "0.0006703",
+        "INCLINATION": "51.6416",
+        "BSTAR": str(BSTAR),
+    }
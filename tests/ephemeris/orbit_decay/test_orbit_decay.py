@@ -0,0 +1,92 @@
+"""Tests for orbit decay and re-entry estimation."""
+
+from datetime import datetime, timedelta, timezone
+from typing import Any
+
+import numpy as np
+import pytest
+
+import rust_ephem
+
+from .conftest import ACTIVITY, BSTAR
+
+
+def test_ballistic_coefficient_from_bstar(iss_tle: tuple[str, str]) -> None:
+    est = rust_ephem.estimate_decay(iss_tle, max_days=30.0, **ACTIVITY)
+    assert est.ballistic_coefficient == pytest.approx(1 / (12.74 * BSTAR), rel=1e-3)
+    epoch = datetime(2008, 9, 20, 12, 25, 40, 104192, tzinfo=timezone.utc)
+    assert abs(est.epoch - epoch) < timedelta(milliseconds=1)
+
+
+def test_heavy_object_survives(iss_tle: tuple[str, str]) -> None:
+    est = rust_ephem.estimate_decay(iss_tle, max_days=30.0, **ACTIVITY)
+    assert est.reentry_time is None
+    assert est.lifetime_days is None
+    assert est.altitude_km[0] == pytest.approx(350.0, abs=15.0)
+    assert np.all(np.diff(est.altitude_km) < 0)
+    assert est.times[-1] - est.epoch == timedelta(days=30)
+    assert len(est) == len(est.times) == len(est.decay_rate_km_per_day)
+
+
+def test_light_object_reenters(iss_tle: tuple[str, str]) -> None:
+    est = rust_ephem.estimate_decay(iss_tle, ballistic_coefficient=50.0, **ACTIVITY)
+    assert est.ballistic_coefficient == 50.0
+    assert est.reentry_time is not None
+    assert est.lifetime_days is not None
+    assert 10.0 < est.lifetime_days < 365.0
+    elapsed = (est.reentry_time - est.epoch).total_seconds()
+    assert elapsed == pytest.approx(est.lifetime_days * 86400.0, abs=1.0)
+    # Decay accelerates as the orbit sinks
+    assert np.all(np.diff(est.decay_rate_km_per_day) > 0)
+    assert est.initial_decay_rate_km_per_day == est.decay_rate_km_per_day[0]
+    assert est.altitude_km[-1] > est.reentry_altitude_km
+
+
+def test_solar_activity_shortens_lifetime(iss_tle: tuple[str, str]) -> None:
+    def lifetime(f107: float) -> float:
+        est = rust_ephem.estimate_decay(
+            iss_tle, ballistic_coefficient=50.0, f107=f107, ap=10.0
+        )
+        assert est.lifetime_days is not None
+        np.testing.assert_array_equal(est.f107, f107)
+        return est.lifetime_days
+
+    assert lifetime(200.0) < lifetime(70.0)
+
+
+def test_omm_matches_tle(iss_tle: tuple[str, str], iss_omm: dict[str, Any]) -> None:
+    from_tle = rust_ephem.estimate_decay(iss_tle, max_days=10.0, **ACTIVITY)
+    from_omm = rust_ephem.estimate_decay(iss_omm, max_days=10.0, **ACTIVITY)
+    assert from_omm.ballistic_coefficient == pytest.approx(
+        from_tle.ballistic_coefficient
+    )
+    np.testing.assert_allclose(from_omm.altitude_km, from_tle.altitude_km)
+
+
+class TestValidation:
+    def test_negative_bstar_needs_ballistic_coefficient(
+        self, iss_tle_negative_bstar: tuple[str, str]
+    ) -> None:
+        with pytest.raises(ValueError, match="ballistic_coefficient"):
+            rust_ephem.estimate_decay(iss_tle_negative_bstar, **ACTIVITY)
+        est = rust_ephem.estimate_decay(
+            iss_tle_negative_bstar,
+            ballistic_coefficient=200.0,
+            max_days=1.0,
+            **ACTIVITY,
+        )
+        assert est.ballistic_coefficient == 200.0
+
+    def test_eccentric_orbit_rejected(self, iss_omm: dict[str, Any]) -> None:
+        iss_omm["ECCENTRICITY"] = 0.3
+        with pytest.raises(ValueError, match="near-circular"):
+            rust_ephem.estimate_decay(iss_omm, **ACTIVITY)
+
+    def test_already_reentered(self, iss_tle: tuple[str, str]) -> None:
+        with pytest.raises(ValueError, match="reentry_altitude_km"):
+            rust_ephem.estimate_decay(iss_tle, reentry_altitude_km=400.0, **ACTIVITY)
+
+    def test_incomplete_omm(self, iss_omm: dict[str, Any]) -> None:
+        del iss_omm["BSTAR"]
+        with pytest.raises(ValueError, match="BSTAR"):
+            rust_ephem.estimate_decay(iss_omm, **ACTIVITY)
@@ -0,0 +1,26 @@
+"""Fixtures for polar motion plumbing tests."""
+
+import datetime
+
+import pytest
+
+from rust_ephem import GroundEphemeris
+
+BEGIN = datetime.datetime(2024, 1, 1, 0, 0, 0, tzinfo=datetime.timezone.utc)
+END = datetime.datetime(2024, 1, 1, 6, 0, 0, tzinfo=datetime.timezone.utc)
+STEP_SIZE = 1800
+
+TARGET_RAS = [83.82, 201.37]
+TARGET_DECS = [-5.39, -43.02]
+
+
+@pytest.fixture
+def site() -> GroundEphemeris:
+    return GroundEphemeris(35.0, -120.0, 500.0, BEGIN, END, STEP_SIZE)
+
+
+@pytest.fixture
+def site_polar_motion() -> GroundEphemeris:
+    return GroundEphemeris(
+        35.0, -120.0, 500.0, BEGIN, END, STEP_SIZE, polar_motion=True
+    )
@@ -0,0 +1,76 @@
+"""Tests for the polar motion flag and its per-call override."""
+
+import numpy as np
+
+import rust_ephem
+from rust_ephem import GroundEphemeris
+
+from .conftest import BEGIN, END, STEP_SIZE, TARGET_DECS, TARGET_RAS
+
+# Polar motion moves the pole by well under an arcsecond
+MAX_SHIFT_DEG = 1e-3
+
+
+def test_default_follows_ephemeris_flag(
+    site: GroundEphemeris, site_polar_motion: GroundEphemeris
+) -> None:
+    ra, dec = TARGET_RAS[0], TARGET_DECS[0]
+    np.testing.assert_allclose(
+        site.radec_to_altaz(ra, dec),
+        site.radec_to_altaz(ra, dec, polar_motion=False),
+    )
+    np.testing.assert_allclose(
+        site_polar_motion.radec_to_altaz(ra, dec),
+        site_polar_motion.radec_to_altaz(ra, dec, polar_motion=True),
+    )
+
+
+def test_override_changes_altaz_by_less_than_an_arcsecond(
+    site: GroundEphemeris,
+) -> None:
+    ra, dec = TARGET_RAS[1], TARGET_DECS[1]
+    with_pm = site.radec_to_altaz(ra, dec, polar_motion=True)
+    without_pm = site.radec_to_altaz(ra, dec, polar_motion=False)
+    assert np.max(np.abs(with_pm[:, 0] - without_pm[:, 0])) < MAX_SHIFT_DEG
+
+
+def test_without_eop_override_has_no_effect(site: GroundEphemeris) -> None:
+    if rust_ephem.is_eop_available():
+        return
+    ra, dec = TARGET_RAS[0], TARGET_DECS[0]
+    np.testing.assert_array_equal(
+        site.radec_to_altaz(ra, dec, polar_motion=True),
+        site.radec_to_altaz(ra, dec, polar_motion=False),
+    )
+
+
+def test_batch_override_matches_single(site: GroundEphemeris) -> None:
+    batch = site.radec_to_altaz_batch(TARGET_RAS, TARGET_DECS, polar_motion=True)
+    for i, (ra, dec) in enumerate(zip(TARGET_RAS, TARGET_DECS)):
+        np.testing.assert_allclose(
+            batch[i], site.radec_to_altaz(ra, dec, polar_motion=True)
+        )
+
+
+def test_metadata_reports_whether_applied(
+    site: GroundEphemeris, site_polar_motion: GroundEphemeris
+) -> None:
+    assert site.metadata["polar_motion_applied"] is False
+    assert (
+        site_polar_motion.metadata["polar_motion_applied"]
+        is rust_ephem.is_eop_available()
+    )
+
+
+def test_link_geometry_override(site_polar_motion: GroundEphemeris) -> None:
+    # A fixed point 500 km up stands in for a spacecraft
+    spacecraft = GroundEphemeris(35.0, -119.0, 500_000.0, BEGIN, END, STEP_SIZE)
+    with_pm = rust_ephem.link_geometry(
+        spacecraft, site_polar_motion, 2200.0, polar_motion=True
+    )
+    without_pm = rust_ephem.link_geometry(
+        spacecraft, site_polar_motion, 2200.0, polar_motion=False
+    )
+    np.testing.assert_allclose(
+        with_pm.slant_range_km, without_pm.slant_range_km, atol=0.1
+    )
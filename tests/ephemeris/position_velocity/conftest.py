@@ -0,0 +1,22 @@
+"""Fixtures for PositionVelocityData arithmetic tests."""
+
+import datetime
+
+import pytest
+
+from rust_ephem import GroundEphemeris
+
+BEGIN = datetime.datetime(2024, 1, 1, 0, 0, 0, tzinfo=datetime.timezone.utc)
+END = datetime.datetime(2024, 1, 1, 6, 0, 0, tzinfo=datetime.timezone.utc)
+STEP_SIZE = 1800
+
+
+@pytest.fixture
+def site() -> GroundEphemeris:
+    return GroundEphemeris(35.0, -120.0, 500.0, BEGIN, END, STEP_SIZE)
+
+
+@pytest.fixture
+def short_site() -> GroundEphemeris:
+    end = BEGIN + datetime.timedelta(hours=1)
+    return GroundEphemeris(35.0, -120.0, 500.0, BEGIN, end, STEP_SIZE)
@@ -0,0 +1,72 @@
+"""Tests for PositionVelocityData arithmetic, norms and unit helpers."""
+
+import astropy.units as u  # type: ignore[import-untyped]
+import numpy as np
+import pytest
+
+from rust_ephem import GroundEphemeris
+
+
+class TestArithmetic:
+    def test_subtraction_gives_relative_vectors(self, site: GroundEphemeris) -> None:
+        relative = site.sun_pv - site.gcrs_pv
+        np.testing.assert_allclose(
+            relative.position, site.sun_pv.position - site.gcrs_pv.position
+        )
+        np.testing.assert_allclose(
+            relative.velocity, site.sun_pv.velocity - site.gcrs_pv.velocity
+        )
+
+    def test_addition_undoes_subtraction(self, site: GroundEphemeris) -> None:
+        restored = (site.sun_pv - site.gcrs_pv) + site.gcrs_pv
+        np.testing.assert_allclose(restored.position, site.sun_pv.position)
+
+    def test_negation(self, site: GroundEphemeris) -> None:
+        np.testing.assert_array_equal((-site.gcrs_pv).position, -site.gcrs_pv.position)
+
+    def test_shape_mismatch_raises(
+        self, site: GroundEphemeris, short_site: GroundEphemeris
+    ) -> None:
+        with pytest.raises(ValueError):
+            site.gcrs_pv - short_site.gcrs_pv
+
+    def test_other_types_are_not_supported(self, site: GroundEphemeris) -> None:
+        with pytest.raises(TypeError):
+            site.gcrs_pv + 1.0  # type: ignore[operator]
+
+
+class TestNormsAndUnits:
+    def test_distance_is_position_norm(self, site: GroundEphemeris) -> None:
+        pv = site.gcrs_pv
+        assert pv.distance.shape == (len(pv),)
+        np.testing.assert_allclose(pv.distance, np.linalg.norm(pv.position, axis=1))
+        assert np.all((pv.distance > 6300.0) & (pv.distance < 6400.0))
+
+    def test_speed_is_velocity_norm(self, site: GroundEphemeris) -> None:
+        pv = site.gcrs_pv
+        np.testing.assert_allclose(pv.speed, np.linalg.norm(pv.velocity, axis=1))
+
+    def test_distance_to(self, site: GroundEphemeris) -> None:
+        np.testing.assert_allclose(
+            site.sun_pv.distance_to(site.gcrs_pv),
+            (site.sun_pv - site.gcrs_pv).distance,
+        )
+
+    def test_meters(self, site: GroundEphemeris) -> None:
+        pv = site.gcrs_pv
+        np.testing.assert_allclose(pv.position_m, pv.position * 1000.0)
+        np.testing.assert_allclose(pv.velocity_m_s, pv.velocity * 1000.0)
+
+
+class TestToAstropy:
+    def test_representation_units_and_values(self, site: GroundEphemeris) -> None:
+        pv = site.gcrs_pv
+        rep = pv.to_astropy()
+        assert rep.shape == (len(pv),)
+        np.testing.assert_allclose(rep.x.to_value(u.km), pv.position[:, 0])
+        np.testing.assert_allclose(rep.z.to_value(u.m), pv.position_m[:, 2])
+
+    def test_differential_attached(self, site: GroundEphemeris) -> None:
+        pv = site.gcrs_pv
+        diff = pv.to_astropy().differentials["s"]
+        np.testing.assert_allclose(diff.d_y.to_value(u.km / u.s), pv.velocity[:, 1])
@@ -0,0 +1,37 @@
+"""Fixtures for provenance records."""
+
+from datetime import datetime, timedelta, timezone
+
+import numpy as np
+import pytest
+
+from rust_ephem import ArrayEphemeris, Constraint, TLEEphemeris
+
+BEGIN = datetime(2024, 1, 1, 0, 0, 0, tzinfo=timezone.utc)
+N_POINTS = 5
+
+TLE1 = "1 25544U 98067A   25315.25818480  .00012468  00000-0  22984-3 0  9991"
+TLE2 = "2 25544  51.6338 298.3179 0004133  57.8977 302.2413 15.49525392537972"
+TLE_BEGIN = datetime(2025, 11, 11, 0, 0, 0, tzinfo=timezone.utc)
+TLE_END = TLE_BEGIN + timedelta(hours=1)
+
+
+@pytest.fixture
+def spacecraft() -> ArrayEphemeris:
+    times = [BEGIN + timedelta(minutes=i) for i in range(N_POINTS)]
+    states = np.zeros((N_POINTS, 6))
+    states[:, 0] = 7000.0
+    states[:, 4] = 7.5
+    return ArrayEphemeris(times, states)
+
+
+@pytest.fixture
+def tle_ephemeris() -> TLEEphemeris:
+    return TLEEphemeris(
+        tle1=TLE1, tle2=TLE2, begin=TLE_BEGIN, end=TLE_END, step_size=60
+    )
+
+
+@pytest.fixture
+def earth_limb() -> Constraint:
+    return Constraint.earth_limb(min_angle=10.0)
@@ -0,0 +1,106 @@
+"""Tests for the provenance records of ephemerides and constraint results."""
+
+import json
+from datetime import timedelta
+from pathlib import Path
+
+import pytest
+
+from rust_ephem import (
+    ArrayEphemeris,
+    Constraint,
+    EarthLimbConstraint,
+    Provenance,
+    TLEEphemeris,
+)
+
+from .conftest import BEGIN, TLE1, TLE2
+
+
+class TestEphemerisProvenance:
+    def test_record_sections(self, spacecraft: ArrayEphemeris) -> None:
+        record = spacecraft.provenance.to_dict()
+        assert record["format"] == "rust_ephem.Provenance"
+        assert set(record) >= {
+            "library",
+            "ephemeris",
+            "element_set",
+            "input_files",
+            "solar_system",
+            "earth_orientation",
+            "leap_seconds",
+        }
+        assert record["element_set"] is None
+        assert record["input_files"] == []
+        assert record["solar_system"]["source"] in ("spice", "erfa")
+        assert record["leap_seconds"]["tai_utc_begin"] == 37.0
+
+    def test_library_and_fingerprint(self, spacecraft: ArrayEphemeris) -> None:
+        provenance = spacecraft.provenance
+        assert provenance.library_version == provenance.to_dict()["library"]["version"]
+        assert provenance.library_version.count(".") == 2
+        assert provenance.fingerprint == spacecraft.fingerprint()
+        assert provenance.to_dict()["ephemeris"] == spacecraft.metadata
+
+    def test_deterministic(self, spacecraft: ArrayEphemeris) -> None:
+        assert spacecraft.provenance == spacecraft.provenance
+        assert spacecraft.provenance.to_json() == spacecraft.provenance.to_json()
+
+    def test_tle_element_set(self, tle_ephemeris: TLEEphemeris) -> None:
+        element_set = tle_ephemeris.provenance.to_dict()["element_set"]
+        assert element_set["norad_id"] == 25544
+        assert element_set["element_set_number"] == 999
+        assert element_set["line1"] == TLE1
+        assert element_set["line2"] == TLE2
+        assert element_set["epoch"].startswith("2025-11-11")
+
+
+class TestJson:
+    def test_round_trip(self, spacecraft: ArrayEphemeris) -> None:
+        provenance = spacecraft.provenance
+        text = provenance.to_json(indent=2)
+        assert json.loads(text) == provenance.to_dict()
+        assert Provenance.from_json(text) == provenance
+
+    def test_rejects_other_documents(self) -> None:
+        with pytest.raises(ValueError, match="format"):
+            Provenance.from_json('{"format": "other"}')
+        with pytest.raises(ValueError, match="Invalid provenance JSON"):
+            Provenance.from_json("not json")
+
+
+class TestConstraintResultProvenance:
+    def test_includes_constraint(
+        self, spacecraft: ArrayEphemeris, earth_limb: Constraint
+    ) -> None:
+        result = earth_limb.evaluate(spacecraft, 0.0, 0.0)
+        record = result.provenance.to_dict()
+        assert record["ephemeris"] == spacecraft.metadata
+        assert record["constraint"] == json.loads(earth_limb.to_json())
+
+    def test_batch_results(
+        self, spacecraft: ArrayEphemeris, earth_limb: Constraint
+    ) -> None:
+        results = earth_limb.evaluate_batch(spacecraft, [0.0, 90.0], [0.0, 10.0])
+        assert all(r.provenance == results[0].provenance for r in results)
+
+    def test_pydantic_result(self, spacecraft: ArrayEphemeris) -> None:
+        result = EarthLimbConstraint(min_angle=10.0).evaluate(spacecraft, 0.0, 0.0)
+        assert result.provenance is not None
+        assert result.provenance.fingerprint == spacecraft.fingerprint()
+
+    def test_interpolated_times(
+        self, spacecraft: ArrayEphemeris, earth_limb: Constraint
+    ) -> None:
+        midpoint = BEGIN + timedelta(seconds=30)
+        result = earth_limb.evaluate(spacecraft, 0.0, 0.0, at_times=[midpoint])
+        assert result.provenance.fingerprint == spacecraft.fingerprint()
+
+    def test_saved_with_result(
+        self, tmp_path: Path, spacecraft: ArrayEphemeris, earth_limb: Constraint
+    ) -> None:
+        result = earth_limb.evaluate(spacecraft, 0.0, 0.0)
+        path = tmp_path / "result.json"
+        result.save(str(path))
+        loaded = type(result).load(str(path))
+        assert loaded.provenance == result.provenance
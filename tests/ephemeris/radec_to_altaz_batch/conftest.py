@@ -0,0 +1,19 @@
+"""Fixtures for batch RA/Dec to Alt/Az conversion tests."""
+
+import datetime
+
+import pytest
+
+from rust_ephem import GroundEphemeris
+
+BEGIN = datetime.datetime(2024, 1, 1, 0, 0, 0, tzinfo=datetime.timezone.utc)
+END = datetime.datetime(2024, 1, 1, 12, 0, 0, tzinfo=datetime.timezone.utc)
+STEP_SIZE = 3600
+
+TARGET_RAS = [0.0, 83.82, 201.37, 310.36]
+TARGET_DECS = [0.0, -5.39, -43.02, 45.28]
+
+
+@pytest.fixture
+def site() -> GroundEphemeris:
+    return GroundEphemeris(35.0, -120.0, 500.0, BEGIN, END, STEP_SIZE)
@@ -0,0 +1,35 @@
+"""Tests for radec_to_altaz_batch."""
+
+import numpy as np
+import pytest
+
+from rust_ephem import GroundEphemeris
+
+from .conftest import TARGET_DECS, TARGET_RAS
+
+
+def test_shape(site: GroundEphemeris) -> None:
+    altaz = site.radec_to_altaz_batch(TARGET_RAS, TARGET_DECS)
+    assert altaz.shape == (len(TARGET_RAS), len(site.timestamp), 2)
+
+
+def test_matches_single_target(site: GroundEphemeris) -> None:
+    altaz = site.radec_to_altaz_batch(np.array(TARGET_RAS), np.array(TARGET_DECS))
+    for i, (ra, dec) in enumerate(zip(TARGET_RAS, TARGET_DECS)):
+        np.testing.assert_allclose(altaz[i], site.radec_to_altaz(ra, dec))
+
+
+def test_time_indices(site: GroundEphemeris) -> None:
+    full = site.radec_to_altaz_batch(TARGET_RAS, TARGET_DECS)
+    subset = site.radec_to_altaz_batch(TARGET_RAS, TARGET_DECS, time_indices=[0, 3])
+    np.testing.assert_allclose(subset, full[:, [0, 3]])
+
+
+def test_no_targets(site: GroundEphemeris) -> None:
+    altaz = site.radec_to_altaz_batch([], [])
+    assert altaz.shape == (0, len(site.timestamp), 2)
+
+
+def test_length_mismatch_raises(site: GroundEphemeris) -> None:
+    with pytest.raises(ValueError, match="same length"):
+        site.radec_to_altaz_batch([0.0, 1.0], [0.0])
@@ -0,0 +1,139 @@
+"""Fixtures for rinex_nav_ephemeris tests."""
+
+import math
+from datetime import datetime, timezone
+from typing import Any
+
+import pytest
+
+BEGIN = datetime(2024, 1, 1, 0, 0, 0, tzinfo=timezone.utc)
+END = datetime(2024, 1, 1, 2, 0, 0, tzinfo=timezone.utc)
+STEP_SIZE = 600
+
+GPS_WEEK = 2295
+GPS_MU = 3.986005e14
+MESSAGE_SPACING = 7200.0
+
+# Broadcast orbit values in RINEX order (broadcast orbit lines 1-4):
+# IODE, Crs, delta_n, M0, Cuc, e, Cus, sqrt(A), toe, Cic, OMEGA0, Cis,
+# i0, Crc, omega, OMEGA_DOT
+# fmt: off
+GPS_ORBIT: list[float] = [
+    17.0, -11.59375, 4.249105564e-9, 0.1432852298,
+    -6.0126185417e-7, 0.005912038265, 5.6810677052e-6, 5153.65531,
+    86400.0, -1.1175870896e-7, -1.6732193420, 1.6763806343e-7,
+    0.9848407943, 278.71875, 0.4122218083, -8.386063264e-9,
+]
+GALILEO_ORBIT: list[float] = [
+    45.0, 31.28125, 2.876548949e-9, -2.021477003,
+    1.430511475e-6, 2.450053487e-4, 7.975101471e-6, 5440.613218,
+    86400.0, 1.862645149e-9, 2.186224390, -2.793967724e-8,
+    0.9883145213, 166.5, -0.7043014660, -5.466656822e-9,
+]
+# fmt: on
+IDOT = 1.342913098e-10
+
+
+def _field(value: float) -> str:
+    return f"{value:19.12E}".replace("E", "D")
+
+
+def advance(orbit: list[float], dt: float) -> list[float]:
+    """Shift the reference epoch of a broadcast orbit by ``dt`` seconds"""
+    sqrt_a = orbit[7]
+    n = math.sqrt(GPS_MU / sqrt_a**6) + orbit[2]
+    shifted = list(orbit)
+    shifted[0] += 1.0
+    shifted[3] = orbit[3] + n * dt
+    shifted[8] = orbit[8] + dt
+    shifted[10] = orbit[10] + orbit[15] * dt
+    shifted[12] = orbit[12] + IDOT * dt
+    return shifted
+
+
+def _orbit_lines(orbit: list[float], indent: int, health: int) -> list[str]:
+    # Broadcast orbit lines 5-7: IDOT, codes, week, L2P flag / accuracy,
+    # health, TGD, IODC / transmission time, fit interval
+    values = orbit + [IDOT, 1.0, float(GPS_WEEK), 0.0]
+    values += [2.0, float(health), -1.117587090e-8, orbit[0]]
+    values += [orbit[8] - 1800.0, 4.0]
+    return [
+        " " * indent + "".join(_field(v) for v in values[k : k + 4])
+        for k in range(0, len(values), 4)
+    ]
+
+
+def _clock(toe: float) -> tuple[int, int, int, int, int, int]:
+    # Messages in these fixtures are all on 2024-01-01 (day 1 of the week)
+    seconds = int(toe - 86400.0)
+    return 2024, 1, 1, seconds // 3600, (seconds % 3600) // 60, seconds % 60
+
+
+def _messages(count: int) -> list[tuple[str, list[float], int]]:
+    messages = []
+    for k in range(count):
+        messages.append(("G05", advance(GPS_ORBIT, k * MESSAGE_SPACING), 0))
+    messages.append(("E11", GALILEO_ORBIT, 0))
+    return messages
+
+
+def rinex3_text(count: int = 2) -> str:
+    """Mixed RINEX 3 navigation file with ``count`` GPS messages and one Galileo message"""
+    lines = [
+        f"{'     3.04':<20}{'N: GNSS NAV DATA':<20}{'M: MIXED':<20}RINEX VERSION / TYPE",
+        f"{'':<60}END OF HEADER",
+    ]
+    for sat, orbit, health in _messages(count):
+        y, mo, d, h, mi, s = _clock(orbit[8])
+        lines.append(
+            f"{sat} {y:04d} {mo:02d} {d:02d} {h:02d} {mi:02d} {s:02d}"
+            + _field(1.0e-4)
+            + _field(0.0)
+            + _field(0.0)
+        )
+        lines += _orbit_lines(orbit, 4, health)
+    return "\n".join(lines) + "\n"
+
+
+def rinex2_text(count: int = 2) -> str:
+    """RINEX 2 GPS navigation file with ``count`` messages"""
+    lines = [
+        f"{'     2.11':<20}{'N: GPS NAV DATA':<40}RINEX VERSION / TYPE",
+        f"{'':<60}END OF HEADER",
+    ]
+    for sat, orbit, health in _messages(count):
+        if not sat.startswith("G"):
+            continue
+        y, mo, d, h, mi, s = _clock(orbit[8])
+        lines.append(
+            f"{int(sat[1:]):2d} {y % 100:02d} {mo:2d} {d:2d} {h:2d} {mi:2d} {s:4.1f}"
+            + _field(1.0e-4)
+            + _field(0.0)
+            + _field(0.0)
+        )
+        lines += _orbit_lines(orbit, 3, health)
+    return "\n".join(lines) + "\n"
+
+
+@pytest.fixture
+def rinex3_path(tmp_path: Any) -> str:
+    """Temporary mixed RINEX 3 navigation file"""
+    path = tmp_path / "BRDC00IGS_R_20240010000_01D_MN.rnx"
+    path.write_text(rinex3_text())
+    return str(path)
+
+
+@pytest.fixture
+def rinex2_path(tmp_path: Any) -> str:
+    """Temporary RINEX 2 GPS navigation file"""
+    path = tmp_path / "brdc0010.24n"
+    path.write_text(rinex2_text())
+    return str(path)
+
+
+@pytest.fixture
+def single_message_path(tmp_path: Any) -> str:
+    """RINEX 3 navigation file with only the first GPS message"""
+    path = tmp_path / "single.rnx"
+    path.write_text(rinex3_text(count=1))
+    return str(path)
@@ -0,0 +1,106 @@
+"""Tests for RINEX navigation (broadcast) ephemeris"""
+
+from datetime import datetime, timedelta, timezone
+
+import numpy as np
+import pytest
+
+import rust_ephem
+from rust_ephem import Ephemeris, RinexNavEphemeris
+
+from .conftest import BEGIN, END, STEP_SIZE
+
+GPS_RADIUS_KM = 26_560.0
+GALILEO_RADIUS_KM = 29_600.0
+
+
+def test_rinex_nav_ephemeris_gps(rinex3_path: str) -> None:
+    eph = RinexNavEphemeris(rinex3_path, "G05", BEGIN, END, step_size=STEP_SIZE)
+
+    assert isinstance(eph, Ephemeris)
+    assert eph.satellite == "G05"
+    assert eph.system == "GPS"
+    assert eph.rinex_version == pytest.approx(3.04)
+    assert eph.n_messages == 2
+    assert len(eph.timestamp) == 13
+
+    radius = np.linalg.norm(eph.gcrs_pv.position, axis=1)
+    assert np.all(np.abs(radius - GPS_RADIUS_KM) < 400.0)
+
+    speed = np.linalg.norm(eph.gcrs_pv.velocity, axis=1)
+    assert np.all(np.abs(speed - 3.87) < 0.15)
+
+
+def test_message_epochs(rinex3_path: str) -> None:
+    eph = RinexNavEphemeris(rinex3_path, "G05", BEGIN, END, step_size=STEP_SIZE)
+    # toe 86400 s in GPS week 2295; GPS-UTC = 18 s
+    first = datetime(2023, 12, 31, 23, 59, 42, tzinfo=timezone.utc)
+    assert eph.message_epochs == [first, first + timedelta(hours=2)]
+
+
+def test_satellite_id_normalized(rinex3_path: str) -> None:
+    eph = RinexNavEphemeris(rinex3_path, "5", BEGIN, END, step_size=STEP_SIZE)
+    assert eph.satellite == "G05"
+
+
+def test_galileo(rinex3_path: str) -> None:
+    eph = RinexNavEphemeris(rinex3_path, "E11", BEGIN, END, step_size=STEP_SIZE)
+    assert eph.system == "Galileo"
+    assert eph.n_messages == 1
+
+    radius = np.linalg.norm(eph.gcrs_pv.position, axis=1)
+    assert np.all(np.abs(radius - GALILEO_RADIUS_KM) < 400.0)
+
+
+def test_rinex2_matches_rinex3(rinex2_path: str, rinex3_path: str) -> None:
+    v2 = RinexNavEphemeris(rinex2_path, "G05", BEGIN, END, step_size=STEP_SIZE)
+    v3 = RinexNavEphemeris(rinex3_path, "G05", BEGIN, END, step_size=STEP_SIZE)
+
+    assert v2.rinex_version == pytest.approx(2.11)
+    np.testing.assert_allclose(v2.itrs_pv.position, v3.itrs_pv.position, atol=1e-6)
+
+
+def test_message_switch_is_continuous(
+    rinex3_path: str, single_message_path: str
+) -> None:
+    # The second message is the first propagated by two hours, so switching to
+    # it after 01:00 must not introduce a jump
+    both = RinexNavEphemeris(rinex3_path, "G05", BEGIN, END, step_size=STEP_SIZE)
+    single = RinexNavEphemeris(
+        single_message_path, "G05", BEGIN, END, step_size=STEP_SIZE
+    )
+    np.testing.assert_allclose(
+        both.itrs_pv.position, single.itrs_pv.position, atol=1e-3
+    )
+
+
+def test_satellites(rinex2_path: str, rinex3_path: str) -> None:
+    assert RinexNavEphemeris.satellites(rinex3_path) == ["E11", "G05"]
+    assert RinexNavEphemeris.satellites(rinex2_path) == ["G05"]
+
+
+def test_missing_satellite_raises(rinex3_path: str) -> None:
+    with pytest.raises(ValueError, match="Satellite G12 not found"):
+        RinexNavEphemeris(rinex3_path, "G12", BEGIN, END)
+
+
+def test_unsupported_constellation_raises(rinex3_path: str) -> None:
+    with pytest.raises(ValueError, match="Unsupported constellation"):
+        RinexNavEphemeris(rinex3_path, "R01", BEGIN, END)
+
+
+def test_time_range_beyond_messages_raises(rinex3_path: str) -> None:
+    with pytest.raises(ValueError, match="more than 4 hours"):
+        RinexNavEphemeris(
+            rinex3_path,
+            "G05",
+            BEGIN + timedelta(hours=7),
+            END + timedelta(hours=7),
+        )
+
+
+def test_constraint_evaluation(rinex3_path: str) -> None:
+    eph = RinexNavEphemeris(rinex3_path, "G05", BEGIN, END, step_size=STEP_SIZE)
+    constraint = rust_ephem.EarthLimbConstraint(min_angle=10.0)
+    result = constraint.evaluate(eph, target_ra=0.0, target_dec=90.0)
+    assert len(result.timestamp) == 13
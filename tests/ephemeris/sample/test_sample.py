@@ -0,0 +1,58 @@
+"""Tests for len(), indexing and iteration over ephemeris time steps."""
+
+import numpy as np
+import pytest
+
+from rust_ephem import ArrayEphemeris, EphemerisSample
+
+from .conftest import BEGIN, N_POINTS
+
+
+class TestIndexing:
+    def test_len(self, array_ephemeris: ArrayEphemeris) -> None:
+        assert len(array_ephemeris) == N_POINTS
+
+    def test_getitem(self, array_ephemeris: ArrayEphemeris) -> None:
+        sample = array_ephemeris[2]
+        assert isinstance(sample, EphemerisSample)
+        assert sample.index == 2
+        np.testing.assert_allclose(sample.gcrs, [7000.0, 0.0, 0.0, 0.0, 7.5, 0.0])
+
+    def test_negative_index(self, array_ephemeris: ArrayEphemeris) -> None:
+        assert array_ephemeris[0].time == BEGIN
+        last = array_ephemeris[-1]
+        assert last.index == N_POINTS - 1
+        assert last.time == array_ephemeris.end
+
+    @pytest.mark.parametrize("index", [N_POINTS, -N_POINTS - 1])
+    def test_out_of_range(self, array_ephemeris: ArrayEphemeris, index: int) -> None:
+        with pytest.raises(IndexError):
+            array_ephemeris[index]
+
+    def test_matches_arrays(self, array_ephemeris: ArrayEphemeris) -> None:
+        sample = array_ephemeris[3]
+        assert sample.latitude_deg == pytest.approx(array_ephemeris.latitude_deg[3])
+        assert sample.longitude_deg == pytest.approx(array_ephemeris.longitude_deg[3])
+        assert sample.height_km == pytest.approx(array_ephemeris.height_km[3])
+        assert sample.sun is not None
+        np.testing.assert_allclose(sample.sun, array_ephemeris.sun_pv.position[3])
+        assert sample.itrs is not None
+        itrs_pv = array_ephemeris.itrs_pv
+        np.testing.assert_allclose(sample.itrs[:3], itrs_pv.position[3])
+        np.testing.assert_allclose(sample.itrs[3:], itrs_pv.velocity[3])
+
+
+class TestIteration:
+    def test_iterates_in_order(self, array_ephemeris: ArrayEphemeris) -> None:
+        indices = [sample.index for sample in array_ephemeris]
+        assert indices == list(range(N_POINTS))
+
+    def test_restartable(self, array_ephemeris: ArrayEphemeris) -> None:
+        assert len(list(array_ephemeris)) == len(list(array_ephemeris)) == N_POINTS
+
+    def test_times_step(self, array_ephemeris: ArrayEphemeris) -> None:
+        times = [sample.time for sample in array_ephemeris]
+        assert times[0] == array_ephemeris.begin
+        assert times[-1] == array_ephemeris.end
+        steps = {(b - a).total_seconds() for a, b in zip(times, times[1:])}
+        assert steps == {float(array_ephemeris.step_size)}
@@ -0,0 +1,77 @@
+"""Tests for the Rust angular separation between ephemeris products."""
+
+import numpy as np
+import pytest
+
+from rust_ephem import GroundEphemeris
+
+
+def unit(vectors: np.ndarray) -> np.ndarray:
+    return vectors / np.linalg.norm(vectors, axis=1, keepdims=True)
+
+
+def angles(a: np.ndarray, b: np.ndarray) -> np.ndarray:
+    cos = np.clip(np.sum(unit(a) * unit(b), axis=1), -1.0, 1.0)
+    return np.degrees(np.arccos(cos))
+
+
+def radec_vector(ra: float, dec: float) -> np.ndarray:
+    ra, dec = np.radians(ra), np.radians(dec)
+    return np.array([np.cos(dec) * np.cos(ra), np.cos(dec) * np.sin(ra), np.sin(dec)])
+
+
+class TestSeparation:
+    def test_sun_moon_matches_vectors(self, site: GroundEphemeris) -> None:
+        sun = site.sun_pv.position - site.gcrs_pv.position
+        moon = site.moon_pv.position - site.gcrs_pv.position
+        np.testing.assert_allclose(
+            site.separation("sun", "moon"), angles(sun, moon), atol=1e-8
+        )
+
+    def test_fixed_radec(self, site: GroundEphemeris) -> None:
+        sun = site.sun_pv.position - site.gcrs_pv.position
+        target = np.tile(radec_vector(83.82, -5.39), (len(sun), 1))
+        result = site.separation((83.82, -5.39), "Sun")
+        assert result.shape == (len(sun),)
+        np.testing.assert_allclose(result, angles(target, sun), atol=1e-8)
+
+    def test_symmetric(self, site: GroundEphemeris) -> None:
+        np.testing.assert_allclose(
+            site.separation("moon", [10.0, 20.0]),
+            site.separation([10.0, 20.0], "moon"),
+        )
+
+    def test_earth_is_nadir(self, site: GroundEphemeris) -> None:
+        zenith = site.gcrs_pv
+        np.testing.assert_allclose(site.separation("earth", zenith), 180.0)
+
+    def test_position_velocity_operand(self, site: GroundEphemeris) -> None:
+        np.testing.assert_allclose(
+            site.separation(site.get_body_pv("Sun"), "moon"),
+            site.separation("sun", "moon"),
+            atol=1e-8,
+        )
+
+    def test_matches_astropy_skycoord_separation(self, site: GroundEphemeris) -> None:
+        expected = site.sun.separation(site.moon).deg
+        np.testing.assert_allclose(
+            site.separation(site.sun, site.moon), expected, atol=1e-6
+        )
+
+    def test_scalar_skycoord_is_fixed(self, site: GroundEphemeris) -> None:
+        np.testing.assert_allclose(
+            site.separation(site.sun[0], (0.0, 90.0)),
+            np.full(len(site), 90.0 - site.sun[0].dec.deg),
+            atol=1e-8,
+        )
+
+    def test_identical_operands(self, site: GroundEphemeris) -> None:
+        np.testing.assert_allclose(site.separation("sun", "sun"), 0.0, atol=1e-12)
+
+    def test_invalid_operand(self, site: GroundEphemeris) -> None:
+        with pytest.raises(TypeError):
+            site.separation(42, "sun")
+
+    def test_wrong_length_skycoord(self, site: GroundEphemeris) -> None:
+        with pytest.raises(ValueError):
+            site.separation(site.sun[:2], "moon")
@@ -0,0 +1,7 @@
+"""Fixtures for site database tests."""
+
+import datetime
+
+BEGIN = datetime.datetime(2024, 1, 1, 0, 0, 0, tzinfo=datetime.timezone.utc)
+END = datetime.datetime(2024, 1, 1, 1, 0, 0, tzinfo=datetime.timezone.utc)
+STEP_SIZE = 600
@@ -0,0 +1,93 @@
+"""Tests for the observatory and ground-station site registry"""
+
+import pytest
+
+import rust_ephem
+from rust_ephem import GroundEphemeris
+
+from .conftest import BEGIN, END, STEP_SIZE
+
+
+def test_ground_ephemeris_from_site() -> None:
+    vlt = GroundEphemeris(site="Paranal", begin=BEGIN, end=END, step_size=STEP_SIZE)
+    site = rust_ephem.get_site("Paranal")
+    assert vlt.site == "Paranal"
+    assert vlt.input_latitude == site["latitude"]
+    assert vlt.input_longitude == site["longitude"]
+    assert vlt.input_height == site["height"]
+    assert len(vlt.timestamp) == 7
+
+
+def test_site_matches_explicit_coordinates() -> None:
+    by_site = GroundEphemeris(site="Kitt Peak", begin=BEGIN, end=END, step_size=STEP_SIZE)
+    site = rust_ephem.get_site("Kitt Peak")
+    explicit = GroundEphemeris(
+        site["latitude"], site["longitude"], site["height"], BEGIN, END, STEP_SIZE
+    )
+    assert explicit.site is None
+    assert (by_site.gcrs_pv.position == explicit.gcrs_pv.position).all()
+
+
+@pytest.mark.parametrize(
+    "name, expected",
+    [
+        ("paranal", "Paranal"),
+        ("VLT", "Paranal"),
+        ("309", "Paranal"),
+        ("568", "Mauna Kea"),
+        ("dss-43", "Canberra"),
+        ("DSS 14", "Goldstone"),
+        ("New Norcia", "New Norcia"),
+        ("la palma", "Roque de los Muchachos"),
+    ],
+)
+def test_lookup_by_alias_and_code(name: str, expected: str) -> None:
+    assert rust_ephem.get_site(name)["name"] == expected
+
+
+def test_get_site_fields() -> None:
+    site = rust_ephem.get_site("Mauna Kea")
+    assert site["code"] == "568"
+    assert site["latitude"] == pytest.approx(19.82, abs=0.01)
+    assert site["longitude"] == pytest.approx(-155.47, abs=0.01)
+    assert 4000 < site["height"] < 4300
+    assert "Keck" in site["aliases"]
+
+
+def test_list_sites_includes_stations() -> None:
+    names = rust_ephem.list_sites()
+    assert names == sorted(names)
+    for name in ["Paranal", "Goldstone", "Canberra", "Madrid", "Cebreros", "Malargue"]:
+        assert name in names
+
+
+def test_unknown_site() -> None:
+    with pytest.raises(ValueError, match="Unknown site"):
+        rust_ephem.get_site("Atlantis")
+    with pytest.raises(ValueError, match="Unknown site"):
+        GroundEphemeris(site="Atlantis", begin=BEGIN, end=END)
+
+
+def test_site_and_coordinates_are_exclusive() -> None:
+    with pytest.raises(ValueError, match="either site"):
+        GroundEphemeris(10.0, site="Paranal", begin=BEGIN, end=END)
+    with pytest.raises(ValueError, match="required unless site"):
+        GroundEphemeris(10.0, 20.0, begin=BEGIN, end=END)
+    with pytest.raises(ValueError, match="begin"):
+        GroundEphemeris(site="Paranal", end=END)
+
+
+def test_register_site() -> None:
+    rust_ephem.register_site(
+        "Test Rooftop", 45.0, 7.5, 300.0, code="ZZ9", aliases=["rooftop"]
+    )
+    assert "Test Rooftop" in rust_ephem.list_sites()
+    eph = GroundEphemeris(site="ROOFTOP", begin=BEGIN, end=END, step_size=STEP_SIZE)
+    assert eph.site == "Test Rooftop"
+    assert eph.input_latitude == 45.0
+    assert rust_ephem.get_site("zz9")["name"] == "Test Rooftop"
+
+
+def test_register_site_validates_coordinates() -> None:
+    with pytest.raises(ValueError, match="latitude"):
+        rust_ephem.register_site("Bad Site", 95.0, 0.0, 0.0)
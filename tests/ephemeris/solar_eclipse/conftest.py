@@ -0,0 +1,27 @@
+"""Fixtures for solar eclipse tests."""
+
+import datetime
+
+import pytest
+
+from rust_ephem import GroundEphemeris
+
+# Total solar eclipse of 2024 April 8; totality over Dallas near 18:42 UT
+BEGIN = datetime.datetime(2024, 4, 8, 17, 0, 0, tzinfo=datetime.timezone.utc)
+END = datetime.datetime(2024, 4, 8, 21, 0, 0, tzinfo=datetime.timezone.utc)
+STEP_SIZE = 30
+
+
+@pytest.fixture
+def dallas() -> GroundEphemeris:
+    return GroundEphemeris(32.78, -96.80, 140.0, BEGIN, END, STEP_SIZE)
+
+
+@pytest.fixture
+def new_york() -> GroundEphemeris:
+    return GroundEphemeris(40.71, -74.01, 10.0, BEGIN, END, STEP_SIZE)
+
+
+@pytest.fixture
+def sydney() -> GroundEphemeris:
+    return GroundEphemeris(-33.87, 151.21, 50.0, BEGIN, END, STEP_SIZE)
@@ -0,0 +1,62 @@
+"""Tests for GroundEphemeris.solar_obscuration and solar_eclipses."""
+
+import datetime
+
+import numpy as np
+import pytest
+
+from rust_ephem import GroundEphemeris, SolarEclipse
+
+
+def test_obscuration_range(dallas: GroundEphemeris) -> None:
+    obscuration = dallas.solar_obscuration
+    assert obscuration.shape == (len(dallas.timestamp),)
+    assert np.all((obscuration >= 0.0) & (obscuration <= 1.0))
+    assert obscuration[0] == 0.0
+    assert obscuration[-1] == 0.0
+
+
+def test_total_eclipse_in_path(dallas: GroundEphemeris) -> None:
+    eclipses = dallas.solar_eclipses()
+    assert len(eclipses) == 1
+    eclipse = eclipses[0]
+    assert isinstance(eclipse, SolarEclipse)
+    assert eclipse.kind == "total"
+    assert eclipse.max_obscuration == pytest.approx(1.0)
+    assert eclipse.sun_altitude_deg > 60.0
+    # First contact near 17:23 UT, maximum near 18:42 UT, last contact near 20:03 UT
+    for actual, hour, minute in (
+        (eclipse.start_time, 17, 23),
+        (eclipse.peak_time, 18, 42),
+        (eclipse.end_time, 20, 3),
+    ):
+        expected = datetime.datetime(
+            2024, 4, 8, hour, minute, tzinfo=datetime.timezone.utc
+        )
+        assert abs((actual - expected).total_seconds()) < 180
+    assert eclipse.duration_seconds == pytest.approx(
+        (eclipse.end_time - eclipse.start_time).total_seconds()
+    )
+
+
+def test_partial_eclipse_outside_path(new_york: GroundEphemeris) -> None:
+    eclipses = new_york.solar_eclipses()
+    assert len(eclipses) == 1
+    assert eclipses[0].kind == "partial"
+    assert 0.8 < eclipses[0].max_obscuration < 1.0
+
+
+def test_min_obscuration_filters(new_york: GroundEphemeris) -> None:
+    assert new_york.solar_eclipses(min_obscuration=0.99) == []
+
+
+def test_sun_below_horizon_skipped(sydney: GroundEphemeris) -> None:
+    # Night in Sydney: the disks overlap only through the Earth
+    assert sydney.solar_eclipses() == []
+    below = sydney.solar_eclipses(include_below_horizon=True)
+    assert all(eclipse.sun_altitude_deg < 0.0 for eclipse in below)
+
+
+def test_invalid_min_obscuration(dallas: GroundEphemeris) -> None:
+    with pytest.raises(ValueError, match="min_obscuration"):
+        dallas.solar_eclipses(min_obscuration=1.5)
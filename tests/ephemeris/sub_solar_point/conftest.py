@@ -0,0 +1,25 @@
+"""Fixtures for sub-satellite and sub-solar point tests."""
+
+import datetime
+
+import pytest
+
+from rust_ephem import GroundEphemeris, TLEEphemeris
+
+VALID_TLE1 = "1 25544U 98067A   08264.51782528 -.00002182  00000-0 -11606-4 0  2927"
+VALID_TLE2 = "2 25544  51.6416 247.4627 0006703 130.5360 325.0288 15.72125391563537"
+
+# June solstice, within a minute of 12:00 UT
+BEGIN = datetime.datetime(2024, 6, 20, 12, 0, 0, tzinfo=datetime.timezone.utc)
+END = datetime.datetime(2024, 6, 20, 14, 0, 0, tzinfo=datetime.timezone.utc)
+STEP_SIZE = 60
+
+
+@pytest.fixture
+def spacecraft() -> TLEEphemeris:
+    return TLEEphemeris(VALID_TLE1, VALID_TLE2, BEGIN, END, STEP_SIZE)
+
+
+@pytest.fixture
+def site() -> GroundEphemeris:
+    return GroundEphemeris(35.0, -120.0, 500.0, BEGIN, END, 600)
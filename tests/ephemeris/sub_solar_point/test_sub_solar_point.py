@@ -0,0 +1,45 @@
+"""Tests for sub_satellite_point, sub_solar_point and nadir_solar_zenith_angle."""
+
+import numpy as np
+
+from rust_ephem import GroundEphemeris, TLEEphemeris
+
+
+def test_sub_satellite_point_is_lat_lon(spacecraft: TLEEphemeris) -> None:
+    points = spacecraft.sub_satellite_point
+    assert points is not None
+    np.testing.assert_allclose(points[:, 0], spacecraft.latitude_deg)
+    np.testing.assert_allclose(points[:, 1], spacecraft.longitude_deg)
+
+
+def test_sub_solar_point_at_solstice_noon(spacecraft: TLEEphemeris) -> None:
+    points = spacecraft.sub_solar_point
+    assert points.shape == (len(spacecraft.timestamp), 2)
+    # Sun over the Tropic of Cancer, near the Greenwich meridian at 12:00 UT
+    np.testing.assert_allclose(points[0, 0], 23.44, atol=0.05)
+    assert abs(points[0, 1]) < 1.0
+    # The sub-solar point moves west by 15 degrees an hour
+    assert points[60, 1] - points[0, 1] < -14.0
+
+
+def test_nadir_zenith_angle_tracks_eclipse(spacecraft: TLEEphemeris) -> None:
+    angles = spacecraft.nadir_solar_zenith_angle
+    assert angles is not None
+    assert np.all((angles >= 0.0) & (angles <= 180.0))
+    # Two hours covers more than one orbit, so the ground below goes dark and light
+    assert (angles < 90.0).any() and (angles > 90.0).any()
+    # The ground below is dark whenever the spacecraft itself is in umbra
+    umbra = spacecraft.illumination_factor == 0.0
+    assert np.all(angles[umbra] > 90.0)
+
+
+def test_ground_site_zenith_angle_matches_sun_altitude(site: GroundEphemeris) -> None:
+    angles = site.nadir_solar_zenith_angle
+    assert angles is not None
+    points = site.sub_solar_point
+    lat, lon = np.radians(35.0), np.radians(-120.0)
+    sun_lat, sun_lon = np.radians(points[:, 0]), np.radians(points[:, 1])
+    cos_zenith = np.sin(lat) * np.sin(sun_lat) + np.cos(lat) * np.cos(sun_lat) * np.cos(
+        sun_lon - lon
+    )
+    np.testing.assert_allclose(angles, np.degrees(np.arccos(cos_zenith)), atol=1e-9)
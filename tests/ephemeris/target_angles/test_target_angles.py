@@ -0,0 +1,58 @@
+"""Tests for the sun_angle, moon_angle and earth_limb_angle arrays."""
+
+import numpy as np
+import pytest
+
+from rust_ephem import Constraint, TLEEphemeris
+
+from .conftest import TARGET_DEC, TARGET_RA
+
+
+class TestTargetAngles:
+    def test_sun_angle_matches_separation(self, ephem: TLEEphemeris) -> None:
+        np.testing.assert_allclose(
+            ephem.sun_angle(TARGET_RA, TARGET_DEC),
+            ephem.separation((TARGET_RA, TARGET_DEC), "sun"),
+        )
+
+    def test_moon_angle_matches_separation(self, ephem: TLEEphemeris) -> None:
+        np.testing.assert_allclose(
+            ephem.moon_angle(TARGET_RA, TARGET_DEC),
+            ephem.separation((TARGET_RA, TARGET_DEC), "moon"),
+        )
+
+    def test_earth_limb_angle_subtracts_earth_radius(
+        self, ephem: TLEEphemeris
+    ) -> None:
+        center = ephem.separation((TARGET_RA, TARGET_DEC), "earth")
+        np.testing.assert_allclose(
+            ephem.earth_limb_angle(TARGET_RA, TARGET_DEC),
+            center - ephem.earth_radius_deg,
+        )
+
+    def test_earth_limb_angle_changes_sign_over_an_orbit(
+        self, ephem: TLEEphemeris
+    ) -> None:
+        limb = ephem.earth_limb_angle(TARGET_RA, TARGET_DEC)
+        assert limb.min() < 0.0 < limb.max()
+
+    def test_time_indices_select_steps(self, ephem: TLEEphemeris) -> None:
+        full = ephem.sun_angle(TARGET_RA, TARGET_DEC)
+        subset = ephem.sun_angle(TARGET_RA, TARGET_DEC, time_indices=[0, 5, 10])
+        np.testing.assert_allclose(subset, full[[0, 5, 10]])
+        limb = ephem.earth_limb_angle(TARGET_RA, TARGET_DEC)
+        np.testing.assert_allclose(
+            ephem.earth_limb_angle(TARGET_RA, TARGET_DEC, time_indices=[3, 1]),
+            limb[[3, 1]],
+        )
+
+    def test_out_of_range_index_raises(self, ephem: TLEEphemeris) -> None:
+        with pytest.raises(ValueError):
+            ephem.moon_angle(TARGET_RA, TARGET_DEC, time_indices=[len(ephem)])
+
+    def test_agrees_with_earth_limb_constraint(self, ephem: TLEEphemeris) -> None:
+        limb = ephem.earth_limb_angle(TARGET_RA, TARGET_DEC)
+        result = Constraint.earth_limb(10.0).evaluate(ephem, TARGET_RA, TARGET_DEC)
+        violated = np.asarray(result.constraint_array)
+        clear = np.abs(limb - 10.0) > 0.01
+        np.testing.assert_array_equal(violated[clear], limb[clear] < 10.0)
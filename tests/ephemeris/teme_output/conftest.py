@@ -0,0 +1,23 @@
+"""Fixtures for TEME and PEF output tests."""
+
+import datetime
+
+import pytest
+
+from rust_ephem import GroundEphemeris, TLEEphemeris
+
+TLE1 = "1 25544U 98067A   25315.25818480  .00012468  00000-0  22984-3 0  9991"
+TLE2 = "2 25544  51.6338 298.3179 0004133  57.8977 302.2413 15.49525392537972"
+BEGIN = datetime.datetime(2024, 1, 1, 0, 0, 0, tzinfo=datetime.timezone.utc)
+END = datetime.datetime(2024, 1, 1, 1, 0, 0, tzinfo=datetime.timezone.utc)
+STEP_SIZE = 120
+
+
+@pytest.fixture
+def tle() -> TLEEphemeris:
+    return TLEEphemeris(TLE1, TLE2, BEGIN, END, step_size=STEP_SIZE)
+
+
+@pytest.fixture
+def site() -> GroundEphemeris:
+    return GroundEphemeris(35.0, -120.0, 500.0, BEGIN, END, STEP_SIZE)
@@ -0,0 +1,44 @@
+"""Tests for TEME and PEF outputs on every ephemeris class."""
+
+import numpy as np
+
+from rust_ephem import ArrayEphemeris, GroundEphemeris, TLEEphemeris
+
+
+def states(pv) -> np.ndarray:  # type: ignore[no-untyped-def]
+    return np.hstack([pv.position, pv.velocity])
+
+
+class TestTemeOutput:
+    def test_array_ephemeris_teme_matches_sgp4(self, tle: TLEEphemeris) -> None:
+        eph = ArrayEphemeris(tle.timestamp, states(tle.gcrs_pv))
+        np.testing.assert_allclose(
+            eph.teme_pv.position, tle.teme_pv.position, atol=1e-6
+        )
+        np.testing.assert_allclose(
+            eph.teme_pv.velocity, tle.teme_pv.velocity, atol=1e-9
+        )
+
+    def test_ground_ephemeris_has_teme(self, site: GroundEphemeris) -> None:
+        teme = site.teme_pv
+        np.testing.assert_allclose(teme.distance, site.gcrs_pv.distance)
+
+
+class TestPefOutput:
+    def test_pef_is_itrs_without_polar_motion(self, tle: TLEEphemeris) -> None:
+        np.testing.assert_allclose(
+            tle.pef_pv.position, tle.itrs_pv.position, atol=1e-6
+        )
+        np.testing.assert_allclose(
+            tle.pef_pv.velocity, tle.itrs_pv.velocity, atol=1e-9
+        )
+
+    def test_ground_site_pef_radius(self, site: GroundEphemeris) -> None:
+        np.testing.assert_allclose(site.pef_pv.distance, site.itrs_pv.distance)
+
+    def test_array_ephemeris_accepts_pef(self, tle: TLEEphemeris) -> None:
+        eph = ArrayEphemeris(tle.timestamp, states(tle.pef_pv), frame="PEF")
+        assert eph.source_frame == "PEF"
+        np.testing.assert_allclose(
+            eph.gcrs_pv.position, tle.gcrs_pv.position, atol=1e-6
+        )
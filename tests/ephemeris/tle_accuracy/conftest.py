@@ -0,0 +1,50 @@
+"""Fixtures for TLE accuracy estimation tests."""
+
+from typing import Any
+
+import pytest
+
+NAME = "ISS (ZARYA)"
+N_TLES = 7
+SPACING_DAYS = 0.5
+FIRST_DAY = 264.51782528
+# Nodal regression of the ISS orbit (degrees per day)
+RAAN_RATE = -5.0
+
+
+def checksum(line: str) -> int:
+    """TLE modulo-10 checksum of the first 68 characters"""
+    return sum(int(c) if c.isdigit() else c == "-" for c in line[:68]) % 10
+
+
+def with_checksum(line: str) -> str:
+    return f"{line[:68]}{checksum(line)}"
+
+
+def make_tle(k: int) -> tuple[str, str]:
+    """The k-th TLE of a synthetic ISS history, SPACING_DAYS apart"""
+    day = FIRST_DAY + k * SPACING_DAYS
+    raan = 247.4627 + RAAN_RATE * k * SPACING_DAYS
+    line1 = (
+        f"1 25544U 98067A   08{day:012.8f} -.00002182  00000-0 -11606-4 0  292 "
+    )
+    line2 = (
+        f"2 25544  51.6416 {raan:8.4f} 0006703 130.5360 325.0288 15.72125391"
+        "56353 "
+    )
+    return with_checksum(line1), with_checksum(line2)
+
+
+@pytest.fixture
+def tle_history() -> list[tuple[str, str]]:
+    return [make_tle(k) for k in range(N_TLES)]
+
+
+@pytest.fixture
+def tle_history_file(tmp_path: Any, tle_history: list[tuple[str, str]]) -> str:
+    """The history as a 3-line TLE file"""
+    path = tmp_path / "history.tle"
+    path.write_text(
+        "".join(f"{NAME}\n{line1}\n{line2}\n" for line1, line2 in tle_history)
+    )
+    return str(path)
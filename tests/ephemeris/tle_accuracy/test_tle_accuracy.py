@@ -0,0 +1,100 @@
+"""Tests for empirical TLE accuracy estimation from consecutive TLEs"""
+
+import math
+
+import numpy as np
+import pytest
+
+from rust_ephem import TLEAccuracy, estimate_tle_accuracy
+
+from .conftest import N_TLES, SPACING_DAYS
+
+
+def test_pairs_from_sequence(tle_history: list[tuple[str, str]]) -> None:
+    acc = estimate_tle_accuracy(tles=tle_history, max_age_days=2.2, bin_days=0.35)
+    assert isinstance(acc, TLEAccuracy)
+    assert len(acc.tle_epochs) == N_TLES
+    # Pairs 0.5, 1.0, 1.5 and 2.0 days apart
+    assert len(acc) == 6 + 5 + 4 + 3
+    assert acc.residuals.shape == (len(acc), 3)
+    assert acc.age_days.min() == pytest.approx(SPACING_DAYS)
+    assert acc.age_days.max() == pytest.approx(2.0)
+
+
+def test_bins(tle_history: list[tuple[str, str]]) -> None:
+    acc = estimate_tle_accuracy(tles=tle_history, max_age_days=2.2, bin_days=0.35)
+    np.testing.assert_allclose(acc.bin_edges, 0.35 * np.arange(8))
+    assert acc.counts == [0, 6, 5, 0, 4, 3, 0]
+    assert math.isnan(acc.rms["total"][0])
+    assert math.isnan(acc.rms["total"][3])
+    assert set(acc.rms) == {"radial", "in_track", "cross_track", "total"}
+
+
+def test_total_rms_combines_components(tle_history: list[tuple[str, str]]) -> None:
+    acc = estimate_tle_accuracy(tles=tle_history, max_age_days=2.2, bin_days=0.35)
+    rms = acc.rms
+    components = rms["radial"] ** 2 + rms["in_track"] ** 2 + rms["cross_track"] ** 2
+    np.testing.assert_allclose(rms["total"], np.sqrt(components))
+
+
+def test_envelope(tle_history: list[tuple[str, str]]) -> None:
+    acc = estimate_tle_accuracy(tles=tle_history, max_age_days=2.2, bin_days=0.35)
+    rms = acc.rms["in_track"]
+    # Bin centers are exact; ages outside the non-empty bins are clamped
+    assert acc.envelope(0.525, "in_track") == pytest.approx(rms[1])
+    assert acc.envelope(0.0, "in_track") == pytest.approx(rms[1])
+    assert acc.envelope(10.0, "in_track") == pytest.approx(rms[5])
+    assert acc.envelope(0.7, "in_track") == pytest.approx((rms[1] + rms[2]) / 2)
+    # The empty bin at 1.05-1.4 days is skipped
+    assert acc.envelope(1.225, "in_track") == pytest.approx((rms[2] + rms[4]) / 2)
+
+
+def test_envelope_rejects_unknown_component(
+    tle_history: list[tuple[str, str]],
+) -> None:
+    acc = estimate_tle_accuracy(tles=tle_history)
+    with pytest.raises(ValueError, match="Unknown component"):
+        acc.envelope(1.0, "along_track")
+
+
+def test_file_matches_sequence(
+    tle_history: list[tuple[str, str]], tle_history_file: str
+) -> None:
+    from_file = estimate_tle_accuracy(tles=tle_history_file, max_age_days=1.0)
+    from_list = estimate_tle_accuracy(tles=tle_history, max_age_days=1.0)
+    np.testing.assert_allclose(from_file.residuals, from_list.residuals)
+
+
+def test_accepts_strings_and_dicts(tle_history: list[tuple[str, str]]) -> None:
+    mixed = [f"{line1}\n{line2}" for line1, line2 in tle_history[:2]] + [
+        {"line1": line1, "line2": line2} for line1, line2 in tle_history[2:]
+    ]
+    acc = estimate_tle_accuracy(tles=mixed, max_age_days=1.0)
+    assert len(acc.tle_epochs) == N_TLES
+
+
+def test_duplicates_are_dropped(tle_history: list[tuple[str, str]]) -> None:
+    acc = estimate_tle_accuracy(tles=tle_history + tle_history[:2])
+    assert len(acc.tle_epochs) == N_TLES
+
+
+def test_needs_two_distinct_tles(tle_history: list[tuple[str, str]]) -> None:
+    with pytest.raises(ValueError, match="At least two TLEs"):
+        estimate_tle_accuracy(tles=[tle_history[0], tle_history[0]])
+
+
+def test_no_pairs_within_max_age(tle_history: list[tuple[str, str]]) -> None:
+    with pytest.raises(ValueError, match="max_age_days"):
+        estimate_tle_accuracy(tles=tle_history, max_age_days=0.25)
+
+
+def test_requires_one_source(tle_history: list[tuple[str, str]]) -> None:
+    with pytest.raises(ValueError, match="Provide tles or norad_id"):
+        estimate_tle_accuracy()
+    with pytest.raises(ValueError, match="not both"):
+        estimate_tle_accuracy(tles=tle_history, norad_id=25544)
+
+
+def test_rejects_bad_items() -> None:
+    with pytest.raises(TypeError):
+        estimate_tle_accuracy(tles=[42, 43])
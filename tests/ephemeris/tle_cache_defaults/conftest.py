@@ -0,0 +1,63 @@
+"""Fixtures for TLE cache default tests."""
+
+import os
+import time
+from datetime import datetime, timezone
+from pathlib import Path
+from typing import Generator
+
+import pytest
+
+import rust_ephem
+
+TLE1 = "1 28485U 04047A   25287.56748435  .00035474  00000+0  70906-3 0  9995"
+TLE2 = "2 28485  20.5535 247.0048 0005179 187.1586 172.8782 15.44937919148530"
+
+# Epoch of the TLE above, to the second
+TLE_EPOCH = datetime(2025, 10, 14, 13, 37, 11, tzinfo=timezone.utc)
+
+# Fake NORAD IDs that will never have real cached TLEs
+CELESTRAK_NORAD_ID = 99997
+SPACETRACK_NORAD_ID = 99996
+
+# Age given to the cached Celestrak download
+CACHED_AGE_SECONDS = 2 * 3600
+
+
+def _fresh_cache_dir(subdir: str, norad_id: int) -> Path:
+    cache_dir = Path(rust_ephem.get_cache_dir()) / subdir / str(norad_id)
+    if cache_dir.exists():
+        for f in cache_dir.iterdir():
+            f.unlink()
+    cache_dir.mkdir(parents=True, exist_ok=True)
+    return cache_dir
+
+
+@pytest.fixture
+def restore_defaults() -> Generator[None, None, None]:
+    yield
+    rust_ephem.set_tle_cache_defaults()
+
+
+@pytest.fixture
+def old_celestrak_cache() -> Generator[Path, None, None]:
+    """A Celestrak download cached CACHED_AGE_SECONDS ago."""
+    cache_dir = _fresh_cache_dir("celestrak_cache", CELESTRAK_NORAD_ID)
+    path = cache_dir / "20251014T133711.tle"
+    path.write_text(f"{TLE1}\n{TLE2}\n")
+    mtime = time.time() - CACHED_AGE_SECONDS
+    os.utime(path, (mtime, mtime))
+    yield path
+    if path.exists():
+        path.unlink()
+
+
+@pytest.fixture
+def spacetrack_cache() -> Generator[Path, None, None]:
+    """A cached Space-Track TLE at TLE_EPOCH."""
+    cache_dir = _fresh_cache_dir("spacetrack_cache", SPACETRACK_NORAD_ID)
+    path = cache_dir / "20251014T133711.tle"
+    path.write_text(f"{TLE1}\n{TLE2}\n")
+    yield path
+    if path.exists():
+        path.unlink()
@@ -0,0 +1,88 @@
+# mypy: ignore-errors
+"""Tests for per-call and module-level TLE cache TTL and epoch tolerance."""
+
+from datetime import timedelta
+
+import pytest
+
+import rust_ephem
+
+from .conftest import (
+    CACHED_AGE_SECONDS,
+    CELESTRAK_NORAD_ID,
+    SPACETRACK_NORAD_ID,
+    TLE1,
+    TLE_EPOCH,
+)
+
+
+class TestTLECacheDefaults:
+    def test_get_defaults(self) -> None:
+        defaults = rust_ephem.get_tle_cache_defaults()
+        assert set(defaults) == {"cache_ttl", "epoch_tolerance_days"}
+        assert defaults["cache_ttl"] >= 0
+        assert defaults["epoch_tolerance_days"] >= 0
+
+    def test_set_and_restore(self, restore_defaults) -> None:
+        configured = rust_ephem.get_tle_cache_defaults()
+        rust_ephem.set_tle_cache_defaults(cache_ttl=60, epoch_tolerance_days=0.5)
+        assert rust_ephem.get_tle_cache_defaults() == {
+            "cache_ttl": 60,
+            "epoch_tolerance_days": 0.5,
+        }
+        rust_ephem.set_tle_cache_defaults()
+        assert rust_ephem.get_tle_cache_defaults() == configured
+
+    @pytest.mark.parametrize("days", [-1.0, float("nan"), float("inf")])
+    def test_invalid_epoch_tolerance(self, days, restore_defaults) -> None:
+        with pytest.raises(ValueError, match="epoch_tolerance_days"):
+            rust_ephem.set_tle_cache_defaults(epoch_tolerance_days=days)
+
+
+class TestCacheTTL:
+    def test_per_call_ttl_reuses_cache(self, old_celestrak_cache) -> None:
+        tle = rust_ephem.fetch_tle(
+            norad_id=CELESTRAK_NORAD_ID,
+            enforce_source="celestrak",
+            cache_ttl=2 * CACHED_AGE_SECONDS,
+        )
+        assert tle.line1 == TLE1
+
+    def test_default_ttl_reuses_cache(
+        self, old_celestrak_cache, restore_defaults
+    ) -> None:
+        rust_ephem.set_tle_cache_defaults(cache_ttl=2 * CACHED_AGE_SECONDS)
+        tle = rust_ephem.fetch_tle(
+            norad_id=CELESTRAK_NORAD_ID, enforce_source="celestrak"
+        )
+        assert tle.line1 == TLE1
+
+
+class TestEpochTolerance:
+    def test_default_tolerance_reuses_cache(
+        self, spacetrack_cache, restore_defaults
+    ) -> None:
+        # The cached TLE is checked before credentials are used, so no network access
+        rust_ephem.set_tle_cache_defaults(epoch_tolerance_days=2.0)
+        tle = rust_ephem.fetch_tle(
+            norad_id=SPACETRACK_NORAD_ID,
+            spacetrack_username="fake_user",
+            spacetrack_password="fake_pass",
+            epoch=TLE_EPOCH + timedelta(days=1),
+            enforce_source="spacetrack",
+        )
+        assert tle.line1 == TLE1
+
+    def test_per_call_tolerance_overrides_default(
+        self, spacetrack_cache, restore_defaults
+    ) -> None:
+        rust_ephem.set_tle_cache_defaults(epoch_tolerance_days=0.0)
+        tle = rust_ephem.fetch_tle(
+            norad_id=SPACETRACK_NORAD_ID,
+            spacetrack_username="fake_user",
+            spacetrack_password="fake_pass",
+            epoch=TLE_EPOCH + timedelta(days=1),
+            epoch_tolerance_days=2.0,
+            enforce_source="spacetrack",
+        )
+        assert tle.line1 == TLE1
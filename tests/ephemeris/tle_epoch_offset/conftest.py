@@ -0,0 +1,12 @@
+"""Constants for TLE epoch offset tests."""
+
+from datetime import datetime, timedelta, timezone
+
+# Epoch 2025-10-14 13:37:10 UTC
+TLE1 = "1 28485U 04047A   25287.56748435  .00035474  00000+0  70906-3 0  9995"
+TLE2 = "2 28485  20.5535 247.0048 0005179 187.1586 172.8782 15.44937919148530"
+EPOCH = datetime(2025, 10, 14, 13, 37, 10, tzinfo=timezone.utc)
+
+NEAR_BEGIN = datetime(2025, 10, 14, 0, 0, 0, tzinfo=timezone.utc)
+FAR_BEGIN = EPOCH + timedelta(days=30)
+STEP_SIZE = 60
@@ -0,0 +1,50 @@
+"""Tests for warnings when propagating a TLE far from its epoch."""
+
+import warnings
+from datetime import datetime, timedelta
+from typing import Any
+
+import pytest
+
+from rust_ephem import TLEEphemeris
+
+from .conftest import EPOCH, FAR_BEGIN, NEAR_BEGIN, STEP_SIZE, TLE1, TLE2
+
+
+def make_ephemeris(begin: datetime, **kwargs: Any) -> TLEEphemeris:
+    return TLEEphemeris(
+        TLE1, TLE2, begin, begin + timedelta(hours=1), STEP_SIZE, **kwargs
+    )
+
+
+class TestEpochOffsetWarning:
+    def test_no_warning_near_epoch(self) -> None:
+        with warnings.catch_warnings():
+            warnings.simplefilter("error")
+            make_ephemeris(NEAR_BEGIN)
+
+    def test_warns_far_from_epoch(self) -> None:
+        with pytest.warns(UserWarning, match=r"\+30\.00 to \+30\.04 days"):
+            make_ephemeris(FAR_BEGIN)
+
+    def test_custom_limit(self) -> None:
+        with pytest.warns(UserWarning, match="limit: 0.1 days"):
+            make_ephemeris(NEAR_BEGIN, max_epoch_offset_days=0.1)
+
+    def test_strict_raises(self) -> None:
+        with pytest.raises(ValueError, match="days from the TLE epoch"):
+            make_ephemeris(FAR_BEGIN, strict=True)
+
+    def test_negative_limit_rejected(self) -> None:
+        with pytest.raises(ValueError, match="non-negative"):
+            make_ephemeris(NEAR_BEGIN, max_epoch_offset_days=-1.0)
+
+
+class TestEpochOffsetDays:
+    def test_offsets(self) -> None:
+        ephem = make_ephemeris(NEAR_BEGIN)
+        begin_offset, end_offset = ephem.epoch_offset_days
+        expected = (NEAR_BEGIN - EPOCH).total_seconds() / 86400.0
+        assert begin_offset == pytest.approx(expected, abs=1e-4)
+        assert end_offset == pytest.approx(expected + 1.0 / 24.0, abs=1e-4)
+        assert ephem.tle_epoch.replace(microsecond=0) == EPOCH
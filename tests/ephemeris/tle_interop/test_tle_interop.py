@@ -0,0 +1,43 @@
+"""Tests for TLEEphemeris.from_sgp4_satrec and TLEEphemeris.from_skyfield"""
+
+import numpy as np
+import pytest
+
+from rust_ephem import TLEEphemeris
+
+from .conftest import BEGIN, END, STEP_SIZE, TLE1, TLE2
+
+
+def test_from_sgp4_satrec_matches_lines() -> None:
+    api = pytest.importorskip("sgp4.api")
+    satrec = api.Satrec.twoline2rv(TLE1, TLE2)
+    ephem = TLEEphemeris.from_sgp4_satrec(satrec, BEGIN, END, STEP_SIZE)
+    reference = TLEEphemeris(TLE1, TLE2, BEGIN, END, STEP_SIZE)
+
+    assert ephem.tle_epoch == reference.tle_epoch
+    assert len(ephem.timestamp) == 61
+    np.testing.assert_allclose(
+        ephem.teme_pv.position, reference.teme_pv.position, atol=1e-6
+    )
+
+
+def test_from_skyfield_matches_lines() -> None:
+    skyfield_api = pytest.importorskip("skyfield.api")
+    satellite = skyfield_api.EarthSatellite(TLE1, TLE2, "TEST")
+    ephem = TLEEphemeris.from_skyfield(satellite, BEGIN, END, step_size=STEP_SIZE)
+    reference = TLEEphemeris(TLE1, TLE2, BEGIN, END, STEP_SIZE)
+
+    np.testing.assert_allclose(
+        ephem.gcrs_pv.position, reference.gcrs_pv.position, atol=1e-6
+    )
+
+
+def test_from_skyfield_rejects_other_objects() -> None:
+    with pytest.raises(TypeError, match="EarthSatellite"):
+        TLEEphemeris.from_skyfield(object(), BEGIN, END)
+
+
+def test_from_sgp4_satrec_rejects_other_objects() -> None:
+    pytest.importorskip("sgp4")
+    with pytest.raises(TypeError):
+        TLEEphemeris.from_sgp4_satrec(object(), BEGIN, END)
@@ -0,0 +1,10 @@
+"""Fixtures for TLE propagation progress tests."""
+
+import datetime
+
+TLE1 = "1 25544U 98067A   25315.25818480  .00012468  00000-0  22984-3 0  9991"
+TLE2 = "2 25544  51.6338 298.3179 0004133  57.8977 302.2413 15.49525392537972"
+BEGIN = datetime.datetime(2025, 11, 11, 0, 0, 0, tzinfo=datetime.timezone.utc)
+# 8641 steps: chunks of 4096, 4096 and 449
+END = BEGIN + datetime.timedelta(days=1)
+STEP_SIZE = 10
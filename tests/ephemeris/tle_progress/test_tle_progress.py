@@ -0,0 +1,46 @@
+"""Tests for progress reporting of TLE propagation."""
+
+import numpy as np
+import pytest
+
+from rust_ephem import TLEEphemeris
+
+from .conftest import BEGIN, END, STEP_SIZE, TLE1, TLE2
+
+
+def test_reports_propagated_steps() -> None:
+    calls: list[tuple[int, int]] = []
+
+    def report(done: int, total: int) -> None:
+        calls.append((done, total))
+
+    ephem = TLEEphemeris(TLE1, TLE2, BEGIN, END, step_size=STEP_SIZE, progress=report)
+    assert calls == [(4096, 8641), (8192, 8641), (8641, 8641)]
+    plain = TLEEphemeris(TLE1, TLE2, BEGIN, END, step_size=STEP_SIZE)
+    np.testing.assert_array_equal(ephem.gcrs_pv.position, plain.gcrs_pv.position)
+
+
+def test_progress_every() -> None:
+    calls: list[tuple[int, int]] = []
+    TLEEphemeris(
+        TLE1,
+        TLE2,
+        BEGIN,
+        END,
+        step_size=STEP_SIZE,
+        progress=lambda done, total: calls.append((done, total)),
+        progress_every=2,
+    )
+    assert calls == [(8192, 8641), (8641, 8641)]
+
+
+def test_cancel() -> None:
+    with pytest.raises(InterruptedError):
+        TLEEphemeris(
+            TLE1,
+            TLE2,
+            BEGIN,
+            END,
+            step_size=STEP_SIZE,
+            progress=lambda done, total: False,
+        )
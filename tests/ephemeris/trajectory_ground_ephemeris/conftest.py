@@ -0,0 +1,28 @@
+"""Fixtures for trajectory_ground_ephemeris tests."""
+
+from datetime import datetime, timedelta, timezone
+
+import pytest
+
+from rust_ephem import TrajectoryGroundEphemeris
+
+BEGIN = datetime(2025, 6, 1, 4, 0, 0, tzinfo=timezone.utc)
+# Flight waypoints an hour apart
+WAYPOINT_TIMES = [BEGIN + timedelta(hours=k) for k in range(3)]
+LATITUDES = [37.4, 45.0, 52.2]
+LONGITUDES = [-122.1, -110.5, -95.0]
+HEIGHTS = [12000.0, 13000.0, 13000.0]
+
+
+@pytest.fixture
+def flight() -> TrajectoryGroundEphemeris:
+    """Flight track resampled every minute"""
+    return TrajectoryGroundEphemeris(
+        WAYPOINT_TIMES, LATITUDES, LONGITUDES, HEIGHTS, step_size=60
+    )
+
+
+@pytest.fixture
+def waypoints_only() -> TrajectoryGroundEphemeris:
+    """Flight track at the waypoint times"""
+    return TrajectoryGroundEphemeris(WAYPOINT_TIMES, LATITUDES, LONGITUDES, HEIGHTS)
@@ -0,0 +1,116 @@
+"""Tests for TrajectoryGroundEphemeris."""
+
+from datetime import timedelta
+
+import numpy as np
+import pytest
+
+from rust_ephem import (
+    Constraint,
+    Ephemeris,
+    GroundEphemeris,
+    TrajectoryGroundEphemeris,
+)
+
+from .conftest import BEGIN, HEIGHTS, LATITUDES, LONGITUDES, WAYPOINT_TIMES
+
+
+class TestConstruction:
+    def test_waypoint_times_used_as_is(
+        self, waypoints_only: TrajectoryGroundEphemeris
+    ) -> None:
+        assert len(waypoints_only) == 3
+        np.testing.assert_allclose(waypoints_only.latitude_deg, LATITUDES)
+        np.testing.assert_allclose(waypoints_only.longitude_deg, LONGITUDES)
+        np.testing.assert_allclose(waypoints_only.height_m, HEIGHTS)
+
+    def test_resampled_grid(self, flight: TrajectoryGroundEphemeris) -> None:
+        assert len(flight) == 121
+        assert flight.step_size == 60
+        # Halfway between the first two waypoints
+        assert flight.latitude_deg[30] == pytest.approx(41.2)
+        assert flight.longitude_deg[30] == pytest.approx(-116.3)
+        assert flight.height_m[30] == pytest.approx(12500.0)
+
+    def test_source_track(self, flight: TrajectoryGroundEphemeris) -> None:
+        assert flight.source_timestamp == WAYPOINT_TIMES
+        assert flight.source_latitude == LATITUDES
+        assert flight.source_longitude == LONGITUDES
+        assert flight.source_height == HEIGHTS
+
+    def test_is_ephemeris(self, flight: TrajectoryGroundEphemeris) -> None:
+        assert isinstance(flight, Ephemeris)
+        assert flight.metadata["type"] == "TrajectoryGroundEphemeris"
+        assert flight.metadata["track_points"] == 3
+
+    def test_crosses_antimeridian(self) -> None:
+        times = WAYPOINT_TIMES[:2]
+        ephem = TrajectoryGroundEphemeris(
+            times, [10.0, 10.0], [170.0, -170.0], [0.0, 0.0], step_size=1800
+        )
+        np.testing.assert_allclose(ephem.longitude_deg, [170.0, -180.0, -170.0])
+
+
+class TestValidation:
+    def test_length_mismatch(self) -> None:
+        with pytest.raises(ValueError, match="one value per time"):
+            TrajectoryGroundEphemeris(
+                WAYPOINT_TIMES, LATITUDES[:2], LONGITUDES, HEIGHTS
+            )
+
+    def test_single_point(self) -> None:
+        with pytest.raises(ValueError, match="At least 2"):
+            TrajectoryGroundEphemeris(WAYPOINT_TIMES[:1], [0.0], [0.0], [0.0])
+
+    def test_times_not_increasing(self) -> None:
+        times = list(reversed(WAYPOINT_TIMES))
+        with pytest.raises(ValueError, match="strictly increasing"):
+            TrajectoryGroundEphemeris(times, LATITUDES, LONGITUDES, HEIGHTS)
+
+    def test_latitude_out_of_range(self) -> None:
+        with pytest.raises(ValueError, match="latitude"):
+            TrajectoryGroundEphemeris(
+                WAYPOINT_TIMES, [0.0, 95.0, 0.0], LONGITUDES, HEIGHTS
+            )
+
+    def test_grid_outside_track(self) -> None:
+        with pytest.raises(ValueError, match="exceeds track range"):
+            TrajectoryGroundEphemeris(
+                WAYPOINT_TIMES,
+                LATITUDES,
+                LONGITUDES,
+                HEIGHTS,
+                end=WAYPOINT_TIMES[-1] + timedelta(hours=1),
+            )
+
+
+class TestObserver:
+    def test_matches_fixed_site_when_stationary(self) -> None:
+        end = BEGIN + timedelta(hours=1)
+        ship = TrajectoryGroundEphemeris(
+            [BEGIN, end], [19.8, 19.8], [-155.5, -155.5], [10.0, 10.0], step_size=600
+        )
+        site = GroundEphemeris(19.8, -155.5, 10.0, BEGIN, end, 600)
+        np.testing.assert_allclose(
+            ship.gcrs_pv.position, site.gcrs_pv.position, atol=1e-6
+        )
+        np.testing.assert_allclose(
+            ship.radec_to_altaz(83.63, 22.01),
+            site.radec_to_altaz(83.63, 22.01),
+            atol=1e-6,
+        )
+
+    def test_altaz_follows_platform(self, flight: TrajectoryGroundEphemeris) -> None:
+        # The zenith at each step is the platform's own latitude
+        altaz = flight.radec_to_altaz(0.0, 90.0)
+        np.testing.assert_allclose(altaz[:, 0], flight.latitude_deg, atol=0.5)
+
+    def test_airmass(self, flight: TrajectoryGroundEphemeris) -> None:
+        airmass = flight.calculate_airmass(0.0, 90.0)
+        assert len(airmass) == len(flight)
+        assert all(1.0 <= value < 2.0 for value in airmass)
+
+    def test_constraint_evaluation(self, flight: TrajectoryGroundEphemeris) -> None:
+        result = Constraint.alt_az(min_altitude=30.0).evaluate(flight, 0.0, 90.0)
+        assert result.all_satisfied
+        assert len(result.timestamp) == len(flight)
@@ -0,0 +1,29 @@
+"""Fixtures for body radii tests."""
+
+from collections.abc import Iterator
+from datetime import datetime, timedelta, timezone
+
+import numpy as np
+import pytest
+
+import rust_ephem
+
+BEGIN = datetime(2024, 1, 1, 0, 0, 0, tzinfo=timezone.utc)
+RADIUS_KM = 7000.0
+
+
+@pytest.fixture(autouse=True)
+def restore_radii() -> Iterator[None]:
+    """Undo radius overrides, which are process-wide"""
+    yield
+    rust_ephem.reset_body_radii()
+
+
+@pytest.fixture
+def spacecraft() -> rust_ephem.ArrayEphemeris:
+    """Spacecraft held on +x at RADIUS_KM from the geocentre"""
+    times = [BEGIN + timedelta(minutes=i) for i in range(3)]
+    states = np.zeros((3, 6))
+    states[:, 0] = RADIUS_KM
+    states[:, 4] = 7.5
+    return rust_ephem.ArrayEphemeris(times, states)
@@ -0,0 +1,65 @@
+"""Tests for the configurable body radii registry."""
+
+import numpy as np
+import pytest
+
+import rust_ephem
+
+from .conftest import RADIUS_KM
+
+
+def test_builtin_earth_is_wgs84() -> None:
+    radii = rust_ephem.get_body_radii("Earth")
+    assert radii["equatorial_km"] == pytest.approx(6378.137)
+    assert radii["polar_km"] == pytest.approx(6356.752, abs=1e-3)
+
+
+def test_lookup_by_naif_id_matches_name() -> None:
+    assert rust_ephem.get_body_radii("301") == rust_ephem.get_body_radii("Moon")
+
+
+def test_override_and_reset() -> None:
+    rust_ephem.set_body_radii("Moon", 1800.0)
+    assert rust_ephem.get_body_radii("moon") == {
+        "equatorial_km": 1800.0,
+        "polar_km": 1800.0,
+    }
+    rust_ephem.reset_body_radii("Moon")
+    assert rust_ephem.get_body_radii("Moon")["equatorial_km"] == pytest.approx(1737.4)
+
+
+def test_custom_body_shape() -> None:
+    rust_ephem.set_body_radii("Arrokoth", 18.0, polar_km=10.0)
+    assert rust_ephem.get_body_radii("arrokoth")["polar_km"] == 10.0
+
+
+def test_unknown_body_raises() -> None:
+    with pytest.raises(ValueError, match="set_body_radii"):
+        rust_ephem.get_body_radii("Arrokoth")
+
+
+def test_invalid_radius_raises() -> None:
+    with pytest.raises(ValueError):
+        rust_ephem.set_body_radii("Earth", -1.0)
+
+
+def test_earth_angular_radius_uses_override(
+    spacecraft: rust_ephem.ArrayEphemeris,
+) -> None:
+    rust_ephem.set_body_radii("Earth", 6400.0)
+    expected = np.degrees(np.arcsin(6400.0 / RADIUS_KM))
+    np.testing.assert_allclose(spacecraft.earth_radius_deg, expected)
+
+
+def test_inflated_earth_widens_limb_avoidance(
+    spacecraft: rust_ephem.ArrayEphemeris,
+) -> None:
+    # A target just outside the WGS84 limb, 1 degree beyond it from nadir (-x)
+    limb_from_nadir = np.degrees(np.arcsin(6378.137 / RADIUS_KM))
+    ra = 180.0 - limb_from_nadir - 1.0
+    builtin = rust_ephem.Constraint.earth_limb(0.0)
+    assert not any(builtin.evaluate(spacecraft, ra, 0.0).constraint_array)
+
+    rust_ephem.set_body_radii("Earth", 6500.0)
+    inflated = rust_ephem.Constraint.earth_limb(0.0)
+    assert all(inflated.evaluate(spacecraft, ra, 0.0).constraint_array)
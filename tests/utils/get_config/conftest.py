@@ -0,0 +1,3 @@
+"""Fixtures for test_get_config tests."""
+
+SECTIONS = ("cache", "kernels", "network", "spacetrack", "tle")
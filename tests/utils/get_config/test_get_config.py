@@ -0,0 +1,29 @@
+from pathlib import Path
+
+from rust_ephem import get_cache_dir, get_config
+
+from .conftest import SECTIONS
+
+
+class TestGetConfig:
+    def test_get_config_sections(self) -> None:
+        config = get_config()
+        for section in SECTIONS:
+            assert isinstance(config[section], dict)
+        assert "config_path" in config
+
+    def test_get_config_cache_dir_matches(self) -> None:
+        config = get_config()
+        assert Path(config["cache"]["dir"]) == Path(get_cache_dir())
+
+    def test_get_config_value_types(self) -> None:
+        config = get_config()
+        assert isinstance(config["cache"]["eop_ttl"], int)
+        assert isinstance(config["network"]["timeout"], int)
+        assert isinstance(config["tle"]["max_epoch_offset_days"], float)
+        assert isinstance(config["tle"]["check_decay"], bool)
+        assert config["network"]["horizons_url"].startswith("http")
+
+    def test_get_config_password_redacted(self) -> None:
+        password = get_config()["spacetrack"]["password"]
+        assert password is None or password == "<redacted>"
@@ -0,0 +1,4 @@
+"""Fixtures for resolve_name tests."""
+
+# A name no resolver knows, so it is never in the cache
+UNKNOWN_NAME = "rust-ephem test object that does not exist"
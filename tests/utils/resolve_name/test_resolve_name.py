@@ -0,0 +1,17 @@
+"""Tests for Sesame name resolution that do not need network access."""
+
+import pytest
+
+from rust_ephem import resolve_name
+
+from .conftest import UNKNOWN_NAME
+
+
+class TestResolveName:
+    def test_offline_uncached_name_raises(self) -> None:
+        with pytest.raises(ValueError, match="network access is disabled"):
+            resolve_name(UNKNOWN_NAME, allow_network=False)
+
+    def test_empty_name_raises(self) -> None:
+        with pytest.raises(ValueError, match="must not be empty"):
+            resolve_name("   ", allow_network=False)
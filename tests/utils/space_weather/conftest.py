@@ -0,0 +1,38 @@
+"""Fixtures for space-weather tests."""
+
+from pathlib import Path
+
+import pytest
+
+import rust_ephem
+
+SW_HEADER = (
+    "DATE,BSRN,ND,KP1,KP2,KP3,KP4,KP5,KP6,KP7,KP8,KP_SUM,"
+    "AP1,AP2,AP3,AP4,AP5,AP6,AP7,AP8,AP_AVG,CP,C9,ISN,"
+    "F10.7_OBS,F10.7_ADJ,F10.7_DATA_TYPE,F10.7_OBS_CENTER81,F10.7_OBS_LAST81,"
+    "F10.7_ADJ_CENTER81,F10.7_ADJ_LAST81"
+)
+
+SW_ROWS = [
+    "2024-05-09,2605,27,7,10,13,17,20,23,27,30,147,"
+    "3,4,5,6,7,9,12,15,8,0.4,2,180,209.3,215.6,OBS,169.9,167.5,174.4,171.6",
+    "2024-05-10,2606,1,40,53,57,70,83,87,90,90,570,"
+    "27,56,67,132,236,300,400,400,202,2.4,9,196,"
+    "190.4,196.1,OBS,170.5,168.2,175.0,172.3",
+    "2024-06-01,2607,23,,,,,,,,,,,,,,,,,,12,,,,165.0,169.8,PRD,160.1,,,",
+]
+
+
+@pytest.fixture
+def sw_file(tmp_path: Path) -> Path:
+    """A small SW-All.csv with two observed days and one predicted day"""
+    path = tmp_path / "SW-All.csv"
+    path.write_text("\n".join([SW_HEADER, *SW_ROWS]) + "\n")
+    return path
+
+
+@pytest.fixture
+def loaded_sw(sw_file: Path) -> Path:
+    """Load the sample file as the space-weather data"""
+    assert rust_ephem.load_space_weather(str(sw_file)) == len(SW_ROWS)
+    return sw_file
@@ -0,0 +1,66 @@
+"""Tests for Celestrak space-weather lookup."""
+
+from datetime import datetime, timedelta, timezone
+from pathlib import Path
+
+import numpy as np
+import pytest
+
+import rust_ephem
+from rust_ephem import space_weather
+
+
+def test_lookup_three_hour_interval(loaded_sw: Path) -> None:
+    time = datetime(2024, 5, 10, 4, 30, tzinfo=timezone.utc)
+    sw = rust_ephem.get_space_weather(time)
+    assert sw is not None
+    assert sw["date"] == "2024-05-10"
+    assert sw["kp"] == pytest.approx(5.3)
+    assert sw["ap"] == 56.0
+    assert sw["ap_daily"] == 202.0
+    assert sw["f107_obs"] == pytest.approx(190.4)
+    assert sw["f107_adj"] == pytest.approx(196.1)
+    assert sw["f107_obs_previous"] == pytest.approx(209.3)
+    assert sw["f107_obs_center81"] == pytest.approx(170.5)
+    assert sw["data_type"] == "OBS"
+
+
+def test_last_interval_of_day(loaded_sw: Path) -> None:
+    sw = rust_ephem.get_space_weather(datetime(2024, 5, 10, 23, 59, 59))
+    assert sw is not None
+    assert sw["kp"] == pytest.approx(9.0)
+
+
+def test_predicted_day_has_no_kp(loaded_sw: Path) -> None:
+    sw = rust_ephem.get_space_weather(datetime(2024, 6, 1, 12, tzinfo=timezone.utc))
+    assert sw is not None
+    assert sw["data_type"] == "PRD"
+    assert sw["kp"] is None
+    assert sw["ap_daily"] == 12.0
+    assert sw["f107_obs"] == pytest.approx(165.0)
+    assert sw["f107_obs_previous"] is None
+
+
+def test_outside_data(loaded_sw: Path) -> None:
+    time = datetime(2024, 5, 20, tzinfo=timezone.utc)
+    assert rust_ephem.get_space_weather(time) is None
+    assert rust_ephem.is_space_weather_available()
+
+
+def test_series(loaded_sw: Path) -> None:
+    start = datetime(2024, 5, 9, 21, tzinfo=timezone.utc)
+    times = [start + timedelta(hours=3 * i) for i in range(3)]
+    times.append(datetime(2024, 6, 1, tzinfo=timezone.utc))
+    series = space_weather.space_weather_series(times)
+    np.testing.assert_allclose(series["kp"][:3], [3.0, 4.0, 5.3])
+    assert np.isnan(series["kp"][3])
+    np.testing.assert_allclose(series["ap_daily"], [8.0, 202.0, 202.0, 12.0])
+
+
+def test_load_rejects_bad_file(tmp_path: Path) -> None:
+    path = tmp_path / "bad.csv"
+    path.write_text("<html>Service unavailable</html>\n")
+    with pytest.raises(ValueError):
+        rust_ephem.load_space_weather(str(path))
+    with pytest.raises(ValueError):
+        rust_ephem.load_space_weather(str(tmp_path / "missing.csv"))
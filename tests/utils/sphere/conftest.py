@@ -0,0 +1,4 @@
+"""Fixtures for spherical geometry tests."""
+
+# A 20-degree square about the vernal equinox, listed anticlockwise
+SQUARE = [(-10.0, -10.0), (10.0, -10.0), (10.0, 10.0), (-10.0, 10.0)]
@@ -0,0 +1,94 @@
+"""Tests for the rust_ephem.sphere great-circle helpers."""
+
+import numpy as np
+import pytest
+
+from rust_ephem import SphericalPolygon, sphere
+
+from .conftest import SQUARE
+
+
+class TestSeparation:
+    def test_known_values(self) -> None:
+        assert sphere.separation(0.0, 0.0, 90.0, 0.0) == pytest.approx(90.0)
+        assert sphere.separation(0.0, -90.0, 45.0, 90.0) == pytest.approx(180.0)
+        assert sphere.separation(12.0, 34.0, 12.0, 34.0) == pytest.approx(0.0)
+
+    def test_scalar_input_gives_float(self) -> None:
+        assert isinstance(sphere.separation(0.0, 0.0, 1.0, 0.0), float)
+
+    def test_broadcasts(self) -> None:
+        lons = np.array([[0.0, 10.0, 20.0]])
+        lats = np.array([[0.0], [30.0]])
+        result = sphere.separation(0.0, 0.0, lons, lats)
+        assert result.shape == (2, 3)
+        assert result[0, 2] == pytest.approx(20.0)
+        assert result[1, 0] == pytest.approx(30.0)
+
+    def test_small_angles_are_precise(self) -> None:
+        arcsec = 1.0 / 3600.0
+        assert sphere.separation(45.0, 30.0, 45.0, 30.0 + arcsec) == pytest.approx(
+            arcsec, rel=1e-9
+        )
+
+
+class TestPositionAngle:
+    def test_cardinal_directions(self) -> None:
+        lons = [0.0, 5.0, 0.0, -5.0]
+        lats = [5.0, 0.0, -5.0, 0.0]
+        angles = sphere.position_angle(0.0, 0.0, lons, lats)
+        assert angles == pytest.approx([0.0, 90.0, 180.0, 270.0])
+
+    def test_bearing_matches_position_angle(self) -> None:
+        # London to New York heads west-north-west
+        bearing = sphere.bearing(-0.13, 51.51, -74.01, 40.71)
+        assert 280.0 < bearing < 290.0
+        assert bearing == sphere.position_angle(-0.13, 51.51, -74.01, 40.71)
+
+
+class TestConesOverlap:
+    def test_overlap(self) -> None:
+        result = sphere.cones_overlap(0.0, 0.0, 5.0, [8.0, 12.0], 0.0, 5.0)
+        assert list(result) == [True, False]
+
+    def test_negative_radius_raises(self) -> None:
+        with pytest.raises(ValueError):
+            sphere.cones_overlap(0.0, 0.0, -1.0, 1.0, 0.0, 1.0)
+
+
+class TestSphericalPolygon:
+    def test_contains(self) -> None:
+        polygon = SphericalPolygon(SQUARE)
+        assert polygon.contains(0.0, 0.0) is True
+        assert polygon.contains(359.0, 9.0) is True
+        assert polygon.contains(20.0, 0.0) is False
+        # The antipode of an interior point is outside
+        assert polygon.contains(180.0, 0.0) is False
+        mask = polygon.contains(np.array([0.0, 20.0]), np.array([0.0, 0.0]))
+        assert list(mask) == [True, False]
+
+    def test_orientation_does_not_matter(self) -> None:
+        forward = SphericalPolygon(SQUARE)
+        reverse = SphericalPolygon(SQUARE[::-1])
+        assert forward.solid_angle == pytest.approx(reverse.solid_angle)
+        assert reverse.contains(0.0, 0.0) is True
+
+    def test_closing_vertex_dropped(self) -> None:
+        polygon = SphericalPolygon(SQUARE + [SQUARE[0]])
+        assert polygon.vertices == SQUARE
+
+    def test_octant_area(self) -> None:
+        octant = SphericalPolygon([(0.0, 0.0), (90.0, 0.0), (0.0, 90.0)])
+        assert octant.solid_angle == pytest.approx(np.pi / 2)
+
+    def test_invalid_polygons(self) -> None:
+        with pytest.raises(ValueError):
+            SphericalPolygon([(0.0, 0.0), (10.0, 0.0)])
+        with pytest.raises(ValueError):
+            SphericalPolygon([(0.0, 0.0), (120.0, 0.0), (240.0, 0.0)])
+
+    def test_point_in_polygon_accepts_vertices(self) -> None:
+        result = sphere.point_in_polygon(SQUARE, [[0.0, 20.0]], [[5.0], [-5.0]])
+        assert result.shape == (2, 2)
+        assert result[:, 0].all() and not result[:, 1].any()
+        assert sphere.point_in_polygon(SphericalPolygon(SQUARE), 0.0, 0.0) is True
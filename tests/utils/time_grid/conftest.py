@@ -0,0 +1,18 @@
+"""Fixtures for timestamp grid time scale tests."""
+
+from datetime import datetime, timezone
+
+import rust_ephem
+
+# A grid straddling the leap second at the end of 2016
+BEGIN = datetime(2016, 12, 31, 23, 59, 0, tzinfo=timezone.utc)
+END = datetime(2017, 1, 1, 0, 1, 0, tzinfo=timezone.utc)
+STEP = 30
+
+
+def ground_grid(time_scale: str = "UTC") -> list[str]:
+    """Timestamps of a ground ephemeris over the leap second, as ISO strings"""
+    eph = rust_ephem.GroundEphemeris(
+        0.0, 0.0, 0.0, BEGIN, END, STEP, time_scale=time_scale
+    )
+    return [str(t) for t in eph.timestamp]
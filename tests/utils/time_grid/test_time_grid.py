@@ -0,0 +1,106 @@
+"""Tests for leap-second-aware timestamp grids and epoch parsing"""
+
+from datetime import datetime, timezone
+from typing import Any
+
+import numpy as np
+import pytest
+
+import rust_ephem
+
+from .conftest import BEGIN, END, STEP, ground_grid
+
+
+def test_default_scale_is_utc() -> None:
+    eph = rust_ephem.GroundEphemeris(0.0, 0.0, 0.0, BEGIN, END, STEP)
+    assert eph.time_scale == "UTC"
+
+
+def test_utc_grid_skips_leap_second() -> None:
+    assert ground_grid() == [
+        "2016-12-31T23:59:00.000000",
+        "2016-12-31T23:59:30.000000",
+        "2017-01-01T00:00:00.000000",
+        "2017-01-01T00:00:30.000000",
+        "2017-01-01T00:01:00.000000",
+    ]
+
+
+@pytest.mark.parametrize("scale", ["TAI", "tt"])
+def test_si_grid_steps_through_leap_second(scale: str) -> None:
+    assert ground_grid(scale) == [
+        "2016-12-31T23:59:00.000000",
+        "2016-12-31T23:59:30.000000",
+        # 23:59:60, which datetime64 cannot label
+        "2016-12-31T23:59:59.999999",
+        "2017-01-01T00:00:29.000000",
+        "2017-01-01T00:00:59.000000",
+    ]
+
+
+def test_si_grid_matches_utc_without_leap_second() -> None:
+    begin = datetime(2024, 1, 1, tzinfo=timezone.utc)
+    end = datetime(2024, 1, 2, tzinfo=timezone.utc)
+    utc = rust_ephem.GroundEphemeris(0.0, 0.0, 0.0, begin, end, 600).timestamp
+    tai = rust_ephem.GroundEphemeris(
+        0.0, 0.0, 0.0, begin, end, 600, time_scale="TAI"
+    ).timestamp
+    np.testing.assert_array_equal(utc, tai)
+
+
+def test_scale_belongs_to_each_ephemeris() -> None:
+    tai = rust_ephem.GroundEphemeris(0.0, 0.0, 0.0, BEGIN, END, STEP, time_scale="tt")
+    utc = rust_ephem.GroundEphemeris(0.0, 0.0, 0.0, BEGIN, END, STEP)
+    assert tai.time_scale == "TT"
+    assert utc.time_scale == "UTC"
+    assert len(tai.timestamp) == len(utc.timestamp) == 5
+    assert str(utc.timestamp[2]) == "2017-01-01T00:00:00.000000"
+
+
+def test_unknown_scale_raises() -> None:
+    with pytest.raises(ValueError, match="Unknown time scale"):
+        rust_ephem.GroundEphemeris(0.0, 0.0, 0.0, BEGIN, END, STEP, time_scale="GPS")
+
+
+def test_oem_leap_second_epoch(tmp_path: Any) -> None:
+    path = tmp_path / "leap.oem"
+    path.write_text(
+        "CCSDS_OEM_VERS = 2.0\n"
+        "META_START\n"
+        "CENTER_NAME = EARTH\n"
+        "REF_FRAME = EME2000\n"
+        "TIME_SYSTEM = UTC\n"
+        "META_STOP\n"
+        "2016-12-31T23:59:59.000 7000.0 0.0 0.0 0.0 7.5 0.0\n"
+        "2016-12-31T23:59:60.000 7000.0 7.5 0.0 0.0 7.5 0.0\n"
+        "2017-01-01T00:00:00.000 7000.0 15.0 0.0 0.0 7.5 0.0\n"
+    )
+    begin = datetime(2016, 12, 31, 23, 59, 59, tzinfo=timezone.utc)
+    end = datetime(2017, 1, 1, 0, 0, 0, tzinfo=timezone.utc)
+    eph = rust_ephem.OEMEphemeris(
+        str(path), begin=begin, end=end, step_size=1, time_scale="TAI"
+    )
+    # The leap second is a state of its own, not merged into the next second
+    assert len(eph.timestamp) == 3
+    np.testing.assert_allclose(
+        eph.gcrs_pv.position[:, 1], [0.0, 7.5, 15.0], atol=1e-9
+    )
+
+
+def test_oem_second_60_outside_leap_second_raises(tmp_path: Any) -> None:
+    path = tmp_path / "not_leap.oem"
+    path.write_text(
+        "CCSDS_OEM_VERS = 2.0\n"
+        "META_START\n"
+        "CENTER_NAME = EARTH\n"
+        "REF_FRAME = EME2000\n"
+        "TIME_SYSTEM = UTC\n"
+        "META_STOP\n"
+        "2016-06-30T23:59:59.000 7000.0 0.0 0.0 0.0 7.5 0.0\n"
+        "2016-06-30T23:59:60.000 7000.0 7.5 0.0 0.0 7.5 0.0\n"
+        "2016-07-01T00:00:00.000 7000.0 15.0 0.0 0.0 7.5 0.0\n"
+    )
+    begin = datetime(2016, 6, 30, 23, 59, 59, tzinfo=timezone.utc)
+    end = datetime(2016, 7, 1, 0, 0, 0, tzinfo=timezone.utc)
+    with pytest.raises(ValueError, match="is not a leap second"):
+        rust_ephem.OEMEphemeris(str(path), begin=begin, end=end, step_size=1)
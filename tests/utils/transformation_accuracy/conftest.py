@@ -0,0 +1,15 @@
+"""Fixtures for transformation accuracy report tests."""
+
+import datetime
+
+import pytest
+
+EPOCH = datetime.datetime(2024, 6, 1, 0, 0, 0, tzinfo=datetime.timezone.utc)
+
+
+@pytest.fixture
+def far_future() -> datetime.datetime:
+    """An epoch well past any EOP prediction or leap-second notice"""
+    return datetime.datetime.now(datetime.timezone.utc) + datetime.timedelta(
+        days=3650
+    )
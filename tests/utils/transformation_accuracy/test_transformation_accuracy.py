@@ -0,0 +1,58 @@
+"""Tests for transformation_accuracy."""
+
+import datetime
+import math
+
+import rust_ephem
+
+from .conftest import EPOCH
+
+CONTRIBUTIONS = {"dut1", "polar_motion", "precession_nutation", "leap_seconds"}
+
+
+def test_report_keys() -> None:
+    report = rust_ephem.transformation_accuracy(EPOCH)
+    assert set(report["contributions_arcsec"]) == CONTRIBUTIONS
+    assert report["polar_motion"] is True
+    assert report["eop_available"] is rust_ephem.is_eop_available()
+    assert report["ut1_available"] is rust_ephem.is_ut1_available()
+
+
+def test_total_is_root_sum_square() -> None:
+    report = rust_ephem.transformation_accuracy(EPOCH)
+    terms = report["contributions_arcsec"].values()
+    assert math.isclose(
+        report["total_arcsec"], math.sqrt(sum(t * t for t in terms))
+    )
+    # One arcsecond is about 31 m at the Earth's equator
+    assert math.isclose(report["total_m"], report["total_arcsec"] * 30.92, rel_tol=1e-3)
+
+
+def test_precession_nutation_grows_since_j2000() -> None:
+    early = rust_ephem.transformation_accuracy(
+        datetime.datetime(2001, 1, 1, tzinfo=datetime.timezone.utc)
+    )
+    late = rust_ephem.transformation_accuracy(EPOCH)
+    early_term = early["contributions_arcsec"]["precession_nutation"]
+    late_term = late["contributions_arcsec"]["precession_nutation"]
+    assert early_term < late_term
+    assert 400.0 < late_term < 600.0
+
+
+def test_far_future_is_predicted(far_future: datetime.datetime) -> None:
+    report = rust_ephem.transformation_accuracy(far_future)
+    assert report["beyond_eop_data"] is True
+    assert report["days_predicted"] > 3000.0
+    contributions = report["contributions_arcsec"]
+    # Without usable UT1 the full 0.9 s bound of UT1-UTC applies
+    assert math.isclose(contributions["dut1"], 0.9 * 15.041, rel_tol=1e-3)
+    assert contributions["leap_seconds"] > 15.0
+
+
+def test_not_applying_polar_motion_costs_accuracy() -> None:
+    applied = rust_ephem.transformation_accuracy(EPOCH, polar_motion=True)
+    skipped = rust_ephem.transformation_accuracy(EPOCH, polar_motion=False)
+    assert (
+        skipped["contributions_arcsec"]["polar_motion"]
+        >= applied["contributions_arcsec"]["polar_motion"]
+    )
@@ -29,6 +29,10 @@ class TestModuleLevelFunctions:
         cache_dir: str = rust_ephem.get_cache_dir()
         assert len(cache_dir) > 0
 
+    def test_clear_horizons_cache_returns_none(self) -> None:
+        result: None = rust_ephem.clear_horizons_cache()
+        assert result is None
+
     def test_is_planetary_ephemeris_initialized_returns_bool(self) -> None:
         is_init: bool = rust_ephem.is_planetary_ephemeris_initialized()
         assert isinstance(is_init, bool)